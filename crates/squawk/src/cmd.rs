@@ -1,7 +1,15 @@
 use std::{path::PathBuf, process};
 
+use squawk_fmt::FormatOptions;
+
 use crate::{
-    Command, config::Config, debug::DebugArgs, file_finding::find_paths, reporter::LintArgs,
+    Command, Opts,
+    config::{Config, ServerConfigOpts},
+    debug::DebugArgs,
+    file_finding::find_paths,
+    fingerprint::FingerprintFiles,
+    format::FormatFiles,
+    reporter::{LintArgs, LintSettings},
 };
 
 pub(crate) struct Stdin {
@@ -15,10 +23,12 @@ pub(crate) enum Input {
 
 pub(crate) enum Cmd {
     Debug(DebugArgs),
+    Fingerprint(FingerprintFiles),
+    Format(FormatFiles),
     Lint(LintArgs),
     Help,
     None,
-    Server,
+    Server(ServerConfigOpts),
     UploadToGithub(Box<Config>),
 }
 
@@ -53,14 +63,21 @@ impl Cmd {
                     input,
                     debug_option,
                     verbose: conf.verbose,
+                    templating: conf.templating,
+                    dialect: conf.dialect,
                 });
             } else {
                 return Cmd::Lint(LintArgs {
                     input,
-                    excluded_rules: conf.excluded_rules,
-                    included_rules: conf.included_rules,
-                    pg_version: conf.pg_version,
-                    assume_in_transaction: conf.assume_in_transaction,
+                    settings: LintSettings {
+                        excluded_rules: conf.excluded_rules,
+                        included_rules: conf.included_rules,
+                        pg_version: conf.pg_version,
+                        assume_in_transaction: conf.assume_in_transaction,
+                        framework: conf.framework,
+                        templating: conf.templating,
+                        dialect: conf.dialect,
+                    },
                     reporter: conf.reporter,
                     github_annotations: conf.github_annotations,
                 });
@@ -72,9 +89,69 @@ impl Cmd {
         }
     }
 
-    pub(crate) fn from(opts: crate::Opts) -> Cmd {
+    pub(crate) fn from(opts: Opts) -> Cmd {
         match opts.cmd {
-            Some(Command::Server) => Cmd::Server,
+            Some(Command::Server) => Cmd::Server(ServerConfigOpts::from(opts)),
+            Some(Command::Fingerprint(args)) => {
+                let conf = Config::from(Opts { cmd: None, ..opts });
+                let found_paths =
+                    find_paths(&args.paths, &conf.excluded_paths).unwrap_or_else(|e| {
+                        eprintln!("Failed to find files: {e}");
+                        process::exit(1);
+                    });
+                if found_paths.is_empty() && !args.paths.is_empty() {
+                    eprintln!(
+                        "Failed to find files for provided patterns: {:?}",
+                        args.paths
+                    );
+                    process::exit(1);
+                }
+                let input = if found_paths.is_empty() {
+                    Input::Stdin(Stdin { path: None })
+                } else {
+                    Input::Paths(found_paths)
+                };
+                Cmd::Fingerprint(FingerprintFiles {
+                    input,
+                    templating: conf.templating,
+                    dialect: conf.dialect,
+                })
+            }
+            Some(Command::Format(args)) => {
+                let conf = Config::from(Opts { cmd: None, ..opts });
+                let found_paths =
+                    find_paths(&args.paths, &conf.excluded_paths).unwrap_or_else(|e| {
+                        eprintln!("Failed to find files: {e}");
+                        process::exit(1);
+                    });
+                if found_paths.is_empty() && !args.paths.is_empty() {
+                    eprintln!(
+                        "Failed to find files for provided patterns: {:?}",
+                        args.paths
+                    );
+                    if !conf.no_error_on_unmatched_pattern {
+                        process::exit(1);
+                    }
+                    return Cmd::None;
+                }
+                let input = if found_paths.is_empty() {
+                    Input::Stdin(Stdin {
+                        path: conf.stdin_filepath,
+                    })
+                } else {
+                    Input::Paths(found_paths)
+                };
+                Cmd::Format(FormatFiles {
+                    input,
+                    options: FormatOptions {
+                        dialect: conf.dialect,
+                        templating: conf.templating,
+                        ..conf.format
+                    },
+                    check: args.check,
+                    diff: args.diff,
+                })
+            }
             Some(Command::UploadToGithub(_)) => {
                 let conf = Config::from(opts);
                 Cmd::UploadToGithub(Box::new(conf))
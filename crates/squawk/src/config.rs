@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
 use log::info;
-use serde::Deserialize;
-use squawk_linter::{Rule, Version};
+use serde::{Deserialize, Deserializer};
+use squawk_fmt::FormatOptions;
+use squawk_lexer::Templating;
+use squawk_linter::{Dialect, Framework, Rule, Version};
+use squawk_server::ServerConfig;
 use std::{
     env,
     io::{self, IsTerminal},
@@ -32,15 +35,45 @@ pub struct ConfigFile {
     #[serde(default)]
     pub assume_in_transaction: Option<bool>,
     #[serde(default)]
+    pub framework: Option<Framework>,
+    #[serde(default, deserialize_with = "deserialize_templating")]
+    pub templating: Option<Templating>,
+    #[serde(default, deserialize_with = "deserialize_dialect")]
+    pub dialect: Option<Dialect>,
+    #[serde(default)]
     pub upload_to_github: UploadToGitHubConfig,
+    #[serde(default)]
+    pub format: FormatOptions,
+}
+
+fn deserialize_templating<'de, D>(deserializer: D) -> Result<Option<Templating>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
+fn deserialize_dialect<'de, D>(deserializer: D) -> Result<Option<Dialect>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map(Some).map_err(serde::de::Error::custom)
 }
 
 impl ConfigFile {
     pub fn parse(custom_path: Option<PathBuf>) -> Result<Option<Self>> {
+        Self::parse_from(custom_path, &env::current_dir()?)
+    }
+
+    /// Like [`ConfigFile::parse`], but looks for the config file from `dir`
+    /// instead of the current directory.
+    pub fn parse_from(custom_path: Option<PathBuf>, dir: &Path) -> Result<Option<Self>> {
         let path = if let Some(path) = custom_path {
             Some(path)
         } else {
-            find_by_traversing_back()?
+            find_by_traversing_back(dir)?
         };
 
         if let Some(p) = path {
@@ -55,14 +88,65 @@ impl ConfigFile {
     }
 }
 
+/// The command line options the language server reads the config file with,
+/// once the client tells it where the workspace folder is.
+pub struct ServerConfigOpts {
+    config_path: Option<PathBuf>,
+    excluded_paths: Option<Vec<String>>,
+    dialect: Option<Dialect>,
+}
+
+impl ServerConfigOpts {
+    pub fn from(opts: Opts) -> ServerConfigOpts {
+        ServerConfigOpts {
+            config_path: opts.config_path,
+            excluded_paths: opts.excluded_path,
+            dialect: opts.dialect,
+        }
+    }
+
+    /// Unlike [`Config::from`], an invalid config file is an error for the
+    /// server to report instead of exiting.
+    pub fn load(&self, root: Option<&Path>) -> Result<ServerConfig> {
+        let dir = if let Some(root) = root {
+            root.to_path_buf()
+        } else {
+            env::current_dir()?
+        };
+        let conf = ConfigFile::parse_from(self.config_path.clone(), &dir)?.unwrap_or_default();
+
+        // the --exclude-path flag completely overrides the configuration file.
+        let excluded_paths = if let Some(excluded_paths) = &self.excluded_paths {
+            excluded_paths.clone()
+        } else {
+            conf.excluded_paths
+        };
+        let dialect = if let Some(dialect) = self.dialect {
+            dialect
+        } else {
+            conf.dialect.unwrap_or_default()
+        };
+
+        Ok(ServerConfig {
+            format_options: conf.format,
+            excluded_paths,
+            dialect,
+        })
+    }
+}
+
 pub struct Config {
     pub excluded_paths: Vec<String>,
     pub excluded_rules: Vec<Rule>,
     pub included_rules: Vec<Rule>,
     pub pg_version: Option<Version>,
     pub assume_in_transaction: bool,
+    pub framework: Option<Framework>,
+    pub templating: Option<Templating>,
+    pub dialect: Dialect,
     pub upload_to_github: UploadToGitHubConfig,
     pub upload_to_github_args: Option<UploadToGithubArgs>,
+    pub format: FormatOptions,
     pub no_error_on_unmatched_pattern: bool,
     pub is_stdin: bool,
     pub stdin_filepath: Option<String>,
@@ -116,6 +200,24 @@ impl Config {
             conf.assume_in_transaction.unwrap_or_default()
         };
 
+        let framework = if let Some(framework) = opts.framework {
+            Some(framework)
+        } else {
+            conf.framework
+        };
+
+        let templating = if let Some(templating) = opts.templating {
+            Some(templating)
+        } else {
+            conf.templating
+        };
+
+        let dialect = if let Some(dialect) = opts.dialect {
+            dialect
+        } else {
+            conf.dialect.unwrap_or_default()
+        };
+
         let no_error_on_unmatched_pattern = if opts.no_error_on_unmatched_pattern {
             opts.no_error_on_unmatched_pattern
         } else {
@@ -128,6 +230,9 @@ impl Config {
         info!("included rules: {:?}", &included_rules);
         info!("excluded paths: {:?}", &excluded_paths);
         info!("assume in a transaction: {assume_in_transaction:?}");
+        info!("framework: {framework:?}");
+        info!("templating: {templating:?}");
+        info!("dialect: {dialect:?}");
         info!("no error on unmatched pattern: {no_error_on_unmatched_pattern:?}");
 
         let is_stdin = !io::stdin().is_terminal();
@@ -154,8 +259,12 @@ impl Config {
             included_rules,
             pg_version,
             assume_in_transaction,
+            framework,
+            templating,
+            dialect,
             upload_to_github,
             upload_to_github_args,
+            format: conf.format,
             no_error_on_unmatched_pattern,
             is_stdin,
             stdin_filepath,
@@ -182,9 +291,8 @@ fn recurse_directory(directory: &Path, file_name: &str) -> Result<Option<PathBuf
     }
 }
 
-fn find_by_traversing_back() -> Result<Option<PathBuf>> {
-    recurse_directory(&env::current_dir()?, FILE_NAME)
-        .context("Error when finding configuration file")
+fn find_by_traversing_back(dir: &Path) -> Result<Option<PathBuf>> {
+    recurse_directory(dir, FILE_NAME).context("Error when finding configuration file")
 }
 
 #[cfg(test)]
@@ -260,6 +368,100 @@ fail_on_violations = true
         assert_debug_snapshot!(ConfigFile::parse(Some(squawk_toml.path().to_path_buf())));
     }
     #[test]
+    fn load_framework() {
+        let squawk_toml = NamedTempFile::new().expect("generate tempFile");
+        let file = r#"
+framework = "goose"
+
+        "#;
+        fs::write(&squawk_toml, file).expect("Unable to write file");
+        assert_debug_snapshot!(ConfigFile::parse(Some(squawk_toml.path().to_path_buf())));
+    }
+    #[test]
+    fn load_templating() {
+        let squawk_toml = NamedTempFile::new().expect("generate tempFile");
+        let file = r#"
+templating = "jinja"
+
+        "#;
+        fs::write(&squawk_toml, file).expect("Unable to write file");
+        assert_debug_snapshot!(ConfigFile::parse(Some(squawk_toml.path().to_path_buf())));
+    }
+    #[test]
+    fn load_dialect() {
+        let squawk_toml = NamedTempFile::new().expect("generate tempFile");
+        let file = r#"
+dialect = "aurora-dsql"
+
+        "#;
+        fs::write(&squawk_toml, file).expect("Unable to write file");
+        assert_debug_snapshot!(ConfigFile::parse(Some(squawk_toml.path().to_path_buf())));
+    }
+    #[test]
+    fn load_server_config_from_workspace() {
+        let workspace = tempfile::tempdir().expect("generate tempdir");
+        let file = r#"
+excluded_paths = ["vendor/**"]
+dialect = "aurora-dsql"
+
+[format]
+keyword_case = "upper"
+        "#;
+        fs::write(workspace.path().join(FILE_NAME), file).expect("Unable to write file");
+        let opts = ServerConfigOpts {
+            config_path: None,
+            excluded_paths: None,
+            dialect: None,
+        };
+        let config = opts.load(Some(workspace.path())).unwrap();
+        assert_eq!(config.excluded_paths, vec!["vendor/**".to_owned()]);
+        assert_eq!(config.dialect, Dialect::AuroraDsql);
+        assert_eq!(
+            config.format_options.keyword_case,
+            squawk_fmt::KeywordCase::Upper
+        );
+
+        // the command line flags still take precedence
+        let opts = ServerConfigOpts {
+            config_path: None,
+            excluded_paths: Some(vec![]),
+            dialect: Some(Dialect::Postgres),
+        };
+        let config = opts.load(Some(workspace.path())).unwrap();
+        assert!(config.excluded_paths.is_empty());
+        assert_eq!(config.dialect, Dialect::Postgres);
+    }
+
+    #[test]
+    fn load_server_config_error() {
+        let workspace = tempfile::tempdir().expect("generate tempdir");
+        fs::write(workspace.path().join(FILE_NAME), "dialect = 1").expect("Unable to write file");
+        let opts = ServerConfigOpts {
+            config_path: None,
+            excluded_paths: None,
+            dialect: None,
+        };
+        assert!(opts.load(Some(workspace.path())).is_err());
+    }
+
+    #[test]
+    fn load_format() {
+        let squawk_toml = NamedTempFile::new().expect("generate tempFile");
+        let file = r#"
+[format]
+keyword_case = "upper"
+indent_width = 4
+quote_identifiers = "preserve"
+alias_as = "always"
+trailing_commas = true
+max_line_width = 100
+comma_style = "leading"
+
+        "#;
+        fs::write(&squawk_toml, file).expect("Unable to write file");
+        assert_debug_snapshot!(ConfigFile::parse(Some(squawk_toml.path().to_path_buf())));
+    }
+    #[test]
     fn load_included_rules() {
         let squawk_toml = NamedTempFile::new().expect("generate tempFile");
         let file = r#"
@@ -3,7 +3,7 @@ use std::io;
 use annotate_snippets::{AnnotationKind, Level, Renderer, Snippet, renderer::DecorStyle};
 use anyhow::Result;
 use serde_json::json;
-use squawk_syntax::{ast::AstNode, syntax_error::SyntaxError};
+use squawk_syntax::{Dialect, Templating, ast::AstNode, syntax_error::SyntaxError};
 
 use crate::{
     DebugOption,
@@ -15,13 +15,15 @@ pub(crate) struct DebugArgs {
     pub(crate) input: Input,
     pub(crate) debug_option: DebugOption,
     pub(crate) verbose: bool,
+    pub(crate) templating: Option<Templating>,
+    pub(crate) dialect: Dialect,
 }
 
 pub(crate) fn debug<W: io::Write>(f: &mut W, args: DebugArgs) -> Result<()> {
     let process_dump_ast = |sql: &str, filename: &str, f: &mut W| -> Result<()> {
         match args.debug_option {
             DebugOption::Lex => {
-                let tokens = squawk_lexer::tokenize(sql);
+                let tokens = squawk_lexer::tokenize_with(sql, args.templating);
                 let mut start = 0;
                 for token in tokens {
                     if args.verbose {
@@ -34,7 +36,8 @@ pub(crate) fn debug<W: io::Write>(f: &mut W, args: DebugArgs) -> Result<()> {
                 }
             }
             DebugOption::Parse => {
-                let parse = squawk_syntax::SourceFile::parse(sql);
+                let parse =
+                    squawk_syntax::SourceFile::parse_dialect(sql, args.templating, args.dialect);
                 if args.verbose {
                     writeln!(f, "{}\n---", parse.syntax_node())?;
                 }
@@ -63,6 +66,9 @@ pub(crate) fn debug<W: io::Write>(f: &mut W, args: DebugArgs) -> Result<()> {
             DebugOption::Ast => {
                 dump_ast(f, sql)?;
             }
+            DebugOption::Json => {
+                dump_json(f, sql)?;
+            }
         }
         Ok(())
     };
@@ -107,6 +113,13 @@ fn dump_ast<W: io::Write>(f: &mut W, sql: &str) -> Result<()> {
     Ok(())
 }
 
+fn dump_json<W: io::Write>(f: &mut W, sql: &str) -> Result<()> {
+    let parse = squawk_syntax::SourceFile::parse(sql);
+    let output = squawk_syntax::json::to_json(&parse);
+    writeln!(f, "{}", serde_json::to_string_pretty(&output)?)?;
+    Ok(())
+}
+
 fn render_syntax_errors(
     errors: &[SyntaxError],
     filename: &str,
@@ -132,7 +145,7 @@ fn render_syntax_errors(
 mod test {
     use insta::assert_snapshot;
 
-    use super::dump_ast;
+    use super::{dump_ast, dump_json};
 
     #[test]
     fn dump_ast_basic_output() {
@@ -151,4 +164,12 @@ truncate t;
         let output = String::from_utf8(buffer).expect("Invalid UTF-8");
         assert_snapshot!(output);
     }
+
+    #[test]
+    fn dump_json_output() {
+        let mut buffer = vec![];
+        dump_json(&mut buffer, "drop table t;").unwrap();
+        let output = String::from_utf8(buffer).expect("Invalid UTF-8");
+        assert_snapshot!(output);
+    }
 }
@@ -0,0 +1,963 @@
+//! Find SQL embedded in string literals of migration files written in other
+//! languages, like Alembic's `op.execute("""...""")`, Rails' `execute <<~SQL`
+//! or Knex's `knex.raw(`...`)`, and in the fenced code blocks of Markdown
+//! docs.
+//!
+//! The scanner is intentionally lightweight, it only understands enough of
+//! each language to skip comments & strings and to read the string literal
+//! passed to a known call.
+//!
+//! The extracted SQL always has the same length as the literal's contents in
+//! the host file so ranges can be mapped back by adding the literal's offset.
+//! To keep that property, escapes, interpolations & placeholders are
+//! replaced with text of the same length.
+
+use std::path::Path;
+
+use squawk_lexer::TokenKind;
+use squawk_line_index::{TextRange, TextSize, UniversalNewlines};
+use squawk_linter::Framework;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Language {
+    Markdown,
+    Python,
+    Ruby,
+    TypeScript,
+}
+
+impl Language {
+    pub(crate) fn from_path(path: &str) -> Option<Language> {
+        let extension = Path::new(path).extension()?.to_str()?;
+        match extension {
+            "md" | "markdown" => Some(Language::Markdown),
+            "py" => Some(Language::Python),
+            "rb" => Some(Language::Ruby),
+            "ts" | "mts" | "cts" | "js" | "mjs" | "cjs" => Some(Language::TypeScript),
+            _ => None,
+        }
+    }
+
+    fn calls(self) -> &'static [&'static str] {
+        match self {
+            // Alembic's `op.execute`, Django's `RunSQL`
+            Language::Python => &["execute", "RunSQL"],
+            // Rails' `execute` & `exec_query`
+            Language::Ruby => &["execute", "exec_query"],
+            // Knex's `raw`, TypeORM & Sequelize's `query`
+            Language::TypeScript => &["raw", "query"],
+            Language::Markdown => &[],
+        }
+    }
+
+    /// Whether the framework will run the SQL in a transaction, based on
+    /// settings that live in the host file instead of in the SQL.
+    pub(crate) fn in_transaction(self, framework: Framework, text: &str) -> Option<bool> {
+        let non_atomic = match (self, framework) {
+            // class Migration(migrations.Migration):
+            //     atomic = False
+            (Language::Python, Framework::Django) => |rest: &str| {
+                let Some(value) = rest.trim_start_matches([' ', '\t']).strip_prefix('=') else {
+                    return false;
+                };
+                let value = value.trim_start_matches([' ', '\t']);
+                !value.starts_with('=')
+                    && value
+                        .strip_prefix("False")
+                        .is_some_and(|after| !after.starts_with(is_ident_continue))
+            },
+            (Language::Ruby, Framework::Rails) => |rest: &str| rest.starts_with('!'),
+            _ => return None,
+        };
+        let setting = match framework {
+            Framework::Django => "atomic",
+            _ => "disable_ddl_transaction",
+        };
+        let mut scanner = Scanner::new(self, text);
+        scanner.run();
+        let found = scanner
+            .statements
+            .iter()
+            .any(|&(start, end)| &text[start..end] == setting && non_atomic(&text[end..]));
+        Some(!found)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct EmbeddedSql {
+    /// Range of the string literal's contents in the host file.
+    pub(crate) range: TextRange,
+    pub(crate) sql: String,
+}
+
+impl EmbeddedSql {
+    pub(crate) fn offset(&self) -> TextSize {
+        self.range.start()
+    }
+}
+
+pub(crate) fn extract_sql(language: Language, text: &str) -> Vec<EmbeddedSql> {
+    if language == Language::Markdown {
+        return markdown_code_blocks(text);
+    }
+    let mut scanner = Scanner::new(language, text);
+    scanner.run();
+    scanner.found
+}
+
+/// Builds a copy of the host file where everything except the embedded SQL is
+/// blanked out, so all of a file's snippets are linted together, like the
+/// statements of one migration, while keeping their offsets.
+///
+/// Each snippet is ended with a `;` so it doesn't run into the next one.
+pub(crate) fn masked_sql(text: &str, embedded: &[EmbeddedSql]) -> String {
+    // keep the newlines so ignore comments still apply to the same lines
+    let mut out: Vec<u8> = text
+        .bytes()
+        .map(|b| if b == b'\n' { b'\n' } else { b' ' })
+        .collect();
+    for snippet in embedded {
+        let range = usize::from(snippet.range.start())..usize::from(snippet.range.end());
+        out[range.clone()].copy_from_slice(snippet.sql.as_bytes());
+        if let Some(b) = out.get_mut(range.end)
+            && *b == b' '
+        {
+            *b = b';';
+        }
+    }
+    // the snippets start & end on char boundaries of the host file, so the
+    // output is still valid utf-8
+    String::from_utf8(out).expect("snippets should line up with the host file")
+}
+
+struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+    language: Language,
+    found: Vec<EmbeddedSql>,
+    /// Identifiers that start a line, outside of comments and strings, e.g.,
+    /// `atomic` in `atomic = False`.
+    statements: Vec<(usize, usize)>,
+}
+
+struct Literal {
+    range: TextRange,
+    sql: String,
+    /// Where scanning continues after the literal, for heredocs this is after
+    /// the terminating line.
+    end: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(language: Language, text: &'a str) -> Self {
+        Self {
+            text,
+            pos: 0,
+            language,
+            found: vec![],
+            statements: vec![],
+        }
+    }
+
+    fn rest(&self) -> &str {
+        &self.text[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn eat_until_newline(&mut self) {
+        while self.peek().is_some_and(|c| c != '\n' && c != '\r') {
+            self.bump();
+        }
+    }
+
+    fn run(&mut self) {
+        while let Some(c) = self.peek() {
+            if self.eat_comment() {
+                continue;
+            }
+            if let Some(literal) = self.literal_at(self.pos) {
+                self.pos = literal.end;
+                continue;
+            }
+            if is_ident_start(c) {
+                let start = self.pos;
+                while self.peek().is_some_and(is_ident_continue) {
+                    self.bump();
+                }
+                let ident = &self.text[start..self.pos];
+                let line_start = self.text[..start].rfind(['\n', '\r']).map_or(0, |i| i + 1);
+                if self.text[line_start..start].trim().is_empty() {
+                    self.statements.push((start, self.pos));
+                }
+                if let Some(literal) = self.call_argument(ident) {
+                    self.pos = literal.end;
+                    self.found.push(EmbeddedSql {
+                        range: literal.range,
+                        sql: normalize_placeholders(&literal.sql),
+                    });
+                }
+                continue;
+            }
+            self.bump();
+        }
+    }
+
+    fn eat_comment(&mut self) -> bool {
+        let rest = self.rest();
+        match self.language {
+            Language::Python | Language::Ruby if rest.starts_with('#') => {
+                self.eat_until_newline();
+                true
+            }
+            Language::TypeScript if rest.starts_with("//") => {
+                self.eat_until_newline();
+                true
+            }
+            Language::TypeScript if rest.starts_with("/*") => {
+                self.pos += rest.find("*/").map_or(rest.len(), |end| end + 2);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Find the SQL string passed to a known call, e.g., `op.execute("...")`
+    fn call_argument(&mut self, ident: &str) -> Option<Literal> {
+        let start = self.pos;
+        let literal = self.call_argument_inner(ident);
+        if literal.is_none() {
+            self.pos = start;
+        }
+        literal
+    }
+
+    fn call_argument_inner(&mut self, ident: &str) -> Option<Literal> {
+        if self.language == Language::TypeScript && ident == "sql" && self.peek() == Some('`') {
+            // tagged template, e.g., sql`select 1`
+            return self.literal_at(self.pos);
+        }
+        if !self.language.calls().contains(&ident) {
+            return None;
+        }
+        self.eat_whitespace();
+        let has_paren = self.peek() == Some('(');
+        if has_paren {
+            self.bump();
+            self.eat_whitespace();
+            // Django's `RunSQL(sql="...")`
+            if self.rest().starts_with("sql=") {
+                self.pos += "sql=".len();
+                self.eat_whitespace();
+            }
+            // Alembic's `op.execute(sa.text("..."))`
+            if self.language == Language::Python {
+                self.eat_text_call();
+            }
+        } else if self.language != Language::Ruby {
+            return None;
+        }
+        self.literal_at(self.pos)
+    }
+
+    fn eat_text_call(&mut self) {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(is_ident_continue(c) || c == '.'))
+            .unwrap_or(rest.len());
+        let callee = &rest[..len];
+        if !(callee == "text" || callee.ends_with(".text")) {
+            return;
+        }
+        let start = self.pos;
+        self.pos += len;
+        self.eat_whitespace();
+        if self.peek() == Some('(') {
+            self.bump();
+            self.eat_whitespace();
+        } else {
+            self.pos = start;
+        }
+    }
+
+    fn literal_at(&self, start: usize) -> Option<Literal> {
+        match self.language {
+            Language::Python => self.python_literal(start),
+            Language::Ruby => self.ruby_literal(start),
+            Language::TypeScript => self.typescript_literal(start),
+            Language::Markdown => None,
+        }
+    }
+
+    fn python_literal(&self, start: usize) -> Option<Literal> {
+        let rest = &self.text[start..];
+        let prefix_len = rest
+            .chars()
+            .take_while(|c| matches!(c, 'r' | 'R' | 'u' | 'U' | 'b' | 'B' | 'f' | 'F'))
+            .count();
+        if prefix_len > 2 {
+            return None;
+        }
+        let prefix = &rest[..prefix_len];
+        let raw = prefix.contains(['r', 'R']);
+        let interpolation = prefix.contains(['f', 'F']).then_some(Interpolation::Braces);
+        let quoted = &rest[prefix_len..];
+        let quote = ["\"\"\"", "'''", "\"", "'"]
+            .into_iter()
+            .find(|quote| quoted.starts_with(quote))?;
+        let content_start = start + prefix_len + quote.len();
+        quoted_content(
+            self.text,
+            content_start,
+            quote,
+            Quoting {
+                backslash: !raw,
+                interpolation,
+                multiline: quote.len() == 3,
+            },
+        )
+    }
+
+    fn ruby_literal(&self, start: usize) -> Option<Literal> {
+        let rest = &self.text[start..];
+        if let Some(heredoc) = rest.strip_prefix("<<") {
+            return self.ruby_heredoc(start, heredoc);
+        }
+        let (quote, quoting) = if rest.starts_with('"') {
+            (
+                "\"",
+                Quoting {
+                    backslash: true,
+                    interpolation: Some(Interpolation::Hash),
+                    multiline: true,
+                },
+            )
+        } else if rest.starts_with('\'') {
+            (
+                "'",
+                Quoting {
+                    backslash: false,
+                    interpolation: None,
+                    multiline: true,
+                },
+            )
+        } else {
+            return None;
+        };
+        quoted_content(self.text, start + 1, quote, quoting)
+    }
+
+    /// ```ruby
+    /// execute <<~SQL
+    ///   select 1;
+    /// SQL
+    /// ```
+    fn ruby_heredoc(&self, start: usize, heredoc: &str) -> Option<Literal> {
+        let without_flag = heredoc.strip_prefix(['~', '-']).unwrap_or(heredoc);
+        let (id, interpolation) = if let Some(quoted) = without_flag.strip_prefix('\'') {
+            (&quoted[..quoted.find('\'')?], None)
+        } else if let Some(quoted) = without_flag.strip_prefix('"') {
+            (&quoted[..quoted.find('"')?], Some(Interpolation::Hash))
+        } else {
+            let len = without_flag
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(without_flag.len());
+            (&without_flag[..len], Some(Interpolation::Hash))
+        };
+        if id.is_empty() || !id.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return None;
+        }
+        // the heredoc body starts on the line after the opening tag
+        let line_end = start + self.text[start..].find(['\n', '\r'])?;
+        let content_start = line_end + newline_len(&self.text[line_end..]);
+
+        let mut line_start = content_start;
+        while line_start < self.text.len() {
+            let line_len = self.text[line_start..]
+                .find(['\n', '\r'])
+                .unwrap_or(self.text.len() - line_start);
+            let line = &self.text[line_start..line_start + line_len];
+            let next_line =
+                line_start + line_len + newline_len(&self.text[line_start + line_len..]);
+            if line.trim() == id {
+                let range = TextRange::new(
+                    TextSize::new(content_start as u32),
+                    TextSize::new(line_start as u32),
+                );
+                let sql = unescape(
+                    &self.text[content_start..line_start],
+                    Quoting {
+                        backslash: interpolation.is_some(),
+                        interpolation,
+                        multiline: true,
+                    },
+                );
+                return Some(Literal {
+                    range,
+                    sql,
+                    end: next_line,
+                });
+            }
+            line_start = next_line;
+        }
+        None
+    }
+
+    fn typescript_literal(&self, start: usize) -> Option<Literal> {
+        let rest = &self.text[start..];
+        let quote = ["`", "\"", "'"]
+            .into_iter()
+            .find(|quote| rest.starts_with(quote))?;
+        let interpolation = (quote == "`").then_some(Interpolation::DollarBraces);
+        quoted_content(
+            self.text,
+            start + 1,
+            quote,
+            Quoting {
+                backslash: true,
+                interpolation,
+                multiline: quote == "`",
+            },
+        )
+    }
+}
+
+pub(crate) const SQL_LANGUAGES: &[&str] = &["sql", "postgresql", "postgres", "psql", "pgsql"];
+
+// Examples that are meant to be bad can opt out, e.g., ```sql squawk-ignore
+const MARKDOWN_IGNORE_FLAG: &str = "squawk-ignore";
+
+struct Fence<'a> {
+    marker: &'a str,
+    is_sql: bool,
+    content_start: TextSize,
+    /// The indent and `>`s before the opening fence.
+    prefix_len: usize,
+}
+
+impl Fence<'_> {
+    /// The code block's SQL, with the prefix of each line blanked out, e.g.,
+    /// the `> ` of a block quote.
+    fn embedded_sql(&self, text: &str, range: TextRange) -> EmbeddedSql {
+        let sql = text[range]
+            .universal_newlines()
+            .map(|line| {
+                let line_text = line.as_full_str();
+                let prefix = line_text
+                    .char_indices()
+                    .take_while(|(i, c)| *i < self.prefix_len && matches!(c, ' ' | '\t' | '>'))
+                    .count();
+                " ".repeat(prefix) + &line_text[prefix..]
+            })
+            .collect();
+        EmbeddedSql { range, sql }
+    }
+}
+
+/// Find the SQL code blocks in a Markdown doc.
+///
+/// ````markdown
+/// ```sql
+/// select 1;
+/// ```
+/// ````
+fn markdown_code_blocks(text: &str) -> Vec<EmbeddedSql> {
+    let mut found = vec![];
+    let mut open_fence: Option<Fence> = None;
+    for line in text.universal_newlines() {
+        // fences in lists & block quotes are indented
+        let trimmed = line.as_str().trim_start_matches([' ', '\t', '>']);
+        match &open_fence {
+            None => {
+                let Some(marker) = fence_marker(trimmed) else {
+                    continue;
+                };
+                let info = &trimmed[marker.len()..];
+                if marker.starts_with('`') && info.contains('`') {
+                    continue;
+                }
+                let mut words = info
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|word| !word.is_empty());
+                let is_sql = words.next().is_some_and(|lang| {
+                    SQL_LANGUAGES
+                        .iter()
+                        .any(|sql| lang.eq_ignore_ascii_case(sql))
+                }) && !words.any(|word| word == MARKDOWN_IGNORE_FLAG);
+                open_fence = Some(Fence {
+                    marker,
+                    is_sql,
+                    content_start: line.full_end(),
+                    prefix_len: line.as_str().len() - trimmed.len(),
+                });
+            }
+            Some(fence) => {
+                let is_closing = fence_marker(trimmed).is_some_and(|marker| {
+                    marker.starts_with(&fence.marker[..1])
+                        && marker.len() >= fence.marker.len()
+                        && trimmed[marker.len()..].trim().is_empty()
+                });
+                if !is_closing {
+                    continue;
+                }
+                if fence.is_sql {
+                    let range = TextRange::new(fence.content_start, line.start());
+                    found.push(fence.embedded_sql(text, range));
+                }
+                open_fence = None;
+            }
+        }
+    }
+    // an unclosed code block runs until the end of the doc
+    if let Some(fence) = open_fence
+        && fence.is_sql
+        && fence.content_start < TextSize::of(text)
+    {
+        let range = TextRange::new(fence.content_start, TextSize::of(text));
+        found.push(fence.embedded_sql(text, range));
+    }
+    found
+}
+
+fn fence_marker(line: &str) -> Option<&str> {
+    let c = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = line.chars().take_while(|x| *x == c).count();
+    (len >= 3).then(|| &line[..len])
+}
+
+fn newline_len(text: &str) -> usize {
+    if text.starts_with("\r\n") {
+        2
+    } else if text.starts_with(['\n', '\r']) {
+        1
+    } else {
+        0
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interpolation {
+    /// Python f-strings, `{value}`
+    Braces,
+    /// Ruby, `#{value}`
+    Hash,
+    /// JS template literals, `${value}`
+    DollarBraces,
+}
+
+impl Interpolation {
+    fn prefix(self) -> &'static str {
+        match self {
+            Interpolation::Braces => "{",
+            Interpolation::Hash => "#{",
+            Interpolation::DollarBraces => "${",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Quoting {
+    backslash: bool,
+    interpolation: Option<Interpolation>,
+    multiline: bool,
+}
+
+fn quoted_content(
+    text: &str,
+    content_start: usize,
+    quote: &str,
+    quoting: Quoting,
+) -> Option<Literal> {
+    let mut pos = content_start;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        if rest.starts_with(quote) {
+            let range = TextRange::new(
+                TextSize::new(content_start as u32),
+                TextSize::new(pos as u32),
+            );
+            return Some(Literal {
+                range,
+                sql: unescape(&text[content_start..pos], quoting),
+                end: pos + quote.len(),
+            });
+        }
+        let c = rest.chars().next()?;
+        if quoting.backslash && c == '\\' {
+            pos += 1;
+            pos += rest[1..].chars().next().map_or(0, char::len_utf8);
+            continue;
+        }
+        if !quoting.multiline && matches!(c, '\n' | '\r') {
+            return None;
+        }
+        pos += c.len_utf8();
+    }
+    None
+}
+
+/// Replace escapes & interpolations with text of the same length so the SQL
+/// lines up with the host file.
+fn unescape(content: &str, quoting: Quoting) -> String {
+    let mut sql = String::with_capacity(content.len());
+    let mut chars = content.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        if quoting.backslash && c == '\\' {
+            sql.push(' ');
+            if let Some((_, escaped)) = chars.next() {
+                match escaped {
+                    '\'' | '"' | '\\' | '`' => sql.push(escaped),
+                    _ => push_spaces(&mut sql, escaped.len_utf8()),
+                }
+            }
+            continue;
+        }
+        if let Some(interpolation) = quoting.interpolation
+            && content[idx..].starts_with(interpolation.prefix())
+            && let Some(len) = content[idx..].find('}').map(|end| end + 1)
+        {
+            // Interpolations are most often identifiers, like table names,
+            // which also work in expression positions.
+            sql.push_str(&"_".repeat(len));
+            while chars.peek().is_some_and(|(next, _)| *next < idx + len) {
+                chars.next();
+            }
+            continue;
+        }
+        sql.push(c);
+    }
+    sql
+}
+
+fn push_spaces(sql: &mut String, len: usize) {
+    for _ in 0..len {
+        sql.push(' ');
+    }
+}
+
+/// Replace the various paramstyles with positional params so they parse.
+///
+/// - qmark, `?`
+/// - named, `:name`
+/// - format, `%s`
+/// - pyformat, `%(name)s`
+///
+/// see: <https://peps.python.org/pep-0249/#paramstyle>
+pub(crate) fn normalize_placeholders(sql: &str) -> String {
+    let mut tokens = vec![];
+    let mut start = 0;
+    for token in squawk_lexer::tokenize(sql) {
+        let end = start + token.len as usize;
+        tokens.push((token.kind, start..end));
+        start = end;
+    }
+
+    let mut replacements = vec![];
+    let mut idx = 0;
+    while idx < tokens.len() {
+        let (kind, range) = &tokens[idx];
+        let kinds = |offset: usize| tokens.get(idx + offset).map(|(kind, _)| *kind);
+        let text = |offset: usize| {
+            tokens
+                .get(idx + offset)
+                .map_or("", |(_, range)| &sql[range.clone()])
+        };
+        let prev_kind = idx.checked_sub(1).map(|prev| tokens[prev].0);
+        let len = match kind {
+            // knex's identifier binding, `??`
+            TokenKind::Question if kinds(1) == Some(TokenKind::Question) => 2,
+            TokenKind::Question => 1,
+            TokenKind::Percent if kinds(1) == Some(TokenKind::Ident) && text(1) == "s" => 2,
+            TokenKind::Percent
+                if kinds(1) == Some(TokenKind::OpenParen)
+                    && kinds(2) == Some(TokenKind::Ident)
+                    && kinds(3) == Some(TokenKind::CloseParen)
+                    && kinds(4) == Some(TokenKind::Ident)
+                    && text(4) == "s" =>
+            {
+                5
+            }
+            // avoid casts, `a::int`
+            TokenKind::Colon
+                if prev_kind != Some(TokenKind::Colon) && kinds(1) == Some(TokenKind::Ident) =>
+            {
+                2
+            }
+            _ => 0,
+        };
+        if len == 0 {
+            idx += 1;
+            continue;
+        }
+        let end = tokens[idx + len - 1].1.end;
+        replacements.push(range.start..end);
+        idx += len;
+    }
+
+    let mut normalized = sql.to_string();
+    for range in replacements.into_iter().rev() {
+        let replacement = match range.len() {
+            // a `$1` doesn't fit, but a literal works for values
+            1 => "0".to_string(),
+            len if &sql[range.clone()] == "??" => "_".repeat(len),
+            len => format!("$1{}", " ".repeat(len - 2)),
+        };
+        normalized.replace_range(range, &replacement);
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use squawk_linter::Framework;
+
+    use super::{Language, extract_sql, masked_sql};
+
+    fn extract(language: Language, text: &str) -> String {
+        let mut out = String::new();
+        for embedded in extract_sql(language, text) {
+            assert_eq!(
+                embedded.sql.len(),
+                usize::from(embedded.range.len()),
+                "the sql should line up with the host file"
+            );
+            out.push_str(&format!("{:?} {:?}\n", embedded.range, embedded.sql));
+        }
+        out
+    }
+
+    #[test]
+    fn language_from_path() {
+        assert_eq!(
+            Language::from_path("migrations/0001_initial.py"),
+            Some(Language::Python)
+        );
+        assert_eq!(
+            Language::from_path("db/migrate/001_add.rb"),
+            Some(Language::Ruby)
+        );
+        assert_eq!(
+            Language::from_path("migrations/001_add.ts"),
+            Some(Language::TypeScript)
+        );
+        assert_eq!(
+            Language::from_path("docs/runbook.md"),
+            Some(Language::Markdown)
+        );
+        assert_eq!(Language::from_path("001_add.sql"), None);
+        assert_eq!(Language::from_path("stdin"), None);
+    }
+
+    #[test]
+    fn alembic() {
+        assert_snapshot!(extract(Language::Python, r#"
+# op.execute("not this")
+def upgrade():
+    op.execute("""
+        alter table t add column c int not null;
+    """)
+    op.execute(sa.text('select * from t where id = :id'))
+"#), @r#"
+        59..113 "\n        alter table t add column c int not null;\n    "
+        142..172 "select * from t where id = $1 "
+        "#);
+    }
+
+    #[test]
+    fn sa_text_outside_of_execute() {
+        assert_snapshot!(extract(Language::Python, r#"
+def upgrade():
+    op.add_column("t", sa.Column("created_at", sa.DateTime(), server_default=sa.text("now()")))
+    op.execute(text("select 1"))
+"#), @r#"133..141 "select 1""#);
+    }
+
+    #[test]
+    fn django_atomic() {
+        let in_transaction = |text| Language::Python.in_transaction(Framework::Django, text);
+        assert_eq!(
+            in_transaction(
+                "class Migration(migrations.Migration):\n    atomic=False\n    operations = []\n"
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            in_transaction("class Migration(migrations.Migration):\n    atomic = False  # slow\n"),
+            Some(false)
+        );
+        assert_eq!(
+            in_transaction("class Migration(migrations.Migration):\n    atomic = True\n"),
+            Some(true)
+        );
+        assert_eq!(
+            in_transaction(
+                "class Migration(migrations.Migration):\n    # atomic = False\n    help = \"atomic = False\"\n"
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            in_transaction("class Migration(migrations.Migration):\n    atomic = Falsey\n"),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn rails_disable_ddl_transaction() {
+        let in_transaction = |text| Language::Ruby.in_transaction(Framework::Rails, text);
+        assert_eq!(
+            in_transaction(
+                "class AddIndex < ActiveRecord::Migration[7.0]\n  disable_ddl_transaction!\nend\n"
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            in_transaction(
+                "class AddIndex < ActiveRecord::Migration[7.0]\n  # disable_ddl_transaction!\nend\n"
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            Language::Ruby.in_transaction(Framework::Goose, "disable_ddl_transaction!"),
+            None
+        );
+    }
+
+    #[test]
+    fn masked() {
+        let text = r#"op.execute("set lock_timeout = '1s'")
+op.execute("""
+alter table t add column c int""")
+"#;
+        assert_snapshot!(masked_sql(text, &extract_sql(Language::Python, text)), @"
+                    set lock_timeout = '1s'; 
+                      
+        alter table t add column c int;
+        ");
+    }
+
+    #[test]
+    fn django_run_sql() {
+        assert_snapshot!(extract(Language::Python, r#"
+operations = [
+    migrations.RunSQL(
+        sql="update t set c = %s where id = %(id)s",
+        reverse_sql="update t set c = null",
+    ),
+]
+"#), @r#"52..89 "update t set c = $1 where id = $1    ""#);
+    }
+
+    #[test]
+    fn python_escapes_and_f_strings() {
+        assert_snapshot!(extract(Language::Python, r#"op.execute(f"alter table {table} add column \"c\" int;\n")"#), @r#"13..56 "alter table _______ add column  \"c \" int;  ""#);
+    }
+
+    #[test]
+    fn rails_heredoc() {
+        assert_snapshot!(extract(Language::Ruby, r#"
+class AddIndex < ActiveRecord::Migration[7.0]
+  disable_ddl_transaction!
+
+  def up
+    execute <<~SQL
+      create index concurrently i on t (#{column});
+    SQL
+    execute "select 1 where id = ?"
+  end
+end
+"#), @r#"
+        103..155 "      create index concurrently i on t (_________);\n"
+        176..197 "select 1 where id = 0"
+        "#);
+    }
+
+    #[test]
+    fn knex() {
+        assert_snapshot!(extract(Language::TypeScript, r#"
+// knex.raw("not this")
+export async function up(knex) {
+  await knex.raw(`
+    alter table ${table} add column c int;
+  `);
+  await knex.raw('select ?? from t where id = ?', ['c', 1]);
+  await db.execute(sql`select 1`);
+}
+"#), @r#"
+        76..122 "\n    alter table ________ add column c int;\n  "
+        144..173 "select __ from t where id = 0"
+        210..218 "select 1"
+        "#);
+    }
+
+    #[test]
+    fn casts_are_not_placeholders() {
+        assert_snapshot!(extract(Language::Python, r#"op.execute("select a::int, ':name' from t")"#), @r#"12..41 "select a::int, ':name' from t""#);
+    }
+
+    #[test]
+    fn markdown() {
+        assert_snapshot!(extract(Language::Markdown, r#"
+# Runbook
+
+```sql
+select 1;
+```
+
+```python
+op.execute("select 2")
+```
+
+- step one
+
+  ```postgresql
+  alter table t add column c int;
+  ```
+
+````sql squawk-ignore
+alter table t drop column c;
+````
+
+~~~psql
+select 3;
+~~~
+"#), @r#"
+        19..29 "select 1;\n"
+        100..134 "  alter table t add column c int;\n"
+        206..216 "select 3;\n"
+        "#);
+    }
+
+    #[test]
+    fn markdown_block_quote() {
+        assert_snapshot!(extract(Language::Markdown, "> ```sql\n> select 1;\n>\n>   from t;\n> ```\n"), @r#"9..35 "  select 1;\n \n    from t;\n""#);
+    }
+
+    #[test]
+    fn markdown_unclosed_block() {
+        assert_snapshot!(extract(Language::Markdown, "```sql\nselect 1;\n"), @r#"7..17 "select 1;\n""#);
+    }
+}
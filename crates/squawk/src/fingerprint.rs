@@ -0,0 +1,145 @@
+use std::{io, process::ExitCode};
+
+use anyhow::Result;
+use squawk_line_index::LineIndex;
+use squawk_syntax::{Dialect, SourceFile, Templating, ast::AstNode, fingerprint::Fingerprint};
+
+use crate::{
+    cmd::Input,
+    file::{sql_from_path, sql_from_stdin},
+};
+
+pub(crate) struct FingerprintFiles {
+    pub(crate) input: Input,
+    pub(crate) templating: Option<Templating>,
+    pub(crate) dialect: Dialect,
+}
+
+pub(crate) fn fingerprint<W: io::Write>(f: &mut W, args: FingerprintFiles) -> Result<ExitCode> {
+    let mut errors = vec![];
+    match &args.input {
+        Input::Stdin(stdin) => {
+            let sql = sql_from_stdin()?;
+            let name = stdin.path.as_deref().unwrap_or("stdin");
+            errors.extend(write_fingerprints(f, name, &sql, &args)?);
+        }
+        Input::Paths(paths) => {
+            for path in paths {
+                let sql = sql_from_path(path)?;
+                let name = path.display().to_string();
+                errors.extend(write_fingerprints(f, &name, &sql, &args)?);
+            }
+        }
+    }
+    for error in &errors {
+        eprintln!("{error}");
+    }
+    if !errors.is_empty() {
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Writes the fingerprint of each statement, returning the syntax errors of
+/// the statements that don't parse instead of guessing at their fingerprint.
+fn write_fingerprints<W: io::Write>(
+    f: &mut W,
+    name: &str,
+    sql: &str,
+    args: &FingerprintFiles,
+) -> Result<Vec<String>> {
+    let parse = SourceFile::parse_dialect(sql, args.templating, args.dialect);
+    let line_index = LineIndex::new(sql);
+    let errors: Vec<_> = parse
+        .errors()
+        .iter()
+        .map(|error| {
+            let line_col = line_index.line_col(error.range().start());
+            (
+                error.range(),
+                format!(
+                    "{name}:{}:{}: syntax error: {}",
+                    line_col.line + 1,
+                    line_col.col + 1,
+                    error.message()
+                ),
+            )
+        })
+        .collect();
+    for stmt in parse.tree().stmts() {
+        let range = stmt.syntax().text_range();
+        if errors
+            .iter()
+            .any(|(error_range, _)| range.contains_range(*error_range))
+        {
+            continue;
+        }
+        let Fingerprint { normalized, hash } = squawk_syntax::fingerprint::fingerprint(&stmt);
+        writeln!(f, "{hash:016x} {normalized}")?;
+    }
+    Ok(errors.into_iter().map(|(_, message)| message).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use squawk_syntax::Dialect;
+
+    use super::{FingerprintFiles, write_fingerprints};
+    use crate::cmd::{Input, Stdin};
+
+    fn fingerprints(sql: &str, dialect: Dialect) -> String {
+        let args = FingerprintFiles {
+            input: Input::Stdin(Stdin { path: None }),
+            templating: None,
+            dialect,
+        };
+        let mut buffer = vec![];
+        let errors = write_fingerprints(&mut buffer, "stdin", sql, &args).unwrap();
+        let mut output = String::from_utf8(buffer).expect("Invalid UTF-8");
+        for error in errors {
+            output.push_str(&error);
+            output.push('\n');
+        }
+        output
+    }
+
+    #[test]
+    fn fingerprints_each_stmt() {
+        let output = fingerprints(
+            "
+SELECT * FROM users WHERE id = 1;
+select * from users where id = 42; -- same as above
+insert into t (a, b) values (1, 'x');
+",
+            Dialect::Postgres,
+        );
+        assert_snapshot!(output, @"
+        4bc03e0ea323629f select * from users where id = $1
+        4bc03e0ea323629f select * from users where id = $1
+        9c17973480c5d0fa insert into t (a, b) values ($1, $2)
+        ");
+    }
+
+    #[test]
+    fn syntax_errors() {
+        assert_snapshot!(fingerprints("select 1;\nselec bad;\nselect 2;\n", Dialect::Postgres), @"
+        6c10d01ac8145628 select $1
+        6c10d01ac8145628 select $1
+        stdin:2:1: syntax error: expected command, found `selec`, did you mean SELECT?
+        ");
+    }
+
+    #[test]
+    fn dialect() {
+        let sql = "create index async i on t (a);\n";
+        assert_snapshot!(fingerprints(sql, Dialect::Postgres), @"
+        stdin:1:19: syntax error: expected ON, found `i`
+        stdin:1:22: syntax error: expected `(`, found `on`
+        stdin:1:22: syntax error: expected `)`, found `on`
+        stdin:1:22: syntax error: expected `;`, found `on`
+        ");
+        assert_snapshot!(fingerprints(sql, Dialect::AuroraDsql), @"f01667cfd439c039 create index async i on t (a)");
+    }
+}
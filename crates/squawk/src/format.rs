@@ -0,0 +1,264 @@
+use std::{fmt, fs, io, process::ExitCode};
+
+use anyhow::Result;
+use similar::TextDiff;
+use squawk_fmt::FormatOptions;
+
+use crate::{
+    cmd::Input,
+    file::{sql_from_path, sql_from_stdin},
+};
+
+pub(crate) struct FormatFiles {
+    pub(crate) input: Input,
+    pub(crate) options: FormatOptions,
+    /// Report the files that would change instead of writing them
+    pub(crate) check: bool,
+    /// Print a diff of the changes instead of writing them
+    pub(crate) diff: bool,
+}
+
+#[derive(Debug, Default)]
+struct Summary {
+    check: bool,
+    changed: usize,
+    unchanged: usize,
+    skipped: usize,
+}
+
+fn files(count: usize) -> String {
+    if count == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{count} files")
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.check {
+            write!(
+                f,
+                "{} would be reformatted, {} already formatted",
+                files(self.changed),
+                files(self.unchanged)
+            )?;
+        } else {
+            write!(
+                f,
+                "{} reformatted, {} left unchanged",
+                files(self.changed),
+                files(self.unchanged)
+            )?;
+        }
+        if self.skipped > 0 {
+            write!(f, ", {} skipped", files(self.skipped))?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn format<W: io::Write>(f: &mut W, args: FormatFiles) -> Result<ExitCode> {
+    let mut summary = Summary {
+        check: args.check || args.diff,
+        ..Default::default()
+    };
+    match &args.input {
+        Input::Stdin(stdin) => {
+            let sql = sql_from_stdin()?;
+            let name = stdin.path.as_deref().unwrap_or("stdin");
+            if let Some(formatted) = format_sql(f, name, &sql, &args, &mut summary)?
+                && !summary.check
+            {
+                write!(f, "{formatted}")?;
+            }
+        }
+        Input::Paths(paths) => {
+            for path in paths {
+                let sql = sql_from_path(path)?;
+                let name = path.display().to_string();
+                if let Some(formatted) = format_sql(f, &name, &sql, &args, &mut summary)?
+                    && !summary.check
+                    && formatted != sql
+                {
+                    fs::write(path, formatted)?;
+                }
+            }
+            eprintln!("{summary}");
+        }
+    }
+    if summary.skipped > 0 || (summary.check && summary.changed > 0) {
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Returns the formatted SQL, or `None` when the file can't be formatted.
+fn format_sql<W: io::Write>(
+    f: &mut W,
+    name: &str,
+    sql: &str,
+    args: &FormatFiles,
+    summary: &mut Summary,
+) -> Result<Option<String>> {
+    let formatted = match squawk_fmt::fmt(sql, &args.options) {
+        Ok(formatted) => formatted,
+        Err(err) => {
+            eprintln!("Skipping {name}: {err}");
+            summary.skipped += 1;
+            return Ok(None);
+        }
+    };
+    if formatted == sql {
+        summary.unchanged += 1;
+        return Ok(Some(formatted));
+    }
+    summary.changed += 1;
+    if args.diff {
+        write_diff(f, name, sql, &formatted)?;
+    } else if args.check {
+        writeln!(f, "Would reformat: {name}")?;
+    }
+    Ok(Some(formatted))
+}
+
+fn write_diff<W: io::Write>(f: &mut W, name: &str, before: &str, after: &str) -> Result<()> {
+    let diff = TextDiff::from_lines(before, after);
+    write!(f, "{}", diff.unified_diff().header(name, name))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf, process::ExitCode};
+
+    use insta::assert_snapshot;
+    use squawk_fmt::FormatOptions;
+    use tempfile::TempDir;
+
+    use super::{FormatFiles, format};
+    use crate::cmd::Input;
+
+    fn format_files(
+        files: &[(&str, &str)],
+        check: bool,
+        diff: bool,
+    ) -> (String, ExitCode, TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let paths: Vec<PathBuf> = files
+            .iter()
+            .map(|(name, sql)| {
+                let path = dir.path().join(name);
+                fs::write(&path, sql).unwrap();
+                path
+            })
+            .collect();
+        let mut buffer = vec![];
+        let exit_code = format(
+            &mut buffer,
+            FormatFiles {
+                input: Input::Paths(paths),
+                options: FormatOptions::default(),
+                check,
+                diff,
+            },
+        )
+        .unwrap();
+        let output = String::from_utf8(buffer)
+            .unwrap()
+            .replace(&dir.path().display().to_string(), "<dir>");
+        (output, exit_code, dir)
+    }
+
+    #[test]
+    fn writes_files() {
+        let (output, exit_code, dir) = format_files(
+            &[("a.sql", "SELECT  1;\n"), ("b.sql", "select 1;\n")],
+            false,
+            false,
+        );
+        assert_eq!(exit_code, ExitCode::SUCCESS);
+        assert_eq!(output, "");
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.sql")).unwrap(),
+            "select 1;\n"
+        );
+    }
+
+    #[test]
+    fn check_reports_changed_files() {
+        let (output, exit_code, dir) = format_files(
+            &[("a.sql", "SELECT  1;\n"), ("b.sql", "select 1;\n")],
+            true,
+            false,
+        );
+        assert_eq!(exit_code, ExitCode::FAILURE);
+        assert_snapshot!(output, @"Would reformat: <dir>/a.sql");
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.sql")).unwrap(),
+            "SELECT  1;\n"
+        );
+    }
+
+    #[test]
+    fn check_passes_when_formatted() {
+        let (output, exit_code, _dir) = format_files(&[("b.sql", "select 1;\n")], true, false);
+        assert_eq!(exit_code, ExitCode::SUCCESS);
+        assert_eq!(output, "");
+    }
+
+    #[test]
+    fn diff() {
+        let (output, exit_code, _dir) = format_files(
+            &[(
+                "a.sql",
+                "-- keep me\nSELECT  1;\n\nselect 2;\ninsert into t (a)   values (1);\n",
+            )],
+            false,
+            true,
+        );
+        assert_eq!(exit_code, ExitCode::FAILURE);
+        assert_snapshot!(output, @"
+        --- <dir>/a.sql
+        +++ <dir>/a.sql
+        @@ -1,5 +1,5 @@
+         -- keep me
+        -SELECT  1;
+        +select 1;
+         
+         select 2;
+        -insert into t (a)   values (1);
+        +insert into t (a) values (1);
+        ");
+    }
+
+    #[test]
+    fn skips_syntax_errors() {
+        let (output, exit_code, dir) = format_files(
+            &[("a.sql", "select from where;\n"), ("b.sql", "SELECT 1;\n")],
+            false,
+            false,
+        );
+        assert_eq!(exit_code, ExitCode::FAILURE);
+        assert_eq!(output, "");
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.sql")).unwrap(),
+            "select from where;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("b.sql")).unwrap(),
+            "select 1;\n"
+        );
+    }
+
+    #[test]
+    fn summary() {
+        let summary = super::Summary {
+            check: true,
+            changed: 2,
+            unchanged: 1,
+            skipped: 1,
+        };
+        assert_snapshot!(summary, @"2 files would be reformatted, 1 file already formatted, 1 file skipped");
+    }
+}
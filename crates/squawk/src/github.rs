@@ -1,6 +1,6 @@
 use crate::cmd::Input;
 use crate::config::Config;
-use crate::reporter::{CheckReport, fmt_github_annotations, fmt_tty_violation};
+use crate::reporter::{CheckReport, LintSettings, fmt_github_annotations, fmt_tty_violation};
 use crate::{LintArgs, UploadToGithubArgs};
 use crate::{file_finding::find_paths, reporter::lint_files};
 use anyhow::{Context, Result, anyhow, bail};
@@ -121,10 +121,15 @@ pub fn check_and_comment_on_pr(cfg: Config) -> Result<()> {
     info!("checking files");
     let file_results = lint_files(&LintArgs {
         input: Input::Paths(found_paths),
-        excluded_rules: cfg.excluded_rules,
-        included_rules: cfg.included_rules,
-        pg_version: cfg.pg_version,
-        assume_in_transaction: cfg.assume_in_transaction,
+        settings: LintSettings {
+            excluded_rules: cfg.excluded_rules,
+            included_rules: cfg.included_rules,
+            pg_version: cfg.pg_version,
+            assume_in_transaction: cfg.assume_in_transaction,
+            framework: cfg.framework,
+            templating: cfg.templating,
+            dialect: cfg.dialect,
+        },
         reporter: cfg.reporter,
         github_annotations: cfg.github_annotations,
     })?;
@@ -222,7 +227,12 @@ fn get_summary_comment_body(
                 let violation_rules: Vec<String> = file
                     .violations
                     .iter()
-                    .map(|v| format!("• `{}` (line {})", v.rule_name, v.line + 1))
+                    .map(|v| match v.cell {
+                        Some(cell) => {
+                            format!("• `{}` (cell {cell}, line {})", v.rule_name, v.line + 1)
+                        }
+                        None => format!("• `{}` (line {})", v.rule_name, v.line + 1),
+                    })
                     .collect();
                 format!("**Violations found:**\n{}", violation_rules.join("\n"))
             } else {
@@ -307,7 +317,7 @@ fn get_sql_file_content(violation: &CheckReport) -> Result<String> {
     let mut buff = Vec::new();
     let violation_count = violation.violations.len();
     for v in &violation.violations {
-        fmt_tty_violation(&mut buff, v, &violation.path, sql)?;
+        fmt_tty_violation(&mut buff, v, violation)?;
     }
     let violations_text_raw = &String::from_utf8_lossy(&buff);
     let violations_text = strip_ansi_codes(violations_text_raw);
@@ -375,6 +385,7 @@ mod test_github_comment {
             sql: "SELECT 1;".into(),
             violations: vec![ReportViolation {
                 file: "alpha.sql".into(),
+                cell: None,
                 line: 1,
                 column: 8,
                 level: ViolationLevel::Warning,
@@ -387,6 +398,7 @@ mod test_github_comment {
                 line_end: 1,
                 fix: None,
             }],
+            cells: vec![],
         }];
 
         let body = get_comment_body(&violations, "0.2.3");
@@ -413,6 +425,7 @@ CREATE TABLE "core_bar" (
                 "#
                 .into(),
                 violations: vec![],
+                cells: vec![],
             },
             CheckReport {
                 path: "bravo.sql".into(),
@@ -421,6 +434,7 @@ ALTER TABLE "core_recipe" ADD COLUMN "foo" integer DEFAULT 10;
                 "#
                 .into(),
                 violations: vec![],
+                cells: vec![],
             },
         ];
 
@@ -469,6 +483,7 @@ ALTER TABLE "core_recipe" ADD COLUMN "foo" integer DEFAULT 10;
             sql: large_sql,
             violations: vec![ReportViolation {
                 file: "large.sql".into(),
+                cell: None,
                 line: 1,
                 column: 0,
                 level: ViolationLevel::Warning,
@@ -480,6 +495,7 @@ ALTER TABLE "core_recipe" ADD COLUMN "foo" integer DEFAULT 10;
                 line_end: 1,
                 fix: None,
             }],
+            cells: vec![],
         }];
 
         let body = get_comment_body(&violations, "0.2.3");
@@ -504,6 +520,7 @@ ALTER TABLE "core_recipe" ADD COLUMN "foo" integer DEFAULT 10;
             sql: massive_sql,
             violations: vec![ReportViolation {
                 file: "massive.sql".into(),
+                cell: None,
                 line: 1,
                 column: 0,
                 level: ViolationLevel::Warning,
@@ -515,6 +532,7 @@ ALTER TABLE "core_recipe" ADD COLUMN "foo" integer DEFAULT 10;
                 line_end: 1,
                 fix: None,
             }],
+            cells: vec![],
         }];
 
         let body = get_comment_body(&violations, "0.2.3");
@@ -1,9 +1,13 @@
 mod cmd;
 mod config;
 mod debug;
+mod embedded;
 mod file;
 mod file_finding;
+mod fingerprint;
+mod format;
 mod github;
+mod notebook;
 mod reporter;
 use crate::cmd::Cmd;
 use crate::reporter::LintArgs;
@@ -12,7 +16,8 @@ use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use debug::debug;
 use reporter::lint_and_report;
 use simplelog::CombinedLogger;
-use squawk_linter::{Rule, Version};
+use squawk_lexer::Templating;
+use squawk_linter::{Dialect, Framework, Rule, Version};
 use squawk_thread::ThreadIntent;
 use std::io;
 use std::panic;
@@ -61,6 +66,35 @@ pub enum Command {
     Server,
     /// Comment on a PR with Squawk's results.
     UploadToGithub(Box<UploadToGithubArgs>),
+    /// Print a normalized form and hash of each statement
+    ///
+    /// Statements that only differ in their constants, comments or formatting
+    /// get the same hash, similar to `pg_stat_statements`.
+    Fingerprint(FingerprintArgs),
+    /// Format SQL files in place
+    ///
+    /// Reads from stdin and writes to stdout when no paths are given.
+    Format(FormatArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct FingerprintArgs {
+    /// Paths or patterns to read, otherwise reads from stdin
+    #[arg(value_name = "path")]
+    paths: Vec<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct FormatArgs {
+    /// Paths or patterns to format, otherwise reads from stdin
+    #[arg(value_name = "path")]
+    paths: Vec<String>,
+    /// Exit with an error if any file would change instead of writing them
+    #[arg(long)]
+    check: bool,
+    /// Print a diff of the changes instead of writing them
+    #[arg(long)]
+    diff: bool,
 }
 
 #[derive(Debug, ValueEnum, Clone)]
@@ -68,6 +102,8 @@ pub enum DebugOption {
     Lex,
     Parse,
     Ast,
+    /// The typed AST as versioned JSON, for building tools on the parser
+    Json,
 }
 
 #[derive(Debug, ValueEnum, Clone, Copy, Default)]
@@ -128,6 +164,35 @@ struct Opts {
     /// --pg-version=13.0
     #[arg(long, global = true)]
     pg_version: Option<Version>,
+    /// Migration framework used to run the SQL files
+    ///
+    /// Annotations like `-- +goose NO TRANSACTION` decide whether each file
+    /// is assumed to run in a transaction and down sections aren't linted.
+    ///
+    /// For example:
+    /// --framework=goose
+    #[arg(long, value_name = "framework", global = true)]
+    framework: Option<Framework>,
+    /// Template syntax to accept in SQL files, `jinja` (e.g., dbt models) or
+    /// `grafana`
+    ///
+    /// Template expressions are parsed as identifiers and template tags are
+    /// ignored.
+    ///
+    /// For example:
+    /// --templating=jinja
+    #[arg(long, value_name = "templating", global = true)]
+    templating: Option<Templating>,
+    /// Postgres compatible database the SQL runs against, `postgres`,
+    /// `cockroachdb`, `aurora-dsql`, or `yugabytedb`
+    ///
+    /// Enables the database's syntax, reports features it doesn't support,
+    /// and skips rules about locking that doesn't happen there.
+    ///
+    /// For example:
+    /// --dialect=aurora-dsql
+    #[arg(long, value_name = "dialect", global = true)]
+    dialect: Option<Dialect>,
     /// Output debug format
     #[arg(long, value_name = "format", ignore_case = true)]
     debug: Option<DebugOption>,
@@ -218,17 +283,25 @@ Please open an issue at https://github.com/sbdchd/squawk/issues/new with the log
     }
 
     match Cmd::from(opts) {
-        Cmd::Server => {
-            with_extra_thread(
-                "LspServer",
-                ThreadIntent::LatencySensitive,
-                squawk_server::run,
-            )
+        Cmd::Server(config_opts) => {
+            with_extra_thread("LspServer", ThreadIntent::LatencySensitive, move || {
+                squawk_server::run(Box::new(move |root| config_opts.load(root)))
+            })
             .context("language server failed")?;
         }
         Cmd::UploadToGithub(config) => {
             github::check_and_comment_on_pr(*config).context("Upload to GitHub failed")?;
         }
+        Cmd::Fingerprint(fingerprint_files) => {
+            let stdout = io::stdout();
+            let mut handle = stdout.lock();
+            return fingerprint::fingerprint(&mut handle, fingerprint_files);
+        }
+        Cmd::Format(format_files) => {
+            let stdout = io::stdout();
+            let mut handle = stdout.lock();
+            return format::format(&mut handle, format_files);
+        }
         Cmd::Debug(debug_args) => {
             let stdout = io::stdout();
             let mut handle = stdout.lock();
@@ -0,0 +1,267 @@
+//! Find the SQL cells of Jupyter notebooks.
+//!
+//! A cell is SQL when the notebook uses a SQL kernel, when VS Code marked
+//! the cell's language as SQL, or when it starts with the `%%sql` magic from
+//! `ipython-sql` & `jupysql`.
+//!
+//! The SQL cells are joined into a single source so the reporters can render
+//! snippets, with each violation's location relative to its cell.
+//!
+//! see: <https://nbformat.readthedocs.io/en/latest/format_description.html>
+
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Deserialize;
+use squawk_line_index::{TextRange, TextSize};
+
+use crate::embedded::{EmbeddedSql, SQL_LANGUAGES, normalize_placeholders};
+
+const SQL_MAGIC: &str = "%%sql";
+
+pub(crate) fn is_notebook(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension == "ipynb")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotebookCell {
+    /// 1-based position of the cell in the notebook, counting non-SQL cells
+    /// too, which matches the numbering in Jupyter.
+    pub number: usize,
+    /// Range of the cell in the notebook's SQL source.
+    pub range: TextRange,
+}
+
+#[derive(Debug)]
+pub(crate) struct SqlCell {
+    pub(crate) cell: NotebookCell,
+    pub(crate) sql: EmbeddedSql,
+}
+
+#[derive(Debug)]
+pub(crate) struct Notebook {
+    /// The SQL cells joined together.
+    pub(crate) source: String,
+    pub(crate) cells: Vec<SqlCell>,
+}
+
+#[derive(Deserialize)]
+struct RawNotebook {
+    cells: Vec<RawCell>,
+    #[serde(default)]
+    metadata: NotebookMetadata,
+}
+
+#[derive(Deserialize, Default)]
+struct NotebookMetadata {
+    kernelspec: Option<KernelSpec>,
+    language_info: Option<LanguageInfo>,
+}
+
+#[derive(Deserialize)]
+struct KernelSpec {
+    language: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LanguageInfo {
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawCell {
+    cell_type: String,
+    #[serde(default)]
+    source: Source,
+    #[serde(default)]
+    metadata: CellMetadata,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Source {
+    Text(String),
+    Lines(Vec<String>),
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::Text(String::new())
+    }
+}
+
+impl Source {
+    fn into_text(self) -> String {
+        match self {
+            Source::Text(text) => text,
+            Source::Lines(lines) => lines.concat(),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct CellMetadata {
+    vscode: Option<VsCodeMetadata>,
+}
+
+#[derive(Deserialize)]
+struct VsCodeMetadata {
+    #[serde(rename = "languageId")]
+    language_id: Option<String>,
+}
+
+fn is_sql_language(language: Option<&str>) -> bool {
+    language.is_some_and(|language| SQL_LANGUAGES.contains(&language.to_ascii_lowercase().as_str()))
+}
+
+fn sql_magic_len(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix(SQL_MAGIC)?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    // the rest of the line has the magic's options, like the connection
+    Some(text.find('\n').map_or(text.len(), |newline| newline + 1))
+}
+
+impl Notebook {
+    pub(crate) fn parse(json: &str) -> Result<Notebook> {
+        let raw: RawNotebook = serde_json::from_str(json)?;
+        let kernel_is_sql = is_sql_language(
+            raw.metadata
+                .kernelspec
+                .as_ref()
+                .and_then(|kernelspec| kernelspec.language.as_deref()),
+        ) || is_sql_language(
+            raw.metadata
+                .language_info
+                .as_ref()
+                .and_then(|language_info| language_info.name.as_deref()),
+        );
+
+        let mut source = String::new();
+        let mut cells = vec![];
+        for (idx, raw_cell) in raw.cells.into_iter().enumerate() {
+            if raw_cell.cell_type != "code" {
+                continue;
+            }
+            let cell_is_sql = is_sql_language(
+                raw_cell
+                    .metadata
+                    .vscode
+                    .as_ref()
+                    .and_then(|vscode| vscode.language_id.as_deref()),
+            );
+            let text = raw_cell.source.into_text();
+            let magic_len = sql_magic_len(&text);
+            if !kernel_is_sql && !cell_is_sql && magic_len.is_none() {
+                continue;
+            }
+
+            let start = TextSize::of(source.as_str());
+            source.push_str(&text);
+            if !text.ends_with('\n') {
+                source.push('\n');
+            }
+            let range = TextRange::new(start, TextSize::of(source.as_str()));
+
+            let sql_start = start + TextSize::new(magic_len.unwrap_or(0) as u32);
+            let sql_range = TextRange::new(sql_start, start + TextSize::of(text.as_str()));
+            let sql = &source[sql_range];
+            let sql = if magic_len.is_some() {
+                // jupysql supports the same named params as sqlalchemy
+                normalize_placeholders(sql)
+            } else {
+                sql.to_string()
+            };
+
+            cells.push(SqlCell {
+                cell: NotebookCell {
+                    number: idx + 1,
+                    range,
+                },
+                sql: EmbeddedSql {
+                    range: sql_range,
+                    sql,
+                },
+            });
+        }
+
+        Ok(Notebook { source, cells })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use super::{Notebook, is_notebook};
+
+    fn cells(json: &str) -> String {
+        let notebook = Notebook::parse(json).unwrap();
+        let mut out = String::new();
+        for cell in notebook.cells {
+            out.push_str(&format!(
+                "cell {} {:?} {:?}\n",
+                cell.cell.number, cell.sql.range, cell.sql.sql
+            ));
+        }
+        out
+    }
+
+    #[test]
+    fn notebook_path() {
+        assert!(is_notebook("analysis.ipynb"));
+        assert!(!is_notebook("analysis.py"));
+    }
+
+    #[test]
+    fn sql_magic() {
+        let json = r##"{
+  "cells": [
+    {"cell_type": "markdown", "metadata": {}, "source": ["# Users\n"]},
+    {"cell_type": "code", "metadata": {}, "source": ["%load_ext sql\n"]},
+    {"cell_type": "code", "metadata": {}, "source": ["%%sql postgresql://localhost/db\n", "select * from users\n", "where id = :user_id"]},
+    {"cell_type": "code", "metadata": {}, "source": "%%sqlite\nselect 1"}
+  ],
+  "metadata": {"kernelspec": {"language": "python", "name": "python3"}},
+  "nbformat": 4,
+  "nbformat_minor": 5
+}"##;
+        assert_snapshot!(cells(json), @r#"cell 3 32..71 "select * from users\nwhere id = $1      ""#);
+    }
+
+    #[test]
+    fn sql_kernel() {
+        let json = r#"{
+  "cells": [
+    {"cell_type": "code", "metadata": {}, "source": "create table t (id int);"},
+    {"cell_type": "raw", "metadata": {}, "source": "not sql"},
+    {"cell_type": "code", "metadata": {}, "source": ["select 1;\n"]}
+  ],
+  "metadata": {"kernelspec": {"language": "sql", "name": "xsql"}}
+}"#;
+        assert_snapshot!(cells(json), @r#"
+        cell 1 0..24 "create table t (id int);"
+        cell 3 25..35 "select 1;\n"
+        "#);
+    }
+
+    #[test]
+    fn vscode_cell_language() {
+        let json = r#"{
+  "cells": [
+    {"cell_type": "code", "metadata": {}, "source": "print(1)"},
+    {"cell_type": "code", "metadata": {"vscode": {"languageId": "sql"}}, "source": "select 1;"}
+  ],
+  "metadata": {}
+}"#;
+        assert_snapshot!(cells(json), @r#"cell 2 0..9 "select 1;""#);
+    }
+
+    #[test]
+    fn invalid_json() {
+        assert!(Notebook::parse("{").is_err());
+    }
+}
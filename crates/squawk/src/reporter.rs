@@ -5,9 +5,9 @@ use log::info;
 use rayon::prelude::*;
 use serde::Serialize;
 use squawk_line_index::LineIndex;
-use squawk_line_index::TextRange;
-use squawk_linter::{Fix, Linter, Rule, Version};
-use squawk_syntax::SourceFile;
+use squawk_line_index::{TextRange, TextSize};
+use squawk_linter::{Dialect, Edit, Fix, Framework, Linter, Rule, Version};
+use squawk_syntax::{SourceFile, Templating};
 use std::hash::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
@@ -15,94 +15,262 @@ use std::io;
 use std::process::ExitCode;
 
 use crate::cmd::Input;
+use crate::embedded::{Language, extract_sql, masked_sql};
+use crate::notebook::{Notebook, NotebookCell, is_notebook};
 use crate::{
     Reporter,
     file::{sql_from_path, sql_from_stdin},
 };
 
-fn check_sql(
-    sql: &str,
-    path: &str,
-    included_rules: &[Rule],
-    excluded_rules: &[Rule],
-    pg_version: Option<Version>,
-    assume_in_transaction: bool,
-) -> CheckReport {
-    let mut linter = Linter::with_rules(included_rules, excluded_rules);
-    if let Some(pg_version) = pg_version {
-        linter.settings.pg_version = pg_version;
+/// How to lint each file, from the config file and the CLI flags.
+#[derive(Debug, Default)]
+pub(crate) struct LintSettings {
+    pub(crate) excluded_rules: Vec<Rule>,
+    pub(crate) included_rules: Vec<Rule>,
+    pub(crate) pg_version: Option<Version>,
+    pub(crate) assume_in_transaction: bool,
+    pub(crate) framework: Option<Framework>,
+    pub(crate) templating: Option<Templating>,
+    pub(crate) dialect: Dialect,
+}
+
+fn check_sql(sql: &str, path: &str, settings: &LintSettings) -> CheckReport {
+    let new_linter = |assume_in_transaction: bool, framework: Option<Framework>| {
+        let mut linter = Linter::with_rules(&settings.included_rules, &settings.excluded_rules);
+        if let Some(pg_version) = settings.pg_version {
+            linter.settings.pg_version = pg_version;
+        }
+        linter.settings.assume_in_transaction = assume_in_transaction;
+        linter.settings.framework = framework;
+        linter.settings.dialect = settings.dialect;
+        linter
+    };
+    let assume_in_transaction = settings.assume_in_transaction;
+    let framework = settings.framework;
+    if is_notebook(path) {
+        return check_notebook(sql, path, |linter_sql, offset, line_index| {
+            lint_sql(
+                new_linter(assume_in_transaction, framework),
+                linter_sql,
+                offset,
+                path,
+                line_index,
+                settings,
+            )
+        });
     }
-    linter.settings.assume_in_transaction = assume_in_transaction;
-    let parse = SourceFile::parse(sql);
-    let parse_errors = parse.errors();
+
     let line_index = LineIndex::new(sql);
 
-    if !parse_errors.is_empty() {
-        let violations = parse_errors
-            .into_iter()
-            .map(|e| {
-                let range_start = e.range().start();
-                let line_col = line_index.line_col(range_start);
-                let range_end = e.range().end();
-                let line_end = line_index.line_col(range_end);
-                ReportViolation {
+    let violations = if Language::from_path(path) == Some(Language::Markdown) {
+        // Code blocks in docs are separate examples, so a `begin` in one
+        // shouldn't affect the next.
+        extract_sql(Language::Markdown, sql)
+            .iter()
+            .flat_map(|block| {
+                lint_sql(
+                    new_linter(assume_in_transaction, framework),
+                    &block.sql,
+                    block.offset(),
+                    path,
+                    &line_index,
+                    settings,
+                )
+            })
+            .collect()
+    } else if let Some(language) = Language::from_path(path) {
+        // Some frameworks configure transactions in the host file, so we
+        // can't rely on annotations in the SQL.
+        let (assume_in_transaction, framework) =
+            match framework.and_then(|framework| language.in_transaction(framework, sql)) {
+                Some(in_transaction) => (in_transaction, None),
+                None => (assume_in_transaction, framework),
+            };
+        // Lint the snippets together, so settings like `set lock_timeout` in
+        // one carry over to the next, like they would in a `.sql` migration.
+        let masked = masked_sql(sql, &extract_sql(language, sql));
+        lint_sql(
+            new_linter(assume_in_transaction, framework),
+            &masked,
+            TextSize::new(0),
+            path,
+            &line_index,
+            settings,
+        )
+    } else {
+        lint_sql(
+            new_linter(assume_in_transaction, framework),
+            sql,
+            TextSize::new(0),
+            path,
+            &line_index,
+            settings,
+        )
+    };
+
+    CheckReport {
+        path: path.into(),
+        sql: sql.into(),
+        violations,
+        cells: vec![],
+    }
+}
+
+/// Lint the SQL cells of a notebook, the report's source is the SQL cells
+/// joined together and the violations' lines are relative to their cell.
+fn check_notebook(
+    json: &str,
+    path: &str,
+    lint: impl Fn(&str, TextSize, &LineIndex) -> Vec<ReportViolation>,
+) -> CheckReport {
+    let notebook = match Notebook::parse(json) {
+        Ok(notebook) => notebook,
+        Err(err) => {
+            return CheckReport {
+                path: path.into(),
+                sql: json.into(),
+                violations: vec![ReportViolation {
                     file: path.to_string(),
-                    line: line_col.line as usize,
-                    line_end: line_end.line as usize,
-                    column: line_col.col as usize,
-                    column_end: line_end.col as usize,
+                    cell: None,
+                    line: 0,
+                    line_end: 0,
+                    column: 0,
+                    column_end: 0,
                     level: ViolationLevel::Error,
                     help: None,
-                    range: e.range(),
-                    message: e.message().to_string(),
+                    range: TextRange::empty(TextSize::new(0)),
+                    message: format!("Invalid notebook: {err}"),
                     rule_name: "syntax-error".to_string(),
                     fix: None,
-                }
-            })
-            .collect();
-        return CheckReport {
-            path: path.into(),
-            sql: sql.into(),
-            violations,
-        };
+                }],
+                cells: vec![],
+            };
+        }
+    };
+
+    let line_index = LineIndex::new(&notebook.source);
+    let mut violations = vec![];
+    for sql_cell in &notebook.cells {
+        let cell = sql_cell.cell;
+        let first_line = line_index.line_col(cell.range.start()).line as usize;
+        for mut violation in lint(&sql_cell.sql.sql, sql_cell.sql.offset(), &line_index) {
+            violation.cell = Some(cell.number);
+            violation.line -= first_line;
+            violation.line_end -= first_line;
+            violations.push(violation);
+        }
     }
 
+    CheckReport {
+        path: path.into(),
+        violations,
+        cells: notebook
+            .cells
+            .iter()
+            .map(|sql_cell| sql_cell.cell)
+            .collect(),
+        sql: notebook.source,
+    }
+}
+
+/// Lint `sql` which starts at `offset` in the file, returning violations
+/// with ranges relative to the file.
+fn lint_sql(
+    mut linter: Linter,
+    sql: &str,
+    offset: TextSize,
+    path: &str,
+    line_index: &LineIndex,
+    settings: &LintSettings,
+) -> Vec<ReportViolation> {
+    let parse = SourceFile::parse_dialect(sql, settings.templating, settings.dialect);
+    let mut parse_errors = parse.errors_for_dialect(
+        settings.pg_version.map(|pg_version| pg_version.major()),
+        settings.dialect,
+    );
+    parse_errors.extend(parse.tree().plpgsql_errors());
+
+    let syntax_errors: Vec<ReportViolation> = parse_errors
+        .iter()
+        .map(|e| {
+            let range = e.range() + offset;
+            let line_col = line_index.line_col(range.start());
+            let line_end = line_index.line_col(range.end());
+            ReportViolation {
+                file: path.to_string(),
+                cell: None,
+                line: line_col.line as usize,
+                line_end: line_end.line as usize,
+                column: line_col.col as usize,
+                column_end: line_end.col as usize,
+                level: ViolationLevel::Error,
+                help: None,
+                range,
+                message: e.message().to_string(),
+                rule_name: "syntax-error".to_string(),
+                fix: None,
+            }
+        })
+        .collect();
+
+    // Statements with syntax errors aren't linted, but the rest of the file
+    // still is.
+    let broken_stmts: Vec<TextRange> = parse
+        .syntax_node()
+        .children()
+        .map(|stmt| stmt.text_range())
+        .filter(|range| {
+            parse_errors
+                .iter()
+                .any(|e| range.intersect(e.range()).is_some())
+        })
+        .collect();
+
     let errors = linter.lint(&parse, sql);
     let violations = errors
         .into_iter()
+        .filter(|e| {
+            !broken_stmts
+                .iter()
+                .any(|range| range.contains_range(e.text_range))
+        })
         .map(|e| {
-            let range_start = e.text_range.start();
-            let line_col = line_index.line_col(range_start);
-            let range_end = e.text_range.end();
-            let line_end = line_index.line_col(range_end);
+            let range = e.text_range + offset;
+            let line_col = line_index.line_col(range.start());
+            let line_end = line_index.line_col(range.end());
+            let fix = e.fix.map(|fix| Fix {
+                edits: fix
+                    .edits
+                    .into_iter()
+                    .map(|edit| Edit {
+                        text_range: edit.text_range + offset,
+                        ..edit
+                    })
+                    .collect(),
+                ..fix
+            });
             ReportViolation {
                 file: path.to_string(),
+                cell: None,
                 line: line_col.line as usize,
                 line_end: line_end.line as usize,
                 column: line_col.col as usize,
                 column_end: line_end.col as usize,
-                range: e.text_range,
+                range,
                 help: e.help,
                 level: ViolationLevel::Warning,
                 message: e.message,
                 rule_name: e.code.to_string(),
-                fix: e.fix,
+                fix,
             }
-        })
-        .collect();
-
-    CheckReport {
-        path: path.into(),
-        sql: sql.into(),
-        violations,
-    }
+        });
+    syntax_errors.into_iter().chain(violations).collect()
 }
 
 fn render_lint_error<W: std::io::Write>(
     f: &mut W,
     err: &ReportViolation,
-    filename: &str,
-    sql: &str,
+    report: &CheckReport,
 ) -> Result<()> {
     let renderer = Renderer::styled().decor_style(DecorStyle::Unicode);
     let level = match err.level {
@@ -110,10 +278,21 @@ fn render_lint_error<W: std::io::Write>(
         ViolationLevel::Error => Level::ERROR,
     };
 
+    // Notebook violations are shown with only their cell so the line numbers
+    // match the ones in Jupyter.
+    let cell = err
+        .cell
+        .and_then(|number| report.cells.iter().find(|cell| cell.number == number));
+    let (sql, start) = match cell {
+        Some(cell) => (&report.sql[cell.range], cell.range.start()),
+        None => (report.sql.as_str(), TextSize::new(0)),
+    };
+    let filename = &err.location();
+
     let snippet = Snippet::source(sql)
         .path(filename)
         .fold(true)
-        .annotation(AnnotationKind::Primary.span(err.range.into()));
+        .annotation(AnnotationKind::Primary.span((err.range - start).into()));
 
     let rule_url = format!("https://squawkhq.com/docs/{}", err.rule_name);
     let mut group = level
@@ -128,8 +307,9 @@ fn render_lint_error<W: std::io::Write>(
 
     if let Some(fix) = &err.fix {
         let patches = fix.edits.iter().map(|edit| {
-            let start = edit.text_range.start().into();
-            let end = edit.text_range.end().into();
+            let text_range = edit.text_range - start;
+            let start = text_range.start().into();
+            let end = text_range.end().into();
             let replacement = edit
                 .text
                 .as_deref()
@@ -153,10 +333,7 @@ fn render_lint_error<W: std::io::Write>(
 
 pub(crate) struct LintArgs {
     pub(crate) input: Input,
-    pub(crate) excluded_rules: Vec<Rule>,
-    pub(crate) included_rules: Vec<Rule>,
-    pub(crate) pg_version: Option<Version>,
-    pub(crate) assume_in_transaction: bool,
+    pub(crate) settings: LintSettings,
     pub(crate) reporter: Reporter,
     pub(crate) github_annotations: bool,
 }
@@ -171,14 +348,7 @@ pub fn lint_files(args: &LintArgs) -> Result<Vec<CheckReport>> {
                 return Ok(vec![]);
             }
             let path = stdin.path.clone().unwrap_or_else(|| "stdin".into());
-            let content = check_sql(
-                &sql,
-                &path,
-                &args.included_rules,
-                &args.excluded_rules,
-                args.pg_version,
-                args.assume_in_transaction,
-            );
+            let content = check_sql(&sql, &path, &args.settings);
             Ok(vec![content])
         }
         Input::Paths(path_bufs) => {
@@ -187,14 +357,7 @@ pub fn lint_files(args: &LintArgs) -> Result<Vec<CheckReport>> {
                 .map(|path| {
                     info!("checking file path: {}", path.display());
                     let sql = sql_from_path(path)?;
-                    Ok(check_sql(
-                        &sql,
-                        path.to_str().unwrap(),
-                        &args.included_rules,
-                        &args.excluded_rules,
-                        args.pg_version,
-                        args.assume_in_transaction,
-                    ))
+                    Ok(check_sql(&sql, path.to_str().unwrap(), &args.settings))
                 })
                 .collect::<Result<Vec<_>>>()?;
             reports.sort_by(|a, b| a.path.cmp(&b.path));
@@ -303,6 +466,10 @@ impl std::fmt::Display for ViolationLevel {
 #[derive(Debug, Serialize)]
 pub struct ReportViolation {
     pub file: String,
+    /// For notebooks, the cell the violation is in. The lines are relative
+    /// to the cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cell: Option<usize>,
     pub line: usize,
     pub column: usize,
     #[serde(skip_serializing)]
@@ -317,13 +484,23 @@ pub struct ReportViolation {
     pub fix: Option<Fix>,
 }
 
+impl ReportViolation {
+    /// The file, plus the cell for notebooks, like `analysis.ipynb:cell 3`.
+    fn location(&self) -> String {
+        match self.cell {
+            Some(cell) => format!("{}:cell {cell}", self.file),
+            None => self.file.clone(),
+        }
+    }
+}
+
 fn fmt_gcc<W: io::Write>(f: &mut W, reports: &[CheckReport]) -> Result<()> {
     for report in reports {
         for violation in &report.violations {
             writeln!(
                 f,
                 "{}:{}:{}: {}: {} {}",
-                violation.file,
+                violation.location(),
                 violation.line,
                 violation.column,
                 violation.level,
@@ -338,10 +515,9 @@ fn fmt_gcc<W: io::Write>(f: &mut W, reports: &[CheckReport]) -> Result<()> {
 pub fn fmt_tty_violation<W: io::Write>(
     f: &mut W,
     violation: &ReportViolation,
-    filename: &str,
-    sql: &str,
+    report: &CheckReport,
 ) -> Result<()> {
-    render_lint_error(f, violation, filename, sql)?;
+    render_lint_error(f, violation, report)?;
     Ok(())
 }
 
@@ -349,7 +525,7 @@ pub fn fmt_tty<W: io::Write>(f: &mut W, reports: &[CheckReport]) -> Result<()> {
     let summary = Summary::from(reports);
     for report in reports {
         for violation in &report.violations {
-            fmt_tty_violation(f, violation, &report.path, &report.sql)?;
+            fmt_tty_violation(f, violation, report)?;
         }
     }
     print_summary(f, &summary)?;
@@ -423,7 +599,12 @@ impl From<&ViolationLevel> for String {
 fn make_fingerprint(v: &ReportViolation) -> String {
     let key = format!(
         "{}:{}-{}:{}:{}:{}",
-        v.file, v.line, v.line_end, v.rule_name, v.message, v.level
+        v.location(),
+        v.line,
+        v.line_end,
+        v.rule_name,
+        v.message,
+        v.level
     );
     let mut hasher = DefaultHasher::new();
     key.hash(&mut hasher);
@@ -475,6 +656,8 @@ pub struct CheckReport {
     pub path: String,
     pub sql: String,
     pub violations: Vec<ReportViolation>,
+    /// For notebooks, where each SQL cell is in `sql`.
+    pub cells: Vec<NotebookCell>,
 }
 
 pub fn print_violations<W: io::Write>(
@@ -496,7 +679,7 @@ pub fn print_violations<W: io::Write>(
 
 #[cfg(test)]
 mod test_check_files {
-    use super::check_sql;
+    use super::{Dialect, LintSettings, Templating, check_sql};
     use crate::reporter::fmt_json;
     use insta::assert_snapshot;
     use serde_json::Value;
@@ -504,32 +687,127 @@ mod test_check_files {
     #[test]
     fn check_files_invalid_syntax() {
         let sql = r"
-select \;
+select );
         ";
         let mut buff = Vec::new();
-        let res = check_sql(sql, "test.sql", &[], &[], None, false);
+        let res = check_sql(sql, "test.sql", &LintSettings::default());
         fmt_json(&mut buff, vec![res]).unwrap();
 
         let val: Value = serde_json::from_slice(&buff).unwrap();
         assert_snapshot!(val);
     }
 
+    #[test]
+    fn lint_psql_script() {
+        let sql = r#"\set ON_ERROR_STOP on
+\connect app
+create index i on :"tbl" (a);
+"#;
+        let mut buff = vec![];
+        let res = check_sql(sql, "test.sql", &LintSettings::default());
+        fmt_json(&mut buff, vec![res]).unwrap();
+        assert_snapshot!(String::from_utf8_lossy(&buff), @r#"[{"file":"test.sql","line":2,"column":0,"level":"Warning","message":"Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.","help":"Use an explicit name for a concurrently created index","rule_name":"prefer-robust-stmts","column_end":29,"line_end":2},{"file":"test.sql","line":2,"column":0,"level":"Warning","message":"During normal index creation, table updates are blocked, but reads are still allowed.","help":"Use `concurrently` to avoid blocking writes.","rule_name":"require-concurrent-index-creation","column_end":29,"line_end":2},{"file":"test.sql","line":2,"column":0,"level":"Warning","message":"Missing `set lock_timeout` before potentially slow SHARE lock operations","help":"Configure a `lock_timeout` before this statement. Statement requires: SHARE lock; blocking: writes, schema changes.","rule_name":"require-lock-timeout","column_end":29,"line_end":2},{"file":"test.sql","line":2,"column":0,"level":"Warning","message":"Missing `set statement_timeout` before potentially slow operations","help":"Configure a `statement_timeout` before this statement","rule_name":"require-statement-timeout","column_end":29,"line_end":2}]"#);
+    }
+
     #[test]
     fn skip_lint_on_syntax_error() {
         let error_sql = "ALTER TABLE foo ALTER CONSTRAINT bar RENAME TO quux;";
         let mut buff = vec![];
-        let res = check_sql(error_sql, "test.sql", &[], &[], None, false);
+        let res = check_sql(error_sql, "test.sql", &LintSettings::default());
         fmt_json(&mut buff, vec![res]).unwrap();
         assert_snapshot!(String::from_utf8_lossy(&buff), @r#"[{"file":"test.sql","line":0,"column":36,"level":"Error","message":"missing comma","help":null,"rule_name":"syntax-error","column_end":36,"line_end":0}]"#);
     }
+
+    #[test]
+    fn syntax_error_in_plpgsql_body() {
+        let sql = "do $$
+begin
+  if true then
+    raise notice 'hi'
+  end if;
+end
+$$;
+";
+        let mut buff = vec![];
+        let res = check_sql(sql, "test.sql", &LintSettings::default());
+        fmt_json(&mut buff, vec![res]).unwrap();
+        assert_snapshot!(String::from_utf8_lossy(&buff), @r#"[{"file":"test.sql","line":3,"column":21,"level":"Error","message":"expected `;`, found `end`","help":null,"rule_name":"syntax-error","column_end":21,"line_end":3}]"#);
+    }
+
+    #[test]
+    fn lint_stmts_around_syntax_error() {
+        let sql = "selct 1;
+alter table t add column c int not null;
+";
+        let mut buff = vec![];
+        let res = check_sql(sql, "test.sql", &LintSettings::default());
+        fmt_json(&mut buff, vec![res]).unwrap();
+        assert_snapshot!(String::from_utf8_lossy(&buff), @r#"[{"file":"test.sql","line":0,"column":0,"level":"Error","message":"expected command, found `selct`, did you mean SELECT?","help":null,"rule_name":"syntax-error","column_end":0,"line_end":0},{"file":"test.sql","line":1,"column":0,"level":"Warning","message":"Missing `set lock_timeout` before potentially slow ACCESS EXCLUSIVE lock operations","help":"Configure a `lock_timeout` before this statement. Statement requires: ACCESS EXCLUSIVE lock; blocking: reads, writes, schema changes.","rule_name":"require-lock-timeout","column_end":40,"line_end":1},{"file":"test.sql","line":1,"column":0,"level":"Warning","message":"Missing `set statement_timeout` before potentially slow operations","help":"Configure a `statement_timeout` before this statement","rule_name":"require-statement-timeout","column_end":40,"line_end":1},{"file":"test.sql","line":1,"column":14,"level":"Warning","message":"Adding a new column that is `NOT NULL` and has no default value to an existing table effectively makes it required.","help":"Make the field nullable or add a non-VOLATILE DEFAULT","rule_name":"adding-required-field","column_end":39,"line_end":1},{"file":"test.sql","line":1,"column":14,"level":"Warning","message":"Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.","help":null,"rule_name":"prefer-robust-stmts","column_end":39,"line_end":1},{"file":"test.sql","line":1,"column":27,"level":"Warning","message":"Using 32-bit integer fields can result in hitting the max `int` limit.","help":"Use 64-bit integer values instead to prevent hitting this limit.","rule_name":"prefer-bigint-over-int","column_end":30,"line_end":1}]"#);
+    }
+
+    #[test]
+    fn syntax_newer_than_pg_version() {
+        let sql = "merge into t using s on t.id = s.id when matched then delete returning *;";
+        let pg_version = Some("15".parse().unwrap());
+        let mut buff = vec![];
+        let res = check_sql(
+            sql,
+            "test.sql",
+            &LintSettings {
+                pg_version,
+                ..Default::default()
+            },
+        );
+        fmt_json(&mut buff, vec![res]).unwrap();
+        assert_snapshot!(String::from_utf8_lossy(&buff), @r#"[{"file":"test.sql","line":0,"column":61,"level":"Error","message":"`merge ... returning` requires PostgreSQL 17","help":null,"rule_name":"syntax-error","column_end":72,"line_end":0}]"#);
+    }
+
+    #[test]
+    fn aurora_dsql_dialect() {
+        let sql = "create index async i on t (a);
+create trigger tr after insert on t execute function f();
+";
+        let mut buff = vec![];
+        let res = check_sql(
+            sql,
+            "test.sql",
+            &LintSettings {
+                dialect: Dialect::AuroraDsql,
+                ..Default::default()
+            },
+        );
+        fmt_json(&mut buff, vec![res]).unwrap();
+        assert_snapshot!(String::from_utf8_lossy(&buff), @r#"[{"file":"test.sql","line":1,"column":0,"level":"Error","message":"`create trigger` isn't supported by Aurora DSQL","help":null,"rule_name":"syntax-error","column_end":14,"line_end":1},{"file":"test.sql","line":0,"column":0,"level":"Warning","message":"Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.","help":"Use an explicit name for a concurrently created index","rule_name":"prefer-robust-stmts","column_end":30,"line_end":0},{"file":"test.sql","line":0,"column":0,"level":"Warning","message":"Missing `set statement_timeout` before potentially slow operations","help":"Configure a `statement_timeout` before this statement","rule_name":"require-statement-timeout","column_end":30,"line_end":0}]"#);
+    }
+
+    #[test]
+    fn templated_sql() {
+        let sql = "{{ config(materialized='table') }}
+{% if target.name == 'prod' %}
+alter table {{ ref('users') }} add column c int not null;
+{% endif %}
+";
+        let res = check_sql(
+            sql,
+            "users.sql",
+            &LintSettings {
+                templating: Some(Templating::Jinja),
+                ..Default::default()
+            },
+        );
+        let mut buff = vec![];
+        fmt_json(&mut buff, vec![res]).unwrap();
+        assert_snapshot!(String::from_utf8_lossy(&buff), @r#"[{"file":"users.sql","line":2,"column":0,"level":"Warning","message":"Missing `set lock_timeout` before potentially slow ACCESS EXCLUSIVE lock operations","help":"Configure a `lock_timeout` before this statement. Statement requires: ACCESS EXCLUSIVE lock; blocking: reads, writes, schema changes.","rule_name":"require-lock-timeout","column_end":57,"line_end":2},{"file":"users.sql","line":2,"column":0,"level":"Warning","message":"Missing `set statement_timeout` before potentially slow operations","help":"Configure a `statement_timeout` before this statement","rule_name":"require-statement-timeout","column_end":57,"line_end":2},{"file":"users.sql","line":2,"column":31,"level":"Warning","message":"Adding a new column that is `NOT NULL` and has no default value to an existing table effectively makes it required.","help":"Make the field nullable or add a non-VOLATILE DEFAULT","rule_name":"adding-required-field","column_end":56,"line_end":2},{"file":"users.sql","line":2,"column":31,"level":"Warning","message":"Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.","help":null,"rule_name":"prefer-robust-stmts","column_end":56,"line_end":2},{"file":"users.sql","line":2,"column":44,"level":"Warning","message":"Using 32-bit integer fields can result in hitting the max `int` limit.","help":"Use 64-bit integer values instead to prevent hitting this limit.","rule_name":"prefer-bigint-over-int","column_end":47,"line_end":2}]"#);
+    }
 }
 
 #[cfg(test)]
 mod test_reporter {
-    use super::check_sql;
+    use super::{LintSettings, check_sql};
     use crate::reporter::{Reporter, print_violations};
     use console::strip_ansi_codes;
     use insta::{assert_debug_snapshot, assert_snapshot};
+    use squawk_linter::{Framework, Rule};
 
     #[test]
     fn display_violations_gcc() {
@@ -545,7 +823,7 @@ SELECT 1;
 
         let res = print_violations(
             &mut buff,
-            vec![check_sql(sql, filename, &[], &[], None, false)],
+            vec![check_sql(sql, filename, &LintSettings::default())],
             &Reporter::Gcc,
             false,
         );
@@ -563,6 +841,287 @@ SELECT 1;
         ");
     }
 
+    #[test]
+    fn display_violations_embedded_python_gcc() {
+        let py = r#"
+from alembic import op
+
+def upgrade():
+    op.execute("""
+        ALTER TABLE "core_recipe" ADD COLUMN "foo" integer NOT NULL;
+    """)
+    op.execute("SELECT * FROM t WHERE id = %s")
+"#;
+
+        let mut buff = Vec::new();
+
+        let res = print_violations(
+            &mut buff,
+            vec![check_sql(py, "0001_add_foo.py", &LintSettings::default())],
+            &Reporter::Gcc,
+            false,
+        );
+        assert!(res.is_ok());
+
+        assert_snapshot!(String::from_utf8_lossy(&buff), @"
+        0001_add_foo.py:5:8: warning: require-lock-timeout Missing `set lock_timeout` before potentially slow ACCESS EXCLUSIVE lock operations
+        0001_add_foo.py:5:8: warning: require-statement-timeout Missing `set statement_timeout` before potentially slow operations
+        0001_add_foo.py:5:34: warning: adding-required-field Adding a new column that is `NOT NULL` and has no default value to an existing table effectively makes it required.
+        0001_add_foo.py:5:34: warning: prefer-robust-stmts Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.
+        0001_add_foo.py:5:51: warning: prefer-bigint-over-int Using 32-bit integer fields can result in hitting the max `int` limit.
+        ");
+    }
+
+    #[test]
+    fn display_violations_markdown_gcc() {
+        let md = r#"# Adding a column
+
+```sql
+ALTER TABLE "core_recipe" ADD COLUMN "foo" integer NOT NULL;
+```
+
+Don't do this:
+
+```sql squawk-ignore
+DROP TABLE "core_recipe";
+```
+"#;
+
+        let mut buff = Vec::new();
+
+        let res = print_violations(
+            &mut buff,
+            vec![check_sql(md, "runbook.md", &LintSettings::default())],
+            &Reporter::Gcc,
+            false,
+        );
+        assert!(res.is_ok());
+
+        assert_snapshot!(String::from_utf8_lossy(&buff), @"
+        runbook.md:3:0: warning: require-lock-timeout Missing `set lock_timeout` before potentially slow ACCESS EXCLUSIVE lock operations
+        runbook.md:3:0: warning: require-statement-timeout Missing `set statement_timeout` before potentially slow operations
+        runbook.md:3:26: warning: adding-required-field Adding a new column that is `NOT NULL` and has no default value to an existing table effectively makes it required.
+        runbook.md:3:26: warning: prefer-robust-stmts Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.
+        runbook.md:3:43: warning: prefer-bigint-over-int Using 32-bit integer fields can result in hitting the max `int` limit.
+        ");
+    }
+
+    #[test]
+    fn display_violations_markdown_blocks_linted_separately() {
+        let md = r#"# Checking a migration by hand
+
+```sql
+begin;
+select 1;
+```
+
+> ```sql
+> create index concurrently if not exists i on t (a);
+> ```
+"#;
+
+        let mut buff = Vec::new();
+
+        let res = print_violations(
+            &mut buff,
+            vec![check_sql(md, "runbook.md", &LintSettings::default())],
+            &Reporter::Gcc,
+            false,
+        );
+        assert!(res.is_ok());
+
+        assert_snapshot!(String::from_utf8_lossy(&buff), @"
+        runbook.md:3:0: warning: ban-uncommitted-transaction Transaction never committed or rolled back.
+        runbook.md:8:2: warning: require-lock-timeout Missing `set lock_timeout` before potentially slow SHARE UPDATE EXCLUSIVE lock operations
+        runbook.md:8:2: warning: require-statement-timeout Missing `set statement_timeout` before potentially slow operations
+        ");
+    }
+
+    const NOTEBOOK: &str = r##"{
+  "cells": [
+    {"cell_type": "markdown", "metadata": {}, "source": ["# Backfill\n"]},
+    {"cell_type": "code", "metadata": {}, "source": ["%load_ext sql\n"]},
+    {"cell_type": "code", "metadata": {}, "source": ["%%sql\n", "select 1;\n"]},
+    {"cell_type": "code", "metadata": {}, "source": ["%%sql\n", "alter table t\n", "  add column c integer;"]}
+  ],
+  "metadata": {"kernelspec": {"language": "python", "name": "python3"}},
+  "nbformat": 4,
+  "nbformat_minor": 5
+}"##;
+
+    #[test]
+    fn display_violations_notebook_gcc() {
+        let mut buff = Vec::new();
+
+        let res = print_violations(
+            &mut buff,
+            vec![check_sql(
+                NOTEBOOK,
+                "backfill.ipynb",
+                &LintSettings::default(),
+            )],
+            &Reporter::Gcc,
+            false,
+        );
+        assert!(res.is_ok());
+
+        assert_snapshot!(String::from_utf8_lossy(&buff), @"
+        backfill.ipynb:cell 4:1:0: warning: require-lock-timeout Missing `set lock_timeout` before potentially slow ACCESS EXCLUSIVE lock operations
+        backfill.ipynb:cell 4:1:0: warning: require-statement-timeout Missing `set statement_timeout` before potentially slow operations
+        backfill.ipynb:cell 4:2:2: warning: prefer-robust-stmts Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.
+        backfill.ipynb:cell 4:2:15: warning: prefer-bigint-over-int Using 32-bit integer fields can result in hitting the max `int` limit.
+        ");
+    }
+
+    #[test]
+    fn display_violations_notebook_tty() {
+        let mut buff = Vec::new();
+
+        let res = print_violations(
+            &mut buff,
+            vec![check_sql(
+                NOTEBOOK,
+                "backfill.ipynb",
+                &LintSettings::default(),
+            )],
+            &Reporter::Tty,
+            false,
+        );
+        assert!(res.is_ok());
+
+        assert_snapshot!(strip_ansi_codes(&String::from_utf8_lossy(&buff)), @r#"
+        warning[]8;;https://squawkhq.com/docs/require-lock-timeout\require-lock-timeout]8;;\]: Missing `set lock_timeout` before potentially slow ACCESS EXCLUSIVE lock operations
+          ╭▸ backfill.ipynb:cell 4:2:1
+          │
+        2 │ ┏ alter table t
+        3 │ ┃   add column c integer;
+          │ ┗━━━━━━━━━━━━━━━━━━━━━━━┛
+          │
+          ├ help: Configure a `lock_timeout` before this statement. Statement requires: ACCESS EXCLUSIVE lock; blocking: reads, writes, schema changes.
+          ╭╴
+        2 + set lock_timeout = '1s';
+          ╰╴
+        warning[]8;;https://squawkhq.com/docs/require-statement-timeout\require-statement-timeout]8;;\]: Missing `set statement_timeout` before potentially slow operations
+          ╭▸ backfill.ipynb:cell 4:2:1
+          │
+        2 │ ┏ alter table t
+        3 │ ┃   add column c integer;
+          │ ┗━━━━━━━━━━━━━━━━━━━━━━━┛
+          │
+          ├ help: Configure a `statement_timeout` before this statement
+          ╭╴
+        2 + set statement_timeout = '5s';
+          ╰╴
+        warning[]8;;https://squawkhq.com/docs/prefer-robust-stmts\prefer-robust-stmts]8;;\]: Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.
+          ╭▸ backfill.ipynb:cell 4:3:3
+          │
+        3 │   add column c integer;
+          │   ━━━━━━━━━━━━━━━━━━━━
+          ╭╴
+        3 │   add column if not exists c integer;
+          ╰╴             +++++++++++++
+        warning[]8;;https://squawkhq.com/docs/prefer-bigint-over-int\prefer-bigint-over-int]8;;\]: Using 32-bit integer fields can result in hitting the max `int` limit.
+          ╭▸ backfill.ipynb:cell 4:3:16
+          │
+        3 │   add column c integer;
+          │                ━━━━━━━
+          │
+          ├ help: Use 64-bit integer values instead to prevent hitting this limit.
+          ╭╴
+        3 -   add column c integer;
+        3 +   add column c bigint;
+          ╰╴
+
+        Find detailed examples and solutions for each rule at https://squawkhq.com/docs/rules
+        Found 4 issues in 1 file (checked 1 source file)
+        "#);
+    }
+
+    #[test]
+    fn display_violations_notebook_json() {
+        let mut buff = Vec::new();
+
+        let res = print_violations(
+            &mut buff,
+            vec![check_sql(
+                NOTEBOOK,
+                "backfill.ipynb",
+                &LintSettings::default(),
+            )],
+            &Reporter::Json,
+            false,
+        );
+        assert!(res.is_ok());
+
+        assert_snapshot!(String::from_utf8_lossy(&buff), @r#"[{"file":"backfill.ipynb","cell":4,"line":1,"column":0,"level":"Warning","message":"Missing `set lock_timeout` before potentially slow ACCESS EXCLUSIVE lock operations","help":"Configure a `lock_timeout` before this statement. Statement requires: ACCESS EXCLUSIVE lock; blocking: reads, writes, schema changes.","rule_name":"require-lock-timeout","column_end":23,"line_end":2},{"file":"backfill.ipynb","cell":4,"line":1,"column":0,"level":"Warning","message":"Missing `set statement_timeout` before potentially slow operations","help":"Configure a `statement_timeout` before this statement","rule_name":"require-statement-timeout","column_end":23,"line_end":2},{"file":"backfill.ipynb","cell":4,"line":2,"column":2,"level":"Warning","message":"Missing `IF NOT EXISTS`, the migration can't be rerun if it fails part way through.","help":null,"rule_name":"prefer-robust-stmts","column_end":22,"line_end":2},{"file":"backfill.ipynb","cell":4,"line":2,"column":15,"level":"Warning","message":"Using 32-bit integer fields can result in hitting the max `int` limit.","help":"Use 64-bit integer values instead to prevent hitting this limit.","rule_name":"prefer-bigint-over-int","column_end":22,"line_end":2}]"#);
+    }
+
+    #[test]
+    fn invalid_notebook() {
+        let mut buff = Vec::new();
+
+        let res = print_violations(
+            &mut buff,
+            vec![check_sql("{", "backfill.ipynb", &LintSettings::default())],
+            &Reporter::Gcc,
+            false,
+        );
+        assert!(res.is_ok());
+
+        assert_snapshot!(String::from_utf8_lossy(&buff), @"backfill.ipynb:0:0: error: syntax-error Invalid notebook: EOF while parsing an object at line 1 column 1");
+    }
+
+    #[test]
+    fn embedded_rails_disable_ddl_transaction() {
+        let rb = r#"
+class AddIndex < ActiveRecord::Migration[7.0]
+  disable_ddl_transaction!
+
+  def change
+    execute <<~SQL
+      SET lock_timeout = '1s';
+      SET statement_timeout = '5s';
+      CREATE INDEX CONCURRENTLY IF NOT EXISTS "i" ON "t" ("c");
+    SQL
+  end
+end
+"#;
+        let report = check_sql(
+            rb,
+            "add_index.rb",
+            &LintSettings {
+                framework: Some(Framework::Rails),
+                ..Default::default()
+            },
+        );
+        assert_eq!(report.violations.len(), 0);
+    }
+
+    #[test]
+    fn embedded_python_snippets_share_settings() {
+        let py = r#"
+def upgrade():
+    op.execute("set lock_timeout = '1s'")
+    op.execute("alter table t add column c int")
+"#;
+        let report = check_sql(
+            py,
+            "0001_add_column.py",
+            &LintSettings {
+                included_rules: vec![Rule::RequireLockTimeout],
+                ..Default::default()
+            },
+        );
+        assert!(
+            report
+                .violations
+                .iter()
+                .all(|violation| violation.rule_name != "require-lock-timeout"),
+            "{:?}",
+            report.violations
+        );
+    }
+
     #[test]
     fn display_violations_tty_and_github_annotations() {
         let sql = r#" 
@@ -578,7 +1137,7 @@ SELECT 1;
 
         let res = print_violations(
             &mut buff,
-            vec![check_sql(sql, filename, &[], &[], None, false)],
+            vec![check_sql(sql, filename, &LintSettings::default())],
             &Reporter::Tty,
             true,
         );
@@ -600,7 +1159,7 @@ SELECT 1;
 
         let res = print_violations(
             &mut buff,
-            vec![check_sql(sql, filename, &[], &[], None, false)],
+            vec![check_sql(sql, filename, &LintSettings::default())],
             &Reporter::Tty,
             false,
         );
@@ -616,7 +1175,7 @@ SELECT 1;
 
         let res = print_violations(
             &mut buff,
-            vec![check_sql(sql, "main.sql", &[], &[], None, false)],
+            vec![check_sql(sql, "main.sql", &LintSettings::default())],
             &Reporter::Tty,
             false,
         );
@@ -638,7 +1197,7 @@ SELECT 1;
 
         let res = print_violations(
             &mut buff,
-            vec![check_sql(sql, filename, &[], &[], None, false)],
+            vec![check_sql(sql, filename, &LintSettings::default())],
             &Reporter::Json,
             false,
         );
@@ -659,7 +1218,7 @@ SELECT 1;
 
         let res = print_violations(
             &mut buff,
-            vec![check_sql(sql, filename, &[], &[], None, false)],
+            vec![check_sql(sql, filename, &LintSettings::default())],
             &Reporter::Gitlab,
             false,
         );
@@ -678,7 +1237,7 @@ ALTER TABLE "core_foo" ADD COLUMN "bar" integer NOT NULL;
 SELECT 1;
 "#;
         let filename = "main.sql";
-        assert_debug_snapshot!(check_sql(sql, filename, &[], &[], None, false));
+        assert_debug_snapshot!(check_sql(sql, filename, &LintSettings::default()));
     }
 
     fn sql_with_line_ending(line_ending: &str) -> String {
@@ -695,7 +1254,7 @@ SELECT 1;
         let mut buff = Vec::new();
         print_violations(
             &mut buff,
-            vec![check_sql(sql, "main.sql", &[], &[], None, false)],
+            vec![check_sql(sql, "main.sql", &LintSettings::default())],
             &reporter,
             false,
         )
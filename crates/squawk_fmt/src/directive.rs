@@ -0,0 +1,65 @@
+use rowan::{NodeOrToken, TextRange};
+use squawk_linter::ignore::directive_info;
+use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::{SyntaxNode, SyntaxToken};
+
+const DIRECTIVE_TEXT: &str = "squawk-fmt:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Directive {
+    /// Stop formatting until the next `on`
+    Off,
+    On,
+    /// Leave the next statement as is
+    Ignore,
+}
+
+/// ```sql
+/// -- squawk-fmt: off -- hand aligned
+/// ```
+/// becomes `Directive::Off`
+pub(crate) fn directive(token: &SyntaxToken) -> Option<Directive> {
+    // parsed the same way as `squawk-ignore` comments
+    let (body, _) = directive_info(token, DIRECTIVE_TEXT)?;
+    match body.trim() {
+        "off" => Some(Directive::Off),
+        "on" => Some(Directive::On),
+        "ignore" => Some(Directive::Ignore),
+        _ => None,
+    }
+}
+
+/// Ranges of the file we keep as is, either between `off` and `on` or the
+/// statement after an `ignore`.
+///
+/// Directives are only recognized between statements.
+pub(crate) fn unformatted_ranges(file: &SyntaxNode) -> Vec<TextRange> {
+    let mut ranges = vec![];
+    let mut off_start = None;
+    let mut ignore_next = false;
+    for el in file.children_with_tokens() {
+        match el {
+            NodeOrToken::Token(token) => match (directive(&token), off_start) {
+                (Some(Directive::Off), None) => {
+                    off_start = Some(token.text_range().end());
+                }
+                (Some(Directive::On), Some(start)) => {
+                    ranges.push(TextRange::new(start, token.text_range().start()));
+                    off_start = None;
+                }
+                (Some(Directive::Ignore), None) => ignore_next = true,
+                _ => (),
+            },
+            NodeOrToken::Node(node) => {
+                if ignore_next && off_start.is_none() && ast::Stmt::can_cast(node.kind()) {
+                    ranges.push(node.text_range());
+                    ignore_next = false;
+                }
+            }
+        }
+    }
+    if let Some(start) = off_start {
+        ranges.push(TextRange::new(start, file.text_range().end()));
+    }
+    ranges
+}
@@ -1,31 +1,46 @@
 use anyhow::{Result, bail};
 use itertools::Itertools;
-use rowan::Direction;
+use rowan::{Direction, TextRange, TextSize};
 use squawk_line_index::{LineEnding, UniversalNewlines, find_newline};
 use squawk_syntax::ast::{self, AstNode, LitKind, normalize_name_node};
-use squawk_syntax::quote::{quote_bare_column_alias, quote_column_alias, quote_ident};
-use squawk_syntax::{SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken};
+use squawk_syntax::edit::Edit;
+use squawk_syntax::quote::{
+    quote_bare_column_alias, quote_column_alias, quote_ident, unquote_ident_text,
+};
+use squawk_syntax::type_alias::{BuiltinType, builtin_type_name, rename_builtin_type};
+use squawk_syntax::{SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, match_ast};
 use tiny_pretty::Doc;
-use tiny_pretty::{LineBreak, PrintOptions, print};
+use tiny_pretty::{LineBreak, print};
+
+use crate::directive::unformatted_ranges;
+use crate::options::{
+    AliasAs, CommaStyle, FormatOptions, KeywordCase, QuoteIdentifiers, TypeAliases,
+};
+use crate::verify::{location, verify};
 
 // TODO: anytime we have `syntax().to_string()`, it means we have to do more to
 // actually convert the data into the IR. to_string() is a temp hack
 
-fn build_source_file(source_file: &ast::SourceFile) -> Doc<'_> {
+fn build_source_file<'a>(options: &FormatOptions, source_file: &ast::SourceFile) -> Doc<'a> {
+    let unformatted = unformatted_ranges(source_file.syntax());
     let mut doc = Doc::nil();
     for el in source_file.syntax().children_with_tokens() {
+        if unformatted
+            .iter()
+            .any(|range| range.contains_range(el.text_range()))
+        {
+            doc = doc.append(Doc::text(el.to_string()));
+            continue;
+        }
         match el {
             rowan::NodeOrToken::Node(node) => {
-                if let Some(stmt) = ast::Stmt::cast(node) {
-                    match stmt {
-                        ast::Stmt::Select(select) => {
-                            doc = doc.append(build_select_doc(&select));
-                        }
-                        ast::Stmt::CreateTable(create_table) => {
-                            doc = doc.append(build_create_table(&create_table));
-                        }
-                        _ => (),
-                    }
+                if let Some(stmt) = ast::Stmt::cast(node.clone()) {
+                    doc = doc.append(build_stmt(options, &stmt));
+                } else if matches!(
+                    node.kind(),
+                    SyntaxKind::PSQL_META_COMMAND | SyntaxKind::COPY_STDIN_DATA
+                ) {
+                    doc = doc.append(Doc::text(node.text().to_string()));
                 }
             }
             rowan::NodeOrToken::Token(token) => {
@@ -36,6 +51,14 @@ fn build_source_file(source_file: &ast::SourceFile) -> Doc<'_> {
                     let lines = token.text().universal_newlines().count();
                     if lines >= 2 {
                         doc = doc.append(Doc::empty_line()).append(Doc::empty_line());
+                    } else if !token.text().contains(['\n', '\r'])
+                        && token
+                            .next_token()
+                            .is_some_and(|next| next.kind() == SyntaxKind::COMMENT)
+                    {
+                        // trailing comments stay with the statement before
+                        // them, e.g., `-- squawk-ignore` comments
+                        doc = doc.append(Doc::space());
                     } else {
                         doc = doc.append(Doc::empty_line());
                     }
@@ -46,48 +69,130 @@ fn build_source_file(source_file: &ast::SourceFile) -> Doc<'_> {
     doc
 }
 
-fn build_create_table<'a>(create_table: &ast::CreateTable) -> Doc<'a> {
-    let table_name = create_table.table_name().unwrap();
-    let mut doc = Doc::text("create")
-        .append(Doc::space())
-        .append(Doc::text("table"))
-        .append(Doc::space())
-        .append(leading_comments(table_name.syntax()))
-        .append(build_path(&table_name.path().unwrap()))
+fn build_stmt<'a>(options: &FormatOptions, stmt: &ast::Stmt) -> Doc<'a> {
+    match stmt {
+        ast::Stmt::Select(select) => build_select_doc(options, select),
+        ast::Stmt::CreateTable(create_table) => build_create_table(options, create_table),
+        ast::Stmt::Insert(insert) => build_insert(options, insert),
+        ast::Stmt::Update(update) => build_update(options, update),
+        ast::Stmt::Delete(delete) => build_delete(options, delete),
+        ast::Stmt::Merge(merge) => build_merge(options, merge),
+        ast::Stmt::Values(values) => build_values(options, values),
+        ast::Stmt::AlterTable(alter_table) => build_alter_table(options, alter_table),
+        ast::Stmt::CreateIndex(create_index) => build_create_index(options, create_index),
+        ast::Stmt::CreateView(create_view) => build_create_view(options, create_view),
+        ast::Stmt::CreateFunction(create_function) => {
+            build_create_function(options, create_function)
+        }
+        ast::Stmt::CommentOn(comment_on) => build_comment_on(options, comment_on),
+        // we don't know how to format the statement yet, so we keep it as is
+        // instead of dropping it
+        _ => build_verbatim(options, stmt.syntax()),
+    }
+}
+
+fn build_create_table<'a>(options: &FormatOptions, create_table: &ast::CreateTable) -> Doc<'a> {
+    let (Some(table_name), Some(arg_list)) =
+        (create_table.table_name(), create_table.table_arg_list())
+    else {
+        // `partition of` tables without columns
+        return build_tokens(options, create_table.syntax());
+    };
+    // `create temp table if not exists`
+    let prefix: Vec<SyntaxToken> =
+        tokens_between(create_table.syntax(), None, Some(table_name.syntax())).collect();
+    let mut doc = build_token_list(options, prefix.iter().cloned());
+    if !prefix.last().is_some_and(is_line_comment) {
+        doc = doc.append(Doc::space());
+    }
+    if let Some(path) = table_name.path() {
+        doc = doc.append(build_path(options, &path));
+    }
+    // `of type` and `partition of`
+    doc = doc.append(build_token_list(
+        options,
+        tokens_between(
+            create_table.syntax(),
+            Some(table_name.syntax()),
+            Some(arg_list.syntax()),
+        ),
+    ));
+    // the comments after the last arg go after the trailing comma, and a
+    // line comment there already breaks the line before the closing paren
+    let last_comments = arg_list
+        .syntax()
+        .last_child()
+        .map(|node| trailing_comment_tokens(&node))
+        .unwrap_or_default();
+    let close = if last_comments.last().is_some_and(is_line_comment) {
+        Doc::hard_line()
+    } else {
+        Doc::line_or_nil()
+    };
+    let args: Vec<SyntaxNode> = arg_list.syntax().children().collect();
+    let last = args.len().saturating_sub(1);
+    doc = doc
         .append(Doc::text("("))
         .append(
             Doc::line_or_nil()
                 .append(Doc::list(
                     Itertools::intersperse(
-                        create_table
-                            .table_arg_list()
-                            .unwrap()
-                            .args()
-                            .map(build_table_arg),
-                        Doc::text(",").append(Doc::hard_line()),
+                        args.into_iter().enumerate().map(|(i, node)| {
+                            let doc = match ast::TableArg::cast(node.clone()) {
+                                Some(arg) => build_table_arg(options, arg),
+                                None => {
+                                    leading_comments(&node).append(build_tokens(options, &node))
+                                }
+                            };
+                            if i == last {
+                                doc
+                            } else {
+                                doc.append(trailing_comments(&node))
+                            }
+                        }),
+                        match options.comma_style {
+                            CommaStyle::Trailing => Doc::text(",").append(Doc::hard_line()),
+                            CommaStyle::Leading => {
+                                Doc::hard_line().append(Doc::text(",")).append(Doc::space())
+                            }
+                        },
                     )
                     .collect(),
                 ))
-                .nest(2)
-                .append(Doc::line_or_nil())
+                .append(build_trailing_comma(options))
+                .append(build_open_trailing_comments(&last_comments))
+                .nest(options.indent_width)
+                .append(close)
                 .group(),
         )
         .append(Doc::text(")"));
 
+    // `inherits`, `partition by`, `with`, `on commit`, etc.
+    let mut suffix: Vec<SyntaxToken> =
+        tokens_between(create_table.syntax(), Some(arg_list.syntax()), None)
+            .filter(|token| token.kind() != SyntaxKind::SEMICOLON)
+            .collect();
+    // comments before the semicolon are handled by `build_semicolon`
+    while suffix.last().is_some_and(|token| token.kind().is_trivia()) {
+        suffix.pop();
+    }
+    doc = doc.append(build_token_list(options, suffix.into_iter()));
+
     doc = doc.append(build_semicolon(create_table.semicolon_token()));
 
     doc
 }
 
-fn build_path<'a>(path: &ast::Path) -> Doc<'a> {
-    build_path_parts(path.qualifier(), path.dot_token(), path.segment())
+fn build_path<'a>(options: &FormatOptions, path: &ast::Path) -> Doc<'a> {
+    build_path_parts(options, path.qualifier(), path.dot_token(), path.segment())
 }
 
-fn build_path_ref<'a>(path: &ast::PathRef) -> Doc<'a> {
-    build_path_parts(path.qualifier(), path.dot_token(), path.segment())
+fn build_path_ref<'a>(options: &FormatOptions, path: &ast::PathRef) -> Doc<'a> {
+    build_path_parts(options, path.qualifier(), path.dot_token(), path.segment())
 }
 
 fn build_path_parts<'a>(
+    options: &FormatOptions,
     qualifier: Option<ast::PathRef>,
     dot: Option<SyntaxToken>,
     segment: Option<impl AstNode>,
@@ -95,7 +200,7 @@ fn build_path_parts<'a>(
     let mut doc = Doc::nil();
     if let Some(qualifier) = qualifier {
         doc = doc
-            .append(build_path_ref(&qualifier))
+            .append(build_path_ref(options, &qualifier))
             .append(trailing_comments(qualifier.syntax()));
     }
     if dot.is_some() {
@@ -104,12 +209,12 @@ fn build_path_parts<'a>(
     if let Some(segment) = segment {
         doc = doc
             .append(leading_comments(segment.syntax()))
-            .append(build_name(segment.syntax()));
+            .append(build_name(options, segment.syntax()));
     }
     doc
 }
 
-fn build_name<'a>(node: &SyntaxNode) -> Doc<'a> {
+fn build_name<'a>(options: &FormatOptions, node: &SyntaxNode) -> Doc<'a> {
     let mut tokens = node
         .children_with_tokens()
         .filter_map(|el| el.into_token())
@@ -124,7 +229,7 @@ fn build_name<'a>(node: &SyntaxNode) -> Doc<'a> {
         for token in tokens {
             let text = match token.kind() {
                 SyntaxKind::STRING | SyntaxKind::COMMENT => token.text().to_string(),
-                _ => token.text().to_ascii_lowercase(),
+                _ => keyword_text(options, token.text()),
             };
             doc = doc.append(Doc::space()).append(Doc::text(text));
             if is_line_comment(&token) {
@@ -134,7 +239,22 @@ fn build_name<'a>(node: &SyntaxNode) -> Doc<'a> {
         return doc;
     }
 
-    Doc::text(quote_ident(&normalize_name_node(node)))
+    if ident.text().starts_with('"') {
+        return Doc::text(ident_text(options, &ident));
+    }
+
+    match options.quote_identifiers {
+        QuoteIdentifiers::Avoid => Doc::text(quote_ident(&normalize_name_node(node))),
+        QuoteIdentifiers::Preserve => Doc::text(ident.text().to_string()),
+    }
+}
+
+fn ident_text(options: &FormatOptions, ident: &SyntaxToken) -> String {
+    let text = ident.text();
+    match options.quote_identifiers {
+        QuoteIdentifiers::Avoid => unquote_ident_text(text).unwrap_or(text).to_string(),
+        QuoteIdentifiers::Preserve => text.to_string(),
+    }
 }
 
 fn is_unicode_escape(text: &str) -> bool {
@@ -142,165 +262,1669 @@ fn is_unicode_escape(text: &str) -> bool {
         .is_some_and(|text| text.starts_with("&\""))
 }
 
-fn build_table_arg<'a>(arg: ast::TableArg) -> Doc<'a> {
-    let doc = leading_comments(arg.syntax());
-    let doc = doc.append(match &arg {
-        ast::TableArg::Column(column) => {
-            let mut doc = build_name(column.name().unwrap().syntax());
-            if let Some(ty) = column.ty() {
-                doc = doc
-                    .append(Doc::space())
-                    .append(leading_comments(ty.syntax()))
-                    .append(build_type(ty));
-            }
-            doc
-        }
-        ast::TableArg::LikeClause(like_clause) => build_like_clause(like_clause),
-        ast::TableArg::TableConstraint(_table_constraint) => todo!(),
-    });
-    doc.append(trailing_comments(arg.syntax()))
+fn build_table_arg<'a>(options: &FormatOptions, arg: ast::TableArg) -> Doc<'a> {
+    leading_comments(arg.syntax()).append(match &arg {
+        ast::TableArg::Column(column) => 'column: {
+            let Some(name) = column.name() else {
+                break 'column build_tokens(options, column.syntax());
+            };
+            let mut doc = build_name(options, name.syntax());
+            let mut last = name.syntax().clone();
+            if let Some(ty) = column.ty() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(leading_comments(ty.syntax()))
+                    .append(build_type(options, ty.clone()));
+                last = ty.syntax().clone();
+            }
+            // constraints, `collate`, `compression`, etc.
+            doc.append(build_token_list(
+                options,
+                tokens_between(column.syntax(), Some(&last), None),
+            ))
+        }
+        ast::TableArg::LikeClause(like_clause) => build_like_clause(options, like_clause),
+        ast::TableArg::TableConstraint(table_constraint) => {
+            build_tokens(options, table_constraint.syntax())
+        }
+    })
+}
+
+fn build_like_clause<'a>(options: &FormatOptions, like_clause: &ast::LikeClause) -> Doc<'a> {
+    let mut doc = keyword(options, "like");
+
+    if let Some(relation_name) = like_clause.relation_name_ref() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(relation_name.syntax()));
+        if let Some(path) = relation_name.path_ref() {
+            doc = doc.append(build_path_ref(options, &path));
+        }
+    }
+
+    let like_options: Vec<Doc<'a>> = like_clause
+        .like_options()
+        .map(|option| {
+            Doc::line_or_space()
+                .append(leading_comments(option.syntax()))
+                .append(build_like_option(options, &option))
+        })
+        .collect();
+    if !like_options.is_empty() {
+        doc = doc.append(Doc::list(like_options).nest(options.indent_width).group());
+    }
+
+    doc
+}
+
+fn build_like_option<'a>(options: &FormatOptions, option: &ast::LikeOption) -> Doc<'a> {
+    let (keyword, property) = match option {
+        ast::LikeOption::ExcludingProperty(n) => ("excluding", n.table_property()),
+        ast::LikeOption::IncludingProperty(n) => ("including", n.table_property()),
+    };
+
+    let mut doc = Doc::text(keyword);
+    if let Some(property) = property {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(property.syntax()))
+            .append(build_keyword_node(options, property.syntax()));
+    }
+    doc
+}
+
+fn build_select_doc<'a>(options: &FormatOptions, select: &ast::Select) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    if let Some(with_clause) = select.with_clause() {
+        doc = doc
+            .append(build_with_clause(options, &with_clause))
+            .append(Doc::line_or_space());
+    }
+    doc = doc.append(keyword(options, "select"));
+
+    // `select;` has an empty select clause
+    if let Some(select_clause) = select
+        .select_clause()
+        .filter(|clause| clause.select_quantifier().is_some() || clause.target_list().is_some())
+    {
+        let mut select_doc = Doc::line_or_space();
+        match select_clause.select_quantifier() {
+            Some(ast::SelectQuantifier::DistinctClause(distinct_clause)) => {
+                select_doc = select_doc.append(leading_comments(distinct_clause.syntax()));
+                select_doc = select_doc
+                    .append(build_tokens(options, distinct_clause.syntax()))
+                    .append(Doc::space());
+            }
+            Some(ast::SelectQuantifier::All(all)) => {
+                select_doc = select_doc.append(leading_comments(all.syntax()));
+                select_doc = select_doc
+                    .append(keyword(options, "all"))
+                    .append(Doc::space());
+            }
+            None => (),
+        }
+        if let Some(target_list) = select_clause.target_list() {
+            select_doc = select_doc.append(build_target_list(options, &target_list));
+        }
+        doc = doc.append(select_doc.nest(options.indent_width).group());
+    }
+
+    if let Some(from) = &select.from_clause() {
+        doc = doc.append(build_clause(
+            from.syntax(),
+            build_from_clause(options, from),
+        ));
+    }
+
+    if let Some(where_clause) = &select.where_clause() {
+        doc = doc.append(build_clause(
+            where_clause.syntax(),
+            build_where_clause(options, where_clause),
+        ));
+    }
+
+    if let Some(group) = &select.group_by_clause() {
+        let mut group_doc = Doc::line_or_space().append(leading_comments(group.syntax()));
+        group_doc = group_doc
+            .append(keyword(options, "group"))
+            .append(Doc::space());
+        if let Some(by_token) = group.by_token() {
+            group_doc = group_doc.append(leading_comments_token(&by_token));
+        }
+        group_doc = group_doc
+            .append(keyword(options, "by"))
+            .append(Doc::space());
+        // `all` or `distinct`
+        for quantifier in group
+            .syntax()
+            .children()
+            .filter(|node| node.kind() != SyntaxKind::GROUP_BY_LIST)
+        {
+            group_doc = group_doc
+                .append(leading_comments(&quantifier))
+                .append(build_tokens(options, &quantifier))
+                .append(Doc::space());
+        }
+        if let Some(list) = group.group_by_list() {
+            group_doc = group_doc.append(build_group_by_list(options, list));
+        }
+        doc = doc.append(group_doc);
+    }
+
+    if let Some(having) = &select.having_clause() {
+        let mut having_doc = keyword(options, "having");
+        if let Some(expr) = having.expr() {
+            having_doc = having_doc
+                .append(Doc::space())
+                .append(leading_comments(expr.syntax()))
+                .append(build_expr(options, expr).nest(options.indent_width));
+        }
+        doc = doc.append(build_clause(having.syntax(), having_doc));
+    }
+
+    // TODO: format these clauses
+    for clause in select.syntax().children().filter(|node| {
+        matches!(
+            node.kind(),
+            SyntaxKind::WINDOW_CLAUSE
+                | SyntaxKind::ORDER_BY_CLAUSE
+                | SyntaxKind::LOCKING_CLAUSE
+                | SyntaxKind::LIMIT_CLAUSE
+                | SyntaxKind::FETCH_CLAUSE
+                | SyntaxKind::OFFSET_CLAUSE
+        )
+    }) {
+        doc = doc.append(build_clause(&clause, build_verbatim(options, &clause)));
+    }
+
+    doc = doc.append(build_semicolon(select.semicolon_token()));
+
+    doc.group()
+}
+
+// Clauses share a line when the statement fits, otherwise each one starts on
+// its own line.
+fn build_clause<'a>(node: &SyntaxNode, clause: Doc<'a>) -> Doc<'a> {
+    Doc::line_or_space()
+        .append(leading_comments(node))
+        .append(clause)
+}
+
+// Each item with its leading and trailing comments. A line comment between
+// an item and its comma goes after the comma, so the comma doesn't end up on
+// a line of its own, and comments following the comma stay on its line.
+fn build_commented_list<'a>(
+    options: &FormatOptions,
+    items: impl Iterator<Item = (SyntaxNode, Doc<'a>)>,
+) -> Doc<'a> {
+    let (doc, comments) = build_commented_items(options, items);
+    doc.append(build_trailing_comments(&comments))
+}
+
+// Like `build_commented_list`, but leaves the trailing comments of the last
+// item to the caller.
+fn build_commented_items<'a>(
+    options: &FormatOptions,
+    items: impl Iterator<Item = (SyntaxNode, Doc<'a>)>,
+) -> (Doc<'a>, Vec<SyntaxToken>) {
+    let mut items = items.peekable();
+    let mut doc = Doc::nil();
+    let mut comma_comments: Vec<SyntaxToken> = vec![];
+    let mut last_comments: Vec<SyntaxToken> = vec![];
+    while let Some((node, item)) = items.next() {
+        let leading: Vec<SyntaxToken> = comment_tokens_before(node.clone())
+            .into_iter()
+            .filter(|comment| !comma_comments.contains(comment))
+            .collect();
+        let comments = trailing_comment_tokens(&node);
+        let line_comment = comments.last().is_some_and(is_line_comment);
+        comma_comments = vec![];
+        doc = doc.append(build_leading_comments(&leading)).append(item);
+        let last = items.peek().is_none();
+        match options.comma_style {
+            CommaStyle::Trailing if last => {
+                if options.trailing_commas {
+                    doc = doc.append(Doc::flat_or_break(Doc::nil(), Doc::text(",")));
+                }
+                last_comments = comments;
+            }
+            CommaStyle::Trailing if line_comment => {
+                doc = doc
+                    .append(Doc::text(","))
+                    .append(build_trailing_comments(&comments));
+            }
+            CommaStyle::Trailing => {
+                comma_comments = comments_after_comma(&node);
+                doc = doc
+                    .append(build_trailing_comments(&comments))
+                    .append(Doc::text(","))
+                    .append(build_trailing_comments(&comma_comments));
+                if !comma_comments.last().is_some_and(is_line_comment) {
+                    doc = doc.append(Doc::line_or_space());
+                }
+            }
+            CommaStyle::Leading if last => {
+                last_comments = comments;
+            }
+            CommaStyle::Leading if line_comment => {
+                doc = doc
+                    .append(build_trailing_comments(&comments))
+                    .append(Doc::text(","))
+                    .append(Doc::space());
+            }
+            CommaStyle::Leading => {
+                doc = doc
+                    .append(build_trailing_comments(&comments))
+                    .append(Doc::flat_or_break(
+                        Doc::text(",").append(Doc::space()),
+                        Doc::hard_line().append(Doc::text(",")).append(Doc::space()),
+                    ));
+            }
+        }
+    }
+    (doc, last_comments)
+}
+
+fn build_with_clause<'a>(options: &FormatOptions, with_clause: &ast::WithClause) -> Doc<'a> {
+    let mut doc = keyword(options, "with");
+    if let Some(recursive) = with_clause.recursive_token() {
+        doc = doc
+            .append(comments_before(recursive))
+            .append(Doc::space())
+            .append(keyword(options, "recursive"));
+    }
+    let tables = with_clause.with_tables().map(|with_table| {
+        let doc = build_with_table(options, &with_table);
+        (with_table.syntax().clone(), doc)
+    });
+    doc.append(Doc::space())
+        .append(build_commented_list(options, tables))
+}
+
+fn build_with_table<'a>(options: &FormatOptions, with_table: &ast::WithTable) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    if let Some(name) = with_table.name() {
+        doc = doc.append(build_name(options, name.syntax()));
+    }
+    if let Some(column_list) = with_table.column_list() {
+        doc = doc.append(Doc::space()).append(build_paren_list(
+            column_list
+                .column_names()
+                .map(|column| build_name(options, column.syntax())),
+        ));
+    }
+    if with_table.as_token().is_some() {
+        doc = doc.append(Doc::space()).append(keyword(options, "as"));
+    }
+    if let Some(materialized) = with_table.materialized_option() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(materialized.syntax()))
+            .append(match materialized {
+                ast::MaterializedOption::Materialized(_) => keyword(options, "materialized"),
+                ast::MaterializedOption::NotMaterialized(_) => keyword(options, "not materialized"),
+            });
+    }
+    if let Some(query) = with_table.query() {
+        doc = doc
+            .append(Doc::space())
+            .append(Doc::text("("))
+            .append(
+                Doc::line_or_nil()
+                    .append(leading_comments(query.syntax()))
+                    .append(build_with_query(options, &query))
+                    .append(trailing_comments(query.syntax()))
+                    .nest(options.indent_width),
+            )
+            .append(Doc::line_or_nil())
+            .append(Doc::text(")"));
+    }
+    if let Some(search) = with_table.search_clause() {
+        doc = doc.append(build_clause(
+            search.syntax(),
+            build_verbatim(options, search.syntax()),
+        ));
+    }
+    if let Some(cycle) = with_table.cycle_clause() {
+        doc = doc.append(build_clause(
+            cycle.syntax(),
+            build_verbatim(options, cycle.syntax()),
+        ));
+    }
+    doc.group()
+}
+
+fn build_with_query<'a>(options: &FormatOptions, query: &ast::WithQuery) -> Doc<'a> {
+    match query {
+        ast::WithQuery::Select(select) => build_select_doc(options, select),
+        ast::WithQuery::Insert(insert) => build_insert(options, insert),
+        ast::WithQuery::Update(update) => build_update(options, update),
+        ast::WithQuery::Delete(delete) => build_delete(options, delete),
+        ast::WithQuery::Merge(merge) => build_merge(options, merge),
+        ast::WithQuery::Values(values) => build_values(options, values),
+        _ => build_verbatim(options, query.syntax()),
+    }
+}
+
+fn build_select_variant<'a>(options: &FormatOptions, select: &ast::SelectVariant) -> Doc<'a> {
+    match select {
+        ast::SelectVariant::Select(select) => build_select_doc(options, select),
+        ast::SelectVariant::Values(values) => build_values(options, values),
+        _ => build_verbatim(options, select.syntax()),
+    }
+}
+
+fn build_paren_list<'a>(docs: impl Iterator<Item = Doc<'a>>) -> Doc<'a> {
+    Doc::text("(")
+        .append(Doc::list(
+            Itertools::intersperse(docs, Doc::text(",").append(Doc::space())).collect(),
+        ))
+        .append(Doc::text(")"))
+}
+
+fn build_column_target_list<'a>(options: &FormatOptions, list: &ast::ColumnTargetList) -> Doc<'a> {
+    if has_comments(list.syntax()) {
+        return build_paren_block(
+            options,
+            list.column_targets().map(|target| {
+                let doc = build_column_target(options, &target);
+                (target.syntax().clone(), doc)
+            }),
+        );
+    }
+    build_paren_list(
+        list.column_targets()
+            .map(|target| build_column_target(options, &target)),
+    )
+}
+
+fn build_column_target<'a>(options: &FormatOptions, target: &ast::ColumnTarget) -> Doc<'a> {
+    match target.name() {
+        Some(name) if target.accessors().next().is_none() => build_name(options, name.syntax()),
+        _ => build_verbatim(options, target.syntax()),
+    }
+}
+
+fn build_relation_name<'a>(options: &FormatOptions, relation_name: &ast::RelationName) -> Doc<'a> {
+    if relation_name.only_token().is_none()
+        && relation_name.star_token().is_none()
+        && let Some(path) = relation_name
+            .relation_name_ref()
+            .and_then(|name| name.path_ref())
+    {
+        return build_path_ref(options, &path);
+    }
+    build_verbatim(options, relation_name.syntax())
+}
+
+fn build_alias<'a>(
+    options: &FormatOptions,
+    node: &SyntaxNode,
+    alias: Option<ast::TableAlias>,
+) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    if alias_with_as(options, node) {
+        doc = doc.append(Doc::space()).append(keyword(options, "as"));
+    }
+    if let Some(alias) = alias {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(alias.syntax()))
+            .append(build_name(options, alias.syntax()));
+    }
+    doc
+}
+
+fn build_from_items<'a>(
+    options: &FormatOptions,
+    items: impl Iterator<Item = SyntaxNode>,
+) -> Doc<'a> {
+    build_commented_list(
+        options,
+        items.map(|item| {
+            let doc = build_tokens(options, &item);
+            (item, doc)
+        }),
+    )
+    .nest(options.indent_width)
+    .group()
+}
+
+fn build_from_clause<'a>(options: &FormatOptions, from: &ast::FromClause) -> Doc<'a> {
+    // the from clause only contains from items and joins
+    let items = from.syntax().children();
+    keyword(options, "from")
+        .append(Doc::space())
+        .append(build_from_items(options, items))
+}
+
+fn build_where_clause<'a>(options: &FormatOptions, where_clause: &ast::WhereClause) -> Doc<'a> {
+    let mut doc = keyword(options, "where");
+    if let Some(expr) = where_clause.expr() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(expr.syntax()))
+            .append(build_expr(options, expr).nest(options.indent_width));
+    }
+    doc
+}
+
+fn build_where_clause_or_current_of<'a>(
+    options: &FormatOptions,
+    clause: &ast::WhereClauseOrCurrentOf,
+) -> Doc<'a> {
+    match clause {
+        ast::WhereClauseOrCurrentOf::WhereClause(where_clause) => {
+            build_where_clause(options, where_clause)
+        }
+        ast::WhereClauseOrCurrentOf::WhereCurrentOf(current_of) => {
+            build_verbatim(options, current_of.syntax())
+        }
+    }
+}
+
+fn build_target_list<'a>(options: &FormatOptions, target_list: &ast::TargetList) -> Doc<'a> {
+    leading_comments(target_list.syntax()).append(build_commented_list(
+        options,
+        target_list.targets().flat_map(|target| {
+            let doc = build_target(options, &target)?;
+            Some((target.syntax().clone(), doc))
+        }),
+    ))
+}
+
+fn build_returning_clause<'a>(
+    options: &FormatOptions,
+    returning: &ast::ReturningClause,
+) -> Doc<'a> {
+    let mut doc = keyword(options, "returning");
+    if let Some(returning_options) = returning.returning_option_list() {
+        doc = doc
+            .append(Doc::space())
+            .append(build_verbatim(options, returning_options.syntax()));
+    }
+    if let Some(target_list) = returning.target_list() {
+        doc = doc
+            .append(Doc::line_or_space())
+            .append(build_target_list(options, &target_list));
+    }
+    doc.nest(options.indent_width).group()
+}
+
+fn build_set_clause<'a>(options: &FormatOptions, set_clause: &ast::SetClause) -> Doc<'a> {
+    let columns = set_clause
+        .set_column_list()
+        .into_iter()
+        .flat_map(|list| list.set_columns())
+        .map(|column| {
+            let doc = build_set_column(options, &column);
+            (column.syntax().clone(), doc)
+        });
+    keyword(options, "set")
+        .append(
+            Doc::line_or_space()
+                .append(build_commented_list(options, columns))
+                .nest(options.indent_width),
+        )
+        .group()
+}
+
+fn build_set_column<'a>(options: &FormatOptions, column: &ast::SetColumn) -> Doc<'a> {
+    let ast::SetColumn::SetSingleColumn(column) = column else {
+        return build_verbatim(options, column.syntax());
+    };
+    let mut doc = Doc::nil();
+    if let Some(target) = column.column_target() {
+        doc = doc.append(build_column_target(options, &target));
+    }
+    doc = doc.append(Doc::space()).append(Doc::text("="));
+    if let Some(set_expr) = column.set_expr() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(set_expr.syntax()));
+        doc = doc.append(match set_expr.expr() {
+            Some(expr) => build_expr(options, expr),
+            None => keyword(options, "default"),
+        });
+    }
+    doc
+}
+
+fn build_insert<'a>(options: &FormatOptions, insert: &ast::Insert) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    if let Some(with_clause) = insert.with_clause() {
+        doc = doc
+            .append(build_with_clause(options, &with_clause))
+            .append(Doc::line_or_space());
+    }
+    doc = doc
+        .append(keyword(options, "insert"))
+        .append(Doc::space())
+        .append(keyword(options, "into"));
+
+    if let Some(relation_name) = insert.relation_name_ref() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(relation_name.syntax()));
+        if let Some(path) = relation_name.path_ref() {
+            doc = doc.append(build_path_ref(options, &path));
+        }
+    }
+    if let Some(alias) = insert.alias() {
+        // unlike other aliases, `as` is required here
+        doc = doc.append(Doc::space()).append(keyword(options, "as"));
+        if let Some(name) = alias.name() {
+            doc = doc
+                .append(Doc::space())
+                .append(leading_comments(name.syntax()))
+                .append(build_name(options, name.syntax()));
+        }
+    }
+    if let Some(column_list) = insert.column_target_list() {
+        doc = doc
+            .append(Doc::space())
+            .append(build_column_target_list(options, &column_list));
+    }
+    if let Some(overriding) = insert.overriding_clause() {
+        doc = doc.append(build_clause(
+            overriding.syntax(),
+            build_keyword_node(options, overriding.syntax()),
+        ));
+    }
+    match insert.insert_source() {
+        Some(ast::InsertSource::DefaultValues(default_values)) => {
+            doc = doc.append(build_clause(
+                default_values.syntax(),
+                build_keyword_node(options, default_values.syntax()),
+            ));
+        }
+        Some(ast::InsertSource::SelectVariant(select)) => {
+            doc = doc.append(build_clause(
+                select.syntax(),
+                build_select_variant(options, &select),
+            ));
+        }
+        None => (),
+    }
+    if let Some(on_conflict) = insert.on_conflict_clause() {
+        doc = doc.append(build_clause(
+            on_conflict.syntax(),
+            build_on_conflict_clause(options, &on_conflict),
+        ));
+    }
+    if let Some(returning) = insert.returning_clause() {
+        doc = doc.append(build_clause(
+            returning.syntax(),
+            build_returning_clause(options, &returning),
+        ));
+    }
+
+    doc = doc.append(build_semicolon(insert.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_on_conflict_clause<'a>(
+    options: &FormatOptions,
+    on_conflict: &ast::OnConflictClause,
+) -> Doc<'a> {
+    let mut doc = keyword(options, "on")
+        .append(Doc::space())
+        .append(keyword(options, "conflict"));
+    match on_conflict.conflict_target() {
+        Some(ast::ConflictTarget::ConflictOnIndex(on_index)) => {
+            if let Some(items) = on_index.conflict_index_item_list() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(build_verbatim(options, items.syntax()));
+            }
+            if let Some(where_clause) = on_index.where_clause() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(build_where_clause(options, &where_clause));
+            }
+        }
+        Some(ast::ConflictTarget::ConflictOnConstraint(on_constraint)) => {
+            doc = doc
+                .append(Doc::space())
+                .append(keyword(options, "on"))
+                .append(Doc::space())
+                .append(keyword(options, "constraint"));
+            if let Some(path) = on_constraint
+                .constraint_name_ref()
+                .and_then(|name| name.path_ref())
+            {
+                doc = doc
+                    .append(Doc::space())
+                    .append(build_path_ref(options, &path));
+            }
+        }
+        None => (),
+    }
+    match on_conflict.conflict_action() {
+        Some(ast::ConflictAction::ConflictDoNothing(do_nothing)) => {
+            doc = doc
+                .append(Doc::space())
+                .append(build_keyword_node(options, do_nothing.syntax()));
+        }
+        Some(ast::ConflictAction::ConflictDoUpdateSet(do_update)) => {
+            doc = doc
+                .append(Doc::space())
+                .append(keyword(options, "do"))
+                .append(Doc::space())
+                .append(keyword(options, "update"));
+            let mut action = Doc::nil();
+            if let Some(set_clause) = do_update.set_clause() {
+                action = action.append(build_clause(
+                    set_clause.syntax(),
+                    build_set_clause(options, &set_clause),
+                ));
+            }
+            if let Some(where_clause) = do_update.where_clause() {
+                action = action.append(build_clause(
+                    where_clause.syntax(),
+                    build_where_clause(options, &where_clause),
+                ));
+            }
+            doc = doc.append(action.nest(options.indent_width));
+        }
+        Some(ast::ConflictAction::ConflictDoSelect(do_select)) => {
+            doc = doc
+                .append(Doc::space())
+                .append(build_verbatim(options, do_select.syntax()));
+        }
+        None => (),
+    }
+    doc.group()
+}
+
+fn build_update<'a>(options: &FormatOptions, update: &ast::Update) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    if let Some(with_clause) = update.with_clause() {
+        doc = doc
+            .append(build_with_clause(options, &with_clause))
+            .append(Doc::line_or_space());
+    }
+    doc = doc.append(keyword(options, "update"));
+    if let Some(relation_name) = update.relation_name() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(relation_name.syntax()))
+            .append(build_relation_name(options, &relation_name));
+    }
+    if let Some(for_portion_of) = update.for_portion_of() {
+        doc = doc
+            .append(Doc::space())
+            .append(build_verbatim(options, for_portion_of.syntax()));
+    }
+    if let Some(alias) = update.alias() {
+        doc = doc.append(build_alias(options, alias.syntax(), alias.name()));
+    }
+    if let Some(set_clause) = update.set_clause() {
+        doc = doc.append(build_clause(
+            set_clause.syntax(),
+            build_set_clause(options, &set_clause),
+        ));
+    }
+    if let Some(from) = update.from_clause() {
+        doc = doc.append(build_clause(
+            from.syntax(),
+            build_from_clause(options, &from),
+        ));
+    }
+    if let Some(where_clause) = update.where_clause_or_current_of() {
+        doc = doc.append(build_clause(
+            where_clause.syntax(),
+            build_where_clause_or_current_of(options, &where_clause),
+        ));
+    }
+    if let Some(returning) = update.returning_clause() {
+        doc = doc.append(build_clause(
+            returning.syntax(),
+            build_returning_clause(options, &returning),
+        ));
+    }
+
+    doc = doc.append(build_semicolon(update.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_delete<'a>(options: &FormatOptions, delete: &ast::Delete) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    if let Some(with_clause) = delete.with_clause() {
+        doc = doc
+            .append(build_with_clause(options, &with_clause))
+            .append(Doc::line_or_space());
+    }
+    doc = doc
+        .append(keyword(options, "delete"))
+        .append(Doc::space())
+        .append(keyword(options, "from"));
+    if let Some(relation_name) = delete.relation_name() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(relation_name.syntax()))
+            .append(build_relation_name(options, &relation_name));
+    }
+    if let Some(for_portion_of) = delete.for_portion_of() {
+        doc = doc
+            .append(Doc::space())
+            .append(build_verbatim(options, for_portion_of.syntax()));
+    }
+    if let Some(alias) = delete.alias() {
+        doc = doc.append(build_alias(options, alias.syntax(), alias.name()));
+    }
+    if let Some(using) = delete.using_clause() {
+        doc = doc.append(build_clause(
+            using.syntax(),
+            keyword(options, "using")
+                .append(Doc::space())
+                .append(build_from_items(options, using.syntax().children())),
+        ));
+    }
+    if let Some(where_clause) = delete.where_clause_or_current_of() {
+        doc = doc.append(build_clause(
+            where_clause.syntax(),
+            build_where_clause_or_current_of(options, &where_clause),
+        ));
+    }
+    if let Some(returning) = delete.returning_clause() {
+        doc = doc.append(build_clause(
+            returning.syntax(),
+            build_returning_clause(options, &returning),
+        ));
+    }
+
+    doc = doc.append(build_semicolon(delete.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_merge<'a>(options: &FormatOptions, merge: &ast::Merge) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    if let Some(with_clause) = merge.with_clause() {
+        doc = doc
+            .append(build_with_clause(options, &with_clause))
+            .append(Doc::line_or_space());
+    }
+    doc = doc
+        .append(keyword(options, "merge"))
+        .append(Doc::space())
+        .append(keyword(options, "into"));
+    if let Some(relation_name) = merge.table_relation_name() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(relation_name.syntax()));
+        doc = doc.append(build_table_relation_name(options, &relation_name));
+    }
+    if let Some(alias) = merge.alias() {
+        doc = doc.append(build_alias(options, alias.syntax(), alias.name()));
+    }
+    if let Some(using_on) = merge.using_on_clause() {
+        let mut using_doc = keyword(options, "using");
+        // the source is either a from item or a join
+        if let Some(source) = using_on
+            .syntax()
+            .children()
+            .find(|node| node.kind() != SyntaxKind::ON_CLAUSE)
+        {
+            using_doc = using_doc
+                .append(Doc::space())
+                .append(leading_comments(&source))
+                .append(build_tokens(options, &source));
+        }
+        if let Some(on_clause) = using_on.on_clause() {
+            using_doc = using_doc
+                .append(Doc::space())
+                .append(leading_comments(on_clause.syntax()))
+                .append(keyword(options, "on"));
+            if let Some(expr) = on_clause.expr() {
+                using_doc = using_doc
+                    .append(Doc::space())
+                    .append(build_expr(options, expr));
+            }
+        }
+        doc = doc.append(build_clause(using_on.syntax(), using_doc));
+    }
+    // each when clause gets its own line so they're easy to scan
+    for when_clause in merge.merge_when_clauses() {
+        doc = doc
+            .append(Doc::hard_line())
+            .append(leading_comments(when_clause.syntax()))
+            .append(build_merge_when_clause(options, &when_clause));
+    }
+    if let Some(returning) = merge.returning_clause() {
+        doc = doc.append(build_clause(
+            returning.syntax(),
+            build_returning_clause(options, &returning),
+        ));
+    }
+
+    doc = doc.append(build_semicolon(merge.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_merge_when_clause<'a>(
+    options: &FormatOptions,
+    when_clause: &ast::MergeWhenClause,
+) -> Doc<'a> {
+    if has_comments_between_tokens(when_clause.syntax()) {
+        return build_tokens(options, when_clause.syntax());
+    }
+    let (mut doc, condition, action) = match when_clause {
+        ast::MergeWhenClause::MergeWhenMatched(matched) => (
+            keyword(options, "when matched"),
+            matched.merge_condition(),
+            matched.merge_action(),
+        ),
+        ast::MergeWhenClause::MergeWhenNotMatchedSource(not_matched) => (
+            keyword(options, "when not matched by source"),
+            not_matched.merge_condition(),
+            not_matched.merge_action(),
+        ),
+        ast::MergeWhenClause::MergeWhenNotMatchedTarget(not_matched) => (
+            if not_matched.by_target().is_some() {
+                keyword(options, "when not matched by target")
+            } else {
+                keyword(options, "when not matched")
+            },
+            not_matched.merge_condition(),
+            not_matched.merge_action(),
+        ),
+    };
+    if let Some(condition) = condition {
+        if has_comments_between_tokens(condition.syntax()) {
+            return build_tokens(options, when_clause.syntax());
+        }
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(condition.syntax()))
+            .append(keyword(options, "and"));
+        if let Some(expr) = condition.expr() {
+            doc = doc
+                .append(Doc::space())
+                .append(leading_comments(expr.syntax()))
+                .append(build_expr(options, expr));
+        }
+    }
+    doc = doc.append(Doc::space()).append(keyword(options, "then"));
+    if let Some(action) = action {
+        doc = doc.append(
+            Doc::line_or_space()
+                .append(leading_comments(action.syntax()))
+                .append(build_merge_action(options, &action))
+                .nest(options.indent_width),
+        );
+    }
+    doc.group()
+}
+
+fn build_merge_action<'a>(options: &FormatOptions, action: &ast::MergeAction) -> Doc<'a> {
+    match action {
+        ast::MergeAction::MergeDelete(delete) => build_keyword_node(options, delete.syntax()),
+        ast::MergeAction::MergeDoNothing(do_nothing) => {
+            build_keyword_node(options, do_nothing.syntax())
+        }
+        ast::MergeAction::MergeUpdate(update) => {
+            let mut doc = keyword(options, "update");
+            if let Some(set_clause) = update.set_clause() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(leading_comments(set_clause.syntax()))
+                    .append(build_set_clause(options, &set_clause));
+            }
+            doc
+        }
+        ast::MergeAction::MergeInsert(insert) => {
+            let mut doc = keyword(options, "insert");
+            if let Some(column_list) = insert.column_target_list() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(build_column_target_list(options, &column_list));
+            }
+            if let Some(overriding) = insert.overriding_clause() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(build_keyword_node(options, overriding.syntax()));
+            }
+            if let Some(values) = insert.values() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(build_values(options, &values));
+            } else if let Some(default_values) = insert.default_values() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(build_keyword_node(options, default_values.syntax()));
+            }
+            doc
+        }
+    }
+}
+
+fn build_values<'a>(options: &FormatOptions, values: &ast::Values) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    if let Some(with_clause) = values.with_clause() {
+        doc = doc
+            .append(build_with_clause(options, &with_clause))
+            .append(Doc::line_or_space());
+    }
+    doc = doc.append(keyword(options, "values"));
+    if let Some(row_list) = values.row_list() {
+        let rows = row_list.rows().map(|row| {
+            let doc = if has_comments(row.syntax()) {
+                build_tokens(options, row.syntax())
+            } else {
+                build_paren_list(row.exprs().map(|expr| build_expr(options, expr)))
+            };
+            (row.syntax().clone(), doc)
+        });
+        doc = doc.append(
+            Doc::line_or_space()
+                .append(leading_comments(row_list.syntax()))
+                .append(build_commented_list(options, rows))
+                .nest(options.indent_width),
+        );
+    }
+    // TODO: format these clauses
+    for clause in values.syntax().children().filter(|node| {
+        matches!(
+            node.kind(),
+            SyntaxKind::ORDER_BY_CLAUSE
+                | SyntaxKind::LOCKING_CLAUSE
+                | SyntaxKind::LIMIT_CLAUSE
+                | SyntaxKind::FETCH_CLAUSE
+                | SyntaxKind::OFFSET_CLAUSE
+        )
+    }) {
+        doc = doc.append(build_clause(&clause, build_verbatim(options, &clause)));
+    }
+
+    doc = doc.append(build_semicolon(values.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_alter_table<'a>(options: &FormatOptions, alter_table: &ast::AlterTable) -> Doc<'a> {
+    if alter_table.all_in_tablespace().is_some() {
+        return build_tokens(options, alter_table.syntax());
+    }
+    let mut doc = keyword(options, "alter")
+        .append(Doc::space())
+        .append(keyword(options, "table"));
+    if let Some(if_exists) = alter_table.if_exists() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(if_exists.syntax()))
+            .append(build_keyword_node(options, if_exists.syntax()));
+    }
+    if let Some(relation_name) = alter_table.table_relation_name() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(relation_name.syntax()))
+            .append(build_table_relation_name(options, &relation_name));
+    }
+
+    // a single action can share the line with the table name, otherwise we
+    // give each action its own line
+    let separator = if alter_table.actions().nth(1).is_some() {
+        Doc::hard_line()
+    } else {
+        Doc::line_or_space()
+    };
+    let mut actions_doc = Doc::nil();
+    let mut comma_comments: Vec<SyntaxToken> = vec![];
+    let mut actions = alter_table.actions().peekable();
+    let mut first = true;
+    while let Some(action) = actions.next() {
+        let comments: Vec<SyntaxToken> = comment_tokens_before(action.syntax().clone())
+            .into_iter()
+            .filter(|comment| !comma_comments.contains(comment))
+            .collect();
+        actions_doc = actions_doc.append(separator.clone());
+        if !first && options.comma_style == CommaStyle::Leading {
+            actions_doc = actions_doc.append(Doc::text(",")).append(Doc::space());
+        }
+        first = false;
+        actions_doc = actions_doc
+            .append(build_leading_comments(&comments))
+            .append(build_alter_table_action(options, &action))
+            .append(trailing_comments(action.syntax()));
+        if actions.peek().is_some() && options.comma_style == CommaStyle::Trailing {
+            actions_doc = actions_doc.append(Doc::text(","));
+        }
+        // comments following the comma stay on the line of their action
+        // instead of moving down to the next one
+        comma_comments = comments_after_comma(action.syntax());
+        for comment in &comma_comments {
+            actions_doc = actions_doc
+                .append(Doc::space())
+                .append(Doc::text(comment.text().to_string()));
+        }
+    }
+    doc = doc.append(actions_doc.nest(options.indent_width));
+
+    doc = doc.append(build_semicolon(alter_table.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_alter_table_action<'a>(
+    options: &FormatOptions,
+    action: &ast::AlterTableAction,
+) -> Doc<'a> {
+    match action {
+        ast::AlterTableAction::AddColumn(add_column) => build_add_column(options, add_column),
+        ast::AlterTableAction::AddConstraint(add_constraint) => {
+            let mut doc = keyword(options, "add");
+            if let Some(constraint) = add_constraint.constraint() {
+                doc = doc
+                    .append(Doc::space())
+                    .append(leading_comments(constraint.syntax()))
+                    .append(build_tokens(options, constraint.syntax()));
+            }
+            doc
+        }
+        _ => build_tokens(options, action.syntax()),
+    }
+}
+
+fn build_add_column<'a>(options: &FormatOptions, add_column: &ast::AddColumn) -> Doc<'a> {
+    let mut doc = keyword(options, "add");
+    if let Some(column_token) = add_column.column_token() {
+        doc = doc
+            .append(comments_before(column_token))
+            .append(Doc::space())
+            .append(keyword(options, "column"));
+    }
+    if let Some(if_not_exists) = add_column.if_not_exists() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(if_not_exists.syntax()))
+            .append(build_keyword_node(options, if_not_exists.syntax()));
+    }
+    if let Some(name) = add_column.column_name() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(name.syntax()))
+            .append(build_name(options, name.syntax()));
+    }
+    if let Some(ty) = add_column.ty() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(ty.syntax()))
+            .append(build_type(options, ty));
+    }
+    // TODO: format the column options and constraints
+    for node in add_column.syntax().children().filter(|node| {
+        ast::AlterOptionList::can_cast(node.kind())
+            || ast::Collate::can_cast(node.kind())
+            || ast::Constraint::can_cast(node.kind())
+    }) {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(&node))
+            .append(build_tokens(options, &node));
+    }
+    doc
+}
+
+fn build_create_index<'a>(options: &FormatOptions, create_index: &ast::CreateIndex) -> Doc<'a> {
+    let mut doc = keyword(options, "create");
+    if create_index.unique_token().is_some() {
+        doc = doc.append(Doc::space()).append(keyword(options, "unique"));
+    }
+    doc = doc.append(Doc::space()).append(keyword(options, "index"));
+    if create_index.concurrently_token().is_some() {
+        doc = doc
+            .append(Doc::space())
+            .append(keyword(options, "concurrently"));
+    }
+    if create_index.async_token().is_some() {
+        doc = doc.append(Doc::space()).append(keyword(options, "async"));
+    }
+    if let Some(if_not_exists) = create_index.if_not_exists() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(if_not_exists.syntax()))
+            .append(build_keyword_node(options, if_not_exists.syntax()));
+    }
+    if let Some(path) = create_index.index().and_then(|index| index.path()) {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(path.syntax()))
+            .append(build_path(options, &path));
+    }
+    doc = doc.append(Doc::space()).append(keyword(options, "on"));
+    if let Some(relation_name) = create_index.table_relation_name() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(relation_name.syntax()))
+            .append(build_table_relation_name(options, &relation_name));
+    }
+    if let Some(using_method) = create_index.using_method() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(using_method.syntax()))
+            .append(build_tokens(options, using_method.syntax()));
+    }
+    if let Some(items) = create_index.partition_item_list() {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(items.syntax()))
+            .append(build_partition_item_list(options, &items));
+    }
+
+    let mut clauses = Doc::nil();
+    if let Some(include) = create_index.index_include_clause() {
+        let mut include_doc = keyword(options, "include");
+        if let Some(items) = include.partition_item_list() {
+            include_doc = include_doc
+                .append(Doc::space())
+                .append(build_partition_item_list(options, &items));
+        }
+        clauses = clauses.append(build_clause(include.syntax(), include_doc));
+    }
+    if let Some(nulls_distinct) = create_index.nulls_distinct_option() {
+        clauses = clauses.append(build_clause(
+            nulls_distinct.syntax(),
+            build_keyword_node(options, nulls_distinct.syntax()),
+        ));
+    }
+    if let Some(with_params) = create_index.with_params() {
+        clauses = clauses.append(build_clause(
+            with_params.syntax(),
+            build_with_params(options, &with_params),
+        ));
+    }
+    if let Some(tablespace) = create_index.tablespace_clause() {
+        clauses = clauses.append(build_clause(
+            tablespace.syntax(),
+            build_tokens(options, tablespace.syntax()),
+        ));
+    }
+    if let Some(where_clause) = create_index.where_clause() {
+        clauses = clauses.append(build_clause(
+            where_clause.syntax(),
+            build_where_clause(options, &where_clause),
+        ));
+    }
+    doc = doc.append(clauses.group());
+
+    doc = doc.append(build_semicolon(create_index.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_partition_item_list<'a>(
+    options: &FormatOptions,
+    items: &ast::PartitionItemList,
+) -> Doc<'a> {
+    build_paren_block(
+        options,
+        items.partition_items().map(|item| {
+            let doc = build_tokens(options, item.syntax());
+            (item.syntax().clone(), doc)
+        }),
+    )
+}
+
+// Wraps the items in parens, breaking one item per line when they don't fit.
+// A line comment after the last item already ends its line, so the closing
+// paren follows it directly instead of after an empty indented line.
+fn build_paren_block<'a>(
+    options: &FormatOptions,
+    items: impl Iterator<Item = (SyntaxNode, Doc<'a>)>,
+) -> Doc<'a> {
+    let (items, comments) = build_commented_items(options, items);
+    let close = if comments.last().is_some_and(is_line_comment) {
+        Doc::hard_line()
+    } else {
+        Doc::line_or_nil()
+    };
+    Doc::text("(")
+        .append(
+            Doc::line_or_nil()
+                .append(items)
+                .append(build_open_trailing_comments(&comments))
+                .nest(options.indent_width),
+        )
+        .append(close)
+        .append(Doc::text(")"))
+        .group()
+}
+
+fn build_with_params<'a>(options: &FormatOptions, with_params: &ast::WithParams) -> Doc<'a> {
+    let mut doc = keyword(options, "with");
+    if let Some(attributes) = with_params.attribute_list() {
+        doc = doc.append(Doc::space()).append(build_paren_block(
+            options,
+            attributes.attribute_options().map(|option| {
+                let doc = build_tokens(options, option.syntax());
+                (option.syntax().clone(), doc)
+            }),
+        ));
+    }
+    doc
+}
+
+fn build_create_view<'a>(options: &FormatOptions, create_view: &ast::CreateView) -> Doc<'a> {
+    let mut doc = keyword(options, "create");
+    if let Some(or_replace) = create_view.or_replace() {
+        doc = doc
+            .append(Doc::space())
+            .append(build_keyword_node(options, or_replace.syntax()));
+    }
+    if let Some(persistence) = create_view.persistence() {
+        doc = doc
+            .append(Doc::space())
+            .append(build_keyword_node(options, persistence.syntax()));
+    }
+    if create_view.recursive_token().is_some() {
+        doc = doc
+            .append(Doc::space())
+            .append(keyword(options, "recursive"));
+    }
+    doc = doc.append(Doc::space()).append(keyword(options, "view"));
+    if let Some(path) = create_view.view().and_then(|view| view.path()) {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(path.syntax()))
+            .append(build_path(options, &path));
+    }
+    if let Some(column_list) = create_view.column_list() {
+        doc = doc.append(Doc::space()).append(build_paren_list(
+            column_list
+                .column_names()
+                .map(|column| build_name(options, column.syntax())),
+        ));
+    }
+    if let Some(with_params) = create_view.with_params() {
+        doc = doc
+            .append(Doc::space())
+            .append(build_with_params(options, &with_params));
+    }
+    let as_comments = create_view
+        .as_token()
+        .map(comment_tokens_before)
+        .unwrap_or_default();
+    doc = doc.append(build_trailing_comments(&as_comments));
+    if !as_comments.last().is_some_and(is_line_comment) {
+        doc = doc.append(Doc::space());
+    }
+    doc = doc.append(keyword(options, "as"));
+    if let Some(query) = create_view.query() {
+        doc = doc.append(build_clause(
+            query.syntax(),
+            build_select_variant(options, &query),
+        ));
+    }
+    if let Some(check_option) = create_view.with_check_option() {
+        doc = doc.append(build_clause(
+            check_option.syntax(),
+            build_tokens(options, check_option.syntax()),
+        ));
+    }
+
+    doc = doc.append(build_semicolon(create_view.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_create_function<'a>(
+    options: &FormatOptions,
+    create_function: &ast::CreateFunction,
+) -> Doc<'a> {
+    let mut doc = keyword(options, "create");
+    if let Some(or_replace) = create_function.or_replace() {
+        doc = doc
+            .append(Doc::space())
+            .append(build_keyword_node(options, or_replace.syntax()));
+    }
+    doc = doc
+        .append(Doc::space())
+        .append(keyword(options, "function"));
+    if let Some(path) = create_function.name().and_then(|name| name.path()) {
+        doc = doc
+            .append(Doc::space())
+            .append(leading_comments(path.syntax()))
+            .append(build_path(options, &path));
+    }
+    if let Some(param_list) = create_function.param_list() {
+        doc = doc.append(build_param_list(options, &param_list));
+    }
+
+    let mut clauses = Doc::nil();
+    if let Some(ret_type) = create_function.ret_type() {
+        let mut ret_doc = keyword(options, "returns");
+        if let Some(ty) = ret_type.ty() {
+            ret_doc = ret_doc
+                .append(Doc::space())
+                .append(leading_comments(ty.syntax()))
+                .append(build_type(options, ty));
+        } else if let Some(table_args) = ret_type.table_arg_list() {
+            ret_doc = ret_doc
+                .append(Doc::space())
+                .append(keyword(options, "table"))
+                .append(Doc::space())
+                .append(build_paren_block(
+                    options,
+                    table_args.args().map(|arg| {
+                        let node = arg.syntax().clone();
+                        (node, build_table_arg(options, arg))
+                    }),
+                ));
+        }
+        clauses = clauses.append(build_clause(ret_type.syntax(), ret_doc));
+    }
+    if let Some(option_list) = create_function.option_list() {
+        for option in option_list.options() {
+            clauses = clauses.append(build_clause(
+                option.syntax(),
+                build_tokens(options, option.syntax()).append(trailing_comments(option.syntax())),
+            ));
+        }
+    }
+    doc = doc.append(clauses);
+
+    doc = doc.append(build_semicolon(create_function.semicolon_token()));
+
+    doc.group()
+}
+
+fn build_param_list<'a>(options: &FormatOptions, param_list: &ast::ParamList) -> Doc<'a> {
+    if param_list.star_token().is_some() || param_list.order_token().is_some() {
+        return build_tokens(options, param_list.syntax());
+    }
+    build_paren_block(
+        options,
+        param_list.params().map(|param| {
+            let doc = build_param(options, &param);
+            (param.syntax().clone(), doc)
+        }),
+    )
 }
 
-fn build_like_clause<'a>(like_clause: &ast::LikeClause) -> Doc<'a> {
-    let mut doc = Doc::text("like");
+fn build_param<'a>(options: &FormatOptions, param: &ast::Param) -> Doc<'a> {
+    // the mode can come before or after the name, e.g., `out a int` or `a out int`
+    let mut parts: Vec<(TextSize, Doc<'a>)> = vec![];
+    if let Some(mode) = param.mode() {
+        parts.push((
+            mode.syntax().text_range().start(),
+            build_keyword_node(options, mode.syntax()),
+        ));
+    }
+    if let Some(name) = param.name() {
+        parts.push((
+            name.syntax().text_range().start(),
+            leading_comments(name.syntax()).append(build_name(options, name.syntax())),
+        ));
+    }
+    if let Some(ty) = param.ty() {
+        parts.push((
+            ty.syntax().text_range().start(),
+            leading_comments(ty.syntax()).append(build_type(options, ty)),
+        ));
+    }
+    if let Some(default) = param.param_default() {
+        parts.push((
+            default.syntax().text_range().start(),
+            leading_comments(default.syntax()).append(build_tokens(options, default.syntax())),
+        ));
+    }
+    parts.sort_by_key(|(start, _)| *start);
+    Doc::list(Itertools::intersperse(parts.into_iter().map(|(_, doc)| doc), Doc::space()).collect())
+}
 
-    if let Some(relation_name) = like_clause.relation_name_ref() {
+fn build_comment_on<'a>(options: &FormatOptions, comment_on: &ast::CommentOn) -> Doc<'a> {
+    let mut doc = keyword(options, "comment")
+        .append(Doc::space())
+        .append(keyword(options, "on"));
+    if let Some(object) = comment_on.comment_object() {
         doc = doc
             .append(Doc::space())
-            .append(leading_comments(relation_name.syntax()));
-        if let Some(path) = relation_name.path_ref() {
-            doc = doc.append(build_path_ref(&path));
-        }
+            .append(leading_comments(object.syntax()))
+            .append(build_tokens(options, object.syntax()));
     }
-
-    let options: Vec<Doc<'a>> = like_clause
-        .like_options()
-        .map(|option| {
-            Doc::line_or_space()
-                .append(leading_comments(option.syntax()))
-                .append(build_like_option(&option))
-        })
-        .collect();
-    if !options.is_empty() {
-        doc = doc.append(Doc::list(options).nest(2).group());
+    let mut is_doc = keyword(options, "is").append(Doc::space());
+    if let Some(literal) = comment_on.literal() {
+        is_doc = is_doc
+            .append(leading_comments(literal.syntax()))
+            .append(build_literal(options, literal));
+    } else if let Some(null_token) = comment_on.null_token() {
+        is_doc = is_doc
+            .append(leading_comments_token(&null_token))
+            .append(keyword(options, "null"));
     }
+    doc = doc.append(
+        Doc::line_or_space()
+            .append(is_doc)
+            .nest(options.indent_width),
+    );
 
-    doc
-}
+    doc = doc.append(build_semicolon(comment_on.semicolon_token()));
 
-fn build_like_option<'a>(option: &ast::LikeOption) -> Doc<'a> {
-    let (keyword, property) = match option {
-        ast::LikeOption::ExcludingProperty(n) => ("excluding", n.table_property()),
-        ast::LikeOption::IncludingProperty(n) => ("including", n.table_property()),
-    };
+    doc.group()
+}
 
-    let mut doc = Doc::text(keyword);
-    if let Some(property) = property {
-        doc = doc
-            .append(Doc::space())
-            .append(leading_comments(property.syntax()))
-            .append(build_keyword_node(property.syntax()));
+fn build_table_relation_name<'a>(
+    options: &FormatOptions,
+    relation_name: &ast::TableRelationName,
+) -> Doc<'a> {
+    if relation_name.only_token().is_none()
+        && relation_name.star_token().is_none()
+        && let Some(path) = relation_name
+            .table_name_ref()
+            .and_then(|name| name.path_ref())
+    {
+        return build_path_ref(options, &path);
     }
-    doc
+    build_tokens(options, relation_name.syntax())
 }
 
-fn build_select_doc<'a>(select: &ast::Select) -> Doc<'a> {
-    let mut doc = Doc::text("select").append(Doc::line_or_space());
+// Comments on the same line as the comma following the node, e.g.,
+// `add column a int, -- comment`
+fn comments_after_comma(node: &SyntaxNode) -> Vec<SyntaxToken> {
+    let mut tokens: Vec<SyntaxToken> = vec![];
+    let mut seen_comma = false;
+    for next in node.siblings_with_tokens(Direction::Next).skip(1) {
+        let Some(token) = next.into_token() else {
+            break;
+        };
+        match token.kind() {
+            SyntaxKind::COMMA if !seen_comma => seen_comma = true,
+            SyntaxKind::COMMENT if seen_comma => {
+                let line_comment = is_line_comment(&token);
+                tokens.push(token);
+                if line_comment {
+                    break;
+                }
+            }
+            SyntaxKind::COMMENT => (),
+            SyntaxKind::WHITESPACE if token.text().contains('\n') => break,
+            SyntaxKind::WHITESPACE => (),
+            _ => break,
+        }
+    }
+    tokens
+}
 
-    if let Some(select_clause) = select.select_clause() {
-        match select_clause.select_quantifier() {
-            Some(ast::SelectQuantifier::DistinctClause(distinct_clause)) => {
-                doc = doc.append(leading_comments(distinct_clause.syntax()));
-                doc = doc.append(Doc::text("distinct")).append(Doc::space());
+// Fallback for nodes we don't have a layout for yet: keeps the node as
+// written, other than identifier quoting and type names.
+fn build_verbatim<'a>(options: &FormatOptions, node: &SyntaxNode) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    let mut text = String::new();
+    let mut renamed_type_end = None;
+    let mut dropped_as = false;
+    for token in node
+        .descendants_with_tokens()
+        .filter_map(|el| el.into_token())
+    {
+        if renamed_type_end.is_some_and(|end| token.text_range().start() < end) {
+            continue;
+        }
+        if std::mem::take(&mut dropped_as) && token.kind() == SyntaxKind::WHITESPACE {
+            continue;
+        }
+        if let Some(alias) = alias_at(&token) {
+            let with_as = alias_with_as(options, &alias);
+            if token.kind() == SyntaxKind::AS_KW && !with_as {
+                dropped_as = true;
+                continue;
             }
-            Some(ast::SelectQuantifier::All(all)) => {
-                doc = doc.append(leading_comments(all.syntax()));
-                doc = doc.append(Doc::text("all")).append(Doc::space());
+            if token.kind() != SyntaxKind::AS_KW && with_as {
+                text.push_str(&keyword_text(options, "as"));
+                text.push(' ');
             }
-            None => (),
         }
-        if let Some(target_list) = select_clause.target_list() {
-            doc = doc.append(leading_comments(target_list.syntax()));
+        if let Some((ty, renamed)) = renamed_type_at(options, &token) {
             doc = doc
-                .append(Doc::list(
-                    Itertools::intersperse(
-                        target_list.targets().flat_map(build_target),
-                        Doc::text(",").append(Doc::line_or_space()),
-                    )
-                    .collect(),
-                ))
-                .nest(2);
+                .append(Doc::text(std::mem::take(&mut text)))
+                .append(build_type(options, renamed));
+            renamed_type_end = Some(ty.syntax().text_range().end());
+            continue;
+        }
+        match token.kind() {
+            SyntaxKind::IDENT => text.push_str(&ident_text(options, &token)),
+            SyntaxKind::COMMENT | SyntaxKind::WHITESPACE => text.push_str(token.text()),
+            _ if is_keyword(&token) => text.push_str(&keyword_text(options, token.text())),
+            _ => text.push_str(token.text()),
         }
     }
+    doc.append(Doc::text(text))
+}
 
-    if let Some(from) = &select.from_clause() {
-        doc = doc.append(
-            Doc::line_or_space()
-                .append(Doc::text("from"))
-                .append(Doc::space())
-                .append(Doc::text(
-                    from.from_items().next().unwrap().syntax().to_string(),
-                )),
-        );
-    }
+// Fallback for nodes we don't have a layout for yet: keeps the tokens and
+// where the source had whitespace between them, with keywords lowercased.
+fn build_tokens<'a>(options: &FormatOptions, node: &SyntaxNode) -> Doc<'a> {
+    build_token_list(
+        options,
+        node.descendants_with_tokens()
+            .filter_map(|el| el.into_token()),
+    )
+}
 
-    if let Some(group) = &select.group_by_clause() {
-        let mut group_doc = Doc::line_or_space().append(leading_comments(group.syntax()));
-        group_doc = group_doc.append(Doc::text("group")).append(Doc::space());
-        if let Some(by_token) = group.by_token() {
-            group_doc = group_doc.append(leading_comments_token(&by_token));
+fn build_token_list<'a>(
+    options: &FormatOptions,
+    tokens: impl Iterator<Item = SyntaxToken>,
+) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    let mut after_line_comment = false;
+    let mut pending_space = false;
+    let mut renamed_type_end = None;
+    for token in tokens {
+        if renamed_type_end.is_some_and(|end| token.text_range().start() < end) {
+            continue;
         }
-        group_doc = group_doc.append(Doc::text("by")).append(Doc::space());
-        if let Some(list) = group.group_by_list() {
-            group_doc = group_doc.append(build_group_by_list(list));
+        let alias = alias_at(&token).map(|alias| alias_with_as(options, &alias));
+        if token.kind() == SyntaxKind::AS_KW && alias == Some(false) {
+            continue;
+        }
+        match token.kind() {
+            SyntaxKind::WHITESPACE => {
+                pending_space = !after_line_comment;
+                continue;
+            }
+            _ if pending_space => doc = doc.append(Doc::space()),
+            _ => (),
+        }
+        pending_space = false;
+        after_line_comment = false;
+        if token.kind() != SyntaxKind::AS_KW && alias == Some(true) {
+            doc = doc.append(keyword(options, "as")).append(Doc::space());
+        }
+        if let Some((ty, renamed)) = renamed_type_at(options, &token) {
+            doc = doc.append(build_type(options, renamed));
+            renamed_type_end = Some(ty.syntax().text_range().end());
+            continue;
+        }
+        let text = match token.kind() {
+            SyntaxKind::IDENT => ident_text(options, &token),
+            SyntaxKind::COMMENT => token.text().to_string(),
+            _ if is_keyword(&token) => keyword_text(options, token.text()),
+            _ => format_string_token(&token),
+        };
+        doc = doc.append(Doc::text(text));
+        if is_line_comment(&token) {
+            doc = doc.append(Doc::hard_line());
+            after_line_comment = true;
         }
-        doc = doc.append(group_doc);
     }
+    doc
+}
 
-    doc = doc.append(build_semicolon(select.semicolon_token()));
+// Keywords used as names, e.g., `select name from t`, aren't keywords here.
+fn is_keyword(token: &SyntaxToken) -> bool {
+    token
+        .text()
+        .chars()
+        .all(|c| c.is_ascii_alphabetic() || c == '_')
+        && !token.parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                SyntaxKind::NAME_REF
+                    | SyntaxKind::COLUMN_NAME
+                    | SyntaxKind::COLUMN_NAME_REF
+                    | SyntaxKind::PATH_SEGMENT
+                    | SyntaxKind::PATH_SEGMENT_REF
+                    | SyntaxKind::TABLE_ALIAS
+            )
+        })
+}
 
-    doc.group()
+// Tokens of `node` after the child `start` and before the child `end`.
+fn tokens_between(
+    node: &SyntaxNode,
+    start: Option<&SyntaxNode>,
+    end: Option<&SyntaxNode>,
+) -> impl Iterator<Item = SyntaxToken> {
+    let start = start.map(|n| n.text_range().end());
+    let end = end.map(|n| n.text_range().start());
+    node.descendants_with_tokens()
+        .filter_map(|el| el.into_token())
+        .filter(move |token| {
+            let range = token.text_range();
+            start.is_none_or(|start| range.start() >= start)
+                && end.is_none_or(|end| range.end() <= end)
+        })
 }
 
-fn build_group_by_list<'a>(list: ast::GroupByList) -> Doc<'a> {
-    leading_comments(list.syntax()).append(Doc::text(list.syntax().to_string()))
+fn build_group_by_list<'a>(options: &FormatOptions, list: ast::GroupByList) -> Doc<'a> {
+    leading_comments(list.syntax()).append(build_verbatim(options, list.syntax()))
 }
 
 fn build_semicolon<'a>(semi: Option<SyntaxToken>) -> Doc<'a> {
     let Some(semi) = semi else {
         return Doc::nil();
     };
-    let mut doc = Doc::nil();
-    for comment in comment_tokens_before(semi) {
-        doc = doc.append(Doc::text(comment.text().to_string()));
-        if is_line_comment(&comment) {
-            doc = doc.append(Doc::hard_line());
-        }
-    }
-    doc.append(Doc::text(";"))
+    build_trailing_comments(&comment_tokens_before(semi)).append(Doc::text(";"))
 }
 
-fn build_expr<'a>(expr: ast::Expr) -> Doc<'a> {
+fn build_expr<'a>(options: &FormatOptions, expr: ast::Expr) -> Doc<'a> {
+    if has_expr_comments(&expr) {
+        return build_tokens(options, expr.syntax());
+    }
     match expr {
         ast::Expr::ArrayExpr(array_expr) => {
             let mut doc = Doc::nil();
 
             // nested parts of array expressions don't require the array token
             if array_expr.array_token().is_some() {
-                doc = doc.append(Doc::text("array"));
+                doc = doc.append(keyword(options, "array"));
             };
 
             if let Some(select) = array_expr.select() {
                 doc = doc
                     .append(Doc::text("("))
-                    .append(build_select_doc(&select))
+                    .append(build_select_doc(options, &select))
                     .append(Doc::text(")"))
             } else {
                 doc = doc
                     .append(Doc::text("["))
                     .append(Doc::list(
                         Itertools::intersperse(
-                            array_expr.exprs().map(build_expr),
+                            array_expr.exprs().map(|x| build_expr(options, x)),
                             Doc::text(",").append(Doc::space()),
                         )
                         .collect(),
@@ -311,32 +1935,36 @@ fn build_expr<'a>(expr: ast::Expr) -> Doc<'a> {
             doc
         }
         ast::Expr::BetweenExpr(between_expr) => {
-            let mut doc = build_expr(between_expr.target().unwrap());
+            let mut doc = build_expr(options, between_expr.target().unwrap());
             if between_expr.not_token().is_some() {
-                doc = doc.append(Doc::space()).append(Doc::text("not"));
+                doc = doc.append(Doc::space()).append(keyword(options, "not"));
             }
-            doc = doc.append(Doc::space()).append(Doc::text("between"));
+            doc = doc.append(Doc::space()).append(keyword(options, "between"));
             match between_expr.between_symmetry() {
                 Some(ast::BetweenSymmetry::Asymmetric(_)) => {
-                    doc = doc.append(Doc::space()).append(Doc::text("asymmetric"));
+                    doc = doc
+                        .append(Doc::space())
+                        .append(keyword(options, "asymmetric"));
                 }
                 Some(ast::BetweenSymmetry::Symmetric(_)) => {
-                    doc = doc.append(Doc::space()).append(Doc::text("symmetric"));
+                    doc = doc
+                        .append(Doc::space())
+                        .append(keyword(options, "symmetric"));
                 }
                 None => (),
             }
             doc.append(Doc::space())
-                .append(build_expr(between_expr.start().unwrap()))
+                .append(build_expr(options, between_expr.start().unwrap()))
                 .append(Doc::space())
-                .append(Doc::text("and"))
+                .append(keyword(options, "and"))
                 .append(Doc::space())
-                .append(build_expr(between_expr.end().unwrap()))
+                .append(build_expr(options, between_expr.end().unwrap()))
         }
-        ast::Expr::BinExpr(bin_expr) => build_expr(bin_expr.lhs().unwrap())
+        ast::Expr::BinExpr(bin_expr) => build_expr(options, bin_expr.lhs().unwrap())
             .append(Doc::space())
-            .append(build_op(bin_expr.op().unwrap()))
+            .append(build_op(options, bin_expr.op().unwrap()))
             .append(Doc::space())
-            .append(build_expr(bin_expr.rhs().unwrap())),
+            .append(build_expr(options, bin_expr.rhs().unwrap())),
         // ast::Expr::CallExpr(call_expr) => todo!(),
         // ast::Expr::CaseExpr(case_expr) => todo!(),
         ast::Expr::CastExpr(cast_expr) => {
@@ -344,16 +1972,16 @@ fn build_expr<'a>(expr: ast::Expr) -> Doc<'a> {
             if let Some(colon_colon) = cast_expr.colon_colon() {
                 let ty = cast_expr.ty().unwrap();
                 doc = doc
-                    .append(build_expr(cast_expr.expr().unwrap()))
+                    .append(build_expr(options, cast_expr.expr().unwrap()))
                     .append(comments_before(colon_colon.syntax().clone()))
                     .append(Doc::text("::"))
                     .append(leading_comments(ty.syntax()))
-                    .append(build_type(ty))
+                    .append(build_type(options, ty))
             } else if let Some(as_token) = cast_expr.as_token() {
                 if cast_expr.cast_token().is_some() {
-                    doc = doc.append(Doc::text("cast"))
+                    doc = doc.append(keyword(options, "cast"))
                 } else if cast_expr.treat_token().is_some() {
-                    doc = doc.append(Doc::text("treat"))
+                    doc = doc.append(keyword(options, "treat"))
                 }
                 let expr = cast_expr.expr().unwrap();
                 let ty = cast_expr.ty().unwrap();
@@ -363,13 +1991,23 @@ fn build_expr<'a>(expr: ast::Expr) -> Doc<'a> {
                 doc = doc
                     .append(Doc::text("("))
                     .append(leading_comments(expr.syntax()))
-                    .append(build_expr(expr))
+                    .append(build_expr(options, expr))
                     .append(Doc::space())
                     .append(leading_comments_token(&as_token))
-                    .append(Doc::text("as"))
+                    .append(keyword(options, "as"))
                     .append(Doc::space())
                     .append(leading_comments(ty.syntax()))
-                    .append(build_type(ty));
+                    .append(build_type(options, ty));
+                if let Some(collate) = cast_expr
+                    .syntax()
+                    .children()
+                    .find(|node| node.kind() == SyntaxKind::COLLATE)
+                {
+                    doc = doc
+                        .append(Doc::space())
+                        .append(leading_comments(&collate))
+                        .append(build_tokens(options, &collate));
+                }
                 if let Some(r_paren) = cast_expr.r_paren_token() {
                     doc = doc.append(comments_before(r_paren));
                 }
@@ -377,144 +2015,146 @@ fn build_expr<'a>(expr: ast::Expr) -> Doc<'a> {
             } else {
                 let literal = cast_expr.literal().unwrap();
                 doc = doc
-                    .append(build_type(cast_expr.ty().unwrap()))
+                    .append(build_type(options, cast_expr.ty().unwrap()))
                     .append(Doc::space())
                     .append(leading_comments(literal.syntax()))
-                    .append(build_literal(literal));
+                    .append(build_literal(options, literal));
                 if let Some(qualifier) = cast_expr.interval_qualifier() {
                     doc = doc
                         .append(Doc::space())
                         .append(leading_comments(qualifier.syntax()))
-                        .append(build_interval_qualifier(&qualifier))
+                        .append(build_interval_qualifier(options, &qualifier))
                 }
             }
             doc
         }
-        ast::Expr::Collate(collate) => build_expr(collate.expr().unwrap())
+        ast::Expr::Collate(collate) => build_expr(options, collate.expr().unwrap())
             .append(Doc::space())
-            .append(Doc::text("collate"))
+            .append(keyword(options, "collate"))
             .append(Doc::space())
             .append(Doc::text(
                 collate.collation_ref().unwrap().syntax().to_string(),
             )),
         // ast::Expr::FieldExpr(field_expr) => todo!(),
         // ast::Expr::IndexExpr(index_expr) => todo!(),
-        ast::Expr::Literal(literal) => build_literal(literal),
+        ast::Expr::Literal(literal) => build_literal(options, literal),
         // ast::Expr::NameRef(name_ref) => todo!(),
         // ast::Expr::ParenExpr(paren_expr) => todo!(),
         ast::Expr::PostfixExpr(postfix_expr) => {
-            let expr = build_expr(postfix_expr.expr().unwrap());
+            let expr = build_expr(options, postfix_expr.expr().unwrap());
             let op = match postfix_expr.op().unwrap() {
-                ast::PostfixOp::AtLocal(_) => Doc::text("at local"),
-                ast::PostfixOp::IsNull(_) => Doc::text("isnull"),
-                ast::PostfixOp::NotNull(_) => Doc::text("notnull"),
+                ast::PostfixOp::AtLocal(_) => keyword(options, "at local"),
+                ast::PostfixOp::IsNull(_) => keyword(options, "isnull"),
+                ast::PostfixOp::NotNull(_) => keyword(options, "notnull"),
                 ast::PostfixOp::IsJson(n) => {
-                    let mut doc = Doc::text("is json");
+                    let mut doc = keyword(options, "is json");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsJsonArray(n) => {
-                    let mut doc = Doc::text("is json array");
+                    let mut doc = keyword(options, "is json array");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsJsonObject(n) => {
-                    let mut doc = Doc::text("is json object");
+                    let mut doc = keyword(options, "is json object");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsJsonScalar(n) => {
-                    let mut doc = Doc::text("is json scalar");
+                    let mut doc = keyword(options, "is json scalar");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsJsonValue(n) => {
-                    let mut doc = Doc::text("is json value");
+                    let mut doc = keyword(options, "is json value");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsNormalized(n) => {
-                    let mut doc = Doc::text("is");
+                    let mut doc = keyword(options, "is");
                     if let Some(form) = n.unicode_normal_form() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_unicode_normal_form(form));
+                            .append(build_unicode_normal_form(options, form));
                     }
-                    doc.append(Doc::space()).append(Doc::text("normalized"))
+                    doc.append(Doc::space())
+                        .append(keyword(options, "normalized"))
                 }
                 ast::PostfixOp::IsNotJson(n) => {
-                    let mut doc = Doc::text("is not json");
+                    let mut doc = keyword(options, "is not json");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsNotJsonArray(n) => {
-                    let mut doc = Doc::text("is not json array");
+                    let mut doc = keyword(options, "is not json array");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsNotJsonObject(n) => {
-                    let mut doc = Doc::text("is not json object");
+                    let mut doc = keyword(options, "is not json object");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsNotJsonScalar(n) => {
-                    let mut doc = Doc::text("is not json scalar");
+                    let mut doc = keyword(options, "is not json scalar");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsNotJsonValue(n) => {
-                    let mut doc = Doc::text("is not json value");
+                    let mut doc = keyword(options, "is not json value");
                     if let Some(clause) = n.json_keys_unique_clause() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_json_keys_unique_clause(clause));
+                            .append(build_json_keys_unique_clause(options, clause));
                     }
                     doc
                 }
                 ast::PostfixOp::IsNotNormalized(n) => {
-                    let mut doc = Doc::text("is not");
+                    let mut doc = keyword(options, "is not");
                     if let Some(form) = n.unicode_normal_form() {
                         doc = doc
                             .append(Doc::space())
-                            .append(build_unicode_normal_form(form));
+                            .append(build_unicode_normal_form(options, form));
                     }
-                    doc.append(Doc::space()).append(Doc::text("normalized"))
+                    doc.append(Doc::space())
+                        .append(keyword(options, "normalized"))
                 }
             };
             expr.append(Doc::space()).append(op)
@@ -522,35 +2162,43 @@ fn build_expr<'a>(expr: ast::Expr) -> Doc<'a> {
         // ast::Expr::PrefixExpr(prefix_expr) => todo!(),
         // ast::Expr::SliceExpr(slice_expr) => todo!(),
         // ast::Expr::TupleExpr(tuple_expr) => todo!(),
-        _ => Doc::text(expr.syntax().to_string()),
+        _ => build_verbatim(options, expr.syntax()),
     }
 }
 
-fn build_json_keys_unique_clause<'a>(clause: ast::JsonKeysUniqueClause) -> Doc<'a> {
-    let prefix = match clause {
-        ast::JsonKeysUniqueClause::JsonWithoutUniqueKeys(_) => "without",
-        ast::JsonKeysUniqueClause::JsonWithUniqueKeys(_) => "with",
-    };
-    Doc::text(prefix)
-        .append(Doc::space())
-        .append(Doc::text("unique"))
-        .append(Doc::space())
-        .append(Doc::text("keys"))
+// Comments that the expression layouts would drop, i.e., those outside of
+// the operands, which handle their own. Casts keep their comments.
+fn has_expr_comments(expr: &ast::Expr) -> bool {
+    if matches!(expr, ast::Expr::CastExpr(_)) {
+        return false;
+    }
+    expr.syntax().children_with_tokens().any(|el| match el {
+        rowan::NodeOrToken::Token(token) => token.kind() == SyntaxKind::COMMENT,
+        rowan::NodeOrToken::Node(node) => !ast::Expr::can_cast(node.kind()) && has_comments(&node),
+    })
+}
+
+fn build_json_keys_unique_clause<'a>(
+    options: &FormatOptions,
+    clause: ast::JsonKeysUniqueClause,
+) -> Doc<'a> {
+    // `keys` is optional
+    build_tokens(options, clause.syntax())
 }
 
-fn build_unicode_normal_form<'a>(form: ast::UnicodeNormalForm) -> Doc<'a> {
+fn build_unicode_normal_form<'a>(options: &FormatOptions, form: ast::UnicodeNormalForm) -> Doc<'a> {
     if form.nfc_token().is_some() {
-        Doc::text("nfc")
+        keyword(options, "nfc")
     } else if form.nfd_token().is_some() {
-        Doc::text("nfd")
+        keyword(options, "nfd")
     } else if form.nfkc_token().is_some() {
-        Doc::text("nfkc")
+        keyword(options, "nfkc")
     } else {
-        Doc::text("nfkd")
+        keyword(options, "nfkd")
     }
 }
 
-fn build_keyword_node<'a>(node: &SyntaxNode) -> Doc<'a> {
+fn build_keyword_node<'a>(options: &FormatOptions, node: &SyntaxNode) -> Doc<'a> {
     let mut docs: Vec<Doc<'a>> = vec![];
     let mut after_line_comment = false;
     for el in node.children_with_tokens() {
@@ -574,65 +2222,65 @@ fn build_keyword_node<'a>(node: &SyntaxNode) -> Doc<'a> {
                     docs.push(Doc::space());
                 }
                 after_line_comment = false;
-                docs.push(Doc::text(token.text().to_ascii_lowercase()));
+                docs.push(Doc::text(keyword_text(options, token.text())));
             }
         }
     }
     Doc::list(docs)
 }
 
-fn build_op<'a>(op: ast::BinOp) -> Doc<'a> {
+fn build_op<'a>(options: &FormatOptions, op: ast::BinOp) -> Doc<'a> {
     match op {
-        ast::BinOp::And(_) => Doc::text("and"),
-        ast::BinOp::AtTimeZone(n) => build_keyword_node(n.syntax()),
+        ast::BinOp::And(_) => keyword(options, "and"),
+        ast::BinOp::AtTimeZone(n) => build_keyword_node(options, n.syntax()),
         ast::BinOp::Caret(_) => Doc::text("^"),
         ast::BinOp::ColonColon(_) => Doc::text("::"),
         ast::BinOp::ColonEq(_) => Doc::text(":="),
         ast::BinOp::CustomOp(custom_op) => Doc::text(custom_op.syntax().to_string()),
         ast::BinOp::Eq(_) => Doc::text("="),
-        ast::BinOp::Escape(_) => Doc::text("escape"),
+        ast::BinOp::Escape(_) => keyword(options, "escape"),
         ast::BinOp::FatArrow(_) => Doc::text("=>"),
         ast::BinOp::Gteq(_) => Doc::text(">="),
-        ast::BinOp::Ilike(_) => Doc::text("ilike"),
-        ast::BinOp::In(_) => Doc::text("in"),
-        ast::BinOp::Is(_) => Doc::text("is"),
-        ast::BinOp::IsDistinctFrom(n) => build_keyword_node(n.syntax()),
-        ast::BinOp::IsNot(n) => build_keyword_node(n.syntax()),
-        ast::BinOp::IsNotDistinctFrom(n) => build_keyword_node(n.syntax()),
+        ast::BinOp::Ilike(_) => keyword(options, "ilike"),
+        ast::BinOp::In(_) => keyword(options, "in"),
+        ast::BinOp::Is(_) => keyword(options, "is"),
+        ast::BinOp::IsDistinctFrom(n) => build_keyword_node(options, n.syntax()),
+        ast::BinOp::IsNot(n) => build_keyword_node(options, n.syntax()),
+        ast::BinOp::IsNotDistinctFrom(n) => build_keyword_node(options, n.syntax()),
         ast::BinOp::LAngle(_) => Doc::text("<"),
-        ast::BinOp::Like(_) => Doc::text("like"),
+        ast::BinOp::Like(_) => keyword(options, "like"),
         ast::BinOp::Lteq(_) => Doc::text("<="),
         ast::BinOp::Minus(_) => Doc::text("-"),
         ast::BinOp::Neq(_) => Doc::text("!="),
         ast::BinOp::Neqb(_) => Doc::text("<>"),
-        ast::BinOp::NotIlike(n) => build_keyword_node(n.syntax()),
-        ast::BinOp::NotIn(n) => build_keyword_node(n.syntax()),
-        ast::BinOp::NotLike(n) => build_keyword_node(n.syntax()),
-        ast::BinOp::NotSimilarTo(n) => build_keyword_node(n.syntax()),
+        ast::BinOp::NotIlike(n) => build_keyword_node(options, n.syntax()),
+        ast::BinOp::NotIn(n) => build_keyword_node(options, n.syntax()),
+        ast::BinOp::NotLike(n) => build_keyword_node(options, n.syntax()),
+        ast::BinOp::NotSimilarTo(n) => build_keyword_node(options, n.syntax()),
         ast::BinOp::OperatorCall(op) => Doc::text(op.syntax().to_string()),
-        ast::BinOp::Or(_) => Doc::text("or"),
-        ast::BinOp::Overlaps(_) => Doc::text("overlaps"),
+        ast::BinOp::Or(_) => keyword(options, "or"),
+        ast::BinOp::Overlaps(_) => keyword(options, "overlaps"),
         ast::BinOp::Percent(_) => Doc::text("%"),
         ast::BinOp::Plus(_) => Doc::text("+"),
         ast::BinOp::RAngle(_) => Doc::text(">"),
-        ast::BinOp::SimilarTo(n) => build_keyword_node(n.syntax()),
+        ast::BinOp::SimilarTo(n) => build_keyword_node(options, n.syntax()),
         ast::BinOp::Slash(_) => Doc::text("/"),
         ast::BinOp::Star(_) => Doc::text("*"),
     }
 }
 
-fn build_literal<'a>(lit: ast::Literal) -> Doc<'a> {
+fn build_literal<'a>(options: &FormatOptions, lit: ast::Literal) -> Doc<'a> {
     let Some(kind) = lit.kind() else {
         return Doc::nil();
     };
     match kind {
-        LitKind::Default(_) => Doc::text("default"),
-        LitKind::False(_) => Doc::text("false"),
+        LitKind::Default(_) => keyword(options, "default"),
+        LitKind::False(_) => keyword(options, "false"),
         LitKind::IntNumber(t) => Doc::text(t.text().to_string()),
-        LitKind::Null(_) => Doc::text("null"),
+        LitKind::Null(_) => keyword(options, "null"),
         LitKind::NumericNumber(t) => Doc::text(t.text().to_string()),
         LitKind::PositionalParam(t) => Doc::text(t.text().to_string()),
-        LitKind::True(_) => Doc::text("true"),
+        LitKind::True(_) => keyword(options, "true"),
         LitKind::BitString(_)
         | LitKind::ByteString(_)
         | LitKind::DollarQuotedString(_)
@@ -657,7 +2305,7 @@ fn build_string_literal<'a>(lit: &ast::Literal) -> Doc<'a> {
     Doc::list(Itertools::intersperse(parts.into_iter(), Doc::hard_line()).collect())
 }
 
-fn format_string_token(t: &SyntaxToken) -> String {
+pub(crate) fn format_string_token(t: &SyntaxToken) -> String {
     let text = t.text();
     if matches!(
         t.kind(),
@@ -677,50 +2325,106 @@ fn format_string_token(t: &SyntaxToken) -> String {
     }
 }
 
-fn build_type<'a>(ty: ast::Type) -> Doc<'a> {
+/// The type starting at `token` with its configured name.
+fn renamed_type_at(options: &FormatOptions, token: &SyntaxToken) -> Option<(ast::Type, ast::Type)> {
+    token
+        .parent_ancestors()
+        .take_while(|node| node.first_token().as_ref() == Some(token))
+        .filter_map(ast::Type::cast)
+        .find_map(|ty| {
+            let renamed = renamed_type(options, &ty)?;
+            Some((ty, renamed))
+        })
+}
+
+/// The type with its configured name, when it isn't already using it.
+fn renamed_type(options: &FormatOptions, ty: &ast::Type) -> Option<ast::Type> {
+    if options.type_aliases != TypeAliases::Canonical {
+        return None;
+    }
+    let (builtin, current) = match builtin_type_name(ty) {
+        Some(builtin) => (builtin.ty, builtin.name),
+        None => quoted_builtin_type(options, ty)?,
+    };
+    let name = options.canonical_types.name(builtin);
+    if current == name {
+        return None;
+    }
+    Some(rename_builtin_type(ty, name))
+}
+
+/// A quoted builtin type name that we unquote, e.g., `"int4"`, which is
+/// then renamed like any other type.
+///
+/// `"char"` and `"int"` keep their quotes since they aren't the same as `char`
+/// and `int`.
+pub(crate) fn quoted_builtin_type(
+    options: &FormatOptions,
+    ty: &ast::Type,
+) -> Option<(BuiltinType, &'static str)> {
+    if options.quote_identifiers != QuoteIdentifiers::Avoid {
+        return None;
+    }
+    let ast::Type::PathType(path_type) = ty else {
+        return None;
+    };
+    let path = path_type.path_ref()?;
+    if path.qualifier().is_some() {
+        return None;
+    }
+    let token = path.segment()?.ident_token()?;
+    BuiltinType::from_name(unquote_ident_text(token.text())?)
+}
+
+fn build_type<'a>(options: &FormatOptions, ty: ast::Type) -> Doc<'a> {
+    if let Some(renamed) = renamed_type(options, &ty) {
+        return build_type(options, renamed);
+    }
     match ty {
         ast::Type::ArrayType(array_type) => {
             let mut doc = match array_type.ty() {
-                Some(inner) => build_type(inner),
+                Some(inner) => build_type(options, inner),
                 None => Doc::nil(),
             };
             if let Some(array_token) = array_type.array_token() {
                 doc = doc
                     .append(Doc::space())
                     .append(leading_comments_token(&array_token))
-                    .append(Doc::text("array"));
+                    .append(keyword(options, "array"));
             }
             for bound in array_type.array_bounds() {
                 doc = doc
                     .append(comments_before(bound.syntax().clone()))
-                    .append(build_array_bound(&bound));
+                    .append(build_array_bound(options, &bound));
             }
             doc
         }
-        ast::Type::BitType(bit_type) => {
-            build_keyword_node(bit_type.syntax()).append(build_type_args(bit_type.arg_list()))
-        }
+        ast::Type::BitType(bit_type) => build_keyword_node(options, bit_type.syntax())
+            .append(build_type_args(options, bit_type.arg_list())),
         ast::Type::BitVaryingType(bit_varying_type) => {
-            build_keyword_node(bit_varying_type.syntax())
-                .append(build_type_args(bit_varying_type.arg_list()))
-        }
-        ast::Type::CharacterType(character_type) => build_keyword_node(character_type.syntax())
-            .append(build_type_args(character_type.arg_list())),
-        ast::Type::VarcharType(varchar_type) => build_keyword_node(varchar_type.syntax())
-            .append(build_type_args(varchar_type.arg_list())),
-        ast::Type::DoubleType(double_type) => build_keyword_node(double_type.syntax()),
+            build_keyword_node(options, bit_varying_type.syntax())
+                .append(build_type_args(options, bit_varying_type.arg_list()))
+        }
+        ast::Type::CharacterType(character_type) => {
+            build_keyword_node(options, character_type.syntax())
+                .append(build_type_args(options, character_type.arg_list()))
+        }
+        ast::Type::VarcharType(varchar_type) => build_keyword_node(options, varchar_type.syntax())
+            .append(build_type_args(options, varchar_type.arg_list())),
+        ast::Type::DoubleType(double_type) => build_keyword_node(options, double_type.syntax()),
         ast::Type::ExprType(expr_type) => match expr_type.expr() {
-            Some(expr) => build_expr(expr),
+            Some(expr) => build_expr(options, expr),
             None => Doc::nil(),
         },
         ast::Type::IntervalType(interval_type) => {
-            let mut doc = build_setof(interval_type.setof_token());
+            let mut doc = build_setof(options, interval_type.setof_token());
             if let Some(interval_token) = interval_type.interval_token() {
                 doc = doc
                     .append(leading_comments_token(&interval_token))
-                    .append(Doc::text("interval"));
+                    .append(keyword(options, "interval"));
             }
             doc = doc.append(build_type_precision(
+                options,
                 interval_type.l_paren_token(),
                 interval_type.literal(),
                 interval_type.r_paren_token(),
@@ -729,29 +2433,29 @@ fn build_type<'a>(ty: ast::Type) -> Doc<'a> {
                 doc = doc
                     .append(Doc::space())
                     .append(leading_comments(qualifier.syntax()))
-                    .append(build_interval_qualifier(&qualifier));
+                    .append(build_interval_qualifier(options, &qualifier));
             }
             doc
         }
         ast::Type::PathType(path_type) => {
-            let mut doc = build_setof(path_type.setof_token());
+            let mut doc = build_setof(options, path_type.setof_token());
             if let Some(path) = path_type.path_ref() {
                 doc = doc
                     .append(leading_comments(path.syntax()))
-                    .append(build_path_ref(&path));
+                    .append(build_path_ref(options, &path));
             }
             let arg_list = path_type.arg_list();
             if let Some(arg_list) = &arg_list {
                 doc = doc.append(comments_before(arg_list.syntax().clone()));
             }
-            doc.append(build_type_args(arg_list))
+            doc.append(build_type_args(options, arg_list))
         }
         ast::Type::PercentType(percent_type) => {
-            let mut doc = build_setof(percent_type.setof_token());
+            let mut doc = build_setof(options, percent_type.setof_token());
             if let Some(path) = percent_type.path_ref() {
                 doc = doc
                     .append(leading_comments(path.syntax()))
-                    .append(build_path_ref(&path));
+                    .append(build_path_ref(options, &path));
             }
             if let Some(clause) = percent_type.percent_type_clause() {
                 doc = doc.append(comments_before(clause.syntax().clone()));
@@ -761,55 +2465,57 @@ fn build_type<'a>(ty: ast::Type) -> Doc<'a> {
                 if let Some(type_token) = clause.type_token() {
                     doc = doc
                         .append(comments_before(type_token))
-                        .append(Doc::text("type"));
+                        .append(keyword(options, "type"));
                 }
             }
             doc
         }
         ast::Type::TimeType(time_type) => {
-            let mut doc = build_setof(time_type.setof_token());
+            let mut doc = build_setof(options, time_type.setof_token());
             if let Some(time_token) = time_type.time_token() {
                 doc = doc
                     .append(leading_comments_token(&time_token))
-                    .append(Doc::text("time"));
+                    .append(keyword(options, "time"));
             }
             doc.append(build_type_precision(
+                options,
                 time_type.l_paren_token(),
                 time_type.literal(),
                 time_type.r_paren_token(),
             ))
-            .append(build_timezone(time_type.timezone()))
+            .append(build_timezone(options, time_type.timezone()))
         }
         ast::Type::TimestampType(timestamp_type) => {
-            let mut doc = build_setof(timestamp_type.setof_token());
+            let mut doc = build_setof(options, timestamp_type.setof_token());
             if let Some(timestamp_token) = timestamp_type.timestamp_token() {
                 doc = doc
                     .append(leading_comments_token(&timestamp_token))
-                    .append(Doc::text("timestamp"));
+                    .append(keyword(options, "timestamp"));
             }
             doc.append(build_type_precision(
+                options,
                 timestamp_type.l_paren_token(),
                 timestamp_type.literal(),
                 timestamp_type.r_paren_token(),
             ))
-            .append(build_timezone(timestamp_type.timezone()))
+            .append(build_timezone(options, timestamp_type.timezone()))
         }
     }
 }
 
-fn build_setof<'a>(setof: Option<SyntaxToken>) -> Doc<'a> {
+fn build_setof<'a>(options: &FormatOptions, setof: Option<SyntaxToken>) -> Doc<'a> {
     match setof {
-        Some(_) => Doc::text("setof").append(Doc::space()),
+        Some(_) => keyword(options, "setof").append(Doc::space()),
         None => Doc::nil(),
     }
 }
 
-fn build_array_bound<'a>(bound: &ast::ArrayBound) -> Doc<'a> {
+fn build_array_bound<'a>(options: &FormatOptions, bound: &ast::ArrayBound) -> Doc<'a> {
     let mut doc = Doc::text("[");
     if let Some(expr) = bound.expr() {
         doc = doc
             .append(leading_comments(expr.syntax()))
-            .append(build_expr(expr));
+            .append(build_expr(options, expr));
     }
     if let Some(r_brack) = bound.r_brack_token() {
         doc = doc.append(comments_before(r_brack));
@@ -817,7 +2523,7 @@ fn build_array_bound<'a>(bound: &ast::ArrayBound) -> Doc<'a> {
     doc.append(Doc::text("]"))
 }
 
-fn build_type_args<'a>(arg_list: Option<ast::ArgList>) -> Doc<'a> {
+fn build_type_args<'a>(options: &FormatOptions, arg_list: Option<ast::ArgList>) -> Doc<'a> {
     let Some(arg_list) = arg_list else {
         return Doc::nil();
     };
@@ -826,7 +2532,7 @@ fn build_type_args<'a>(arg_list: Option<ast::ArgList>) -> Doc<'a> {
         .map(|arg| {
             let mut doc = leading_comments(arg.syntax());
             if let Some(expr) = arg.expr() {
-                doc = doc.append(build_expr(expr));
+                doc = doc.append(build_expr(options, expr));
             }
             doc.append(trailing_comments(arg.syntax()))
         })
@@ -845,6 +2551,7 @@ fn build_type_args<'a>(arg_list: Option<ast::ArgList>) -> Doc<'a> {
 }
 
 fn build_type_precision<'a>(
+    options: &FormatOptions,
     l_paren: Option<SyntaxToken>,
     literal: Option<ast::Literal>,
     r_paren: Option<SyntaxToken>,
@@ -856,7 +2563,7 @@ fn build_type_precision<'a>(
     if let Some(literal) = literal {
         doc = doc
             .append(leading_comments(literal.syntax()))
-            .append(build_literal(literal));
+            .append(build_literal(options, literal));
     }
     if let Some(r_paren) = r_paren {
         doc = doc.append(comments_before(r_paren));
@@ -864,22 +2571,25 @@ fn build_type_precision<'a>(
     doc.append(Doc::text(")"))
 }
 
-fn build_timezone<'a>(timezone: Option<ast::Timezone>) -> Doc<'a> {
+fn build_timezone<'a>(options: &FormatOptions, timezone: Option<ast::Timezone>) -> Doc<'a> {
     let Some(timezone) = timezone else {
         return Doc::nil();
     };
     let doc = Doc::space().append(leading_comments(timezone.syntax()));
     match timezone {
         ast::Timezone::WithTimezone(with_timezone) => {
-            doc.append(build_keyword_node(with_timezone.syntax()))
+            doc.append(build_keyword_node(options, with_timezone.syntax()))
         }
         ast::Timezone::WithoutTimezone(without_timezone) => {
-            doc.append(build_keyword_node(without_timezone.syntax()))
+            doc.append(build_keyword_node(options, without_timezone.syntax()))
         }
     }
 }
 
-fn build_interval_qualifier<'a>(qualifier: &ast::IntervalQualifier) -> Doc<'a> {
+fn build_interval_qualifier<'a>(
+    options: &FormatOptions,
+    qualifier: &ast::IntervalQualifier,
+) -> Doc<'a> {
     match qualifier {
         ast::IntervalQualifier::IntervalSecond(second) => {
             let mut doc = Doc::nil();
@@ -889,31 +2599,34 @@ fn build_interval_qualifier<'a>(qualifier: &ast::IntervalQualifier) -> Doc<'a> {
                 .or_else(|| second.minute_token())
             {
                 doc = doc
-                    .append(Doc::text(unit.text().to_ascii_lowercase()))
+                    .append(Doc::text(keyword_text(options, unit.text())))
                     .append(Doc::space());
             }
             if let Some(to_token) = second.to_token() {
                 doc = doc
                     .append(leading_comments_token(&to_token))
-                    .append(Doc::text("to"))
+                    .append(keyword(options, "to"))
                     .append(Doc::space());
             }
             if let Some(second_token) = second.second_token() {
                 doc = doc
                     .append(leading_comments_token(&second_token))
-                    .append(Doc::text("second"));
+                    .append(keyword(options, "second"));
             }
             doc.append(build_type_precision(
+                options,
                 second.l_paren_token(),
                 second.literal(),
                 second.r_paren_token(),
             ))
         }
-        ast::IntervalQualifier::IntervalDay(day) => build_keyword_node(day.syntax()),
-        ast::IntervalQualifier::IntervalHour(hour) => build_keyword_node(hour.syntax()),
-        ast::IntervalQualifier::IntervalMinute(minute) => build_keyword_node(minute.syntax()),
-        ast::IntervalQualifier::IntervalMonth(month) => build_keyword_node(month.syntax()),
-        ast::IntervalQualifier::IntervalYear(year) => build_keyword_node(year.syntax()),
+        ast::IntervalQualifier::IntervalDay(day) => build_keyword_node(options, day.syntax()),
+        ast::IntervalQualifier::IntervalHour(hour) => build_keyword_node(options, hour.syntax()),
+        ast::IntervalQualifier::IntervalMinute(minute) => {
+            build_keyword_node(options, minute.syntax())
+        }
+        ast::IntervalQualifier::IntervalMonth(month) => build_keyword_node(options, month.syntax()),
+        ast::IntervalQualifier::IntervalYear(year) => build_keyword_node(options, year.syntax()),
     }
 }
 
@@ -971,89 +2684,321 @@ fn build_leading_comments<'a>(tokens: &[SyntaxToken]) -> Doc<'a> {
 }
 
 fn trailing_comments<'a>(node: &SyntaxNode) -> Doc<'a> {
-    let mut doc = Doc::nil();
-    let mut after_line_comment = false;
+    build_trailing_comments(&trailing_comment_tokens(node))
+}
+
+fn trailing_comment_tokens(node: &SyntaxNode) -> Vec<SyntaxToken> {
+    let mut tokens: Vec<SyntaxToken> = vec![];
     for next in node.siblings_with_tokens(Direction::Next).skip(1) {
-        match next {
-            rowan::NodeOrToken::Node(_node) => {
-                break;
-            }
-            rowan::NodeOrToken::Token(token) => {
-                if token.kind() == SyntaxKind::COMMENT {
-                    if !after_line_comment {
-                        doc = doc.append(Doc::space());
-                    }
-                    doc = doc.append(Doc::text(token.text().to_string()));
-                    after_line_comment = is_line_comment(&token);
-                    if after_line_comment {
-                        doc = doc.append(Doc::hard_line());
-                    }
-                } else if token.kind() == SyntaxKind::WHITESPACE {
-                    continue;
-                } else {
-                    break;
-                }
-            }
+        let Some(token) = next.into_token() else {
+            break;
+        };
+        match token.kind() {
+            SyntaxKind::COMMENT => tokens.push(token),
+            SyntaxKind::WHITESPACE => (),
+            _ => break,
         }
     }
+    tokens
+}
+
+fn build_trailing_comments<'a>(tokens: &[SyntaxToken]) -> Doc<'a> {
+    let doc = build_open_trailing_comments(tokens);
+    if tokens.last().is_some_and(is_line_comment) {
+        return doc.append(Doc::hard_line());
+    }
+    doc
+}
+
+// Trailing comments without the line break after a final line comment.
+fn build_open_trailing_comments<'a>(tokens: &[SyntaxToken]) -> Doc<'a> {
+    let mut doc = Doc::nil();
+    let mut after_line_comment = false;
+    for token in tokens {
+        doc = if after_line_comment {
+            doc.append(Doc::hard_line())
+        } else {
+            doc.append(Doc::space())
+        };
+        doc = doc.append(Doc::text(token.text().to_string()));
+        after_line_comment = is_line_comment(token);
+    }
     doc
 }
 
-fn build_target<'a>(target: ast::Target) -> Option<Doc<'a>> {
-    let mut doc = leading_comments(target.syntax());
+// Comments among the node's own tokens, which aren't the leading comments of
+// a child node, e.g., `when matched /* m */ then`.
+fn has_comments_between_tokens(node: &SyntaxNode) -> bool {
+    node.children_with_tokens()
+        .filter_map(|el| el.into_token())
+        .filter(|token| token.kind() == SyntaxKind::COMMENT)
+        .any(|comment| {
+            comment
+                .siblings_with_tokens(Direction::Next)
+                .find(|el| !matches!(el.kind(), SyntaxKind::COMMENT | SyntaxKind::WHITESPACE))
+                .is_none_or(|el| el.as_node().is_none())
+        })
+}
+
+// Comments inside the node, which layouts that rebuild the node from its
+// children would drop.
+fn has_comments(node: &SyntaxNode) -> bool {
+    node.descendants_with_tokens()
+        .any(|el| el.kind() == SyntaxKind::COMMENT)
+}
+
+fn build_target<'a>(options: &FormatOptions, target: &ast::Target) -> Option<Doc<'a>> {
+    let mut doc = Doc::nil();
 
     if target.star_token().is_some() {
         return Some(doc.append(Doc::text("*")));
     }
+    // `null::json is json` parses as two expressions, so keep it as is
+    if target
+        .syntax()
+        .children()
+        .filter(|node| ast::Expr::can_cast(node.kind()))
+        .count()
+        > 1
+    {
+        return Some(build_tokens(options, target.syntax()));
+    }
     let expr = target.expr()?;
-    doc = doc.append(build_expr(expr));
+    doc = doc.append(build_expr(options, expr));
 
     if let Some(as_name) = target.as_name() {
-        if as_name.as_token().is_some() {
-            doc = doc.append(Doc::space()).append(Doc::text("as"))
+        let column_name = as_name.name();
+        let text = column_name
+            .as_ref()
+            .map(|name| name.text())
+            .unwrap_or_default();
+        let with_as = alias_with_as(options, as_name.syntax());
+        if with_as {
+            doc = doc.append(Doc::space()).append(keyword(options, "as"))
         }
 
-        if let Some(column_name) = as_name.name() {
-            let alias = if as_name.as_token().is_some() {
-                quote_column_alias(&column_name.text())
-            } else {
-                quote_bare_column_alias(&column_name.text())
+        if let Some(column_name) = column_name {
+            let alias = match options.quote_identifiers {
+                QuoteIdentifiers::Preserve => column_name.syntax().text().to_string(),
+                QuoteIdentifiers::Avoid if with_as => quote_column_alias(&text),
+                QuoteIdentifiers::Avoid => quote_bare_column_alias(&text),
             };
             doc = doc.append(Doc::space()).append(Doc::text(alias));
         }
     }
 
-    doc = doc.append(trailing_comments(target.syntax()));
-
     Some(doc)
 }
 
-pub fn fmt(text: &str) -> Result<String> {
-    let line_ending = find_newline(text)
-        .map(|(_, ending)| ending)
-        .unwrap_or_default();
+// Whether to write `as` before the alias of a column, table or function
+fn alias_with_as(options: &FormatOptions, alias: &SyntaxNode) -> bool {
+    let has_as = alias
+        .children_with_tokens()
+        .any(|el| el.kind() == SyntaxKind::AS_KW);
+    match options.alias_as {
+        AliasAs::Preserve => has_as,
+        AliasAs::Always => true,
+        AliasAs::Never => match_ast! {
+            match alias {
+                ast::AsName(as_name) => {
+                    // keywords like `from` can only be used as a label with `as`
+                    let text = as_name.name().map(|name| name.text()).unwrap_or_default();
+                    quote_bare_column_alias(&text) != quote_column_alias(&text)
+                },
+                // `as (a int)` for functions returning records
+                ast::FromAlias(from_alias) => from_alias.name().is_none(),
+                // `update t as set set a = 1`
+                ast::OptionalAsAlias(optional) => optional
+                    .name()
+                    .and_then(|name| name.syntax().first_token())
+                    .is_some_and(|name| {
+                        let text = match name.kind() {
+                            SyntaxKind::IDENT => ident_text(options, &name),
+                            _ => name.text().to_string(),
+                        };
+                        text.eq_ignore_ascii_case("set")
+                    }),
+                _ => has_as,
+            }
+        },
+    }
+}
+
+// The alias when `token` is its `as`, or its first token and it has no `as`.
+fn alias_at(token: &SyntaxToken) -> Option<SyntaxNode> {
+    let is_alias = |kind| {
+        matches!(
+            kind,
+            SyntaxKind::AS_NAME | SyntaxKind::FROM_ALIAS | SyntaxKind::OPTIONAL_AS_ALIAS
+        )
+    };
+    if token.kind() == SyntaxKind::AS_KW {
+        return token.parent().filter(|parent| is_alias(parent.kind()));
+    }
+    token
+        .parent_ancestors()
+        .take_while(|node| node.first_token().as_ref() == Some(token))
+        .find(|node| is_alias(node.kind()))
+        .filter(|alias| {
+            !alias
+                .children_with_tokens()
+                .any(|el| el.kind() == SyntaxKind::AS_KW)
+        })
+}
+
+fn keyword<'a>(options: &FormatOptions, text: &str) -> Doc<'a> {
+    Doc::text(keyword_text(options, text))
+}
+
+fn keyword_text(options: &FormatOptions, text: &str) -> String {
+    match options.keyword_case {
+        KeywordCase::Lower => text.to_ascii_lowercase(),
+        KeywordCase::Upper => text.to_ascii_uppercase(),
+    }
+}
+
+// For lists that are always broken over multiple lines
+fn build_trailing_comma<'a>(options: &FormatOptions) -> Doc<'a> {
+    if options.trailing_commas && options.comma_style == CommaStyle::Trailing {
+        Doc::text(",")
+    } else {
+        Doc::nil()
+    }
+}
 
-    let line_break = match line_ending {
+pub fn fmt(text: &str, options: &FormatOptions) -> Result<String> {
+    fmt_file(&parse(options, text)?, options)
+}
+
+/// Like [`fmt`], but for a file that's already parsed without errors.
+pub fn fmt_file(file: &ast::SourceFile, options: &FormatOptions) -> Result<String> {
+    let text = file.syntax().to_string();
+    let line_break = match line_ending(options, &text) {
+        // tiny_pretty can't print CR line breaks so we format with LF and
+        // switch back after
         // see: https://github.com/g-plane/tiny_pretty/issues/3
-        LineEnding::Cr => bail!("CR line endings aren't supported"),
+        LineEnding::Cr => {
+            let lf_text = replace_line_breaks(options, &text, |ws| {
+                ws.replace("\r\n", "\n").replace('\r', "\n")
+            });
+            let lf_file = parse(options, &lf_text)?;
+            let formatted = print(
+                &build_source_file(options, &lf_file),
+                &options.print_options(LineBreak::Lf),
+            );
+            let formatted = replace_line_breaks(options, &formatted, |ws| ws.replace('\n', "\r"));
+            verify(options, &text, file.syntax(), &formatted)?;
+            return Ok(formatted);
+        }
         LineEnding::CrLf => LineBreak::Crlf,
         LineEnding::Lf => LineBreak::Lf,
     };
 
-    let parse = ast::SourceFile::parse(text);
-    let file = parse.tree();
-    debug_assert_eq!(
-        parse.errors(),
-        vec![],
-        "should bail out when there's parse errors"
-    );
-    let doc = build_source_file(&file);
+    let doc = build_source_file(options, file);
+    let formatted = print(&doc, &options.print_options(line_break));
 
-    Ok(print(
-        &doc,
-        &PrintOptions {
-            line_break,
-            ..Default::default()
-        },
-    ))
+    verify(options, &text, file.syntax(), &formatted)?;
+
+    Ok(formatted)
+}
+
+/// Replaces the line breaks between tokens, leaving the ones in strings,
+/// dollar quoted strings, and comments as is since they're part of the SQL.
+fn replace_line_breaks(options: &FormatOptions, text: &str, f: impl Fn(&str) -> String) -> String {
+    let mut replaced = String::with_capacity(text.len());
+    let mut start = 0;
+    for token in squawk_lexer::tokenize_with(text, options.templating) {
+        let end = start + token.len as usize;
+        let token_text = &text[start..end];
+        if token.kind == squawk_lexer::TokenKind::Whitespace {
+            replaced.push_str(&f(token_text));
+        } else {
+            replaced.push_str(token_text);
+        }
+        start = end;
+    }
+    replaced
+}
+
+/// Formats the statements overlapping `range` and leaves the rest of the file
+/// as is, so editors can format a selection or only the statement that
+/// changed.
+pub fn fmt_range(text: &str, range: TextRange, options: &FormatOptions) -> Result<Vec<Edit>> {
+    fmt_file_range(&parse(options, text)?, range, options)
+}
+
+/// Like [`fmt_range`], but for a file that's already parsed without errors.
+pub fn fmt_file_range(
+    file: &ast::SourceFile,
+    range: TextRange,
+    options: &FormatOptions,
+) -> Result<Vec<Edit>> {
+    let text = file.syntax().to_string();
+    let line_ending = line_ending(options, &text);
+    // Like `fmt_file`, we format CR files with LF. Swapping one for the other
+    // keeps the offsets the same, so the statements line up.
+    let parsed;
+    let (lf_file, line_break) = match line_ending {
+        LineEnding::Cr => {
+            parsed = parse(
+                options,
+                &replace_line_breaks(options, &text, |ws| ws.replace('\r', "\n")),
+            )?;
+            (&parsed, LineBreak::Lf)
+        }
+        LineEnding::CrLf => (file, LineBreak::Crlf),
+        LineEnding::Lf => (file, LineBreak::Lf),
+    };
+
+    let unformatted = unformatted_ranges(lf_file.syntax());
+    let print_options = options.print_options(line_break);
+    let mut edits = vec![];
+    for (lf_stmt, stmt) in lf_file.stmts().zip(file.stmts()) {
+        let stmt_range = stmt.syntax().text_range();
+        if stmt_range.intersect(range).is_none()
+            || unformatted
+                .iter()
+                .any(|unformatted| unformatted.contains_range(stmt_range))
+        {
+            continue;
+        }
+        let mut formatted = print(&build_stmt(options, &lf_stmt), &print_options);
+        if line_ending == LineEnding::Cr {
+            formatted = replace_line_breaks(options, &formatted, |ws| ws.replace('\n', "\r"));
+        }
+        verify(options, &text, stmt.syntax(), &formatted)?;
+        if let Some(mut edit) = Edit::diff(&stmt.syntax().to_string(), &formatted) {
+            edit.text_range += stmt_range.start();
+            edits.push(edit);
+        }
+    }
+    Ok(edits)
+}
+
+/// The line ending of the first line break between tokens, since strings can
+/// have their own, e.g., a `$$` function body with LF in a CR file.
+fn line_ending(options: &FormatOptions, text: &str) -> LineEnding {
+    let mut start = 0;
+    for token in squawk_lexer::tokenize_with(text, options.templating) {
+        let end = start + token.len as usize;
+        if token.kind == squawk_lexer::TokenKind::Whitespace
+            && let Some((_, ending)) = find_newline(&text[start..end])
+        {
+            return ending;
+        }
+        start = end;
+    }
+    LineEnding::default()
+}
+
+fn parse(options: &FormatOptions, text: &str) -> Result<ast::SourceFile> {
+    let parse = ast::SourceFile::parse_dialect(text, options.templating, options.dialect);
+    if let Some(error) = parse.errors().first() {
+        bail!(
+            "syntax error at {}: {}",
+            location(text, error.range().start()),
+            error.message()
+        );
+    }
+    Ok(parse.tree())
 }
@@ -1,3 +1,9 @@
+mod directive;
 mod fmt;
+mod options;
+mod verify;
 
-pub use fmt::fmt;
+pub use fmt::{fmt, fmt_file, fmt_file_range, fmt_range};
+pub use options::{
+    AliasAs, CanonicalTypes, CommaStyle, FormatOptions, KeywordCase, QuoteIdentifiers, TypeAliases,
+};
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 use clap::Parser;
+use squawk_fmt::FormatOptions;
 
 #[derive(Parser)]
 #[command(name = "squawk-fmt")]
@@ -23,6 +24,6 @@ fn main() -> Result<()> {
         }
     };
 
-    print!("{}", squawk_fmt::fmt(&input)?);
+    print!("{}", squawk_fmt::fmt(&input, &FormatOptions::default())?);
     Ok(())
 }
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use squawk_syntax::type_alias::BuiltinType;
+use squawk_syntax::{Dialect, Templating};
+use tiny_pretty::{IndentKind, LineBreak, PrintOptions};
+
+/// Configures the layout of the formatted SQL.
+///
+/// Read from the `[format]` table of `.squawk.toml`, e.g.,
+///
+/// ```toml
+/// [format]
+/// keyword_case = "upper"
+/// indent_width = 4
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct FormatOptions {
+    /// `select` vs `SELECT`
+    pub keyword_case: KeywordCase,
+    /// Number of spaces used for each level of indentation.
+    pub indent_width: usize,
+    /// Whether to remove quotes from identifiers that don't need them.
+    pub quote_identifiers: QuoteIdentifiers,
+    /// Whether aliases use `as`, e.g., `select a as b from t as u`.
+    pub alias_as: AliasAs,
+    /// Add a comma after the last item of lists that are broken over multiple
+    /// lines.
+    ///
+    /// Postgres doesn't accept trailing commas, so only enable this for
+    /// databases that do.
+    pub trailing_commas: bool,
+    /// The width we try to keep lines under.
+    pub max_line_width: usize,
+    /// Where to put the commas of lists that are broken over multiple lines.
+    pub comma_style: CommaStyle,
+    /// Whether to rename builtin types that have more than one name, e.g.,
+    /// `int4` to `integer`.
+    pub type_aliases: TypeAliases,
+    /// The name to use for each type when renaming, e.g.,
+    /// `timestamptz = "timestamp with time zone"`.
+    pub canonical_types: CanonicalTypes,
+    /// The database the SQL targets, from the top level `dialect` setting
+    /// rather than `[format]`.
+    #[serde(skip)]
+    pub dialect: Dialect,
+    /// The template syntax in the SQL, from the top level `templating`
+    /// setting rather than `[format]`.
+    #[serde(skip)]
+    pub templating: Option<Templating>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            keyword_case: KeywordCase::default(),
+            indent_width: 2,
+            quote_identifiers: QuoteIdentifiers::default(),
+            alias_as: AliasAs::default(),
+            trailing_commas: false,
+            max_line_width: 80,
+            comma_style: CommaStyle::default(),
+            type_aliases: TypeAliases::default(),
+            canonical_types: CanonicalTypes::default(),
+            dialect: Dialect::default(),
+            templating: None,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub(crate) fn print_options(&self, line_break: LineBreak) -> PrintOptions {
+        PrintOptions {
+            line_break,
+            indent_kind: IndentKind::Space,
+            width: self.max_line_width,
+            // tiny_pretty panics with a tab size of zero
+            tab_size: self.indent_width.max(1),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeywordCase {
+    #[default]
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteIdentifiers {
+    /// Only quote identifiers when they'd change meaning without quotes, e.g.,
+    /// `"Foo"`, but not `"foo"`.
+    #[default]
+    Avoid,
+    /// Keep identifiers quoted the same as the input.
+    Preserve,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AliasAs {
+    /// Keep `as` where the input has it.
+    #[default]
+    Preserve,
+    Always,
+    /// Drop `as`, unless the alias requires it, e.g., `select 1 as from`.
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommaStyle {
+    /// ```sql
+    /// select
+    ///   a,
+    ///   b
+    /// ```
+    #[default]
+    Trailing,
+    /// ```sql
+    /// select
+    ///   a
+    ///   , b
+    /// ```
+    Leading,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeAliases {
+    /// Keep type names as written.
+    #[default]
+    Preserve,
+    /// Rename types to their canonical name, e.g., `int4` and `int` to
+    /// `integer`, `bool` to `boolean`, and `timestamp with time zone` to
+    /// `timestamptz`.
+    Canonical,
+}
+
+/// Overrides the canonical name of builtin types, e.g.,
+///
+/// ```toml
+/// [format.canonical_types]
+/// timestamptz = "timestamp with time zone"
+/// integer = "int"
+/// ```
+///
+/// Keys and values can be any name of the type.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "BTreeMap<String, String>")]
+pub struct CanonicalTypes(BTreeMap<BuiltinType, &'static str>);
+
+impl CanonicalTypes {
+    pub fn name(&self, ty: BuiltinType) -> &'static str {
+        self.0
+            .get(&ty)
+            .copied()
+            .unwrap_or_else(|| ty.canonical_name())
+    }
+
+    /// The types whose canonical name is overridden, and their names.
+    pub fn overrides(&self) -> &BTreeMap<BuiltinType, &'static str> {
+        &self.0
+    }
+}
+
+impl TryFrom<BTreeMap<String, String>> for CanonicalTypes {
+    type Error = String;
+
+    fn try_from(value: BTreeMap<String, String>) -> Result<Self, Self::Error> {
+        let mut names = BTreeMap::new();
+        for (key, value) in value {
+            let Some((ty, _)) = BuiltinType::from_name(&key) else {
+                return Err(format!(
+                    "`{key}` isn't a builtin type with more than one name"
+                ));
+            };
+            match BuiltinType::from_name(&value) {
+                Some((value_ty, name)) if value_ty == ty => {
+                    names.insert(ty, name);
+                }
+                _ => {
+                    return Err(format!("`{value}` isn't another name for `{key}`"));
+                }
+            }
+        }
+        Ok(Self(names))
+    }
+}
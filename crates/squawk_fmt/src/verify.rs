@@ -0,0 +1,253 @@
+use anyhow::{Result, bail};
+use rowan::TextSize;
+use squawk_line_index::{LineCol, LineIndex};
+use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::type_alias::builtin_type_name;
+use squawk_syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+use crate::fmt::{format_string_token, quoted_builtin_type};
+use crate::options::{AliasAs, FormatOptions, TypeAliases};
+
+/// One-based `line:col` of `offset`, for error messages.
+pub(crate) fn location(text: &str, offset: TextSize) -> String {
+    let LineCol { line, col } = LineIndex::new(text).line_col(offset);
+    format!("{}:{}", line + 1, col + 1)
+}
+
+/// Reparses the formatted output and makes sure it has the same tokens as the
+/// input, ignoring whitespace, keyword case, and identifier quoting.
+///
+/// We'd much rather fail than write out SQL that means something different.
+pub(crate) fn verify(
+    options: &FormatOptions,
+    before_text: &str,
+    before: &SyntaxNode,
+    after_text: &str,
+) -> Result<()> {
+    let parse = ast::SourceFile::parse_dialect(after_text, options.templating, options.dialect);
+    let after = parse.tree();
+    for error in parse.errors() {
+        // Postgres doesn't accept trailing commas, but the user asked for them.
+        if options.trailing_commas && error.message() == "unexpected trailing comma" {
+            continue;
+        }
+        bail!(
+            "formatting introduced a syntax error at {}: {}",
+            location(after_text, error.range().start()),
+            error.message()
+        );
+    }
+
+    let mut before_tokens = meaningful_tokens(options, before).into_iter();
+    let mut after_tokens = meaningful_tokens(options, after.syntax()).into_iter();
+
+    loop {
+        match (before_tokens.next(), after_tokens.next()) {
+            (None, None) => return Ok(()),
+            (Some((b_text, b)), Some((a_text, a))) => {
+                if b_text != a_text {
+                    bail!(
+                        "formatting changed the meaning of the SQL at {}: expected `{}`, found `{}`",
+                        location(before_text, b.text_range().start()),
+                        b.text(),
+                        a.text()
+                    );
+                }
+            }
+            (Some((_, b)), None) => {
+                bail!(
+                    "formatting dropped `{}` at {}",
+                    b.text(),
+                    location(before_text, b.text_range().start())
+                );
+            }
+            (None, Some((_, a))) => {
+                bail!(
+                    "formatting added `{}` at {}",
+                    a.text(),
+                    location(after_text, a.text_range().start())
+                );
+            }
+        }
+    }
+}
+
+/// The tokens to compare, along with their normalized text.
+fn meaningful_tokens(options: &FormatOptions, node: &SyntaxNode) -> Vec<(String, SyntaxToken)> {
+    let mut tokens: Vec<(String, SyntaxToken)> = node
+        .descendants_with_tokens()
+        .filter_map(|el| el.into_token())
+        .filter(move |token| !is_ignored(options, token))
+        .filter_map(move |token| {
+            if options.type_aliases == TypeAliases::Canonical
+                && let Some(builtin) = token
+                    .parent_ancestors()
+                    .find_map(ast::Type::cast)
+                    .and_then(|ty| builtin_type_name(&ty))
+                && let Some(idx) = builtin.tokens.iter().position(|t| *t == token)
+            {
+                // Renaming can change the number of words, e.g.,
+                // `timestamp with time zone` and `timestamptz`, so we compare
+                // the whole name at its first word.
+                return (idx == 0).then(|| (builtin.ty.canonical_name().to_string(), token));
+            }
+            // `"int4"` is unquoted and renamed to `integer`
+            if options.type_aliases == TypeAliases::Canonical
+                && token.kind() == SyntaxKind::IDENT
+                && let Some(ty) = token
+                    .parent_ancestors()
+                    .take_while(|node| node.first_token().as_ref() == Some(&token))
+                    .find_map(ast::Type::cast)
+                && let Some((builtin, _)) = quoted_builtin_type(options, &ty)
+            {
+                return Some((builtin.canonical_name().to_string(), token));
+            }
+            Some((normalize(&token), token))
+        })
+        .collect();
+    // The formatter moves a comma in front of the comments before it, e.g.,
+    // `a -- c\n, b` becomes `a, -- c\nb`.
+    for i in 0..tokens.len() {
+        if tokens[i].1.kind() != SyntaxKind::COMMA {
+            continue;
+        }
+        let mut j = i;
+        while j > 0 && tokens[j - 1].1.kind() == SyntaxKind::COMMENT {
+            tokens.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+    tokens
+}
+
+/// Tokens the formatter is allowed to add or remove.
+fn is_ignored(options: &FormatOptions, token: &SyntaxToken) -> bool {
+    if token.kind() == SyntaxKind::WHITESPACE {
+        return true;
+    }
+    let parent = token.parent().map(|p| p.kind());
+    match token.kind() {
+        SyntaxKind::AS_KW => {
+            options.alias_as != AliasAs::Preserve
+                && matches!(
+                    parent,
+                    Some(
+                        SyntaxKind::AS_NAME
+                            | SyntaxKind::FROM_ALIAS
+                            | SyntaxKind::OPTIONAL_AS_ALIAS
+                    )
+                )
+        }
+        SyntaxKind::COMMA => options.trailing_commas && parent == Some(SyntaxKind::ERROR),
+        _ => false,
+    }
+}
+
+/// Keywords and unquoted identifiers are case insensitive while quoted
+/// identifiers aren't, so `"foo"`, `foo`, and `FOO` are all equivalent.
+fn normalize(token: &SyntaxToken) -> String {
+    let text = token.text();
+    if token.kind() == SyntaxKind::COMMENT {
+        return text.trim_end().to_string();
+    }
+    if token.kind() == SyntaxKind::IDENT
+        && let Some(inner) = text.strip_prefix('"').and_then(|t| t.strip_suffix('"'))
+    {
+        return inner.replace(r#""""#, "\"");
+    }
+    if token.kind() == SyntaxKind::IDENT || is_word(text) {
+        return text.to_ascii_lowercase();
+    }
+    format_string_token(token)
+}
+
+fn is_word(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+    use squawk_syntax::ast::{self, AstNode};
+
+    use super::verify;
+    use crate::options::{AliasAs, FormatOptions, TypeAliases};
+
+    fn check(options: &FormatOptions, before: &str, after: &str) -> String {
+        let parse = ast::SourceFile::parse(before);
+        match verify(options, before, parse.tree().syntax(), after) {
+            Ok(()) => "ok".to_string(),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn equivalent() {
+        let options = FormatOptions::default();
+        assert_snapshot!(check(&options, r#"SELECT "a" FROM "T";"#, "select a\nfrom \"T\";"), @"ok");
+    }
+
+    #[test]
+    fn quoted_ident_case() {
+        let options = FormatOptions::default();
+        assert_snapshot!(check(&options, r#"select "A";"#, "select a;"), @"formatting changed the meaning of the SQL at 1:8: expected `\"A\"`, found `a`");
+    }
+
+    #[test]
+    fn dropped_token() {
+        let options = FormatOptions::default();
+        assert_snapshot!(check(&options, "create temp table t(a int);", "create table t(a int);"), @"formatting changed the meaning of the SQL at 1:8: expected `temp`, found `table`");
+    }
+
+    #[test]
+    fn dropped_comment() {
+        let options = FormatOptions::default();
+        assert_snapshot!(check(&options, "select a /* c */ + 1;", "select a + 1;"), @"formatting changed the meaning of the SQL at 1:10: expected `/* c */`, found `+`");
+        assert_snapshot!(check(&options, "select 1 -- a\n;", "select 1 -- b\n;"), @"formatting changed the meaning of the SQL at 1:10: expected `-- a`, found `-- b`");
+        assert_snapshot!(check(&options, "select a -- c\n, b;", "select a, -- c\nb;"), @"ok");
+    }
+
+    #[test]
+    fn string_case() {
+        let options = FormatOptions::default();
+        assert_snapshot!(check(&options, "select E'A';", "select e'a';"), @"formatting changed the meaning of the SQL at 1:8: expected `E'A'`, found `e'a'`");
+    }
+
+    #[test]
+    fn syntax_error() {
+        let options = FormatOptions::default();
+        assert_snapshot!(check(&options, "select 1;", "select;1;"), @"formatting introduced a syntax error at 1:8: expected command, found `1`");
+    }
+
+    #[test]
+    fn alias_as() {
+        let options = FormatOptions {
+            alias_as: AliasAs::Always,
+            ..Default::default()
+        };
+        assert_snapshot!(check(&options, "select 1 a;", "select 1 as a;"), @"ok");
+    }
+
+    #[test]
+    fn type_aliases() {
+        let options = FormatOptions {
+            type_aliases: TypeAliases::Canonical,
+            ..Default::default()
+        };
+        assert_snapshot!(check(&options, "select 1::timestamp(3) with time zone;", "select 1::timestamptz(3);"), @"ok");
+        assert_snapshot!(check(&options, "select 1::int4;", "select 1::int8;"), @"formatting changed the meaning of the SQL at 1:11: expected `int4`, found `int8`");
+    }
+
+    #[test]
+    fn trailing_commas() {
+        let options = FormatOptions {
+            trailing_commas: true,
+            ..Default::default()
+        };
+        assert_snapshot!(check(&options, "select a, b;", "select a, b,;"), @"ok");
+    }
+}
@@ -0,0 +1,11 @@
+alter table t add column a int;
+
+ALTER TABLE IF EXISTS public.users ADD COLUMN IF NOT EXISTS email text NOT NULL DEFAULT '', ADD CONSTRAINT users_email_key UNIQUE (email), DROP COLUMN legacy_id;
+
+alter table only t
+  -- tracks the owner
+  add column owner_id bigint references users (id) on delete cascade, -- nullable for now
+  alter column name set not null;
+
+alter table t add constraint t_amount_check check (amount > 0) not valid;
+alter table t validate constraint t_amount_check;
@@ -0,0 +1,5 @@
+comment on table users is 'people who can sign in';
+
+COMMENT ON COLUMN public.users.email IS 'the primary email address for the account, used for login and for notifications';
+
+comment on index users_email_key is null;
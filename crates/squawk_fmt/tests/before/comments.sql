@@ -24,4 +24,12 @@
 
 
 
+-- trailing comments stay on the statement's line
+create index i on t (a); -- squawk-ignore require-concurrent-index-creation
+create index j on t (b);
+alter table t add column a int;   /* why */
+select 1;
+-- but not the next statement's comments
+select 2;
+
 /* end */
@@ -0,0 +1,17 @@
+create function add(a int, b int) returns int language sql immutable as 'select a + b';
+
+CREATE OR REPLACE FUNCTION public.set_updated_at(IN tbl text, max_rows int DEFAULT 100) RETURNS trigger LANGUAGE plpgsql SECURITY DEFINER AS $$
+BEGIN
+    NEW.updated_at   := now();
+  RETURN NEW;
+END;
+$$;
+
+create function f() returns table (id int, name text) as $body$ select 1, 'a' $body$ language sql;
+
+-- line comment after the last param
+create function f(a int, b int -- arg
+) returns int language sql as $$ select a + b $$;
+
+create function f() returns table (id int -- key
+) as $body$ select 1 $body$ language sql;
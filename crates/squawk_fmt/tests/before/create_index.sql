@@ -0,0 +1,5 @@
+create index concurrently idx on t (a);
+
+CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS users_lower_email_idx ON public.users USING btree (lower(email), created_at DESC NULLS LAST) INCLUDE (id) WITH (fillfactor = 70) WHERE deleted_at IS NULL;
+
+create index idx_events_on_account_id_and_created_at on events (account_id, created_at, kind, some_other_column);
@@ -0,0 +1,7 @@
+create view v as select 1;
+
+CREATE OR REPLACE VIEW active_users (id, name) WITH (security_barrier = true) AS SELECT id, name FROM users WHERE active AND deleted_at IS NULL WITH LOCAL CHECK OPTION;
+
+-- comment before as
+create view v with (security_barrier = true) -- why
+  as select 1;
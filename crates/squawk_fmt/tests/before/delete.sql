@@ -0,0 +1,5 @@
+delete from t;
+
+DELETE FROM orders o USING customers c, regions r WHERE o.customer_id = c.id AND c.region_id = r.id AND r.name = 'closed' RETURNING o.id;
+
+delete from t where id = 1 returning *;
@@ -0,0 +1,23 @@
+SELECT  1;
+
+-- squawk-fmt: off
+insert into users (id, name,    email) values
+  (1,  'alice', 'alice@example.com'),
+  (10, 'bob',   'bob@example.com');
+-- squawk-fmt: on
+
+SELECT  2;
+
+-- squawk-fmt: ignore -- hand aligned
+insert into t (a,   b) values (1,   2);
+insert into t (a,   b) values (1,   2);
+
+/* squawk-fmt: ignore */
+SELECT  3;
+
+-- not a directive
+-- squawk-fmt: nope
+SELECT  4;
+
+-- squawk-fmt: off
+SELECT  5;
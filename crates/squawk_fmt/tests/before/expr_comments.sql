@@ -0,0 +1,33 @@
+-- comments inside expressions
+select * from t where a = 1 -- why
+  and b = 2;
+
+update t set a = 1 where /* w */ b = 2 or /* c */ c between 1 and /* d */ 3;
+
+select a + /* one */ 1, b isnull /* e */ from t;
+
+-- comments inside column lists
+insert into t (a, /* c1 */ b) values (1, 2);
+
+insert into t (a -- c2
+, b) values (1, 2);
+
+-- comments inside merge clauses
+merge into t using s on t.id = s.id
+when matched /* m */ then delete
+when matched and /* c */ t.a = 1 then update set a = 1
+when not matched /* n */ and s.b > 2 then insert (a, /* x */ b) values (1, 2);
+
+-- comments around commas
+insert into t values (1, 2) -- t1
+, (3, 4);
+
+insert into t values (1, 2), -- t2
+(3, /* v */ 4);
+
+select a -- first
+  ,
+  b from t;
+
+select a, -- second
+  b /* third */, c from t;
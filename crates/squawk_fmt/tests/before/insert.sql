@@ -0,0 +1,20 @@
+insert into t values (1, 2);
+
+INSERT INTO public.users (id, name, email) VALUES (1, 'alice', 'alice@example.com'), (2, 'bob', 'bob@example.com'), (3, 'carol', 'carol@example.com');
+
+insert into t as x (a, b) overriding system value values (default, 2);
+
+insert into t default values returning *;
+
+insert into t (a) select a from u where a > 1;
+
+-- upsert
+insert into users (id, name) values (1, 'a') on conflict (id) do nothing;
+
+insert into users (id, name, email, updated_at) values (1, 'alice', 'alice@example.com', now()) on conflict (id) do update set name = excluded.name, email = excluded.email, updated_at = excluded.updated_at where users.name <> excluded.name returning id, name;
+
+insert into users (id) values (1) on conflict on constraint users_pkey do nothing;
+
+-- comments in the column list
+insert into users (id, -- key
+name) values (1, 'a');
@@ -0,0 +1,12 @@
+merge into t using s on t.id = s.id when matched then delete;
+
+MERGE INTO customer_account ca
+USING recent_transactions t
+ON t.customer_id = ca.customer_id
+WHEN MATCHED AND t.amount > 0 THEN
+  UPDATE SET balance = balance + t.amount
+WHEN NOT MATCHED THEN
+  INSERT (customer_id, balance) VALUES (t.customer_id, t.amount)
+WHEN NOT MATCHED BY SOURCE THEN
+  DO NOTHING
+RETURNING merge_action(), ca.*;
@@ -0,0 +1,9 @@
+select 1;
+
+-- not formatted yet
+CREATE   INDEX   idx ON t (a);
+grant select on t to bob;
+
+select a from t where a > 1 order by a desc limit 10 offset 5;
+
+select a, count(*) from t group by a having count(*) > 1;
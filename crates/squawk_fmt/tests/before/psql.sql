@@ -0,0 +1,3 @@
+\set id 1
+select   1;
+select   2 \gexec
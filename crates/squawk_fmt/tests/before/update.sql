@@ -0,0 +1,11 @@
+update t set a = 1;
+
+UPDATE only accounts AS a SET balance = a.balance + t.amount, updated_at = default FROM transfers t WHERE t.account_id = a.id RETURNING a.id, a.balance;
+
+update t set a = 1 where current of c;
+
+update t
+-- set them all
+set a = 1, -- first
+  b = 2
+where id = 3;
@@ -0,0 +1,9 @@
+values (1, 2), (3, 4);
+
+VALUES (1, 'a really long string value'), (2, 'another long string value'), (3, 'yet another') ORDER BY 1 LIMIT 2;
+
+-- comment before the rows
+values
+  -- first
+  (1, 2),
+  (3, 4);
@@ -0,0 +1,7 @@
+with a as (select 1) select * from a;
+
+WITH RECURSIVE active_users (id, name) AS MATERIALIZED (SELECT id, name FROM users WHERE active), recent AS NOT MATERIALIZED (SELECT user_id FROM events WHERE created_at > now() - interval '1 day') SELECT * FROM active_users JOIN recent ON recent.user_id = active_users.id;
+
+with moved as (delete from queue where id = 1 returning *) insert into archive select * from moved;
+
+with u as (update t set a = 1 returning *) values (1);
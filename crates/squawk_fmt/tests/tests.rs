@@ -1,7 +1,12 @@
 use camino::Utf8Path;
 use dir_test::{Fixture, dir_test};
 use insta::{assert_snapshot, with_settings};
+use rowan::TextRange;
+use squawk_fmt::{
+    AliasAs, CanonicalTypes, CommaStyle, FormatOptions, KeywordCase, QuoteIdentifiers, TypeAliases,
+};
 use squawk_lexer::{Token, TokenKind, tokenize};
+use squawk_syntax::{Dialect, Templating};
 
 #[dir_test(
     dir: "$CARGO_MANIFEST_DIR/tests/before",
@@ -15,7 +20,7 @@ fn fmt(fixture: Fixture<&str>) {
         .and_then(|x| x.strip_suffix(".sql"))
         .unwrap();
 
-    let formatted = squawk_fmt::fmt(content).unwrap();
+    let formatted = squawk_fmt::fmt(content, &FormatOptions::default()).unwrap();
 
     assert_no_dropped_tokens(content, &formatted);
 
@@ -29,6 +34,34 @@ fn fmt(fixture: Fixture<&str>) {
     });
 }
 
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/../../postgres/regression_suite",
+    glob: "*.sql",
+)]
+fn regression_suite_idempotency(fixture: Fixture<&str>) {
+    let content = fixture.content();
+    let absolute_fixture_path = Utf8Path::new(fixture.path());
+    let test_name = absolute_fixture_path
+        .file_name()
+        .and_then(|x| x.strip_suffix(".sql"))
+        .unwrap();
+    // these intentionally have syntax errors
+    if test_name == "errors" || test_name == "strings" {
+        return;
+    }
+
+    let options = FormatOptions::default();
+    let formatted = squawk_fmt::fmt(content, &options)
+        .unwrap_or_else(|err| panic!("failed to format `{test_name}`: {err}"));
+    let reformatted = squawk_fmt::fmt(&formatted, &options)
+        .unwrap_or_else(|err| panic!("failed to reformat `{test_name}`: {err}"));
+
+    assert!(
+        formatted == reformatted,
+        "formatting `{test_name}` isn't idempotent"
+    );
+}
+
 fn fmt_with_line_ending(line_ending: &str) -> String {
     let sql = [
         "-- a comment",
@@ -42,7 +75,7 @@ fn fmt_with_line_ending(line_ending: &str) -> String {
     ]
     .join(line_ending);
 
-    match squawk_fmt::fmt(&sql) {
+    match squawk_fmt::fmt(&sql, &FormatOptions::default()) {
         Ok(formatted) => {
             assert_no_dropped_tokens(&sql, &formatted);
             formatted.replace('\r', "<CR>")
@@ -83,7 +116,490 @@ fn fmt_crlf_line_endings() {
 
 #[test]
 fn fmt_cr_line_endings() {
-    assert_snapshot!(fmt_with_line_ending("\r"), @"error: CR line endings aren't supported");
+    assert_snapshot!(fmt_with_line_ending("\r"), @"-- a comment<CR>select 1;<CR><CR>/* a comment<CR> * spanning lines<CR> */<CR>select<CR>  'a',<CR>  'really long string                                                    ';<CR>");
+}
+
+#[test]
+fn fmt_cr_line_endings_keep_line_breaks_in_strings() {
+    let sql = "select  $$a\nb$$, 'c\nd';\r/* e\nf */\rselect  1;\r";
+    let formatted = squawk_fmt::fmt(sql, &FormatOptions::default()).unwrap();
+    assert_snapshot!(formatted.replace('\r', "<CR>"), @"
+    select $$a
+    b$$, 'c
+    d';<CR>/* e
+    f */<CR>select 1;<CR>
+    ");
+}
+
+#[test]
+fn fmt_dialect() {
+    let sql = "CREATE INDEX ASYNC i ON t (a);\n";
+    assert_snapshot!(squawk_fmt::fmt(sql, &FormatOptions::default()).unwrap_err(), @"syntax error at 1:19: expected ON, found `i`");
+    let options = FormatOptions {
+        dialect: Dialect::AuroraDsql,
+        ..Default::default()
+    };
+    assert_snapshot!(squawk_fmt::fmt(sql, &options).unwrap(), @"create index async i on t (a);");
+}
+
+#[test]
+fn fmt_templating() {
+    let sql = "SELECT  a FROM {{ ref('t') }};\n";
+    assert_snapshot!(squawk_fmt::fmt(sql, &FormatOptions::default()).unwrap_err(), @"syntax error at 1:15: expected from item, found `{`");
+    let options = FormatOptions {
+        templating: Some(Templating::Jinja),
+        ..Default::default()
+    };
+    assert_snapshot!(squawk_fmt::fmt(sql, &options).unwrap(), @"select a from {{ ref('t') }};");
+}
+
+#[test]
+fn fmt_syntax_error() {
+    let sql = "select 1;\nselect from where;\n";
+    assert_snapshot!(squawk_fmt::fmt(sql, &FormatOptions::default()).unwrap_err(), @"syntax error at 2:12: expected from item, found `where`");
+}
+
+fn fmt_range(sql: &str, range: std::ops::Range<u32>) -> String {
+    let range = TextRange::new(range.start.into(), range.end.into());
+    let edits = squawk_fmt::fmt_range(sql, range, &FormatOptions::default()).unwrap();
+    let mut sql = sql.to_string();
+    for edit in edits.iter().rev() {
+        edit.apply(&mut sql);
+    }
+    sql
+}
+
+#[test]
+fn fmt_range_single_statement() {
+    let sql = "SELECT  1;\nSELECT  2;\nSELECT  3;\n";
+    assert_snapshot!(fmt_range(sql, 13..13), @"
+    SELECT  1;
+    select 2;
+    SELECT  3;
+    ");
+}
+
+#[test]
+fn fmt_range_multiple_statements() {
+    let sql = "SELECT  1;\nSELECT  2;\nSELECT  3;\n";
+    assert_snapshot!(fmt_range(sql, 5..15), @"
+    select 1;
+    select 2;
+    SELECT  3;
+    ");
+}
+
+#[test]
+fn fmt_range_ignore() {
+    let sql = "-- squawk-fmt: ignore\nSELECT  1;\nSELECT  2;\n";
+    assert_snapshot!(fmt_range(sql, 0..44), @"
+    -- squawk-fmt: ignore
+    SELECT  1;
+    select 2;
+    ");
+}
+
+#[test]
+fn fmt_range_crlf() {
+    let sql = "SELECT  1;\r\nselect 'a really long string', 'another really long string', 'and yet another one';\r\n";
+    assert_snapshot!(fmt_range(sql, 12..12).replace('\r', "<CR>"), @"
+    SELECT  1;<CR>
+    select<CR>
+      'a really long string',<CR>
+      'another really long string',<CR>
+      'and yet another one';<CR>
+    ");
+}
+
+#[test]
+fn fmt_range_cr() {
+    let sql = "SELECT  1;\rselect  $$a\nb$$;\r";
+    assert_snapshot!(fmt_range(sql, 12..12).replace('\r', "<CR>"), @"
+    SELECT  1;<CR>select $$a
+    b$$;<CR>
+    ");
+}
+
+#[test]
+fn fmt_range_syntax_error() {
+    let sql = "select 1;\nselect from where;\n";
+    let range = TextRange::new(0.into(), 1.into());
+    assert_snapshot!(squawk_fmt::fmt_range(sql, range, &FormatOptions::default()).unwrap_err(), @"syntax error at 2:12: expected from item, found `where`");
+}
+
+const OPTIONS_SQL: &str = r#"select "id", name username, email as "Email", created_at from public.users as u;
+create table "t" (a int, b text);
+alter table t add column c int, drop column d;
+"#;
+
+fn fmt_with_options(options: FormatOptions) -> String {
+    squawk_fmt::fmt(OPTIONS_SQL, &options).unwrap()
+}
+
+#[test]
+fn fmt_options_default() {
+    assert_snapshot!(fmt_with_options(FormatOptions::default()), @r#"
+    select id, name username, email as "Email", created_at from public.users as u;
+    create table t(
+      a int,
+      b text
+    );
+    alter table t
+      add column c int,
+      drop column d;
+    "#);
+}
+
+#[test]
+fn fmt_options_keyword_case_upper() {
+    let formatted = fmt_with_options(FormatOptions {
+        keyword_case: KeywordCase::Upper,
+        ..Default::default()
+    });
+    assert_no_dropped_tokens(OPTIONS_SQL, &formatted);
+    assert_snapshot!(formatted, @r#"
+    SELECT id, name username, email AS "Email", created_at FROM public.users AS u;
+    CREATE TABLE t(
+      a int,
+      b text
+    );
+    ALTER TABLE t
+      ADD COLUMN c int,
+      DROP COLUMN d;
+    "#);
+}
+
+#[test]
+fn fmt_options_keyword_case_upper_passthrough() {
+    let sql = "select a from t left join u using (id) where a is not null order by a desc nulls last limit 1 offset 2 for update;";
+    let formatted = squawk_fmt::fmt(
+        sql,
+        &FormatOptions {
+            keyword_case: KeywordCase::Upper,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_no_dropped_tokens(sql, &formatted);
+    assert_snapshot!(formatted, @"
+    SELECT a
+    FROM t LEFT JOIN u USING (id)
+    WHERE a IS NOT NULL
+    ORDER BY a DESC NULLS LAST
+    LIMIT 1
+    OFFSET 2
+    FOR UPDATE;
+    ");
+}
+
+#[test]
+fn fmt_options_indent_width() {
+    let formatted = fmt_with_options(FormatOptions {
+        indent_width: 4,
+        ..Default::default()
+    });
+    assert_no_dropped_tokens(OPTIONS_SQL, &formatted);
+    assert_snapshot!(formatted, @r#"
+    select id, name username, email as "Email", created_at from public.users as u;
+    create table t(
+        a int,
+        b text
+    );
+    alter table t
+        add column c int,
+        drop column d;
+    "#);
+}
+
+#[test]
+fn fmt_options_quote_identifiers_preserve() {
+    let formatted = fmt_with_options(FormatOptions {
+        quote_identifiers: QuoteIdentifiers::Preserve,
+        ..Default::default()
+    });
+    assert_no_dropped_tokens(OPTIONS_SQL, &formatted);
+    assert_snapshot!(formatted, @r#"
+    select "id", name username, email as "Email", created_at from public.users as u;
+    create table "t"(
+      a int,
+      b text
+    );
+    alter table t
+      add column c int,
+      drop column d;
+    "#);
+}
+
+#[test]
+fn fmt_options_alias_as_always() {
+    assert_snapshot!(fmt_with_options(FormatOptions {
+        alias_as: AliasAs::Always,
+        ..Default::default()
+    }), @r#"
+    select id, name as username, email as "Email", created_at
+    from public.users as u;
+    create table t(
+      a int,
+      b text
+    );
+    alter table t
+      add column c int,
+      drop column d;
+    "#);
+}
+
+#[test]
+fn fmt_options_alias_as_never() {
+    let sql = "select 1 as a, 2 as from, 3 b from t as u;";
+    assert_snapshot!(squawk_fmt::fmt(sql, &FormatOptions {
+        alias_as: AliasAs::Never,
+        ..Default::default()
+    }).unwrap(), @"select 1 a, 2 as from, 3 b from t u;");
+}
+
+const ALIASES_SQL: &str =
+    "select a from t as u join (select 1 as x, 2 y) s on true, f() as (a int);
+update t as set set a = 1;
+delete from t u;
+";
+
+#[test]
+fn fmt_options_alias_as_never_from_items() {
+    let formatted = squawk_fmt::fmt(
+        ALIASES_SQL,
+        &FormatOptions {
+            alias_as: AliasAs::Never,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_snapshot!(formatted, @"
+    select a from t u join (select 1 x, 2 y) s on true, f() as (a int);
+    update t as set set a = 1;
+    delete from t u;
+    ");
+}
+
+#[test]
+fn fmt_options_alias_as_always_from_items() {
+    let formatted = squawk_fmt::fmt(
+        ALIASES_SQL,
+        &FormatOptions {
+            alias_as: AliasAs::Always,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_snapshot!(formatted, @"
+    select a from t as u join (select 1 as x, 2 as y) as s on true, f() as (a int);
+    update t as set set a = 1;
+    delete from t as u;
+    ");
+}
+
+#[test]
+fn fmt_options_alias_as_never_insert() {
+    let sql = "insert into t as u values (1);";
+    assert_snapshot!(squawk_fmt::fmt(sql, &FormatOptions {
+        alias_as: AliasAs::Never,
+        ..Default::default()
+    }).unwrap(), @"insert into t as u values (1);");
+}
+
+#[test]
+fn fmt_options_trailing_commas() {
+    assert_snapshot!(fmt_with_options(FormatOptions {
+        trailing_commas: true,
+        max_line_width: 40,
+        ..Default::default()
+    }), @r#"
+    select
+      id,
+      name username,
+      email as "Email",
+      created_at,
+    from public.users as u;
+    create table t(
+      a int,
+      b text,
+    );
+    alter table t
+      add column c int,
+      drop column d;
+    "#);
+}
+
+#[test]
+fn fmt_options_max_line_width() {
+    let formatted = fmt_with_options(FormatOptions {
+        max_line_width: 40,
+        ..Default::default()
+    });
+    assert_no_dropped_tokens(OPTIONS_SQL, &formatted);
+    assert_snapshot!(formatted, @r#"
+    select
+      id,
+      name username,
+      email as "Email",
+      created_at
+    from public.users as u;
+    create table t(
+      a int,
+      b text
+    );
+    alter table t
+      add column c int,
+      drop column d;
+    "#);
+}
+
+#[test]
+fn fmt_options_comma_style_leading() {
+    let formatted = fmt_with_options(FormatOptions {
+        comma_style: CommaStyle::Leading,
+        ..Default::default()
+    });
+    assert_no_dropped_tokens(OPTIONS_SQL, &formatted);
+    assert_snapshot!(formatted, @r#"
+    select id, name username, email as "Email", created_at from public.users as u;
+    create table t(
+      a int
+      , b text
+    );
+    alter table t
+      add column c int
+      , drop column d;
+    "#);
+}
+
+const TYPES_SQL: &str = r#"create table "core_recipe" (
+  "id" int8 primary key,
+  "name" CHARACTER VARYING(100),
+  "edits" INT4[],
+  "ok" bool,
+  "created" timestamp(3) with time zone,
+  "kind" "char"
+);
+select "core_recipe"."id"::int, cast("core_recipe"."created" as timestamp without time zone) from "core_recipe";
+"#;
+
+#[test]
+fn fmt_options_type_aliases_preserve() {
+    assert_snapshot!(squawk_fmt::fmt(TYPES_SQL, &FormatOptions::default()).unwrap(), @r#"
+    create table core_recipe(
+      id int8 primary key,
+      name character varying(100),
+      edits int4[],
+      ok bool,
+      created timestamp(3) with time zone,
+      kind "char"
+    );
+    select
+      core_recipe.id::int,
+      cast(core_recipe.created as timestamp without time zone)
+    from core_recipe;
+    "#);
+}
+
+#[test]
+fn fmt_options_type_aliases_canonical() {
+    let formatted = squawk_fmt::fmt(
+        TYPES_SQL,
+        &FormatOptions {
+            type_aliases: TypeAliases::Canonical,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_snapshot!(formatted, @r#"
+    create table core_recipe(
+      id bigint primary key,
+      name varchar(100),
+      edits integer[],
+      ok boolean,
+      created timestamptz(3),
+      kind "char"
+    );
+    select core_recipe.id::integer, cast(core_recipe.created as timestamp)
+    from core_recipe;
+    "#);
+}
+
+#[test]
+fn fmt_options_type_aliases_canonical_quoted() {
+    let sql = r#"create table t ("a" "int4", b "int8"[], c "char", d "int", e "timestamptz");
+select 1::"bool", 1::pg_catalog."int4";
+"#;
+    let options = FormatOptions {
+        type_aliases: TypeAliases::Canonical,
+        ..Default::default()
+    };
+    assert_snapshot!(squawk_fmt::fmt(sql, &options).unwrap(), @r#"
+    create table t(
+      a integer,
+      b bigint[],
+      c "char",
+      d "int",
+      e timestamptz
+    );
+    select 1::boolean, 1::pg_catalog.int4;
+    "#);
+    let options = FormatOptions {
+        type_aliases: TypeAliases::Canonical,
+        quote_identifiers: QuoteIdentifiers::Preserve,
+        ..Default::default()
+    };
+    assert_snapshot!(squawk_fmt::fmt(sql, &options).unwrap(), @r#"
+    create table t(
+      "a" "int4",
+      b "int8"[],
+      c "char",
+      d "int",
+      e "timestamptz"
+    );
+    select 1::"bool", 1::pg_catalog."int4";
+    "#);
+}
+
+#[test]
+fn fmt_options_canonical_types() {
+    let options: FormatOptions = toml::from_str(
+        r#"
+type_aliases = "canonical"
+keyword_case = "upper"
+
+[canonical_types]
+timestamptz = "timestamp with time zone"
+int = "int4"
+"#,
+    )
+    .unwrap();
+    assert_snapshot!(squawk_fmt::fmt(TYPES_SQL, &options).unwrap(), @r#"
+    CREATE TABLE core_recipe(
+      id bigint PRIMARY KEY,
+      name VARCHAR(100),
+      edits int4[],
+      ok boolean,
+      created TIMESTAMP(3) WITH TIME ZONE,
+      kind "char"
+    );
+    SELECT core_recipe.id::int4, CAST(core_recipe.created AS TIMESTAMP)
+    FROM core_recipe;
+    "#);
+}
+
+#[test]
+fn fmt_options_canonical_types_invalid() {
+    let err = toml::from_str::<FormatOptions>(
+        r#"
+[canonical_types]
+int4 = "bigint"
+"#,
+    )
+    .unwrap_err();
+    assert_snapshot!(err, @"`bigint` isn't another name for `int4` for key `canonical_types` at line 2 column 1");
+    let err = toml::from_str::<CanonicalTypes>(r#"text = "varchar""#).unwrap_err();
+    assert_snapshot!(err, @"`text` isn't a builtin type with more than one name");
 }
 
 fn meaningful_tokens(text: &str) -> Vec<(TokenKind, &str)> {
@@ -96,6 +612,23 @@ fn meaningful_tokens(text: &str) -> Vec<(TokenKind, &str)> {
         }
         offset += len;
     }
+    // the formatter moves a comma in front of the comments before it,
+    // `a -- c\n, b` becomes `a, -- c\nb`
+    for i in 1..tokens.len() {
+        if tokens[i].0 != TokenKind::Comma {
+            continue;
+        }
+        let mut j = i;
+        while j > 0
+            && matches!(
+                tokens[j - 1].0,
+                TokenKind::LineComment | TokenKind::BlockComment { .. }
+            )
+        {
+            tokens.swap(j - 1, j);
+            j -= 1;
+        }
+    }
     tokens
 }
 
@@ -273,6 +273,25 @@ impl Binder {
         names
     }
 
+    /// Symbols defined by the name at `range`, e.g., a table and its row type
+    /// for `create table t`.
+    pub(crate) fn symbols_at(&self, range: TextRange) -> impl Iterator<Item = &Symbol> {
+        self.symbols
+            .values()
+            .filter(move |symbol| symbol.ptr.text_range() == range)
+    }
+
+    pub(crate) fn symbols_named<N: AsName + ?Sized>(
+        &self,
+        name: &N,
+    ) -> impl Iterator<Item = &Symbol> {
+        self.scope
+            .get(name)
+            .unwrap_or_default()
+            .iter()
+            .map(|id| &self.symbols[*id])
+    }
+
     pub(crate) fn functions_with_single_param(&self, param_type: &Name) -> Vec<&Name> {
         let mut names = vec![];
         for (name, symbol_ids) in &self.scope.entries {
@@ -114,6 +114,7 @@ fn classify_object_definition(kind: SyntaxKind) -> Option<LocationKind> {
         SyntaxKind::LANGUAGE => LocationKind::Language,
         SyntaxKind::OP_CLASS_NAME => LocationKind::OperatorClass,
         SyntaxKind::OP_FAMILY_NAME => LocationKind::OperatorFamily,
+        SyntaxKind::PLPGSQL_VARIABLE => LocationKind::Variable,
         SyntaxKind::POLICY => LocationKind::Policy,
         SyntaxKind::PREPARED_STATEMENT => LocationKind::PreparedStatement,
         SyntaxKind::PROCEDURE_NAME => LocationKind::Procedure,
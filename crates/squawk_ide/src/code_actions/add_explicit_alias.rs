@@ -1,13 +1,12 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
-use squawk_syntax::quote::quote_column_alias;
+use squawk_syntax::ast::{self, AstNode, make};
+use squawk_syntax::syntax_editor::Position;
 
 use crate::{file::InFile, offsets::token_from_offset};
 use squawk_syntax::column_name::ColumnName;
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn add_explicit_alias(
     db: &dyn Db,
@@ -29,14 +28,20 @@ pub(super) fn add_explicit_alias(
 
     let alias = ColumnName::from_target(target.clone()).and_then(|c| c.0.to_string())?;
 
-    let expr_end = target.expr().map(|e| e.syntax().text_range().end())?;
+    let expr = target.expr()?;
 
-    let quoted_alias = quote_column_alias(&alias);
-    let replacement = format!(" as {quoted_alias}");
+    let mut editor = editor(db, position.file_id);
+    editor.insert_all(
+        Position::after(expr),
+        vec![
+            make::tokens::single_space().into(),
+            make::as_name(&alias).syntax().clone().into(),
+        ],
+    );
 
     actions.push(CodeAction {
         title: "Add explicit alias".to_owned(),
-        edits: vec![Edit::insert(replacement, expr_end)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
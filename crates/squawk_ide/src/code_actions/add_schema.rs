@@ -1,9 +1,9 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
+use squawk_syntax::syntax_editor::Position;
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 use crate::{db::bind, file::InFile, offsets::token_from_offset};
 
 pub(super) fn add_schema(
@@ -43,11 +43,19 @@ pub(super) fn add_schema(
         .search_path_at(token_start)
         .first()?
         .to_string();
-    let replacement = format!("{schema}.");
+
+    let mut editor = editor(db, file);
+    editor.insert_all(
+        Position::before(token),
+        vec![
+            make::tokens::ident(&schema).into(),
+            make::tokens::dot().into(),
+        ],
+    );
 
     actions.push(CodeAction {
         title: "Add schema".to_owned(),
-        edits: vec![Edit::insert(replacement, token_start)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,10 +1,10 @@
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
 use squawk_line_index::{NewlineWithTrailingNewline, find_newline};
-use squawk_linter::Edit;
 use squawk_syntax::{
-    SyntaxToken,
-    ast::{self, AstNode, AstToken},
+    SyntaxElement, SyntaxToken,
+    ast::{self, AstNode, AstToken, make},
+    syntax_editor::SyntaxEditor,
 };
 
 use crate::{
@@ -15,7 +15,7 @@ use crate::{
     file::InFile,
 };
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn convert_comment(
     db: &dyn Db,
@@ -29,24 +29,23 @@ pub(super) fn convert_comment(
         .token_at_offset(position.value)
         .find_map(ast::Comment::cast)?;
 
-    let action = if comment.kind().is_block() {
+    let mut editor = editor(db, file);
+    let title = if comment.kind().is_block() {
         if has_trailing_content(&comment) {
             return None;
         }
-        CodeAction {
-            title: "Convert to line comment".to_owned(),
-            edits: vec![block_to_lines(&comment)?],
-            kind: ActionKind::RefactorRewrite,
-        }
+        block_to_lines(&mut editor, &comment)?;
+        "Convert to line comment"
     } else {
-        CodeAction {
-            title: "Convert to block comment".to_owned(),
-            edits: vec![lines_to_block(&comment)?],
-            kind: ActionKind::RefactorRewrite,
-        }
+        lines_to_block(&mut editor, &comment)?;
+        "Convert to block comment"
     };
 
-    actions.push(action);
+    actions.push(CodeAction {
+        title: title.to_owned(),
+        edits: editor.finish(),
+        kind: ActionKind::RefactorRewrite,
+    });
 
     Some(())
 }
@@ -62,7 +61,7 @@ fn has_trailing_content(comment: &ast::Comment) -> bool {
     find_newline(whitespace.text()).is_none() && whitespace.syntax().next_token().is_some()
 }
 
-fn block_to_lines(comment: &ast::Comment) -> Option<Edit> {
+fn block_to_lines(editor: &mut SyntaxEditor, comment: &ast::Comment) -> Option<()> {
     let lines = NewlineWithTrailingNewline::from(block_comment_content(comment.text())?)
         .map(|line| {
             let content = strip_block_decoration(line.as_str());
@@ -72,35 +71,30 @@ fn block_to_lines(comment: &ast::Comment) -> Option<Edit> {
     // Trim leading `/*` and trailing `*/` lines.
     let lines = trim_empty_edges(&lines, |(_, content)| content.is_empty());
 
-    let text = match lines {
-        [] => "--".to_owned(),
-        [(_, content)] => line_comment(content),
+    let elements: Vec<SyntaxElement> = match lines {
+        [] => vec![make::tokens::comment("--").into()],
+        [(_, content)] => vec![make::tokens::comment(&line_comment(content)).into()],
         lines => {
             let indent = indent_of(comment.syntax());
-            let mut text = String::with_capacity(comment.text().len());
+            let mut elements = Vec::with_capacity(lines.len() * 2);
             for (i, (line, content)) in lines.iter().enumerate() {
-                if i > 0 {
-                    text.push_str(&indent);
-                }
-                text.push_str("--");
-                if !content.is_empty() {
-                    text.push(' ');
-                    text.push_str(content);
-                }
+                elements.push(make::tokens::comment(&line_comment(content)).into());
                 if i + 1 < lines.len()
                     && let Some(line_ending) = line.line_ending()
                 {
-                    text.push_str(line_ending.as_str());
+                    let whitespace = format!("{}{indent}", line_ending.as_str());
+                    elements.push(make::tokens::whitespace(&whitespace).into());
                 }
             }
-            text
+            elements
         }
     };
 
-    Some(Edit::replace(comment.syntax().text_range(), text))
+    editor.replace_with_many(comment.syntax().clone(), elements);
+    Some(())
 }
 
-fn lines_to_block(comment: &ast::Comment) -> Option<Edit> {
+fn lines_to_block(editor: &mut SyntaxEditor, comment: &ast::Comment) -> Option<()> {
     let comments = line_comment_group(comment);
 
     let mut contents = Vec::with_capacity(comments.len());
@@ -114,10 +108,8 @@ fn lines_to_block(comment: &ast::Comment) -> Option<Edit> {
 
     let contents = trim_empty_edges(&contents, |content| content.is_empty());
 
-    let range = TextRange::new(
-        comments.first()?.syntax().text_range().start(),
-        comments.last()?.syntax().text_range().end(),
-    );
+    let first = comments.first()?.syntax().clone();
+    let last = comments.last()?.syntax().clone();
 
     let text = if let [content] = contents {
         if content.is_empty() {
@@ -128,7 +120,8 @@ fn lines_to_block(comment: &ast::Comment) -> Option<Edit> {
     } else {
         let indent = indent_of(comments.first()?.syntax());
         let line_ending = group_line_ending(&comments)?;
-        let mut text = String::with_capacity(usize::from(range.len()));
+        let len = last.text_range().end() - first.text_range().start();
+        let mut text = String::with_capacity(usize::from(len));
         text.push_str("/*");
         for content in contents {
             text.push_str(line_ending);
@@ -145,7 +138,11 @@ fn lines_to_block(comment: &ast::Comment) -> Option<Edit> {
         text
     };
 
-    Some(Edit::replace(range, text))
+    editor.replace_all(
+        first.into()..=last.into(),
+        vec![make::tokens::comment(&text).into()],
+    );
+    Some(())
 }
 
 fn trim_empty_edges<T>(items: &[T], is_empty: impl Fn(&T) -> bool) -> &[T] {
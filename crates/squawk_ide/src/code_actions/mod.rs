@@ -1,8 +1,12 @@
 use rowan::TextSize;
 use salsa::Database as Db;
 use squawk_linter::Edit;
+use squawk_syntax::{ast::AstNode, syntax_editor::SyntaxEditor};
 
-use crate::file::InFile;
+use crate::{
+    db::{File, parse},
+    file::InFile,
+};
 
 mod add_explicit_alias;
 mod add_schema;
@@ -39,8 +43,8 @@ mod rewrite_select_into_as_create_table_as;
 mod rewrite_substring_as_function_call;
 mod rewrite_system_user_as_function_call;
 mod rewrite_table_as_select;
-mod rewrite_timestamp_type;
 mod rewrite_trim_as_function_call;
+mod rewrite_type_alias;
 mod rewrite_unnest_as_rows_from;
 mod rewrite_values_as_select;
 mod rewrite_xmlexists_as_function_call;
@@ -85,8 +89,8 @@ use rewrite_select_into_as_create_table_as::rewrite_select_into_as_create_table_
 use rewrite_substring_as_function_call::rewrite_substring_as_function_call;
 use rewrite_system_user_as_function_call::rewrite_system_user_as_function_call;
 use rewrite_table_as_select::rewrite_table_as_select;
-use rewrite_timestamp_type::rewrite_timestamp_type;
 use rewrite_trim_as_function_call::rewrite_trim_as_function_call;
+use rewrite_type_alias::rewrite_type_alias;
 use rewrite_unnest_as_rows_from::rewrite_unnest_as_rows_from;
 use rewrite_values_as_select::rewrite_values_as_select;
 use rewrite_xmlexists_as_function_call::rewrite_xmlexists_as_function_call;
@@ -105,6 +109,10 @@ pub struct CodeAction {
     pub kind: ActionKind,
 }
 
+fn editor(db: &dyn Db, file: File) -> SyntaxEditor {
+    SyntaxEditor::new(parse(db, file).tree().syntax().clone())
+}
+
 pub fn code_actions(db: &dyn Db, position: InFile<TextSize>) -> Option<Vec<CodeAction>> {
     let mut actions = vec![];
     convert_comment(db, position, &mut actions);
@@ -130,7 +138,7 @@ pub fn code_actions(db: &dyn Db, position: InFile<TextSize>) -> Option<Vec<CodeA
     rewrite_between_as_binary_expression(db, position, &mut actions);
     rewrite_not_equals_operator(db, position, &mut actions);
     rewrite_null_predicate(db, position, &mut actions);
-    rewrite_timestamp_type(db, position, &mut actions);
+    rewrite_type_alias(db, position, &mut actions);
     rewrite_unnest_as_rows_from(db, position, &mut actions);
     rewrite_rows_from_as_unnest(db, position, &mut actions);
     rewrite_at_time_zone_as_timezone(db, position, &mut actions);
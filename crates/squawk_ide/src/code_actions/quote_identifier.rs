@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode, NameLike};
+use squawk_syntax::ast::{self, AstNode, NameLike, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn quote_identifier(
     db: &dyn Db,
@@ -21,11 +20,15 @@ pub(super) fn quote_identifier(
         return None;
     }
 
-    let quoted = format!(r#""{}""#, name.text());
+    let mut editor = editor(db, position.file_id);
+    editor.replace(
+        name.syntax().clone(),
+        make::tokens::quoted_ident(&name.text()),
+    );
 
     actions.push(CodeAction {
         title: "Quote identifier".to_owned(),
-        edits: vec![Edit::replace(name.syntax().text_range(), quoted)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,6 +1,5 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
 use squawk_syntax::{
     SyntaxKind,
     ast::{self, AstNode},
@@ -9,7 +8,7 @@ use squawk_syntax::{
 use crate::db::parse;
 use crate::file::InFile;
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn remove_else_clause(
     db: &dyn Db,
@@ -27,17 +26,17 @@ pub(super) fn remove_else_clause(
     let parent = else_token.parent()?;
     let else_clause = ast::ElseClause::cast(parent)?;
 
-    let mut edits = vec![];
-    edits.push(Edit::delete(else_clause.syntax().text_range()));
+    let mut editor = editor(db, file);
+    editor.delete(else_clause);
     if let Some(token) = else_token.prev_token()
         && token.kind() == SyntaxKind::WHITESPACE
     {
-        edits.push(Edit::delete(token.text_range()));
+        editor.delete(token);
     }
 
     actions.push(CodeAction {
         title: "Remove `else` clause".to_owned(),
-        edits,
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
     Some(())
@@ -1,12 +1,11 @@
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
 use squawk_syntax::ast::{self, AstNode};
 
 use crate::{file::InFile, offsets::token_from_offset, symbols::Name};
 use squawk_syntax::column_name::ColumnName;
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn remove_redundant_alias(
     db: &dyn Db,
@@ -26,12 +25,15 @@ pub(super) fn remove_redundant_alias(
         return None;
     }
 
-    let expr_end = target.expr()?.syntax().text_range().end();
-    let alias_end = as_name.syntax().text_range().end();
+    // the alias and the whitespace before it
+    let first = target.expr()?.syntax().next_sibling_or_token()?;
+
+    let mut editor = editor(db, position.file_id);
+    editor.delete_all(first..=as_name.syntax().clone().into());
 
     actions.push(CodeAction {
         title: "Remove redundant alias".to_owned(),
-        edits: vec![Edit::delete(TextRange::new(expr_end, alias_end))],
+        edits: editor.finish(),
         kind: ActionKind::QuickFix,
     });
 
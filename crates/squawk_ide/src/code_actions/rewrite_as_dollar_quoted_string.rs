@@ -1,12 +1,14 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::{SyntaxKind, ast::AstNode};
+use squawk_syntax::{
+    SyntaxKind,
+    ast::{AstNode, make},
+};
 
 use crate::db::parse;
 use crate::file::InFile;
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_as_dollar_quoted_string(
     db: &dyn Db,
@@ -21,21 +23,25 @@ pub(super) fn rewrite_as_dollar_quoted_string(
         .token_at_offset(offset)
         .find(|token| token.kind() == SyntaxKind::STRING)?;
 
-    let replacement = string_to_dollar_quoted(string.text())?;
+    let (delimiter, content) = string_to_dollar_quoted(string.text())?;
+    let mut editor = editor(db, file);
+    editor.replace(
+        string,
+        make::tokens::dollar_quoted_string(&delimiter, &content),
+    );
     actions.push(CodeAction {
         title: "Rewrite as dollar-quoted string".to_owned(),
-        edits: vec![Edit::replace(string.text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
     Some(())
 }
 
-fn string_to_dollar_quoted(text: &str) -> Option<String> {
+fn string_to_dollar_quoted(text: &str) -> Option<(String, String)> {
     let normalized = normalize_single_quoted_string(text)?;
     let delimiter = dollar_delimiter(&normalized)?;
-    let boundary = format!("${delimiter}$");
-    Some(format!("{boundary}{normalized}{boundary}"))
+    Some((delimiter, normalized))
 }
 
 fn normalize_single_quoted_string(text: &str) -> Option<String> {
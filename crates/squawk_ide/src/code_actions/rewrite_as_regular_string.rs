@@ -1,12 +1,14 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::{SyntaxKind, ast::AstNode, quote::quote_string_literal};
+use squawk_syntax::{
+    SyntaxKind,
+    ast::{AstNode, make},
+};
 
 use crate::db::parse;
 use crate::file::InFile;
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_as_regular_string(
     db: &dyn Db,
@@ -21,17 +23,19 @@ pub(super) fn rewrite_as_regular_string(
         .token_at_offset(offset)
         .find(|token| token.kind() == SyntaxKind::DOLLAR_QUOTED_STRING)?;
 
-    let replacement = dollar_quoted_to_string(dollar_string.text())?;
+    let content = dollar_quoted_content(dollar_string.text())?;
+    let mut editor = editor(db, file);
+    editor.replace(dollar_string.clone(), make::tokens::string(content));
     actions.push(CodeAction {
         title: "Rewrite as regular string".to_owned(),
-        edits: vec![Edit::replace(dollar_string.text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
     Some(())
 }
 
-fn dollar_quoted_to_string(text: &str) -> Option<String> {
+fn dollar_quoted_content(text: &str) -> Option<&str> {
     debug_assert!(text.starts_with('$'));
     let (delimiter, content) = split_dollar_quoted(text)?;
     let boundary = format!("${delimiter}$");
@@ -40,7 +44,7 @@ fn dollar_quoted_to_string(text: &str) -> Option<String> {
         return None;
     }
 
-    Some(quote_string_literal(content))
+    Some(content)
 }
 
 fn split_dollar_quoted(text: &str) -> Option<(String, &str)> {
@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_at_local_as_timezone(
     db: &dyn Db,
@@ -19,14 +18,12 @@ pub(super) fn rewrite_at_local_as_timezone(
         .find(|expr| matches!(expr.op(), Some(ast::PostfixOp::AtLocal(_))))?;
 
     let expr = postfix_expr.expr()?;
-    let replacement = format!("timezone({})", expr.syntax().text());
+    let mut editor = editor(db, position.file_id);
+    editor.replace(postfix_expr, make::call_expr("timezone", [expr]));
 
     actions.push(CodeAction {
         title: "Rewrite `AT LOCAL` as `timezone`".to_owned(),
-        edits: vec![Edit::replace(
-            postfix_expr.syntax().text_range(),
-            replacement,
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
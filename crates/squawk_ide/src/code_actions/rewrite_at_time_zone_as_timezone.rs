@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_at_time_zone_as_timezone(
     db: &dyn Db,
@@ -20,15 +19,12 @@ pub(super) fn rewrite_at_time_zone_as_timezone(
 
     let value = bin_expr.lhs()?;
     let zone = bin_expr.rhs()?;
-    let replacement = format!(
-        "timezone({}, {})",
-        zone.syntax().text(),
-        value.syntax().text()
-    );
+    let mut editor = editor(db, position.file_id);
+    editor.replace(bin_expr, make::call_expr("timezone", [zone, value]));
 
     actions.push(CodeAction {
         title: "Rewrite as timezone function `timezone()`".to_owned(),
-        edits: vec![Edit::replace(bin_expr.syntax().text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
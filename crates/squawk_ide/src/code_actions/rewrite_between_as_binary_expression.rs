@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::AstNode;
+use squawk_syntax::ast::{AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_between_as_binary_expression(
     db: &dyn Db,
@@ -27,31 +26,40 @@ pub(super) fn rewrite_between_as_binary_expression(
         Some(ast::BetweenSymmetry::Symmetric(_))
     );
 
-    let target_text = target.syntax().text();
-    let start_text = start.syntax().text();
-    let end_text = end.syntax().text();
-
-    let replacement = match (is_not, is_symmetric) {
-        (false, false) => {
-            format!("{target_text} >= {start_text} and {target_text} <= {end_text}")
-        }
-        (true, false) => {
-            format!("({target_text} < {start_text} or {target_text} > {end_text})")
-        }
-        (false, true) => format!(
-            "{target_text} >= least({start_text}, {end_text}) and {target_text} <= greatest({start_text}, {end_text})"
-        ),
-        (true, true) => format!(
-            "({target_text} < least({start_text}, {end_text}) or {target_text} > greatest({start_text}, {end_text}))"
-        ),
+    let (lower, upper): (ast::Expr, ast::Expr) = if is_symmetric {
+        (
+            make::call_expr("least", [start.clone(), end.clone()]).into(),
+            make::call_expr("greatest", [start, end]).into(),
+        )
+    } else {
+        (start, end)
     };
 
+    let replacement: ast::Expr = if is_not {
+        make::paren_expr(
+            make::bin_expr(
+                make::bin_expr(target.clone(), "<", lower).into(),
+                "or",
+                make::bin_expr(target, ">", upper).into(),
+            )
+            .into(),
+        )
+        .into()
+    } else {
+        make::bin_expr(
+            make::bin_expr(target.clone(), ">=", lower).into(),
+            "and",
+            make::bin_expr(target, "<=", upper).into(),
+        )
+        .into()
+    };
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace(between_expr, replacement);
+
     actions.push(CodeAction {
         title: "Rewrite as binary expression".to_owned(),
-        edits: vec![Edit::replace(
-            between_expr.syntax().text_range(),
-            replacement,
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
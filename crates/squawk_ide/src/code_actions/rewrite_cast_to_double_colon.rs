@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_cast_to_double_colon(
     db: &dyn Db,
@@ -22,14 +21,12 @@ pub(super) fn rewrite_cast_to_double_colon(
     let expr = cast_expr.expr()?;
     let ty = cast_expr.ty()?;
 
-    let expr_text = expr.syntax().text();
-    let type_text = ty.syntax().text();
-
-    let replacement = format!("{expr_text}::{type_text}");
+    let mut editor = editor(db, position.file_id);
+    editor.replace(cast_expr, make::colon_colon_cast_expr(expr, ty));
 
     actions.push(CodeAction {
         title: "Rewrite as cast operator `::`".to_owned(),
-        edits: vec![Edit::replace(cast_expr.syntax().text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -85,7 +82,7 @@ mod test {
         assert_snapshot!(apply_code_action(
             rewrite_cast_to_double_colon,
             "select ca$0st(1 + 2 as bigint) from t;"),
-            @"select 1 + 2::bigint from t;"
+            @"select (1 + 2)::bigint from t;"
         );
     }
 
@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_collation_for_as_function_call(
     db: &dyn Db,
@@ -18,12 +17,12 @@ pub(super) fn rewrite_collation_for_as_function_call(
         .find_map(ast::CollationForFn::cast)?;
     let expr = collation_for.expr()?;
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace(collation_for, make::call_expr("pg_collation_for", [expr]));
+
     actions.push(CodeAction {
         title: "Rewrite as `pg_collation_for` function call".to_owned(),
-        edits: vec![Edit::replace(
-            collation_for.syntax().text_range(),
-            format!("pg_collation_for({})", expr.syntax().text()),
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
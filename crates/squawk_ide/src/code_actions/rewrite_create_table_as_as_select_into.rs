@@ -1,14 +1,14 @@
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
 use squawk_syntax::{
-    SyntaxKind,
-    ast::{self, AstNode},
+    SyntaxElement, SyntaxKind,
+    ast::{self, AstNode, make},
+    syntax_editor::Position,
 };
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_create_table_as_as_select_into(
     db: &dyn Db,
@@ -35,37 +35,33 @@ pub(super) fn rewrite_create_table_as_as_select_into(
         return None;
     };
 
-    let delete_edit = {
-        let as_token = create_table_as.as_token()?;
-        let mut delete_end = as_token.text_range().end();
-        if let Some(next) = as_token.next_sibling_or_token()
-            && next.kind() == SyntaxKind::WHITESPACE
-        {
-            delete_end = next.text_range().end();
-        }
-        Edit::delete(TextRange::new(
-            create_table_as.syntax().text_range().start(),
-            delete_end,
-        ))
-    };
+    let mut editor = editor(db, position.file_id);
 
-    let insert_edit = {
-        let persistence_text = create_table_as
-            .persistence()
-            .map(|p| format!(" {}", p.syntax()))
-            .unwrap_or_default();
-        let into_text = format!(
-            " into{} {}",
-            persistence_text,
-            create_table_as.table_name()?.path()?.syntax()
-        );
-        let select_end = select.select_clause()?.syntax().text_range().end();
-        Edit::insert(into_text, select_end)
-    };
+    // delete `create table foo as `
+    let as_token = create_table_as.as_token()?;
+    let mut delete_end: SyntaxElement = as_token.clone().into();
+    if let Some(next) = as_token.next_sibling_or_token()
+        && next.kind() == SyntaxKind::WHITESPACE
+    {
+        delete_end = next;
+    }
+    editor.delete_all(create_table_as.syntax().first_child_or_token()?..=delete_end);
+
+    let into_clause = make::into_clause(
+        create_table_as.persistence(),
+        create_table_as.table_name()?.path()?,
+    );
+    editor.insert_all(
+        Position::after(select.select_clause()?),
+        vec![
+            make::tokens::single_space().into(),
+            into_clause.syntax().clone().into(),
+        ],
+    );
 
     actions.push(CodeAction {
         title: "Rewrite as `select into`".to_owned(),
-        edits: vec![delete_edit, insert_edit],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
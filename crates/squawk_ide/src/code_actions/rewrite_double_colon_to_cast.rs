@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_double_colon_to_cast(
     db: &dyn Db,
@@ -22,14 +21,12 @@ pub(super) fn rewrite_double_colon_to_cast(
     let expr = cast_expr.expr()?;
     let ty = cast_expr.ty()?;
 
-    let expr_text = expr.syntax().text();
-    let type_text = ty.syntax().text();
-
-    let replacement = format!("cast({expr_text} as {type_text})");
+    let mut editor = editor(db, position.file_id);
+    editor.replace(cast_expr, make::cast_expr(expr, ty));
 
     actions.push(CodeAction {
         title: "Rewrite as cast function `cast()`".to_owned(),
-        edits: vec![Edit::replace(cast_expr.syntax().text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,14 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::{
-    ast::{self, AstNode, LitKind},
-    quote::quote_string_literal,
-};
+use squawk_syntax::ast::{self, AstNode, LitKind, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_extract_as_function_call(
     db: &dyn Db,
@@ -21,18 +17,23 @@ pub(super) fn rewrite_extract_as_function_call(
     let expr = extract.expr()?;
 
     let field = extract_field_argument(&field)?;
-    let replacement = format!("pg_catalog.extract({field}, {})", expr.syntax().text());
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace(
+        extract,
+        make::call_expr("pg_catalog.extract", [field.into(), expr]),
+    );
 
     actions.push(CodeAction {
         title: "Rewrite as function call `pg_catalog.extract()`".to_owned(),
-        edits: vec![Edit::replace(extract.syntax().text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
     Some(())
 }
 
-fn extract_field_argument(field: &ast::ExtractField) -> Option<String> {
+fn extract_field_argument(field: &ast::ExtractField) -> Option<ast::Literal> {
     match field {
         ast::ExtractField::ExtractFieldLiteral(field)
             if matches!(
@@ -44,9 +45,9 @@ fn extract_field_argument(field: &ast::ExtractField) -> Option<String> {
                     | LitKind::DollarQuotedString(_)
             ) =>
         {
-            Some(field.syntax().text().to_string())
+            field.literal()
         }
-        ast::ExtractField::ExtractFieldName(name) => Some(quote_string_literal(&name.text())),
+        ast::ExtractField::ExtractFieldName(name) => Some(make::string_literal(&name.text())),
         ast::ExtractField::ExtractFieldLiteral(_) => None,
     }
 }
@@ -1,11 +1,13 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::{
+    ast::{self, AstNode, make},
+    syntax_editor::Position,
+};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_from(
     db: &dyn Db,
@@ -21,12 +23,21 @@ pub(super) fn rewrite_from(
 
     select.from_clause()?;
 
+    let mut editor = editor(db, position.file_id);
+    editor.insert_all(
+        Position::before(select),
+        vec![
+            make::select_clause([make::target_star()])
+                .syntax()
+                .clone()
+                .into(),
+            make::tokens::single_space().into(),
+        ],
+    );
+
     actions.push(CodeAction {
         title: "Insert leading `select *`".to_owned(),
-        edits: vec![Edit::insert(
-            "select * ".to_owned(),
-            select.syntax().text_range().start(),
-        )],
+        edits: editor.finish(),
         kind: ActionKind::QuickFix,
     });
 
@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode, BinOp};
+use squawk_syntax::ast::{self, AstNode, BinOp, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_in_as_expression(
     db: &dyn Db,
@@ -26,19 +25,17 @@ pub(super) fn rewrite_in_as_expression(
     let ast::Expr::TupleExpr(tuple) = in_expr.rhs()? else {
         return None;
     };
-    let items = tuple
-        .exprs()
-        .map(|expr| expr.syntax().text().to_string())
-        .collect::<Vec<_>>()
-        .join(", ");
-    let replacement = format!(
-        "{} {comparison} {quantifier} (ARRAY[{items}])",
-        lhs.syntax().text()
+    let array = make::paren_expr(make::array_expr(tuple.exprs()).into());
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace(
+        in_expr,
+        make::bin_expr(lhs, &format!("{comparison} {quantifier}"), array.into()),
     );
 
     actions.push(CodeAction {
         title: "Rewrite `IN` as an expression".to_owned(),
-        edits: vec![Edit::replace(in_expr.syntax().text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
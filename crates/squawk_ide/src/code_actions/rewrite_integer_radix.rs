@@ -1,8 +1,7 @@
 use num_bigint::BigUint;
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::SyntaxKind;
+use squawk_syntax::{SyntaxKind, ast::make};
 
 use crate::{
     file::InFile,
@@ -10,7 +9,7 @@ use crate::{
     offsets::token_from_offset,
 };
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 impl IntegerRadix {
     const ALL: [Self; 4] = [Self::Binary, Self::Octal, Self::Decimal, Self::Hexadecimal];
@@ -55,10 +54,14 @@ pub(super) fn rewrite_integer_radix(
             continue;
         }
 
-        let replacement = target_radix.format(&value);
+        let mut editor = editor(db, position.file_id);
+        editor.replace(
+            token.clone(),
+            make::tokens::int_number(&target_radix.format(&value)),
+        );
         actions.push(CodeAction {
             title: format!("Rewrite integer as {}", target_radix.name()),
-            edits: vec![Edit::replace(token.text_range(), replacement)],
+            edits: editor.finish(),
             kind: ActionKind::RefactorRewrite,
         });
     }
@@ -78,7 +81,8 @@ mod tests {
 
     use crate::{
         code_actions::test_utils::{
-            apply_code_action, code_action_not_applicable, code_action_not_applicable_with_errors,
+            apply_code_action, apply_edits, code_action_not_applicable,
+            code_action_not_applicable_with_errors,
         },
         test_utils::Fixture,
     };
@@ -87,15 +91,16 @@ mod tests {
 
     fn available_actions(sql: &str) -> String {
         let fixture = Fixture::new(sql);
+        let offset = fixture.marker().offset_before();
+        let content = offset.file_id.content(fixture.db());
         let mut actions = vec![];
-        rewrite_integer_radix(fixture.db(), fixture.marker().offset_before(), &mut actions);
+        rewrite_integer_radix(fixture.db(), offset, &mut actions);
 
         actions
             .iter()
             .map(|action| {
-                let edit = action.edits.first().expect("expected edit");
-                let replacement = edit.text.as_deref().expect("expected replacement");
-                format!("{} -> {replacement}", action.title)
+                let result = apply_edits(content, &action.edits);
+                format!("{} -> {result}", action.title)
             })
             .collect::<Vec<_>>()
             .join("\n")
@@ -104,45 +109,45 @@ mod tests {
     #[test]
     fn rewrite_decimal_integer() {
         assert_snapshot!(available_actions("select 100$00;"), @"
-        Rewrite integer as binary -> 0b1111101000
-        Rewrite integer as octal -> 0o1750
-        Rewrite integer as hexadecimal -> 0x3E8
+        Rewrite integer as binary -> select 0b1111101000;
+        Rewrite integer as octal -> select 0o1750;
+        Rewrite integer as hexadecimal -> select 0x3E8;
         ");
     }
 
     #[test]
     fn rewrite_binary_integer() {
         assert_snapshot!(available_actions("select 0b1111101000$0;"), @"
-        Rewrite integer as octal -> 0o1750
-        Rewrite integer as decimal -> 1000
-        Rewrite integer as hexadecimal -> 0x3E8
+        Rewrite integer as octal -> select 0o1750;
+        Rewrite integer as decimal -> select 1000;
+        Rewrite integer as hexadecimal -> select 0x3E8;
         ");
     }
 
     #[test]
     fn rewrite_octal_integer() {
         assert_snapshot!(available_actions("select 0o1750$0;"), @"
-        Rewrite integer as binary -> 0b1111101000
-        Rewrite integer as decimal -> 1000
-        Rewrite integer as hexadecimal -> 0x3E8
+        Rewrite integer as binary -> select 0b1111101000;
+        Rewrite integer as decimal -> select 1000;
+        Rewrite integer as hexadecimal -> select 0x3E8;
         ");
     }
 
     #[test]
     fn rewrite_hexadecimal_integer() {
         assert_snapshot!(available_actions("select 0x3E8$0;"), @"
-        Rewrite integer as binary -> 0b1111101000
-        Rewrite integer as octal -> 0o1750
-        Rewrite integer as decimal -> 1000
+        Rewrite integer as binary -> select 0b1111101000;
+        Rewrite integer as octal -> select 0o1750;
+        Rewrite integer as decimal -> select 1000;
         ");
     }
 
     #[test]
     fn rewrite_integer_with_separators_and_uppercase_prefix() {
         assert_snapshot!(available_actions("select 0X_FF_FF$0;"), @"
-        Rewrite integer as binary -> 0b1111111111111111
-        Rewrite integer as octal -> 0o177777
-        Rewrite integer as decimal -> 65535
+        Rewrite integer as binary -> select 0b1111111111111111;
+        Rewrite integer as octal -> select 0o177777;
+        Rewrite integer as decimal -> select 65535;
         ");
     }
 
@@ -151,9 +156,9 @@ mod tests {
         assert_snapshot!(available_actions(
             "select 340282366920938463463374607431768211456$0;"
         ), @"
-        Rewrite integer as binary -> 0b100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000
-        Rewrite integer as octal -> 0o4000000000000000000000000000000000000000000
-        Rewrite integer as hexadecimal -> 0x100000000000000000000000000000000
+        Rewrite integer as binary -> select 0b100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000;
+        Rewrite integer as octal -> select 0o4000000000000000000000000000000000000000000;
+        Rewrite integer as hexadecimal -> select 0x100000000000000000000000000000000;
         ");
     }
 
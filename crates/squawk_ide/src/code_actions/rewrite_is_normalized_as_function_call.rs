@@ -1,14 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::{
-    ast::{self, AstNode, PostfixOp},
-    quote::quote_string_literal,
-};
+use squawk_syntax::ast::{self, AstNode, PostfixOp, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_is_normalized_as_function_call(
     db: &dyn Db,
@@ -25,22 +21,22 @@ pub(super) fn rewrite_is_normalized_as_function_call(
     };
 
     let expr = postfix_expr.expr()?;
-    let expr_text = expr.syntax().text();
-    let normal_form = normal_form.map(|form| form.syntax().text().to_string().to_ascii_uppercase());
-
-    let arguments = match normal_form {
-        Some(form) => format!("{expr_text}, {}", quote_string_literal(&form)),
-        None => expr_text.to_string(),
-    };
-    let negation = if negated { "not " } else { "" };
-    let replacement = format!("{negation}is_normalized({arguments})");
+    let args = std::iter::once(expr).chain(normal_form.map(|form| {
+        let form = form.syntax().text().to_string().to_ascii_uppercase();
+        make::string_literal(&form).into()
+    }));
+    let call = make::call_expr("is_normalized", args);
+
+    let mut editor = editor(db, position.file_id);
+    if negated {
+        editor.replace(postfix_expr, make::prefix_expr("not", call.into()));
+    } else {
+        editor.replace(postfix_expr, call);
+    }
 
     actions.push(CodeAction {
         title: "Rewrite as `is_normalized` function call".to_owned(),
-        edits: vec![Edit::replace(
-            postfix_expr.syntax().text_range(),
-            replacement,
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,14 +1,14 @@
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
 use squawk_syntax::{
     SyntaxKind,
-    ast::{self, AstNode},
+    ast::{self, AstNode, make},
+    syntax_editor::Position,
 };
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_leading_from(
     db: &dyn Db,
@@ -25,25 +25,26 @@ pub(super) fn rewrite_leading_from(
         return None;
     }
 
-    let select_text = select_clause.syntax().text().to_string();
-
-    let mut delete_start = select_clause.syntax().text_range().start();
+    let mut delete_start = select_clause.syntax().clone().into();
     if let Some(prev) = select_clause.syntax().prev_sibling_or_token()
         && prev.kind() == SyntaxKind::WHITESPACE
     {
-        delete_start = prev.text_range().start();
+        delete_start = prev;
     }
-    let select_with_ws = TextRange::new(delete_start, select_clause.syntax().text_range().end());
+
+    let mut editor = editor(db, position.file_id);
+    editor.delete_all(delete_start..=select_clause.syntax().clone().into());
+    editor.insert_all(
+        Position::before(from_clause),
+        vec![
+            select_clause.syntax().clone().into(),
+            make::tokens::single_space().into(),
+        ],
+    );
 
     actions.push(CodeAction {
         title: "Swap `from` and `select` clauses".to_owned(),
-        edits: vec![
-            Edit::delete(select_with_ws),
-            Edit::insert(
-                format!("{select_text} "),
-                from_clause.syntax().text_range().start(),
-            ),
-        ],
+        edits: editor.finish(),
         kind: ActionKind::QuickFix,
     });
 
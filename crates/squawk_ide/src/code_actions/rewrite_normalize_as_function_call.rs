@@ -1,15 +1,13 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
 use squawk_syntax::{
     SyntaxKind,
-    ast::{self, AstNode},
-    quote::quote_string_literal,
+    ast::{self, AstNode, make},
 };
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_normalize_as_function_call(
     db: &dyn Db,
@@ -34,7 +32,9 @@ pub(super) fn rewrite_normalize_as_function_call(
             }
             [arg, form] => {
                 arg.expr()?;
-                Some(normal_form(&form.expr()?)?)
+                let form = form.expr()?;
+                let literal = normal_form(&form)?;
+                Some((form, literal))
             }
             _ => return None,
         };
@@ -42,24 +42,22 @@ pub(super) fn rewrite_normalize_as_function_call(
         Some((name, normal_form))
     })?;
 
-    let mut edits = vec![Edit::replace(
-        name.syntax().text_range(),
-        "pg_catalog.normalize",
-    )];
-    if let Some((range, form)) = normal_form {
-        edits.push(Edit::replace(range, quote_string_literal(&form)));
+    let mut editor = editor(db, position.file_id);
+    editor.replace(name, make::field_expr("pg_catalog", "normalize"));
+    if let Some((form, literal)) = normal_form {
+        editor.replace(form, literal);
     }
 
     actions.push(CodeAction {
         title: "Rewrite as function call `pg_catalog.normalize()`".to_owned(),
-        edits,
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
     Some(())
 }
 
-fn normal_form(expr: &ast::Expr) -> Option<(rowan::TextRange, String)> {
+fn normal_form(expr: &ast::Expr) -> Option<ast::Literal> {
     let ast::Expr::NameRef(name) = expr else {
         return None;
     };
@@ -70,10 +68,7 @@ fn normal_form(expr: &ast::Expr) -> Option<(rowan::TextRange, String)> {
     ) {
         return None;
     }
-    Some((
-        expr.syntax().text_range(),
-        token.text().to_ascii_uppercase(),
-    ))
+    Some(make::string_literal(&token.text().to_ascii_uppercase()))
 }
 
 #[cfg(test)]
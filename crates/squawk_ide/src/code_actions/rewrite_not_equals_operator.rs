@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_not_equals_operator(
     db: &dyn Db,
@@ -15,15 +14,20 @@ pub(super) fn rewrite_not_equals_operator(
     let token = token_from_offset(db, position)?;
     let bin_expr = token.parent_ancestors().find_map(ast::BinExpr::cast)?;
 
-    let (op_token, replacement, title) = match bin_expr.op()? {
-        ast::BinOp::Neq(token) => (token, "<>", "Rewrite `!=` as `<>`"),
-        ast::BinOp::Neqb(token) => (token, "!=", "Rewrite `<>` as `!=`"),
+    let (op, title) = match bin_expr.op()? {
+        ast::BinOp::Neq(_) => ("<>", "Rewrite `!=` as `<>`"),
+        ast::BinOp::Neqb(_) => ("!=", "Rewrite `<>` as `!=`"),
         _ => return None,
     };
 
+    let lhs = bin_expr.lhs()?;
+    let rhs = bin_expr.rhs()?;
+    let mut editor = editor(db, position.file_id);
+    editor.replace(bin_expr, make::bin_expr(lhs, op, rhs));
+
     actions.push(CodeAction {
         title: title.to_owned(),
-        edits: vec![Edit::replace(op_token.text_range(), replacement.to_owned())],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode, PostfixOp};
+use squawk_syntax::ast::{self, AstNode, PostfixOp, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_null_predicate(
     db: &dyn Db,
@@ -15,15 +14,22 @@ pub(super) fn rewrite_null_predicate(
     let token = token_from_offset(db, position)?;
     let postfix_expr = token.parent_ancestors().find_map(ast::PostfixExpr::cast)?;
 
-    let (op_token, replacement, title) = match postfix_expr.op()? {
-        PostfixOp::IsNull(token) => (token, "is null", "Rewrite as `IS NULL`"),
-        PostfixOp::NotNull(token) => (token, "is not null", "Rewrite as `IS NOT NULL`"),
+    let (op, title) = match postfix_expr.op()? {
+        PostfixOp::IsNull(_) => ("is", "Rewrite as `IS NULL`"),
+        PostfixOp::NotNull(_) => ("is not", "Rewrite as `IS NOT NULL`"),
         _ => return None,
     };
 
+    let expr = postfix_expr.expr()?;
+    let mut editor = editor(db, position.file_id);
+    editor.replace(
+        postfix_expr,
+        make::bin_expr(expr, op, make::null_literal().into()),
+    );
+
     actions.push(CodeAction {
         title: title.to_owned(),
-        edits: vec![Edit::replace(op_token.text_range(), replacement.to_owned())],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
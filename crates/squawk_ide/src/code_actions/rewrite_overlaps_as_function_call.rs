@@ -1,14 +1,10 @@
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::{
-    SyntaxKind, SyntaxNode,
-    ast::{self, AstNode},
-};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_overlaps_as_function_call(
     db: &dyn Db,
@@ -21,31 +17,25 @@ pub(super) fn rewrite_overlaps_as_function_call(
         .filter_map(ast::BinExpr::cast)
         .find(|expr| matches!(expr.op(), Some(ast::BinOp::Overlaps(_))))?;
 
-    let lhs = pair_tuple_ranges(bin_expr.lhs()?)?;
-    let rhs = pair_tuple_ranges(bin_expr.rhs()?)?;
+    let lhs = pair_tuple(bin_expr.lhs()?)?;
+    let rhs = pair_tuple(bin_expr.rhs()?)?;
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace(
+        bin_expr,
+        make::call_expr("overlaps", lhs.exprs().chain(rhs.exprs())),
+    );
 
     actions.push(CodeAction {
         title: "Rewrite as `overlaps` function call".to_owned(),
-        edits: vec![
-            Edit::replace(
-                TextRange::new(lhs.outer.start(), lhs.l_paren.start()),
-                "overlaps",
-            ),
-            Edit::replace(TextRange::new(lhs.r_paren.start(), rhs.l_paren.end()), ", "),
-        ],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
     Some(())
 }
 
-struct TupleRanges {
-    outer: TextRange,
-    l_paren: TextRange,
-    r_paren: TextRange,
-}
-
-fn pair_tuple_ranges(expr: ast::Expr) -> Option<TupleRanges> {
+fn pair_tuple(expr: ast::Expr) -> Option<ast::TupleExpr> {
     let ast::Expr::TupleExpr(tuple) = expr else {
         return None;
     };
@@ -54,22 +44,7 @@ fn pair_tuple_ranges(expr: ast::Expr) -> Option<TupleRanges> {
         return None;
     }
 
-    tuple_ranges(tuple.syntax())
-}
-
-fn tuple_ranges(tuple: &SyntaxNode) -> Option<TupleRanges> {
-    let l_paren = tuple
-        .children_with_tokens()
-        .find(|element| element.kind() == SyntaxKind::L_PAREN)?;
-    let r_paren = tuple
-        .children_with_tokens()
-        .find(|element| element.kind() == SyntaxKind::R_PAREN)?;
-
-    Some(TupleRanges {
-        outer: tuple.text_range(),
-        l_paren: l_paren.text_range(),
-        r_paren: r_paren.text_range(),
-    })
+    Some(tuple)
 }
 
 #[cfg(test)]
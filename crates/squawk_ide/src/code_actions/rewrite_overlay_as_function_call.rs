@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode, OverlayArgs};
+use squawk_syntax::ast::{self, AstNode, OverlayArgs, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_overlay_as_function_call(
     db: &dyn Db,
@@ -22,22 +21,14 @@ pub(super) fn rewrite_overlay_as_function_call(
     let string = args.string()?;
     let placing = args.placing()?;
     let from = args.from()?;
-    let mut arguments = format!(
-        "{}, {}, {}",
-        string.syntax().text(),
-        placing.syntax().text(),
-        from.syntax().text()
-    );
-    if let Some(for_) = args.for_() {
-        arguments.push_str(&format!(", {}", for_.syntax().text()));
-    }
+    let arguments = [string, placing, from].into_iter().chain(args.for_());
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace(overlay, make::call_expr("overlay", arguments));
 
     actions.push(CodeAction {
         title: "Rewrite as `overlay` function call".to_owned(),
-        edits: vec![Edit::replace(
-            overlay.syntax().text_range(),
-            format!("overlay({arguments})"),
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
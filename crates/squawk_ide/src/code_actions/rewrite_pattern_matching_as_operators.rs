@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode, BinOp};
+use squawk_syntax::ast::{self, AstNode, BinOp, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_pattern_matching_as_operators(
     db: &dyn Db,
@@ -14,51 +13,24 @@ pub(super) fn rewrite_pattern_matching_as_operators(
 ) -> Option<()> {
     let token = token_from_offset(db, position)?;
 
-    let (expr, operator, operator_range, title, needs_similar_to_escape) = token
+    let (expr, operator, title, needs_similar_to_escape) = token
         .parent_ancestors()
         .filter_map(ast::BinExpr::cast)
         .find_map(|expr| {
-            let (operator, operator_range, title, needs_similar_to_escape) = match expr.op()? {
-                BinOp::Like(token) => ("~~", token.text_range(), "Rewrite `LIKE` as `~~`", false),
-                BinOp::NotLike(op) => (
-                    "!~~",
-                    op.syntax().text_range(),
-                    "Rewrite `NOT LIKE` as `!~~`",
-                    false,
-                ),
-                BinOp::Ilike(token) => {
-                    ("~~*", token.text_range(), "Rewrite `ILIKE` as `~~*`", false)
-                }
-                BinOp::NotIlike(op) => (
-                    "!~~*",
-                    op.syntax().text_range(),
-                    "Rewrite `NOT ILIKE` as `!~~*`",
-                    false,
-                ),
-                BinOp::SimilarTo(op) => (
-                    "~",
-                    op.syntax().text_range(),
-                    "Rewrite `SIMILAR TO` as `~`",
-                    true,
-                ),
-                BinOp::NotSimilarTo(op) => (
-                    "!~",
-                    op.syntax().text_range(),
-                    "Rewrite `NOT SIMILAR TO` as `!~`",
-                    true,
-                ),
+            let (operator, title, needs_similar_to_escape) = match expr.op()? {
+                BinOp::Like(_) => ("~~", "Rewrite `LIKE` as `~~`", false),
+                BinOp::NotLike(_) => ("!~~", "Rewrite `NOT LIKE` as `!~~`", false),
+                BinOp::Ilike(_) => ("~~*", "Rewrite `ILIKE` as `~~*`", false),
+                BinOp::NotIlike(_) => ("!~~*", "Rewrite `NOT ILIKE` as `!~~*`", false),
+                BinOp::SimilarTo(_) => ("~", "Rewrite `SIMILAR TO` as `~`", true),
+                BinOp::NotSimilarTo(_) => ("!~", "Rewrite `NOT SIMILAR TO` as `!~`", true),
                 _ => return None,
             };
-            Some((
-                expr,
-                operator,
-                operator_range,
-                title,
-                needs_similar_to_escape,
-            ))
+            Some((expr, operator, title, needs_similar_to_escape))
         })?;
 
-    let rhs = expr.rhs()?;
+    let lhs = expr.lhs()?;
+    let mut rhs = expr.rhs()?;
     let explicit_escape = ast::BinExpr::cast(rhs.syntax().clone()).and_then(|escape_expr| {
         if !matches!(escape_expr.op(), Some(BinOp::Escape(_))) {
             return None;
@@ -66,7 +38,6 @@ pub(super) fn rewrite_pattern_matching_as_operators(
         Some((escape_expr.lhs()?, escape_expr.rhs()?))
     });
 
-    let mut edits = vec![Edit::replace(operator_range, operator.to_owned())];
     if needs_similar_to_escape || explicit_escape.is_some() {
         let function = if needs_similar_to_escape {
             "similar_to_escape"
@@ -74,20 +45,18 @@ pub(super) fn rewrite_pattern_matching_as_operators(
             "like_escape"
         };
         let arguments = match explicit_escape {
-            Some((pattern, escape)) => {
-                format!("{}, {}", pattern.syntax().text(), escape.syntax().text())
-            }
-            None => rhs.syntax().text().to_string(),
+            Some((pattern, escape)) => vec![pattern, escape],
+            None => vec![rhs],
         };
-        edits.push(Edit::replace(
-            rhs.syntax().text_range(),
-            format!("pg_catalog.{function}({arguments})"),
-        ));
+        rhs = make::call_expr(&format!("pg_catalog.{function}"), arguments).into();
     }
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace(expr, make::bin_expr(lhs, operator, rhs));
+
     actions.push(CodeAction {
         title: title.to_owned(),
-        edits,
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_position_as_function_call(
     db: &dyn Db,
@@ -18,16 +17,15 @@ pub(super) fn rewrite_position_as_function_call(
     let needle = position_fn.pos()?;
     let haystack = position_fn.string()?;
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace(
+        position_fn,
+        make::call_expr("pg_catalog.position", [haystack, needle]),
+    );
+
     actions.push(CodeAction {
         title: "Rewrite as function call `pg_catalog.position()`".to_owned(),
-        edits: vec![Edit::replace(
-            position_fn.syntax().text_range(),
-            format!(
-                "pg_catalog.position({}, {})",
-                haystack.syntax().text(),
-                needle.syntax().text()
-            ),
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,12 +1,11 @@
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
 use super::unnest::{unnest_call, unnest_shadowed};
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_rows_from_as_unnest(
     db: &dyn Db,
@@ -33,28 +32,29 @@ pub(super) fn rewrite_rows_from_as_unnest(
     }
     let (first, rest) = calls.split_first()?;
 
-    let rows_from_range = TextRange::new(
-        rows_from.rows_token()?.text_range().start(),
-        rows_from.r_paren_token()?.text_range().end(),
-    );
+    let rows_token = rows_from.rows_token()?;
+    let r_paren_token = rows_from.r_paren_token()?;
 
-    if !rest.is_empty() && unnest_shadowed(db, position.file_id, rows_from_range.start()) {
+    if !rest.is_empty() && unnest_shadowed(db, position.file_id, rows_token.text_range().start()) {
         return None;
     }
 
     let args = calls
         .iter()
         .flat_map(|call| &call.args)
-        .map(|arg| arg.syntax().text().to_string())
-        .collect::<Vec<_>>()
-        .join(", ");
+        .map(|arg| arg.expr())
+        .collect::<Option<Vec<_>>>()?;
+    let unnest = make::call_expr(&first.name.syntax().to_string(), args);
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace_all(
+        rows_token.into()..=r_paren_token.into(),
+        vec![unnest.syntax().clone().into()],
+    );
 
     actions.push(CodeAction {
         title: "Rewrite as `unnest`".to_owned(),
-        edits: vec![Edit::replace(
-            rows_from_range,
-            format!("{}({args})", first.name.syntax().text()),
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_select_as_table(
     db: &dyn Db,
@@ -25,16 +24,22 @@ pub(super) fn rewrite_select_as_table(
     let ast::FromItem::RelationFromItem(relation) = from_item else {
         return None;
     };
-    let table_name = relation.relation_name_ref()?.syntax().text().to_string();
-
-    let mut replacement = format!("table {table_name}");
+    let mut replacement = vec![
+        make::table(relation.relation_name_ref()?)
+            .syntax()
+            .clone()
+            .into(),
+    ];
     if select.semicolon_token().is_some() {
-        replacement.push(';');
+        replacement.push(make::tokens::semicolon().into());
     };
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace_with_many(select, replacement);
+
     actions.push(CodeAction {
         title: "Rewrite as `table`".to_owned(),
-        edits: vec![Edit::replace(select.syntax().text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,7 +1,6 @@
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{
     ast_nav::{self, SelectContext},
@@ -10,7 +9,7 @@ use crate::{
     symbols::Name,
 };
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_select_as_values(
     db: &dyn Db,
@@ -22,22 +21,21 @@ pub(super) fn rewrite_select_as_values(
     let parent = ast_nav::find_select_parent(token)?;
 
     let mut selects = parent.iter()?.peekable();
-    let select_token_start = selects
+    let select_token = selects
         .peek()?
         .select_clause()
-        .and_then(|x| x.select_token())
-        .map(|x| x.text_range().start())?;
+        .and_then(|x| x.select_token())?;
 
     let mut rows = vec![];
     for (idx, select) in selects.enumerate() {
-        let exprs: Vec<String> = select
+        let exprs: Vec<_> = select
             .select_clause()?
             .target_list()?
             .targets()
             .enumerate()
             .map(|(i, t)| {
                 if idx != 0 || is_values_row_column_name(&t, i) {
-                    t.expr().map(|expr| expr.syntax().text().to_string())
+                    t.expr()
                 } else {
                     None
                 }
@@ -48,7 +46,7 @@ pub(super) fn rewrite_select_as_values(
             return None;
         }
 
-        rows.push(format!("({})", exprs.join(", ")));
+        rows.push(make::row(exprs));
     }
 
     let had_semicolon = match &parent {
@@ -56,20 +54,22 @@ pub(super) fn rewrite_select_as_values(
         SelectContext::Single(select) => select.semicolon_token().is_some(),
     };
 
-    let mut values_stmt = format!("values {}", rows.join(", "));
+    let mut values_stmt = vec![make::values(rows).syntax().clone().into()];
     if had_semicolon {
-        values_stmt.push(';');
+        values_stmt.push(make::tokens::semicolon().into());
     }
 
-    let select_end = match &parent {
-        SelectContext::Compound(compound) => compound.syntax().text_range().end(),
-        SelectContext::Single(select) => select.syntax().text_range().end(),
+    let last_token = match &parent {
+        SelectContext::Compound(compound) => compound.syntax().last_token()?,
+        SelectContext::Single(select) => select.syntax().last_token()?,
     };
-    let select_range = TextRange::new(select_token_start, select_end);
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace_all(select_token.into()..=last_token.into(), values_stmt);
 
     actions.push(CodeAction {
         title: "Rewrite as `values`".to_owned(),
-        edits: vec![Edit::replace(select_range, values_stmt)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,14 +1,13 @@
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
 use squawk_syntax::{
     SyntaxKind,
-    ast::{self, AstNode},
+    ast::{self, AstNode, make},
 };
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_select_into_as_create_table_as(
     db: &dyn Db,
@@ -21,44 +20,39 @@ pub(super) fn rewrite_select_into_as_create_table_as(
     let into_clause = select_into.into_clause()?;
 
     // temp, unlogged, etc.
-    let persistence = into_clause
-        .persistence()
-        .map(|x| format!("{} ", x.syntax()))
-        .unwrap_or_default();
-    let create_prefix = format!(
-        "create {}table {} as ",
-        persistence,
-        into_clause.table_name()?.path()?.syntax()
-    );
-    let stmt_start = {
-        let mut start = select_into.syntax().text_range().start();
+    let persistence = into_clause.persistence();
+    let path = into_clause.table_name()?.path()?;
+    let stmt = {
+        let mut stmt = select_into.syntax().clone();
         for parent in select_into.syntax().parent()?.ancestors() {
             if ast::CompoundSelect::can_cast(parent.kind())
                 || ast::ParenSelect::can_cast(parent.kind())
             {
-                start = parent.text_range().start();
+                stmt = parent;
             } else {
                 break;
             }
         }
-        start
-    };
-    let insert_prefix = Edit::insert(create_prefix, stmt_start);
-
-    let delete_into = {
-        let range = into_clause.syntax().text_range();
-        let mut start = range.start();
-        if let Some(prev) = into_clause.syntax().prev_sibling_or_token()
-            && prev.kind() == SyntaxKind::WHITESPACE
-        {
-            start = prev.text_range().start();
-        }
-        Edit::delete(TextRange::new(start, range.end()))
+        stmt
     };
 
+    // the query is the statement without its `into` clause
+    let query = stmt.clone_subtree().clone_for_update();
+    let query_into = query.descendants().find_map(ast::IntoClause::cast)?;
+    if let Some(prev) = query_into.syntax().prev_sibling_or_token()
+        && prev.kind() == SyntaxKind::WHITESPACE
+    {
+        prev.detach();
+    }
+    query_into.syntax().detach();
+    let query = ast::SelectVariant::cast(query)?;
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace(stmt, make::create_table_as(persistence, path, query));
+
     actions.push(CodeAction {
         title: "Rewrite as `create table as`".to_owned(),
-        edits: vec![delete_into, insert_prefix],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
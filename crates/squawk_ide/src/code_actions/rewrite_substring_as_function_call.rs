@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode, SubstringArgs};
+use squawk_syntax::ast::{self, AstNode, SubstringArgs, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_substring_as_function_call(
     db: &dyn Db,
@@ -20,13 +19,8 @@ pub(super) fn rewrite_substring_as_function_call(
             let string = args.string()?;
             let start = args.start()?;
             match args.count() {
-                Some(count) => format!(
-                    "{}, {}, {}",
-                    string.syntax().text(),
-                    start.syntax().text(),
-                    count.syntax().text()
-                ),
-                None => format!("{}, {}", string.syntax().text(), start.syntax().text()),
+                Some(count) => vec![string, start, count],
+                None => vec![string, start],
             }
         }
         SubstringArgs::SubstringForFrom(args) => {
@@ -34,29 +28,21 @@ pub(super) fn rewrite_substring_as_function_call(
             let count = args.count()?;
             let start = args
                 .start()
-                .map(|start| start.syntax().text().to_string())
-                .unwrap_or_else(|| "1".to_owned());
-            format!(
-                "{}, {start}, {}",
-                string.syntax().text(),
-                count.syntax().text()
-            )
+                .unwrap_or_else(|| make::number_literal("1").into());
+            vec![string, start, count]
+        }
+        SubstringArgs::SubstringSimilarEscape(args) => {
+            vec![args.string()?, args.pattern()?, args.escape()?]
         }
-        SubstringArgs::SubstringSimilarEscape(args) => format!(
-            "{}, {}, {}",
-            args.string()?.syntax().text(),
-            args.pattern()?.syntax().text(),
-            args.escape()?.syntax().text()
-        ),
         SubstringArgs::SubstringExprs(_) => return None,
     };
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace(substring, make::call_expr("substring", arguments));
+
     actions.push(CodeAction {
         title: "Rewrite as `substring` function call".to_owned(),
-        edits: vec![Edit::replace(
-            substring.syntax().text_range(),
-            format!("substring({arguments})"),
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
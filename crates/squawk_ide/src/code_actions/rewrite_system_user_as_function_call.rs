@@ -1,14 +1,13 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
 use squawk_syntax::{
     SyntaxKind,
-    ast::{self, AstNode},
+    ast::{self, AstNode, make},
 };
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_system_user_as_function_call(
     db: &dyn Db,
@@ -27,12 +26,12 @@ pub(super) fn rewrite_system_user_as_function_call(
         return None;
     }
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace(name, make::call_expr("pg_catalog.system_user", []));
+
     actions.push(CodeAction {
         title: "Rewrite as function call `pg_catalog.system_user()`".to_owned(),
-        edits: vec![Edit::replace(
-            name.syntax().text_range(),
-            "pg_catalog.system_user()",
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_table_as_select(
     db: &dyn Db,
@@ -15,16 +14,23 @@ pub(super) fn rewrite_table_as_select(
     let token = token_from_offset(db, position)?;
     let table = token.parent_ancestors().find_map(ast::Table::cast)?;
 
-    let table_name = table.relation_name()?.syntax().text();
+    let from_item = make::relation_from_item(table.relation_name()?);
+    let select = make::select(
+        [make::target_star()],
+        Some(make::from_clause([from_item.into()])),
+    );
 
-    let mut replacement = format!("select * from {table_name}");
+    let mut replacement = vec![select.syntax().clone().into()];
     if table.semicolon_token().is_some() {
-        replacement.push(';');
+        replacement.push(make::tokens::semicolon().into());
     };
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace_with_many(table, replacement);
+
     actions.push(CodeAction {
         title: "Rewrite as `select`".to_owned(),
-        edits: vec![Edit::replace(table.syntax().text_range(), replacement)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
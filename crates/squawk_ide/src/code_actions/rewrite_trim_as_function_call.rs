@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode, TrimArgs, TrimSide};
+use squawk_syntax::ast::{self, AstNode, TrimArgs, TrimSide, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_trim_as_function_call(
     db: &dyn Db,
@@ -38,18 +37,12 @@ pub(super) fn rewrite_trim_as_function_call(
         return None;
     }
 
-    let arguments = expressions
-        .iter()
-        .map(|expr| expr.syntax().text().to_string())
-        .collect::<Vec<_>>()
-        .join(", ");
+    let mut editor = editor(db, position.file_id);
+    editor.replace(trim, make::call_expr(function, expressions));
 
     actions.push(CodeAction {
         title: format!("Rewrite as `{function}` function call"),
-        edits: vec![Edit::replace(
-            trim.syntax().text_range(),
-            format!("{function}({arguments})"),
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
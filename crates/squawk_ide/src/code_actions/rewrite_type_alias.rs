@@ -0,0 +1,200 @@
+use rowan::TextSize;
+use salsa::Database as Db;
+use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::type_alias::{builtin_type_name, rename_builtin_type};
+
+use crate::db::canonical_type_name;
+use crate::{file::InFile, offsets::token_from_offset};
+
+use super::{ActionKind, CodeAction, editor};
+
+pub(super) fn rewrite_type_alias(
+    db: &dyn Db,
+    position: InFile<TextSize>,
+    actions: &mut Vec<CodeAction>,
+) -> Option<()> {
+    let token = token_from_offset(db, position)?;
+    let ty = token.parent_ancestors().find_map(ast::Type::cast)?;
+
+    let builtin = builtin_type_name(&ty)?;
+    let name = canonical_type_name(db, builtin.ty);
+    if builtin.name == name {
+        return None;
+    }
+
+    let mut editor = editor(db, position.file_id);
+    let renamed = rename_builtin_type(&ty, name);
+    editor.replace(ty, renamed);
+
+    actions.push(CodeAction {
+        title: format!("Rewrite as `{name}`"),
+        edits: editor.finish(),
+        kind: ActionKind::RefactorRewrite,
+    });
+
+    Some(())
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use std::collections::BTreeMap;
+
+    use squawk_syntax::type_alias::BuiltinType;
+
+    use crate::code_actions::test_utils::{
+        apply_code_action, apply_edits, code_action_not_applicable,
+    };
+    use crate::db::set_canonical_types;
+    use crate::test_utils::Fixture;
+
+    use super::rewrite_type_alias;
+
+    #[test]
+    fn rewrite_timestamp_without_tz_column() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "create table t(a time$0stamp without time zone);"),
+            @"create table t(a timestamp);"
+        );
+    }
+
+    #[test]
+    fn rewrite_timestamp_without_tz_cast() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "select timestamp$0 without time zone '2021-01-01';"),
+            @"select timestamp '2021-01-01';"
+        );
+    }
+
+    #[test]
+    fn rewrite_time_without_tz() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "create table t(a ti$0me without time zone);"),
+            @"create table t(a time);"
+        );
+    }
+
+    #[test]
+    fn rewrite_timestamp_without_tz_not_applicable_plain() {
+        assert!(code_action_not_applicable(
+            rewrite_type_alias,
+            "create table t(a time$0stamp);"
+        ));
+    }
+
+    #[test]
+    fn rewrite_timestamp_with_tz_column() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "create table t(a time$0stamp with time zone);"),
+            @"create table t(a timestamptz);"
+        );
+    }
+
+    #[test]
+    fn rewrite_timestamp_with_tz_cast() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "select timestamp$0 with time zone '2021-01-01';"),
+            @"select timestamptz '2021-01-01';"
+        );
+    }
+
+    #[test]
+    fn rewrite_time_with_tz() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "create table t(a ti$0me with time zone);"),
+            @"create table t(a timetz);"
+        );
+    }
+
+    #[test]
+    fn rewrite_timestamp_with_tz_precision() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "create table t(a time$0stamp(3) with time zone);"),
+            @"create table t(a timestamptz(3));"
+        );
+    }
+
+    #[test]
+    fn rewrite_int4() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "select 1::in$0t4;"),
+            @"select 1::integer;"
+        );
+    }
+
+    #[test]
+    fn rewrite_int4_array() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "create table t(a in$0t4[]);"),
+            @"create table t(a integer[]);"
+        );
+    }
+
+    #[test]
+    fn rewrite_bool() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "create function f(bo$0ol) returns setof bool language sql as 'select true';"),
+            @"create function f(boolean) returns setof bool language sql as 'select true';"
+        );
+    }
+
+    #[test]
+    fn rewrite_character_varying() {
+        assert_snapshot!(apply_code_action(
+            rewrite_type_alias,
+            "create table t(a character var$0ying(100));"),
+            @"create table t(a varchar(100));"
+        );
+    }
+
+    #[test]
+    fn rewrite_type_alias_canonical_types() {
+        let mut fixture = Fixture::new("create table t(a time$0stamptz, b int4);");
+        set_canonical_types(
+            fixture.db_mut(),
+            BTreeMap::from([(BuiltinType::TimestampTz, "timestamp with time zone")]),
+        );
+        let offset = fixture.marker().offset_before();
+        let db = fixture.db();
+        let mut actions = vec![];
+        rewrite_type_alias(db, offset, &mut actions);
+        assert_snapshot!(
+            apply_edits(offset.file_id.content(db), &actions[0].edits),
+            @"create table t(a timestamp with time zone, b int4);"
+        );
+    }
+
+    #[test]
+    fn rewrite_type_alias_not_applicable() {
+        // already canonical
+        assert!(code_action_not_applicable(
+            rewrite_type_alias,
+            "create table t(a integ$0er);"
+        ));
+        // quoted names skip the grammar's aliases, `"char"` is the single
+        // byte internal type rather than `character`
+        assert!(code_action_not_applicable(
+            rewrite_type_alias,
+            r#"create table t(a "ch$0ar");"#
+        ));
+        assert!(code_action_not_applicable(
+            rewrite_type_alias,
+            r#"create table t(a "int$04");"#
+        ));
+        assert!(code_action_not_applicable(
+            rewrite_type_alias,
+            "create table t(a te$0xt);"
+        ));
+    }
+}
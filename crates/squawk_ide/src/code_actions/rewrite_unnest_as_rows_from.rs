@@ -1,12 +1,11 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
 use super::unnest::{unnest_call, unnest_shadowed};
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_unnest_as_rows_from(
     db: &dyn Db,
@@ -32,22 +31,27 @@ pub(super) fn rewrite_unnest_as_rows_from(
         return None;
     }
 
-    let call_range = call_expr.syntax().text_range();
-    if unnest_shadowed(db, position.file_id, call_range.start()) {
+    if unnest_shadowed(
+        db,
+        position.file_id,
+        call_expr.syntax().text_range().start(),
+    ) {
         return None;
     }
 
-    let name = call.name.syntax().text();
+    let name = call.name.syntax().to_string();
     let calls = call
         .args
         .iter()
-        .map(|arg| format!("{name}({})", arg.syntax().text()))
-        .collect::<Vec<_>>()
-        .join(", ");
+        .map(|arg| Some(make::call_expr(&name, [arg.expr()?])))
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut editor = editor(db, position.file_id);
+    editor.replace(call_expr, make::rows_from_item(calls));
 
     actions.push(CodeAction {
         title: "Rewrite as `rows from`".to_owned(),
-        edits: vec![Edit::replace(call_range, format!("rows from ({calls})"))],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,12 +1,10 @@
-use itertools::Itertools;
-use rowan::{TextRange, TextSize};
+use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
-use crate::{db::line_ending, file::InFile, offsets::token_from_offset};
+use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_values_as_select(
     db: &dyn Db,
@@ -16,11 +14,9 @@ pub(super) fn rewrite_values_as_select(
     let token = token_from_offset(db, position)?;
     let values = token.parent_ancestors().find_map(ast::Values::cast)?;
 
-    let value_token_start = values.values_token().map(|x| x.text_range().start())?;
-    let values_end = values.syntax().text_range().end();
     // `values` but we skip over the possibly preceeding CTE
-    let values_range = TextRange::new(value_token_start, values_end);
-    let line_ending = line_ending(db, position.file_id);
+    let values_token = values.values_token()?;
+    let last_token = values.syntax().last_token()?;
 
     let mut rows = values.row_list()?.rows();
 
@@ -28,35 +24,34 @@ pub(super) fn rewrite_values_as_select(
         .next()?
         .exprs()
         .enumerate()
-        .map(|(idx, expr)| format!("{} as column{}", expr.syntax().text(), idx + 1))
+        .map(|(idx, expr)| make::target(expr, Some(&format!("column{}", idx + 1))))
         .collect();
 
     if first_targets.is_empty() {
         return None;
     }
 
-    let mut select_parts = vec![format!("select {}", first_targets.join(", "))];
+    let mut selects = vec![make::select(first_targets, None)];
 
     for row in rows {
-        let row_targets = row
-            .exprs()
-            .map(|e| e.syntax().text().to_string())
-            .join(", ");
+        let row_targets: Vec<_> = row.exprs().map(|e| make::target(e, None)).collect();
         if row_targets.is_empty() {
             return None;
         }
-        select_parts.push("union all".to_owned());
-        select_parts.push(format!("select {row_targets}"));
+        selects.push(make::select(row_targets, None));
     }
 
-    let mut select_stmt = select_parts.join(line_ending);
+    let mut select_stmt = vec![make::union_all(selects).syntax().clone().into()];
     if values.semicolon_token().is_some() {
-        select_stmt.push(';');
+        select_stmt.push(make::tokens::semicolon().into());
     }
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace_all(values_token.into()..=last_token.into(), select_stmt);
+
     actions.push(CodeAction {
         title: "Rewrite as `select`".to_owned(),
-        edits: vec![Edit::replace(values_range, select_stmt)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -1,11 +1,10 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_linter::Edit;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn rewrite_xmlexists_as_function_call(
     db: &dyn Db,
@@ -18,16 +17,15 @@ pub(super) fn rewrite_xmlexists_as_function_call(
     let xpath = passing.row()?;
     let xml = passing.xml_passing_doc()?.expr()?;
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace(
+        xml_exists,
+        make::call_expr("pg_catalog.xmlexists", [xpath, xml]),
+    );
+
     actions.push(CodeAction {
         title: "Rewrite as function call `pg_catalog.xmlexists()`".to_owned(),
-        edits: vec![Edit::replace(
-            xml_exists.syntax().text_range(),
-            format!(
-                "pg_catalog.xmlexists({}, {})",
-                xpath.syntax().text(),
-                xml.syntax().text()
-            ),
-        )],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -48,19 +48,7 @@ fn apply_code_action_(
 
     let action = &actions[0];
 
-    let mut result = file.content(db).to_string();
-
-    let mut edits = action.edits.clone();
-    edits.sort_by_key(|e| e.text_range.start());
-    check_overlap(&edits);
-    edits.reverse();
-
-    for edit in edits {
-        let start: usize = edit.text_range.start().into();
-        let end: usize = edit.text_range.end().into();
-        let replacement = edit.text.as_deref().unwrap_or("");
-        result.replace_range(start..end, replacement);
-    }
+    let result = apply_edits(file.content(db), &action.edits);
 
     let reparse = ast::SourceFile::parse(&result);
 
@@ -80,6 +68,25 @@ fn apply_code_action_(
     result
 }
 
+#[must_use]
+pub(super) fn apply_edits(text: &str, edits: &[Edit]) -> String {
+    let mut result = text.to_string();
+
+    let mut edits = edits.to_vec();
+    edits.sort_by_key(|e| e.text_range.start());
+    check_overlap(&edits);
+    edits.reverse();
+
+    for edit in edits {
+        let start: usize = edit.text_range.start().into();
+        let end: usize = edit.text_range.end().into();
+        let replacement = edit.text.as_deref().unwrap_or("");
+        result.replace_range(start..end, replacement);
+    }
+
+    result
+}
+
 // There's an invariant where the edits can't overlap.
 // For example, if we have an edit that deletes the full `else clause` and
 // another edit that deletes the `else` keyword and they overlap, then
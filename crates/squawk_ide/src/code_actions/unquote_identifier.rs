@@ -1,13 +1,12 @@
 use rowan::TextSize;
 use salsa::Database as Db;
-use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::ast::{self, AstNode, make};
 
-use squawk_linter::Edit;
 use squawk_syntax::quote::unquote_ident;
 
 use crate::{file::InFile, offsets::token_from_offset};
 
-use super::{ActionKind, CodeAction};
+use super::{ActionKind, CodeAction, editor};
 
 pub(super) fn unquote_identifier(
     db: &dyn Db,
@@ -21,9 +20,12 @@ pub(super) fn unquote_identifier(
 
     let unquoted = unquote_ident(&name_node)?;
 
+    let mut editor = editor(db, position.file_id);
+    editor.replace(name_node, make::tokens::ident(&unquoted));
+
     actions.push(CodeAction {
         title: "Unquote identifier".to_owned(),
-        edits: vec![Edit::replace(name_node.text_range(), unquoted)],
+        edits: editor.finish(),
         kind: ActionKind::RefactorRewrite,
     });
 
@@ -149,6 +151,11 @@ mod test {
             unquote_identifier,
             r#"create table t ("left"$0 int);"#
         ));
+        // column name word, `"char"` and `char` are different types
+        assert!(code_action_not_applicable(
+            unquote_identifier,
+            r#"create table t (a "char"$0);"#
+        ));
     }
 
     #[test]
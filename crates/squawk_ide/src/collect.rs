@@ -1,5 +1,5 @@
 use crate::ast_nav;
-use crate::db::{File, bind, list_files, parse};
+use crate::db::{File, bind, list_files, ptr_to_node};
 use crate::file::InFile;
 use crate::goto_definition::goto_definition;
 use crate::infer::{Type, infer_type_from_expr, infer_type_from_ty};
@@ -356,8 +356,7 @@ fn table_query_columns_with_types(
     // Try CTE resolution first since resolve_table_name doesn't handle CTEs
     if let Some((ptr, kind)) = resolve_table_like(db, Some(&name_ref), &table_name, &schemas, file)
     {
-        let tree = parse(db, file).tree();
-        let node = ptr.to_node(tree.syntax());
+        let node = ptr_to_node(db, file, &ptr);
         match kind {
             LocationKind::Table => {
                 if let Some(with_table) = node.ancestors().find_map(ast::WithTable::cast) {
@@ -736,9 +735,7 @@ fn columns_for_star_from_table_ptr(
     file: File,
     table_ptr: &SyntaxNodePtr,
 ) -> Vec<(Name, Option<Type>)> {
-    let tree = parse(db, file).tree();
-    let root = tree.syntax();
-    let table_node = table_ptr.to_node(root);
+    let table_node = ptr_to_node(db, file, table_ptr);
 
     match ast_nav::parent_source(&table_node) {
         Some(ast_nav::ParentSouce::Alias(alias)) => {
@@ -827,9 +824,7 @@ fn select_variant_columns_with_types(
 }
 
 pub(crate) fn star_column_names(db: &dyn Db, file: File, table_ptr: &SyntaxNodePtr) -> Vec<Name> {
-    let source_file = parse(db, file).tree();
-    let root = source_file.syntax();
-    let table_name_node = table_ptr.to_node(root);
+    let table_name_node = ptr_to_node(db, file, table_ptr);
 
     match ast_nav::parent_source(&table_name_node) {
         Some(ast_nav::ParentSouce::Alias(alias)) => alias
@@ -1,12 +1,12 @@
 use rowan::TextSize;
 use salsa::Database as Db;
 use squawk_syntax::ast::{self, AstNode};
-use squawk_syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
+use squawk_syntax::{SyntaxKind, SyntaxNode, SyntaxNodePtr, SyntaxToken};
 
 use crate::ast_nav;
 use crate::binder;
 use crate::collect;
-use crate::db::{File, bind, parse};
+use crate::db::{File, bind, parse, ptr_to_node, source_files};
 use crate::file::InFile;
 use crate::name::{self, Name, Schema};
 use crate::resolve;
@@ -22,7 +22,12 @@ pub fn completion(db: &dyn Db, position: InFile<TextSize>) -> Vec<CompletionItem
     let source_file = parse.tree();
 
     let marker_file = file_with_completion_marker(&source_file, offset);
-    let Some(token) = token_at_offset(&marker_file, offset) else {
+    // inside a PL/pgSQL body we complete from the body's tree
+    let root = match marker_file.plpgsql_body_at(offset) {
+        Some(body) => body.tree().syntax().clone(),
+        None => marker_file.syntax().clone(),
+    };
+    let Some(token) = token_at_offset(&root, offset) else {
         // empty file
         return default_completions();
     };
@@ -35,7 +40,7 @@ pub fn completion(db: &dyn Db, position: InFile<TextSize>) -> Vec<CompletionItem
     }
 
     match completion_context(&token) {
-        CompletionContext::TableOnly => table_completions(&marker_file, &token),
+        CompletionContext::TableOnly => table_completions(db, file, &marker_file, &token),
         CompletionContext::Default => default_completions(),
         CompletionContext::SelectClause(select_clause) => {
             select_completions(db, file, select_clause, &token)
@@ -45,7 +50,7 @@ pub fn completion(db: &dyn Db, position: InFile<TextSize>) -> Vec<CompletionItem
         CompletionContext::LimitClause => limit_completions(db, file, &token),
         CompletionContext::OffsetClause => offset_completions(db, file, &token),
         CompletionContext::DeleteClauses(delete) => {
-            delete_clauses_completions(&marker_file, &delete, &token)
+            delete_clauses_completions(db, file, &marker_file, &delete, &token)
         }
         CompletionContext::DeleteExpr(delete) => {
             delete_expr_completions(db, file, &marker_file, &delete, &token)
@@ -66,7 +71,7 @@ fn select_completions(
 
     completions.extend(function_completions(db, file, schema.as_ref(), position));
 
-    let tables = binder.all_symbols_by_kind(SymbolKind::Table, schema.as_ref());
+    let tables = symbol_names(db, file, &binder, SymbolKind::Table, schema.as_ref());
     completions.extend(tables.into_iter().map(|name| CompletionItem {
         label: name.to_string(),
         kind: CompletionItemKind::Table,
@@ -78,7 +83,8 @@ fn select_completions(
     }));
 
     if schema.is_none() {
-        completions.extend(schema_completions(&binder));
+        completions.extend(schema_completions(db, file, &binder));
+        completions.extend(plpgsql_variable_completions(token));
     }
 
     if let Some(parent) = select_clause.syntax().parent()
@@ -314,6 +320,43 @@ fn select_expr_completions(
         completions.extend(column_completions_from_clause(db, file, &from_clause));
     }
 
+    if schema.is_none() {
+        completions.extend(plpgsql_variable_completions(token));
+    }
+
+    completions
+}
+
+// Inside a PL/pgSQL body, the variables in scope can be used in queries.
+fn plpgsql_variable_completions(token: &SyntaxToken) -> Vec<CompletionItem> {
+    let Some(parent) = token.parent() else {
+        return vec![];
+    };
+    let mut seen = vec![];
+    let mut completions = vec![];
+    for variable in resolve::plpgsql_variables_in_scope(&parent) {
+        let name = Name::from_node(&variable);
+        // inner declarations shadow outer ones
+        if seen.contains(&name) {
+            continue;
+        }
+        let detail = variable
+            .syntax()
+            .parent()
+            .and_then(ast::PlpgsqlVarDecl::cast)
+            .and_then(|decl| decl.ty())
+            .map(|ty| ty.syntax().to_string());
+        completions.push(CompletionItem {
+            label: name.to_string(),
+            kind: CompletionItemKind::Variable,
+            detail,
+            insert_text: None,
+            insert_text_format: None,
+            trigger_completion_after_insert: false,
+            sort_text: None,
+        });
+        seen.push(name);
+    }
     completions
 }
 
@@ -324,13 +367,12 @@ fn function_completions(
     position: TextSize,
 ) -> Vec<CompletionItem> {
     let binder = bind(db, file);
-    binder
-        .all_symbols_by_kind(SymbolKind::Function, schema)
+    symbol_names(db, file, &binder, SymbolKind::Function, schema)
         .into_iter()
         .map(|name| CompletionItem {
             label: format!("{name}()"),
             kind: CompletionItemKind::Function,
-            detail: function_detail(db, file, name, schema, position),
+            detail: function_detail(db, file, &name, schema, position),
             insert_text: None,
             insert_text_format: None,
             trigger_completion_after_insert: false,
@@ -347,7 +389,7 @@ fn column_completions_from_clause(
     let mut completions = vec![];
     let syntax_root = from_clause.syntax().ancestors().last().unwrap();
     for table_ptr in resolve::table_ptrs_from_clause(db, InFile::new(file, from_clause)) {
-        let table_node = table_ptr.to_node(&syntax_root);
+        let table_node = ptr_to_node_in(db, file, &syntax_root, &table_ptr);
         match ast_nav::parent_source(&table_node) {
             Some(ast_nav::ParentSouce::CreateTable(create_table)) => {
                 let columns = collect::table_columns(db, file, &create_table);
@@ -466,6 +508,18 @@ fn column_completions_from_clause(
     completions
 }
 
+// The pointer is into the tree we're completing in or, inside a PL/pgSQL body,
+// can be into the file's tree, e.g., a table defined outside of the body.
+fn ptr_to_node_in(
+    db: &dyn Db,
+    file: File,
+    syntax_root: &SyntaxNode,
+    ptr: &SyntaxNodePtr,
+) -> SyntaxNode {
+    ptr.try_to_node(syntax_root)
+        .unwrap_or_else(|| ptr_to_node(db, file, ptr))
+}
+
 fn alias_base_columns_with_types(
     db: &dyn Db,
     file: File,
@@ -480,7 +534,7 @@ fn alias_base_columns_with_types(
         return vec![];
     };
 
-    let table_node = table_ptr.to_node(syntax_root);
+    let table_node = ptr_to_node_in(db, file, syntax_root, &table_ptr);
 
     match ast_nav::parent_source(&table_node) {
         Some(ast_nav::ParentSouce::CreateTable(create_table)) => {
@@ -523,7 +577,31 @@ fn alias_base_columns_with_types(
     }
 }
 
-fn schema_completions(binder: &binder::Binder) -> Vec<CompletionItem> {
+/// Names of `kind` defined in the file, along with the ones from the files it
+/// can see, e.g., the tables created by earlier migrations.
+fn symbol_names(
+    db: &dyn Db,
+    file: File,
+    binder: &binder::Binder,
+    kind: SymbolKind,
+    schema: Option<&Schema>,
+) -> Vec<Name> {
+    let mut names: Vec<Name> = binder
+        .all_symbols_by_kind(kind, schema)
+        .into_iter()
+        .cloned()
+        .collect();
+    for other in source_files(db, file).skip(1) {
+        for name in bind(db, other).all_symbols_by_kind(kind, schema) {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+    }
+    names
+}
+
+fn schema_completions(db: &dyn Db, file: File, binder: &binder::Binder) -> Vec<CompletionItem> {
     let builtin_schemas = [
         "public",
         "pg_catalog",
@@ -545,7 +623,7 @@ fn schema_completions(binder: &binder::Binder) -> Vec<CompletionItem> {
         })
         .collect();
 
-    for name in binder.all_symbols_by_kind(SymbolKind::Schema, None) {
+    for name in symbol_names(db, file, binder, SymbolKind::Schema, None) {
         completions.push(CompletionItem {
             label: name.to_string(),
             kind: CompletionItemKind::Schema,
@@ -560,10 +638,15 @@ fn schema_completions(binder: &binder::Binder) -> Vec<CompletionItem> {
     completions
 }
 
-fn table_completions(file: &ast::SourceFile, token: &SyntaxToken) -> Vec<CompletionItem> {
-    let binder = binder::bind(file);
+fn table_completions(
+    db: &dyn Db,
+    file: File,
+    source_file: &ast::SourceFile,
+    token: &SyntaxToken,
+) -> Vec<CompletionItem> {
+    let binder = binder::bind(source_file);
     let schema = schema_qualifier_at_token(token);
-    let tables = binder.all_symbols_by_kind(SymbolKind::Table, schema.as_ref());
+    let tables = symbol_names(db, file, &binder, SymbolKind::Table, schema.as_ref());
     let mut completions: Vec<CompletionItem> = tables
         .into_iter()
         .map(|name| CompletionItem {
@@ -578,14 +661,16 @@ fn table_completions(file: &ast::SourceFile, token: &SyntaxToken) -> Vec<Complet
         .collect();
 
     if schema.is_none() {
-        completions.extend(schema_completions(&binder));
+        completions.extend(schema_completions(db, file, &binder));
     }
 
     completions
 }
 
 fn delete_clauses_completions(
-    file: &ast::SourceFile,
+    db: &dyn Db,
+    file: File,
+    source_file: &ast::SourceFile,
     delete: &ast::Delete,
     token: &SyntaxToken,
 ) -> Vec<CompletionItem> {
@@ -593,7 +678,7 @@ fn delete_clauses_completions(
 
     // `delete from $0`
     if token.kind() == SyntaxKind::FROM_KW {
-        return table_completions(file, token);
+        return table_completions(db, file, source_file, token);
     }
 
     if delete.using_clause().is_none() {
@@ -673,11 +758,11 @@ fn delete_expr_completions(
             sort_text: None,
         }));
     } else {
-        let functions = binder.all_symbols_by_kind(SymbolKind::Function, None);
+        let functions = symbol_names(db, file, &binder, SymbolKind::Function, None);
         completions.extend(functions.into_iter().map(|name| CompletionItem {
             label: format!("{name}()"),
             kind: CompletionItemKind::Function,
-            detail: function_detail(db, file, name, schema.as_ref(), position),
+            detail: function_detail(db, file, &name, schema.as_ref(), position),
             insert_text: None,
             insert_text_format: None,
             trigger_completion_after_insert: false,
@@ -697,12 +782,23 @@ fn delete_expr_completions(
 
     let schema = name::schema_name(&path);
     let schemas = binder.resolved_schemas(position, schema.as_ref());
-    if let Some(table_ptr) = binder.lookup_with(&delete_table_name, SymbolKind::Table, &schemas)
-        && let Some(create_table) = table_ptr
+    let create_table = match binder.lookup_with(&delete_table_name, SymbolKind::Table, &schemas) {
+        Some(table_ptr) => table_ptr
             .to_node(source_file.syntax())
             .ancestors()
             .find_map(ast::CreateTableLike::cast)
-    {
+            .map(|create_table| (file, create_table)),
+        None => source_files(db, file).skip(1).find_map(|file| {
+            let table_ptr =
+                bind(db, file).lookup_with(&delete_table_name, SymbolKind::Table, &schemas)?;
+            table_ptr
+                .to_node(parse(db, file).tree().syntax())
+                .ancestors()
+                .find_map(ast::CreateTableLike::cast)
+                .map(|create_table| (file, create_table))
+        }),
+    };
+    if let Some((file, create_table)) = create_table {
         let columns = collect::table_columns(db, file, &create_table);
         completions.extend(columns.into_iter().map(|(name, ty)| CompletionItem {
             label: name.to_string(),
@@ -828,8 +924,8 @@ fn completion_context(token: &SyntaxToken) -> CompletionContext {
     CompletionContext::Default
 }
 
-fn token_at_offset(file: &ast::SourceFile, offset: TextSize) -> Option<SyntaxToken> {
-    let Some(mut token) = file.syntax().token_at_offset(offset).left_biased() else {
+fn token_at_offset(root: &SyntaxNode, offset: TextSize) -> Option<SyntaxToken> {
+    let Some(mut token) = root.token_at_offset(offset).left_biased() else {
         // empty file - definitely at top level
         return None;
     };
@@ -866,14 +962,16 @@ fn function_detail(
     schema: Option<&Schema>,
     position: TextSize,
 ) -> Option<String> {
-    let binder = bind(db, file);
-    let source_file = parse(db, file).tree();
-    let schemas = binder.resolved_schemas(position, schema);
-    let create_function = binder
-        .lookup_with(function_name, SymbolKind::Function, &schemas)?
-        .to_node(source_file.syntax())
-        .ancestors()
-        .find_map(ast::CreateFunction::cast)?;
+    let schemas = bind(db, file).resolved_schemas(position, schema);
+    let (file, create_function) = source_files(db, file).find_map(|file| {
+        let ptr = bind(db, file).lookup_with(function_name, SymbolKind::Function, &schemas)?;
+        let source_file = parse(db, file).tree();
+        let create_function = ptr
+            .to_node(source_file.syntax())
+            .ancestors()
+            .find_map(ast::CreateFunction::cast)?;
+        Some((file, create_function))
+    })?;
     let path = create_function.name()?.path()?;
     let (schema, function_name) = resolve::resolve_function_info(db, InFile::new(file, &path))?;
 
@@ -911,12 +1009,14 @@ pub enum CompletionItemKind {
     Type,
     Snippet,
     Operator,
+    Variable,
 }
 
 impl CompletionItemKind {
     fn sort_prefix(self) -> &'static str {
         match self {
             Self::Column => "0",
+            Self::Variable => "0",
             Self::Keyword => "1",
             Self::Table => "1",
             Self::Type => "1",
@@ -957,8 +1057,10 @@ pub struct CompletionItem {
 mod tests {
     use super::completion;
 
+    use crate::file::InFile;
     use crate::test_utils::Fixture;
     use insta::assert_snapshot;
+    use rowan::TextSize;
     use tabled::builder::Builder;
     use tabled::settings::Style;
 
@@ -1037,6 +1139,95 @@ $0
         ");
     }
 
+    #[test]
+    fn completion_in_plpgsql_body() {
+        assert_snapshot!(completions("
+create table t(a int, b text);
+do $$
+begin
+  perform 1;
+  select $0 from t;
+end
+$$;
+"), @"
+         label              | kind     | detail 
+        --------------------+----------+--------
+         a                  | Column   | int    
+         b                  | Column   | text   
+         t                  | Table    |        
+         *                  | Operator |        
+         public             | Schema   |        
+         pg_catalog         | Schema   |        
+         pg_temp            | Schema   |        
+         pg_toast           | Schema   |        
+         information_schema | Schema   |
+        ");
+    }
+
+    #[test]
+    fn completion_plpgsql_variables() {
+        assert_snapshot!(completions("
+create table t(a int);
+create function f() returns void language plpgsql as $$
+declare
+  n int;
+  label text;
+begin
+  declare
+    n bigint;
+  begin
+    select $0 from t;
+  end;
+end;
+$$;
+"), @"
+         label              | kind     | detail                  
+        --------------------+----------+-------------------------
+         a                  | Column   | int                     
+         label              | Variable | text                    
+         n                  | Variable | bigint                  
+         t                  | Table    |                         
+         f()                | Function | public.f() returns void 
+         *                  | Operator |                         
+         public             | Schema   |                         
+         pg_catalog         | Schema   |                         
+         pg_temp            | Schema   |                         
+         pg_toast           | Schema   |                         
+         information_schema | Schema   |
+        ");
+    }
+
+    #[test]
+    fn completion_table_in_function_body() {
+        assert_snapshot!(completions("
+create table users(id int);
+create function f() returns void language plpgsql as $$
+begin
+  delete from $0
+end;
+$$;
+"), @"
+         label              | kind   | detail 
+        --------------------+--------+--------
+         users              | Table  |        
+         public             | Schema |        
+         pg_catalog         | Schema |        
+         pg_temp            | Schema |        
+         pg_toast           | Schema |        
+         information_schema | Schema |
+        ");
+    }
+
+    #[test]
+    fn completion_in_sql_function_body() {
+        completions_not_found(
+            "
+create table t(a int, b text);
+create function f() returns int language sql as $$ select $0 from t $$;
+",
+        );
+    }
+
     #[test]
     fn completion_in_string() {
         completions_not_found("select '$0';");
@@ -1543,4 +1734,26 @@ select 1 from t offset $0;
          get_offset() | Function | public.get_offset() returns int
         ");
     }
+
+    #[test]
+    fn completion_tables_from_earlier_migrations() {
+        let (db, files) = crate::test_utils::workspace(&[
+            "create table users (id int);\n",
+            "create table posts (id int);\n",
+            "delete from ",
+            "create table comments (id int);\n",
+        ]);
+        let items = completion(&db, InFile::new(files[2], TextSize::new(12)));
+        assert_snapshot!(format_items(items), @"
+         label              | kind   | detail 
+        --------------------+--------+--------
+         posts              | Table  |        
+         users              | Table  |        
+         public             | Schema |        
+         pg_catalog         | Schema |        
+         pg_temp            | Schema |        
+         pg_toast           | Schema |        
+         information_schema | Schema |
+        ");
+    }
 }
@@ -1,10 +1,14 @@
+use rowan::TextRange;
 use salsa::Database as Db;
-#[cfg(test)]
 use salsa::Setter;
 use salsa::Storage;
-use squawk_line_index::{LineIndex, find_newline};
-use squawk_syntax::{Parse, SourceFile};
-use std::sync::Arc;
+use squawk_line_index::LineIndex;
+use squawk_syntax::{
+    Dialect, Parse, SourceFile, SyntaxNode, SyntaxNodePtr, ast::PlpgsqlRoot, edit::Edit,
+    syntax_error::SyntaxError, type_alias::BuiltinType,
+};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
 
 use crate::binder;
 use crate::binder::Binder;
@@ -14,11 +18,127 @@ use crate::builtins::builtins_file;
 pub struct File {
     #[returns(ref)]
     pub content: Arc<str>,
+    /// Set for the cells of a notebook, so that names defined in one cell
+    /// resolve from the others.
+    #[default]
+    pub notebook: Option<Notebook>,
+    /// The previous parse and the edit that turned its text into `content`,
+    /// so small edits only reparse the token or statement they touch.
+    #[default]
+    pub reparse: Option<Reparse>,
+    #[default]
+    pub last_parse: LastParse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reparse {
+    pub parse: Parse<SourceFile>,
+    /// The dialect `parse` was parsed with, we parse from scratch if it's
+    /// since changed.
+    pub dialect: Dialect,
+    pub edit: Edit,
+}
+
+/// The most recent parse of a file, from whichever revision last asked for
+/// it. It lives outside of Salsa so an edit can start from it without
+/// parsing the old text first.
+#[derive(Debug, Clone, Default)]
+pub struct LastParse(Arc<Mutex<Option<TextParse>>>);
+
+type TextParse = (Arc<str>, Parse<SourceFile>, Dialect);
+
+impl LastParse {
+    /// The last parse and the edit that turns its text into `content`, if
+    /// there is one.
+    pub fn reparse(&self, content: &str) -> Option<Reparse> {
+        let (text, parse, dialect) = self.0.lock().unwrap().clone()?;
+        let edit = Edit::diff(&text, content)?;
+        Some(Reparse {
+            parse,
+            dialect,
+            edit,
+        })
+    }
+
+    fn set(&self, text: Arc<str>, parse: Parse<SourceFile>, dialect: Dialect) {
+        *self.0.lock().unwrap() = Some((text, parse, dialect));
+    }
+}
+
+impl PartialEq for LastParse {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for LastParse {}
+
+/// The SQL cells of a notebook, in order. Each cell is its own [`File`] and
+/// for binding they're treated like one file made of all the cells.
+#[salsa::input]
+pub struct Notebook {
+    #[returns(ref)]
+    pub cells: Vec<File>,
 }
 
 #[salsa::tracked]
 pub fn parse(db: &dyn Db, file: File) -> Parse<SourceFile> {
-    SourceFile::parse(file.content(db))
+    let content = file.content(db);
+    let dialect = dialect(db);
+    let parse = match file.reparse(db) {
+        Some(reparse) if reparse.dialect == dialect => {
+            let parse = reparse.parse.reparse(&reparse.edit, dialect);
+            debug_assert_eq!(parse.syntax_node().text(), &**content);
+            parse
+        }
+        _ => SourceFile::parse_dialect(content, None, dialect),
+    };
+    file.last_parse(db)
+        .set(content.clone(), parse.clone(), dialect);
+    parse
+}
+
+/// Parse errors, plus syntax that's newer than the configured Postgres
+/// version or unsupported by the dialect, see [`set_pg_version`] and
+/// [`set_dialect`].
+#[salsa::tracked]
+pub fn syntax_errors(db: &dyn Db, file: File) -> Vec<SyntaxError> {
+    let parse = parse(db, file);
+    let mut errors = parse.errors_for_dialect(pg_version(db), dialect(db));
+    errors.extend(
+        plpgsql_bodies(db, file)
+            .iter()
+            .flat_map(|(_, body)| body.errors()),
+    );
+    errors
+}
+
+/// The `DO` blocks and PL/pgSQL function bodies in the file, along with the
+/// range of their text. Their trees line up with the file, so nodes from them
+/// can be used like the file's own.
+#[salsa::tracked]
+pub(crate) fn plpgsql_bodies(db: &dyn Db, file: File) -> Vec<(TextRange, Parse<PlpgsqlRoot>)> {
+    parse(db, file)
+        .tree()
+        .plpgsql_bodies()
+        .map(|body| (body.text_range(), body.parse().clone()))
+        .collect()
+}
+
+/// Like [`SyntaxNodePtr::to_node`], for pointers into the file's tree or the
+/// tree of one of its PL/pgSQL bodies.
+pub(crate) fn ptr_to_node(db: &dyn Db, file: File, ptr: &SyntaxNodePtr) -> SyntaxNode {
+    ptr.to_node(&root_at(db, file, ptr.text_range()))
+}
+
+/// The root of the tree that contains `range`, either the file's tree or the
+/// tree of a PL/pgSQL body.
+pub(crate) fn root_at(db: &dyn Db, file: File, range: TextRange) -> SyntaxNode {
+    plpgsql_bodies(db, file)
+        .iter()
+        .find(|(body_range, _)| body_range.contains_range(range))
+        .map(|(_, body)| body.syntax_node())
+        .unwrap_or_else(|| parse(db, file).syntax_node())
 }
 
 #[salsa::tracked]
@@ -26,18 +146,70 @@ pub fn line_index(db: &dyn Db, file: File) -> LineIndex {
     LineIndex::new(file.content(db))
 }
 
-pub(crate) fn line_ending(db: &dyn Db, file: File) -> &'static str {
-    find_newline(file.content(db))
-        .map(|(_, line_ending)| line_ending)
-        .unwrap_or_default()
-        .as_str()
+/// The file, followed by the other cells when it's part of a notebook.
+#[inline]
+pub(crate) fn document_files(db: &dyn Db, file: File) -> impl Iterator<Item = File> {
+    let cells = file
+        .notebook(db)
+        .map(|notebook| notebook.cells(db).as_slice())
+        .unwrap_or_default();
+    std::iter::once(file).chain(cells.iter().copied().filter(move |cell| *cell != file))
+}
+
+/// The `.sql` files of the workspace in migration order, e.g.,
+/// `0001_init.sql` before `0042_add_index.sql`. They're only parsed and bound
+/// when a query reaches them.
+#[salsa::input(singleton)]
+pub(crate) struct Workspace {
+    #[returns(ref)]
+    pub(crate) files: Vec<File>,
+}
+
+pub fn set_workspace_files(db: &mut dyn Db, files: Vec<File>) {
+    Workspace::get(db).set_files(db).to(files);
+}
+
+#[salsa::tracked]
+fn workspace_position(db: &dyn Db, file: File) -> Option<usize> {
+    Workspace::get(db)
+        .files(db)
+        .iter()
+        .position(|other| *other == file)
+}
+
+/// The workspace files that run before `file`, latest first, so the most
+/// recent migration to define a name wins. Files outside of the workspace,
+/// like scratch buffers, come after every migration.
+#[inline]
+fn earlier_workspace_files(db: &dyn Db, file: File) -> impl Iterator<Item = File> {
+    let files = Workspace::get(db).files(db);
+    let end = workspace_position(db, file).unwrap_or(files.len());
+    files[..end].iter().rev().copied()
+}
+
+/// The files we look in when resolving names from `file`, before falling
+/// back to the builtins.
+#[inline]
+pub(crate) fn source_files(db: &dyn Db, file: File) -> impl Iterator<Item = File> {
+    document_files(db, file).chain(earlier_workspace_files(db, file))
 }
 
 #[inline]
 pub(crate) fn list_files(db: &dyn Db, file: File) -> impl Iterator<Item = File> {
-    [Some(file), include_builtins(db).then(|| builtins_file(db))]
-        .into_iter()
-        .flatten()
+    source_files(db, file).chain(include_builtins(db).then(|| builtins_file(db)))
+}
+
+/// The files that could reference a name defined in `file`, i.e., the
+/// document followed by every workspace file in migration order.
+#[inline]
+pub(crate) fn project_files(db: &dyn Db, file: File) -> impl Iterator<Item = File> {
+    let workspace = Workspace::get(db).files(db);
+    document_files(db, file).chain(
+        workspace
+            .iter()
+            .copied()
+            .filter(move |other| *other != file),
+    )
 }
 
 #[salsa::tracked]
@@ -51,6 +223,14 @@ pub(crate) fn bind(db: &dyn Db, file: File) -> Binder {
 pub(crate) struct Config {
     // currently only used for improve test runtime by skipping builtins
     pub(crate) include_builtins: bool,
+    /// The Postgres major version the SQL targets.
+    pub(crate) pg_version: Option<i32>,
+    /// The database the SQL targets, which decides its grammar extensions.
+    pub(crate) dialect: Dialect,
+    /// Overrides of the name builtin types are rewritten to, from
+    /// `[format.canonical_types]`.
+    #[returns(ref)]
+    pub(crate) canonical_types: BTreeMap<BuiltinType, &'static str>,
 }
 
 #[salsa::tracked]
@@ -58,6 +238,38 @@ pub(crate) fn include_builtins(db: &dyn Db) -> bool {
     Config::get(db).include_builtins(db)
 }
 
+#[salsa::tracked]
+pub fn pg_version(db: &dyn Db) -> Option<i32> {
+    Config::get(db).pg_version(db)
+}
+
+pub fn set_pg_version(db: &mut dyn Db, pg_version: Option<i32>) {
+    Config::get(db).set_pg_version(db).to(pg_version);
+}
+
+#[salsa::tracked]
+pub fn dialect(db: &dyn Db) -> Dialect {
+    Config::get(db).dialect(db)
+}
+
+pub fn set_dialect(db: &mut dyn Db, dialect: Dialect) {
+    Config::get(db).set_dialect(db).to(dialect);
+}
+
+/// The name `ty` is rewritten to, its canonical name unless it's overridden
+/// with [`set_canonical_types`].
+pub fn canonical_type_name(db: &dyn Db, ty: BuiltinType) -> &'static str {
+    Config::get(db)
+        .canonical_types(db)
+        .get(&ty)
+        .copied()
+        .unwrap_or_else(|| ty.canonical_name())
+}
+
+pub fn set_canonical_types(db: &mut dyn Db, names: BTreeMap<BuiltinType, &'static str>) {
+    Config::get(db).set_canonical_types(db).to(names);
+}
+
 #[salsa::db]
 #[derive(Clone)]
 pub struct Database {
@@ -69,7 +281,8 @@ impl Default for Database {
         let db = Self {
             storage: Storage::default(),
         };
-        Config::new(&db, true);
+        Config::new(&db, true, None, Dialect::Postgres, BTreeMap::new());
+        Workspace::new(&db, vec![]);
         db
     }
 }
@@ -82,3 +295,44 @@ pub(crate) fn set_include_builtins(db: &mut dyn Db, include_builtins: bool) {
 }
 
 impl salsa::Database for Database {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reparse_from_last_parse() {
+        let mut db = Database::default();
+        let file = File::new(&db, "select 1;\nselect 2;\n".into());
+        assert!(file.last_parse(&db).reparse("select 1;").is_none());
+
+        parse(&db, file);
+        let content = "select 1;\nselect 3;\n";
+        let reparse = file.last_parse(&db).reparse(content);
+        assert!(reparse.is_some());
+        file.set_content(&mut db).to(content.into());
+        file.set_reparse(&mut db).to(reparse);
+
+        let parse = parse(&db, file);
+        assert_eq!(
+            format!("{:#?}", parse.syntax_node()),
+            format!("{:#?}", SourceFile::parse(content).syntax_node())
+        );
+    }
+
+    #[test]
+    fn parse_with_dialect() {
+        let mut db = Database::default();
+        let file = File::new(&db, "create index async i on t (a);\n".into());
+        assert!(!syntax_errors(&db, file).is_empty());
+
+        set_dialect(&mut db, Dialect::AuroraDsql);
+        assert_eq!(syntax_errors(&db, file), vec![]);
+
+        let content = "create index async t_a_idx on t (a);\n";
+        let reparse = file.last_parse(&db).reparse(content);
+        file.set_content(&mut db).to(content.into());
+        file.set_reparse(&mut db).to(reparse);
+        assert_eq!(syntax_errors(&db, file), vec![]);
+    }
+}
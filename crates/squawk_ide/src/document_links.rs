@@ -0,0 +1,105 @@
+use rowan::{TextRange, TextSize};
+use salsa::Database as Db;
+use squawk_syntax::ast;
+
+use crate::db::{File, parse};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncludeKind {
+    /// `\i` or `\include`, relative to the directory psql is run from.
+    WorkingDirectory,
+    /// `\ir` or `\include_relative`, relative to the including file.
+    Relative,
+}
+
+/// A file included with psql's `\i` or `\ir`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentLink {
+    /// Range of the path in the meta-command.
+    pub range: TextRange,
+    pub target: String,
+    pub kind: IncludeKind,
+}
+
+#[salsa::tracked]
+pub fn document_links(db: &dyn Db, file: File) -> Vec<DocumentLink> {
+    let source_file = parse(db, file).tree();
+    source_file
+        .psql_meta_commands()
+        .filter_map(|meta_command| include_link(&meta_command))
+        .collect()
+}
+
+fn include_link(meta_command: &ast::PsqlMetaCommand) -> Option<DocumentLink> {
+    let kind = match meta_command.name()?.as_str() {
+        "i" | "include" => IncludeKind::WorkingDirectory,
+        "ir" | "include_relative" => IncludeKind::Relative,
+        _ => return None,
+    };
+    let (args, range) = meta_command.args()?;
+    let (target, range) = match args.strip_prefix('\'') {
+        Some(quoted) => (quoted.strip_suffix('\'')?.replace("''", "'"), range),
+        // psql only takes the first word when the path isn't quoted
+        None => {
+            let path = args.split_whitespace().next()?;
+            (
+                path.to_owned(),
+                TextRange::at(range.start(), TextSize::of(path)),
+            )
+        }
+    };
+    if target.is_empty() {
+        return None;
+    }
+    Some(DocumentLink {
+        range,
+        target,
+        kind,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use super::document_links;
+    use crate::db::{Database, File};
+
+    #[must_use]
+    fn links(sql: &str) -> String {
+        let db = Database::default();
+        let file = File::new(&db, sql.into());
+        document_links(&db, file)
+            .into_iter()
+            .map(|link| {
+                format!(
+                    "{:?} {:?} -> {:?}\n",
+                    &sql[link.range], link.kind, link.target
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn includes() {
+        assert_snapshot!(links(r"
+\i setup.sql
+\ir ./views/users.sql
+\include_relative 'with space''s.sql'
+\set file other.sql
+select 1;
+"), @r#"
+        "setup.sql" WorkingDirectory -> "setup.sql"
+        "./views/users.sql" Relative -> "./views/users.sql"
+        "'with space''s.sql'" Relative -> "with space's.sql"
+        "#);
+    }
+
+    #[test]
+    fn empty_include() {
+        assert_snapshot!(links(r"
+\i
+\ir ''
+"), @"");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::db::parse;
+use crate::db::{parse, plpgsql_bodies, project_files};
 use crate::file::InFile;
 use crate::goto_definition;
 use crate::location::Location;
@@ -49,25 +49,33 @@ pub fn find_references(db: &dyn Db, position: InFile<TextSize>) -> Vec<Location>
 
     let mut refs = targets.to_vec();
 
-    for node in parse(db, file)
-        .tree()
-        .syntax()
-        .descendants()
-        .filter(is_reference_node)
-    {
-        let range = node.text_range();
-        let matches = goto_definition::goto_definition(db, InFile::new(file, range.start()))
-            .into_iter()
-            .any(|location| targets.contains(&location));
-        if matches {
-            refs.push(Location {
-                file,
-                range,
-                kind: first.kind,
-            });
+    let files: Vec<_> = project_files(db, file).collect();
+    for &search_file in &files {
+        let bodies = plpgsql_bodies(db, search_file);
+        let roots = std::iter::once(parse(db, search_file).syntax_node())
+            .chain(bodies.iter().map(|(_, body)| body.syntax_node()));
+        for node in roots
+            .flat_map(|root| root.descendants())
+            .filter(is_reference_node)
+        {
+            let range = node.text_range();
+            let matches =
+                goto_definition::goto_definition(db, InFile::new(search_file, range.start()))
+                    .into_iter()
+                    .any(|location| targets.contains(&location));
+            if matches {
+                refs.push(Location {
+                    file: search_file,
+                    range,
+                    kind: first.kind,
+                });
+            }
         }
     }
-    refs.sort_by_key(|loc| (loc.file != file, loc.range.start()));
+    refs.sort_by_key(|loc| {
+        let file_order = files.iter().position(|file| *file == loc.file);
+        (file_order.unwrap_or(files.len()), loc.range.start())
+    });
     refs
 }
 
@@ -76,11 +84,12 @@ mod test {
     use crate::builtins::builtins_file;
     use crate::db::File;
 
+    use crate::file::InFile;
     use crate::find_references::find_references;
     use crate::test_utils::Fixture;
     use annotate_snippets::{AnnotationKind, Level, Renderer, Snippet, renderer::DecorStyle};
     use insta::assert_snapshot;
-    use rowan::TextRange;
+    use rowan::{TextRange, TextSize};
     use rustc_hash::FxHashMap;
 
     #[must_use]
@@ -171,6 +180,57 @@ drop table t$0;
         ");
     }
 
+    #[test]
+    fn table_references_in_plpgsql_body() {
+        assert_snapshot!(find_refs("
+create table t$0(a int);
+do $$
+begin
+  insert into t values (1);
+  perform * from t;
+end
+$$;
+"), @"
+          ╭▸ 
+        2 │ create table t(a int);
+          │              ┬
+          │              │
+          │              0. query
+          │              1. reference
+          ‡
+        5 │   insert into t values (1);
+          │               ─ 2. reference
+        6 │   perform * from t;
+          ╰╴                 ─ 3. reference
+        ");
+    }
+
+    #[test]
+    fn plpgsql_variable_references() {
+        assert_snapshot!(find_refs("
+create function f() returns int language plpgsql as $$
+declare
+  n$0 int;
+begin
+  select count(*) into n from t;
+  return n;
+end;
+$$;
+"), @"
+          ╭▸ 
+        4 │   n int;
+          │   ┬
+          │   │
+          │   0. query
+          │   1. reference
+        5 │ begin
+        6 │   select count(*) into n from t;
+          │                        ─ 2. reference
+        7 │   return n;
+          ╰╴         ─ 3. reference
+        ");
+    }
+
     #[test]
     fn multiple_references() {
         assert_snapshot!(find_refs("
@@ -907,4 +967,46 @@ window w$0 as (partition by 1);
           ╰╴       2. reference
         ");
     }
+
+    #[test]
+    fn refs_in_other_notebook_cells() {
+        let (db, cells) = crate::test_utils::notebook(&[
+            "create table t (a int);\n",
+            "select a from t;\n",
+            "insert into t values (1);\n",
+        ]);
+        let references = find_references(&db, InFile::new(cells[0], TextSize::new(13)));
+        let found: Vec<_> = references
+            .iter()
+            .map(|location| {
+                let cell = cells
+                    .iter()
+                    .position(|cell| *cell == location.file)
+                    .unwrap();
+                (cell, &location.file.content(&db)[location.range])
+            })
+            .collect();
+        assert_eq!(found, vec![(0, "t"), (1, "t"), (2, "t")]);
+    }
+
+    #[test]
+    fn refs_in_other_migrations() {
+        let (db, files) = crate::test_utils::workspace(&[
+            "create table t (a int);\n",
+            "create index on t (a);\n",
+            "select a from t;\n",
+        ]);
+        let references = find_references(&db, InFile::new(files[0], TextSize::new(13)));
+        let found: Vec<_> = references
+            .iter()
+            .map(|location| {
+                let migration = files
+                    .iter()
+                    .position(|file| *file == location.file)
+                    .unwrap();
+                (migration, &location.file.content(&db)[location.range])
+            })
+            .collect();
+        assert_eq!(found, vec![(0, "t"), (1, "t"), (2, "t")]);
+    }
 }
@@ -43,6 +43,7 @@ pub enum FoldKind {
     ArgList,
     Array,
     Comment,
+    CopyData,
     FunctionCall,
     Join,
     List,
@@ -129,6 +130,7 @@ fn fold_kind(kind: SyntaxKind) -> Option<FoldKind> {
             Some(FoldKind::ArgList)
         }
         SyntaxKind::ARRAY_EXPR => Some(FoldKind::Array),
+        SyntaxKind::COPY_STDIN_DATA => Some(FoldKind::CopyData),
         SyntaxKind::CALL_EXPR => Some(FoldKind::FunctionCall),
         SyntaxKind::JOIN => Some(FoldKind::Join),
         SyntaxKind::PAREN_SELECT => Some(FoldKind::Subquery),
@@ -240,6 +242,7 @@ mod tests {
             FoldKind::ArgList => "arglist",
             FoldKind::Array => "array",
             FoldKind::Comment => "comment",
+            FoldKind::CopyData => "copy_data",
             FoldKind::FunctionCall => "function_call",
             FoldKind::Join => "join",
             FoldKind::List => "list",
@@ -318,6 +321,21 @@ create table t (
         ");
     }
 
+    #[test]
+    fn fold_copy_data() {
+        assert_snapshot!(check("
+copy t from stdin;
+1\tfoo
+2\tbar
+\\.
+"), @r#"
+        copy t from stdin;
+        <fold copy_data>1	foo
+        2	bar
+        \.</fold>
+        "#);
+    }
+
     #[test]
     fn fold_select() {
         assert_snapshot!(check("
@@ -138,6 +138,7 @@ pub fn goto_definition(db: &dyn Db, position: InFile<TextSize>) -> SmallVec<[Loc
                 | ast::AnyName::Language(_)
                 | ast::AnyName::ParamName(_)
                 | ast::AnyName::PathSegment(_)
+                | ast::AnyName::PlpgsqlVariable(_)
                 | ast::AnyName::Policy(_)
                 | ast::AnyName::PreparedStatement(_)
                 | ast::AnyName::Publication(_)
@@ -294,6 +295,12 @@ pub fn goto_definition(db: &dyn Db, position: InFile<TextSize>) -> SmallVec<[Loc
         if let Some(locations) = locations {
             return locations;
         }
+        if let Some(name_ref) = ast::NameRef::cast(parent.clone())
+            && let Some(locations) =
+                resolve::resolve_plpgsql_variable(db, InFile::new(file, &name_ref))
+        {
+            return locations;
+        }
     }
 
     if let Some(config_value_name) = ast::ConfigValueName::cast(parent.clone()) {
@@ -386,11 +393,12 @@ mod test {
     use crate::builtins::builtins_file;
     use crate::db::File;
 
+    use crate::file::InFile;
     use crate::goto_definition::goto_definition;
     use crate::test_utils::Fixture;
     use annotate_snippets::{AnnotationKind, Level, Renderer, Snippet, renderer::DecorStyle};
     use insta::assert_snapshot;
-    use rowan::TextRange;
+    use rowan::{TextRange, TextSize};
     use rustc_hash::FxHashMap;
 
     #[must_use]
@@ -1309,6 +1317,180 @@ from
         );
     }
 
+    #[test]
+    fn goto_table_in_do_block() {
+        assert_snapshot!(goto("
+create table t(a int);
+do $$
+begin
+  alter table t$0 drop column a;
+end
+$$;
+"), @"
+          ╭▸ 
+        2 │ create table t(a int);
+          │              ─ 2. destination
+          ‡
+        5 │   alter table t drop column a;
+          ╰╴              ─ 1. source
+        ");
+    }
+
+    #[test]
+    fn goto_column_in_function_body() {
+        assert_snapshot!(goto("
+create table t(a int);
+create function f() returns int language plpgsql as $$
+declare
+  x int;
+begin
+  select a$0 into x from t;
+  return x;
+end;
+$$;
+"), @"
+          ╭▸ 
+        2 │ create table t(a int);
+          │                ─ 2. destination
+          ‡
+        7 │   select a into x from t;
+          ╰╴         ─ 1. source
+        ");
+    }
+
+    #[test]
+    fn goto_cte_in_function_body() {
+        assert_snapshot!(goto("
+create function f() returns void language plpgsql as $$
+begin
+  perform 1;
+  insert into u with c as (select 1) select * from c$0;
+end;
+$$;
+"), @"
+          ╭▸ 
+        5 │   insert into u with c as (select 1) select * from c;
+          ╰╴                     ─ 2. destination              ─ 1. source
+        ");
+    }
+
+    #[test]
+    fn goto_function_in_do_block() {
+        assert_snapshot!(goto("
+create function g() returns int language sql as 'select 1';
+do $$
+begin
+  if g$0() > 0 then
+    raise notice 'positive';
+  end if;
+end
+$$;
+"), @"
+          ╭▸ 
+        2 │ create function g() returns int language sql as 'select 1';
+          │                 ─ 2. destination
+          ‡
+        5 │   if g() > 0 then
+          ╰╴     ─ 1. source
+        ");
+    }
+
+    #[test]
+    fn goto_plpgsql_variable() {
+        assert_snapshot!(goto("
+create function f() returns int language plpgsql as $$
+declare
+  total int := 0;
+begin
+  perform 1;
+  return total$0;
+end;
+$$;
+"), @"
+          ╭▸ 
+        4 │   total int := 0;
+          │   ───── 2. destination
+          ‡
+        7 │   return total;
+          ╰╴             ─ 1. source
+        ");
+    }
+
+    #[test]
+    fn goto_plpgsql_loop_variable() {
+        assert_snapshot!(goto("
+create function f() returns int language plpgsql as $$
+declare
+  total int := 0;
+begin
+  for i in 1..10 loop
+    total := total + i$0;
+  end loop;
+  return total;
+end;
+$$;
+"), @"
+          ╭▸ 
+        6 │   for i in 1..10 loop
+          │       ─ 2. destination
+        7 │     total := total + i;
+          ╰╴                     ─ 1. source
+        ");
+    }
+
+    #[test]
+    fn goto_plpgsql_variable_in_nested_block() {
+        assert_snapshot!(goto("
+do $$
+declare
+  x int;
+begin
+  declare
+    x text;
+  begin
+    raise notice '%', x$0;
+  end;
+end
+$$;
+"), @"
+          ╭▸ 
+        7 │     x text;
+          │     ─ 2. destination
+        8 │   begin
+        9 │     raise notice '%', x;
+          ╰╴                      ─ 1. source
+        ");
+    }
+
+    #[test]
+    fn goto_plpgsql_function_param() {
+        assert_snapshot!(goto("
+create table t(id int);
+create function f(p_id int) returns void language plpgsql as $$
+begin
+  delete from t where id = p_id$0;
+end;
+$$;
+"), @"
+          ╭▸ 
+        3 │ create function f(p_id int) returns void language plpgsql as $$
+          │                   ──── 2. destination
+        4 │ begin
+        5 │   delete from t where id = p_id;
+          ╰╴                              ─ 1. source
+        ");
+    }
+
+    #[test]
+    fn goto_in_sql_function_body_not_found() {
+        goto_not_found(
+            "
+create table t(a int);
+create function f() returns int language sql as $$ select a$0 from t $$;
+",
+        );
+    }
+
     #[test]
     fn goto_cte_forward_ref_not_found() {
         // b is defined after a, so a can't reference it in a non-recursive WITH
@@ -15940,4 +16122,79 @@ select y$0 from rows from (f(), g()) as z(q, r);
           ╰╴       ─ 1. source
         ");
     }
+
+    #[test]
+    fn goto_table_in_other_notebook_cell() {
+        let (db, cells) =
+            crate::test_utils::notebook(&["create table t (a int);\n", "select a from t;\n"]);
+        let results = goto_definition(&db, InFile::new(cells[1], TextSize::new(14)));
+        let found: Vec<_> = results
+            .iter()
+            .map(|location| {
+                let cell = cells
+                    .iter()
+                    .position(|cell| *cell == location.file)
+                    .unwrap();
+                (cell, &location.file.content(&db)[location.range])
+            })
+            .collect();
+        assert_eq!(found, vec![(0, "t")]);
+    }
+
+    fn goto_in_workspace(migrations: &[&str], file: usize, offset: u32) -> Vec<(usize, String)> {
+        let (db, files) = crate::test_utils::workspace(migrations);
+        goto_definition(&db, InFile::new(files[file], TextSize::new(offset)))
+            .iter()
+            .map(|location| {
+                let migration = files
+                    .iter()
+                    .position(|file| *file == location.file)
+                    .unwrap();
+                (
+                    migration,
+                    location.file.content(&db)[location.range].to_owned(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn goto_table_in_earlier_migration() {
+        let found = goto_in_workspace(
+            &["create table t (a int);\n", "create index on t (a);\n"],
+            1,
+            16,
+        );
+        assert_eq!(found, vec![(0, "t".to_owned())]);
+    }
+
+    #[test]
+    fn goto_column_in_earlier_migration() {
+        let found = goto_in_workspace(
+            &["create table t (a int);\n", "create index on t (a);\n"],
+            1,
+            19,
+        );
+        assert_eq!(found, vec![(0, "a".to_owned())]);
+    }
+
+    #[test]
+    fn goto_table_in_latest_migration() {
+        let found = goto_in_workspace(
+            &[
+                "create table t (a int);\n",
+                "drop table t;\ncreate table t (b int);\n",
+                "select * from t;\n",
+            ],
+            2,
+            14,
+        );
+        assert_eq!(found, vec![(1, "t".to_owned())]);
+    }
+
+    #[test]
+    fn goto_table_in_later_migration_not_found() {
+        let found = goto_in_workspace(&["select * from t;\n", "create table t (a int);\n"], 0, 14);
+        assert_eq!(found, vec![]);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::ast_nav;
 use crate::collect;
 use crate::comments::preceding_comment;
-use crate::db::{File, bind, list_files, parse};
+use crate::db::{File, bind, list_files, ptr_to_node};
 use crate::file::InFile;
 use crate::infer::{infer_type_from_expr, infer_type_from_literal};
 use crate::literals::binary_digits_to_hex;
@@ -228,6 +228,8 @@ pub fn hover(db: &dyn Db, position: InFile<TextSize>) -> Option<Hover> {
             | ast::AnyName::OptionItemValueName(_)
             | ast::AnyName::ParamNameRef(_)
             | ast::AnyName::PathSegmentRef(_)
+            | ast::AnyName::PlpgsqlLabel(_)
+            | ast::AnyName::PlpgsqlVariable(_)
             | ast::AnyName::PolicyRef(_)
             | ast::AnyName::PreparedStatementRef(_)
             | ast::AnyName::PropertyName(_)
@@ -358,7 +360,8 @@ fn hover_name(db: &dyn Db, def: Location) -> Option<Hover> {
         | LocationKind::ElementTable
         | LocationKind::Label
         | LocationKind::PreparedTransaction
-        | LocationKind::Property => None,
+        | LocationKind::Property
+        | LocationKind::Variable => None,
         LocationKind::Channel => hover_channel(db, def),
         LocationKind::Column => hover_name_column(db, def),
         LocationKind::Constraint => hover_constraint(db, def),
@@ -447,7 +450,8 @@ fn hover_position(db: &dyn Db, position: InFile<TextSize>) -> Option<Hover> {
         | LocationKind::ElementTable
         | LocationKind::Label
         | LocationKind::PreparedTransaction
-        | LocationKind::Property => None,
+        | LocationKind::Property
+        | LocationKind::Variable => None,
         LocationKind::Channel => hover_channel(db, def),
         LocationKind::Column => {
             if let Some(result) = hover_composite_type_field(db, def) {
@@ -920,9 +924,7 @@ fn hover_qualified_star_columns(
     table_ptr: InFile<&squawk_syntax::SyntaxNodePtr>,
 ) -> Option<Hover> {
     let file = table_ptr.file_id;
-    let source_file = parse(db, file).tree();
-    let root = source_file.syntax();
-    let table_name_node = table_ptr.value.to_node(root);
+    let table_name_node = ptr_to_node(db, file, table_ptr.value);
 
     match ast_nav::parent_source(&table_name_node)? {
         ast_nav::ParentSouce::Alias(alias) => {
@@ -2189,10 +2191,12 @@ fn unqualified_star_in_arg_list_ptrs(
 #[cfg(test)]
 mod test {
 
+    use crate::file::InFile;
     use crate::hover::hover;
     use crate::test_utils::Fixture;
     use annotate_snippets::{AnnotationKind, Level, Renderer, Snippet, renderer::DecorStyle};
     use insta::assert_snapshot;
+    use rowan::TextSize;
 
     #[must_use]
     #[track_caller]
@@ -6327,4 +6331,14 @@ select x'10000000000000000000000000000000$00';
         value of literal: ` x'100000000000000000000000000000000'|b'000100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000' `
         ");
     }
+
+    #[test]
+    fn hover_table_in_earlier_migration() {
+        let (db, files) = crate::test_utils::workspace(&[
+            "create table t (a int, b text);\n",
+            "select * from t;\n",
+        ]);
+        let hover = hover(&db, InFile::new(files[1], TextSize::new(14))).unwrap();
+        assert_snapshot!(hover.snippet, @"table public.t(a int, b text)");
+    }
 }
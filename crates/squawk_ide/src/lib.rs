@@ -7,6 +7,7 @@ mod collect;
 mod comments;
 pub mod completion;
 pub mod db;
+pub mod document_links;
 pub mod document_symbols;
 pub mod expand_selection;
 pub mod file;
@@ -20,6 +21,7 @@ mod literals;
 pub mod location;
 mod name;
 mod offsets;
+pub mod rename;
 mod resolve;
 mod scope;
 pub mod semantic_tokens;
@@ -1,11 +1,10 @@
 use rowan::TextRange;
 use salsa::Database as Db;
 use squawk_syntax::SyntaxNode;
-use squawk_syntax::ast::AstNode;
 
 use crate::{
     classify::classify_def_node,
-    db::{File, parse},
+    db::{File, root_at},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -58,6 +57,7 @@ pub enum LocationKind {
     TextSearchTemplate,
     Trigger,
     Type,
+    Variable,
     View,
     Window,
 }
@@ -80,8 +80,7 @@ impl Location {
     }
 
     pub(crate) fn to_node(self, db: &dyn Db) -> Option<SyntaxNode> {
-        let tree = parse(db, self.file).tree();
-        match tree.syntax().covering_element(self.range) {
+        match root_at(db, self.file, self.range).covering_element(self.range) {
             rowan::NodeOrToken::Token(token) => token.parent(),
             rowan::NodeOrToken::Node(node) => Some(node.clone()),
         }
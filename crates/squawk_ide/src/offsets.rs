@@ -1,13 +1,12 @@
-use crate::db::parse;
+use crate::db::root_at;
 use crate::file::InFile;
-use rowan::TextSize;
+use rowan::{TextRange, TextSize};
 use salsa::Database as Db;
-use squawk_syntax::{SyntaxKind, SyntaxToken, ast::AstNode};
+use squawk_syntax::{SyntaxKind, SyntaxToken};
 
 pub(crate) fn token_from_offset(db: &dyn Db, position: InFile<TextSize>) -> Option<SyntaxToken> {
-    let mut token = parse(db, position.file_id)
-        .tree()
-        .syntax()
+    // inside a PL/pgSQL body we want the token from the body's tree
+    let mut token = root_at(db, position.file_id, TextRange::empty(position.value))
         .token_at_offset(position.value)
         .right_biased()?;
     // want to be lenient in case someone clicks:
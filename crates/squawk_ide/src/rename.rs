@@ -0,0 +1,565 @@
+use std::fmt;
+
+use rowan::{TextRange, TextSize};
+use salsa::Database as Db;
+use squawk_syntax::ast::{self, AstNode};
+use squawk_syntax::edit::Edit;
+use squawk_syntax::quote::quote_ident;
+use squawk_syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+use crate::builtins::builtins_file;
+use crate::classify::classify_def_node;
+use crate::db::{File, bind, parse, project_files};
+use crate::file::InFile;
+use crate::find_references::find_references;
+use crate::location::Location;
+use crate::name::Name;
+use crate::symbols::{Symbol, SymbolKind};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameError(String);
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for RenameError {}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct FileEdits {
+    pub file: File,
+    pub edits: Vec<Edit>,
+}
+
+/// The range of the name at `position`, if it's something we can rename.
+pub fn prepare_rename(db: &dyn Db, position: InFile<TextSize>) -> Result<TextRange, RenameError> {
+    let refs = references(db, position)?;
+    refs.iter()
+        .find(|location| {
+            location.file == position.file_id && location.range.contains_inclusive(position.value)
+        })
+        .map(|location| location.range)
+        .ok_or_else(no_references)
+}
+
+/// Renames the definition at `position` and all its references.
+///
+/// `new_name` is read like an identifier in SQL, so `Foo` is `foo` and
+/// `"Foo"` keeps its case. We then quote it only where it's needed, e.g.,
+/// renaming to `"users"` gives `users`, and renaming to `select` gives
+/// `"select"`.
+///
+/// Columns used as is in the targets of a view, CTE, or subquery also name
+/// its output column, so we alias them to keep the old name, e.g.,
+/// `select a from t` becomes `select b as a from t`.
+pub fn rename(
+    db: &dyn Db,
+    position: InFile<TextSize>,
+    new_name: &str,
+) -> Result<Vec<FileEdits>, RenameError> {
+    let refs = references(db, position)?;
+    let Some(name) = parse_name(new_name) else {
+        return Err(RenameError(format!("Invalid name `{new_name}`")));
+    };
+    let text = quote_ident(&name.0);
+    if has_conflict(db, position.file_id, &refs, &name) {
+        return Err(RenameError(format!("`{text}` is already defined")));
+    }
+
+    let mut changes: Vec<FileEdits> = vec![];
+    for location in refs {
+        let edit = match output_name(db, location) {
+            Some(output_name) => Edit::replace(location.range, format!("{text} as {output_name}")),
+            None => Edit::replace(location.range, text.clone()),
+        };
+        match changes
+            .iter_mut()
+            .find(|change| change.file == location.file)
+        {
+            Some(change) => {
+                if !change.edits.contains(&edit) {
+                    change.edits.push(edit);
+                }
+            }
+            None => changes.push(FileEdits {
+                file: location.file,
+                edits: vec![edit],
+            }),
+        }
+    }
+    Ok(changes)
+}
+
+fn no_references() -> RenameError {
+    RenameError("No references found at position".to_string())
+}
+
+fn references(db: &dyn Db, position: InFile<TextSize>) -> Result<Vec<Location>, RenameError> {
+    let refs = find_references(db, position);
+    if refs.is_empty() {
+        return Err(no_references());
+    }
+    let builtins = builtins_file(db);
+    for location in &refs {
+        let text = &location.file.content(db)[location.range];
+        if location.file == builtins {
+            return Err(RenameError(format!("Cannot rename builtin `{text}`")));
+        }
+        // Things like `case` expressions and string literals have references,
+        // but there's no name for us to change.
+        if name_token(db, *location).is_none() {
+            return Err(RenameError(format!("Cannot rename `{text}`")));
+        }
+    }
+    Ok(refs)
+}
+
+fn name_token(db: &dyn Db, location: Location) -> Option<SyntaxToken> {
+    let tree = parse(db, location.file).tree();
+    let token = tree
+        .syntax()
+        .covering_element(location.range)
+        .into_token()?;
+    let parent = token.parent()?;
+    (ast::AnyName::can_cast(parent.kind()) && token.text_range() == location.range).then_some(token)
+}
+
+/// The output column name `location` gives the view, CTE, or subquery it's a
+/// target of, e.g., the `a` in `create view v as select t.a from t`.
+fn output_name(db: &dyn Db, location: Location) -> Option<String> {
+    let token = name_token(db, location)?;
+    let target = token.parent_ancestors().find_map(ast::Target::cast)?;
+    if target.as_name().is_some() {
+        return None;
+    }
+    let expr = target.expr()?;
+    if !matches!(expr, ast::Expr::NameRef(_) | ast::Expr::FieldExpr(_))
+        || expr.syntax().last_token().as_ref() != Some(&token)
+    {
+        return None;
+    }
+    let select = target
+        .syntax()
+        .ancestors()
+        .find(|node| node.kind() == SyntaxKind::SELECT)?;
+    let parent = select
+        .ancestors()
+        .skip(1)
+        .find(|node| node.kind() != SyntaxKind::COMPOUND_SELECT)?;
+    matches!(
+        parent.kind(),
+        SyntaxKind::CREATE_VIEW
+            | SyntaxKind::CREATE_MATERIALIZED_VIEW
+            | SyntaxKind::WITH_TABLE
+            | SyntaxKind::PAREN_SELECT
+    )
+    .then(|| token.text().to_string())
+}
+
+fn parse_name(text: &str) -> Option<Name> {
+    let text = text.trim();
+    if let Some(quoted) = text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+        let valid = !quoted.is_empty() && !quoted.replace(r#""""#, "").contains('"');
+        return valid.then(|| Name::from_string(text));
+    }
+    let mut chars = text.chars();
+    let first = chars.next()?;
+    let valid = (first.is_alphabetic() || first == '_')
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '$'));
+    valid.then(|| Name::from_string(text))
+}
+
+/// Whether one of the renamed definitions would clash with an existing
+/// definition of the new name.
+fn has_conflict(db: &dyn Db, file: File, refs: &[Location], name: &Name) -> bool {
+    refs.iter().any(|location| {
+        let Some(token) = name_token(db, *location) else {
+            return false;
+        };
+        let Some(def) = token.parent().filter(is_definition) else {
+            return false;
+        };
+        let binder = bind(db, location.file);
+        let symbols: Vec<&Symbol> = binder.symbols_at(location.range).collect();
+        if symbols.is_empty() {
+            has_local_conflict(&def, name)
+        } else {
+            project_files(db, file).any(|other_file| {
+                bind(db, other_file).symbols_named(name).any(|other| {
+                    // Symbols from different files can look the same, e.g.,
+                    // `create table t();` and `create table u();`.
+                    let same_file = other_file == location.file;
+                    symbols.iter().any(|symbol| {
+                        !(same_file && *symbol == other) && same_namespace(symbol, other)
+                    })
+                })
+            })
+        }
+    })
+}
+
+fn same_namespace(a: &Symbol, b: &Symbol) -> bool {
+    same_kind_namespace(a.kind, b.kind)
+        && a.schema == b.schema
+        && a.table == b.table
+        && a.params == b.params
+}
+
+fn same_kind_namespace(a: SymbolKind, b: SymbolKind) -> bool {
+    // Tables, views, sequences, and indexes all live in `pg_class` and
+    // functions, procedures, and aggregates in `pg_proc`, so their names clash.
+    const RELATIONS: &[SymbolKind] = &[
+        SymbolKind::Table,
+        SymbolKind::View,
+        SymbolKind::Sequence,
+        SymbolKind::Index,
+    ];
+    const ROUTINES: &[SymbolKind] = &[
+        SymbolKind::Function,
+        SymbolKind::Procedure,
+        SymbolKind::Aggregate,
+    ];
+    a == b
+        || (RELATIONS.contains(&a) && RELATIONS.contains(&b))
+        || (ROUTINES.contains(&a) && ROUTINES.contains(&b))
+}
+
+/// Columns, aliases, and params aren't in the binder, so we look for a
+/// definition of the same kind in the same statement instead.
+fn has_local_conflict(def: &SyntaxNode, name: &Name) -> bool {
+    let Some(kind) = classify_def_node(def) else {
+        return false;
+    };
+    let Some(stmt) = enclosing_stmt(def) else {
+        return false;
+    };
+    stmt.descendants().filter(is_definition).any(|other| {
+        other != *def
+            && ast::AnyName::cast(other.clone())
+                .is_some_and(|other| Name::from_node(&other) == *name)
+            && classify_def_node(&other) == Some(kind)
+            && enclosing_stmt(&other).as_ref() == Some(&stmt)
+    })
+}
+
+fn is_definition(node: &SyntaxNode) -> bool {
+    ast::AnyName::can_cast(node.kind()) && !ast::AnyNameRef::can_cast(node.kind())
+}
+
+fn enclosing_stmt(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.ancestors()
+        .skip(1)
+        .find(|ancestor| ast::Stmt::can_cast(ancestor.kind()))
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use crate::db::File;
+    use crate::file::InFile;
+    use crate::rename::{prepare_rename, rename};
+    use crate::test_utils::{Fixture, notebook, workspace};
+
+    #[must_use]
+    #[track_caller]
+    fn check_rename(sql: &str, new_name: &str) -> String {
+        let fixture = Fixture::new(sql);
+        let db = fixture.db();
+        let position = fixture.marker().offset_before();
+        match rename(db, position, new_name) {
+            Ok(changes) => {
+                assert_eq!(changes.len(), 1);
+                apply(db, changes[0].file, &changes[0].edits)
+            }
+            Err(err) => format!("error: {err}"),
+        }
+    }
+
+    #[must_use]
+    #[track_caller]
+    fn check_prepare_rename(sql: &str) -> String {
+        let fixture = Fixture::new_allow_errors(sql);
+        let db = fixture.db();
+        let position = fixture.marker().offset_before();
+        match prepare_rename(db, position) {
+            Ok(range) => position.file_id.content(db)[range].to_string(),
+            Err(err) => format!("error: {err}"),
+        }
+    }
+
+    fn apply(db: &dyn salsa::Database, file: File, edits: &[squawk_syntax::edit::Edit]) -> String {
+        let mut text = file.content(db).to_string();
+        let mut edits = edits.to_vec();
+        edits.sort_by_key(|edit| edit.text_range.start());
+        for edit in edits.iter().rev() {
+            edit.apply(&mut text);
+        }
+        text
+    }
+
+    #[test]
+    fn rename_table() {
+        assert_snapshot!(check_rename("
+create table t(a int);
+insert into t values (1);
+select t.a from t$0;
+", "users"), @"
+        create table users(a int);
+        insert into users values (1);
+        select users.a from users;
+        ");
+    }
+
+    #[test]
+    fn rename_table_qualified() {
+        assert_snapshot!(check_rename("
+create table public.t(a int);
+create index on public.t (a);
+select public.t.a from public.t$0;
+", "u"), @"
+        create table public.u(a int);
+        create index on public.u (a);
+        select public.u.a from public.u;
+        ");
+    }
+
+    #[test]
+    fn rename_column() {
+        assert_snapshot!(check_rename("
+create table t(a$0 int);
+create index on t (a, (lower(a)));
+insert into t (a) values (1);
+update t set a = 1 where t.a = 2;
+select x.a from t x;
+", "b"), @"
+        create table t(b int);
+        create index on t (b, (lower(b)));
+        insert into t (b) values (1);
+        update t set b = 1 where t.b = 2;
+        select x.b from t x;
+        ");
+    }
+
+    #[test]
+    fn rename_column_keeps_output_names() {
+        assert_snapshot!(check_rename("
+create table t(a$0 int);
+create view v as select a from t;
+create materialized view m as select t.a, a as c from t;
+select a from v;
+with w as (select a from t union select a from t) select a from w;
+select s.a from (select a from t) s;
+select a, (select a from t limit 1) from t;
+", "b"), @"
+        create table t(b int);
+        create view v as select b as a from t;
+        create materialized view m as select t.b as a, b as c from t;
+        select a from v;
+        with w as (select b as a from t union select b as a from t) select a from w;
+        select s.a from (select b as a from t) s;
+        select b, (select b from t limit 1) from t;
+        ");
+    }
+
+    #[test]
+    fn rename_column_from_reference() {
+        assert_snapshot!(check_rename("
+create table t(a int, b int);
+create index i on t (a$0);
+", "c"), @"
+        create table t(c int, b int);
+        create index i on t (c);
+        ");
+    }
+
+    #[test]
+    fn rename_quotes_when_needed() {
+        assert_snapshot!(check_rename(r#"
+create table "T"(a int);
+select * from "T"$0;
+"#, "users"), @"
+        create table users(a int);
+        select * from users;
+        ");
+        assert_snapshot!(check_rename("
+create table t(a int);
+select * from t$0;
+", "Users"), @"
+        create table users(a int);
+        select * from users;
+        ");
+        assert_snapshot!(check_rename("
+create table t(a int);
+select * from t$0;
+", r#""User""#), @r#"
+        create table "User"(a int);
+        select * from "User";
+        "#);
+        assert_snapshot!(check_rename("
+create table t(a$0 int);
+select a from t;
+", "select"), @r#"
+        create table t("select" int);
+        select "select" from t;
+        "#);
+    }
+
+    #[test]
+    fn rename_function() {
+        assert_snapshot!(check_rename("
+create function f(x int) returns int language sql as 'select 1';
+select f$0(1);
+", "g"), @"
+        create function g(x int) returns int language sql as 'select 1';
+        select g(1);
+        ");
+    }
+
+    #[test]
+    fn rename_invalid_name() {
+        assert_snapshot!(check_rename("
+create table t();
+select * from t$0;
+", "my table"), @"error: Invalid name `my table`");
+        assert_snapshot!(check_rename("
+create table t();
+select * from t$0;
+", r#""""#), @r#"error: Invalid name `""`"#);
+    }
+
+    #[test]
+    fn rename_conflict_table() {
+        assert_snapshot!(check_rename("
+create table t();
+create table u();
+select * from t$0;
+", "u"), @"error: `u` is already defined");
+        // indexes and tables share a namespace
+        assert_snapshot!(check_rename("
+create table t(a int);
+create index u on t (a);
+select * from t$0;
+", "u"), @"error: `u` is already defined");
+    }
+
+    #[test]
+    fn rename_no_conflict_other_schema() {
+        assert_snapshot!(check_rename("
+create schema s;
+create table t();
+create table s.u();
+select * from t$0;
+", "u"), @"
+        create schema s;
+        create table u();
+        create table s.u();
+        select * from u;
+        ");
+    }
+
+    #[test]
+    fn rename_conflict_column() {
+        assert_snapshot!(check_rename("
+create table t(a int, b int);
+select a$0 from t;
+", "b"), @"error: `b` is already defined");
+        // columns in other tables are fine
+        assert_snapshot!(check_rename("
+create table t(a int);
+create table u(b int);
+select a$0 from t;
+", "b"), @"
+        create table t(b int);
+        create table u(b int);
+        select b from t;
+        ");
+    }
+
+    #[test]
+    fn rename_notebook() {
+        let (db, files) = notebook(&[
+            "create table t(a int);",
+            "select a from t;",
+            "drop table t;",
+        ]);
+        let position = InFile::new(files[1], 15.into());
+        let changes = rename(&db, position, "u").unwrap();
+        let out = changes
+            .iter()
+            .map(|change| apply(&db, change.file, &change.edits))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_snapshot!(out, @"
+        select a from u;
+        create table u(a int);
+        drop table u;
+        ");
+    }
+
+    #[test]
+    fn prepare_rename_range() {
+        assert_snapshot!(check_prepare_rename(r#"
+create table "T"();
+select * from "T$0";
+"#), @r#""T""#);
+        assert_snapshot!(check_prepare_rename("
+create table t(a int);
+select a$0 from t;
+"), @"a");
+    }
+
+    #[test]
+    fn prepare_rename_builtin() {
+        assert_snapshot!(check_prepare_rename("
+-- include-builtins
+select now$0();
+"), @"error: Cannot rename builtin `now`");
+    }
+
+    #[test]
+    fn prepare_rename_nothing() {
+        assert_snapshot!(check_prepare_rename("
+sel$0ect 1;
+"), @"error: No references found at position");
+        assert_snapshot!(check_prepare_rename("
+select case$0 when true then 1 end;
+"), @"error: No references found at position");
+        assert_snapshot!(check_prepare_rename("
+select * from missing$0;
+"), @"error: No references found at position");
+    }
+
+    #[test]
+    fn rename_across_migrations() {
+        let (db, files) = workspace(&[
+            "create table t(a int);",
+            "select a from t;",
+            "drop table t;",
+        ]);
+        let position = InFile::new(files[1], 15.into());
+        let changes = rename(&db, position, "u").unwrap();
+        let out = changes
+            .iter()
+            .map(|change| apply(&db, change.file, &change.edits))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_snapshot!(out, @"
+        select a from u;
+        create table u(a int);
+        drop table u;
+        ");
+    }
+
+    #[test]
+    fn rename_conflict_in_other_migration() {
+        let (db, files) = workspace(&["create table t(a int);", "create table u(b int);"]);
+        let position = InFile::new(files[0], 13.into());
+        let Err(err) = rename(&db, position, "u") else {
+            panic!("expected a conflict");
+        };
+        assert_eq!(err.to_string(), "`u` is already defined");
+    }
+}
@@ -2880,6 +2880,91 @@ fn resolve_enclosing_function_param(
     None
 }
 
+/// Variables declared in the PL/pgSQL blocks and loops around `node`, the
+/// innermost first.
+pub(crate) fn plpgsql_variables_in_scope(node: &SyntaxNode) -> Vec<ast::PlpgsqlVariable> {
+    let mut variables = vec![];
+    for ancestor in node.ancestors() {
+        if let Some(block) = ast::PlpgsqlBlock::cast(ancestor.clone()) {
+            let decls = block
+                .plpgsql_declare_section()
+                .into_iter()
+                .flat_map(|section| section.plpgsql_decls());
+            for decl in decls {
+                variables.extend(match decl {
+                    ast::PlpgsqlDecl::PlpgsqlVarDecl(decl) => decl.plpgsql_variable(),
+                    ast::PlpgsqlDecl::PlpgsqlAliasDecl(decl) => decl.plpgsql_variable(),
+                    ast::PlpgsqlDecl::PlpgsqlCursorDecl(decl) => decl.plpgsql_variable(),
+                });
+            }
+        } else if let Some(for_int) = ast::PlpgsqlForInt::cast(ancestor.clone()) {
+            variables.extend(for_int.plpgsql_variable());
+        } else if let Some(for_query) = ast::PlpgsqlForQuery::cast(ancestor.clone()) {
+            variables.extend(for_query.plpgsql_variables());
+        } else if let Some(foreach) = ast::PlpgsqlForeach::cast(ancestor) {
+            variables.extend(foreach.plpgsql_variables());
+        }
+    }
+    variables
+}
+
+/// Resolves a name in a PL/pgSQL body that isn't a column to the variable
+/// it refers to, declared in an enclosing block or loop, or to a parameter of
+/// the function.
+pub(crate) fn resolve_plpgsql_variable(
+    db: &dyn Db,
+    name_ref: InFile<&impl ast::NameLike>,
+) -> Option<SmallVec<[Location; 1]>> {
+    let file = name_ref.file_id;
+    let name_ref = name_ref.value;
+    let name = Name::from_node(name_ref);
+
+    let in_body = name_ref
+        .syntax()
+        .ancestors()
+        .any(|ancestor| ast::PlpgsqlRoot::can_cast(ancestor.kind()));
+    if !in_body {
+        return None;
+    }
+    if let Some(variable) = plpgsql_variables_in_scope(name_ref.syntax())
+        .into_iter()
+        .find(|variable| Name::from_node(variable) == name)
+    {
+        return Some(smallvec![Location::new(
+            file,
+            variable.syntax().text_range(),
+            LocationKind::Variable
+        )]);
+    }
+
+    // The body's tree is separate from the file's, so we find the function
+    // from the string literal in the file.
+    let token = parse(db, file)
+        .syntax_node()
+        .token_at_offset(name_ref.syntax().text_range().start())
+        .right_biased()?;
+    for ancestor in token.parent_ancestors() {
+        let Some(has_param_list) = ast::HasParamList::cast(ancestor) else {
+            continue;
+        };
+        let Some(param_list) = has_param_list.param_list() else {
+            continue;
+        };
+        for param in param_list.params() {
+            if let Some(param_name) = param.name()
+                && Name::from_node(&param_name) == name
+            {
+                return Some(smallvec![Location::new(
+                    file,
+                    param_name.syntax().text_range(),
+                    LocationKind::NamedArgParameter
+                )]);
+            }
+        }
+    }
+    None
+}
+
 fn resolve_select_group_by_alias_or_column_ptr(
     db: &dyn Db,
     column_name_ref: InFile<&impl ast::NameLike>,
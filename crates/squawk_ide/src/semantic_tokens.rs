@@ -222,9 +222,10 @@ impl TryFrom<LocationKind> for SemanticTokenType {
                 Ok(SemanticTokenType::Function)
             }
             LocationKind::Column => Ok(SemanticTokenType::Column),
-            LocationKind::JsonPath | LocationKind::Label | LocationKind::Property => {
-                Ok(SemanticTokenType::Name)
-            }
+            LocationKind::JsonPath
+            | LocationKind::Label
+            | LocationKind::Property
+            | LocationKind::Variable => Ok(SemanticTokenType::Name),
             LocationKind::NamedArgParameter => Ok(SemanticTokenType::Parameter),
             LocationKind::Schema => Ok(SemanticTokenType::Schema),
             LocationKind::PropertyGraph => Ok(SemanticTokenType::PropertyGraph),
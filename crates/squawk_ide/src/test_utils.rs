@@ -1,8 +1,9 @@
 use std::ops::Range;
 
-use crate::db::{Database, File, set_include_builtins};
+use crate::db::{Database, File, Notebook, set_include_builtins, set_workspace_files};
 use crate::file::InFile;
 use rowan::TextSize;
+use salsa::Setter;
 
 // TODO: we should probably use something else since `$0` is valid syntax, maybe `%0`?
 const MARKER: &str = "$0";
@@ -81,6 +82,37 @@ impl Fixture {
     pub(crate) fn db(&self) -> &Database {
         &self.db
     }
+
+    pub(crate) fn db_mut(&mut self) -> &mut Database {
+        &mut self.db
+    }
+}
+
+/// Create a notebook with a file per cell.
+pub(crate) fn notebook(cells: &[&str]) -> (Database, Vec<File>) {
+    let mut db = Database::default();
+    set_include_builtins(&mut db, false);
+    let files: Vec<File> = cells
+        .iter()
+        .map(|cell| File::new(&db, (*cell).into()))
+        .collect();
+    let notebook = Notebook::new(&db, files.clone());
+    for file in &files {
+        file.set_notebook(&mut db).to(Some(notebook));
+    }
+    (db, files)
+}
+
+/// Create a workspace with a file per migration, in order.
+pub(crate) fn workspace(migrations: &[&str]) -> (Database, Vec<File>) {
+    let mut db = Database::default();
+    set_include_builtins(&mut db, false);
+    let files: Vec<File> = migrations
+        .iter()
+        .map(|migration| File::new(&db, (*migration).into()))
+        .collect();
+    set_workspace_files(&mut db, files.clone());
+    (db, files)
 }
 
 impl Marker {
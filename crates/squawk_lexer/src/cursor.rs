@@ -1,5 +1,7 @@
 use std::str::Chars;
 
+use crate::Templating;
+
 /// Peekable iterator over a char sequence.
 ///
 /// Next characters can be peeked via `first` method,
@@ -12,6 +14,10 @@ pub(crate) struct Cursor<'a> {
     /// Iterator over chars. Slightly faster than a &str.
     chars: Chars<'a>,
     len_remaining: usize,
+    /// The last consumed char, used to tell `::"t"` casts apart from psql
+    /// variables.
+    prev: char,
+    pub(crate) templating: Option<Templating>,
 }
 
 pub(crate) const EOF_CHAR: char = '\0';
@@ -21,9 +27,16 @@ impl<'a> Cursor<'a> {
         Cursor {
             len_remaining: input.len(),
             chars: input.chars(),
+            prev: EOF_CHAR,
+            templating: None,
         }
     }
 
+    /// Returns the last consumed char, or `EOF_CHAR` at the start of the input.
+    pub(crate) fn prev(&self) -> char {
+        self.prev
+    }
+
     /// Peeks the next symbol from the input stream without consuming it.
     /// If requested position doesn't exist, `EOF_CHAR` is returned.
     /// However, getting `EOF_CHAR` doesn't always mean actual end of file,
@@ -60,7 +73,9 @@ impl<'a> Cursor<'a> {
 
     /// Moves to the next character.
     pub(crate) fn bump(&mut self) -> Option<char> {
-        self.chars.next()
+        let c = self.chars.next()?;
+        self.prev = c;
+        Some(c)
     }
 
     /// Eats symbols while predicate returns true or until the end of file is reached.
@@ -0,0 +1,78 @@
+// based on https://github.com/rust-lang/rust/blob/ba1d7f4a083e6402679105115ded645512a7aea8/compiler/rustc_span/src/edit_distance.rs
+
+/// Case insensitive Damerau-Levenshtein distance between `a` and `b`, where
+/// swapping two adjacent chars counts as a single edit, e.g., `form` and
+/// `from`.
+///
+/// Returns `None` when the distance is greater than `limit`.
+pub fn edit_distance(a: &str, b: &str, limit: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let b: Vec<char> = b.chars().map(|c| c.to_ascii_lowercase()).collect();
+
+    if a.len().abs_diff(b.len()) > limit {
+        return None;
+    }
+
+    // distances for the previous two rows of the matrix
+    let mut prev_prev = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                curr[j] = curr[j].min(prev_prev[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut prev_prev, &mut prev);
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let distance = prev[b.len()];
+    (distance <= limit).then_some(distance)
+}
+
+/// Finds the keyword that `word` is most likely a typo of, e.g., `selct` for
+/// `select`.
+///
+/// Short words need to be a closer match so we don't suggest `as` for `at`.
+pub fn closest_keyword<'a>(
+    word: &str,
+    keywords: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let limit = (word.chars().count() / 3).max(1);
+    keywords
+        .into_iter()
+        .filter(|keyword| keyword.len() > 2 && !keyword.eq_ignore_ascii_case(word))
+        .filter_map(|keyword| Some((edit_distance(word, keyword, limit)?, keyword)))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, keyword)| keyword)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("select", "select", 3), Some(0));
+        assert_eq!(edit_distance("selct", "SELECT", 3), Some(1));
+        assert_eq!(edit_distance("form", "from", 3), Some(1));
+        assert_eq!(edit_distance("tabel", "table", 3), Some(1));
+        assert_eq!(edit_distance("insert", "update", 3), None);
+        assert_eq!(edit_distance("a", "alter", 3), None);
+    }
+
+    #[test]
+    fn closest() {
+        let keywords = ["select", "set", "delete", "create", "alter"];
+        assert_eq!(closest_keyword("selct", keywords), Some("select"));
+        assert_eq!(closest_keyword("craete", keywords), Some("create"));
+        assert_eq!(closest_keyword("atler", keywords), Some("alter"));
+        assert_eq!(closest_keyword("users", keywords), None);
+        assert_eq!(closest_keyword("id", keywords), None);
+    }
+}
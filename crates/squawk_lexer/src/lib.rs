@@ -1,7 +1,11 @@
 mod cursor;
+mod edit_distance;
 mod token;
+use std::fmt;
+
 use cursor::{Cursor, EOF_CHAR};
-pub use token::{Base, LiteralKind, Token, TokenKind};
+pub use edit_distance::{closest_keyword, edit_distance};
+pub use token::{Base, LiteralKind, PsqlVariableKind, Token, TokenKind};
 
 // via: https://github.com/postgres/postgres/blob/db0c96cc18aec417101e37e59fcc53d4bf647915/src/backend/parser/scan.l#L346
 // ident_start		[A-Za-z\200-\377_]
@@ -32,6 +36,7 @@ const fn is_whitespace(c: char) -> bool {
 impl Cursor<'_> {
     // see: https://github.com/rust-lang/rust/blob/ba1d7f4a083e6402679105115ded645512a7aea8/compiler/rustc_lexer/src/lib.rs#L339
     pub(crate) fn advance_token(&mut self) -> Token {
+        let prev = self.prev();
         let Some(first_char) = self.bump() else {
             return Token::new(TokenKind::Eof, 0);
         };
@@ -104,7 +109,8 @@ impl Cursor<'_> {
                 TokenKind::Literal { kind: literal_kind }
             }
             '.' => match self.first() {
-                '0'..='9' => {
+                // `1..3` is a range in PL/pgSQL, not `1.` followed by `.3`
+                '0'..='9' if prev != '.' => {
                     let literal_kind = self.number('.');
                     TokenKind::Literal { kind: literal_kind }
                 }
@@ -117,16 +123,31 @@ impl Cursor<'_> {
             ')' => TokenKind::CloseParen,
             '[' => TokenKind::OpenBracket,
             ']' => TokenKind::CloseBracket,
+            '{' if self.templating == Some(Templating::Jinja)
+                && matches!(self.first(), '{' | '%' | '#') =>
+            {
+                self.jinja()
+            }
             '{' => TokenKind::OpenCurly,
             '}' => TokenKind::CloseCurly,
             '@' => TokenKind::At,
             '#' => TokenKind::Pound,
             '~' => TokenKind::Tilde,
             '?' => TokenKind::Question,
+            // A `:` right after a value is a cast or slice, like `::"t"` or
+            // `a['x':'y']`, rather than a psql variable.
+            ':' if !is_ident_cont(prev) && !matches!(prev, ':' | '\'' | '"' | ')' | ']') => {
+                self.psql_variable().unwrap_or(TokenKind::Colon)
+            }
             ':' => TokenKind::Colon,
+            '\\' => self.meta_command(),
             '$' => {
                 if self.is_dollar_quote_start() {
                     self.dollar_quoted_string()
+                } else if self.templating == Some(Templating::Grafana)
+                    && (self.first() == '{' || is_ident_start(self.first()))
+                {
+                    self.grafana_variable()
                 } else {
                     // Parameters
                     while self.first().is_ascii_digit() {
@@ -139,6 +160,11 @@ impl Cursor<'_> {
                     }
                 }
             }
+            '`' if self.is_backtick_quoted_ident() => {
+                self.eat_while(|c| c != '`');
+                self.bump();
+                TokenKind::BacktickQuotedIdent
+            }
             '`' => TokenKind::Backtick,
             '=' => TokenKind::Eq,
             '!' => TokenKind::Bang,
@@ -172,6 +198,98 @@ impl Cursor<'_> {
         self.reset_pos_within_token();
         res
     }
+
+    fn meta_command(&mut self) -> TokenKind {
+        // `\;` adds a semicolon to the query buffer without sending it, so
+        // the line continues with more SQL.
+        if self.first() == ';' {
+            self.bump();
+            return TokenKind::MetaCommand;
+        }
+        self.eat_while(|c| !matches!(c, '\n' | '\r'));
+        TokenKind::MetaCommand
+    }
+
+    // Ends at the first `}}`, `%}` or `#}`, so we don't have to understand
+    // Jinja's expressions.
+    // see: https://jinja.palletsprojects.com/en/stable/templates/#synopsis
+    fn jinja(&mut self) -> TokenKind {
+        let open = self.bump();
+        let close = match open {
+            Some('{') => '}',
+            Some(c) => c,
+            None => EOF_CHAR,
+        };
+        let mut terminated = false;
+        while let Some(c) = self.bump() {
+            if c == close && self.first() == '}' {
+                self.bump();
+                terminated = true;
+                break;
+            }
+        }
+        if open == Some('{') {
+            TokenKind::TemplateExpr { terminated }
+        } else {
+            TokenKind::TemplateBlock { terminated }
+        }
+    }
+
+    // `${region}`, `${region:csv}`, `$region`, or a macro call like
+    // `$__timeFilter(time)`.
+    // see: https://grafana.com/docs/grafana/latest/dashboards/variables/variable-syntax/
+    fn grafana_variable(&mut self) -> TokenKind {
+        if self.first() == '{' {
+            self.bump();
+            self.eat_while(|c| c != '}');
+            let terminated = self.bump() == Some('}');
+            return TokenKind::TemplateExpr { terminated };
+        }
+        self.eat_while(|c| c.is_alphanumeric() || c == '_');
+        if self.first() != '(' {
+            return TokenKind::TemplateExpr { terminated: true };
+        }
+        let mut depth = 0;
+        while let Some(c) = self.bump() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return TokenKind::TemplateExpr { terminated: true };
+                    }
+                }
+                _ => (),
+            }
+        }
+        TokenKind::TemplateExpr { terminated: false }
+    }
+
+    // psql's variable names are letters, digits, and underscores, anything
+    // else is left as a `:` followed by a string or quoted identifier.
+    // see: https://github.com/postgres/postgres/blob/master/src/fe_utils/psqlscan.l
+    fn psql_variable(&mut self) -> Option<TokenKind> {
+        let quote = self.first();
+        let kind = match quote {
+            '\'' => PsqlVariableKind::Literal,
+            '"' => PsqlVariableKind::Ident,
+            _ => return None,
+        };
+        let mut chars = self.chars();
+        chars.next();
+        let name_len = chars
+            .clone()
+            .take_while(|c| matches!(c, 'a'..='z' | 'A'..='Z' | '_' | '0'..='9' | '\u{80}'..))
+            .count();
+        if name_len == 0 || chars.nth(name_len) != Some(quote) {
+            return None;
+        }
+        for _ in 0..name_len + 2 {
+            self.bump();
+        }
+        Some(TokenKind::PsqlVariable { kind })
+    }
+
     pub(crate) fn ident(&mut self) -> TokenKind {
         self.eat_while(is_ident_cont);
         TokenKind::Ident
@@ -300,7 +418,8 @@ impl Cursor<'_> {
         };
 
         match self.first() {
-            '.' => {
+            // `1..3` is an int followed by `..`, like Postgres' `numericfail` rule
+            '.' if self.second() != '.' => {
                 self.bump();
                 self.eat_fractional()
             }
@@ -381,6 +500,23 @@ impl Cursor<'_> {
         false
     }
 
+    /// Check for backtick quoting from mysql, like `` `users` ``, so we can suggest
+    /// double quotes instead of parsing operators.
+    fn is_backtick_quoted_ident(&self) -> bool {
+        let mut chars = self.chars();
+        if !chars.next().is_some_and(is_ident_start) {
+            return false;
+        }
+        for c in chars {
+            match c {
+                '`' => return true,
+                c if is_ident_cont(c) => (),
+                _ => return false,
+            }
+        }
+        false
+    }
+
     /// Check for `$$` and `$tag$`
     fn is_dollar_quote_start(&self) -> bool {
         let mut chars = self.chars();
@@ -558,9 +694,68 @@ impl Cursor<'_> {
     }
 }
 
+/// Template syntax to lex as placeholders, for SQL that another tool renders
+/// before it's run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Templating {
+    /// Jinja, used by dbt, e.g., `{{ ref('users') }}` and `{% if x %}`
+    Jinja,
+    /// Grafana's variables and macros, e.g., `${region}` and
+    /// `$__timeFilter(time)`
+    Grafana,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTemplating {
+    val: String,
+}
+
+impl fmt::Display for UnknownTemplating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid templating {}, expected jinja or grafana",
+            self.val
+        )
+    }
+}
+
+impl std::error::Error for UnknownTemplating {}
+
+impl std::str::FromStr for Templating {
+    type Err = UnknownTemplating;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jinja" => Ok(Templating::Jinja),
+            "grafana" => Ok(Templating::Grafana),
+            _ => Err(UnknownTemplating { val: s.to_string() }),
+        }
+    }
+}
+
+impl fmt::Display for Templating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = match self {
+            Templating::Jinja => "jinja",
+            Templating::Grafana => "grafana",
+        };
+        write!(f, "{val}")
+    }
+}
+
 /// Creates an iterator that produces tokens from the input string.
 pub fn tokenize(input: &str) -> impl Iterator<Item = Token> + '_ {
+    tokenize_with(input, None)
+}
+
+/// Like [`tokenize`], but lexes template syntax as
+/// [`TokenKind::TemplateExpr`] and [`TokenKind::TemplateBlock`] tokens.
+pub fn tokenize_with(
+    input: &str,
+    templating: Option<Templating>,
+) -> impl Iterator<Item = Token> + '_ {
     let mut cursor = Cursor::new(input);
+    cursor.templating = templating;
     std::iter::from_fn(move || {
         let token = cursor.advance_token();
         if token.kind != TokenKind::Eof {
@@ -571,6 +766,29 @@ pub fn tokenize(input: &str) -> impl Iterator<Item = Token> + '_ {
     })
 }
 
+/// The length of the rows after a `COPY ... FROM STDIN;`, up to and including
+/// the `\.` line that ends them, or `None` if there isn't one.
+///
+/// `input` starts at the first row, e.g., the rows in `pg_dump` output:
+///
+/// ```text
+/// 1\tfoo
+/// 2\tbar
+/// \.
+/// ```
+///
+/// see: <https://www.postgresql.org/docs/current/sql-copy.html#SQL-COPY-FILE-FORMATS>
+pub fn copy_data_len(input: &str) -> Option<usize> {
+    let mut len = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim_end_matches(['\r', '\n']) == "\\." {
+            return Some(len + 2);
+        }
+        len += line.len();
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use std::fmt;
@@ -598,10 +816,14 @@ mod tests {
     }
 
     fn lex(input: &str) -> Vec<TokenDebug<'_>> {
+        lex_with(input, None)
+    }
+
+    fn lex_with(input: &str, templating: Option<Templating>) -> Vec<TokenDebug<'_>> {
         let mut tokens = vec![];
         let mut start = 0;
 
-        for token in tokenize(input) {
+        for token in tokenize_with(input, templating) {
             let length = token.len;
             tokens.push(TokenDebug::new(token, input, start));
             start += length;
@@ -1028,4 +1250,238 @@ U&"d!0061t!+000061" UESCAPE '!'
         ]
         "#);
     }
+
+    #[test]
+    fn psql_meta_commands() {
+        assert_debug_snapshot!(lex("\\set id 1\nselect 1 \\gset\r\n\\i 'other file.sql'\nselect 1 \\; select 2;"), @r#"
+        [
+            "\\set id 1" @ MetaCommand,
+            "\n" @ Whitespace,
+            "select" @ Ident,
+            " " @ Whitespace,
+            "1" @ Literal { kind: Int { base: Decimal, empty_int: false, trailing_junk_start: 1 } },
+            " " @ Whitespace,
+            "\\gset" @ MetaCommand,
+            "\r\n" @ Whitespace,
+            "\\i 'other file.sql'" @ MetaCommand,
+            "\n" @ Whitespace,
+            "select" @ Ident,
+            " " @ Whitespace,
+            "1" @ Literal { kind: Int { base: Decimal, empty_int: false, trailing_junk_start: 1 } },
+            " " @ Whitespace,
+            "\\;" @ MetaCommand,
+            " " @ Whitespace,
+            "select" @ Ident,
+            " " @ Whitespace,
+            "2" @ Literal { kind: Int { base: Decimal, empty_int: false, trailing_junk_start: 1 } },
+            ";" @ Semi,
+        ]
+        "#);
+    }
+
+    #[test]
+    fn copy_data() {
+        assert_eq!(copy_data_len("1\tfoo\n2\tbar\n\\.\n"), Some(14));
+        assert_eq!(copy_data_len("1\tfoo\r\n\\.\r\n"), Some(9));
+        assert_eq!(copy_data_len("\\."), Some(2));
+        // `\.` has to be on its own line
+        assert_eq!(copy_data_len("1\t\\.\n"), None);
+        assert_eq!(copy_data_len("1\tfoo\n"), None);
+    }
+
+    #[test]
+    fn psql_variables() {
+        assert_debug_snapshot!(lex(r#"select :'name', :"col" from :"tbl";"#), @r#"
+        [
+            "select" @ Ident,
+            " " @ Whitespace,
+            ":'name'" @ PsqlVariable { kind: Literal },
+            "," @ Comma,
+            " " @ Whitespace,
+            ":\"col\"" @ PsqlVariable { kind: Ident },
+            " " @ Whitespace,
+            "from" @ Ident,
+            " " @ Whitespace,
+            ":\"tbl\"" @ PsqlVariable { kind: Ident },
+            ";" @ Semi,
+        ]
+        "#);
+    }
+
+    #[test]
+    fn psql_variable_like_casts() {
+        assert_debug_snapshot!(lex(r#"select 'a'::"text", a['x':'y'], :'', :'a b', :"a"#), @r#"
+        [
+            "select" @ Ident,
+            " " @ Whitespace,
+            "'a'" @ Literal { kind: Str { terminated: true } },
+            ":" @ Colon,
+            ":" @ Colon,
+            "\"text\"" @ QuotedIdent { terminated: true, uescape: false },
+            "," @ Comma,
+            " " @ Whitespace,
+            "a" @ Ident,
+            "[" @ OpenBracket,
+            "'x'" @ Literal { kind: Str { terminated: true } },
+            ":" @ Colon,
+            "'y'" @ Literal { kind: Str { terminated: true } },
+            "]" @ CloseBracket,
+            "," @ Comma,
+            " " @ Whitespace,
+            ":" @ Colon,
+            "''" @ Literal { kind: Str { terminated: true } },
+            "," @ Comma,
+            " " @ Whitespace,
+            ":" @ Colon,
+            "'a b'" @ Literal { kind: Str { terminated: true } },
+            "," @ Comma,
+            " " @ Whitespace,
+            ":" @ Colon,
+            "\"a" @ QuotedIdent { terminated: false, uescape: false },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn plpgsql_int_range() {
+        assert_debug_snapshot!(lex("1..3 1 .. 3 1.5 .5"), @r#"
+        [
+            "1" @ Literal { kind: Int { base: Decimal, empty_int: false, trailing_junk_start: 1 } },
+            "." @ Dot,
+            "." @ Dot,
+            "3" @ Literal { kind: Int { base: Decimal, empty_int: false, trailing_junk_start: 1 } },
+            " " @ Whitespace,
+            "1" @ Literal { kind: Int { base: Decimal, empty_int: false, trailing_junk_start: 1 } },
+            " " @ Whitespace,
+            "." @ Dot,
+            "." @ Dot,
+            " " @ Whitespace,
+            "3" @ Literal { kind: Int { base: Decimal, empty_int: false, trailing_junk_start: 1 } },
+            " " @ Whitespace,
+            "1.5" @ Literal { kind: Numeric { empty_exponent_start: None, trailing_junk_start: 3 } },
+            " " @ Whitespace,
+            ".5" @ Literal { kind: Numeric { empty_exponent_start: None, trailing_junk_start: 2 } },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn jinja_templates() {
+        assert_debug_snapshot!(lex_with("{{ config(materialized='table') }}\nselect {{ col }} from {{ ref('users') }}\n{%- if is_incremental() %}where a > {{ var(\"a\") }}{% endif %}{# note #}", Some(Templating::Jinja)), @r#"
+        [
+            "{{ config(materialized='table') }}" @ TemplateExpr { terminated: true },
+            "\n" @ Whitespace,
+            "select" @ Ident,
+            " " @ Whitespace,
+            "{{ col }}" @ TemplateExpr { terminated: true },
+            " " @ Whitespace,
+            "from" @ Ident,
+            " " @ Whitespace,
+            "{{ ref('users') }}" @ TemplateExpr { terminated: true },
+            "\n" @ Whitespace,
+            "{%- if is_incremental() %}" @ TemplateBlock { terminated: true },
+            "where" @ Ident,
+            " " @ Whitespace,
+            "a" @ Ident,
+            " " @ Whitespace,
+            ">" @ Gt,
+            " " @ Whitespace,
+            "{{ var(\"a\") }}" @ TemplateExpr { terminated: true },
+            "{% endif %}" @ TemplateBlock { terminated: true },
+            "{# note #}" @ TemplateBlock { terminated: true },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn jinja_templates_unterminated() {
+        assert_debug_snapshot!(lex_with("select {{ col from t; {% if x", Some(Templating::Jinja)), @r#"
+        [
+            "select" @ Ident,
+            " " @ Whitespace,
+            "{{ col from t; {% if x" @ TemplateExpr { terminated: false },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn jinja_templates_without_templating() {
+        assert_debug_snapshot!(lex("{{ a }}"), @r#"
+        [
+            "{" @ OpenCurly,
+            "{" @ OpenCurly,
+            " " @ Whitespace,
+            "a" @ Ident,
+            " " @ Whitespace,
+            "}" @ CloseCurly,
+            "}" @ CloseCurly,
+        ]
+        "#);
+    }
+
+    #[test]
+    fn grafana_templates() {
+        assert_debug_snapshot!(lex_with("select $__timeGroup(time, '1m'), ${region:csv}, $host from t where $__timeFilter(time) and $1 = $$x$$", Some(Templating::Grafana)), @r#"
+        [
+            "select" @ Ident,
+            " " @ Whitespace,
+            "$__timeGroup(time, '1m')" @ TemplateExpr { terminated: true },
+            "," @ Comma,
+            " " @ Whitespace,
+            "${region:csv}" @ TemplateExpr { terminated: true },
+            "," @ Comma,
+            " " @ Whitespace,
+            "$host" @ TemplateExpr { terminated: true },
+            " " @ Whitespace,
+            "from" @ Ident,
+            " " @ Whitespace,
+            "t" @ Ident,
+            " " @ Whitespace,
+            "where" @ Ident,
+            " " @ Whitespace,
+            "$__timeFilter(time)" @ TemplateExpr { terminated: true },
+            " " @ Whitespace,
+            "and" @ Ident,
+            " " @ Whitespace,
+            "$1" @ PositionalParam { trailing_junk_start: 2 },
+            " " @ Whitespace,
+            "=" @ Eq,
+            " " @ Whitespace,
+            "$$x$$" @ Literal { kind: DollarQuotedString { terminated: true } },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn backtick_quoted_idents() {
+        assert_debug_snapshot!(lex("select `id` from `users`.`t` where a ` b and `a b`"), @r#"
+        [
+            "select" @ Ident,
+            " " @ Whitespace,
+            "`id`" @ BacktickQuotedIdent,
+            " " @ Whitespace,
+            "from" @ Ident,
+            " " @ Whitespace,
+            "`users`" @ BacktickQuotedIdent,
+            "." @ Dot,
+            "`t`" @ BacktickQuotedIdent,
+            " " @ Whitespace,
+            "where" @ Ident,
+            " " @ Whitespace,
+            "a" @ Ident,
+            " " @ Whitespace,
+            "`" @ Backtick,
+            " " @ Whitespace,
+            "b" @ Ident,
+            " " @ Whitespace,
+            "and" @ Ident,
+            " " @ Whitespace,
+            "`" @ Backtick,
+            "a" @ Ident,
+            " " @ Whitespace,
+            "b" @ Ident,
+            "`" @ Backtick,
+        ]
+        "#);
+    }
 }
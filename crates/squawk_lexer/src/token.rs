@@ -89,6 +89,37 @@ pub enum TokenKind {
     ///
     /// see: <https://www.postgresql.org/docs/16/sql-syntax-lexical.html#SQL-SYNTAX-IDENTIFIERS>
     QuotedIdent { terminated: bool, uescape: bool },
+    /// Backtick quoted identifier, e.g., `` `users` ``, which Postgres
+    /// doesn't support.
+    BacktickQuotedIdent,
+    /// psql meta-command, e.g., `\set foo 1` or `\gexec`, up to the end of
+    /// the line
+    ///
+    /// see: <https://www.postgresql.org/docs/current/app-psql.html#APP-PSQL-META-COMMANDS>
+    MetaCommand,
+    /// psql variable interpolated as a literal or identifier, e.g., `:'foo'`
+    /// or `:"foo"`
+    ///
+    /// see: <https://www.postgresql.org/docs/current/app-psql.html#APP-PSQL-INTERPOLATION>
+    PsqlVariable { kind: PsqlVariableKind },
+    /// Template expression that's replaced before the SQL is run, e.g.,
+    /// `{{ ref('users') }}` or `$__timeFilter(time)`
+    ///
+    /// Only produced when lexing with [`crate::Templating`].
+    TemplateExpr { terminated: bool },
+    /// Template tag or comment, e.g., `{% if is_incremental() %}` or
+    /// `{# note #}`
+    ///
+    /// Only produced when lexing with [`crate::Templating`].
+    TemplateBlock { terminated: bool },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PsqlVariableKind {
+    /// `:'foo'`, interpolated as a string literal
+    Literal,
+    /// `:"foo"`, interpolated as a quoted identifier
+    Ident,
 }
 
 /// Parsed token.
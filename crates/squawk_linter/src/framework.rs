@@ -0,0 +1,391 @@
+use std::fmt;
+
+use enum_iterator::Sequence;
+use rowan::{NodeOrToken, TextRange};
+use serde::Deserialize;
+use squawk_syntax::{SyntaxKind, SyntaxNode};
+
+use crate::ignore::comment_body;
+
+/// Migration tools that have their own conventions for splitting a file into
+/// up/down sections and for wrapping migrations in transactions.
+#[derive(Debug, PartialEq, Clone, Copy, Hash, Eq, Sequence)]
+pub enum Framework {
+    /// `sqlmigrate` output includes its own `BEGIN` & `COMMIT`, while Python
+    /// migrations run in a transaction unless they set `atomic = False`
+    Django,
+    /// `-- migrate:up transaction:false` & `-- migrate:down`
+    Dbmate,
+    /// each versioned migration runs in a transaction unless it has a
+    /// `-- flyway:executeInTransaction=false` script config
+    Flyway,
+    /// `-- +goose Up`, `-- +goose Down` & `-- +goose NO TRANSACTION`
+    Goose,
+    /// each migration runs in a transaction unless its Ruby file calls
+    /// `disable_ddl_transaction!`
+    Rails,
+    /// deploy scripts include their own `BEGIN` & `COMMIT`
+    Sqitch,
+}
+
+impl TryFrom<&str> for Framework {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "django" => Ok(Framework::Django),
+            "dbmate" => Ok(Framework::Dbmate),
+            "flyway" => Ok(Framework::Flyway),
+            "goose" => Ok(Framework::Goose),
+            "rails" => Ok(Framework::Rails),
+            "sqitch" => Ok(Framework::Sqitch),
+            _ => Err(format!("Unknown framework name: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownFrameworkName {
+    val: String,
+}
+
+impl fmt::Display for UnknownFrameworkName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid framework name {}", self.val)
+    }
+}
+
+impl std::error::Error for UnknownFrameworkName {}
+
+impl std::str::FromStr for Framework {
+    type Err = UnknownFrameworkName;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Framework::try_from(s).map_err(|_| UnknownFrameworkName { val: s.to_string() })
+    }
+}
+
+impl fmt::Display for Framework {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = match self {
+            Framework::Django => "django",
+            Framework::Dbmate => "dbmate",
+            Framework::Flyway => "flyway",
+            Framework::Goose => "goose",
+            Framework::Rails => "rails",
+            Framework::Sqitch => "sqitch",
+        };
+        write!(f, "{val}")
+    }
+}
+
+impl<'de> Deserialize<'de> for Framework {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// How a framework will run a given migration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    /// The part of the file that runs when migrating forward. Violations
+    /// outside of it, like in a down section, aren't reported.
+    pub up: TextRange,
+    /// Whether the framework wraps the up section in a transaction.
+    pub in_transaction: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Annotation {
+    Up { in_transaction: Option<bool> },
+    Down,
+    NoTransaction,
+}
+
+impl Framework {
+    fn in_transaction_by_default(self) -> bool {
+        match self {
+            Framework::Dbmate | Framework::Flyway | Framework::Goose | Framework::Rails => true,
+            Framework::Django | Framework::Sqitch => false,
+        }
+    }
+
+    fn annotation(self, comment: &str) -> Option<Annotation> {
+        match self {
+            Framework::Goose => {
+                let command = comment.trim().strip_prefix("+goose")?.trim();
+                if command.eq_ignore_ascii_case("up") {
+                    Some(Annotation::Up {
+                        in_transaction: None,
+                    })
+                } else if command.eq_ignore_ascii_case("down") {
+                    Some(Annotation::Down)
+                } else if command.eq_ignore_ascii_case("no transaction") {
+                    Some(Annotation::NoTransaction)
+                } else {
+                    None
+                }
+            }
+            Framework::Dbmate => {
+                let mut parts = comment.split_whitespace();
+                match parts.next()? {
+                    "migrate:up" => {
+                        let in_transaction = parts
+                            .filter_map(|option| option.strip_prefix("transaction:"))
+                            .map(|value| value != "false")
+                            .next_back();
+                        Some(Annotation::Up { in_transaction })
+                    }
+                    "migrate:down" => Some(Annotation::Down),
+                    _ => None,
+                }
+            }
+            Framework::Flyway => {
+                let (key, value) = comment.trim().strip_prefix("flyway:")?.split_once('=')?;
+                if key.trim() == "executeInTransaction" && value.trim() == "false" {
+                    Some(Annotation::NoTransaction)
+                } else {
+                    None
+                }
+            }
+            // Django's `atomic = False` and Rails' `disable_ddl_transaction!`
+            // live in the Python and Ruby files, see `squawk::embedded`.
+            Framework::Django | Framework::Rails | Framework::Sqitch => None,
+        }
+    }
+
+    /// Find the up section of the file and whether it will run in a
+    /// transaction based on the framework's annotation comments.
+    #[must_use]
+    pub fn migration(self, file: &SyntaxNode) -> Migration {
+        let file_range = file.text_range();
+        let mut in_transaction = self.in_transaction_by_default();
+        let mut up_start = None;
+        let mut up_end = None;
+
+        for event in file.preorder_with_tokens() {
+            let rowan::WalkEvent::Enter(NodeOrToken::Token(token)) = event else {
+                continue;
+            };
+            if token.kind() != SyntaxKind::COMMENT {
+                continue;
+            }
+            let Some((body, _)) = comment_body(&token) else {
+                continue;
+            };
+            let Some(annotation) = self.annotation(body) else {
+                continue;
+            };
+            let start = token.text_range().start();
+            match annotation {
+                Annotation::Up {
+                    in_transaction: up_in_transaction,
+                } => {
+                    if up_start.is_none() {
+                        up_start = Some(start);
+                    }
+                    if let Some(up_in_transaction) = up_in_transaction {
+                        in_transaction = up_in_transaction;
+                    }
+                }
+                Annotation::Down => {
+                    if up_start.is_some() && up_end.is_none() {
+                        up_end = Some(start);
+                    }
+                }
+                Annotation::NoTransaction => {
+                    in_transaction = false;
+                }
+            }
+        }
+
+        let up = match up_start {
+            Some(start) => TextRange::new(start, up_end.unwrap_or(file_range.end())),
+            None => file_range,
+        };
+
+        Migration { up, in_transaction }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use super::{Framework, Migration};
+    use crate::test_utils::{lint_errors_with, lint_ok_with};
+    use crate::{LinterSettings, Rule};
+
+    fn migration(sql: &str, framework: Framework) -> Migration {
+        let parse = squawk_syntax::SourceFile::parse(sql);
+        framework.migration(&parse.syntax_node())
+    }
+
+    fn settings(framework: Framework) -> LinterSettings {
+        LinterSettings {
+            framework: Some(framework),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_framework_names() {
+        for name in ["django", "dbmate", "flyway", "goose", "rails", "sqitch"] {
+            let framework: Framework = name.parse().unwrap();
+            assert_eq!(framework.to_string(), name);
+        }
+        assert!("alembic".parse::<Framework>().is_err());
+    }
+
+    #[test]
+    fn goose_up_section() {
+        let sql = "-- +goose Up
+create table t (id bigint);
+
+-- +goose Down
+drop table t;
+";
+        let migration = migration(sql, Framework::Goose);
+        assert_eq!(
+            &sql[migration.up],
+            "-- +goose Up\ncreate table t (id bigint);\n\n"
+        );
+        assert!(migration.in_transaction);
+    }
+
+    #[test]
+    fn goose_no_transaction() {
+        let sql = "-- +goose NO TRANSACTION
+-- +goose Up
+create index concurrently i on t (id);
+";
+        assert!(!migration(sql, Framework::Goose).in_transaction);
+    }
+
+    #[test]
+    fn dbmate_transaction_false() {
+        let sql = "-- migrate:up transaction:false
+create index concurrently i on t (id);
+
+-- migrate:down
+drop index i;
+";
+        let migration = migration(sql, Framework::Dbmate);
+        assert_eq!(
+            &sql[migration.up],
+            "-- migrate:up transaction:false\ncreate index concurrently i on t (id);\n\n"
+        );
+        assert!(!migration.in_transaction);
+    }
+
+    #[test]
+    fn flyway_execute_in_transaction_false() {
+        let sql = "-- flyway:executeInTransaction=false
+create index concurrently i on t (id);
+";
+        assert!(!migration(sql, Framework::Flyway).in_transaction);
+        lint_ok_with(
+            sql,
+            settings(Framework::Flyway),
+            Rule::BanConcurrentIndexCreationInTransaction,
+        );
+    }
+
+    #[test]
+    fn flyway_execute_in_transaction_true() {
+        let sql = "-- flyway:executeInTransaction=true
+create index concurrently i on t (id);
+";
+        assert!(migration(sql, Framework::Flyway).in_transaction);
+    }
+
+    #[test]
+    fn no_annotations_uses_whole_file() {
+        let sql = "create table t (id bigint);\n";
+        for framework in enum_iterator::all::<Framework>() {
+            assert_eq!(&sql[migration(sql, framework).up], sql);
+        }
+        assert!(migration(sql, Framework::Flyway).in_transaction);
+        assert!(!migration(sql, Framework::Sqitch).in_transaction);
+        assert!(!migration(sql, Framework::Django).in_transaction);
+        assert!(migration(sql, Framework::Rails).in_transaction);
+    }
+
+    #[test]
+    fn goose_down_section_is_not_linted() {
+        let sql = "-- +goose Up
+create table t (id bigint);
+
+-- +goose Down
+drop table t;
+";
+        lint_ok_with(sql, settings(Framework::Goose), Rule::BanDropTable);
+    }
+
+    #[test]
+    fn goose_concurrent_index_in_transaction_err() {
+        let sql = "-- +goose Up
+create index concurrently i on t (id);
+
+-- +goose Down
+drop index i;
+";
+        assert_snapshot!(lint_errors_with(
+            sql,
+            settings(Framework::Goose),
+            Rule::BanConcurrentIndexCreationInTransaction
+        ));
+    }
+
+    #[test]
+    fn goose_no_transaction_concurrent_index_ok() {
+        let sql = "-- +goose NO TRANSACTION
+-- +goose Up
+create index concurrently i on t (id);
+
+-- +goose Down
+drop index i;
+";
+        lint_ok_with(
+            sql,
+            settings(Framework::Goose),
+            Rule::BanConcurrentIndexCreationInTransaction,
+        );
+    }
+
+    #[test]
+    fn dbmate_transaction_false_concurrent_index_ok() {
+        let sql = "-- migrate:up transaction:false
+create index concurrently i on t (id);
+
+-- migrate:down
+drop index i;
+";
+        lint_ok_with(
+            sql,
+            settings(Framework::Dbmate),
+            Rule::BanConcurrentIndexCreationInTransaction,
+        );
+    }
+
+    #[test]
+    fn framework_overrides_assume_in_transaction() {
+        let sql = "
+begin;
+create index concurrently i on t (id);
+commit;
+";
+        lint_ok_with(
+            sql,
+            LinterSettings {
+                framework: Some(Framework::Django),
+                assume_in_transaction: true,
+                ..Default::default()
+            },
+            Rule::TransactionNesting,
+        );
+    }
+}
@@ -60,27 +60,33 @@ pub const IGNORE_LINE_TEXT: &str = "squawk-ignore";
 pub const IGNORE_FILE_TEXT: &str = "squawk-ignore-file";
 
 pub fn ignore_rule_info(token: &SyntaxToken) -> Option<(&str, TextRange, IgnoreKind)> {
-    if let Some((comment_body, range)) = comment_body(token) {
-        let without_start = comment_body.trim_start();
-        let trim_start_size = comment_body.len() - without_start.len();
-
-        let without_end = trim_trailing_comment(without_start);
-        let trim_end_size = without_start.len() - without_end.len();
-
-        for (prefix, kind) in [
-            (IGNORE_FILE_TEXT, IgnoreKind::File),
-            (IGNORE_LINE_TEXT, IgnoreKind::Line),
-        ] {
-            if let Some(without_prefix) = without_end.strip_prefix(prefix) {
-                let start = range.start() + TextSize::new((trim_start_size + prefix.len()) as u32);
-                let end = range.end() - TextSize::new(trim_end_size as u32);
-
-                let range = TextRange::new(start, end);
-                return Some((without_prefix, range, kind));
-            }
-        }
-    }
-    None
+    [
+        (IGNORE_FILE_TEXT, IgnoreKind::File),
+        (IGNORE_LINE_TEXT, IgnoreKind::Line),
+    ]
+    .into_iter()
+    .find_map(|(prefix, kind)| {
+        let (rest, range) = directive_info(token, prefix)?;
+        Some((rest, range, kind))
+    })
+}
+
+/// The text after `prefix` in a directive comment, along with its range,
+/// e.g., `-- squawk-fmt: off -- hand aligned` with the `squawk-fmt:` prefix
+/// becomes ` off`.
+pub fn directive_info<'a>(token: &'a SyntaxToken, prefix: &str) -> Option<(&'a str, TextRange)> {
+    let (comment_body, range) = comment_body(token)?;
+    let without_start = comment_body.trim_start();
+    let trim_start_size = comment_body.len() - without_start.len();
+
+    let without_end = trim_trailing_comment(without_start);
+    let trim_end_size = without_start.len() - without_end.len();
+
+    let without_prefix = without_end.strip_prefix(prefix)?;
+    let start = range.start() + TextSize::new((trim_start_size + prefix.len()) as u32);
+    let end = range.end() - TextSize::new(trim_end_size as u32);
+
+    Some((without_prefix, TextRange::new(start, end)))
 }
 
 pub(crate) fn find_ignores(ctx: &mut Linter, file: &SyntaxNode) {
@@ -8,17 +8,21 @@ use ignore::find_ignores;
 use ignore::has_disable_assume_in_transaction;
 use ignore_index::IgnoreIndex;
 use rowan::TextRange;
-use rowan::TextSize;
 use serde::Deserialize;
 
 use squawk_syntax::SyntaxNode;
 use squawk_syntax::{Parse, SourceFile};
 
+pub use framework::{Framework, Migration};
+pub use squawk_syntax::Dialect;
+pub use squawk_syntax::edit::Edit;
 pub use version::Version;
 
 pub mod analyze;
+mod framework;
 pub mod ignore;
 mod ignore_index;
+mod plpgsql;
 mod version;
 mod visitors;
 
@@ -121,6 +125,48 @@ impl Rule {
         )
     }
 
+    /// Whether the rule is relevant for `dialect`.
+    ///
+    /// CockroachDB and YugabyteDB build indexes online, and CockroachDB runs
+    /// other schema changes in the background too, so rules about blocking
+    /// locks don't apply there. Aurora DSQL doesn't take locks, since it uses
+    /// optimistic concurrency, and rejects the statements some rules suggest,
+    /// like `concurrently`.
+    pub fn applies_to(&self, dialect: Dialect) -> bool {
+        match dialect {
+            Dialect::Postgres => true,
+            Dialect::CockroachDb => !matches!(
+                self,
+                Rule::RequireConcurrentIndexCreation
+                    | Rule::RequireConcurrentIndexDeletion
+                    | Rule::BanConcurrentIndexCreationInTransaction
+                    | Rule::RequireConcurrentReindex
+                    | Rule::RequireConcurrentPartitionDetach
+                    | Rule::ConstraintMissingNotValid
+                    | Rule::AddingFieldWithDefault
+                    | Rule::AddingForeignKeyConstraint
+                    | Rule::DisallowedUniqueConstraint
+                    | Rule::PreferRepack
+            ),
+            Dialect::AuroraDsql => !matches!(
+                self,
+                Rule::RequireConcurrentIndexDeletion
+                    | Rule::BanConcurrentIndexCreationInTransaction
+                    | Rule::RequireConcurrentReindex
+                    | Rule::RequireConcurrentPartitionDetach
+                    | Rule::AddingForeignKeyConstraint
+                    | Rule::AddingSerialPrimaryKeyField
+                    | Rule::PreferIdentity
+                    | Rule::PreferRepack
+                    | Rule::RequireLockTimeout
+            ),
+            Dialect::YugabyteDb => !matches!(
+                self,
+                Rule::RequireConcurrentIndexCreation | Rule::PreferRepack
+            ),
+        }
+    }
+
     /// Rules that are deprecated aliases for other rules.
     pub fn expands_to(&self) -> &[Rule] {
         match self {
@@ -282,33 +328,6 @@ impl Fix {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Edit {
-    pub text_range: TextRange,
-    // TODO: does this need to be an Option?
-    pub text: Option<String>,
-}
-impl Edit {
-    pub fn insert<T: Into<String>>(text: T, at: TextSize) -> Self {
-        Self {
-            text_range: TextRange::new(at, at),
-            text: Some(text.into()),
-        }
-    }
-    pub fn replace<T: Into<String>>(text_range: TextRange, text: T) -> Self {
-        Self {
-            text_range,
-            text: Some(text.into()),
-        }
-    }
-    pub fn delete(text_range: TextRange) -> Self {
-        Self {
-            text_range,
-            text: None,
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Violation {
     // TODO: should this be String instead?
@@ -365,6 +384,12 @@ impl Violation {
 pub struct LinterSettings {
     pub pg_version: Version,
     pub assume_in_transaction: bool,
+    /// When set, the framework's annotations decide which part of the file
+    /// is linted and whether it's assumed to run in a transaction.
+    pub framework: Option<Framework>,
+    /// The database the migrations run against. Rules about locking that
+    /// doesn't happen there are skipped, see [`Rule::applies_to`].
+    pub dialect: Dialect,
 }
 
 pub struct Linter {
@@ -385,10 +410,38 @@ impl Linter {
 
     #[must_use]
     pub fn lint(&mut self, file: &Parse<SourceFile>, text: &str) -> Vec<Violation> {
+        let migration = self
+            .settings
+            .framework
+            .map(|framework| framework.migration(&file.syntax_node()));
+        if let Some(migration) = &migration {
+            self.settings.assume_in_transaction = migration.in_transaction;
+        }
         if has_disable_assume_in_transaction(&file.syntax_node()) {
             self.settings.assume_in_transaction = false;
         }
+        let dialect = self.settings.dialect;
+        self.rules.retain(|rule| rule.applies_to(dialect));
+
+        self.run_rules(file, false);
+
+        // locate any ignores in the file
+        find_ignores(self, &file.syntax_node());
+
+        // Statements inside PL/pgSQL bodies, like the `alter table` in a `do`
+        // block, get linted too.
+        if let Some(embedded_text) = plpgsql::embedded_sql(&file.tree(), text) {
+            let embedded = SourceFile::parse_dialect(&embedded_text, None, dialect);
+            self.run_rules(&embedded, true);
+            find_ignores(self, &embedded.syntax_node());
+        }
+
+        self.errors(text, migration.as_ref())
+    }
 
+    /// Rules that depend on the statements around them, like whether a lock
+    /// timeout was set, are skipped for statements in PL/pgSQL bodies.
+    fn run_rules(&mut self, file: &Parse<SourceFile>, embedded: bool) {
         if self.rules.contains(&Rule::AddingFieldWithDefault) {
             adding_field_with_default(self, file);
         }
@@ -473,18 +526,19 @@ impl Linter {
         if self.rules.contains(&Rule::BanAlterDomainWithAddConstraint) {
             ban_alter_domain_with_add_constraint(self, file);
         }
-        if self.rules.contains(&Rule::TransactionNesting) {
+        if !embedded && self.rules.contains(&Rule::TransactionNesting) {
             transaction_nesting(self, file);
         }
         if self.rules.contains(&Rule::BanTruncateCascade) {
             ban_truncate_cascade(self, file);
         }
-        if self.rules.contains(&Rule::RequireLockTimeout)
-            || self.rules.contains(&Rule::RequireStatementTimeout)
+        if !embedded
+            && (self.rules.contains(&Rule::RequireLockTimeout)
+                || self.rules.contains(&Rule::RequireStatementTimeout))
         {
             require_timeout_settings(self, file);
         }
-        if self.rules.contains(&Rule::BanUncommittedTransaction) {
+        if !embedded && self.rules.contains(&Rule::BanUncommittedTransaction) {
             ban_uncommitted_transaction(self, file);
         }
         if self.rules.contains(&Rule::RequireEnumValueOrdering) {
@@ -506,14 +560,9 @@ impl Linter {
             ban_duplicate_column_assignments(self, file);
         }
         // xtask:new-rule:rule-call
-
-        // locate any ignores in the file
-        find_ignores(self, &file.syntax_node());
-
-        self.errors(text)
     }
 
-    fn errors(&mut self, text: &str) -> Vec<Violation> {
+    fn errors(&mut self, text: &str, migration: Option<&Migration>) -> Vec<Violation> {
         let ignore_index = IgnoreIndex::new(text, &self.ignores);
         let mut errors: Vec<Violation> = self
             .errors
@@ -521,6 +570,8 @@ impl Linter {
             // TODO: we should have errors for when there was an ignore but that
             // ignore didn't actually ignore anything
             .filter(|err| !ignore_index.contains(err.text_range, err.code))
+            // only the up section of a migration is run
+            .filter(|err| migration.is_none_or(|m| m.up.contains_range(err.text_range)))
             .cloned()
             .collect::<Vec<_>>();
         // ensure we order them by where they appear in the file
@@ -589,6 +640,19 @@ mod tests {
         assert_debug_snapshot!(rule1, @"PreferTimestampTz");
     }
 
+    #[test]
+    fn rules_skipped_for_dialect() {
+        let sql = "create index i on t (a);";
+        let file = SourceFile::parse(sql);
+
+        let mut linter = Linter::from([Rule::RequireConcurrentIndexCreation]);
+        assert_eq!(linter.lint(&file, sql).len(), 1);
+
+        let mut linter = Linter::from([Rule::RequireConcurrentIndexCreation]);
+        linter.settings.dialect = Dialect::CockroachDb;
+        assert_eq!(linter.lint(&file, sql), vec![]);
+    }
+
     #[test]
     fn invalid_rule_name() {
         let result: Result<Rule, _> = "invalid-rule-name".parse();
@@ -0,0 +1,112 @@
+use rowan::{TextRange, TextSize};
+use squawk_syntax::{
+    SourceFile, SyntaxKind,
+    ast::{self, AstNode},
+    quote::{dollar_quote_tag, strip_quotes},
+};
+
+/// Builds a copy of `text` where everything except the SQL statements inside
+/// PL/pgSQL bodies is blanked out, so they can be linted like the file's own
+/// statements while keeping the same offsets.
+///
+/// Static `execute` strings are included too, e.g. `execute 'drop table t'`.
+pub(crate) fn embedded_sql(file: &SourceFile, text: &str) -> Option<String> {
+    let mut out = Masked::new(text);
+    for body in file.plpgsql_bodies() {
+        let root = body.tree();
+        for node in root.syntax().descendants_with_tokens() {
+            if node.kind() == SyntaxKind::COMMENT {
+                out.copy(node.text_range());
+                continue;
+            }
+            let Some(node) = node.into_node() else {
+                continue;
+            };
+            if let Some(sql_stmt) = ast::PlpgsqlSqlStmt::cast(node.clone()) {
+                if let Some(stmt) = sql_stmt.stmt() {
+                    let range = stmt.syntax().text_range();
+                    out.copy(range);
+                    out.semicolon(range.end());
+                }
+            } else if let Some(execute) = ast::PlpgsqlExecute::cast(node.clone()) {
+                out.execute_string(execute.expr());
+            } else if let Some(query) = ast::PlpgsqlDynamicQuery::cast(node) {
+                out.execute_string(query.expr());
+            }
+        }
+    }
+    out.finish()
+}
+
+struct Masked<'a> {
+    text: &'a str,
+    out: Vec<u8>,
+    found: bool,
+}
+
+impl<'a> Masked<'a> {
+    fn new(text: &'a str) -> Self {
+        // keep the newlines so ignore comments still apply to the same lines
+        let out = text
+            .bytes()
+            .map(|b| if b == b'\n' { b'\n' } else { b' ' })
+            .collect();
+        Self {
+            text,
+            out,
+            found: false,
+        }
+    }
+
+    fn copy(&mut self, range: TextRange) {
+        let range = usize::from(range.start())..usize::from(range.end());
+        self.out[range.clone()].copy_from_slice(&self.text.as_bytes()[range]);
+        self.found = true;
+    }
+
+    // Ends the statement, the position must be blank in the output.
+    fn semicolon(&mut self, offset: TextSize) {
+        if let Some(b) = self.out.get_mut(usize::from(offset))
+            && *b == b' '
+        {
+            *b = b';';
+        }
+    }
+
+    fn execute_string(&mut self, expr: Option<ast::Expr>) {
+        let Some(ast::Expr::Literal(literal)) = expr else {
+            return;
+        };
+        let Some(token) = literal.syntax().first_token() else {
+            return;
+        };
+        let text = token.text();
+        let quote_len = match token.kind() {
+            SyntaxKind::STRING => match strip_quotes(text) {
+                Some(inner) if !inner.contains('\'') => 1,
+                _ => return,
+            },
+            SyntaxKind::DOLLAR_QUOTED_STRING => match dollar_quote_tag(text) {
+                Some(tag) => tag.len() + 2,
+                None => return,
+            },
+            _ => return,
+        };
+        let range = token.text_range();
+        let quote_len = TextSize::new(quote_len as u32);
+        if range.len() < quote_len + quote_len {
+            return;
+        }
+        let inner = TextRange::new(range.start() + quote_len, range.end() - quote_len);
+        self.copy(inner);
+        self.semicolon(inner.end());
+    }
+
+    fn finish(self) -> Option<String> {
+        if !self.found {
+            return None;
+        }
+        // we only copy whole tokens so the output is still valid utf-8
+        String::from_utf8(self.out).ok()
+    }
+}
@@ -27,7 +27,7 @@ mod test {
     use insta::assert_snapshot;
 
     use crate::Rule;
-    use crate::test_utils::lint_errors;
+    use crate::test_utils::{lint_errors, lint_ok};
 
     #[test]
     fn err() {
@@ -36,4 +36,41 @@ ALTER TABLE "bar_tbl" DROP COLUMN "foo_col" CASCADE;
         "#;
         assert_snapshot!(lint_errors(sql, Rule::BanDropColumn));
     }
+
+    #[test]
+    fn err_in_do_block() {
+        let sql = r#"
+do $$
+begin
+  if exists (select 1 from information_schema.columns where column_name = 'foo_col') then
+    alter table bar_tbl drop column foo_col;
+  end if;
+  execute 'alter table bar_tbl drop column baz_col';
+end
+$$;
+        "#;
+        assert_snapshot!(lint_errors(sql, Rule::BanDropColumn));
+    }
+
+    #[test]
+    fn ok_in_do_block_with_ignore() {
+        let sql = r#"
+do $$
+begin
+  -- squawk-ignore ban-drop-column
+  alter table bar_tbl drop column foo_col;
+end
+$$;
+        "#;
+        lint_ok(sql, Rule::BanDropColumn);
+    }
+
+    #[test]
+    fn ok_in_other_languages() {
+        let sql = r#"
+do language plpython3u $$ plpy.execute("alter table bar_tbl drop column foo_col") $$;
+create function f() returns void language sql as $$ select 1 $$;
+        "#;
+        lint_ok(sql, Rule::BanDropColumn);
+    }
 }
@@ -1,5 +1,5 @@
 use squawk_syntax::{
-    Parse, SourceFile,
+    Dialect, Parse, SourceFile,
     ast::{self, AstNode},
 };
 
@@ -14,8 +14,39 @@ fn concurrently_fix(create_index: &ast::CreateIndex) -> Option<Fix> {
     Some(Fix::new("Add `concurrently`", vec![edit]))
 }
 
+fn async_fix(create_index: &ast::CreateIndex) -> Option<Fix> {
+    let token = create_index
+        .concurrently_token()
+        .or_else(|| create_index.index_token())?;
+    let edit = Edit::insert(" async", token.text_range().end());
+    Some(Fix::new("Add `async`", vec![edit]))
+}
+
+// Aurora DSQL rejects synchronous index builds, even for new tables.
+fn require_async_index_creation(ctx: &mut Linter, file: &SourceFile) {
+    for stmt in file.stmts() {
+        if let ast::Stmt::CreateIndex(create_index) = stmt
+            && create_index.async_token().is_none()
+        {
+            ctx.report(
+                Violation::for_node(
+                    Rule::RequireConcurrentIndexCreation,
+                    "Aurora DSQL only supports building indexes asynchronously.".into(),
+                    create_index.syntax(),
+                )
+                .help("Use `async` to build the index in the background.")
+                .fix(async_fix(&create_index)),
+            );
+        }
+    }
+}
+
 pub(crate) fn require_concurrent_index_creation(ctx: &mut Linter, parse: &Parse<SourceFile>) {
     let file = parse.tree();
+    if ctx.settings.dialect == Dialect::AuroraDsql {
+        require_async_index_creation(ctx, &file);
+        return;
+    }
     let tables_created = tables_created_in_transaction(ctx.settings.assume_in_transaction, &file);
     for stmt in file.stmts() {
         if let ast::Stmt::CreateIndex(create_index) = stmt {
@@ -48,8 +79,8 @@ mod test {
     use insta::assert_snapshot;
 
     use crate::{
-        LinterSettings, Rule,
-        test_utils::{fix_sql, lint_errors, lint_ok},
+        Dialect, LinterSettings, Rule,
+        test_utils::{fix_sql, fix_sql_with, lint_errors, lint_errors_with, lint_ok},
     };
 
     fn lint_ok_with(sql: &str, settings: LinterSettings) {
@@ -128,4 +159,27 @@ CREATE INDEX "core_foo_tenant_id_4d397ef9" ON "core_foo" ("tenant_id");
             },
         );
     }
+
+    #[test]
+    fn aurora_dsql_requires_async() {
+        let settings = LinterSettings {
+            dialect: Dialect::AuroraDsql,
+            ..Default::default()
+        };
+        let sql = "
+create table t (a int);
+create index t_a_idx on t (a);
+create index async t_a_idx on t (a);
+";
+        assert_snapshot!(lint_errors_with(
+            sql,
+            settings.clone(),
+            Rule::RequireConcurrentIndexCreation
+        ));
+        assert_snapshot!(fix_sql_with(
+            "create unique index on t (a);",
+            settings,
+            Rule::RequireConcurrentIndexCreation
+        ), @"create unique index async on t (a);");
+    }
 }
@@ -227,7 +227,10 @@ impl LockKind {
             ast::Stmt::Cluster(_) => LockKind::AccessExclusive,
             ast::Stmt::CommentOn(_) => LockKind::ShareUpdateExclusive,
             ast::Stmt::CreateIndex(create_index) => {
-                if create_index.concurrently_token().is_some() {
+                // Aurora DSQL's `async` builds don't block writes either
+                if create_index.concurrently_token().is_some()
+                    || create_index.async_token().is_some()
+                {
                     LockKind::ShareUpdateExclusive
                 } else {
                     LockKind::Share
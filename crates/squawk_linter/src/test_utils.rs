@@ -27,7 +27,7 @@ pub(crate) fn lint_errors(sql: &str, rule: Rule) -> String {
 
 #[must_use]
 fn lint_settings(sql: &str, settings: LinterSettings, rule: Rule) -> Vec<Violation> {
-    let file = squawk_syntax::SourceFile::parse(sql);
+    let file = squawk_syntax::SourceFile::parse_dialect(sql, None, settings.dialect);
     assert_eq!(file.errors().len(), 0);
     let mut linter = Linter::from([rule]);
     linter.settings = settings;
@@ -74,7 +74,7 @@ pub(crate) fn fix_sql_with(sql: &str, settings: LinterSettings, rule: Rule) -> S
         result.replace_range(start..end, text);
     }
 
-    let file = squawk_syntax::SourceFile::parse(&result);
+    let file = squawk_syntax::SourceFile::parse_dialect(&result, None, settings.dialect);
     assert_eq!(
         file.errors(),
         vec![],
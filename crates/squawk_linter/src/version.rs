@@ -23,6 +23,10 @@ impl Version {
             patch: patch.into(),
         }
     }
+
+    pub fn major(&self) -> i32 {
+        self.major
+    }
 }
 
 impl Default for Version {
@@ -0,0 +1,72 @@
+use std::fmt;
+
+/// Databases that speak the Postgres wire protocol and SQL, but support a
+/// different set of features.
+///
+/// The dialect enables its grammar extensions, e.g., `create index async` on
+/// Aurora DSQL.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    #[default]
+    Postgres,
+    /// see: <https://www.cockroachlabs.com/docs/stable/postgresql-compatibility>
+    CockroachDb,
+    /// see: <https://docs.aws.amazon.com/aurora-dsql/latest/userguide/working-with-postgresql-compatibility-unsupported-features.html>
+    AuroraDsql,
+    /// see: <https://docs.yugabyte.com/preview/explore/ysql-language-features/postgresql-compatibility/>
+    YugabyteDb,
+}
+
+impl Dialect {
+    /// The product name, used in diagnostics.
+    pub fn name(self) -> &'static str {
+        match self {
+            Dialect::Postgres => "PostgreSQL",
+            Dialect::CockroachDb => "CockroachDB",
+            Dialect::AuroraDsql => "Aurora DSQL",
+            Dialect::YugabyteDb => "YugabyteDB",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownDialect {
+    val: String,
+}
+
+impl fmt::Display for UnknownDialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid dialect {}, expected postgres, cockroachdb, aurora-dsql, or yugabytedb",
+            self.val
+        )
+    }
+}
+
+impl std::error::Error for UnknownDialect {}
+
+impl std::str::FromStr for Dialect {
+    type Err = UnknownDialect;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "postgres" => Ok(Dialect::Postgres),
+            "cockroachdb" => Ok(Dialect::CockroachDb),
+            "aurora-dsql" => Ok(Dialect::AuroraDsql),
+            "yugabytedb" => Ok(Dialect::YugabyteDb),
+            _ => Err(UnknownDialect { val: s.to_string() }),
+        }
+    }
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = match self {
+            Dialect::Postgres => "postgres",
+            Dialect::CockroachDb => "cockroachdb",
+            Dialect::AuroraDsql => "aurora-dsql",
+            Dialect::YugabyteDb => "yugabytedb",
+        };
+        write!(f, "{val}")
+    }
+}
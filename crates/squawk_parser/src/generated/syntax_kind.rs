@@ -48,6 +48,7 @@ pub enum SyntaxKind {
     ADMIN_KW,
     AFTER_KW,
     AGGREGATE_KW,
+    ALIAS_KW,
     ALL_KW,
     ALSO_KW,
     ALTER_KW,
@@ -60,9 +61,11 @@ pub enum SyntaxKind {
     AS_KW,
     ASC_KW,
     ASENSITIVE_KW,
+    ASSERT_KW,
     ASSERTION_KW,
     ASSIGNMENT_KW,
     ASYMMETRIC_KW,
+    ASYNC_KW,
     AT_KW,
     ATOMIC_KW,
     ATTACH_KW,
@@ -111,6 +114,7 @@ pub enum SyntaxKind {
     CONFIGURATION_KW,
     CONFLICT_KW,
     CONNECTION_KW,
+    CONSTANT_KW,
     CONSTRAINT_KW,
     CONSTRAINTS_KW,
     CONTENT_KW,
@@ -136,6 +140,7 @@ pub enum SyntaxKind {
     DATABASE_KW,
     DAY_KW,
     DEALLOCATE_KW,
+    DEBUG_KW,
     DEC_KW,
     DECIMAL_KW,
     DECLARE_KW,
@@ -152,6 +157,7 @@ pub enum SyntaxKind {
     DESC_KW,
     DESTINATION_KW,
     DETACH_KW,
+    DIAGNOSTICS_KW,
     DICTIONARY_KW,
     DISABLE_KW,
     DISCARD_KW,
@@ -164,6 +170,8 @@ pub enum SyntaxKind {
     EACH_KW,
     EDGE_KW,
     ELSE_KW,
+    ELSEIF_KW,
+    ELSIF_KW,
     EMPTY_KW,
     ENABLE_KW,
     ENCODING_KW,
@@ -175,11 +183,13 @@ pub enum SyntaxKind {
     ESCAPE_KW,
     EVENT_KW,
     EXCEPT_KW,
+    EXCEPTION_KW,
     EXCLUDE_KW,
     EXCLUDING_KW,
     EXCLUSIVE_KW,
     EXECUTE_KW,
     EXISTS_KW,
+    EXIT_KW,
     EXPLAIN_KW,
     EXPRESSION_KW,
     EXTENSION_KW,
@@ -195,6 +205,7 @@ pub enum SyntaxKind {
     FOLLOWING_KW,
     FOR_KW,
     FORCE_KW,
+    FOREACH_KW,
     FOREIGN_KW,
     FORMAT_KW,
     FORWARD_KW,
@@ -204,6 +215,7 @@ pub enum SyntaxKind {
     FUNCTION_KW,
     FUNCTIONS_KW,
     GENERATED_KW,
+    GET_KW,
     GLOBAL_KW,
     GRANT_KW,
     GRANTED_KW,
@@ -233,6 +245,7 @@ pub enum SyntaxKind {
     INDENT_KW,
     INDEX_KW,
     INDEXES_KW,
+    INFO_KW,
     INHERIT_KW,
     INHERITS_KW,
     INITIALLY_KW,
@@ -287,7 +300,9 @@ pub enum SyntaxKind {
     LOCATION_KW,
     LOCK_KW,
     LOCKED_KW,
+    LOG_KW,
     LOGGED_KW,
+    LOOP_KW,
     LSN_KW,
     MAPPING_KW,
     MATCH_KW,
@@ -321,6 +336,7 @@ pub enum SyntaxKind {
     NORMALIZED_KW,
     NOT_KW,
     NOTHING_KW,
+    NOTICE_KW,
     NOTIFY_KW,
     NOTNULL_KW,
     NOWAIT_KW,
@@ -338,6 +354,7 @@ pub enum SyntaxKind {
     OMIT_KW,
     ON_KW,
     ONLY_KW,
+    OPEN_KW,
     OPERATOR_KW,
     OPTION_KW,
     OPTIONS_KW,
@@ -362,6 +379,7 @@ pub enum SyntaxKind {
     PASSING_KW,
     PASSWORD_KW,
     PATH_KW,
+    PERFORM_KW,
     PERIOD_KW,
     PLACING_KW,
     PLAN_KW,
@@ -384,8 +402,10 @@ pub enum SyntaxKind {
     PROPERTIES_KW,
     PROPERTY_KW,
     PUBLICATION_KW,
+    QUERY_KW,
     QUOTE_KW,
     QUOTES_KW,
+    RAISE_KW,
     RANGE_KW,
     READ_KW,
     REAL_KW,
@@ -411,6 +431,7 @@ pub enum SyntaxKind {
     RETURN_KW,
     RETURNING_KW,
     RETURNS_KW,
+    REVERSE_KW,
     REVOKE_KW,
     RIGHT_KW,
     ROLE_KW,
@@ -420,6 +441,7 @@ pub enum SyntaxKind {
     ROUTINES_KW,
     ROW_KW,
     ROWS_KW,
+    ROWTYPE_KW,
     RULE_KW,
     SAVEPOINT_KW,
     SCALAR_KW,
@@ -444,13 +466,16 @@ pub enum SyntaxKind {
     SIMILAR_KW,
     SIMPLE_KW,
     SKIP_KW,
+    SLICE_KW,
     SMALLINT_KW,
     SNAPSHOT_KW,
     SOME_KW,
     SOURCE_KW,
     SPLIT_KW,
     SQL_KW,
+    SQLSTATE_KW,
     STABLE_KW,
+    STACKED_KW,
     STANDALONE_KW,
     START_KW,
     STATEMENT_KW,
@@ -525,8 +550,10 @@ pub enum SyntaxKind {
     VIRTUAL_KW,
     VOLATILE_KW,
     WAIT_KW,
+    WARNING_KW,
     WHEN_KW,
     WHERE_KW,
+    WHILE_KW,
     WHITESPACE_KW,
     WINDOW_KW,
     WITH_KW,
@@ -562,8 +589,10 @@ pub enum SyntaxKind {
     STRING,
     UNICODE_ESC_STRING,
     COMMENT,
+    COPY_DATA,
     ERROR,
     IDENT,
+    META_COMMAND,
     WHITESPACE,
     ABSOLUTE,
     ACCESS_EXCLUSIVE,
@@ -777,6 +806,7 @@ pub enum SyntaxKind {
     COPY_PROGRAM,
     COPY_QUERY,
     COPY_STDIN,
+    COPY_STDIN_DATA,
     COPY_STDOUT,
     COPY_TABLE,
     COPY_TO,
@@ -1446,6 +1476,47 @@ pub enum SyntaxKind {
     PERCENT_TYPE,
     PERCENT_TYPE_CLAUSE,
     PERIOD_COLUMN,
+    PLPGSQL_ALIAS_DECL,
+    PLPGSQL_ASSERT,
+    PLPGSQL_ASSIGN,
+    PLPGSQL_BLOCK,
+    PLPGSQL_CASE,
+    PLPGSQL_CASE_WHEN,
+    PLPGSQL_CONDITION,
+    PLPGSQL_CURSOR_DECL,
+    PLPGSQL_DECLARE_SECTION,
+    PLPGSQL_DEFAULT,
+    PLPGSQL_DIAGNOSTICS_ITEM,
+    PLPGSQL_DYNAMIC_QUERY,
+    PLPGSQL_ELSE,
+    PLPGSQL_ELSIF,
+    PLPGSQL_END_LOOP,
+    PLPGSQL_EXCEPTION_HANDLER,
+    PLPGSQL_EXCEPTION_SECTION,
+    PLPGSQL_EXECUTE,
+    PLPGSQL_EXIT,
+    PLPGSQL_FOREACH,
+    PLPGSQL_FOR_INT,
+    PLPGSQL_FOR_QUERY,
+    PLPGSQL_GET_DIAGNOSTICS,
+    PLPGSQL_IF,
+    PLPGSQL_INTO,
+    PLPGSQL_LABEL,
+    PLPGSQL_LOOP,
+    PLPGSQL_NULL,
+    PLPGSQL_OPEN,
+    PLPGSQL_OPTION,
+    PLPGSQL_PERFORM,
+    PLPGSQL_RAISE,
+    PLPGSQL_RAISE_OPTION,
+    PLPGSQL_RAISE_USING,
+    PLPGSQL_RETURN,
+    PLPGSQL_ROOT,
+    PLPGSQL_SQL_STMT,
+    PLPGSQL_USING,
+    PLPGSQL_VARIABLE,
+    PLPGSQL_VAR_DECL,
+    PLPGSQL_WHILE,
     POLICY,
     POLICY_COMMAND,
     POLICY_COMMAND_ALL,
@@ -1519,6 +1590,7 @@ pub enum SyntaxKind {
     PROPERTY_NAME_REF,
     PROPERTY_STATISTICS,
     PROPERTY_STORAGE,
+    PSQL_META_COMMAND,
     PUBLICATION,
     PUBLICATION_OBJECT_CURRENT_SCHEMA,
     PUBLICATION_OBJECT_TABLE,
@@ -2951,4 +3023,88 @@ impl SyntaxKind {
         };
         Some(kw)
     }
+    pub fn from_contextual_keyword(ident: &str) -> Option<SyntaxKind> {
+        if ident.eq_ignore_ascii_case("alias") {
+            return Some(SyntaxKind::ALIAS_KW);
+        }
+        if ident.eq_ignore_ascii_case("assert") {
+            return Some(SyntaxKind::ASSERT_KW);
+        }
+        if ident.eq_ignore_ascii_case("constant") {
+            return Some(SyntaxKind::CONSTANT_KW);
+        }
+        if ident.eq_ignore_ascii_case("debug") {
+            return Some(SyntaxKind::DEBUG_KW);
+        }
+        if ident.eq_ignore_ascii_case("diagnostics") {
+            return Some(SyntaxKind::DIAGNOSTICS_KW);
+        }
+        if ident.eq_ignore_ascii_case("elseif") {
+            return Some(SyntaxKind::ELSEIF_KW);
+        }
+        if ident.eq_ignore_ascii_case("elsif") {
+            return Some(SyntaxKind::ELSIF_KW);
+        }
+        if ident.eq_ignore_ascii_case("exception") {
+            return Some(SyntaxKind::EXCEPTION_KW);
+        }
+        if ident.eq_ignore_ascii_case("exit") {
+            return Some(SyntaxKind::EXIT_KW);
+        }
+        if ident.eq_ignore_ascii_case("foreach") {
+            return Some(SyntaxKind::FOREACH_KW);
+        }
+        if ident.eq_ignore_ascii_case("get") {
+            return Some(SyntaxKind::GET_KW);
+        }
+        if ident.eq_ignore_ascii_case("info") {
+            return Some(SyntaxKind::INFO_KW);
+        }
+        if ident.eq_ignore_ascii_case("log") {
+            return Some(SyntaxKind::LOG_KW);
+        }
+        if ident.eq_ignore_ascii_case("loop") {
+            return Some(SyntaxKind::LOOP_KW);
+        }
+        if ident.eq_ignore_ascii_case("notice") {
+            return Some(SyntaxKind::NOTICE_KW);
+        }
+        if ident.eq_ignore_ascii_case("open") {
+            return Some(SyntaxKind::OPEN_KW);
+        }
+        if ident.eq_ignore_ascii_case("perform") {
+            return Some(SyntaxKind::PERFORM_KW);
+        }
+        if ident.eq_ignore_ascii_case("query") {
+            return Some(SyntaxKind::QUERY_KW);
+        }
+        if ident.eq_ignore_ascii_case("raise") {
+            return Some(SyntaxKind::RAISE_KW);
+        }
+        if ident.eq_ignore_ascii_case("reverse") {
+            return Some(SyntaxKind::REVERSE_KW);
+        }
+        if ident.eq_ignore_ascii_case("rowtype") {
+            return Some(SyntaxKind::ROWTYPE_KW);
+        }
+        if ident.eq_ignore_ascii_case("slice") {
+            return Some(SyntaxKind::SLICE_KW);
+        }
+        if ident.eq_ignore_ascii_case("sqlstate") {
+            return Some(SyntaxKind::SQLSTATE_KW);
+        }
+        if ident.eq_ignore_ascii_case("stacked") {
+            return Some(SyntaxKind::STACKED_KW);
+        }
+        if ident.eq_ignore_ascii_case("warning") {
+            return Some(SyntaxKind::WARNING_KW);
+        }
+        if ident.eq_ignore_ascii_case("while") {
+            return Some(SyntaxKind::WHILE_KW);
+        }
+        if ident.eq_ignore_ascii_case("async") {
+            return Some(SyntaxKind::ASYNC_KW);
+        }
+        None
+    }
 }
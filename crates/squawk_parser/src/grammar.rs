@@ -1,8 +1,10 @@
 // Modeled after rust-analyzer's grammar, but SQL instead of Rust!
 // https://github.com/rust-lang/rust-analyzer/tree/d8887c0758bbd2d5f752d5bd405d4491e90e7ed6/crates/parser/src/grammar
 
+pub(crate) mod plpgsql;
+
 use crate::{
-    CompletedMarker, Marker, Parser,
+    CompletedMarker, Dialect, Marker, Parser,
     generated::token_sets::{
         ALL_KEYWORDS, BARE_LABEL_KEYWORDS, COL_NAME_KEYWORD_FIRST, COLUMN_OR_TABLE_KEYWORDS,
         RESERVED_KEYWORDS, TYPE_FUNC_NAME_KEYWORDS, TYPE_KEYWORDS, UNRESERVED_KEYWORDS,
@@ -300,8 +302,8 @@ fn extract_arg(p: &mut Parser<'_>) {
         m.complete(p, EXTRACT_FIELD_NAME);
     } else {
         p.error(format!(
-            "expected ident, year, month, day, hour, minute, second, or string, got {:?}",
-            p.current()
+            "expected ident, year, month, day, hour, minute, second, or string, found {}",
+            p.found()
         ));
     }
 }
@@ -2062,7 +2064,7 @@ fn path_segment(p: &mut Parser<'_>, kind: PathSegmentKind) {
     } else if p.at_ts(COL_LABEL_FIRST) {
         pg_name(p);
     } else {
-        p.error(format!("expected name, got {:?}", p.current()));
+        p.error(format!("expected name, found {}", p.found()));
         m.abandon(p);
         return;
     }
@@ -2144,6 +2146,12 @@ fn opt_percent_type(p: &mut Parser<'_>) -> Option<CompletedMarker> {
         p.bump(PERCENT);
         p.bump(TYPE_KW);
         Some(m.complete(p, PERCENT_TYPE_CLAUSE))
+    // PL/pgSQL variables can have the type of a table's row, `t%rowtype`
+    } else if p.in_plpgsql() && p.at(PERCENT) && p.nth_at_contextual_kw(1, ROWTYPE_KW) {
+        let m = p.start();
+        p.bump(PERCENT);
+        p.bump_remap(ROWTYPE_KW);
+        Some(m.complete(p, PERCENT_TYPE_CLAUSE))
     } else {
         None
     }
@@ -2176,29 +2184,39 @@ fn type_mods(
     type_args_enabled: bool,
     kind: SyntaxKind,
 ) -> Option<CompletedMarker> {
-    if opt_percent_type(p).is_some() {
-        return Some(m.complete(p, PERCENT_TYPE));
-    }
-    if p.at(L_PAREN) && type_args_enabled && allows_type_mods(kind) {
-        let m = p.start();
-        delimited(
-            p,
-            L_PAREN,
-            R_PAREN,
-            COMMA,
-            || "unexpected comma".to_string(),
-            EXPR_FIRST.union(TokenSet::new(&[VARIADIC_KW])),
-            |p| arg_expr(p).is_some(),
-        );
-        m.complete(p, ARG_LIST);
-    }
-    let cm = m.complete(p, kind);
+    let cm = if opt_percent_type(p).is_some() {
+        let cm = m.complete(p, PERCENT_TYPE);
+        // PL/pgSQL allows arrays of them, e.g. `r%type[]`
+        if !p.in_plpgsql() {
+            return Some(cm);
+        }
+        cm
+    } else {
+        if p.at(L_PAREN) && type_args_enabled && allows_type_mods(kind) {
+            let m = p.start();
+            delimited(
+                p,
+                L_PAREN,
+                R_PAREN,
+                COMMA,
+                || "unexpected comma".to_string(),
+                EXPR_FIRST.union(TokenSet::new(&[VARIADIC_KW])),
+                |p| arg_expr(p).is_some(),
+            );
+            m.complete(p, ARG_LIST);
+        }
+        m.complete(p, kind)
+    };
     if !p.at(L_BRACK) && !p.at(ARRAY_KW) {
         return Some(cm);
     }
     let m = cm.precede(p);
     if p.eat(ARRAY_KW) {
         opt_array_bound(p);
+        // PL/pgSQL allows more than one, e.g. `v%type array[1][1]`
+        if p.in_plpgsql() {
+            while opt_array_bound(p) {}
+        }
     } else {
         let mut found_one_array_bracks = false;
         while !p.at(EOF) && p.at(L_BRACK) {
@@ -2786,7 +2804,7 @@ fn field_expr(p: &mut Parser<'_>, lhs: CompletedMarker) -> CompletedMarker {
     } else if p.eat(STAR) || opt_operator(p) {
         //
     } else {
-        p.error(format!("expected field name, got {:?}", p.current()));
+        p.error(format!("expected field name, found {}", p.found()));
     }
     m.complete(p, FIELD_EXPR)
 }
@@ -2981,7 +2999,7 @@ fn expr_bp(p: &mut Parser<'_>, bp: u8, r: &Restrictions) -> Option<(CompletedMar
     let m = p.start();
     if !p.at_ts(EXPR_FIRST) {
         p.err_recover(
-            &format!("expected an expression, found {:?}", p.current()),
+            &format!("expected an expression, found {}", p.found()),
             EXPR_RECOVERY_SET,
         );
         m.abandon(p);
@@ -3247,6 +3265,10 @@ fn select_clause(p: &mut Parser<'_>) -> CompletedMarker {
     // select distinct
     //        ^^^^^^^^
     opt_select_all_or_distinct(p);
+    // PL/pgSQL also allows the `into` before the targets, `select into a b from t`
+    if p.in_plpgsql() && p.at(INTO_KW) {
+        plpgsql::into_clause(p);
+    }
     opt_target_list(p);
     m.complete(p, SELECT_CLAUSE)
 }
@@ -3358,7 +3380,7 @@ fn select(p: &mut Parser, m: Option<Marker>, r: &SelectRestrictions) -> Option<C
             select_clause(p);
         }
     }
-    if opt_into_clause(p).is_some() {
+    if opt_into_clause(p).is_some_and(|cm| cm.kind() == INTO_CLAUSE) {
         out_kind = SELECT_INTO;
     }
     opt_from_clause(p);
@@ -3421,6 +3443,10 @@ fn opt_select_trailing_clauses(p: &mut Parser<'_>) -> bool {
 
 // INTO [ TEMPORARY | TEMP | UNLOGGED ] [ TABLE ] new_table
 fn opt_into_clause(p: &mut Parser<'_>) -> Option<CompletedMarker> {
+    // In PL/pgSQL, `select a into b` assigns the variable `b`
+    if p.in_plpgsql() && p.at(INTO_KW) {
+        return Some(plpgsql::into_clause(p));
+    }
     if p.at(INTO_KW) {
         let m = p.start();
         p.bump(INTO_KW);
@@ -3648,7 +3674,7 @@ fn opt_from_clause(p: &mut Parser<'_>) -> Option<CompletedMarker> {
         return None;
     }
     if opt_from_item(p).is_none() {
-        p.error(format!("expected from item, got {:?}", p.current()));
+        p.error(format!("expected from item, found {}", p.found()));
     }
     while !p.at(EOF) && p.eat(COMMA) {
         if opt_from_item(p).is_none() {
@@ -4538,7 +4564,7 @@ fn field_accessor(p: &mut Parser<'_>) {
     } else if p.at(INT_NUMBER) || p.at(NUMERIC_NUMBER) {
         p.err_and_bump("expected field name");
     } else if !p.eat(STAR) {
-        p.error(format!("expected field name, got {:?}", p.current()));
+        p.error(format!("expected field name, found {}", p.found()));
     }
     m.complete(p, FIELD_ACCESSOR);
 }
@@ -5029,7 +5055,7 @@ fn table_property(p: &mut Parser<'_>) {
         }
         _ => {
             m.abandon(p);
-            p.err_and_bump(&format!("expected like option, got {:?}", p.current()));
+            p.err_and_bump(&format!("expected like option, found {}", p.found()));
             return;
         }
     };
@@ -5056,7 +5082,7 @@ fn operator(p: &mut Parser<'_>) {
     let m = p.start();
     opt_path_name_ref(p);
     if !opt_operator(p) {
-        p.error(format!("expected operator, got {:?}", p.current()));
+        p.error(format!("expected operator, found {}", p.found()));
     }
     m.complete(p, OP);
 }
@@ -5509,7 +5535,7 @@ fn opt_repeatable_clause(p: &mut Parser<'_>) -> Option<CompletedMarker> {
 
 fn clause_expr(p: &mut Parser<'_>) {
     if p.at(AND_KW) || p.at(OR_KW) {
-        p.err_and_bump(&format!("expected expression but got {:?}", p.current()));
+        p.err_and_bump(&format!("expected expression but found {}", p.found()));
     }
     expr(p);
 }
@@ -6008,6 +6034,12 @@ const TARGET_FOLLOW: TokenSet = TokenSet::new(&[
 
 const TARGET_FIRST: TokenSet = EXPR_FIRST;
 
+// Clauses that commonly follow a target list, used to suggest a fix for typos
+// like `form`.
+const SELECT_CLAUSE_KEYWORDS: TokenSet = TokenSet::new(&[
+    FROM_KW, WHERE_KW, GROUP_KW, HAVING_KW, ORDER_KW, LIMIT_KW, OFFSET_KW, WINDOW_KW,
+]);
+
 // target_el:
 //   | a_expr AS ColLabel
 //   | a_expr BareColLabel
@@ -6025,8 +6057,8 @@ fn opt_target_el(p: &mut Parser) -> Option<CompletedMarker> {
     } else {
         m.abandon(p);
         p.error(format!(
-            "expected an expression in target_el, found {:?}",
-            p.current()
+            "expected an expression in target_el, found {}",
+            p.found()
         ));
         return None;
     };
@@ -6040,12 +6072,12 @@ fn opt_as_col_label(p: &mut Parser<'_>) {
             column_name(p);
             m.complete(p, AS_NAME);
         } else {
-            p.err_and_bump(&format!("expected column label, got {:?}", p.current()));
+            p.err_and_bump(&format!("expected column label, found {}", p.found()));
             m.abandon(p);
         }
     } else if p.at(FORMAT_KW) && p.nth_at(1, JSON_KW) {
         m.abandon(p);
-    } else if p.at_ts(BARE_COL_LABEL_FIRST) {
+    } else if p.at_ts(BARE_COL_LABEL_FIRST) && !at_next_stmt(p) {
         column_name(p);
         m.complete(p, AS_NAME);
     } else {
@@ -6053,6 +6085,12 @@ fn opt_as_col_label(p: &mut Parser<'_>) {
     }
 }
 
+// `select 1 select 2` is more likely missing a semicolon than labeling a
+// column `select`.
+fn at_next_stmt(p: &Parser<'_>) -> bool {
+    p.at_ts(STMT_FIRST) && !p.at(L_PAREN) && !p.nth_at(1, COMMA) && !p.nth_at_ts(1, TARGET_FOLLOW)
+}
+
 fn opt_target_list(p: &mut Parser) -> Option<CompletedMarker> {
     if !p.at_ts(TARGET_LIST_START) || p.at_ts(TARGET_FOLLOW) {
         return None;
@@ -6071,8 +6109,22 @@ fn opt_target_list(p: &mut Parser) -> Option<CompletedMarker> {
                 if p.at(RETURNING_KW) && p.nth_at(1, TEXT_KW) {
                     break;
                 }
-                if p.at_ts(TARGET_FIRST) {
-                    p.error("missing comma");
+                if p.at_ts(TARGET_FIRST) && !at_next_stmt(p) {
+                    // `select * form t` or `select a form t`
+                    let typo = if let Some(kind) = p.suggest_keyword(SELECT_CLAUSE_KEYWORDS) {
+                        Some((kind, p.found()))
+                    } else {
+                        p.suggest_prev_keyword(SELECT_CLAUSE_KEYWORDS)
+                            .map(|kind| (kind, format!("`{}`", p.prev_text())))
+                    };
+                    if let Some((kind, found)) = typo {
+                        p.error(format!(
+                            "missing comma, or did you mean {} instead of {found}?",
+                            kind.display_name(),
+                        ));
+                    } else {
+                        p.error("missing comma");
+                    }
                 } else {
                     break;
                 }
@@ -7561,6 +7613,49 @@ struct StmtRestrictions {
     semi_allowed: bool,
 }
 
+// The objects that can follow CREATE, ALTER, and DROP, used to suggest a fix
+// for typos like `create tabel`.
+const DDL_OBJECT_FIRST: TokenSet = TokenSet::new(&[
+    AGGREGATE_KW,
+    CAST_KW,
+    COLLATION_KW,
+    CONSTRAINT_KW,
+    CONVERSION_KW,
+    DATABASE_KW,
+    DEFAULT_KW,
+    DOMAIN_KW,
+    EVENT_KW,
+    EXTENSION_KW,
+    FOREIGN_KW,
+    FUNCTION_KW,
+    GROUP_KW,
+    INDEX_KW,
+    LANGUAGE_KW,
+    MATERIALIZED_KW,
+    OPERATOR_KW,
+    POLICY_KW,
+    PROCEDURE_KW,
+    PUBLICATION_KW,
+    ROLE_KW,
+    ROUTINE_KW,
+    RULE_KW,
+    SCHEMA_KW,
+    SEQUENCE_KW,
+    SERVER_KW,
+    STATISTICS_KW,
+    SUBSCRIPTION_KW,
+    TABLESPACE_KW,
+    TABLE_KW,
+    TEMPORARY_KW,
+    TRANSFORM_KW,
+    TRIGGER_KW,
+    TYPE_KW,
+    UNIQUE_KW,
+    UNLOGGED_KW,
+    USER_KW,
+    VIEW_KW,
+]);
+
 fn stmt(p: &mut Parser, r: &StmtRestrictions) -> Option<CompletedMarker> {
     match (p.current(), p.nth(1)) {
         (SEMICOLON, _) => Some(empty_stmt(p)),
@@ -7854,11 +7949,26 @@ fn stmt(p: &mut Parser, r: &StmtRestrictions) -> Option<CompletedMarker> {
                 ..SelectRestrictions::default()
             },
         ),
-        (command, _) => {
+        _ => {
             // commands are outlined in:
             // https://www.postgresql.org/docs/17/sql-commands.html
             // TODO: see error recovery in rust-analyzer's expr_bp
-            p.err_and_bump(&format!("expected command, found {command:?}"));
+            // point at the misspelled object type, e.g., the `tabel` of
+            // `create tabel`
+            if matches!(p.current(), CREATE_KW | ALTER_KW | DROP_KW)
+                && let Some(suggestion) = p.suggest_nth_keyword(1, DDL_OBJECT_FIRST)
+            {
+                p.bump_any();
+                let msg = format!(
+                    "expected object type, found {}, did you mean {}?",
+                    p.found(),
+                    suggestion.display_name()
+                );
+                p.err_and_bump(&msg);
+                return None;
+            }
+            let msg = format!("expected command, found {}", p.found());
+            p.err_and_bump(&msg);
             // m.abandon(p);
             None
         }
@@ -10226,7 +10336,7 @@ fn alter_trigger(p: &mut Parser<'_>) -> CompletedMarker {
         if p.at(NO_KW) || p.at(DEPENDS_KW) {
             depends_on_extension(p);
         } else {
-            p.error(format!("expected NO or DEPENDS, found {:?}", p.current()));
+            p.error(format!("expected NO or DEPENDS, found {}", p.found()));
         }
     }
     p.eat(SEMICOLON);
@@ -10387,6 +10497,7 @@ fn alter_user(p: &mut Parser<'_>) -> CompletedMarker {
     // be careful of the case where we're at the IN of IN DATABASE
     if p.at(WITH_KW) || (p.at_ts(ROLE_OPTION_FIRST) && !p.nth_at(1, DATABASE_KW)) {
         opt_role_option_list(p);
+        p.eat(SEMICOLON);
         return m.complete(p, ALTER_USER);
     }
     // RENAME TO new_name
@@ -10394,6 +10505,7 @@ fn alter_user(p: &mut Parser<'_>) -> CompletedMarker {
         rename_to(p, ROLE_RENAME_TO, |p| {
             role(p);
         });
+        p.eat(SEMICOLON);
         return m.complete(p, ALTER_USER);
     }
     opt_in_database(p);
@@ -14625,7 +14737,7 @@ fn revoke_command(p: &mut Parser<'_>) {
         } else if p.at_ts(REVOKE_COMMAND_FIRST) {
             p.bump_any();
         } else {
-            p.error(format!("expected command name, got {:?}", p.current()))
+            p.error(format!("expected command name, found {}", p.found()))
         }
     }
     // [ ( column_name [, ...] ) ]
@@ -14641,7 +14753,7 @@ fn revoke_command(p: &mut Parser<'_>) {
 //  | SESSION_USER
 fn role_ref(p: &mut Parser<'_>) {
     if !opt_role_ref(p) {
-        p.error(format!("expected role, got {:?}", p.current()))
+        p.error(format!("expected role, found {}", p.found()))
     }
 }
 
@@ -16912,8 +17024,8 @@ fn with(p: &mut Parser<'_>, m: Option<Marker>, r: &SelectRestrictions) -> Option
         _ => {
             m.abandon(p);
             p.error(format!(
-                "expected DELETE, SELECT, TABLE, UPDATE, VALUES, or MERGE, got: {:?}",
-                p.current()
+                "expected DELETE, SELECT, TABLE, UPDATE, VALUES, or MERGE, found {}",
+                p.found()
             ));
             None
         }
@@ -17164,6 +17276,10 @@ fn create_index(p: &mut Parser<'_>) -> CompletedMarker {
     p.eat(UNIQUE_KW);
     p.expect(INDEX_KW);
     p.eat(CONCURRENTLY_KW);
+    // Aurora DSQL builds indexes in the background with `async`
+    if p.dialect() == Dialect::AuroraDsql {
+        p.eat_contextual_kw(ASYNC_KW);
+    }
     // [ [ IF NOT EXISTS ] name ]
     if opt_if_not_exists(p).is_some() || p.at_ts(NAME_FIRST) {
         index(p);
@@ -17395,7 +17511,7 @@ fn opt_function_option(p: &mut Parser<'_>) -> bool {
                 if p.at_ts(UNRESERVED_KEYWORDS) || p.at(IDENT) {
                     language_ref(p);
                 } else {
-                    p.error(format!("expected a language name, got {:?}", p.current()));
+                    p.error(format!("expected a language name, found {}", p.found()));
                 }
             }
             LANGUAGE_FUNC_OPTION
@@ -17911,7 +18027,7 @@ fn config_assignment(p: &mut Parser<'_>) {
     } else {
         let _ = p.eat(TO_KW) || p.expect(EQ);
         if !config_value(p) {
-            p.error(format!("expected config value, got {:?}", p.current()));
+            p.error(format!("expected config value, found {}", p.found()));
         }
         m.complete(p, TO_CONFIG_VALUE);
     }
@@ -17967,7 +18083,7 @@ fn set(p: &mut Parser<'_>) -> CompletedMarker {
         p.bump(TIME_KW);
         p.expect(ZONE_KW);
         if !p.eat(LOCAL_KW) && !config_value(p) {
-            p.error(format!("expected config value, got {:?}", p.current()));
+            p.error(format!("expected config value, found {}", p.found()));
         }
         m.complete(p, SET_TIME_ZONE);
     } else if p.at(CATALOG_KW) || p.at(SCHEMA_KW) {
@@ -18926,16 +19042,141 @@ fn set_data_type(p: &mut Parser<'_>) {
     }
 }
 
+// psql runs meta-commands like `\set` or `\gexec` itself, they aren't sent
+// to the server.
+fn psql_meta_command(p: &mut Parser) {
+    let m = p.start();
+    p.bump(META_COMMAND);
+    m.complete(p, PSQL_META_COMMAND);
+}
+
+const STMT_FIRST: TokenSet = TokenSet::new(&[
+    SEMICOLON,
+    L_PAREN,
+    ABORT_KW,
+    ALTER_KW,
+    ANALYZE_KW,
+    ANALYSE_KW,
+    BEGIN_KW,
+    CALL_KW,
+    CHECKPOINT_KW,
+    CLOSE_KW,
+    CLUSTER_KW,
+    COMMENT_KW,
+    COMMIT_KW,
+    COPY_KW,
+    CREATE_KW,
+    DEALLOCATE_KW,
+    DECLARE_KW,
+    DELETE_KW,
+    DISCARD_KW,
+    DO_KW,
+    DROP_KW,
+    END_KW,
+    EXECUTE_KW,
+    EXPLAIN_KW,
+    FETCH_KW,
+    FROM_KW,
+    GRANT_KW,
+    IMPORT_KW,
+    INSERT_KW,
+    LISTEN_KW,
+    LOAD_KW,
+    LOCK_KW,
+    MERGE_KW,
+    MOVE_KW,
+    NOTIFY_KW,
+    PREPARE_KW,
+    REASSIGN_KW,
+    REFRESH_KW,
+    REINDEX_KW,
+    RELEASE_KW,
+    REPACK_KW,
+    RESET_KW,
+    REVOKE_KW,
+    ROLLBACK_KW,
+    SAVEPOINT_KW,
+    SECURITY_KW,
+    SELECT_KW,
+    SET_KW,
+    SHOW_KW,
+    START_KW,
+    TABLE_KW,
+    TRUNCATE_KW,
+    UNLISTEN_KW,
+    UPDATE_KW,
+    VACUUM_KW,
+    VALUES_KW,
+    WITH_KW,
+]);
+
+// Reports `msg` and skips the rest of the statement so the following
+// statements are still parsed.
+fn err_recover_stmt(p: &mut Parser<'_>, msg: String) {
+    let m = p.start();
+    p.error(msg);
+    skip_to_stmt_end(p);
+    m.complete(p, ERROR);
+}
+
+fn skip_to_stmt_end(p: &mut Parser<'_>) {
+    while !p.at(EOF) && !p.at(SEMICOLON) && !p.at(META_COMMAND) {
+        p.bump_any();
+    }
+    p.eat(SEMICOLON);
+}
+
+// The rows after `COPY ... FROM STDIN;` are sent to the server as is, so we
+// don't parse them.
+fn copy_stdin_data(p: &mut Parser) {
+    let m = p.start();
+    p.bump(COPY_DATA);
+    m.complete(p, COPY_STDIN_DATA);
+}
+
 pub(crate) fn entry_point(p: &mut Parser) {
     let m = p.start();
     while !p.at(EOF) {
-        stmt(
+        if p.at(META_COMMAND) {
+            psql_meta_command(p);
+            continue;
+        }
+        if p.at(COPY_DATA) {
+            copy_stdin_data(p);
+            continue;
+        }
+        if !p.at_ts(STMT_FIRST) {
+            // commands are outlined in:
+            // https://www.postgresql.org/docs/17/sql-commands.html
+            let mut msg = format!("expected command, found {}", p.found());
+            if let Some(suggestion) = p.suggest_keyword(STMT_FIRST) {
+                msg.push_str(&format!(", did you mean {}?", suggestion.display_name()));
+            }
+            err_recover_stmt(p, msg);
+            continue;
+        }
+        let m = p.start();
+        let stmt = stmt(
             p,
             &StmtRestrictions {
                 semi_allowed: true,
                 begin_end_allowed: true,
             },
         );
+        if stmt.is_none() {
+            // keep what's left of the statement in the same node so it
+            // doesn't look like the start of another one
+            skip_to_stmt_end(p);
+            m.complete(p, ERROR);
+            continue;
+        }
+        m.abandon(p);
+        // a missing semicolon between two statements is reported during
+        // validation, here we skip junk at the end of a statement
+        if !p.at(EOF) && !p.at_ts(STMT_FIRST) && !p.at(META_COMMAND) && !p.prev_at(SEMICOLON) {
+            let msg = p.expected_one_of(TokenSet::new(&[SEMICOLON]));
+            err_recover_stmt(p, msg);
+        }
     }
     m.complete(p, SOURCE_FILE);
 }
@@ -0,0 +1,867 @@
+//! PL/pgSQL, the language of most function bodies and `DO` blocks.
+//!
+//! see: <https://www.postgresql.org/docs/current/plpgsql.html>
+//!
+//! Expressions and SQL statements are parsed with the SQL grammar. PL/pgSQL
+//! statements can end at words the SQL grammar doesn't know about, e.g.
+//! `loop` in `for r in select * from t loop`, so we limit the input to the end
+//! of the SQL before parsing it.
+//!
+//! PL/pgSQL keywords that aren't also SQL keywords, like `loop` and `raise`,
+//! are lexed as identifiers and remapped by the parser.
+
+use super::{
+    ParamKind, StmtRestrictions, arg_list, cursor_ref, expr, literal, opt_collate, opt_from_clause,
+    opt_group_by_clause, opt_having_clause, opt_select_trailing_clauses, opt_target_list,
+    opt_where_clause, opt_window_clause, param_list, pg_name, stmt, type_name,
+};
+use crate::{
+    CompletedMarker, Marker, Parser, generated::token_sets::ALL_KEYWORDS,
+    syntax_kind::SyntaxKind::*,
+};
+
+pub(crate) fn entry_point(p: &mut Parser<'_>) {
+    let m = p.start();
+    while p.at(POUND) {
+        option(p);
+    }
+    let block_m = p.start();
+    opt_label(p);
+    block(p, block_m, false);
+    if !p.at(EOF) {
+        let m = p.start();
+        p.error("expected end of function body");
+        while !p.at(EOF) {
+            p.bump_any();
+        }
+        m.complete(p, ERROR);
+    }
+    m.complete(p, PLPGSQL_ROOT);
+}
+
+// #variable_conflict use_column
+// #print_strict_params on
+fn option(p: &mut Parser<'_>) {
+    assert!(p.at(POUND));
+    let m = p.start();
+    p.bump(POUND);
+    name_ref(p);
+    name_ref(p);
+    m.complete(p, PLPGSQL_OPTION);
+}
+
+// [ <<label>> ]
+// [ DECLARE
+//     declarations ]
+// BEGIN
+//     statements
+// [ EXCEPTION
+//     WHEN condition [ OR condition ... ] THEN
+//         handler_statements ]
+// END [ label ];
+//
+// The semicolon is optional for the outermost block.
+fn block(p: &mut Parser<'_>, m: Marker, semicolon_required: bool) -> CompletedMarker {
+    opt_declare_section(p);
+    p.expect(BEGIN_KW);
+    stmt_list(p);
+    opt_exception_section(p);
+    p.expect(END_KW);
+    opt_label_ref(p);
+    if semicolon_required {
+        p.expect(SEMICOLON);
+    } else {
+        p.eat(SEMICOLON);
+    }
+    m.complete(p, PLPGSQL_BLOCK)
+}
+
+fn opt_label(p: &mut Parser<'_>) -> bool {
+    if !(p.at(L_ANGLE) && p.nth_at(1, L_ANGLE)) {
+        return false;
+    }
+    let m = p.start();
+    p.bump(L_ANGLE);
+    p.bump(L_ANGLE);
+    pg_name(p);
+    p.expect(R_ANGLE);
+    p.expect(R_ANGLE);
+    m.complete(p, PLPGSQL_LABEL);
+    true
+}
+
+// end loop outer;
+//          ^^^^^
+// exit outer when done;
+//      ^^^^^
+fn opt_label_ref(p: &mut Parser<'_>) {
+    if (p.at(IDENT) || p.at_ts(ALL_KEYWORDS)) && !p.at(WHEN_KW) {
+        name_ref(p);
+    }
+}
+
+fn name_ref(p: &mut Parser<'_>) {
+    let m = p.start();
+    pg_name(p);
+    m.complete(p, NAME_REF);
+}
+
+fn variable(p: &mut Parser<'_>) {
+    let m = p.start();
+    pg_name(p);
+    m.complete(p, PLPGSQL_VARIABLE);
+}
+
+// Query loops assign to existing variables, which can be qualified, e.g.
+// `for lbl.a, lbl.b in select ...`
+fn loop_target_list(p: &mut Parser<'_>) {
+    loop_target(p);
+    while !p.at(EOF) && p.eat(COMMA) {
+        loop_target(p);
+    }
+}
+
+fn loop_target(p: &mut Parser<'_>) {
+    if p.nth_at(1, DOT) && !p.nth_at(2, DOT) {
+        let n = find_at_top_level(p, |p, n| p.nth_at(n, COMMA) || p.nth_at(n, IN_KW));
+        limited(p, n, sql_expr);
+    } else {
+        variable(p);
+    }
+}
+
+fn opt_declare_section(p: &mut Parser<'_>) {
+    if !p.at(DECLARE_KW) {
+        return;
+    }
+    let m = p.start();
+    p.bump(DECLARE_KW);
+    while !p.at(EOF) && !p.at(BEGIN_KW) {
+        // a block can have multiple `declare` keywords:
+        //   declare a int; declare b int; begin ... end
+        if p.eat(DECLARE_KW) {
+            continue;
+        }
+        decl(p);
+    }
+    m.complete(p, PLPGSQL_DECLARE_SECTION);
+}
+
+// name [ CONSTANT ] type [ COLLATE collation_name ] [ NOT NULL ] [ { DEFAULT | := | = } expression ];
+// name ALIAS FOR oldname;
+// name [ [ NO ] SCROLL ] CURSOR [ ( arguments ) ] FOR query;
+fn decl(p: &mut Parser<'_>) {
+    if !p.at(IDENT) && !p.at_ts(ALL_KEYWORDS) {
+        p.err_and_bump("expected declaration");
+        return;
+    }
+    let m = p.start();
+    variable(p);
+    if p.eat_contextual_kw(ALIAS_KW) {
+        p.expect(FOR_KW);
+        sql_expr(p);
+        p.expect(SEMICOLON);
+        m.complete(p, PLPGSQL_ALIAS_DECL);
+        return;
+    }
+    if p.at(CURSOR_KW) || p.at(SCROLL_KW) || (p.at(NO_KW) && p.nth_at(1, SCROLL_KW)) {
+        if p.eat(NO_KW) {
+            p.expect(SCROLL_KW);
+        } else {
+            p.eat(SCROLL_KW);
+        }
+        p.expect(CURSOR_KW);
+        if p.at(L_PAREN) {
+            param_list(p, ParamKind::All);
+        }
+        if !p.eat(FOR_KW) {
+            p.expect(IS_KW);
+        }
+        sql_stmt_until_semicolon(p);
+        p.expect(SEMICOLON);
+        m.complete(p, PLPGSQL_CURSOR_DECL);
+        return;
+    }
+    p.eat_contextual_kw(CONSTANT_KW);
+    type_name(p);
+    opt_collate(p);
+    if p.eat(NOT_KW) {
+        p.expect(NULL_KW);
+    }
+    if p.at(DEFAULT_KW) || p.at(COLON_EQ) || p.at(EQ) {
+        let m = p.start();
+        if !p.eat(DEFAULT_KW) && !p.eat(COLON_EQ) {
+            p.bump(EQ);
+        }
+        sql_expr(p);
+        m.complete(p, PLPGSQL_DEFAULT);
+    }
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_VAR_DECL);
+}
+
+fn opt_exception_section(p: &mut Parser<'_>) {
+    if !p.at_contextual_kw(EXCEPTION_KW) {
+        return;
+    }
+    let m = p.start();
+    p.bump_remap(EXCEPTION_KW);
+    while p.at(WHEN_KW) {
+        exception_handler(p);
+    }
+    m.complete(p, PLPGSQL_EXCEPTION_SECTION);
+}
+
+// WHEN condition [ OR condition ... ] THEN handler_statements
+fn exception_handler(p: &mut Parser<'_>) {
+    assert!(p.at(WHEN_KW));
+    let m = p.start();
+    p.bump(WHEN_KW);
+    condition(p);
+    while !p.at(EOF) && p.eat(OR_KW) {
+        condition(p);
+    }
+    p.expect(THEN_KW);
+    stmt_list(p);
+    m.complete(p, PLPGSQL_EXCEPTION_HANDLER);
+}
+
+// division_by_zero
+// SQLSTATE '22012'
+fn condition(p: &mut Parser<'_>) {
+    let m = p.start();
+    if p.eat_contextual_kw(SQLSTATE_KW) {
+        string_literal(p);
+    } else {
+        name_ref(p);
+    }
+    m.complete(p, PLPGSQL_CONDITION);
+}
+
+fn string_literal(p: &mut Parser<'_>) {
+    if !p.at(STRING) || literal(p).is_none() {
+        p.error("expected string literal");
+    }
+}
+
+fn at_stmt_list_end(p: &Parser<'_>) -> bool {
+    p.at(EOF)
+        || p.at(END_KW)
+        || p.at(ELSE_KW)
+        || p.at(WHEN_KW)
+        || p.at_contextual_kw(ELSIF_KW)
+        || p.at_contextual_kw(ELSEIF_KW)
+        || p.at_contextual_kw(EXCEPTION_KW)
+}
+
+fn stmt_list(p: &mut Parser<'_>) {
+    while !at_stmt_list_end(p) {
+        plpgsql_stmt(p);
+    }
+}
+
+fn plpgsql_stmt(p: &mut Parser<'_>) {
+    let m = p.start();
+    let has_label = opt_label(p);
+    match p.current() {
+        DECLARE_KW | BEGIN_KW => {
+            block(p, m, true);
+            return;
+        }
+        FOR_KW => {
+            for_loop(p, m);
+            return;
+        }
+        IDENT if p.at_contextual_kw(LOOP_KW) => {
+            loop_(p, m);
+            return;
+        }
+        IDENT if p.at_contextual_kw(WHILE_KW) => {
+            while_loop(p, m);
+            return;
+        }
+        IDENT if p.at_contextual_kw(FOREACH_KW) => {
+            foreach_loop(p, m);
+            return;
+        }
+        _ => (),
+    }
+    if has_label {
+        p.error("expected a block or loop after the label");
+    }
+    if let Some(n) = assignment_op_pos(p) {
+        assign(p, m, n);
+        return;
+    }
+    match p.current() {
+        IF_KW => if_stmt(p, m),
+        CASE_KW => case_stmt(p, m),
+        CONTINUE_KW => exit_stmt(p, m),
+        IDENT if p.at_contextual_kw(EXIT_KW) => exit_stmt(p, m),
+        RETURN_KW => return_stmt(p, m),
+        IDENT if p.at_contextual_kw(RAISE_KW) => raise_stmt(p, m),
+        IDENT if p.at_contextual_kw(ASSERT_KW) => assert_stmt(p, m),
+        IDENT if p.at_contextual_kw(PERFORM_KW) => perform_stmt(p, m),
+        IDENT if p.at_contextual_kw(GET_KW) => get_diagnostics_stmt(p, m),
+        IDENT if p.at_contextual_kw(OPEN_KW) => open_stmt(p, m),
+        EXECUTE_KW => execute_stmt(p, m),
+        NULL_KW => {
+            p.bump(NULL_KW);
+            p.expect(SEMICOLON);
+            m.complete(p, PLPGSQL_NULL)
+        }
+        _ => sql_stmt(p, m),
+    };
+}
+
+/// Finds the first token at the top level, i.e., not in parens or brackets,
+/// where `pred` is true, without looking past the end of the statement.
+fn find_at_top_level(p: &Parser<'_>, pred: impl Fn(&Parser<'_>, usize) -> bool) -> Option<usize> {
+    let mut depth = 0usize;
+    // `create function ... begin atomic select 1; end` has semicolons inside
+    // the statement, so we track `begin atomic` and `case` until their `end`.
+    let mut atomic_depth = 0usize;
+    let mut n = 0;
+    loop {
+        if depth == 0 && atomic_depth == 0 && pred(p, n) {
+            return Some(n);
+        }
+        match p.nth(n) {
+            EOF => return None,
+            SEMICOLON if depth == 0 && atomic_depth == 0 => return None,
+            L_PAREN | L_BRACK => depth += 1,
+            R_PAREN | R_BRACK => depth = depth.saturating_sub(1),
+            BEGIN_KW if p.nth_at(n + 1, ATOMIC_KW) => atomic_depth += 1,
+            CASE_KW if atomic_depth > 0 => atomic_depth += 1,
+            END_KW if atomic_depth > 0 => atomic_depth -= 1,
+            _ => (),
+        }
+        n += 1;
+    }
+}
+
+fn semicolon_pos(p: &Parser<'_>) -> Option<usize> {
+    find_at_top_level(p, |p, n| p.nth_at(n, SEMICOLON))
+}
+
+fn loop_pos(p: &Parser<'_>) -> Option<usize> {
+    find_at_top_level(p, |p, n| p.nth_at_contextual_kw(n, LOOP_KW))
+}
+
+/// Position of the `:=` or `=` when at an assignment like `a.b[1] := 1`.
+fn assignment_op_pos(p: &Parser<'_>) -> Option<usize> {
+    if !p.at(IDENT) && !p.at_ts(ALL_KEYWORDS) {
+        return None;
+    }
+    let mut n = 1;
+    loop {
+        match p.nth(n) {
+            // a.b := 1
+            DOT => n += 2,
+            // a[1] := 1
+            L_BRACK => {
+                let mut depth = 0usize;
+                loop {
+                    match p.nth(n) {
+                        EOF | SEMICOLON => return None,
+                        L_BRACK => depth += 1,
+                        R_BRACK => depth -= 1,
+                        _ => (),
+                    }
+                    n += 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            EQ => return Some(n),
+            COLON if p.nth_at(n, COLON_EQ) => return Some(n),
+            _ => return None,
+        }
+    }
+}
+
+/// Parses the SQL in `f` as if the input ended `n` tokens ahead, wrapping
+/// anything left over in an error.
+fn limited(p: &mut Parser<'_>, n: Option<usize>, f: impl FnOnce(&mut Parser<'_>)) {
+    let Some(n) = n else {
+        f(p);
+        return;
+    };
+    p.with_limit(n, |p| {
+        f(p);
+        if !p.at(EOF) {
+            let m = p.start();
+            p.error("unexpected input");
+            while !p.at(EOF) {
+                p.bump_any();
+            }
+            m.complete(p, ERROR);
+        }
+    });
+}
+
+fn sql_expr(p: &mut Parser<'_>) {
+    if expr(p).is_none() {
+        p.error("expected an expression");
+    }
+}
+
+fn sql_expr_list(p: &mut Parser<'_>) {
+    sql_expr(p);
+    while !p.at(EOF) && p.eat(COMMA) {
+        sql_expr(p);
+    }
+}
+
+fn sql_stmt_until_semicolon(p: &mut Parser<'_>) {
+    let n = semicolon_pos(p);
+    limited(p, n, |p| {
+        embedded_stmt(p);
+    });
+}
+
+fn embedded_stmt(p: &mut Parser<'_>) -> Option<CompletedMarker> {
+    stmt(
+        p,
+        &StmtRestrictions {
+            begin_end_allowed: false,
+            semi_allowed: false,
+        },
+    )
+}
+
+// target := expression;
+fn assign(p: &mut Parser<'_>, m: Marker, op_pos: usize) {
+    limited(p, Some(op_pos), sql_expr);
+    if !p.eat(COLON_EQ) {
+        p.expect(EQ);
+    }
+    let n = semicolon_pos(p);
+    limited(p, n, |p| {
+        // a := * from t;
+        if p.at(STAR) {
+            opt_target_list(p);
+        } else {
+            sql_expr(p);
+        }
+        // the value is a select without the `select` keyword:
+        //   a := max(x) from t where y > 1;
+        opt_from_clause(p);
+        opt_where_clause(p);
+        opt_group_by_clause(p);
+        opt_having_clause(p);
+        opt_window_clause(p);
+        opt_select_trailing_clauses(p);
+    });
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_ASSIGN);
+}
+
+// IF boolean-expression THEN
+//     statements
+// [ ELSIF boolean-expression THEN
+//     statements ]
+// [ ELSE
+//     statements ]
+// END IF;
+fn if_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump(IF_KW);
+    sql_expr(p);
+    p.expect(THEN_KW);
+    stmt_list(p);
+    while p.at_contextual_kw(ELSIF_KW) || p.at_contextual_kw(ELSEIF_KW) {
+        let m = p.start();
+        if !p.eat_contextual_kw(ELSIF_KW) {
+            p.bump_remap(ELSEIF_KW);
+        }
+        sql_expr(p);
+        p.expect(THEN_KW);
+        stmt_list(p);
+        m.complete(p, PLPGSQL_ELSIF);
+    }
+    opt_else(p);
+    p.expect(END_KW);
+    p.expect(IF_KW);
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_IF)
+}
+
+fn opt_else(p: &mut Parser<'_>) {
+    if !p.at(ELSE_KW) {
+        return;
+    }
+    let m = p.start();
+    p.bump(ELSE_KW);
+    stmt_list(p);
+    m.complete(p, PLPGSQL_ELSE);
+}
+
+// CASE [ search-expression ]
+//     WHEN expression [, expression [ ... ]] THEN
+//       statements
+//   [ ... ]
+//   [ ELSE
+//       statements ]
+// END CASE;
+fn case_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump(CASE_KW);
+    if !p.at(WHEN_KW) {
+        sql_expr(p);
+    }
+    while p.at(WHEN_KW) {
+        let m = p.start();
+        p.bump(WHEN_KW);
+        sql_expr_list(p);
+        p.expect(THEN_KW);
+        stmt_list(p);
+        m.complete(p, PLPGSQL_CASE_WHEN);
+    }
+    opt_else(p);
+    p.expect(END_KW);
+    p.expect(CASE_KW);
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_CASE)
+}
+
+fn loop_body(p: &mut Parser<'_>) {
+    p.expect_contextual_kw(LOOP_KW);
+    stmt_list(p);
+    let m = p.start();
+    p.expect(END_KW);
+    p.expect_contextual_kw(LOOP_KW);
+    opt_label_ref(p);
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_END_LOOP);
+}
+
+// [ <<label>> ]
+// LOOP
+//     statements
+// END LOOP [ label ];
+fn loop_(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    loop_body(p);
+    m.complete(p, PLPGSQL_LOOP)
+}
+
+// [ <<label>> ]
+// WHILE boolean-expression LOOP
+//     statements
+// END LOOP [ label ];
+fn while_loop(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump_remap(WHILE_KW);
+    let n = loop_pos(p);
+    limited(p, n, sql_expr);
+    loop_body(p);
+    m.complete(p, PLPGSQL_WHILE)
+}
+
+// [ <<label>> ]
+// FOR name IN [ REVERSE ] expression .. expression [ BY expression ] LOOP
+//     statements
+// END LOOP [ label ];
+//
+// [ <<label>> ]
+// FOR target IN query LOOP
+//     statements
+// END LOOP [ label ];
+//
+// [ <<label>> ]
+// FOR target IN EXECUTE text_expression [ USING expression [, ... ] ] LOOP
+//     statements
+// END LOOP [ label ];
+//
+// [ <<label>> ]
+// FOR recordvar IN bound_cursorvar [ ( [ argument_name := ] argument_value [, ...] ) ] LOOP
+//     statements
+// END LOOP [ label ];
+fn for_loop(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump(FOR_KW);
+    loop_target_list(p);
+    p.expect(IN_KW);
+    let loop_n = loop_pos(p);
+    let is_reverse = p.eat_contextual_kw(REVERSE_KW);
+    let range_n = p.with_limit(loop_n.unwrap_or(usize::MAX), |p| {
+        find_at_top_level(p, |p, n| p.nth_at(n, DOT) && p.nth_at(n + 1, DOT))
+    });
+    if is_reverse || range_n.is_some() {
+        limited(p, range_n, sql_expr);
+        if p.at(DOT) && p.nth_at(1, DOT) {
+            p.bump(DOT);
+            p.bump(DOT);
+        } else {
+            p.error("expected `..`");
+        }
+        let loop_n = loop_pos(p);
+        limited(p, loop_n, |p| {
+            sql_expr(p);
+            if p.eat(BY_KW) {
+                sql_expr(p);
+            }
+        });
+        loop_body(p);
+        return m.complete(p, PLPGSQL_FOR_INT);
+    }
+    limited(p, loop_n, |p| {
+        if p.at(EXECUTE_KW) {
+            dynamic_query(p);
+        } else if p.at(IDENT) {
+            cursor_ref(p);
+            if p.at(L_PAREN) {
+                arg_list(p);
+            }
+        } else {
+            embedded_stmt(p);
+        }
+    });
+    loop_body(p);
+    m.complete(p, PLPGSQL_FOR_QUERY)
+}
+
+// [ <<label>> ]
+// FOREACH target [ SLICE number ] IN ARRAY expression LOOP
+//     statements
+// END LOOP [ label ];
+fn foreach_loop(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump_remap(FOREACH_KW);
+    loop_target_list(p);
+    if p.eat_contextual_kw(SLICE_KW) && (!p.at(INT_NUMBER) || literal(p).is_none()) {
+        p.error("expected integer");
+    }
+    p.expect(IN_KW);
+    p.expect(ARRAY_KW);
+    let n = loop_pos(p);
+    limited(p, n, sql_expr);
+    loop_body(p);
+    m.complete(p, PLPGSQL_FOREACH)
+}
+
+// EXIT [ label ] [ WHEN boolean-expression ];
+// CONTINUE [ label ] [ WHEN boolean-expression ];
+fn exit_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    if !p.eat(CONTINUE_KW) {
+        p.bump_remap(EXIT_KW);
+    }
+    opt_label_ref(p);
+    if p.eat(WHEN_KW) {
+        sql_expr(p);
+    }
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_EXIT)
+}
+
+// RETURN expression;
+// RETURN NEXT expression;
+// RETURN QUERY query;
+// RETURN QUERY EXECUTE command-string [ USING expression [, ... ] ];
+fn return_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump(RETURN_KW);
+    if p.eat_contextual_kw(QUERY_KW) {
+        if p.at(EXECUTE_KW) {
+            dynamic_query(p);
+        } else {
+            sql_stmt_until_semicolon(p);
+        }
+    } else {
+        p.eat(NEXT_KW);
+        if !p.at(SEMICOLON) {
+            sql_expr(p);
+        }
+    }
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_RETURN)
+}
+
+// RAISE [ level ] 'format' [, expression [, ... ]] [ USING option = expression [, ... ] ];
+// RAISE [ level ] condition_name [ USING option = expression [, ... ] ];
+// RAISE [ level ] SQLSTATE 'sqlstate' [ USING option = expression [, ... ] ];
+// RAISE [ level ] USING option = expression [, ... ];
+// RAISE ;
+fn raise_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump_remap(RAISE_KW);
+    for level in [
+        DEBUG_KW,
+        LOG_KW,
+        INFO_KW,
+        NOTICE_KW,
+        WARNING_KW,
+        EXCEPTION_KW,
+    ] {
+        if p.eat_contextual_kw(level) {
+            break;
+        }
+    }
+    if p.eat_contextual_kw(SQLSTATE_KW) {
+        string_literal(p);
+    } else if !p.at(USING_KW) && !p.at(SEMICOLON) {
+        sql_expr_list(p);
+    }
+    if p.at(USING_KW) {
+        let m = p.start();
+        p.bump(USING_KW);
+        raise_option(p);
+        while !p.at(EOF) && p.eat(COMMA) {
+            raise_option(p);
+        }
+        m.complete(p, PLPGSQL_RAISE_USING);
+    }
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_RAISE)
+}
+
+// MESSAGE = 'Nonexistent ID --> %'
+fn raise_option(p: &mut Parser<'_>) {
+    let m = p.start();
+    name_ref(p);
+    if !p.eat(COLON_EQ) {
+        p.expect(EQ);
+    }
+    sql_expr(p);
+    m.complete(p, PLPGSQL_RAISE_OPTION);
+}
+
+// ASSERT condition [ , message ];
+fn assert_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump_remap(ASSERT_KW);
+    sql_expr(p);
+    if p.eat(COMMA) {
+        sql_expr(p);
+    }
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_ASSERT)
+}
+
+// PERFORM query;
+//
+// The query is a `select` without the `select` keyword.
+fn perform_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump_remap(PERFORM_KW);
+    let n = semicolon_pos(p);
+    limited(p, n, |p| {
+        opt_target_list(p);
+        opt_from_clause(p);
+        opt_where_clause(p);
+        opt_group_by_clause(p);
+        opt_having_clause(p);
+        opt_window_clause(p);
+        opt_select_trailing_clauses(p);
+    });
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_PERFORM)
+}
+
+// GET [ CURRENT | STACKED ] DIAGNOSTICS variable { = | := } item [ , ... ];
+fn get_diagnostics_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump_remap(GET_KW);
+    if !p.eat(CURRENT_KW) {
+        p.eat_contextual_kw(STACKED_KW);
+    }
+    p.expect_contextual_kw(DIAGNOSTICS_KW);
+    diagnostics_item(p);
+    while !p.at(EOF) && p.eat(COMMA) {
+        diagnostics_item(p);
+    }
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_GET_DIAGNOSTICS)
+}
+
+fn diagnostics_item(p: &mut Parser<'_>) {
+    let m = p.start();
+    let n = assignment_op_pos(p);
+    if n.is_none() {
+        p.error("expected `=` or `:=`");
+    }
+    limited(p, n, sql_expr);
+    if !p.eat(COLON_EQ) {
+        p.expect(EQ);
+    }
+    name_ref(p);
+    m.complete(p, PLPGSQL_DIAGNOSTICS_ITEM);
+}
+
+// EXECUTE command-string [ INTO [STRICT] target ] [ USING expression [, ... ] ];
+fn execute_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump(EXECUTE_KW);
+    sql_expr(p);
+    loop {
+        if p.at(INTO_KW) {
+            into_clause(p);
+        } else if p.at(USING_KW) {
+            using_clause(p);
+        } else {
+            break;
+        }
+    }
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_EXECUTE)
+}
+
+// EXECUTE command-string [ USING expression [, ... ] ]
+fn dynamic_query(p: &mut Parser<'_>) {
+    assert!(p.at(EXECUTE_KW));
+    let m = p.start();
+    p.bump(EXECUTE_KW);
+    sql_expr(p);
+    if p.at(USING_KW) {
+        using_clause(p);
+    }
+    m.complete(p, PLPGSQL_DYNAMIC_QUERY);
+}
+
+// INTO [ STRICT ] target [, ...]
+pub(super) fn into_clause(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(INTO_KW));
+    let m = p.start();
+    p.bump(INTO_KW);
+    p.eat(STRICT_KW);
+    sql_expr_list(p);
+    m.complete(p, PLPGSQL_INTO)
+}
+
+fn using_clause(p: &mut Parser<'_>) {
+    assert!(p.at(USING_KW));
+    let m = p.start();
+    p.bump(USING_KW);
+    sql_expr_list(p);
+    m.complete(p, PLPGSQL_USING);
+}
+
+// OPEN unbound_cursorvar [ [ NO ] SCROLL ] FOR query;
+// OPEN unbound_cursorvar [ [ NO ] SCROLL ] FOR EXECUTE query_string [ USING expression [, ... ] ];
+// OPEN bound_cursorvar [ ( [ argument_name { := | => } ] argument_value [, ...] ) ];
+fn open_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    p.bump_remap(OPEN_KW);
+    cursor_ref(p);
+    if p.eat(NO_KW) {
+        p.expect(SCROLL_KW);
+    } else {
+        p.eat(SCROLL_KW);
+    }
+    if p.eat(FOR_KW) {
+        if p.at(EXECUTE_KW) {
+            dynamic_query(p);
+        } else {
+            sql_stmt_until_semicolon(p);
+        }
+    } else if p.at(L_PAREN) {
+        arg_list(p);
+    }
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_OPEN)
+}
+
+// Any other statement is SQL, e.g. `insert`, `update`, `fetch`, `commit`.
+//
+// insert into t values (1) returning id into new_id;
+//                                       ^^^^^^^^^^^
+fn sql_stmt(p: &mut Parser<'_>, m: Marker) -> CompletedMarker {
+    let n = semicolon_pos(p);
+    limited(p, n, |p| {
+        embedded_stmt(p);
+        if p.at(INTO_KW) {
+            into_clause(p);
+        }
+    });
+    p.expect(SEMICOLON);
+    m.complete(p, PLPGSQL_SQL_STMT)
+}
@@ -33,29 +33,31 @@ type bits = u64;
 
 /// Input for the parser -- a sequence of tokens.
 ///
-/// As of now, parser doesn't have access to the *text* of the tokens, and makes
-/// decisions based solely on their classification. Unlike `LexerToken`, the
-/// `Tokens` doesn't include whitespace and comments. Main input to the parser.
+/// The parser makes decisions based solely on the classification of the
+/// tokens, their *text* is only used in error messages. Unlike `LexerToken`,
+/// the `Tokens` doesn't include whitespace and comments. Main input to the
+/// parser.
 ///
 /// Struct of arrays internally, but this shouldn't really matter.
 #[derive(Default)]
 pub struct Input {
     kind: Vec<SyntaxKind>,
     joint: Vec<bits>,
-    // TODO: I think we can remove this
     contextual_kind: Vec<SyntaxKind>,
+    text: String,
+    text_end: Vec<u32>,
 }
 
 /// `pub` impl used by callers to create `Tokens`.
 impl Input {
     #[inline]
-    pub(crate) fn push(&mut self, kind: SyntaxKind) {
-        self.push_impl(kind, SyntaxKind::EOF)
+    pub(crate) fn push(&mut self, kind: SyntaxKind, text: &str) {
+        self.push_impl(kind, SyntaxKind::EOF, text)
+    }
+    #[inline]
+    pub(crate) fn push_ident(&mut self, contextual_kind: SyntaxKind, text: &str) {
+        self.push_impl(SyntaxKind::IDENT, contextual_kind, text)
     }
-    // #[inline]
-    // pub(crate) fn push_ident(&mut self, contextual_kind: SyntaxKind) {
-    //     self.push_impl(SyntaxKind::IDENT, contextual_kind)
-    // }
     /// Sets jointness for the last token we've pushed.
     ///
     /// This is a separate API rather than an argument to the `push` to make it
@@ -79,13 +81,15 @@ impl Input {
         self.joint[idx] |= 1 << b_idx;
     }
     #[inline]
-    fn push_impl(&mut self, kind: SyntaxKind, contextual_kind: SyntaxKind) {
+    fn push_impl(&mut self, kind: SyntaxKind, contextual_kind: SyntaxKind, text: &str) {
         let idx = self.len();
         if idx.is_multiple_of(bits::BITS as usize) {
             self.joint.push(0);
         }
         self.kind.push(kind);
         self.contextual_kind.push(contextual_kind);
+        self.text.push_str(text);
+        self.text_end.push(self.text.len() as u32);
     }
 }
 
@@ -94,14 +98,22 @@ impl Input {
     pub(crate) fn kind(&self, idx: usize) -> SyntaxKind {
         self.kind.get(idx).copied().unwrap_or(SyntaxKind::EOF)
     }
-    // TODO: we may want to use this in the parser since we have a lot of
-    // "keywords" that are actually contextual.
-    // pub(crate) fn contextual_kind(&self, idx: usize) -> SyntaxKind {
-    //     self.contextual_kind
-    //         .get(idx)
-    //         .copied()
-    //         .unwrap_or(SyntaxKind::EOF)
-    // }
+    /// Keyword kind of an identifier that's only a keyword in PL/pgSQL, e.g.
+    /// `loop` or `raise`.
+    pub(crate) fn contextual_kind(&self, idx: usize) -> SyntaxKind {
+        self.contextual_kind
+            .get(idx)
+            .copied()
+            .unwrap_or(SyntaxKind::EOF)
+    }
+    /// Text of the token, for error messages.
+    pub(crate) fn text(&self, idx: usize) -> &str {
+        let Some(&end) = self.text_end.get(idx) else {
+            return "";
+        };
+        let start = if idx == 0 { 0 } else { self.text_end[idx - 1] };
+        &self.text[start as usize..end as usize]
+    }
     pub(crate) fn is_joint(&self, n: usize) -> bool {
         let (idx, b_idx) = self.bit_index(n);
         self.joint[idx] & 1 << b_idx != 0
@@ -2,7 +2,7 @@
 
 use std::{num::IntErrorKind, ops};
 
-use squawk_lexer::tokenize;
+use squawk_lexer::{Templating, tokenize, tokenize_with};
 
 use crate::SyntaxKind;
 
@@ -22,34 +22,52 @@ impl<'a> LexedStr<'a> {
     // TODO: rust-analyzer has an edition thing to specify things that are only
     // available in certain version, we can do that later
     pub fn new(text: &'a str) -> LexedStr<'a> {
+        LexedStr::with_templating(text, None)
+    }
+
+    /// Like [`LexedStr::new`], but template expressions become identifiers
+    /// and template tags become comments, so the rest of the SQL still
+    /// parses.
+    pub fn with_templating(text: &'a str, templating: Option<Templating>) -> LexedStr<'a> {
         let mut conv = Converter::new(text);
 
-        for token in tokenize(&text[conv.offset..]) {
-            let token_text = &text[conv.offset..][..token.len as usize];
+        'lex: loop {
+            for token in tokenize_with(&text[conv.offset..], templating) {
+                let token_text = &text[conv.offset..][..token.len as usize];
+
+                conv.extend_token(&token.kind, token_text);
 
-            conv.extend_token(&token.kind, token_text);
+                // The rows after `COPY ... FROM STDIN;` aren't SQL, so we
+                // start lexing again after them.
+                if token.kind == squawk_lexer::TokenKind::Semi && conv.extend_copy_data() {
+                    continue 'lex;
+                }
+            }
+            break;
         }
 
         conv.finalize_with_eof()
     }
 
-    // pub(crate) fn single_token(text: &'a str) -> Option<(SyntaxKind, Option<String>)> {
-    //     if text.is_empty() {
-    //         return None;
-    //     }
-
-    //     let token = tokenize(text).next()?;
-    //     if token.len as usize != text.len() {
-    //         return None;
-    //     }
-
-    //     let mut conv = Converter::new(text);
-    //     conv.extend_token(&token.kind, text);
-    //     match &*conv.res.kind {
-    //         [kind] => Some((*kind, conv.res.error.pop().map(|it| it.msg))),
-    //         _ => None,
-    //     }
-    // }
+    /// Lexes `text` as a single token, returning `None` if it's empty or
+    /// more than one token.
+    pub fn single_token(text: &'a str) -> Option<(SyntaxKind, Option<String>)> {
+        if text.is_empty() {
+            return None;
+        }
+
+        let token = tokenize(text).next()?;
+        if token.len as usize != text.len() {
+            return None;
+        }
+
+        let mut conv = Converter::new(text);
+        conv.extend_token(&token.kind, text);
+        match &*conv.res.kind {
+            [kind] => Some((*kind, conv.res.error.pop().map(|it| it.msg))),
+            _ => None,
+        }
+    }
 
     // pub(crate) fn as_str(&self) -> &str {
     //     self.text
@@ -158,6 +176,69 @@ impl<'a> Converter<'a> {
         }
     }
 
+    fn at_stmt_start(&self) -> bool {
+        self.res
+            .kind
+            .iter()
+            .rev()
+            .find(|kind| !kind.is_trivia())
+            .is_none_or(|kind| matches!(kind, SyntaxKind::SEMICOLON | SyntaxKind::META_COMMAND))
+    }
+
+    /// Whether the last token is the semicolon of a `COPY ... FROM STDIN`.
+    fn at_copy_from_stdin_end(&self) -> bool {
+        let mut kinds = self
+            .res
+            .kind
+            .iter()
+            .rev()
+            .copied()
+            .filter(|kind| !kind.is_trivia());
+        if kinds.next() != Some(SyntaxKind::SEMICOLON) {
+            return false;
+        }
+        // the statement's tokens in reverse
+        let stmt: Vec<SyntaxKind> = kinds
+            .take_while(|kind| {
+                !matches!(
+                    kind,
+                    SyntaxKind::SEMICOLON | SyntaxKind::META_COMMAND | SyntaxKind::COPY_DATA
+                )
+            })
+            .collect();
+        stmt.last() == Some(&SyntaxKind::COPY_KW)
+            && stmt
+                .windows(2)
+                .any(|kinds| kinds == [SyntaxKind::STDIN_KW, SyntaxKind::FROM_KW])
+    }
+
+    /// Pushes the rows after a `COPY ... FROM STDIN;` as a single token,
+    /// returning whether there were any.
+    ///
+    /// The rows start on the next line and end with a `\.` line, like in
+    /// `pg_dump` output.
+    fn extend_copy_data(&mut self) -> bool {
+        if !self.at_copy_from_stdin_end() {
+            return false;
+        }
+        let rest = &self.res.text[self.offset..];
+        let Some(line_end) = rest.find('\n') else {
+            return false;
+        };
+        if !rest[..line_end]
+            .chars()
+            .all(|c| matches!(c, ' ' | '\t' | '\r'))
+        {
+            return false;
+        }
+        let Some(data_len) = squawk_lexer::copy_data_len(&rest[line_end + 1..]) else {
+            return false;
+        };
+        self.push(SyntaxKind::WHITESPACE, line_end + 1, None);
+        self.push(SyntaxKind::COPY_DATA, data_len, None);
+        true
+    }
+
     fn extend_token(&mut self, kind: &squawk_lexer::TokenKind, token_text: &str) {
         // A note on an intended tradeoff:
         // We drop some useful information here (see patterns with double dots `..`)
@@ -243,6 +324,38 @@ impl<'a> Converter<'a> {
                     }
                     SyntaxKind::IDENT
                 }
+                squawk_lexer::TokenKind::BacktickQuotedIdent => {
+                    err = "Backticks aren't supported for quoting identifiers, use double quotes instead";
+                    SyntaxKind::IDENT
+                }
+                squawk_lexer::TokenKind::MetaCommand => SyntaxKind::META_COMMAND,
+                // psql substitutes the variable's value before sending the
+                // query so they're parsed like the literal or identifier
+                // they'll become.
+                squawk_lexer::TokenKind::PsqlVariable { kind } => match kind {
+                    squawk_lexer::PsqlVariableKind::Literal => SyntaxKind::STRING,
+                    squawk_lexer::PsqlVariableKind::Ident => SyntaxKind::IDENT,
+                },
+                // A template expression at the start of a statement, like
+                // dbt's `{{ config(materialized='view') }}`, renders to
+                // nothing or a whole statement, otherwise it's a value or
+                // name.
+                squawk_lexer::TokenKind::TemplateExpr { terminated } => {
+                    if !terminated {
+                        err = "Unterminated template expression";
+                    }
+                    if self.at_stmt_start() {
+                        SyntaxKind::COMMENT
+                    } else {
+                        SyntaxKind::IDENT
+                    }
+                }
+                squawk_lexer::TokenKind::TemplateBlock { terminated } => {
+                    if !terminated {
+                        err = "Unterminated template tag";
+                    }
+                    SyntaxKind::COMMENT
+                }
             }
         };
 
@@ -29,6 +29,7 @@ use event::Event;
 use grammar::OPERATOR_FIRST;
 use std::cell::Cell;
 use token_set::TokenSet;
+mod dialect;
 mod event;
 mod generated;
 mod grammar;
@@ -40,11 +41,13 @@ mod syntax_kind;
 mod token_set;
 
 pub use crate::{
+    dialect::{Dialect, UnknownDialect},
     lexed_str::LexedStr,
     // output::{Output, Step},
     shortcuts::StrStep,
     syntax_kind::SyntaxKind,
 };
+pub use squawk_lexer::Templating;
 
 use crate::input::Input;
 pub use crate::output::Output;
@@ -150,7 +153,13 @@ impl CompletedMarker {
 }
 
 pub fn parse(input: &Input) -> Output {
+    parse_dialect(input, Dialect::Postgres)
+}
+
+/// Like [`parse`], but also accepts the grammar extensions of `dialect`.
+pub fn parse_dialect(input: &Input, dialect: Dialect) -> Output {
     let mut p = Parser::new(input);
+    p.dialect = dialect;
     // 2. lex tokens to event vec via parser aka actually run the parser code,
     // it calls the methods on the parser to create a vector of events
     grammar::entry_point(&mut p);
@@ -159,9 +168,24 @@ pub fn parse(input: &Input) -> Output {
     event::process(events)
 }
 
+/// Parses the body of a PL/pgSQL function or `DO` block.
+pub fn parse_plpgsql(input: &Input) -> Output {
+    let mut p = Parser::new(input);
+    p.plpgsql = true;
+    grammar::plpgsql::entry_point(&mut p);
+    let events = p.finish();
+    event::process(events)
+}
+
 pub(crate) struct Parser<'t> {
     inp: &'t Input,
     pos: usize,
+    // tokens at or past the limit are treated as `EOF`
+    limit: usize,
+    // whether we're parsing SQL embedded in PL/pgSQL, where `select into`
+    // assigns variables
+    plpgsql: bool,
+    dialect: Dialect,
     events: Vec<Event>,
     steps: Cell<u32>,
 }
@@ -201,6 +225,9 @@ impl<'t> Parser<'t> {
         Parser {
             inp,
             pos: 0,
+            limit: usize::MAX,
+            plpgsql: false,
+            dialect: Dialect::Postgres,
             events: vec![],
             steps: Cell::new(0),
         }
@@ -473,8 +500,7 @@ impl<'t> Parser<'t> {
     }
 
     fn at_composite2(&self, n: usize, k1: SyntaxKind, k2: SyntaxKind, triva: TrivaBetween) -> bool {
-        let tokens_match =
-            self.inp.kind(self.pos + n) == k1 && self.inp.kind(self.pos + n + 1) == k2;
+        let tokens_match = self.kind_at(self.pos + n) == k1 && self.kind_at(self.pos + n + 1) == k2;
         // We need to do this so we can say that:
         // 1 > > 2, is not the same as 1 >> 2
         match triva {
@@ -488,9 +514,9 @@ impl<'t> Parser<'t> {
     }
 
     fn at_composite3(&self, n: usize, k1: SyntaxKind, k2: SyntaxKind, k3: SyntaxKind) -> bool {
-        self.inp.kind(self.pos + n) == k1
-            && self.inp.kind(self.pos + n + 1) == k2
-            && self.inp.kind(self.pos + n + 2) == k3
+        self.kind_at(self.pos + n) == k1
+            && self.kind_at(self.pos + n + 1) == k2
+            && self.kind_at(self.pos + n + 2) == k3
     }
 
     fn at_composite4(
@@ -501,10 +527,10 @@ impl<'t> Parser<'t> {
         k3: SyntaxKind,
         k4: SyntaxKind,
     ) -> bool {
-        self.inp.kind(self.pos + n) == k1
-            && self.inp.kind(self.pos + n + 1) == k2
-            && self.inp.kind(self.pos + n + 2) == k3
-            && self.inp.kind(self.pos + n + 3) == k4
+        self.kind_at(self.pos + n) == k1
+            && self.kind_at(self.pos + n + 1) == k2
+            && self.kind_at(self.pos + n + 2) == k3
+            && self.kind_at(self.pos + n + 3) == k4
     }
 
     fn next_not_joined_op(&self, n: usize) -> bool {
@@ -548,6 +574,67 @@ impl<'t> Parser<'t> {
         kinds.contains(self.current())
     }
 
+    /// Runs `f` with the input ending `n` tokens ahead.
+    ///
+    /// Used for PL/pgSQL where SQL expressions and queries end at words like
+    /// `loop` that the SQL grammar doesn't know about.
+    pub(crate) fn with_limit<T>(&mut self, n: usize, f: impl FnOnce(&mut Self) -> T) -> T {
+        let prev = self.limit;
+        self.limit = prev.min(self.pos + n);
+        let res = f(self);
+        self.limit = prev;
+        res
+    }
+
+    /// Checks if the nth token is an identifier that PL/pgSQL treats as the
+    /// keyword `kind`.
+    #[must_use]
+    pub(crate) fn nth_at_contextual_kw(&self, n: usize, kind: SyntaxKind) -> bool {
+        self.nth(n) == SyntaxKind::IDENT && self.inp.contextual_kind(self.pos + n) == kind
+    }
+
+    #[must_use]
+    pub(crate) fn at_contextual_kw(&self, kind: SyntaxKind) -> bool {
+        self.nth_at_contextual_kw(0, kind)
+    }
+
+    /// Consume the next token, giving it the kind `kind`.
+    pub(crate) fn bump_remap(&mut self, kind: SyntaxKind) {
+        if self.nth(0) == SyntaxKind::EOF {
+            return;
+        }
+        self.do_bump(kind, 1);
+    }
+
+    #[must_use]
+    pub(crate) fn in_plpgsql(&self) -> bool {
+        self.plpgsql
+    }
+
+    #[must_use]
+    pub(crate) fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    /// Consume the next token if it's the contextual keyword `kind`.
+    pub(crate) fn eat_contextual_kw(&mut self, kind: SyntaxKind) -> bool {
+        if !self.at_contextual_kw(kind) {
+            return false;
+        }
+        self.do_bump(kind, 1);
+        true
+    }
+
+    /// Consume the next token as the contextual keyword `kind` or emit an
+    /// error otherwise.
+    pub(crate) fn expect_contextual_kw(&mut self, kind: SyntaxKind) -> bool {
+        if self.eat_contextual_kw(kind) {
+            return true;
+        }
+        self.error(self.expected_msg(&[kind]));
+        false
+    }
+
     /// Starts a new node in the syntax tree. All nodes and tokens
     /// consumed between the `start` and the corresponding `Marker::complete`
     /// belong to the same node.
@@ -577,10 +664,91 @@ impl<'t> Parser<'t> {
         if self.eat(kind) {
             return true;
         }
-        self.error(format!("expected {kind:?}"));
+        self.error(self.expected_msg(&[kind]));
         false
     }
 
+    /// An error message listing the tokens in `expected`, e.g., ``expected
+    /// `;`, found `zzz` ``.
+    pub(crate) fn expected_one_of(&self, expected: TokenSet) -> String {
+        let expected: Vec<SyntaxKind> = expected.iter().collect();
+        self.expected_msg(&expected)
+    }
+
+    /// The current token for error messages, e.g., `` `selct` `` or `end of
+    /// file`.
+    pub(crate) fn found(&self) -> String {
+        if self.current() == SyntaxKind::EOF {
+            return SyntaxKind::EOF.display_name();
+        }
+        format!("`{}`", self.inp.text(self.pos))
+    }
+
+    /// The keyword in `expected` that the current identifier is most likely a
+    /// typo of, e.g., `SELECT` for `selct`.
+    pub(crate) fn suggest_keyword(&self, expected: TokenSet) -> Option<SyntaxKind> {
+        self.suggest_nth_keyword(0, expected)
+    }
+
+    /// Like [`Parser::suggest_keyword`] but for the nth token.
+    pub(crate) fn suggest_nth_keyword(&self, n: usize, expected: TokenSet) -> Option<SyntaxKind> {
+        if self.nth(n) != SyntaxKind::IDENT {
+            return None;
+        }
+        self.suggest_keyword_at(self.pos + n, &expected.iter().collect::<Vec<_>>())
+    }
+
+    /// Like [`Parser::suggest_keyword`] but for the last consumed token, e.g.,
+    /// `form` in `select * form t`.
+    pub(crate) fn suggest_prev_keyword(&self, expected: TokenSet) -> Option<SyntaxKind> {
+        if !self.prev_at(SyntaxKind::IDENT) {
+            return None;
+        }
+        self.suggest_keyword_at(self.pos - 1, &expected.iter().collect::<Vec<_>>())
+    }
+
+    /// The text of the last consumed token.
+    pub(crate) fn prev_text(&self) -> &str {
+        if self.pos == 0 {
+            return "";
+        }
+        self.inp.text(self.pos - 1)
+    }
+
+    fn suggest_keyword_at(&self, idx: usize, expected: &[SyntaxKind]) -> Option<SyntaxKind> {
+        let keywords: Vec<(SyntaxKind, String)> = expected
+            .iter()
+            .filter_map(|kind| Some((*kind, kind.keyword_text()?)))
+            .collect();
+        let suggestion = squawk_lexer::closest_keyword(
+            self.inp.text(idx),
+            keywords.iter().map(|(_, text)| text.as_str()),
+        )?;
+        keywords
+            .iter()
+            .find(|(_, text)| text == suggestion)
+            .map(|(kind, _)| *kind)
+    }
+
+    fn expected_msg(&self, expected: &[SyntaxKind]) -> String {
+        let names: Vec<String> = expected.iter().map(|kind| kind.display_name()).collect();
+        let names = match names.as_slice() {
+            [] => "nothing".to_owned(),
+            [name] => name.clone(),
+            [a, b] => format!("{a} or {b}"),
+            [rest @ .., last] => format!("{}, or {last}", rest.join(", ")),
+        };
+        let mut msg = format!("expected {names}, found {}", self.found());
+        // with a single expected keyword the suggestion would repeat it
+        if expected.len() > 1
+            && self.current() == SyntaxKind::IDENT
+            && let Some(suggestion) = self.suggest_keyword_at(self.pos, expected)
+        {
+            msg.push_str(&format!(", did you mean {}?", suggestion.display_name()));
+        }
+        msg
+    }
+
     /// Create an error node and consume the next token.
     pub(crate) fn err_and_bump(&mut self, message: &str) {
         self.err_recover(message, TokenSet::EMPTY);
@@ -628,6 +796,12 @@ impl<'t> Parser<'t> {
         self.push_event(Event::Error { msg });
     }
 
+    /// Checks if the last consumed token is `kind`.
+    #[must_use]
+    pub(crate) fn prev_at(&self, kind: SyntaxKind) -> bool {
+        self.pos > 0 && self.inp.kind(self.pos - 1) == kind
+    }
+
     /// Checks if the current token is `kind`.
     #[must_use]
     pub(crate) fn at(&self, kind: SyntaxKind) -> bool {
@@ -892,7 +1066,7 @@ impl<'t> Parser<'t> {
                 return false;
             }
             // TODO: we probably shouldn't be using a _ for this but be explicit for each type?
-            _ => self.inp.kind(self.pos + n) == kind,
+            _ => self.kind_at(self.pos + n) == kind,
         }
     }
 
@@ -915,6 +1089,13 @@ impl<'t> Parser<'t> {
         );
         self.steps.set(steps + 1);
 
-        self.inp.kind(self.pos + n)
+        self.kind_at(self.pos + n)
+    }
+
+    fn kind_at(&self, idx: usize) -> SyntaxKind {
+        if idx >= self.limit {
+            return SyntaxKind::EOF;
+        }
+        self.inp.kind(idx)
     }
 }
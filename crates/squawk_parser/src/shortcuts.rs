@@ -115,18 +115,18 @@ impl LexedStr<'_> {
                 was_joint = false;
                 // skip over any triva since the parser shouldn't have to deal
                 // with it
-            }
-            // else if kind == SyntaxKind::IDENT {
-            //     let token_text = self.text(i);
-            //     let contextual_kw =
-            //         SyntaxKind::from_contextual_keyword(token_text).unwrap_or(SyntaxKind::IDENT);
-            //     res.push_ident(contextual_kw);
-            // }
-            else {
+            } else {
                 if was_joint {
                     res.was_joint();
                 }
-                res.push(kind);
+                let token_text = self.range_text(i..i + 1);
+                if kind == SyntaxKind::IDENT {
+                    let contextual_kw = SyntaxKind::from_contextual_keyword(token_text)
+                        .unwrap_or(SyntaxKind::IDENT);
+                    res.push_ident(contextual_kw, token_text);
+                } else {
+                    res.push(kind, token_text);
+                }
                 was_joint = true;
             }
         }
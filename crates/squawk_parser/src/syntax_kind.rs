@@ -23,3 +23,72 @@ impl SyntaxKind {
         matches!(self, SyntaxKind::WHITESPACE | SyntaxKind::COMMENT)
     }
 }
+
+impl SyntaxKind {
+    /// How the token is shown in error messages, e.g., `SELECT` or `` `,` ``
+    pub(crate) fn display_name(self) -> String {
+        let punct = match self {
+            SyntaxKind::DOLLAR => "$",
+            SyntaxKind::SEMICOLON => ";",
+            SyntaxKind::COMMA => ",",
+            SyntaxKind::L_PAREN => "(",
+            SyntaxKind::R_PAREN => ")",
+            SyntaxKind::L_BRACK => "[",
+            SyntaxKind::R_BRACK => "]",
+            SyntaxKind::L_CURLY => "{",
+            SyntaxKind::R_CURLY => "}",
+            SyntaxKind::L_ANGLE => "<",
+            SyntaxKind::R_ANGLE => ">",
+            SyntaxKind::AT => "@",
+            SyntaxKind::POUND => "#",
+            SyntaxKind::TILDE => "~",
+            SyntaxKind::QUESTION => "?",
+            SyntaxKind::AMP => "&",
+            SyntaxKind::PIPE => "|",
+            SyntaxKind::PLUS => "+",
+            SyntaxKind::STAR => "*",
+            SyntaxKind::SLASH => "/",
+            SyntaxKind::CARET => "^",
+            SyntaxKind::PERCENT => "%",
+            SyntaxKind::UNDERSCORE => "_",
+            SyntaxKind::DOT => ".",
+            SyntaxKind::COLON => ":",
+            SyntaxKind::EQ => "=",
+            SyntaxKind::BANG => "!",
+            SyntaxKind::MINUS => "-",
+            SyntaxKind::BACKTICK => "`",
+            SyntaxKind::FAT_ARROW => "=>",
+            SyntaxKind::COLON_EQ => ":=",
+            SyntaxKind::COLON_COLON => "::",
+            SyntaxKind::NEQ => "!=",
+            SyntaxKind::NEQB => "<>",
+            SyntaxKind::LTEQ => "<=",
+            SyntaxKind::GTEQ => ">=",
+            SyntaxKind::EOF => return "end of file".to_owned(),
+            SyntaxKind::IDENT => return "name".to_owned(),
+            SyntaxKind::INT_NUMBER | SyntaxKind::NUMERIC_NUMBER => return "number".to_owned(),
+            SyntaxKind::STRING
+            | SyntaxKind::DOLLAR_QUOTED_STRING
+            | SyntaxKind::ESC_STRING
+            | SyntaxKind::NATIONAL_STRING
+            | SyntaxKind::UNICODE_ESC_STRING
+            | SyntaxKind::BIT_STRING
+            | SyntaxKind::BYTE_STRING => return "string".to_owned(),
+            _ => {
+                let name = format!("{self:?}");
+                return match name.strip_suffix("_KW") {
+                    Some(keyword) => keyword.to_owned(),
+                    None => name.to_lowercase().replace('_', " "),
+                };
+            }
+        };
+        format!("`{punct}`")
+    }
+
+    /// The text of a keyword, e.g., `select` for `SELECT_KW`.
+    pub(crate) fn keyword_text(self) -> Option<String> {
+        format!("{self:?}")
+            .strip_suffix("_KW")
+            .map(|keyword| keyword.to_lowercase())
+    }
+}
@@ -81,6 +81,13 @@ impl TokenSet {
         let mask = 1 << (discriminant % 64);
         self.0[idx] & mask != 0
     }
+
+    /// The kinds in the set, in `SyntaxKind` order.
+    pub(crate) fn iter(self) -> impl Iterator<Item = SyntaxKind> {
+        (0..=LAST_TOKEN_KIND_DISCRIMINANT)
+            .filter(move |d| self.0[d / 64] & (1 << (d % 64)) != 0)
+            .map(|d| SyntaxKind::from(d as u16))
+    }
 }
 
 #[test]
@@ -91,3 +98,10 @@ fn token_set_works_for_tokens() {
     assert!(ts.contains(WHITESPACE));
     assert!(!ts.contains(PLUS));
 }
+
+#[test]
+fn token_set_iter() {
+    use crate::SyntaxKind::*;
+    let ts = TokenSet::new(&[SELECT_KW, COMMA, IDENT]);
+    assert_eq!(ts.iter().collect::<Vec<_>>(), vec![COMMA, SELECT_KW, IDENT]);
+}
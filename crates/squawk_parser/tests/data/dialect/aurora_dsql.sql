@@ -0,0 +1,8 @@
+-- async index builds
+create index async on t (a);
+create unique index async if not exists t_a_idx on t using btree (a, b desc);
+create index async t_b_idx on t (b) include (c);
+
+-- async is still an identifier elsewhere
+create table async (async int);
+select async from async;
@@ -0,0 +1,32 @@
+-- misspelled keyword
+selct 1;
+
+-- misspelled keyword inside a statement
+create tabel t (id int);
+
+drop tabel t;
+
+alter tabel t add column b int;
+
+select * form t;
+
+-- mysql style quoting
+select `id` from `users`;
+
+-- trailing comma in a column list
+create table t (a int, b text,);
+
+insert into t (a, b,) values (1, 2);
+
+-- missing semicolon
+select 1
+select 2;
+
+select a from t
+select b from u;
+
+-- junk after a statement
+select 1 foo bar;
+
+-- later statements still parse
+create table u (id int);
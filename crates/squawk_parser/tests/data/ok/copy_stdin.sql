@@ -0,0 +1,20 @@
+-- pg_dump output
+COPY public.users (id, name, bio) FROM stdin;
+1	alice	\N
+2	bob	likes; semicolons -- and comments
+\.
+
+-- csv
+copy t from stdin with (format csv);
+1,"a, b"
+\.
+
+-- no rows
+COPY public.empty (id) FROM stdin;
+\.
+
+SELECT pg_catalog.setval('public.users_id_seq', 2, true);
+
+-- not followed by rows
+copy t from stdin;
+copy t to stdout;
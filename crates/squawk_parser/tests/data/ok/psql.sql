@@ -0,0 +1,23 @@
+\set ON_ERROR_STOP on
+\connect app
+\set tbl users
+
+-- variables are interpolated as literals and identifiers
+select * from :"tbl" where name = :'name';
+alter table :"tbl" add column c text default :'default';
+
+-- `::"t"` is still a cast
+select 'a'::"text";
+
+-- queries can be sent with a meta-command instead of a semicolon
+select count(*) as total from :"tbl" \gset
+select format('vacuum %I', tablename) from pg_tables
+\gexec
+select 1 \; select 2;
+
+\if :{?total}
+  \echo :total
+\else
+  \i other.sql
+\endif
+\ir ./relative.sql
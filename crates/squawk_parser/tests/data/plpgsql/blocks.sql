@@ -0,0 +1,38 @@
+<<outer>>
+declare
+  quantity integer := 30;
+  total constant numeric not null default 0;
+  name text collate "C" = 'x';
+  r record;
+  emp employees%rowtype;
+  emps employees.name%type[];
+  grid emps%type array[2][2];
+  id users.id%type;
+  arr int[];
+  first_arg alias for $1;
+  curs1 refcursor;
+  curs2 scroll cursor for select * from tenk1;
+  curs3 no scroll cursor (key integer) is select * from tenk1 where unique1 = key;
+begin
+  quantity := 50;
+  quantity = quantity + 1;
+  r.f1 := 1;
+  arr[1] := 2;
+  total := sum(amount) from orders where id = 1 limit 1;
+  declare
+    quantity integer := 80;
+  begin
+    raise notice 'inner %, outer %', quantity, outer.quantity;
+  end;
+  begin
+    null;
+  exception
+    when division_by_zero or unique_violation then
+      raise notice 'caught';
+    when sqlstate '22012' then
+      null;
+    when others then
+      raise;
+  end;
+  return quantity;
+end outer;
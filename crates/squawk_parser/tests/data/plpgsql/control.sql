@@ -0,0 +1,54 @@
+begin
+  if x > 0 then
+    y := 1;
+  elsif x < 0 then
+    y := -1;
+  elseif x is null then
+    y := null;
+  else
+    y := 0;
+  end if;
+  case x
+    when 1, 2 then
+      msg := 'one or two';
+    else
+      msg := 'other';
+  end case;
+  case
+    when x between 0 and 10 then
+      msg := 'small';
+  end case;
+  loop
+    i := i + 1;
+    exit when i > 10;
+    continue when i % 2 = 0;
+  end loop;
+  <<lbl>>
+  while i < 20 loop
+    exit lbl;
+  end loop lbl;
+  for i in 1..10 loop
+    null;
+  end loop;
+  for i in reverse 10 .. 1 by 2 loop
+    null;
+  end loop;
+  for r in select * from t where a = 1 loop
+    raise notice '%', r.a;
+  end loop;
+  for a, b in execute 'select a, b from ' || quote_ident(tbl) using x loop
+    null;
+  end loop;
+  for lbl.a, lbl.b in select 1, 2 loop
+    null;
+  end loop;
+  for r in curs(42) loop
+    null;
+  end loop;
+  foreach x slice 1 in array arr loop
+    null;
+  end loop;
+  foreach x in array array[1, 2] loop
+    null;
+  end loop;
+end
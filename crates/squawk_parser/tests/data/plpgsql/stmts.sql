@@ -0,0 +1,43 @@
+#variable_conflict use_column
+declare
+  n int;
+  c refcursor;
+begin
+  raise;
+  raise notice 'value: %, %', a, b;
+  raise exception 'bad' using hint = 'try again', errcode = 'P0001';
+  raise sqlstate '22012';
+  raise division_by_zero using message = 'no';
+  raise using message := 'x';
+  assert n > 0;
+  assert n > 0, 'n must be positive';
+  perform pg_sleep(1);
+  perform 1 from t where a = 1;
+  perform from t;
+  get diagnostics n = row_count;
+  get stacked diagnostics msg = message_text, det := pg_exception_detail;
+  execute 'alter table ' || quote_ident(t) || ' add column c int';
+  execute format('select count(*) from %I', t) into strict n using a, b;
+  select count(*) into n from t;
+  select a, b into strict x, y from t where id = 1;
+  select into y data from t where id = 1;
+  create function f() returns int begin atomic select 1; end;
+  insert into t (a) values (1) returning id into n;
+  update t set a = 2 where id = n;
+  delete from t where id = n returning a into x;
+  alter table t add column d int;
+  open c for select * from t;
+  open c scroll for execute 'select 1' using n;
+  open c2(42);
+  fetch c into x;
+  fetch next from c into x, y;
+  move forward 2 from c;
+  close c;
+  call proc(1);
+  commit;
+  rollback;
+  return next x;
+  return query select * from t;
+  return query execute 'select 1' using n;
+  return;
+end;
@@ -3,7 +3,7 @@ use annotate_snippets::{AnnotationKind, Level, Renderer, Snippet, renderer::Deco
 use camino::Utf8Path;
 use dir_test::{Fixture, dir_test};
 use insta::{assert_snapshot, with_settings};
-use squawk_parser::{LexedStr, parse};
+use squawk_parser::{Dialect, LexedStr, parse_dialect, parse_plpgsql};
 use std::fmt::Write;
 
 #[dir_test(
@@ -64,6 +64,64 @@ fn parser_err(fixture: Fixture<&str>) {
     );
 }
 
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/tests/data/plpgsql",
+    glob: "*.sql",
+)]
+fn parser_plpgsql(fixture: Fixture<&str>) {
+    let content = fixture.content();
+    let absolute_fixture_path = Utf8Path::new(fixture.path());
+    let input_file = absolute_fixture_path;
+    let test_name = absolute_fixture_path
+        .file_name()
+        .and_then(|x| x.strip_suffix(".sql"))
+        .unwrap();
+
+    let (parsed, errors) = parse_text_with(content, true, Dialect::Postgres);
+
+    with_settings!({
+      omit_expression => true,
+      input_file => input_file,
+    }, {
+      assert_snapshot!(format!("plpgsql_{}", test_name), parsed);
+    });
+
+    assert!(
+        errors.is_none(),
+        "tests defined in the `plpgsql` directory can't have parser errors."
+    );
+}
+
+// Each file is named after the dialect whose grammar extensions it uses.
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/tests/data/dialect",
+    glob: "*.sql",
+)]
+fn parser_dialect(fixture: Fixture<&str>) {
+    let content = fixture.content();
+    let absolute_fixture_path = Utf8Path::new(fixture.path());
+    let input_file = absolute_fixture_path;
+    let test_name = absolute_fixture_path
+        .file_name()
+        .and_then(|x| x.strip_suffix(".sql"))
+        .unwrap();
+    let dialect: Dialect = test_name.replace('_', "-").parse().unwrap();
+
+    let (parsed, errors) = parse_text_with(content, false, dialect);
+
+    with_settings!({
+      omit_expression => true,
+      input_file => input_file,
+    }, {
+      assert_snapshot!(format!("dialect_{}", test_name), parsed);
+    });
+
+    assert!(
+        errors.is_none(),
+        "tests defined in the `dialect` directory can't have parser errors."
+    );
+}
+
 #[dir_test(
     dir: "$CARGO_MANIFEST_DIR/../../postgres/regression_suite",
     glob: "*.sql",
@@ -111,9 +169,18 @@ fn regression_suite(fixture: Fixture<&str>) {
 
 #[must_use]
 fn parse_text(text: &str) -> (String, Option<String>) {
+    parse_text_with(text, false, Dialect::Postgres)
+}
+
+#[must_use]
+fn parse_text_with(text: &str, plpgsql: bool, dialect: Dialect) -> (String, Option<String>) {
     let lexed = LexedStr::new(text);
     let input = lexed.to_input();
-    let output = parse(&input);
+    let output = if plpgsql {
+        parse_plpgsql(&input)
+    } else {
+        parse_dialect(&input, dialect)
+    };
 
     let mut buf = String::new();
     let mut errors: Vec<(std::ops::Range<usize>, String)> = Vec::new();
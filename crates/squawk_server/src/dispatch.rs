@@ -12,6 +12,7 @@ use squawk_thread::ThreadIntent;
 
 use crate::{
     global_state::{GlobalState, Snapshot, TaskResult},
+    lsp_utils::LspError,
     panic::PanicError,
 };
 
@@ -159,11 +160,14 @@ where
     match result {
         Ok(handler_result) => match handler_result {
             Ok(result) => Ok(Response::new_ok(id, result)),
-            Err(error) => Ok(Response::new_err(
-                id,
-                lsp_server::ErrorCode::InternalError as i32,
-                error.to_string(),
-            )),
+            Err(error) => match error.downcast::<LspError>() {
+                Ok(error) => Ok(Response::new_err(id, error.code, error.message)),
+                Err(error) => Ok(Response::new_err(
+                    id,
+                    lsp_server::ErrorCode::InternalError as i32,
+                    error.to_string(),
+                )),
+            },
         },
         Err(panic) => {
             // Check if the request was canceled due to some modifications to the salsa database.
@@ -1,34 +1,53 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::{num::NonZeroUsize, sync::Arc, time::Instant};
 
 use crossbeam_channel::{Receiver, Sender, select, unbounded};
-use gen_lsp_types::Notification as _;
 use gen_lsp_types::{
-    CancelNotification, DidChangeTextDocumentNotification, DidCloseTextDocumentNotification,
-    DidOpenTextDocumentNotification, ExitNotification,
+    CancelNotification, DidChangeNotebookDocumentNotification, DidChangeTextDocumentNotification,
+    DidChangeWatchedFilesNotification, DidChangeWatchedFilesRegistrationOptions,
+    DidCloseNotebookDocumentNotification, DidCloseTextDocumentNotification,
+    DidOpenNotebookDocumentNotification, DidOpenTextDocumentNotification, ExitNotification,
+    FileSystemWatcher, GlobPattern, Registration, RegistrationParams, RegistrationRequest,
 };
-use log::info;
+use gen_lsp_types::{Notification as _, Request as _};
+use log::{error, info, warn};
 use lsp_server::{Message, Request, Response};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use salsa::Setter;
+use squawk_fmt::FormatOptions;
 use squawk_ide::builtins::{builtins_file, builtins_url};
-use squawk_ide::db::{Database, File};
-use squawk_thread::TaskPool;
+use squawk_ide::db::{
+    Database, File, Notebook, set_canonical_types, set_dialect, set_pg_version, set_workspace_files,
+};
+use squawk_linter::Dialect;
+use squawk_thread::{TaskPool, ThreadIntent};
 use url::Url;
 
 use gen_lsp_types::{
     CodeActionRequest, CompletionRequest, DefinitionRequest, DocumentDiagnosticRequest,
-    DocumentSymbolRequest, FoldingRangeRequest, HoverRequest, InlayHintRequest, ReferencesRequest,
+    DocumentFormattingRequest, DocumentLinkRequest, DocumentOnTypeFormattingRequest,
+    DocumentRangeFormattingRequest, DocumentSymbolRequest, FoldingRangeRequest, HoverRequest,
+    InlayHintRequest, PrepareRenameRequest, ReferencesRequest, RenameRequest,
     SelectionRangeRequest, SemanticTokensRangeRequest, SemanticTokensRequest, ShutdownRequest,
 };
 
 use crate::dispatch::{NotificationDispatcher, RequestDispatcher};
 use crate::handlers::{
     SyntaxTreeRequest, TokensRequest, handle_cancel, handle_code_action, handle_completion,
-    handle_did_change, handle_did_close, handle_did_open, handle_document_diagnostic,
-    handle_document_symbol, handle_folding_range, handle_goto_definition, handle_hover,
-    handle_inlay_hints, handle_references, handle_selection_range, handle_semantic_tokens_full,
+    handle_did_change, handle_did_change_notebook, handle_did_change_watched_files,
+    handle_did_close, handle_did_close_notebook, handle_did_open, handle_did_open_notebook,
+    handle_document_diagnostic, handle_document_link, handle_document_symbol, handle_folding_range,
+    handle_formatting, handle_goto_definition, handle_hover, handle_inlay_hints,
+    handle_on_type_formatting, handle_prepare_rename, handle_range_formatting, handle_references,
+    handle_rename, handle_selection_range, handle_semantic_tokens_full,
     handle_semantic_tokens_range, handle_shutdown, handle_syntax_tree, handle_tokens,
 };
+use crate::server::{LoadConfig, ServerConfig};
+use crate::workspace::WorkspaceRoots;
+
+/// The config file we reload when it changes, see [`GlobalState::reload_config`].
+pub(crate) const CONFIG_FILE_NAME: &str = ".squawk.toml";
 
 type ReqQueue = lsp_server::ReqQueue<(String, Instant), ()>;
 
@@ -37,10 +56,30 @@ pub(crate) struct Handle<H, C> {
     pub(crate) receiver: C,
 }
 
+struct OpenNotebook {
+    notebook: Notebook,
+    /// Every cell in the notebook, including the ones we don't sync like
+    /// markdown cells, so we can apply the client's changes by index.
+    cells: Vec<Url>,
+}
+
 pub(super) struct GlobalState {
     db: Database,
     files: Arc<FxHashMap<Url, File>>,
     uris: Arc<FxHashMap<File, Url>>,
+    notebooks: FxHashMap<Url, OpenNotebook>,
+    /// Documents the client has open, their content comes from the client
+    /// instead of the disk.
+    open_files: FxHashSet<Url>,
+    workspace: Arc<WorkspaceRoots>,
+    /// The `.sql` files of the workspace, sorted by path so they're in
+    /// migration order.
+    workspace_files: BTreeMap<PathBuf, File>,
+    format_options: Arc<FormatOptions>,
+    load_config: LoadConfig,
+    /// From the client's initialization options, takes precedence over the
+    /// config file.
+    dialect: Option<Dialect>,
     req_queue: ReqQueue,
     sender: Sender<Message>,
     pub(crate) task_pool: Handle<TaskPool<TaskResult>, Receiver<TaskResult>>,
@@ -48,28 +87,90 @@ pub(super) struct GlobalState {
 }
 
 impl GlobalState {
-    pub(super) fn new(sender: Sender<Message>) -> Self {
+    pub(super) fn new(
+        sender: Sender<Message>,
+        pg_version: Option<i32>,
+        dialect: Option<Dialect>,
+        roots: Vec<PathBuf>,
+        load_config: LoadConfig,
+    ) -> Self {
         let threads = std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
         let task_pool = {
             let (sender, receiver) = unbounded();
             let handle = TaskPool::new_with_threads(sender.clone(), threads);
             Handle { handle, receiver }
         };
-        let db = Database::default();
+        let mut db = Database::default();
+        set_pg_version(&mut db, pg_version);
         let mut uris = FxHashMap::default();
         if let Some(uri) = builtins_url(&db) {
             uris.insert(builtins_file(&db), uri);
         }
 
-        Self {
+        let mut state = Self {
             db,
             files: Arc::new(FxHashMap::default()),
             uris: Arc::new(uris),
+            notebooks: FxHashMap::default(),
+            open_files: FxHashSet::default(),
+            workspace: Arc::new(WorkspaceRoots::new(roots, &[])),
+            workspace_files: BTreeMap::new(),
+            format_options: Arc::default(),
+            load_config,
+            dialect,
             req_queue: ReqQueue::default(),
             task_pool,
             sender,
             shutdown_requested: false,
+        };
+        let config = state.read_config();
+        state.apply_config(config);
+        state
+    }
+
+    /// An invalid config file shouldn't take down the server, so we log the
+    /// error and use the defaults until it's fixed.
+    fn read_config(&self) -> ServerConfig {
+        let root = self.workspace.roots().first().map(PathBuf::as_path);
+        (self.load_config)(root).unwrap_or_else(|err| {
+            error!("Failed to load the config file, using the defaults: {err:#}");
+            ServerConfig::default()
+        })
+    }
+
+    fn apply_config(&mut self, config: ServerConfig) {
+        info!("Config: {config:?}");
+        let dialect = self.dialect.unwrap_or(config.dialect);
+        set_dialect(&mut self.db, dialect);
+        set_canonical_types(
+            &mut self.db,
+            config.format_options.canonical_types.overrides().clone(),
+        );
+        self.format_options = Arc::new(FormatOptions {
+            dialect,
+            ..config.format_options
+        });
+        self.workspace = Arc::new(WorkspaceRoots::new(
+            self.workspace.roots().to_vec(),
+            &config.excluded_paths,
+        ));
+    }
+
+    /// Reread the config file after it changes on disk, and reindex the
+    /// workspace in case its `excluded_paths` changed.
+    pub(crate) fn reload_config(&mut self) {
+        let config = self.read_config();
+        self.apply_config(config);
+        let excluded: Vec<PathBuf> = self
+            .workspace_files
+            .keys()
+            .filter(|path| !self.workspace.contains(path))
+            .cloned()
+            .collect();
+        for path in excluded {
+            self.remove_workspace_files(&path);
         }
+        self.load_workspace();
     }
 
     /// Readonly snapshot of the database & files for request handlers
@@ -78,6 +179,7 @@ impl GlobalState {
             db: self.db.clone(),
             files: self.files.clone(),
             uris: self.uris.clone(),
+            format_options: self.format_options.clone(),
         }
     }
 
@@ -90,24 +192,187 @@ impl GlobalState {
     }
 
     pub(crate) fn set(&mut self, uri: Url, content: String) {
+        self.open_files.insert(uri.clone());
+        self.set_content(uri, content);
+    }
+
+    fn set_content(&mut self, uri: Url, content: String) -> File {
         if let Some(file) = self.files.get(&uri).copied() {
             file.set_content(&mut self.db).to(content.into());
+            file.set_reparse(&mut self.db).to(None);
+            file
         } else {
             let file = File::new(&self.db, content.into());
             Arc::make_mut(&mut self.files).insert(uri.clone(), file);
             Arc::make_mut(&mut self.uris).insert(file, uri);
+            file
         }
     }
 
+    /// Update an open file after an edit, so the next parse can reuse the
+    /// last one. We don't parse here, that's left to whichever request needs
+    /// the tree next.
+    pub(crate) fn change(&mut self, uri: Url, content: String) {
+        let Some(file) = self.file(&uri) else {
+            return self.set(uri, content);
+        };
+        if **file.content(&self.db) == *content {
+            return;
+        }
+        let reparse = file.last_parse(&self.db).reparse(&content);
+        file.set_content(&mut self.db).to(content.into());
+        file.set_reparse(&mut self.db).to(reparse);
+    }
+
     pub(crate) fn remove(&mut self, uri: &Url) {
+        self.open_files.remove(uri);
+        // Closing a migration goes back to what's on disk, so the rest of the
+        // workspace can still see it.
+        if let Ok(path) = uri.to_file_path()
+            && self.workspace_files.contains_key(&path)
+        {
+            match std::fs::read_to_string(&path) {
+                Ok(content) => {
+                    self.set_content(uri.clone(), content);
+                    return;
+                }
+                Err(err) => {
+                    warn!("Failed to read {}: {err}", path.display());
+                    self.workspace_files.remove(&path);
+                    self.sync_workspace();
+                }
+            }
+        }
         if let Some(file) = Arc::make_mut(&mut self.files).remove(uri) {
             // We can't delete file inputs in Salsa, so just set the content to
             // empty string.
             file.set_content(&mut self.db).to("".into());
+            file.set_notebook(&mut self.db).to(None);
+            file.set_reparse(&mut self.db).to(None);
             Arc::make_mut(&mut self.uris).remove(&file);
         }
     }
 
+    pub(crate) fn in_workspace(&self, path: &Path) -> bool {
+        self.workspace.contains(path)
+    }
+
+    /// Add or update migrations with their content on disk, files the client
+    /// has open keep the client's content.
+    pub(crate) fn add_workspace_files(&mut self, files: Vec<(PathBuf, String)>) {
+        let mut added = false;
+        for (path, content) in files {
+            let Ok(uri) = Url::from_file_path(&path) else {
+                continue;
+            };
+            let file = if self.open_files.contains(&uri) {
+                self.files[&uri]
+            } else {
+                self.set_content(uri, content)
+            };
+            added |= self.workspace_files.insert(path, file).is_none();
+        }
+        if added {
+            self.sync_workspace();
+        }
+    }
+
+    /// Remove the migrations at or under `path`, e.g., when a directory is
+    /// deleted.
+    pub(crate) fn remove_workspace_files(&mut self, path: &Path) {
+        let removed: Vec<PathBuf> = self
+            .workspace_files
+            .keys()
+            .filter(|file_path| file_path.starts_with(path))
+            .cloned()
+            .collect();
+        if removed.is_empty() {
+            return;
+        }
+        for file_path in removed {
+            self.workspace_files.remove(&file_path);
+            if let Ok(uri) = Url::from_file_path(&file_path)
+                && !self.open_files.contains(&uri)
+            {
+                self.remove(&uri);
+            }
+        }
+        self.sync_workspace();
+    }
+
+    fn sync_workspace(&mut self) {
+        let files = self.workspace_files.values().copied().collect();
+        set_workspace_files(&mut self.db, files);
+    }
+
+    /// Index the migrations in the background so we can start answering
+    /// requests for the open files right away.
+    fn load_workspace(&mut self) {
+        let workspace = self.workspace.clone();
+        self.task_pool.handle.spawn(ThreadIntent::Worker, move || {
+            TaskResult::Workspace(workspace.scan())
+        });
+    }
+
+    /// Ask the client to tell us when `.sql` files or the config file change
+    /// on disk.
+    fn watch_workspace(&mut self) {
+        let options = DidChangeWatchedFilesRegistrationOptions {
+            watchers: ["**/*.sql".to_owned(), format!("**/{CONFIG_FILE_NAME}")]
+                .into_iter()
+                .map(|pattern| FileSystemWatcher {
+                    glob_pattern: GlobPattern::Pattern(pattern),
+                    kind: None,
+                })
+                .collect(),
+        };
+        let params = RegistrationParams {
+            registrations: vec![Registration {
+                id: DidChangeWatchedFilesNotification::METHOD.to_string(),
+                method: DidChangeWatchedFilesNotification::METHOD.to_string(),
+                register_options: serde_json::to_value(options).ok(),
+            }],
+        };
+        let request =
+            self.req_queue
+                .outgoing
+                .register(RegistrationRequest::METHOD.to_string(), params, ());
+        self.send(request.into());
+    }
+
+    pub(crate) fn notebook_cells(&self, uri: &Url) -> Option<&[Url]> {
+        self.notebooks.get(uri).map(|open| open.cells.as_slice())
+    }
+
+    /// Update the cells of a notebook, the cells' text documents should
+    /// already be open.
+    pub(crate) fn set_notebook(&mut self, uri: Url, cells: Vec<Url>) {
+        let files: Vec<File> = cells.iter().filter_map(|cell| self.file(cell)).collect();
+        let notebook = match self.notebooks.get_mut(&uri) {
+            Some(open) => {
+                open.cells = cells;
+                open.notebook.set_cells(&mut self.db).to(files.clone());
+                open.notebook
+            }
+            None => {
+                let notebook = Notebook::new(&self.db, files.clone());
+                self.notebooks.insert(uri, OpenNotebook { notebook, cells });
+                notebook
+            }
+        };
+        for file in files {
+            if file.notebook(&self.db) != Some(notebook) {
+                file.set_notebook(&mut self.db).to(Some(notebook));
+            }
+        }
+    }
+
+    pub(crate) fn remove_notebook(&mut self, uri: &Url) {
+        if let Some(open) = self.notebooks.remove(uri) {
+            open.notebook.set_cells(&mut self.db).to(vec![]);
+        }
+    }
+
     /// Track the request time and support marking cancellation
     pub(crate) fn register_request(
         &mut self,
@@ -149,7 +414,15 @@ impl GlobalState {
         self.sender.send(message).unwrap();
     }
 
-    pub(crate) fn run(&mut self, inbox: Receiver<Message>) -> anyhow::Result<()> {
+    pub(crate) fn run(
+        &mut self,
+        inbox: Receiver<Message>,
+        watch_files: bool,
+    ) -> anyhow::Result<()> {
+        self.load_workspace();
+        if watch_files {
+            self.watch_workspace();
+        }
         let outbox = &self.task_pool.receiver.clone();
         while let Ok(event) = self.next_event(&inbox, outbox) {
             let loop_start = Instant::now();
@@ -158,6 +431,7 @@ impl GlobalState {
                     Message::Request(req) => self.handle_request(req, loop_start),
                     Message::Response(resp) => {
                         info!("Received response: id={:?}", resp.id);
+                        self.req_queue.outgoing.complete(resp.id);
                     }
                     Message::Notification(notif) => {
                         info!("Received notification: method={}", notif.method);
@@ -171,6 +445,14 @@ impl GlobalState {
                             .on::<DidOpenTextDocumentNotification>(handle_did_open)?
                             .on::<DidChangeTextDocumentNotification>(handle_did_change)?
                             .on::<DidCloseTextDocumentNotification>(handle_did_close)?
+                            .on::<DidOpenNotebookDocumentNotification>(handle_did_open_notebook)?
+                            .on::<DidChangeNotebookDocumentNotification>(
+                                handle_did_change_notebook,
+                            )?
+                            .on::<DidCloseNotebookDocumentNotification>(handle_did_close_notebook)?
+                            .on::<DidChangeWatchedFilesNotification>(
+                                handle_did_change_watched_files,
+                            )?
                             .finish();
                     }
                 },
@@ -186,6 +468,10 @@ impl GlobalState {
                             self.handle_request(req, loop_start)
                         }
                         TaskResult::Retry(_) => (),
+                        TaskResult::Workspace(files) => {
+                            info!("Indexed {} workspace files", files.len());
+                            self.add_workspace_files(files);
+                        }
                     }
                 }
             }
@@ -243,12 +529,18 @@ impl GlobalState {
             .on::<NO_RETRY, InlayHintRequest>(handle_inlay_hints)
             .on::<RETRY, DocumentSymbolRequest>(handle_document_symbol)
             .on::<RETRY, FoldingRangeRequest>(handle_folding_range)
+            .on::<RETRY, DocumentLinkRequest>(handle_document_link)
             .on::<NO_RETRY, DocumentDiagnosticRequest>(handle_document_diagnostic)
             .on::<NO_RETRY, SyntaxTreeRequest>(handle_syntax_tree)
             .on::<NO_RETRY, TokensRequest>(handle_tokens)
             .on::<NO_RETRY, ReferencesRequest>(handle_references)
+            .on::<NO_RETRY, PrepareRenameRequest>(handle_prepare_rename)
+            .on::<NO_RETRY, RenameRequest>(handle_rename)
             .on::<NO_RETRY, SemanticTokensRequest>(handle_semantic_tokens_full)
             .on::<NO_RETRY, SemanticTokensRangeRequest>(handle_semantic_tokens_range)
+            .on::<NO_RETRY, DocumentFormattingRequest>(handle_formatting)
+            .on::<NO_RETRY, DocumentRangeFormattingRequest>(handle_range_formatting)
+            .on::<NO_RETRY, DocumentOnTypeFormattingRequest>(handle_on_type_formatting)
             .finish();
     }
 }
@@ -257,6 +549,7 @@ pub(crate) struct Snapshot {
     pub(crate) db: Database,
     pub(crate) files: Arc<FxHashMap<Url, File>>,
     pub(crate) uris: Arc<FxHashMap<File, Url>>,
+    pub(crate) format_options: Arc<FormatOptions>,
 }
 
 impl Snapshot {
@@ -281,4 +574,5 @@ enum Event {
 pub(crate) enum TaskResult {
     Response(Response),
     Retry(Request),
+    Workspace(Vec<(PathBuf, String)>),
 }
@@ -1,13 +1,16 @@
 mod code_action;
 mod completion;
 mod diagnostic;
+mod document_link;
 mod document_symbol;
 mod folding_range;
+mod formatting;
 mod goto_definition;
 mod hover;
 mod inlay_hints;
 mod notifications;
 mod references;
+mod rename;
 mod selection_range;
 mod semantic_tokens;
 mod shutdown;
@@ -17,15 +20,21 @@ mod tokens;
 pub(crate) use code_action::handle_code_action;
 pub(crate) use completion::handle_completion;
 pub(crate) use diagnostic::handle_document_diagnostic;
+pub(crate) use document_link::handle_document_link;
 pub(crate) use document_symbol::handle_document_symbol;
 pub(crate) use folding_range::handle_folding_range;
+pub(crate) use formatting::{
+    handle_formatting, handle_on_type_formatting, handle_range_formatting,
+};
 pub(crate) use goto_definition::handle_goto_definition;
 pub(crate) use hover::handle_hover;
 pub(crate) use inlay_hints::handle_inlay_hints;
 pub(crate) use notifications::{
-    handle_cancel, handle_did_change, handle_did_close, handle_did_open,
+    handle_cancel, handle_did_change, handle_did_change_notebook, handle_did_change_watched_files,
+    handle_did_close, handle_did_close_notebook, handle_did_open, handle_did_open_notebook,
 };
 pub(crate) use references::handle_references;
+pub(crate) use rename::{handle_prepare_rename, handle_rename};
 pub(crate) use selection_range::handle_selection_range;
 pub(crate) use semantic_tokens::{handle_semantic_tokens_full, handle_semantic_tokens_range};
 pub(crate) use shutdown::handle_shutdown;
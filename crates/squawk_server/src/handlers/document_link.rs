@@ -0,0 +1,33 @@
+use anyhow::Result;
+use gen_lsp_types::{DocumentLink, DocumentLinkParams};
+use squawk_ide::db::line_index;
+use squawk_ide::document_links::document_links;
+
+use crate::global_state::Snapshot;
+use crate::lsp_utils;
+
+pub(crate) fn handle_document_link(
+    snapshot: &Snapshot,
+    params: DocumentLinkParams,
+) -> Result<Option<Vec<DocumentLink>>> {
+    let uri = params.text_document.uri;
+
+    let db = snapshot.db();
+    let file = snapshot.file(&uri).unwrap();
+    let line_idx = line_index(db, file);
+
+    let links = document_links(db, file)
+        .into_iter()
+        .filter_map(|link| {
+            let target = lsp_utils::include_target(&uri, &link)?;
+            Some(DocumentLink {
+                range: lsp_utils::range(&line_idx, link.range),
+                target: Some(target),
+                tooltip: None,
+                data: None,
+            })
+        })
+        .collect();
+
+    Ok(Some(links))
+}
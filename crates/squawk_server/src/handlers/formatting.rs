@@ -0,0 +1,169 @@
+use anyhow::Result;
+use gen_lsp_types::{
+    DocumentFormattingParams, DocumentOnTypeFormattingParams, DocumentRangeFormattingParams,
+    TextEdit,
+};
+use log::{info, warn};
+use similar::{DiffTag, TextDiff};
+use squawk_ide::db::{File, line_index, parse};
+use squawk_line_index::{TextRange, TextSize};
+use squawk_syntax::ast::{AstNode, SourceFile};
+use squawk_syntax::edit::Edit;
+use url::Url;
+
+use crate::global_state::Snapshot;
+use crate::lsp_utils;
+
+pub(crate) fn handle_formatting(
+    snapshot: &Snapshot,
+    params: DocumentFormattingParams,
+) -> Result<Option<Vec<TextEdit>>> {
+    let uri = params.text_document.uri;
+    let file = snapshot.file(&uri).unwrap();
+    format(snapshot, &uri, file, |tree| {
+        let formatted = squawk_fmt::fmt_file(tree, &snapshot.format_options)?;
+        Ok(Edit::diff(&tree.syntax().to_string(), &formatted)
+            .into_iter()
+            .collect())
+    })
+}
+
+pub(crate) fn handle_range_formatting(
+    snapshot: &Snapshot,
+    params: DocumentRangeFormattingParams,
+) -> Result<Option<Vec<TextEdit>>> {
+    let uri = params.text_document.uri;
+    let file = snapshot.file(&uri).unwrap();
+    let line_index = line_index(snapshot.db(), file);
+    let Some(range) = lsp_utils::text_range(&line_index, params.range) else {
+        return Ok(None);
+    };
+    format(snapshot, &uri, file, |tree| {
+        squawk_fmt::fmt_file_range(tree, range, &snapshot.format_options)
+    })
+}
+
+pub(crate) fn handle_on_type_formatting(
+    snapshot: &Snapshot,
+    params: DocumentOnTypeFormattingParams,
+) -> Result<Option<Vec<TextEdit>>> {
+    let uri = params.text_document.uri;
+    let file = snapshot.file(&uri).unwrap();
+    let Some(offset) = lsp_utils::offset(snapshot.db(), file, params.position) else {
+        return Ok(None);
+    };
+    // The cursor is right after the `;`, so an empty range there only
+    // overlaps the statement we just finished.
+    let range = TextRange::empty(offset.value);
+    format(snapshot, &uri, file, |tree| {
+        squawk_fmt::fmt_file_range(tree, range, &snapshot.format_options)
+    })
+}
+
+/// Formatting a file with syntax errors would mangle whatever the user is in
+/// the middle of typing, so we leave those alone.
+fn format(
+    snapshot: &Snapshot,
+    uri: &Url,
+    file: File,
+    f: impl FnOnce(&SourceFile) -> Result<Vec<Edit>>,
+) -> Result<Option<Vec<TextEdit>>> {
+    let db = snapshot.db();
+    let parse = parse(db, file);
+    if !parse.errors().is_empty() {
+        info!("Skipping formatting {uri}, the file has syntax errors");
+        return Ok(None);
+    }
+    let edits = match f(&parse.tree()) {
+        Ok(edits) => edits,
+        Err(err) => {
+            warn!("Failed to format {uri}: {err}");
+            return Ok(None);
+        }
+    };
+    let text = file.content(db);
+    let line_index = line_index(db, file);
+    Ok(Some(
+        edits
+            .into_iter()
+            .flat_map(|edit| split_edit(text, edit))
+            .map(|edit| lsp_utils::text_edit(&line_index, edit))
+            .collect(),
+    ))
+}
+
+/// Formatting a statement usually only touches a few of its lines, so we send
+/// an edit per changed hunk instead of replacing the whole thing. That way the
+/// client keeps its cursor, folds, and breakpoints on the lines in between.
+fn split_edit(text: &str, edit: Edit) -> Vec<Edit> {
+    let start = edit.text_range.start();
+    let old = &text[edit.text_range];
+    let new = edit.text.as_deref().unwrap_or_default();
+    let diff = TextDiff::from_lines(old, new);
+    let old_offsets = line_offsets(diff.old_slices());
+    let new_offsets = line_offsets(diff.new_slices());
+    diff.ops()
+        .iter()
+        .filter_map(|op| {
+            let (tag, old_lines, new_lines) = op.as_tag_tuple();
+            if tag == DiffTag::Equal {
+                return None;
+            }
+            let old_range = old_offsets[old_lines.start]..old_offsets[old_lines.end];
+            let new_range = new_offsets[new_lines.start]..new_offsets[new_lines.end];
+            let mut edit = Edit::diff(&old[old_range.clone()], &new[new_range])?;
+            edit.text_range += start + TextSize::new(old_range.start as u32);
+            Some(edit)
+        })
+        .collect()
+}
+
+/// Byte offset of the start of each line, plus the end of the text.
+fn line_offsets(lines: &[&str]) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(lines.iter().scan(0, |offset, line| {
+            *offset += line.len();
+            Some(*offset)
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+
+    fn check(text: &str, formatted: &str) -> String {
+        let edit = Edit::diff(text, formatted).unwrap();
+        let edits = split_edit(text, edit);
+        let mut applied = text.to_string();
+        for edit in edits.iter().rev() {
+            edit.apply(&mut applied);
+        }
+        assert_eq!(applied, formatted);
+        edits
+            .iter()
+            .map(|edit| format!("{:?} {:?}", edit.text_range, edit.text))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn split_edit_keeps_unchanged_lines() {
+        assert_snapshot!(check(
+            "SELECT  1;\nselect 2;\nselect   3;\n",
+            "select 1;\nselect 2;\nselect 3;\n"
+        ), @r#"
+        0..7 Some("select")
+        27..29 None
+        "#);
+    }
+
+    #[test]
+    fn split_edit_added_lines() {
+        assert_snapshot!(check(
+            "select a, b from t;\nselect 2;\n",
+            "select\n  a,\n  b\nfrom t;\nselect 2;\n"
+        ), @r#"6..12 Some("\n  a,\n  b\n")"#);
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
-use gen_lsp_types::{Definition, DefinitionParams, DefinitionResponse};
+use gen_lsp_types::{Definition, DefinitionParams, DefinitionResponse, Location, Range};
+use squawk_ide::document_links::document_links;
 use squawk_ide::goto_definition::goto_definition;
 
 use crate::global_state::Snapshot;
@@ -16,11 +17,25 @@ pub(crate) fn handle_goto_definition(
     let file = snapshot.file(&uri).unwrap();
     let position = lsp_utils::offset(db, file, position).unwrap();
 
+    // follow psql includes like `\i setup.sql` to the start of the file
+    if let Some(target) = document_links(db, file)
+        .into_iter()
+        .find(|link| link.range.contains_inclusive(position.value))
+        .and_then(|link| lsp_utils::include_target(&uri, &link))
+    {
+        return Ok(Some(DefinitionResponse::Definition(Definition::Location(
+            Location {
+                uri: target,
+                range: Range::default(),
+            },
+        ))));
+    }
+
     let ranges = goto_definition(db, position)
         .into_iter()
         .filter_map(|location| {
             debug_assert!(
-                !location.range.contains(position.value),
+                location.file != file || !location.range.contains(position.value),
                 "Our target destination range must not include the source range otherwise go to def won't work in vscode."
             );
             to_location(snapshot, location)
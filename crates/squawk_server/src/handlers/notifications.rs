@@ -1,12 +1,15 @@
 use anyhow::Result;
 use gen_lsp_types::{
-    CancelParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-    DidOpenTextDocumentParams, Id, Notification as _, PublishDiagnosticsNotification,
-    PublishDiagnosticsParams,
+    CancelParams, DidChangeNotebookDocumentParams, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesParams, DidCloseNotebookDocumentParams, DidCloseTextDocumentParams,
+    DidOpenNotebookDocumentParams, DidOpenTextDocumentParams, FileChangeType, Id,
+    Notification as _, PublishDiagnosticsNotification, PublishDiagnosticsParams,
 };
+use log::warn;
 use lsp_server::{Message, Notification};
+use url::Url;
 
-use crate::global_state::GlobalState;
+use crate::global_state::{CONFIG_FILE_NAME, GlobalState};
 use crate::lsp_utils;
 
 pub(crate) fn handle_cancel(state: &mut GlobalState, params: CancelParams) -> Result<()> {
@@ -42,7 +45,7 @@ pub(crate) fn handle_did_change(
 
     let updated_content = lsp_utils::apply_incremental_changes(content, params.content_changes);
 
-    state.set(uri, updated_content);
+    state.change(uri, updated_content);
 
     Ok(())
 }
@@ -55,6 +58,10 @@ pub(crate) fn handle_did_close(
 
     state.remove(&uri);
 
+    clear_diagnostics(state, uri)
+}
+
+fn clear_diagnostics(state: &GlobalState, uri: Url) -> Result<()> {
     let publish_params = PublishDiagnosticsParams {
         uri,
         diagnostics: vec![],
@@ -70,3 +77,113 @@ pub(crate) fn handle_did_close(
 
     Ok(())
 }
+
+pub(crate) fn handle_did_open_notebook(
+    state: &mut GlobalState,
+    params: DidOpenNotebookDocumentParams,
+) -> Result<()> {
+    for cell in params.cell_text_documents {
+        state.set(cell.uri, cell.text);
+    }
+
+    let cells = params
+        .notebook_document
+        .cells
+        .into_iter()
+        .map(|cell| cell.document)
+        .collect();
+    state.set_notebook(params.notebook_document.uri, cells);
+
+    Ok(())
+}
+
+pub(crate) fn handle_did_change_notebook(
+    state: &mut GlobalState,
+    params: DidChangeNotebookDocumentParams,
+) -> Result<()> {
+    let uri = params.notebook_document.uri;
+    let Some(changes) = params.change.cells else {
+        return Ok(());
+    };
+
+    if let Some(structure) = changes.structure {
+        for cell in structure.did_open.unwrap_or_default() {
+            state.set(cell.uri, cell.text);
+        }
+        for cell in structure.did_close.unwrap_or_default() {
+            state.remove(&cell.uri);
+            clear_diagnostics(state, cell.uri)?;
+        }
+
+        let mut cells = state.notebook_cells(&uri).unwrap_or_default().to_vec();
+        let start = (structure.array.start as usize).min(cells.len());
+        let end = (start + structure.array.delete_count as usize).min(cells.len());
+        let inserted = structure.array.cells.unwrap_or_default();
+        cells.splice(start..end, inserted.into_iter().map(|cell| cell.document));
+        state.set_notebook(uri, cells);
+    }
+
+    for cell in changes.text_content.unwrap_or_default() {
+        let cell_uri = cell.document.text_document_identifier.uri;
+        let Some(file) = state.file(&cell_uri) else {
+            continue;
+        };
+        let content = file.content(state.db());
+        let updated_content = lsp_utils::apply_incremental_changes(content, cell.changes);
+        state.change(cell_uri, updated_content);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn handle_did_close_notebook(
+    state: &mut GlobalState,
+    params: DidCloseNotebookDocumentParams,
+) -> Result<()> {
+    state.remove_notebook(&params.notebook_document.uri);
+
+    for cell in params.cell_text_documents {
+        state.remove(&cell.uri);
+        clear_diagnostics(state, cell.uri)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn handle_did_change_watched_files(
+    state: &mut GlobalState,
+    params: DidChangeWatchedFilesParams,
+) -> Result<()> {
+    let mut config_changed = false;
+    for change in params.changes {
+        let Ok(path) = change.uri.to_file_path() else {
+            continue;
+        };
+        if path
+            .file_name()
+            .is_some_and(|name| name == CONFIG_FILE_NAME)
+        {
+            config_changed = true;
+            continue;
+        }
+        match change.kind {
+            FileChangeType::Created | FileChangeType::Changed => {
+                if !state.in_workspace(&path) {
+                    continue;
+                }
+                match std::fs::read_to_string(&path) {
+                    Ok(content) => state.add_workspace_files(vec![(path, content)]),
+                    Err(err) => warn!("Failed to read {}: {err}", path.display()),
+                }
+            }
+            // We only hear about the directory when one is deleted.
+            FileChangeType::Deleted => state.remove_workspace_files(&path),
+            FileChangeType::Custom(_) => (),
+        }
+    }
+    if config_changed {
+        state.reload_config();
+    }
+
+    Ok(())
+}
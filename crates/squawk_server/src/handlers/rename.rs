@@ -0,0 +1,68 @@
+use anyhow::Result;
+use gen_lsp_types::{PrepareRenameParams, PrepareRenameResult, RenameParams, WorkspaceEdit};
+use lsp_server::ErrorCode;
+use squawk_ide::db::line_index;
+use squawk_ide::rename::{RenameError, prepare_rename, rename};
+
+use crate::global_state::Snapshot;
+use crate::lsp_utils::{self, LspError};
+
+pub(crate) fn handle_prepare_rename(
+    snapshot: &Snapshot,
+    params: PrepareRenameParams,
+) -> Result<Option<PrepareRenameResult>> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+
+    let db = snapshot.db();
+    let file = snapshot.file(&uri).unwrap();
+    let Some(position) = lsp_utils::offset(db, file, position) else {
+        return Ok(None);
+    };
+
+    let range = prepare_rename(db, position).map_err(rename_error)?;
+    let line_index = line_index(db, file);
+    Ok(Some(PrepareRenameResult::Range(lsp_utils::range(
+        &line_index,
+        range,
+    ))))
+}
+
+pub(crate) fn handle_rename(
+    snapshot: &Snapshot,
+    params: RenameParams,
+) -> Result<Option<WorkspaceEdit>> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+
+    let db = snapshot.db();
+    let file = snapshot.file(&uri).unwrap();
+    let Some(position) = lsp_utils::offset(db, file, position) else {
+        return Ok(None);
+    };
+
+    let changes = rename(db, position, &params.new_name).map_err(rename_error)?;
+    let changes = changes
+        .into_iter()
+        .filter_map(|change| {
+            let uri = snapshot.uri(change.file)?;
+            let line_index = line_index(db, change.file);
+            let edits = change
+                .edits
+                .into_iter()
+                .map(|edit| lsp_utils::text_edit(&line_index, edit))
+                .collect();
+            Some((uri, edits))
+        })
+        .collect();
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    }))
+}
+
+/// The client shows these to the user, e.g., why a builtin can't be renamed.
+fn rename_error(err: RenameError) -> LspError {
+    LspError::new(ErrorCode::InvalidParams as i32, err.to_string())
+}
@@ -8,5 +8,6 @@ mod lsp_utils;
 mod panic;
 mod semantic_tokens;
 mod server;
+mod workspace;
 
-pub use server::run;
+pub use server::{LoadConfig, ServerConfig, run};
@@ -2,7 +2,7 @@ use gen_lsp_types::{
     Code, CodeDescription, Diagnostic, DiagnosticSeverity, Message, Position, Range, TextEdit,
 };
 use salsa::Database as Db;
-use squawk_ide::db::{File, line_index as file_line_index, parse};
+use squawk_ide::db::{File, dialect, line_index as file_line_index, parse, syntax_errors};
 use squawk_line_index::{LineIndex, find_newline};
 use squawk_linter::{Edit, Linter};
 use url::Url;
@@ -26,8 +26,9 @@ fn to_text_edit(edit: Edit, line_index: &LineIndex) -> Option<TextEdit> {
 pub(crate) fn lint(db: &dyn Db, file: File) -> Vec<Diagnostic> {
     let parse = parse(db, file);
     let content = file.content(db);
-    let parse_errors = parse.errors();
+    let parse_errors = syntax_errors(db, file);
     let mut linter = Linter::with_default_rules();
+    linter.settings.dialect = dialect(db);
     let violations = linter.lint(&parse, content);
     let line_index = file_line_index(db, file);
     let line_ending = find_newline(content)
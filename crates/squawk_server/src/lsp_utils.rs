@@ -1,24 +1,53 @@
+use std::fmt;
 use std::ops::Range;
 
 use rustc_hash::FxHashMap;
 
 use gen_lsp_types::{
     CodeAction, CodeActionKind, FoldingRange, FoldingRangeKind as LspFoldingRangeKind, Location,
-    SemanticToken, TextDocumentContentChangeEvent, WorkspaceEdit,
+    SemanticToken, TextDocumentContentChangeEvent, TextEdit, WorkspaceEdit,
 };
 use log::warn;
 use salsa::Database as Db;
 use squawk_ide::code_actions::ActionKind;
 use squawk_ide::db::{File, line_index};
+use squawk_ide::document_links::DocumentLink;
 use squawk_ide::file::InFile;
 use squawk_ide::folding_ranges::{Fold, FoldKind};
 use squawk_ide::semantic_tokens::{SemanticTokenModifier, SemanticTokenType};
 use squawk_line_index::{LineIndex, TextRange, TextSize, find_newline};
+use squawk_syntax::edit::Edit;
 use url::Url;
 
 use crate::global_state::Snapshot;
 use crate::semantic_tokens;
 
+/// An error with a message for the user, instead of the internal errors we
+/// return for everything else.
+#[derive(Debug)]
+pub(crate) struct LspError {
+    pub(crate) code: i32,
+    pub(crate) message: String,
+}
+
+impl LspError {
+    pub(crate) fn new(code: i32, message: String) -> LspError {
+        LspError { code, message }
+    }
+}
+
+impl fmt::Display for LspError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Language Server request failed with {}. ({})",
+            self.code, self.message
+        )
+    }
+}
+
+impl std::error::Error for LspError {}
+
 pub(crate) fn text_range(index: &LineIndex, range: gen_lsp_types::Range) -> Option<TextRange> {
     let start = text_size(index, range.start)?;
     let end = text_size(index, range.end)?;
@@ -81,10 +110,7 @@ pub(crate) fn code_action(
                 let edits = action
                     .edits
                     .into_iter()
-                    .map(|edit| gen_lsp_types::TextEdit {
-                        range: range(line_index, edit.text_range),
-                        new_text: edit.text.unwrap_or_default(),
-                    })
+                    .map(|edit| text_edit(line_index, edit))
                     .collect();
                 changes.insert(uri, edits);
                 changes.into_iter().collect()
@@ -110,6 +136,7 @@ pub(crate) fn completion_item(
         CompletionItemKind::Type => gen_lsp_types::CompletionItemKind::Class,
         CompletionItemKind::Snippet => gen_lsp_types::CompletionItemKind::Snippet,
         CompletionItemKind::Operator => gen_lsp_types::CompletionItemKind::Operator,
+        CompletionItemKind::Variable => gen_lsp_types::CompletionItemKind::Variable,
     };
 
     let sort_text = Some(item.sort_text());
@@ -165,6 +192,13 @@ pub(crate) fn range(line_index: &LineIndex, range: TextRange) -> gen_lsp_types::
     )
 }
 
+pub(crate) fn text_edit(line_index: &LineIndex, edit: Edit) -> TextEdit {
+    TextEdit {
+        range: range(line_index, edit.text_range),
+        new_text: edit.text.unwrap_or_default(),
+    }
+}
+
 pub(crate) fn folding_range(line_index: &LineIndex, fold: Fold) -> FoldingRange {
     let start = line_index.line_col(fold.range.start());
     let end = line_index.line_col(fold.range.end());
@@ -250,6 +284,17 @@ pub(crate) fn to_location(
     Some(Location { uri, range })
 }
 
+/// Resolve the path of a psql include against the including document.
+///
+/// We don't know the directory psql will run from, so `\i` paths are
+/// resolved like `\ir` paths.
+pub(crate) fn include_target(uri: &Url, link: &DocumentLink) -> Option<Url> {
+    if uri.scheme() != "file" {
+        return None;
+    }
+    uri.join(&link.target).ok()
+}
+
 pub(crate) fn to_semantic_tokens(
     text: &str,
     line_index: LineIndex,
@@ -352,6 +397,7 @@ mod tests {
         Position, Range, TextDocumentContentChangePartial, TextDocumentContentChangeWholeDocument,
     };
     use insta::assert_snapshot;
+    use squawk_ide::document_links::IncludeKind;
 
     fn partial_change(range: Range, text: &str) -> TextDocumentContentChangeEvent {
         TextDocumentContentChangeEvent::TextDocumentContentChangePartial(
@@ -526,4 +572,31 @@ mod tests {
     fn apply_incremental_changes_cr_line_endings() {
         assert_snapshot!(edit_third_line("\r"), @"line 1<CR>line 2<CR>replaced");
     }
+
+    #[test]
+    fn include_target_paths() {
+        let uri = Url::parse("file:///project/migrations/001.sql").unwrap();
+        let target = |target: &str| {
+            let link = DocumentLink {
+                range: TextRange::default(),
+                target: target.to_owned(),
+                kind: IncludeKind::Relative,
+            };
+            include_target(&uri, &link).map(|url| url.to_string())
+        };
+        assert_snapshot!(format!("{:?}", [
+            target("setup.sql"),
+            target("../seed/users.sql"),
+            target("/abs/path.sql"),
+            target("with space.sql"),
+        ]), @r#"[Some("file:///project/migrations/setup.sql"), Some("file:///project/seed/users.sql"), Some("file:///abs/path.sql"), Some("file:///project/migrations/with%20space.sql")]"#);
+
+        let cell = Url::parse("vscode-notebook-cell:/project/notebook.ipynb#W0sZmlsZQ").unwrap();
+        let link = DocumentLink {
+            range: TextRange::default(),
+            target: "setup.sql".to_owned(),
+            kind: IncludeKind::Relative,
+        };
+        assert_eq!(include_target(&cell, &link), None);
+    }
 }
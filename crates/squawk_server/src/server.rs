@@ -1,27 +1,69 @@
+use std::path::{Path, PathBuf};
+
 use anyhow::Result;
 use gen_lsp_types::{
     CodeActionKind, CodeActionOptions, CodeActionProvider, CompletionOptions, DefinitionProvider,
-    DiagnosticOptions, DiagnosticProvider, DocumentSymbolProvider, FoldingRangeProvider, Full,
-    HoverProvider, InitializeParams, InlayHintProvider, ReferencesProvider, SelectionRangeProvider,
-    SemanticTokensLegend, SemanticTokensOptions, SemanticTokensOptionsRange,
-    SemanticTokensProvider, ServerCapabilities, TextDocumentSync, TextDocumentSyncKind,
-    WorkDoneProgressOptions,
+    DiagnosticOptions, DiagnosticProvider, DocumentFormattingProvider, DocumentLinkOptions,
+    DocumentOnTypeFormattingOptions, DocumentRangeFormattingProvider, DocumentSymbolProvider,
+    FoldingRangeProvider, Full, HoverProvider, InitializeParams, InlayHintProvider,
+    NotebookCellLanguage, NotebookDocumentFilterWithCells, NotebookDocumentSync,
+    NotebookDocumentSyncOptions, NotebookSelector, ReferencesProvider, RenameOptions,
+    RenameProvider, SelectionRangeProvider, SemanticTokensLegend, SemanticTokensOptions,
+    SemanticTokensOptionsRange, SemanticTokensProvider, ServerCapabilities, TextDocumentSync,
+    TextDocumentSyncKind, WorkDoneProgressOptions, WorkspaceFolders,
 };
 use log::info;
 use lsp_server::Connection;
+use serde::{Deserialize, Deserializer};
+use squawk_fmt::FormatOptions;
+use squawk_linter::{Dialect, Version};
 
 use crate::{
     global_state::GlobalState,
     semantic_tokens::{SUPPORTED_MODIFIERS, SUPPORTED_TYPES},
 };
 
-pub fn run() -> Result<()> {
+/// The settings the server reads from the config file.
+#[derive(Debug, Default)]
+pub struct ServerConfig {
+    pub format_options: FormatOptions,
+    /// Paths of the workspace we don't index.
+    pub excluded_paths: Vec<String>,
+    /// The client can override this with its initialization options.
+    pub dialect: Dialect,
+}
+
+/// Reads the config file for the workspace folder, or for the current
+/// directory when the client didn't open a folder.
+pub type LoadConfig = Box<dyn Fn(Option<&Path>) -> Result<ServerConfig> + Send>;
+
+/// Runs the server over stdio, indexing the `.sql` files in the workspace.
+///
+/// The config is loaded once the client tells us the workspace folder, and
+/// again whenever its `.squawk.toml` changes.
+pub fn run(load_config: LoadConfig) -> Result<()> {
     info!("Starting Squawk LSP server");
 
     let (connection, io_threads) = Connection::stdio();
 
     let server_capabilities = serde_json::to_value(&ServerCapabilities {
         text_document_sync: Some(TextDocumentSync::Kind(TextDocumentSyncKind::Incremental)),
+        // Sync the SQL cells of any notebook, each cell is its own text
+        // document.
+        notebook_document_sync: Some(NotebookDocumentSync::Options(NotebookDocumentSyncOptions {
+            notebook_selector: vec![NotebookSelector::NotebookDocumentFilterWithCells(
+                NotebookDocumentFilterWithCells {
+                    notebook: None,
+                    cells: ["sql", "postgres"]
+                        .into_iter()
+                        .map(|language| NotebookCellLanguage {
+                            language: language.to_owned(),
+                        })
+                        .collect(),
+                },
+            )],
+            save: None,
+        })),
         code_action_provider: Some(CodeActionProvider::CodeActionOptions(CodeActionOptions {
             code_action_kinds: Some(vec![
                 CodeActionKind::QuickFix,
@@ -35,6 +77,12 @@ pub fn run() -> Result<()> {
         })),
         selection_range_provider: Some(SelectionRangeProvider::Bool(true)),
         references_provider: Some(ReferencesProvider::Bool(true)),
+        rename_provider: Some(RenameProvider::RenameOptions(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        })),
         definition_provider: Some(DefinitionProvider::Bool(true)),
         hover_provider: Some(HoverProvider::Bool(true)),
         inlay_hint_provider: Some(InlayHintProvider::Bool(true)),
@@ -47,7 +95,20 @@ pub fn run() -> Result<()> {
             },
         })),
         document_symbol_provider: Some(DocumentSymbolProvider::Bool(true)),
+        document_formatting_provider: Some(DocumentFormattingProvider::Bool(true)),
+        document_range_formatting_provider: Some(DocumentRangeFormattingProvider::Bool(true)),
+        // Format the statement we just finished typing.
+        document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+            first_trigger_character: ";".to_owned(),
+            more_trigger_character: None,
+        }),
         folding_range_provider: Some(FoldingRangeProvider::Bool(true)),
+        document_link_provider: Some(DocumentLinkOptions {
+            resolve_provider: Some(false),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        }),
         completion_provider: Some(CompletionOptions {
             resolve_provider: Some(false),
             trigger_characters: Some(vec![".".to_owned()]),
@@ -82,7 +143,7 @@ pub fn run() -> Result<()> {
     let initialization_params = connection.initialize(server_capabilities)?;
     info!("LSP server initialized, entering main loop");
 
-    main_loop(connection, initialization_params)?;
+    main_loop(connection, initialization_params, load_config)?;
 
     info!("LSP server shutting down");
 
@@ -90,13 +151,75 @@ pub fn run() -> Result<()> {
     Ok(())
 }
 
-fn main_loop(connection: Connection, params: serde_json::Value) -> Result<()> {
+fn main_loop(
+    connection: Connection,
+    params: serde_json::Value,
+    load_config: LoadConfig,
+) -> Result<()> {
     info!("Server main loop");
 
     let init_params: InitializeParams = serde_json::from_value(params).unwrap_or_default();
+    let roots = workspace_roots(&init_params);
+    info!("Workspace roots: {roots:?}");
     info!("Client process ID: {:?}", init_params.process_id);
     let client_name = init_params.client_info.map(|x| x.name);
     info!("Client name: {client_name:?}");
+    let options: InitializationOptions = init_params
+        .initialization_options
+        .and_then(|options| serde_json::from_value(options).ok())
+        .unwrap_or_default();
+    info!("Initialization options: {options:?}");
+
+    let watch_files = init_params
+        .capabilities
+        .workspace
+        .and_then(|workspace| workspace.did_change_watched_files)
+        .and_then(|capabilities| capabilities.dynamic_registration)
+        .unwrap_or(false);
+
+    GlobalState::new(
+        connection.sender,
+        options.pg_version.map(|v| v.major()),
+        options.dialect,
+        roots,
+        load_config,
+    )
+    .run(connection.receiver, watch_files)
+}
+
+/// The workspace folders, falling back to the deprecated `rootUri` for older
+/// clients.
+#[allow(deprecated)]
+fn workspace_roots(params: &InitializeParams) -> Vec<PathBuf> {
+    match &params.workspace_folders_initialize_params.workspace_folders {
+        Some(WorkspaceFolders::WorkspaceFolderList(folders)) => folders
+            .iter()
+            .filter_map(|folder| folder.uri.to_file_path().ok())
+            .collect(),
+        _ => params
+            .root_uri
+            .iter()
+            .filter_map(|uri| uri.to_file_path().ok())
+            .collect(),
+    }
+}
+
+/// Settings the client sends when it starts the server, e.g.
+/// `{"pgVersion": "14"}`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InitializationOptions {
+    /// Syntax newer than this version is reported as an error.
+    pg_version: Option<Version>,
+    /// e.g., `"aurora-dsql"`, overrides the config file's `dialect`.
+    #[serde(default, deserialize_with = "deserialize_dialect")]
+    dialect: Option<Dialect>,
+}
 
-    GlobalState::new(connection.sender).run(connection.receiver)
+fn deserialize_dialect<'de, D>(deserializer: D) -> Result<Option<Dialect>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map(Some).map_err(serde::de::Error::custom)
 }
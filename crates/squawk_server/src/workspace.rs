@@ -0,0 +1,316 @@
+use std::cmp::Ordering;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use log::{info, warn};
+
+/// Build output and dependency directories, which can be huge and won't have
+/// the project's migrations.
+const SKIPPED_DIRS: &[&str] = &[
+    "node_modules",
+    "target",
+    "vendor",
+    "venv",
+    "__pycache__",
+    "dist",
+    "build",
+];
+
+fn is_skipped_dir(name: &str) -> bool {
+    name.starts_with('.') || SKIPPED_DIRS.contains(&name)
+}
+
+/// The workspace folders from the client, along with the `excluded_paths`
+/// from the config file, to find the migrations we index.
+#[derive(Debug, Default)]
+pub(crate) struct WorkspaceRoots {
+    roots: Vec<PathBuf>,
+    excluded_paths: Vec<glob::Pattern>,
+}
+
+impl WorkspaceRoots {
+    pub(crate) fn new(roots: Vec<PathBuf>, excluded_paths: &[String]) -> Self {
+        let excluded_paths = excluded_paths
+            .iter()
+            .filter_map(|pattern| match glob::Pattern::new(pattern) {
+                Ok(pattern) => Some(pattern),
+                Err(err) => {
+                    warn!("Invalid excluded path pattern `{pattern}`: {err}");
+                    None
+                }
+            })
+            .collect();
+        Self {
+            roots,
+            excluded_paths,
+        }
+    }
+
+    pub(crate) fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Whether the file at `path` is a `.sql` file under one of the roots that
+    /// isn't excluded, hidden, e.g., in `.git`, or in a dependency directory,
+    /// e.g., `node_modules`.
+    pub(crate) fn contains(&self, path: &Path) -> bool {
+        if path.extension().is_none_or(|ext| ext != "sql") {
+            return false;
+        }
+        let Some(relative) = self
+            .roots
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())
+        else {
+            return false;
+        };
+        let mut dirs = relative.parent().into_iter().flat_map(Path::components);
+        let skipped = dirs.any(|component| {
+            matches!(component, Component::Normal(name) if is_skipped_dir(&name.to_string_lossy()))
+        });
+        let hidden = relative
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if skipped || hidden {
+            return false;
+        }
+        // Like the CLI, patterns are relative to where squawk runs, which for
+        // the server is the workspace folder.
+        if let Some(pattern) = self
+            .excluded_paths
+            .iter()
+            .find(|pattern| pattern.matches_path(relative) || pattern.matches_path(path))
+        {
+            info!(
+                "skipping excluded file path: {}. pattern: {}",
+                path.display(),
+                pattern
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Reads every file in the workspace, sorted by path so they're in
+    /// migration order.
+    pub(crate) fn scan(&self) -> Vec<(PathBuf, String)> {
+        let mut paths = vec![];
+        for root in &self.roots {
+            self.walk(root, &mut paths);
+        }
+        paths.sort_by(|a, b| natural_cmp(a, b));
+        paths.dedup();
+        paths
+            .into_iter()
+            .filter_map(|path| match fs::read_to_string(&path) {
+                Ok(content) => Some((path, content)),
+                Err(err) => {
+                    warn!("Failed to read {}: {err}", path.display());
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the `.sql` files under `dir` without going into the directories
+    /// we skip, so we don't list everything in `node_modules` just to ignore
+    /// it. Symlinked directories aren't followed to avoid cycles.
+    fn walk(&self, dir: &Path, paths: &mut Vec<PathBuf>) {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) => {
+                warn!("Failed to read {}: {err}", dir.display());
+                return;
+            }
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                if !is_skipped_dir(&entry.file_name().to_string_lossy()) {
+                    self.walk(&path, paths);
+                }
+            } else if path.is_file() && self.contains(&path) {
+                paths.push(path);
+            }
+        }
+    }
+}
+
+/// Compares paths with the numbers in them compared by value, so
+/// `V9__init.sql` comes before `V10__add_index.sql`.
+fn natural_cmp(a: &Path, b: &Path) -> Ordering {
+    let mut a = a.components();
+    let mut b = b.components();
+    loop {
+        return match (a.next(), b.next()) {
+            (Some(x), Some(y)) => match natural_cmp_str(
+                &x.as_os_str().to_string_lossy(),
+                &y.as_os_str().to_string_lossy(),
+            ) {
+                Ordering::Equal => continue,
+                ordering => ordering,
+            },
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+    }
+}
+
+fn natural_cmp_str(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        let ordering = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_number(&mut a);
+                let y = take_number(&mut b);
+                let (x_trimmed, y_trimmed) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                x_trimmed
+                    .len()
+                    .cmp(&y_trimmed.len())
+                    .then_with(|| x_trimmed.cmp(y_trimmed))
+                    // `01` and `1` are the same number, keep the order stable
+                    .then_with(|| x.len().cmp(&y.len()))
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.cmp(y);
+                a.next();
+                b.next();
+                ordering
+            }
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (None, None) => return Ordering::Equal,
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut number = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        number.push(c);
+    }
+    number
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::{WorkspaceRoots, natural_cmp};
+
+    #[test]
+    fn scan_in_migration_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("migrations")).unwrap();
+        fs::create_dir_all(root.join("old")).unwrap();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        fs::write(
+            root.join("migrations/0042_add_index.sql"),
+            "create index on t (a);",
+        )
+        .unwrap();
+        fs::write(
+            root.join("migrations/0001_init.sql"),
+            "create table t (a int);",
+        )
+        .unwrap();
+        fs::write(root.join("migrations/notes.md"), "").unwrap();
+        fs::write(root.join("old/0000_legacy.sql"), "").unwrap();
+        fs::write(root.join(".git/hooks.sql"), "").unwrap();
+        fs::write(root.join("node_modules/pkg/0000_schema.sql"), "").unwrap();
+
+        let workspace = WorkspaceRoots::new(vec![root.to_path_buf()], &["old/*".to_owned()]);
+        let found: Vec<_> = workspace
+            .scan()
+            .into_iter()
+            .map(|(path, content)| (path.strip_prefix(root).unwrap().to_owned(), content))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "migrations/0001_init.sql".into(),
+                    "create table t (a int);".to_owned()
+                ),
+                (
+                    "migrations/0042_add_index.sql".into(),
+                    "create index on t (a);".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_in_numeric_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        for name in ["V10__add_index.sql", "V9__add_column.sql", "V1__init.sql"] {
+            fs::write(root.join(name), "").unwrap();
+        }
+
+        let workspace = WorkspaceRoots::new(vec![root.to_path_buf()], &[]);
+        let found: Vec<_> = workspace
+            .scan()
+            .into_iter()
+            .map(|(path, _)| path.strip_prefix(root).unwrap().to_owned())
+            .collect();
+        assert_eq!(
+            found,
+            ["V1__init.sql", "V9__add_column.sql", "V10__add_index.sql"].map(PathBuf::from)
+        );
+    }
+
+    #[test]
+    fn natural_order() {
+        let mut paths = vec![
+            "b/2.sql",
+            "a/10.sql",
+            "a/9.sql",
+            "a/09b.sql",
+            "a/9a.sql",
+            "a-b/1.sql",
+            "a/0010.sql",
+        ]
+        .into_iter()
+        .map(PathBuf::from)
+        .collect::<Vec<_>>();
+        paths.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            paths,
+            [
+                "a/9.sql",
+                "a/9a.sql",
+                "a/09b.sql",
+                "a/10.sql",
+                "a/0010.sql",
+                "a-b/1.sql",
+                "b/2.sql",
+            ]
+            .map(PathBuf::from)
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let workspace =
+            WorkspaceRoots::new(vec!["/project".into()], &["vendor/**/*.sql".to_owned()]);
+        assert!(workspace.contains("/project/migrations/0001_init.sql".as_ref()));
+        assert!(!workspace.contains("/project/migrations/README.md".as_ref()));
+        assert!(!workspace.contains("/project/vendor/pg/0001_init.sql".as_ref()));
+        assert!(!workspace.contains("/project/.venv/0001_init.sql".as_ref()));
+        assert!(!workspace.contains("/project/node_modules/pkg/0001_init.sql".as_ref()));
+        assert!(!workspace.contains("/project/target/0001_init.sql".as_ref()));
+        assert!(!workspace.contains("/elsewhere/0001_init.sql".as_ref()));
+    }
+}
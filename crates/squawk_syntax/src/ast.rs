@@ -25,6 +25,7 @@
 // DEALINGS IN THE SOFTWARE.
 
 mod generated;
+pub mod make;
 mod node_ext;
 mod nodes;
 mod support;
@@ -39,7 +40,7 @@ use squawk_parser::SyntaxKind;
 pub use self::node_ext::normalize_name_node;
 pub use self::{
     generated::tokens::*,
-    node_ext::{BinOp, LitKind, PostfixOp},
+    node_ext::{BinOp, LitKind, PostfixOp, PrefixOp},
     nodes::*,
     traits::{HasCreateTable, HasWithClause, NameLike},
 };
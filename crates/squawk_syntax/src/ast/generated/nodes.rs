@@ -4822,6 +4822,17 @@ impl CopyStdin {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CopyStdinData {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CopyStdinData {
+    #[inline]
+    pub fn copy_data_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COPY_DATA)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CopyStdout {
     pub(crate) syntax: SyntaxNode,
@@ -5435,6 +5446,10 @@ impl CreateIndex {
         support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
+    pub fn async_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ASYNC_KW)
+    }
+    #[inline]
     pub fn concurrently_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
     }
@@ -19276,6 +19291,10 @@ impl PercentTypeClause {
         support::token(&self.syntax, SyntaxKind::PERCENT)
     }
     #[inline]
+    pub fn rowtype_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROWTYPE_KW)
+    }
+    #[inline]
     pub fn type_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::TYPE_KW)
     }
@@ -19297,248 +19316,378 @@ impl PeriodColumn {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Policy {
+pub struct PlpgsqlAliasDecl {
     pub(crate) syntax: SyntaxNode,
 }
-impl Policy {
+impl PlpgsqlAliasDecl {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyCommand {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PolicyCommand {
     #[inline]
-    pub fn command(&self) -> Option<PolicyCommandKind> {
+    pub fn plpgsql_variable(&self) -> Option<PlpgsqlVariable> {
         support::child(&self.syntax)
     }
     #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn alias_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALIAS_KW)
+    }
+    #[inline]
     pub fn for_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::FOR_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyCommandAll {
+pub struct PlpgsqlAssert {
     pub(crate) syntax: SyntaxNode,
 }
-impl PolicyCommandAll {
+impl PlpgsqlAssert {
     #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyCommandDelete {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PolicyCommandDelete {
     #[inline]
-    pub fn delete_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DELETE_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn assert_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ASSERT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyCommandInsert {
+pub struct PlpgsqlAssign {
     pub(crate) syntax: SyntaxNode,
 }
-impl PolicyCommandInsert {
+impl PlpgsqlAssign {
     #[inline]
-    pub fn insert_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INSERT_KW)
+    pub fn from_clause(&self) -> Option<FromClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn group_by_clause(&self) -> Option<GroupByClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn having_clause(&self) -> Option<HavingClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn limit_clause(&self) -> Option<LimitClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn offset_clause(&self) -> Option<OffsetClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn target_list(&self) -> Option<TargetList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn window_clause(&self) -> Option<WindowClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EQ)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyCommandSelect {
+pub struct PlpgsqlBlock {
     pub(crate) syntax: SyntaxNode,
 }
-impl PolicyCommandSelect {
+impl PlpgsqlBlock {
     #[inline]
-    pub fn select_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SELECT_KW)
+    pub fn name_ref(&self) -> Option<NameRef> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_declare_section(&self) -> Option<PlpgsqlDeclareSection> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_exception_section(&self) -> Option<PlpgsqlExceptionSection> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_label(&self) -> Option<PlpgsqlLabel> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn begin_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::BEGIN_KW)
+    }
+    #[inline]
+    pub fn end_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::END_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyCommandUpdate {
+pub struct PlpgsqlCase {
     pub(crate) syntax: SyntaxNode,
 }
-impl PolicyCommandUpdate {
+impl PlpgsqlCase {
     #[inline]
-    pub fn update_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_case_whens(&self) -> AstChildren<PlpgsqlCaseWhen> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_else(&self) -> Option<PlpgsqlElse> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn case_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CASE_KW)
+    }
+    #[inline]
+    pub fn end_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::END_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyRef {
+pub struct PlpgsqlCaseWhen {
     pub(crate) syntax: SyntaxNode,
 }
-impl PolicyRef {
+impl PlpgsqlCaseWhen {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn then_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::THEN_KW)
+    }
+    #[inline]
+    pub fn when_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHEN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyRenameTo {
+pub struct PlpgsqlCondition {
     pub(crate) syntax: SyntaxNode,
 }
-impl PolicyRenameTo {
+impl PlpgsqlCondition {
     #[inline]
-    pub fn policy(&self) -> Option<Policy> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn name_ref(&self) -> Option<NameRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn sqlstate_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SQLSTATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PolicyRoles {
+pub struct PlpgsqlCursorDecl {
     pub(crate) syntax: SyntaxNode,
 }
-impl PolicyRoles {
+impl PlpgsqlCursorDecl {
     #[inline]
-    pub fn role_ref_list(&self) -> Option<RoleRefList> {
+    pub fn param_list(&self) -> Option<ParamList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn plpgsql_variable(&self) -> Option<PlpgsqlVariable> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn stmt(&self) -> Option<Stmt> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn cursor_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURSOR_KW)
+    }
+    #[inline]
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
+    }
+    #[inline]
+    pub fn is_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IS_KW)
+    }
+    #[inline]
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
+    }
+    #[inline]
+    pub fn scroll_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCROLL_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PortionFromTo {
+pub struct PlpgsqlDeclareSection {
     pub(crate) syntax: SyntaxNode,
 }
-impl PortionFromTo {
+impl PlpgsqlDeclareSection {
     #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
+    pub fn plpgsql_decls(&self) -> AstChildren<PlpgsqlDecl> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn declare_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DECLARE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PortionRange {
+pub struct PlpgsqlDefault {
     pub(crate) syntax: SyntaxNode,
 }
-impl PortionRange {
+impl PlpgsqlDefault {
     #[inline]
     pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EQ)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PositionFn {
+pub struct PlpgsqlDiagnosticsItem {
     pub(crate) syntax: SyntaxNode,
 }
-impl PositionFn {
-    #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
+impl PlpgsqlDiagnosticsItem {
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn in_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IN_KW)
+    pub fn name_ref(&self) -> Option<NameRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn position_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::POSITION_KW)
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EQ)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PostfixExpr {
+pub struct PlpgsqlDynamicQuery {
     pub(crate) syntax: SyntaxNode,
 }
-impl PostfixExpr {
+impl PlpgsqlDynamicQuery {
     #[inline]
     pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn plpgsql_using(&self) -> Option<PlpgsqlUsing> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn execute_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXECUTE_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrefixExpr {
+pub struct PlpgsqlElse {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrefixExpr {
+impl PlpgsqlElse {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn else_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ELSE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Prepare {
+pub struct PlpgsqlElsif {
     pub(crate) syntax: SyntaxNode,
 }
-impl Prepare {
-    #[inline]
-    pub fn name(&self) -> Option<PreparedStatement> {
-        support::child(&self.syntax)
-    }
+impl PlpgsqlElsif {
     #[inline]
-    pub fn param_list(&self) -> Option<ParamList> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn stmt(&self) -> Option<PreparableStmt> {
-        support::child(&self.syntax)
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn elseif_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ELSEIF_KW)
     }
     #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
+    pub fn elsif_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ELSIF_KW)
     }
     #[inline]
-    pub fn prepare_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PREPARE_KW)
+    pub fn then_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::THEN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrepareTransaction {
+pub struct PlpgsqlEndLoop {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrepareTransaction {
+impl PlpgsqlEndLoop {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn name_ref(&self) -> Option<NameRef> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -19546,683 +19695,804 @@ impl PrepareTransaction {
         support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn prepare_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PREPARE_KW)
+    pub fn end_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::END_KW)
     }
     #[inline]
-    pub fn transaction_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    pub fn loop_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOOP_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PreparedStatement {
+pub struct PlpgsqlExceptionHandler {
     pub(crate) syntax: SyntaxNode,
 }
-impl PreparedStatement {
+impl PlpgsqlExceptionHandler {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn plpgsql_conditions(&self) -> AstChildren<PlpgsqlCondition> {
+        support::children(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PreparedStatementRef {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PreparedStatementRef {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
+        support::children(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PreserveRows {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PreserveRows {
     #[inline]
-    pub fn preserve_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PRESERVE_KW)
+    pub fn then_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::THEN_KW)
     }
     #[inline]
-    pub fn rows_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROWS_KW)
+    pub fn when_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHEN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PreserveWhitespace {
+pub struct PlpgsqlExceptionSection {
     pub(crate) syntax: SyntaxNode,
 }
-impl PreserveWhitespace {
+impl PlpgsqlExceptionSection {
     #[inline]
-    pub fn preserve_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PRESERVE_KW)
+    pub fn plpgsql_exception_handlers(&self) -> AstChildren<PlpgsqlExceptionHandler> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn whitespace_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WHITESPACE_KW)
+    pub fn exception_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXCEPTION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrimaryKeyConstraint {
+pub struct PlpgsqlExecute {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrimaryKeyConstraint {
+impl PlpgsqlExecute {
     #[inline]
-    pub fn constraint_name_clause(&self) -> Option<ConstraintNameClause> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn constraint_options(&self) -> AstChildren<ConstraintOption> {
-        support::children(&self.syntax)
-    }
-    #[inline]
-    pub fn index_parameters(&self) -> Option<IndexParameters> {
+    pub fn plpgsql_into(&self) -> Option<PlpgsqlInto> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn using_index(&self) -> Option<UsingIndexName> {
+    pub fn plpgsql_using(&self) -> Option<PlpgsqlUsing> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn key_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::KEY_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn primary_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PRIMARY_KW)
+    pub fn execute_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXECUTE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Prior {
+pub struct PlpgsqlExit {
     pub(crate) syntax: SyntaxNode,
 }
-impl Prior {
+impl PlpgsqlExit {
     #[inline]
-    pub fn prior_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PRIOR_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeAllFunctionsInSchema {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeAllFunctionsInSchema {
     #[inline]
-    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
-        support::children(&self.syntax)
+    pub fn name_ref(&self) -> Option<NameRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn functions_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FUNCTIONS_KW)
+    pub fn continue_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONTINUE_KW)
     }
     #[inline]
-    pub fn in_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IN_KW)
+    pub fn exit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXIT_KW)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn when_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHEN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeAllProceduresInSchema {
+pub struct PlpgsqlForInt {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeAllProceduresInSchema {
+impl PlpgsqlForInt {
     #[inline]
-    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
-        support::children(&self.syntax)
+    pub fn plpgsql_end_loop(&self) -> Option<PlpgsqlEndLoop> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    pub fn plpgsql_label(&self) -> Option<PlpgsqlLabel> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn in_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IN_KW)
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn procedures_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PROCEDURES_KW)
+    pub fn plpgsql_variable(&self) -> Option<PlpgsqlVariable> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn dot_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DOT)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeAllRoutinesInSchema {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeAllRoutinesInSchema {
     #[inline]
-    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
-        support::children(&self.syntax)
+    pub fn by_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::BY_KW)
     }
     #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
     }
     #[inline]
     pub fn in_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::IN_KW)
     }
     #[inline]
-    pub fn routines_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROUTINES_KW)
+    pub fn loop_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOOP_KW)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn reverse_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REVERSE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeAllSequencesInSchema {
+pub struct PlpgsqlForQuery {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeAllSequencesInSchema {
+impl PlpgsqlForQuery {
     #[inline]
-    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
-        support::children(&self.syntax)
+    pub fn arg_list(&self) -> Option<ArgList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    pub fn cursor_ref(&self) -> Option<CursorRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn in_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IN_KW)
+    pub fn plpgsql_dynamic_query(&self) -> Option<PlpgsqlDynamicQuery> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn plpgsql_end_loop(&self) -> Option<PlpgsqlEndLoop> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn sequences_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEQUENCES_KW)
+    pub fn plpgsql_label(&self) -> Option<PlpgsqlLabel> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeAllTablesInSchema {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeAllTablesInSchema {
     #[inline]
-    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    pub fn plpgsql_variables(&self) -> AstChildren<PlpgsqlVariable> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn in_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IN_KW)
+    pub fn stmt(&self) -> Option<Stmt> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
     }
     #[inline]
-    pub fn tables_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLES_KW)
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
+    }
+    #[inline]
+    pub fn loop_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOOP_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeDatabase {
+pub struct PlpgsqlForeach {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeDatabase {
+impl PlpgsqlForeach {
     #[inline]
-    pub fn database_refs(&self) -> AstChildren<DatabaseRef> {
-        support::children(&self.syntax)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn database_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DATABASE_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeDefault {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeDefault {
     #[inline]
-    pub fn relation_name_refs(&self) -> AstChildren<RelationNameRef> {
+    pub fn plpgsql_end_loop(&self) -> Option<PlpgsqlEndLoop> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_label(&self) -> Option<PlpgsqlLabel> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
         support::children(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeDomain {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeDomain {
     #[inline]
-    pub fn domain_refs(&self) -> AstChildren<DomainRef> {
+    pub fn plpgsql_variables(&self) -> AstChildren<PlpgsqlVariable> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn domain_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DOMAIN_KW)
+    pub fn array_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ARRAY_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeForeignDataWrapper {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeForeignDataWrapper {
     #[inline]
-    pub fn foreign_data_wrapper_refs(&self) -> AstChildren<ForeignDataWrapperRef> {
-        support::children(&self.syntax)
+    pub fn foreach_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOREACH_KW)
     }
     #[inline]
-    pub fn data_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DATA_KW)
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
     }
     #[inline]
-    pub fn foreign_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FOREIGN_KW)
+    pub fn loop_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOOP_KW)
     }
     #[inline]
-    pub fn wrapper_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WRAPPER_KW)
+    pub fn slice_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SLICE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeForeignServer {
+pub struct PlpgsqlGetDiagnostics {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeForeignServer {
+impl PlpgsqlGetDiagnostics {
     #[inline]
-    pub fn server_refs(&self) -> AstChildren<ServerRef> {
+    pub fn plpgsql_diagnostics_items(&self) -> AstChildren<PlpgsqlDiagnosticsItem> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn foreign_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FOREIGN_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn server_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SERVER_KW)
+    pub fn current_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURRENT_KW)
+    }
+    #[inline]
+    pub fn diagnostics_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DIAGNOSTICS_KW)
+    }
+    #[inline]
+    pub fn get_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GET_KW)
+    }
+    #[inline]
+    pub fn stacked_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STACKED_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeFunction {
+pub struct PlpgsqlIf {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeFunction {
+impl PlpgsqlIf {
     #[inline]
-    pub fn function_sig_list(&self) -> Option<FunctionSigList> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn function_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
+    pub fn plpgsql_else(&self) -> Option<PlpgsqlElse> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_elsifs(&self) -> AstChildren<PlpgsqlElsif> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn end_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::END_KW)
+    }
+    #[inline]
+    pub fn if_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IF_KW)
+    }
+    #[inline]
+    pub fn then_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::THEN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeLanguage {
+pub struct PlpgsqlInto {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeLanguage {
+impl PlpgsqlInto {
     #[inline]
-    pub fn language_refs(&self) -> AstChildren<LanguageRef> {
+    pub fn exprs(&self) -> AstChildren<Expr> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn language_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LANGUAGE_KW)
+    pub fn into_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INTO_KW)
+    }
+    #[inline]
+    pub fn strict_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STRICT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeLargeObject {
+pub struct PlpgsqlLabel {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeLargeObject {
+impl PlpgsqlLabel {
     #[inline]
-    pub fn literals(&self) -> AstChildren<Literal> {
-        support::children(&self.syntax)
+    pub fn l_angle_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_ANGLE)
     }
     #[inline]
-    pub fn large_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LARGE_KW)
+    pub fn r_angle_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_ANGLE)
     }
     #[inline]
-    pub fn object_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OBJECT_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeParameter {
+pub struct PlpgsqlLoop {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeParameter {
+impl PlpgsqlLoop {
     #[inline]
-    pub fn config_parameter_refs(&self) -> AstChildren<ConfigParameterRef> {
+    pub fn plpgsql_end_loop(&self) -> Option<PlpgsqlEndLoop> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_label(&self) -> Option<PlpgsqlLabel> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn parameter_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PARAMETER_KW)
+    pub fn loop_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOOP_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeProcedure {
+pub struct PlpgsqlNull {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeProcedure {
+impl PlpgsqlNull {
     #[inline]
-    pub fn procedure_sig_list(&self) -> Option<ProcedureSigList> {
-        support::child(&self.syntax)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn procedure_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PROCEDURE_KW)
+    pub fn null_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULL_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegePropertyGraph {
+pub struct PlpgsqlOpen {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegePropertyGraph {
+impl PlpgsqlOpen {
     #[inline]
-    pub fn property_graph_refs(&self) -> AstChildren<PropertyGraphRef> {
-        support::children(&self.syntax)
+    pub fn arg_list(&self) -> Option<ArgList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn graph_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::GRAPH_KW)
+    pub fn cursor_ref(&self) -> Option<CursorRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn property_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PROPERTY_KW)
+    pub fn plpgsql_dynamic_query(&self) -> Option<PlpgsqlDynamicQuery> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn stmt(&self) -> Option<Stmt> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
+    }
+    #[inline]
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
+    }
+    #[inline]
+    pub fn open_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OPEN_KW)
+    }
+    #[inline]
+    pub fn scroll_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCROLL_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeRoutine {
+pub struct PlpgsqlOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeRoutine {
+impl PlpgsqlOption {
     #[inline]
-    pub fn routine_sig_list(&self) -> Option<RoutineSigList> {
+    pub fn name_ref(&self) -> Option<NameRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn routine_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROUTINE_KW)
+    pub fn pound_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::POUND)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeSchema {
+pub struct PlpgsqlPerform {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeSchema {
+impl PlpgsqlPerform {
     #[inline]
-    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
-        support::children(&self.syntax)
+    pub fn from_clause(&self) -> Option<FromClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn group_by_clause(&self) -> Option<GroupByClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn having_clause(&self) -> Option<HavingClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn limit_clause(&self) -> Option<LimitClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn offset_clause(&self) -> Option<OffsetClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn target_list(&self) -> Option<TargetList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn window_clause(&self) -> Option<WindowClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn perform_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PERFORM_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeSequence {
+pub struct PlpgsqlRaise {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeSequence {
+impl PlpgsqlRaise {
     #[inline]
-    pub fn sequence_refs(&self) -> AstChildren<SequenceRef> {
+    pub fn exprs(&self) -> AstChildren<Expr> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn sequence_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEQUENCE_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_raise_using(&self) -> Option<PlpgsqlRaiseUsing> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn debug_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEBUG_KW)
+    }
+    #[inline]
+    pub fn exception_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXCEPTION_KW)
+    }
+    #[inline]
+    pub fn info_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INFO_KW)
+    }
+    #[inline]
+    pub fn log_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOG_KW)
+    }
+    #[inline]
+    pub fn notice_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NOTICE_KW)
+    }
+    #[inline]
+    pub fn raise_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RAISE_KW)
+    }
+    #[inline]
+    pub fn sqlstate_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SQLSTATE_KW)
+    }
+    #[inline]
+    pub fn warning_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WARNING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTable {
+pub struct PlpgsqlRaiseOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeTable {
+impl PlpgsqlRaiseOption {
     #[inline]
-    pub fn relation_name_refs(&self) -> AstChildren<RelationNameRef> {
-        support::children(&self.syntax)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn table_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLE_KW)
+    pub fn name_ref(&self) -> Option<NameRef> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EQ)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTablespace {
+pub struct PlpgsqlRaiseUsing {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeTablespace {
+impl PlpgsqlRaiseUsing {
     #[inline]
-    pub fn tablespace_refs(&self) -> AstChildren<TablespaceRef> {
+    pub fn plpgsql_raise_options(&self) -> AstChildren<PlpgsqlRaiseOption> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn tablespace_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLESPACE_KW)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTargetFunctions {
+pub struct PlpgsqlReturn {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeTargetFunctions {
+impl PlpgsqlReturn {
     #[inline]
-    pub fn functions_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FUNCTIONS_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTargetLargeObjects {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeTargetLargeObjects {
     #[inline]
-    pub fn large_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LARGE_KW)
+    pub fn plpgsql_dynamic_query(&self) -> Option<PlpgsqlDynamicQuery> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn objects_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OBJECTS_KW)
+    pub fn stmt(&self) -> Option<Stmt> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTargetRoutines {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeTargetRoutines {
     #[inline]
-    pub fn routines_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROUTINES_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTargetSchemas {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeTargetSchemas {
     #[inline]
-    pub fn schemas_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMAS_KW)
+    pub fn next_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NEXT_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTargetSequences {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PrivilegeTargetSequences {
     #[inline]
-    pub fn sequences_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEQUENCES_KW)
+    pub fn query_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::QUERY_KW)
+    }
+    #[inline]
+    pub fn return_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RETURN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTargetTables {
+pub struct PlpgsqlRoot {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeTargetTables {
+impl PlpgsqlRoot {
     #[inline]
-    pub fn tables_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLES_KW)
+    pub fn plpgsql_block(&self) -> Option<PlpgsqlBlock> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_options(&self) -> AstChildren<PlpgsqlOption> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeTargetTypes {
+pub struct PlpgsqlSqlStmt {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeTargetTypes {
+impl PlpgsqlSqlStmt {
     #[inline]
-    pub fn types_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TYPES_KW)
+    pub fn plpgsql_into(&self) -> Option<PlpgsqlInto> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn stmt(&self) -> Option<Stmt> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrivilegeType {
+pub struct PlpgsqlUsing {
     pub(crate) syntax: SyntaxNode,
 }
-impl PrivilegeType {
+impl PlpgsqlUsing {
     #[inline]
-    pub fn type_name_refs(&self) -> AstChildren<TypeNameRef> {
+    pub fn exprs(&self) -> AstChildren<Expr> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn type_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TYPE_KW)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ProcedureName {
+pub struct PlpgsqlVarDecl {
     pub(crate) syntax: SyntaxNode,
 }
-impl ProcedureName {
+impl PlpgsqlVarDecl {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
+    pub fn collate(&self) -> Option<Collate> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_default(&self) -> Option<PlpgsqlDefault> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn plpgsql_variable(&self) -> Option<PlpgsqlVariable> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn ty(&self) -> Option<Type> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn constant_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONSTANT_KW)
+    }
+    #[inline]
+    pub fn not_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NOT_KW)
+    }
+    #[inline]
+    pub fn null_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ProcedureNameRef {
+pub struct PlpgsqlVariable {
     pub(crate) syntax: SyntaxNode,
 }
-impl ProcedureNameRef {
+impl PlpgsqlVariable {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
-        support::child(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ProcedureRenameTo {
+pub struct PlpgsqlWhile {
     pub(crate) syntax: SyntaxNode,
 }
-impl ProcedureRenameTo {
+impl PlpgsqlWhile {
     #[inline]
-    pub fn procedure_name(&self) -> Option<ProcedureName> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn plpgsql_end_loop(&self) -> Option<PlpgsqlEndLoop> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn plpgsql_label(&self) -> Option<PlpgsqlLabel> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ProcedureSig {
-    pub(crate) syntax: SyntaxNode,
-}
-impl ProcedureSig {
     #[inline]
-    pub fn param_list(&self) -> Option<ParamList> {
-        support::child(&self.syntax)
+    pub fn plpgsql_stmts(&self) -> AstChildren<PlpgsqlStmt> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn procedure_name_ref(&self) -> Option<ProcedureNameRef> {
-        support::child(&self.syntax)
+    pub fn loop_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOOP_KW)
+    }
+    #[inline]
+    pub fn while_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHILE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ProcedureSigList {
+pub struct Policy {
     pub(crate) syntax: SyntaxNode,
 }
-impl ProcedureSigList {
+impl Policy {
     #[inline]
-    pub fn procedure_sigs(&self) -> AstChildren<ProcedureSig> {
-        support::children(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Properties {
+pub struct PolicyCommand {
     pub(crate) syntax: SyntaxNode,
 }
-impl Properties {
+impl PolicyCommand {
     #[inline]
-    pub fn expr_as_property_name_list(&self) -> Option<ExprAsPropertyNameList> {
+    pub fn command(&self) -> Option<PolicyCommandKind> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn properties_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PROPERTIES_KW)
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyAll {
+pub struct PolicyCommandAll {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyAll {
+impl PolicyCommandAll {
     #[inline]
     pub fn all_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::ALL_KW)
@@ -20230,94 +20500,102 @@ impl PropertyAll {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyComments {
+pub struct PolicyCommandDelete {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyComments {
+impl PolicyCommandDelete {
     #[inline]
-    pub fn comments_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMENTS_KW)
+    pub fn delete_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DELETE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyCompression {
+pub struct PolicyCommandInsert {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyCompression {
+impl PolicyCommandInsert {
     #[inline]
-    pub fn compression_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMPRESSION_KW)
+    pub fn insert_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INSERT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyConstraints {
+pub struct PolicyCommandSelect {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyConstraints {
+impl PolicyCommandSelect {
     #[inline]
-    pub fn constraints_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONSTRAINTS_KW)
+    pub fn select_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SELECT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyDefaults {
+pub struct PolicyCommandUpdate {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyDefaults {
+impl PolicyCommandUpdate {
     #[inline]
-    pub fn defaults_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULTS_KW)
+    pub fn update_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyGenerated {
+pub struct PolicyRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyGenerated {
+impl PolicyRef {
     #[inline]
-    pub fn generated_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::GENERATED_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyGraph {
+pub struct PolicyRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyGraph {
+impl PolicyRenameTo {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
+    pub fn policy(&self) -> Option<Policy> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyGraphRef {
+pub struct PolicyRoles {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyGraphRef {
+impl PolicyRoles {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
+    pub fn role_ref_list(&self) -> Option<RoleRefList> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyGraphRenameTo {
+pub struct PortionFromTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyGraphRenameTo {
-    #[inline]
-    pub fn property_graph(&self) -> Option<PropertyGraph> {
-        support::child(&self.syntax)
-    }
+impl PortionFromTo {
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
     #[inline]
     pub fn to_token(&self) -> Option<SyntaxToken> {
@@ -20326,168 +20604,128 @@ impl PropertyGraphRenameTo {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyIdentity {
+pub struct PortionRange {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyIdentity {
+impl PortionRange {
     #[inline]
-    pub fn identity_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENTITY_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyIndexes {
+pub struct PositionFn {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyIndexes {
+impl PositionFn {
     #[inline]
-    pub fn indexes_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INDEXES_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
+    }
+    #[inline]
+    pub fn position_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::POSITION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyName {
+pub struct PostfixExpr {
     pub(crate) syntax: SyntaxNode,
 }
-impl PropertyName {
+impl PostfixExpr {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyNameRef {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PropertyNameRef {
-    #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyStatistics {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PropertyStatistics {
-    #[inline]
-    pub fn statistics_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STATISTICS_KW)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PropertyStorage {
-    pub(crate) syntax: SyntaxNode,
-}
-impl PropertyStorage {
-    #[inline]
-    pub fn storage_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STORAGE_KW)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Publication {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Publication {
-    #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PublicationObjectCurrentSchema {
+pub struct PrefixExpr {
     pub(crate) syntax: SyntaxNode,
 }
-impl PublicationObjectCurrentSchema {
+impl PrefixExpr {
     #[inline]
-    pub fn current_schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CURRENT_SCHEMA_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PublicationObjectTable {
+pub struct Prepare {
     pub(crate) syntax: SyntaxNode,
 }
-impl PublicationObjectTable {
+impl Prepare {
     #[inline]
-    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+    pub fn name(&self) -> Option<PreparedStatement> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn table_name_ref(&self) -> Option<TableNameRef> {
+    pub fn param_list(&self) -> Option<ParamList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn where_condition_clause(&self) -> Option<WhereConditionClause> {
+    pub fn stmt(&self) -> Option<PreparableStmt> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
-    }
-    #[inline]
-    pub fn star_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STAR)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn only_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ONLY_KW)
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
     }
     #[inline]
-    pub fn table_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLE_KW)
+    pub fn prepare_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PREPARE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PublicationObjectTablesInSchema {
+pub struct PrepareTransaction {
     pub(crate) syntax: SyntaxNode,
 }
-impl PublicationObjectTablesInSchema {
-    #[inline]
-    pub fn schema_ref(&self) -> Option<SchemaRef> {
-        support::child(&self.syntax)
-    }
+impl PrepareTransaction {
     #[inline]
-    pub fn where_condition_clause(&self) -> Option<WhereConditionClause> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn current_schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CURRENT_SCHEMA_KW)
-    }
-    #[inline]
-    pub fn in_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IN_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn prepare_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PREPARE_KW)
     }
     #[inline]
-    pub fn tables_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLES_KW)
+    pub fn transaction_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PublicationRef {
+pub struct PreparedStatement {
     pub(crate) syntax: SyntaxNode,
 }
-impl PublicationRef {
+impl PreparedStatement {
     #[inline]
     pub fn ident_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::IDENT)
@@ -20495,728 +20733,607 @@ impl PublicationRef {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PublicationRenameTo {
+pub struct PreparedStatementRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl PublicationRenameTo {
-    #[inline]
-    pub fn publication(&self) -> Option<Publication> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
-    }
+impl PreparedStatementRef {
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct QualifiedColumnNameRef {
+pub struct PreserveRows {
     pub(crate) syntax: SyntaxNode,
 }
-impl QualifiedColumnNameRef {
+impl PreserveRows {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
-        support::child(&self.syntax)
+    pub fn preserve_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PRESERVE_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct QualifierMax {
-    pub(crate) syntax: SyntaxNode,
-}
-impl QualifierMax {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
+    pub fn rows_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROWS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct QualifierMin {
+pub struct PreserveWhitespace {
     pub(crate) syntax: SyntaxNode,
 }
-impl QualifierMin {
+impl PreserveWhitespace {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
+    pub fn preserve_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PRESERVE_KW)
+    }
+    #[inline]
+    pub fn whitespace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHITESPACE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RangeType {
+pub struct PrimaryKeyConstraint {
     pub(crate) syntax: SyntaxNode,
 }
-impl RangeType {
+impl PrimaryKeyConstraint {
     #[inline]
-    pub fn attribute_list(&self) -> Option<AttributeList> {
+    pub fn constraint_name_clause(&self) -> Option<ConstraintNameClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
-    }
-    #[inline]
-    pub fn range_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RANGE_KW)
+    pub fn constraint_options(&self) -> AstChildren<ConstraintOption> {
+        support::children(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReadCommitted {
-    pub(crate) syntax: SyntaxNode,
-}
-impl ReadCommitted {
     #[inline]
-    pub fn committed_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMITTED_KW)
+    pub fn index_parameters(&self) -> Option<IndexParameters> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn isolation_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
+    pub fn using_index(&self) -> Option<UsingIndexName> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn level_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
+    pub fn key_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::KEY_KW)
     }
     #[inline]
-    pub fn read_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::READ_KW)
+    pub fn primary_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PRIMARY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReadOnly {
+pub struct Prior {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReadOnly {
-    #[inline]
-    pub fn only_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ONLY_KW)
-    }
+impl Prior {
     #[inline]
-    pub fn read_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::READ_KW)
+    pub fn prior_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PRIOR_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReadUncommitted {
+pub struct PrivilegeAllFunctionsInSchema {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReadUncommitted {
+impl PrivilegeAllFunctionsInSchema {
     #[inline]
-    pub fn isolation_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
+    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn level_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
     }
     #[inline]
-    pub fn read_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::READ_KW)
+    pub fn functions_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FUNCTIONS_KW)
     }
     #[inline]
-    pub fn uncommitted_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNCOMMITTED_KW)
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
+    }
+    #[inline]
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReadWrite {
+pub struct PrivilegeAllProceduresInSchema {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReadWrite {
+impl PrivilegeAllProceduresInSchema {
     #[inline]
-    pub fn read_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::READ_KW)
+    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn write_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WRITE_KW)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    }
+    #[inline]
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
+    }
+    #[inline]
+    pub fn procedures_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PROCEDURES_KW)
+    }
+    #[inline]
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Reassign {
+pub struct PrivilegeAllRoutinesInSchema {
     pub(crate) syntax: SyntaxNode,
 }
-impl Reassign {
+impl PrivilegeAllRoutinesInSchema {
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn by_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::BY_KW)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
     }
     #[inline]
-    pub fn owned_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OWNED_KW)
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
     }
     #[inline]
-    pub fn reassign_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REASSIGN_KW)
+    pub fn routines_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROUTINES_KW)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReferencesConstraint {
+pub struct PrivilegeAllSequencesInSchema {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReferencesConstraint {
+impl PrivilegeAllSequencesInSchema {
     #[inline]
-    pub fn column(&self) -> Option<ColumnNameRef> {
-        support::child(&self.syntax)
+    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn constraint_name_clause(&self) -> Option<ConstraintNameClause> {
-        support::child(&self.syntax)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
     }
     #[inline]
-    pub fn constraint_options(&self) -> AstChildren<ConstraintOption> {
-        support::children(&self.syntax)
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
     }
     #[inline]
-    pub fn match_type(&self) -> Option<MatchType> {
-        support::child(&self.syntax)
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
     #[inline]
-    pub fn on_delete_action(&self) -> Option<OnDeleteAction> {
-        support::child(&self.syntax)
+    pub fn sequences_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEQUENCES_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrivilegeAllTablesInSchema {
+    pub(crate) syntax: SyntaxNode,
+}
+impl PrivilegeAllTablesInSchema {
     #[inline]
-    pub fn on_update_action(&self) -> Option<OnUpdateAction> {
-        support::child(&self.syntax)
+    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn table(&self) -> Option<TableNameRef> {
-        support::child(&self.syntax)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
     #[inline]
-    pub fn references_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REFERENCES_KW)
+    pub fn tables_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReferencesTable {
+pub struct PrivilegeDatabase {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReferencesTable {
-    #[inline]
-    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeDatabase {
     #[inline]
-    pub fn vertex_table_ref(&self) -> Option<VertexTableRef> {
-        support::child(&self.syntax)
+    pub fn database_refs(&self) -> AstChildren<DatabaseRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn references_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REFERENCES_KW)
+    pub fn database_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DATABASE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Referencing {
+pub struct PrivilegeDefault {
     pub(crate) syntax: SyntaxNode,
 }
-impl Referencing {
+impl PrivilegeDefault {
     #[inline]
-    pub fn referencing_tables(&self) -> AstChildren<ReferencingTable> {
+    pub fn relation_name_refs(&self) -> AstChildren<RelationNameRef> {
         support::children(&self.syntax)
     }
-    #[inline]
-    pub fn referencing_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REFERENCING_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Refresh {
+pub struct PrivilegeDomain {
     pub(crate) syntax: SyntaxNode,
 }
-impl Refresh {
-    #[inline]
-    pub fn data_option(&self) -> Option<DataOption> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeDomain {
     #[inline]
-    pub fn view_ref(&self) -> Option<ViewRef> {
-        support::child(&self.syntax)
+    pub fn domain_refs(&self) -> AstChildren<DomainRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn domain_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DOMAIN_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrivilegeForeignDataWrapper {
+    pub(crate) syntax: SyntaxNode,
+}
+impl PrivilegeForeignDataWrapper {
     #[inline]
-    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    pub fn foreign_data_wrapper_refs(&self) -> AstChildren<ForeignDataWrapperRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn materialized_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::MATERIALIZED_KW)
+    pub fn data_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DATA_KW)
     }
     #[inline]
-    pub fn refresh_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REFRESH_KW)
+    pub fn foreign_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOREIGN_KW)
     }
     #[inline]
-    pub fn view_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VIEW_KW)
+    pub fn wrapper_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WRAPPER_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RefreshCollationVersion {
+pub struct PrivilegeForeignServer {
     pub(crate) syntax: SyntaxNode,
 }
-impl RefreshCollationVersion {
+impl PrivilegeForeignServer {
     #[inline]
-    pub fn collation_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COLLATION_KW)
+    pub fn server_refs(&self) -> AstChildren<ServerRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn refresh_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REFRESH_KW)
+    pub fn foreign_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOREIGN_KW)
     }
     #[inline]
-    pub fn version_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VERSION_KW)
+    pub fn server_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SERVER_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RefreshPublication {
+pub struct PrivilegeFunction {
     pub(crate) syntax: SyntaxNode,
 }
-impl RefreshPublication {
+impl PrivilegeFunction {
     #[inline]
-    pub fn with_params(&self) -> Option<WithParams> {
+    pub fn function_sig_list(&self) -> Option<FunctionSigList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn publication_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PUBLICATION_KW)
-    }
-    #[inline]
-    pub fn refresh_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REFRESH_KW)
+    pub fn function_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RefreshVersion {
+pub struct PrivilegeLanguage {
     pub(crate) syntax: SyntaxNode,
 }
-impl RefreshVersion {
+impl PrivilegeLanguage {
     #[inline]
-    pub fn refresh_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REFRESH_KW)
+    pub fn language_refs(&self) -> AstChildren<LanguageRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn version_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VERSION_KW)
+    pub fn language_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LANGUAGE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Reindex {
+pub struct PrivilegeLargeObject {
     pub(crate) syntax: SyntaxNode,
 }
-impl Reindex {
-    #[inline]
-    pub fn reindex_option_list(&self) -> Option<ReindexOptionList> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeLargeObject {
     #[inline]
-    pub fn reindex_target(&self) -> Option<ReindexTarget> {
-        support::child(&self.syntax)
+    pub fn literals(&self) -> AstChildren<Literal> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn large_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LARGE_KW)
     }
     #[inline]
-    pub fn reindex_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REINDEX_KW)
+    pub fn object_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OBJECT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexOptionConcurrently {
+pub struct PrivilegeParameter {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexOptionConcurrently {
-    #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeParameter {
     #[inline]
-    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    pub fn config_parameter_refs(&self) -> AstChildren<ConfigParameterRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn parameter_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PARAMETER_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrivilegeProcedure {
+    pub(crate) syntax: SyntaxNode,
+}
+impl PrivilegeProcedure {
     #[inline]
-    pub fn no_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NO_KW)
+    pub fn procedure_sig_list(&self) -> Option<ProcedureSigList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn yes_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::YES_KW)
+    pub fn procedure_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PROCEDURE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexOptionList {
+pub struct PrivilegePropertyGraph {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexOptionList {
+impl PrivilegePropertyGraph {
     #[inline]
-    pub fn reindex_options(&self) -> AstChildren<ReindexOption> {
+    pub fn property_graph_refs(&self) -> AstChildren<PropertyGraphRef> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn graph_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GRAPH_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn property_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PROPERTY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexOptionTablespace {
+pub struct PrivilegeRoutine {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexOptionTablespace {
+impl PrivilegeRoutine {
     #[inline]
-    pub fn tablespace_ref(&self) -> Option<TablespaceRef> {
+    pub fn routine_sig_list(&self) -> Option<RoutineSigList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn tablespace_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLESPACE_KW)
-    }
+    pub fn routine_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROUTINE_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexOptionVerbose {
+pub struct PrivilegeSchema {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexOptionVerbose {
-    #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
-    }
-    #[inline]
-    pub fn no_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NO_KW)
-    }
+impl PrivilegeSchema {
     #[inline]
-    pub fn verbose_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VERBOSE_KW)
+    pub fn schema_refs(&self) -> AstChildren<SchemaRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn yes_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::YES_KW)
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexTargetDatabase {
+pub struct PrivilegeSequence {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexTargetDatabase {
-    #[inline]
-    pub fn database_ref(&self) -> Option<DatabaseRef> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeSequence {
     #[inline]
-    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    pub fn sequence_refs(&self) -> AstChildren<SequenceRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn database_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DATABASE_KW)
+    pub fn sequence_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEQUENCE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexTargetIndex {
+pub struct PrivilegeTable {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexTargetIndex {
-    #[inline]
-    pub fn index_ref(&self) -> Option<IndexRef> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeTable {
     #[inline]
-    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    pub fn relation_name_refs(&self) -> AstChildren<RelationNameRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn index_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INDEX_KW)
+    pub fn table_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexTargetSchema {
+pub struct PrivilegeTablespace {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexTargetSchema {
-    #[inline]
-    pub fn schema_ref(&self) -> Option<SchemaRef> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeTablespace {
     #[inline]
-    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    pub fn tablespace_refs(&self) -> AstChildren<TablespaceRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn tablespace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLESPACE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexTargetSystem {
+pub struct PrivilegeTargetFunctions {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexTargetSystem {
-    #[inline]
-    pub fn database_ref(&self) -> Option<DatabaseRef> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
-    }
+impl PrivilegeTargetFunctions {
     #[inline]
-    pub fn system_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SYSTEM_KW)
+    pub fn functions_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FUNCTIONS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReindexTargetTable {
+pub struct PrivilegeTargetLargeObjects {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReindexTargetTable {
-    #[inline]
-    pub fn table_name_ref(&self) -> Option<TableNameRef> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeTargetLargeObjects {
     #[inline]
-    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    pub fn large_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LARGE_KW)
     }
     #[inline]
-    pub fn table_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLE_KW)
+    pub fn objects_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OBJECTS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RelationFromItem {
+pub struct PrivilegeTargetRoutines {
     pub(crate) syntax: SyntaxNode,
 }
-impl RelationFromItem {
-    #[inline]
-    pub fn alias(&self) -> Option<FromAlias> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn relation_name_ref(&self) -> Option<RelationNameRef> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn tablesample_clause(&self) -> Option<TablesampleClause> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn star_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STAR)
-    }
+impl PrivilegeTargetRoutines {
     #[inline]
-    pub fn only_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ONLY_KW)
+    pub fn routines_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROUTINES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RelationList {
+pub struct PrivilegeTargetSchemas {
     pub(crate) syntax: SyntaxNode,
 }
-impl RelationList {
+impl PrivilegeTargetSchemas {
     #[inline]
-    pub fn relation_names(&self) -> AstChildren<RelationName> {
-        support::children(&self.syntax)
+    pub fn schemas_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMAS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RelationName {
+pub struct PrivilegeTargetSequences {
     pub(crate) syntax: SyntaxNode,
 }
-impl RelationName {
-    #[inline]
-    pub fn relation_name_ref(&self) -> Option<RelationNameRef> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
-    }
-    #[inline]
-    pub fn star_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STAR)
-    }
+impl PrivilegeTargetSequences {
     #[inline]
-    pub fn only_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ONLY_KW)
+    pub fn sequences_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEQUENCES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RelationNameRef {
+pub struct PrivilegeTargetTables {
     pub(crate) syntax: SyntaxNode,
 }
-impl RelationNameRef {
+impl PrivilegeTargetTables {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
-        support::child(&self.syntax)
+    pub fn tables_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Relative {
+pub struct PrivilegeTargetTypes {
     pub(crate) syntax: SyntaxNode,
 }
-impl Relative {
-    #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
+impl PrivilegeTargetTypes {
     #[inline]
-    pub fn relative_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RELATIVE_KW)
+    pub fn types_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TYPES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReleaseSavepoint {
+pub struct PrivilegeType {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReleaseSavepoint {
-    #[inline]
-    pub fn savepoint_ref(&self) -> Option<SavepointRef> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
+impl PrivilegeType {
     #[inline]
-    pub fn release_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RELEASE_KW)
+    pub fn type_name_refs(&self) -> AstChildren<TypeNameRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn savepoint_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SAVEPOINT_KW)
+    pub fn type_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TYPE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RemoteTableNameRef {
+pub struct ProcedureName {
     pub(crate) syntax: SyntaxNode,
 }
-impl RemoteTableNameRef {
+impl ProcedureName {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn path(&self) -> Option<Path> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RenameAttribute {
+pub struct ProcedureNameRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl RenameAttribute {
-    #[inline]
-    pub fn composite_field(&self) -> Option<CompositeField> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn composite_field_ref(&self) -> Option<CompositeFieldRef> {
-        support::child(&self.syntax)
-    }
+impl ProcedureNameRef {
     #[inline]
-    pub fn drop_behavior(&self) -> Option<DropBehavior> {
+    pub fn path_ref(&self) -> Option<PathRef> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn attribute_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ATTRIBUTE_KW)
-    }
-    #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
-    }
-    #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RenameColumn {
+pub struct ProcedureRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl RenameColumn {
-    #[inline]
-    pub fn column_name(&self) -> Option<ColumnName> {
-        support::child(&self.syntax)
-    }
+impl ProcedureRenameTo {
     #[inline]
-    pub fn column_name_ref(&self) -> Option<ColumnNameRef> {
+    pub fn procedure_name(&self) -> Option<ProcedureName> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn column_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COLUMN_KW)
-    }
-    #[inline]
     pub fn rename_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
@@ -21227,501 +21344,392 @@ impl RenameColumn {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RenameConstraint {
+pub struct ProcedureSig {
     pub(crate) syntax: SyntaxNode,
 }
-impl RenameConstraint {
+impl ProcedureSig {
     #[inline]
-    pub fn constraint_name(&self) -> Option<ConstraintName> {
+    pub fn param_list(&self) -> Option<ParamList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn constraint_name_ref(&self) -> Option<ConstraintNameRef> {
+    pub fn procedure_name_ref(&self) -> Option<ProcedureNameRef> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn constraint_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONSTRAINT_KW)
-    }
-    #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
-    }
-    #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RenameValue {
+pub struct ProcedureSigList {
     pub(crate) syntax: SyntaxNode,
 }
-impl RenameValue {
-    #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
-    }
-    #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
-    }
+impl ProcedureSigList {
     #[inline]
-    pub fn value_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VALUE_KW)
+    pub fn procedure_sigs(&self) -> AstChildren<ProcedureSig> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Repack {
+pub struct Properties {
     pub(crate) syntax: SyntaxNode,
 }
-impl Repack {
-    #[inline]
-    pub fn option_item_list(&self) -> Option<OptionItemList> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn table_and_columns_list(&self) -> Option<TableAndColumnsList> {
-        support::child(&self.syntax)
-    }
+impl Properties {
     #[inline]
-    pub fn using_index(&self) -> Option<UsingIndex> {
+    pub fn expr_as_property_name_list(&self) -> Option<ExprAsPropertyNameList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
-    #[inline]
-    pub fn repack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REPACK_KW)
+    pub fn properties_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PROPERTIES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RepeatableClause {
+pub struct PropertyAll {
     pub(crate) syntax: SyntaxNode,
 }
-impl RepeatableClause {
-    #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
-    }
+impl PropertyAll {
     #[inline]
-    pub fn repeatable_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REPEATABLE_KW)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RepeatableRead {
+pub struct PropertyComments {
     pub(crate) syntax: SyntaxNode,
 }
-impl RepeatableRead {
-    #[inline]
-    pub fn isolation_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
-    }
-    #[inline]
-    pub fn level_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
-    }
-    #[inline]
-    pub fn read_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::READ_KW)
-    }
+impl PropertyComments {
     #[inline]
-    pub fn repeatable_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REPEATABLE_KW)
+    pub fn comments_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMENTS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReplaceDictionary {
+pub struct PropertyCompression {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReplaceDictionary {
-    #[inline]
-    pub fn replace_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REPLACE_KW)
-    }
+impl PropertyCompression {
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn compression_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMPRESSION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReplicaIdentity {
+pub struct PropertyConstraints {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReplicaIdentity {
-    #[inline]
-    pub fn replica_identity_option(&self) -> Option<ReplicaIdentityOption> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn identity_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENTITY_KW)
-    }
+impl PropertyConstraints {
     #[inline]
-    pub fn replica_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REPLICA_KW)
+    pub fn constraints_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONSTRAINTS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReplicaIdentityDefault {
+pub struct PropertyDefaults {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReplicaIdentityDefault {
+impl PropertyDefaults {
     #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    pub fn defaults_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULTS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReplicaIdentityFull {
+pub struct PropertyGenerated {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReplicaIdentityFull {
+impl PropertyGenerated {
     #[inline]
-    pub fn full_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FULL_KW)
+    pub fn generated_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GENERATED_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReplicaIdentityNothing {
+pub struct PropertyGraph {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReplicaIdentityNothing {
+impl PropertyGraph {
     #[inline]
-    pub fn nothing_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NOTHING_KW)
+    pub fn path(&self) -> Option<Path> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RequiredAsAlias {
+pub struct PropertyGraphRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl RequiredAsAlias {
+impl PropertyGraphRef {
     #[inline]
-    pub fn name(&self) -> Option<TableAlias> {
+    pub fn path_ref(&self) -> Option<PathRef> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Reset {
+pub struct PropertyGraphRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl Reset {
+impl PropertyGraphRenameTo {
     #[inline]
-    pub fn reset_target(&self) -> Option<ResetTarget> {
+    pub fn property_graph(&self) -> Option<PropertyGraph> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn reset_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESET_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ResetConfigParam {
+pub struct PropertyIdentity {
     pub(crate) syntax: SyntaxNode,
 }
-impl ResetConfigParam {
-    #[inline]
-    pub fn config_parameter_ref(&self) -> Option<ConfigParameterRef> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
-    }
+impl PropertyIdentity {
     #[inline]
-    pub fn reset_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESET_KW)
+    pub fn identity_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENTITY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ResetFuncOption {
+pub struct PropertyIndexes {
     pub(crate) syntax: SyntaxNode,
 }
-impl ResetFuncOption {
-    #[inline]
-    pub fn config_parameter_ref(&self) -> Option<ConfigParameterRef> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
-    }
+impl PropertyIndexes {
     #[inline]
-    pub fn reset_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESET_KW)
+    pub fn indexes_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INDEXES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ResetOptions {
+pub struct PropertyName {
     pub(crate) syntax: SyntaxNode,
 }
-impl ResetOptions {
-    #[inline]
-    pub fn attribute_list(&self) -> Option<AttributeList> {
-        support::child(&self.syntax)
-    }
+impl PropertyName {
     #[inline]
-    pub fn reset_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESET_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ResetRole {
+pub struct PropertyNameRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl ResetRole {
-    #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
-    #[inline]
-    pub fn reset_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESET_KW)
-    }
+impl PropertyNameRef {
     #[inline]
-    pub fn role_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROLE_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ResetSessionAuth {
+pub struct PropertyStatistics {
     pub(crate) syntax: SyntaxNode,
 }
-impl ResetSessionAuth {
-    #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
-    #[inline]
-    pub fn authorization_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AUTHORIZATION_KW)
-    }
-    #[inline]
-    pub fn reset_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESET_KW)
-    }
+impl PropertyStatistics {
     #[inline]
-    pub fn session_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SESSION_KW)
+    pub fn statistics_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STATISTICS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ResetTimeZone {
+pub struct PropertyStorage {
     pub(crate) syntax: SyntaxNode,
 }
-impl ResetTimeZone {
+impl PropertyStorage {
     #[inline]
-    pub fn time_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIME_KW)
-    }
-    #[inline]
-    pub fn zone_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ZONE_KW)
+    pub fn storage_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STORAGE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ResetTransactionIsolation {
+pub struct PsqlMetaCommand {
     pub(crate) syntax: SyntaxNode,
 }
-impl ResetTransactionIsolation {
-    #[inline]
-    pub fn isolation_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
-    }
-    #[inline]
-    pub fn level_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
-    }
+impl PsqlMetaCommand {
     #[inline]
-    pub fn transaction_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    pub fn meta_command_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::META_COMMAND)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RespectNulls {
+pub struct Publication {
     pub(crate) syntax: SyntaxNode,
 }
-impl RespectNulls {
+impl Publication {
     #[inline]
-    pub fn nulls_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NULLS_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PublicationObjectCurrentSchema {
+    pub(crate) syntax: SyntaxNode,
+}
+impl PublicationObjectCurrentSchema {
     #[inline]
-    pub fn respect_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESPECT_KW)
+    pub fn current_schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURRENT_SCHEMA_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Restart {
+pub struct PublicationObjectTable {
     pub(crate) syntax: SyntaxNode,
 }
-impl Restart {
+impl PublicationObjectTable {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn restart_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESTART_KW)
+    pub fn table_name_ref(&self) -> Option<TableNameRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn where_condition_clause(&self) -> Option<WhereConditionClause> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RestartIdentity {
-    pub(crate) syntax: SyntaxNode,
-}
-impl RestartIdentity {
     #[inline]
-    pub fn identity_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENTITY_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn restart_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESTART_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Restrict {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Restrict {
     #[inline]
-    pub fn restrict_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RESTRICT_KW)
+    pub fn star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STAR)
+    }
+    #[inline]
+    pub fn only_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ONLY_KW)
+    }
+    #[inline]
+    pub fn table_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RetType {
+pub struct PublicationObjectTablesInSchema {
     pub(crate) syntax: SyntaxNode,
 }
-impl RetType {
+impl PublicationObjectTablesInSchema {
     #[inline]
-    pub fn table_arg_list(&self) -> Option<TableArgList> {
+    pub fn schema_ref(&self) -> Option<SchemaRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn ty(&self) -> Option<Type> {
+    pub fn where_condition_clause(&self) -> Option<WhereConditionClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn returns_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RETURNS_KW)
+    pub fn current_schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURRENT_SCHEMA_KW)
     }
     #[inline]
-    pub fn table_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLE_KW)
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
+    }
+    #[inline]
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    }
+    #[inline]
+    pub fn tables_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReturnFuncOption {
+pub struct PublicationRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReturnFuncOption {
-    #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
+impl PublicationRef {
     #[inline]
-    pub fn return_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RETURN_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReturningClause {
+pub struct PublicationRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReturningClause {
+impl PublicationRenameTo {
     #[inline]
-    pub fn returning_option_list(&self) -> Option<ReturningOptionList> {
+    pub fn publication(&self) -> Option<Publication> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn target_list(&self) -> Option<TargetList> {
-        support::child(&self.syntax)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn returning_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RETURNING_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReturningNew {
+pub struct QualifiedColumnNameRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReturningNew {
+impl QualifiedColumnNameRef {
     #[inline]
-    pub fn name(&self) -> Option<TableAlias> {
+    pub fn path_ref(&self) -> Option<PathRef> {
         support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QualifierMax {
+    pub(crate) syntax: SyntaxNode,
+}
+impl QualifierMax {
     #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QualifierMin {
+    pub(crate) syntax: SyntaxNode,
+}
+impl QualifierMin {
     #[inline]
-    pub fn new_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NEW_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReturningOld {
+pub struct RangeType {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReturningOld {
+impl RangeType {
     #[inline]
-    pub fn name(&self) -> Option<TableAlias> {
+    pub fn attribute_list(&self) -> Option<AttributeList> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -21729,548 +21737,585 @@ impl ReturningOld {
         support::token(&self.syntax, SyntaxKind::AS_KW)
     }
     #[inline]
-    pub fn old_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OLD_KW)
+    pub fn range_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RANGE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReturningOptionList {
+pub struct ReadCommitted {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReturningOptionList {
+impl ReadCommitted {
     #[inline]
-    pub fn returning_options(&self) -> AstChildren<ReturningOption> {
-        support::children(&self.syntax)
+    pub fn committed_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMITTED_KW)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn isolation_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn level_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn read_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::READ_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReturnsNullOnNullInputFuncOption {
+pub struct ReadOnly {
     pub(crate) syntax: SyntaxNode,
 }
-impl ReturnsNullOnNullInputFuncOption {
-    #[inline]
-    pub fn input_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INPUT_KW)
-    }
-    #[inline]
-    pub fn null_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NULL_KW)
-    }
+impl ReadOnly {
     #[inline]
-    pub fn on_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ON_KW)
+    pub fn only_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ONLY_KW)
     }
     #[inline]
-    pub fn returns_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RETURNS_KW)
+    pub fn read_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::READ_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Revoke {
+pub struct ReadUncommitted {
     pub(crate) syntax: SyntaxNode,
 }
-impl Revoke {
+impl ReadUncommitted {
     #[inline]
-    pub fn drop_behavior(&self) -> Option<DropBehavior> {
-        support::child(&self.syntax)
+    pub fn isolation_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
     }
     #[inline]
-    pub fn granted_by_clause(&self) -> Option<GrantedByClause> {
-        support::child(&self.syntax)
+    pub fn level_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
     }
     #[inline]
-    pub fn on_privilege_objects_clause(&self) -> Option<OnPrivilegeObjectsClause> {
-        support::child(&self.syntax)
+    pub fn read_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::READ_KW)
     }
     #[inline]
-    pub fn privileges(&self) -> Option<Privileges> {
-        support::child(&self.syntax)
+    pub fn uncommitted_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNCOMMITTED_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReadWrite {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ReadWrite {
     #[inline]
-    pub fn revoke_option_for(&self) -> Option<RevokeOptionFor> {
-        support::child(&self.syntax)
+    pub fn read_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::READ_KW)
     }
     #[inline]
-    pub fn role_ref_list(&self) -> Option<RoleRefList> {
-        support::child(&self.syntax)
+    pub fn write_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WRITE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reassign {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Reassign {
     #[inline]
     pub fn semicolon_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
+    pub fn by_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::BY_KW)
     }
     #[inline]
-    pub fn revoke_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REVOKE_KW)
+    pub fn owned_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OWNED_KW)
+    }
+    #[inline]
+    pub fn reassign_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REASSIGN_KW)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RevokeCommand {
+pub struct ReferencesConstraint {
     pub(crate) syntax: SyntaxNode,
 }
-impl RevokeCommand {
+impl ReferencesConstraint {
     #[inline]
-    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+    pub fn column(&self) -> Option<ColumnNameRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn role_ref(&self) -> Option<RoleRef> {
+    pub fn constraint_name_clause(&self) -> Option<ConstraintNameClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    pub fn constraint_options(&self) -> AstChildren<ConstraintOption> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn alter_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALTER_KW)
+    pub fn match_type(&self) -> Option<MatchType> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn create_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CREATE_KW)
+    pub fn on_delete_action(&self) -> Option<OnDeleteAction> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn delete_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DELETE_KW)
+    pub fn on_update_action(&self) -> Option<OnUpdateAction> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn execute_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EXECUTE_KW)
+    pub fn table(&self) -> Option<TableNameRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn insert_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INSERT_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
     pub fn references_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::REFERENCES_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReferencesTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ReferencesTable {
     #[inline]
-    pub fn select_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SELECT_KW)
-    }
-    #[inline]
-    pub fn system_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SYSTEM_KW)
-    }
-    #[inline]
-    pub fn temp_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TEMP_KW)
-    }
-    #[inline]
-    pub fn temporary_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TEMPORARY_KW)
-    }
-    #[inline]
-    pub fn trigger_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRIGGER_KW)
+    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn truncate_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRUNCATE_KW)
+    pub fn vertex_table_ref(&self) -> Option<VertexTableRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn update_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
+    pub fn references_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REFERENCES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RevokeCommandList {
+pub struct Referencing {
     pub(crate) syntax: SyntaxNode,
 }
-impl RevokeCommandList {
+impl Referencing {
     #[inline]
-    pub fn revoke_commands(&self) -> AstChildren<RevokeCommand> {
+    pub fn referencing_tables(&self) -> AstChildren<ReferencingTable> {
         support::children(&self.syntax)
     }
+    #[inline]
+    pub fn referencing_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REFERENCING_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RevokeDefaultPrivileges {
+pub struct Refresh {
     pub(crate) syntax: SyntaxNode,
 }
-impl RevokeDefaultPrivileges {
-    #[inline]
-    pub fn drop_behavior(&self) -> Option<DropBehavior> {
-        support::child(&self.syntax)
-    }
+impl Refresh {
     #[inline]
-    pub fn grant_option_for(&self) -> Option<GrantOptionFor> {
+    pub fn data_option(&self) -> Option<DataOption> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn privilege_target(&self) -> Option<PrivilegeTarget> {
+    pub fn view_ref(&self) -> Option<ViewRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn privileges(&self) -> Option<Privileges> {
-        support::child(&self.syntax)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn role_ref_list(&self) -> Option<RoleRefList> {
-        support::child(&self.syntax)
+    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
     }
     #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
+    pub fn materialized_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::MATERIALIZED_KW)
     }
     #[inline]
-    pub fn on_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ON_KW)
+    pub fn refresh_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REFRESH_KW)
     }
     #[inline]
-    pub fn revoke_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REVOKE_KW)
+    pub fn view_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VIEW_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Role {
+pub struct RefreshCollationVersion {
     pub(crate) syntax: SyntaxNode,
 }
-impl Role {
-    #[inline]
-    pub fn current_role_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CURRENT_ROLE_KW)
-    }
-    #[inline]
-    pub fn current_user_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CURRENT_USER_KW)
-    }
+impl RefreshCollationVersion {
     #[inline]
-    pub fn group_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::GROUP_KW)
+    pub fn collation_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COLLATION_KW)
     }
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn refresh_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REFRESH_KW)
     }
     #[inline]
-    pub fn session_user_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SESSION_USER_KW)
+    pub fn version_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VERSION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionAdmin {
+pub struct RefreshPublication {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionAdmin {
+impl RefreshPublication {
     #[inline]
-    pub fn role_ref_list(&self) -> Option<RoleRefList> {
+    pub fn with_params(&self) -> Option<WithParams> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn admin_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ADMIN_KW)
+    pub fn publication_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PUBLICATION_KW)
+    }
+    #[inline]
+    pub fn refresh_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REFRESH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionConnectionLimit {
+pub struct RefreshVersion {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionConnectionLimit {
-    #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
-    }
+impl RefreshVersion {
     #[inline]
-    pub fn connection_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONNECTION_KW)
+    pub fn refresh_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REFRESH_KW)
     }
     #[inline]
-    pub fn limit_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LIMIT_KW)
+    pub fn version_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VERSION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionEncryptedPassword {
+pub struct Reindex {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionEncryptedPassword {
+impl Reindex {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn reindex_option_list(&self) -> Option<ReindexOptionList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn encrypted_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ENCRYPTED_KW)
+    pub fn reindex_target(&self) -> Option<ReindexTarget> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn password_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PASSWORD_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn reindex_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REINDEX_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionGeneric {
+pub struct ReindexOptionConcurrently {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionGeneric {
+impl ReindexOptionConcurrently {
+    #[inline]
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    }
     #[inline]
     pub fn ident_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::IDENT)
     }
+    #[inline]
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
+    }
+    #[inline]
+    pub fn yes_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::YES_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionInGroup {
+pub struct ReindexOptionList {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionInGroup {
+impl ReindexOptionList {
     #[inline]
-    pub fn role_ref_list(&self) -> Option<RoleRefList> {
-        support::child(&self.syntax)
+    pub fn reindex_options(&self) -> AstChildren<ReindexOption> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn group_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::GROUP_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn in_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IN_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionInRole {
+pub struct ReindexOptionTablespace {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionInRole {
+impl ReindexOptionTablespace {
     #[inline]
-    pub fn role_ref_list(&self) -> Option<RoleRefList> {
+    pub fn tablespace_ref(&self) -> Option<TablespaceRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn in_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IN_KW)
-    }
-    #[inline]
-    pub fn role_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROLE_KW)
+    pub fn tablespace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLESPACE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionInherit {
+pub struct ReindexOptionVerbose {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionInherit {
+impl ReindexOptionVerbose {
     #[inline]
-    pub fn inherit_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INHERIT_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionList {
-    pub(crate) syntax: SyntaxNode,
-}
-impl RoleOptionList {
     #[inline]
-    pub fn role_options(&self) -> AstChildren<RoleOption> {
-        support::children(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
+    }
+    #[inline]
+    pub fn verbose_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VERBOSE_KW)
+    }
+    #[inline]
+    pub fn yes_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::YES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionPassword {
+pub struct ReindexTargetDatabase {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionPassword {
+impl ReindexTargetDatabase {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn database_ref(&self) -> Option<DatabaseRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn null_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
     }
     #[inline]
-    pub fn password_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PASSWORD_KW)
+    pub fn database_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DATABASE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionRole {
+pub struct ReindexTargetIndex {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionRole {
+impl ReindexTargetIndex {
     #[inline]
-    pub fn role_ref_list(&self) -> Option<RoleRefList> {
+    pub fn index_ref(&self) -> Option<IndexRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn role_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROLE_KW)
+    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    }
+    #[inline]
+    pub fn index_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INDEX_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionSysid {
+pub struct ReindexTargetSchema {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionSysid {
+impl ReindexTargetSchema {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn schema_ref(&self) -> Option<SchemaRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn sysid_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SYSID_KW)
+    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    }
+    #[inline]
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionUser {
+pub struct ReindexTargetSystem {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionUser {
+impl ReindexTargetSystem {
     #[inline]
-    pub fn role_ref_list(&self) -> Option<RoleRefList> {
+    pub fn database_ref(&self) -> Option<DatabaseRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn user_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USER_KW)
+    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
+    }
+    #[inline]
+    pub fn system_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SYSTEM_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleOptionValidUntil {
+pub struct ReindexTargetTable {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleOptionValidUntil {
+impl ReindexTargetTable {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn table_name_ref(&self) -> Option<TableNameRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn until_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNTIL_KW)
+    pub fn concurrently_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONCURRENTLY_KW)
     }
     #[inline]
-    pub fn valid_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VALID_KW)
+    pub fn table_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleRef {
+pub struct RelationFromItem {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleRef {
+impl RelationFromItem {
     #[inline]
-    pub fn current_role_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CURRENT_ROLE_KW)
+    pub fn alias(&self) -> Option<FromAlias> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn current_user_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CURRENT_USER_KW)
+    pub fn relation_name_ref(&self) -> Option<RelationNameRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn group_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::GROUP_KW)
+    pub fn tablesample_clause(&self) -> Option<TablesampleClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STAR)
     }
     #[inline]
-    pub fn session_user_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SESSION_USER_KW)
+    pub fn only_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ONLY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleRefList {
+pub struct RelationList {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleRefList {
+impl RelationList {
     #[inline]
-    pub fn role_refs(&self) -> AstChildren<RoleRef> {
+    pub fn relation_names(&self) -> AstChildren<RelationName> {
         support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoleRenameTo {
+pub struct RelationName {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoleRenameTo {
+impl RelationName {
     #[inline]
-    pub fn role(&self) -> Option<Role> {
+    pub fn relation_name_ref(&self) -> Option<RelationNameRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STAR)
+    }
+    #[inline]
+    pub fn only_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ONLY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RollbackPrepared {
+pub struct RelationNameRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl RollbackPrepared {
+impl RelationNameRef {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn path_ref(&self) -> Option<PathRef> {
         support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Relative {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Relative {
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
-    #[inline]
-    pub fn prepared_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PREPARED_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn rollback_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROLLBACK_KW)
+    pub fn relative_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RELATIVE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RollbackToSavepoint {
+pub struct ReleaseSavepoint {
     pub(crate) syntax: SyntaxNode,
 }
-impl RollbackToSavepoint {
+impl ReleaseSavepoint {
     #[inline]
     pub fn savepoint_ref(&self) -> Option<SavepointRef> {
         support::child(&self.syntax)
@@ -22280,88 +22325,73 @@ impl RollbackToSavepoint {
         support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn rollback_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROLLBACK_KW)
+    pub fn release_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RELEASE_KW)
     }
     #[inline]
     pub fn savepoint_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::SAVEPOINT_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RemoteTableNameRef {
+    pub(crate) syntax: SyntaxNode,
+}
+impl RemoteTableNameRef {
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
-    }
-    #[inline]
-    pub fn transaction_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
-    }
-    #[inline]
-    pub fn work_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WORK_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RollbackTransaction {
+pub struct RenameAttribute {
     pub(crate) syntax: SyntaxNode,
 }
-impl RollbackTransaction {
+impl RenameAttribute {
     #[inline]
-    pub fn chain_clause(&self) -> Option<ChainClause> {
+    pub fn composite_field(&self) -> Option<CompositeField> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn composite_field_ref(&self) -> Option<CompositeFieldRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn abort_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ABORT_KW)
+    pub fn drop_behavior(&self) -> Option<DropBehavior> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn rollback_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROLLBACK_KW)
+    pub fn attribute_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ATTRIBUTE_KW)
     }
     #[inline]
-    pub fn transaction_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn work_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WORK_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoutineName {
+pub struct RenameColumn {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoutineName {
+impl RenameColumn {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
+    pub fn column_name(&self) -> Option<ColumnName> {
         support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoutineNameRef {
-    pub(crate) syntax: SyntaxNode,
-}
-impl RoutineNameRef {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
+    pub fn column_name_ref(&self) -> Option<ColumnNameRef> {
         support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoutineRenameTo {
-    pub(crate) syntax: SyntaxNode,
-}
-impl RoutineRenameTo {
     #[inline]
-    pub fn routine_name(&self) -> Option<RoutineName> {
-        support::child(&self.syntax)
+    pub fn column_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COLUMN_KW)
     }
     #[inline]
     pub fn rename_token(&self) -> Option<SyntaxToken> {
@@ -22374,125 +22404,85 @@ impl RoutineRenameTo {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoutineSig {
+pub struct RenameConstraint {
     pub(crate) syntax: SyntaxNode,
 }
-impl RoutineSig {
+impl RenameConstraint {
     #[inline]
-    pub fn param_list(&self) -> Option<ParamList> {
+    pub fn constraint_name(&self) -> Option<ConstraintName> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn routine_name_ref(&self) -> Option<RoutineNameRef> {
+    pub fn constraint_name_ref(&self) -> Option<ConstraintNameRef> {
         support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RoutineSigList {
-    pub(crate) syntax: SyntaxNode,
-}
-impl RoutineSigList {
-    #[inline]
-    pub fn routine_sigs(&self) -> AstChildren<RoutineSig> {
-        support::children(&self.syntax)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Row {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Row {
     #[inline]
-    pub fn exprs(&self) -> AstChildren<Expr> {
-        support::children(&self.syntax)
+    pub fn constraint_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONSTRAINT_KW)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RowExclusive {
+pub struct RenameValue {
     pub(crate) syntax: SyntaxNode,
 }
-impl RowExclusive {
+impl RenameValue {
     #[inline]
-    pub fn exclusive_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EXCLUSIVE_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn row_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROW_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RowList {
-    pub(crate) syntax: SyntaxNode,
-}
-impl RowList {
     #[inline]
-    pub fn rows(&self) -> AstChildren<Row> {
-        support::children(&self.syntax)
+    pub fn value_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VALUE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RowShare {
+pub struct Repack {
     pub(crate) syntax: SyntaxNode,
 }
-impl RowShare {
+impl Repack {
     #[inline]
-    pub fn row_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROW_KW)
+    pub fn option_item_list(&self) -> Option<OptionItemList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn share_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SHARE_KW)
+    pub fn table_and_columns_list(&self) -> Option<TableAndColumnsList> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RowsFromArg {
-    pub(crate) syntax: SyntaxNode,
-}
-impl RowsFromArg {
     #[inline]
-    pub fn call_expr(&self) -> Option<CallExpr> {
+    pub fn using_index(&self) -> Option<UsingIndex> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn column_def_list(&self) -> Option<ColumnDefList> {
-        support::child(&self.syntax)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
+    pub fn repack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REPACK_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RowsFromItem {
+pub struct RepeatableClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl RowsFromItem {
-    #[inline]
-    pub fn alias(&self) -> Option<FromAlias> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn rows_from_args(&self) -> AstChildren<RowsFromArg> {
-        support::children(&self.syntax)
-    }
+impl RepeatableClause {
     #[inline]
-    pub fn with_ordinality(&self) -> Option<WithOrdinality> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -22504,638 +22494,722 @@ impl RowsFromItem {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
-    }
-    #[inline]
-    pub fn lateral_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LATERAL_KW)
-    }
-    #[inline]
-    pub fn rows_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROWS_KW)
+    pub fn repeatable_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REPEATABLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RowsFuncOption {
+pub struct RepeatableRead {
     pub(crate) syntax: SyntaxNode,
 }
-impl RowsFuncOption {
+impl RepeatableRead {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
+    pub fn isolation_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
     }
     #[inline]
-    pub fn rows_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROWS_KW)
+    pub fn level_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
+    }
+    #[inline]
+    pub fn read_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::READ_KW)
+    }
+    #[inline]
+    pub fn repeatable_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REPEATABLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Rule {
+pub struct ReplaceDictionary {
     pub(crate) syntax: SyntaxNode,
 }
-impl Rule {
+impl ReplaceDictionary {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn replace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REPLACE_KW)
+    }
+    #[inline]
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RuleDo {
+pub struct ReplicaIdentity {
     pub(crate) syntax: SyntaxNode,
 }
-impl RuleDo {
+impl ReplicaIdentity {
     #[inline]
-    pub fn rule_action(&self) -> Option<RuleAction> {
+    pub fn replica_identity_option(&self) -> Option<ReplicaIdentityOption> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn also_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALSO_KW)
-    }
-    #[inline]
-    pub fn do_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DO_KW)
+    pub fn identity_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENTITY_KW)
     }
     #[inline]
-    pub fn instead_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INSTEAD_KW)
+    pub fn replica_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REPLICA_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RuleEvent {
+pub struct ReplicaIdentityDefault {
     pub(crate) syntax: SyntaxNode,
 }
-impl RuleEvent {
-    #[inline]
-    pub fn delete_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DELETE_KW)
-    }
-    #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
-    }
-    #[inline]
-    pub fn insert_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INSERT_KW)
-    }
-    #[inline]
-    pub fn select_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SELECT_KW)
-    }
+impl ReplicaIdentityDefault {
     #[inline]
-    pub fn update_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RuleOn {
+pub struct ReplicaIdentityFull {
     pub(crate) syntax: SyntaxNode,
 }
-impl RuleOn {
-    #[inline]
-    pub fn relation_name_ref(&self) -> Option<RelationNameRef> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn rule_event(&self) -> Option<RuleEvent> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn where_clause(&self) -> Option<WhereClause> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn on_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ON_KW)
-    }
+impl ReplicaIdentityFull {
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn full_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FULL_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RuleRef {
+pub struct ReplicaIdentityNothing {
     pub(crate) syntax: SyntaxNode,
 }
-impl RuleRef {
+impl ReplicaIdentityNothing {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn nothing_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NOTHING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RuleRenameTo {
+pub struct RequiredAsAlias {
     pub(crate) syntax: SyntaxNode,
 }
-impl RuleRenameTo {
+impl RequiredAsAlias {
     #[inline]
-    pub fn rule(&self) -> Option<Rule> {
+    pub fn name(&self) -> Option<TableAlias> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
-    }
-    #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RuleStmtList {
+pub struct Reset {
     pub(crate) syntax: SyntaxNode,
 }
-impl RuleStmtList {
+impl Reset {
     #[inline]
-    pub fn rule_stmts(&self) -> AstChildren<RuleStmt> {
-        support::children(&self.syntax)
+    pub fn reset_target(&self) -> Option<ResetTarget> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn reset_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Savepoint {
+pub struct ResetConfigParam {
     pub(crate) syntax: SyntaxNode,
 }
-impl Savepoint {
+impl ResetConfigParam {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn config_parameter_ref(&self) -> Option<ConfigParameterRef> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
+    }
+    #[inline]
+    pub fn reset_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SavepointCreate {
+pub struct ResetFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl SavepointCreate {
+impl ResetFuncOption {
     #[inline]
-    pub fn savepoint(&self) -> Option<Savepoint> {
+    pub fn config_parameter_ref(&self) -> Option<ConfigParameterRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
     }
     #[inline]
-    pub fn savepoint_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SAVEPOINT_KW)
+    pub fn reset_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SavepointRef {
+pub struct ResetOptions {
     pub(crate) syntax: SyntaxNode,
 }
-impl SavepointRef {
+impl ResetOptions {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn attribute_list(&self) -> Option<AttributeList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn reset_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Schema {
+pub struct ResetRole {
     pub(crate) syntax: SyntaxNode,
 }
-impl Schema {
+impl ResetRole {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn reset_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESET_KW)
+    }
+    #[inline]
+    pub fn role_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SchemaRef {
+pub struct ResetSessionAuth {
     pub(crate) syntax: SyntaxNode,
 }
-impl SchemaRef {
+impl ResetSessionAuth {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn authorization_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AUTHORIZATION_KW)
+    }
+    #[inline]
+    pub fn reset_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESET_KW)
+    }
+    #[inline]
+    pub fn session_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SESSION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SchemaRenameTo {
+pub struct ResetTimeZone {
     pub(crate) syntax: SyntaxNode,
 }
-impl SchemaRenameTo {
+impl ResetTimeZone {
     #[inline]
-    pub fn schema(&self) -> Option<Schema> {
-        support::child(&self.syntax)
+    pub fn time_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIME_KW)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn zone_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ZONE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResetTransactionIsolation {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ResetTransactionIsolation {
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn isolation_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
+    }
+    #[inline]
+    pub fn level_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
+    }
+    #[inline]
+    pub fn transaction_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Scroll {
+pub struct RespectNulls {
     pub(crate) syntax: SyntaxNode,
 }
-impl Scroll {
+impl RespectNulls {
     #[inline]
-    pub fn scroll_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCROLL_KW)
+    pub fn nulls_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULLS_KW)
+    }
+    #[inline]
+    pub fn respect_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESPECT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SearchClause {
+pub struct Restart {
     pub(crate) syntax: SyntaxNode,
 }
-impl SearchClause {
+impl Restart {
     #[inline]
-    pub fn columns(&self) -> Option<SearchColumns> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn search_order(&self) -> Option<SearchOrder> {
-        support::child(&self.syntax)
+    pub fn restart_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESTART_KW)
     }
     #[inline]
-    pub fn set_column(&self) -> Option<SearchSetColumn> {
-        support::child(&self.syntax)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RestartIdentity {
+    pub(crate) syntax: SyntaxNode,
+}
+impl RestartIdentity {
     #[inline]
-    pub fn by_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::BY_KW)
+    pub fn identity_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENTITY_KW)
     }
     #[inline]
-    pub fn search_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEARCH_KW)
+    pub fn restart_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESTART_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SearchColumns {
+pub struct Restrict {
     pub(crate) syntax: SyntaxNode,
 }
-impl SearchColumns {
+impl Restrict {
     #[inline]
-    pub fn column_name_refs(&self) -> AstChildren<ColumnNameRef> {
-        support::children(&self.syntax)
+    pub fn restrict_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RESTRICT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SearchSetColumn {
+pub struct RetType {
     pub(crate) syntax: SyntaxNode,
 }
-impl SearchSetColumn {
+impl RetType {
     #[inline]
-    pub fn column_name_ref(&self) -> Option<ColumnNameRef> {
+    pub fn table_arg_list(&self) -> Option<TableArgList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn ty(&self) -> Option<Type> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn returns_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RETURNS_KW)
+    }
+    #[inline]
+    pub fn table_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SecurityDefinerFuncOption {
+pub struct ReturnFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl SecurityDefinerFuncOption {
+impl ReturnFuncOption {
     #[inline]
-    pub fn definer_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFINER_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn external_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EXTERNAL_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn security_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SECURITY_KW)
+    pub fn return_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RETURN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SecurityInvokerFuncOption {
+pub struct ReturningClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl SecurityInvokerFuncOption {
+impl ReturningClause {
     #[inline]
-    pub fn external_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EXTERNAL_KW)
+    pub fn returning_option_list(&self) -> Option<ReturningOptionList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn invoker_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INVOKER_KW)
+    pub fn target_list(&self) -> Option<TargetList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn security_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SECURITY_KW)
+    pub fn returning_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RETURNING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SecurityLabel {
+pub struct ReturningNew {
     pub(crate) syntax: SyntaxNode,
 }
-impl SecurityLabel {
+impl ReturningNew {
     #[inline]
-    pub fn for_provider(&self) -> Option<ForProvider> {
+    pub fn name(&self) -> Option<TableAlias> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
     }
     #[inline]
-    pub fn security_label_object(&self) -> Option<SecurityLabelObject> {
+    pub fn new_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NEW_KW)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReturningOld {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ReturningOld {
+    #[inline]
+    pub fn name(&self) -> Option<TableAlias> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
     }
     #[inline]
-    pub fn is_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IS_KW)
+    pub fn old_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OLD_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReturningOptionList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ReturningOptionList {
     #[inline]
-    pub fn label_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LABEL_KW)
+    pub fn returning_options(&self) -> AstChildren<ReturningOption> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn null_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn on_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ON_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn security_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SECURITY_KW)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SecurityLabelProvider {
+pub struct ReturnsNullOnNullInputFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl SecurityLabelProvider {
+impl ReturnsNullOnNullInputFuncOption {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn input_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INPUT_KW)
+    }
+    #[inline]
+    pub fn null_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    }
+    #[inline]
+    pub fn on_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ON_KW)
+    }
+    #[inline]
+    pub fn returns_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RETURNS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Select {
+pub struct Revoke {
     pub(crate) syntax: SyntaxNode,
 }
-impl Select {
+impl Revoke {
     #[inline]
-    pub fn fetch_clause(&self) -> Option<FetchClause> {
+    pub fn drop_behavior(&self) -> Option<DropBehavior> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn filter_clause(&self) -> Option<FilterClause> {
+    pub fn granted_by_clause(&self) -> Option<GrantedByClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn from_clause(&self) -> Option<FromClause> {
+    pub fn on_privilege_objects_clause(&self) -> Option<OnPrivilegeObjectsClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn group_by_clause(&self) -> Option<GroupByClause> {
+    pub fn privileges(&self) -> Option<Privileges> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn having_clause(&self) -> Option<HavingClause> {
+    pub fn revoke_option_for(&self) -> Option<RevokeOptionFor> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn limit_clause(&self) -> Option<LimitClause> {
+    pub fn role_ref_list(&self) -> Option<RoleRefList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn locking_clauses(&self) -> AstChildren<LockingClause> {
-        support::children(&self.syntax)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn offset_clause(&self) -> Option<OffsetClause> {
-        support::child(&self.syntax)
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
     #[inline]
-    pub fn order_by_clause(&self) -> Option<OrderByClause> {
-        support::child(&self.syntax)
+    pub fn revoke_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REVOKE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RevokeCommand {
+    pub(crate) syntax: SyntaxNode,
+}
+impl RevokeCommand {
     #[inline]
-    pub fn select_clause(&self) -> Option<SelectClause> {
+    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn where_clause(&self) -> Option<WhereClause> {
+    pub fn role_ref(&self) -> Option<RoleRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn window_clause(&self) -> Option<WindowClause> {
-        support::child(&self.syntax)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
     }
     #[inline]
-    pub fn with_clause(&self) -> Option<WithClause> {
-        support::child(&self.syntax)
+    pub fn alter_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALTER_KW)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn create_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CREATE_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SelectClause {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SelectClause {
     #[inline]
-    pub fn select_quantifier(&self) -> Option<SelectQuantifier> {
-        support::child(&self.syntax)
+    pub fn delete_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DELETE_KW)
     }
     #[inline]
-    pub fn target_list(&self) -> Option<TargetList> {
-        support::child(&self.syntax)
+    pub fn execute_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXECUTE_KW)
+    }
+    #[inline]
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
+    #[inline]
+    pub fn insert_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INSERT_KW)
+    }
+    #[inline]
+    pub fn references_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REFERENCES_KW)
     }
     #[inline]
     pub fn select_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::SELECT_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SelectInto {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SelectInto {
     #[inline]
-    pub fn filter_clause(&self) -> Option<FilterClause> {
-        support::child(&self.syntax)
+    pub fn system_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SYSTEM_KW)
     }
     #[inline]
-    pub fn from_clause(&self) -> Option<FromClause> {
-        support::child(&self.syntax)
+    pub fn temp_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TEMP_KW)
     }
     #[inline]
-    pub fn group_by_clause(&self) -> Option<GroupByClause> {
-        support::child(&self.syntax)
+    pub fn temporary_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TEMPORARY_KW)
     }
     #[inline]
-    pub fn having_clause(&self) -> Option<HavingClause> {
-        support::child(&self.syntax)
+    pub fn trigger_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRIGGER_KW)
     }
     #[inline]
-    pub fn into_clause(&self) -> Option<IntoClause> {
-        support::child(&self.syntax)
+    pub fn truncate_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRUNCATE_KW)
     }
     #[inline]
-    pub fn limit_clause(&self) -> Option<LimitClause> {
-        support::child(&self.syntax)
+    pub fn update_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RevokeCommandList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl RevokeCommandList {
     #[inline]
-    pub fn locking_clauses(&self) -> AstChildren<LockingClause> {
+    pub fn revoke_commands(&self) -> AstChildren<RevokeCommand> {
         support::children(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RevokeDefaultPrivileges {
+    pub(crate) syntax: SyntaxNode,
+}
+impl RevokeDefaultPrivileges {
     #[inline]
-    pub fn offset_clause(&self) -> Option<OffsetClause> {
+    pub fn drop_behavior(&self) -> Option<DropBehavior> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+    pub fn grant_option_for(&self) -> Option<GrantOptionFor> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn select_clause(&self) -> Option<SelectClause> {
+    pub fn privilege_target(&self) -> Option<PrivilegeTarget> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn where_clause(&self) -> Option<WhereClause> {
+    pub fn privileges(&self) -> Option<Privileges> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn window_clause(&self) -> Option<WindowClause> {
+    pub fn role_ref_list(&self) -> Option<RoleRefList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_clause(&self) -> Option<WithClause> {
-        support::child(&self.syntax)
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn on_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ON_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Sequence {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Sequence {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
-        support::child(&self.syntax)
+    pub fn revoke_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REVOKE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SequenceOptionList {
+pub struct Role {
     pub(crate) syntax: SyntaxNode,
 }
-impl SequenceOptionList {
+impl Role {
     #[inline]
-    pub fn sequence_options(&self) -> AstChildren<SequenceOption> {
-        support::children(&self.syntax)
+    pub fn current_role_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURRENT_ROLE_KW)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn current_user_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURRENT_USER_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn group_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GROUP_KW)
+    }
+    #[inline]
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
+    #[inline]
+    pub fn session_user_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SESSION_USER_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SequenceRef {
+pub struct RoleOptionAdmin {
     pub(crate) syntax: SyntaxNode,
 }
-impl SequenceRef {
+impl RoleOptionAdmin {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
+    pub fn role_ref_list(&self) -> Option<RoleRefList> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn admin_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ADMIN_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SequenceRenameTo {
+pub struct RoleOptionConnectionLimit {
     pub(crate) syntax: SyntaxNode,
 }
-impl SequenceRenameTo {
+impl RoleOptionConnectionLimit {
     #[inline]
-    pub fn sequence(&self) -> Option<Sequence> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn connection_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONNECTION_KW)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn limit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LIMIT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Serializable {
+pub struct RoleOptionEncryptedPassword {
     pub(crate) syntax: SyntaxNode,
 }
-impl Serializable {
+impl RoleOptionEncryptedPassword {
     #[inline]
-    pub fn isolation_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn level_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
+    pub fn encrypted_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ENCRYPTED_KW)
     }
     #[inline]
-    pub fn serializable_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SERIALIZABLE_KW)
+    pub fn password_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PASSWORD_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Server {
+pub struct RoleOptionGeneric {
     pub(crate) syntax: SyntaxNode,
 }
-impl Server {
+impl RoleOptionGeneric {
     #[inline]
     pub fn ident_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::IDENT)
@@ -23143,410 +23217,459 @@ impl Server {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ServerClause {
+pub struct RoleOptionInGroup {
     pub(crate) syntax: SyntaxNode,
 }
-impl ServerClause {
+impl RoleOptionInGroup {
     #[inline]
-    pub fn server_ref(&self) -> Option<ServerRef> {
+    pub fn role_ref_list(&self) -> Option<RoleRefList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn server_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SERVER_KW)
+    pub fn group_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GROUP_KW)
+    }
+    #[inline]
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ServerRef {
+pub struct RoleOptionInRole {
     pub(crate) syntax: SyntaxNode,
 }
-impl ServerRef {
+impl RoleOptionInRole {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn role_ref_list(&self) -> Option<RoleRefList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IN_KW)
+    }
+    #[inline]
+    pub fn role_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ServerRenameTo {
+pub struct RoleOptionInherit {
     pub(crate) syntax: SyntaxNode,
 }
-impl ServerRenameTo {
+impl RoleOptionInherit {
     #[inline]
-    pub fn server(&self) -> Option<Server> {
-        support::child(&self.syntax)
+    pub fn inherit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INHERIT_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoleOptionList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl RoleOptionList {
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn role_options(&self) -> AstChildren<RoleOption> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ServerType {
+pub struct RoleOptionPassword {
     pub(crate) syntax: SyntaxNode,
 }
-impl ServerType {
+impl RoleOptionPassword {
     #[inline]
     pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn type_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TYPE_KW)
+    pub fn null_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    }
+    #[inline]
+    pub fn password_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PASSWORD_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ServerVersion {
+pub struct RoleOptionRole {
     pub(crate) syntax: SyntaxNode,
 }
-impl ServerVersion {
+impl RoleOptionRole {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn role_ref_list(&self) -> Option<RoleRefList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn version_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VERSION_KW)
+    pub fn role_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SessionAuthorization {
+pub struct RoleOptionSysid {
     pub(crate) syntax: SyntaxNode,
 }
-impl SessionAuthorization {
+impl RoleOptionSysid {
     #[inline]
-    pub fn authorization_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AUTHORIZATION_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn session_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SESSION_KW)
+    pub fn sysid_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SYSID_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SessionCharacteristics {
+pub struct RoleOptionUser {
     pub(crate) syntax: SyntaxNode,
 }
-impl SessionCharacteristics {
+impl RoleOptionUser {
     #[inline]
-    pub fn transaction_mode_list(&self) -> Option<TransactionModeList> {
+    pub fn role_ref_list(&self) -> Option<RoleRefList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
+    pub fn user_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USER_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoleOptionValidUntil {
+    pub(crate) syntax: SyntaxNode,
+}
+impl RoleOptionValidUntil {
     #[inline]
-    pub fn characteristics_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CHARACTERISTICS_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn session_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SESSION_KW)
+    pub fn until_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNTIL_KW)
     }
     #[inline]
-    pub fn transaction_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    pub fn valid_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VALID_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SessionScope {
+pub struct RoleRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl SessionScope {
+impl RoleRef {
     #[inline]
-    pub fn session_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SESSION_KW)
+    pub fn current_role_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURRENT_ROLE_KW)
+    }
+    #[inline]
+    pub fn current_user_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURRENT_USER_KW)
+    }
+    #[inline]
+    pub fn group_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GROUP_KW)
+    }
+    #[inline]
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
+    #[inline]
+    pub fn session_user_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SESSION_USER_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Set {
+pub struct RoleRefList {
     pub(crate) syntax: SyntaxNode,
 }
-impl Set {
+impl RoleRefList {
     #[inline]
-    pub fn set_scope(&self) -> Option<SetScope> {
-        support::child(&self.syntax)
+    pub fn role_refs(&self) -> AstChildren<RoleRef> {
+        support::children(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoleRenameTo {
+    pub(crate) syntax: SyntaxNode,
+}
+impl RoleRenameTo {
     #[inline]
-    pub fn set_target(&self) -> Option<SetTarget> {
+    pub fn role(&self) -> Option<Role> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetAccessMethod {
+pub struct RollbackPrepared {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetAccessMethod {
+impl RollbackPrepared {
     #[inline]
-    pub fn access_method_ref(&self) -> Option<AccessMethodRef> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn access_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ACCESS_KW)
-    }
-    #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn method_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::METHOD_KW)
+    pub fn prepared_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PREPARED_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn rollback_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROLLBACK_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetAllPublicationObjectList {
+pub struct RollbackToSavepoint {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetAllPublicationObjectList {
+impl RollbackToSavepoint {
     #[inline]
-    pub fn all_publication_objects(&self) -> AstChildren<AllPublicationObject> {
-        support::children(&self.syntax)
+    pub fn savepoint_ref(&self) -> Option<SavepointRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn except_table_clause(&self) -> Option<ExceptTableClause> {
-        support::child(&self.syntax)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn rollback_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROLLBACK_KW)
+    }
+    #[inline]
+    pub fn savepoint_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SAVEPOINT_KW)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
+    }
+    #[inline]
+    pub fn transaction_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    }
+    #[inline]
+    pub fn work_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WORK_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetCatalog {
+pub struct RollbackTransaction {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetCatalog {
+impl RollbackTransaction {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn chain_clause(&self) -> Option<ChainClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn catalog_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CATALOG_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn abort_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ABORT_KW)
+    }
+    #[inline]
+    pub fn rollback_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROLLBACK_KW)
+    }
+    #[inline]
+    pub fn transaction_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    }
+    #[inline]
+    pub fn work_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WORK_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetClause {
+pub struct RoutineName {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetClause {
+impl RoutineName {
     #[inline]
-    pub fn set_column_list(&self) -> Option<SetColumnList> {
+    pub fn path(&self) -> Option<Path> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetColumnList {
+pub struct RoutineNameRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetColumnList {
+impl RoutineNameRef {
     #[inline]
-    pub fn set_columns(&self) -> AstChildren<SetColumn> {
-        support::children(&self.syntax)
+    pub fn path_ref(&self) -> Option<PathRef> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetCompression {
+pub struct RoutineRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetCompression {
+impl RoutineRenameTo {
     #[inline]
-    pub fn compression_method_name(&self) -> Option<CompressionMethodName> {
+    pub fn routine_name(&self) -> Option<RoutineName> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn compression_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMPRESSION_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetConfig {
+pub struct RoutineSig {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetConfig {
+impl RoutineSig {
     #[inline]
-    pub fn config_assignment(&self) -> Option<ConfigAssignment> {
+    pub fn param_list(&self) -> Option<ParamList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn config_parameter_ref(&self) -> Option<ConfigParameterRef> {
+    pub fn routine_name_ref(&self) -> Option<RoutineNameRef> {
         support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetConfigParam {
+pub struct RoutineSigList {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetConfigParam {
-    #[inline]
-    pub fn config_assignment(&self) -> Option<ConfigAssignment> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn config_parameter_ref(&self) -> Option<ConfigParameterRef> {
-        support::child(&self.syntax)
-    }
+impl RoutineSigList {
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn routine_sigs(&self) -> AstChildren<RoutineSig> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetConstraints {
+pub struct Row {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetConstraints {
+impl Row {
     #[inline]
-    pub fn constraint_name_refs(&self) -> AstChildren<ConstraintNameRef> {
+    pub fn exprs(&self) -> AstChildren<Expr> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn constraint_timing(&self) -> Option<ConstraintTiming> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
-    #[inline]
-    pub fn all_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ALL_KW)
-    }
-    #[inline]
-    pub fn constraints_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONSTRAINTS_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetDefault {
+pub struct RowExclusive {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetDefault {
-    #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
+impl RowExclusive {
     #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    pub fn exclusive_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXCLUSIVE_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn row_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROW_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetDefaultColumns {
+pub struct RowList {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetDefaultColumns {
-    #[inline]
-    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
-    }
+impl RowList {
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn rows(&self) -> AstChildren<Row> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetExpr {
+pub struct RowShare {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetExpr {
+impl RowShare {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
+    pub fn row_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROW_KW)
     }
     #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    pub fn share_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SHARE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetExprList {
+pub struct RowsFromArg {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetExprList {
-    #[inline]
-    pub fn set_exprs(&self) -> AstChildren<SetExpr> {
-        support::children(&self.syntax)
-    }
+impl RowsFromArg {
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn call_expr(&self) -> Option<CallExpr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn column_def_list(&self) -> Option<ColumnDefList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn row_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROW_KW)
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetExpression {
+pub struct RowsFromItem {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetExpression {
+impl RowsFromItem {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn alias(&self) -> Option<FromAlias> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn rows_from_args(&self) -> AstChildren<RowsFromArg> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn with_ordinality(&self) -> Option<WithOrdinality> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -23558,344 +23681,370 @@ impl SetExpression {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
     #[inline]
-    pub fn expression_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EXPRESSION_KW)
+    pub fn lateral_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LATERAL_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn rows_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROWS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetForeignOption {
+pub struct RowsFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetForeignOption {
-    #[inline]
-    pub fn foreign_option_name(&self) -> Option<ForeignOptionName> {
-        support::child(&self.syntax)
-    }
+impl RowsFuncOption {
     #[inline]
     pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn rows_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROWS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetFuncOption {
+pub struct Rule {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetFuncOption {
+impl Rule {
     #[inline]
-    pub fn set_config_param(&self) -> Option<SetConfigParam> {
-        support::child(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetGenerated {
+pub struct RuleDo {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetGenerated {
+impl RuleDo {
     #[inline]
-    pub fn generated_when(&self) -> Option<GeneratedWhen> {
+    pub fn rule_action(&self) -> Option<RuleAction> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn generated_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::GENERATED_KW)
+    pub fn also_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALSO_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn do_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DO_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetGeneratedOptions {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SetGeneratedOptions {
     #[inline]
-    pub fn set_generated_options(&self) -> AstChildren<SetGeneratedOption> {
-        support::children(&self.syntax)
+    pub fn instead_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INSTEAD_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetLogged {
+pub struct RuleEvent {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetLogged {
+impl RuleEvent {
     #[inline]
-    pub fn logged_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LOGGED_KW)
+    pub fn delete_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DELETE_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
+    #[inline]
+    pub fn insert_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INSERT_KW)
+    }
+    #[inline]
+    pub fn select_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SELECT_KW)
+    }
+    #[inline]
+    pub fn update_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetMultipleColumns {
+pub struct RuleOn {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetMultipleColumns {
+impl RuleOn {
     #[inline]
-    pub fn column_target_list(&self) -> Option<ColumnTargetList> {
+    pub fn relation_name_ref(&self) -> Option<RelationNameRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn paren_select(&self) -> Option<ParenSelect> {
+    pub fn rule_event(&self) -> Option<RuleEvent> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_expr_list(&self) -> Option<SetExprList> {
+    pub fn where_clause(&self) -> Option<WhereClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn eq_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EQ)
+    pub fn on_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ON_KW)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetNotNull {
+pub struct RuleRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetNotNull {
-    #[inline]
-    pub fn not_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NOT_KW)
-    }
-    #[inline]
-    pub fn null_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NULL_KW)
-    }
+impl RuleRef {
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetNullColumns {
+pub struct RuleRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetNullColumns {
+impl RuleRenameTo {
     #[inline]
-    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+    pub fn rule(&self) -> Option<Rule> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn null_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetOptionFor {
+pub struct RuleStmtList {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetOptionFor {
+impl RuleStmtList {
     #[inline]
-    pub fn for_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FOR_KW)
+    pub fn rule_stmts(&self) -> AstChildren<RuleStmt> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn option_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OPTION_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetOptions {
+pub struct Savepoint {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetOptions {
-    #[inline]
-    pub fn attribute_list(&self) -> Option<AttributeList> {
-        support::child(&self.syntax)
-    }
+impl Savepoint {
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetOptionsList {
+pub struct SavepointCreate {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetOptionsList {
+impl SavepointCreate {
     #[inline]
-    pub fn alter_option_list(&self) -> Option<AlterOptionList> {
+    pub fn savepoint(&self) -> Option<Savepoint> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn options_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OPTIONS_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn savepoint_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SAVEPOINT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetPublication {
+pub struct SavepointRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetPublication {
+impl SavepointRef {
     #[inline]
-    pub fn publication_refs(&self) -> AstChildren<PublicationRef> {
-        support::children(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Schema {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Schema {
+    #[inline]
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchemaRef {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SchemaRef {
     #[inline]
-    pub fn with_params(&self) -> Option<WithParams> {
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchemaRenameTo {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SchemaRenameTo {
+    #[inline]
+    pub fn schema(&self) -> Option<Schema> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn publication_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PUBLICATION_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetPublicationObjects {
+pub struct Scroll {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetPublicationObjects {
-    #[inline]
-    pub fn publication_objects(&self) -> AstChildren<PublicationObject> {
-        support::children(&self.syntax)
-    }
+impl Scroll {
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn scroll_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCROLL_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetRole {
+pub struct SearchClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetRole {
+impl SearchClause {
     #[inline]
-    pub fn set_role_target(&self) -> Option<SetRoleTarget> {
+    pub fn columns(&self) -> Option<SearchColumns> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_scope(&self) -> Option<SetScope> {
+    pub fn search_order(&self) -> Option<SearchOrder> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn set_column(&self) -> Option<SearchSetColumn> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn role_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROLE_KW)
+    pub fn by_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::BY_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn search_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEARCH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetRoleNone {
+pub struct SearchColumns {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetRoleNone {
+impl SearchColumns {
     #[inline]
-    pub fn none_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NONE_KW)
+    pub fn column_name_refs(&self) -> AstChildren<ColumnNameRef> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetSchema {
+pub struct SearchSetColumn {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetSchema {
+impl SearchSetColumn {
     #[inline]
-    pub fn schema_ref(&self) -> Option<SchemaRef> {
+    pub fn column_name_ref(&self) -> Option<ColumnNameRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
-    }
-    #[inline]
     pub fn set_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetSchemaValue {
+pub struct SecurityDefinerFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetSchemaValue {
+impl SecurityDefinerFuncOption {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
+    pub fn definer_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFINER_KW)
     }
     #[inline]
-    pub fn schema_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
+    pub fn external_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXTERNAL_KW)
+    }
+    #[inline]
+    pub fn security_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SECURITY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetSequenceOption {
+pub struct SecurityInvokerFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetSequenceOption {
+impl SecurityInvokerFuncOption {
     #[inline]
-    pub fn sequence_option(&self) -> Option<SequenceOption> {
-        support::child(&self.syntax)
+    pub fn external_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXTERNAL_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn invoker_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INVOKER_KW)
+    }
+    #[inline]
+    pub fn security_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SECURITY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetSessionAuth {
+pub struct SecurityLabel {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetSessionAuth {
+impl SecurityLabel {
     #[inline]
-    pub fn set_scope(&self) -> Option<SetScope> {
+    pub fn for_provider(&self) -> Option<ForProvider> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_session_auth_target(&self) -> Option<SetSessionAuthTarget> {
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn security_label_object(&self) -> Option<SecurityLabelObject> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -23903,656 +24052,663 @@ impl SetSessionAuth {
         support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn authorization_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AUTHORIZATION_KW)
+    pub fn is_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IS_KW)
     }
     #[inline]
-    pub fn session_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SESSION_KW)
+    pub fn label_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LABEL_KW)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn null_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    }
+    #[inline]
+    pub fn on_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ON_KW)
+    }
+    #[inline]
+    pub fn security_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SECURITY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetSessionAuthDefault {
+pub struct SecurityLabelProvider {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetSessionAuthDefault {
+impl SecurityLabelProvider {
     #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetSingleColumn {
+pub struct Select {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetSingleColumn {
+impl Select {
     #[inline]
-    pub fn column_target(&self) -> Option<ColumnTarget> {
+    pub fn fetch_clause(&self) -> Option<FetchClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_expr(&self) -> Option<SetExpr> {
+    pub fn filter_clause(&self) -> Option<FilterClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn eq_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EQ)
+    pub fn from_clause(&self) -> Option<FromClause> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetStatistics {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SetStatistics {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn group_by_clause(&self) -> Option<GroupByClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    pub fn having_clause(&self) -> Option<HavingClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn limit_clause(&self) -> Option<LimitClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn statistics_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STATISTICS_KW)
+    pub fn locking_clauses(&self) -> AstChildren<LockingClause> {
+        support::children(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetStorage {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SetStorage {
     #[inline]
-    pub fn storage_mode(&self) -> Option<StorageMode> {
+    pub fn offset_clause(&self) -> Option<OffsetClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn storage_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STORAGE_KW)
+    pub fn plpgsql_into(&self) -> Option<PlpgsqlInto> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetTablespace {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SetTablespace {
     #[inline]
-    pub fn tablespace_ref(&self) -> Option<TablespaceRef> {
+    pub fn select_clause(&self) -> Option<SelectClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn tablespace_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLESPACE_KW)
+    pub fn window_clause(&self) -> Option<WindowClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn with_clause(&self) -> Option<WithClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetTimeZone {
+pub struct SelectClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetTimeZone {
+impl SelectClause {
     #[inline]
-    pub fn config_value(&self) -> Option<ConfigValue> {
+    pub fn plpgsql_into(&self) -> Option<PlpgsqlInto> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
-    }
-    #[inline]
-    pub fn local_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LOCAL_KW)
+    pub fn select_quantifier(&self) -> Option<SelectQuantifier> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn time_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIME_KW)
+    pub fn target_list(&self) -> Option<TargetList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn zone_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ZONE_KW)
+    pub fn select_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SELECT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetTransaction {
+pub struct SelectInto {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetTransaction {
+impl SelectInto {
     #[inline]
-    pub fn session_characteristics(&self) -> Option<SessionCharacteristics> {
+    pub fn filter_clause(&self) -> Option<FilterClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn transaction_modes(&self) -> Option<TransactionModes> {
+    pub fn from_clause(&self) -> Option<FromClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn transaction_snapshot(&self) -> Option<TransactionSnapshot> {
+    pub fn group_by_clause(&self) -> Option<GroupByClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn having_clause(&self) -> Option<HavingClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn into_clause(&self) -> Option<IntoClause> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetType {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SetType {
     #[inline]
-    pub fn collate(&self) -> Option<Collate> {
+    pub fn limit_clause(&self) -> Option<LimitClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn locking_clauses(&self) -> AstChildren<LockingClause> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn offset_clause(&self) -> Option<OffsetClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn ty(&self) -> Option<Type> {
+    pub fn order_by_clause(&self) -> Option<OrderByClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn data_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DATA_KW)
+    pub fn select_clause(&self) -> Option<SelectClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn type_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TYPE_KW)
+    pub fn window_clause(&self) -> Option<WindowClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn using_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USING_KW)
+    pub fn with_clause(&self) -> Option<WithClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetUnlogged {
+pub struct Sequence {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetUnlogged {
-    #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
-    }
+impl Sequence {
     #[inline]
-    pub fn unlogged_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNLOGGED_KW)
+    pub fn path(&self) -> Option<Path> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetWithoutCluster {
+pub struct SequenceOptionList {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetWithoutCluster {
+impl SequenceOptionList {
     #[inline]
-    pub fn cluster_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CLUSTER_KW)
+    pub fn sequence_options(&self) -> AstChildren<SequenceOption> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn without_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetWithoutOids {
+pub struct SequenceRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetWithoutOids {
+impl SequenceRef {
     #[inline]
-    pub fn oids_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OIDS_KW)
+    pub fn path_ref(&self) -> Option<PathRef> {
+        support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SequenceRenameTo {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SequenceRenameTo {
     #[inline]
-    pub fn set_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SET_KW)
+    pub fn sequence(&self) -> Option<Sequence> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn without_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetXmlOption {
+pub struct Serializable {
     pub(crate) syntax: SyntaxNode,
 }
-impl SetXmlOption {
+impl Serializable {
     #[inline]
-    pub fn xml_document_or_content(&self) -> Option<XmlDocumentOrContent> {
-        support::child(&self.syntax)
+    pub fn isolation_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
     }
     #[inline]
-    pub fn option_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OPTION_KW)
+    pub fn level_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
     }
     #[inline]
-    pub fn xml_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XML_KW)
+    pub fn serializable_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SERIALIZABLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Share {
+pub struct Server {
     pub(crate) syntax: SyntaxNode,
 }
-impl Share {
+impl Server {
     #[inline]
-    pub fn share_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SHARE_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ShareRowExclusive {
+pub struct ServerClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl ShareRowExclusive {
-    #[inline]
-    pub fn exclusive_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EXCLUSIVE_KW)
-    }
+impl ServerClause {
     #[inline]
-    pub fn row_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROW_KW)
+    pub fn server_ref(&self) -> Option<ServerRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn share_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SHARE_KW)
+    pub fn server_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SERVER_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ShareUpdateExclusive {
+pub struct ServerRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl ShareUpdateExclusive {
-    #[inline]
-    pub fn exclusive_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EXCLUSIVE_KW)
-    }
-    #[inline]
-    pub fn share_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SHARE_KW)
-    }
+impl ServerRef {
     #[inline]
-    pub fn update_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Show {
+pub struct ServerRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl Show {
+impl ServerRenameTo {
     #[inline]
-    pub fn show_action(&self) -> Option<ShowAction> {
+    pub fn server(&self) -> Option<Server> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn show_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SHOW_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SimilarTo {
+pub struct ServerType {
     pub(crate) syntax: SyntaxNode,
 }
-impl SimilarTo {
+impl ServerType {
     #[inline]
-    pub fn similar_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SIMILAR_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn type_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TYPE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SkipLocked {
+pub struct ServerVersion {
     pub(crate) syntax: SyntaxNode,
 }
-impl SkipLocked {
+impl ServerVersion {
     #[inline]
-    pub fn locked_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LOCKED_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn skip_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SKIP_KW)
+    pub fn version_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VERSION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SkipSubscription {
+pub struct SessionAuthorization {
     pub(crate) syntax: SyntaxNode,
 }
-impl SkipSubscription {
+impl SessionAuthorization {
     #[inline]
-    pub fn attribute_list(&self) -> Option<AttributeList> {
-        support::child(&self.syntax)
+    pub fn authorization_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AUTHORIZATION_KW)
     }
     #[inline]
-    pub fn skip_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SKIP_KW)
+    pub fn session_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SESSION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SliceAccessor {
+pub struct SessionCharacteristics {
     pub(crate) syntax: SyntaxNode,
 }
-impl SliceAccessor {
+impl SessionCharacteristics {
     #[inline]
-    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_BRACK)
+    pub fn transaction_mode_list(&self) -> Option<TransactionModeList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_BRACK)
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
     }
     #[inline]
-    pub fn colon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COLON)
+    pub fn characteristics_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CHARACTERISTICS_KW)
+    }
+    #[inline]
+    pub fn session_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SESSION_KW)
+    }
+    #[inline]
+    pub fn transaction_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SliceExpr {
+pub struct SessionScope {
     pub(crate) syntax: SyntaxNode,
 }
-impl SliceExpr {
+impl SessionScope {
     #[inline]
-    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_BRACK)
+    pub fn session_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SESSION_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Set {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Set {
     #[inline]
-    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_BRACK)
+    pub fn set_scope(&self) -> Option<SetScope> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn colon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COLON)
+    pub fn set_target(&self) -> Option<SetTarget> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SomeFn {
+pub struct SetAccessMethod {
     pub(crate) syntax: SyntaxNode,
 }
-impl SomeFn {
+impl SetAccessMethod {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn access_method_ref(&self) -> Option<AccessMethodRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn select_variant(&self) -> Option<SelectVariant> {
-        support::child(&self.syntax)
+    pub fn access_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ACCESS_KW)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn method_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::METHOD_KW)
     }
     #[inline]
-    pub fn some_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SOME_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SortAsc {
+pub struct SetAllPublicationObjectList {
     pub(crate) syntax: SyntaxNode,
 }
-impl SortAsc {
+impl SetAllPublicationObjectList {
     #[inline]
-    pub fn asc_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ASC_KW)
+    pub fn all_publication_objects(&self) -> AstChildren<AllPublicationObject> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn except_table_clause(&self) -> Option<ExceptTableClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SortBy {
+pub struct SetCatalog {
     pub(crate) syntax: SyntaxNode,
 }
-impl SortBy {
-    #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
+impl SetCatalog {
     #[inline]
-    pub fn nulls_order(&self) -> Option<NullsOrder> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn sort_order(&self) -> Option<SortOrder> {
-        support::child(&self.syntax)
+    pub fn catalog_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CATALOG_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SortByList {
+pub struct SetClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl SortByList {
+impl SetClause {
     #[inline]
-    pub fn sort_bys(&self) -> AstChildren<SortBy> {
-        support::children(&self.syntax)
+    pub fn set_column_list(&self) -> Option<SetColumnList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SortDesc {
+pub struct SetColumnList {
     pub(crate) syntax: SyntaxNode,
 }
-impl SortDesc {
+impl SetColumnList {
     #[inline]
-    pub fn desc_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DESC_KW)
+    pub fn set_columns(&self) -> AstChildren<SetColumn> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SortUsing {
+pub struct SetCompression {
     pub(crate) syntax: SyntaxNode,
 }
-impl SortUsing {
+impl SetCompression {
     #[inline]
-    pub fn op(&self) -> Option<Op> {
+    pub fn compression_method_name(&self) -> Option<CompressionMethodName> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn using_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USING_KW)
+    pub fn compression_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMPRESSION_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SourceFile {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SourceFile {
     #[inline]
-    pub fn stmts(&self) -> AstChildren<Stmt> {
-        support::children(&self.syntax)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SourceVertexTable {
+pub struct SetConfig {
     pub(crate) syntax: SyntaxNode,
 }
-impl SourceVertexTable {
+impl SetConfig {
     #[inline]
-    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+    pub fn config_assignment(&self) -> Option<ConfigAssignment> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn references_table(&self) -> Option<ReferencesTable> {
+    pub fn config_parameter_ref(&self) -> Option<ConfigParameterRef> {
         support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SetConfigParam {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SetConfigParam {
     #[inline]
-    pub fn vertex_table_ref(&self) -> Option<VertexTableRef> {
+    pub fn config_assignment(&self) -> Option<ConfigAssignment> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn key_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::KEY_KW)
+    pub fn config_parameter_ref(&self) -> Option<ConfigParameterRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn source_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SOURCE_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SplitPartition {
+pub struct SetConstraints {
     pub(crate) syntax: SyntaxNode,
 }
-impl SplitPartition {
+impl SetConstraints {
     #[inline]
-    pub fn partition_list(&self) -> Option<PartitionList> {
+    pub fn constraint_name_refs(&self) -> AstChildren<ConstraintNameRef> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn constraint_timing(&self) -> Option<ConstraintTiming> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn into_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INTO_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn partition_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PARTITION_KW)
+    pub fn all_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ALL_KW)
     }
     #[inline]
-    pub fn split_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SPLIT_KW)
+    pub fn constraints_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONSTRAINTS_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Stable {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Stable {
     #[inline]
-    pub fn stable_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STABLE_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StandaloneNo {
+pub struct SetDefault {
     pub(crate) syntax: SyntaxNode,
 }
-impl StandaloneNo {
+impl SetDefault {
     #[inline]
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMA)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn no_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NO_KW)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
     #[inline]
-    pub fn standalone_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STANDALONE_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StandaloneNoValue {
+pub struct SetDefaultColumns {
     pub(crate) syntax: SyntaxNode,
 }
-impl StandaloneNoValue {
-    #[inline]
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMA)
-    }
+impl SetDefaultColumns {
     #[inline]
-    pub fn no_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NO_KW)
+    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn standalone_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STANDALONE_KW)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
     #[inline]
-    pub fn value_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VALUE_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StandaloneYes {
+pub struct SetExpr {
     pub(crate) syntax: SyntaxNode,
 }
-impl StandaloneYes {
-    #[inline]
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMA)
-    }
+impl SetExpr {
     #[inline]
-    pub fn standalone_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STANDALONE_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn yes_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::YES_KW)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StatTypes {
+pub struct SetExprList {
     pub(crate) syntax: SyntaxNode,
 }
-impl StatTypes {
+impl SetExprList {
     #[inline]
-    pub fn statistics_kinds(&self) -> AstChildren<StatisticsKind> {
+    pub fn set_exprs(&self) -> AstChildren<SetExpr> {
         support::children(&self.syntax)
     }
     #[inline]
@@ -24563,335 +24719,281 @@ impl StatTypes {
     pub fn r_paren_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
+    #[inline]
+    pub fn row_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROW_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Statistics {
+pub struct SetExpression {
     pub(crate) syntax: SyntaxNode,
 }
-impl Statistics {
+impl SetExpression {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StatisticsKind {
-    pub(crate) syntax: SyntaxNode,
-}
-impl StatisticsKind {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StatisticsOnClause {
-    pub(crate) syntax: SyntaxNode,
-}
-impl StatisticsOnClause {
     #[inline]
-    pub fn exprs(&self) -> AstChildren<Expr> {
-        support::children(&self.syntax)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn on_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ON_KW)
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StatisticsRef {
-    pub(crate) syntax: SyntaxNode,
-}
-impl StatisticsRef {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
-        support::child(&self.syntax)
+    pub fn expression_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXPRESSION_KW)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StatisticsRenameTo {
+pub struct SetForeignOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl StatisticsRenameTo {
+impl SetForeignOption {
     #[inline]
-    pub fn statistics(&self) -> Option<Statistics> {
+    pub fn foreign_option_name(&self) -> Option<ForeignOptionName> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Storage {
+pub struct SetFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl Storage {
+impl SetFuncOption {
     #[inline]
-    pub fn storage_mode(&self) -> Option<StorageMode> {
+    pub fn set_config_param(&self) -> Option<SetConfigParam> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn storage_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STORAGE_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StorageMode {
+pub struct SetGenerated {
     pub(crate) syntax: SyntaxNode,
 }
-impl StorageMode {
+impl SetGenerated {
     #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    pub fn generated_when(&self) -> Option<GeneratedWhen> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn external_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EXTERNAL_KW)
+    pub fn generated_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GENERATED_KW)
     }
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Stored {
+pub struct SetGeneratedOptions {
     pub(crate) syntax: SyntaxNode,
 }
-impl Stored {
+impl SetGeneratedOptions {
     #[inline]
-    pub fn stored_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STORED_KW)
+    pub fn set_generated_options(&self) -> AstChildren<SetGeneratedOption> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StrictFuncOption {
+pub struct SetLogged {
     pub(crate) syntax: SyntaxNode,
 }
-impl StrictFuncOption {
+impl SetLogged {
     #[inline]
-    pub fn strict_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STRICT_KW)
+    pub fn logged_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOGGED_KW)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StripWhitespace {
+pub struct SetMultipleColumns {
     pub(crate) syntax: SyntaxNode,
 }
-impl StripWhitespace {
+impl SetMultipleColumns {
     #[inline]
-    pub fn strip_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STRIP_KW)
+    pub fn column_target_list(&self) -> Option<ColumnTargetList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn whitespace_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WHITESPACE_KW)
+    pub fn paren_select(&self) -> Option<ParenSelect> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Subscription {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Subscription {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn set_expr_list(&self) -> Option<SetExprList> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SubscriptionRef {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SubscriptionRef {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EQ)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SubscriptionRenameTo {
+pub struct SetNotNull {
     pub(crate) syntax: SyntaxNode,
 }
-impl SubscriptionRenameTo {
+impl SetNotNull {
     #[inline]
-    pub fn subscription(&self) -> Option<Subscription> {
-        support::child(&self.syntax)
+    pub fn not_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NOT_KW)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn null_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULL_KW)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SubstringExprs {
+pub struct SetNullColumns {
     pub(crate) syntax: SyntaxNode,
 }
-impl SubstringExprs {
+impl SetNullColumns {
     #[inline]
-    pub fn exprs(&self) -> AstChildren<Expr> {
-        support::children(&self.syntax)
+    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn null_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SubstringFn {
+pub struct SetOptionFor {
     pub(crate) syntax: SyntaxNode,
 }
-impl SubstringFn {
-    #[inline]
-    pub fn substring_args(&self) -> Option<SubstringArgs> {
-        support::child(&self.syntax)
-    }
+impl SetOptionFor {
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn option_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OPTION_KW)
     }
     #[inline]
-    pub fn substring_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SUBSTRING_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SubstringForFrom {
+pub struct SetOptions {
     pub(crate) syntax: SyntaxNode,
 }
-impl SubstringForFrom {
+impl SetOptions {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn attribute_list(&self) -> Option<AttributeList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn for_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FOR_KW)
-    }
-    #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SubstringFromFor {
+pub struct SetOptionsList {
     pub(crate) syntax: SyntaxNode,
 }
-impl SubstringFromFor {
+impl SetOptionsList {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn alter_option_list(&self) -> Option<AlterOptionList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn for_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FOR_KW)
+    pub fn options_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OPTIONS_KW)
     }
     #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SubstringSimilarEscape {
+pub struct SetPublication {
     pub(crate) syntax: SyntaxNode,
 }
-impl SubstringSimilarEscape {
+impl SetPublication {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn publication_refs(&self) -> AstChildren<PublicationRef> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn with_params(&self) -> Option<WithParams> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn escape_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ESCAPE_KW)
+    pub fn publication_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PUBLICATION_KW)
     }
     #[inline]
-    pub fn similar_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SIMILAR_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SupportFuncOption {
+pub struct SetPublicationObjects {
     pub(crate) syntax: SyntaxNode,
 }
-impl SupportFuncOption {
-    #[inline]
-    pub fn function_name_ref(&self) -> Option<FunctionNameRef> {
-        support::child(&self.syntax)
-    }
+impl SetPublicationObjects {
     #[inline]
-    pub fn support_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SUPPORT_KW)
+    pub fn publication_objects(&self) -> AstChildren<PublicationObject> {
+        support::children(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Symmetric {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Symmetric {
     #[inline]
-    pub fn symmetric_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SYMMETRIC_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Table {
+pub struct SetRole {
     pub(crate) syntax: SyntaxNode,
 }
-impl Table {
-    #[inline]
-    pub fn fetch_clause(&self) -> Option<FetchClause> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn limit_clause(&self) -> Option<LimitClause> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn locking_clauses(&self) -> AstChildren<LockingClause> {
-        support::children(&self.syntax)
-    }
-    #[inline]
-    pub fn offset_clause(&self) -> Option<OffsetClause> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn order_by_clause(&self) -> Option<OrderByClause> {
-        support::child(&self.syntax)
-    }
+impl SetRole {
     #[inline]
-    pub fn relation_name(&self) -> Option<RelationName> {
+    pub fn set_role_target(&self) -> Option<SetRoleTarget> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_clause(&self) -> Option<WithClause> {
+    pub fn set_scope(&self) -> Option<SetScope> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -24899,262 +25001,258 @@ impl Table {
         support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn table_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLE_KW)
+    pub fn role_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROLE_KW)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableAlias {
+pub struct SetRoleNone {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableAlias {
+impl SetRoleNone {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn none_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NONE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableAndColumns {
+pub struct SetSchema {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableAndColumns {
+impl SetSchema {
     #[inline]
-    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+    pub fn schema_ref(&self) -> Option<SchemaRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn table_relation_name(&self) -> Option<TableRelationName> {
-        support::child(&self.syntax)
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableAndColumnsList {
-    pub(crate) syntax: SyntaxNode,
-}
-impl TableAndColumnsList {
     #[inline]
-    pub fn table_and_columnss(&self) -> AstChildren<TableAndColumns> {
-        support::children(&self.syntax)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableArgList {
+pub struct SetSchemaValue {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableArgList {
-    #[inline]
-    pub fn args(&self) -> AstChildren<TableArg> {
-        support::children(&self.syntax)
-    }
+impl SetSchemaValue {
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn schema_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SCHEMA_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableList {
+pub struct SetSequenceOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableList {
+impl SetSequenceOption {
     #[inline]
-    pub fn table_relation_names(&self) -> AstChildren<TableRelationName> {
-        support::children(&self.syntax)
+    pub fn sequence_option(&self) -> Option<SequenceOption> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableName {
+pub struct SetSessionAuth {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableName {
+impl SetSessionAuth {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
+    pub fn set_scope(&self) -> Option<SetScope> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn set_session_auth_target(&self) -> Option<SetSessionAuthTarget> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn authorization_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AUTHORIZATION_KW)
+    }
+    #[inline]
+    pub fn session_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SESSION_KW)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableNameRef {
+pub struct SetSessionAuthDefault {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableNameRef {
+impl SetSessionAuthDefault {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
-        support::child(&self.syntax)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableNameRefList {
+pub struct SetSingleColumn {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableNameRefList {
+impl SetSingleColumn {
     #[inline]
-    pub fn table_name_refs(&self) -> AstChildren<TableNameRef> {
-        support::children(&self.syntax)
+    pub fn column_target(&self) -> Option<ColumnTarget> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn set_expr(&self) -> Option<SetExpr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EQ)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableRelationName {
+pub struct SetStatistics {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableRelationName {
+impl SetStatistics {
     #[inline]
-    pub fn table_name_ref(&self) -> Option<TableNameRef> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
     #[inline]
-    pub fn star_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STAR)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
     #[inline]
-    pub fn only_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ONLY_KW)
+    pub fn statistics_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STATISTICS_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TableRenameTo {
+pub struct SetStorage {
     pub(crate) syntax: SyntaxNode,
 }
-impl TableRenameTo {
+impl SetStorage {
     #[inline]
-    pub fn table_name(&self) -> Option<TableName> {
+    pub fn storage_mode(&self) -> Option<StorageMode> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn storage_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STORAGE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TablesampleClause {
+pub struct SetTablespace {
     pub(crate) syntax: SyntaxNode,
 }
-impl TablesampleClause {
+impl SetTablespace {
     #[inline]
-    pub fn call_expr(&self) -> Option<CallExpr> {
+    pub fn tablespace_ref(&self) -> Option<TablespaceRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn repeatable_clause(&self) -> Option<RepeatableClause> {
-        support::child(&self.syntax)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
     #[inline]
-    pub fn tablesample_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLESAMPLE_KW)
+    pub fn tablespace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLESPACE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Tablespace {
+pub struct SetTimeZone {
     pub(crate) syntax: SyntaxNode,
 }
-impl Tablespace {
+impl SetTimeZone {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn config_value(&self) -> Option<ConfigValue> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TablespaceClause {
-    pub(crate) syntax: SyntaxNode,
-}
-impl TablespaceClause {
     #[inline]
-    pub fn tablespace_ref(&self) -> Option<TablespaceRef> {
-        support::child(&self.syntax)
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
     }
     #[inline]
-    pub fn tablespace_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLESPACE_KW)
+    pub fn local_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOCAL_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TablespaceOwner {
-    pub(crate) syntax: SyntaxNode,
-}
-impl TablespaceOwner {
     #[inline]
-    pub fn role_ref(&self) -> Option<RoleRef> {
-        support::child(&self.syntax)
+    pub fn time_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIME_KW)
     }
     #[inline]
-    pub fn owner_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OWNER_KW)
+    pub fn zone_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ZONE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TablespaceRef {
+pub struct SetTransaction {
     pub(crate) syntax: SyntaxNode,
 }
-impl TablespaceRef {
+impl SetTransaction {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn session_characteristics(&self) -> Option<SessionCharacteristics> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TablespaceRenameTo {
-    pub(crate) syntax: SyntaxNode,
-}
-impl TablespaceRenameTo {
     #[inline]
-    pub fn tablespace(&self) -> Option<Tablespace> {
+    pub fn transaction_modes(&self) -> Option<TransactionModes> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn transaction_snapshot(&self) -> Option<TransactionSnapshot> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Target {
+pub struct SetType {
     pub(crate) syntax: SyntaxNode,
 }
-impl Target {
+impl SetType {
     #[inline]
-    pub fn as_name(&self) -> Option<AsName> {
+    pub fn collate(&self) -> Option<Collate> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -25162,161 +25260,175 @@ impl Target {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn star_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STAR)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TargetList {
-    pub(crate) syntax: SyntaxNode,
-}
-impl TargetList {
-    #[inline]
-    pub fn targets(&self) -> AstChildren<Target> {
-        support::children(&self.syntax)
+    pub fn ty(&self) -> Option<Type> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Temp {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Temp {
     #[inline]
-    pub fn global_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::GLOBAL_KW)
+    pub fn data_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DATA_KW)
     }
     #[inline]
-    pub fn local_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LOCAL_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
     #[inline]
-    pub fn temp_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TEMP_KW)
+    pub fn type_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TYPE_KW)
     }
     #[inline]
-    pub fn temporary_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TEMPORARY_KW)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchConfiguration {
+pub struct SetUnlogged {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchConfiguration {
+impl SetUnlogged {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
-        support::child(&self.syntax)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
+    }
+    #[inline]
+    pub fn unlogged_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNLOGGED_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchConfigurationRef {
+pub struct SetWithoutCluster {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchConfigurationRef {
+impl SetWithoutCluster {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
-        support::child(&self.syntax)
+    pub fn cluster_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CLUSTER_KW)
+    }
+    #[inline]
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
+    }
+    #[inline]
+    pub fn without_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchConfigurationRenameTo {
+pub struct SetWithoutOids {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchConfigurationRenameTo {
+impl SetWithoutOids {
     #[inline]
-    pub fn text_search_configuration(&self) -> Option<TextSearchConfiguration> {
-        support::child(&self.syntax)
+    pub fn oids_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OIDS_KW)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn set_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SET_KW)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn without_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchDictionary {
+pub struct SetXmlOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchDictionary {
+impl SetXmlOption {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
+    pub fn xml_document_or_content(&self) -> Option<XmlDocumentOrContent> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn option_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OPTION_KW)
+    }
+    #[inline]
+    pub fn xml_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XML_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchDictionaryRef {
+pub struct Share {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchDictionaryRef {
+impl Share {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
-        support::child(&self.syntax)
+    pub fn share_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SHARE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchDictionaryRenameTo {
+pub struct ShareRowExclusive {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchDictionaryRenameTo {
+impl ShareRowExclusive {
     #[inline]
-    pub fn text_search_dictionary(&self) -> Option<TextSearchDictionary> {
-        support::child(&self.syntax)
+    pub fn exclusive_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXCLUSIVE_KW)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn row_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROW_KW)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn share_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SHARE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchParser {
+pub struct ShareUpdateExclusive {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchParser {
+impl ShareUpdateExclusive {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
-        support::child(&self.syntax)
+    pub fn exclusive_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXCLUSIVE_KW)
+    }
+    #[inline]
+    pub fn share_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SHARE_KW)
+    }
+    #[inline]
+    pub fn update_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchParserRef {
+pub struct Show {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchParserRef {
+impl Show {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
+    pub fn show_action(&self) -> Option<ShowAction> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn show_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SHOW_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchParserRenameTo {
+pub struct SimilarTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchParserRenameTo {
-    #[inline]
-    pub fn text_search_parser(&self) -> Option<TextSearchParser> {
-        support::child(&self.syntax)
-    }
+impl SimilarTo {
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn similar_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SIMILAR_KW)
     }
     #[inline]
     pub fn to_token(&self) -> Option<SyntaxToken> {
@@ -25325,79 +25437,84 @@ impl TextSearchParserRenameTo {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchTemplate {
+pub struct SkipLocked {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchTemplate {
+impl SkipLocked {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
-        support::child(&self.syntax)
+    pub fn locked_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOCKED_KW)
+    }
+    #[inline]
+    pub fn skip_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SKIP_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchTemplateRef {
+pub struct SkipSubscription {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchTemplateRef {
+impl SkipSubscription {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
+    pub fn attribute_list(&self) -> Option<AttributeList> {
         support::child(&self.syntax)
     }
+    #[inline]
+    pub fn skip_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SKIP_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchTemplateRenameTo {
+pub struct SliceAccessor {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchTemplateRenameTo {
+impl SliceAccessor {
     #[inline]
-    pub fn text_search_template(&self) -> Option<TextSearchTemplate> {
-        support::child(&self.syntax)
+    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_BRACK)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_BRACK)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn colon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COLON)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TextSearchTokenKind {
+pub struct SliceExpr {
     pub(crate) syntax: SyntaxNode,
 }
-impl TextSearchTokenKind {
+impl SliceExpr {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_BRACK)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Ties {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Ties {
     #[inline]
-    pub fn ties_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIES_KW)
+    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_BRACK)
+    }
+    #[inline]
+    pub fn colon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COLON)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TimeType {
+pub struct SomeFn {
     pub(crate) syntax: SyntaxNode,
 }
-impl TimeType {
+impl SomeFn {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn timezone(&self) -> Option<Timezone> {
+    pub fn select_variant(&self) -> Option<SelectVariant> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -25409,293 +25526,325 @@ impl TimeType {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn setof_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SETOF_KW)
-    }
-    #[inline]
-    pub fn time_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIME_KW)
+    pub fn some_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SOME_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TimeZone {
+pub struct SortAsc {
     pub(crate) syntax: SyntaxNode,
 }
-impl TimeZone {
-    #[inline]
-    pub fn time_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIME_KW)
-    }
+impl SortAsc {
     #[inline]
-    pub fn zone_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ZONE_KW)
+    pub fn asc_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ASC_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TimestampType {
+pub struct SortBy {
     pub(crate) syntax: SyntaxNode,
 }
-impl TimestampType {
+impl SortBy {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn timezone(&self) -> Option<Timezone> {
+    pub fn nulls_order(&self) -> Option<NullsOrder> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
-    }
-    #[inline]
-    pub fn setof_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SETOF_KW)
-    }
-    #[inline]
-    pub fn timestamp_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIMESTAMP_KW)
+    pub fn sort_order(&self) -> Option<SortOrder> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TimingAfter {
+pub struct SortByList {
     pub(crate) syntax: SyntaxNode,
 }
-impl TimingAfter {
+impl SortByList {
     #[inline]
-    pub fn after_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AFTER_KW)
+    pub fn sort_bys(&self) -> AstChildren<SortBy> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TimingBefore {
+pub struct SortDesc {
     pub(crate) syntax: SyntaxNode,
 }
-impl TimingBefore {
+impl SortDesc {
     #[inline]
-    pub fn before_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::BEFORE_KW)
+    pub fn desc_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DESC_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TimingInsteadOf {
+pub struct SortUsing {
     pub(crate) syntax: SyntaxNode,
 }
-impl TimingInsteadOf {
+impl SortUsing {
     #[inline]
-    pub fn instead_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INSTEAD_KW)
+    pub fn op(&self) -> Option<Op> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn of_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OF_KW)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ToConfigValue {
+pub struct SourceFile {
     pub(crate) syntax: SyntaxNode,
 }
-impl ToConfigValue {
+impl SourceFile {
     #[inline]
-    pub fn config_values(&self) -> AstChildren<ConfigValue> {
+    pub fn stmts(&self) -> AstChildren<Stmt> {
         support::children(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceVertexTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SourceVertexTable {
     #[inline]
-    pub fn eq_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::EQ)
+    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    pub fn references_table(&self) -> Option<ReferencesTable> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn null_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    pub fn vertex_table_ref(&self) -> Option<VertexTableRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn key_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::KEY_KW)
+    }
+    #[inline]
+    pub fn source_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SOURCE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransactionIsolationLevel {
+pub struct SplitPartition {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransactionIsolationLevel {
+impl SplitPartition {
     #[inline]
-    pub fn isolation_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
+    pub fn partition_list(&self) -> Option<PartitionList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn level_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
+    pub fn into_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INTO_KW)
     }
     #[inline]
-    pub fn transaction_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    pub fn partition_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PARTITION_KW)
+    }
+    #[inline]
+    pub fn split_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SPLIT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransactionModeList {
+pub struct Stable {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransactionModeList {
+impl Stable {
     #[inline]
-    pub fn transaction_modes(&self) -> AstChildren<TransactionMode> {
-        support::children(&self.syntax)
+    pub fn stable_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STABLE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransactionModes {
+pub struct StandaloneNo {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransactionModes {
+impl StandaloneNo {
     #[inline]
-    pub fn transaction_mode_list(&self) -> Option<TransactionModeList> {
-        support::child(&self.syntax)
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMA)
     }
     #[inline]
-    pub fn transaction_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
+    }
+    #[inline]
+    pub fn standalone_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STANDALONE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransactionSnapshot {
+pub struct StandaloneNoValue {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransactionSnapshot {
+impl StandaloneNoValue {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMA)
     }
     #[inline]
-    pub fn snapshot_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SNAPSHOT_KW)
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
     }
     #[inline]
-    pub fn transaction_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
+    pub fn standalone_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STANDALONE_KW)
+    }
+    #[inline]
+    pub fn value_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VALUE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransformForType {
+pub struct StandaloneYes {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransformForType {
+impl StandaloneYes {
     #[inline]
-    pub fn ty(&self) -> Option<Type> {
-        support::child(&self.syntax)
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMA)
     }
     #[inline]
-    pub fn for_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FOR_KW)
+    pub fn standalone_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STANDALONE_KW)
     }
     #[inline]
-    pub fn type_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TYPE_KW)
+    pub fn yes_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::YES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransformFromFunc {
+pub struct StatTypes {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransformFromFunc {
+impl StatTypes {
     #[inline]
-    pub fn function_sig(&self) -> Option<FunctionSig> {
-        support::child(&self.syntax)
+    pub fn statistics_kinds(&self) -> AstChildren<StatisticsKind> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn function_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Statistics {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Statistics {
     #[inline]
-    pub fn sql_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SQL_KW)
+    pub fn path(&self) -> Option<Path> {
+        support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatisticsKind {
+    pub(crate) syntax: SyntaxNode,
+}
+impl StatisticsKind {
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransformFuncOption {
+pub struct StatisticsOnClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransformFuncOption {
+impl StatisticsOnClause {
     #[inline]
-    pub fn transform_for_types(&self) -> AstChildren<TransformForType> {
+    pub fn exprs(&self) -> AstChildren<Expr> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn transform_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRANSFORM_KW)
+    pub fn on_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ON_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransformToFunc {
+pub struct StatisticsRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransformToFunc {
+impl StatisticsRef {
     #[inline]
-    pub fn function_sig(&self) -> Option<FunctionSig> {
+    pub fn path_ref(&self) -> Option<PathRef> {
         support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatisticsRenameTo {
+    pub(crate) syntax: SyntaxNode,
+}
+impl StatisticsRenameTo {
     #[inline]
-    pub fn function_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
+    pub fn statistics(&self) -> Option<Statistics> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn sql_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SQL_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
     pub fn to_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::TO_KW)
     }
-    #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TransitionRelationName {
+pub struct Storage {
     pub(crate) syntax: SyntaxNode,
 }
-impl TransitionRelationName {
+impl Storage {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn storage_mode(&self) -> Option<StorageMode> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn storage_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STORAGE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Trigger {
+pub struct StorageMode {
     pub(crate) syntax: SyntaxNode,
 }
-impl Trigger {
+impl StorageMode {
+    #[inline]
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    }
+    #[inline]
+    pub fn external_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EXTERNAL_KW)
+    }
     #[inline]
     pub fn ident_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::IDENT)
@@ -25703,73 +25852,58 @@ impl Trigger {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TriggerEventDelete {
+pub struct Stored {
     pub(crate) syntax: SyntaxNode,
 }
-impl TriggerEventDelete {
+impl Stored {
     #[inline]
-    pub fn delete_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DELETE_KW)
+    pub fn stored_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STORED_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TriggerEventInsert {
+pub struct StrictFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl TriggerEventInsert {
+impl StrictFuncOption {
     #[inline]
-    pub fn insert_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INSERT_KW)
+    pub fn strict_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STRICT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TriggerEventList {
+pub struct StripWhitespace {
     pub(crate) syntax: SyntaxNode,
 }
-impl TriggerEventList {
+impl StripWhitespace {
     #[inline]
-    pub fn trigger_events(&self) -> AstChildren<TriggerEvent> {
-        support::children(&self.syntax)
+    pub fn strip_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STRIP_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TriggerEventTruncate {
-    pub(crate) syntax: SyntaxNode,
-}
-impl TriggerEventTruncate {
     #[inline]
-    pub fn truncate_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRUNCATE_KW)
+    pub fn whitespace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHITESPACE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TriggerEventUpdate {
+pub struct Subscription {
     pub(crate) syntax: SyntaxNode,
 }
-impl TriggerEventUpdate {
-    #[inline]
-    pub fn column_name_refs(&self) -> AstChildren<ColumnNameRef> {
-        support::children(&self.syntax)
-    }
-    #[inline]
-    pub fn of_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OF_KW)
-    }
+impl Subscription {
     #[inline]
-    pub fn update_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TriggerRef {
+pub struct SubscriptionRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl TriggerRef {
+impl SubscriptionRef {
     #[inline]
     pub fn ident_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::IDENT)
@@ -25777,12 +25911,12 @@ impl TriggerRef {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TriggerRenameTo {
+pub struct SubscriptionRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl TriggerRenameTo {
+impl SubscriptionRenameTo {
     #[inline]
-    pub fn trigger(&self) -> Option<Trigger> {
+    pub fn subscription(&self) -> Option<Subscription> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -25796,125 +25930,153 @@ impl TriggerRenameTo {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TrimBoth {
+pub struct SubstringExprs {
     pub(crate) syntax: SyntaxNode,
 }
-impl TrimBoth {
+impl SubstringExprs {
     #[inline]
-    pub fn both_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::BOTH_KW)
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TrimExprFrom {
+pub struct SubstringFn {
     pub(crate) syntax: SyntaxNode,
 }
-impl TrimExprFrom {
+impl SubstringFn {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn substring_args(&self) -> Option<SubstringArgs> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn exprs(&self) -> AstChildren<Expr> {
-        support::children(&self.syntax)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn substring_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SUBSTRING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TrimExprs {
+pub struct SubstringForFrom {
     pub(crate) syntax: SyntaxNode,
 }
-impl TrimExprs {
+impl SubstringForFrom {
     #[inline]
-    pub fn exprs(&self) -> AstChildren<Expr> {
-        support::children(&self.syntax)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
+    }
+    #[inline]
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TrimFn {
+pub struct SubstringFromFor {
     pub(crate) syntax: SyntaxNode,
 }
-impl TrimFn {
-    #[inline]
-    pub fn trim_args(&self) -> Option<TrimArgs> {
-        support::child(&self.syntax)
-    }
+impl SubstringFromFor {
     #[inline]
-    pub fn trim_side(&self) -> Option<TrimSide> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
     }
     #[inline]
-    pub fn trim_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRIM_KW)
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TrimFrom {
+pub struct SubstringSimilarEscape {
     pub(crate) syntax: SyntaxNode,
 }
-impl TrimFrom {
+impl SubstringSimilarEscape {
     #[inline]
-    pub fn exprs(&self) -> AstChildren<Expr> {
-        support::children(&self.syntax)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn from_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FROM_KW)
+    pub fn escape_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ESCAPE_KW)
+    }
+    #[inline]
+    pub fn similar_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SIMILAR_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TrimLeading {
+pub struct SupportFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl TrimLeading {
+impl SupportFuncOption {
     #[inline]
-    pub fn leading_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LEADING_KW)
+    pub fn function_name_ref(&self) -> Option<FunctionNameRef> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn support_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SUPPORT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TrimTrailing {
+pub struct Symmetric {
     pub(crate) syntax: SyntaxNode,
 }
-impl TrimTrailing {
+impl Symmetric {
     #[inline]
-    pub fn trailing_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRAILING_KW)
+    pub fn symmetric_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SYMMETRIC_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Truncate {
+pub struct Table {
     pub(crate) syntax: SyntaxNode,
 }
-impl Truncate {
+impl Table {
     #[inline]
-    pub fn drop_behavior(&self) -> Option<DropBehavior> {
+    pub fn fetch_clause(&self) -> Option<FetchClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn identity_action(&self) -> Option<IdentityAction> {
+    pub fn limit_clause(&self) -> Option<LimitClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn table_list(&self) -> Option<TableList> {
+    pub fn locking_clauses(&self) -> AstChildren<LockingClause> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn offset_clause(&self) -> Option<OffsetClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn relation_name(&self) -> Option<RelationName> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn with_clause(&self) -> Option<WithClause> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -25925,26 +26087,55 @@ impl Truncate {
     pub fn table_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::TABLE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableAlias {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TableAlias {
     #[inline]
-    pub fn truncate_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TRUNCATE_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TupleExpr {
+pub struct TableAndColumns {
     pub(crate) syntax: SyntaxNode,
 }
-impl TupleExpr {
+impl TableAndColumns {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn column_ref_list(&self) -> Option<ColumnRefList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn exprs(&self) -> AstChildren<Expr> {
-        support::children(&self.syntax)
+    pub fn table_relation_name(&self) -> Option<TableRelationName> {
+        support::child(&self.syntax)
     }
-    #[inline]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableAndColumnsList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TableAndColumnsList {
+    #[inline]
+    pub fn table_and_columnss(&self) -> AstChildren<TableAndColumns> {
+        support::children(&self.syntax)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableArgList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TableArgList {
+    #[inline]
+    pub fn args(&self) -> AstChildren<TableArg> {
+        support::children(&self.syntax)
+    }
+    #[inline]
     pub fn l_paren_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
@@ -25952,21 +26143,24 @@ impl TupleExpr {
     pub fn r_paren_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TableList {
     #[inline]
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMA)
-    }
-    #[inline]
-    pub fn row_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ROW_KW)
+    pub fn table_relation_names(&self) -> AstChildren<TableRelationName> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TypeName {
+pub struct TableName {
     pub(crate) syntax: SyntaxNode,
 }
-impl TypeName {
+impl TableName {
     #[inline]
     pub fn path(&self) -> Option<Path> {
         support::child(&self.syntax)
@@ -25974,10 +26168,10 @@ impl TypeName {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TypeNameRef {
+pub struct TableNameRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl TypeNameRef {
+impl TableNameRef {
     #[inline]
     pub fn path_ref(&self) -> Option<PathRef> {
         support::child(&self.syntax)
@@ -25985,212 +26179,247 @@ impl TypeNameRef {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TypeRenameTo {
+pub struct TableNameRefList {
     pub(crate) syntax: SyntaxNode,
 }
-impl TypeRenameTo {
+impl TableNameRefList {
     #[inline]
-    pub fn type_name(&self) -> Option<TypeName> {
-        support::child(&self.syntax)
+    pub fn table_name_refs(&self) -> AstChildren<TableNameRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UnboundedFollowing {
+pub struct TableRelationName {
     pub(crate) syntax: SyntaxNode,
 }
-impl UnboundedFollowing {
+impl TableRelationName {
     #[inline]
-    pub fn following_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FOLLOWING_KW)
+    pub fn table_name_ref(&self) -> Option<TableNameRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn unbounded_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNBOUNDED_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STAR)
+    }
+    #[inline]
+    pub fn only_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ONLY_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UnboundedPreceding {
+pub struct TableRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl UnboundedPreceding {
+impl TableRenameTo {
     #[inline]
-    pub fn preceding_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PRECEDING_KW)
+    pub fn table_name(&self) -> Option<TableName> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn unbounded_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNBOUNDED_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UnicodeNormalForm {
+pub struct TablesampleClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl UnicodeNormalForm {
+impl TablesampleClause {
     #[inline]
-    pub fn nfc_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NFC_KW)
+    pub fn call_expr(&self) -> Option<CallExpr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn nfd_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NFD_KW)
+    pub fn repeatable_clause(&self) -> Option<RepeatableClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn nfkc_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NFKC_KW)
+    pub fn tablesample_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLESAMPLE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tablespace {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Tablespace {
     #[inline]
-    pub fn nfkd_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NFKD_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Union {
+pub struct TablespaceClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl Union {
+impl TablespaceClause {
     #[inline]
-    pub fn all_or_distinct(&self) -> Option<AllOrDistinct> {
+    pub fn tablespace_ref(&self) -> Option<TablespaceRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn union_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNION_KW)
+    pub fn tablespace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLESPACE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UniqueConstraint {
+pub struct TablespaceOwner {
     pub(crate) syntax: SyntaxNode,
 }
-impl UniqueConstraint {
+impl TablespaceOwner {
     #[inline]
-    pub fn constraint_name_clause(&self) -> Option<ConstraintNameClause> {
+    pub fn role_ref(&self) -> Option<RoleRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn constraint_options(&self) -> AstChildren<ConstraintOption> {
-        support::children(&self.syntax)
+    pub fn owner_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OWNER_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TablespaceRef {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TablespaceRef {
     #[inline]
-    pub fn index_parameters(&self) -> Option<IndexParameters> {
-        support::child(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TablespaceRenameTo {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TablespaceRenameTo {
     #[inline]
-    pub fn using_index(&self) -> Option<UsingIndexName> {
+    pub fn tablespace(&self) -> Option<Tablespace> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn unique_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNIQUE_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Unlisten {
+pub struct Target {
     pub(crate) syntax: SyntaxNode,
 }
-impl Unlisten {
+impl Target {
     #[inline]
-    pub fn channel_ref(&self) -> Option<ChannelRef> {
+    pub fn as_name(&self) -> Option<AsName> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn star_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::STAR)
-    }
-    #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn unlisten_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNLISTEN_KW)
+    pub fn star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STAR)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Unlogged {
+pub struct TargetList {
     pub(crate) syntax: SyntaxNode,
 }
-impl Unlogged {
+impl TargetList {
     #[inline]
-    pub fn unlogged_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UNLOGGED_KW)
+    pub fn targets(&self) -> AstChildren<Target> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Update {
+pub struct Temp {
     pub(crate) syntax: SyntaxNode,
 }
-impl Update {
-    #[inline]
-    pub fn alias(&self) -> Option<OptionalAsAlias> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn for_portion_of(&self) -> Option<ForPortionOf> {
-        support::child(&self.syntax)
-    }
+impl Temp {
     #[inline]
-    pub fn from_clause(&self) -> Option<FromClause> {
-        support::child(&self.syntax)
+    pub fn global_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GLOBAL_KW)
     }
     #[inline]
-    pub fn relation_name(&self) -> Option<RelationName> {
-        support::child(&self.syntax)
+    pub fn local_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LOCAL_KW)
     }
     #[inline]
-    pub fn returning_clause(&self) -> Option<ReturningClause> {
-        support::child(&self.syntax)
+    pub fn temp_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TEMP_KW)
     }
     #[inline]
-    pub fn set_clause(&self) -> Option<SetClause> {
-        support::child(&self.syntax)
+    pub fn temporary_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TEMPORARY_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextSearchConfiguration {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TextSearchConfiguration {
     #[inline]
-    pub fn where_clause_or_current_of(&self) -> Option<WhereClauseOrCurrentOf> {
+    pub fn path(&self) -> Option<Path> {
         support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextSearchConfigurationRef {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TextSearchConfigurationRef {
     #[inline]
-    pub fn with_clause(&self) -> Option<WithClause> {
+    pub fn path_ref(&self) -> Option<PathRef> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
-    #[inline]
-    pub fn update_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UpdateTo {
+pub struct TextSearchConfigurationRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl UpdateTo {
+impl TextSearchConfigurationRenameTo {
     #[inline]
-    pub fn extension_version(&self) -> Option<ExtensionVersion> {
+    pub fn text_search_configuration(&self) -> Option<TextSearchConfiguration> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
     pub fn to_token(&self) -> Option<SyntaxToken> {
@@ -26199,192 +26428,162 @@ impl UpdateTo {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct User {
+pub struct TextSearchDictionary {
     pub(crate) syntax: SyntaxNode,
 }
-impl User {
+impl TextSearchDictionary {
     #[inline]
-    pub fn user_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USER_KW)
+    pub fn path(&self) -> Option<Path> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UsingClause {
+pub struct TextSearchDictionaryRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl UsingClause {
-    #[inline]
-    pub fn from_items(&self) -> AstChildren<FromItem> {
-        support::children(&self.syntax)
-    }
+impl TextSearchDictionaryRef {
     #[inline]
-    pub fn using_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USING_KW)
+    pub fn path_ref(&self) -> Option<PathRef> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UsingExprClause {
+pub struct TextSearchDictionaryRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl UsingExprClause {
+impl TextSearchDictionaryRenameTo {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn text_search_dictionary(&self) -> Option<TextSearchDictionary> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn using_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USING_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UsingIndex {
+pub struct TextSearchParser {
     pub(crate) syntax: SyntaxNode,
 }
-impl UsingIndex {
+impl TextSearchParser {
     #[inline]
-    pub fn index_ref(&self) -> Option<IndexRef> {
+    pub fn path(&self) -> Option<Path> {
         support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextSearchParserRef {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TextSearchParserRef {
     #[inline]
-    pub fn index_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INDEX_KW)
-    }
-    #[inline]
-    pub fn using_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USING_KW)
+    pub fn path_ref(&self) -> Option<PathRef> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UsingIndexName {
+pub struct TextSearchParserRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl UsingIndexName {
+impl TextSearchParserRenameTo {
     #[inline]
-    pub fn index_ref(&self) -> Option<IndexRef> {
+    pub fn text_search_parser(&self) -> Option<TextSearchParser> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn index_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INDEX_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn using_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USING_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UsingMethod {
+pub struct TextSearchTemplate {
     pub(crate) syntax: SyntaxNode,
 }
-impl UsingMethod {
+impl TextSearchTemplate {
     #[inline]
-    pub fn access_method_ref(&self) -> Option<AccessMethodRef> {
+    pub fn path(&self) -> Option<Path> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn using_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USING_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UsingOnClause {
+pub struct TextSearchTemplateRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl UsingOnClause {
-    #[inline]
-    pub fn from_item(&self) -> Option<FromItem> {
-        support::child(&self.syntax)
-    }
+impl TextSearchTemplateRef {
     #[inline]
-    pub fn on_clause(&self) -> Option<OnClause> {
+    pub fn path_ref(&self) -> Option<PathRef> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn using_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::USING_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Vacuum {
+pub struct TextSearchTemplateRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl Vacuum {
-    #[inline]
-    pub fn table_and_columns_list(&self) -> Option<TableAndColumnsList> {
-        support::child(&self.syntax)
-    }
+impl TextSearchTemplateRenameTo {
     #[inline]
-    pub fn vacuum_option_list(&self) -> Option<VacuumOptionList> {
+    pub fn text_search_template(&self) -> Option<TextSearchTemplate> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
-    }
-    #[inline]
-    pub fn analyse_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ANALYSE_KW)
-    }
-    #[inline]
-    pub fn analyze_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ANALYZE_KW)
-    }
-    #[inline]
-    pub fn freeze_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FREEZE_KW)
-    }
-    #[inline]
-    pub fn full_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FULL_KW)
-    }
-    #[inline]
-    pub fn vacuum_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VACUUM_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn verbose_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VERBOSE_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VacuumOption {
+pub struct TextSearchTokenKind {
     pub(crate) syntax: SyntaxNode,
 }
-impl VacuumOption {
+impl TextSearchTokenKind {
     #[inline]
-    pub fn vacuum_option_name(&self) -> Option<VacuumOptionName> {
-        support::child(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ties {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Ties {
     #[inline]
-    pub fn vacuum_option_value(&self) -> Option<VacuumOptionValue> {
-        support::child(&self.syntax)
+    pub fn ties_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VacuumOptionList {
+pub struct TimeType {
     pub(crate) syntax: SyntaxNode,
 }
-impl VacuumOptionList {
+impl TimeType {
     #[inline]
-    pub fn vacuum_options(&self) -> AstChildren<VacuumOption> {
-        support::children(&self.syntax)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn timezone(&self) -> Option<Timezone> {
+        support::child(&self.syntax)
     }
     #[inline]
     pub fn l_paren_token(&self) -> Option<SyntaxToken> {
@@ -26394,254 +26593,294 @@ impl VacuumOptionList {
     pub fn r_paren_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
+    #[inline]
+    pub fn setof_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SETOF_KW)
+    }
+    #[inline]
+    pub fn time_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIME_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VacuumOptionName {
+pub struct TimeZone {
     pub(crate) syntax: SyntaxNode,
 }
-impl VacuumOptionName {
+impl TimeZone {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn time_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIME_KW)
+    }
+    #[inline]
+    pub fn zone_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ZONE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VacuumOptionValue {
+pub struct TimestampType {
     pub(crate) syntax: SyntaxNode,
 }
-impl VacuumOptionValue {
+impl TimestampType {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn vacuum_option_value_name(&self) -> Option<VacuumOptionValueName> {
+    pub fn timezone(&self) -> Option<Timezone> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn no_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NO_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn yes_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::YES_KW)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn setof_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SETOF_KW)
+    }
+    #[inline]
+    pub fn timestamp_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIMESTAMP_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VacuumOptionValueName {
+pub struct TimingAfter {
     pub(crate) syntax: SyntaxNode,
 }
-impl VacuumOptionValueName {
+impl TimingAfter {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn after_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AFTER_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ValidateConstraint {
+pub struct TimingBefore {
     pub(crate) syntax: SyntaxNode,
 }
-impl ValidateConstraint {
+impl TimingBefore {
     #[inline]
-    pub fn constraint_name_ref(&self) -> Option<ConstraintNameRef> {
-        support::child(&self.syntax)
+    pub fn before_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::BEFORE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimingInsteadOf {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TimingInsteadOf {
     #[inline]
-    pub fn constraint_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONSTRAINT_KW)
+    pub fn instead_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INSTEAD_KW)
     }
     #[inline]
-    pub fn validate_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VALIDATE_KW)
+    pub fn of_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OF_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ValidatorClause {
+pub struct ToConfigValue {
     pub(crate) syntax: SyntaxNode,
 }
-impl ValidatorClause {
+impl ToConfigValue {
     #[inline]
-    pub fn function_name_ref(&self) -> Option<FunctionNameRef> {
-        support::child(&self.syntax)
+    pub fn config_values(&self) -> AstChildren<ConfigValue> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn validator_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VALIDATOR_KW)
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::EQ)
+    }
+    #[inline]
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    }
+    #[inline]
+    pub fn null_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NULL_KW)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Values {
+pub struct TransactionIsolationLevel {
     pub(crate) syntax: SyntaxNode,
 }
-impl Values {
+impl TransactionIsolationLevel {
     #[inline]
-    pub fn fetch_clause(&self) -> Option<FetchClause> {
-        support::child(&self.syntax)
+    pub fn isolation_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ISOLATION_KW)
     }
     #[inline]
-    pub fn limit_clause(&self) -> Option<LimitClause> {
-        support::child(&self.syntax)
+    pub fn level_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LEVEL_KW)
     }
     #[inline]
-    pub fn locking_clauses(&self) -> AstChildren<LockingClause> {
-        support::children(&self.syntax)
+    pub fn transaction_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransactionModeList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TransactionModeList {
     #[inline]
-    pub fn offset_clause(&self) -> Option<OffsetClause> {
-        support::child(&self.syntax)
+    pub fn transaction_modes(&self) -> AstChildren<TransactionMode> {
+        support::children(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransactionModes {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TransactionModes {
     #[inline]
-    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+    pub fn transaction_mode_list(&self) -> Option<TransactionModeList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn row_list(&self) -> Option<RowList> {
-        support::child(&self.syntax)
+    pub fn transaction_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransactionSnapshot {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TransactionSnapshot {
     #[inline]
-    pub fn with_clause(&self) -> Option<WithClause> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    pub fn snapshot_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SNAPSHOT_KW)
     }
     #[inline]
-    pub fn values_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VALUES_KW)
+    pub fn transaction_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSACTION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VarcharType {
+pub struct TransformForType {
     pub(crate) syntax: SyntaxNode,
 }
-impl VarcharType {
+impl TransformForType {
     #[inline]
-    pub fn arg_list(&self) -> Option<ArgList> {
+    pub fn ty(&self) -> Option<Type> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn char_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CHAR_KW)
-    }
-    #[inline]
-    pub fn character_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CHARACTER_KW)
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
     }
     #[inline]
-    pub fn national_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NATIONAL_KW)
+    pub fn type_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TYPE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransformFromFunc {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TransformFromFunc {
     #[inline]
-    pub fn nchar_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NCHAR_KW)
+    pub fn function_sig(&self) -> Option<FunctionSig> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn setof_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::SETOF_KW)
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
     #[inline]
-    pub fn varchar_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VARCHAR_KW)
+    pub fn function_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
     }
     #[inline]
-    pub fn varying_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VARYING_KW)
+    pub fn sql_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SQL_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Variant {
-    pub(crate) syntax: SyntaxNode,
-}
-impl Variant {
     #[inline]
-    pub fn literal(&self) -> Option<Literal> {
-        support::child(&self.syntax)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VariantList {
+pub struct TransformFuncOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl VariantList {
+impl TransformFuncOption {
     #[inline]
-    pub fn variants(&self) -> AstChildren<Variant> {
+    pub fn transform_for_types(&self) -> AstChildren<TransformForType> {
         support::children(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn transform_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRANSFORM_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VertexPattern {
+pub struct TransformToFunc {
     pub(crate) syntax: SyntaxNode,
 }
-impl VertexPattern {
+impl TransformToFunc {
     #[inline]
-    pub fn element_variable(&self) -> Option<ElementVariable> {
+    pub fn function_sig(&self) -> Option<FunctionSig> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn is_label(&self) -> Option<IsLabel> {
-        support::child(&self.syntax)
+    pub fn function_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
     }
     #[inline]
-    pub fn where_clause(&self) -> Option<WhereClause> {
-        support::child(&self.syntax)
+    pub fn sql_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SQL_KW)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VertexTableDef {
+pub struct TransitionRelationName {
     pub(crate) syntax: SyntaxNode,
 }
-impl VertexTableDef {
-    #[inline]
-    pub fn element_table_alias_clause(&self) -> Option<ElementTableAliasClause> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn element_table_key_clause(&self) -> Option<ElementTableKeyClause> {
-        support::child(&self.syntax)
-    }
-    #[inline]
-    pub fn element_table_label_and_properties(&self) -> Option<ElementTableLabelAndProperties> {
-        support::child(&self.syntax)
-    }
+impl TransitionRelationName {
     #[inline]
-    pub fn table_name_ref(&self) -> Option<TableNameRef> {
-        support::child(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VertexTableRef {
+pub struct Trigger {
     pub(crate) syntax: SyntaxNode,
 }
-impl VertexTableRef {
+impl Trigger {
     #[inline]
     pub fn ident_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::IDENT)
@@ -26649,174 +26888,150 @@ impl VertexTableRef {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct VertexTables {
+pub struct TriggerEventDelete {
     pub(crate) syntax: SyntaxNode,
 }
-impl VertexTables {
-    #[inline]
-    pub fn vertex_table_defs(&self) -> AstChildren<VertexTableDef> {
-        support::children(&self.syntax)
-    }
-    #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
-    }
-    #[inline]
-    pub fn node_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NODE_KW)
-    }
+impl TriggerEventDelete {
     #[inline]
-    pub fn tables_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TABLES_KW)
+    pub fn delete_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DELETE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriggerEventInsert {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TriggerEventInsert {
     #[inline]
-    pub fn vertex_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VERTEX_KW)
+    pub fn insert_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INSERT_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct View {
+pub struct TriggerEventList {
     pub(crate) syntax: SyntaxNode,
 }
-impl View {
+impl TriggerEventList {
     #[inline]
-    pub fn path(&self) -> Option<Path> {
-        support::child(&self.syntax)
+    pub fn trigger_events(&self) -> AstChildren<TriggerEvent> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ViewRef {
+pub struct TriggerEventTruncate {
     pub(crate) syntax: SyntaxNode,
 }
-impl ViewRef {
+impl TriggerEventTruncate {
     #[inline]
-    pub fn path_ref(&self) -> Option<PathRef> {
-        support::child(&self.syntax)
+    pub fn truncate_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRUNCATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ViewRenameTo {
+pub struct TriggerEventUpdate {
     pub(crate) syntax: SyntaxNode,
 }
-impl ViewRenameTo {
+impl TriggerEventUpdate {
     #[inline]
-    pub fn view(&self) -> Option<View> {
-        support::child(&self.syntax)
+    pub fn column_name_refs(&self) -> AstChildren<ColumnNameRef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn rename_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RENAME_KW)
+    pub fn of_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OF_KW)
     }
     #[inline]
-    pub fn to_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TO_KW)
+    pub fn update_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Virtual {
+pub struct TriggerRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl Virtual {
+impl TriggerRef {
     #[inline]
-    pub fn virtual_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VIRTUAL_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Volatile {
+pub struct TriggerRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl Volatile {
+impl TriggerRenameTo {
     #[inline]
-    pub fn volatile_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VOLATILE_KW)
+    pub fn trigger(&self) -> Option<Trigger> {
+        support::child(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WhenClause {
-    pub(crate) syntax: SyntaxNode,
-}
-impl WhenClause {
     #[inline]
-    pub fn then_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::THEN_KW)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn when_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WHEN_KW)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WhenClauseList {
+pub struct TrimBoth {
     pub(crate) syntax: SyntaxNode,
 }
-impl WhenClauseList {
-    #[inline]
-    pub fn when_clause(&self) -> Option<WhenClause> {
-        support::child(&self.syntax)
-    }
+impl TrimBoth {
     #[inline]
-    pub fn when_clauses(&self) -> AstChildren<WhenClause> {
-        support::children(&self.syntax)
+    pub fn both_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::BOTH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WhenCondition {
+pub struct TrimExprFrom {
     pub(crate) syntax: SyntaxNode,
 }
-impl WhenCondition {
+impl TrimExprFrom {
     #[inline]
     pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
-    }
-    #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn when_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WHEN_KW)
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WhereClause {
+pub struct TrimExprs {
     pub(crate) syntax: SyntaxNode,
 }
-impl WhereClause {
-    #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
+impl TrimExprs {
     #[inline]
-    pub fn where_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WHERE_KW)
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WhereConditionClause {
+pub struct TrimFn {
     pub(crate) syntax: SyntaxNode,
 }
-impl WhereConditionClause {
+impl TrimFn {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn trim_args(&self) -> Option<TrimArgs> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn trim_side(&self) -> Option<TrimSide> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -26828,72 +27043,91 @@ impl WhereConditionClause {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn where_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WHERE_KW)
+    pub fn trim_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRIM_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WhereCurrentOf {
+pub struct TrimFrom {
     pub(crate) syntax: SyntaxNode,
 }
-impl WhereCurrentOf {
-    #[inline]
-    pub fn cursor_ref(&self) -> Option<CursorRef> {
-        support::child(&self.syntax)
-    }
+impl TrimFrom {
     #[inline]
-    pub fn current_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CURRENT_KW)
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn of_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OF_KW)
+    pub fn from_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FROM_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrimLeading {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TrimLeading {
     #[inline]
-    pub fn where_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WHERE_KW)
+    pub fn leading_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LEADING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Window {
+pub struct TrimTrailing {
     pub(crate) syntax: SyntaxNode,
 }
-impl Window {
+impl TrimTrailing {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn trailing_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRAILING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WindowClause {
+pub struct Truncate {
     pub(crate) syntax: SyntaxNode,
 }
-impl WindowClause {
+impl Truncate {
     #[inline]
-    pub fn window_defs(&self) -> AstChildren<WindowDef> {
-        support::children(&self.syntax)
+    pub fn drop_behavior(&self) -> Option<DropBehavior> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn window_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WINDOW_KW)
+    pub fn identity_action(&self) -> Option<IdentityAction> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn table_list(&self) -> Option<TableList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn table_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLE_KW)
+    }
+    #[inline]
+    pub fn truncate_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TRUNCATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WindowDef {
+pub struct TupleExpr {
     pub(crate) syntax: SyntaxNode,
 }
-impl WindowDef {
+impl TupleExpr {
     #[inline]
-    pub fn window(&self) -> Option<Window> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn window_spec(&self) -> Option<WindowSpec> {
-        support::child(&self.syntax)
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
     }
     #[inline]
     pub fn l_paren_token(&self) -> Option<SyntaxToken> {
@@ -26904,441 +27138,488 @@ impl WindowDef {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMA)
+    }
+    #[inline]
+    pub fn row_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ROW_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WindowFuncOption {
+pub struct TypeName {
     pub(crate) syntax: SyntaxNode,
 }
-impl WindowFuncOption {
+impl TypeName {
     #[inline]
-    pub fn window_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WINDOW_KW)
+    pub fn path(&self) -> Option<Path> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WindowRef {
+pub struct TypeNameRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl WindowRef {
+impl TypeNameRef {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn path_ref(&self) -> Option<PathRef> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WindowSpec {
+pub struct TypeRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl WindowSpec {
-    #[inline]
-    pub fn frame_clause(&self) -> Option<FrameClause> {
-        support::child(&self.syntax)
-    }
+impl TypeRenameTo {
     #[inline]
-    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+    pub fn type_name(&self) -> Option<TypeName> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn partition_by_clause(&self) -> Option<PartitionByClause> {
-        support::child(&self.syntax)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn window_ref(&self) -> Option<WindowRef> {
-        support::child(&self.syntax)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithCheckExprClause {
+pub struct UnboundedFollowing {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithCheckExprClause {
-    #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
+impl UnboundedFollowing {
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn following_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOLLOWING_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn unbounded_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNBOUNDED_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnboundedPreceding {
+    pub(crate) syntax: SyntaxNode,
+}
+impl UnboundedPreceding {
     #[inline]
-    pub fn check_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CHECK_KW)
+    pub fn preceding_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PRECEDING_KW)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn unbounded_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNBOUNDED_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithCheckOption {
+pub struct UnicodeNormalForm {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithCheckOption {
+impl UnicodeNormalForm {
     #[inline]
-    pub fn check_option_level(&self) -> Option<CheckOptionLevel> {
-        support::child(&self.syntax)
+    pub fn nfc_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NFC_KW)
     }
     #[inline]
-    pub fn check_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CHECK_KW)
+    pub fn nfd_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NFD_KW)
     }
     #[inline]
-    pub fn option_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OPTION_KW)
+    pub fn nfkc_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NFKC_KW)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn nfkd_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NFKD_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithClause {
+pub struct Union {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithClause {
+impl Union {
     #[inline]
-    pub fn with_tables(&self) -> AstChildren<WithTable> {
-        support::children(&self.syntax)
-    }
-    #[inline]
-    pub fn recursive_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::RECURSIVE_KW)
+    pub fn all_or_distinct(&self) -> Option<AllOrDistinct> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn union_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNION_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithData {
+pub struct UniqueConstraint {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithData {
+impl UniqueConstraint {
     #[inline]
-    pub fn data_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DATA_KW)
+    pub fn constraint_name_clause(&self) -> Option<ConstraintNameClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn constraint_options(&self) -> AstChildren<ConstraintOption> {
+        support::children(&self.syntax)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithFunction {
-    pub(crate) syntax: SyntaxNode,
-}
-impl WithFunction {
     #[inline]
-    pub fn function_sig(&self) -> Option<FunctionSig> {
+    pub fn index_parameters(&self) -> Option<IndexParameters> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn function_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
+    pub fn using_index(&self) -> Option<UsingIndexName> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn unique_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNIQUE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithHold {
+pub struct Unlisten {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithHold {
+impl Unlisten {
     #[inline]
-    pub fn hold_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::HOLD_KW)
+    pub fn channel_ref(&self) -> Option<ChannelRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::STAR)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn unlisten_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNLISTEN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithInout {
+pub struct Unlogged {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithInout {
-    #[inline]
-    pub fn inout_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::INOUT_KW)
-    }
+impl Unlogged {
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn unlogged_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UNLOGGED_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithNoData {
+pub struct Update {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithNoData {
+impl Update {
     #[inline]
-    pub fn data_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DATA_KW)
+    pub fn alias(&self) -> Option<OptionalAsAlias> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn no_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NO_KW)
+    pub fn for_portion_of(&self) -> Option<ForPortionOf> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn from_clause(&self) -> Option<FromClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn relation_name(&self) -> Option<RelationName> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn returning_clause(&self) -> Option<ReturningClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn set_clause(&self) -> Option<SetClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn where_clause_or_current_of(&self) -> Option<WhereClauseOrCurrentOf> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn with_clause(&self) -> Option<WithClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
+    }
+    #[inline]
+    pub fn update_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::UPDATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithOptions {
+pub struct UpdateTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithOptions {
+impl UpdateTo {
     #[inline]
-    pub fn options_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OPTIONS_KW)
+    pub fn extension_version(&self) -> Option<ExtensionVersion> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithOrdinality {
+pub struct User {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithOrdinality {
-    #[inline]
-    pub fn ordinality_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ORDINALITY_KW)
-    }
+impl User {
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn user_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USER_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithParams {
+pub struct UsingClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithParams {
+impl UsingClause {
     #[inline]
-    pub fn attribute_list(&self) -> Option<AttributeList> {
-        support::child(&self.syntax)
+    pub fn from_items(&self) -> AstChildren<FromItem> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithTable {
+pub struct UsingExprClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithTable {
+impl UsingExprClause {
     #[inline]
-    pub fn column_list(&self) -> Option<ColumnList> {
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn cycle_clause(&self) -> Option<CycleClause> {
-        support::child(&self.syntax)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
     }
     #[inline]
-    pub fn materialized_option(&self) -> Option<MaterializedOption> {
-        support::child(&self.syntax)
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn name(&self) -> Option<CteName> {
-        support::child(&self.syntax)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsingIndex {
+    pub(crate) syntax: SyntaxNode,
+}
+impl UsingIndex {
     #[inline]
-    pub fn query(&self) -> Option<WithQuery> {
+    pub fn index_ref(&self) -> Option<IndexRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn search_clause(&self) -> Option<SearchClause> {
-        support::child(&self.syntax)
+    pub fn index_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INDEX_KW)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsingIndexName {
+    pub(crate) syntax: SyntaxNode,
+}
+impl UsingIndexName {
+    #[inline]
+    pub fn index_ref(&self) -> Option<IndexRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn index_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INDEX_KW)
     }
     #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithTies {
+pub struct UsingMethod {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithTies {
+impl UsingMethod {
     #[inline]
-    pub fn ties_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIES_KW)
+    pub fn access_method_ref(&self) -> Option<AccessMethodRef> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithTimezone {
+pub struct UsingOnClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithTimezone {
+impl UsingOnClause {
     #[inline]
-    pub fn time_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIME_KW)
+    pub fn from_item(&self) -> Option<FromItem> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn with_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    pub fn on_clause(&self) -> Option<OnClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn zone_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ZONE_KW)
+    pub fn using_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::USING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithinClause {
+pub struct Vacuum {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithinClause {
+impl Vacuum {
     #[inline]
-    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+    pub fn table_and_columns_list(&self) -> Option<TableAndColumnsList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn vacuum_option_list(&self) -> Option<VacuumOptionList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn group_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::GROUP_KW)
+    pub fn analyse_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ANALYSE_KW)
     }
     #[inline]
-    pub fn within_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITHIN_KW)
+    pub fn analyze_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ANALYZE_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithoutFunction {
-    pub(crate) syntax: SyntaxNode,
-}
-impl WithoutFunction {
     #[inline]
-    pub fn function_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
+    pub fn freeze_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FREEZE_KW)
     }
     #[inline]
-    pub fn without_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    pub fn full_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FULL_KW)
+    }
+    #[inline]
+    pub fn vacuum_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VACUUM_KW)
+    }
+    #[inline]
+    pub fn verbose_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VERBOSE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithoutHold {
+pub struct VacuumOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithoutHold {
+impl VacuumOption {
     #[inline]
-    pub fn hold_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::HOLD_KW)
+    pub fn vacuum_option_name(&self) -> Option<VacuumOptionName> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn without_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    pub fn vacuum_option_value(&self) -> Option<VacuumOptionValue> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithoutOids {
+pub struct VacuumOptionList {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithoutOids {
+impl VacuumOptionList {
     #[inline]
-    pub fn oids_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OIDS_KW)
+    pub fn vacuum_options(&self) -> AstChildren<VacuumOption> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn without_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithoutOverlaps {
+pub struct VacuumOptionName {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithoutOverlaps {
-    #[inline]
-    pub fn overlaps_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::OVERLAPS_KW)
-    }
+impl VacuumOptionName {
     #[inline]
-    pub fn without_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WithoutTimezone {
+pub struct VacuumOptionValue {
     pub(crate) syntax: SyntaxNode,
 }
-impl WithoutTimezone {
+impl VacuumOptionValue {
     #[inline]
-    pub fn time_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::TIME_KW)
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn without_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    pub fn vacuum_option_value_name(&self) -> Option<VacuumOptionValueName> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn zone_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ZONE_KW)
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
+    }
+    #[inline]
+    pub fn yes_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::YES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlAttr {
+pub struct VacuumOptionValueName {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlAttr {
+impl VacuumOptionValueName {
     #[inline]
     pub fn ident_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::IDENT)
@@ -27346,153 +27627,139 @@ impl XmlAttr {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlColumnOptionList {
+pub struct ValidateConstraint {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlColumnOptionList {
+impl ValidateConstraint {
     #[inline]
-    pub fn xml_column_option(&self) -> Option<XmlColumnOption> {
+    pub fn constraint_name_ref(&self) -> Option<ConstraintNameRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_column_options(&self) -> AstChildren<XmlColumnOption> {
-        support::children(&self.syntax)
+    pub fn constraint_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONSTRAINT_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlContent {
-    pub(crate) syntax: SyntaxNode,
-}
-impl XmlContent {
     #[inline]
-    pub fn content_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::CONTENT_KW)
+    pub fn validate_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VALIDATE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlDocument {
+pub struct ValidatorClause {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlDocument {
+impl ValidatorClause {
     #[inline]
-    pub fn document_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DOCUMENT_KW)
+    pub fn function_name_ref(&self) -> Option<FunctionNameRef> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn validator_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VALIDATOR_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlElementFn {
+pub struct Values {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlElementFn {
+impl Values {
     #[inline]
-    pub fn expr_as_xml_attr_list(&self) -> Option<ExprAsXmlAttrList> {
+    pub fn fetch_clause(&self) -> Option<FetchClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn exprs(&self) -> AstChildren<Expr> {
-        support::children(&self.syntax)
+    pub fn limit_clause(&self) -> Option<LimitClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn tag(&self) -> Option<ElementTag> {
-        support::child(&self.syntax)
+    pub fn locking_clauses(&self) -> AstChildren<LockingClause> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn offset_clause(&self) -> Option<OffsetClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMA)
+    pub fn row_list(&self) -> Option<RowList> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn name_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NAME_KW)
+    pub fn with_clause(&self) -> Option<WithClause> {
+        support::child(&self.syntax)
     }
     #[inline]
-    pub fn xmlattributes_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLATTRIBUTES_KW)
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SEMICOLON)
     }
     #[inline]
-    pub fn xmlelement_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLELEMENT_KW)
+    pub fn values_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VALUES_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlExistsFn {
+pub struct VarcharType {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlExistsFn {
+impl VarcharType {
     #[inline]
-    pub fn xml_row_passing_clause(&self) -> Option<XmlRowPassingClause> {
+    pub fn arg_list(&self) -> Option<ArgList> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn char_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CHAR_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn character_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CHARACTER_KW)
     }
     #[inline]
-    pub fn xmlexists_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLEXISTS_KW)
+    pub fn national_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NATIONAL_KW)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlForestFn {
-    pub(crate) syntax: SyntaxNode,
-}
-impl XmlForestFn {
     #[inline]
-    pub fn expr_as_element_tag_list(&self) -> Option<ExprAsElementTagList> {
-        support::child(&self.syntax)
+    pub fn nchar_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NCHAR_KW)
     }
     #[inline]
-    pub fn xmlforest_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLFOREST_KW)
+    pub fn setof_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::SETOF_KW)
+    }
+    #[inline]
+    pub fn varchar_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VARCHAR_KW)
+    }
+    #[inline]
+    pub fn varying_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VARYING_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlNamespace {
+pub struct Variant {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlNamespace {
-    #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
+impl Variant {
     #[inline]
-    pub fn prefix(&self) -> Option<XmlNamespacePrefix> {
+    pub fn literal(&self) -> Option<Literal> {
         support::child(&self.syntax)
     }
-    #[inline]
-    pub fn as_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::AS_KW)
-    }
-    #[inline]
-    pub fn default_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlNamespaceList {
+pub struct VariantList {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlNamespaceList {
+impl VariantList {
     #[inline]
-    pub fn xml_namespaces(&self) -> AstChildren<XmlNamespace> {
+    pub fn variants(&self) -> AstChildren<Variant> {
         support::children(&self.syntax)
     }
     #[inline]
@@ -27506,31 +27773,20 @@ impl XmlNamespaceList {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlNamespacePrefix {
-    pub(crate) syntax: SyntaxNode,
-}
-impl XmlNamespacePrefix {
-    #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlParseFn {
+pub struct VertexPattern {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlParseFn {
+impl VertexPattern {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn element_variable(&self) -> Option<ElementVariable> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_document_or_content(&self) -> Option<XmlDocumentOrContent> {
+    pub fn is_label(&self) -> Option<IsLabel> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_whitespace(&self) -> Option<XmlWhitespace> {
+    pub fn where_clause(&self) -> Option<WhereClause> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -27541,114 +27797,211 @@ impl XmlParseFn {
     pub fn r_paren_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
-    #[inline]
-    pub fn xmlparse_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLPARSE_KW)
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlPassingDoc {
+pub struct VertexTableDef {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlPassingDoc {
+impl VertexTableDef {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn element_table_alias_clause(&self) -> Option<ElementTableAliasClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_passing_mech(&self) -> Option<XmlPassingMech> {
+    pub fn element_table_key_clause(&self) -> Option<ElementTableKeyClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn element_table_label_and_properties(&self) -> Option<ElementTableLabelAndProperties> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn table_name_ref(&self) -> Option<TableNameRef> {
         support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlPassingMechByRef {
+pub struct VertexTableRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlPassingMechByRef {
+impl VertexTableRef {
     #[inline]
-    pub fn by_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::BY_KW)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VertexTables {
+    pub(crate) syntax: SyntaxNode,
+}
+impl VertexTables {
     #[inline]
-    pub fn ref_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::REF_KW)
+    pub fn vertex_table_defs(&self) -> AstChildren<VertexTableDef> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn node_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NODE_KW)
+    }
+    #[inline]
+    pub fn tables_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TABLES_KW)
+    }
+    #[inline]
+    pub fn vertex_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VERTEX_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlPassingMechByValue {
+pub struct View {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlPassingMechByValue {
+impl View {
     #[inline]
-    pub fn by_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::BY_KW)
+    pub fn path(&self) -> Option<Path> {
+        support::child(&self.syntax)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ViewRef {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ViewRef {
     #[inline]
-    pub fn value_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VALUE_KW)
+    pub fn path_ref(&self) -> Option<PathRef> {
+        support::child(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlPiFn {
+pub struct ViewRenameTo {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlPiFn {
+impl ViewRenameTo {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn view(&self) -> Option<View> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    pub fn rename_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RENAME_KW)
     }
     #[inline]
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    pub fn to_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TO_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Virtual {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Virtual {
     #[inline]
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMA)
+    pub fn virtual_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VIRTUAL_KW)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Volatile {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Volatile {
+    #[inline]
+    pub fn volatile_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VOLATILE_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhenClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WhenClause {
     #[inline]
-    pub fn name_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NAME_KW)
+    pub fn then_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::THEN_KW)
     }
     #[inline]
-    pub fn xmlpi_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLPI_KW)
+    pub fn when_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHEN_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlPiTarget {
+pub struct WhenClauseList {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlPiTarget {
+impl WhenClauseList {
     #[inline]
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::IDENT)
+    pub fn when_clause(&self) -> Option<WhenClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn when_clauses(&self) -> AstChildren<WhenClause> {
+        support::children(&self.syntax)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlRootFn {
+pub struct WhenCondition {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlRootFn {
+impl WhenCondition {
     #[inline]
     pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_root_version(&self) -> Option<XmlRootVersion> {
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn when_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHEN_KW)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhereClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WhereClause {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_standalone(&self) -> Option<XmlStandalone> {
+    pub fn where_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHERE_KW)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhereConditionClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WhereConditionClause {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -27660,57 +28013,71 @@ impl XmlRootFn {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMA)
-    }
-    #[inline]
-    pub fn xmlroot_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLROOT_KW)
+    pub fn where_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHERE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlRowPassingClause {
+pub struct WhereCurrentOf {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlRowPassingClause {
+impl WhereCurrentOf {
     #[inline]
-    pub fn row(&self) -> Option<Expr> {
+    pub fn cursor_ref(&self) -> Option<CursorRef> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_passing_doc(&self) -> Option<XmlPassingDoc> {
-        support::child(&self.syntax)
+    pub fn current_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CURRENT_KW)
     }
     #[inline]
-    pub fn xml_passing_mech(&self) -> Option<XmlPassingMech> {
-        support::child(&self.syntax)
+    pub fn of_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OF_KW)
     }
     #[inline]
-    pub fn passing_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::PASSING_KW)
+    pub fn where_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WHERE_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlSerializeFn {
+pub struct Window {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlSerializeFn {
+impl Window {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WindowClause {
     #[inline]
-    pub fn ty(&self) -> Option<Type> {
-        support::child(&self.syntax)
+    pub fn window_defs(&self) -> AstChildren<WindowDef> {
+        support::children(&self.syntax)
     }
     #[inline]
-    pub fn xml_document_or_content(&self) -> Option<XmlDocumentOrContent> {
+    pub fn window_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WINDOW_KW)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowDef {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WindowDef {
+    #[inline]
+    pub fn window(&self) -> Option<Window> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_indent(&self) -> Option<XmlIndent> {
+    pub fn window_spec(&self) -> Option<WindowSpec> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -27725,27 +28092,60 @@ impl XmlSerializeFn {
     pub fn as_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, SyntaxKind::AS_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowFuncOption {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WindowFuncOption {
     #[inline]
-    pub fn xmlserialize_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLSERIALIZE_KW)
+    pub fn window_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WINDOW_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlTable {
+pub struct WindowRef {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlTable {
+impl WindowRef {
     #[inline]
-    pub fn xml_namespace_list(&self) -> Option<XmlNamespaceList> {
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowSpec {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WindowSpec {
+    #[inline]
+    pub fn frame_clause(&self) -> Option<FrameClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_row_passing_clause(&self) -> Option<XmlRowPassingClause> {
+    pub fn order_by_clause(&self) -> Option<OrderByClause> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_table_column_list(&self) -> Option<XmlTableColumnList> {
+    pub fn partition_by_clause(&self) -> Option<PartitionByClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn window_ref(&self) -> Option<WindowRef> {
+        support::child(&self.syntax)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WithCheckExprClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WithCheckExprClause {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
     #[inline]
@@ -27757,1407 +28157,2194 @@ impl XmlTable {
         support::token(&self.syntax, SyntaxKind::R_PAREN)
     }
     #[inline]
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COMMA)
-    }
-    #[inline]
-    pub fn xmlnamespaces_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLNAMESPACES_KW)
+    pub fn check_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CHECK_KW)
     }
     #[inline]
-    pub fn xmltable_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::XMLTABLE_KW)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlTableColumn {
+pub struct WithCheckOption {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlTableColumn {
+impl WithCheckOption {
     #[inline]
-    pub fn column_name(&self) -> Option<ColumnName> {
+    pub fn check_option_level(&self) -> Option<CheckOptionLevel> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn ty(&self) -> Option<Type> {
-        support::child(&self.syntax)
+    pub fn check_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CHECK_KW)
     }
     #[inline]
-    pub fn xml_column_option_list(&self) -> Option<XmlColumnOptionList> {
-        support::child(&self.syntax)
+    pub fn option_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OPTION_KW)
     }
     #[inline]
-    pub fn for_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::FOR_KW)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WithClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WithClause {
     #[inline]
-    pub fn ordinality_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::ORDINALITY_KW)
+    pub fn with_tables(&self) -> AstChildren<WithTable> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn recursive_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::RECURSIVE_KW)
+    }
+    #[inline]
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlTableColumnList {
+pub struct WithData {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlTableColumnList {
+impl WithData {
     #[inline]
-    pub fn xml_table_columns(&self) -> AstChildren<XmlTableColumn> {
-        support::children(&self.syntax)
+    pub fn data_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DATA_KW)
     }
     #[inline]
-    pub fn columns_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::COLUMNS_KW)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlTableFromItem {
+pub struct WithFunction {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlTableFromItem {
+impl WithFunction {
     #[inline]
-    pub fn alias(&self) -> Option<FromAlias> {
+    pub fn function_sig(&self) -> Option<FunctionSig> {
         support::child(&self.syntax)
     }
     #[inline]
-    pub fn xml_table(&self) -> Option<XmlTable> {
-        support::child(&self.syntax)
+    pub fn function_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
     }
     #[inline]
-    pub fn lateral_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::LATERAL_KW)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlVersionExpr {
+pub struct WithHold {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlVersionExpr {
+impl WithHold {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
+    pub fn hold_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::HOLD_KW)
     }
     #[inline]
-    pub fn version_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VERSION_KW)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct XmlVersionNoValue {
+pub struct WithInout {
     pub(crate) syntax: SyntaxNode,
 }
-impl XmlVersionNoValue {
-    #[inline]
-    pub fn no_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::NO_KW)
-    }
+impl WithInout {
     #[inline]
-    pub fn value_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VALUE_KW)
+    pub fn inout_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::INOUT_KW)
     }
     #[inline]
-    pub fn version_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, SyntaxKind::VERSION_KW)
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AccessMethodType {
-    AccessMethodTypeIndex(AccessMethodTypeIndex),
-    AccessMethodTypeTable(AccessMethodTypeTable),
+pub struct WithNoData {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WithNoData {
+    #[inline]
+    pub fn data_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DATA_KW)
+    }
+    #[inline]
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
+    }
+    #[inline]
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Accessor {
-    FieldAccessor(FieldAccessor),
-    IndexAccessor(IndexAccessor),
-    SliceAccessor(SliceAccessor),
+pub struct WithOptions {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WithOptions {
+    #[inline]
+    pub fn options_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OPTIONS_KW)
+    }
+    #[inline]
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AllOrDistinct {
-    All(All),
-    Distinct(Distinct),
+pub struct WithOrdinality {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WithOrdinality {
+    #[inline]
+    pub fn ordinality_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ORDINALITY_KW)
+    }
+    #[inline]
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AllPublicationObject {
-    AllPublicationSequences(AllPublicationSequences),
-    AllPublicationTables(AllPublicationTables),
+pub struct WithParams {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WithParams {
+    #[inline]
+    pub fn attribute_list(&self) -> Option<AttributeList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterAggregateAction {
-    AggregateRenameTo(AggregateRenameTo),
-    OwnerTo(OwnerTo),
-    SetSchema(SetSchema),
+pub struct WithTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WithTable {
+    #[inline]
+    pub fn column_list(&self) -> Option<ColumnList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn cycle_clause(&self) -> Option<CycleClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn materialized_option(&self) -> Option<MaterializedOption> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn name(&self) -> Option<CteName> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn query(&self) -> Option<WithQuery> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn search_clause(&self) -> Option<SearchClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterCollationAction {
-    CollationRenameTo(CollationRenameTo),
-    OwnerTo(OwnerTo),
-    RefreshVersion(RefreshVersion),
-    SetSchema(SetSchema),
+pub struct WithTies {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WithTies {
+    #[inline]
+    pub fn ties_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIES_KW)
+    }
+    #[inline]
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterColumnOption {
-    AddGenerated(AddGenerated),
-    DropDefault(DropDefault),
-    DropExpression(DropExpression),
-    DropIdentity(DropIdentity),
-    DropNotNull(DropNotNull),
-    Inherit(Inherit),
-    NoInherit(NoInherit),
-    ResetOptions(ResetOptions),
-    Restart(Restart),
-    SetCompression(SetCompression),
-    SetDefault(SetDefault),
-    SetExpression(SetExpression),
-    SetGenerated(SetGenerated),
-    SetGeneratedOptions(SetGeneratedOptions),
-    SetNotNull(SetNotNull),
-    SetOptions(SetOptions),
-    SetOptionsList(SetOptionsList),
-    SetSequenceOption(SetSequenceOption),
-    SetStatistics(SetStatistics),
-    SetStorage(SetStorage),
-    SetType(SetType),
+pub struct WithTimezone {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterConversionAction {
-    ConversionRenameTo(ConversionRenameTo),
-    OwnerTo(OwnerTo),
-    SetSchema(SetSchema),
+impl WithTimezone {
+    #[inline]
+    pub fn time_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIME_KW)
+    }
+    #[inline]
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITH_KW)
+    }
+    #[inline]
+    pub fn zone_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ZONE_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterDatabaseAction {
-    DatabaseOptionList(DatabaseOptionList),
-    DatabaseRenameTo(DatabaseRenameTo),
-    OwnerTo(OwnerTo),
-    RefreshCollationVersion(RefreshCollationVersion),
-    ResetConfigParam(ResetConfigParam),
-    SetConfigParam(SetConfigParam),
-    SetTablespace(SetTablespace),
+pub struct WithinClause {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterDefaultPrivilegesAction {
-    GrantDefaultPrivileges(GrantDefaultPrivileges),
-    RevokeDefaultPrivileges(RevokeDefaultPrivileges),
+impl WithinClause {
+    #[inline]
+    pub fn order_by_clause(&self) -> Option<OrderByClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn group_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::GROUP_KW)
+    }
+    #[inline]
+    pub fn within_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITHIN_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterDomainAction {
-    AddConstraint(AddConstraint),
-    DomainRenameTo(DomainRenameTo),
-    DropConstraint(DropConstraint),
-    DropDefault(DropDefault),
-    DropNotNull(DropNotNull),
-    OwnerTo(OwnerTo),
-    RenameConstraint(RenameConstraint),
-    SetDefault(SetDefault),
-    SetNotNull(SetNotNull),
-    SetSchema(SetSchema),
-    ValidateConstraint(ValidateConstraint),
+pub struct WithoutFunction {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterEventTriggerAction {
-    Disable(Disable),
-    Enable(Enable),
-    EnableAlways(EnableAlways),
-    EnableReplica(EnableReplica),
-    EventTriggerRenameTo(EventTriggerRenameTo),
-    OwnerTo(OwnerTo),
+impl WithoutFunction {
+    #[inline]
+    pub fn function_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FUNCTION_KW)
+    }
+    #[inline]
+    pub fn without_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterExtensionAction {
-    AlterExtensionAdd(AlterExtensionAdd),
-    AlterExtensionDrop(AlterExtensionDrop),
-    AlterExtensionUpdate(AlterExtensionUpdate),
-    SetSchema(SetSchema),
+pub struct WithoutHold {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterForeignDataWrapperAction {
-    FdwOptionList(FdwOptionList),
-    ForeignDataWrapperRenameTo(ForeignDataWrapperRenameTo),
-    OwnerTo(OwnerTo),
+impl WithoutHold {
+    #[inline]
+    pub fn hold_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::HOLD_KW)
+    }
+    #[inline]
+    pub fn without_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterFunctionAction {
-    DependsOnExtension(DependsOnExtension),
-    FuncOptionList(FuncOptionList),
-    FunctionRenameTo(FunctionRenameTo),
-    NoDependsOnExtension(NoDependsOnExtension),
-    OwnerTo(OwnerTo),
-    SetSchema(SetSchema),
+pub struct WithoutOids {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterGroupAction {
-    AddUsers(AddUsers),
-    DropUsers(DropUsers),
-    RoleRenameTo(RoleRenameTo),
+impl WithoutOids {
+    #[inline]
+    pub fn oids_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OIDS_KW)
+    }
+    #[inline]
+    pub fn without_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterIndexAction {
-    AlterSetStatistics(AlterSetStatistics),
-    AttachIndexPartition(AttachIndexPartition),
-    DependsOnExtension(DependsOnExtension),
-    IndexRenameTo(IndexRenameTo),
-    NoDependsOnExtension(NoDependsOnExtension),
-    ResetOptions(ResetOptions),
-    SetOptions(SetOptions),
-    SetTablespace(SetTablespace),
+pub struct WithoutOverlaps {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterLanguageAction {
-    LanguageRenameTo(LanguageRenameTo),
-    OwnerTo(OwnerTo),
+impl WithoutOverlaps {
+    #[inline]
+    pub fn overlaps_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::OVERLAPS_KW)
+    }
+    #[inline]
+    pub fn without_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterMaterializedViewAction {
-    DependsOnExtension(DependsOnExtension),
-    NoDependsOnExtension(NoDependsOnExtension),
-    RenameColumn(RenameColumn),
-    SetSchema(SetSchema),
-    ViewRenameTo(ViewRenameTo),
-    AlterTableAction(AlterTableAction),
+pub struct WithoutTimezone {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterOperatorAction {
-    OwnerTo(OwnerTo),
-    SetOptions(SetOptions),
-    SetSchema(SetSchema),
+impl WithoutTimezone {
+    #[inline]
+    pub fn time_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::TIME_KW)
+    }
+    #[inline]
+    pub fn without_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::WITHOUT_KW)
+    }
+    #[inline]
+    pub fn zone_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ZONE_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterOperatorClassAction {
-    OpClassRenameTo(OpClassRenameTo),
-    OwnerTo(OwnerTo),
-    SetSchema(SetSchema),
+pub struct XmlAttr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlAttr {
+    #[inline]
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterOperatorFamilyAction {
-    AddOpClassOptions(AddOpClassOptions),
-    DropOpClassOptions(DropOpClassOptions),
-    OpFamilyRenameTo(OpFamilyRenameTo),
-    OwnerTo(OwnerTo),
-    SetSchema(SetSchema),
+pub struct XmlColumnOptionList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlColumnOptionList {
+    #[inline]
+    pub fn xml_column_option(&self) -> Option<XmlColumnOption> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_column_options(&self) -> AstChildren<XmlColumnOption> {
+        support::children(&self.syntax)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterOption {
-    AddForeignOption(AddForeignOption),
-    DropForeignOption(DropForeignOption),
-    SetForeignOption(SetForeignOption),
+pub struct XmlContent {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlContent {
+    #[inline]
+    pub fn content_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::CONTENT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterPolicyAction {
-    AlterPolicyTo(AlterPolicyTo),
-    PolicyRenameTo(PolicyRenameTo),
+pub struct XmlDocument {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlDocument {
+    #[inline]
+    pub fn document_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DOCUMENT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterProcedureAction {
-    DependsOnExtension(DependsOnExtension),
-    FuncOptionList(FuncOptionList),
-    NoDependsOnExtension(NoDependsOnExtension),
-    OwnerTo(OwnerTo),
-    ProcedureRenameTo(ProcedureRenameTo),
-    SetSchema(SetSchema),
+pub struct XmlElementFn {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlElementFn {
+    #[inline]
+    pub fn expr_as_xml_attr_list(&self) -> Option<ExprAsXmlAttrList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn tag(&self) -> Option<ElementTag> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMA)
+    }
+    #[inline]
+    pub fn name_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NAME_KW)
+    }
+    #[inline]
+    pub fn xmlattributes_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLATTRIBUTES_KW)
+    }
+    #[inline]
+    pub fn xmlelement_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLELEMENT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterPropertyGraphAction {
-    AddVertexEdgeLabelProperties(AddVertexEdgeLabelProperties),
-    AddVertexEdgeTables(AddVertexEdgeTables),
-    AlterVertexEdgeLabels(AlterVertexEdgeLabels),
-    DropEdgeTables(DropEdgeTables),
-    DropVertexEdgeLabel(DropVertexEdgeLabel),
-    DropVertexEdgeLabelProperties(DropVertexEdgeLabelProperties),
-    DropVertexTables(DropVertexTables),
-    OwnerTo(OwnerTo),
-    PropertyGraphRenameTo(PropertyGraphRenameTo),
-    SetSchema(SetSchema),
+pub struct XmlExistsFn {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlExistsFn {
+    #[inline]
+    pub fn xml_row_passing_clause(&self) -> Option<XmlRowPassingClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn xmlexists_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLEXISTS_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterPublicationAction {
-    AddPublicationObjects(AddPublicationObjects),
-    DropPublicationObjects(DropPublicationObjects),
-    OwnerTo(OwnerTo),
-    PublicationRenameTo(PublicationRenameTo),
-    SetAllPublicationObjectList(SetAllPublicationObjectList),
-    SetOptions(SetOptions),
-    SetPublicationObjects(SetPublicationObjects),
+pub struct XmlForestFn {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlForestFn {
+    #[inline]
+    pub fn expr_as_element_tag_list(&self) -> Option<ExprAsElementTagList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xmlforest_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLFOREST_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterRoleAction {
-    ResetConfigParam(ResetConfigParam),
-    RoleOptionList(RoleOptionList),
-    RoleRenameTo(RoleRenameTo),
-    SetConfigParam(SetConfigParam),
+pub struct XmlNamespace {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlNamespace {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn prefix(&self) -> Option<XmlNamespacePrefix> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
+    }
+    #[inline]
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::DEFAULT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterRoutineAction {
-    DependsOnExtension(DependsOnExtension),
-    FuncOptionList(FuncOptionList),
-    NoDependsOnExtension(NoDependsOnExtension),
-    OwnerTo(OwnerTo),
-    RoutineRenameTo(RoutineRenameTo),
-    SetSchema(SetSchema),
+pub struct XmlNamespaceList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlNamespaceList {
+    #[inline]
+    pub fn xml_namespaces(&self) -> AstChildren<XmlNamespace> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterSchemaAction {
-    OwnerTo(OwnerTo),
-    SchemaRenameTo(SchemaRenameTo),
+pub struct XmlNamespacePrefix {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlNamespacePrefix {
+    #[inline]
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterSequenceAction {
-    OwnerTo(OwnerTo),
-    SequenceRenameTo(SequenceRenameTo),
-    SetLogged(SetLogged),
-    SetSchema(SetSchema),
-    SetUnlogged(SetUnlogged),
-    SequenceOption(SequenceOption),
+pub struct XmlParseFn {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlParseFn {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_document_or_content(&self) -> Option<XmlDocumentOrContent> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_whitespace(&self) -> Option<XmlWhitespace> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn xmlparse_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLPARSE_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterServerAction {
-    AlterOptionList(AlterOptionList),
-    OwnerTo(OwnerTo),
-    ServerRenameTo(ServerRenameTo),
-    ServerVersion(ServerVersion),
+pub struct XmlPassingDoc {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlPassingDoc {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_passing_mech(&self) -> Option<XmlPassingMech> {
+        support::child(&self.syntax)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterStatisticsAction {
-    OwnerTo(OwnerTo),
-    SetSchema(SetSchema),
-    SetStatistics(SetStatistics),
-    StatisticsRenameTo(StatisticsRenameTo),
+pub struct XmlPassingMechByRef {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlPassingMechByRef {
+    #[inline]
+    pub fn by_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::BY_KW)
+    }
+    #[inline]
+    pub fn ref_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::REF_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterSubscriptionAction {
-    AddPublication(AddPublication),
-    ConnectionClause(ConnectionClause),
-    DisableSubscription(DisableSubscription),
-    DropSubscriptionPublication(DropSubscriptionPublication),
-    EnableSubscription(EnableSubscription),
-    OwnerTo(OwnerTo),
-    RefreshPublication(RefreshPublication),
-    ServerClause(ServerClause),
-    SetOptions(SetOptions),
-    SetPublication(SetPublication),
-    SkipSubscription(SkipSubscription),
-    SubscriptionRenameTo(SubscriptionRenameTo),
+pub struct XmlPassingMechByValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlPassingMechByValue {
+    #[inline]
+    pub fn by_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::BY_KW)
+    }
+    #[inline]
+    pub fn value_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VALUE_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterSystemAction {
-    ResetConfigParam(ResetConfigParam),
-    SetConfigParam(SetConfigParam),
+pub struct XmlPiFn {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTableAction {
-    AddColumn(AddColumn),
-    AddConstraint(AddConstraint),
-    AlterColumn(AlterColumn),
-    AlterConstraint(AlterConstraint),
-    AttachPartition(AttachPartition),
-    ClusterOn(ClusterOn),
-    DetachPartition(DetachPartition),
-    DisableRls(DisableRls),
-    DisableRule(DisableRule),
-    DisableTrigger(DisableTrigger),
-    DropColumn(DropColumn),
-    DropConstraint(DropConstraint),
-    EnableAlwaysRule(EnableAlwaysRule),
-    EnableAlwaysTrigger(EnableAlwaysTrigger),
-    EnableReplicaRule(EnableReplicaRule),
-    EnableReplicaTrigger(EnableReplicaTrigger),
-    EnableRls(EnableRls),
-    EnableRule(EnableRule),
-    EnableTrigger(EnableTrigger),
-    ForceRls(ForceRls),
-    InheritTable(InheritTable),
-    MergePartitions(MergePartitions),
-    NoForceRls(NoForceRls),
-    NoInheritTable(NoInheritTable),
-    NotOf(NotOf),
-    OfType(OfType),
-    OptionItemList(OptionItemList),
-    OwnerTo(OwnerTo),
-    RenameColumn(RenameColumn),
-    RenameConstraint(RenameConstraint),
-    ReplicaIdentity(ReplicaIdentity),
-    ResetOptions(ResetOptions),
-    SetAccessMethod(SetAccessMethod),
-    SetLogged(SetLogged),
-    SetOptions(SetOptions),
-    SetSchema(SetSchema),
-    SetTablespace(SetTablespace),
-    SetUnlogged(SetUnlogged),
-    SetWithoutCluster(SetWithoutCluster),
-    SetWithoutOids(SetWithoutOids),
-    SplitPartition(SplitPartition),
-    TableRenameTo(TableRenameTo),
-    ValidateConstraint(ValidateConstraint),
+impl XmlPiFn {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMA)
+    }
+    #[inline]
+    pub fn name_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NAME_KW)
+    }
+    #[inline]
+    pub fn xmlpi_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLPI_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTablespaceAction {
-    OwnerTo(OwnerTo),
-    ResetOptions(ResetOptions),
-    SetOptions(SetOptions),
-    TablespaceRenameTo(TablespaceRenameTo),
+pub struct XmlPiTarget {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTextSearchConfigurationAction {
-    AddMapping(AddMapping),
-    AlterMapping(AlterMapping),
-    DropMapping(DropMapping),
-    OwnerTo(OwnerTo),
-    SetSchema(SetSchema),
-    TextSearchConfigurationRenameTo(TextSearchConfigurationRenameTo),
+impl XmlPiTarget {
+    #[inline]
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTextSearchDictionaryAction {
-    AttributeList(AttributeList),
-    OwnerTo(OwnerTo),
-    SetSchema(SetSchema),
-    TextSearchDictionaryRenameTo(TextSearchDictionaryRenameTo),
+pub struct XmlRootFn {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTextSearchParserAction {
-    SetSchema(SetSchema),
-    TextSearchParserRenameTo(TextSearchParserRenameTo),
+impl XmlRootFn {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_root_version(&self) -> Option<XmlRootVersion> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_standalone(&self) -> Option<XmlStandalone> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMA)
+    }
+    #[inline]
+    pub fn xmlroot_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLROOT_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTextSearchTemplateAction {
-    SetSchema(SetSchema),
-    TextSearchTemplateRenameTo(TextSearchTemplateRenameTo),
+pub struct XmlRowPassingClause {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTriggerAction {
-    DependsOnExtension(DependsOnExtension),
-    NoDependsOnExtension(NoDependsOnExtension),
-    TriggerRenameTo(TriggerRenameTo),
+impl XmlRowPassingClause {
+    #[inline]
+    pub fn row(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_passing_doc(&self) -> Option<XmlPassingDoc> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_passing_mech(&self) -> Option<XmlPassingMech> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn passing_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::PASSING_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTypeAction {
-    AddValue(AddValue),
-    AlterTypeAttributeActionList(AlterTypeAttributeActionList),
-    OwnerTo(OwnerTo),
-    RenameAttribute(RenameAttribute),
-    RenameValue(RenameValue),
-    SetOptions(SetOptions),
-    SetSchema(SetSchema),
-    TypeRenameTo(TypeRenameTo),
+pub struct XmlSerializeFn {
+    pub(crate) syntax: SyntaxNode,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterTypeAttributeAction {
-    AddAttribute(AddAttribute),
-    AlterAttribute(AlterAttribute),
-    DropAttribute(DropAttribute),
+impl XmlSerializeFn {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn ty(&self) -> Option<Type> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_document_or_content(&self) -> Option<XmlDocumentOrContent> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_indent(&self) -> Option<XmlIndent> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn as_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::AS_KW)
+    }
+    #[inline]
+    pub fn xmlserialize_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLSERIALIZE_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterUserAction {
-    ResetConfigParam(ResetConfigParam),
-    RoleOptionList(RoleOptionList),
-    RoleRenameTo(RoleRenameTo),
-    SetConfigParam(SetConfigParam),
+pub struct XmlTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlTable {
+    #[inline]
+    pub fn xml_namespace_list(&self) -> Option<XmlNamespaceList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_row_passing_clause(&self) -> Option<XmlRowPassingClause> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_table_column_list(&self) -> Option<XmlTableColumnList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::L_PAREN)
+    }
+    #[inline]
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::R_PAREN)
+    }
+    #[inline]
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COMMA)
+    }
+    #[inline]
+    pub fn xmlnamespaces_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLNAMESPACES_KW)
+    }
+    #[inline]
+    pub fn xmltable_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::XMLTABLE_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterViewAction {
-    AlterViewColumn(AlterViewColumn),
-    OwnerTo(OwnerTo),
-    RenameColumn(RenameColumn),
-    ResetOptions(ResetOptions),
-    SetOptions(SetOptions),
-    SetSchema(SetSchema),
-    ViewRenameTo(ViewRenameTo),
+pub struct XmlTableColumn {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlTableColumn {
+    #[inline]
+    pub fn column_name(&self) -> Option<ColumnName> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn ty(&self) -> Option<Type> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_column_option_list(&self) -> Option<XmlColumnOptionList> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::FOR_KW)
+    }
+    #[inline]
+    pub fn ordinality_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::ORDINALITY_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AlterViewColumnAction {
-    DropDefault(DropDefault),
-    SetDefault(SetDefault),
+pub struct XmlTableColumnList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlTableColumnList {
+    #[inline]
+    pub fn xml_table_columns(&self) -> AstChildren<XmlTableColumn> {
+        support::children(&self.syntax)
+    }
+    #[inline]
+    pub fn columns_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::COLUMNS_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AnyName {
-    AccessMethod(AccessMethod),
-    AccessMethodRef(AccessMethodRef),
-    AttributeName(AttributeName),
-    AttributeNamespace(AttributeNamespace),
-    BindParamNameRef(BindParamNameRef),
-    Channel(Channel),
-    ChannelRef(ChannelRef),
-    ColumnName(ColumnName),
-    ColumnNameRef(ColumnNameRef),
-    CompositeField(CompositeField),
-    CompositeFieldRef(CompositeFieldRef),
-    ConfigValueName(ConfigValueName),
-    ConstraintName(ConstraintName),
-    CopyOptionKey(CopyOptionKey),
-    CopyOptionValueName(CopyOptionValueName),
-    CteName(CteName),
-    Cursor(Cursor),
-    CursorRef(CursorRef),
-    Database(Database),
-    DatabaseRef(DatabaseRef),
-    ElementTableAlias(ElementTableAlias),
-    ElementTableRef(ElementTableRef),
-    ElementTag(ElementTag),
-    EventTrigger(EventTrigger),
-    EventTriggerRef(EventTriggerRef),
-    ExplainOptionName(ExplainOptionName),
-    Extension(Extension),
-    ExtensionRef(ExtensionRef),
-    ExtensionVersion(ExtensionVersion),
-    ForeignDataWrapper(ForeignDataWrapper),
-    ForeignDataWrapperRef(ForeignDataWrapperRef),
-    ForeignOptionName(ForeignOptionName),
-    GrantRoleOptionName(GrantRoleOptionName),
-    JsonPathName(JsonPathName),
-    JsonPathNameRef(JsonPathNameRef),
-    JsonVariableName(JsonVariableName),
-    Label(Label),
-    LabelRef(LabelRef),
-    Language(Language),
-    LanguageRef(LanguageRef),
-    NameRef(NameRef),
-    OptionItemKey(OptionItemKey),
-    OptionItemValueName(OptionItemValueName),
-    ParamName(ParamName),
-    ParamNameRef(ParamNameRef),
-    PathSegment(PathSegment),
-    PathSegmentRef(PathSegmentRef),
-    Policy(Policy),
-    PolicyRef(PolicyRef),
-    PreparedStatement(PreparedStatement),
-    PreparedStatementRef(PreparedStatementRef),
-    PropertyName(PropertyName),
-    PropertyNameRef(PropertyNameRef),
-    Publication(Publication),
-    PublicationRef(PublicationRef),
-    RemoteTableNameRef(RemoteTableNameRef),
-    Role(Role),
-    RoleRef(RoleRef),
-    Rule(Rule),
-    RuleRef(RuleRef),
-    Savepoint(Savepoint),
-    SavepointRef(SavepointRef),
-    Schema(Schema),
-    SchemaRef(SchemaRef),
-    Server(Server),
-    ServerRef(ServerRef),
-    Subscription(Subscription),
-    SubscriptionRef(SubscriptionRef),
-    TableAlias(TableAlias),
-    Tablespace(Tablespace),
-    TablespaceRef(TablespaceRef),
-    TransitionRelationName(TransitionRelationName),
-    Trigger(Trigger),
-    TriggerRef(TriggerRef),
-    VacuumOptionName(VacuumOptionName),
-    VacuumOptionValueName(VacuumOptionValueName),
-    VertexTableRef(VertexTableRef),
-    Window(Window),
-    WindowRef(WindowRef),
-    XmlAttr(XmlAttr),
-    XmlNamespacePrefix(XmlNamespacePrefix),
-    XmlPiTarget(XmlPiTarget),
+pub struct XmlTableFromItem {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlTableFromItem {
+    #[inline]
+    pub fn alias(&self) -> Option<FromAlias> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn xml_table(&self) -> Option<XmlTable> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn lateral_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::LATERAL_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AnyNameRef {
-    AccessMethodRef(AccessMethodRef),
-    BindParamNameRef(BindParamNameRef),
-    ChannelRef(ChannelRef),
-    ColumnNameRef(ColumnNameRef),
-    CompositeFieldRef(CompositeFieldRef),
-    CursorRef(CursorRef),
-    DatabaseRef(DatabaseRef),
-    ElementTableRef(ElementTableRef),
-    EventTriggerRef(EventTriggerRef),
-    ExtensionRef(ExtensionRef),
-    ForeignDataWrapperRef(ForeignDataWrapperRef),
-    JsonPathNameRef(JsonPathNameRef),
-    LabelRef(LabelRef),
-    LanguageRef(LanguageRef),
-    NameRef(NameRef),
-    ParamNameRef(ParamNameRef),
-    PathSegmentRef(PathSegmentRef),
-    PolicyRef(PolicyRef),
-    PreparedStatementRef(PreparedStatementRef),
-    PropertyNameRef(PropertyNameRef),
-    PublicationRef(PublicationRef),
-    RemoteTableNameRef(RemoteTableNameRef),
-    RoleRef(RoleRef),
-    RuleRef(RuleRef),
-    SavepointRef(SavepointRef),
-    SchemaRef(SchemaRef),
-    ServerRef(ServerRef),
-    SubscriptionRef(SubscriptionRef),
-    TablespaceRef(TablespaceRef),
-    TriggerRef(TriggerRef),
-    VertexTableRef(VertexTableRef),
-    WindowRef(WindowRef),
+pub struct XmlVersionExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlVersionExpr {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    #[inline]
+    pub fn version_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VERSION_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AsFuncTarget {
-    AsDefinition(AsDefinition),
-    AsObjFile(AsObjFile),
+pub struct XmlVersionNoValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl XmlVersionNoValue {
+    #[inline]
+    pub fn no_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::NO_KW)
+    }
+    #[inline]
+    pub fn value_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VALUE_KW)
+    }
+    #[inline]
+    pub fn version_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::VERSION_KW)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum BeginFuncOption {
-    ReturnFuncOption(ReturnFuncOption),
-    Stmt(Stmt),
+pub enum AccessMethodType {
+    AccessMethodTypeIndex(AccessMethodTypeIndex),
+    AccessMethodTypeTable(AccessMethodTypeTable),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum BetweenSymmetry {
-    Asymmetric(Asymmetric),
-    Symmetric(Symmetric),
+pub enum Accessor {
+    FieldAccessor(FieldAccessor),
+    IndexAccessor(IndexAccessor),
+    SliceAccessor(SliceAccessor),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CastContext {
-    AsAssignment(AsAssignment),
-    AsImplicit(AsImplicit),
+pub enum AllOrDistinct {
+    All(All),
+    Distinct(Distinct),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CastMethod {
-    WithFunction(WithFunction),
-    WithInout(WithInout),
-    WithoutFunction(WithoutFunction),
+pub enum AllPublicationObject {
+    AllPublicationSequences(AllPublicationSequences),
+    AllPublicationTables(AllPublicationTables),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ChainClause {
-    AndChain(AndChain),
-    AndNoChain(AndNoChain),
+pub enum AlterAggregateAction {
+    AggregateRenameTo(AggregateRenameTo),
+    OwnerTo(OwnerTo),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CheckOptionLevel {
-    CascadedCheckOption(CascadedCheckOption),
-    LocalCheckOption(LocalCheckOption),
+pub enum AlterCollationAction {
+    CollationRenameTo(CollationRenameTo),
+    OwnerTo(OwnerTo),
+    RefreshVersion(RefreshVersion),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ColumnConstraint {
-    CheckConstraint(CheckConstraint),
-    DefaultConstraint(DefaultConstraint),
-    ExcludeConstraint(ExcludeConstraint),
-    GeneratedConstraint(GeneratedConstraint),
-    NotNullConstraint(NotNullConstraint),
-    NullConstraint(NullConstraint),
-    PrimaryKeyConstraint(PrimaryKeyConstraint),
-    ReferencesConstraint(ReferencesConstraint),
-    UniqueConstraint(UniqueConstraint),
+pub enum AlterColumnOption {
+    AddGenerated(AddGenerated),
+    DropDefault(DropDefault),
+    DropExpression(DropExpression),
+    DropIdentity(DropIdentity),
+    DropNotNull(DropNotNull),
+    Inherit(Inherit),
+    NoInherit(NoInherit),
+    ResetOptions(ResetOptions),
+    Restart(Restart),
+    SetCompression(SetCompression),
+    SetDefault(SetDefault),
+    SetExpression(SetExpression),
+    SetGenerated(SetGenerated),
+    SetGeneratedOptions(SetGeneratedOptions),
+    SetNotNull(SetNotNull),
+    SetOptions(SetOptions),
+    SetOptionsList(SetOptionsList),
+    SetSequenceOption(SetSequenceOption),
+    SetStatistics(SetStatistics),
+    SetStorage(SetStorage),
+    SetType(SetType),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CommentObject {
-    ObjectAccessMethod(ObjectAccessMethod),
-    ObjectAggregate(ObjectAggregate),
-    ObjectCast(ObjectCast),
-    ObjectCollation(ObjectCollation),
-    ObjectColumn(ObjectColumn),
-    ObjectConstraint(ObjectConstraint),
-    ObjectConversion(ObjectConversion),
-    ObjectDatabase(ObjectDatabase),
-    ObjectDomain(ObjectDomain),
-    ObjectEventTrigger(ObjectEventTrigger),
-    ObjectExtension(ObjectExtension),
-    ObjectForeignDataWrapper(ObjectForeignDataWrapper),
-    ObjectForeignTable(ObjectForeignTable),
-    ObjectFunction(ObjectFunction),
-    ObjectIndex(ObjectIndex),
-    ObjectLanguage(ObjectLanguage),
-    ObjectLargeObject(ObjectLargeObject),
-    ObjectMaterializedView(ObjectMaterializedView),
-    ObjectOperator(ObjectOperator),
-    ObjectOperatorClass(ObjectOperatorClass),
-    ObjectOperatorFamily(ObjectOperatorFamily),
-    ObjectPolicy(ObjectPolicy),
-    ObjectProcedure(ObjectProcedure),
-    ObjectPropertyGraph(ObjectPropertyGraph),
-    ObjectPublication(ObjectPublication),
-    ObjectRole(ObjectRole),
-    ObjectRoutine(ObjectRoutine),
-    ObjectRule(ObjectRule),
-    ObjectSchema(ObjectSchema),
-    ObjectSequence(ObjectSequence),
-    ObjectServer(ObjectServer),
-    ObjectStatistics(ObjectStatistics),
-    ObjectSubscription(ObjectSubscription),
-    ObjectTable(ObjectTable),
-    ObjectTablespace(ObjectTablespace),
-    ObjectTextSearchConfig(ObjectTextSearchConfig),
-    ObjectTextSearchDict(ObjectTextSearchDict),
-    ObjectTextSearchParser(ObjectTextSearchParser),
-    ObjectTextSearchTemplate(ObjectTextSearchTemplate),
-    ObjectTransform(ObjectTransform),
-    ObjectTrigger(ObjectTrigger),
-    ObjectType(ObjectType),
-    ObjectView(ObjectView),
+pub enum AlterConversionAction {
+    ConversionRenameTo(ConversionRenameTo),
+    OwnerTo(OwnerTo),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Commit {
-    CommitPrepared(CommitPrepared),
-    CommitTransaction(CommitTransaction),
+pub enum AlterDatabaseAction {
+    DatabaseOptionList(DatabaseOptionList),
+    DatabaseRenameTo(DatabaseRenameTo),
+    OwnerTo(OwnerTo),
+    RefreshCollationVersion(RefreshCollationVersion),
+    ResetConfigParam(ResetConfigParam),
+    SetConfigParam(SetConfigParam),
+    SetTablespace(SetTablespace),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CompoundOp {
-    Except(Except),
-    Intersect(Intersect),
-    Union(Union),
+pub enum AlterDefaultPrivilegesAction {
+    GrantDefaultPrivileges(GrantDefaultPrivileges),
+    RevokeDefaultPrivileges(RevokeDefaultPrivileges),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ConfigAssignment {
-    FromCurrent(FromCurrent),
-    ToConfigValue(ToConfigValue),
+pub enum AlterDomainAction {
+    AddConstraint(AddConstraint),
+    DomainRenameTo(DomainRenameTo),
+    DropConstraint(DropConstraint),
+    DropDefault(DropDefault),
+    DropNotNull(DropNotNull),
+    OwnerTo(OwnerTo),
+    RenameConstraint(RenameConstraint),
+    SetDefault(SetDefault),
+    SetNotNull(SetNotNull),
+    SetSchema(SetSchema),
+    ValidateConstraint(ValidateConstraint),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ConfigValue {
-    ConfigValueName(ConfigValueName),
-    Literal(Literal),
+pub enum AlterEventTriggerAction {
+    Disable(Disable),
+    Enable(Enable),
+    EnableAlways(EnableAlways),
+    EnableReplica(EnableReplica),
+    EventTriggerRenameTo(EventTriggerRenameTo),
+    OwnerTo(OwnerTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ConflictAction {
-    ConflictDoNothing(ConflictDoNothing),
-    ConflictDoSelect(ConflictDoSelect),
-    ConflictDoUpdateSet(ConflictDoUpdateSet),
+pub enum AlterExtensionAction {
+    AlterExtensionAdd(AlterExtensionAdd),
+    AlterExtensionDrop(AlterExtensionDrop),
+    AlterExtensionUpdate(AlterExtensionUpdate),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ConflictTarget {
-    ConflictOnConstraint(ConflictOnConstraint),
-    ConflictOnIndex(ConflictOnIndex),
+pub enum AlterForeignDataWrapperAction {
+    FdwOptionList(FdwOptionList),
+    ForeignDataWrapperRenameTo(ForeignDataWrapperRenameTo),
+    OwnerTo(OwnerTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Constraint {
-    CheckConstraint(CheckConstraint),
-    DefaultConstraint(DefaultConstraint),
-    ExcludeConstraint(ExcludeConstraint),
-    ForeignKeyConstraint(ForeignKeyConstraint),
-    GeneratedConstraint(GeneratedConstraint),
-    NotNullConstraint(NotNullConstraint),
-    NullConstraint(NullConstraint),
-    PrimaryKeyConstraint(PrimaryKeyConstraint),
-    ReferencesConstraint(ReferencesConstraint),
-    UniqueConstraint(UniqueConstraint),
+pub enum AlterFunctionAction {
+    DependsOnExtension(DependsOnExtension),
+    FuncOptionList(FuncOptionList),
+    FunctionRenameTo(FunctionRenameTo),
+    NoDependsOnExtension(NoDependsOnExtension),
+    OwnerTo(OwnerTo),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ConstraintOption {
-    DeferrableConstraintOption(DeferrableConstraintOption),
-    Enforced(Enforced),
-    Inherit(Inherit),
-    InitiallyDeferredConstraintOption(InitiallyDeferredConstraintOption),
-    InitiallyImmediateConstraintOption(InitiallyImmediateConstraintOption),
-    NoInherit(NoInherit),
-    NotDeferrableConstraintOption(NotDeferrableConstraintOption),
-    NotEnforced(NotEnforced),
-    NotValid(NotValid),
+pub enum AlterGroupAction {
+    AddUsers(AddUsers),
+    DropUsers(DropUsers),
+    RoleRenameTo(RoleRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ConstraintTiming {
-    Deferred(Deferred),
-    Immediate(Immediate),
+pub enum AlterIndexAction {
+    AlterSetStatistics(AlterSetStatistics),
+    AttachIndexPartition(AttachIndexPartition),
+    DependsOnExtension(DependsOnExtension),
+    IndexRenameTo(IndexRenameTo),
+    NoDependsOnExtension(NoDependsOnExtension),
+    ResetOptions(ResetOptions),
+    SetOptions(SetOptions),
+    SetTablespace(SetTablespace),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CopyDirection {
-    CopyFrom(CopyFrom),
-    CopyTo(CopyTo),
+pub enum AlterLanguageAction {
+    LanguageRenameTo(LanguageRenameTo),
+    OwnerTo(OwnerTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CopyForceKind {
-    ForceNotNull(ForceNotNull),
-    ForceNull(ForceNull),
-    ForceQuote(ForceQuote),
+pub enum AlterMaterializedViewAction {
+    DependsOnExtension(DependsOnExtension),
+    NoDependsOnExtension(NoDependsOnExtension),
+    RenameColumn(RenameColumn),
+    SetSchema(SetSchema),
+    ViewRenameTo(ViewRenameTo),
+    AlterTableAction(AlterTableAction),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CopySource {
-    CopyProgram(CopyProgram),
-    CopyStdin(CopyStdin),
-    CopyStdout(CopyStdout),
+pub enum AlterOperatorAction {
+    OwnerTo(OwnerTo),
+    SetOptions(SetOptions),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CopyTarget {
-    CopyProgram(CopyProgram),
-    CopyStdout(CopyStdout),
+pub enum AlterOperatorClassAction {
+    OpClassRenameTo(OpClassRenameTo),
+    OwnerTo(OwnerTo),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CreateCollationDefinition {
-    AttributeList(AttributeList),
-    CollationFrom(CollationFrom),
+pub enum AlterOperatorFamilyAction {
+    AddOpClassOptions(AddOpClassOptions),
+    DropOpClassOptions(DropOpClassOptions),
+    OpFamilyRenameTo(OpFamilyRenameTo),
+    OwnerTo(OwnerTo),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CreateSchemaTarget {
-    AuthorizationSchema(AuthorizationSchema),
-    NamedSchema(NamedSchema),
+pub enum AlterOption {
+    AddForeignOption(AddForeignOption),
+    DropForeignOption(DropForeignOption),
+    SetForeignOption(SetForeignOption),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CreateTableAsQuery {
-    Execute(Execute),
-    SelectVariant(SelectVariant),
+pub enum AlterPolicyAction {
+    AlterPolicyTo(AlterPolicyTo),
+    PolicyRenameTo(PolicyRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CreateTypeKind {
-    BaseType(BaseType),
-    CompositeType(CompositeType),
-    EnumType(EnumType),
-    RangeType(RangeType),
+pub enum AlterProcedureAction {
+    DependsOnExtension(DependsOnExtension),
+    FuncOptionList(FuncOptionList),
+    NoDependsOnExtension(NoDependsOnExtension),
+    OwnerTo(OwnerTo),
+    ProcedureRenameTo(ProcedureRenameTo),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CursorAction {
-    Absolute(Absolute),
-    All(All),
-    Backward(Backward),
-    First(First),
-    Forward(Forward),
-    Last(Last),
-    Next(Next),
-    Prior(Prior),
-    Relative(Relative),
-    Expr(Expr),
+pub enum AlterPropertyGraphAction {
+    AddVertexEdgeLabelProperties(AddVertexEdgeLabelProperties),
+    AddVertexEdgeTables(AddVertexEdgeTables),
+    AlterVertexEdgeLabels(AlterVertexEdgeLabels),
+    DropEdgeTables(DropEdgeTables),
+    DropVertexEdgeLabel(DropVertexEdgeLabel),
+    DropVertexEdgeLabelProperties(DropVertexEdgeLabelProperties),
+    DropVertexTables(DropVertexTables),
+    OwnerTo(OwnerTo),
+    PropertyGraphRenameTo(PropertyGraphRenameTo),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CursorHold {
-    WithHold(WithHold),
-    WithoutHold(WithoutHold),
+pub enum AlterPublicationAction {
+    AddPublicationObjects(AddPublicationObjects),
+    DropPublicationObjects(DropPublicationObjects),
+    OwnerTo(OwnerTo),
+    PublicationRenameTo(PublicationRenameTo),
+    SetAllPublicationObjectList(SetAllPublicationObjectList),
+    SetOptions(SetOptions),
+    SetPublicationObjects(SetPublicationObjects),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CursorScroll {
-    NoScroll(NoScroll),
-    Scroll(Scroll),
+pub enum AlterRoleAction {
+    ResetConfigParam(ResetConfigParam),
+    RoleOptionList(RoleOptionList),
+    RoleRenameTo(RoleRenameTo),
+    SetConfigParam(SetConfigParam),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CursorSensitivity {
-    Asensitive(Asensitive),
-    Insensitive(Insensitive),
+pub enum AlterRoutineAction {
+    DependsOnExtension(DependsOnExtension),
+    FuncOptionList(FuncOptionList),
+    NoDependsOnExtension(NoDependsOnExtension),
+    OwnerTo(OwnerTo),
+    RoutineRenameTo(RoutineRenameTo),
+    SetSchema(SetSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum DataOption {
-    WithData(WithData),
-    WithNoData(WithNoData),
+pub enum AlterSchemaAction {
+    OwnerTo(OwnerTo),
+    SchemaRenameTo(SchemaRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum DatabaseOption {
-    DatabaseOptionConnectionLimit(DatabaseOptionConnectionLimit),
-    DatabaseOptionEncoding(DatabaseOptionEncoding),
-    DatabaseOptionGeneric(DatabaseOptionGeneric),
-    DatabaseOptionOwner(DatabaseOptionOwner),
-    DatabaseOptionTablespace(DatabaseOptionTablespace),
-    DatabaseOptionTemplate(DatabaseOptionTemplate),
+pub enum AlterSequenceAction {
+    OwnerTo(OwnerTo),
+    SequenceRenameTo(SequenceRenameTo),
+    SetLogged(SetLogged),
+    SetSchema(SetSchema),
+    SetUnlogged(SetUnlogged),
+    SequenceOption(SequenceOption),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum DetachPartitionOption {
-    DetachConcurrently(DetachConcurrently),
-    DetachFinalize(DetachFinalize),
+pub enum AlterServerAction {
+    AlterOptionList(AlterOptionList),
+    OwnerTo(OwnerTo),
+    ServerRenameTo(ServerRenameTo),
+    ServerVersion(ServerVersion),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum DiscardTarget {
-    DiscardAll(DiscardAll),
-    DiscardPlans(DiscardPlans),
-    DiscardSequences(DiscardSequences),
-    DiscardTemp(DiscardTemp),
+pub enum AlterStatisticsAction {
+    OwnerTo(OwnerTo),
+    SetSchema(SetSchema),
+    SetStatistics(SetStatistics),
+    StatisticsRenameTo(StatisticsRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum DropBehavior {
-    Cascade(Cascade),
-    Restrict(Restrict),
+pub enum AlterSubscriptionAction {
+    AddPublication(AddPublication),
+    ConnectionClause(ConnectionClause),
+    DisableSubscription(DisableSubscription),
+    DropSubscriptionPublication(DropSubscriptionPublication),
+    EnableSubscription(EnableSubscription),
+    OwnerTo(OwnerTo),
+    RefreshPublication(RefreshPublication),
+    ServerClause(ServerClause),
+    SetOptions(SetOptions),
+    SetPublication(SetPublication),
+    SkipSubscription(SkipSubscription),
+    SubscriptionRenameTo(SubscriptionRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum DropOpClassOption {
-    DropOpClassOptionFunction(DropOpClassOptionFunction),
-    DropOpClassOptionOperator(DropOpClassOptionOperator),
+pub enum AlterSystemAction {
+    ResetConfigParam(ResetConfigParam),
+    SetConfigParam(SetConfigParam),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ElementTableLabelAndProperties {
-    LabelAndPropertiesList(LabelAndPropertiesList),
-    ElementTableProperties(ElementTableProperties),
+pub enum AlterTableAction {
+    AddColumn(AddColumn),
+    AddConstraint(AddConstraint),
+    AlterColumn(AlterColumn),
+    AlterConstraint(AlterConstraint),
+    AttachPartition(AttachPartition),
+    ClusterOn(ClusterOn),
+    DetachPartition(DetachPartition),
+    DisableRls(DisableRls),
+    DisableRule(DisableRule),
+    DisableTrigger(DisableTrigger),
+    DropColumn(DropColumn),
+    DropConstraint(DropConstraint),
+    EnableAlwaysRule(EnableAlwaysRule),
+    EnableAlwaysTrigger(EnableAlwaysTrigger),
+    EnableReplicaRule(EnableReplicaRule),
+    EnableReplicaTrigger(EnableReplicaTrigger),
+    EnableRls(EnableRls),
+    EnableRule(EnableRule),
+    EnableTrigger(EnableTrigger),
+    ForceRls(ForceRls),
+    InheritTable(InheritTable),
+    MergePartitions(MergePartitions),
+    NoForceRls(NoForceRls),
+    NoInheritTable(NoInheritTable),
+    NotOf(NotOf),
+    OfType(OfType),
+    OptionItemList(OptionItemList),
+    OwnerTo(OwnerTo),
+    RenameColumn(RenameColumn),
+    RenameConstraint(RenameConstraint),
+    ReplicaIdentity(ReplicaIdentity),
+    ResetOptions(ResetOptions),
+    SetAccessMethod(SetAccessMethod),
+    SetLogged(SetLogged),
+    SetOptions(SetOptions),
+    SetSchema(SetSchema),
+    SetTablespace(SetTablespace),
+    SetUnlogged(SetUnlogged),
+    SetWithoutCluster(SetWithoutCluster),
+    SetWithoutOids(SetWithoutOids),
+    SplitPartition(SplitPartition),
+    TableRenameTo(TableRenameTo),
+    ValidateConstraint(ValidateConstraint),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ElementTableProperties {
-    AllProperties(AllProperties),
-    NoProperties(NoProperties),
-    Properties(Properties),
+pub enum AlterTablespaceAction {
+    OwnerTo(OwnerTo),
+    ResetOptions(ResetOptions),
+    SetOptions(SetOptions),
+    TablespaceRenameTo(TablespaceRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ExplainMode {
-    ExplainAnalyze(ExplainAnalyze),
-    ExplainOptionList(ExplainOptionList),
-    ExplainVerbose(ExplainVerbose),
+pub enum AlterTextSearchConfigurationAction {
+    AddMapping(AddMapping),
+    AlterMapping(AlterMapping),
+    DropMapping(DropMapping),
+    OwnerTo(OwnerTo),
+    SetSchema(SetSchema),
+    TextSearchConfigurationRenameTo(TextSearchConfigurationRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ExplainStmt {
-    CompoundSelect(CompoundSelect),
-    CreateMaterializedView(CreateMaterializedView),
-    CreateTableAs(CreateTableAs),
-    Declare(Declare),
-    Delete(Delete),
-    Execute(Execute),
-    Insert(Insert),
-    Merge(Merge),
-    ParenSelect(ParenSelect),
-    Select(Select),
-    SelectInto(SelectInto),
-    Table(Table),
-    Update(Update),
-    Values(Values),
+pub enum AlterTextSearchDictionaryAction {
+    AttributeList(AttributeList),
+    OwnerTo(OwnerTo),
+    SetSchema(SetSchema),
+    TextSearchDictionaryRenameTo(TextSearchDictionaryRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Expr {
-    ArrayExpr(ArrayExpr),
-    BetweenExpr(BetweenExpr),
-    BinExpr(BinExpr),
-    CallExpr(CallExpr),
-    CaseExpr(CaseExpr),
-    CastExpr(CastExpr),
-    Collate(Collate),
-    FieldExpr(FieldExpr),
-    IndexExpr(IndexExpr),
-    Literal(Literal),
-    NameRef(NameRef),
-    ParenExpr(ParenExpr),
-    PostfixExpr(PostfixExpr),
-    PrefixExpr(PrefixExpr),
-    SliceExpr(SliceExpr),
-    TupleExpr(TupleExpr),
+pub enum AlterTextSearchParserAction {
+    SetSchema(SetSchema),
+    TextSearchParserRenameTo(TextSearchParserRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ExtensionMemberObject {
-    ObjectAccessMethod(ObjectAccessMethod),
-    ObjectAggregate(ObjectAggregate),
-    ObjectCast(ObjectCast),
-    ObjectCollation(ObjectCollation),
-    ObjectConversion(ObjectConversion),
-    ObjectDatabase(ObjectDatabase),
-    ObjectDomain(ObjectDomain),
-    ObjectEventTrigger(ObjectEventTrigger),
-    ObjectExtension(ObjectExtension),
-    ObjectForeignDataWrapper(ObjectForeignDataWrapper),
-    ObjectForeignTable(ObjectForeignTable),
-    ObjectFunction(ObjectFunction),
-    ObjectIndex(ObjectIndex),
-    ObjectLanguage(ObjectLanguage),
-    ObjectMaterializedView(ObjectMaterializedView),
-    ObjectOperator(ObjectOperator),
-    ObjectOperatorClass(ObjectOperatorClass),
-    ObjectOperatorFamily(ObjectOperatorFamily),
-    ObjectProcedure(ObjectProcedure),
-    ObjectPublication(ObjectPublication),
-    ObjectRole(ObjectRole),
-    ObjectRoutine(ObjectRoutine),
-    ObjectSchema(ObjectSchema),
-    ObjectSequence(ObjectSequence),
-    ObjectServer(ObjectServer),
-    ObjectStatistics(ObjectStatistics),
-    ObjectSubscription(ObjectSubscription),
-    ObjectTable(ObjectTable),
-    ObjectTablespace(ObjectTablespace),
-    ObjectTextSearchConfig(ObjectTextSearchConfig),
-    ObjectTextSearchDict(ObjectTextSearchDict),
-    ObjectTextSearchParser(ObjectTextSearchParser),
-    ObjectTextSearchTemplate(ObjectTextSearchTemplate),
-    ObjectTransform(ObjectTransform),
-    ObjectType(ObjectType),
-    ObjectView(ObjectView),
+pub enum AlterTextSearchTemplateAction {
+    SetSchema(SetSchema),
+    TextSearchTemplateRenameTo(TextSearchTemplateRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ExtractField {
-    ExtractFieldLiteral(ExtractFieldLiteral),
-    ExtractFieldName(ExtractFieldName),
+pub enum AlterTriggerAction {
+    DependsOnExtension(DependsOnExtension),
+    NoDependsOnExtension(NoDependsOnExtension),
+    TriggerRenameTo(TriggerRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FdwOption {
-    OptionAlterOptionList(OptionAlterOptionList),
-    OptionConnection(OptionConnection),
-    OptionHandler(OptionHandler),
-    OptionNoConnection(OptionNoConnection),
-    OptionNoHandler(OptionNoHandler),
-    OptionNoValidator(OptionNoValidator),
-    OptionValidator(OptionValidator),
+pub enum AlterTypeAction {
+    AddValue(AddValue),
+    AlterTypeAttributeActionList(AlterTypeAttributeActionList),
+    OwnerTo(OwnerTo),
+    RenameAttribute(RenameAttribute),
+    RenameValue(RenameValue),
+    SetOptions(SetOptions),
+    SetSchema(SetSchema),
+    TypeRenameTo(TypeRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FetchQuantity {
-    FetchOnly(FetchOnly),
-    WithTies(WithTies),
+pub enum AlterTypeAttributeAction {
+    AddAttribute(AddAttribute),
+    AlterAttribute(AlterAttribute),
+    DropAttribute(DropAttribute),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FrameBound {
-    CurrentRow(CurrentRow),
-    ExprFollowing(ExprFollowing),
-    ExprPreceding(ExprPreceding),
-    UnboundedFollowing(UnboundedFollowing),
-    UnboundedPreceding(UnboundedPreceding),
+pub enum AlterUserAction {
+    ResetConfigParam(ResetConfigParam),
+    RoleOptionList(RoleOptionList),
+    RoleRenameTo(RoleRenameTo),
+    SetConfigParam(SetConfigParam),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FrameExcludeTarget {
-    CurrentRow(CurrentRow),
-    Group(Group),
-    NoOthers(NoOthers),
-    Ties(Ties),
+pub enum AlterViewAction {
+    AlterViewColumn(AlterViewColumn),
+    OwnerTo(OwnerTo),
+    RenameColumn(RenameColumn),
+    ResetOptions(ResetOptions),
+    SetOptions(SetOptions),
+    SetSchema(SetSchema),
+    ViewRenameTo(ViewRenameTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FrameExtent {
-    FrameBetween(FrameBetween),
-    FrameBound(FrameBound),
+pub enum AlterViewColumnAction {
+    DropDefault(DropDefault),
+    SetDefault(SetDefault),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FrameUnits {
-    FrameGroups(FrameGroups),
-    FrameRange(FrameRange),
-    FrameRows(FrameRows),
+pub enum AnyName {
+    AccessMethod(AccessMethod),
+    AccessMethodRef(AccessMethodRef),
+    AttributeName(AttributeName),
+    AttributeNamespace(AttributeNamespace),
+    BindParamNameRef(BindParamNameRef),
+    Channel(Channel),
+    ChannelRef(ChannelRef),
+    ColumnName(ColumnName),
+    ColumnNameRef(ColumnNameRef),
+    CompositeField(CompositeField),
+    CompositeFieldRef(CompositeFieldRef),
+    ConfigValueName(ConfigValueName),
+    ConstraintName(ConstraintName),
+    CopyOptionKey(CopyOptionKey),
+    CopyOptionValueName(CopyOptionValueName),
+    CteName(CteName),
+    Cursor(Cursor),
+    CursorRef(CursorRef),
+    Database(Database),
+    DatabaseRef(DatabaseRef),
+    ElementTableAlias(ElementTableAlias),
+    ElementTableRef(ElementTableRef),
+    ElementTag(ElementTag),
+    EventTrigger(EventTrigger),
+    EventTriggerRef(EventTriggerRef),
+    ExplainOptionName(ExplainOptionName),
+    Extension(Extension),
+    ExtensionRef(ExtensionRef),
+    ExtensionVersion(ExtensionVersion),
+    ForeignDataWrapper(ForeignDataWrapper),
+    ForeignDataWrapperRef(ForeignDataWrapperRef),
+    ForeignOptionName(ForeignOptionName),
+    GrantRoleOptionName(GrantRoleOptionName),
+    JsonPathName(JsonPathName),
+    JsonPathNameRef(JsonPathNameRef),
+    JsonVariableName(JsonVariableName),
+    Label(Label),
+    LabelRef(LabelRef),
+    Language(Language),
+    LanguageRef(LanguageRef),
+    NameRef(NameRef),
+    OptionItemKey(OptionItemKey),
+    OptionItemValueName(OptionItemValueName),
+    ParamName(ParamName),
+    ParamNameRef(ParamNameRef),
+    PathSegment(PathSegment),
+    PathSegmentRef(PathSegmentRef),
+    PlpgsqlLabel(PlpgsqlLabel),
+    PlpgsqlVariable(PlpgsqlVariable),
+    Policy(Policy),
+    PolicyRef(PolicyRef),
+    PreparedStatement(PreparedStatement),
+    PreparedStatementRef(PreparedStatementRef),
+    PropertyName(PropertyName),
+    PropertyNameRef(PropertyNameRef),
+    Publication(Publication),
+    PublicationRef(PublicationRef),
+    RemoteTableNameRef(RemoteTableNameRef),
+    Role(Role),
+    RoleRef(RoleRef),
+    Rule(Rule),
+    RuleRef(RuleRef),
+    Savepoint(Savepoint),
+    SavepointRef(SavepointRef),
+    Schema(Schema),
+    SchemaRef(SchemaRef),
+    Server(Server),
+    ServerRef(ServerRef),
+    Subscription(Subscription),
+    SubscriptionRef(SubscriptionRef),
+    TableAlias(TableAlias),
+    Tablespace(Tablespace),
+    TablespaceRef(TablespaceRef),
+    TransitionRelationName(TransitionRelationName),
+    Trigger(Trigger),
+    TriggerRef(TriggerRef),
+    VacuumOptionName(VacuumOptionName),
+    VacuumOptionValueName(VacuumOptionValueName),
+    VertexTableRef(VertexTableRef),
+    Window(Window),
+    WindowRef(WindowRef),
+    XmlAttr(XmlAttr),
+    XmlNamespacePrefix(XmlNamespacePrefix),
+    XmlPiTarget(XmlPiTarget),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FromAliasColumns {
-    ColumnDefList(ColumnDefList),
-    ColumnList(ColumnList),
+pub enum AnyNameRef {
+    AccessMethodRef(AccessMethodRef),
+    BindParamNameRef(BindParamNameRef),
+    ChannelRef(ChannelRef),
+    ColumnNameRef(ColumnNameRef),
+    CompositeFieldRef(CompositeFieldRef),
+    CursorRef(CursorRef),
+    DatabaseRef(DatabaseRef),
+    ElementTableRef(ElementTableRef),
+    EventTriggerRef(EventTriggerRef),
+    ExtensionRef(ExtensionRef),
+    ForeignDataWrapperRef(ForeignDataWrapperRef),
+    JsonPathNameRef(JsonPathNameRef),
+    LabelRef(LabelRef),
+    LanguageRef(LanguageRef),
+    NameRef(NameRef),
+    ParamNameRef(ParamNameRef),
+    PathSegmentRef(PathSegmentRef),
+    PolicyRef(PolicyRef),
+    PreparedStatementRef(PreparedStatementRef),
+    PropertyNameRef(PropertyNameRef),
+    PublicationRef(PublicationRef),
+    RemoteTableNameRef(RemoteTableNameRef),
+    RoleRef(RoleRef),
+    RuleRef(RuleRef),
+    SavepointRef(SavepointRef),
+    SchemaRef(SchemaRef),
+    ServerRef(ServerRef),
+    SubscriptionRef(SubscriptionRef),
+    TablespaceRef(TablespaceRef),
+    TriggerRef(TriggerRef),
+    VertexTableRef(VertexTableRef),
+    WindowRef(WindowRef),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FromItem {
-    ExprFromItem(ExprFromItem),
-    FunctionFromItem(FunctionFromItem),
-    GraphTableFromItem(GraphTableFromItem),
-    JsonTableFromItem(JsonTableFromItem),
-    ParenFromItem(ParenFromItem),
-    RelationFromItem(RelationFromItem),
-    RowsFromItem(RowsFromItem),
-    XmlTableFromItem(XmlTableFromItem),
+pub enum AsFuncTarget {
+    AsDefinition(AsDefinition),
+    AsObjFile(AsObjFile),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum FuncOption {
-    AsFuncOption(AsFuncOption),
-    BeginFuncOptionList(BeginFuncOptionList),
-    CalledOnNullInputFuncOption(CalledOnNullInputFuncOption),
-    CostFuncOption(CostFuncOption),
-    LanguageFuncOption(LanguageFuncOption),
-    LeakproofFuncOption(LeakproofFuncOption),
-    NotLeakproofFuncOption(NotLeakproofFuncOption),
-    ParallelFuncOption(ParallelFuncOption),
-    ResetFuncOption(ResetFuncOption),
+pub enum BeginFuncOption {
     ReturnFuncOption(ReturnFuncOption),
-    ReturnsNullOnNullInputFuncOption(ReturnsNullOnNullInputFuncOption),
-    RowsFuncOption(RowsFuncOption),
-    SecurityDefinerFuncOption(SecurityDefinerFuncOption),
-    SecurityInvokerFuncOption(SecurityInvokerFuncOption),
-    SetFuncOption(SetFuncOption),
-    StrictFuncOption(StrictFuncOption),
-    SupportFuncOption(SupportFuncOption),
-    TransformFuncOption(TransformFuncOption),
-    WindowFuncOption(WindowFuncOption),
-    VolatilityFuncOption(VolatilityFuncOption),
+    Stmt(Stmt),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum GeneratedAs {
-    GeneratedIdentity(GeneratedIdentity),
-    GeneratedStored(GeneratedStored),
+pub enum BetweenSymmetry {
+    Asymmetric(Asymmetric),
+    Symmetric(Symmetric),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum GeneratedKind {
-    Stored(Stored),
-    Virtual(Virtual),
+pub enum CastContext {
+    AsAssignment(AsAssignment),
+    AsImplicit(AsImplicit),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum GeneratedWhen {
-    GeneratedAlways(GeneratedAlways),
-    GeneratedByDefault(GeneratedByDefault),
+pub enum CastMethod {
+    WithFunction(WithFunction),
+    WithInout(WithInout),
+    WithoutFunction(WithoutFunction),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum GroupBy {
-    GroupingCube(GroupingCube),
-    GroupingExpr(GroupingExpr),
-    GroupingRollup(GroupingRollup),
-    GroupingSets(GroupingSets),
+pub enum ChainClause {
+    AndChain(AndChain),
+    AndNoChain(AndNoChain),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum HasParamList {
-    Aggregate(Aggregate),
-    CreateAggregate(CreateAggregate),
-    CreateFunction(CreateFunction),
-    CreateProcedure(CreateProcedure),
-    FunctionSig(FunctionSig),
-    ProcedureSig(ProcedureSig),
-    RoutineSig(RoutineSig),
+pub enum CheckOptionLevel {
+    CascadedCheckOption(CascadedCheckOption),
+    LocalCheckOption(LocalCheckOption),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum IdentityAction {
-    ContinueIdentity(ContinueIdentity),
-    RestartIdentity(RestartIdentity),
+pub enum ColumnConstraint {
+    CheckConstraint(CheckConstraint),
+    DefaultConstraint(DefaultConstraint),
+    ExcludeConstraint(ExcludeConstraint),
+    GeneratedConstraint(GeneratedConstraint),
+    NotNullConstraint(NotNullConstraint),
+    NullConstraint(NullConstraint),
+    PrimaryKeyConstraint(PrimaryKeyConstraint),
+    ReferencesConstraint(ReferencesConstraint),
+    UniqueConstraint(UniqueConstraint),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ImportTableFilter {
-    ExceptTables(ExceptTables),
-    LimitToTables(LimitToTables),
+pub enum CommentObject {
+    ObjectAccessMethod(ObjectAccessMethod),
+    ObjectAggregate(ObjectAggregate),
+    ObjectCast(ObjectCast),
+    ObjectCollation(ObjectCollation),
+    ObjectColumn(ObjectColumn),
+    ObjectConstraint(ObjectConstraint),
+    ObjectConversion(ObjectConversion),
+    ObjectDatabase(ObjectDatabase),
+    ObjectDomain(ObjectDomain),
+    ObjectEventTrigger(ObjectEventTrigger),
+    ObjectExtension(ObjectExtension),
+    ObjectForeignDataWrapper(ObjectForeignDataWrapper),
+    ObjectForeignTable(ObjectForeignTable),
+    ObjectFunction(ObjectFunction),
+    ObjectIndex(ObjectIndex),
+    ObjectLanguage(ObjectLanguage),
+    ObjectLargeObject(ObjectLargeObject),
+    ObjectMaterializedView(ObjectMaterializedView),
+    ObjectOperator(ObjectOperator),
+    ObjectOperatorClass(ObjectOperatorClass),
+    ObjectOperatorFamily(ObjectOperatorFamily),
+    ObjectPolicy(ObjectPolicy),
+    ObjectProcedure(ObjectProcedure),
+    ObjectPropertyGraph(ObjectPropertyGraph),
+    ObjectPublication(ObjectPublication),
+    ObjectRole(ObjectRole),
+    ObjectRoutine(ObjectRoutine),
+    ObjectRule(ObjectRule),
+    ObjectSchema(ObjectSchema),
+    ObjectSequence(ObjectSequence),
+    ObjectServer(ObjectServer),
+    ObjectStatistics(ObjectStatistics),
+    ObjectSubscription(ObjectSubscription),
+    ObjectTable(ObjectTable),
+    ObjectTablespace(ObjectTablespace),
+    ObjectTextSearchConfig(ObjectTextSearchConfig),
+    ObjectTextSearchDict(ObjectTextSearchDict),
+    ObjectTextSearchParser(ObjectTextSearchParser),
+    ObjectTextSearchTemplate(ObjectTextSearchTemplate),
+    ObjectTransform(ObjectTransform),
+    ObjectTrigger(ObjectTrigger),
+    ObjectType(ObjectType),
+    ObjectView(ObjectView),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum InsertSource {
-    DefaultValues(DefaultValues),
-    SelectVariant(SelectVariant),
+pub enum Commit {
+    CommitPrepared(CommitPrepared),
+    CommitTransaction(CommitTransaction),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum IntervalQualifier {
-    IntervalDay(IntervalDay),
-    IntervalHour(IntervalHour),
-    IntervalMinute(IntervalMinute),
-    IntervalMonth(IntervalMonth),
-    IntervalSecond(IntervalSecond),
-    IntervalYear(IntervalYear),
+pub enum CompoundOp {
+    Except(Except),
+    Intersect(Intersect),
+    Union(Union),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JoinCondition {
-    JoinUsingClause(JoinUsingClause),
-    OnClause(OnClause),
+pub enum ConfigAssignment {
+    FromCurrent(FromCurrent),
+    ToConfigValue(ToConfigValue),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JoinType {
-    JoinCross(JoinCross),
-    JoinFull(JoinFull),
-    JoinInner(JoinInner),
-    JoinLeft(JoinLeft),
-    JoinRight(JoinRight),
+pub enum ConfigValue {
+    ConfigValueName(ConfigValueName),
+    Literal(Literal),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JsonBehavior {
-    JsonBehaviorDefault(JsonBehaviorDefault),
-    JsonBehaviorEmptyArray(JsonBehaviorEmptyArray),
-    JsonBehaviorEmptyObject(JsonBehaviorEmptyObject),
-    JsonBehaviorError(JsonBehaviorError),
-    JsonBehaviorFalse(JsonBehaviorFalse),
-    JsonBehaviorNull(JsonBehaviorNull),
-    JsonBehaviorTrue(JsonBehaviorTrue),
-    JsonBehaviorUnknown(JsonBehaviorUnknown),
+pub enum ConflictAction {
+    ConflictDoNothing(ConflictDoNothing),
+    ConflictDoSelect(ConflictDoSelect),
+    ConflictDoUpdateSet(ConflictDoUpdateSet),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JsonKeysUniqueClause {
-    JsonWithUniqueKeys(JsonWithUniqueKeys),
-    JsonWithoutUniqueKeys(JsonWithoutUniqueKeys),
+pub enum ConflictTarget {
+    ConflictOnConstraint(ConflictOnConstraint),
+    ConflictOnIndex(ConflictOnIndex),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JsonNullClause {
-    JsonAbsentOnNull(JsonAbsentOnNull),
-    JsonNullOnNull(JsonNullOnNull),
+pub enum Constraint {
+    CheckConstraint(CheckConstraint),
+    DefaultConstraint(DefaultConstraint),
+    ExcludeConstraint(ExcludeConstraint),
+    ForeignKeyConstraint(ForeignKeyConstraint),
+    GeneratedConstraint(GeneratedConstraint),
+    NotNullConstraint(NotNullConstraint),
+    NullConstraint(NullConstraint),
+    PrimaryKeyConstraint(PrimaryKeyConstraint),
+    ReferencesConstraint(ReferencesConstraint),
+    UniqueConstraint(UniqueConstraint),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JsonTableColumn {
-    JsonTableExistsColumn(JsonTableExistsColumn),
-    JsonTableNestedColumn(JsonTableNestedColumn),
-    JsonTableOrdinalityColumn(JsonTableOrdinalityColumn),
-    JsonTableValueColumn(JsonTableValueColumn),
+pub enum ConstraintOption {
+    DeferrableConstraintOption(DeferrableConstraintOption),
+    Enforced(Enforced),
+    Inherit(Inherit),
+    InitiallyDeferredConstraintOption(InitiallyDeferredConstraintOption),
+    InitiallyImmediateConstraintOption(InitiallyImmediateConstraintOption),
+    NoInherit(NoInherit),
+    NotDeferrableConstraintOption(NotDeferrableConstraintOption),
+    NotEnforced(NotEnforced),
+    NotValid(NotValid),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JsonTablePlan {
-    JsonPathNameRef(JsonPathNameRef),
-    JsonTablePlanChoice(JsonTablePlanChoice),
-    JsonTablePlanJoin(JsonTablePlanJoin),
-    ParenJsonTablePlan(ParenJsonTablePlan),
+pub enum ConstraintTiming {
+    Deferred(Deferred),
+    Immediate(Immediate),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JsonTablePlanOperator {
-    JsonTablePlanCross(JsonTablePlanCross),
-    JsonTablePlanInner(JsonTablePlanInner),
-    JsonTablePlanOuter(JsonTablePlanOuter),
-    JsonTablePlanUnion(JsonTablePlanUnion),
+pub enum CopyDirection {
+    CopyFrom(CopyFrom),
+    CopyTo(CopyTo),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JsonTableValueBehavior {
-    JsonOnEmptyClause(JsonOnEmptyClause),
-    JsonOnErrorClause(JsonOnErrorClause),
+pub enum CopyForceKind {
+    ForceNotNull(ForceNotNull),
+    ForceNull(ForceNull),
+    ForceQuote(ForceQuote),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum JsonWrapperBehaviorClause {
-    JsonWithConditionalWrapper(JsonWithConditionalWrapper),
-    JsonWithUnconditionalWrapper(JsonWithUnconditionalWrapper),
-    JsonWithoutWrapper(JsonWithoutWrapper),
+pub enum CopySource {
+    CopyProgram(CopyProgram),
+    CopyStdin(CopyStdin),
+    CopyStdout(CopyStdout),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum LabelSpec {
-    DefaultLabel(DefaultLabel),
-    NamedLabel(NamedLabel),
+pub enum CopyTarget {
+    CopyProgram(CopyProgram),
+    CopyStdout(CopyStdout),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum LikeOption {
-    ExcludingProperty(ExcludingProperty),
-    IncludingProperty(IncludingProperty),
+pub enum CreateCollationDefinition {
+    AttributeList(AttributeList),
+    CollationFrom(CollationFrom),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum LimitValue {
-    All(All),
-    Expr(Expr),
+pub enum CreateSchemaTarget {
+    AuthorizationSchema(AuthorizationSchema),
+    NamedSchema(NamedSchema),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum LockMode {
-    AccessExclusive(AccessExclusive),
-    AccessShare(AccessShare),
-    Exclusive(Exclusive),
-    RowExclusive(RowExclusive),
-    RowShare(RowShare),
-    Share(Share),
-    ShareRowExclusive(ShareRowExclusive),
-    ShareUpdateExclusive(ShareUpdateExclusive),
+pub enum CreateTableAsQuery {
+    Execute(Execute),
+    SelectVariant(SelectVariant),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum LockStrength {
-    ForKeyShare(ForKeyShare),
-    ForNoKeyUpdate(ForNoKeyUpdate),
-    ForShare(ForShare),
-    ForUpdate(ForUpdate),
+pub enum CreateTypeKind {
+    BaseType(BaseType),
+    CompositeType(CompositeType),
+    EnumType(EnumType),
+    RangeType(RangeType),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum LockWait {
-    Nowait(Nowait),
-    SkipLocked(SkipLocked),
+pub enum CursorAction {
+    Absolute(Absolute),
+    All(All),
+    Backward(Backward),
+    First(First),
+    Forward(Forward),
+    Last(Last),
+    Next(Next),
+    Prior(Prior),
+    Relative(Relative),
+    Expr(Expr),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum MatchType {
-    MatchFull(MatchFull),
-    MatchPartial(MatchPartial),
-    MatchSimple(MatchSimple),
+pub enum CursorHold {
+    WithHold(WithHold),
+    WithoutHold(WithoutHold),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum MaterializedOption {
-    Materialized(Materialized),
-    NotMaterialized(NotMaterialized),
+pub enum CursorScroll {
+    NoScroll(NoScroll),
+    Scroll(Scroll),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum MergeAction {
-    MergeDelete(MergeDelete),
-    MergeDoNothing(MergeDoNothing),
-    MergeInsert(MergeInsert),
-    MergeUpdate(MergeUpdate),
+pub enum CursorSensitivity {
+    Asensitive(Asensitive),
+    Insensitive(Insensitive),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum MergeWhenClause {
-    MergeWhenMatched(MergeWhenMatched),
-    MergeWhenNotMatchedSource(MergeWhenNotMatchedSource),
-    MergeWhenNotMatchedTarget(MergeWhenNotMatchedTarget),
+pub enum DataOption {
+    WithData(WithData),
+    WithNoData(WithNoData),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum NullTreatment {
-    IgnoreNulls(IgnoreNulls),
-    RespectNulls(RespectNulls),
+pub enum DatabaseOption {
+    DatabaseOptionConnectionLimit(DatabaseOptionConnectionLimit),
+    DatabaseOptionEncoding(DatabaseOptionEncoding),
+    DatabaseOptionGeneric(DatabaseOptionGeneric),
+    DatabaseOptionOwner(DatabaseOptionOwner),
+    DatabaseOptionTablespace(DatabaseOptionTablespace),
+    DatabaseOptionTemplate(DatabaseOptionTemplate),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum NullsDistinctOption {
-    NullsDistinct(NullsDistinct),
-    NullsNotDistinct(NullsNotDistinct),
-}
-
+pub enum DetachPartitionOption {
+    DetachConcurrently(DetachConcurrently),
+    DetachFinalize(DetachFinalize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiscardTarget {
+    DiscardAll(DiscardAll),
+    DiscardPlans(DiscardPlans),
+    DiscardSequences(DiscardSequences),
+    DiscardTemp(DiscardTemp),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DropBehavior {
+    Cascade(Cascade),
+    Restrict(Restrict),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DropOpClassOption {
+    DropOpClassOptionFunction(DropOpClassOptionFunction),
+    DropOpClassOptionOperator(DropOpClassOptionOperator),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ElementTableLabelAndProperties {
+    LabelAndPropertiesList(LabelAndPropertiesList),
+    ElementTableProperties(ElementTableProperties),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ElementTableProperties {
+    AllProperties(AllProperties),
+    NoProperties(NoProperties),
+    Properties(Properties),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplainMode {
+    ExplainAnalyze(ExplainAnalyze),
+    ExplainOptionList(ExplainOptionList),
+    ExplainVerbose(ExplainVerbose),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplainStmt {
+    CompoundSelect(CompoundSelect),
+    CreateMaterializedView(CreateMaterializedView),
+    CreateTableAs(CreateTableAs),
+    Declare(Declare),
+    Delete(Delete),
+    Execute(Execute),
+    Insert(Insert),
+    Merge(Merge),
+    ParenSelect(ParenSelect),
+    Select(Select),
+    SelectInto(SelectInto),
+    Table(Table),
+    Update(Update),
+    Values(Values),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expr {
+    ArrayExpr(ArrayExpr),
+    BetweenExpr(BetweenExpr),
+    BinExpr(BinExpr),
+    CallExpr(CallExpr),
+    CaseExpr(CaseExpr),
+    CastExpr(CastExpr),
+    Collate(Collate),
+    FieldExpr(FieldExpr),
+    IndexExpr(IndexExpr),
+    Literal(Literal),
+    NameRef(NameRef),
+    ParenExpr(ParenExpr),
+    PostfixExpr(PostfixExpr),
+    PrefixExpr(PrefixExpr),
+    SliceExpr(SliceExpr),
+    TupleExpr(TupleExpr),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExtensionMemberObject {
+    ObjectAccessMethod(ObjectAccessMethod),
+    ObjectAggregate(ObjectAggregate),
+    ObjectCast(ObjectCast),
+    ObjectCollation(ObjectCollation),
+    ObjectConversion(ObjectConversion),
+    ObjectDatabase(ObjectDatabase),
+    ObjectDomain(ObjectDomain),
+    ObjectEventTrigger(ObjectEventTrigger),
+    ObjectExtension(ObjectExtension),
+    ObjectForeignDataWrapper(ObjectForeignDataWrapper),
+    ObjectForeignTable(ObjectForeignTable),
+    ObjectFunction(ObjectFunction),
+    ObjectIndex(ObjectIndex),
+    ObjectLanguage(ObjectLanguage),
+    ObjectMaterializedView(ObjectMaterializedView),
+    ObjectOperator(ObjectOperator),
+    ObjectOperatorClass(ObjectOperatorClass),
+    ObjectOperatorFamily(ObjectOperatorFamily),
+    ObjectProcedure(ObjectProcedure),
+    ObjectPublication(ObjectPublication),
+    ObjectRole(ObjectRole),
+    ObjectRoutine(ObjectRoutine),
+    ObjectSchema(ObjectSchema),
+    ObjectSequence(ObjectSequence),
+    ObjectServer(ObjectServer),
+    ObjectStatistics(ObjectStatistics),
+    ObjectSubscription(ObjectSubscription),
+    ObjectTable(ObjectTable),
+    ObjectTablespace(ObjectTablespace),
+    ObjectTextSearchConfig(ObjectTextSearchConfig),
+    ObjectTextSearchDict(ObjectTextSearchDict),
+    ObjectTextSearchParser(ObjectTextSearchParser),
+    ObjectTextSearchTemplate(ObjectTextSearchTemplate),
+    ObjectTransform(ObjectTransform),
+    ObjectType(ObjectType),
+    ObjectView(ObjectView),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExtractField {
+    ExtractFieldLiteral(ExtractFieldLiteral),
+    ExtractFieldName(ExtractFieldName),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FdwOption {
+    OptionAlterOptionList(OptionAlterOptionList),
+    OptionConnection(OptionConnection),
+    OptionHandler(OptionHandler),
+    OptionNoConnection(OptionNoConnection),
+    OptionNoHandler(OptionNoHandler),
+    OptionNoValidator(OptionNoValidator),
+    OptionValidator(OptionValidator),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FetchQuantity {
+    FetchOnly(FetchOnly),
+    WithTies(WithTies),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FrameBound {
+    CurrentRow(CurrentRow),
+    ExprFollowing(ExprFollowing),
+    ExprPreceding(ExprPreceding),
+    UnboundedFollowing(UnboundedFollowing),
+    UnboundedPreceding(UnboundedPreceding),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FrameExcludeTarget {
+    CurrentRow(CurrentRow),
+    Group(Group),
+    NoOthers(NoOthers),
+    Ties(Ties),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FrameExtent {
+    FrameBetween(FrameBetween),
+    FrameBound(FrameBound),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FrameUnits {
+    FrameGroups(FrameGroups),
+    FrameRange(FrameRange),
+    FrameRows(FrameRows),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FromAliasColumns {
+    ColumnDefList(ColumnDefList),
+    ColumnList(ColumnList),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FromItem {
+    ExprFromItem(ExprFromItem),
+    FunctionFromItem(FunctionFromItem),
+    GraphTableFromItem(GraphTableFromItem),
+    JsonTableFromItem(JsonTableFromItem),
+    ParenFromItem(ParenFromItem),
+    RelationFromItem(RelationFromItem),
+    RowsFromItem(RowsFromItem),
+    XmlTableFromItem(XmlTableFromItem),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FuncOption {
+    AsFuncOption(AsFuncOption),
+    BeginFuncOptionList(BeginFuncOptionList),
+    CalledOnNullInputFuncOption(CalledOnNullInputFuncOption),
+    CostFuncOption(CostFuncOption),
+    LanguageFuncOption(LanguageFuncOption),
+    LeakproofFuncOption(LeakproofFuncOption),
+    NotLeakproofFuncOption(NotLeakproofFuncOption),
+    ParallelFuncOption(ParallelFuncOption),
+    ResetFuncOption(ResetFuncOption),
+    ReturnFuncOption(ReturnFuncOption),
+    ReturnsNullOnNullInputFuncOption(ReturnsNullOnNullInputFuncOption),
+    RowsFuncOption(RowsFuncOption),
+    SecurityDefinerFuncOption(SecurityDefinerFuncOption),
+    SecurityInvokerFuncOption(SecurityInvokerFuncOption),
+    SetFuncOption(SetFuncOption),
+    StrictFuncOption(StrictFuncOption),
+    SupportFuncOption(SupportFuncOption),
+    TransformFuncOption(TransformFuncOption),
+    WindowFuncOption(WindowFuncOption),
+    VolatilityFuncOption(VolatilityFuncOption),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GeneratedAs {
+    GeneratedIdentity(GeneratedIdentity),
+    GeneratedStored(GeneratedStored),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GeneratedKind {
+    Stored(Stored),
+    Virtual(Virtual),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GeneratedWhen {
+    GeneratedAlways(GeneratedAlways),
+    GeneratedByDefault(GeneratedByDefault),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupBy {
+    GroupingCube(GroupingCube),
+    GroupingExpr(GroupingExpr),
+    GroupingRollup(GroupingRollup),
+    GroupingSets(GroupingSets),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HasParamList {
+    Aggregate(Aggregate),
+    CreateAggregate(CreateAggregate),
+    CreateFunction(CreateFunction),
+    CreateProcedure(CreateProcedure),
+    FunctionSig(FunctionSig),
+    ProcedureSig(ProcedureSig),
+    RoutineSig(RoutineSig),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IdentityAction {
+    ContinueIdentity(ContinueIdentity),
+    RestartIdentity(RestartIdentity),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImportTableFilter {
+    ExceptTables(ExceptTables),
+    LimitToTables(LimitToTables),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InsertSource {
+    DefaultValues(DefaultValues),
+    SelectVariant(SelectVariant),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IntervalQualifier {
+    IntervalDay(IntervalDay),
+    IntervalHour(IntervalHour),
+    IntervalMinute(IntervalMinute),
+    IntervalMonth(IntervalMonth),
+    IntervalSecond(IntervalSecond),
+    IntervalYear(IntervalYear),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JoinCondition {
+    JoinUsingClause(JoinUsingClause),
+    OnClause(OnClause),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JoinType {
+    JoinCross(JoinCross),
+    JoinFull(JoinFull),
+    JoinInner(JoinInner),
+    JoinLeft(JoinLeft),
+    JoinRight(JoinRight),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonBehavior {
+    JsonBehaviorDefault(JsonBehaviorDefault),
+    JsonBehaviorEmptyArray(JsonBehaviorEmptyArray),
+    JsonBehaviorEmptyObject(JsonBehaviorEmptyObject),
+    JsonBehaviorError(JsonBehaviorError),
+    JsonBehaviorFalse(JsonBehaviorFalse),
+    JsonBehaviorNull(JsonBehaviorNull),
+    JsonBehaviorTrue(JsonBehaviorTrue),
+    JsonBehaviorUnknown(JsonBehaviorUnknown),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonKeysUniqueClause {
+    JsonWithUniqueKeys(JsonWithUniqueKeys),
+    JsonWithoutUniqueKeys(JsonWithoutUniqueKeys),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonNullClause {
+    JsonAbsentOnNull(JsonAbsentOnNull),
+    JsonNullOnNull(JsonNullOnNull),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonTableColumn {
+    JsonTableExistsColumn(JsonTableExistsColumn),
+    JsonTableNestedColumn(JsonTableNestedColumn),
+    JsonTableOrdinalityColumn(JsonTableOrdinalityColumn),
+    JsonTableValueColumn(JsonTableValueColumn),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonTablePlan {
+    JsonPathNameRef(JsonPathNameRef),
+    JsonTablePlanChoice(JsonTablePlanChoice),
+    JsonTablePlanJoin(JsonTablePlanJoin),
+    ParenJsonTablePlan(ParenJsonTablePlan),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonTablePlanOperator {
+    JsonTablePlanCross(JsonTablePlanCross),
+    JsonTablePlanInner(JsonTablePlanInner),
+    JsonTablePlanOuter(JsonTablePlanOuter),
+    JsonTablePlanUnion(JsonTablePlanUnion),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonTableValueBehavior {
+    JsonOnEmptyClause(JsonOnEmptyClause),
+    JsonOnErrorClause(JsonOnErrorClause),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonWrapperBehaviorClause {
+    JsonWithConditionalWrapper(JsonWithConditionalWrapper),
+    JsonWithUnconditionalWrapper(JsonWithUnconditionalWrapper),
+    JsonWithoutWrapper(JsonWithoutWrapper),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LabelSpec {
+    DefaultLabel(DefaultLabel),
+    NamedLabel(NamedLabel),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LikeOption {
+    ExcludingProperty(ExcludingProperty),
+    IncludingProperty(IncludingProperty),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LimitValue {
+    All(All),
+    Expr(Expr),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LockMode {
+    AccessExclusive(AccessExclusive),
+    AccessShare(AccessShare),
+    Exclusive(Exclusive),
+    RowExclusive(RowExclusive),
+    RowShare(RowShare),
+    Share(Share),
+    ShareRowExclusive(ShareRowExclusive),
+    ShareUpdateExclusive(ShareUpdateExclusive),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LockStrength {
+    ForKeyShare(ForKeyShare),
+    ForNoKeyUpdate(ForNoKeyUpdate),
+    ForShare(ForShare),
+    ForUpdate(ForUpdate),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LockWait {
+    Nowait(Nowait),
+    SkipLocked(SkipLocked),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MatchType {
+    MatchFull(MatchFull),
+    MatchPartial(MatchPartial),
+    MatchSimple(MatchSimple),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MaterializedOption {
+    Materialized(Materialized),
+    NotMaterialized(NotMaterialized),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeAction {
+    MergeDelete(MergeDelete),
+    MergeDoNothing(MergeDoNothing),
+    MergeInsert(MergeInsert),
+    MergeUpdate(MergeUpdate),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeWhenClause {
+    MergeWhenMatched(MergeWhenMatched),
+    MergeWhenNotMatchedSource(MergeWhenNotMatchedSource),
+    MergeWhenNotMatchedTarget(MergeWhenNotMatchedTarget),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NullTreatment {
+    IgnoreNulls(IgnoreNulls),
+    RespectNulls(RespectNulls),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NullsDistinctOption {
+    NullsDistinct(NullsDistinct),
+    NullsNotDistinct(NullsNotDistinct),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NullsOrder {
     NullsFirst(NullsFirst),
@@ -29245,6 +30432,36 @@ pub enum Persistence {
     Unlogged(Unlogged),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlpgsqlDecl {
+    PlpgsqlAliasDecl(PlpgsqlAliasDecl),
+    PlpgsqlCursorDecl(PlpgsqlCursorDecl),
+    PlpgsqlVarDecl(PlpgsqlVarDecl),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlpgsqlStmt {
+    PlpgsqlAssert(PlpgsqlAssert),
+    PlpgsqlAssign(PlpgsqlAssign),
+    PlpgsqlBlock(PlpgsqlBlock),
+    PlpgsqlCase(PlpgsqlCase),
+    PlpgsqlExecute(PlpgsqlExecute),
+    PlpgsqlExit(PlpgsqlExit),
+    PlpgsqlForInt(PlpgsqlForInt),
+    PlpgsqlForQuery(PlpgsqlForQuery),
+    PlpgsqlForeach(PlpgsqlForeach),
+    PlpgsqlGetDiagnostics(PlpgsqlGetDiagnostics),
+    PlpgsqlIf(PlpgsqlIf),
+    PlpgsqlLoop(PlpgsqlLoop),
+    PlpgsqlNull(PlpgsqlNull),
+    PlpgsqlOpen(PlpgsqlOpen),
+    PlpgsqlPerform(PlpgsqlPerform),
+    PlpgsqlRaise(PlpgsqlRaise),
+    PlpgsqlReturn(PlpgsqlReturn),
+    PlpgsqlSqlStmt(PlpgsqlSqlStmt),
+    PlpgsqlWhile(PlpgsqlWhile),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PolicyCommandKind {
     PolicyCommandAll(PolicyCommandAll),
@@ -29969,10 +31186,766 @@ pub enum XmlWhitespace {
     PreserveWhitespace(PreserveWhitespace),
     StripWhitespace(StripWhitespace),
 }
-impl AstNode for Absolute {
+impl AstNode for Absolute {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ABSOLUTE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AccessExclusive {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ACCESS_EXCLUSIVE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AccessMethod {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ACCESS_METHOD
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AccessMethodRef {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ACCESS_METHOD_REF
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AccessMethodTypeIndex {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ACCESS_METHOD_TYPE_INDEX
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AccessMethodTypeTable {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ACCESS_METHOD_TYPE_TABLE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AccessShare {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ACCESS_SHARE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddAttribute {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_ATTRIBUTE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddColumn {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_COLUMN
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddConstraint {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_CONSTRAINT
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddForeignOption {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_FOREIGN_OPTION
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddGenerated {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_GENERATED
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddLabel {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_LABEL
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddMapping {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_MAPPING
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddOpClassOptions {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_OP_CLASS_OPTIONS
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddPublication {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_PUBLICATION
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddPublicationObjects {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_PUBLICATION_OBJECTS
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddUsers {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_USERS
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddValue {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_VALUE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddVertexEdgeLabelProperties {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_VERTEX_EDGE_LABEL_PROPERTIES
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AddVertexEdgeTables {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADD_VERTEX_EDGE_TABLES
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AdminOptionFor {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ADMIN_OPTION_FOR
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AfterValue {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::AFTER_VALUE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Aggregate {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::AGGREGATE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AggregateName {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::AGGREGATE_NAME
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AggregateRenameTo {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::AGGREGATE_RENAME_TO
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for All {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALL
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AllFn {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALL_FN
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AllInTablespace {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALL_IN_TABLESPACE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AllPrivileges {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALL_PRIVILEGES
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AllProperties {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALL_PROPERTIES
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AllPublicationSequences {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALL_PUBLICATION_SEQUENCES
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AllPublicationTables {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALL_PUBLICATION_TABLES
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterAggregate {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_AGGREGATE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterAttribute {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_ATTRIBUTE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterCollation {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_COLLATION
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterColumn {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_COLUMN
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterConstraint {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_CONSTRAINT
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterConversion {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_CONVERSION
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterDatabase {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_DATABASE
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterDefaultPrivileges {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_DEFAULT_PRIVILEGES
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterDomain {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ALTER_DOMAIN
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AlterEventTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ABSOLUTE
+        kind == SyntaxKind::ALTER_EVENT_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -29987,10 +31960,10 @@ impl AstNode for Absolute {
         &self.syntax
     }
 }
-impl AstNode for AccessExclusive {
+impl AstNode for AlterExtension {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ACCESS_EXCLUSIVE
+        kind == SyntaxKind::ALTER_EXTENSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30005,10 +31978,10 @@ impl AstNode for AccessExclusive {
         &self.syntax
     }
 }
-impl AstNode for AccessMethod {
+impl AstNode for AlterExtensionAdd {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ACCESS_METHOD
+        kind == SyntaxKind::ALTER_EXTENSION_ADD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30023,10 +31996,10 @@ impl AstNode for AccessMethod {
         &self.syntax
     }
 }
-impl AstNode for AccessMethodRef {
+impl AstNode for AlterExtensionDrop {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ACCESS_METHOD_REF
+        kind == SyntaxKind::ALTER_EXTENSION_DROP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30041,10 +32014,10 @@ impl AstNode for AccessMethodRef {
         &self.syntax
     }
 }
-impl AstNode for AccessMethodTypeIndex {
+impl AstNode for AlterExtensionUpdate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ACCESS_METHOD_TYPE_INDEX
+        kind == SyntaxKind::ALTER_EXTENSION_UPDATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30059,10 +32032,10 @@ impl AstNode for AccessMethodTypeIndex {
         &self.syntax
     }
 }
-impl AstNode for AccessMethodTypeTable {
+impl AstNode for AlterForeignDataWrapper {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ACCESS_METHOD_TYPE_TABLE
+        kind == SyntaxKind::ALTER_FOREIGN_DATA_WRAPPER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30077,10 +32050,10 @@ impl AstNode for AccessMethodTypeTable {
         &self.syntax
     }
 }
-impl AstNode for AccessShare {
+impl AstNode for AlterForeignTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ACCESS_SHARE
+        kind == SyntaxKind::ALTER_FOREIGN_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30095,10 +32068,10 @@ impl AstNode for AccessShare {
         &self.syntax
     }
 }
-impl AstNode for AddAttribute {
+impl AstNode for AlterFunction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_ATTRIBUTE
+        kind == SyntaxKind::ALTER_FUNCTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30113,10 +32086,10 @@ impl AstNode for AddAttribute {
         &self.syntax
     }
 }
-impl AstNode for AddColumn {
+impl AstNode for AlterGroup {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_COLUMN
+        kind == SyntaxKind::ALTER_GROUP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30131,10 +32104,10 @@ impl AstNode for AddColumn {
         &self.syntax
     }
 }
-impl AstNode for AddConstraint {
+impl AstNode for AlterIndex {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_CONSTRAINT
+        kind == SyntaxKind::ALTER_INDEX
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30149,10 +32122,10 @@ impl AstNode for AddConstraint {
         &self.syntax
     }
 }
-impl AstNode for AddForeignOption {
+impl AstNode for AlterLanguage {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_FOREIGN_OPTION
+        kind == SyntaxKind::ALTER_LANGUAGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30167,10 +32140,10 @@ impl AstNode for AddForeignOption {
         &self.syntax
     }
 }
-impl AstNode for AddGenerated {
+impl AstNode for AlterLargeObject {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_GENERATED
+        kind == SyntaxKind::ALTER_LARGE_OBJECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30185,10 +32158,10 @@ impl AstNode for AddGenerated {
         &self.syntax
     }
 }
-impl AstNode for AddLabel {
+impl AstNode for AlterMapping {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_LABEL
+        kind == SyntaxKind::ALTER_MAPPING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30203,10 +32176,10 @@ impl AstNode for AddLabel {
         &self.syntax
     }
 }
-impl AstNode for AddMapping {
+impl AstNode for AlterMaterializedView {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_MAPPING
+        kind == SyntaxKind::ALTER_MATERIALIZED_VIEW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30221,10 +32194,10 @@ impl AstNode for AddMapping {
         &self.syntax
     }
 }
-impl AstNode for AddOpClassOptions {
+impl AstNode for AlterOperator {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_OP_CLASS_OPTIONS
+        kind == SyntaxKind::ALTER_OPERATOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30239,10 +32212,10 @@ impl AstNode for AddOpClassOptions {
         &self.syntax
     }
 }
-impl AstNode for AddPublication {
+impl AstNode for AlterOperatorClass {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_PUBLICATION
+        kind == SyntaxKind::ALTER_OPERATOR_CLASS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30257,10 +32230,10 @@ impl AstNode for AddPublication {
         &self.syntax
     }
 }
-impl AstNode for AddPublicationObjects {
+impl AstNode for AlterOperatorFamily {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_PUBLICATION_OBJECTS
+        kind == SyntaxKind::ALTER_OPERATOR_FAMILY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30275,10 +32248,10 @@ impl AstNode for AddPublicationObjects {
         &self.syntax
     }
 }
-impl AstNode for AddUsers {
+impl AstNode for AlterOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_USERS
+        kind == SyntaxKind::ALTER_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30293,10 +32266,10 @@ impl AstNode for AddUsers {
         &self.syntax
     }
 }
-impl AstNode for AddValue {
+impl AstNode for AlterPolicy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_VALUE
+        kind == SyntaxKind::ALTER_POLICY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30311,10 +32284,10 @@ impl AstNode for AddValue {
         &self.syntax
     }
 }
-impl AstNode for AddVertexEdgeLabelProperties {
+impl AstNode for AlterPolicyTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_VERTEX_EDGE_LABEL_PROPERTIES
+        kind == SyntaxKind::ALTER_POLICY_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30329,10 +32302,10 @@ impl AstNode for AddVertexEdgeLabelProperties {
         &self.syntax
     }
 }
-impl AstNode for AddVertexEdgeTables {
+impl AstNode for AlterProcedure {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADD_VERTEX_EDGE_TABLES
+        kind == SyntaxKind::ALTER_PROCEDURE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30347,10 +32320,10 @@ impl AstNode for AddVertexEdgeTables {
         &self.syntax
     }
 }
-impl AstNode for AdminOptionFor {
+impl AstNode for AlterPropertyGraph {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ADMIN_OPTION_FOR
+        kind == SyntaxKind::ALTER_PROPERTY_GRAPH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30365,10 +32338,10 @@ impl AstNode for AdminOptionFor {
         &self.syntax
     }
 }
-impl AstNode for AfterValue {
+impl AstNode for AlterPublication {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AFTER_VALUE
+        kind == SyntaxKind::ALTER_PUBLICATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30383,10 +32356,10 @@ impl AstNode for AfterValue {
         &self.syntax
     }
 }
-impl AstNode for Aggregate {
+impl AstNode for AlterRole {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AGGREGATE
+        kind == SyntaxKind::ALTER_ROLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30401,10 +32374,10 @@ impl AstNode for Aggregate {
         &self.syntax
     }
 }
-impl AstNode for AggregateName {
+impl AstNode for AlterRoutine {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AGGREGATE_NAME
+        kind == SyntaxKind::ALTER_ROUTINE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30419,10 +32392,10 @@ impl AstNode for AggregateName {
         &self.syntax
     }
 }
-impl AstNode for AggregateRenameTo {
+impl AstNode for AlterRule {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AGGREGATE_RENAME_TO
+        kind == SyntaxKind::ALTER_RULE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30437,10 +32410,10 @@ impl AstNode for AggregateRenameTo {
         &self.syntax
     }
 }
-impl AstNode for All {
+impl AstNode for AlterSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALL
+        kind == SyntaxKind::ALTER_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30455,10 +32428,10 @@ impl AstNode for All {
         &self.syntax
     }
 }
-impl AstNode for AllFn {
+impl AstNode for AlterSequence {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALL_FN
+        kind == SyntaxKind::ALTER_SEQUENCE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30473,10 +32446,10 @@ impl AstNode for AllFn {
         &self.syntax
     }
 }
-impl AstNode for AllInTablespace {
+impl AstNode for AlterServer {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALL_IN_TABLESPACE
+        kind == SyntaxKind::ALTER_SERVER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30491,10 +32464,10 @@ impl AstNode for AllInTablespace {
         &self.syntax
     }
 }
-impl AstNode for AllPrivileges {
+impl AstNode for AlterSetStatistics {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALL_PRIVILEGES
+        kind == SyntaxKind::ALTER_SET_STATISTICS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30509,10 +32482,10 @@ impl AstNode for AllPrivileges {
         &self.syntax
     }
 }
-impl AstNode for AllProperties {
+impl AstNode for AlterStatistics {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALL_PROPERTIES
+        kind == SyntaxKind::ALTER_STATISTICS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30527,10 +32500,10 @@ impl AstNode for AllProperties {
         &self.syntax
     }
 }
-impl AstNode for AllPublicationSequences {
+impl AstNode for AlterSubscription {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALL_PUBLICATION_SEQUENCES
+        kind == SyntaxKind::ALTER_SUBSCRIPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30545,10 +32518,10 @@ impl AstNode for AllPublicationSequences {
         &self.syntax
     }
 }
-impl AstNode for AllPublicationTables {
+impl AstNode for AlterSystem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALL_PUBLICATION_TABLES
+        kind == SyntaxKind::ALTER_SYSTEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30563,10 +32536,10 @@ impl AstNode for AllPublicationTables {
         &self.syntax
     }
 }
-impl AstNode for AlterAggregate {
+impl AstNode for AlterTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_AGGREGATE
+        kind == SyntaxKind::ALTER_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30581,10 +32554,10 @@ impl AstNode for AlterAggregate {
         &self.syntax
     }
 }
-impl AstNode for AlterAttribute {
+impl AstNode for AlterTablespace {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_ATTRIBUTE
+        kind == SyntaxKind::ALTER_TABLESPACE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30599,10 +32572,10 @@ impl AstNode for AlterAttribute {
         &self.syntax
     }
 }
-impl AstNode for AlterCollation {
+impl AstNode for AlterTextSearchConfiguration {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_COLLATION
+        kind == SyntaxKind::ALTER_TEXT_SEARCH_CONFIGURATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30617,10 +32590,10 @@ impl AstNode for AlterCollation {
         &self.syntax
     }
 }
-impl AstNode for AlterColumn {
+impl AstNode for AlterTextSearchDictionary {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_COLUMN
+        kind == SyntaxKind::ALTER_TEXT_SEARCH_DICTIONARY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30635,10 +32608,10 @@ impl AstNode for AlterColumn {
         &self.syntax
     }
 }
-impl AstNode for AlterConstraint {
+impl AstNode for AlterTextSearchParser {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_CONSTRAINT
+        kind == SyntaxKind::ALTER_TEXT_SEARCH_PARSER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30653,10 +32626,10 @@ impl AstNode for AlterConstraint {
         &self.syntax
     }
 }
-impl AstNode for AlterConversion {
+impl AstNode for AlterTextSearchTemplate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_CONVERSION
+        kind == SyntaxKind::ALTER_TEXT_SEARCH_TEMPLATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30671,10 +32644,10 @@ impl AstNode for AlterConversion {
         &self.syntax
     }
 }
-impl AstNode for AlterDatabase {
+impl AstNode for AlterTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_DATABASE
+        kind == SyntaxKind::ALTER_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30689,10 +32662,10 @@ impl AstNode for AlterDatabase {
         &self.syntax
     }
 }
-impl AstNode for AlterDefaultPrivileges {
+impl AstNode for AlterType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_DEFAULT_PRIVILEGES
+        kind == SyntaxKind::ALTER_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30707,10 +32680,10 @@ impl AstNode for AlterDefaultPrivileges {
         &self.syntax
     }
 }
-impl AstNode for AlterDomain {
+impl AstNode for AlterTypeAttributeActionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_DOMAIN
+        kind == SyntaxKind::ALTER_TYPE_ATTRIBUTE_ACTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30725,10 +32698,10 @@ impl AstNode for AlterDomain {
         &self.syntax
     }
 }
-impl AstNode for AlterEventTrigger {
+impl AstNode for AlterUser {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_EVENT_TRIGGER
+        kind == SyntaxKind::ALTER_USER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30743,10 +32716,10 @@ impl AstNode for AlterEventTrigger {
         &self.syntax
     }
 }
-impl AstNode for AlterExtension {
+impl AstNode for AlterUserMapping {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_EXTENSION
+        kind == SyntaxKind::ALTER_USER_MAPPING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30761,10 +32734,10 @@ impl AstNode for AlterExtension {
         &self.syntax
     }
 }
-impl AstNode for AlterExtensionAdd {
+impl AstNode for AlterVertexEdgeLabels {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_EXTENSION_ADD
+        kind == SyntaxKind::ALTER_VERTEX_EDGE_LABELS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30779,10 +32752,10 @@ impl AstNode for AlterExtensionAdd {
         &self.syntax
     }
 }
-impl AstNode for AlterExtensionDrop {
+impl AstNode for AlterView {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_EXTENSION_DROP
+        kind == SyntaxKind::ALTER_VIEW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30797,10 +32770,10 @@ impl AstNode for AlterExtensionDrop {
         &self.syntax
     }
 }
-impl AstNode for AlterExtensionUpdate {
+impl AstNode for AlterViewColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_EXTENSION_UPDATE
+        kind == SyntaxKind::ALTER_VIEW_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30815,10 +32788,10 @@ impl AstNode for AlterExtensionUpdate {
         &self.syntax
     }
 }
-impl AstNode for AlterForeignDataWrapper {
+impl AstNode for Analyze {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_FOREIGN_DATA_WRAPPER
+        kind == SyntaxKind::ANALYZE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30833,10 +32806,10 @@ impl AstNode for AlterForeignDataWrapper {
         &self.syntax
     }
 }
-impl AstNode for AlterForeignTable {
+impl AstNode for AndChain {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_FOREIGN_TABLE
+        kind == SyntaxKind::AND_CHAIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30851,10 +32824,10 @@ impl AstNode for AlterForeignTable {
         &self.syntax
     }
 }
-impl AstNode for AlterFunction {
+impl AstNode for AndNoChain {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_FUNCTION
+        kind == SyntaxKind::AND_NO_CHAIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30869,10 +32842,10 @@ impl AstNode for AlterFunction {
         &self.syntax
     }
 }
-impl AstNode for AlterGroup {
+impl AstNode for AnyFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_GROUP
+        kind == SyntaxKind::ANY_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30887,10 +32860,10 @@ impl AstNode for AlterGroup {
         &self.syntax
     }
 }
-impl AstNode for AlterIndex {
+impl AstNode for Arg {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_INDEX
+        kind == SyntaxKind::ARG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30905,10 +32878,10 @@ impl AstNode for AlterIndex {
         &self.syntax
     }
 }
-impl AstNode for AlterLanguage {
+impl AstNode for ArgList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_LANGUAGE
+        kind == SyntaxKind::ARG_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30923,10 +32896,10 @@ impl AstNode for AlterLanguage {
         &self.syntax
     }
 }
-impl AstNode for AlterLargeObject {
+impl AstNode for ArrayBound {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_LARGE_OBJECT
+        kind == SyntaxKind::ARRAY_BOUND
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30941,10 +32914,10 @@ impl AstNode for AlterLargeObject {
         &self.syntax
     }
 }
-impl AstNode for AlterMapping {
+impl AstNode for ArrayExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_MAPPING
+        kind == SyntaxKind::ARRAY_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30959,10 +32932,10 @@ impl AstNode for AlterMapping {
         &self.syntax
     }
 }
-impl AstNode for AlterMaterializedView {
+impl AstNode for ArrayType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_MATERIALIZED_VIEW
+        kind == SyntaxKind::ARRAY_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30977,10 +32950,10 @@ impl AstNode for AlterMaterializedView {
         &self.syntax
     }
 }
-impl AstNode for AlterOperator {
+impl AstNode for AsAssignment {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_OPERATOR
+        kind == SyntaxKind::AS_ASSIGNMENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -30995,10 +32968,10 @@ impl AstNode for AlterOperator {
         &self.syntax
     }
 }
-impl AstNode for AlterOperatorClass {
+impl AstNode for AsDefinition {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_OPERATOR_CLASS
+        kind == SyntaxKind::AS_DEFINITION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31013,10 +32986,10 @@ impl AstNode for AlterOperatorClass {
         &self.syntax
     }
 }
-impl AstNode for AlterOperatorFamily {
+impl AstNode for AsFuncOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_OPERATOR_FAMILY
+        kind == SyntaxKind::AS_FUNC_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31031,10 +33004,10 @@ impl AstNode for AlterOperatorFamily {
         &self.syntax
     }
 }
-impl AstNode for AlterOptionList {
+impl AstNode for AsImplicit {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_OPTION_LIST
+        kind == SyntaxKind::AS_IMPLICIT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31049,10 +33022,10 @@ impl AstNode for AlterOptionList {
         &self.syntax
     }
 }
-impl AstNode for AlterPolicy {
+impl AstNode for AsName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_POLICY
+        kind == SyntaxKind::AS_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31067,10 +33040,10 @@ impl AstNode for AlterPolicy {
         &self.syntax
     }
 }
-impl AstNode for AlterPolicyTo {
+impl AstNode for AsObjFile {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_POLICY_TO
+        kind == SyntaxKind::AS_OBJ_FILE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31085,10 +33058,10 @@ impl AstNode for AlterPolicyTo {
         &self.syntax
     }
 }
-impl AstNode for AlterProcedure {
+impl AstNode for AsPolicyType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_PROCEDURE
+        kind == SyntaxKind::AS_POLICY_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31103,10 +33076,10 @@ impl AstNode for AlterProcedure {
         &self.syntax
     }
 }
-impl AstNode for AlterPropertyGraph {
+impl AstNode for Asensitive {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_PROPERTY_GRAPH
+        kind == SyntaxKind::ASENSITIVE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31121,10 +33094,10 @@ impl AstNode for AlterPropertyGraph {
         &self.syntax
     }
 }
-impl AstNode for AlterPublication {
+impl AstNode for Asymmetric {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_PUBLICATION
+        kind == SyntaxKind::ASYMMETRIC
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31139,10 +33112,10 @@ impl AstNode for AlterPublication {
         &self.syntax
     }
 }
-impl AstNode for AlterRole {
+impl AstNode for AtLocal {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_ROLE
+        kind == SyntaxKind::AT_LOCAL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31157,10 +33130,10 @@ impl AstNode for AlterRole {
         &self.syntax
     }
 }
-impl AstNode for AlterRoutine {
+impl AstNode for AtTimeZone {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_ROUTINE
+        kind == SyntaxKind::AT_TIME_ZONE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31175,10 +33148,10 @@ impl AstNode for AlterRoutine {
         &self.syntax
     }
 }
-impl AstNode for AlterRule {
+impl AstNode for AttachIndexPartition {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_RULE
+        kind == SyntaxKind::ATTACH_INDEX_PARTITION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31193,10 +33166,10 @@ impl AstNode for AlterRule {
         &self.syntax
     }
 }
-impl AstNode for AlterSchema {
+impl AstNode for AttachPartition {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_SCHEMA
+        kind == SyntaxKind::ATTACH_PARTITION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31211,10 +33184,10 @@ impl AstNode for AlterSchema {
         &self.syntax
     }
 }
-impl AstNode for AlterSequence {
+impl AstNode for AttributeList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_SEQUENCE
+        kind == SyntaxKind::ATTRIBUTE_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31229,10 +33202,10 @@ impl AstNode for AlterSequence {
         &self.syntax
     }
 }
-impl AstNode for AlterServer {
+impl AstNode for AttributeName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_SERVER
+        kind == SyntaxKind::ATTRIBUTE_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31247,10 +33220,10 @@ impl AstNode for AlterServer {
         &self.syntax
     }
 }
-impl AstNode for AlterSetStatistics {
+impl AstNode for AttributeNamespace {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_SET_STATISTICS
+        kind == SyntaxKind::ATTRIBUTE_NAMESPACE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31265,10 +33238,10 @@ impl AstNode for AlterSetStatistics {
         &self.syntax
     }
 }
-impl AstNode for AlterStatistics {
+impl AstNode for AttributeOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_STATISTICS
+        kind == SyntaxKind::ATTRIBUTE_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31283,10 +33256,10 @@ impl AstNode for AlterStatistics {
         &self.syntax
     }
 }
-impl AstNode for AlterSubscription {
+impl AstNode for AttributeValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_SUBSCRIPTION
+        kind == SyntaxKind::ATTRIBUTE_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31301,10 +33274,10 @@ impl AstNode for AlterSubscription {
         &self.syntax
     }
 }
-impl AstNode for AlterSystem {
+impl AstNode for AuthorizationSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_SYSTEM
+        kind == SyntaxKind::AUTHORIZATION_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31319,10 +33292,10 @@ impl AstNode for AlterSystem {
         &self.syntax
     }
 }
-impl AstNode for AlterTable {
+impl AstNode for Backward {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TABLE
+        kind == SyntaxKind::BACKWARD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31337,10 +33310,10 @@ impl AstNode for AlterTable {
         &self.syntax
     }
 }
-impl AstNode for AlterTablespace {
+impl AstNode for BaseType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TABLESPACE
+        kind == SyntaxKind::BASE_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31355,10 +33328,10 @@ impl AstNode for AlterTablespace {
         &self.syntax
     }
 }
-impl AstNode for AlterTextSearchConfiguration {
+impl AstNode for BeforeValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TEXT_SEARCH_CONFIGURATION
+        kind == SyntaxKind::BEFORE_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31373,10 +33346,10 @@ impl AstNode for AlterTextSearchConfiguration {
         &self.syntax
     }
 }
-impl AstNode for AlterTextSearchDictionary {
+impl AstNode for Begin {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TEXT_SEARCH_DICTIONARY
+        kind == SyntaxKind::BEGIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31391,10 +33364,10 @@ impl AstNode for AlterTextSearchDictionary {
         &self.syntax
     }
 }
-impl AstNode for AlterTextSearchParser {
+impl AstNode for BeginFuncOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TEXT_SEARCH_PARSER
+        kind == SyntaxKind::BEGIN_FUNC_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31409,10 +33382,10 @@ impl AstNode for AlterTextSearchParser {
         &self.syntax
     }
 }
-impl AstNode for AlterTextSearchTemplate {
+impl AstNode for BetweenExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TEXT_SEARCH_TEMPLATE
+        kind == SyntaxKind::BETWEEN_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31427,10 +33400,10 @@ impl AstNode for AlterTextSearchTemplate {
         &self.syntax
     }
 }
-impl AstNode for AlterTrigger {
+impl AstNode for BinExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TRIGGER
+        kind == SyntaxKind::BIN_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31445,10 +33418,10 @@ impl AstNode for AlterTrigger {
         &self.syntax
     }
 }
-impl AstNode for AlterType {
+impl AstNode for BindParamNameRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TYPE
+        kind == SyntaxKind::BIND_PARAM_NAME_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31463,10 +33436,10 @@ impl AstNode for AlterType {
         &self.syntax
     }
 }
-impl AstNode for AlterTypeAttributeActionList {
+impl AstNode for BitType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_TYPE_ATTRIBUTE_ACTION_LIST
+        kind == SyntaxKind::BIT_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31481,10 +33454,10 @@ impl AstNode for AlterTypeAttributeActionList {
         &self.syntax
     }
 }
-impl AstNode for AlterUser {
+impl AstNode for BitVaryingType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_USER
+        kind == SyntaxKind::BIT_VARYING_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31499,10 +33472,10 @@ impl AstNode for AlterUser {
         &self.syntax
     }
 }
-impl AstNode for AlterUserMapping {
+impl AstNode for BreadthFirst {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_USER_MAPPING
+        kind == SyntaxKind::BREADTH_FIRST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31517,10 +33490,10 @@ impl AstNode for AlterUserMapping {
         &self.syntax
     }
 }
-impl AstNode for AlterVertexEdgeLabels {
+impl AstNode for ByTarget {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_VERTEX_EDGE_LABELS
+        kind == SyntaxKind::BY_TARGET
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31535,10 +33508,10 @@ impl AstNode for AlterVertexEdgeLabels {
         &self.syntax
     }
 }
-impl AstNode for AlterView {
+impl AstNode for Call {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_VIEW
+        kind == SyntaxKind::CALL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31553,10 +33526,10 @@ impl AstNode for AlterView {
         &self.syntax
     }
 }
-impl AstNode for AlterViewColumn {
+impl AstNode for CallExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ALTER_VIEW_COLUMN
+        kind == SyntaxKind::CALL_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31571,10 +33544,10 @@ impl AstNode for AlterViewColumn {
         &self.syntax
     }
 }
-impl AstNode for Analyze {
+impl AstNode for CalledOnNullInputFuncOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ANALYZE
+        kind == SyntaxKind::CALLED_ON_NULL_INPUT_FUNC_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31589,10 +33562,10 @@ impl AstNode for Analyze {
         &self.syntax
     }
 }
-impl AstNode for AndChain {
+impl AstNode for Cascade {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AND_CHAIN
+        kind == SyntaxKind::CASCADE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31607,10 +33580,10 @@ impl AstNode for AndChain {
         &self.syntax
     }
 }
-impl AstNode for AndNoChain {
+impl AstNode for CascadedCheckOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AND_NO_CHAIN
+        kind == SyntaxKind::CASCADED_CHECK_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31625,10 +33598,10 @@ impl AstNode for AndNoChain {
         &self.syntax
     }
 }
-impl AstNode for AnyFn {
+impl AstNode for CaseExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ANY_FN
+        kind == SyntaxKind::CASE_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31643,10 +33616,10 @@ impl AstNode for AnyFn {
         &self.syntax
     }
 }
-impl AstNode for Arg {
+impl AstNode for CastExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ARG
+        kind == SyntaxKind::CAST_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31661,10 +33634,10 @@ impl AstNode for Arg {
         &self.syntax
     }
 }
-impl AstNode for ArgList {
+impl AstNode for CastSig {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ARG_LIST
+        kind == SyntaxKind::CAST_SIG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31679,10 +33652,10 @@ impl AstNode for ArgList {
         &self.syntax
     }
 }
-impl AstNode for ArrayBound {
+impl AstNode for Channel {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ARRAY_BOUND
+        kind == SyntaxKind::CHANNEL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31697,10 +33670,10 @@ impl AstNode for ArrayBound {
         &self.syntax
     }
 }
-impl AstNode for ArrayExpr {
+impl AstNode for ChannelRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ARRAY_EXPR
+        kind == SyntaxKind::CHANNEL_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31715,10 +33688,10 @@ impl AstNode for ArrayExpr {
         &self.syntax
     }
 }
-impl AstNode for ArrayType {
+impl AstNode for CharacterType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ARRAY_TYPE
+        kind == SyntaxKind::CHARACTER_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31733,10 +33706,10 @@ impl AstNode for ArrayType {
         &self.syntax
     }
 }
-impl AstNode for AsAssignment {
+impl AstNode for CheckConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AS_ASSIGNMENT
+        kind == SyntaxKind::CHECK_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31751,10 +33724,10 @@ impl AstNode for AsAssignment {
         &self.syntax
     }
 }
-impl AstNode for AsDefinition {
+impl AstNode for Checkpoint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AS_DEFINITION
+        kind == SyntaxKind::CHECKPOINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31769,10 +33742,10 @@ impl AstNode for AsDefinition {
         &self.syntax
     }
 }
-impl AstNode for AsFuncOption {
+impl AstNode for CheckpointOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AS_FUNC_OPTION
+        kind == SyntaxKind::CHECKPOINT_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31787,10 +33760,10 @@ impl AstNode for AsFuncOption {
         &self.syntax
     }
 }
-impl AstNode for AsImplicit {
+impl AstNode for CheckpointOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AS_IMPLICIT
+        kind == SyntaxKind::CHECKPOINT_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31805,10 +33778,10 @@ impl AstNode for AsImplicit {
         &self.syntax
     }
 }
-impl AstNode for AsName {
+impl AstNode for CheckpointOptionName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AS_NAME
+        kind == SyntaxKind::CHECKPOINT_OPTION_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31823,10 +33796,10 @@ impl AstNode for AsName {
         &self.syntax
     }
 }
-impl AstNode for AsObjFile {
+impl AstNode for Close {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AS_OBJ_FILE
+        kind == SyntaxKind::CLOSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31841,10 +33814,10 @@ impl AstNode for AsObjFile {
         &self.syntax
     }
 }
-impl AstNode for AsPolicyType {
+impl AstNode for Cluster {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AS_POLICY_TYPE
+        kind == SyntaxKind::CLUSTER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31859,10 +33832,10 @@ impl AstNode for AsPolicyType {
         &self.syntax
     }
 }
-impl AstNode for Asensitive {
+impl AstNode for ClusterLegacy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ASENSITIVE
+        kind == SyntaxKind::CLUSTER_LEGACY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31877,10 +33850,10 @@ impl AstNode for Asensitive {
         &self.syntax
     }
 }
-impl AstNode for Asymmetric {
+impl AstNode for ClusterOn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ASYMMETRIC
+        kind == SyntaxKind::CLUSTER_ON
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31895,10 +33868,10 @@ impl AstNode for Asymmetric {
         &self.syntax
     }
 }
-impl AstNode for AtLocal {
+impl AstNode for ClusterUsingIndex {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AT_LOCAL
+        kind == SyntaxKind::CLUSTER_USING_INDEX
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31913,10 +33886,10 @@ impl AstNode for AtLocal {
         &self.syntax
     }
 }
-impl AstNode for AtTimeZone {
+impl AstNode for Collate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AT_TIME_ZONE
+        kind == SyntaxKind::COLLATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31931,10 +33904,10 @@ impl AstNode for AtTimeZone {
         &self.syntax
     }
 }
-impl AstNode for AttachIndexPartition {
+impl AstNode for Collation {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ATTACH_INDEX_PARTITION
+        kind == SyntaxKind::COLLATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31949,10 +33922,10 @@ impl AstNode for AttachIndexPartition {
         &self.syntax
     }
 }
-impl AstNode for AttachPartition {
+impl AstNode for CollationForFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ATTACH_PARTITION
+        kind == SyntaxKind::COLLATION_FOR_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31967,10 +33940,10 @@ impl AstNode for AttachPartition {
         &self.syntax
     }
 }
-impl AstNode for AttributeList {
+impl AstNode for CollationFrom {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ATTRIBUTE_LIST
+        kind == SyntaxKind::COLLATION_FROM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -31985,10 +33958,10 @@ impl AstNode for AttributeList {
         &self.syntax
     }
 }
-impl AstNode for AttributeName {
+impl AstNode for CollationRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ATTRIBUTE_NAME
+        kind == SyntaxKind::COLLATION_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32003,10 +33976,10 @@ impl AstNode for AttributeName {
         &self.syntax
     }
 }
-impl AstNode for AttributeNamespace {
+impl AstNode for CollationRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ATTRIBUTE_NAMESPACE
+        kind == SyntaxKind::COLLATION_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32021,10 +33994,10 @@ impl AstNode for AttributeNamespace {
         &self.syntax
     }
 }
-impl AstNode for AttributeOption {
+impl AstNode for ColonColon {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ATTRIBUTE_OPTION
+        kind == SyntaxKind::COLON_COLON
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32039,10 +34012,10 @@ impl AstNode for AttributeOption {
         &self.syntax
     }
 }
-impl AstNode for AttributeValue {
+impl AstNode for ColonEq {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ATTRIBUTE_VALUE
+        kind == SyntaxKind::COLON_EQ
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32057,10 +34030,10 @@ impl AstNode for AttributeValue {
         &self.syntax
     }
 }
-impl AstNode for AuthorizationSchema {
+impl AstNode for Column {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::AUTHORIZATION_SCHEMA
+        kind == SyntaxKind::COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32075,10 +34048,10 @@ impl AstNode for AuthorizationSchema {
         &self.syntax
     }
 }
-impl AstNode for Backward {
+impl AstNode for ColumnDef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BACKWARD
+        kind == SyntaxKind::COLUMN_DEF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32093,10 +34066,10 @@ impl AstNode for Backward {
         &self.syntax
     }
 }
-impl AstNode for BaseType {
+impl AstNode for ColumnDefList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BASE_TYPE
+        kind == SyntaxKind::COLUMN_DEF_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32111,10 +34084,10 @@ impl AstNode for BaseType {
         &self.syntax
     }
 }
-impl AstNode for BeforeValue {
+impl AstNode for ColumnList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BEFORE_VALUE
+        kind == SyntaxKind::COLUMN_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32129,10 +34102,10 @@ impl AstNode for BeforeValue {
         &self.syntax
     }
 }
-impl AstNode for Begin {
+impl AstNode for ColumnName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BEGIN
+        kind == SyntaxKind::COLUMN_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32147,10 +34120,10 @@ impl AstNode for Begin {
         &self.syntax
     }
 }
-impl AstNode for BeginFuncOptionList {
+impl AstNode for ColumnNameRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BEGIN_FUNC_OPTION_LIST
+        kind == SyntaxKind::COLUMN_NAME_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32165,10 +34138,10 @@ impl AstNode for BeginFuncOptionList {
         &self.syntax
     }
 }
-impl AstNode for BetweenExpr {
+impl AstNode for ColumnRefList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BETWEEN_EXPR
+        kind == SyntaxKind::COLUMN_REF_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32183,10 +34156,10 @@ impl AstNode for BetweenExpr {
         &self.syntax
     }
 }
-impl AstNode for BinExpr {
+impl AstNode for ColumnTarget {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BIN_EXPR
+        kind == SyntaxKind::COLUMN_TARGET
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32201,10 +34174,10 @@ impl AstNode for BinExpr {
         &self.syntax
     }
 }
-impl AstNode for BindParamNameRef {
+impl AstNode for ColumnTargetList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BIND_PARAM_NAME_REF
+        kind == SyntaxKind::COLUMN_TARGET_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32219,10 +34192,10 @@ impl AstNode for BindParamNameRef {
         &self.syntax
     }
 }
-impl AstNode for BitType {
+impl AstNode for CommentOn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BIT_TYPE
+        kind == SyntaxKind::COMMENT_ON
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32237,10 +34210,10 @@ impl AstNode for BitType {
         &self.syntax
     }
 }
-impl AstNode for BitVaryingType {
+impl AstNode for CommitPrepared {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BIT_VARYING_TYPE
+        kind == SyntaxKind::COMMIT_PREPARED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32255,10 +34228,10 @@ impl AstNode for BitVaryingType {
         &self.syntax
     }
 }
-impl AstNode for BreadthFirst {
+impl AstNode for CommitTransaction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BREADTH_FIRST
+        kind == SyntaxKind::COMMIT_TRANSACTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32273,10 +34246,10 @@ impl AstNode for BreadthFirst {
         &self.syntax
     }
 }
-impl AstNode for ByTarget {
+impl AstNode for CompositeField {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::BY_TARGET
+        kind == SyntaxKind::COMPOSITE_FIELD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32291,10 +34264,10 @@ impl AstNode for ByTarget {
         &self.syntax
     }
 }
-impl AstNode for Call {
+impl AstNode for CompositeFieldDef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CALL
+        kind == SyntaxKind::COMPOSITE_FIELD_DEF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32309,10 +34282,10 @@ impl AstNode for Call {
         &self.syntax
     }
 }
-impl AstNode for CallExpr {
+impl AstNode for CompositeFieldList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CALL_EXPR
+        kind == SyntaxKind::COMPOSITE_FIELD_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32327,10 +34300,10 @@ impl AstNode for CallExpr {
         &self.syntax
     }
 }
-impl AstNode for CalledOnNullInputFuncOption {
+impl AstNode for CompositeFieldRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CALLED_ON_NULL_INPUT_FUNC_OPTION
+        kind == SyntaxKind::COMPOSITE_FIELD_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32345,10 +34318,10 @@ impl AstNode for CalledOnNullInputFuncOption {
         &self.syntax
     }
 }
-impl AstNode for Cascade {
+impl AstNode for CompositeType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CASCADE
+        kind == SyntaxKind::COMPOSITE_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32363,10 +34336,10 @@ impl AstNode for Cascade {
         &self.syntax
     }
 }
-impl AstNode for CascadedCheckOption {
+impl AstNode for CompoundSelect {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CASCADED_CHECK_OPTION
+        kind == SyntaxKind::COMPOUND_SELECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32381,10 +34354,10 @@ impl AstNode for CascadedCheckOption {
         &self.syntax
     }
 }
-impl AstNode for CaseExpr {
+impl AstNode for CompressionMethod {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CASE_EXPR
+        kind == SyntaxKind::COMPRESSION_METHOD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32399,10 +34372,10 @@ impl AstNode for CaseExpr {
         &self.syntax
     }
 }
-impl AstNode for CastExpr {
+impl AstNode for CompressionMethodName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CAST_EXPR
+        kind == SyntaxKind::COMPRESSION_METHOD_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32417,10 +34390,10 @@ impl AstNode for CastExpr {
         &self.syntax
     }
 }
-impl AstNode for CastSig {
+impl AstNode for ConfigParameterRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CAST_SIG
+        kind == SyntaxKind::CONFIG_PARAMETER_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32435,10 +34408,10 @@ impl AstNode for CastSig {
         &self.syntax
     }
 }
-impl AstNode for Channel {
+impl AstNode for ConfigValueName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CHANNEL
+        kind == SyntaxKind::CONFIG_VALUE_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32453,10 +34426,10 @@ impl AstNode for Channel {
         &self.syntax
     }
 }
-impl AstNode for ChannelRef {
+impl AstNode for ConflictDoNothing {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CHANNEL_REF
+        kind == SyntaxKind::CONFLICT_DO_NOTHING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32471,10 +34444,10 @@ impl AstNode for ChannelRef {
         &self.syntax
     }
 }
-impl AstNode for CharacterType {
+impl AstNode for ConflictDoSelect {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CHARACTER_TYPE
+        kind == SyntaxKind::CONFLICT_DO_SELECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32489,10 +34462,10 @@ impl AstNode for CharacterType {
         &self.syntax
     }
 }
-impl AstNode for CheckConstraint {
+impl AstNode for ConflictDoUpdateSet {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CHECK_CONSTRAINT
+        kind == SyntaxKind::CONFLICT_DO_UPDATE_SET
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32507,10 +34480,10 @@ impl AstNode for CheckConstraint {
         &self.syntax
     }
 }
-impl AstNode for Checkpoint {
+impl AstNode for ConflictIndexItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CHECKPOINT
+        kind == SyntaxKind::CONFLICT_INDEX_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32525,10 +34498,10 @@ impl AstNode for Checkpoint {
         &self.syntax
     }
 }
-impl AstNode for CheckpointOption {
+impl AstNode for ConflictIndexItemList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CHECKPOINT_OPTION
+        kind == SyntaxKind::CONFLICT_INDEX_ITEM_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32543,10 +34516,10 @@ impl AstNode for CheckpointOption {
         &self.syntax
     }
 }
-impl AstNode for CheckpointOptionList {
+impl AstNode for ConflictOnConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CHECKPOINT_OPTION_LIST
+        kind == SyntaxKind::CONFLICT_ON_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32561,10 +34534,10 @@ impl AstNode for CheckpointOptionList {
         &self.syntax
     }
 }
-impl AstNode for CheckpointOptionName {
+impl AstNode for ConflictOnIndex {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CHECKPOINT_OPTION_NAME
+        kind == SyntaxKind::CONFLICT_ON_INDEX
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32579,10 +34552,10 @@ impl AstNode for CheckpointOptionName {
         &self.syntax
     }
 }
-impl AstNode for Close {
+impl AstNode for ConnectionClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CLOSE
+        kind == SyntaxKind::CONNECTION_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32597,10 +34570,10 @@ impl AstNode for Close {
         &self.syntax
     }
 }
-impl AstNode for Cluster {
+impl AstNode for ConstraintColumnRefList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CLUSTER
+        kind == SyntaxKind::CONSTRAINT_COLUMN_REF_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32615,10 +34588,10 @@ impl AstNode for Cluster {
         &self.syntax
     }
 }
-impl AstNode for ClusterLegacy {
+impl AstNode for ConstraintExclusion {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CLUSTER_LEGACY
+        kind == SyntaxKind::CONSTRAINT_EXCLUSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32633,10 +34606,10 @@ impl AstNode for ClusterLegacy {
         &self.syntax
     }
 }
-impl AstNode for ClusterOn {
+impl AstNode for ConstraintExclusionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CLUSTER_ON
+        kind == SyntaxKind::CONSTRAINT_EXCLUSION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32651,10 +34624,10 @@ impl AstNode for ClusterOn {
         &self.syntax
     }
 }
-impl AstNode for ClusterUsingIndex {
+impl AstNode for ConstraintIncludeClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CLUSTER_USING_INDEX
+        kind == SyntaxKind::CONSTRAINT_INCLUDE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32669,10 +34642,10 @@ impl AstNode for ClusterUsingIndex {
         &self.syntax
     }
 }
-impl AstNode for Collate {
+impl AstNode for ConstraintIndexMethod {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLLATE
+        kind == SyntaxKind::CONSTRAINT_INDEX_METHOD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32687,10 +34660,10 @@ impl AstNode for Collate {
         &self.syntax
     }
 }
-impl AstNode for Collation {
+impl AstNode for ConstraintIndexTablespace {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLLATION
+        kind == SyntaxKind::CONSTRAINT_INDEX_TABLESPACE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32705,10 +34678,10 @@ impl AstNode for Collation {
         &self.syntax
     }
 }
-impl AstNode for CollationForFn {
+impl AstNode for ConstraintName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLLATION_FOR_FN
+        kind == SyntaxKind::CONSTRAINT_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32723,10 +34696,10 @@ impl AstNode for CollationForFn {
         &self.syntax
     }
 }
-impl AstNode for CollationFrom {
+impl AstNode for ConstraintNameClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLLATION_FROM
+        kind == SyntaxKind::CONSTRAINT_NAME_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32741,10 +34714,10 @@ impl AstNode for CollationFrom {
         &self.syntax
     }
 }
-impl AstNode for CollationRef {
+impl AstNode for ConstraintNameRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLLATION_REF
+        kind == SyntaxKind::CONSTRAINT_NAME_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32759,10 +34732,10 @@ impl AstNode for CollationRef {
         &self.syntax
     }
 }
-impl AstNode for CollationRenameTo {
+impl AstNode for ContinueIdentity {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLLATION_RENAME_TO
+        kind == SyntaxKind::CONTINUE_IDENTITY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32777,10 +34750,10 @@ impl AstNode for CollationRenameTo {
         &self.syntax
     }
 }
-impl AstNode for ColonColon {
+impl AstNode for Conversion {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLON_COLON
+        kind == SyntaxKind::CONVERSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32795,10 +34768,10 @@ impl AstNode for ColonColon {
         &self.syntax
     }
 }
-impl AstNode for ColonEq {
+impl AstNode for ConversionRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLON_EQ
+        kind == SyntaxKind::CONVERSION_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32813,10 +34786,10 @@ impl AstNode for ColonEq {
         &self.syntax
     }
 }
-impl AstNode for Column {
+impl AstNode for ConversionRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN
+        kind == SyntaxKind::CONVERSION_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32831,10 +34804,10 @@ impl AstNode for Column {
         &self.syntax
     }
 }
-impl AstNode for ColumnDef {
+impl AstNode for Copy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN_DEF
+        kind == SyntaxKind::COPY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32849,10 +34822,10 @@ impl AstNode for ColumnDef {
         &self.syntax
     }
 }
-impl AstNode for ColumnDefList {
+impl AstNode for CopyFrom {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN_DEF_LIST
+        kind == SyntaxKind::COPY_FROM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32867,10 +34840,10 @@ impl AstNode for ColumnDefList {
         &self.syntax
     }
 }
-impl AstNode for ColumnList {
+impl AstNode for CopyLegacyOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN_LIST
+        kind == SyntaxKind::COPY_LEGACY_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32885,10 +34858,10 @@ impl AstNode for ColumnList {
         &self.syntax
     }
 }
-impl AstNode for ColumnName {
+impl AstNode for CopyOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN_NAME
+        kind == SyntaxKind::COPY_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32903,10 +34876,10 @@ impl AstNode for ColumnName {
         &self.syntax
     }
 }
-impl AstNode for ColumnNameRef {
+impl AstNode for CopyOptionKey {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN_NAME_REF
+        kind == SyntaxKind::COPY_OPTION_KEY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32921,10 +34894,10 @@ impl AstNode for ColumnNameRef {
         &self.syntax
     }
 }
-impl AstNode for ColumnRefList {
+impl AstNode for CopyOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN_REF_LIST
+        kind == SyntaxKind::COPY_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32939,10 +34912,10 @@ impl AstNode for ColumnRefList {
         &self.syntax
     }
 }
-impl AstNode for ColumnTarget {
+impl AstNode for CopyOptionValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN_TARGET
+        kind == SyntaxKind::COPY_OPTION_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32957,10 +34930,10 @@ impl AstNode for ColumnTarget {
         &self.syntax
     }
 }
-impl AstNode for ColumnTargetList {
+impl AstNode for CopyOptionValueName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COLUMN_TARGET_LIST
+        kind == SyntaxKind::COPY_OPTION_VALUE_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32975,10 +34948,10 @@ impl AstNode for ColumnTargetList {
         &self.syntax
     }
 }
-impl AstNode for CommentOn {
+impl AstNode for CopyProgram {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMMENT_ON
+        kind == SyntaxKind::COPY_PROGRAM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -32993,10 +34966,10 @@ impl AstNode for CommentOn {
         &self.syntax
     }
 }
-impl AstNode for CommitPrepared {
+impl AstNode for CopyQuery {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMMIT_PREPARED
+        kind == SyntaxKind::COPY_QUERY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33011,10 +34984,10 @@ impl AstNode for CommitPrepared {
         &self.syntax
     }
 }
-impl AstNode for CommitTransaction {
+impl AstNode for CopyStdin {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMMIT_TRANSACTION
+        kind == SyntaxKind::COPY_STDIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33029,10 +35002,10 @@ impl AstNode for CommitTransaction {
         &self.syntax
     }
 }
-impl AstNode for CompositeField {
+impl AstNode for CopyStdinData {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMPOSITE_FIELD
+        kind == SyntaxKind::COPY_STDIN_DATA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33047,10 +35020,10 @@ impl AstNode for CompositeField {
         &self.syntax
     }
 }
-impl AstNode for CompositeFieldDef {
+impl AstNode for CopyStdout {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMPOSITE_FIELD_DEF
+        kind == SyntaxKind::COPY_STDOUT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33065,10 +35038,10 @@ impl AstNode for CompositeFieldDef {
         &self.syntax
     }
 }
-impl AstNode for CompositeFieldList {
+impl AstNode for CopyTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMPOSITE_FIELD_LIST
+        kind == SyntaxKind::COPY_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33083,10 +35056,10 @@ impl AstNode for CompositeFieldList {
         &self.syntax
     }
 }
-impl AstNode for CompositeFieldRef {
+impl AstNode for CopyTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMPOSITE_FIELD_REF
+        kind == SyntaxKind::COPY_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33101,10 +35074,10 @@ impl AstNode for CompositeFieldRef {
         &self.syntax
     }
 }
-impl AstNode for CompositeType {
+impl AstNode for CostFuncOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMPOSITE_TYPE
+        kind == SyntaxKind::COST_FUNC_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33119,10 +35092,10 @@ impl AstNode for CompositeType {
         &self.syntax
     }
 }
-impl AstNode for CompoundSelect {
+impl AstNode for CreateAccessMethod {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMPOUND_SELECT
+        kind == SyntaxKind::CREATE_ACCESS_METHOD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33137,10 +35110,10 @@ impl AstNode for CompoundSelect {
         &self.syntax
     }
 }
-impl AstNode for CompressionMethod {
+impl AstNode for CreateAggregate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMPRESSION_METHOD
+        kind == SyntaxKind::CREATE_AGGREGATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33155,10 +35128,10 @@ impl AstNode for CompressionMethod {
         &self.syntax
     }
 }
-impl AstNode for CompressionMethodName {
+impl AstNode for CreateCast {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COMPRESSION_METHOD_NAME
+        kind == SyntaxKind::CREATE_CAST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33173,10 +35146,10 @@ impl AstNode for CompressionMethodName {
         &self.syntax
     }
 }
-impl AstNode for ConfigParameterRef {
+impl AstNode for CreateCollation {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFIG_PARAMETER_REF
+        kind == SyntaxKind::CREATE_COLLATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33191,10 +35164,10 @@ impl AstNode for ConfigParameterRef {
         &self.syntax
     }
 }
-impl AstNode for ConfigValueName {
+impl AstNode for CreateConversion {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFIG_VALUE_NAME
+        kind == SyntaxKind::CREATE_CONVERSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33209,10 +35182,10 @@ impl AstNode for ConfigValueName {
         &self.syntax
     }
 }
-impl AstNode for ConflictDoNothing {
+impl AstNode for CreateDatabase {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFLICT_DO_NOTHING
+        kind == SyntaxKind::CREATE_DATABASE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33227,10 +35200,10 @@ impl AstNode for ConflictDoNothing {
         &self.syntax
     }
 }
-impl AstNode for ConflictDoSelect {
+impl AstNode for CreateDomain {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFLICT_DO_SELECT
+        kind == SyntaxKind::CREATE_DOMAIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33245,10 +35218,10 @@ impl AstNode for ConflictDoSelect {
         &self.syntax
     }
 }
-impl AstNode for ConflictDoUpdateSet {
+impl AstNode for CreateEventTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFLICT_DO_UPDATE_SET
+        kind == SyntaxKind::CREATE_EVENT_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33263,10 +35236,10 @@ impl AstNode for ConflictDoUpdateSet {
         &self.syntax
     }
 }
-impl AstNode for ConflictIndexItem {
+impl AstNode for CreateExtension {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFLICT_INDEX_ITEM
+        kind == SyntaxKind::CREATE_EXTENSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33281,10 +35254,10 @@ impl AstNode for ConflictIndexItem {
         &self.syntax
     }
 }
-impl AstNode for ConflictIndexItemList {
+impl AstNode for CreateForeignDataWrapper {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFLICT_INDEX_ITEM_LIST
+        kind == SyntaxKind::CREATE_FOREIGN_DATA_WRAPPER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33299,10 +35272,10 @@ impl AstNode for ConflictIndexItemList {
         &self.syntax
     }
 }
-impl AstNode for ConflictOnConstraint {
+impl AstNode for CreateForeignTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFLICT_ON_CONSTRAINT
+        kind == SyntaxKind::CREATE_FOREIGN_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33317,10 +35290,10 @@ impl AstNode for ConflictOnConstraint {
         &self.syntax
     }
 }
-impl AstNode for ConflictOnIndex {
+impl AstNode for CreateFunction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONFLICT_ON_INDEX
+        kind == SyntaxKind::CREATE_FUNCTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33335,10 +35308,10 @@ impl AstNode for ConflictOnIndex {
         &self.syntax
     }
 }
-impl AstNode for ConnectionClause {
+impl AstNode for CreateGroup {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONNECTION_CLAUSE
+        kind == SyntaxKind::CREATE_GROUP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33353,10 +35326,10 @@ impl AstNode for ConnectionClause {
         &self.syntax
     }
 }
-impl AstNode for ConstraintColumnRefList {
+impl AstNode for CreateIndex {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_COLUMN_REF_LIST
+        kind == SyntaxKind::CREATE_INDEX
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33371,10 +35344,10 @@ impl AstNode for ConstraintColumnRefList {
         &self.syntax
     }
 }
-impl AstNode for ConstraintExclusion {
+impl AstNode for CreateLanguage {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_EXCLUSION
+        kind == SyntaxKind::CREATE_LANGUAGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33389,10 +35362,10 @@ impl AstNode for ConstraintExclusion {
         &self.syntax
     }
 }
-impl AstNode for ConstraintExclusionList {
+impl AstNode for CreateMaterializedView {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_EXCLUSION_LIST
+        kind == SyntaxKind::CREATE_MATERIALIZED_VIEW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33407,10 +35380,10 @@ impl AstNode for ConstraintExclusionList {
         &self.syntax
     }
 }
-impl AstNode for ConstraintIncludeClause {
+impl AstNode for CreateOperator {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_INCLUDE_CLAUSE
+        kind == SyntaxKind::CREATE_OPERATOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33425,10 +35398,10 @@ impl AstNode for ConstraintIncludeClause {
         &self.syntax
     }
 }
-impl AstNode for ConstraintIndexMethod {
+impl AstNode for CreateOperatorClass {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_INDEX_METHOD
+        kind == SyntaxKind::CREATE_OPERATOR_CLASS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33443,10 +35416,10 @@ impl AstNode for ConstraintIndexMethod {
         &self.syntax
     }
 }
-impl AstNode for ConstraintIndexTablespace {
+impl AstNode for CreateOperatorFamily {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_INDEX_TABLESPACE
+        kind == SyntaxKind::CREATE_OPERATOR_FAMILY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33461,10 +35434,10 @@ impl AstNode for ConstraintIndexTablespace {
         &self.syntax
     }
 }
-impl AstNode for ConstraintName {
+impl AstNode for CreatePolicy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_NAME
+        kind == SyntaxKind::CREATE_POLICY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33479,10 +35452,10 @@ impl AstNode for ConstraintName {
         &self.syntax
     }
 }
-impl AstNode for ConstraintNameClause {
+impl AstNode for CreateProcedure {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_NAME_CLAUSE
+        kind == SyntaxKind::CREATE_PROCEDURE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33497,10 +35470,10 @@ impl AstNode for ConstraintNameClause {
         &self.syntax
     }
 }
-impl AstNode for ConstraintNameRef {
+impl AstNode for CreatePropertyGraph {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONSTRAINT_NAME_REF
+        kind == SyntaxKind::CREATE_PROPERTY_GRAPH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33515,10 +35488,10 @@ impl AstNode for ConstraintNameRef {
         &self.syntax
     }
 }
-impl AstNode for ContinueIdentity {
+impl AstNode for CreatePublication {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONTINUE_IDENTITY
+        kind == SyntaxKind::CREATE_PUBLICATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33533,10 +35506,10 @@ impl AstNode for ContinueIdentity {
         &self.syntax
     }
 }
-impl AstNode for Conversion {
+impl AstNode for CreateRole {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONVERSION
+        kind == SyntaxKind::CREATE_ROLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33551,10 +35524,10 @@ impl AstNode for Conversion {
         &self.syntax
     }
 }
-impl AstNode for ConversionRef {
+impl AstNode for CreateRule {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONVERSION_REF
+        kind == SyntaxKind::CREATE_RULE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33569,10 +35542,10 @@ impl AstNode for ConversionRef {
         &self.syntax
     }
 }
-impl AstNode for ConversionRenameTo {
+impl AstNode for CreateSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CONVERSION_RENAME_TO
+        kind == SyntaxKind::CREATE_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33587,10 +35560,10 @@ impl AstNode for ConversionRenameTo {
         &self.syntax
     }
 }
-impl AstNode for Copy {
+impl AstNode for CreateSequence {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY
+        kind == SyntaxKind::CREATE_SEQUENCE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33605,10 +35578,10 @@ impl AstNode for Copy {
         &self.syntax
     }
 }
-impl AstNode for CopyFrom {
+impl AstNode for CreateServer {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_FROM
+        kind == SyntaxKind::CREATE_SERVER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33623,10 +35596,10 @@ impl AstNode for CopyFrom {
         &self.syntax
     }
 }
-impl AstNode for CopyLegacyOption {
+impl AstNode for CreateStatistics {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_LEGACY_OPTION
+        kind == SyntaxKind::CREATE_STATISTICS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33641,10 +35614,10 @@ impl AstNode for CopyLegacyOption {
         &self.syntax
     }
 }
-impl AstNode for CopyOption {
+impl AstNode for CreateSubscription {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_OPTION
+        kind == SyntaxKind::CREATE_SUBSCRIPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33659,10 +35632,10 @@ impl AstNode for CopyOption {
         &self.syntax
     }
 }
-impl AstNode for CopyOptionKey {
+impl AstNode for CreateTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_OPTION_KEY
+        kind == SyntaxKind::CREATE_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33677,10 +35650,10 @@ impl AstNode for CopyOptionKey {
         &self.syntax
     }
 }
-impl AstNode for CopyOptionList {
+impl AstNode for CreateTableAs {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_OPTION_LIST
+        kind == SyntaxKind::CREATE_TABLE_AS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33695,10 +35668,10 @@ impl AstNode for CopyOptionList {
         &self.syntax
     }
 }
-impl AstNode for CopyOptionValue {
+impl AstNode for CreateTablespace {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_OPTION_VALUE
+        kind == SyntaxKind::CREATE_TABLESPACE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33713,10 +35686,10 @@ impl AstNode for CopyOptionValue {
         &self.syntax
     }
 }
-impl AstNode for CopyOptionValueName {
+impl AstNode for CreateTextSearchConfiguration {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_OPTION_VALUE_NAME
+        kind == SyntaxKind::CREATE_TEXT_SEARCH_CONFIGURATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33731,10 +35704,10 @@ impl AstNode for CopyOptionValueName {
         &self.syntax
     }
 }
-impl AstNode for CopyProgram {
+impl AstNode for CreateTextSearchDictionary {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_PROGRAM
+        kind == SyntaxKind::CREATE_TEXT_SEARCH_DICTIONARY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33749,10 +35722,10 @@ impl AstNode for CopyProgram {
         &self.syntax
     }
 }
-impl AstNode for CopyQuery {
+impl AstNode for CreateTextSearchParser {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_QUERY
+        kind == SyntaxKind::CREATE_TEXT_SEARCH_PARSER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33767,10 +35740,10 @@ impl AstNode for CopyQuery {
         &self.syntax
     }
 }
-impl AstNode for CopyStdin {
+impl AstNode for CreateTextSearchTemplate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_STDIN
+        kind == SyntaxKind::CREATE_TEXT_SEARCH_TEMPLATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33785,10 +35758,10 @@ impl AstNode for CopyStdin {
         &self.syntax
     }
 }
-impl AstNode for CopyStdout {
+impl AstNode for CreateTransform {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_STDOUT
+        kind == SyntaxKind::CREATE_TRANSFORM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33803,10 +35776,10 @@ impl AstNode for CopyStdout {
         &self.syntax
     }
 }
-impl AstNode for CopyTable {
+impl AstNode for CreateTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_TABLE
+        kind == SyntaxKind::CREATE_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33821,10 +35794,10 @@ impl AstNode for CopyTable {
         &self.syntax
     }
 }
-impl AstNode for CopyTo {
+impl AstNode for CreateType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COPY_TO
+        kind == SyntaxKind::CREATE_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33839,10 +35812,10 @@ impl AstNode for CopyTo {
         &self.syntax
     }
 }
-impl AstNode for CostFuncOption {
+impl AstNode for CreateUser {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::COST_FUNC_OPTION
+        kind == SyntaxKind::CREATE_USER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33857,10 +35830,10 @@ impl AstNode for CostFuncOption {
         &self.syntax
     }
 }
-impl AstNode for CreateAccessMethod {
+impl AstNode for CreateUserMapping {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_ACCESS_METHOD
+        kind == SyntaxKind::CREATE_USER_MAPPING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33875,10 +35848,10 @@ impl AstNode for CreateAccessMethod {
         &self.syntax
     }
 }
-impl AstNode for CreateAggregate {
+impl AstNode for CreateView {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_AGGREGATE
+        kind == SyntaxKind::CREATE_VIEW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33893,10 +35866,10 @@ impl AstNode for CreateAggregate {
         &self.syntax
     }
 }
-impl AstNode for CreateCast {
+impl AstNode for CteName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_CAST
+        kind == SyntaxKind::CTE_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33911,10 +35884,10 @@ impl AstNode for CreateCast {
         &self.syntax
     }
 }
-impl AstNode for CreateCollation {
+impl AstNode for CurrentRow {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_COLLATION
+        kind == SyntaxKind::CURRENT_ROW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33929,10 +35902,10 @@ impl AstNode for CreateCollation {
         &self.syntax
     }
 }
-impl AstNode for CreateConversion {
+impl AstNode for Cursor {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_CONVERSION
+        kind == SyntaxKind::CURSOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33947,10 +35920,10 @@ impl AstNode for CreateConversion {
         &self.syntax
     }
 }
-impl AstNode for CreateDatabase {
+impl AstNode for CursorRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_DATABASE
+        kind == SyntaxKind::CURSOR_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33965,10 +35938,10 @@ impl AstNode for CreateDatabase {
         &self.syntax
     }
 }
-impl AstNode for CreateDomain {
+impl AstNode for CustomOp {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_DOMAIN
+        kind == SyntaxKind::CUSTOM_OP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -33983,10 +35956,10 @@ impl AstNode for CreateDomain {
         &self.syntax
     }
 }
-impl AstNode for CreateEventTrigger {
+impl AstNode for CycleClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_EVENT_TRIGGER
+        kind == SyntaxKind::CYCLE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34001,10 +35974,10 @@ impl AstNode for CreateEventTrigger {
         &self.syntax
     }
 }
-impl AstNode for CreateExtension {
+impl AstNode for CycleColumnTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_EXTENSION
+        kind == SyntaxKind::CYCLE_COLUMN_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34019,10 +35992,10 @@ impl AstNode for CreateExtension {
         &self.syntax
     }
 }
-impl AstNode for CreateForeignDataWrapper {
+impl AstNode for CycleColumns {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_FOREIGN_DATA_WRAPPER
+        kind == SyntaxKind::CYCLE_COLUMNS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34037,10 +36010,10 @@ impl AstNode for CreateForeignDataWrapper {
         &self.syntax
     }
 }
-impl AstNode for CreateForeignTable {
+impl AstNode for CycleDefault {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_FOREIGN_TABLE
+        kind == SyntaxKind::CYCLE_DEFAULT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34055,10 +36028,10 @@ impl AstNode for CreateForeignTable {
         &self.syntax
     }
 }
-impl AstNode for CreateFunction {
+impl AstNode for CyclePath {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_FUNCTION
+        kind == SyntaxKind::CYCLE_PATH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34073,10 +36046,10 @@ impl AstNode for CreateFunction {
         &self.syntax
     }
 }
-impl AstNode for CreateGroup {
+impl AstNode for CycleSetColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_GROUP
+        kind == SyntaxKind::CYCLE_SET_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34091,10 +36064,10 @@ impl AstNode for CreateGroup {
         &self.syntax
     }
 }
-impl AstNode for CreateIndex {
+impl AstNode for Database {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_INDEX
+        kind == SyntaxKind::DATABASE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34109,10 +36082,10 @@ impl AstNode for CreateIndex {
         &self.syntax
     }
 }
-impl AstNode for CreateLanguage {
+impl AstNode for DatabaseOptionConnectionLimit {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_LANGUAGE
+        kind == SyntaxKind::DATABASE_OPTION_CONNECTION_LIMIT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34127,10 +36100,10 @@ impl AstNode for CreateLanguage {
         &self.syntax
     }
 }
-impl AstNode for CreateMaterializedView {
+impl AstNode for DatabaseOptionEncoding {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_MATERIALIZED_VIEW
+        kind == SyntaxKind::DATABASE_OPTION_ENCODING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34145,10 +36118,10 @@ impl AstNode for CreateMaterializedView {
         &self.syntax
     }
 }
-impl AstNode for CreateOperator {
+impl AstNode for DatabaseOptionGeneric {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_OPERATOR
+        kind == SyntaxKind::DATABASE_OPTION_GENERIC
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34163,10 +36136,10 @@ impl AstNode for CreateOperator {
         &self.syntax
     }
 }
-impl AstNode for CreateOperatorClass {
+impl AstNode for DatabaseOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_OPERATOR_CLASS
+        kind == SyntaxKind::DATABASE_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34181,10 +36154,10 @@ impl AstNode for CreateOperatorClass {
         &self.syntax
     }
 }
-impl AstNode for CreateOperatorFamily {
+impl AstNode for DatabaseOptionOwner {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_OPERATOR_FAMILY
+        kind == SyntaxKind::DATABASE_OPTION_OWNER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34199,10 +36172,10 @@ impl AstNode for CreateOperatorFamily {
         &self.syntax
     }
 }
-impl AstNode for CreatePolicy {
+impl AstNode for DatabaseOptionTablespace {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_POLICY
+        kind == SyntaxKind::DATABASE_OPTION_TABLESPACE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34217,10 +36190,10 @@ impl AstNode for CreatePolicy {
         &self.syntax
     }
 }
-impl AstNode for CreateProcedure {
+impl AstNode for DatabaseOptionTemplate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_PROCEDURE
+        kind == SyntaxKind::DATABASE_OPTION_TEMPLATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34235,10 +36208,10 @@ impl AstNode for CreateProcedure {
         &self.syntax
     }
 }
-impl AstNode for CreatePropertyGraph {
+impl AstNode for DatabaseRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_PROPERTY_GRAPH
+        kind == SyntaxKind::DATABASE_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34253,10 +36226,10 @@ impl AstNode for CreatePropertyGraph {
         &self.syntax
     }
 }
-impl AstNode for CreatePublication {
+impl AstNode for DatabaseRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_PUBLICATION
+        kind == SyntaxKind::DATABASE_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34271,10 +36244,10 @@ impl AstNode for CreatePublication {
         &self.syntax
     }
 }
-impl AstNode for CreateRole {
+impl AstNode for Deallocate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_ROLE
+        kind == SyntaxKind::DEALLOCATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34289,10 +36262,10 @@ impl AstNode for CreateRole {
         &self.syntax
     }
 }
-impl AstNode for CreateRule {
+impl AstNode for Declare {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_RULE
+        kind == SyntaxKind::DECLARE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34307,10 +36280,10 @@ impl AstNode for CreateRule {
         &self.syntax
     }
 }
-impl AstNode for CreateSchema {
+impl AstNode for DefaultConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_SCHEMA
+        kind == SyntaxKind::DEFAULT_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34325,10 +36298,10 @@ impl AstNode for CreateSchema {
         &self.syntax
     }
 }
-impl AstNode for CreateSequence {
+impl AstNode for DefaultLabel {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_SEQUENCE
+        kind == SyntaxKind::DEFAULT_LABEL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34343,10 +36316,10 @@ impl AstNode for CreateSequence {
         &self.syntax
     }
 }
-impl AstNode for CreateServer {
+impl AstNode for DefaultValues {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_SERVER
+        kind == SyntaxKind::DEFAULT_VALUES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34361,10 +36334,10 @@ impl AstNode for CreateServer {
         &self.syntax
     }
 }
-impl AstNode for CreateStatistics {
+impl AstNode for Deferrable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_STATISTICS
+        kind == SyntaxKind::DEFERRABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34379,10 +36352,10 @@ impl AstNode for CreateStatistics {
         &self.syntax
     }
 }
-impl AstNode for CreateSubscription {
+impl AstNode for DeferrableConstraintOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_SUBSCRIPTION
+        kind == SyntaxKind::DEFERRABLE_CONSTRAINT_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34397,10 +36370,10 @@ impl AstNode for CreateSubscription {
         &self.syntax
     }
 }
-impl AstNode for CreateTable {
+impl AstNode for Deferred {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TABLE
+        kind == SyntaxKind::DEFERRED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34415,10 +36388,10 @@ impl AstNode for CreateTable {
         &self.syntax
     }
 }
-impl AstNode for CreateTableAs {
+impl AstNode for Delete {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TABLE_AS
+        kind == SyntaxKind::DELETE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34433,10 +36406,10 @@ impl AstNode for CreateTableAs {
         &self.syntax
     }
 }
-impl AstNode for CreateTablespace {
+impl AstNode for DeleteRows {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TABLESPACE
+        kind == SyntaxKind::DELETE_ROWS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34451,10 +36424,10 @@ impl AstNode for CreateTablespace {
         &self.syntax
     }
 }
-impl AstNode for CreateTextSearchConfiguration {
+impl AstNode for DependsOnExtension {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TEXT_SEARCH_CONFIGURATION
+        kind == SyntaxKind::DEPENDS_ON_EXTENSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34469,10 +36442,10 @@ impl AstNode for CreateTextSearchConfiguration {
         &self.syntax
     }
 }
-impl AstNode for CreateTextSearchDictionary {
+impl AstNode for DepthFirst {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TEXT_SEARCH_DICTIONARY
+        kind == SyntaxKind::DEPTH_FIRST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34487,10 +36460,10 @@ impl AstNode for CreateTextSearchDictionary {
         &self.syntax
     }
 }
-impl AstNode for CreateTextSearchParser {
+impl AstNode for DestVertexTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TEXT_SEARCH_PARSER
+        kind == SyntaxKind::DEST_VERTEX_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34505,10 +36478,10 @@ impl AstNode for CreateTextSearchParser {
         &self.syntax
     }
 }
-impl AstNode for CreateTextSearchTemplate {
+impl AstNode for DetachConcurrently {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TEXT_SEARCH_TEMPLATE
+        kind == SyntaxKind::DETACH_CONCURRENTLY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34523,10 +36496,10 @@ impl AstNode for CreateTextSearchTemplate {
         &self.syntax
     }
 }
-impl AstNode for CreateTransform {
+impl AstNode for DetachFinalize {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TRANSFORM
+        kind == SyntaxKind::DETACH_FINALIZE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34541,10 +36514,10 @@ impl AstNode for CreateTransform {
         &self.syntax
     }
 }
-impl AstNode for CreateTrigger {
+impl AstNode for DetachPartition {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TRIGGER
+        kind == SyntaxKind::DETACH_PARTITION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34559,10 +36532,10 @@ impl AstNode for CreateTrigger {
         &self.syntax
     }
 }
-impl AstNode for CreateType {
+impl AstNode for Disable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_TYPE
+        kind == SyntaxKind::DISABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34577,10 +36550,10 @@ impl AstNode for CreateType {
         &self.syntax
     }
 }
-impl AstNode for CreateUser {
+impl AstNode for DisableRls {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_USER
+        kind == SyntaxKind::DISABLE_RLS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34595,10 +36568,10 @@ impl AstNode for CreateUser {
         &self.syntax
     }
 }
-impl AstNode for CreateUserMapping {
+impl AstNode for DisableRule {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_USER_MAPPING
+        kind == SyntaxKind::DISABLE_RULE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34613,10 +36586,10 @@ impl AstNode for CreateUserMapping {
         &self.syntax
     }
 }
-impl AstNode for CreateView {
+impl AstNode for DisableSubscription {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CREATE_VIEW
+        kind == SyntaxKind::DISABLE_SUBSCRIPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34631,10 +36604,10 @@ impl AstNode for CreateView {
         &self.syntax
     }
 }
-impl AstNode for CteName {
+impl AstNode for DisableTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CTE_NAME
+        kind == SyntaxKind::DISABLE_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34649,10 +36622,10 @@ impl AstNode for CteName {
         &self.syntax
     }
 }
-impl AstNode for CurrentRow {
+impl AstNode for Discard {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CURRENT_ROW
+        kind == SyntaxKind::DISCARD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34667,10 +36640,10 @@ impl AstNode for CurrentRow {
         &self.syntax
     }
 }
-impl AstNode for Cursor {
+impl AstNode for DiscardAll {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CURSOR
+        kind == SyntaxKind::DISCARD_ALL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34685,10 +36658,10 @@ impl AstNode for Cursor {
         &self.syntax
     }
 }
-impl AstNode for CursorRef {
+impl AstNode for DiscardPlans {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CURSOR_REF
+        kind == SyntaxKind::DISCARD_PLANS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34703,10 +36676,10 @@ impl AstNode for CursorRef {
         &self.syntax
     }
 }
-impl AstNode for CustomOp {
+impl AstNode for DiscardSequences {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CUSTOM_OP
+        kind == SyntaxKind::DISCARD_SEQUENCES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34721,10 +36694,10 @@ impl AstNode for CustomOp {
         &self.syntax
     }
 }
-impl AstNode for CycleClause {
+impl AstNode for DiscardTemp {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CYCLE_CLAUSE
+        kind == SyntaxKind::DISCARD_TEMP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34739,10 +36712,10 @@ impl AstNode for CycleClause {
         &self.syntax
     }
 }
-impl AstNode for CycleColumnTo {
+impl AstNode for Distinct {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CYCLE_COLUMN_TO
+        kind == SyntaxKind::DISTINCT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34757,10 +36730,10 @@ impl AstNode for CycleColumnTo {
         &self.syntax
     }
 }
-impl AstNode for CycleColumns {
+impl AstNode for DistinctClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CYCLE_COLUMNS
+        kind == SyntaxKind::DISTINCT_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34775,10 +36748,10 @@ impl AstNode for CycleColumns {
         &self.syntax
     }
 }
-impl AstNode for CycleDefault {
+impl AstNode for DistinctOn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CYCLE_DEFAULT
+        kind == SyntaxKind::DISTINCT_ON
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34793,10 +36766,10 @@ impl AstNode for CycleDefault {
         &self.syntax
     }
 }
-impl AstNode for CyclePath {
+impl AstNode for Do {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CYCLE_PATH
+        kind == SyntaxKind::DO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34811,10 +36784,10 @@ impl AstNode for CyclePath {
         &self.syntax
     }
 }
-impl AstNode for CycleSetColumn {
+impl AstNode for DoLanguage {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::CYCLE_SET_COLUMN
+        kind == SyntaxKind::DO_LANGUAGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34829,10 +36802,10 @@ impl AstNode for CycleSetColumn {
         &self.syntax
     }
 }
-impl AstNode for Database {
+impl AstNode for Domain {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE
+        kind == SyntaxKind::DOMAIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34847,10 +36820,10 @@ impl AstNode for Database {
         &self.syntax
     }
 }
-impl AstNode for DatabaseOptionConnectionLimit {
+impl AstNode for DomainRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_OPTION_CONNECTION_LIMIT
+        kind == SyntaxKind::DOMAIN_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34865,10 +36838,10 @@ impl AstNode for DatabaseOptionConnectionLimit {
         &self.syntax
     }
 }
-impl AstNode for DatabaseOptionEncoding {
+impl AstNode for DomainRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_OPTION_ENCODING
+        kind == SyntaxKind::DOMAIN_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34883,10 +36856,10 @@ impl AstNode for DatabaseOptionEncoding {
         &self.syntax
     }
 }
-impl AstNode for DatabaseOptionGeneric {
+impl AstNode for DoubleType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_OPTION_GENERIC
+        kind == SyntaxKind::DOUBLE_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34901,10 +36874,10 @@ impl AstNode for DatabaseOptionGeneric {
         &self.syntax
     }
 }
-impl AstNode for DatabaseOptionList {
+impl AstNode for Drop {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_OPTION_LIST
+        kind == SyntaxKind::DROP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34919,10 +36892,10 @@ impl AstNode for DatabaseOptionList {
         &self.syntax
     }
 }
-impl AstNode for DatabaseOptionOwner {
+impl AstNode for DropAccessMethod {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_OPTION_OWNER
+        kind == SyntaxKind::DROP_ACCESS_METHOD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34937,10 +36910,10 @@ impl AstNode for DatabaseOptionOwner {
         &self.syntax
     }
 }
-impl AstNode for DatabaseOptionTablespace {
+impl AstNode for DropAggregate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_OPTION_TABLESPACE
+        kind == SyntaxKind::DROP_AGGREGATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34955,10 +36928,10 @@ impl AstNode for DatabaseOptionTablespace {
         &self.syntax
     }
 }
-impl AstNode for DatabaseOptionTemplate {
+impl AstNode for DropAttribute {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_OPTION_TEMPLATE
+        kind == SyntaxKind::DROP_ATTRIBUTE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34973,10 +36946,10 @@ impl AstNode for DatabaseOptionTemplate {
         &self.syntax
     }
 }
-impl AstNode for DatabaseRef {
+impl AstNode for DropCast {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_REF
+        kind == SyntaxKind::DROP_CAST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -34991,10 +36964,10 @@ impl AstNode for DatabaseRef {
         &self.syntax
     }
 }
-impl AstNode for DatabaseRenameTo {
+impl AstNode for DropCollation {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DATABASE_RENAME_TO
+        kind == SyntaxKind::DROP_COLLATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35009,10 +36982,10 @@ impl AstNode for DatabaseRenameTo {
         &self.syntax
     }
 }
-impl AstNode for Deallocate {
+impl AstNode for DropColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEALLOCATE
+        kind == SyntaxKind::DROP_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35027,10 +37000,10 @@ impl AstNode for Deallocate {
         &self.syntax
     }
 }
-impl AstNode for Declare {
+impl AstNode for DropConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DECLARE
+        kind == SyntaxKind::DROP_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35045,10 +37018,10 @@ impl AstNode for Declare {
         &self.syntax
     }
 }
-impl AstNode for DefaultConstraint {
+impl AstNode for DropConversion {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEFAULT_CONSTRAINT
+        kind == SyntaxKind::DROP_CONVERSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35063,10 +37036,10 @@ impl AstNode for DefaultConstraint {
         &self.syntax
     }
 }
-impl AstNode for DefaultLabel {
+impl AstNode for DropDatabase {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEFAULT_LABEL
+        kind == SyntaxKind::DROP_DATABASE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35081,10 +37054,10 @@ impl AstNode for DefaultLabel {
         &self.syntax
     }
 }
-impl AstNode for DefaultValues {
+impl AstNode for DropDatabaseForceClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEFAULT_VALUES
+        kind == SyntaxKind::DROP_DATABASE_FORCE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35099,10 +37072,10 @@ impl AstNode for DefaultValues {
         &self.syntax
     }
 }
-impl AstNode for Deferrable {
+impl AstNode for DropDefault {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEFERRABLE
+        kind == SyntaxKind::DROP_DEFAULT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35117,10 +37090,10 @@ impl AstNode for Deferrable {
         &self.syntax
     }
 }
-impl AstNode for DeferrableConstraintOption {
+impl AstNode for DropDomain {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEFERRABLE_CONSTRAINT_OPTION
+        kind == SyntaxKind::DROP_DOMAIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35135,10 +37108,10 @@ impl AstNode for DeferrableConstraintOption {
         &self.syntax
     }
 }
-impl AstNode for Deferred {
+impl AstNode for DropEdgeTables {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEFERRED
+        kind == SyntaxKind::DROP_EDGE_TABLES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35153,10 +37126,10 @@ impl AstNode for Deferred {
         &self.syntax
     }
 }
-impl AstNode for Delete {
+impl AstNode for DropEventTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DELETE
+        kind == SyntaxKind::DROP_EVENT_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35171,10 +37144,10 @@ impl AstNode for Delete {
         &self.syntax
     }
 }
-impl AstNode for DeleteRows {
+impl AstNode for DropExpression {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DELETE_ROWS
+        kind == SyntaxKind::DROP_EXPRESSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35189,10 +37162,10 @@ impl AstNode for DeleteRows {
         &self.syntax
     }
 }
-impl AstNode for DependsOnExtension {
+impl AstNode for DropExtension {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEPENDS_ON_EXTENSION
+        kind == SyntaxKind::DROP_EXTENSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35207,10 +37180,10 @@ impl AstNode for DependsOnExtension {
         &self.syntax
     }
 }
-impl AstNode for DepthFirst {
+impl AstNode for DropForeignDataWrapper {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEPTH_FIRST
+        kind == SyntaxKind::DROP_FOREIGN_DATA_WRAPPER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35225,10 +37198,10 @@ impl AstNode for DepthFirst {
         &self.syntax
     }
 }
-impl AstNode for DestVertexTable {
+impl AstNode for DropForeignOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DEST_VERTEX_TABLE
+        kind == SyntaxKind::DROP_FOREIGN_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35243,10 +37216,10 @@ impl AstNode for DestVertexTable {
         &self.syntax
     }
 }
-impl AstNode for DetachConcurrently {
+impl AstNode for DropForeignTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DETACH_CONCURRENTLY
+        kind == SyntaxKind::DROP_FOREIGN_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35261,10 +37234,10 @@ impl AstNode for DetachConcurrently {
         &self.syntax
     }
 }
-impl AstNode for DetachFinalize {
+impl AstNode for DropFunction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DETACH_FINALIZE
+        kind == SyntaxKind::DROP_FUNCTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35279,10 +37252,10 @@ impl AstNode for DetachFinalize {
         &self.syntax
     }
 }
-impl AstNode for DetachPartition {
+impl AstNode for DropGroup {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DETACH_PARTITION
+        kind == SyntaxKind::DROP_GROUP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35297,10 +37270,10 @@ impl AstNode for DetachPartition {
         &self.syntax
     }
 }
-impl AstNode for Disable {
+impl AstNode for DropIdentity {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISABLE
+        kind == SyntaxKind::DROP_IDENTITY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35315,10 +37288,10 @@ impl AstNode for Disable {
         &self.syntax
     }
 }
-impl AstNode for DisableRls {
+impl AstNode for DropIndex {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISABLE_RLS
+        kind == SyntaxKind::DROP_INDEX
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35333,10 +37306,10 @@ impl AstNode for DisableRls {
         &self.syntax
     }
 }
-impl AstNode for DisableRule {
+impl AstNode for DropLanguage {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISABLE_RULE
+        kind == SyntaxKind::DROP_LANGUAGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35351,10 +37324,10 @@ impl AstNode for DisableRule {
         &self.syntax
     }
 }
-impl AstNode for DisableSubscription {
+impl AstNode for DropMapping {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISABLE_SUBSCRIPTION
+        kind == SyntaxKind::DROP_MAPPING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35369,10 +37342,10 @@ impl AstNode for DisableSubscription {
         &self.syntax
     }
 }
-impl AstNode for DisableTrigger {
+impl AstNode for DropMaterializedView {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISABLE_TRIGGER
+        kind == SyntaxKind::DROP_MATERIALIZED_VIEW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35387,10 +37360,10 @@ impl AstNode for DisableTrigger {
         &self.syntax
     }
 }
-impl AstNode for Discard {
+impl AstNode for DropNotNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISCARD
+        kind == SyntaxKind::DROP_NOT_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35405,10 +37378,10 @@ impl AstNode for Discard {
         &self.syntax
     }
 }
-impl AstNode for DiscardAll {
+impl AstNode for DropOpClassOptionFunction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISCARD_ALL
+        kind == SyntaxKind::DROP_OP_CLASS_OPTION_FUNCTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35423,10 +37396,10 @@ impl AstNode for DiscardAll {
         &self.syntax
     }
 }
-impl AstNode for DiscardPlans {
+impl AstNode for DropOpClassOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISCARD_PLANS
+        kind == SyntaxKind::DROP_OP_CLASS_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35441,10 +37414,10 @@ impl AstNode for DiscardPlans {
         &self.syntax
     }
 }
-impl AstNode for DiscardSequences {
+impl AstNode for DropOpClassOptionOperator {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISCARD_SEQUENCES
+        kind == SyntaxKind::DROP_OP_CLASS_OPTION_OPERATOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35459,10 +37432,10 @@ impl AstNode for DiscardSequences {
         &self.syntax
     }
 }
-impl AstNode for DiscardTemp {
+impl AstNode for DropOpClassOptions {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISCARD_TEMP
+        kind == SyntaxKind::DROP_OP_CLASS_OPTIONS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35477,10 +37450,10 @@ impl AstNode for DiscardTemp {
         &self.syntax
     }
 }
-impl AstNode for Distinct {
+impl AstNode for DropOperator {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISTINCT
+        kind == SyntaxKind::DROP_OPERATOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35495,10 +37468,10 @@ impl AstNode for Distinct {
         &self.syntax
     }
 }
-impl AstNode for DistinctClause {
+impl AstNode for DropOperatorClass {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISTINCT_CLAUSE
+        kind == SyntaxKind::DROP_OPERATOR_CLASS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35513,10 +37486,10 @@ impl AstNode for DistinctClause {
         &self.syntax
     }
 }
-impl AstNode for DistinctOn {
+impl AstNode for DropOperatorFamily {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DISTINCT_ON
+        kind == SyntaxKind::DROP_OPERATOR_FAMILY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35531,10 +37504,10 @@ impl AstNode for DistinctOn {
         &self.syntax
     }
 }
-impl AstNode for Do {
+impl AstNode for DropOwned {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DO
+        kind == SyntaxKind::DROP_OWNED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35549,10 +37522,10 @@ impl AstNode for Do {
         &self.syntax
     }
 }
-impl AstNode for DoLanguage {
+impl AstNode for DropPolicy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DO_LANGUAGE
+        kind == SyntaxKind::DROP_POLICY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35567,10 +37540,10 @@ impl AstNode for DoLanguage {
         &self.syntax
     }
 }
-impl AstNode for Domain {
+impl AstNode for DropProcedure {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DOMAIN
+        kind == SyntaxKind::DROP_PROCEDURE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35585,10 +37558,10 @@ impl AstNode for Domain {
         &self.syntax
     }
 }
-impl AstNode for DomainRef {
+impl AstNode for DropPropertyGraph {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DOMAIN_REF
+        kind == SyntaxKind::DROP_PROPERTY_GRAPH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35603,10 +37576,10 @@ impl AstNode for DomainRef {
         &self.syntax
     }
 }
-impl AstNode for DomainRenameTo {
+impl AstNode for DropPublication {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DOMAIN_RENAME_TO
+        kind == SyntaxKind::DROP_PUBLICATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35621,10 +37594,10 @@ impl AstNode for DomainRenameTo {
         &self.syntax
     }
 }
-impl AstNode for DoubleType {
+impl AstNode for DropPublicationObjects {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DOUBLE_TYPE
+        kind == SyntaxKind::DROP_PUBLICATION_OBJECTS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35639,10 +37612,10 @@ impl AstNode for DoubleType {
         &self.syntax
     }
 }
-impl AstNode for Drop {
+impl AstNode for DropRole {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP
+        kind == SyntaxKind::DROP_ROLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35657,10 +37630,10 @@ impl AstNode for Drop {
         &self.syntax
     }
 }
-impl AstNode for DropAccessMethod {
+impl AstNode for DropRoutine {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_ACCESS_METHOD
+        kind == SyntaxKind::DROP_ROUTINE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35675,10 +37648,10 @@ impl AstNode for DropAccessMethod {
         &self.syntax
     }
 }
-impl AstNode for DropAggregate {
+impl AstNode for DropRule {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_AGGREGATE
+        kind == SyntaxKind::DROP_RULE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35693,10 +37666,10 @@ impl AstNode for DropAggregate {
         &self.syntax
     }
 }
-impl AstNode for DropAttribute {
+impl AstNode for DropSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_ATTRIBUTE
+        kind == SyntaxKind::DROP_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35711,10 +37684,10 @@ impl AstNode for DropAttribute {
         &self.syntax
     }
 }
-impl AstNode for DropCast {
+impl AstNode for DropSequence {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_CAST
+        kind == SyntaxKind::DROP_SEQUENCE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35729,10 +37702,10 @@ impl AstNode for DropCast {
         &self.syntax
     }
 }
-impl AstNode for DropCollation {
+impl AstNode for DropServer {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_COLLATION
+        kind == SyntaxKind::DROP_SERVER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35747,10 +37720,10 @@ impl AstNode for DropCollation {
         &self.syntax
     }
 }
-impl AstNode for DropColumn {
+impl AstNode for DropStatistics {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_COLUMN
+        kind == SyntaxKind::DROP_STATISTICS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35765,10 +37738,10 @@ impl AstNode for DropColumn {
         &self.syntax
     }
 }
-impl AstNode for DropConstraint {
+impl AstNode for DropSubscription {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_CONSTRAINT
+        kind == SyntaxKind::DROP_SUBSCRIPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35783,10 +37756,10 @@ impl AstNode for DropConstraint {
         &self.syntax
     }
 }
-impl AstNode for DropConversion {
+impl AstNode for DropSubscriptionPublication {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_CONVERSION
+        kind == SyntaxKind::DROP_SUBSCRIPTION_PUBLICATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35801,10 +37774,10 @@ impl AstNode for DropConversion {
         &self.syntax
     }
 }
-impl AstNode for DropDatabase {
+impl AstNode for DropTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_DATABASE
+        kind == SyntaxKind::DROP_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35819,10 +37792,10 @@ impl AstNode for DropDatabase {
         &self.syntax
     }
 }
-impl AstNode for DropDatabaseForceClause {
+impl AstNode for DropTablespace {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_DATABASE_FORCE_CLAUSE
+        kind == SyntaxKind::DROP_TABLESPACE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35837,10 +37810,10 @@ impl AstNode for DropDatabaseForceClause {
         &self.syntax
     }
 }
-impl AstNode for DropDefault {
+impl AstNode for DropTextSearchConfig {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_DEFAULT
+        kind == SyntaxKind::DROP_TEXT_SEARCH_CONFIG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35855,10 +37828,10 @@ impl AstNode for DropDefault {
         &self.syntax
     }
 }
-impl AstNode for DropDomain {
+impl AstNode for DropTextSearchDict {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_DOMAIN
+        kind == SyntaxKind::DROP_TEXT_SEARCH_DICT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35873,10 +37846,10 @@ impl AstNode for DropDomain {
         &self.syntax
     }
 }
-impl AstNode for DropEdgeTables {
+impl AstNode for DropTextSearchParser {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_EDGE_TABLES
+        kind == SyntaxKind::DROP_TEXT_SEARCH_PARSER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35891,10 +37864,10 @@ impl AstNode for DropEdgeTables {
         &self.syntax
     }
 }
-impl AstNode for DropEventTrigger {
+impl AstNode for DropTextSearchTemplate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_EVENT_TRIGGER
+        kind == SyntaxKind::DROP_TEXT_SEARCH_TEMPLATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35909,10 +37882,10 @@ impl AstNode for DropEventTrigger {
         &self.syntax
     }
 }
-impl AstNode for DropExpression {
+impl AstNode for DropTransform {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_EXPRESSION
+        kind == SyntaxKind::DROP_TRANSFORM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35927,10 +37900,10 @@ impl AstNode for DropExpression {
         &self.syntax
     }
 }
-impl AstNode for DropExtension {
+impl AstNode for DropTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_EXTENSION
+        kind == SyntaxKind::DROP_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35945,10 +37918,10 @@ impl AstNode for DropExtension {
         &self.syntax
     }
 }
-impl AstNode for DropForeignDataWrapper {
+impl AstNode for DropType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_FOREIGN_DATA_WRAPPER
+        kind == SyntaxKind::DROP_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35963,10 +37936,10 @@ impl AstNode for DropForeignDataWrapper {
         &self.syntax
     }
 }
-impl AstNode for DropForeignOption {
+impl AstNode for DropUser {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_FOREIGN_OPTION
+        kind == SyntaxKind::DROP_USER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35981,10 +37954,10 @@ impl AstNode for DropForeignOption {
         &self.syntax
     }
 }
-impl AstNode for DropForeignTable {
+impl AstNode for DropUserMapping {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_FOREIGN_TABLE
+        kind == SyntaxKind::DROP_USER_MAPPING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -35999,10 +37972,10 @@ impl AstNode for DropForeignTable {
         &self.syntax
     }
 }
-impl AstNode for DropFunction {
+impl AstNode for DropUsers {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_FUNCTION
+        kind == SyntaxKind::DROP_USERS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36017,10 +37990,10 @@ impl AstNode for DropFunction {
         &self.syntax
     }
 }
-impl AstNode for DropGroup {
+impl AstNode for DropVertexEdgeLabel {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_GROUP
+        kind == SyntaxKind::DROP_VERTEX_EDGE_LABEL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36035,10 +38008,10 @@ impl AstNode for DropGroup {
         &self.syntax
     }
 }
-impl AstNode for DropIdentity {
+impl AstNode for DropVertexEdgeLabelProperties {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_IDENTITY
+        kind == SyntaxKind::DROP_VERTEX_EDGE_LABEL_PROPERTIES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36053,10 +38026,10 @@ impl AstNode for DropIdentity {
         &self.syntax
     }
 }
-impl AstNode for DropIndex {
+impl AstNode for DropVertexTables {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_INDEX
+        kind == SyntaxKind::DROP_VERTEX_TABLES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36071,10 +38044,10 @@ impl AstNode for DropIndex {
         &self.syntax
     }
 }
-impl AstNode for DropLanguage {
+impl AstNode for DropView {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_LANGUAGE
+        kind == SyntaxKind::DROP_VIEW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36089,10 +38062,10 @@ impl AstNode for DropLanguage {
         &self.syntax
     }
 }
-impl AstNode for DropMapping {
+impl AstNode for EdgeAny {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_MAPPING
+        kind == SyntaxKind::EDGE_ANY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36107,10 +38080,10 @@ impl AstNode for DropMapping {
         &self.syntax
     }
 }
-impl AstNode for DropMaterializedView {
+impl AstNode for EdgeLeft {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_MATERIALIZED_VIEW
+        kind == SyntaxKind::EDGE_LEFT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36125,10 +38098,10 @@ impl AstNode for DropMaterializedView {
         &self.syntax
     }
 }
-impl AstNode for DropNotNull {
+impl AstNode for EdgeRight {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_NOT_NULL
+        kind == SyntaxKind::EDGE_RIGHT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36143,10 +38116,10 @@ impl AstNode for DropNotNull {
         &self.syntax
     }
 }
-impl AstNode for DropOpClassOptionFunction {
+impl AstNode for EdgeTableDef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_OP_CLASS_OPTION_FUNCTION
+        kind == SyntaxKind::EDGE_TABLE_DEF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36161,10 +38134,10 @@ impl AstNode for DropOpClassOptionFunction {
         &self.syntax
     }
 }
-impl AstNode for DropOpClassOptionList {
+impl AstNode for EdgeTables {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_OP_CLASS_OPTION_LIST
+        kind == SyntaxKind::EDGE_TABLES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36179,10 +38152,10 @@ impl AstNode for DropOpClassOptionList {
         &self.syntax
     }
 }
-impl AstNode for DropOpClassOptionOperator {
+impl AstNode for ElementTableAlias {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_OP_CLASS_OPTION_OPERATOR
+        kind == SyntaxKind::ELEMENT_TABLE_ALIAS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36197,10 +38170,10 @@ impl AstNode for DropOpClassOptionOperator {
         &self.syntax
     }
 }
-impl AstNode for DropOpClassOptions {
+impl AstNode for ElementTableAliasClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_OP_CLASS_OPTIONS
+        kind == SyntaxKind::ELEMENT_TABLE_ALIAS_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36215,10 +38188,10 @@ impl AstNode for DropOpClassOptions {
         &self.syntax
     }
 }
-impl AstNode for DropOperator {
+impl AstNode for ElementTableKeyClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_OPERATOR
+        kind == SyntaxKind::ELEMENT_TABLE_KEY_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36233,10 +38206,10 @@ impl AstNode for DropOperator {
         &self.syntax
     }
 }
-impl AstNode for DropOperatorClass {
+impl AstNode for ElementTableRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_OPERATOR_CLASS
+        kind == SyntaxKind::ELEMENT_TABLE_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36251,10 +38224,10 @@ impl AstNode for DropOperatorClass {
         &self.syntax
     }
 }
-impl AstNode for DropOperatorFamily {
+impl AstNode for ElementTag {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_OPERATOR_FAMILY
+        kind == SyntaxKind::ELEMENT_TAG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36269,10 +38242,10 @@ impl AstNode for DropOperatorFamily {
         &self.syntax
     }
 }
-impl AstNode for DropOwned {
+impl AstNode for ElementVariable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_OWNED
+        kind == SyntaxKind::ELEMENT_VARIABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36287,10 +38260,10 @@ impl AstNode for DropOwned {
         &self.syntax
     }
 }
-impl AstNode for DropPolicy {
+impl AstNode for ElseClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_POLICY
+        kind == SyntaxKind::ELSE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36305,10 +38278,10 @@ impl AstNode for DropPolicy {
         &self.syntax
     }
 }
-impl AstNode for DropProcedure {
+impl AstNode for EmptyStmt {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_PROCEDURE
+        kind == SyntaxKind::EMPTY_STMT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36323,10 +38296,10 @@ impl AstNode for DropProcedure {
         &self.syntax
     }
 }
-impl AstNode for DropPropertyGraph {
+impl AstNode for Enable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_PROPERTY_GRAPH
+        kind == SyntaxKind::ENABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36341,10 +38314,10 @@ impl AstNode for DropPropertyGraph {
         &self.syntax
     }
 }
-impl AstNode for DropPublication {
+impl AstNode for EnableAlways {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_PUBLICATION
+        kind == SyntaxKind::ENABLE_ALWAYS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36359,10 +38332,10 @@ impl AstNode for DropPublication {
         &self.syntax
     }
 }
-impl AstNode for DropPublicationObjects {
+impl AstNode for EnableAlwaysRule {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_PUBLICATION_OBJECTS
+        kind == SyntaxKind::ENABLE_ALWAYS_RULE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36377,10 +38350,10 @@ impl AstNode for DropPublicationObjects {
         &self.syntax
     }
 }
-impl AstNode for DropRole {
+impl AstNode for EnableAlwaysTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_ROLE
+        kind == SyntaxKind::ENABLE_ALWAYS_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36395,10 +38368,10 @@ impl AstNode for DropRole {
         &self.syntax
     }
 }
-impl AstNode for DropRoutine {
+impl AstNode for EnableReplica {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_ROUTINE
+        kind == SyntaxKind::ENABLE_REPLICA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36413,10 +38386,10 @@ impl AstNode for DropRoutine {
         &self.syntax
     }
 }
-impl AstNode for DropRule {
+impl AstNode for EnableReplicaRule {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_RULE
+        kind == SyntaxKind::ENABLE_REPLICA_RULE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36431,10 +38404,10 @@ impl AstNode for DropRule {
         &self.syntax
     }
 }
-impl AstNode for DropSchema {
+impl AstNode for EnableReplicaTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_SCHEMA
+        kind == SyntaxKind::ENABLE_REPLICA_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36449,10 +38422,10 @@ impl AstNode for DropSchema {
         &self.syntax
     }
 }
-impl AstNode for DropSequence {
+impl AstNode for EnableRls {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_SEQUENCE
+        kind == SyntaxKind::ENABLE_RLS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36467,10 +38440,10 @@ impl AstNode for DropSequence {
         &self.syntax
     }
 }
-impl AstNode for DropServer {
+impl AstNode for EnableRule {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_SERVER
+        kind == SyntaxKind::ENABLE_RULE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36485,10 +38458,10 @@ impl AstNode for DropServer {
         &self.syntax
     }
 }
-impl AstNode for DropStatistics {
+impl AstNode for EnableSubscription {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_STATISTICS
+        kind == SyntaxKind::ENABLE_SUBSCRIPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36503,10 +38476,10 @@ impl AstNode for DropStatistics {
         &self.syntax
     }
 }
-impl AstNode for DropSubscription {
+impl AstNode for EnableTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_SUBSCRIPTION
+        kind == SyntaxKind::ENABLE_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36521,10 +38494,10 @@ impl AstNode for DropSubscription {
         &self.syntax
     }
 }
-impl AstNode for DropSubscriptionPublication {
+impl AstNode for Enforced {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_SUBSCRIPTION_PUBLICATION
+        kind == SyntaxKind::ENFORCED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36539,10 +38512,10 @@ impl AstNode for DropSubscriptionPublication {
         &self.syntax
     }
 }
-impl AstNode for DropTable {
+impl AstNode for EnumType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TABLE
+        kind == SyntaxKind::ENUM_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36557,10 +38530,10 @@ impl AstNode for DropTable {
         &self.syntax
     }
 }
-impl AstNode for DropTablespace {
+impl AstNode for EventTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TABLESPACE
+        kind == SyntaxKind::EVENT_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36575,10 +38548,10 @@ impl AstNode for DropTablespace {
         &self.syntax
     }
 }
-impl AstNode for DropTextSearchConfig {
+impl AstNode for EventTriggerEvent {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TEXT_SEARCH_CONFIG
+        kind == SyntaxKind::EVENT_TRIGGER_EVENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36593,10 +38566,10 @@ impl AstNode for DropTextSearchConfig {
         &self.syntax
     }
 }
-impl AstNode for DropTextSearchDict {
+impl AstNode for EventTriggerFilterVariable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TEXT_SEARCH_DICT
+        kind == SyntaxKind::EVENT_TRIGGER_FILTER_VARIABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36611,10 +38584,10 @@ impl AstNode for DropTextSearchDict {
         &self.syntax
     }
 }
-impl AstNode for DropTextSearchParser {
+impl AstNode for EventTriggerRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TEXT_SEARCH_PARSER
+        kind == SyntaxKind::EVENT_TRIGGER_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36629,10 +38602,10 @@ impl AstNode for DropTextSearchParser {
         &self.syntax
     }
 }
-impl AstNode for DropTextSearchTemplate {
+impl AstNode for EventTriggerRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TEXT_SEARCH_TEMPLATE
+        kind == SyntaxKind::EVENT_TRIGGER_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36647,10 +38620,10 @@ impl AstNode for DropTextSearchTemplate {
         &self.syntax
     }
 }
-impl AstNode for DropTransform {
+impl AstNode for EventTriggerWhen {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TRANSFORM
+        kind == SyntaxKind::EVENT_TRIGGER_WHEN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36665,10 +38638,10 @@ impl AstNode for DropTransform {
         &self.syntax
     }
 }
-impl AstNode for DropTrigger {
+impl AstNode for EventTriggerWhenClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TRIGGER
+        kind == SyntaxKind::EVENT_TRIGGER_WHEN_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36683,10 +38656,10 @@ impl AstNode for DropTrigger {
         &self.syntax
     }
 }
-impl AstNode for DropType {
+impl AstNode for Except {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_TYPE
+        kind == SyntaxKind::EXCEPT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36701,10 +38674,10 @@ impl AstNode for DropType {
         &self.syntax
     }
 }
-impl AstNode for DropUser {
+impl AstNode for ExceptTableClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_USER
+        kind == SyntaxKind::EXCEPT_TABLE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36719,10 +38692,10 @@ impl AstNode for DropUser {
         &self.syntax
     }
 }
-impl AstNode for DropUserMapping {
+impl AstNode for ExceptTableName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_USER_MAPPING
+        kind == SyntaxKind::EXCEPT_TABLE_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36737,10 +38710,10 @@ impl AstNode for DropUserMapping {
         &self.syntax
     }
 }
-impl AstNode for DropUsers {
+impl AstNode for ExceptTables {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_USERS
+        kind == SyntaxKind::EXCEPT_TABLES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36755,10 +38728,10 @@ impl AstNode for DropUsers {
         &self.syntax
     }
 }
-impl AstNode for DropVertexEdgeLabel {
+impl AstNode for ExcludeConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_VERTEX_EDGE_LABEL
+        kind == SyntaxKind::EXCLUDE_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36773,10 +38746,10 @@ impl AstNode for DropVertexEdgeLabel {
         &self.syntax
     }
 }
-impl AstNode for DropVertexEdgeLabelProperties {
+impl AstNode for ExcludingProperty {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_VERTEX_EDGE_LABEL_PROPERTIES
+        kind == SyntaxKind::EXCLUDING_PROPERTY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36791,10 +38764,10 @@ impl AstNode for DropVertexEdgeLabelProperties {
         &self.syntax
     }
 }
-impl AstNode for DropVertexTables {
+impl AstNode for Exclusive {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_VERTEX_TABLES
+        kind == SyntaxKind::EXCLUSIVE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36809,10 +38782,10 @@ impl AstNode for DropVertexTables {
         &self.syntax
     }
 }
-impl AstNode for DropView {
+impl AstNode for Execute {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::DROP_VIEW
+        kind == SyntaxKind::EXECUTE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36827,10 +38800,10 @@ impl AstNode for DropView {
         &self.syntax
     }
 }
-impl AstNode for EdgeAny {
+impl AstNode for ExistsFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EDGE_ANY
+        kind == SyntaxKind::EXISTS_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36845,10 +38818,10 @@ impl AstNode for EdgeAny {
         &self.syntax
     }
 }
-impl AstNode for EdgeLeft {
+impl AstNode for Explain {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EDGE_LEFT
+        kind == SyntaxKind::EXPLAIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36863,10 +38836,10 @@ impl AstNode for EdgeLeft {
         &self.syntax
     }
 }
-impl AstNode for EdgeRight {
+impl AstNode for ExplainAnalyze {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EDGE_RIGHT
+        kind == SyntaxKind::EXPLAIN_ANALYZE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36881,10 +38854,10 @@ impl AstNode for EdgeRight {
         &self.syntax
     }
 }
-impl AstNode for EdgeTableDef {
+impl AstNode for ExplainOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EDGE_TABLE_DEF
+        kind == SyntaxKind::EXPLAIN_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36899,10 +38872,10 @@ impl AstNode for EdgeTableDef {
         &self.syntax
     }
 }
-impl AstNode for EdgeTables {
+impl AstNode for ExplainOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EDGE_TABLES
+        kind == SyntaxKind::EXPLAIN_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36917,10 +38890,10 @@ impl AstNode for EdgeTables {
         &self.syntax
     }
 }
-impl AstNode for ElementTableAlias {
+impl AstNode for ExplainOptionName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ELEMENT_TABLE_ALIAS
+        kind == SyntaxKind::EXPLAIN_OPTION_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36935,10 +38908,10 @@ impl AstNode for ElementTableAlias {
         &self.syntax
     }
 }
-impl AstNode for ElementTableAliasClause {
+impl AstNode for ExplainOptionValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ELEMENT_TABLE_ALIAS_CLAUSE
+        kind == SyntaxKind::EXPLAIN_OPTION_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36953,10 +38926,10 @@ impl AstNode for ElementTableAliasClause {
         &self.syntax
     }
 }
-impl AstNode for ElementTableKeyClause {
+impl AstNode for ExplainVerbose {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ELEMENT_TABLE_KEY_CLAUSE
+        kind == SyntaxKind::EXPLAIN_VERBOSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36971,10 +38944,10 @@ impl AstNode for ElementTableKeyClause {
         &self.syntax
     }
 }
-impl AstNode for ElementTableRef {
+impl AstNode for ExprAsColumnName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ELEMENT_TABLE_REF
+        kind == SyntaxKind::EXPR_AS_COLUMN_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -36989,10 +38962,10 @@ impl AstNode for ElementTableRef {
         &self.syntax
     }
 }
-impl AstNode for ElementTag {
+impl AstNode for ExprAsColumnNameList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ELEMENT_TAG
+        kind == SyntaxKind::EXPR_AS_COLUMN_NAME_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37007,10 +38980,10 @@ impl AstNode for ElementTag {
         &self.syntax
     }
 }
-impl AstNode for ElementVariable {
+impl AstNode for ExprAsElementTag {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ELEMENT_VARIABLE
+        kind == SyntaxKind::EXPR_AS_ELEMENT_TAG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37025,10 +38998,10 @@ impl AstNode for ElementVariable {
         &self.syntax
     }
 }
-impl AstNode for ElseClause {
+impl AstNode for ExprAsElementTagList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ELSE_CLAUSE
+        kind == SyntaxKind::EXPR_AS_ELEMENT_TAG_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37043,10 +39016,10 @@ impl AstNode for ElseClause {
         &self.syntax
     }
 }
-impl AstNode for EmptyStmt {
+impl AstNode for ExprAsPropertyName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EMPTY_STMT
+        kind == SyntaxKind::EXPR_AS_PROPERTY_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37061,10 +39034,10 @@ impl AstNode for EmptyStmt {
         &self.syntax
     }
 }
-impl AstNode for Enable {
+impl AstNode for ExprAsPropertyNameList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE
+        kind == SyntaxKind::EXPR_AS_PROPERTY_NAME_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37079,10 +39052,10 @@ impl AstNode for Enable {
         &self.syntax
     }
 }
-impl AstNode for EnableAlways {
+impl AstNode for ExprAsXmlAttr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_ALWAYS
+        kind == SyntaxKind::EXPR_AS_XML_ATTR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37097,10 +39070,10 @@ impl AstNode for EnableAlways {
         &self.syntax
     }
 }
-impl AstNode for EnableAlwaysRule {
+impl AstNode for ExprAsXmlAttrList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_ALWAYS_RULE
+        kind == SyntaxKind::EXPR_AS_XML_ATTR_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37115,10 +39088,10 @@ impl AstNode for EnableAlwaysRule {
         &self.syntax
     }
 }
-impl AstNode for EnableAlwaysTrigger {
+impl AstNode for ExprFollowing {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_ALWAYS_TRIGGER
+        kind == SyntaxKind::EXPR_FOLLOWING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37133,10 +39106,10 @@ impl AstNode for EnableAlwaysTrigger {
         &self.syntax
     }
 }
-impl AstNode for EnableReplica {
+impl AstNode for ExprFromItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_REPLICA
+        kind == SyntaxKind::EXPR_FROM_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37151,10 +39124,10 @@ impl AstNode for EnableReplica {
         &self.syntax
     }
 }
-impl AstNode for EnableReplicaRule {
+impl AstNode for ExprPreceding {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_REPLICA_RULE
+        kind == SyntaxKind::EXPR_PRECEDING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37169,10 +39142,10 @@ impl AstNode for EnableReplicaRule {
         &self.syntax
     }
 }
-impl AstNode for EnableReplicaTrigger {
+impl AstNode for ExprType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_REPLICA_TRIGGER
+        kind == SyntaxKind::EXPR_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37187,10 +39160,10 @@ impl AstNode for EnableReplicaTrigger {
         &self.syntax
     }
 }
-impl AstNode for EnableRls {
+impl AstNode for Extension {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_RLS
+        kind == SyntaxKind::EXTENSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37205,10 +39178,10 @@ impl AstNode for EnableRls {
         &self.syntax
     }
 }
-impl AstNode for EnableRule {
+impl AstNode for ExtensionRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_RULE
+        kind == SyntaxKind::EXTENSION_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37223,10 +39196,10 @@ impl AstNode for EnableRule {
         &self.syntax
     }
 }
-impl AstNode for EnableSubscription {
+impl AstNode for ExtensionSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_SUBSCRIPTION
+        kind == SyntaxKind::EXTENSION_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37241,10 +39214,10 @@ impl AstNode for EnableSubscription {
         &self.syntax
     }
 }
-impl AstNode for EnableTrigger {
+impl AstNode for ExtensionVersion {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENABLE_TRIGGER
+        kind == SyntaxKind::EXTENSION_VERSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37259,10 +39232,10 @@ impl AstNode for EnableTrigger {
         &self.syntax
     }
 }
-impl AstNode for Enforced {
+impl AstNode for ExtensionVersionClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENFORCED
+        kind == SyntaxKind::EXTENSION_VERSION_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37277,10 +39250,10 @@ impl AstNode for Enforced {
         &self.syntax
     }
 }
-impl AstNode for EnumType {
+impl AstNode for ExtractFieldLiteral {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ENUM_TYPE
+        kind == SyntaxKind::EXTRACT_FIELD_LITERAL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37295,10 +39268,10 @@ impl AstNode for EnumType {
         &self.syntax
     }
 }
-impl AstNode for EventTrigger {
+impl AstNode for ExtractFieldName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EVENT_TRIGGER
+        kind == SyntaxKind::EXTRACT_FIELD_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37313,10 +39286,10 @@ impl AstNode for EventTrigger {
         &self.syntax
     }
 }
-impl AstNode for EventTriggerEvent {
+impl AstNode for ExtractFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EVENT_TRIGGER_EVENT
+        kind == SyntaxKind::EXTRACT_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37331,10 +39304,10 @@ impl AstNode for EventTriggerEvent {
         &self.syntax
     }
 }
-impl AstNode for EventTriggerFilterVariable {
+impl AstNode for FatArrow {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EVENT_TRIGGER_FILTER_VARIABLE
+        kind == SyntaxKind::FAT_ARROW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37349,10 +39322,10 @@ impl AstNode for EventTriggerFilterVariable {
         &self.syntax
     }
 }
-impl AstNode for EventTriggerRef {
+impl AstNode for FdwOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EVENT_TRIGGER_REF
+        kind == SyntaxKind::FDW_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37367,10 +39340,10 @@ impl AstNode for EventTriggerRef {
         &self.syntax
     }
 }
-impl AstNode for EventTriggerRenameTo {
+impl AstNode for Fetch {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EVENT_TRIGGER_RENAME_TO
+        kind == SyntaxKind::FETCH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37385,10 +39358,10 @@ impl AstNode for EventTriggerRenameTo {
         &self.syntax
     }
 }
-impl AstNode for EventTriggerWhen {
+impl AstNode for FetchClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EVENT_TRIGGER_WHEN
+        kind == SyntaxKind::FETCH_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37403,10 +39376,10 @@ impl AstNode for EventTriggerWhen {
         &self.syntax
     }
 }
-impl AstNode for EventTriggerWhenClause {
+impl AstNode for FetchOnly {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EVENT_TRIGGER_WHEN_CLAUSE
+        kind == SyntaxKind::FETCH_ONLY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37421,10 +39394,10 @@ impl AstNode for EventTriggerWhenClause {
         &self.syntax
     }
 }
-impl AstNode for Except {
+impl AstNode for FieldAccessor {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXCEPT
+        kind == SyntaxKind::FIELD_ACCESSOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37439,10 +39412,10 @@ impl AstNode for Except {
         &self.syntax
     }
 }
-impl AstNode for ExceptTableClause {
+impl AstNode for FieldExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXCEPT_TABLE_CLAUSE
+        kind == SyntaxKind::FIELD_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37457,10 +39430,10 @@ impl AstNode for ExceptTableClause {
         &self.syntax
     }
 }
-impl AstNode for ExceptTableName {
+impl AstNode for FilterClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXCEPT_TABLE_NAME
+        kind == SyntaxKind::FILTER_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37475,10 +39448,10 @@ impl AstNode for ExceptTableName {
         &self.syntax
     }
 }
-impl AstNode for ExceptTables {
+impl AstNode for First {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXCEPT_TABLES
+        kind == SyntaxKind::FIRST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37493,10 +39466,10 @@ impl AstNode for ExceptTables {
         &self.syntax
     }
 }
-impl AstNode for ExcludeConstraint {
+impl AstNode for ForAllPublicationObjects {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXCLUDE_CONSTRAINT
+        kind == SyntaxKind::FOR_ALL_PUBLICATION_OBJECTS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37511,10 +39484,10 @@ impl AstNode for ExcludeConstraint {
         &self.syntax
     }
 }
-impl AstNode for ExcludingProperty {
+impl AstNode for ForEachRow {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXCLUDING_PROPERTY
+        kind == SyntaxKind::FOR_EACH_ROW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37529,10 +39502,10 @@ impl AstNode for ExcludingProperty {
         &self.syntax
     }
 }
-impl AstNode for Exclusive {
+impl AstNode for ForEachStatement {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXCLUSIVE
+        kind == SyntaxKind::FOR_EACH_STATEMENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37547,10 +39520,10 @@ impl AstNode for Exclusive {
         &self.syntax
     }
 }
-impl AstNode for Execute {
+impl AstNode for ForKeyShare {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXECUTE
+        kind == SyntaxKind::FOR_KEY_SHARE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37565,10 +39538,10 @@ impl AstNode for Execute {
         &self.syntax
     }
 }
-impl AstNode for ExistsFn {
+impl AstNode for ForNoKeyUpdate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXISTS_FN
+        kind == SyntaxKind::FOR_NO_KEY_UPDATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37583,10 +39556,10 @@ impl AstNode for ExistsFn {
         &self.syntax
     }
 }
-impl AstNode for Explain {
+impl AstNode for ForPortionOf {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPLAIN
+        kind == SyntaxKind::FOR_PORTION_OF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37601,10 +39574,10 @@ impl AstNode for Explain {
         &self.syntax
     }
 }
-impl AstNode for ExplainAnalyze {
+impl AstNode for ForProvider {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPLAIN_ANALYZE
+        kind == SyntaxKind::FOR_PROVIDER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37619,10 +39592,10 @@ impl AstNode for ExplainAnalyze {
         &self.syntax
     }
 }
-impl AstNode for ExplainOption {
+impl AstNode for ForPublicationObjects {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPLAIN_OPTION
+        kind == SyntaxKind::FOR_PUBLICATION_OBJECTS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37637,10 +39610,10 @@ impl AstNode for ExplainOption {
         &self.syntax
     }
 }
-impl AstNode for ExplainOptionList {
+impl AstNode for ForRoles {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPLAIN_OPTION_LIST
+        kind == SyntaxKind::FOR_ROLES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37655,10 +39628,10 @@ impl AstNode for ExplainOptionList {
         &self.syntax
     }
 }
-impl AstNode for ExplainOptionName {
+impl AstNode for ForShare {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPLAIN_OPTION_NAME
+        kind == SyntaxKind::FOR_SHARE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37673,10 +39646,10 @@ impl AstNode for ExplainOptionName {
         &self.syntax
     }
 }
-impl AstNode for ExplainOptionValue {
+impl AstNode for ForUpdate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPLAIN_OPTION_VALUE
+        kind == SyntaxKind::FOR_UPDATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37691,10 +39664,10 @@ impl AstNode for ExplainOptionValue {
         &self.syntax
     }
 }
-impl AstNode for ExplainVerbose {
+impl AstNode for Force {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPLAIN_VERBOSE
+        kind == SyntaxKind::FORCE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37709,10 +39682,10 @@ impl AstNode for ExplainVerbose {
         &self.syntax
     }
 }
-impl AstNode for ExprAsColumnName {
+impl AstNode for ForceNotNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_AS_COLUMN_NAME
+        kind == SyntaxKind::FORCE_NOT_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37727,10 +39700,10 @@ impl AstNode for ExprAsColumnName {
         &self.syntax
     }
 }
-impl AstNode for ExprAsColumnNameList {
+impl AstNode for ForceNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_AS_COLUMN_NAME_LIST
+        kind == SyntaxKind::FORCE_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37745,10 +39718,10 @@ impl AstNode for ExprAsColumnNameList {
         &self.syntax
     }
 }
-impl AstNode for ExprAsElementTag {
+impl AstNode for ForceQuote {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_AS_ELEMENT_TAG
+        kind == SyntaxKind::FORCE_QUOTE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37763,10 +39736,10 @@ impl AstNode for ExprAsElementTag {
         &self.syntax
     }
 }
-impl AstNode for ExprAsElementTagList {
+impl AstNode for ForceRls {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_AS_ELEMENT_TAG_LIST
+        kind == SyntaxKind::FORCE_RLS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37781,10 +39754,10 @@ impl AstNode for ExprAsElementTagList {
         &self.syntax
     }
 }
-impl AstNode for ExprAsPropertyName {
+impl AstNode for ForeignDataWrapper {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_AS_PROPERTY_NAME
+        kind == SyntaxKind::FOREIGN_DATA_WRAPPER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37799,10 +39772,10 @@ impl AstNode for ExprAsPropertyName {
         &self.syntax
     }
 }
-impl AstNode for ExprAsPropertyNameList {
+impl AstNode for ForeignDataWrapperRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_AS_PROPERTY_NAME_LIST
+        kind == SyntaxKind::FOREIGN_DATA_WRAPPER_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37817,10 +39790,10 @@ impl AstNode for ExprAsPropertyNameList {
         &self.syntax
     }
 }
-impl AstNode for ExprAsXmlAttr {
+impl AstNode for ForeignDataWrapperRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_AS_XML_ATTR
+        kind == SyntaxKind::FOREIGN_DATA_WRAPPER_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37835,10 +39808,10 @@ impl AstNode for ExprAsXmlAttr {
         &self.syntax
     }
 }
-impl AstNode for ExprAsXmlAttrList {
+impl AstNode for ForeignKeyColumnList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_AS_XML_ATTR_LIST
+        kind == SyntaxKind::FOREIGN_KEY_COLUMN_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37853,10 +39826,10 @@ impl AstNode for ExprAsXmlAttrList {
         &self.syntax
     }
 }
-impl AstNode for ExprFollowing {
+impl AstNode for ForeignKeyConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_FOLLOWING
+        kind == SyntaxKind::FOREIGN_KEY_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37871,10 +39844,10 @@ impl AstNode for ExprFollowing {
         &self.syntax
     }
 }
-impl AstNode for ExprFromItem {
+impl AstNode for ForeignOptionName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_FROM_ITEM
+        kind == SyntaxKind::FOREIGN_OPTION_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37889,10 +39862,10 @@ impl AstNode for ExprFromItem {
         &self.syntax
     }
 }
-impl AstNode for ExprPreceding {
+impl AstNode for Forward {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_PRECEDING
+        kind == SyntaxKind::FORWARD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37907,10 +39880,10 @@ impl AstNode for ExprPreceding {
         &self.syntax
     }
 }
-impl AstNode for ExprType {
+impl AstNode for FrameBetween {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXPR_TYPE
+        kind == SyntaxKind::FRAME_BETWEEN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37925,10 +39898,10 @@ impl AstNode for ExprType {
         &self.syntax
     }
 }
-impl AstNode for Extension {
+impl AstNode for FrameClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXTENSION
+        kind == SyntaxKind::FRAME_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37943,10 +39916,10 @@ impl AstNode for Extension {
         &self.syntax
     }
 }
-impl AstNode for ExtensionRef {
+impl AstNode for FrameExclude {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXTENSION_REF
+        kind == SyntaxKind::FRAME_EXCLUDE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37961,10 +39934,10 @@ impl AstNode for ExtensionRef {
         &self.syntax
     }
 }
-impl AstNode for ExtensionSchema {
+impl AstNode for FrameGroups {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXTENSION_SCHEMA
+        kind == SyntaxKind::FRAME_GROUPS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37979,10 +39952,10 @@ impl AstNode for ExtensionSchema {
         &self.syntax
     }
 }
-impl AstNode for ExtensionVersion {
+impl AstNode for FrameRange {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXTENSION_VERSION
+        kind == SyntaxKind::FRAME_RANGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -37997,10 +39970,10 @@ impl AstNode for ExtensionVersion {
         &self.syntax
     }
 }
-impl AstNode for ExtensionVersionClause {
+impl AstNode for FrameRows {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXTENSION_VERSION_CLAUSE
+        kind == SyntaxKind::FRAME_ROWS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38015,10 +39988,10 @@ impl AstNode for ExtensionVersionClause {
         &self.syntax
     }
 }
-impl AstNode for ExtractFieldLiteral {
+impl AstNode for FromAlias {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXTRACT_FIELD_LITERAL
+        kind == SyntaxKind::FROM_ALIAS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38033,10 +40006,10 @@ impl AstNode for ExtractFieldLiteral {
         &self.syntax
     }
 }
-impl AstNode for ExtractFieldName {
+impl AstNode for FromClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXTRACT_FIELD_NAME
+        kind == SyntaxKind::FROM_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38051,10 +40024,10 @@ impl AstNode for ExtractFieldName {
         &self.syntax
     }
 }
-impl AstNode for ExtractFn {
+impl AstNode for FromCurrent {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::EXTRACT_FN
+        kind == SyntaxKind::FROM_CURRENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38069,10 +40042,10 @@ impl AstNode for ExtractFn {
         &self.syntax
     }
 }
-impl AstNode for FatArrow {
+impl AstNode for FromTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FAT_ARROW
+        kind == SyntaxKind::FROM_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38087,10 +40060,10 @@ impl AstNode for FatArrow {
         &self.syntax
     }
 }
-impl AstNode for FdwOptionList {
+impl AstNode for FuncOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FDW_OPTION_LIST
+        kind == SyntaxKind::FUNC_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38105,10 +40078,10 @@ impl AstNode for FdwOptionList {
         &self.syntax
     }
 }
-impl AstNode for Fetch {
+impl AstNode for FunctionFromItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FETCH
+        kind == SyntaxKind::FUNCTION_FROM_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38123,10 +40096,10 @@ impl AstNode for Fetch {
         &self.syntax
     }
 }
-impl AstNode for FetchClause {
+impl AstNode for FunctionName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FETCH_CLAUSE
+        kind == SyntaxKind::FUNCTION_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38141,10 +40114,10 @@ impl AstNode for FetchClause {
         &self.syntax
     }
 }
-impl AstNode for FetchOnly {
+impl AstNode for FunctionNameRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FETCH_ONLY
+        kind == SyntaxKind::FUNCTION_NAME_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38159,10 +40132,10 @@ impl AstNode for FetchOnly {
         &self.syntax
     }
 }
-impl AstNode for FieldAccessor {
+impl AstNode for FunctionRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FIELD_ACCESSOR
+        kind == SyntaxKind::FUNCTION_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38177,10 +40150,10 @@ impl AstNode for FieldAccessor {
         &self.syntax
     }
 }
-impl AstNode for FieldExpr {
+impl AstNode for FunctionSig {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FIELD_EXPR
+        kind == SyntaxKind::FUNCTION_SIG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38195,10 +40168,10 @@ impl AstNode for FieldExpr {
         &self.syntax
     }
 }
-impl AstNode for FilterClause {
+impl AstNode for FunctionSigList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FILTER_CLAUSE
+        kind == SyntaxKind::FUNCTION_SIG_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38213,10 +40186,10 @@ impl AstNode for FilterClause {
         &self.syntax
     }
 }
-impl AstNode for First {
+impl AstNode for GeneratedAlways {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FIRST
+        kind == SyntaxKind::GENERATED_ALWAYS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38231,10 +40204,10 @@ impl AstNode for First {
         &self.syntax
     }
 }
-impl AstNode for ForAllPublicationObjects {
+impl AstNode for GeneratedByDefault {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_ALL_PUBLICATION_OBJECTS
+        kind == SyntaxKind::GENERATED_BY_DEFAULT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38249,10 +40222,10 @@ impl AstNode for ForAllPublicationObjects {
         &self.syntax
     }
 }
-impl AstNode for ForEachRow {
+impl AstNode for GeneratedConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_EACH_ROW
+        kind == SyntaxKind::GENERATED_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38267,10 +40240,10 @@ impl AstNode for ForEachRow {
         &self.syntax
     }
 }
-impl AstNode for ForEachStatement {
+impl AstNode for GeneratedIdentity {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_EACH_STATEMENT
+        kind == SyntaxKind::GENERATED_IDENTITY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38285,10 +40258,10 @@ impl AstNode for ForEachStatement {
         &self.syntax
     }
 }
-impl AstNode for ForKeyShare {
+impl AstNode for GeneratedStored {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_KEY_SHARE
+        kind == SyntaxKind::GENERATED_STORED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38303,10 +40276,10 @@ impl AstNode for ForKeyShare {
         &self.syntax
     }
 }
-impl AstNode for ForNoKeyUpdate {
+impl AstNode for Grant {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_NO_KEY_UPDATE
+        kind == SyntaxKind::GRANT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38321,10 +40294,10 @@ impl AstNode for ForNoKeyUpdate {
         &self.syntax
     }
 }
-impl AstNode for ForPortionOf {
+impl AstNode for GrantDefaultPrivileges {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_PORTION_OF
+        kind == SyntaxKind::GRANT_DEFAULT_PRIVILEGES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38339,10 +40312,10 @@ impl AstNode for ForPortionOf {
         &self.syntax
     }
 }
-impl AstNode for ForProvider {
+impl AstNode for GrantOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_PROVIDER
+        kind == SyntaxKind::GRANT_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38357,10 +40330,10 @@ impl AstNode for ForProvider {
         &self.syntax
     }
 }
-impl AstNode for ForPublicationObjects {
+impl AstNode for GrantOptionFor {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_PUBLICATION_OBJECTS
+        kind == SyntaxKind::GRANT_OPTION_FOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38375,10 +40348,10 @@ impl AstNode for ForPublicationObjects {
         &self.syntax
     }
 }
-impl AstNode for ForRoles {
+impl AstNode for GrantRoleOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_ROLES
+        kind == SyntaxKind::GRANT_ROLE_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38393,10 +40366,10 @@ impl AstNode for ForRoles {
         &self.syntax
     }
 }
-impl AstNode for ForShare {
+impl AstNode for GrantRoleOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_SHARE
+        kind == SyntaxKind::GRANT_ROLE_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38411,10 +40384,10 @@ impl AstNode for ForShare {
         &self.syntax
     }
 }
-impl AstNode for ForUpdate {
+impl AstNode for GrantRoleOptionName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOR_UPDATE
+        kind == SyntaxKind::GRANT_ROLE_OPTION_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38429,10 +40402,10 @@ impl AstNode for ForUpdate {
         &self.syntax
     }
 }
-impl AstNode for Force {
+impl AstNode for GrantWithClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FORCE
+        kind == SyntaxKind::GRANT_WITH_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38447,10 +40420,10 @@ impl AstNode for Force {
         &self.syntax
     }
 }
-impl AstNode for ForceNotNull {
+impl AstNode for GrantedByClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FORCE_NOT_NULL
+        kind == SyntaxKind::GRANTED_BY_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38465,10 +40438,10 @@ impl AstNode for ForceNotNull {
         &self.syntax
     }
 }
-impl AstNode for ForceNull {
+impl AstNode for GraphPatternQualifier {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FORCE_NULL
+        kind == SyntaxKind::GRAPH_PATTERN_QUALIFIER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38483,10 +40456,10 @@ impl AstNode for ForceNull {
         &self.syntax
     }
 }
-impl AstNode for ForceQuote {
+impl AstNode for GraphTableFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FORCE_QUOTE
+        kind == SyntaxKind::GRAPH_TABLE_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38501,10 +40474,10 @@ impl AstNode for ForceQuote {
         &self.syntax
     }
 }
-impl AstNode for ForceRls {
+impl AstNode for GraphTableFromItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FORCE_RLS
+        kind == SyntaxKind::GRAPH_TABLE_FROM_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38519,10 +40492,10 @@ impl AstNode for ForceRls {
         &self.syntax
     }
 }
-impl AstNode for ForeignDataWrapper {
+impl AstNode for Group {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOREIGN_DATA_WRAPPER
+        kind == SyntaxKind::GROUP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38537,10 +40510,10 @@ impl AstNode for ForeignDataWrapper {
         &self.syntax
     }
 }
-impl AstNode for ForeignDataWrapperRef {
+impl AstNode for GroupByClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOREIGN_DATA_WRAPPER_REF
+        kind == SyntaxKind::GROUP_BY_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38555,10 +40528,10 @@ impl AstNode for ForeignDataWrapperRef {
         &self.syntax
     }
 }
-impl AstNode for ForeignDataWrapperRenameTo {
+impl AstNode for GroupByList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOREIGN_DATA_WRAPPER_RENAME_TO
+        kind == SyntaxKind::GROUP_BY_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38573,10 +40546,10 @@ impl AstNode for ForeignDataWrapperRenameTo {
         &self.syntax
     }
 }
-impl AstNode for ForeignKeyColumnList {
+impl AstNode for GroupingCube {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOREIGN_KEY_COLUMN_LIST
+        kind == SyntaxKind::GROUPING_CUBE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38591,10 +40564,10 @@ impl AstNode for ForeignKeyColumnList {
         &self.syntax
     }
 }
-impl AstNode for ForeignKeyConstraint {
+impl AstNode for GroupingExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOREIGN_KEY_CONSTRAINT
+        kind == SyntaxKind::GROUPING_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38609,10 +40582,10 @@ impl AstNode for ForeignKeyConstraint {
         &self.syntax
     }
 }
-impl AstNode for ForeignOptionName {
+impl AstNode for GroupingRollup {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FOREIGN_OPTION_NAME
+        kind == SyntaxKind::GROUPING_ROLLUP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38627,10 +40600,10 @@ impl AstNode for ForeignOptionName {
         &self.syntax
     }
 }
-impl AstNode for Forward {
+impl AstNode for GroupingSets {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FORWARD
+        kind == SyntaxKind::GROUPING_SETS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38645,10 +40618,10 @@ impl AstNode for Forward {
         &self.syntax
     }
 }
-impl AstNode for FrameBetween {
+impl AstNode for Gteq {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FRAME_BETWEEN
+        kind == SyntaxKind::GTEQ
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38663,10 +40636,10 @@ impl AstNode for FrameBetween {
         &self.syntax
     }
 }
-impl AstNode for FrameClause {
+impl AstNode for HandlerClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FRAME_CLAUSE
+        kind == SyntaxKind::HANDLER_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38681,10 +40654,10 @@ impl AstNode for FrameClause {
         &self.syntax
     }
 }
-impl AstNode for FrameExclude {
+impl AstNode for HavingClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FRAME_EXCLUDE
+        kind == SyntaxKind::HAVING_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38699,10 +40672,10 @@ impl AstNode for FrameExclude {
         &self.syntax
     }
 }
-impl AstNode for FrameGroups {
+impl AstNode for IfExists {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FRAME_GROUPS
+        kind == SyntaxKind::IF_EXISTS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38717,10 +40690,10 @@ impl AstNode for FrameGroups {
         &self.syntax
     }
 }
-impl AstNode for FrameRange {
+impl AstNode for IfNotExists {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FRAME_RANGE
+        kind == SyntaxKind::IF_NOT_EXISTS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38735,10 +40708,10 @@ impl AstNode for FrameRange {
         &self.syntax
     }
 }
-impl AstNode for FrameRows {
+impl AstNode for IgnoreNulls {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FRAME_ROWS
+        kind == SyntaxKind::IGNORE_NULLS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38753,10 +40726,10 @@ impl AstNode for FrameRows {
         &self.syntax
     }
 }
-impl AstNode for FromAlias {
+impl AstNode for Immediate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FROM_ALIAS
+        kind == SyntaxKind::IMMEDIATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38771,10 +40744,10 @@ impl AstNode for FromAlias {
         &self.syntax
     }
 }
-impl AstNode for FromClause {
+impl AstNode for Immutable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FROM_CLAUSE
+        kind == SyntaxKind::IMMUTABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38789,10 +40762,10 @@ impl AstNode for FromClause {
         &self.syntax
     }
 }
-impl AstNode for FromCurrent {
+impl AstNode for ImportForeignSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FROM_CURRENT
+        kind == SyntaxKind::IMPORT_FOREIGN_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38807,10 +40780,10 @@ impl AstNode for FromCurrent {
         &self.syntax
     }
 }
-impl AstNode for FromTable {
+impl AstNode for InDatabase {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FROM_TABLE
+        kind == SyntaxKind::IN_DATABASE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38825,10 +40798,10 @@ impl AstNode for FromTable {
         &self.syntax
     }
 }
-impl AstNode for FuncOptionList {
+impl AstNode for InSchemas {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FUNC_OPTION_LIST
+        kind == SyntaxKind::IN_SCHEMAS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38843,10 +40816,10 @@ impl AstNode for FuncOptionList {
         &self.syntax
     }
 }
-impl AstNode for FunctionFromItem {
+impl AstNode for IncludingProperty {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FUNCTION_FROM_ITEM
+        kind == SyntaxKind::INCLUDING_PROPERTY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38861,10 +40834,10 @@ impl AstNode for FunctionFromItem {
         &self.syntax
     }
 }
-impl AstNode for FunctionName {
+impl AstNode for Indent {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FUNCTION_NAME
+        kind == SyntaxKind::INDENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38879,10 +40852,10 @@ impl AstNode for FunctionName {
         &self.syntax
     }
 }
-impl AstNode for FunctionNameRef {
+impl AstNode for Index {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FUNCTION_NAME_REF
+        kind == SyntaxKind::INDEX
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38897,10 +40870,10 @@ impl AstNode for FunctionNameRef {
         &self.syntax
     }
 }
-impl AstNode for FunctionRenameTo {
+impl AstNode for IndexAccessor {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FUNCTION_RENAME_TO
+        kind == SyntaxKind::INDEX_ACCESSOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38915,10 +40888,10 @@ impl AstNode for FunctionRenameTo {
         &self.syntax
     }
 }
-impl AstNode for FunctionSig {
+impl AstNode for IndexExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FUNCTION_SIG
+        kind == SyntaxKind::INDEX_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38933,10 +40906,10 @@ impl AstNode for FunctionSig {
         &self.syntax
     }
 }
-impl AstNode for FunctionSigList {
+impl AstNode for IndexIncludeClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::FUNCTION_SIG_LIST
+        kind == SyntaxKind::INDEX_INCLUDE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38951,10 +40924,10 @@ impl AstNode for FunctionSigList {
         &self.syntax
     }
 }
-impl AstNode for GeneratedAlways {
+impl AstNode for IndexParameters {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GENERATED_ALWAYS
+        kind == SyntaxKind::INDEX_PARAMETERS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38969,10 +40942,10 @@ impl AstNode for GeneratedAlways {
         &self.syntax
     }
 }
-impl AstNode for GeneratedByDefault {
+impl AstNode for IndexRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GENERATED_BY_DEFAULT
+        kind == SyntaxKind::INDEX_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -38987,10 +40960,10 @@ impl AstNode for GeneratedByDefault {
         &self.syntax
     }
 }
-impl AstNode for GeneratedConstraint {
+impl AstNode for IndexRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GENERATED_CONSTRAINT
+        kind == SyntaxKind::INDEX_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39005,10 +40978,10 @@ impl AstNode for GeneratedConstraint {
         &self.syntax
     }
 }
-impl AstNode for GeneratedIdentity {
+impl AstNode for Inherit {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GENERATED_IDENTITY
+        kind == SyntaxKind::INHERIT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39023,10 +40996,10 @@ impl AstNode for GeneratedIdentity {
         &self.syntax
     }
 }
-impl AstNode for GeneratedStored {
+impl AstNode for InheritOptionFor {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GENERATED_STORED
+        kind == SyntaxKind::INHERIT_OPTION_FOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39041,10 +41014,10 @@ impl AstNode for GeneratedStored {
         &self.syntax
     }
 }
-impl AstNode for Grant {
+impl AstNode for InheritTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANT
+        kind == SyntaxKind::INHERIT_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39059,10 +41032,10 @@ impl AstNode for Grant {
         &self.syntax
     }
 }
-impl AstNode for GrantDefaultPrivileges {
+impl AstNode for Inherits {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANT_DEFAULT_PRIVILEGES
+        kind == SyntaxKind::INHERITS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39077,10 +41050,10 @@ impl AstNode for GrantDefaultPrivileges {
         &self.syntax
     }
 }
-impl AstNode for GrantOption {
+impl AstNode for InitiallyDeferredConstraintOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANT_OPTION
+        kind == SyntaxKind::INITIALLY_DEFERRED_CONSTRAINT_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39095,10 +41068,10 @@ impl AstNode for GrantOption {
         &self.syntax
     }
 }
-impl AstNode for GrantOptionFor {
+impl AstNode for InitiallyImmediateConstraintOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANT_OPTION_FOR
+        kind == SyntaxKind::INITIALLY_IMMEDIATE_CONSTRAINT_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39113,10 +41086,10 @@ impl AstNode for GrantOptionFor {
         &self.syntax
     }
 }
-impl AstNode for GrantRoleOption {
+impl AstNode for InlineClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANT_ROLE_OPTION
+        kind == SyntaxKind::INLINE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39131,10 +41104,10 @@ impl AstNode for GrantRoleOption {
         &self.syntax
     }
 }
-impl AstNode for GrantRoleOptionList {
+impl AstNode for Insensitive {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANT_ROLE_OPTION_LIST
+        kind == SyntaxKind::INSENSITIVE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39149,10 +41122,10 @@ impl AstNode for GrantRoleOptionList {
         &self.syntax
     }
 }
-impl AstNode for GrantRoleOptionName {
+impl AstNode for Insert {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANT_ROLE_OPTION_NAME
+        kind == SyntaxKind::INSERT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39167,10 +41140,10 @@ impl AstNode for GrantRoleOptionName {
         &self.syntax
     }
 }
-impl AstNode for GrantWithClause {
+impl AstNode for Intersect {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANT_WITH_CLAUSE
+        kind == SyntaxKind::INTERSECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39185,10 +41158,10 @@ impl AstNode for GrantWithClause {
         &self.syntax
     }
 }
-impl AstNode for GrantedByClause {
+impl AstNode for IntervalDay {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRANTED_BY_CLAUSE
+        kind == SyntaxKind::INTERVAL_DAY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39203,10 +41176,10 @@ impl AstNode for GrantedByClause {
         &self.syntax
     }
 }
-impl AstNode for GraphPatternQualifier {
+impl AstNode for IntervalHour {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRAPH_PATTERN_QUALIFIER
+        kind == SyntaxKind::INTERVAL_HOUR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39221,10 +41194,10 @@ impl AstNode for GraphPatternQualifier {
         &self.syntax
     }
 }
-impl AstNode for GraphTableFn {
+impl AstNode for IntervalMinute {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRAPH_TABLE_FN
+        kind == SyntaxKind::INTERVAL_MINUTE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39239,10 +41212,10 @@ impl AstNode for GraphTableFn {
         &self.syntax
     }
 }
-impl AstNode for GraphTableFromItem {
+impl AstNode for IntervalMonth {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GRAPH_TABLE_FROM_ITEM
+        kind == SyntaxKind::INTERVAL_MONTH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39257,10 +41230,10 @@ impl AstNode for GraphTableFromItem {
         &self.syntax
     }
 }
-impl AstNode for Group {
+impl AstNode for IntervalSecond {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GROUP
+        kind == SyntaxKind::INTERVAL_SECOND
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39275,10 +41248,10 @@ impl AstNode for Group {
         &self.syntax
     }
 }
-impl AstNode for GroupByClause {
+impl AstNode for IntervalType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GROUP_BY_CLAUSE
+        kind == SyntaxKind::INTERVAL_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39293,10 +41266,10 @@ impl AstNode for GroupByClause {
         &self.syntax
     }
 }
-impl AstNode for GroupByList {
+impl AstNode for IntervalYear {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GROUP_BY_LIST
+        kind == SyntaxKind::INTERVAL_YEAR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39311,10 +41284,10 @@ impl AstNode for GroupByList {
         &self.syntax
     }
 }
-impl AstNode for GroupingCube {
+impl AstNode for IntoClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GROUPING_CUBE
+        kind == SyntaxKind::INTO_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39329,10 +41302,10 @@ impl AstNode for GroupingCube {
         &self.syntax
     }
 }
-impl AstNode for GroupingExpr {
+impl AstNode for IntoSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GROUPING_EXPR
+        kind == SyntaxKind::INTO_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39347,10 +41320,10 @@ impl AstNode for GroupingExpr {
         &self.syntax
     }
 }
-impl AstNode for GroupingRollup {
+impl AstNode for IsDistinctFrom {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GROUPING_ROLLUP
+        kind == SyntaxKind::IS_DISTINCT_FROM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39365,10 +41338,10 @@ impl AstNode for GroupingRollup {
         &self.syntax
     }
 }
-impl AstNode for GroupingSets {
+impl AstNode for IsJson {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GROUPING_SETS
+        kind == SyntaxKind::IS_JSON
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39383,10 +41356,10 @@ impl AstNode for GroupingSets {
         &self.syntax
     }
 }
-impl AstNode for Gteq {
+impl AstNode for IsJsonArray {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::GTEQ
+        kind == SyntaxKind::IS_JSON_ARRAY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39401,10 +41374,10 @@ impl AstNode for Gteq {
         &self.syntax
     }
 }
-impl AstNode for HandlerClause {
+impl AstNode for IsJsonObject {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::HANDLER_CLAUSE
+        kind == SyntaxKind::IS_JSON_OBJECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39419,10 +41392,10 @@ impl AstNode for HandlerClause {
         &self.syntax
     }
 }
-impl AstNode for HavingClause {
+impl AstNode for IsJsonScalar {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::HAVING_CLAUSE
+        kind == SyntaxKind::IS_JSON_SCALAR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39437,10 +41410,10 @@ impl AstNode for HavingClause {
         &self.syntax
     }
 }
-impl AstNode for IfExists {
+impl AstNode for IsJsonValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IF_EXISTS
+        kind == SyntaxKind::IS_JSON_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39455,10 +41428,10 @@ impl AstNode for IfExists {
         &self.syntax
     }
 }
-impl AstNode for IfNotExists {
+impl AstNode for IsLabel {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IF_NOT_EXISTS
+        kind == SyntaxKind::IS_LABEL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39473,10 +41446,10 @@ impl AstNode for IfNotExists {
         &self.syntax
     }
 }
-impl AstNode for IgnoreNulls {
+impl AstNode for IsNormalized {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IGNORE_NULLS
+        kind == SyntaxKind::IS_NORMALIZED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39491,10 +41464,10 @@ impl AstNode for IgnoreNulls {
         &self.syntax
     }
 }
-impl AstNode for Immediate {
+impl AstNode for IsNot {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IMMEDIATE
+        kind == SyntaxKind::IS_NOT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39509,10 +41482,10 @@ impl AstNode for Immediate {
         &self.syntax
     }
 }
-impl AstNode for Immutable {
+impl AstNode for IsNotDistinctFrom {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IMMUTABLE
+        kind == SyntaxKind::IS_NOT_DISTINCT_FROM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39527,10 +41500,10 @@ impl AstNode for Immutable {
         &self.syntax
     }
 }
-impl AstNode for ImportForeignSchema {
+impl AstNode for IsNotJson {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IMPORT_FOREIGN_SCHEMA
+        kind == SyntaxKind::IS_NOT_JSON
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39545,10 +41518,10 @@ impl AstNode for ImportForeignSchema {
         &self.syntax
     }
 }
-impl AstNode for InDatabase {
+impl AstNode for IsNotJsonArray {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IN_DATABASE
+        kind == SyntaxKind::IS_NOT_JSON_ARRAY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39563,10 +41536,10 @@ impl AstNode for InDatabase {
         &self.syntax
     }
 }
-impl AstNode for InSchemas {
+impl AstNode for IsNotJsonObject {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IN_SCHEMAS
+        kind == SyntaxKind::IS_NOT_JSON_OBJECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39581,10 +41554,10 @@ impl AstNode for InSchemas {
         &self.syntax
     }
 }
-impl AstNode for IncludingProperty {
+impl AstNode for IsNotJsonScalar {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INCLUDING_PROPERTY
+        kind == SyntaxKind::IS_NOT_JSON_SCALAR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39599,10 +41572,10 @@ impl AstNode for IncludingProperty {
         &self.syntax
     }
 }
-impl AstNode for Indent {
+impl AstNode for IsNotJsonValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INDENT
+        kind == SyntaxKind::IS_NOT_JSON_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39617,10 +41590,10 @@ impl AstNode for Indent {
         &self.syntax
     }
 }
-impl AstNode for Index {
+impl AstNode for IsNotNormalized {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INDEX
+        kind == SyntaxKind::IS_NOT_NORMALIZED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39635,10 +41608,10 @@ impl AstNode for Index {
         &self.syntax
     }
 }
-impl AstNode for IndexAccessor {
+impl AstNode for Join {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INDEX_ACCESSOR
+        kind == SyntaxKind::JOIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39653,10 +41626,10 @@ impl AstNode for IndexAccessor {
         &self.syntax
     }
 }
-impl AstNode for IndexExpr {
+impl AstNode for JoinCross {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INDEX_EXPR
+        kind == SyntaxKind::JOIN_CROSS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39671,10 +41644,10 @@ impl AstNode for IndexExpr {
         &self.syntax
     }
 }
-impl AstNode for IndexIncludeClause {
+impl AstNode for JoinExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INDEX_INCLUDE_CLAUSE
+        kind == SyntaxKind::JOIN_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39689,10 +41662,10 @@ impl AstNode for IndexIncludeClause {
         &self.syntax
     }
 }
-impl AstNode for IndexParameters {
+impl AstNode for JoinFull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INDEX_PARAMETERS
+        kind == SyntaxKind::JOIN_FULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39707,10 +41680,10 @@ impl AstNode for IndexParameters {
         &self.syntax
     }
 }
-impl AstNode for IndexRef {
+impl AstNode for JoinInner {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INDEX_REF
+        kind == SyntaxKind::JOIN_INNER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39725,10 +41698,10 @@ impl AstNode for IndexRef {
         &self.syntax
     }
 }
-impl AstNode for IndexRenameTo {
+impl AstNode for JoinLeft {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INDEX_RENAME_TO
+        kind == SyntaxKind::JOIN_LEFT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39743,10 +41716,10 @@ impl AstNode for IndexRenameTo {
         &self.syntax
     }
 }
-impl AstNode for Inherit {
+impl AstNode for JoinRight {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INHERIT
+        kind == SyntaxKind::JOIN_RIGHT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39761,10 +41734,10 @@ impl AstNode for Inherit {
         &self.syntax
     }
 }
-impl AstNode for InheritOptionFor {
+impl AstNode for JoinUsingClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INHERIT_OPTION_FOR
+        kind == SyntaxKind::JOIN_USING_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39779,10 +41752,10 @@ impl AstNode for InheritOptionFor {
         &self.syntax
     }
 }
-impl AstNode for InheritTable {
+impl AstNode for JsonAbsentOnNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INHERIT_TABLE
+        kind == SyntaxKind::JSON_ABSENT_ON_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39797,10 +41770,10 @@ impl AstNode for InheritTable {
         &self.syntax
     }
 }
-impl AstNode for Inherits {
+impl AstNode for JsonArrayAggFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INHERITS
+        kind == SyntaxKind::JSON_ARRAY_AGG_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39815,10 +41788,10 @@ impl AstNode for Inherits {
         &self.syntax
     }
 }
-impl AstNode for InitiallyDeferredConstraintOption {
+impl AstNode for JsonArrayFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INITIALLY_DEFERRED_CONSTRAINT_OPTION
+        kind == SyntaxKind::JSON_ARRAY_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39833,10 +41806,10 @@ impl AstNode for InitiallyDeferredConstraintOption {
         &self.syntax
     }
 }
-impl AstNode for InitiallyImmediateConstraintOption {
+impl AstNode for JsonBehaviorDefault {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INITIALLY_IMMEDIATE_CONSTRAINT_OPTION
+        kind == SyntaxKind::JSON_BEHAVIOR_DEFAULT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39851,10 +41824,10 @@ impl AstNode for InitiallyImmediateConstraintOption {
         &self.syntax
     }
 }
-impl AstNode for InlineClause {
+impl AstNode for JsonBehaviorEmptyArray {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INLINE_CLAUSE
+        kind == SyntaxKind::JSON_BEHAVIOR_EMPTY_ARRAY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39869,10 +41842,10 @@ impl AstNode for InlineClause {
         &self.syntax
     }
 }
-impl AstNode for Insensitive {
+impl AstNode for JsonBehaviorEmptyObject {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INSENSITIVE
+        kind == SyntaxKind::JSON_BEHAVIOR_EMPTY_OBJECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39887,10 +41860,10 @@ impl AstNode for Insensitive {
         &self.syntax
     }
 }
-impl AstNode for Insert {
+impl AstNode for JsonBehaviorError {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INSERT
+        kind == SyntaxKind::JSON_BEHAVIOR_ERROR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39905,10 +41878,10 @@ impl AstNode for Insert {
         &self.syntax
     }
 }
-impl AstNode for Intersect {
+impl AstNode for JsonBehaviorFalse {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTERSECT
+        kind == SyntaxKind::JSON_BEHAVIOR_FALSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39923,10 +41896,10 @@ impl AstNode for Intersect {
         &self.syntax
     }
 }
-impl AstNode for IntervalDay {
+impl AstNode for JsonBehaviorNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTERVAL_DAY
+        kind == SyntaxKind::JSON_BEHAVIOR_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39941,10 +41914,10 @@ impl AstNode for IntervalDay {
         &self.syntax
     }
 }
-impl AstNode for IntervalHour {
+impl AstNode for JsonBehaviorTrue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTERVAL_HOUR
+        kind == SyntaxKind::JSON_BEHAVIOR_TRUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39959,10 +41932,10 @@ impl AstNode for IntervalHour {
         &self.syntax
     }
 }
-impl AstNode for IntervalMinute {
+impl AstNode for JsonBehaviorUnknown {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTERVAL_MINUTE
+        kind == SyntaxKind::JSON_BEHAVIOR_UNKNOWN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39977,10 +41950,10 @@ impl AstNode for IntervalMinute {
         &self.syntax
     }
 }
-impl AstNode for IntervalMonth {
+impl AstNode for JsonEncoding {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTERVAL_MONTH
+        kind == SyntaxKind::JSON_ENCODING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -39995,10 +41968,10 @@ impl AstNode for IntervalMonth {
         &self.syntax
     }
 }
-impl AstNode for IntervalSecond {
+impl AstNode for JsonEncodingClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTERVAL_SECOND
+        kind == SyntaxKind::JSON_ENCODING_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40013,10 +41986,10 @@ impl AstNode for IntervalSecond {
         &self.syntax
     }
 }
-impl AstNode for IntervalType {
+impl AstNode for JsonExistsFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTERVAL_TYPE
+        kind == SyntaxKind::JSON_EXISTS_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40031,10 +42004,10 @@ impl AstNode for IntervalType {
         &self.syntax
     }
 }
-impl AstNode for IntervalYear {
+impl AstNode for JsonExprFormat {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTERVAL_YEAR
+        kind == SyntaxKind::JSON_EXPR_FORMAT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40049,10 +42022,10 @@ impl AstNode for IntervalYear {
         &self.syntax
     }
 }
-impl AstNode for IntoClause {
+impl AstNode for JsonFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTO_CLAUSE
+        kind == SyntaxKind::JSON_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40067,10 +42040,10 @@ impl AstNode for IntoClause {
         &self.syntax
     }
 }
-impl AstNode for IntoSchema {
+impl AstNode for JsonFormatClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::INTO_SCHEMA
+        kind == SyntaxKind::JSON_FORMAT_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40085,10 +42058,10 @@ impl AstNode for IntoSchema {
         &self.syntax
     }
 }
-impl AstNode for IsDistinctFrom {
+impl AstNode for JsonKeyValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_DISTINCT_FROM
+        kind == SyntaxKind::JSON_KEY_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40103,10 +42076,10 @@ impl AstNode for IsDistinctFrom {
         &self.syntax
     }
 }
-impl AstNode for IsJson {
+impl AstNode for JsonNullOnNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_JSON
+        kind == SyntaxKind::JSON_NULL_ON_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40121,10 +42094,10 @@ impl AstNode for IsJson {
         &self.syntax
     }
 }
-impl AstNode for IsJsonArray {
+impl AstNode for JsonObjectAggFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_JSON_ARRAY
+        kind == SyntaxKind::JSON_OBJECT_AGG_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40139,10 +42112,10 @@ impl AstNode for IsJsonArray {
         &self.syntax
     }
 }
-impl AstNode for IsJsonObject {
+impl AstNode for JsonObjectFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_JSON_OBJECT
+        kind == SyntaxKind::JSON_OBJECT_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40157,10 +42130,10 @@ impl AstNode for IsJsonObject {
         &self.syntax
     }
 }
-impl AstNode for IsJsonScalar {
+impl AstNode for JsonOnEmptyClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_JSON_SCALAR
+        kind == SyntaxKind::JSON_ON_EMPTY_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40175,10 +42148,10 @@ impl AstNode for IsJsonScalar {
         &self.syntax
     }
 }
-impl AstNode for IsJsonValue {
+impl AstNode for JsonOnErrorClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_JSON_VALUE
+        kind == SyntaxKind::JSON_ON_ERROR_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40193,10 +42166,10 @@ impl AstNode for IsJsonValue {
         &self.syntax
     }
 }
-impl AstNode for IsLabel {
+impl AstNode for JsonPassingArg {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_LABEL
+        kind == SyntaxKind::JSON_PASSING_ARG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40211,10 +42184,10 @@ impl AstNode for IsLabel {
         &self.syntax
     }
 }
-impl AstNode for IsNormalized {
+impl AstNode for JsonPassingClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NORMALIZED
+        kind == SyntaxKind::JSON_PASSING_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40229,10 +42202,10 @@ impl AstNode for IsNormalized {
         &self.syntax
     }
 }
-impl AstNode for IsNot {
+impl AstNode for JsonPathClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NOT
+        kind == SyntaxKind::JSON_PATH_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40247,10 +42220,10 @@ impl AstNode for IsNot {
         &self.syntax
     }
 }
-impl AstNode for IsNotDistinctFrom {
+impl AstNode for JsonPathName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NOT_DISTINCT_FROM
+        kind == SyntaxKind::JSON_PATH_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40265,10 +42238,10 @@ impl AstNode for IsNotDistinctFrom {
         &self.syntax
     }
 }
-impl AstNode for IsNotJson {
+impl AstNode for JsonPathNameClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NOT_JSON
+        kind == SyntaxKind::JSON_PATH_NAME_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40283,10 +42256,10 @@ impl AstNode for IsNotJson {
         &self.syntax
     }
 }
-impl AstNode for IsNotJsonArray {
+impl AstNode for JsonPathNameRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NOT_JSON_ARRAY
+        kind == SyntaxKind::JSON_PATH_NAME_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40301,10 +42274,10 @@ impl AstNode for IsNotJsonArray {
         &self.syntax
     }
 }
-impl AstNode for IsNotJsonObject {
+impl AstNode for JsonQueryFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NOT_JSON_OBJECT
+        kind == SyntaxKind::JSON_QUERY_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40319,10 +42292,10 @@ impl AstNode for IsNotJsonObject {
         &self.syntax
     }
 }
-impl AstNode for IsNotJsonScalar {
+impl AstNode for JsonQuotesClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NOT_JSON_SCALAR
+        kind == SyntaxKind::JSON_QUOTES_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40337,10 +42310,10 @@ impl AstNode for IsNotJsonScalar {
         &self.syntax
     }
 }
-impl AstNode for IsNotJsonValue {
+impl AstNode for JsonReturningClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NOT_JSON_VALUE
+        kind == SyntaxKind::JSON_RETURNING_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40355,10 +42328,10 @@ impl AstNode for IsNotJsonValue {
         &self.syntax
     }
 }
-impl AstNode for IsNotNormalized {
+impl AstNode for JsonScalarFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::IS_NOT_NORMALIZED
+        kind == SyntaxKind::JSON_SCALAR_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40373,10 +42346,10 @@ impl AstNode for IsNotNormalized {
         &self.syntax
     }
 }
-impl AstNode for Join {
+impl AstNode for JsonSelectFormat {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JOIN
+        kind == SyntaxKind::JSON_SELECT_FORMAT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40391,10 +42364,10 @@ impl AstNode for Join {
         &self.syntax
     }
 }
-impl AstNode for JoinCross {
+impl AstNode for JsonSerializeFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JOIN_CROSS
+        kind == SyntaxKind::JSON_SERIALIZE_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40409,10 +42382,10 @@ impl AstNode for JoinCross {
         &self.syntax
     }
 }
-impl AstNode for JoinExpr {
+impl AstNode for JsonTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JOIN_EXPR
+        kind == SyntaxKind::JSON_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40427,10 +42400,10 @@ impl AstNode for JoinExpr {
         &self.syntax
     }
 }
-impl AstNode for JoinFull {
+impl AstNode for JsonTableColumnList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JOIN_FULL
+        kind == SyntaxKind::JSON_TABLE_COLUMN_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40445,10 +42418,10 @@ impl AstNode for JoinFull {
         &self.syntax
     }
 }
-impl AstNode for JoinInner {
+impl AstNode for JsonTableExistsColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JOIN_INNER
+        kind == SyntaxKind::JSON_TABLE_EXISTS_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40463,10 +42436,10 @@ impl AstNode for JoinInner {
         &self.syntax
     }
 }
-impl AstNode for JoinLeft {
+impl AstNode for JsonTableFromItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JOIN_LEFT
+        kind == SyntaxKind::JSON_TABLE_FROM_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40481,10 +42454,10 @@ impl AstNode for JoinLeft {
         &self.syntax
     }
 }
-impl AstNode for JoinRight {
+impl AstNode for JsonTableNestedColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JOIN_RIGHT
+        kind == SyntaxKind::JSON_TABLE_NESTED_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40499,10 +42472,10 @@ impl AstNode for JoinRight {
         &self.syntax
     }
 }
-impl AstNode for JoinUsingClause {
+impl AstNode for JsonTableOrdinalityColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JOIN_USING_CLAUSE
+        kind == SyntaxKind::JSON_TABLE_ORDINALITY_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40517,10 +42490,10 @@ impl AstNode for JoinUsingClause {
         &self.syntax
     }
 }
-impl AstNode for JsonAbsentOnNull {
+impl AstNode for JsonTablePlanChoice {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_ABSENT_ON_NULL
+        kind == SyntaxKind::JSON_TABLE_PLAN_CHOICE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40535,10 +42508,10 @@ impl AstNode for JsonAbsentOnNull {
         &self.syntax
     }
 }
-impl AstNode for JsonArrayAggFn {
+impl AstNode for JsonTablePlanClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_ARRAY_AGG_FN
+        kind == SyntaxKind::JSON_TABLE_PLAN_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40553,10 +42526,10 @@ impl AstNode for JsonArrayAggFn {
         &self.syntax
     }
 }
-impl AstNode for JsonArrayFn {
+impl AstNode for JsonTablePlanCross {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_ARRAY_FN
+        kind == SyntaxKind::JSON_TABLE_PLAN_CROSS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40571,10 +42544,10 @@ impl AstNode for JsonArrayFn {
         &self.syntax
     }
 }
-impl AstNode for JsonBehaviorDefault {
+impl AstNode for JsonTablePlanInner {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_BEHAVIOR_DEFAULT
+        kind == SyntaxKind::JSON_TABLE_PLAN_INNER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40589,10 +42562,10 @@ impl AstNode for JsonBehaviorDefault {
         &self.syntax
     }
 }
-impl AstNode for JsonBehaviorEmptyArray {
+impl AstNode for JsonTablePlanJoin {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_BEHAVIOR_EMPTY_ARRAY
+        kind == SyntaxKind::JSON_TABLE_PLAN_JOIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40607,10 +42580,10 @@ impl AstNode for JsonBehaviorEmptyArray {
         &self.syntax
     }
 }
-impl AstNode for JsonBehaviorEmptyObject {
+impl AstNode for JsonTablePlanOuter {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_BEHAVIOR_EMPTY_OBJECT
+        kind == SyntaxKind::JSON_TABLE_PLAN_OUTER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40625,10 +42598,10 @@ impl AstNode for JsonBehaviorEmptyObject {
         &self.syntax
     }
 }
-impl AstNode for JsonBehaviorError {
+impl AstNode for JsonTablePlanUnion {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_BEHAVIOR_ERROR
+        kind == SyntaxKind::JSON_TABLE_PLAN_UNION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40643,10 +42616,10 @@ impl AstNode for JsonBehaviorError {
         &self.syntax
     }
 }
-impl AstNode for JsonBehaviorFalse {
+impl AstNode for JsonTableValueColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_BEHAVIOR_FALSE
+        kind == SyntaxKind::JSON_TABLE_VALUE_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40661,10 +42634,10 @@ impl AstNode for JsonBehaviorFalse {
         &self.syntax
     }
 }
-impl AstNode for JsonBehaviorNull {
+impl AstNode for JsonValueExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_BEHAVIOR_NULL
+        kind == SyntaxKind::JSON_VALUE_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40679,10 +42652,10 @@ impl AstNode for JsonBehaviorNull {
         &self.syntax
     }
 }
-impl AstNode for JsonBehaviorTrue {
+impl AstNode for JsonValueFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_BEHAVIOR_TRUE
+        kind == SyntaxKind::JSON_VALUE_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40697,10 +42670,10 @@ impl AstNode for JsonBehaviorTrue {
         &self.syntax
     }
 }
-impl AstNode for JsonBehaviorUnknown {
+impl AstNode for JsonVariableName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_BEHAVIOR_UNKNOWN
+        kind == SyntaxKind::JSON_VARIABLE_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40715,10 +42688,10 @@ impl AstNode for JsonBehaviorUnknown {
         &self.syntax
     }
 }
-impl AstNode for JsonEncoding {
+impl AstNode for JsonWithConditionalWrapper {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_ENCODING
+        kind == SyntaxKind::JSON_WITH_CONDITIONAL_WRAPPER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40733,10 +42706,10 @@ impl AstNode for JsonEncoding {
         &self.syntax
     }
 }
-impl AstNode for JsonEncodingClause {
+impl AstNode for JsonWithUnconditionalWrapper {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_ENCODING_CLAUSE
+        kind == SyntaxKind::JSON_WITH_UNCONDITIONAL_WRAPPER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40751,10 +42724,10 @@ impl AstNode for JsonEncodingClause {
         &self.syntax
     }
 }
-impl AstNode for JsonExistsFn {
+impl AstNode for JsonWithUniqueKeys {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_EXISTS_FN
+        kind == SyntaxKind::JSON_WITH_UNIQUE_KEYS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40769,10 +42742,10 @@ impl AstNode for JsonExistsFn {
         &self.syntax
     }
 }
-impl AstNode for JsonExprFormat {
+impl AstNode for JsonWithoutUniqueKeys {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_EXPR_FORMAT
+        kind == SyntaxKind::JSON_WITHOUT_UNIQUE_KEYS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40787,10 +42760,10 @@ impl AstNode for JsonExprFormat {
         &self.syntax
     }
 }
-impl AstNode for JsonFn {
+impl AstNode for JsonWithoutWrapper {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_FN
+        kind == SyntaxKind::JSON_WITHOUT_WRAPPER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40805,10 +42778,10 @@ impl AstNode for JsonFn {
         &self.syntax
     }
 }
-impl AstNode for JsonFormatClause {
+impl AstNode for KeepQuotes {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_FORMAT_CLAUSE
+        kind == SyntaxKind::KEEP_QUOTES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40823,10 +42796,10 @@ impl AstNode for JsonFormatClause {
         &self.syntax
     }
 }
-impl AstNode for JsonKeyValue {
+impl AstNode for Label {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_KEY_VALUE
+        kind == SyntaxKind::LABEL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40841,10 +42814,10 @@ impl AstNode for JsonKeyValue {
         &self.syntax
     }
 }
-impl AstNode for JsonNullOnNull {
+impl AstNode for LabelAndProperties {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_NULL_ON_NULL
+        kind == SyntaxKind::LABEL_AND_PROPERTIES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40859,10 +42832,10 @@ impl AstNode for JsonNullOnNull {
         &self.syntax
     }
 }
-impl AstNode for JsonObjectAggFn {
+impl AstNode for LabelAndPropertiesList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_OBJECT_AGG_FN
+        kind == SyntaxKind::LABEL_AND_PROPERTIES_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40877,10 +42850,10 @@ impl AstNode for JsonObjectAggFn {
         &self.syntax
     }
 }
-impl AstNode for JsonObjectFn {
+impl AstNode for LabelRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_OBJECT_FN
+        kind == SyntaxKind::LABEL_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40895,10 +42868,10 @@ impl AstNode for JsonObjectFn {
         &self.syntax
     }
 }
-impl AstNode for JsonOnEmptyClause {
+impl AstNode for Language {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_ON_EMPTY_CLAUSE
+        kind == SyntaxKind::LANGUAGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40913,10 +42886,10 @@ impl AstNode for JsonOnEmptyClause {
         &self.syntax
     }
 }
-impl AstNode for JsonOnErrorClause {
+impl AstNode for LanguageFuncOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_ON_ERROR_CLAUSE
+        kind == SyntaxKind::LANGUAGE_FUNC_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40931,10 +42904,10 @@ impl AstNode for JsonOnErrorClause {
         &self.syntax
     }
 }
-impl AstNode for JsonPassingArg {
+impl AstNode for LanguageRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_PASSING_ARG
+        kind == SyntaxKind::LANGUAGE_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40949,10 +42922,10 @@ impl AstNode for JsonPassingArg {
         &self.syntax
     }
 }
-impl AstNode for JsonPassingClause {
+impl AstNode for LanguageRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_PASSING_CLAUSE
+        kind == SyntaxKind::LANGUAGE_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40967,10 +42940,10 @@ impl AstNode for JsonPassingClause {
         &self.syntax
     }
 }
-impl AstNode for JsonPathClause {
+impl AstNode for Last {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_PATH_CLAUSE
+        kind == SyntaxKind::LAST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -40985,10 +42958,10 @@ impl AstNode for JsonPathClause {
         &self.syntax
     }
 }
-impl AstNode for JsonPathName {
+impl AstNode for LeakproofFuncOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_PATH_NAME
+        kind == SyntaxKind::LEAKPROOF_FUNC_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41003,10 +42976,10 @@ impl AstNode for JsonPathName {
         &self.syntax
     }
 }
-impl AstNode for JsonPathNameClause {
+impl AstNode for LikeClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_PATH_NAME_CLAUSE
+        kind == SyntaxKind::LIKE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41021,10 +42994,10 @@ impl AstNode for JsonPathNameClause {
         &self.syntax
     }
 }
-impl AstNode for JsonPathNameRef {
+impl AstNode for LimitClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_PATH_NAME_REF
+        kind == SyntaxKind::LIMIT_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41039,10 +43012,10 @@ impl AstNode for JsonPathNameRef {
         &self.syntax
     }
 }
-impl AstNode for JsonQueryFn {
+impl AstNode for LimitToTables {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_QUERY_FN
+        kind == SyntaxKind::LIMIT_TO_TABLES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41057,10 +43030,10 @@ impl AstNode for JsonQueryFn {
         &self.syntax
     }
 }
-impl AstNode for JsonQuotesClause {
+impl AstNode for Listen {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_QUOTES_CLAUSE
+        kind == SyntaxKind::LISTEN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41075,10 +43048,10 @@ impl AstNode for JsonQuotesClause {
         &self.syntax
     }
 }
-impl AstNode for JsonReturningClause {
+impl AstNode for Literal {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_RETURNING_CLAUSE
+        kind == SyntaxKind::LITERAL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41093,10 +43066,10 @@ impl AstNode for JsonReturningClause {
         &self.syntax
     }
 }
-impl AstNode for JsonScalarFn {
+impl AstNode for Load {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_SCALAR_FN
+        kind == SyntaxKind::LOAD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41111,10 +43084,10 @@ impl AstNode for JsonScalarFn {
         &self.syntax
     }
 }
-impl AstNode for JsonSelectFormat {
+impl AstNode for LocalCheckOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_SELECT_FORMAT
+        kind == SyntaxKind::LOCAL_CHECK_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41129,10 +43102,10 @@ impl AstNode for JsonSelectFormat {
         &self.syntax
     }
 }
-impl AstNode for JsonSerializeFn {
+impl AstNode for LocalScope {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_SERIALIZE_FN
+        kind == SyntaxKind::LOCAL_SCOPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41147,10 +43120,10 @@ impl AstNode for JsonSerializeFn {
         &self.syntax
     }
 }
-impl AstNode for JsonTable {
+impl AstNode for Lock {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE
+        kind == SyntaxKind::LOCK
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41165,10 +43138,10 @@ impl AstNode for JsonTable {
         &self.syntax
     }
 }
-impl AstNode for JsonTableColumnList {
+impl AstNode for LockModeClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_COLUMN_LIST
+        kind == SyntaxKind::LOCK_MODE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41183,10 +43156,10 @@ impl AstNode for JsonTableColumnList {
         &self.syntax
     }
 }
-impl AstNode for JsonTableExistsColumn {
+impl AstNode for LockingClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_EXISTS_COLUMN
+        kind == SyntaxKind::LOCKING_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41201,10 +43174,10 @@ impl AstNode for JsonTableExistsColumn {
         &self.syntax
     }
 }
-impl AstNode for JsonTableFromItem {
+impl AstNode for LockingOf {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_FROM_ITEM
+        kind == SyntaxKind::LOCKING_OF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41219,10 +43192,10 @@ impl AstNode for JsonTableFromItem {
         &self.syntax
     }
 }
-impl AstNode for JsonTableNestedColumn {
+impl AstNode for Lteq {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_NESTED_COLUMN
+        kind == SyntaxKind::LTEQ
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41237,10 +43210,10 @@ impl AstNode for JsonTableNestedColumn {
         &self.syntax
     }
 }
-impl AstNode for JsonTableOrdinalityColumn {
+impl AstNode for MatchFull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_ORDINALITY_COLUMN
+        kind == SyntaxKind::MATCH_FULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41255,10 +43228,10 @@ impl AstNode for JsonTableOrdinalityColumn {
         &self.syntax
     }
 }
-impl AstNode for JsonTablePlanChoice {
+impl AstNode for MatchPartial {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_PLAN_CHOICE
+        kind == SyntaxKind::MATCH_PARTIAL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41273,10 +43246,10 @@ impl AstNode for JsonTablePlanChoice {
         &self.syntax
     }
 }
-impl AstNode for JsonTablePlanClause {
+impl AstNode for MatchSimple {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_PLAN_CLAUSE
+        kind == SyntaxKind::MATCH_SIMPLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41291,10 +43264,10 @@ impl AstNode for JsonTablePlanClause {
         &self.syntax
     }
 }
-impl AstNode for JsonTablePlanCross {
+impl AstNode for Materialized {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_PLAN_CROSS
+        kind == SyntaxKind::MATERIALIZED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41309,10 +43282,10 @@ impl AstNode for JsonTablePlanCross {
         &self.syntax
     }
 }
-impl AstNode for JsonTablePlanInner {
+impl AstNode for Merge {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_PLAN_INNER
+        kind == SyntaxKind::MERGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41327,10 +43300,10 @@ impl AstNode for JsonTablePlanInner {
         &self.syntax
     }
 }
-impl AstNode for JsonTablePlanJoin {
+impl AstNode for MergeCondition {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_PLAN_JOIN
+        kind == SyntaxKind::MERGE_CONDITION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41345,10 +43318,10 @@ impl AstNode for JsonTablePlanJoin {
         &self.syntax
     }
 }
-impl AstNode for JsonTablePlanOuter {
+impl AstNode for MergeDelete {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_PLAN_OUTER
+        kind == SyntaxKind::MERGE_DELETE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41363,10 +43336,10 @@ impl AstNode for JsonTablePlanOuter {
         &self.syntax
     }
 }
-impl AstNode for JsonTablePlanUnion {
+impl AstNode for MergeDoNothing {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_PLAN_UNION
+        kind == SyntaxKind::MERGE_DO_NOTHING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41381,10 +43354,10 @@ impl AstNode for JsonTablePlanUnion {
         &self.syntax
     }
 }
-impl AstNode for JsonTableValueColumn {
+impl AstNode for MergeInsert {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_TABLE_VALUE_COLUMN
+        kind == SyntaxKind::MERGE_INSERT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41399,10 +43372,10 @@ impl AstNode for JsonTableValueColumn {
         &self.syntax
     }
 }
-impl AstNode for JsonValueExpr {
+impl AstNode for MergePartitions {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_VALUE_EXPR
+        kind == SyntaxKind::MERGE_PARTITIONS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41417,10 +43390,10 @@ impl AstNode for JsonValueExpr {
         &self.syntax
     }
 }
-impl AstNode for JsonValueFn {
+impl AstNode for MergeUpdate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_VALUE_FN
+        kind == SyntaxKind::MERGE_UPDATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41435,10 +43408,10 @@ impl AstNode for JsonValueFn {
         &self.syntax
     }
 }
-impl AstNode for JsonVariableName {
+impl AstNode for MergeWhenMatched {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_VARIABLE_NAME
+        kind == SyntaxKind::MERGE_WHEN_MATCHED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41453,10 +43426,10 @@ impl AstNode for JsonVariableName {
         &self.syntax
     }
 }
-impl AstNode for JsonWithConditionalWrapper {
+impl AstNode for MergeWhenNotMatchedSource {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_WITH_CONDITIONAL_WRAPPER
+        kind == SyntaxKind::MERGE_WHEN_NOT_MATCHED_SOURCE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41471,10 +43444,10 @@ impl AstNode for JsonWithConditionalWrapper {
         &self.syntax
     }
 }
-impl AstNode for JsonWithUnconditionalWrapper {
+impl AstNode for MergeWhenNotMatchedTarget {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_WITH_UNCONDITIONAL_WRAPPER
+        kind == SyntaxKind::MERGE_WHEN_NOT_MATCHED_TARGET
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41489,10 +43462,10 @@ impl AstNode for JsonWithUnconditionalWrapper {
         &self.syntax
     }
 }
-impl AstNode for JsonWithUniqueKeys {
+impl AstNode for Move {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_WITH_UNIQUE_KEYS
+        kind == SyntaxKind::MOVE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41507,10 +43480,10 @@ impl AstNode for JsonWithUniqueKeys {
         &self.syntax
     }
 }
-impl AstNode for JsonWithoutUniqueKeys {
+impl AstNode for NameRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_WITHOUT_UNIQUE_KEYS
+        kind == SyntaxKind::NAME_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41525,10 +43498,10 @@ impl AstNode for JsonWithoutUniqueKeys {
         &self.syntax
     }
 }
-impl AstNode for JsonWithoutWrapper {
+impl AstNode for NamedArg {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::JSON_WITHOUT_WRAPPER
+        kind == SyntaxKind::NAMED_ARG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41543,10 +43516,10 @@ impl AstNode for JsonWithoutWrapper {
         &self.syntax
     }
 }
-impl AstNode for KeepQuotes {
+impl AstNode for NamedLabel {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::KEEP_QUOTES
+        kind == SyntaxKind::NAMED_LABEL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41561,10 +43534,10 @@ impl AstNode for KeepQuotes {
         &self.syntax
     }
 }
-impl AstNode for Label {
+impl AstNode for NamedSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LABEL
+        kind == SyntaxKind::NAMED_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41579,10 +43552,10 @@ impl AstNode for Label {
         &self.syntax
     }
 }
-impl AstNode for LabelAndProperties {
+impl AstNode for Neq {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LABEL_AND_PROPERTIES
+        kind == SyntaxKind::NEQ
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41597,10 +43570,10 @@ impl AstNode for LabelAndProperties {
         &self.syntax
     }
 }
-impl AstNode for LabelAndPropertiesList {
+impl AstNode for Neqb {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LABEL_AND_PROPERTIES_LIST
+        kind == SyntaxKind::NEQB
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41615,10 +43588,10 @@ impl AstNode for LabelAndPropertiesList {
         &self.syntax
     }
 }
-impl AstNode for LabelRef {
+impl AstNode for NewTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LABEL_REF
+        kind == SyntaxKind::NEW_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41633,10 +43606,10 @@ impl AstNode for LabelRef {
         &self.syntax
     }
 }
-impl AstNode for Language {
+impl AstNode for Next {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LANGUAGE
+        kind == SyntaxKind::NEXT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41651,10 +43624,10 @@ impl AstNode for Language {
         &self.syntax
     }
 }
-impl AstNode for LanguageFuncOption {
+impl AstNode for NoAction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LANGUAGE_FUNC_OPTION
+        kind == SyntaxKind::NO_ACTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41669,10 +43642,10 @@ impl AstNode for LanguageFuncOption {
         &self.syntax
     }
 }
-impl AstNode for LanguageRef {
+impl AstNode for NoDependsOnExtension {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LANGUAGE_REF
+        kind == SyntaxKind::NO_DEPENDS_ON_EXTENSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41687,10 +43660,10 @@ impl AstNode for LanguageRef {
         &self.syntax
     }
 }
-impl AstNode for LanguageRenameTo {
+impl AstNode for NoForceRls {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LANGUAGE_RENAME_TO
+        kind == SyntaxKind::NO_FORCE_RLS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41705,10 +43678,10 @@ impl AstNode for LanguageRenameTo {
         &self.syntax
     }
 }
-impl AstNode for Last {
+impl AstNode for NoIndent {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LAST
+        kind == SyntaxKind::NO_INDENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41723,10 +43696,10 @@ impl AstNode for Last {
         &self.syntax
     }
 }
-impl AstNode for LeakproofFuncOption {
+impl AstNode for NoInherit {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LEAKPROOF_FUNC_OPTION
+        kind == SyntaxKind::NO_INHERIT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41741,10 +43714,10 @@ impl AstNode for LeakproofFuncOption {
         &self.syntax
     }
 }
-impl AstNode for LikeClause {
+impl AstNode for NoInheritTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LIKE_CLAUSE
+        kind == SyntaxKind::NO_INHERIT_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41759,10 +43732,10 @@ impl AstNode for LikeClause {
         &self.syntax
     }
 }
-impl AstNode for LimitClause {
+impl AstNode for NoOthers {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LIMIT_CLAUSE
+        kind == SyntaxKind::NO_OTHERS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41777,10 +43750,10 @@ impl AstNode for LimitClause {
         &self.syntax
     }
 }
-impl AstNode for LimitToTables {
+impl AstNode for NoProperties {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LIMIT_TO_TABLES
+        kind == SyntaxKind::NO_PROPERTIES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41795,10 +43768,10 @@ impl AstNode for LimitToTables {
         &self.syntax
     }
 }
-impl AstNode for Listen {
+impl AstNode for NoScroll {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LISTEN
+        kind == SyntaxKind::NO_SCROLL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41813,10 +43786,10 @@ impl AstNode for Listen {
         &self.syntax
     }
 }
-impl AstNode for Literal {
+impl AstNode for NonStandardParam {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LITERAL
+        kind == SyntaxKind::NON_STANDARD_PARAM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41831,10 +43804,10 @@ impl AstNode for Literal {
         &self.syntax
     }
 }
-impl AstNode for Load {
+impl AstNode for NotDeferrable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LOAD
+        kind == SyntaxKind::NOT_DEFERRABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41849,10 +43822,10 @@ impl AstNode for Load {
         &self.syntax
     }
 }
-impl AstNode for LocalCheckOption {
+impl AstNode for NotDeferrableConstraintOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LOCAL_CHECK_OPTION
+        kind == SyntaxKind::NOT_DEFERRABLE_CONSTRAINT_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41867,10 +43840,10 @@ impl AstNode for LocalCheckOption {
         &self.syntax
     }
 }
-impl AstNode for LocalScope {
+impl AstNode for NotEnforced {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LOCAL_SCOPE
+        kind == SyntaxKind::NOT_ENFORCED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41885,10 +43858,10 @@ impl AstNode for LocalScope {
         &self.syntax
     }
 }
-impl AstNode for Lock {
+impl AstNode for NotIlike {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LOCK
+        kind == SyntaxKind::NOT_ILIKE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41903,10 +43876,10 @@ impl AstNode for Lock {
         &self.syntax
     }
 }
-impl AstNode for LockModeClause {
+impl AstNode for NotIn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LOCK_MODE_CLAUSE
+        kind == SyntaxKind::NOT_IN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41921,10 +43894,10 @@ impl AstNode for LockModeClause {
         &self.syntax
     }
 }
-impl AstNode for LockingClause {
+impl AstNode for NotLeakproofFuncOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LOCKING_CLAUSE
+        kind == SyntaxKind::NOT_LEAKPROOF_FUNC_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41939,10 +43912,10 @@ impl AstNode for LockingClause {
         &self.syntax
     }
 }
-impl AstNode for LockingOf {
+impl AstNode for NotLike {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LOCKING_OF
+        kind == SyntaxKind::NOT_LIKE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41957,10 +43930,10 @@ impl AstNode for LockingOf {
         &self.syntax
     }
 }
-impl AstNode for Lteq {
+impl AstNode for NotMaterialized {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::LTEQ
+        kind == SyntaxKind::NOT_MATERIALIZED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41975,10 +43948,10 @@ impl AstNode for Lteq {
         &self.syntax
     }
 }
-impl AstNode for MatchFull {
+impl AstNode for NotNullConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MATCH_FULL
+        kind == SyntaxKind::NOT_NULL_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -41993,10 +43966,10 @@ impl AstNode for MatchFull {
         &self.syntax
     }
 }
-impl AstNode for MatchPartial {
+impl AstNode for NotOf {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MATCH_PARTIAL
+        kind == SyntaxKind::NOT_OF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42011,10 +43984,10 @@ impl AstNode for MatchPartial {
         &self.syntax
     }
 }
-impl AstNode for MatchSimple {
+impl AstNode for NotSimilarTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MATCH_SIMPLE
+        kind == SyntaxKind::NOT_SIMILAR_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42029,10 +44002,10 @@ impl AstNode for MatchSimple {
         &self.syntax
     }
 }
-impl AstNode for Materialized {
+impl AstNode for NotValid {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MATERIALIZED
+        kind == SyntaxKind::NOT_VALID
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42047,10 +44020,10 @@ impl AstNode for Materialized {
         &self.syntax
     }
 }
-impl AstNode for Merge {
+impl AstNode for Nothing {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE
+        kind == SyntaxKind::NOTHING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42065,10 +44038,10 @@ impl AstNode for Merge {
         &self.syntax
     }
 }
-impl AstNode for MergeCondition {
+impl AstNode for Notify {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_CONDITION
+        kind == SyntaxKind::NOTIFY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42083,10 +44056,10 @@ impl AstNode for MergeCondition {
         &self.syntax
     }
 }
-impl AstNode for MergeDelete {
+impl AstNode for Nowait {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_DELETE
+        kind == SyntaxKind::NOWAIT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42101,10 +44074,10 @@ impl AstNode for MergeDelete {
         &self.syntax
     }
 }
-impl AstNode for MergeDoNothing {
+impl AstNode for NullConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_DO_NOTHING
+        kind == SyntaxKind::NULL_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42119,10 +44092,10 @@ impl AstNode for MergeDoNothing {
         &self.syntax
     }
 }
-impl AstNode for MergeInsert {
+impl AstNode for NullsDistinct {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_INSERT
+        kind == SyntaxKind::NULLS_DISTINCT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42137,10 +44110,10 @@ impl AstNode for MergeInsert {
         &self.syntax
     }
 }
-impl AstNode for MergePartitions {
+impl AstNode for NullsFirst {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_PARTITIONS
+        kind == SyntaxKind::NULLS_FIRST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42155,10 +44128,10 @@ impl AstNode for MergePartitions {
         &self.syntax
     }
 }
-impl AstNode for MergeUpdate {
+impl AstNode for NullsLast {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_UPDATE
+        kind == SyntaxKind::NULLS_LAST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42173,10 +44146,10 @@ impl AstNode for MergeUpdate {
         &self.syntax
     }
 }
-impl AstNode for MergeWhenMatched {
+impl AstNode for NullsNotDistinct {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_WHEN_MATCHED
+        kind == SyntaxKind::NULLS_NOT_DISTINCT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42191,10 +44164,10 @@ impl AstNode for MergeWhenMatched {
         &self.syntax
     }
 }
-impl AstNode for MergeWhenNotMatchedSource {
+impl AstNode for ObjectAccessMethod {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_WHEN_NOT_MATCHED_SOURCE
+        kind == SyntaxKind::OBJECT_ACCESS_METHOD
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42209,10 +44182,10 @@ impl AstNode for MergeWhenNotMatchedSource {
         &self.syntax
     }
 }
-impl AstNode for MergeWhenNotMatchedTarget {
+impl AstNode for ObjectAggregate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MERGE_WHEN_NOT_MATCHED_TARGET
+        kind == SyntaxKind::OBJECT_AGGREGATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42227,10 +44200,10 @@ impl AstNode for MergeWhenNotMatchedTarget {
         &self.syntax
     }
 }
-impl AstNode for Move {
+impl AstNode for ObjectCast {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::MOVE
+        kind == SyntaxKind::OBJECT_CAST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42245,10 +44218,10 @@ impl AstNode for Move {
         &self.syntax
     }
 }
-impl AstNode for NameRef {
+impl AstNode for ObjectCollation {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NAME_REF
+        kind == SyntaxKind::OBJECT_COLLATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42263,10 +44236,10 @@ impl AstNode for NameRef {
         &self.syntax
     }
 }
-impl AstNode for NamedArg {
+impl AstNode for ObjectColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NAMED_ARG
+        kind == SyntaxKind::OBJECT_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42281,10 +44254,10 @@ impl AstNode for NamedArg {
         &self.syntax
     }
 }
-impl AstNode for NamedLabel {
+impl AstNode for ObjectConstraint {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NAMED_LABEL
+        kind == SyntaxKind::OBJECT_CONSTRAINT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42299,10 +44272,10 @@ impl AstNode for NamedLabel {
         &self.syntax
     }
 }
-impl AstNode for NamedSchema {
+impl AstNode for ObjectConversion {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NAMED_SCHEMA
+        kind == SyntaxKind::OBJECT_CONVERSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42317,10 +44290,10 @@ impl AstNode for NamedSchema {
         &self.syntax
     }
 }
-impl AstNode for Neq {
+impl AstNode for ObjectDatabase {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NEQ
+        kind == SyntaxKind::OBJECT_DATABASE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42335,10 +44308,10 @@ impl AstNode for Neq {
         &self.syntax
     }
 }
-impl AstNode for Neqb {
+impl AstNode for ObjectDomain {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NEQB
+        kind == SyntaxKind::OBJECT_DOMAIN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42353,10 +44326,10 @@ impl AstNode for Neqb {
         &self.syntax
     }
 }
-impl AstNode for NewTable {
+impl AstNode for ObjectEventTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NEW_TABLE
+        kind == SyntaxKind::OBJECT_EVENT_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42371,10 +44344,10 @@ impl AstNode for NewTable {
         &self.syntax
     }
 }
-impl AstNode for Next {
+impl AstNode for ObjectExtension {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NEXT
+        kind == SyntaxKind::OBJECT_EXTENSION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42389,10 +44362,10 @@ impl AstNode for Next {
         &self.syntax
     }
 }
-impl AstNode for NoAction {
+impl AstNode for ObjectForeignDataWrapper {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_ACTION
+        kind == SyntaxKind::OBJECT_FOREIGN_DATA_WRAPPER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42407,10 +44380,10 @@ impl AstNode for NoAction {
         &self.syntax
     }
 }
-impl AstNode for NoDependsOnExtension {
+impl AstNode for ObjectForeignTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_DEPENDS_ON_EXTENSION
+        kind == SyntaxKind::OBJECT_FOREIGN_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42425,10 +44398,10 @@ impl AstNode for NoDependsOnExtension {
         &self.syntax
     }
 }
-impl AstNode for NoForceRls {
+impl AstNode for ObjectFunction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_FORCE_RLS
+        kind == SyntaxKind::OBJECT_FUNCTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42443,10 +44416,10 @@ impl AstNode for NoForceRls {
         &self.syntax
     }
 }
-impl AstNode for NoIndent {
+impl AstNode for ObjectIndex {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_INDENT
+        kind == SyntaxKind::OBJECT_INDEX
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42461,10 +44434,10 @@ impl AstNode for NoIndent {
         &self.syntax
     }
 }
-impl AstNode for NoInherit {
+impl AstNode for ObjectLanguage {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_INHERIT
+        kind == SyntaxKind::OBJECT_LANGUAGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42479,10 +44452,10 @@ impl AstNode for NoInherit {
         &self.syntax
     }
 }
-impl AstNode for NoInheritTable {
+impl AstNode for ObjectLargeObject {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_INHERIT_TABLE
+        kind == SyntaxKind::OBJECT_LARGE_OBJECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42497,10 +44470,10 @@ impl AstNode for NoInheritTable {
         &self.syntax
     }
 }
-impl AstNode for NoOthers {
+impl AstNode for ObjectMaterializedView {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_OTHERS
+        kind == SyntaxKind::OBJECT_MATERIALIZED_VIEW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42515,10 +44488,10 @@ impl AstNode for NoOthers {
         &self.syntax
     }
 }
-impl AstNode for NoProperties {
+impl AstNode for ObjectOperator {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_PROPERTIES
+        kind == SyntaxKind::OBJECT_OPERATOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42533,10 +44506,10 @@ impl AstNode for NoProperties {
         &self.syntax
     }
 }
-impl AstNode for NoScroll {
+impl AstNode for ObjectOperatorClass {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NO_SCROLL
+        kind == SyntaxKind::OBJECT_OPERATOR_CLASS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42551,10 +44524,10 @@ impl AstNode for NoScroll {
         &self.syntax
     }
 }
-impl AstNode for NonStandardParam {
+impl AstNode for ObjectOperatorFamily {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NON_STANDARD_PARAM
+        kind == SyntaxKind::OBJECT_OPERATOR_FAMILY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42569,10 +44542,10 @@ impl AstNode for NonStandardParam {
         &self.syntax
     }
 }
-impl AstNode for NotDeferrable {
+impl AstNode for ObjectPolicy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_DEFERRABLE
+        kind == SyntaxKind::OBJECT_POLICY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42587,10 +44560,10 @@ impl AstNode for NotDeferrable {
         &self.syntax
     }
 }
-impl AstNode for NotDeferrableConstraintOption {
+impl AstNode for ObjectProcedure {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_DEFERRABLE_CONSTRAINT_OPTION
+        kind == SyntaxKind::OBJECT_PROCEDURE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42605,10 +44578,10 @@ impl AstNode for NotDeferrableConstraintOption {
         &self.syntax
     }
 }
-impl AstNode for NotEnforced {
+impl AstNode for ObjectPropertyGraph {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_ENFORCED
+        kind == SyntaxKind::OBJECT_PROPERTY_GRAPH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42623,10 +44596,10 @@ impl AstNode for NotEnforced {
         &self.syntax
     }
 }
-impl AstNode for NotIlike {
+impl AstNode for ObjectPublication {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_ILIKE
+        kind == SyntaxKind::OBJECT_PUBLICATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42641,10 +44614,10 @@ impl AstNode for NotIlike {
         &self.syntax
     }
 }
-impl AstNode for NotIn {
+impl AstNode for ObjectRole {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_IN
+        kind == SyntaxKind::OBJECT_ROLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42659,10 +44632,10 @@ impl AstNode for NotIn {
         &self.syntax
     }
 }
-impl AstNode for NotLeakproofFuncOption {
+impl AstNode for ObjectRoutine {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_LEAKPROOF_FUNC_OPTION
+        kind == SyntaxKind::OBJECT_ROUTINE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42677,10 +44650,10 @@ impl AstNode for NotLeakproofFuncOption {
         &self.syntax
     }
 }
-impl AstNode for NotLike {
+impl AstNode for ObjectRule {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_LIKE
+        kind == SyntaxKind::OBJECT_RULE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42695,10 +44668,10 @@ impl AstNode for NotLike {
         &self.syntax
     }
 }
-impl AstNode for NotMaterialized {
+impl AstNode for ObjectSchema {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_MATERIALIZED
+        kind == SyntaxKind::OBJECT_SCHEMA
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42713,10 +44686,10 @@ impl AstNode for NotMaterialized {
         &self.syntax
     }
 }
-impl AstNode for NotNullConstraint {
+impl AstNode for ObjectSequence {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_NULL_CONSTRAINT
+        kind == SyntaxKind::OBJECT_SEQUENCE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42731,10 +44704,10 @@ impl AstNode for NotNullConstraint {
         &self.syntax
     }
 }
-impl AstNode for NotOf {
+impl AstNode for ObjectServer {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_OF
+        kind == SyntaxKind::OBJECT_SERVER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42749,10 +44722,10 @@ impl AstNode for NotOf {
         &self.syntax
     }
 }
-impl AstNode for NotSimilarTo {
+impl AstNode for ObjectStatistics {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_SIMILAR_TO
+        kind == SyntaxKind::OBJECT_STATISTICS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42767,10 +44740,10 @@ impl AstNode for NotSimilarTo {
         &self.syntax
     }
 }
-impl AstNode for NotValid {
+impl AstNode for ObjectSubscription {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOT_VALID
+        kind == SyntaxKind::OBJECT_SUBSCRIPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42785,10 +44758,10 @@ impl AstNode for NotValid {
         &self.syntax
     }
 }
-impl AstNode for Nothing {
+impl AstNode for ObjectTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOTHING
+        kind == SyntaxKind::OBJECT_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42803,10 +44776,10 @@ impl AstNode for Nothing {
         &self.syntax
     }
 }
-impl AstNode for Notify {
+impl AstNode for ObjectTablespace {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOTIFY
+        kind == SyntaxKind::OBJECT_TABLESPACE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42821,10 +44794,10 @@ impl AstNode for Notify {
         &self.syntax
     }
 }
-impl AstNode for Nowait {
+impl AstNode for ObjectTextSearchConfig {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NOWAIT
+        kind == SyntaxKind::OBJECT_TEXT_SEARCH_CONFIG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42839,10 +44812,10 @@ impl AstNode for Nowait {
         &self.syntax
     }
 }
-impl AstNode for NullConstraint {
+impl AstNode for ObjectTextSearchDict {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NULL_CONSTRAINT
+        kind == SyntaxKind::OBJECT_TEXT_SEARCH_DICT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42857,10 +44830,10 @@ impl AstNode for NullConstraint {
         &self.syntax
     }
 }
-impl AstNode for NullsDistinct {
+impl AstNode for ObjectTextSearchParser {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NULLS_DISTINCT
+        kind == SyntaxKind::OBJECT_TEXT_SEARCH_PARSER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42875,10 +44848,10 @@ impl AstNode for NullsDistinct {
         &self.syntax
     }
 }
-impl AstNode for NullsFirst {
+impl AstNode for ObjectTextSearchTemplate {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NULLS_FIRST
+        kind == SyntaxKind::OBJECT_TEXT_SEARCH_TEMPLATE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42893,10 +44866,10 @@ impl AstNode for NullsFirst {
         &self.syntax
     }
 }
-impl AstNode for NullsLast {
+impl AstNode for ObjectTransform {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NULLS_LAST
+        kind == SyntaxKind::OBJECT_TRANSFORM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42911,10 +44884,10 @@ impl AstNode for NullsLast {
         &self.syntax
     }
 }
-impl AstNode for NullsNotDistinct {
+impl AstNode for ObjectTrigger {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::NULLS_NOT_DISTINCT
+        kind == SyntaxKind::OBJECT_TRIGGER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42929,10 +44902,10 @@ impl AstNode for NullsNotDistinct {
         &self.syntax
     }
 }
-impl AstNode for ObjectAccessMethod {
+impl AstNode for ObjectType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_ACCESS_METHOD
+        kind == SyntaxKind::OBJECT_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42947,10 +44920,10 @@ impl AstNode for ObjectAccessMethod {
         &self.syntax
     }
 }
-impl AstNode for ObjectAggregate {
+impl AstNode for ObjectView {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_AGGREGATE
+        kind == SyntaxKind::OBJECT_VIEW
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42965,10 +44938,10 @@ impl AstNode for ObjectAggregate {
         &self.syntax
     }
 }
-impl AstNode for ObjectCast {
+impl AstNode for OfType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_CAST
+        kind == SyntaxKind::OF_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -42983,10 +44956,10 @@ impl AstNode for ObjectCast {
         &self.syntax
     }
 }
-impl AstNode for ObjectCollation {
+impl AstNode for OffsetClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_COLLATION
+        kind == SyntaxKind::OFFSET_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43001,10 +44974,10 @@ impl AstNode for ObjectCollation {
         &self.syntax
     }
 }
-impl AstNode for ObjectColumn {
+impl AstNode for OldTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_COLUMN
+        kind == SyntaxKind::OLD_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43019,10 +44992,10 @@ impl AstNode for ObjectColumn {
         &self.syntax
     }
 }
-impl AstNode for ObjectConstraint {
+impl AstNode for OmitQuotes {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_CONSTRAINT
+        kind == SyntaxKind::OMIT_QUOTES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43037,10 +45010,10 @@ impl AstNode for ObjectConstraint {
         &self.syntax
     }
 }
-impl AstNode for ObjectConversion {
+impl AstNode for OnClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_CONVERSION
+        kind == SyntaxKind::ON_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43055,10 +45028,10 @@ impl AstNode for ObjectConversion {
         &self.syntax
     }
 }
-impl AstNode for ObjectDatabase {
+impl AstNode for OnCommit {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_DATABASE
+        kind == SyntaxKind::ON_COMMIT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43073,10 +45046,10 @@ impl AstNode for ObjectDatabase {
         &self.syntax
     }
 }
-impl AstNode for ObjectDomain {
+impl AstNode for OnConflictClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_DOMAIN
+        kind == SyntaxKind::ON_CONFLICT_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43091,10 +45064,10 @@ impl AstNode for ObjectDomain {
         &self.syntax
     }
 }
-impl AstNode for ObjectEventTrigger {
+impl AstNode for OnDeleteAction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_EVENT_TRIGGER
+        kind == SyntaxKind::ON_DELETE_ACTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43109,10 +45082,10 @@ impl AstNode for ObjectEventTrigger {
         &self.syntax
     }
 }
-impl AstNode for ObjectExtension {
+impl AstNode for OnPath {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_EXTENSION
+        kind == SyntaxKind::ON_PATH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43127,10 +45100,10 @@ impl AstNode for ObjectExtension {
         &self.syntax
     }
 }
-impl AstNode for ObjectForeignDataWrapper {
+impl AstNode for OnPrivilegeObjectsClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_FOREIGN_DATA_WRAPPER
+        kind == SyntaxKind::ON_PRIVILEGE_OBJECTS_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43145,10 +45118,10 @@ impl AstNode for ObjectForeignDataWrapper {
         &self.syntax
     }
 }
-impl AstNode for ObjectForeignTable {
+impl AstNode for OnRelation {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_FOREIGN_TABLE
+        kind == SyntaxKind::ON_RELATION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43163,10 +45136,10 @@ impl AstNode for ObjectForeignTable {
         &self.syntax
     }
 }
-impl AstNode for ObjectFunction {
+impl AstNode for OnScalarString {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_FUNCTION
+        kind == SyntaxKind::ON_SCALAR_STRING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43181,10 +45154,10 @@ impl AstNode for ObjectFunction {
         &self.syntax
     }
 }
-impl AstNode for ObjectIndex {
+impl AstNode for OnTable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_INDEX
+        kind == SyntaxKind::ON_TABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43199,10 +45172,10 @@ impl AstNode for ObjectIndex {
         &self.syntax
     }
 }
-impl AstNode for ObjectLanguage {
+impl AstNode for OnUpdateAction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_LANGUAGE
+        kind == SyntaxKind::ON_UPDATE_ACTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43217,10 +45190,10 @@ impl AstNode for ObjectLanguage {
         &self.syntax
     }
 }
-impl AstNode for ObjectLargeObject {
+impl AstNode for Op {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_LARGE_OBJECT
+        kind == SyntaxKind::OP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43235,10 +45208,10 @@ impl AstNode for ObjectLargeObject {
         &self.syntax
     }
 }
-impl AstNode for ObjectMaterializedView {
+impl AstNode for OpClassForOrderBy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_MATERIALIZED_VIEW
+        kind == SyntaxKind::OP_CLASS_FOR_ORDER_BY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43253,10 +45226,10 @@ impl AstNode for ObjectMaterializedView {
         &self.syntax
     }
 }
-impl AstNode for ObjectOperator {
+impl AstNode for OpClassForSearch {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_OPERATOR
+        kind == SyntaxKind::OP_CLASS_FOR_SEARCH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43271,10 +45244,10 @@ impl AstNode for ObjectOperator {
         &self.syntax
     }
 }
-impl AstNode for ObjectOperatorClass {
+impl AstNode for OpClassName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_OPERATOR_CLASS
+        kind == SyntaxKind::OP_CLASS_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43289,10 +45262,10 @@ impl AstNode for ObjectOperatorClass {
         &self.syntax
     }
 }
-impl AstNode for ObjectOperatorFamily {
+impl AstNode for OpClassOptionFunction {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_OPERATOR_FAMILY
+        kind == SyntaxKind::OP_CLASS_OPTION_FUNCTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43307,10 +45280,10 @@ impl AstNode for ObjectOperatorFamily {
         &self.syntax
     }
 }
-impl AstNode for ObjectPolicy {
+impl AstNode for OpClassOptionOperator {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_POLICY
+        kind == SyntaxKind::OP_CLASS_OPTION_OPERATOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43325,10 +45298,10 @@ impl AstNode for ObjectPolicy {
         &self.syntax
     }
 }
-impl AstNode for ObjectProcedure {
+impl AstNode for OpClassOptionStorage {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_PROCEDURE
+        kind == SyntaxKind::OP_CLASS_OPTION_STORAGE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43343,10 +45316,10 @@ impl AstNode for ObjectProcedure {
         &self.syntax
     }
 }
-impl AstNode for ObjectPropertyGraph {
+impl AstNode for OpClassRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_PROPERTY_GRAPH
+        kind == SyntaxKind::OP_CLASS_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43361,10 +45334,10 @@ impl AstNode for ObjectPropertyGraph {
         &self.syntax
     }
 }
-impl AstNode for ObjectPublication {
+impl AstNode for OpClassRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_PUBLICATION
+        kind == SyntaxKind::OP_CLASS_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43379,10 +45352,10 @@ impl AstNode for ObjectPublication {
         &self.syntax
     }
 }
-impl AstNode for ObjectRole {
+impl AstNode for OpFamilyName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_ROLE
+        kind == SyntaxKind::OP_FAMILY_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43397,10 +45370,10 @@ impl AstNode for ObjectRole {
         &self.syntax
     }
 }
-impl AstNode for ObjectRoutine {
+impl AstNode for OpFamilyRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_ROUTINE
+        kind == SyntaxKind::OP_FAMILY_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43415,10 +45388,10 @@ impl AstNode for ObjectRoutine {
         &self.syntax
     }
 }
-impl AstNode for ObjectRule {
+impl AstNode for OpFamilyRenameTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_RULE
+        kind == SyntaxKind::OP_FAMILY_RENAME_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43433,10 +45406,10 @@ impl AstNode for ObjectRule {
         &self.syntax
     }
 }
-impl AstNode for ObjectSchema {
+impl AstNode for OpSig {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_SCHEMA
+        kind == SyntaxKind::OP_SIG
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43451,10 +45424,10 @@ impl AstNode for ObjectSchema {
         &self.syntax
     }
 }
-impl AstNode for ObjectSequence {
+impl AstNode for OpSigList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_SEQUENCE
+        kind == SyntaxKind::OP_SIG_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43469,10 +45442,10 @@ impl AstNode for ObjectSequence {
         &self.syntax
     }
 }
-impl AstNode for ObjectServer {
+impl AstNode for OperatorCall {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_SERVER
+        kind == SyntaxKind::OPERATOR_CALL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43487,10 +45460,10 @@ impl AstNode for ObjectServer {
         &self.syntax
     }
 }
-impl AstNode for ObjectStatistics {
+impl AstNode for OperatorClassOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_STATISTICS
+        kind == SyntaxKind::OPERATOR_CLASS_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43505,10 +45478,10 @@ impl AstNode for ObjectStatistics {
         &self.syntax
     }
 }
-impl AstNode for ObjectSubscription {
+impl AstNode for OperatorFamilyClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_SUBSCRIPTION
+        kind == SyntaxKind::OPERATOR_FAMILY_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43523,10 +45496,10 @@ impl AstNode for ObjectSubscription {
         &self.syntax
     }
 }
-impl AstNode for ObjectTable {
+impl AstNode for OptionAlterOptionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TABLE
+        kind == SyntaxKind::OPTION_ALTER_OPTION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43541,10 +45514,10 @@ impl AstNode for ObjectTable {
         &self.syntax
     }
 }
-impl AstNode for ObjectTablespace {
+impl AstNode for OptionAsType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TABLESPACE
+        kind == SyntaxKind::OPTION_AS_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43559,10 +45532,10 @@ impl AstNode for ObjectTablespace {
         &self.syntax
     }
 }
-impl AstNode for ObjectTextSearchConfig {
+impl AstNode for OptionCache {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TEXT_SEARCH_CONFIG
+        kind == SyntaxKind::OPTION_CACHE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43577,10 +45550,10 @@ impl AstNode for ObjectTextSearchConfig {
         &self.syntax
     }
 }
-impl AstNode for ObjectTextSearchDict {
+impl AstNode for OptionConnection {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TEXT_SEARCH_DICT
+        kind == SyntaxKind::OPTION_CONNECTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43595,10 +45568,10 @@ impl AstNode for ObjectTextSearchDict {
         &self.syntax
     }
 }
-impl AstNode for ObjectTextSearchParser {
+impl AstNode for OptionCycle {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TEXT_SEARCH_PARSER
+        kind == SyntaxKind::OPTION_CYCLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43613,10 +45586,10 @@ impl AstNode for ObjectTextSearchParser {
         &self.syntax
     }
 }
-impl AstNode for ObjectTextSearchTemplate {
+impl AstNode for OptionDefault {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TEXT_SEARCH_TEMPLATE
+        kind == SyntaxKind::OPTION_DEFAULT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43631,10 +45604,10 @@ impl AstNode for ObjectTextSearchTemplate {
         &self.syntax
     }
 }
-impl AstNode for ObjectTransform {
+impl AstNode for OptionHandler {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TRANSFORM
+        kind == SyntaxKind::OPTION_HANDLER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43649,10 +45622,10 @@ impl AstNode for ObjectTransform {
         &self.syntax
     }
 }
-impl AstNode for ObjectTrigger {
+impl AstNode for OptionIdent {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TRIGGER
+        kind == SyntaxKind::OPTION_IDENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43667,10 +45640,10 @@ impl AstNode for ObjectTrigger {
         &self.syntax
     }
 }
-impl AstNode for ObjectType {
+impl AstNode for OptionIncrement {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_TYPE
+        kind == SyntaxKind::OPTION_INCREMENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43685,10 +45658,10 @@ impl AstNode for ObjectType {
         &self.syntax
     }
 }
-impl AstNode for ObjectView {
+impl AstNode for OptionItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OBJECT_VIEW
+        kind == SyntaxKind::OPTION_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43703,10 +45676,10 @@ impl AstNode for ObjectView {
         &self.syntax
     }
 }
-impl AstNode for OfType {
+impl AstNode for OptionItemKey {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OF_TYPE
+        kind == SyntaxKind::OPTION_ITEM_KEY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43721,10 +45694,10 @@ impl AstNode for OfType {
         &self.syntax
     }
 }
-impl AstNode for OffsetClause {
+impl AstNode for OptionItemList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OFFSET_CLAUSE
+        kind == SyntaxKind::OPTION_ITEM_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43739,10 +45712,10 @@ impl AstNode for OffsetClause {
         &self.syntax
     }
 }
-impl AstNode for OldTable {
+impl AstNode for OptionItemValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OLD_TABLE
+        kind == SyntaxKind::OPTION_ITEM_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43757,10 +45730,10 @@ impl AstNode for OldTable {
         &self.syntax
     }
 }
-impl AstNode for OmitQuotes {
+impl AstNode for OptionItemValueName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OMIT_QUOTES
+        kind == SyntaxKind::OPTION_ITEM_VALUE_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43775,10 +45748,10 @@ impl AstNode for OmitQuotes {
         &self.syntax
     }
 }
-impl AstNode for OnClause {
+impl AstNode for OptionLogged {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_CLAUSE
+        kind == SyntaxKind::OPTION_LOGGED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43793,10 +45766,10 @@ impl AstNode for OnClause {
         &self.syntax
     }
 }
-impl AstNode for OnCommit {
+impl AstNode for OptionMaxValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_COMMIT
+        kind == SyntaxKind::OPTION_MAX_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43811,10 +45784,10 @@ impl AstNode for OnCommit {
         &self.syntax
     }
 }
-impl AstNode for OnConflictClause {
+impl AstNode for OptionMinValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_CONFLICT_CLAUSE
+        kind == SyntaxKind::OPTION_MIN_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43829,10 +45802,10 @@ impl AstNode for OnConflictClause {
         &self.syntax
     }
 }
-impl AstNode for OnDeleteAction {
+impl AstNode for OptionNoConnection {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_DELETE_ACTION
+        kind == SyntaxKind::OPTION_NO_CONNECTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43847,10 +45820,10 @@ impl AstNode for OnDeleteAction {
         &self.syntax
     }
 }
-impl AstNode for OnPath {
+impl AstNode for OptionNoCycle {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_PATH
+        kind == SyntaxKind::OPTION_NO_CYCLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43865,10 +45838,10 @@ impl AstNode for OnPath {
         &self.syntax
     }
 }
-impl AstNode for OnPrivilegeObjectsClause {
+impl AstNode for OptionNoHandler {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_PRIVILEGE_OBJECTS_CLAUSE
+        kind == SyntaxKind::OPTION_NO_HANDLER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43883,10 +45856,10 @@ impl AstNode for OnPrivilegeObjectsClause {
         &self.syntax
     }
 }
-impl AstNode for OnRelation {
+impl AstNode for OptionNoMaxValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_RELATION
+        kind == SyntaxKind::OPTION_NO_MAX_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43901,10 +45874,10 @@ impl AstNode for OnRelation {
         &self.syntax
     }
 }
-impl AstNode for OnScalarString {
+impl AstNode for OptionNoMinValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_SCALAR_STRING
+        kind == SyntaxKind::OPTION_NO_MIN_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43919,10 +45892,10 @@ impl AstNode for OnScalarString {
         &self.syntax
     }
 }
-impl AstNode for OnTable {
+impl AstNode for OptionNoValidator {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_TABLE
+        kind == SyntaxKind::OPTION_NO_VALIDATOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43937,10 +45910,10 @@ impl AstNode for OnTable {
         &self.syntax
     }
 }
-impl AstNode for OnUpdateAction {
+impl AstNode for OptionNotNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ON_UPDATE_ACTION
+        kind == SyntaxKind::OPTION_NOT_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43955,10 +45928,10 @@ impl AstNode for OnUpdateAction {
         &self.syntax
     }
 }
-impl AstNode for Op {
+impl AstNode for OptionNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP
+        kind == SyntaxKind::OPTION_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43973,10 +45946,10 @@ impl AstNode for Op {
         &self.syntax
     }
 }
-impl AstNode for OpClassForOrderBy {
+impl AstNode for OptionOwnedBy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_CLASS_FOR_ORDER_BY
+        kind == SyntaxKind::OPTION_OWNED_BY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -43991,10 +45964,10 @@ impl AstNode for OpClassForOrderBy {
         &self.syntax
     }
 }
-impl AstNode for OpClassForSearch {
+impl AstNode for OptionPath {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_CLASS_FOR_SEARCH
+        kind == SyntaxKind::OPTION_PATH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44009,10 +45982,10 @@ impl AstNode for OpClassForSearch {
         &self.syntax
     }
 }
-impl AstNode for OpClassName {
+impl AstNode for OptionRestart {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_CLASS_NAME
+        kind == SyntaxKind::OPTION_RESTART
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44027,10 +46000,10 @@ impl AstNode for OpClassName {
         &self.syntax
     }
 }
-impl AstNode for OpClassOptionFunction {
+impl AstNode for OptionSequenceName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_CLASS_OPTION_FUNCTION
+        kind == SyntaxKind::OPTION_SEQUENCE_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44045,10 +46018,10 @@ impl AstNode for OpClassOptionFunction {
         &self.syntax
     }
 }
-impl AstNode for OpClassOptionOperator {
+impl AstNode for OptionStart {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_CLASS_OPTION_OPERATOR
+        kind == SyntaxKind::OPTION_START
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44063,10 +46036,10 @@ impl AstNode for OpClassOptionOperator {
         &self.syntax
     }
 }
-impl AstNode for OpClassOptionStorage {
+impl AstNode for OptionUnlogged {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_CLASS_OPTION_STORAGE
+        kind == SyntaxKind::OPTION_UNLOGGED
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44081,10 +46054,10 @@ impl AstNode for OpClassOptionStorage {
         &self.syntax
     }
 }
-impl AstNode for OpClassRef {
+impl AstNode for OptionValidator {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_CLASS_REF
+        kind == SyntaxKind::OPTION_VALIDATOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44099,10 +46072,10 @@ impl AstNode for OpClassRef {
         &self.syntax
     }
 }
-impl AstNode for OpClassRenameTo {
+impl AstNode for OptionalAsAlias {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_CLASS_RENAME_TO
+        kind == SyntaxKind::OPTIONAL_AS_ALIAS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44117,10 +46090,10 @@ impl AstNode for OpClassRenameTo {
         &self.syntax
     }
 }
-impl AstNode for OpFamilyName {
+impl AstNode for OrReplace {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_FAMILY_NAME
+        kind == SyntaxKind::OR_REPLACE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44135,10 +46108,10 @@ impl AstNode for OpFamilyName {
         &self.syntax
     }
 }
-impl AstNode for OpFamilyRef {
+impl AstNode for OrderByClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_FAMILY_REF
+        kind == SyntaxKind::ORDER_BY_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44153,10 +46126,10 @@ impl AstNode for OpFamilyRef {
         &self.syntax
     }
 }
-impl AstNode for OpFamilyRenameTo {
+impl AstNode for OverClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_FAMILY_RENAME_TO
+        kind == SyntaxKind::OVER_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44171,10 +46144,10 @@ impl AstNode for OpFamilyRenameTo {
         &self.syntax
     }
 }
-impl AstNode for OpSig {
+impl AstNode for OverWindowSpec {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_SIG
+        kind == SyntaxKind::OVER_WINDOW_SPEC
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44189,10 +46162,10 @@ impl AstNode for OpSig {
         &self.syntax
     }
 }
-impl AstNode for OpSigList {
+impl AstNode for OverlayExprs {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OP_SIG_LIST
+        kind == SyntaxKind::OVERLAY_EXPRS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44207,10 +46180,10 @@ impl AstNode for OpSigList {
         &self.syntax
     }
 }
-impl AstNode for OperatorCall {
+impl AstNode for OverlayFn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPERATOR_CALL
+        kind == SyntaxKind::OVERLAY_FN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44225,10 +46198,10 @@ impl AstNode for OperatorCall {
         &self.syntax
     }
 }
-impl AstNode for OperatorClassOptionList {
+impl AstNode for OverlayPlacing {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPERATOR_CLASS_OPTION_LIST
+        kind == SyntaxKind::OVERLAY_PLACING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44243,10 +46216,10 @@ impl AstNode for OperatorClassOptionList {
         &self.syntax
     }
 }
-impl AstNode for OperatorFamilyClause {
+impl AstNode for OverridingSystemValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPERATOR_FAMILY_CLAUSE
+        kind == SyntaxKind::OVERRIDING_SYSTEM_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44261,10 +46234,10 @@ impl AstNode for OperatorFamilyClause {
         &self.syntax
     }
 }
-impl AstNode for OptionAlterOptionList {
+impl AstNode for OverridingUserValue {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_ALTER_OPTION_LIST
+        kind == SyntaxKind::OVERRIDING_USER_VALUE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44279,10 +46252,10 @@ impl AstNode for OptionAlterOptionList {
         &self.syntax
     }
 }
-impl AstNode for OptionAsType {
+impl AstNode for OwnedByNone {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_AS_TYPE
+        kind == SyntaxKind::OWNED_BY_NONE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44297,10 +46270,10 @@ impl AstNode for OptionAsType {
         &self.syntax
     }
 }
-impl AstNode for OptionCache {
+impl AstNode for OwnedByRoles {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_CACHE
+        kind == SyntaxKind::OWNED_BY_ROLES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44315,10 +46288,10 @@ impl AstNode for OptionCache {
         &self.syntax
     }
 }
-impl AstNode for OptionConnection {
+impl AstNode for OwnerTo {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_CONNECTION
+        kind == SyntaxKind::OWNER_TO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44333,10 +46306,10 @@ impl AstNode for OptionConnection {
         &self.syntax
     }
 }
-impl AstNode for OptionCycle {
+impl AstNode for ParallelFuncOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_CYCLE
+        kind == SyntaxKind::PARALLEL_FUNC_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44351,10 +46324,10 @@ impl AstNode for OptionCycle {
         &self.syntax
     }
 }
-impl AstNode for OptionDefault {
+impl AstNode for Param {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_DEFAULT
+        kind == SyntaxKind::PARAM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44369,10 +46342,10 @@ impl AstNode for OptionDefault {
         &self.syntax
     }
 }
-impl AstNode for OptionHandler {
+impl AstNode for ParamDefault {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_HANDLER
+        kind == SyntaxKind::PARAM_DEFAULT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44387,10 +46360,10 @@ impl AstNode for OptionHandler {
         &self.syntax
     }
 }
-impl AstNode for OptionIdent {
+impl AstNode for ParamIn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_IDENT
+        kind == SyntaxKind::PARAM_IN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44405,10 +46378,10 @@ impl AstNode for OptionIdent {
         &self.syntax
     }
 }
-impl AstNode for OptionIncrement {
+impl AstNode for ParamInOut {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_INCREMENT
+        kind == SyntaxKind::PARAM_IN_OUT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44423,10 +46396,10 @@ impl AstNode for OptionIncrement {
         &self.syntax
     }
 }
-impl AstNode for OptionItem {
+impl AstNode for ParamList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_ITEM
+        kind == SyntaxKind::PARAM_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44441,10 +46414,10 @@ impl AstNode for OptionItem {
         &self.syntax
     }
 }
-impl AstNode for OptionItemKey {
+impl AstNode for ParamName {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_ITEM_KEY
+        kind == SyntaxKind::PARAM_NAME
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44459,10 +46432,10 @@ impl AstNode for OptionItemKey {
         &self.syntax
     }
 }
-impl AstNode for OptionItemList {
+impl AstNode for ParamNameRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_ITEM_LIST
+        kind == SyntaxKind::PARAM_NAME_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44477,10 +46450,10 @@ impl AstNode for OptionItemList {
         &self.syntax
     }
 }
-impl AstNode for OptionItemValue {
+impl AstNode for ParamOut {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_ITEM_VALUE
+        kind == SyntaxKind::PARAM_OUT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44495,10 +46468,10 @@ impl AstNode for OptionItemValue {
         &self.syntax
     }
 }
-impl AstNode for OptionItemValueName {
+impl AstNode for ParamVariadic {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_ITEM_VALUE_NAME
+        kind == SyntaxKind::PARAM_VARIADIC
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44513,10 +46486,10 @@ impl AstNode for OptionItemValueName {
         &self.syntax
     }
 }
-impl AstNode for OptionLogged {
+impl AstNode for ParenExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_LOGGED
+        kind == SyntaxKind::PAREN_EXPR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44531,10 +46504,10 @@ impl AstNode for OptionLogged {
         &self.syntax
     }
 }
-impl AstNode for OptionMaxValue {
+impl AstNode for ParenFromItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_MAX_VALUE
+        kind == SyntaxKind::PAREN_FROM_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44549,10 +46522,10 @@ impl AstNode for OptionMaxValue {
         &self.syntax
     }
 }
-impl AstNode for OptionMinValue {
+impl AstNode for ParenGraphPattern {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_MIN_VALUE
+        kind == SyntaxKind::PAREN_GRAPH_PATTERN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44567,10 +46540,10 @@ impl AstNode for OptionMinValue {
         &self.syntax
     }
 }
-impl AstNode for OptionNoConnection {
+impl AstNode for ParenJsonTablePlan {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_NO_CONNECTION
+        kind == SyntaxKind::PAREN_JSON_TABLE_PLAN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44585,10 +46558,10 @@ impl AstNode for OptionNoConnection {
         &self.syntax
     }
 }
-impl AstNode for OptionNoCycle {
+impl AstNode for ParenSelect {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_NO_CYCLE
+        kind == SyntaxKind::PAREN_SELECT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44603,10 +46576,10 @@ impl AstNode for OptionNoCycle {
         &self.syntax
     }
 }
-impl AstNode for OptionNoHandler {
+impl AstNode for Partition {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_NO_HANDLER
+        kind == SyntaxKind::PARTITION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44621,10 +46594,10 @@ impl AstNode for OptionNoHandler {
         &self.syntax
     }
 }
-impl AstNode for OptionNoMaxValue {
+impl AstNode for PartitionBy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_NO_MAX_VALUE
+        kind == SyntaxKind::PARTITION_BY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44639,10 +46612,10 @@ impl AstNode for OptionNoMaxValue {
         &self.syntax
     }
 }
-impl AstNode for OptionNoMinValue {
+impl AstNode for PartitionByClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_NO_MIN_VALUE
+        kind == SyntaxKind::PARTITION_BY_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44657,10 +46630,10 @@ impl AstNode for OptionNoMinValue {
         &self.syntax
     }
 }
-impl AstNode for OptionNoValidator {
+impl AstNode for PartitionDefault {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_NO_VALIDATOR
+        kind == SyntaxKind::PARTITION_DEFAULT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44675,10 +46648,10 @@ impl AstNode for OptionNoValidator {
         &self.syntax
     }
 }
-impl AstNode for OptionNotNull {
+impl AstNode for PartitionForValuesFrom {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_NOT_NULL
+        kind == SyntaxKind::PARTITION_FOR_VALUES_FROM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44693,10 +46666,10 @@ impl AstNode for OptionNotNull {
         &self.syntax
     }
 }
-impl AstNode for OptionNull {
+impl AstNode for PartitionForValuesIn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_NULL
+        kind == SyntaxKind::PARTITION_FOR_VALUES_IN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44711,10 +46684,10 @@ impl AstNode for OptionNull {
         &self.syntax
     }
 }
-impl AstNode for OptionOwnedBy {
+impl AstNode for PartitionForValuesWith {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_OWNED_BY
+        kind == SyntaxKind::PARTITION_FOR_VALUES_WITH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44729,10 +46702,10 @@ impl AstNode for OptionOwnedBy {
         &self.syntax
     }
 }
-impl AstNode for OptionPath {
+impl AstNode for PartitionFromValues {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_PATH
+        kind == SyntaxKind::PARTITION_FROM_VALUES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44747,10 +46720,10 @@ impl AstNode for OptionPath {
         &self.syntax
     }
 }
-impl AstNode for OptionRestart {
+impl AstNode for PartitionItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_RESTART
+        kind == SyntaxKind::PARTITION_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44765,10 +46738,10 @@ impl AstNode for OptionRestart {
         &self.syntax
     }
 }
-impl AstNode for OptionSequenceName {
+impl AstNode for PartitionItemList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_SEQUENCE_NAME
+        kind == SyntaxKind::PARTITION_ITEM_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44783,10 +46756,10 @@ impl AstNode for OptionSequenceName {
         &self.syntax
     }
 }
-impl AstNode for OptionStart {
+impl AstNode for PartitionList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_START
+        kind == SyntaxKind::PARTITION_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44801,10 +46774,10 @@ impl AstNode for OptionStart {
         &self.syntax
     }
 }
-impl AstNode for OptionUnlogged {
+impl AstNode for PartitionModulus {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_UNLOGGED
+        kind == SyntaxKind::PARTITION_MODULUS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44819,10 +46792,10 @@ impl AstNode for OptionUnlogged {
         &self.syntax
     }
 }
-impl AstNode for OptionValidator {
+impl AstNode for PartitionOf {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTION_VALIDATOR
+        kind == SyntaxKind::PARTITION_OF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44837,10 +46810,10 @@ impl AstNode for OptionValidator {
         &self.syntax
     }
 }
-impl AstNode for OptionalAsAlias {
+impl AstNode for PartitionRemainder {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OPTIONAL_AS_ALIAS
+        kind == SyntaxKind::PARTITION_REMAINDER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44855,10 +46828,10 @@ impl AstNode for OptionalAsAlias {
         &self.syntax
     }
 }
-impl AstNode for OrReplace {
+impl AstNode for PartitionStrategy {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OR_REPLACE
+        kind == SyntaxKind::PARTITION_STRATEGY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44873,10 +46846,10 @@ impl AstNode for OrReplace {
         &self.syntax
     }
 }
-impl AstNode for OrderByClause {
+impl AstNode for PartitionToValues {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::ORDER_BY_CLAUSE
+        kind == SyntaxKind::PARTITION_TO_VALUES
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44891,10 +46864,10 @@ impl AstNode for OrderByClause {
         &self.syntax
     }
 }
-impl AstNode for OverClause {
+impl AstNode for Path {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OVER_CLAUSE
+        kind == SyntaxKind::PATH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44909,10 +46882,10 @@ impl AstNode for OverClause {
         &self.syntax
     }
 }
-impl AstNode for OverWindowSpec {
+impl AstNode for PathFactor {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OVER_WINDOW_SPEC
+        kind == SyntaxKind::PATH_FACTOR
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44927,10 +46900,10 @@ impl AstNode for OverWindowSpec {
         &self.syntax
     }
 }
-impl AstNode for OverlayExprs {
+impl AstNode for PathPattern {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OVERLAY_EXPRS
+        kind == SyntaxKind::PATH_PATTERN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44945,10 +46918,10 @@ impl AstNode for OverlayExprs {
         &self.syntax
     }
 }
-impl AstNode for OverlayFn {
+impl AstNode for PathPatternList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OVERLAY_FN
+        kind == SyntaxKind::PATH_PATTERN_LIST
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44963,10 +46936,10 @@ impl AstNode for OverlayFn {
         &self.syntax
     }
 }
-impl AstNode for OverlayPlacing {
+impl AstNode for PathRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OVERLAY_PLACING
+        kind == SyntaxKind::PATH_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44981,10 +46954,10 @@ impl AstNode for OverlayPlacing {
         &self.syntax
     }
 }
-impl AstNode for OverridingSystemValue {
+impl AstNode for PathSegment {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OVERRIDING_SYSTEM_VALUE
+        kind == SyntaxKind::PATH_SEGMENT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -44999,10 +46972,10 @@ impl AstNode for OverridingSystemValue {
         &self.syntax
     }
 }
-impl AstNode for OverridingUserValue {
+impl AstNode for PathSegmentRef {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OVERRIDING_USER_VALUE
+        kind == SyntaxKind::PATH_SEGMENT_REF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45017,10 +46990,10 @@ impl AstNode for OverridingUserValue {
         &self.syntax
     }
 }
-impl AstNode for OwnedByNone {
+impl AstNode for PathType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OWNED_BY_NONE
+        kind == SyntaxKind::PATH_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45035,10 +47008,10 @@ impl AstNode for OwnedByNone {
         &self.syntax
     }
 }
-impl AstNode for OwnedByRoles {
+impl AstNode for PercentType {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OWNED_BY_ROLES
+        kind == SyntaxKind::PERCENT_TYPE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45053,10 +47026,10 @@ impl AstNode for OwnedByRoles {
         &self.syntax
     }
 }
-impl AstNode for OwnerTo {
+impl AstNode for PercentTypeClause {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::OWNER_TO
+        kind == SyntaxKind::PERCENT_TYPE_CLAUSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45071,10 +47044,10 @@ impl AstNode for OwnerTo {
         &self.syntax
     }
 }
-impl AstNode for ParallelFuncOption {
+impl AstNode for PeriodColumn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARALLEL_FUNC_OPTION
+        kind == SyntaxKind::PERIOD_COLUMN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45089,10 +47062,10 @@ impl AstNode for ParallelFuncOption {
         &self.syntax
     }
 }
-impl AstNode for Param {
+impl AstNode for PlpgsqlAliasDecl {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM
+        kind == SyntaxKind::PLPGSQL_ALIAS_DECL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45107,10 +47080,10 @@ impl AstNode for Param {
         &self.syntax
     }
 }
-impl AstNode for ParamDefault {
+impl AstNode for PlpgsqlAssert {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM_DEFAULT
+        kind == SyntaxKind::PLPGSQL_ASSERT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45125,10 +47098,10 @@ impl AstNode for ParamDefault {
         &self.syntax
     }
 }
-impl AstNode for ParamIn {
+impl AstNode for PlpgsqlAssign {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM_IN
+        kind == SyntaxKind::PLPGSQL_ASSIGN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45143,10 +47116,10 @@ impl AstNode for ParamIn {
         &self.syntax
     }
 }
-impl AstNode for ParamInOut {
+impl AstNode for PlpgsqlBlock {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM_IN_OUT
+        kind == SyntaxKind::PLPGSQL_BLOCK
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45161,10 +47134,10 @@ impl AstNode for ParamInOut {
         &self.syntax
     }
 }
-impl AstNode for ParamList {
+impl AstNode for PlpgsqlCase {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM_LIST
+        kind == SyntaxKind::PLPGSQL_CASE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45179,10 +47152,10 @@ impl AstNode for ParamList {
         &self.syntax
     }
 }
-impl AstNode for ParamName {
+impl AstNode for PlpgsqlCaseWhen {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM_NAME
+        kind == SyntaxKind::PLPGSQL_CASE_WHEN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45197,10 +47170,10 @@ impl AstNode for ParamName {
         &self.syntax
     }
 }
-impl AstNode for ParamNameRef {
+impl AstNode for PlpgsqlCondition {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM_NAME_REF
+        kind == SyntaxKind::PLPGSQL_CONDITION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45215,10 +47188,10 @@ impl AstNode for ParamNameRef {
         &self.syntax
     }
 }
-impl AstNode for ParamOut {
+impl AstNode for PlpgsqlCursorDecl {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM_OUT
+        kind == SyntaxKind::PLPGSQL_CURSOR_DECL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45233,10 +47206,10 @@ impl AstNode for ParamOut {
         &self.syntax
     }
 }
-impl AstNode for ParamVariadic {
+impl AstNode for PlpgsqlDeclareSection {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARAM_VARIADIC
+        kind == SyntaxKind::PLPGSQL_DECLARE_SECTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45251,10 +47224,10 @@ impl AstNode for ParamVariadic {
         &self.syntax
     }
 }
-impl AstNode for ParenExpr {
+impl AstNode for PlpgsqlDefault {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PAREN_EXPR
+        kind == SyntaxKind::PLPGSQL_DEFAULT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45269,10 +47242,10 @@ impl AstNode for ParenExpr {
         &self.syntax
     }
 }
-impl AstNode for ParenFromItem {
+impl AstNode for PlpgsqlDiagnosticsItem {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PAREN_FROM_ITEM
+        kind == SyntaxKind::PLPGSQL_DIAGNOSTICS_ITEM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45287,10 +47260,10 @@ impl AstNode for ParenFromItem {
         &self.syntax
     }
 }
-impl AstNode for ParenGraphPattern {
+impl AstNode for PlpgsqlDynamicQuery {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PAREN_GRAPH_PATTERN
+        kind == SyntaxKind::PLPGSQL_DYNAMIC_QUERY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45305,10 +47278,10 @@ impl AstNode for ParenGraphPattern {
         &self.syntax
     }
 }
-impl AstNode for ParenJsonTablePlan {
+impl AstNode for PlpgsqlElse {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PAREN_JSON_TABLE_PLAN
+        kind == SyntaxKind::PLPGSQL_ELSE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45323,10 +47296,10 @@ impl AstNode for ParenJsonTablePlan {
         &self.syntax
     }
 }
-impl AstNode for ParenSelect {
+impl AstNode for PlpgsqlElsif {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PAREN_SELECT
+        kind == SyntaxKind::PLPGSQL_ELSIF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45341,10 +47314,10 @@ impl AstNode for ParenSelect {
         &self.syntax
     }
 }
-impl AstNode for Partition {
+impl AstNode for PlpgsqlEndLoop {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION
+        kind == SyntaxKind::PLPGSQL_END_LOOP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45359,10 +47332,10 @@ impl AstNode for Partition {
         &self.syntax
     }
 }
-impl AstNode for PartitionBy {
+impl AstNode for PlpgsqlExceptionHandler {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_BY
+        kind == SyntaxKind::PLPGSQL_EXCEPTION_HANDLER
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45377,10 +47350,10 @@ impl AstNode for PartitionBy {
         &self.syntax
     }
 }
-impl AstNode for PartitionByClause {
+impl AstNode for PlpgsqlExceptionSection {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_BY_CLAUSE
+        kind == SyntaxKind::PLPGSQL_EXCEPTION_SECTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45395,10 +47368,10 @@ impl AstNode for PartitionByClause {
         &self.syntax
     }
 }
-impl AstNode for PartitionDefault {
+impl AstNode for PlpgsqlExecute {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_DEFAULT
+        kind == SyntaxKind::PLPGSQL_EXECUTE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45413,10 +47386,10 @@ impl AstNode for PartitionDefault {
         &self.syntax
     }
 }
-impl AstNode for PartitionForValuesFrom {
+impl AstNode for PlpgsqlExit {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_FOR_VALUES_FROM
+        kind == SyntaxKind::PLPGSQL_EXIT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45431,10 +47404,10 @@ impl AstNode for PartitionForValuesFrom {
         &self.syntax
     }
 }
-impl AstNode for PartitionForValuesIn {
+impl AstNode for PlpgsqlForInt {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_FOR_VALUES_IN
+        kind == SyntaxKind::PLPGSQL_FOR_INT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45449,10 +47422,10 @@ impl AstNode for PartitionForValuesIn {
         &self.syntax
     }
 }
-impl AstNode for PartitionForValuesWith {
+impl AstNode for PlpgsqlForQuery {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_FOR_VALUES_WITH
+        kind == SyntaxKind::PLPGSQL_FOR_QUERY
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45467,10 +47440,10 @@ impl AstNode for PartitionForValuesWith {
         &self.syntax
     }
 }
-impl AstNode for PartitionFromValues {
+impl AstNode for PlpgsqlForeach {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_FROM_VALUES
+        kind == SyntaxKind::PLPGSQL_FOREACH
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45485,10 +47458,10 @@ impl AstNode for PartitionFromValues {
         &self.syntax
     }
 }
-impl AstNode for PartitionItem {
+impl AstNode for PlpgsqlGetDiagnostics {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_ITEM
+        kind == SyntaxKind::PLPGSQL_GET_DIAGNOSTICS
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45503,10 +47476,10 @@ impl AstNode for PartitionItem {
         &self.syntax
     }
 }
-impl AstNode for PartitionItemList {
+impl AstNode for PlpgsqlIf {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_ITEM_LIST
+        kind == SyntaxKind::PLPGSQL_IF
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45521,10 +47494,10 @@ impl AstNode for PartitionItemList {
         &self.syntax
     }
 }
-impl AstNode for PartitionList {
+impl AstNode for PlpgsqlInto {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_LIST
+        kind == SyntaxKind::PLPGSQL_INTO
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45539,10 +47512,10 @@ impl AstNode for PartitionList {
         &self.syntax
     }
 }
-impl AstNode for PartitionModulus {
+impl AstNode for PlpgsqlLabel {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_MODULUS
+        kind == SyntaxKind::PLPGSQL_LABEL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45557,10 +47530,10 @@ impl AstNode for PartitionModulus {
         &self.syntax
     }
 }
-impl AstNode for PartitionOf {
+impl AstNode for PlpgsqlLoop {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_OF
+        kind == SyntaxKind::PLPGSQL_LOOP
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45575,10 +47548,10 @@ impl AstNode for PartitionOf {
         &self.syntax
     }
 }
-impl AstNode for PartitionRemainder {
+impl AstNode for PlpgsqlNull {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_REMAINDER
+        kind == SyntaxKind::PLPGSQL_NULL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45593,10 +47566,10 @@ impl AstNode for PartitionRemainder {
         &self.syntax
     }
 }
-impl AstNode for PartitionStrategy {
+impl AstNode for PlpgsqlOpen {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_STRATEGY
+        kind == SyntaxKind::PLPGSQL_OPEN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45611,10 +47584,10 @@ impl AstNode for PartitionStrategy {
         &self.syntax
     }
 }
-impl AstNode for PartitionToValues {
+impl AstNode for PlpgsqlOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PARTITION_TO_VALUES
+        kind == SyntaxKind::PLPGSQL_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45629,10 +47602,10 @@ impl AstNode for PartitionToValues {
         &self.syntax
     }
 }
-impl AstNode for Path {
+impl AstNode for PlpgsqlPerform {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PATH
+        kind == SyntaxKind::PLPGSQL_PERFORM
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45647,10 +47620,10 @@ impl AstNode for Path {
         &self.syntax
     }
 }
-impl AstNode for PathFactor {
+impl AstNode for PlpgsqlRaise {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PATH_FACTOR
+        kind == SyntaxKind::PLPGSQL_RAISE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45665,10 +47638,10 @@ impl AstNode for PathFactor {
         &self.syntax
     }
 }
-impl AstNode for PathPattern {
+impl AstNode for PlpgsqlRaiseOption {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PATH_PATTERN
+        kind == SyntaxKind::PLPGSQL_RAISE_OPTION
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45683,10 +47656,10 @@ impl AstNode for PathPattern {
         &self.syntax
     }
 }
-impl AstNode for PathPatternList {
+impl AstNode for PlpgsqlRaiseUsing {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PATH_PATTERN_LIST
+        kind == SyntaxKind::PLPGSQL_RAISE_USING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45701,10 +47674,10 @@ impl AstNode for PathPatternList {
         &self.syntax
     }
 }
-impl AstNode for PathRef {
+impl AstNode for PlpgsqlReturn {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PATH_REF
+        kind == SyntaxKind::PLPGSQL_RETURN
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45719,10 +47692,10 @@ impl AstNode for PathRef {
         &self.syntax
     }
 }
-impl AstNode for PathSegment {
+impl AstNode for PlpgsqlRoot {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PATH_SEGMENT
+        kind == SyntaxKind::PLPGSQL_ROOT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45737,10 +47710,10 @@ impl AstNode for PathSegment {
         &self.syntax
     }
 }
-impl AstNode for PathSegmentRef {
+impl AstNode for PlpgsqlSqlStmt {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PATH_SEGMENT_REF
+        kind == SyntaxKind::PLPGSQL_SQL_STMT
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45755,10 +47728,10 @@ impl AstNode for PathSegmentRef {
         &self.syntax
     }
 }
-impl AstNode for PathType {
+impl AstNode for PlpgsqlUsing {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PATH_TYPE
+        kind == SyntaxKind::PLPGSQL_USING
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45773,10 +47746,10 @@ impl AstNode for PathType {
         &self.syntax
     }
 }
-impl AstNode for PercentType {
+impl AstNode for PlpgsqlVarDecl {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PERCENT_TYPE
+        kind == SyntaxKind::PLPGSQL_VAR_DECL
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45791,10 +47764,10 @@ impl AstNode for PercentType {
         &self.syntax
     }
 }
-impl AstNode for PercentTypeClause {
+impl AstNode for PlpgsqlVariable {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PERCENT_TYPE_CLAUSE
+        kind == SyntaxKind::PLPGSQL_VARIABLE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -45809,10 +47782,10 @@ impl AstNode for PercentTypeClause {
         &self.syntax
     }
 }
-impl AstNode for PeriodColumn {
+impl AstNode for PlpgsqlWhile {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SyntaxKind::PERIOD_COLUMN
+        kind == SyntaxKind::PLPGSQL_WHILE
     }
     #[inline]
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -47141,6 +49114,24 @@ impl AstNode for PropertyStorage {
         &self.syntax
     }
 }
+impl AstNode for PsqlMetaCommand {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::PSQL_META_COMMAND
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
 impl AstNode for Publication {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
@@ -58047,6 +60038,8 @@ impl AstNode for AnyName {
                 | SyntaxKind::PARAM_NAME_REF
                 | SyntaxKind::PATH_SEGMENT
                 | SyntaxKind::PATH_SEGMENT_REF
+                | SyntaxKind::PLPGSQL_LABEL
+                | SyntaxKind::PLPGSQL_VARIABLE
                 | SyntaxKind::POLICY
                 | SyntaxKind::POLICY_REF
                 | SyntaxKind::PREPARED_STATEMENT
@@ -58158,6 +60151,8 @@ impl AstNode for AnyName {
             SyntaxKind::PARAM_NAME_REF => AnyName::ParamNameRef(ParamNameRef { syntax }),
             SyntaxKind::PATH_SEGMENT => AnyName::PathSegment(PathSegment { syntax }),
             SyntaxKind::PATH_SEGMENT_REF => AnyName::PathSegmentRef(PathSegmentRef { syntax }),
+            SyntaxKind::PLPGSQL_LABEL => AnyName::PlpgsqlLabel(PlpgsqlLabel { syntax }),
+            SyntaxKind::PLPGSQL_VARIABLE => AnyName::PlpgsqlVariable(PlpgsqlVariable { syntax }),
             SyntaxKind::POLICY => AnyName::Policy(Policy { syntax }),
             SyntaxKind::POLICY_REF => AnyName::PolicyRef(PolicyRef { syntax }),
             SyntaxKind::PREPARED_STATEMENT => {
@@ -58263,6 +60258,8 @@ impl AstNode for AnyName {
             AnyName::ParamNameRef(it) => &it.syntax,
             AnyName::PathSegment(it) => &it.syntax,
             AnyName::PathSegmentRef(it) => &it.syntax,
+            AnyName::PlpgsqlLabel(it) => &it.syntax,
+            AnyName::PlpgsqlVariable(it) => &it.syntax,
             AnyName::Policy(it) => &it.syntax,
             AnyName::PolicyRef(it) => &it.syntax,
             AnyName::PreparedStatement(it) => &it.syntax,
@@ -58583,6 +60580,18 @@ impl From<PathSegmentRef> for AnyName {
         AnyName::PathSegmentRef(node)
     }
 }
+impl From<PlpgsqlLabel> for AnyName {
+    #[inline]
+    fn from(node: PlpgsqlLabel) -> AnyName {
+        AnyName::PlpgsqlLabel(node)
+    }
+}
+impl From<PlpgsqlVariable> for AnyName {
+    #[inline]
+    fn from(node: PlpgsqlVariable) -> AnyName {
+        AnyName::PlpgsqlVariable(node)
+    }
+}
 impl From<Policy> for AnyName {
     #[inline]
     fn from(node: Policy) -> AnyName {
@@ -65240,6 +67249,256 @@ impl From<Unlogged> for Persistence {
         Persistence::Unlogged(node)
     }
 }
+impl AstNode for PlpgsqlDecl {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            SyntaxKind::PLPGSQL_ALIAS_DECL
+                | SyntaxKind::PLPGSQL_CURSOR_DECL
+                | SyntaxKind::PLPGSQL_VAR_DECL
+        )
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            SyntaxKind::PLPGSQL_ALIAS_DECL => {
+                PlpgsqlDecl::PlpgsqlAliasDecl(PlpgsqlAliasDecl { syntax })
+            }
+            SyntaxKind::PLPGSQL_CURSOR_DECL => {
+                PlpgsqlDecl::PlpgsqlCursorDecl(PlpgsqlCursorDecl { syntax })
+            }
+            SyntaxKind::PLPGSQL_VAR_DECL => PlpgsqlDecl::PlpgsqlVarDecl(PlpgsqlVarDecl { syntax }),
+            _ => {
+                return None;
+            }
+        };
+        Some(res)
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            PlpgsqlDecl::PlpgsqlAliasDecl(it) => &it.syntax,
+            PlpgsqlDecl::PlpgsqlCursorDecl(it) => &it.syntax,
+            PlpgsqlDecl::PlpgsqlVarDecl(it) => &it.syntax,
+        }
+    }
+}
+impl From<PlpgsqlAliasDecl> for PlpgsqlDecl {
+    #[inline]
+    fn from(node: PlpgsqlAliasDecl) -> PlpgsqlDecl {
+        PlpgsqlDecl::PlpgsqlAliasDecl(node)
+    }
+}
+impl From<PlpgsqlCursorDecl> for PlpgsqlDecl {
+    #[inline]
+    fn from(node: PlpgsqlCursorDecl) -> PlpgsqlDecl {
+        PlpgsqlDecl::PlpgsqlCursorDecl(node)
+    }
+}
+impl From<PlpgsqlVarDecl> for PlpgsqlDecl {
+    #[inline]
+    fn from(node: PlpgsqlVarDecl) -> PlpgsqlDecl {
+        PlpgsqlDecl::PlpgsqlVarDecl(node)
+    }
+}
+impl AstNode for PlpgsqlStmt {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            SyntaxKind::PLPGSQL_ASSERT
+                | SyntaxKind::PLPGSQL_ASSIGN
+                | SyntaxKind::PLPGSQL_BLOCK
+                | SyntaxKind::PLPGSQL_CASE
+                | SyntaxKind::PLPGSQL_EXECUTE
+                | SyntaxKind::PLPGSQL_EXIT
+                | SyntaxKind::PLPGSQL_FOR_INT
+                | SyntaxKind::PLPGSQL_FOR_QUERY
+                | SyntaxKind::PLPGSQL_FOREACH
+                | SyntaxKind::PLPGSQL_GET_DIAGNOSTICS
+                | SyntaxKind::PLPGSQL_IF
+                | SyntaxKind::PLPGSQL_LOOP
+                | SyntaxKind::PLPGSQL_NULL
+                | SyntaxKind::PLPGSQL_OPEN
+                | SyntaxKind::PLPGSQL_PERFORM
+                | SyntaxKind::PLPGSQL_RAISE
+                | SyntaxKind::PLPGSQL_RETURN
+                | SyntaxKind::PLPGSQL_SQL_STMT
+                | SyntaxKind::PLPGSQL_WHILE
+        )
+    }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            SyntaxKind::PLPGSQL_ASSERT => PlpgsqlStmt::PlpgsqlAssert(PlpgsqlAssert { syntax }),
+            SyntaxKind::PLPGSQL_ASSIGN => PlpgsqlStmt::PlpgsqlAssign(PlpgsqlAssign { syntax }),
+            SyntaxKind::PLPGSQL_BLOCK => PlpgsqlStmt::PlpgsqlBlock(PlpgsqlBlock { syntax }),
+            SyntaxKind::PLPGSQL_CASE => PlpgsqlStmt::PlpgsqlCase(PlpgsqlCase { syntax }),
+            SyntaxKind::PLPGSQL_EXECUTE => PlpgsqlStmt::PlpgsqlExecute(PlpgsqlExecute { syntax }),
+            SyntaxKind::PLPGSQL_EXIT => PlpgsqlStmt::PlpgsqlExit(PlpgsqlExit { syntax }),
+            SyntaxKind::PLPGSQL_FOR_INT => PlpgsqlStmt::PlpgsqlForInt(PlpgsqlForInt { syntax }),
+            SyntaxKind::PLPGSQL_FOR_QUERY => {
+                PlpgsqlStmt::PlpgsqlForQuery(PlpgsqlForQuery { syntax })
+            }
+            SyntaxKind::PLPGSQL_FOREACH => PlpgsqlStmt::PlpgsqlForeach(PlpgsqlForeach { syntax }),
+            SyntaxKind::PLPGSQL_GET_DIAGNOSTICS => {
+                PlpgsqlStmt::PlpgsqlGetDiagnostics(PlpgsqlGetDiagnostics { syntax })
+            }
+            SyntaxKind::PLPGSQL_IF => PlpgsqlStmt::PlpgsqlIf(PlpgsqlIf { syntax }),
+            SyntaxKind::PLPGSQL_LOOP => PlpgsqlStmt::PlpgsqlLoop(PlpgsqlLoop { syntax }),
+            SyntaxKind::PLPGSQL_NULL => PlpgsqlStmt::PlpgsqlNull(PlpgsqlNull { syntax }),
+            SyntaxKind::PLPGSQL_OPEN => PlpgsqlStmt::PlpgsqlOpen(PlpgsqlOpen { syntax }),
+            SyntaxKind::PLPGSQL_PERFORM => PlpgsqlStmt::PlpgsqlPerform(PlpgsqlPerform { syntax }),
+            SyntaxKind::PLPGSQL_RAISE => PlpgsqlStmt::PlpgsqlRaise(PlpgsqlRaise { syntax }),
+            SyntaxKind::PLPGSQL_RETURN => PlpgsqlStmt::PlpgsqlReturn(PlpgsqlReturn { syntax }),
+            SyntaxKind::PLPGSQL_SQL_STMT => PlpgsqlStmt::PlpgsqlSqlStmt(PlpgsqlSqlStmt { syntax }),
+            SyntaxKind::PLPGSQL_WHILE => PlpgsqlStmt::PlpgsqlWhile(PlpgsqlWhile { syntax }),
+            _ => {
+                return None;
+            }
+        };
+        Some(res)
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            PlpgsqlStmt::PlpgsqlAssert(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlAssign(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlBlock(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlCase(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlExecute(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlExit(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlForInt(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlForQuery(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlForeach(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlGetDiagnostics(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlIf(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlLoop(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlNull(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlOpen(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlPerform(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlRaise(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlReturn(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlSqlStmt(it) => &it.syntax,
+            PlpgsqlStmt::PlpgsqlWhile(it) => &it.syntax,
+        }
+    }
+}
+impl From<PlpgsqlAssert> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlAssert) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlAssert(node)
+    }
+}
+impl From<PlpgsqlAssign> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlAssign) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlAssign(node)
+    }
+}
+impl From<PlpgsqlBlock> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlBlock) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlBlock(node)
+    }
+}
+impl From<PlpgsqlCase> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlCase) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlCase(node)
+    }
+}
+impl From<PlpgsqlExecute> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlExecute) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlExecute(node)
+    }
+}
+impl From<PlpgsqlExit> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlExit) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlExit(node)
+    }
+}
+impl From<PlpgsqlForInt> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlForInt) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlForInt(node)
+    }
+}
+impl From<PlpgsqlForQuery> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlForQuery) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlForQuery(node)
+    }
+}
+impl From<PlpgsqlForeach> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlForeach) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlForeach(node)
+    }
+}
+impl From<PlpgsqlGetDiagnostics> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlGetDiagnostics) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlGetDiagnostics(node)
+    }
+}
+impl From<PlpgsqlIf> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlIf) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlIf(node)
+    }
+}
+impl From<PlpgsqlLoop> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlLoop) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlLoop(node)
+    }
+}
+impl From<PlpgsqlNull> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlNull) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlNull(node)
+    }
+}
+impl From<PlpgsqlOpen> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlOpen) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlOpen(node)
+    }
+}
+impl From<PlpgsqlPerform> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlPerform) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlPerform(node)
+    }
+}
+impl From<PlpgsqlRaise> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlRaise) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlRaise(node)
+    }
+}
+impl From<PlpgsqlReturn> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlReturn) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlReturn(node)
+    }
+}
+impl From<PlpgsqlSqlStmt> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlSqlStmt) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlSqlStmt(node)
+    }
+}
+impl From<PlpgsqlWhile> for PlpgsqlStmt {
+    #[inline]
+    fn from(node: PlpgsqlWhile) -> PlpgsqlStmt {
+        PlpgsqlStmt::PlpgsqlWhile(node)
+    }
+}
 impl AstNode for PolicyCommandKind {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool {
@@ -0,0 +1,580 @@
+//! Constructors for syntax nodes, for use with [`crate::syntax_editor::SyntaxEditor`].
+//!
+//! Nodes are built by parsing a snippet of SQL and pulling out the node we
+//! want, similar to rust-analyzer's `make`. Identifiers and strings are quoted
+//! as needed and expressions are wrapped in parens where precedence would
+//! otherwise change their meaning.
+//!
+//! The returned nodes are detached, so they're only useful for their text or
+//! for reading with the usual `ast` accessors.
+
+use crate::{
+    SourceFile,
+    ast::{self, AstNode},
+    quote::{quote_column_alias, quote_ident},
+};
+
+pub fn name_ref(text: &str) -> ast::NameRef {
+    ast_from_text(&format!("select {}", quote_ident(text)))
+}
+
+pub fn ty(text: &str) -> ast::Type {
+    ast_from_text(&format!("select null::{text}"))
+}
+
+pub fn string_literal(text: &str) -> ast::Literal {
+    ast_from_text(&format!("select {}", tokens::string(text)))
+}
+
+pub fn number_literal(text: &str) -> ast::Literal {
+    ast_from_text(&format!("select {}", tokens::int_number(text)))
+}
+
+pub fn null_literal() -> ast::Literal {
+    ast_from_text("select null")
+}
+
+pub fn paren_expr(expr: ast::Expr) -> ast::ParenExpr {
+    ast_from_text(&format!("select ({expr})", expr = expr.syntax()))
+}
+
+/// e.g., `bin_expr(a, ">=", b)` for `a >= b`.
+pub fn bin_expr(lhs: ast::Expr, op: &str, rhs: ast::Expr) -> ast::BinExpr {
+    let prec = binary_precedence(&normalize_op(op));
+    let lhs_prec = expr_precedence(&lhs);
+    let lhs = parenthesize_if(
+        lhs,
+        lhs_prec < prec || (lhs_prec == prec && is_non_assoc(prec)),
+    );
+    let rhs_prec = expr_precedence(&rhs);
+    let rhs = parenthesize_if(rhs, rhs_prec <= prec);
+    ast_from_text(&format!(
+        "select {lhs} {op} {rhs}",
+        lhs = lhs.syntax(),
+        rhs = rhs.syntax()
+    ))
+}
+
+/// e.g., `prefix_expr("not", a)` for `not a`.
+pub fn prefix_expr(op: &str, expr: ast::Expr) -> ast::PrefixExpr {
+    let prec = prefix_precedence(&normalize_op(op));
+    let expr_prec = expr_precedence(&expr);
+    let expr = parenthesize_if(expr, expr_prec < prec);
+    ast_from_text(&format!("select {op} {expr}", expr = expr.syntax()))
+}
+
+/// e.g., `postfix_expr(a, "is normalized")` for `a is normalized`.
+pub fn postfix_expr(expr: ast::Expr, op: &str) -> ast::PostfixExpr {
+    // Our parser binds postfix operators tighter than Postgres does, so we
+    // wrap any operand with an operator to read the same either way.
+    let expr_prec = expr_precedence(&expr);
+    let expr = parenthesize_if(expr, expr_prec < CAST);
+    ast_from_text(&format!("select {expr} {op}", expr = expr.syntax()))
+}
+
+/// `base.field`, e.g., `pg_catalog.normalize`
+pub fn field_expr(base: &str, field: &str) -> ast::FieldExpr {
+    ast_from_text(&format!("select {base}.{field}"))
+}
+
+/// `name` is the function's possibly schema qualified name, e.g.,
+/// `pg_catalog.position`.
+pub fn call_expr(name: &str, args: impl IntoIterator<Item = ast::Expr>) -> ast::CallExpr {
+    let args = join(args, ", ");
+    ast_from_text(&format!("select {name}({args})"))
+}
+
+/// `cast(expr as ty)`
+pub fn cast_expr(expr: ast::Expr, ty: ast::Type) -> ast::CastExpr {
+    ast_from_text(&format!(
+        "select cast({expr} as {ty})",
+        expr = expr.syntax(),
+        ty = ty.syntax()
+    ))
+}
+
+/// `expr::ty`
+pub fn colon_colon_cast_expr(expr: ast::Expr, ty: ast::Type) -> ast::CastExpr {
+    let expr_prec = expr_precedence(&expr);
+    let expr = parenthesize_if(expr, expr_prec < CAST);
+    ast_from_text(&format!(
+        "select {expr}::{ty}",
+        expr = expr.syntax(),
+        ty = ty.syntax()
+    ))
+}
+
+pub fn array_expr(exprs: impl IntoIterator<Item = ast::Expr>) -> ast::ArrayExpr {
+    let exprs = join(exprs, ", ");
+    ast_from_text(&format!("select ARRAY[{exprs}]"))
+}
+
+/// `expr as alias`
+pub fn target(expr: ast::Expr, alias: Option<&str>) -> ast::Target {
+    match alias {
+        Some(alias) => ast_from_text(&format!(
+            "select {expr} {as_name}",
+            expr = expr.syntax(),
+            as_name = as_name(alias).syntax()
+        )),
+        None => ast_from_text(&format!("select {expr}", expr = expr.syntax())),
+    }
+}
+
+/// `*`
+pub fn target_star() -> ast::Target {
+    ast_from_text("select *")
+}
+
+pub fn as_name(alias: &str) -> ast::AsName {
+    ast_from_text(&format!("select 1 as {}", quote_column_alias(alias)))
+}
+
+pub fn select_clause(targets: impl IntoIterator<Item = ast::Target>) -> ast::SelectClause {
+    let targets = join(targets, ", ");
+    ast_from_text(&format!("select {targets}"))
+}
+
+pub fn select(
+    targets: impl IntoIterator<Item = ast::Target>,
+    from_clause: Option<ast::FromClause>,
+) -> ast::Select {
+    let select_clause = select_clause(targets);
+    match from_clause {
+        Some(from_clause) => ast_from_text(&format!(
+            "{} {}",
+            select_clause.syntax(),
+            from_clause.syntax()
+        )),
+        None => ast_from_text(&select_clause.syntax().to_string()),
+    }
+}
+
+/// The selects joined with `union all`, one per line.
+///
+/// Panics if there are no selects.
+pub fn union_all(selects: impl IntoIterator<Item = ast::Select>) -> ast::SelectVariant {
+    let text = join(selects, "\nunion all\n");
+    assert!(!text.is_empty(), "union_all requires at least one select");
+    ast_from_text(&text)
+}
+
+pub fn from_clause(items: impl IntoIterator<Item = ast::FromItem>) -> ast::FromClause {
+    let items = join(items, ", ");
+    ast_from_text(&format!("select * from {items}"))
+}
+
+pub fn relation_from_item(relation: ast::RelationName) -> ast::RelationFromItem {
+    ast_from_text(&format!("select * from {}", relation.syntax()))
+}
+
+/// `rows from (f(a), g(b))`
+pub fn rows_from_item(calls: impl IntoIterator<Item = ast::CallExpr>) -> ast::RowsFromItem {
+    let calls = join(calls, ", ");
+    ast_from_text(&format!("select * from rows from ({calls})"))
+}
+
+pub fn row(exprs: impl IntoIterator<Item = ast::Expr>) -> ast::Row {
+    let exprs = join(exprs, ", ");
+    ast_from_text(&format!("values ({exprs})"))
+}
+
+pub fn values(rows: impl IntoIterator<Item = ast::Row>) -> ast::Values {
+    let rows = join(rows, ", ");
+    ast_from_text(&format!("values {rows}"))
+}
+
+/// `table relation`
+pub fn table(relation: ast::RelationNameRef) -> ast::Table {
+    ast_from_text(&format!("table {}", relation.syntax()))
+}
+
+/// `into [persistence] table_name`
+pub fn into_clause(persistence: Option<ast::Persistence>, path: ast::Path) -> ast::IntoClause {
+    let persistence = persistence
+        .map(|persistence| format!("{} ", persistence.syntax()))
+        .unwrap_or_default();
+    ast_from_text(&format!("select 1 into {persistence}{}", path.syntax()))
+}
+
+/// `create [persistence] table table_name as query`
+pub fn create_table_as(
+    persistence: Option<ast::Persistence>,
+    path: ast::Path,
+    query: ast::SelectVariant,
+) -> ast::CreateTableAs {
+    let persistence = persistence
+        .map(|persistence| format!("{} ", persistence.syntax()))
+        .unwrap_or_default();
+    ast_from_text(&format!(
+        "create {persistence}table {} as {}",
+        path.syntax(),
+        query.syntax()
+    ))
+}
+
+/// `name type [constraints]`
+pub fn column(
+    name: &str,
+    ty: ast::Type,
+    constraints: impl IntoIterator<Item = ast::ColumnConstraint>,
+) -> ast::Column {
+    let mut text = format!("{} {}", quote_ident(name), ty.syntax());
+    for constraint in constraints {
+        text.push(' ');
+        text.push_str(&constraint.syntax().to_string());
+    }
+    ast_from_text(&format!("create table t({text});"))
+}
+
+pub fn not_null_constraint() -> ast::NotNullConstraint {
+    ast_from_text("create table t(a int not null);")
+}
+
+pub fn default_constraint(expr: ast::Expr) -> ast::DefaultConstraint {
+    ast_from_text(&format!("create table t(a int default {});", expr.syntax()))
+}
+
+pub fn check_constraint(expr: ast::Expr) -> ast::CheckConstraint {
+    ast_from_text(&format!("create table t(a int check ({}));", expr.syntax()))
+}
+
+pub fn primary_key_constraint() -> ast::PrimaryKeyConstraint {
+    ast_from_text("create table t(a int primary key);")
+}
+
+// Operator precedence, lowest to highest, from
+// https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-PRECEDENCE
+const OR: u8 = 1;
+const AND: u8 = 2;
+const NOT: u8 = 3;
+const IS: u8 = 4;
+const COMPARISON: u8 = 5;
+const PATTERN: u8 = 6;
+const OTHER: u8 = 7;
+const ADDITIVE: u8 = 8;
+const MULTIPLICATIVE: u8 = 9;
+const EXPONENT: u8 = 10;
+const AT: u8 = 11;
+const COLLATE: u8 = 12;
+const UNARY: u8 = 13;
+const CAST: u8 = 14;
+const ATOM: u8 = 15;
+
+fn parenthesize_if(expr: ast::Expr, cond: bool) -> ast::Expr {
+    if cond { paren_expr(expr).into() } else { expr }
+}
+
+// `IS`, the comparison operators, and `LIKE` & friends don't associate, so
+// `a = b = c` is an error rather than `(a = b) = c`.
+fn is_non_assoc(prec: u8) -> bool {
+    matches!(prec, IS | COMPARISON | PATTERN)
+}
+
+fn expr_precedence(expr: &ast::Expr) -> u8 {
+    match expr {
+        ast::Expr::BinExpr(it) => binary_precedence(&op_text(it.syntax())),
+        ast::Expr::PrefixExpr(it) => prefix_precedence(&op_text(it.syntax())),
+        ast::Expr::PostfixExpr(it) => postfix_precedence(&op_text(it.syntax())),
+        ast::Expr::BetweenExpr(_) => PATTERN,
+        ast::Expr::Collate(_) => COLLATE,
+        ast::Expr::CastExpr(it) if it.colon_colon().is_some() => CAST,
+        _ => ATOM,
+    }
+}
+
+fn binary_precedence(op: &str) -> u8 {
+    match op {
+        "or" => OR,
+        "and" => AND,
+        "+" | "-" => ADDITIVE,
+        "*" | "/" | "%" => MULTIPLICATIVE,
+        "^" => EXPONENT,
+        "at time zone" => AT,
+        "like" | "not like" | "ilike" | "not ilike" | "similar to" | "not similar to" | "in"
+        | "not in" => PATTERN,
+        _ if op == "is" || op.starts_with("is ") => IS,
+        _ => {
+            // `a = any (...)` compares like `=`.
+            let op = op.split(' ').next().unwrap_or(op);
+            match op {
+                "<" | ">" | "=" | "<=" | ">=" | "<>" | "!=" => COMPARISON,
+                _ => OTHER,
+            }
+        }
+    }
+}
+
+fn prefix_precedence(op: &str) -> u8 {
+    match op {
+        "not" => NOT,
+        "+" | "-" => UNARY,
+        _ => OTHER,
+    }
+}
+
+fn postfix_precedence(op: &str) -> u8 {
+    match op {
+        "isnull" | "notnull" => IS,
+        "at local" => AT,
+        _ if op.starts_with("is ") => IS,
+        _ => OTHER,
+    }
+}
+
+// The operator of a `BinExpr`, `PrefixExpr`, or `PostfixExpr`, i.e., all of
+// the non-trivia text that isn't an operand.
+fn op_text(node: &crate::SyntaxNode) -> String {
+    let parts: Vec<String> = node
+        .children_with_tokens()
+        .filter_map(|child| match child {
+            rowan::NodeOrToken::Node(node) if ast::Expr::can_cast(node.kind()) => None,
+            rowan::NodeOrToken::Node(node) => Some(node.to_string()),
+            rowan::NodeOrToken::Token(token) if token.kind().is_trivia() => None,
+            rowan::NodeOrToken::Token(token) => Some(token.text().to_string()),
+        })
+        .collect();
+    normalize_op(&parts.join(" "))
+}
+
+fn normalize_op(op: &str) -> String {
+    op.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_lowercase()
+}
+
+fn join<N: AstNode>(nodes: impl IntoIterator<Item = N>, sep: &str) -> String {
+    nodes
+        .into_iter()
+        .map(|node| node.syntax().to_string())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+#[track_caller]
+fn ast_from_text<N: AstNode>(text: &str) -> N {
+    let parse = SourceFile::parse(text);
+    let Some(node) = parse.tree().syntax().descendants().find_map(N::cast) else {
+        panic!(
+            "Failed to make ast node `{}` from text `{text}`",
+            std::any::type_name::<N>()
+        )
+    };
+    let node = node.clone_subtree();
+    assert_eq!(node.syntax().text_range().start(), 0.into());
+    node
+}
+
+pub mod tokens {
+    use rowan::{GreenNode, GreenToken};
+
+    use crate::{
+        SyntaxKind, SyntaxNode, SyntaxToken,
+        quote::{quote, quote_string_literal},
+    };
+
+    pub fn single_space() -> SyntaxToken {
+        whitespace(" ")
+    }
+
+    pub fn whitespace(text: &str) -> SyntaxToken {
+        assert!(text.trim().is_empty());
+        token(SyntaxKind::WHITESPACE, text)
+    }
+
+    /// A line or block comment, including its `--` or `/*`.
+    pub fn comment(text: &str) -> SyntaxToken {
+        assert!(text.starts_with("--") || text.starts_with("/*"));
+        token(SyntaxKind::COMMENT, text)
+    }
+
+    pub fn semicolon() -> SyntaxToken {
+        token(SyntaxKind::SEMICOLON, ";")
+    }
+
+    pub fn dot() -> SyntaxToken {
+        token(SyntaxKind::DOT, ".")
+    }
+
+    /// An identifier, as is.
+    pub fn ident(text: &str) -> SyntaxToken {
+        token(SyntaxKind::IDENT, text)
+    }
+
+    /// An identifier, always in double quotes.
+    pub fn quoted_ident(text: &str) -> SyntaxToken {
+        token(SyntaxKind::IDENT, &quote(text))
+    }
+
+    pub fn int_number(text: &str) -> SyntaxToken {
+        token(SyntaxKind::INT_NUMBER, text)
+    }
+
+    /// A single quoted string, with any quotes in `text` escaped.
+    pub fn string(text: &str) -> SyntaxToken {
+        token(SyntaxKind::STRING, &quote_string_literal(text))
+    }
+
+    /// A dollar quoted string, e.g., `$q$text$q$`. It's up to the caller to
+    /// pick a `delimiter` that doesn't appear in `text`.
+    pub fn dollar_quoted_string(delimiter: &str, text: &str) -> SyntaxToken {
+        token(
+            SyntaxKind::DOLLAR_QUOTED_STRING,
+            &format!("${delimiter}${text}${delimiter}$"),
+        )
+    }
+
+    fn token(kind: SyntaxKind, text: &str) -> SyntaxToken {
+        let green = GreenNode::new(
+            rowan::SyntaxKind(SyntaxKind::SOURCE_FILE.into()),
+            [GreenToken::new(rowan::SyntaxKind(kind.into()), text).into()],
+        );
+        SyntaxNode::new_root(green)
+            .first_token()
+            .expect("we just made the token")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+
+    fn text(node: impl AstNode) -> String {
+        node.syntax().to_string()
+    }
+
+    fn expr(text: &str) -> ast::Expr {
+        ast_from_text(&format!("select {text}"))
+    }
+
+    #[test]
+    fn quotes_names() {
+        assert_snapshot!(text(name_ref("foo")), @"foo");
+        assert_snapshot!(text(name_ref("Foo")), @r#""Foo""#);
+        assert_snapshot!(text(name_ref("user")), @r#""user""#);
+        assert_snapshot!(text(name_ref(r#"a"b"#)), @r#""a""b""#);
+        assert_snapshot!(text(as_name("Foo Bar")), @r#"as "Foo Bar""#);
+    }
+
+    #[test]
+    fn quotes_strings() {
+        assert_snapshot!(text(string_literal("it's")), @"'it''s'");
+        assert_snapshot!(tokens::dollar_quoted_string("q", "it's"), @"$q$it's$q$");
+    }
+
+    #[test]
+    fn exprs() {
+        assert_snapshot!(
+            text(call_expr("pg_catalog.position", [expr("'b'"), expr("'abc'")])),
+            @"pg_catalog.position('b', 'abc')"
+        );
+        assert_snapshot!(text(call_expr("now", [])), @"now()");
+        assert_snapshot!(text(field_expr("pg_catalog", "normalize")), @"pg_catalog.normalize");
+        assert_snapshot!(text(bin_expr(expr("a"), ">=", expr("b + 1"))), @"a >= b + 1");
+        assert_snapshot!(text(prefix_expr("not", expr("a"))), @"not a");
+        assert_snapshot!(text(postfix_expr(expr("a"), "isnull")), @"a isnull");
+        assert_snapshot!(text(cast_expr(expr("1 + 2"), ty("bigint"))), @"cast(1 + 2 as bigint)");
+        assert_snapshot!(text(array_expr([expr("1"), expr("2")])), @"ARRAY[1, 2]");
+    }
+
+    #[test]
+    fn bin_expr_keeps_precedence() {
+        assert_snapshot!(text(bin_expr(expr("a + b"), "*", expr("c"))), @"(a + b) * c");
+        assert_snapshot!(text(bin_expr(expr("a * b"), "+", expr("c"))), @"a * b + c");
+        assert_snapshot!(text(bin_expr(expr("a"), "-", expr("b - c"))), @"a - (b - c)");
+        assert_snapshot!(text(bin_expr(expr("a - b"), "-", expr("c"))), @"a - b - c");
+        assert_snapshot!(text(bin_expr(expr("a = b"), "=", expr("c"))), @"(a = b) = c");
+        assert_snapshot!(
+            text(bin_expr(expr("a >= 1"), "and", expr("a <= 2"))),
+            @"a >= 1 and a <= 2"
+        );
+        assert_snapshot!(text(bin_expr(expr("a or b"), "AND", expr("c"))), @"(a or b) AND c");
+        assert_snapshot!(text(bin_expr(expr("a"), "~~", expr("b || c"))), @"a ~~ (b || c)");
+        assert_snapshot!(text(bin_expr(expr("not a"), "=", expr("b"))), @"(not a) = b");
+        assert_snapshot!(
+            text(bin_expr(expr("a"), "= ANY", expr("(ARRAY[1])"))),
+            @"a = ANY (ARRAY[1])"
+        );
+    }
+
+    #[test]
+    fn prefix_and_postfix_exprs_keep_precedence() {
+        assert_snapshot!(text(prefix_expr("not", expr("a = b"))), @"not a = b");
+        assert_snapshot!(text(prefix_expr("not", expr("a and b"))), @"not (a and b)");
+        assert_snapshot!(text(prefix_expr("-", expr("a + b"))), @"- (a + b)");
+        assert_snapshot!(text(prefix_expr("-", expr("a::int"))), @"- a::int");
+        assert_snapshot!(text(postfix_expr(expr("a = b"), "isnull")), @"(a = b) isnull");
+        assert_snapshot!(text(postfix_expr(expr("not a"), "isnull")), @"(not a) isnull");
+        assert_snapshot!(text(postfix_expr(expr("a isnull"), "isnull")), @"(a isnull) isnull");
+    }
+
+    #[test]
+    fn colon_colon_cast_keeps_precedence() {
+        assert_snapshot!(text(colon_colon_cast_expr(expr("x"), ty("text"))), @"x::text");
+        assert_snapshot!(
+            text(colon_colon_cast_expr(expr("1 + 2"), ty("bigint"))),
+            @"(1 + 2)::bigint"
+        );
+        assert_snapshot!(
+            text(colon_colon_cast_expr(expr("not a"), ty("int"))),
+            @"(not a)::int"
+        );
+    }
+
+    #[test]
+    fn columns() {
+        assert_snapshot!(
+            text(column(
+                "Created At",
+                ty("timestamptz"),
+                [
+                    not_null_constraint().into(),
+                    default_constraint(call_expr("now", []).into()).into(),
+                ],
+            )),
+            @r#""Created At" timestamptz not null default now()"#
+        );
+        assert_snapshot!(
+            text(column("id", ty("bigint"), [primary_key_constraint().into()])),
+            @"id bigint primary key"
+        );
+        assert_snapshot!(text(check_constraint(expr("a > 0"))), @"check (a > 0)");
+    }
+
+    #[test]
+    fn statements() {
+        assert_snapshot!(
+            text(select(
+                [target(expr("1"), Some("one")), target_star()],
+                Some(from_clause([ast_from_text("select * from t")])),
+            )),
+            @"select 1 as one, * from t"
+        );
+        assert_snapshot!(
+            text(union_all([
+                select([target(expr("1"), None)], None),
+                select([target(expr("2"), None)], None),
+            ])),
+            @"
+        select 1
+        union all
+        select 2
+        "
+        );
+        assert_snapshot!(
+            text(values([row([expr("1"), expr("'a'")]), row([expr("2"), expr("'b'")])])),
+            @"values (1, 'a'), (2, 'b')"
+        );
+        assert_snapshot!(
+            text(create_table_as(
+                None,
+                ast_from_text("create table foo.bar()"),
+                union_all([select([target_star()], None)]),
+            )),
+            @"create table foo.bar as select *"
+        );
+    }
+}
@@ -29,7 +29,7 @@ use std::borrow::Cow;
 use either::Either;
 #[cfg(test)]
 use insta::assert_snapshot;
-use rowan::{GreenNodeData, GreenTokenData, NodeOrToken};
+use rowan::{GreenNodeData, GreenTokenData, NodeOrToken, TextRange, TextSize};
 use squawk_line_index::{LineEnding, find_newline};
 
 #[cfg(test)]
@@ -67,6 +67,50 @@ impl ast::SourceFile {
             .map(|(_, line_ending)| line_ending)
             .unwrap_or_default()
     }
+
+    #[inline]
+    pub fn psql_meta_commands(&self) -> ast::AstChildren<ast::PsqlMetaCommand> {
+        support::children(self.syntax())
+    }
+}
+
+impl ast::PsqlMetaCommand {
+    /// The command without the backslash, e.g., `set` for `\set foo 1`.
+    pub fn name(&self) -> Option<String> {
+        let token = self.meta_command_token()?;
+        let name = token
+            .text()
+            .strip_prefix('\\')?
+            .split(|c: char| c.is_whitespace() || c == '\\')
+            .next()?;
+        Some(name.to_owned())
+    }
+
+    /// The arguments after the command's name, e.g., `foo 1` for `\set foo 1`.
+    pub fn args(&self) -> Option<(String, TextRange)> {
+        let token = self.meta_command_token()?;
+        let name_len = self.name()?.len() + 1;
+        let args = &token.text()[name_len..];
+        let trimmed = args.trim_start();
+        let start = token.text_range().start()
+            + TextSize::new((name_len + args.len() - trimmed.len()) as u32);
+        let trimmed = trimmed.trim_end();
+        Some((
+            trimmed.to_owned(),
+            TextRange::at(start, TextSize::of(trimmed)),
+        ))
+    }
+
+    /// Whether psql sends the query buffer to the server, ending the
+    /// statement before it like a semicolon, e.g., `\gset` or `\gexec`.
+    pub fn sends_query(&self) -> bool {
+        self.name().is_some_and(|name| {
+            matches!(
+                name.as_str(),
+                "g" | "gx" | "gset" | "gexec" | "gdesc" | "crosstabview" | "watch" | ";"
+            )
+        })
+    }
 }
 
 impl ast::Literal {
@@ -91,6 +135,12 @@ impl ast::Literal {
         };
         Some(kind)
     }
+
+    /// The literal's token, e.g. `'foo'` or `true`.
+    pub fn value(&self) -> Option<SyntaxToken> {
+        let token = self.syntax().first_child_or_token()?.into_token()?;
+        self.kind().is_some().then_some(token)
+    }
 }
 
 impl ast::Constraint {
@@ -248,6 +298,35 @@ pub enum PrefixOp {
     Plus(SyntaxToken),
 }
 
+impl ast::PlpgsqlAssign {
+    #[inline]
+    pub fn target(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    #[inline]
+    pub fn value(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl ast::PlpgsqlForInt {
+    #[inline]
+    pub fn lower(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    #[inline]
+    pub fn upper(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+
+    #[inline]
+    pub fn step(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).nth(2)
+    }
+}
+
 impl ast::BinExpr {
     #[inline]
     pub fn lhs(&self) -> Option<ast::Expr> {
@@ -473,6 +552,29 @@ impl ast::SliceExpr {
     }
 }
 
+impl ast::IndexAccessor {
+    #[inline]
+    pub fn index(&self) -> Option<ast::Expr> {
+        support::child(&self.syntax)
+    }
+}
+
+impl ast::SliceAccessor {
+    #[inline]
+    pub fn start(&self) -> Option<ast::Expr> {
+        let colon = self.colon_token()?;
+        support::children(&self.syntax)
+            .find(|expr: &ast::Expr| expr.syntax().text_range().end() <= colon.text_range().start())
+    }
+
+    #[inline]
+    pub fn end(&self) -> Option<ast::Expr> {
+        let colon = self.colon_token()?;
+        support::children(&self.syntax)
+            .find(|expr: &ast::Expr| expr.syntax().text_range().start() >= colon.text_range().end())
+    }
+}
+
 impl ast::RenameValue {
     #[inline]
     pub fn from(&self) -> Option<ast::Literal> {
@@ -549,6 +651,17 @@ impl ast::ReturningOption {
     }
 }
 
+impl ast::JsonTablePlanJoin {
+    #[inline]
+    pub fn lhs(&self) -> Option<ast::JsonTablePlan> {
+        support::children(&self.syntax).next()
+    }
+    #[inline]
+    pub fn rhs(&self) -> Option<ast::JsonTablePlan> {
+        support::children(&self.syntax).nth(1)
+    }
+}
+
 impl ast::CompoundSelect {
     #[inline]
     pub fn lhs(&self) -> Option<ast::SelectVariant> {
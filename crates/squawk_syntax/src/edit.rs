@@ -0,0 +1,73 @@
+use rowan::{TextRange, TextSize};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub text_range: TextRange,
+    // TODO: does this need to be an Option?
+    pub text: Option<String>,
+}
+
+impl Edit {
+    pub fn insert<T: Into<String>>(text: T, at: TextSize) -> Self {
+        Self {
+            text_range: TextRange::new(at, at),
+            text: Some(text.into()),
+        }
+    }
+    pub fn replace<T: Into<String>>(text_range: TextRange, text: T) -> Self {
+        Self {
+            text_range,
+            text: Some(text.into()),
+        }
+    }
+    pub fn delete(text_range: TextRange) -> Self {
+        Self {
+            text_range,
+            text: None,
+        }
+    }
+
+    /// The smallest edit that turns `old` into `new`, if they differ.
+    pub fn diff(old: &str, new: &str) -> Option<Self> {
+        minimal_edit(TextRange::up_to(TextSize::of(old)), old, new)
+    }
+
+    pub fn apply(&self, text: &mut String) {
+        text.replace_range(
+            std::ops::Range::<usize>::from(self.text_range),
+            self.text.as_deref().unwrap_or(""),
+        );
+    }
+}
+
+/// Replaces `old` at `range` with `new`, without the text they start and end
+/// with in common.
+pub(crate) fn minimal_edit(range: TextRange, old: &str, new: &str) -> Option<Edit> {
+    let prefix: usize = old
+        .chars()
+        .zip(new.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    let suffix: usize = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    let old_len = old.len() - prefix - suffix;
+    let new = &new[prefix..new.len() - suffix];
+    if old_len == 0 && new.is_empty() {
+        return None;
+    }
+    let start = range.start() + TextSize::new(prefix as u32);
+    let range = TextRange::at(start, TextSize::new(old_len as u32));
+    Some(if new.is_empty() {
+        Edit::delete(range)
+    } else if range.is_empty() {
+        Edit::insert(new, start)
+    } else {
+        Edit::replace(range, new)
+    })
+}
@@ -0,0 +1,317 @@
+//! Fingerprints for grouping queries that only differ in their constants or
+//! formatting, similar to `pg_stat_statements`.
+//!
+//! ```sql
+//! SELECT * FROM users WHERE id IN (1, 2, 3) -- recent users
+//! ```
+//!
+//! normalizes to:
+//!
+//! ```sql
+//! select * from users where id in ($1 /*, ... */)
+//! ```
+//!
+//! Constants are replaced with `$n`, lists of constants after `in` are
+//! collapsed, comments are removed and keywords and unquoted identifiers are
+//! lowercased, since Postgres folds them anyway. Tokens are separated by a
+//! single space, except around punctuation like `(`, `,` and `::`.
+
+use rowan::NodeOrToken;
+
+use crate::{
+    SyntaxKind, SyntaxNode, SyntaxToken,
+    ast::{self, AstNode, LitKind},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fingerprint {
+    /// The statement with its constants replaced, e.g. `select * from t where id = $1`.
+    pub normalized: String,
+    /// A hash of `normalized` that's the same across platforms and versions
+    /// of squawk.
+    pub hash: u64,
+}
+
+pub fn fingerprint(stmt: &ast::Stmt) -> Fingerprint {
+    let mut normalizer = Normalizer {
+        next_param: max_param(stmt.syntax()) + 1,
+        pieces: vec![],
+    };
+    normalizer.node(stmt.syntax());
+    let normalized = normalizer.finish();
+    let hash = fnv1a(normalized.as_bytes());
+    Fingerprint { normalized, hash }
+}
+
+struct Piece {
+    text: String,
+    kind: SyntaxKind,
+    // e.g., the `(` of a function call
+    attached: bool,
+}
+
+struct Normalizer {
+    next_param: u32,
+    pieces: Vec<Piece>,
+}
+
+impl Normalizer {
+    fn node(&mut self, node: &SyntaxNode) {
+        if is_constant(node) {
+            self.param();
+            return;
+        }
+        if let Some((lhs, op_tokens)) = constant_in_list(node) {
+            self.node(lhs.syntax());
+            for token in op_tokens {
+                self.token(&token);
+            }
+            self.push("(", SyntaxKind::L_PAREN, false);
+            self.param();
+            self.push("/*, ... */", SyntaxKind::COMMENT, false);
+            self.push(")", SyntaxKind::R_PAREN, false);
+            return;
+        }
+        for child in node.children_with_tokens() {
+            match child {
+                NodeOrToken::Node(node) => self.node(&node),
+                NodeOrToken::Token(token) => self.token(&token),
+            }
+        }
+    }
+
+    fn token(&mut self, token: &SyntaxToken) {
+        if token.kind().is_trivia() {
+            return;
+        }
+        // `select 1;` and `select 1` are the same query
+        if token.kind() == SyntaxKind::SEMICOLON
+            && token
+                .parent()
+                .and_then(|parent| parent.parent())
+                .is_some_and(|grandparent| grandparent.kind() == SyntaxKind::SOURCE_FILE)
+        {
+            return;
+        }
+        let text = if is_case_sensitive(token) {
+            token.text().to_owned()
+        } else {
+            token.text().to_lowercase()
+        };
+        let attached = token.kind() == SyntaxKind::L_PAREN
+            && token
+                .parent()
+                .is_some_and(|parent| parent.kind() == SyntaxKind::ARG_LIST);
+        self.pieces.push(Piece {
+            text,
+            kind: token.kind(),
+            attached,
+        });
+    }
+
+    fn param(&mut self) {
+        let text = format!("${}", self.next_param);
+        self.next_param += 1;
+        self.push(&text, SyntaxKind::POSITIONAL_PARAM, false);
+    }
+
+    fn push(&mut self, text: &str, kind: SyntaxKind, attached: bool) {
+        self.pieces.push(Piece {
+            text: text.to_owned(),
+            kind,
+            attached,
+        });
+    }
+
+    fn finish(self) -> String {
+        let mut out = String::new();
+        let mut prev: Option<SyntaxKind> = None;
+        for piece in self.pieces {
+            if let Some(prev) = prev
+                && needs_space(prev, &piece)
+            {
+                out.push(' ');
+            }
+            out.push_str(&piece.text);
+            prev = Some(piece.kind);
+        }
+        out
+    }
+}
+
+fn needs_space(prev: SyntaxKind, next: &Piece) -> bool {
+    if matches!(
+        prev,
+        SyntaxKind::L_PAREN | SyntaxKind::L_BRACK | SyntaxKind::DOT | SyntaxKind::COLON
+    ) {
+        return false;
+    }
+    if matches!(
+        next.kind,
+        SyntaxKind::R_PAREN
+            | SyntaxKind::R_BRACK
+            | SyntaxKind::COMMA
+            | SyntaxKind::DOT
+            | SyntaxKind::COLON
+            | SyntaxKind::SEMICOLON
+    ) {
+        return false;
+    }
+    !next.attached
+}
+
+// Quoted identifiers and strings keep their case, everything else is folded
+// to lowercase by Postgres.
+fn is_case_sensitive(token: &SyntaxToken) -> bool {
+    match token.kind() {
+        SyntaxKind::IDENT => token.text().contains('"'),
+        SyntaxKind::STRING
+        | SyntaxKind::BYTE_STRING
+        | SyntaxKind::BIT_STRING
+        | SyntaxKind::DOLLAR_QUOTED_STRING
+        | SyntaxKind::ESC_STRING
+        | SyntaxKind::UNICODE_ESC_STRING
+        | SyntaxKind::NATIONAL_STRING => true,
+        _ => false,
+    }
+}
+
+// Like Postgres, `-1` is a single constant.
+fn is_constant(node: &SyntaxNode) -> bool {
+    if let Some(literal) = ast::Literal::cast(node.clone()) {
+        return is_constant_literal(&literal);
+    }
+    if let Some(prefix_expr) = ast::PrefixExpr::cast(node.clone())
+        && let Some(ast::PrefixOp::Minus(_)) = prefix_expr.op()
+        && let Some(ast::Expr::Literal(literal)) = prefix_expr.expr()
+    {
+        return matches!(
+            literal.kind(),
+            Some(LitKind::IntNumber(_) | LitKind::NumericNumber(_))
+        );
+    }
+    false
+}
+
+fn is_constant_literal(literal: &ast::Literal) -> bool {
+    match literal.kind() {
+        Some(
+            LitKind::BitString(_)
+            | LitKind::ByteString(_)
+            | LitKind::DollarQuotedString(_)
+            | LitKind::EscString(_)
+            | LitKind::False(_)
+            | LitKind::IntNumber(_)
+            | LitKind::NationalString(_)
+            | LitKind::NumericNumber(_)
+            | LitKind::String(_)
+            | LitKind::True(_)
+            | LitKind::UnicodeEscString(_),
+        ) => true,
+        Some(LitKind::Default(_) | LitKind::Null(_) | LitKind::PositionalParam(_)) | None => false,
+    }
+}
+
+// `x in (1, 2, 3)` and `x not in (1)`, returns the lhs and the tokens of the
+// operator.
+fn constant_in_list(node: &SyntaxNode) -> Option<(ast::Expr, Vec<SyntaxToken>)> {
+    let bin_expr = ast::BinExpr::cast(node.clone())?;
+    let op_tokens = match bin_expr.op()? {
+        ast::BinOp::In(token) => vec![token],
+        ast::BinOp::NotIn(not_in) => not_in
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(NodeOrToken::into_token)
+            .collect(),
+        _ => return None,
+    };
+    let all_constant = match bin_expr.rhs()? {
+        ast::Expr::TupleExpr(tuple) => tuple.exprs().all(|expr| is_constant(expr.syntax())),
+        ast::Expr::ParenExpr(paren) => paren.expr().is_some_and(|expr| is_constant(expr.syntax())),
+        _ => false,
+    };
+    if !all_constant {
+        return None;
+    }
+    Some((bin_expr.lhs()?, op_tokens))
+}
+
+// Existing params are kept, so new ones are numbered after them.
+fn max_param(node: &SyntaxNode) -> u32 {
+    node.descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .filter(|token| token.kind() == SyntaxKind::POSITIONAL_PARAM)
+        .filter_map(|token| token.text().strip_prefix('$')?.parse().ok())
+        .max()
+        .unwrap_or(0)
+}
+
+// FNV-1a, since std's hashers aren't guaranteed to be stable between releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::fingerprint;
+    use crate::SourceFile;
+
+    #[track_caller]
+    fn normalize(sql: &str) -> String {
+        let file = SourceFile::parse(sql).tree();
+        let stmt = file.stmts().next().unwrap();
+        fingerprint(&stmt).normalized
+    }
+
+    #[track_caller]
+    fn hash(sql: &str) -> u64 {
+        let file = SourceFile::parse(sql).tree();
+        let stmt = file.stmts().next().unwrap();
+        fingerprint(&stmt).hash
+    }
+
+    #[test]
+    fn replaces_constants() {
+        assert_snapshot!(normalize("select * from t where a = 'x' and b > -1.5 and c = true and d is null limit 10;"), @"select * from t where a = $1 and b > $2 and c = $3 and d is null limit $4");
+    }
+
+    #[test]
+    fn keeps_existing_params() {
+        assert_snapshot!(normalize("select * from t where a = $2 and b = 1"), @"select * from t where a = $2 and b = $3");
+    }
+
+    #[test]
+    fn collapses_in_lists() {
+        assert_snapshot!(normalize("select * from t where a in (1, 2, 3) and b not in ('x') and c in (d, 1)"), @"select * from t where a in ($1 /*, ... */) and b not in ($2 /*, ... */) and c in (d, $3)");
+    }
+
+    #[test]
+    fn normalizes_case_whitespace_and_comments() {
+        assert_snapshot!(normalize(r#"SELECT  "Foo".Bar,count( * ) -- comment
+FROM   Foo /* x */ WHERE a::TEXT = 'A'"#), @r#"select "Foo".bar, count(*) from foo where a::text = $1"#);
+    }
+
+    #[test]
+    fn same_hash_for_formatting_differences() {
+        assert_eq!(
+            hash("SELECT a, b FROM t WHERE id = 1;"),
+            hash("select a,b\n  from t -- comment\n where id=2")
+        );
+        assert_ne!(
+            hash("select a from t where id = 1"),
+            hash("select b from t where id = 1")
+        );
+    }
+
+    #[test]
+    fn hash_is_stable() {
+        assert_snapshot!(format!("{:016x}", hash("select 1")), @"6c10d01ac8145628");
+    }
+}
@@ -0,0 +1,12007 @@
+// Generated via:
+//   cargo xtask codegen
+
+use crate::SyntaxKind;
+use crate::ast;
+use crate::json::Fields;
+use crate::syntax_node::SyntaxNode;
+pub(crate) fn fields(node: &SyntaxNode, fields: &mut Fields) {
+    match node.kind() {
+        SyntaxKind::ABSOLUTE => {
+            let node = ast::Absolute {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("absolute_token", node.absolute_token());
+        }
+        SyntaxKind::ACCESS_EXCLUSIVE => {
+            let node = ast::AccessExclusive {
+                syntax: node.clone(),
+            };
+            fields.add("access_token", node.access_token());
+            fields.add("exclusive_token", node.exclusive_token());
+        }
+        SyntaxKind::ACCESS_METHOD => {
+            let node = ast::AccessMethod {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ACCESS_METHOD_REF => {
+            let node = ast::AccessMethodRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ACCESS_METHOD_TYPE_INDEX => {
+            let node = ast::AccessMethodTypeIndex {
+                syntax: node.clone(),
+            };
+            fields.add("index_token", node.index_token());
+        }
+        SyntaxKind::ACCESS_METHOD_TYPE_TABLE => {
+            let node = ast::AccessMethodTypeTable {
+                syntax: node.clone(),
+            };
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::ACCESS_SHARE => {
+            let node = ast::AccessShare {
+                syntax: node.clone(),
+            };
+            fields.add("access_token", node.access_token());
+            fields.add("share_token", node.share_token());
+        }
+        SyntaxKind::ADD_ATTRIBUTE => {
+            let node = ast::AddAttribute {
+                syntax: node.clone(),
+            };
+            fields.add("collate", node.collate());
+            fields.add("composite_field", node.composite_field());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("type", node.ty());
+            fields.add("add_token", node.add_token());
+            fields.add("attribute_token", node.attribute_token());
+        }
+        SyntaxKind::ADD_COLUMN => {
+            let node = ast::AddColumn {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("collate", node.collate());
+            fields.add("column_name", node.column_name());
+            fields.add("constraints", node.constraints());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("type", node.ty());
+            fields.add("add_token", node.add_token());
+            fields.add("column_token", node.column_token());
+        }
+        SyntaxKind::ADD_CONSTRAINT => {
+            let node = ast::AddConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint", node.constraint());
+            fields.add("add_token", node.add_token());
+        }
+        SyntaxKind::ADD_FOREIGN_OPTION => {
+            let node = ast::AddForeignOption {
+                syntax: node.clone(),
+            };
+            fields.add("foreign_option_name", node.foreign_option_name());
+            fields.add("literal", node.literal());
+            fields.add("add_token", node.add_token());
+        }
+        SyntaxKind::ADD_GENERATED => {
+            let node = ast::AddGenerated {
+                syntax: node.clone(),
+            };
+            fields.add("generated_when", node.generated_when());
+            fields.add("sequence_option_list", node.sequence_option_list());
+            fields.add("add_token", node.add_token());
+            fields.add("as_token", node.as_token());
+            fields.add("generated_token", node.generated_token());
+            fields.add("identity_token", node.identity_token());
+        }
+        SyntaxKind::ADD_LABEL => {
+            let node = ast::AddLabel {
+                syntax: node.clone(),
+            };
+            fields.add("element_table_properties", node.element_table_properties());
+            fields.add("label", node.label());
+            fields.add("add_token", node.add_token());
+            fields.add("label_token", node.label_token());
+        }
+        SyntaxKind::ADD_MAPPING => {
+            let node = ast::AddMapping {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "text_search_dictionary_refs",
+                node.text_search_dictionary_refs(),
+            );
+            fields.add("text_search_token_kinds", node.text_search_token_kinds());
+            fields.add("add_token", node.add_token());
+            fields.add("for_token", node.for_token());
+            fields.add("mapping_token", node.mapping_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::ADD_OP_CLASS_OPTIONS => {
+            let node = ast::AddOpClassOptions {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "operator_class_option_list",
+                node.operator_class_option_list(),
+            );
+            fields.add("add_token", node.add_token());
+        }
+        SyntaxKind::ADD_PUBLICATION => {
+            let node = ast::AddPublication {
+                syntax: node.clone(),
+            };
+            fields.add("publication_refs", node.publication_refs());
+            fields.add("with_params", node.with_params());
+            fields.add("add_token", node.add_token());
+            fields.add("publication_token", node.publication_token());
+        }
+        SyntaxKind::ADD_PUBLICATION_OBJECTS => {
+            let node = ast::AddPublicationObjects {
+                syntax: node.clone(),
+            };
+            fields.add("publication_objects", node.publication_objects());
+            fields.add("add_token", node.add_token());
+        }
+        SyntaxKind::ADD_USERS => {
+            let node = ast::AddUsers {
+                syntax: node.clone(),
+            };
+            fields.add("role_refs", node.role_refs());
+            fields.add("add_token", node.add_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::ADD_VALUE => {
+            let node = ast::AddValue {
+                syntax: node.clone(),
+            };
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("literal", node.literal());
+            fields.add("value_position", node.value_position());
+            fields.add("add_token", node.add_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::ADD_VERTEX_EDGE_LABEL_PROPERTIES => {
+            let node = ast::AddVertexEdgeLabelProperties {
+                syntax: node.clone(),
+            };
+            fields.add("element_table_ref", node.element_table_ref());
+            fields.add(
+                "expr_as_property_name_list",
+                node.expr_as_property_name_list(),
+            );
+            fields.add("label_ref", node.label_ref());
+            fields.add("add_token", node.add_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("edge_token", node.edge_token());
+            fields.add("label_token", node.label_token());
+            fields.add("node_token", node.node_token());
+            fields.add("properties_token", node.properties_token());
+            fields.add("relationship_token", node.relationship_token());
+            fields.add("table_token", node.table_token());
+            fields.add("vertex_token", node.vertex_token());
+        }
+        SyntaxKind::ADD_VERTEX_EDGE_TABLES => {
+            let node = ast::AddVertexEdgeTables {
+                syntax: node.clone(),
+            };
+            fields.add("edge_tables", node.edge_tables());
+            fields.add("vertex_tables", node.vertex_tables());
+            fields.add("add_token", node.add_token());
+        }
+        SyntaxKind::ADMIN_OPTION_FOR => {
+            let node = ast::AdminOptionFor {
+                syntax: node.clone(),
+            };
+            fields.add("admin_token", node.admin_token());
+            fields.add("for_token", node.for_token());
+            fields.add("option_token", node.option_token());
+        }
+        SyntaxKind::AFTER_VALUE => {
+            let node = ast::AfterValue {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("after_token", node.after_token());
+        }
+        SyntaxKind::AGGREGATE => {
+            let node = ast::Aggregate {
+                syntax: node.clone(),
+            };
+            fields.add("param_list", node.param_list());
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::AGGREGATE_NAME => {
+            let node = ast::AggregateName {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::AGGREGATE_RENAME_TO => {
+            let node = ast::AggregateRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("aggregate_name", node.aggregate_name());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::ALL => {
+            let node = ast::All {
+                syntax: node.clone(),
+            };
+            fields.add("all_token", node.all_token());
+        }
+        SyntaxKind::ALL_FN => {
+            let node = ast::AllFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("select_variant", node.select_variant());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("all_token", node.all_token());
+        }
+        SyntaxKind::ALL_IN_TABLESPACE => {
+            let node = ast::AllInTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("nowait", node.nowait());
+            fields.add("owned_by_roles", node.owned_by_roles());
+            fields.add("set_tablespace", node.set_tablespace());
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("all_token", node.all_token());
+            fields.add("in_token", node.in_token());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::ALL_PRIVILEGES => {
+            let node = ast::AllPrivileges {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("all_token", node.all_token());
+            fields.add("privileges_token", node.privileges_token());
+        }
+        SyntaxKind::ALL_PROPERTIES => {
+            let node = ast::AllProperties {
+                syntax: node.clone(),
+            };
+            fields.add("all_token", node.all_token());
+            fields.add("columns_token", node.columns_token());
+            fields.add("properties_token", node.properties_token());
+        }
+        SyntaxKind::ALL_PUBLICATION_SEQUENCES => {
+            let node = ast::AllPublicationSequences {
+                syntax: node.clone(),
+            };
+            fields.add("all_token", node.all_token());
+            fields.add("sequences_token", node.sequences_token());
+        }
+        SyntaxKind::ALL_PUBLICATION_TABLES => {
+            let node = ast::AllPublicationTables {
+                syntax: node.clone(),
+            };
+            fields.add("all_token", node.all_token());
+            fields.add("tables_token", node.tables_token());
+        }
+        SyntaxKind::ALTER_AGGREGATE => {
+            let node = ast::AlterAggregate {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("aggregate", node.aggregate());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("aggregate_token", node.aggregate_token());
+            fields.add("alter_token", node.alter_token());
+        }
+        SyntaxKind::ALTER_ATTRIBUTE => {
+            let node = ast::AlterAttribute {
+                syntax: node.clone(),
+            };
+            fields.add("collate", node.collate());
+            fields.add("composite_field_ref", node.composite_field_ref());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("type", node.ty());
+            fields.add("alter_token", node.alter_token());
+            fields.add("attribute_token", node.attribute_token());
+            fields.add("data_token", node.data_token());
+            fields.add("set_token", node.set_token());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::ALTER_COLLATION => {
+            let node = ast::AlterCollation {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("collation_ref", node.collation_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("collation_token", node.collation_token());
+        }
+        SyntaxKind::ALTER_COLUMN => {
+            let node = ast::AlterColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("option", node.option());
+            fields.add("alter_token", node.alter_token());
+            fields.add("column_token", node.column_token());
+        }
+        SyntaxKind::ALTER_CONSTRAINT => {
+            let node = ast::AlterConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_ref", node.constraint_name_ref());
+            fields.add(
+                "deferrable_constraint_option",
+                node.deferrable_constraint_option(),
+            );
+            fields.add(
+                "initially_deferred_constraint_option",
+                node.initially_deferred_constraint_option(),
+            );
+            fields.add(
+                "initially_immediate_constraint_option",
+                node.initially_immediate_constraint_option(),
+            );
+            fields.add(
+                "not_deferrable_constraint_option",
+                node.not_deferrable_constraint_option(),
+            );
+            fields.add("alter_token", node.alter_token());
+            fields.add("constraint_token", node.constraint_token());
+        }
+        SyntaxKind::ALTER_CONVERSION => {
+            let node = ast::AlterConversion {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("conversion_ref", node.conversion_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("conversion_token", node.conversion_token());
+        }
+        SyntaxKind::ALTER_DATABASE => {
+            let node = ast::AlterDatabase {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("database_ref", node.database_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("database_token", node.database_token());
+        }
+        SyntaxKind::ALTER_DEFAULT_PRIVILEGES => {
+            let node = ast::AlterDefaultPrivileges {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("for_roles", node.for_roles());
+            fields.add("in_schemas", node.in_schemas());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("default_token", node.default_token());
+            fields.add("privileges_token", node.privileges_token());
+        }
+        SyntaxKind::ALTER_DOMAIN => {
+            let node = ast::AlterDomain {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("domain_ref", node.domain_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("domain_token", node.domain_token());
+        }
+        SyntaxKind::ALTER_EVENT_TRIGGER => {
+            let node = ast::AlterEventTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("event_trigger_ref", node.event_trigger_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("event_token", node.event_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::ALTER_EXTENSION => {
+            let node = ast::AlterExtension {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("extension_ref", node.extension_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("extension_token", node.extension_token());
+        }
+        SyntaxKind::ALTER_EXTENSION_ADD => {
+            let node = ast::AlterExtensionAdd {
+                syntax: node.clone(),
+            };
+            fields.add("extension_member_object", node.extension_member_object());
+            fields.add("add_token", node.add_token());
+        }
+        SyntaxKind::ALTER_EXTENSION_DROP => {
+            let node = ast::AlterExtensionDrop {
+                syntax: node.clone(),
+            };
+            fields.add("extension_member_object", node.extension_member_object());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::ALTER_EXTENSION_UPDATE => {
+            let node = ast::AlterExtensionUpdate {
+                syntax: node.clone(),
+            };
+            fields.add("update_to", node.update_to());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::ALTER_FOREIGN_DATA_WRAPPER => {
+            let node = ast::AlterForeignDataWrapper {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("foreign_data_wrapper_ref", node.foreign_data_wrapper_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("data_token", node.data_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::ALTER_FOREIGN_TABLE => {
+            let node = ast::AlterForeignTable {
+                syntax: node.clone(),
+            };
+            fields.add("actions", node.actions());
+            fields.add("if_exists", node.if_exists());
+            fields.add("table_relation_name", node.table_relation_name());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::ALTER_FUNCTION => {
+            let node = ast::AlterFunction {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("function_sig", node.function_sig());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("function_token", node.function_token());
+            fields.add("restrict_token", node.restrict_token());
+        }
+        SyntaxKind::ALTER_GROUP => {
+            let node = ast::AlterGroup {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("role_ref", node.role_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("group_token", node.group_token());
+        }
+        SyntaxKind::ALTER_INDEX => {
+            let node = ast::AlterIndex {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("all_in_tablespace", node.all_in_tablespace());
+            fields.add("if_exists", node.if_exists());
+            fields.add("index_ref", node.index_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("index_token", node.index_token());
+        }
+        SyntaxKind::ALTER_LANGUAGE => {
+            let node = ast::AlterLanguage {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("language_ref", node.language_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("language_token", node.language_token());
+            fields.add("procedural_token", node.procedural_token());
+        }
+        SyntaxKind::ALTER_LARGE_OBJECT => {
+            let node = ast::AlterLargeObject {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("role_ref", node.role_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("large_token", node.large_token());
+            fields.add("object_token", node.object_token());
+            fields.add("owner_token", node.owner_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::ALTER_MAPPING => {
+            let node = ast::AlterMapping {
+                syntax: node.clone(),
+            };
+            fields.add("replace_dictionary", node.replace_dictionary());
+            fields.add(
+                "text_search_dictionary_refs",
+                node.text_search_dictionary_refs(),
+            );
+            fields.add("text_search_token_kinds", node.text_search_token_kinds());
+            fields.add("alter_token", node.alter_token());
+            fields.add("for_token", node.for_token());
+            fields.add("mapping_token", node.mapping_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::ALTER_MATERIALIZED_VIEW => {
+            let node = ast::AlterMaterializedView {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("all_in_tablespace", node.all_in_tablespace());
+            fields.add("if_exists", node.if_exists());
+            fields.add("view_ref", node.view_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("materialized_token", node.materialized_token());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::ALTER_OPERATOR => {
+            let node = ast::AlterOperator {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("op_sig", node.op_sig());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::ALTER_OPERATOR_CLASS => {
+            let node = ast::AlterOperatorClass {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("op_class_ref", node.op_class_ref());
+            fields.add("using_method", node.using_method());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("class_token", node.class_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::ALTER_OPERATOR_FAMILY => {
+            let node = ast::AlterOperatorFamily {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("op_family_ref", node.op_family_ref());
+            fields.add("using_method", node.using_method());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("family_token", node.family_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::ALTER_OPTION_LIST => {
+            let node = ast::AlterOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("alter_options", node.alter_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("options_token", node.options_token());
+        }
+        SyntaxKind::ALTER_POLICY => {
+            let node = ast::AlterPolicy {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("on_table", node.on_table());
+            fields.add("policy_ref", node.policy_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("policy_token", node.policy_token());
+        }
+        SyntaxKind::ALTER_POLICY_TO => {
+            let node = ast::AlterPolicyTo {
+                syntax: node.clone(),
+            };
+            fields.add("policy_roles", node.policy_roles());
+            fields.add("using_expr_clause", node.using_expr_clause());
+            fields.add("with_check_expr_clause", node.with_check_expr_clause());
+        }
+        SyntaxKind::ALTER_PROCEDURE => {
+            let node = ast::AlterProcedure {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("procedure_sig", node.procedure_sig());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("procedure_token", node.procedure_token());
+            fields.add("restrict_token", node.restrict_token());
+        }
+        SyntaxKind::ALTER_PROPERTY_GRAPH => {
+            let node = ast::AlterPropertyGraph {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("if_exists", node.if_exists());
+            fields.add("property_graph_ref", node.property_graph_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("graph_token", node.graph_token());
+            fields.add("property_token", node.property_token());
+        }
+        SyntaxKind::ALTER_PUBLICATION => {
+            let node = ast::AlterPublication {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("publication_ref", node.publication_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("publication_token", node.publication_token());
+        }
+        SyntaxKind::ALTER_ROLE => {
+            let node = ast::AlterRole {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("in_database", node.in_database());
+            fields.add("role_ref", node.role_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("all_token", node.all_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("role_token", node.role_token());
+        }
+        SyntaxKind::ALTER_ROUTINE => {
+            let node = ast::AlterRoutine {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("routine_sig", node.routine_sig());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("restrict_token", node.restrict_token());
+            fields.add("routine_token", node.routine_token());
+        }
+        SyntaxKind::ALTER_RULE => {
+            let node = ast::AlterRule {
+                syntax: node.clone(),
+            };
+            fields.add("on_relation", node.on_relation());
+            fields.add("rule_ref", node.rule_ref());
+            fields.add("rule_rename_to", node.rule_rename_to());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("rule_token", node.rule_token());
+        }
+        SyntaxKind::ALTER_SCHEMA => {
+            let node = ast::AlterSchema {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("schema_ref", node.schema_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::ALTER_SEQUENCE => {
+            let node = ast::AlterSequence {
+                syntax: node.clone(),
+            };
+            fields.add("actions", node.actions());
+            fields.add("if_exists", node.if_exists());
+            fields.add("sequence_ref", node.sequence_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("sequence_token", node.sequence_token());
+        }
+        SyntaxKind::ALTER_SERVER => {
+            let node = ast::AlterServer {
+                syntax: node.clone(),
+            };
+            fields.add("actions", node.actions());
+            fields.add("server_ref", node.server_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("server_token", node.server_token());
+        }
+        SyntaxKind::ALTER_SET_STATISTICS => {
+            let node = ast::AlterSetStatistics {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("literal", node.literal());
+            fields.add("set_options", node.set_options());
+            fields.add("set_statistics", node.set_statistics());
+            fields.add("alter_token", node.alter_token());
+            fields.add("column_token", node.column_token());
+        }
+        SyntaxKind::ALTER_STATISTICS => {
+            let node = ast::AlterStatistics {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("statistics_ref", node.statistics_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("statistics_token", node.statistics_token());
+        }
+        SyntaxKind::ALTER_SUBSCRIPTION => {
+            let node = ast::AlterSubscription {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("subscription_ref", node.subscription_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("subscription_token", node.subscription_token());
+        }
+        SyntaxKind::ALTER_SYSTEM => {
+            let node = ast::AlterSystem {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("system_token", node.system_token());
+        }
+        SyntaxKind::ALTER_TABLE => {
+            let node = ast::AlterTable {
+                syntax: node.clone(),
+            };
+            fields.add("actions", node.actions());
+            fields.add("all_in_tablespace", node.all_in_tablespace());
+            fields.add("if_exists", node.if_exists());
+            fields.add("table_relation_name", node.table_relation_name());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::ALTER_TABLESPACE => {
+            let node = ast::AlterTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::ALTER_TEXT_SEARCH_CONFIGURATION => {
+            let node = ast::AlterTextSearchConfiguration {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add(
+                "text_search_configuration_ref",
+                node.text_search_configuration_ref(),
+            );
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("configuration_token", node.configuration_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::ALTER_TEXT_SEARCH_DICTIONARY => {
+            let node = ast::AlterTextSearchDictionary {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add(
+                "text_search_dictionary_ref",
+                node.text_search_dictionary_ref(),
+            );
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("dictionary_token", node.dictionary_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::ALTER_TEXT_SEARCH_PARSER => {
+            let node = ast::AlterTextSearchParser {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("text_search_parser_ref", node.text_search_parser_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("parser_token", node.parser_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::ALTER_TEXT_SEARCH_TEMPLATE => {
+            let node = ast::AlterTextSearchTemplate {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("text_search_template_ref", node.text_search_template_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("search_token", node.search_token());
+            fields.add("template_token", node.template_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::ALTER_TRIGGER => {
+            let node = ast::AlterTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("on_relation", node.on_relation());
+            fields.add("trigger_ref", node.trigger_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::ALTER_TYPE => {
+            let node = ast::AlterType {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("type_name_ref", node.type_name_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::ALTER_TYPE_ATTRIBUTE_ACTION_LIST => {
+            let node = ast::AlterTypeAttributeActionList {
+                syntax: node.clone(),
+            };
+            fields.add("actions", node.actions());
+        }
+        SyntaxKind::ALTER_USER => {
+            let node = ast::AlterUser {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("in_database", node.in_database());
+            fields.add("role_ref", node.role_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("all_token", node.all_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::ALTER_USER_MAPPING => {
+            let node = ast::AlterUserMapping {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("role_ref", node.role_ref());
+            fields.add("server_clause", node.server_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("for_token", node.for_token());
+            fields.add("mapping_token", node.mapping_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::ALTER_VERTEX_EDGE_LABELS => {
+            let node = ast::AlterVertexEdgeLabels {
+                syntax: node.clone(),
+            };
+            fields.add("add_label", node.add_label());
+            fields.add("add_labels", node.add_labels());
+            fields.add("element_table_ref", node.element_table_ref());
+            fields.add("alter_token", node.alter_token());
+            fields.add("edge_token", node.edge_token());
+            fields.add("node_token", node.node_token());
+            fields.add("relationship_token", node.relationship_token());
+            fields.add("table_token", node.table_token());
+            fields.add("vertex_token", node.vertex_token());
+        }
+        SyntaxKind::ALTER_VIEW => {
+            let node = ast::AlterView {
+                syntax: node.clone(),
+            };
+            fields.add("action", node.action());
+            fields.add("if_exists", node.if_exists());
+            fields.add("view_ref", node.view_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::ALTER_VIEW_COLUMN => {
+            let node = ast::AlterViewColumn {
+                syntax: node.clone(),
+            };
+            fields.add("alter_view_column_action", node.alter_view_column_action());
+            fields.add("name", node.name());
+            fields.add("alter_token", node.alter_token());
+            fields.add("column_token", node.column_token());
+        }
+        SyntaxKind::ANALYZE => {
+            let node = ast::Analyze {
+                syntax: node.clone(),
+            };
+            fields.add("option_item_list", node.option_item_list());
+            fields.add("table_and_columns_list", node.table_and_columns_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("analyse_token", node.analyse_token());
+            fields.add("analyze_token", node.analyze_token());
+            fields.add("verbose_token", node.verbose_token());
+        }
+        SyntaxKind::AND_CHAIN => {
+            let node = ast::AndChain {
+                syntax: node.clone(),
+            };
+            fields.add("and_token", node.and_token());
+            fields.add("chain_token", node.chain_token());
+        }
+        SyntaxKind::AND_NO_CHAIN => {
+            let node = ast::AndNoChain {
+                syntax: node.clone(),
+            };
+            fields.add("and_token", node.and_token());
+            fields.add("chain_token", node.chain_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::ANY_FN => {
+            let node = ast::AnyFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("select_variant", node.select_variant());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("any_token", node.any_token());
+        }
+        SyntaxKind::ARG => {
+            let node = ast::Arg {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("named_arg", node.named_arg());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("variadic_token", node.variadic_token());
+        }
+        SyntaxKind::ARG_LIST => {
+            let node = ast::ArgList {
+                syntax: node.clone(),
+            };
+            fields.add("all_or_distinct", node.all_or_distinct());
+            fields.add("args", node.args());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("star_token", node.star_token());
+        }
+        SyntaxKind::ARRAY_BOUND => {
+            let node = ast::ArrayBound {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+        }
+        SyntaxKind::ARRAY_EXPR => {
+            let node = ast::ArrayExpr {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("select", node.select());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+            fields.add("array_token", node.array_token());
+        }
+        SyntaxKind::ARRAY_TYPE => {
+            let node = ast::ArrayType {
+                syntax: node.clone(),
+            };
+            fields.add("array_bounds", node.array_bounds());
+            fields.add("type", node.ty());
+            fields.add("array_token", node.array_token());
+        }
+        SyntaxKind::AS_ASSIGNMENT => {
+            let node = ast::AsAssignment {
+                syntax: node.clone(),
+            };
+            fields.add("as_token", node.as_token());
+            fields.add("assignment_token", node.assignment_token());
+        }
+        SyntaxKind::AS_DEFINITION => {
+            let node = ast::AsDefinition {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+        }
+        SyntaxKind::AS_FUNC_OPTION => {
+            let node = ast::AsFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("as_func_target", node.as_func_target());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::AS_IMPLICIT => {
+            let node = ast::AsImplicit {
+                syntax: node.clone(),
+            };
+            fields.add("as_token", node.as_token());
+            fields.add("implicit_token", node.implicit_token());
+        }
+        SyntaxKind::AS_NAME => {
+            let node = ast::AsName {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::AS_OBJ_FILE => {
+            let node = ast::AsObjFile {
+                syntax: node.clone(),
+            };
+            fields.add("obj_file", node.obj_file());
+            fields.add("link_symbol", node.link_symbol());
+            fields.add("comma_token", node.comma_token());
+        }
+        SyntaxKind::AS_POLICY_TYPE => {
+            let node = ast::AsPolicyType {
+                syntax: node.clone(),
+            };
+            fields.add("as_token", node.as_token());
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ASENSITIVE => {
+            let node = ast::Asensitive {
+                syntax: node.clone(),
+            };
+            fields.add("asensitive_token", node.asensitive_token());
+        }
+        SyntaxKind::ASYMMETRIC => {
+            let node = ast::Asymmetric {
+                syntax: node.clone(),
+            };
+            fields.add("asymmetric_token", node.asymmetric_token());
+        }
+        SyntaxKind::AT_LOCAL => {
+            let node = ast::AtLocal {
+                syntax: node.clone(),
+            };
+            fields.add("at_token", node.at_token());
+            fields.add("local_token", node.local_token());
+        }
+        SyntaxKind::AT_TIME_ZONE => {
+            let node = ast::AtTimeZone {
+                syntax: node.clone(),
+            };
+            fields.add("at_token", node.at_token());
+            fields.add("time_token", node.time_token());
+            fields.add("zone_token", node.zone_token());
+        }
+        SyntaxKind::ATTACH_INDEX_PARTITION => {
+            let node = ast::AttachIndexPartition {
+                syntax: node.clone(),
+            };
+            fields.add("index_ref", node.index_ref());
+            fields.add("attach_token", node.attach_token());
+            fields.add("partition_token", node.partition_token());
+        }
+        SyntaxKind::ATTACH_PARTITION => {
+            let node = ast::AttachPartition {
+                syntax: node.clone(),
+            };
+            fields.add("partition_type", node.partition_type());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("attach_token", node.attach_token());
+            fields.add("partition_token", node.partition_token());
+        }
+        SyntaxKind::ATTRIBUTE_LIST => {
+            let node = ast::AttributeList {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_options", node.attribute_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::ATTRIBUTE_NAME => {
+            let node = ast::AttributeName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ATTRIBUTE_NAMESPACE => {
+            let node = ast::AttributeNamespace {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ATTRIBUTE_OPTION => {
+            let node = ast::AttributeOption {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_value", node.attribute_value());
+            fields.add("name", node.name());
+            fields.add("namespace", node.namespace());
+            fields.add("dot_token", node.dot_token());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::ATTRIBUTE_VALUE => {
+            let node = ast::AttributeValue {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("op", node.op());
+            fields.add("type", node.ty());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("none_token", node.none_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::AUTHORIZATION_SCHEMA => {
+            let node = ast::AuthorizationSchema {
+                syntax: node.clone(),
+            };
+            fields.add("role", node.role());
+            fields.add("authorization_token", node.authorization_token());
+        }
+        SyntaxKind::BACKWARD => {
+            let node = ast::Backward {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("all_token", node.all_token());
+            fields.add("backward_token", node.backward_token());
+        }
+        SyntaxKind::BASE_TYPE => {
+            let node = ast::BaseType {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+        }
+        SyntaxKind::BEFORE_VALUE => {
+            let node = ast::BeforeValue {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("before_token", node.before_token());
+        }
+        SyntaxKind::BEGIN => {
+            let node = ast::Begin {
+                syntax: node.clone(),
+            };
+            fields.add("transaction_mode_list", node.transaction_mode_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("begin_token", node.begin_token());
+            fields.add("start_token", node.start_token());
+            fields.add("transaction_token", node.transaction_token());
+            fields.add("work_token", node.work_token());
+        }
+        SyntaxKind::BEGIN_FUNC_OPTION_LIST => {
+            let node = ast::BeginFuncOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("begin_func_options", node.begin_func_options());
+            fields.add("atomic_token", node.atomic_token());
+            fields.add("begin_token", node.begin_token());
+            fields.add("end_token", node.end_token());
+        }
+        SyntaxKind::BETWEEN_EXPR => {
+            let node = ast::BetweenExpr {
+                syntax: node.clone(),
+            };
+            fields.add("target", node.target());
+            fields.add("start", node.start());
+            fields.add("end", node.end());
+            fields.add("between_symmetry", node.between_symmetry());
+            fields.add("and_token", node.and_token());
+            fields.add("between_token", node.between_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::BIN_EXPR => {
+            let node = ast::BinExpr {
+                syntax: node.clone(),
+            };
+            fields.add("lhs", node.lhs());
+            fields.add("op", node.op());
+            fields.add("rhs", node.rhs());
+        }
+        SyntaxKind::BIND_PARAM_NAME_REF => {
+            let node = ast::BindParamNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::BIT_TYPE => {
+            let node = ast::BitType {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("bit_token", node.bit_token());
+            fields.add("setof_token", node.setof_token());
+        }
+        SyntaxKind::BIT_VARYING_TYPE => {
+            let node = ast::BitVaryingType {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("bit_token", node.bit_token());
+            fields.add("setof_token", node.setof_token());
+            fields.add("varying_token", node.varying_token());
+        }
+        SyntaxKind::BREADTH_FIRST => {
+            let node = ast::BreadthFirst {
+                syntax: node.clone(),
+            };
+            fields.add("breadth_token", node.breadth_token());
+            fields.add("first_token", node.first_token());
+        }
+        SyntaxKind::BY_TARGET => {
+            let node = ast::ByTarget {
+                syntax: node.clone(),
+            };
+            fields.add("by_token", node.by_token());
+            fields.add("target_token", node.target_token());
+        }
+        SyntaxKind::CALL => {
+            let node = ast::Call {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("procedure_name_ref", node.procedure_name_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("call_token", node.call_token());
+        }
+        SyntaxKind::CALL_EXPR => {
+            let node = ast::CallExpr {
+                syntax: node.clone(),
+            };
+            fields.add("all_fn", node.all_fn());
+            fields.add("any_fn", node.any_fn());
+            fields.add("arg_list", node.arg_list());
+            fields.add("collation_for_fn", node.collation_for_fn());
+            fields.add("exists_fn", node.exists_fn());
+            fields.add("expr", node.expr());
+            fields.add("extract_fn", node.extract_fn());
+            fields.add("filter_clause", node.filter_clause());
+            fields.add("graph_table_fn", node.graph_table_fn());
+            fields.add("json_array_agg_fn", node.json_array_agg_fn());
+            fields.add("json_array_fn", node.json_array_fn());
+            fields.add("json_exists_fn", node.json_exists_fn());
+            fields.add("json_fn", node.json_fn());
+            fields.add("json_object_agg_fn", node.json_object_agg_fn());
+            fields.add("json_object_fn", node.json_object_fn());
+            fields.add("json_query_fn", node.json_query_fn());
+            fields.add("json_scalar_fn", node.json_scalar_fn());
+            fields.add("json_serialize_fn", node.json_serialize_fn());
+            fields.add("json_value_fn", node.json_value_fn());
+            fields.add("null_treatment", node.null_treatment());
+            fields.add("over_clause", node.over_clause());
+            fields.add("overlay_fn", node.overlay_fn());
+            fields.add("position_fn", node.position_fn());
+            fields.add("some_fn", node.some_fn());
+            fields.add("substring_fn", node.substring_fn());
+            fields.add("trim_fn", node.trim_fn());
+            fields.add("within_clause", node.within_clause());
+            fields.add("xml_element_fn", node.xml_element_fn());
+            fields.add("xml_exists_fn", node.xml_exists_fn());
+            fields.add("xml_forest_fn", node.xml_forest_fn());
+            fields.add("xml_parse_fn", node.xml_parse_fn());
+            fields.add("xml_pi_fn", node.xml_pi_fn());
+            fields.add("xml_root_fn", node.xml_root_fn());
+            fields.add("xml_serialize_fn", node.xml_serialize_fn());
+        }
+        SyntaxKind::CALLED_ON_NULL_INPUT_FUNC_OPTION => {
+            let node = ast::CalledOnNullInputFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("called_token", node.called_token());
+            fields.add("input_token", node.input_token());
+            fields.add("null_token", node.null_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::CASCADE => {
+            let node = ast::Cascade {
+                syntax: node.clone(),
+            };
+            fields.add("cascade_token", node.cascade_token());
+        }
+        SyntaxKind::CASCADED_CHECK_OPTION => {
+            let node = ast::CascadedCheckOption {
+                syntax: node.clone(),
+            };
+            fields.add("cascaded_token", node.cascaded_token());
+        }
+        SyntaxKind::CASE_EXPR => {
+            let node = ast::CaseExpr {
+                syntax: node.clone(),
+            };
+            fields.add("else_clause", node.else_clause());
+            fields.add("expr", node.expr());
+            fields.add("when_clause_list", node.when_clause_list());
+            fields.add("case_token", node.case_token());
+            fields.add("end_token", node.end_token());
+        }
+        SyntaxKind::CAST_EXPR => {
+            let node = ast::CastExpr {
+                syntax: node.clone(),
+            };
+            fields.add("colon_colon", node.colon_colon());
+            fields.add("expr", node.expr());
+            fields.add("interval_qualifier", node.interval_qualifier());
+            fields.add("literal", node.literal());
+            fields.add("type", node.ty());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("as_token", node.as_token());
+            fields.add("cast_token", node.cast_token());
+            fields.add("treat_token", node.treat_token());
+        }
+        SyntaxKind::CAST_SIG => {
+            let node = ast::CastSig {
+                syntax: node.clone(),
+            };
+            fields.add("lhs", node.lhs());
+            fields.add("rhs", node.rhs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::CHANNEL => {
+            let node = ast::Channel {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CHANNEL_REF => {
+            let node = ast::ChannelRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CHARACTER_TYPE => {
+            let node = ast::CharacterType {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("char_token", node.char_token());
+            fields.add("character_token", node.character_token());
+            fields.add("national_token", node.national_token());
+            fields.add("nchar_token", node.nchar_token());
+            fields.add("setof_token", node.setof_token());
+        }
+        SyntaxKind::CHECK_CONSTRAINT => {
+            let node = ast::CheckConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("check_token", node.check_token());
+        }
+        SyntaxKind::CHECKPOINT => {
+            let node = ast::Checkpoint {
+                syntax: node.clone(),
+            };
+            fields.add("checkpoint_option_list", node.checkpoint_option_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("checkpoint_token", node.checkpoint_token());
+        }
+        SyntaxKind::CHECKPOINT_OPTION => {
+            let node = ast::CheckpointOption {
+                syntax: node.clone(),
+            };
+            fields.add("checkpoint_option_name", node.checkpoint_option_name());
+            fields.add("expr", node.expr());
+        }
+        SyntaxKind::CHECKPOINT_OPTION_LIST => {
+            let node = ast::CheckpointOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("checkpoint_options", node.checkpoint_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::CHECKPOINT_OPTION_NAME => {
+            let node = ast::CheckpointOptionName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CLOSE => {
+            let node = ast::Close {
+                syntax: node.clone(),
+            };
+            fields.add("cursor_ref", node.cursor_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("all_token", node.all_token());
+            fields.add("close_token", node.close_token());
+        }
+        SyntaxKind::CLUSTER => {
+            let node = ast::Cluster {
+                syntax: node.clone(),
+            };
+            fields.add("cluster_legacy", node.cluster_legacy());
+            fields.add("cluster_using_index", node.cluster_using_index());
+            fields.add("option_item_list", node.option_item_list());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("cluster_token", node.cluster_token());
+            fields.add("verbose_token", node.verbose_token());
+        }
+        SyntaxKind::CLUSTER_LEGACY => {
+            let node = ast::ClusterLegacy {
+                syntax: node.clone(),
+            };
+            fields.add("index_ref", node.index_ref());
+            fields.add("on_path", node.on_path());
+        }
+        SyntaxKind::CLUSTER_ON => {
+            let node = ast::ClusterOn {
+                syntax: node.clone(),
+            };
+            fields.add("index_ref", node.index_ref());
+            fields.add("cluster_token", node.cluster_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::CLUSTER_USING_INDEX => {
+            let node = ast::ClusterUsingIndex {
+                syntax: node.clone(),
+            };
+            fields.add("index_ref", node.index_ref());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::COLLATE => {
+            let node = ast::Collate {
+                syntax: node.clone(),
+            };
+            fields.add("collation_ref", node.collation_ref());
+            fields.add("expr", node.expr());
+            fields.add("collate_token", node.collate_token());
+        }
+        SyntaxKind::COLLATION => {
+            let node = ast::Collation {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::COLLATION_FOR_FN => {
+            let node = ast::CollationForFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("collation_token", node.collation_token());
+            fields.add("for_token", node.for_token());
+        }
+        SyntaxKind::COLLATION_FROM => {
+            let node = ast::CollationFrom {
+                syntax: node.clone(),
+            };
+            fields.add("collation_ref", node.collation_ref());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::COLLATION_REF => {
+            let node = ast::CollationRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::COLLATION_RENAME_TO => {
+            let node = ast::CollationRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("collation", node.collation());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::COLON_COLON => {
+            let node = ast::ColonColon {
+                syntax: node.clone(),
+            };
+            fields.add("colon_token", node.colon_token());
+        }
+        SyntaxKind::COLON_EQ => {
+            let node = ast::ColonEq {
+                syntax: node.clone(),
+            };
+            fields.add("colon_token", node.colon_token());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::COLUMN => {
+            let node = ast::Column {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("collate", node.collate());
+            fields.add("compression_method", node.compression_method());
+            fields.add("constraints", node.constraints());
+            fields.add("name", node.name());
+            fields.add("storage", node.storage());
+            fields.add("type", node.ty());
+            fields.add("with_options", node.with_options());
+        }
+        SyntaxKind::COLUMN_DEF => {
+            let node = ast::ColumnDef {
+                syntax: node.clone(),
+            };
+            fields.add("collate", node.collate());
+            fields.add("name", node.name());
+            fields.add("type", node.ty());
+        }
+        SyntaxKind::COLUMN_DEF_LIST => {
+            let node = ast::ColumnDefList {
+                syntax: node.clone(),
+            };
+            fields.add("column_defs", node.column_defs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::COLUMN_LIST => {
+            let node = ast::ColumnList {
+                syntax: node.clone(),
+            };
+            fields.add("column_names", node.column_names());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::COLUMN_NAME => {
+            let node = ast::ColumnName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::COLUMN_NAME_REF => {
+            let node = ast::ColumnNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::COLUMN_REF_LIST => {
+            let node = ast::ColumnRefList {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_refs", node.column_name_refs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::COLUMN_TARGET => {
+            let node = ast::ColumnTarget {
+                syntax: node.clone(),
+            };
+            fields.add("accessors", node.accessors());
+            fields.add("name", node.name());
+        }
+        SyntaxKind::COLUMN_TARGET_LIST => {
+            let node = ast::ColumnTargetList {
+                syntax: node.clone(),
+            };
+            fields.add("column_targets", node.column_targets());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::COMMENT_ON => {
+            let node = ast::CommentOn {
+                syntax: node.clone(),
+            };
+            fields.add("comment_object", node.comment_object());
+            fields.add("literal", node.literal());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("comment_token", node.comment_token());
+            fields.add("is_token", node.is_token());
+            fields.add("null_token", node.null_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::COMMIT_PREPARED => {
+            let node = ast::CommitPrepared {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("commit_token", node.commit_token());
+            fields.add("prepared_token", node.prepared_token());
+        }
+        SyntaxKind::COMMIT_TRANSACTION => {
+            let node = ast::CommitTransaction {
+                syntax: node.clone(),
+            };
+            fields.add("chain_clause", node.chain_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("commit_token", node.commit_token());
+            fields.add("end_token", node.end_token());
+            fields.add("transaction_token", node.transaction_token());
+            fields.add("work_token", node.work_token());
+        }
+        SyntaxKind::COMPOSITE_FIELD => {
+            let node = ast::CompositeField {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::COMPOSITE_FIELD_DEF => {
+            let node = ast::CompositeFieldDef {
+                syntax: node.clone(),
+            };
+            fields.add("collate", node.collate());
+            fields.add("name", node.name());
+            fields.add("type", node.ty());
+        }
+        SyntaxKind::COMPOSITE_FIELD_LIST => {
+            let node = ast::CompositeFieldList {
+                syntax: node.clone(),
+            };
+            fields.add("composite_field_defs", node.composite_field_defs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::COMPOSITE_FIELD_REF => {
+            let node = ast::CompositeFieldRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::COMPOSITE_TYPE => {
+            let node = ast::CompositeType {
+                syntax: node.clone(),
+            };
+            fields.add("composite_field_list", node.composite_field_list());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::COMPOUND_SELECT => {
+            let node = ast::CompoundSelect {
+                syntax: node.clone(),
+            };
+            fields.add("lhs", node.lhs());
+            fields.add("op", node.op());
+            fields.add("rhs", node.rhs());
+            fields.add("fetch_clause", node.fetch_clause());
+            fields.add("limit_clause", node.limit_clause());
+            fields.add("locking_clauses", node.locking_clauses());
+            fields.add("offset_clause", node.offset_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+        }
+        SyntaxKind::COMPRESSION_METHOD => {
+            let node = ast::CompressionMethod {
+                syntax: node.clone(),
+            };
+            fields.add("compression_method_name", node.compression_method_name());
+            fields.add("compression_token", node.compression_token());
+        }
+        SyntaxKind::COMPRESSION_METHOD_NAME => {
+            let node = ast::CompressionMethodName {
+                syntax: node.clone(),
+            };
+            fields.add("default_token", node.default_token());
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CONFIG_PARAMETER_REF => {
+            let node = ast::ConfigParameterRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::CONFIG_VALUE_NAME => {
+            let node = ast::ConfigValueName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CONFLICT_DO_NOTHING => {
+            let node = ast::ConflictDoNothing {
+                syntax: node.clone(),
+            };
+            fields.add("do_token", node.do_token());
+            fields.add("nothing_token", node.nothing_token());
+        }
+        SyntaxKind::CONFLICT_DO_SELECT => {
+            let node = ast::ConflictDoSelect {
+                syntax: node.clone(),
+            };
+            fields.add("locking_clause", node.locking_clause());
+            fields.add("where_clause", node.where_clause());
+            fields.add("do_token", node.do_token());
+            fields.add("select_token", node.select_token());
+        }
+        SyntaxKind::CONFLICT_DO_UPDATE_SET => {
+            let node = ast::ConflictDoUpdateSet {
+                syntax: node.clone(),
+            };
+            fields.add("set_clause", node.set_clause());
+            fields.add("where_clause", node.where_clause());
+            fields.add("do_token", node.do_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::CONFLICT_INDEX_ITEM => {
+            let node = ast::ConflictIndexItem {
+                syntax: node.clone(),
+            };
+            fields.add("collate", node.collate());
+            fields.add("expr", node.expr());
+            fields.add("op_class_ref", node.op_class_ref());
+        }
+        SyntaxKind::CONFLICT_INDEX_ITEM_LIST => {
+            let node = ast::ConflictIndexItemList {
+                syntax: node.clone(),
+            };
+            fields.add("conflict_index_items", node.conflict_index_items());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::CONFLICT_ON_CONSTRAINT => {
+            let node = ast::ConflictOnConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_ref", node.constraint_name_ref());
+            fields.add("constraint_token", node.constraint_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::CONFLICT_ON_INDEX => {
+            let node = ast::ConflictOnIndex {
+                syntax: node.clone(),
+            };
+            fields.add("conflict_index_item_list", node.conflict_index_item_list());
+            fields.add("where_clause", node.where_clause());
+        }
+        SyntaxKind::CONNECTION_CLAUSE => {
+            let node = ast::ConnectionClause {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("connection_token", node.connection_token());
+        }
+        SyntaxKind::CONSTRAINT_COLUMN_REF_LIST => {
+            let node = ast::ConstraintColumnRefList {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_refs", node.column_name_refs());
+            fields.add("without_overlaps", node.without_overlaps());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::CONSTRAINT_EXCLUSION => {
+            let node = ast::ConstraintExclusion {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("op", node.op());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::CONSTRAINT_EXCLUSION_LIST => {
+            let node = ast::ConstraintExclusionList {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_exclusions", node.constraint_exclusions());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::CONSTRAINT_INCLUDE_CLAUSE => {
+            let node = ast::ConstraintIncludeClause {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("include_token", node.include_token());
+        }
+        SyntaxKind::CONSTRAINT_INDEX_METHOD => {
+            let node = ast::ConstraintIndexMethod {
+                syntax: node.clone(),
+            };
+            fields.add("access_method_ref", node.access_method_ref());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::CONSTRAINT_INDEX_TABLESPACE => {
+            let node = ast::ConstraintIndexTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("index_token", node.index_token());
+            fields.add("tablespace_token", node.tablespace_token());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::CONSTRAINT_NAME => {
+            let node = ast::ConstraintName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CONSTRAINT_NAME_CLAUSE => {
+            let node = ast::ConstraintNameClause {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name", node.constraint_name());
+            fields.add("constraint_token", node.constraint_token());
+        }
+        SyntaxKind::CONSTRAINT_NAME_REF => {
+            let node = ast::ConstraintNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::CONTINUE_IDENTITY => {
+            let node = ast::ContinueIdentity {
+                syntax: node.clone(),
+            };
+            fields.add("continue_token", node.continue_token());
+            fields.add("identity_token", node.identity_token());
+        }
+        SyntaxKind::CONVERSION => {
+            let node = ast::Conversion {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::CONVERSION_REF => {
+            let node = ast::ConversionRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::CONVERSION_RENAME_TO => {
+            let node = ast::ConversionRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("conversion", node.conversion());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::COPY => {
+            let node = ast::Copy {
+                syntax: node.clone(),
+            };
+            fields.add("copy_direction", node.copy_direction());
+            fields.add("copy_legacy_options", node.copy_legacy_options());
+            fields.add("copy_option_list", node.copy_option_list());
+            fields.add("copy_query", node.copy_query());
+            fields.add("copy_table", node.copy_table());
+            fields.add("where_clause", node.where_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("copy_token", node.copy_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::COPY_FROM => {
+            let node = ast::CopyFrom {
+                syntax: node.clone(),
+            };
+            fields.add("copy_source", node.copy_source());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::COPY_LEGACY_OPTION => {
+            let node = ast::CopyLegacyOption {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_refs", node.column_name_refs());
+            fields.add("copy_force_kind", node.copy_force_kind());
+            fields.add("literal", node.literal());
+            fields.add("star_token", node.star_token());
+            fields.add("as_token", node.as_token());
+            fields.add("binary_token", node.binary_token());
+            fields.add("csv_token", node.csv_token());
+            fields.add("delimiter_token", node.delimiter_token());
+            fields.add("encoding_token", node.encoding_token());
+            fields.add("escape_token", node.escape_token());
+            fields.add("force_token", node.force_token());
+            fields.add("freeze_token", node.freeze_token());
+            fields.add("header_token", node.header_token());
+            fields.add("json_token", node.json_token());
+            fields.add("null_token", node.null_token());
+            fields.add("quote_token", node.quote_token());
+        }
+        SyntaxKind::COPY_OPTION => {
+            let node = ast::CopyOption {
+                syntax: node.clone(),
+            };
+            fields.add("copy_option_key", node.copy_option_key());
+            fields.add("copy_option_value", node.copy_option_value());
+        }
+        SyntaxKind::COPY_OPTION_KEY => {
+            let node = ast::CopyOptionKey {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::COPY_OPTION_LIST => {
+            let node = ast::CopyOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("copy_options", node.copy_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::COPY_OPTION_VALUE => {
+            let node = ast::CopyOptionValue {
+                syntax: node.clone(),
+            };
+            fields.add("copy_option_list", node.copy_option_list());
+            fields.add("copy_option_value_name", node.copy_option_value_name());
+            fields.add("expr", node.expr());
+            fields.add("star_token", node.star_token());
+            fields.add("default_token", node.default_token());
+            fields.add("off_token", node.off_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::COPY_OPTION_VALUE_NAME => {
+            let node = ast::CopyOptionValueName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::COPY_PROGRAM => {
+            let node = ast::CopyProgram {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("program_token", node.program_token());
+        }
+        SyntaxKind::COPY_QUERY => {
+            let node = ast::CopyQuery {
+                syntax: node.clone(),
+            };
+            fields.add("preparable_stmt", node.preparable_stmt());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::COPY_STDIN => {
+            let node = ast::CopyStdin {
+                syntax: node.clone(),
+            };
+            fields.add("stdin_token", node.stdin_token());
+        }
+        SyntaxKind::COPY_STDIN_DATA => {
+            let node = ast::CopyStdinData {
+                syntax: node.clone(),
+            };
+            fields.add("copy_data_token", node.copy_data_token());
+        }
+        SyntaxKind::COPY_STDOUT => {
+            let node = ast::CopyStdout {
+                syntax: node.clone(),
+            };
+            fields.add("stdout_token", node.stdout_token());
+        }
+        SyntaxKind::COPY_TABLE => {
+            let node = ast::CopyTable {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("binary_token", node.binary_token());
+        }
+        SyntaxKind::COPY_TO => {
+            let node = ast::CopyTo {
+                syntax: node.clone(),
+            };
+            fields.add("copy_target", node.copy_target());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::COST_FUNC_OPTION => {
+            let node = ast::CostFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("cost_token", node.cost_token());
+        }
+        SyntaxKind::CREATE_ACCESS_METHOD => {
+            let node = ast::CreateAccessMethod {
+                syntax: node.clone(),
+            };
+            fields.add("access_method", node.access_method());
+            fields.add("access_method_type", node.access_method_type());
+            fields.add("handler_clause", node.handler_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("access_token", node.access_token());
+            fields.add("create_token", node.create_token());
+            fields.add("method_token", node.method_token());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::CREATE_AGGREGATE => {
+            let node = ast::CreateAggregate {
+                syntax: node.clone(),
+            };
+            fields.add("aggregate_name", node.aggregate_name());
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("or_replace", node.or_replace());
+            fields.add("param_list", node.param_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("aggregate_token", node.aggregate_token());
+            fields.add("create_token", node.create_token());
+        }
+        SyntaxKind::CREATE_CAST => {
+            let node = ast::CreateCast {
+                syntax: node.clone(),
+            };
+            fields.add("cast_context", node.cast_context());
+            fields.add("cast_method", node.cast_method());
+            fields.add("cast_sig", node.cast_sig());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("cast_token", node.cast_token());
+            fields.add("create_token", node.create_token());
+        }
+        SyntaxKind::CREATE_COLLATION => {
+            let node = ast::CreateCollation {
+                syntax: node.clone(),
+            };
+            fields.add("collation", node.collation());
+            fields.add("definition", node.definition());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("collation_token", node.collation_token());
+            fields.add("create_token", node.create_token());
+        }
+        SyntaxKind::CREATE_CONVERSION => {
+            let node = ast::CreateConversion {
+                syntax: node.clone(),
+            };
+            fields.add("for", node.for_());
+            fields.add("to", node.to());
+            fields.add("conversion", node.conversion());
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("conversion_token", node.conversion_token());
+            fields.add("create_token", node.create_token());
+            fields.add("default_token", node.default_token());
+            fields.add("for_token", node.for_token());
+            fields.add("from_token", node.from_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::CREATE_DATABASE => {
+            let node = ast::CreateDatabase {
+                syntax: node.clone(),
+            };
+            fields.add("database", node.database());
+            fields.add("database_option_list", node.database_option_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("database_token", node.database_token());
+        }
+        SyntaxKind::CREATE_DOMAIN => {
+            let node = ast::CreateDomain {
+                syntax: node.clone(),
+            };
+            fields.add("collate", node.collate());
+            fields.add("constraints", node.constraints());
+            fields.add("domain", node.domain());
+            fields.add("type", node.ty());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("as_token", node.as_token());
+            fields.add("create_token", node.create_token());
+            fields.add("domain_token", node.domain_token());
+        }
+        SyntaxKind::CREATE_EVENT_TRIGGER => {
+            let node = ast::CreateEventTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("call_expr", node.call_expr());
+            fields.add("event", node.event());
+            fields.add("event_trigger", node.event_trigger());
+            fields.add(
+                "event_trigger_when_clause",
+                node.event_trigger_when_clause(),
+            );
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("event_token", node.event_token());
+            fields.add("execute_token", node.execute_token());
+            fields.add("function_token", node.function_token());
+            fields.add("on_token", node.on_token());
+            fields.add("procedure_token", node.procedure_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::CREATE_EXTENSION => {
+            let node = ast::CreateExtension {
+                syntax: node.clone(),
+            };
+            fields.add("extension", node.extension());
+            fields.add("extension_schema", node.extension_schema());
+            fields.add("extension_version_clause", node.extension_version_clause());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("cascade_token", node.cascade_token());
+            fields.add("create_token", node.create_token());
+            fields.add("extension_token", node.extension_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::CREATE_FOREIGN_DATA_WRAPPER => {
+            let node = ast::CreateForeignDataWrapper {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("fdw_option_list", node.fdw_option_list());
+            fields.add("foreign_data_wrapper", node.foreign_data_wrapper());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("data_token", node.data_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::CREATE_FOREIGN_TABLE => {
+            let node = ast::CreateForeignTable {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("inherits", node.inherits());
+            fields.add("partition_of", node.partition_of());
+            fields.add("partition_type", node.partition_type());
+            fields.add("server_clause", node.server_clause());
+            fields.add("table_arg_list", node.table_arg_list());
+            fields.add("table_name", node.table_name());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::CREATE_FUNCTION => {
+            let node = ast::CreateFunction {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("option_list", node.option_list());
+            fields.add("or_replace", node.or_replace());
+            fields.add("param_list", node.param_list());
+            fields.add("ret_type", node.ret_type());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("function_token", node.function_token());
+        }
+        SyntaxKind::CREATE_GROUP => {
+            let node = ast::CreateGroup {
+                syntax: node.clone(),
+            };
+            fields.add("role", node.role());
+            fields.add("role_option_list", node.role_option_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("group_token", node.group_token());
+        }
+        SyntaxKind::CREATE_INDEX => {
+            let node = ast::CreateIndex {
+                syntax: node.clone(),
+            };
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("index", node.index());
+            fields.add("index_include_clause", node.index_include_clause());
+            fields.add("nulls_distinct_option", node.nulls_distinct_option());
+            fields.add("partition_item_list", node.partition_item_list());
+            fields.add("table_relation_name", node.table_relation_name());
+            fields.add("tablespace_clause", node.tablespace_clause());
+            fields.add("using_method", node.using_method());
+            fields.add("where_clause", node.where_clause());
+            fields.add("with_params", node.with_params());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("async_token", node.async_token());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("create_token", node.create_token());
+            fields.add("index_token", node.index_token());
+            fields.add("on_token", node.on_token());
+            fields.add("unique_token", node.unique_token());
+        }
+        SyntaxKind::CREATE_LANGUAGE => {
+            let node = ast::CreateLanguage {
+                syntax: node.clone(),
+            };
+            fields.add("handler_clause", node.handler_clause());
+            fields.add("inline_clause", node.inline_clause());
+            fields.add("language", node.language());
+            fields.add("or_replace", node.or_replace());
+            fields.add("validator_clause", node.validator_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("language_token", node.language_token());
+            fields.add("procedural_token", node.procedural_token());
+            fields.add("trusted_token", node.trusted_token());
+        }
+        SyntaxKind::CREATE_MATERIALIZED_VIEW => {
+            let node = ast::CreateMaterializedView {
+                syntax: node.clone(),
+            };
+            fields.add("column_list", node.column_list());
+            fields.add("data_option", node.data_option());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("query", node.query());
+            fields.add("tablespace_clause", node.tablespace_clause());
+            fields.add("using_method", node.using_method());
+            fields.add("view", node.view());
+            fields.add("with_params", node.with_params());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("as_token", node.as_token());
+            fields.add("create_token", node.create_token());
+            fields.add("materialized_token", node.materialized_token());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::CREATE_OPERATOR => {
+            let node = ast::CreateOperator {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("op", node.op());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::CREATE_OPERATOR_CLASS => {
+            let node = ast::CreateOperatorClass {
+                syntax: node.clone(),
+            };
+            fields.add("op_class_name", node.op_class_name());
+            fields.add(
+                "operator_class_option_list",
+                node.operator_class_option_list(),
+            );
+            fields.add("operator_family_clause", node.operator_family_clause());
+            fields.add("type", node.ty());
+            fields.add("using_method", node.using_method());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("as_token", node.as_token());
+            fields.add("class_token", node.class_token());
+            fields.add("create_token", node.create_token());
+            fields.add("default_token", node.default_token());
+            fields.add("for_token", node.for_token());
+            fields.add("operator_token", node.operator_token());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::CREATE_OPERATOR_FAMILY => {
+            let node = ast::CreateOperatorFamily {
+                syntax: node.clone(),
+            };
+            fields.add("op_family_name", node.op_family_name());
+            fields.add("using_method", node.using_method());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("family_token", node.family_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::CREATE_POLICY => {
+            let node = ast::CreatePolicy {
+                syntax: node.clone(),
+            };
+            fields.add("as_policy_type", node.as_policy_type());
+            fields.add("on_table", node.on_table());
+            fields.add("policy", node.policy());
+            fields.add("policy_command", node.policy_command());
+            fields.add("policy_roles", node.policy_roles());
+            fields.add("using_expr_clause", node.using_expr_clause());
+            fields.add("with_check_expr_clause", node.with_check_expr_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("policy_token", node.policy_token());
+        }
+        SyntaxKind::CREATE_PROCEDURE => {
+            let node = ast::CreateProcedure {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("option_list", node.option_list());
+            fields.add("or_replace", node.or_replace());
+            fields.add("param_list", node.param_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("procedure_token", node.procedure_token());
+        }
+        SyntaxKind::CREATE_PROPERTY_GRAPH => {
+            let node = ast::CreatePropertyGraph {
+                syntax: node.clone(),
+            };
+            fields.add("edge_tables", node.edge_tables());
+            fields.add("persistence", node.persistence());
+            fields.add("property_graph", node.property_graph());
+            fields.add("vertex_tables", node.vertex_tables());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("graph_token", node.graph_token());
+            fields.add("property_token", node.property_token());
+        }
+        SyntaxKind::CREATE_PUBLICATION => {
+            let node = ast::CreatePublication {
+                syntax: node.clone(),
+            };
+            fields.add("publication", node.publication());
+            fields.add("publication_for_clause", node.publication_for_clause());
+            fields.add("with_params", node.with_params());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("publication_token", node.publication_token());
+        }
+        SyntaxKind::CREATE_ROLE => {
+            let node = ast::CreateRole {
+                syntax: node.clone(),
+            };
+            fields.add("role", node.role());
+            fields.add("role_option_list", node.role_option_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("role_token", node.role_token());
+        }
+        SyntaxKind::CREATE_RULE => {
+            let node = ast::CreateRule {
+                syntax: node.clone(),
+            };
+            fields.add("or_replace", node.or_replace());
+            fields.add("rule", node.rule());
+            fields.add("rule_do", node.rule_do());
+            fields.add("rule_on", node.rule_on());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("as_token", node.as_token());
+            fields.add("create_token", node.create_token());
+            fields.add("rule_token", node.rule_token());
+        }
+        SyntaxKind::CREATE_SCHEMA => {
+            let node = ast::CreateSchema {
+                syntax: node.clone(),
+            };
+            fields.add("create_schema_target", node.create_schema_target());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("schema_elements", node.schema_elements());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::CREATE_SEQUENCE => {
+            let node = ast::CreateSequence {
+                syntax: node.clone(),
+            };
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("persistence", node.persistence());
+            fields.add("sequence", node.sequence());
+            fields.add("sequence_options", node.sequence_options());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("sequence_token", node.sequence_token());
+        }
+        SyntaxKind::CREATE_SERVER => {
+            let node = ast::CreateServer {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("foreign_data_wrapper_ref", node.foreign_data_wrapper_ref());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("server", node.server());
+            fields.add("server_type", node.server_type());
+            fields.add("server_version", node.server_version());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("data_token", node.data_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("server_token", node.server_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::CREATE_STATISTICS => {
+            let node = ast::CreateStatistics {
+                syntax: node.clone(),
+            };
+            fields.add("from_table", node.from_table());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("stat_types", node.stat_types());
+            fields.add("statistics", node.statistics());
+            fields.add("statistics_on_clause", node.statistics_on_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("statistics_token", node.statistics_token());
+        }
+        SyntaxKind::CREATE_SUBSCRIPTION => {
+            let node = ast::CreateSubscription {
+                syntax: node.clone(),
+            };
+            fields.add("publication_refs", node.publication_refs());
+            fields.add("source", node.source());
+            fields.add("subscription", node.subscription());
+            fields.add("with_params", node.with_params());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("publication_token", node.publication_token());
+            fields.add("subscription_token", node.subscription_token());
+        }
+        SyntaxKind::CREATE_TABLE => {
+            let node = ast::CreateTable {
+                syntax: node.clone(),
+            };
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("inherits", node.inherits());
+            fields.add("of_type", node.of_type());
+            fields.add("on_commit", node.on_commit());
+            fields.add("partition_by", node.partition_by());
+            fields.add("partition_of", node.partition_of());
+            fields.add("partition_type", node.partition_type());
+            fields.add("persistence", node.persistence());
+            fields.add("table_arg_list", node.table_arg_list());
+            fields.add("table_name", node.table_name());
+            fields.add("table_params", node.table_params());
+            fields.add("tablespace_clause", node.tablespace_clause());
+            fields.add("using_method", node.using_method());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::CREATE_TABLE_AS => {
+            let node = ast::CreateTableAs {
+                syntax: node.clone(),
+            };
+            fields.add("data_option", node.data_option());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("on_commit", node.on_commit());
+            fields.add("persistence", node.persistence());
+            fields.add("query", node.query());
+            fields.add("table_arg_list", node.table_arg_list());
+            fields.add("table_name", node.table_name());
+            fields.add("table_params", node.table_params());
+            fields.add("tablespace_clause", node.tablespace_clause());
+            fields.add("using_method", node.using_method());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("as_token", node.as_token());
+            fields.add("create_token", node.create_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::CREATE_TABLESPACE => {
+            let node = ast::CreateTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("tablespace", node.tablespace());
+            fields.add("tablespace_owner", node.tablespace_owner());
+            fields.add("with_params", node.with_params());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("location_token", node.location_token());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::CREATE_TEXT_SEARCH_CONFIGURATION => {
+            let node = ast::CreateTextSearchConfiguration {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add(
+                "text_search_configuration",
+                node.text_search_configuration(),
+            );
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("configuration_token", node.configuration_token());
+            fields.add("create_token", node.create_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::CREATE_TEXT_SEARCH_DICTIONARY => {
+            let node = ast::CreateTextSearchDictionary {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("text_search_dictionary", node.text_search_dictionary());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("dictionary_token", node.dictionary_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::CREATE_TEXT_SEARCH_PARSER => {
+            let node = ast::CreateTextSearchParser {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("text_search_parser", node.text_search_parser());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("parser_token", node.parser_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::CREATE_TEXT_SEARCH_TEMPLATE => {
+            let node = ast::CreateTextSearchTemplate {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("text_search_template", node.text_search_template());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("search_token", node.search_token());
+            fields.add("template_token", node.template_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::CREATE_TRANSFORM => {
+            let node = ast::CreateTransform {
+                syntax: node.clone(),
+            };
+            fields.add("language_ref", node.language_ref());
+            fields.add("or_replace", node.or_replace());
+            fields.add("transform_funcs", node.transform_funcs());
+            fields.add("type", node.ty());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("for_token", node.for_token());
+            fields.add("language_token", node.language_token());
+            fields.add("transform_token", node.transform_token());
+        }
+        SyntaxKind::CREATE_TRIGGER => {
+            let node = ast::CreateTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("call_expr", node.call_expr());
+            fields.add(
+                "deferrable_constraint_option",
+                node.deferrable_constraint_option(),
+            );
+            fields.add("from_table", node.from_table());
+            fields.add(
+                "initially_deferred_constraint_option",
+                node.initially_deferred_constraint_option(),
+            );
+            fields.add(
+                "initially_immediate_constraint_option",
+                node.initially_immediate_constraint_option(),
+            );
+            fields.add(
+                "not_deferrable_constraint_option",
+                node.not_deferrable_constraint_option(),
+            );
+            fields.add("on_relation", node.on_relation());
+            fields.add("or_replace", node.or_replace());
+            fields.add("referencing", node.referencing());
+            fields.add("timing", node.timing());
+            fields.add("trigger", node.trigger());
+            fields.add("trigger_event_list", node.trigger_event_list());
+            fields.add("trigger_level", node.trigger_level());
+            fields.add("when_condition", node.when_condition());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("constraint_token", node.constraint_token());
+            fields.add("create_token", node.create_token());
+            fields.add("execute_token", node.execute_token());
+            fields.add("function_token", node.function_token());
+            fields.add("procedure_token", node.procedure_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::CREATE_TYPE => {
+            let node = ast::CreateType {
+                syntax: node.clone(),
+            };
+            fields.add("kind", node.kind());
+            fields.add("type_name", node.type_name());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::CREATE_USER => {
+            let node = ast::CreateUser {
+                syntax: node.clone(),
+            };
+            fields.add("role", node.role());
+            fields.add("role_option_list", node.role_option_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::CREATE_USER_MAPPING => {
+            let node = ast::CreateUserMapping {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("if_not_exists", node.if_not_exists());
+            fields.add("role_ref", node.role_ref());
+            fields.add("server_clause", node.server_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("create_token", node.create_token());
+            fields.add("for_token", node.for_token());
+            fields.add("mapping_token", node.mapping_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::CREATE_VIEW => {
+            let node = ast::CreateView {
+                syntax: node.clone(),
+            };
+            fields.add("column_list", node.column_list());
+            fields.add("or_replace", node.or_replace());
+            fields.add("persistence", node.persistence());
+            fields.add("query", node.query());
+            fields.add("view", node.view());
+            fields.add("with_check_option", node.with_check_option());
+            fields.add("with_params", node.with_params());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("as_token", node.as_token());
+            fields.add("create_token", node.create_token());
+            fields.add("recursive_token", node.recursive_token());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::CTE_NAME => {
+            let node = ast::CteName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CURRENT_ROW => {
+            let node = ast::CurrentRow {
+                syntax: node.clone(),
+            };
+            fields.add("current_token", node.current_token());
+            fields.add("row_token", node.row_token());
+        }
+        SyntaxKind::CURSOR => {
+            let node = ast::Cursor {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CURSOR_REF => {
+            let node = ast::CursorRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::CUSTOM_OP => {
+            let node = ast::CustomOp {
+                syntax: node.clone(),
+            };
+            fields.add("bang_token", node.bang_token());
+            fields.add("pound_token", node.pound_token());
+            fields.add("percent_token", node.percent_token());
+            fields.add("amp_token", node.amp_token());
+            fields.add("star_token", node.star_token());
+            fields.add("plus_token", node.plus_token());
+            fields.add("minus_token", node.minus_token());
+            fields.add("slash_token", node.slash_token());
+            fields.add("l_angle_token", node.l_angle_token());
+            fields.add("eq_token", node.eq_token());
+            fields.add("r_angle_token", node.r_angle_token());
+            fields.add("question_token", node.question_token());
+            fields.add("at_token", node.at_token());
+            fields.add("caret_token", node.caret_token());
+            fields.add("backtick_token", node.backtick_token());
+            fields.add("pipe_token", node.pipe_token());
+            fields.add("tilde_token", node.tilde_token());
+        }
+        SyntaxKind::CYCLE_CLAUSE => {
+            let node = ast::CycleClause {
+                syntax: node.clone(),
+            };
+            fields.add("columns", node.columns());
+            fields.add("path", node.path());
+            fields.add("set_column", node.set_column());
+            fields.add("cycle_token", node.cycle_token());
+        }
+        SyntaxKind::CYCLE_COLUMN_TO => {
+            let node = ast::CycleColumnTo {
+                syntax: node.clone(),
+            };
+            fields.add("default", node.default());
+            fields.add("expr", node.expr());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::CYCLE_COLUMNS => {
+            let node = ast::CycleColumns {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_refs", node.column_name_refs());
+        }
+        SyntaxKind::CYCLE_DEFAULT => {
+            let node = ast::CycleDefault {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::CYCLE_PATH => {
+            let node = ast::CyclePath {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::CYCLE_SET_COLUMN => {
+            let node = ast::CycleSetColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("column_to", node.column_to());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::DATABASE => {
+            let node = ast::Database {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::DATABASE_OPTION_CONNECTION_LIMIT => {
+            let node = ast::DatabaseOptionConnectionLimit {
+                syntax: node.clone(),
+            };
+            fields.add("config_value_name", node.config_value_name());
+            fields.add("literal", node.literal());
+            fields.add("eq_token", node.eq_token());
+            fields.add("connection_token", node.connection_token());
+            fields.add("default_token", node.default_token());
+            fields.add("limit_token", node.limit_token());
+        }
+        SyntaxKind::DATABASE_OPTION_ENCODING => {
+            let node = ast::DatabaseOptionEncoding {
+                syntax: node.clone(),
+            };
+            fields.add("config_value_name", node.config_value_name());
+            fields.add("literal", node.literal());
+            fields.add("eq_token", node.eq_token());
+            fields.add("default_token", node.default_token());
+            fields.add("encoding_token", node.encoding_token());
+        }
+        SyntaxKind::DATABASE_OPTION_GENERIC => {
+            let node = ast::DatabaseOptionGeneric {
+                syntax: node.clone(),
+            };
+            fields.add("config_value_name", node.config_value_name());
+            fields.add("literal", node.literal());
+            fields.add("eq_token", node.eq_token());
+            fields.add("default_token", node.default_token());
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::DATABASE_OPTION_LIST => {
+            let node = ast::DatabaseOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("database_options", node.database_options());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::DATABASE_OPTION_OWNER => {
+            let node = ast::DatabaseOptionOwner {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("role_ref", node.role_ref());
+            fields.add("eq_token", node.eq_token());
+            fields.add("default_token", node.default_token());
+            fields.add("owner_token", node.owner_token());
+        }
+        SyntaxKind::DATABASE_OPTION_TABLESPACE => {
+            let node = ast::DatabaseOptionTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("eq_token", node.eq_token());
+            fields.add("default_token", node.default_token());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::DATABASE_OPTION_TEMPLATE => {
+            let node = ast::DatabaseOptionTemplate {
+                syntax: node.clone(),
+            };
+            fields.add("database_ref", node.database_ref());
+            fields.add("literal", node.literal());
+            fields.add("eq_token", node.eq_token());
+            fields.add("default_token", node.default_token());
+            fields.add("template_token", node.template_token());
+        }
+        SyntaxKind::DATABASE_REF => {
+            let node = ast::DatabaseRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::DATABASE_RENAME_TO => {
+            let node = ast::DatabaseRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("database", node.database());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::DEALLOCATE => {
+            let node = ast::Deallocate {
+                syntax: node.clone(),
+            };
+            fields.add("prepared_statement_ref", node.prepared_statement_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("all_token", node.all_token());
+            fields.add("deallocate_token", node.deallocate_token());
+            fields.add("prepare_token", node.prepare_token());
+        }
+        SyntaxKind::DECLARE => {
+            let node = ast::Declare {
+                syntax: node.clone(),
+            };
+            fields.add("cursor", node.cursor());
+            fields.add("cursor_hold", node.cursor_hold());
+            fields.add("cursor_scroll", node.cursor_scroll());
+            fields.add("cursor_sensitivity", node.cursor_sensitivity());
+            fields.add("query", node.query());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("binary_token", node.binary_token());
+            fields.add("cursor_token", node.cursor_token());
+            fields.add("declare_token", node.declare_token());
+            fields.add("for_token", node.for_token());
+        }
+        SyntaxKind::DEFAULT_CONSTRAINT => {
+            let node = ast::DefaultConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("expr", node.expr());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::DEFAULT_LABEL => {
+            let node = ast::DefaultLabel {
+                syntax: node.clone(),
+            };
+            fields.add("default_token", node.default_token());
+            fields.add("label_token", node.label_token());
+        }
+        SyntaxKind::DEFAULT_VALUES => {
+            let node = ast::DefaultValues {
+                syntax: node.clone(),
+            };
+            fields.add("default_token", node.default_token());
+            fields.add("values_token", node.values_token());
+        }
+        SyntaxKind::DEFERRABLE => {
+            let node = ast::Deferrable {
+                syntax: node.clone(),
+            };
+            fields.add("deferrable_token", node.deferrable_token());
+        }
+        SyntaxKind::DEFERRABLE_CONSTRAINT_OPTION => {
+            let node = ast::DeferrableConstraintOption {
+                syntax: node.clone(),
+            };
+            fields.add("deferrable_token", node.deferrable_token());
+        }
+        SyntaxKind::DEFERRED => {
+            let node = ast::Deferred {
+                syntax: node.clone(),
+            };
+            fields.add("deferred_token", node.deferred_token());
+        }
+        SyntaxKind::DELETE => {
+            let node = ast::Delete {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("for_portion_of", node.for_portion_of());
+            fields.add("relation_name", node.relation_name());
+            fields.add("returning_clause", node.returning_clause());
+            fields.add("using_clause", node.using_clause());
+            fields.add(
+                "where_clause_or_current_of",
+                node.where_clause_or_current_of(),
+            );
+            fields.add("with_clause", node.with_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("delete_token", node.delete_token());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::DELETE_ROWS => {
+            let node = ast::DeleteRows {
+                syntax: node.clone(),
+            };
+            fields.add("delete_token", node.delete_token());
+            fields.add("rows_token", node.rows_token());
+        }
+        SyntaxKind::DEPENDS_ON_EXTENSION => {
+            let node = ast::DependsOnExtension {
+                syntax: node.clone(),
+            };
+            fields.add("extension_ref", node.extension_ref());
+            fields.add("depends_token", node.depends_token());
+            fields.add("extension_token", node.extension_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::DEPTH_FIRST => {
+            let node = ast::DepthFirst {
+                syntax: node.clone(),
+            };
+            fields.add("depth_token", node.depth_token());
+            fields.add("first_token", node.first_token());
+        }
+        SyntaxKind::DEST_VERTEX_TABLE => {
+            let node = ast::DestVertexTable {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("references_table", node.references_table());
+            fields.add("vertex_table_ref", node.vertex_table_ref());
+            fields.add("destination_token", node.destination_token());
+            fields.add("key_token", node.key_token());
+        }
+        SyntaxKind::DETACH_CONCURRENTLY => {
+            let node = ast::DetachConcurrently {
+                syntax: node.clone(),
+            };
+            fields.add("concurrently_token", node.concurrently_token());
+        }
+        SyntaxKind::DETACH_FINALIZE => {
+            let node = ast::DetachFinalize {
+                syntax: node.clone(),
+            };
+            fields.add("finalize_token", node.finalize_token());
+        }
+        SyntaxKind::DETACH_PARTITION => {
+            let node = ast::DetachPartition {
+                syntax: node.clone(),
+            };
+            fields.add("detach_partition_option", node.detach_partition_option());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("detach_token", node.detach_token());
+            fields.add("partition_token", node.partition_token());
+        }
+        SyntaxKind::DISABLE => {
+            let node = ast::Disable {
+                syntax: node.clone(),
+            };
+            fields.add("disable_token", node.disable_token());
+        }
+        SyntaxKind::DISABLE_RLS => {
+            let node = ast::DisableRls {
+                syntax: node.clone(),
+            };
+            fields.add("disable_token", node.disable_token());
+            fields.add("level_token", node.level_token());
+            fields.add("row_token", node.row_token());
+            fields.add("security_token", node.security_token());
+        }
+        SyntaxKind::DISABLE_RULE => {
+            let node = ast::DisableRule {
+                syntax: node.clone(),
+            };
+            fields.add("rule_ref", node.rule_ref());
+            fields.add("disable_token", node.disable_token());
+            fields.add("rule_token", node.rule_token());
+        }
+        SyntaxKind::DISABLE_SUBSCRIPTION => {
+            let node = ast::DisableSubscription {
+                syntax: node.clone(),
+            };
+            fields.add("disable_token", node.disable_token());
+        }
+        SyntaxKind::DISABLE_TRIGGER => {
+            let node = ast::DisableTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("trigger_target", node.trigger_target());
+            fields.add("disable_token", node.disable_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::DISCARD => {
+            let node = ast::Discard {
+                syntax: node.clone(),
+            };
+            fields.add("discard_target", node.discard_target());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("discard_token", node.discard_token());
+        }
+        SyntaxKind::DISCARD_ALL => {
+            let node = ast::DiscardAll {
+                syntax: node.clone(),
+            };
+            fields.add("all_token", node.all_token());
+        }
+        SyntaxKind::DISCARD_PLANS => {
+            let node = ast::DiscardPlans {
+                syntax: node.clone(),
+            };
+            fields.add("plans_token", node.plans_token());
+        }
+        SyntaxKind::DISCARD_SEQUENCES => {
+            let node = ast::DiscardSequences {
+                syntax: node.clone(),
+            };
+            fields.add("sequences_token", node.sequences_token());
+        }
+        SyntaxKind::DISCARD_TEMP => {
+            let node = ast::DiscardTemp {
+                syntax: node.clone(),
+            };
+            fields.add("temp_token", node.temp_token());
+            fields.add("temporary_token", node.temporary_token());
+        }
+        SyntaxKind::DISTINCT => {
+            let node = ast::Distinct {
+                syntax: node.clone(),
+            };
+            fields.add("distinct_token", node.distinct_token());
+        }
+        SyntaxKind::DISTINCT_CLAUSE => {
+            let node = ast::DistinctClause {
+                syntax: node.clone(),
+            };
+            fields.add("distinct_on", node.distinct_on());
+            fields.add("distinct_token", node.distinct_token());
+        }
+        SyntaxKind::DISTINCT_ON => {
+            let node = ast::DistinctOn {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::DO => {
+            let node = ast::Do {
+                syntax: node.clone(),
+            };
+            fields.add("body", node.body());
+            fields.add("do_language", node.do_language());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("do_token", node.do_token());
+        }
+        SyntaxKind::DO_LANGUAGE => {
+            let node = ast::DoLanguage {
+                syntax: node.clone(),
+            };
+            fields.add("language_ref", node.language_ref());
+            fields.add("literal", node.literal());
+            fields.add("language_token", node.language_token());
+        }
+        SyntaxKind::DOMAIN => {
+            let node = ast::Domain {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::DOMAIN_REF => {
+            let node = ast::DomainRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::DOMAIN_RENAME_TO => {
+            let node = ast::DomainRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("domain", node.domain());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::DOUBLE_TYPE => {
+            let node = ast::DoubleType {
+                syntax: node.clone(),
+            };
+            fields.add("double_token", node.double_token());
+            fields.add("precision_token", node.precision_token());
+            fields.add("setof_token", node.setof_token());
+        }
+        SyntaxKind::DROP => {
+            let node = ast::Drop {
+                syntax: node.clone(),
+            };
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_ACCESS_METHOD => {
+            let node = ast::DropAccessMethod {
+                syntax: node.clone(),
+            };
+            fields.add("access_method_ref", node.access_method_ref());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("access_token", node.access_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("method_token", node.method_token());
+        }
+        SyntaxKind::DROP_AGGREGATE => {
+            let node = ast::DropAggregate {
+                syntax: node.clone(),
+            };
+            fields.add("aggregates", node.aggregates());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("aggregate_token", node.aggregate_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_ATTRIBUTE => {
+            let node = ast::DropAttribute {
+                syntax: node.clone(),
+            };
+            fields.add("composite_field_ref", node.composite_field_ref());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("attribute_token", node.attribute_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_CAST => {
+            let node = ast::DropCast {
+                syntax: node.clone(),
+            };
+            fields.add("cast_sig", node.cast_sig());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("cast_token", node.cast_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_COLLATION => {
+            let node = ast::DropCollation {
+                syntax: node.clone(),
+            };
+            fields.add("collation_refs", node.collation_refs());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("collation_token", node.collation_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_COLUMN => {
+            let node = ast::DropColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("column_token", node.column_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_CONSTRAINT => {
+            let node = ast::DropConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_ref", node.constraint_name_ref());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("constraint_token", node.constraint_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_CONVERSION => {
+            let node = ast::DropConversion {
+                syntax: node.clone(),
+            };
+            fields.add("conversion_ref", node.conversion_ref());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("conversion_token", node.conversion_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_DATABASE => {
+            let node = ast::DropDatabase {
+                syntax: node.clone(),
+            };
+            fields.add("database_ref", node.database_ref());
+            fields.add(
+                "drop_database_force_clause",
+                node.drop_database_force_clause(),
+            );
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("database_token", node.database_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_DATABASE_FORCE_CLAUSE => {
+            let node = ast::DropDatabaseForceClause {
+                syntax: node.clone(),
+            };
+            fields.add("forces", node.forces());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::DROP_DEFAULT => {
+            let node = ast::DropDefault {
+                syntax: node.clone(),
+            };
+            fields.add("default_token", node.default_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_DOMAIN => {
+            let node = ast::DropDomain {
+                syntax: node.clone(),
+            };
+            fields.add("domain_refs", node.domain_refs());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("domain_token", node.domain_token());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_EDGE_TABLES => {
+            let node = ast::DropEdgeTables {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("element_table_refs", node.element_table_refs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("edge_token", node.edge_token());
+            fields.add("relationship_token", node.relationship_token());
+            fields.add("tables_token", node.tables_token());
+        }
+        SyntaxKind::DROP_EVENT_TRIGGER => {
+            let node = ast::DropEventTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("event_trigger_ref", node.event_trigger_ref());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("event_token", node.event_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::DROP_EXPRESSION => {
+            let node = ast::DropExpression {
+                syntax: node.clone(),
+            };
+            fields.add("if_exists", node.if_exists());
+            fields.add("drop_token", node.drop_token());
+            fields.add("expression_token", node.expression_token());
+        }
+        SyntaxKind::DROP_EXTENSION => {
+            let node = ast::DropExtension {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("extension_refs", node.extension_refs());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("extension_token", node.extension_token());
+        }
+        SyntaxKind::DROP_FOREIGN_DATA_WRAPPER => {
+            let node = ast::DropForeignDataWrapper {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add(
+                "foreign_data_wrapper_refs",
+                node.foreign_data_wrapper_refs(),
+            );
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("data_token", node.data_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::DROP_FOREIGN_OPTION => {
+            let node = ast::DropForeignOption {
+                syntax: node.clone(),
+            };
+            fields.add("foreign_option_name", node.foreign_option_name());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_FOREIGN_TABLE => {
+            let node = ast::DropForeignTable {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("table_name_refs", node.table_name_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::DROP_FUNCTION => {
+            let node = ast::DropFunction {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("function_sig_list", node.function_sig_list());
+            fields.add("if_exists", node.if_exists());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("function_token", node.function_token());
+        }
+        SyntaxKind::DROP_GROUP => {
+            let node = ast::DropGroup {
+                syntax: node.clone(),
+            };
+            fields.add("if_exists", node.if_exists());
+            fields.add("role_refs", node.role_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("group_token", node.group_token());
+        }
+        SyntaxKind::DROP_IDENTITY => {
+            let node = ast::DropIdentity {
+                syntax: node.clone(),
+            };
+            fields.add("if_exists", node.if_exists());
+            fields.add("drop_token", node.drop_token());
+            fields.add("identity_token", node.identity_token());
+        }
+        SyntaxKind::DROP_INDEX => {
+            let node = ast::DropIndex {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("index_refs", node.index_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("index_token", node.index_token());
+        }
+        SyntaxKind::DROP_LANGUAGE => {
+            let node = ast::DropLanguage {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("language_ref", node.language_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("language_token", node.language_token());
+            fields.add("procedural_token", node.procedural_token());
+        }
+        SyntaxKind::DROP_MAPPING => {
+            let node = ast::DropMapping {
+                syntax: node.clone(),
+            };
+            fields.add("if_exists", node.if_exists());
+            fields.add("text_search_token_kinds", node.text_search_token_kinds());
+            fields.add("drop_token", node.drop_token());
+            fields.add("for_token", node.for_token());
+            fields.add("mapping_token", node.mapping_token());
+        }
+        SyntaxKind::DROP_MATERIALIZED_VIEW => {
+            let node = ast::DropMaterializedView {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("view_refs", node.view_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("materialized_token", node.materialized_token());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::DROP_NOT_NULL => {
+            let node = ast::DropNotNull {
+                syntax: node.clone(),
+            };
+            fields.add("drop_token", node.drop_token());
+            fields.add("not_token", node.not_token());
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::DROP_OP_CLASS_OPTION_FUNCTION => {
+            let node = ast::DropOpClassOptionFunction {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("param_list", node.param_list());
+            fields.add("function_token", node.function_token());
+        }
+        SyntaxKind::DROP_OP_CLASS_OPTION_LIST => {
+            let node = ast::DropOpClassOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("drop_op_class_options", node.drop_op_class_options());
+        }
+        SyntaxKind::DROP_OP_CLASS_OPTION_OPERATOR => {
+            let node = ast::DropOpClassOptionOperator {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("param_list", node.param_list());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::DROP_OP_CLASS_OPTIONS => {
+            let node = ast::DropOpClassOptions {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "drop_op_class_option_list",
+                node.drop_op_class_option_list(),
+            );
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_OPERATOR => {
+            let node = ast::DropOperator {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("op_sig_list", node.op_sig_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::DROP_OPERATOR_CLASS => {
+            let node = ast::DropOperatorClass {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("op_class_ref", node.op_class_ref());
+            fields.add("using_method", node.using_method());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("class_token", node.class_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::DROP_OPERATOR_FAMILY => {
+            let node = ast::DropOperatorFamily {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("op_family_ref", node.op_family_ref());
+            fields.add("using_method", node.using_method());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("family_token", node.family_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::DROP_OWNED => {
+            let node = ast::DropOwned {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("by_token", node.by_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("owned_token", node.owned_token());
+        }
+        SyntaxKind::DROP_POLICY => {
+            let node = ast::DropPolicy {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("on_table", node.on_table());
+            fields.add("policy_ref", node.policy_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("policy_token", node.policy_token());
+        }
+        SyntaxKind::DROP_PROCEDURE => {
+            let node = ast::DropProcedure {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("procedure_sig_list", node.procedure_sig_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("procedure_token", node.procedure_token());
+        }
+        SyntaxKind::DROP_PROPERTY_GRAPH => {
+            let node = ast::DropPropertyGraph {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("property_graph_ref", node.property_graph_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("graph_token", node.graph_token());
+            fields.add("property_token", node.property_token());
+        }
+        SyntaxKind::DROP_PUBLICATION => {
+            let node = ast::DropPublication {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("publication_refs", node.publication_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("publication_token", node.publication_token());
+        }
+        SyntaxKind::DROP_PUBLICATION_OBJECTS => {
+            let node = ast::DropPublicationObjects {
+                syntax: node.clone(),
+            };
+            fields.add("publication_objects", node.publication_objects());
+            fields.add("drop_token", node.drop_token());
+        }
+        SyntaxKind::DROP_ROLE => {
+            let node = ast::DropRole {
+                syntax: node.clone(),
+            };
+            fields.add("if_exists", node.if_exists());
+            fields.add("role_refs", node.role_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("role_token", node.role_token());
+        }
+        SyntaxKind::DROP_ROUTINE => {
+            let node = ast::DropRoutine {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("routine_sig_list", node.routine_sig_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("routine_token", node.routine_token());
+        }
+        SyntaxKind::DROP_RULE => {
+            let node = ast::DropRule {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("on_relation", node.on_relation());
+            fields.add("rule_ref", node.rule_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("rule_token", node.rule_token());
+        }
+        SyntaxKind::DROP_SCHEMA => {
+            let node = ast::DropSchema {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("schema_refs", node.schema_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::DROP_SEQUENCE => {
+            let node = ast::DropSequence {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("sequence_refs", node.sequence_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("sequence_token", node.sequence_token());
+        }
+        SyntaxKind::DROP_SERVER => {
+            let node = ast::DropServer {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("server_refs", node.server_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("server_token", node.server_token());
+        }
+        SyntaxKind::DROP_STATISTICS => {
+            let node = ast::DropStatistics {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("statistics_refs", node.statistics_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("statistics_token", node.statistics_token());
+        }
+        SyntaxKind::DROP_SUBSCRIPTION => {
+            let node = ast::DropSubscription {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("subscription_ref", node.subscription_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("subscription_token", node.subscription_token());
+        }
+        SyntaxKind::DROP_SUBSCRIPTION_PUBLICATION => {
+            let node = ast::DropSubscriptionPublication {
+                syntax: node.clone(),
+            };
+            fields.add("publication_refs", node.publication_refs());
+            fields.add("with_params", node.with_params());
+            fields.add("drop_token", node.drop_token());
+            fields.add("publication_token", node.publication_token());
+        }
+        SyntaxKind::DROP_TABLE => {
+            let node = ast::DropTable {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("table_name_refs", node.table_name_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::DROP_TABLESPACE => {
+            let node = ast::DropTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("if_exists", node.if_exists());
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::DROP_TEXT_SEARCH_CONFIG => {
+            let node = ast::DropTextSearchConfig {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add(
+                "text_search_configuration_ref",
+                node.text_search_configuration_ref(),
+            );
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("configuration_token", node.configuration_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::DROP_TEXT_SEARCH_DICT => {
+            let node = ast::DropTextSearchDict {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add(
+                "text_search_dictionary_ref",
+                node.text_search_dictionary_ref(),
+            );
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("dictionary_token", node.dictionary_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::DROP_TEXT_SEARCH_PARSER => {
+            let node = ast::DropTextSearchParser {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("text_search_parser_ref", node.text_search_parser_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("parser_token", node.parser_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::DROP_TEXT_SEARCH_TEMPLATE => {
+            let node = ast::DropTextSearchTemplate {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("text_search_template_ref", node.text_search_template_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("search_token", node.search_token());
+            fields.add("template_token", node.template_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::DROP_TRANSFORM => {
+            let node = ast::DropTransform {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("language_ref", node.language_ref());
+            fields.add("ty", node.ty());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("for_token", node.for_token());
+            fields.add("language_token", node.language_token());
+            fields.add("transform_token", node.transform_token());
+        }
+        SyntaxKind::DROP_TRIGGER => {
+            let node = ast::DropTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("on_relation", node.on_relation());
+            fields.add("trigger_ref", node.trigger_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::DROP_TYPE => {
+            let node = ast::DropType {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("type_name_refs", node.type_name_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::DROP_USER => {
+            let node = ast::DropUser {
+                syntax: node.clone(),
+            };
+            fields.add("if_exists", node.if_exists());
+            fields.add("role_refs", node.role_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::DROP_USER_MAPPING => {
+            let node = ast::DropUserMapping {
+                syntax: node.clone(),
+            };
+            fields.add("if_exists", node.if_exists());
+            fields.add("role_ref", node.role_ref());
+            fields.add("server_clause", node.server_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("for_token", node.for_token());
+            fields.add("mapping_token", node.mapping_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::DROP_USERS => {
+            let node = ast::DropUsers {
+                syntax: node.clone(),
+            };
+            fields.add("role_refs", node.role_refs());
+            fields.add("drop_token", node.drop_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::DROP_VERTEX_EDGE_LABEL => {
+            let node = ast::DropVertexEdgeLabel {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("element_table_ref", node.element_table_ref());
+            fields.add("label_ref", node.label_ref());
+            fields.add("alter_token", node.alter_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("edge_token", node.edge_token());
+            fields.add("label_token", node.label_token());
+            fields.add("node_token", node.node_token());
+            fields.add("relationship_token", node.relationship_token());
+            fields.add("table_token", node.table_token());
+            fields.add("vertex_token", node.vertex_token());
+        }
+        SyntaxKind::DROP_VERTEX_EDGE_LABEL_PROPERTIES => {
+            let node = ast::DropVertexEdgeLabelProperties {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("element_table_ref", node.element_table_ref());
+            fields.add("label_ref", node.label_ref());
+            fields.add("property_name_refs", node.property_name_refs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("edge_token", node.edge_token());
+            fields.add("label_token", node.label_token());
+            fields.add("node_token", node.node_token());
+            fields.add("properties_token", node.properties_token());
+            fields.add("relationship_token", node.relationship_token());
+            fields.add("table_token", node.table_token());
+            fields.add("vertex_token", node.vertex_token());
+        }
+        SyntaxKind::DROP_VERTEX_TABLES => {
+            let node = ast::DropVertexTables {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("element_table_refs", node.element_table_refs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("node_token", node.node_token());
+            fields.add("tables_token", node.tables_token());
+            fields.add("vertex_token", node.vertex_token());
+        }
+        SyntaxKind::DROP_VIEW => {
+            let node = ast::DropView {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("if_exists", node.if_exists());
+            fields.add("view_refs", node.view_refs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("drop_token", node.drop_token());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::EDGE_ANY => {
+            let node = ast::EdgeAny {
+                syntax: node.clone(),
+            };
+            fields.add("element_variable", node.element_variable());
+            fields.add("is_label", node.is_label());
+            fields.add("where_clause", node.where_clause());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+            fields.add("minus_token", node.minus_token());
+        }
+        SyntaxKind::EDGE_LEFT => {
+            let node = ast::EdgeLeft {
+                syntax: node.clone(),
+            };
+            fields.add("element_variable", node.element_variable());
+            fields.add("is_label", node.is_label());
+            fields.add("where_clause", node.where_clause());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+            fields.add("minus_token", node.minus_token());
+            fields.add("l_angle_token", node.l_angle_token());
+        }
+        SyntaxKind::EDGE_RIGHT => {
+            let node = ast::EdgeRight {
+                syntax: node.clone(),
+            };
+            fields.add("element_variable", node.element_variable());
+            fields.add("is_label", node.is_label());
+            fields.add("where_clause", node.where_clause());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+            fields.add("minus_token", node.minus_token());
+            fields.add("r_angle_token", node.r_angle_token());
+        }
+        SyntaxKind::EDGE_TABLE_DEF => {
+            let node = ast::EdgeTableDef {
+                syntax: node.clone(),
+            };
+            fields.add("dest_vertex_table", node.dest_vertex_table());
+            fields.add(
+                "element_table_alias_clause",
+                node.element_table_alias_clause(),
+            );
+            fields.add("element_table_key_clause", node.element_table_key_clause());
+            fields.add(
+                "element_table_label_and_properties",
+                node.element_table_label_and_properties(),
+            );
+            fields.add("source_vertex_table", node.source_vertex_table());
+            fields.add("table_name_ref", node.table_name_ref());
+        }
+        SyntaxKind::EDGE_TABLES => {
+            let node = ast::EdgeTables {
+                syntax: node.clone(),
+            };
+            fields.add("edge_table_defs", node.edge_table_defs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("edge_token", node.edge_token());
+            fields.add("relationship_token", node.relationship_token());
+            fields.add("tables_token", node.tables_token());
+        }
+        SyntaxKind::ELEMENT_TABLE_ALIAS => {
+            let node = ast::ElementTableAlias {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ELEMENT_TABLE_ALIAS_CLAUSE => {
+            let node = ast::ElementTableAliasClause {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::ELEMENT_TABLE_KEY_CLAUSE => {
+            let node = ast::ElementTableKeyClause {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("key_token", node.key_token());
+        }
+        SyntaxKind::ELEMENT_TABLE_REF => {
+            let node = ast::ElementTableRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ELEMENT_TAG => {
+            let node = ast::ElementTag {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ELEMENT_VARIABLE => {
+            let node = ast::ElementVariable {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ELSE_CLAUSE => {
+            let node = ast::ElseClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("else_token", node.else_token());
+        }
+        SyntaxKind::EMPTY_STMT => {
+            let node = ast::EmptyStmt {
+                syntax: node.clone(),
+            };
+            fields.add("semicolon_token", node.semicolon_token());
+        }
+        SyntaxKind::ENABLE => {
+            let node = ast::Enable {
+                syntax: node.clone(),
+            };
+            fields.add("enable_token", node.enable_token());
+        }
+        SyntaxKind::ENABLE_ALWAYS => {
+            let node = ast::EnableAlways {
+                syntax: node.clone(),
+            };
+            fields.add("always_token", node.always_token());
+            fields.add("enable_token", node.enable_token());
+        }
+        SyntaxKind::ENABLE_ALWAYS_RULE => {
+            let node = ast::EnableAlwaysRule {
+                syntax: node.clone(),
+            };
+            fields.add("rule_ref", node.rule_ref());
+            fields.add("always_token", node.always_token());
+            fields.add("enable_token", node.enable_token());
+            fields.add("rule_token", node.rule_token());
+        }
+        SyntaxKind::ENABLE_ALWAYS_TRIGGER => {
+            let node = ast::EnableAlwaysTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("trigger_ref", node.trigger_ref());
+            fields.add("always_token", node.always_token());
+            fields.add("enable_token", node.enable_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::ENABLE_REPLICA => {
+            let node = ast::EnableReplica {
+                syntax: node.clone(),
+            };
+            fields.add("enable_token", node.enable_token());
+            fields.add("replica_token", node.replica_token());
+        }
+        SyntaxKind::ENABLE_REPLICA_RULE => {
+            let node = ast::EnableReplicaRule {
+                syntax: node.clone(),
+            };
+            fields.add("rule_ref", node.rule_ref());
+            fields.add("enable_token", node.enable_token());
+            fields.add("replica_token", node.replica_token());
+            fields.add("rule_token", node.rule_token());
+        }
+        SyntaxKind::ENABLE_REPLICA_TRIGGER => {
+            let node = ast::EnableReplicaTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("trigger_ref", node.trigger_ref());
+            fields.add("enable_token", node.enable_token());
+            fields.add("replica_token", node.replica_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::ENABLE_RLS => {
+            let node = ast::EnableRls {
+                syntax: node.clone(),
+            };
+            fields.add("enable_token", node.enable_token());
+            fields.add("level_token", node.level_token());
+            fields.add("row_token", node.row_token());
+            fields.add("security_token", node.security_token());
+        }
+        SyntaxKind::ENABLE_RULE => {
+            let node = ast::EnableRule {
+                syntax: node.clone(),
+            };
+            fields.add("rule_ref", node.rule_ref());
+            fields.add("enable_token", node.enable_token());
+            fields.add("rule_token", node.rule_token());
+        }
+        SyntaxKind::ENABLE_SUBSCRIPTION => {
+            let node = ast::EnableSubscription {
+                syntax: node.clone(),
+            };
+            fields.add("enable_token", node.enable_token());
+        }
+        SyntaxKind::ENABLE_TRIGGER => {
+            let node = ast::EnableTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("trigger_target", node.trigger_target());
+            fields.add("enable_token", node.enable_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::ENFORCED => {
+            let node = ast::Enforced {
+                syntax: node.clone(),
+            };
+            fields.add("enforced_token", node.enforced_token());
+        }
+        SyntaxKind::ENUM_TYPE => {
+            let node = ast::EnumType {
+                syntax: node.clone(),
+            };
+            fields.add("variant_list", node.variant_list());
+            fields.add("as_token", node.as_token());
+            fields.add("enum_token", node.enum_token());
+        }
+        SyntaxKind::EVENT_TRIGGER => {
+            let node = ast::EventTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::EVENT_TRIGGER_EVENT => {
+            let node = ast::EventTriggerEvent {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::EVENT_TRIGGER_FILTER_VARIABLE => {
+            let node = ast::EventTriggerFilterVariable {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::EVENT_TRIGGER_REF => {
+            let node = ast::EventTriggerRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::EVENT_TRIGGER_RENAME_TO => {
+            let node = ast::EventTriggerRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("event_trigger", node.event_trigger());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::EVENT_TRIGGER_WHEN => {
+            let node = ast::EventTriggerWhen {
+                syntax: node.clone(),
+            };
+            fields.add("literals", node.literals());
+            fields.add("var", node.var());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("in_token", node.in_token());
+        }
+        SyntaxKind::EVENT_TRIGGER_WHEN_CLAUSE => {
+            let node = ast::EventTriggerWhenClause {
+                syntax: node.clone(),
+            };
+            fields.add("event_trigger_whens", node.event_trigger_whens());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::EXCEPT => {
+            let node = ast::Except {
+                syntax: node.clone(),
+            };
+            fields.add("all_or_distinct", node.all_or_distinct());
+            fields.add("except_token", node.except_token());
+        }
+        SyntaxKind::EXCEPT_TABLE_CLAUSE => {
+            let node = ast::ExceptTableClause {
+                syntax: node.clone(),
+            };
+            fields.add("except_table_names", node.except_table_names());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("except_token", node.except_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::EXCEPT_TABLE_NAME => {
+            let node = ast::ExceptTableName {
+                syntax: node.clone(),
+            };
+            fields.add("table_relation_name", node.table_relation_name());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::EXCEPT_TABLES => {
+            let node = ast::ExceptTables {
+                syntax: node.clone(),
+            };
+            fields.add("remote_table_name_refs", node.remote_table_name_refs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("except_token", node.except_token());
+        }
+        SyntaxKind::EXCLUDE_CONSTRAINT => {
+            let node = ast::ExcludeConstraint {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "constraint_exclusion_list",
+                node.constraint_exclusion_list(),
+            );
+            fields.add(
+                "constraint_include_clause",
+                node.constraint_include_clause(),
+            );
+            fields.add("constraint_index_method", node.constraint_index_method());
+            fields.add(
+                "constraint_index_tablespace",
+                node.constraint_index_tablespace(),
+            );
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("where_condition_clause", node.where_condition_clause());
+            fields.add("with_params", node.with_params());
+            fields.add("exclude_token", node.exclude_token());
+        }
+        SyntaxKind::EXCLUDING_PROPERTY => {
+            let node = ast::ExcludingProperty {
+                syntax: node.clone(),
+            };
+            fields.add("table_property", node.table_property());
+            fields.add("excluding_token", node.excluding_token());
+        }
+        SyntaxKind::EXCLUSIVE => {
+            let node = ast::Exclusive {
+                syntax: node.clone(),
+            };
+            fields.add("exclusive_token", node.exclusive_token());
+        }
+        SyntaxKind::EXECUTE => {
+            let node = ast::Execute {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("prepared_statement_ref", node.prepared_statement_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("execute_token", node.execute_token());
+        }
+        SyntaxKind::EXISTS_FN => {
+            let node = ast::ExistsFn {
+                syntax: node.clone(),
+            };
+            fields.add("select_variant", node.select_variant());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("exists_token", node.exists_token());
+        }
+        SyntaxKind::EXPLAIN => {
+            let node = ast::Explain {
+                syntax: node.clone(),
+            };
+            fields.add("explain_mode", node.explain_mode());
+            fields.add("explain_stmt", node.explain_stmt());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("explain_token", node.explain_token());
+        }
+        SyntaxKind::EXPLAIN_ANALYZE => {
+            let node = ast::ExplainAnalyze {
+                syntax: node.clone(),
+            };
+            fields.add("explain_verbose", node.explain_verbose());
+            fields.add("analyse_token", node.analyse_token());
+            fields.add("analyze_token", node.analyze_token());
+        }
+        SyntaxKind::EXPLAIN_OPTION => {
+            let node = ast::ExplainOption {
+                syntax: node.clone(),
+            };
+            fields.add("explain_option_name", node.explain_option_name());
+            fields.add("explain_option_value", node.explain_option_value());
+        }
+        SyntaxKind::EXPLAIN_OPTION_LIST => {
+            let node = ast::ExplainOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("explain_options", node.explain_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::EXPLAIN_OPTION_NAME => {
+            let node = ast::ExplainOptionName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::EXPLAIN_OPTION_VALUE => {
+            let node = ast::ExplainOptionValue {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("binary_token", node.binary_token());
+            fields.add("ident_token", node.ident_token());
+            fields.add("json_token", node.json_token());
+            fields.add("no_token", node.no_token());
+            fields.add("none_token", node.none_token());
+            fields.add("text_token", node.text_token());
+            fields.add("xml_token", node.xml_token());
+            fields.add("yes_token", node.yes_token());
+        }
+        SyntaxKind::EXPLAIN_VERBOSE => {
+            let node = ast::ExplainVerbose {
+                syntax: node.clone(),
+            };
+            fields.add("verbose_token", node.verbose_token());
+        }
+        SyntaxKind::EXPR_AS_COLUMN_NAME => {
+            let node = ast::ExprAsColumnName {
+                syntax: node.clone(),
+            };
+            fields.add("column_name", node.column_name());
+            fields.add("expr", node.expr());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::EXPR_AS_COLUMN_NAME_LIST => {
+            let node = ast::ExprAsColumnNameList {
+                syntax: node.clone(),
+            };
+            fields.add("expr_as_column_names", node.expr_as_column_names());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::EXPR_AS_ELEMENT_TAG => {
+            let node = ast::ExprAsElementTag {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("tag", node.tag());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::EXPR_AS_ELEMENT_TAG_LIST => {
+            let node = ast::ExprAsElementTagList {
+                syntax: node.clone(),
+            };
+            fields.add("expr_as_element_tags", node.expr_as_element_tags());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::EXPR_AS_PROPERTY_NAME => {
+            let node = ast::ExprAsPropertyName {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("property_name", node.property_name());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::EXPR_AS_PROPERTY_NAME_LIST => {
+            let node = ast::ExprAsPropertyNameList {
+                syntax: node.clone(),
+            };
+            fields.add("expr_as_property_names", node.expr_as_property_names());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::EXPR_AS_XML_ATTR => {
+            let node = ast::ExprAsXmlAttr {
+                syntax: node.clone(),
+            };
+            fields.add("attr", node.attr());
+            fields.add("expr", node.expr());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::EXPR_AS_XML_ATTR_LIST => {
+            let node = ast::ExprAsXmlAttrList {
+                syntax: node.clone(),
+            };
+            fields.add("expr_as_xml_attrs", node.expr_as_xml_attrs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::EXPR_FOLLOWING => {
+            let node = ast::ExprFollowing {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("following_token", node.following_token());
+        }
+        SyntaxKind::EXPR_FROM_ITEM => {
+            let node = ast::ExprFromItem {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("cast_expr", node.cast_expr());
+            fields.add("collation_for_fn", node.collation_for_fn());
+            fields.add("lateral_token", node.lateral_token());
+        }
+        SyntaxKind::EXPR_PRECEDING => {
+            let node = ast::ExprPreceding {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("preceding_token", node.preceding_token());
+        }
+        SyntaxKind::EXPR_TYPE => {
+            let node = ast::ExprType {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+        }
+        SyntaxKind::EXTENSION => {
+            let node = ast::Extension {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::EXTENSION_REF => {
+            let node = ast::ExtensionRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::EXTENSION_SCHEMA => {
+            let node = ast::ExtensionSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_ref", node.schema_ref());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::EXTENSION_VERSION => {
+            let node = ast::ExtensionVersion {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::EXTENSION_VERSION_CLAUSE => {
+            let node = ast::ExtensionVersionClause {
+                syntax: node.clone(),
+            };
+            fields.add("extension_version", node.extension_version());
+            fields.add("literal", node.literal());
+            fields.add("version_token", node.version_token());
+        }
+        SyntaxKind::EXTRACT_FIELD_LITERAL => {
+            let node = ast::ExtractFieldLiteral {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+        }
+        SyntaxKind::EXTRACT_FIELD_NAME => {
+            let node = ast::ExtractFieldName {
+                syntax: node.clone(),
+            };
+            fields.add("day_token", node.day_token());
+            fields.add("hour_token", node.hour_token());
+            fields.add("ident_token", node.ident_token());
+            fields.add("minute_token", node.minute_token());
+            fields.add("month_token", node.month_token());
+            fields.add("second_token", node.second_token());
+            fields.add("year_token", node.year_token());
+        }
+        SyntaxKind::EXTRACT_FN => {
+            let node = ast::ExtractFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("extract_field", node.extract_field());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("extract_token", node.extract_token());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::FAT_ARROW => {
+            let node = ast::FatArrow {
+                syntax: node.clone(),
+            };
+            fields.add("eq_token", node.eq_token());
+            fields.add("r_angle_token", node.r_angle_token());
+        }
+        SyntaxKind::FDW_OPTION_LIST => {
+            let node = ast::FdwOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("fdw_options", node.fdw_options());
+        }
+        SyntaxKind::FETCH => {
+            let node = ast::Fetch {
+                syntax: node.clone(),
+            };
+            fields.add("cursor_action", node.cursor_action());
+            fields.add("cursor_ref", node.cursor_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("fetch_token", node.fetch_token());
+            fields.add("from_token", node.from_token());
+            fields.add("in_token", node.in_token());
+        }
+        SyntaxKind::FETCH_CLAUSE => {
+            let node = ast::FetchClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("fetch_quantity", node.fetch_quantity());
+            fields.add("fetch_token", node.fetch_token());
+            fields.add("first_token", node.first_token());
+            fields.add("next_token", node.next_token());
+            fields.add("row_token", node.row_token());
+            fields.add("rows_token", node.rows_token());
+        }
+        SyntaxKind::FETCH_ONLY => {
+            let node = ast::FetchOnly {
+                syntax: node.clone(),
+            };
+            fields.add("only_token", node.only_token());
+        }
+        SyntaxKind::FIELD_ACCESSOR => {
+            let node = ast::FieldAccessor {
+                syntax: node.clone(),
+            };
+            fields.add("composite_field_ref", node.composite_field_ref());
+            fields.add("star_token", node.star_token());
+            fields.add("dot_token", node.dot_token());
+        }
+        SyntaxKind::FIELD_EXPR => {
+            let node = ast::FieldExpr {
+                syntax: node.clone(),
+            };
+            fields.add("base", node.base());
+            fields.add("field", node.field());
+            fields.add("star_token", node.star_token());
+            fields.add("dot_token", node.dot_token());
+        }
+        SyntaxKind::FILTER_CLAUSE => {
+            let node = ast::FilterClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("filter_token", node.filter_token());
+            fields.add("where_token", node.where_token());
+        }
+        SyntaxKind::FIRST => {
+            let node = ast::First {
+                syntax: node.clone(),
+            };
+            fields.add("first_token", node.first_token());
+        }
+        SyntaxKind::FOR_ALL_PUBLICATION_OBJECTS => {
+            let node = ast::ForAllPublicationObjects {
+                syntax: node.clone(),
+            };
+            fields.add("all_publication_objects", node.all_publication_objects());
+            fields.add("except_table_clause", node.except_table_clause());
+            fields.add("for_token", node.for_token());
+        }
+        SyntaxKind::FOR_EACH_ROW => {
+            let node = ast::ForEachRow {
+                syntax: node.clone(),
+            };
+            fields.add("each_token", node.each_token());
+            fields.add("for_token", node.for_token());
+            fields.add("row_token", node.row_token());
+        }
+        SyntaxKind::FOR_EACH_STATEMENT => {
+            let node = ast::ForEachStatement {
+                syntax: node.clone(),
+            };
+            fields.add("each_token", node.each_token());
+            fields.add("for_token", node.for_token());
+            fields.add("statement_token", node.statement_token());
+        }
+        SyntaxKind::FOR_KEY_SHARE => {
+            let node = ast::ForKeyShare {
+                syntax: node.clone(),
+            };
+            fields.add("key_token", node.key_token());
+            fields.add("share_token", node.share_token());
+        }
+        SyntaxKind::FOR_NO_KEY_UPDATE => {
+            let node = ast::ForNoKeyUpdate {
+                syntax: node.clone(),
+            };
+            fields.add("key_token", node.key_token());
+            fields.add("no_token", node.no_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::FOR_PORTION_OF => {
+            let node = ast::ForPortionOf {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("range", node.range());
+            fields.add("for_token", node.for_token());
+            fields.add("of_token", node.of_token());
+            fields.add("portion_token", node.portion_token());
+        }
+        SyntaxKind::FOR_PROVIDER => {
+            let node = ast::ForProvider {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("security_label_provider", node.security_label_provider());
+            fields.add("for_token", node.for_token());
+        }
+        SyntaxKind::FOR_PUBLICATION_OBJECTS => {
+            let node = ast::ForPublicationObjects {
+                syntax: node.clone(),
+            };
+            fields.add("publication_objects", node.publication_objects());
+            fields.add("for_token", node.for_token());
+        }
+        SyntaxKind::FOR_ROLES => {
+            let node = ast::ForRoles {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("for_token", node.for_token());
+            fields.add("role_token", node.role_token());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::FOR_SHARE => {
+            let node = ast::ForShare {
+                syntax: node.clone(),
+            };
+            fields.add("share_token", node.share_token());
+        }
+        SyntaxKind::FOR_UPDATE => {
+            let node = ast::ForUpdate {
+                syntax: node.clone(),
+            };
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::FORCE => {
+            let node = ast::Force {
+                syntax: node.clone(),
+            };
+            fields.add("force_token", node.force_token());
+        }
+        SyntaxKind::FORCE_NOT_NULL => {
+            let node = ast::ForceNotNull {
+                syntax: node.clone(),
+            };
+            fields.add("not_token", node.not_token());
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::FORCE_NULL => {
+            let node = ast::ForceNull {
+                syntax: node.clone(),
+            };
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::FORCE_QUOTE => {
+            let node = ast::ForceQuote {
+                syntax: node.clone(),
+            };
+            fields.add("quote_token", node.quote_token());
+        }
+        SyntaxKind::FORCE_RLS => {
+            let node = ast::ForceRls {
+                syntax: node.clone(),
+            };
+            fields.add("force_token", node.force_token());
+            fields.add("level_token", node.level_token());
+            fields.add("row_token", node.row_token());
+            fields.add("security_token", node.security_token());
+        }
+        SyntaxKind::FOREIGN_DATA_WRAPPER => {
+            let node = ast::ForeignDataWrapper {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::FOREIGN_DATA_WRAPPER_REF => {
+            let node = ast::ForeignDataWrapperRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::FOREIGN_DATA_WRAPPER_RENAME_TO => {
+            let node = ast::ForeignDataWrapperRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("foreign_data_wrapper", node.foreign_data_wrapper());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::FOREIGN_KEY_COLUMN_LIST => {
+            let node = ast::ForeignKeyColumnList {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_refs", node.column_name_refs());
+            fields.add("period_column", node.period_column());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::FOREIGN_KEY_CONSTRAINT => {
+            let node = ast::ForeignKeyConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("from_columns", node.from_columns());
+            fields.add("to_columns", node.to_columns());
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("match_type", node.match_type());
+            fields.add("on_delete_action", node.on_delete_action());
+            fields.add("on_update_action", node.on_update_action());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("key_token", node.key_token());
+            fields.add("references_token", node.references_token());
+        }
+        SyntaxKind::FOREIGN_OPTION_NAME => {
+            let node = ast::ForeignOptionName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::FORWARD => {
+            let node = ast::Forward {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("all_token", node.all_token());
+            fields.add("forward_token", node.forward_token());
+        }
+        SyntaxKind::FRAME_BETWEEN => {
+            let node = ast::FrameBetween {
+                syntax: node.clone(),
+            };
+            fields.add("start", node.start());
+            fields.add("end", node.end());
+            fields.add("and_token", node.and_token());
+            fields.add("between_token", node.between_token());
+        }
+        SyntaxKind::FRAME_CLAUSE => {
+            let node = ast::FrameClause {
+                syntax: node.clone(),
+            };
+            fields.add("frame_exclude", node.frame_exclude());
+            fields.add("frame_extent", node.frame_extent());
+            fields.add("frame_units", node.frame_units());
+        }
+        SyntaxKind::FRAME_EXCLUDE => {
+            let node = ast::FrameExclude {
+                syntax: node.clone(),
+            };
+            fields.add("frame_exclude_target", node.frame_exclude_target());
+            fields.add("exclude_token", node.exclude_token());
+        }
+        SyntaxKind::FRAME_GROUPS => {
+            let node = ast::FrameGroups {
+                syntax: node.clone(),
+            };
+            fields.add("groups_token", node.groups_token());
+        }
+        SyntaxKind::FRAME_RANGE => {
+            let node = ast::FrameRange {
+                syntax: node.clone(),
+            };
+            fields.add("range_token", node.range_token());
+        }
+        SyntaxKind::FRAME_ROWS => {
+            let node = ast::FrameRows {
+                syntax: node.clone(),
+            };
+            fields.add("rows_token", node.rows_token());
+        }
+        SyntaxKind::FROM_ALIAS => {
+            let node = ast::FromAlias {
+                syntax: node.clone(),
+            };
+            fields.add("columns", node.columns());
+            fields.add("name", node.name());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::FROM_CLAUSE => {
+            let node = ast::FromClause {
+                syntax: node.clone(),
+            };
+            fields.add("from_items", node.from_items());
+            fields.add("join_exprs", node.join_exprs());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::FROM_CURRENT => {
+            let node = ast::FromCurrent {
+                syntax: node.clone(),
+            };
+            fields.add("current_token", node.current_token());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::FROM_TABLE => {
+            let node = ast::FromTable {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::FUNC_OPTION_LIST => {
+            let node = ast::FuncOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("options", node.options());
+        }
+        SyntaxKind::FUNCTION_FROM_ITEM => {
+            let node = ast::FunctionFromItem {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("call_expr", node.call_expr());
+            fields.add("with_ordinality", node.with_ordinality());
+            fields.add("lateral_token", node.lateral_token());
+        }
+        SyntaxKind::FUNCTION_NAME => {
+            let node = ast::FunctionName {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::FUNCTION_NAME_REF => {
+            let node = ast::FunctionNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::FUNCTION_RENAME_TO => {
+            let node = ast::FunctionRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("function_name", node.function_name());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::FUNCTION_SIG => {
+            let node = ast::FunctionSig {
+                syntax: node.clone(),
+            };
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("param_list", node.param_list());
+        }
+        SyntaxKind::FUNCTION_SIG_LIST => {
+            let node = ast::FunctionSigList {
+                syntax: node.clone(),
+            };
+            fields.add("function_sigs", node.function_sigs());
+        }
+        SyntaxKind::GENERATED_ALWAYS => {
+            let node = ast::GeneratedAlways {
+                syntax: node.clone(),
+            };
+            fields.add("always_token", node.always_token());
+        }
+        SyntaxKind::GENERATED_BY_DEFAULT => {
+            let node = ast::GeneratedByDefault {
+                syntax: node.clone(),
+            };
+            fields.add("by_token", node.by_token());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::GENERATED_CONSTRAINT => {
+            let node = ast::GeneratedConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("generated_as", node.generated_as());
+            fields.add("generated_token", node.generated_token());
+        }
+        SyntaxKind::GENERATED_IDENTITY => {
+            let node = ast::GeneratedIdentity {
+                syntax: node.clone(),
+            };
+            fields.add("generated_when", node.generated_when());
+            fields.add("sequence_option_list", node.sequence_option_list());
+            fields.add("as_token", node.as_token());
+            fields.add("identity_token", node.identity_token());
+        }
+        SyntaxKind::GENERATED_STORED => {
+            let node = ast::GeneratedStored {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("generated_kind", node.generated_kind());
+            fields.add("generated_when", node.generated_when());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::GRANT => {
+            let node = ast::Grant {
+                syntax: node.clone(),
+            };
+            fields.add("grant_with_clause", node.grant_with_clause());
+            fields.add("granted_by_clause", node.granted_by_clause());
+            fields.add(
+                "on_privilege_objects_clause",
+                node.on_privilege_objects_clause(),
+            );
+            fields.add("privileges", node.privileges());
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("grant_token", node.grant_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::GRANT_DEFAULT_PRIVILEGES => {
+            let node = ast::GrantDefaultPrivileges {
+                syntax: node.clone(),
+            };
+            fields.add("grant_with_clause", node.grant_with_clause());
+            fields.add("privilege_target", node.privilege_target());
+            fields.add("privileges", node.privileges());
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("grant_token", node.grant_token());
+            fields.add("on_token", node.on_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::GRANT_OPTION => {
+            let node = ast::GrantOption {
+                syntax: node.clone(),
+            };
+            fields.add("grant_token", node.grant_token());
+            fields.add("option_token", node.option_token());
+        }
+        SyntaxKind::GRANT_OPTION_FOR => {
+            let node = ast::GrantOptionFor {
+                syntax: node.clone(),
+            };
+            fields.add("for_token", node.for_token());
+            fields.add("grant_token", node.grant_token());
+            fields.add("option_token", node.option_token());
+        }
+        SyntaxKind::GRANT_ROLE_OPTION => {
+            let node = ast::GrantRoleOption {
+                syntax: node.clone(),
+            };
+            fields.add("grant_role_option_name", node.grant_role_option_name());
+            fields.add("false_token", node.false_token());
+            fields.add("option_token", node.option_token());
+            fields.add("true_token", node.true_token());
+        }
+        SyntaxKind::GRANT_ROLE_OPTION_LIST => {
+            let node = ast::GrantRoleOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("grant_role_options", node.grant_role_options());
+        }
+        SyntaxKind::GRANT_ROLE_OPTION_NAME => {
+            let node = ast::GrantRoleOptionName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::GRANT_WITH_CLAUSE => {
+            let node = ast::GrantWithClause {
+                syntax: node.clone(),
+            };
+            fields.add("grant_option", node.grant_option());
+            fields.add("grant_role_option_list", node.grant_role_option_list());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::GRANTED_BY_CLAUSE => {
+            let node = ast::GrantedByClause {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref", node.role_ref());
+            fields.add("by_token", node.by_token());
+            fields.add("granted_token", node.granted_token());
+        }
+        SyntaxKind::GRAPH_PATTERN_QUALIFIER => {
+            let node = ast::GraphPatternQualifier {
+                syntax: node.clone(),
+            };
+            fields.add("max", node.max());
+            fields.add("min", node.min());
+            fields.add("l_curly_token", node.l_curly_token());
+            fields.add("r_curly_token", node.r_curly_token());
+            fields.add("comma_token", node.comma_token());
+        }
+        SyntaxKind::GRAPH_TABLE_FN => {
+            let node = ast::GraphTableFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr_as_column_name_list", node.expr_as_column_name_list());
+            fields.add("path_pattern_list", node.path_pattern_list());
+            fields.add("property_graph_ref", node.property_graph_ref());
+            fields.add("where_clause", node.where_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("columns_token", node.columns_token());
+            fields.add("graph_table_token", node.graph_table_token());
+            fields.add("match_token", node.match_token());
+        }
+        SyntaxKind::GRAPH_TABLE_FROM_ITEM => {
+            let node = ast::GraphTableFromItem {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("graph_table_fn", node.graph_table_fn());
+            fields.add("lateral_token", node.lateral_token());
+        }
+        SyntaxKind::GROUP => {
+            let node = ast::Group {
+                syntax: node.clone(),
+            };
+            fields.add("group_token", node.group_token());
+        }
+        SyntaxKind::GROUP_BY_CLAUSE => {
+            let node = ast::GroupByClause {
+                syntax: node.clone(),
+            };
+            fields.add("all_or_distinct", node.all_or_distinct());
+            fields.add("group_by_list", node.group_by_list());
+            fields.add("by_token", node.by_token());
+            fields.add("group_token", node.group_token());
+        }
+        SyntaxKind::GROUP_BY_LIST => {
+            let node = ast::GroupByList {
+                syntax: node.clone(),
+            };
+            fields.add("group_bys", node.group_bys());
+        }
+        SyntaxKind::GROUPING_CUBE => {
+            let node = ast::GroupingCube {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("cube_token", node.cube_token());
+        }
+        SyntaxKind::GROUPING_EXPR => {
+            let node = ast::GroupingExpr {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+        }
+        SyntaxKind::GROUPING_ROLLUP => {
+            let node = ast::GroupingRollup {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("rollup_token", node.rollup_token());
+        }
+        SyntaxKind::GROUPING_SETS => {
+            let node = ast::GroupingSets {
+                syntax: node.clone(),
+            };
+            fields.add("group_bys", node.group_bys());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("grouping_token", node.grouping_token());
+            fields.add("sets_token", node.sets_token());
+        }
+        SyntaxKind::GTEQ => {
+            let node = ast::Gteq {
+                syntax: node.clone(),
+            };
+            fields.add("eq_token", node.eq_token());
+            fields.add("r_angle_token", node.r_angle_token());
+        }
+        SyntaxKind::HANDLER_CLAUSE => {
+            let node = ast::HandlerClause {
+                syntax: node.clone(),
+            };
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("handler_token", node.handler_token());
+        }
+        SyntaxKind::HAVING_CLAUSE => {
+            let node = ast::HavingClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("having_token", node.having_token());
+        }
+        SyntaxKind::IF_EXISTS => {
+            let node = ast::IfExists {
+                syntax: node.clone(),
+            };
+            fields.add("exists_token", node.exists_token());
+            fields.add("if_token", node.if_token());
+        }
+        SyntaxKind::IF_NOT_EXISTS => {
+            let node = ast::IfNotExists {
+                syntax: node.clone(),
+            };
+            fields.add("exists_token", node.exists_token());
+            fields.add("if_token", node.if_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::IGNORE_NULLS => {
+            let node = ast::IgnoreNulls {
+                syntax: node.clone(),
+            };
+            fields.add("ignore_token", node.ignore_token());
+            fields.add("nulls_token", node.nulls_token());
+        }
+        SyntaxKind::IMMEDIATE => {
+            let node = ast::Immediate {
+                syntax: node.clone(),
+            };
+            fields.add("immediate_token", node.immediate_token());
+        }
+        SyntaxKind::IMMUTABLE => {
+            let node = ast::Immutable {
+                syntax: node.clone(),
+            };
+            fields.add("immutable_token", node.immutable_token());
+        }
+        SyntaxKind::IMPORT_FOREIGN_SCHEMA => {
+            let node = ast::ImportForeignSchema {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("import_table_filter", node.import_table_filter());
+            fields.add("into_schema", node.into_schema());
+            fields.add("schema_ref", node.schema_ref());
+            fields.add("server_clause", node.server_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("from_token", node.from_token());
+            fields.add("import_token", node.import_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::IN_DATABASE => {
+            let node = ast::InDatabase {
+                syntax: node.clone(),
+            };
+            fields.add("database_ref", node.database_ref());
+            fields.add("database_token", node.database_token());
+            fields.add("in_token", node.in_token());
+        }
+        SyntaxKind::IN_SCHEMAS => {
+            let node = ast::InSchemas {
+                syntax: node.clone(),
+            };
+            fields.add("schema_refs", node.schema_refs());
+            fields.add("in_token", node.in_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::INCLUDING_PROPERTY => {
+            let node = ast::IncludingProperty {
+                syntax: node.clone(),
+            };
+            fields.add("table_property", node.table_property());
+            fields.add("including_token", node.including_token());
+        }
+        SyntaxKind::INDENT => {
+            let node = ast::Indent {
+                syntax: node.clone(),
+            };
+            fields.add("indent_token", node.indent_token());
+        }
+        SyntaxKind::INDEX => {
+            let node = ast::Index {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::INDEX_ACCESSOR => {
+            let node = ast::IndexAccessor {
+                syntax: node.clone(),
+            };
+            fields.add("index", node.index());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+        }
+        SyntaxKind::INDEX_EXPR => {
+            let node = ast::IndexExpr {
+                syntax: node.clone(),
+            };
+            fields.add("base", node.base());
+            fields.add("index", node.index());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+        }
+        SyntaxKind::INDEX_INCLUDE_CLAUSE => {
+            let node = ast::IndexIncludeClause {
+                syntax: node.clone(),
+            };
+            fields.add("partition_item_list", node.partition_item_list());
+            fields.add("include_token", node.include_token());
+        }
+        SyntaxKind::INDEX_PARAMETERS => {
+            let node = ast::IndexParameters {
+                syntax: node.clone(),
+            };
+            fields.add("column_list", node.column_list());
+            fields.add(
+                "constraint_include_clause",
+                node.constraint_include_clause(),
+            );
+            fields.add(
+                "constraint_index_tablespace",
+                node.constraint_index_tablespace(),
+            );
+            fields.add("nulls_distinct_option", node.nulls_distinct_option());
+            fields.add("with_params", node.with_params());
+        }
+        SyntaxKind::INDEX_REF => {
+            let node = ast::IndexRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::INDEX_RENAME_TO => {
+            let node = ast::IndexRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("index", node.index());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::INHERIT => {
+            let node = ast::Inherit {
+                syntax: node.clone(),
+            };
+            fields.add("inherit_token", node.inherit_token());
+        }
+        SyntaxKind::INHERIT_OPTION_FOR => {
+            let node = ast::InheritOptionFor {
+                syntax: node.clone(),
+            };
+            fields.add("for_token", node.for_token());
+            fields.add("inherit_token", node.inherit_token());
+            fields.add("option_token", node.option_token());
+        }
+        SyntaxKind::INHERIT_TABLE => {
+            let node = ast::InheritTable {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("inherit_token", node.inherit_token());
+        }
+        SyntaxKind::INHERITS => {
+            let node = ast::Inherits {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_refs", node.table_name_refs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("inherits_token", node.inherits_token());
+        }
+        SyntaxKind::INITIALLY_DEFERRED_CONSTRAINT_OPTION => {
+            let node = ast::InitiallyDeferredConstraintOption {
+                syntax: node.clone(),
+            };
+            fields.add("deferred_token", node.deferred_token());
+            fields.add("initially_token", node.initially_token());
+        }
+        SyntaxKind::INITIALLY_IMMEDIATE_CONSTRAINT_OPTION => {
+            let node = ast::InitiallyImmediateConstraintOption {
+                syntax: node.clone(),
+            };
+            fields.add("immediate_token", node.immediate_token());
+            fields.add("initially_token", node.initially_token());
+        }
+        SyntaxKind::INLINE_CLAUSE => {
+            let node = ast::InlineClause {
+                syntax: node.clone(),
+            };
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("inline_token", node.inline_token());
+        }
+        SyntaxKind::INSENSITIVE => {
+            let node = ast::Insensitive {
+                syntax: node.clone(),
+            };
+            fields.add("insensitive_token", node.insensitive_token());
+        }
+        SyntaxKind::INSERT => {
+            let node = ast::Insert {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("column_target_list", node.column_target_list());
+            fields.add("insert_source", node.insert_source());
+            fields.add("on_conflict_clause", node.on_conflict_clause());
+            fields.add("overriding_clause", node.overriding_clause());
+            fields.add("relation_name_ref", node.relation_name_ref());
+            fields.add("returning_clause", node.returning_clause());
+            fields.add("with_clause", node.with_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("insert_token", node.insert_token());
+            fields.add("into_token", node.into_token());
+        }
+        SyntaxKind::INTERSECT => {
+            let node = ast::Intersect {
+                syntax: node.clone(),
+            };
+            fields.add("all_or_distinct", node.all_or_distinct());
+            fields.add("intersect_token", node.intersect_token());
+        }
+        SyntaxKind::INTERVAL_DAY => {
+            let node = ast::IntervalDay {
+                syntax: node.clone(),
+            };
+            fields.add("day_token", node.day_token());
+        }
+        SyntaxKind::INTERVAL_HOUR => {
+            let node = ast::IntervalHour {
+                syntax: node.clone(),
+            };
+            fields.add("day_token", node.day_token());
+            fields.add("hour_token", node.hour_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::INTERVAL_MINUTE => {
+            let node = ast::IntervalMinute {
+                syntax: node.clone(),
+            };
+            fields.add("day_token", node.day_token());
+            fields.add("hour_token", node.hour_token());
+            fields.add("minute_token", node.minute_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::INTERVAL_MONTH => {
+            let node = ast::IntervalMonth {
+                syntax: node.clone(),
+            };
+            fields.add("month_token", node.month_token());
+            fields.add("to_token", node.to_token());
+            fields.add("year_token", node.year_token());
+        }
+        SyntaxKind::INTERVAL_SECOND => {
+            let node = ast::IntervalSecond {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("day_token", node.day_token());
+            fields.add("hour_token", node.hour_token());
+            fields.add("minute_token", node.minute_token());
+            fields.add("second_token", node.second_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::INTERVAL_TYPE => {
+            let node = ast::IntervalType {
+                syntax: node.clone(),
+            };
+            fields.add("interval_qualifier", node.interval_qualifier());
+            fields.add("literal", node.literal());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("interval_token", node.interval_token());
+            fields.add("setof_token", node.setof_token());
+        }
+        SyntaxKind::INTERVAL_YEAR => {
+            let node = ast::IntervalYear {
+                syntax: node.clone(),
+            };
+            fields.add("year_token", node.year_token());
+        }
+        SyntaxKind::INTO_CLAUSE => {
+            let node = ast::IntoClause {
+                syntax: node.clone(),
+            };
+            fields.add("persistence", node.persistence());
+            fields.add("table_name", node.table_name());
+            fields.add("into_token", node.into_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::INTO_SCHEMA => {
+            let node = ast::IntoSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_ref", node.schema_ref());
+            fields.add("into_token", node.into_token());
+        }
+        SyntaxKind::IS_DISTINCT_FROM => {
+            let node = ast::IsDistinctFrom {
+                syntax: node.clone(),
+            };
+            fields.add("distinct_token", node.distinct_token());
+            fields.add("from_token", node.from_token());
+            fields.add("is_token", node.is_token());
+        }
+        SyntaxKind::IS_JSON => {
+            let node = ast::IsJson {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+        }
+        SyntaxKind::IS_JSON_ARRAY => {
+            let node = ast::IsJsonArray {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("array_token", node.array_token());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+        }
+        SyntaxKind::IS_JSON_OBJECT => {
+            let node = ast::IsJsonObject {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+            fields.add("object_token", node.object_token());
+        }
+        SyntaxKind::IS_JSON_SCALAR => {
+            let node = ast::IsJsonScalar {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+            fields.add("scalar_token", node.scalar_token());
+        }
+        SyntaxKind::IS_JSON_VALUE => {
+            let node = ast::IsJsonValue {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::IS_LABEL => {
+            let node = ast::IsLabel {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("is_token", node.is_token());
+        }
+        SyntaxKind::IS_NORMALIZED => {
+            let node = ast::IsNormalized {
+                syntax: node.clone(),
+            };
+            fields.add("unicode_normal_form", node.unicode_normal_form());
+            fields.add("is_token", node.is_token());
+            fields.add("normalized_token", node.normalized_token());
+        }
+        SyntaxKind::IS_NOT => {
+            let node = ast::IsNot {
+                syntax: node.clone(),
+            };
+            fields.add("is_token", node.is_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::IS_NOT_DISTINCT_FROM => {
+            let node = ast::IsNotDistinctFrom {
+                syntax: node.clone(),
+            };
+            fields.add("distinct_token", node.distinct_token());
+            fields.add("from_token", node.from_token());
+            fields.add("is_token", node.is_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::IS_NOT_JSON => {
+            let node = ast::IsNotJson {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::IS_NOT_JSON_ARRAY => {
+            let node = ast::IsNotJsonArray {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("array_token", node.array_token());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::IS_NOT_JSON_OBJECT => {
+            let node = ast::IsNotJsonObject {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+            fields.add("not_token", node.not_token());
+            fields.add("object_token", node.object_token());
+        }
+        SyntaxKind::IS_NOT_JSON_SCALAR => {
+            let node = ast::IsNotJsonScalar {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+            fields.add("not_token", node.not_token());
+            fields.add("scalar_token", node.scalar_token());
+        }
+        SyntaxKind::IS_NOT_JSON_VALUE => {
+            let node = ast::IsNotJsonValue {
+                syntax: node.clone(),
+            };
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("is_token", node.is_token());
+            fields.add("json_token", node.json_token());
+            fields.add("not_token", node.not_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::IS_NOT_NORMALIZED => {
+            let node = ast::IsNotNormalized {
+                syntax: node.clone(),
+            };
+            fields.add("unicode_normal_form", node.unicode_normal_form());
+            fields.add("is_token", node.is_token());
+            fields.add("normalized_token", node.normalized_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::JOIN => {
+            let node = ast::Join {
+                syntax: node.clone(),
+            };
+            fields.add("from_item", node.from_item());
+            fields.add("join_condition", node.join_condition());
+            fields.add("join_type", node.join_type());
+            fields.add("natural_token", node.natural_token());
+        }
+        SyntaxKind::JOIN_CROSS => {
+            let node = ast::JoinCross {
+                syntax: node.clone(),
+            };
+            fields.add("cross_token", node.cross_token());
+            fields.add("join_token", node.join_token());
+        }
+        SyntaxKind::JOIN_EXPR => {
+            let node = ast::JoinExpr {
+                syntax: node.clone(),
+            };
+            fields.add("from_item", node.from_item());
+            fields.add("join", node.join());
+            fields.add("join_expr", node.join_expr());
+        }
+        SyntaxKind::JOIN_FULL => {
+            let node = ast::JoinFull {
+                syntax: node.clone(),
+            };
+            fields.add("full_token", node.full_token());
+            fields.add("join_token", node.join_token());
+            fields.add("outer_token", node.outer_token());
+        }
+        SyntaxKind::JOIN_INNER => {
+            let node = ast::JoinInner {
+                syntax: node.clone(),
+            };
+            fields.add("inner_token", node.inner_token());
+            fields.add("join_token", node.join_token());
+        }
+        SyntaxKind::JOIN_LEFT => {
+            let node = ast::JoinLeft {
+                syntax: node.clone(),
+            };
+            fields.add("join_token", node.join_token());
+            fields.add("left_token", node.left_token());
+            fields.add("outer_token", node.outer_token());
+        }
+        SyntaxKind::JOIN_RIGHT => {
+            let node = ast::JoinRight {
+                syntax: node.clone(),
+            };
+            fields.add("join_token", node.join_token());
+            fields.add("outer_token", node.outer_token());
+            fields.add("right_token", node.right_token());
+        }
+        SyntaxKind::JOIN_USING_CLAUSE => {
+            let node = ast::JoinUsingClause {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::JSON_ABSENT_ON_NULL => {
+            let node = ast::JsonAbsentOnNull {
+                syntax: node.clone(),
+            };
+            fields.add("absent_token", node.absent_token());
+            fields.add("null_token", node.null_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::JSON_ARRAY_AGG_FN => {
+            let node = ast::JsonArrayAggFn {
+                syntax: node.clone(),
+            };
+            fields.add("json_null_clause", node.json_null_clause());
+            fields.add("json_returning_clause", node.json_returning_clause());
+            fields.add("json_value_expr", node.json_value_expr());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("json_arrayagg_token", node.json_arrayagg_token());
+        }
+        SyntaxKind::JSON_ARRAY_FN => {
+            let node = ast::JsonArrayFn {
+                syntax: node.clone(),
+            };
+            fields.add("json_expr_formats", node.json_expr_formats());
+            fields.add("json_null_clause", node.json_null_clause());
+            fields.add("json_returning_clause", node.json_returning_clause());
+            fields.add("json_select_formats", node.json_select_formats());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("json_array_token", node.json_array_token());
+        }
+        SyntaxKind::JSON_BEHAVIOR_DEFAULT => {
+            let node = ast::JsonBehaviorDefault {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::JSON_BEHAVIOR_EMPTY_ARRAY => {
+            let node = ast::JsonBehaviorEmptyArray {
+                syntax: node.clone(),
+            };
+            fields.add("array_token", node.array_token());
+            fields.add("empty_token", node.empty_token());
+        }
+        SyntaxKind::JSON_BEHAVIOR_EMPTY_OBJECT => {
+            let node = ast::JsonBehaviorEmptyObject {
+                syntax: node.clone(),
+            };
+            fields.add("empty_token", node.empty_token());
+            fields.add("object_token", node.object_token());
+        }
+        SyntaxKind::JSON_BEHAVIOR_ERROR => {
+            let node = ast::JsonBehaviorError {
+                syntax: node.clone(),
+            };
+            fields.add("error_token", node.error_token());
+        }
+        SyntaxKind::JSON_BEHAVIOR_FALSE => {
+            let node = ast::JsonBehaviorFalse {
+                syntax: node.clone(),
+            };
+            fields.add("false_token", node.false_token());
+        }
+        SyntaxKind::JSON_BEHAVIOR_NULL => {
+            let node = ast::JsonBehaviorNull {
+                syntax: node.clone(),
+            };
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::JSON_BEHAVIOR_TRUE => {
+            let node = ast::JsonBehaviorTrue {
+                syntax: node.clone(),
+            };
+            fields.add("true_token", node.true_token());
+        }
+        SyntaxKind::JSON_BEHAVIOR_UNKNOWN => {
+            let node = ast::JsonBehaviorUnknown {
+                syntax: node.clone(),
+            };
+            fields.add("unknown_token", node.unknown_token());
+        }
+        SyntaxKind::JSON_ENCODING => {
+            let node = ast::JsonEncoding {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::JSON_ENCODING_CLAUSE => {
+            let node = ast::JsonEncodingClause {
+                syntax: node.clone(),
+            };
+            fields.add("json_encoding", node.json_encoding());
+            fields.add("encoding_token", node.encoding_token());
+        }
+        SyntaxKind::JSON_EXISTS_FN => {
+            let node = ast::JsonExistsFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("json_on_error_clause", node.json_on_error_clause());
+            fields.add("json_passing_clause", node.json_passing_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("json_exists_token", node.json_exists_token());
+        }
+        SyntaxKind::JSON_EXPR_FORMAT => {
+            let node = ast::JsonExprFormat {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_format_clause", node.json_format_clause());
+        }
+        SyntaxKind::JSON_FN => {
+            let node = ast::JsonFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("json_token", node.json_token());
+        }
+        SyntaxKind::JSON_FORMAT_CLAUSE => {
+            let node = ast::JsonFormatClause {
+                syntax: node.clone(),
+            };
+            fields.add("json_encoding_clause", node.json_encoding_clause());
+            fields.add("format_token", node.format_token());
+            fields.add("json_token", node.json_token());
+        }
+        SyntaxKind::JSON_KEY_VALUE => {
+            let node = ast::JsonKeyValue {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_value_expr", node.json_value_expr());
+            fields.add("colon_token", node.colon_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::JSON_NULL_ON_NULL => {
+            let node = ast::JsonNullOnNull {
+                syntax: node.clone(),
+            };
+            fields.add("null_token", node.null_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::JSON_OBJECT_AGG_FN => {
+            let node = ast::JsonObjectAggFn {
+                syntax: node.clone(),
+            };
+            fields.add("json_key_value", node.json_key_value());
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("json_null_clause", node.json_null_clause());
+            fields.add("json_returning_clause", node.json_returning_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("json_objectagg_token", node.json_objectagg_token());
+        }
+        SyntaxKind::JSON_OBJECT_FN => {
+            let node = ast::JsonObjectFn {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("json_key_values", node.json_key_values());
+            fields.add("json_keys_unique_clause", node.json_keys_unique_clause());
+            fields.add("json_null_clause", node.json_null_clause());
+            fields.add("json_returning_clause", node.json_returning_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("json_object_token", node.json_object_token());
+        }
+        SyntaxKind::JSON_ON_EMPTY_CLAUSE => {
+            let node = ast::JsonOnEmptyClause {
+                syntax: node.clone(),
+            };
+            fields.add("json_behavior", node.json_behavior());
+            fields.add("empty_token", node.empty_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::JSON_ON_ERROR_CLAUSE => {
+            let node = ast::JsonOnErrorClause {
+                syntax: node.clone(),
+            };
+            fields.add("json_behavior", node.json_behavior());
+            fields.add("error_token", node.error_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::JSON_PASSING_ARG => {
+            let node = ast::JsonPassingArg {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("name", node.name());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::JSON_PASSING_CLAUSE => {
+            let node = ast::JsonPassingClause {
+                syntax: node.clone(),
+            };
+            fields.add("json_passing_args", node.json_passing_args());
+            fields.add("passing_token", node.passing_token());
+        }
+        SyntaxKind::JSON_PATH_CLAUSE => {
+            let node = ast::JsonPathClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("path_token", node.path_token());
+        }
+        SyntaxKind::JSON_PATH_NAME => {
+            let node = ast::JsonPathName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::JSON_PATH_NAME_CLAUSE => {
+            let node = ast::JsonPathNameClause {
+                syntax: node.clone(),
+            };
+            fields.add("json_path_name", node.json_path_name());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::JSON_PATH_NAME_REF => {
+            let node = ast::JsonPathNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::JSON_QUERY_FN => {
+            let node = ast::JsonQueryFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("json_on_empty_clause", node.json_on_empty_clause());
+            fields.add("json_on_error_clause", node.json_on_error_clause());
+            fields.add("json_passing_clause", node.json_passing_clause());
+            fields.add("json_quotes_clause", node.json_quotes_clause());
+            fields.add("json_returning_clause", node.json_returning_clause());
+            fields.add(
+                "json_wrapper_behavior_clause",
+                node.json_wrapper_behavior_clause(),
+            );
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("json_query_token", node.json_query_token());
+        }
+        SyntaxKind::JSON_QUOTES_CLAUSE => {
+            let node = ast::JsonQuotesClause {
+                syntax: node.clone(),
+            };
+            fields.add("on_scalar_string", node.on_scalar_string());
+            fields.add("quotes_behavior", node.quotes_behavior());
+        }
+        SyntaxKind::JSON_RETURNING_CLAUSE => {
+            let node = ast::JsonReturningClause {
+                syntax: node.clone(),
+            };
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("type", node.ty());
+            fields.add("returning_token", node.returning_token());
+        }
+        SyntaxKind::JSON_SCALAR_FN => {
+            let node = ast::JsonScalarFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("json_scalar_token", node.json_scalar_token());
+        }
+        SyntaxKind::JSON_SELECT_FORMAT => {
+            let node = ast::JsonSelectFormat {
+                syntax: node.clone(),
+            };
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("select_variant", node.select_variant());
+        }
+        SyntaxKind::JSON_SERIALIZE_FN => {
+            let node = ast::JsonSerializeFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("json_returning_clause", node.json_returning_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("json_serialize_token", node.json_serialize_token());
+        }
+        SyntaxKind::JSON_TABLE => {
+            let node = ast::JsonTable {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("json_on_error_clause", node.json_on_error_clause());
+            fields.add("json_passing_clause", node.json_passing_clause());
+            fields.add("json_path_name_clause", node.json_path_name_clause());
+            fields.add("json_table_column_list", node.json_table_column_list());
+            fields.add("json_table_plan_clause", node.json_table_plan_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("json_table_token", node.json_table_token());
+        }
+        SyntaxKind::JSON_TABLE_COLUMN_LIST => {
+            let node = ast::JsonTableColumnList {
+                syntax: node.clone(),
+            };
+            fields.add("json_table_columns", node.json_table_columns());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("columns_token", node.columns_token());
+        }
+        SyntaxKind::JSON_TABLE_EXISTS_COLUMN => {
+            let node = ast::JsonTableExistsColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name", node.column_name());
+            fields.add("json_on_error_clause", node.json_on_error_clause());
+            fields.add("json_path_clause", node.json_path_clause());
+            fields.add("type", node.ty());
+            fields.add("exists_token", node.exists_token());
+        }
+        SyntaxKind::JSON_TABLE_FROM_ITEM => {
+            let node = ast::JsonTableFromItem {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("json_table", node.json_table());
+            fields.add("lateral_token", node.lateral_token());
+        }
+        SyntaxKind::JSON_TABLE_NESTED_COLUMN => {
+            let node = ast::JsonTableNestedColumn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_path_name_clause", node.json_path_name_clause());
+            fields.add("json_table_column_list", node.json_table_column_list());
+            fields.add("nested_token", node.nested_token());
+            fields.add("path_token", node.path_token());
+        }
+        SyntaxKind::JSON_TABLE_ORDINALITY_COLUMN => {
+            let node = ast::JsonTableOrdinalityColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name", node.column_name());
+            fields.add("for_token", node.for_token());
+            fields.add("ordinality_token", node.ordinality_token());
+        }
+        SyntaxKind::JSON_TABLE_PLAN_CHOICE => {
+            let node = ast::JsonTablePlanChoice {
+                syntax: node.clone(),
+            };
+            fields.add("json_table_plan_operator", node.json_table_plan_operator());
+        }
+        SyntaxKind::JSON_TABLE_PLAN_CLAUSE => {
+            let node = ast::JsonTablePlanClause {
+                syntax: node.clone(),
+            };
+            fields.add("json_table_plan", node.json_table_plan());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("default_token", node.default_token());
+            fields.add("plan_token", node.plan_token());
+        }
+        SyntaxKind::JSON_TABLE_PLAN_CROSS => {
+            let node = ast::JsonTablePlanCross {
+                syntax: node.clone(),
+            };
+            fields.add("cross_token", node.cross_token());
+        }
+        SyntaxKind::JSON_TABLE_PLAN_INNER => {
+            let node = ast::JsonTablePlanInner {
+                syntax: node.clone(),
+            };
+            fields.add("inner_token", node.inner_token());
+        }
+        SyntaxKind::JSON_TABLE_PLAN_JOIN => {
+            let node = ast::JsonTablePlanJoin {
+                syntax: node.clone(),
+            };
+            fields.add("lhs", node.lhs());
+            fields.add("rhs", node.rhs());
+            fields.add("json_table_plan_operator", node.json_table_plan_operator());
+        }
+        SyntaxKind::JSON_TABLE_PLAN_OUTER => {
+            let node = ast::JsonTablePlanOuter {
+                syntax: node.clone(),
+            };
+            fields.add("outer_token", node.outer_token());
+        }
+        SyntaxKind::JSON_TABLE_PLAN_UNION => {
+            let node = ast::JsonTablePlanUnion {
+                syntax: node.clone(),
+            };
+            fields.add("union_token", node.union_token());
+        }
+        SyntaxKind::JSON_TABLE_VALUE_COLUMN => {
+            let node = ast::JsonTableValueColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name", node.column_name());
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("json_path_clause", node.json_path_clause());
+            fields.add("json_quotes_clause", node.json_quotes_clause());
+            fields.add(
+                "json_table_value_behavior",
+                node.json_table_value_behavior(),
+            );
+            fields.add(
+                "json_wrapper_behavior_clause",
+                node.json_wrapper_behavior_clause(),
+            );
+            fields.add("type", node.ty());
+        }
+        SyntaxKind::JSON_VALUE_EXPR => {
+            let node = ast::JsonValueExpr {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_format_clause", node.json_format_clause());
+        }
+        SyntaxKind::JSON_VALUE_FN => {
+            let node = ast::JsonValueFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("json_format_clause", node.json_format_clause());
+            fields.add("json_on_empty_clause", node.json_on_empty_clause());
+            fields.add("json_on_error_clause", node.json_on_error_clause());
+            fields.add("json_passing_clause", node.json_passing_clause());
+            fields.add("json_returning_clause", node.json_returning_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("json_value_token", node.json_value_token());
+        }
+        SyntaxKind::JSON_VARIABLE_NAME => {
+            let node = ast::JsonVariableName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::JSON_WITH_CONDITIONAL_WRAPPER => {
+            let node = ast::JsonWithConditionalWrapper {
+                syntax: node.clone(),
+            };
+            fields.add("array_token", node.array_token());
+            fields.add("conditional_token", node.conditional_token());
+            fields.add("with_token", node.with_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::JSON_WITH_UNCONDITIONAL_WRAPPER => {
+            let node = ast::JsonWithUnconditionalWrapper {
+                syntax: node.clone(),
+            };
+            fields.add("array_token", node.array_token());
+            fields.add("unconditional_token", node.unconditional_token());
+            fields.add("with_token", node.with_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::JSON_WITH_UNIQUE_KEYS => {
+            let node = ast::JsonWithUniqueKeys {
+                syntax: node.clone(),
+            };
+            fields.add("keys_token", node.keys_token());
+            fields.add("unique_token", node.unique_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::JSON_WITHOUT_UNIQUE_KEYS => {
+            let node = ast::JsonWithoutUniqueKeys {
+                syntax: node.clone(),
+            };
+            fields.add("keys_token", node.keys_token());
+            fields.add("unique_token", node.unique_token());
+            fields.add("without_token", node.without_token());
+        }
+        SyntaxKind::JSON_WITHOUT_WRAPPER => {
+            let node = ast::JsonWithoutWrapper {
+                syntax: node.clone(),
+            };
+            fields.add("array_token", node.array_token());
+            fields.add("without_token", node.without_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::KEEP_QUOTES => {
+            let node = ast::KeepQuotes {
+                syntax: node.clone(),
+            };
+            fields.add("keep_token", node.keep_token());
+            fields.add("quotes_token", node.quotes_token());
+        }
+        SyntaxKind::LABEL => {
+            let node = ast::Label {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::LABEL_AND_PROPERTIES => {
+            let node = ast::LabelAndProperties {
+                syntax: node.clone(),
+            };
+            fields.add("element_table_properties", node.element_table_properties());
+            fields.add("label_spec", node.label_spec());
+        }
+        SyntaxKind::LABEL_AND_PROPERTIES_LIST => {
+            let node = ast::LabelAndPropertiesList {
+                syntax: node.clone(),
+            };
+            fields.add("label_and_propertiess", node.label_and_propertiess());
+        }
+        SyntaxKind::LABEL_REF => {
+            let node = ast::LabelRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::LANGUAGE => {
+            let node = ast::Language {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::LANGUAGE_FUNC_OPTION => {
+            let node = ast::LanguageFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("language_ref", node.language_ref());
+            fields.add("literal", node.literal());
+            fields.add("language_token", node.language_token());
+        }
+        SyntaxKind::LANGUAGE_REF => {
+            let node = ast::LanguageRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::LANGUAGE_RENAME_TO => {
+            let node = ast::LanguageRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("language", node.language());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::LAST => {
+            let node = ast::Last {
+                syntax: node.clone(),
+            };
+            fields.add("last_token", node.last_token());
+        }
+        SyntaxKind::LEAKPROOF_FUNC_OPTION => {
+            let node = ast::LeakproofFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("leakproof_token", node.leakproof_token());
+        }
+        SyntaxKind::LIKE_CLAUSE => {
+            let node = ast::LikeClause {
+                syntax: node.clone(),
+            };
+            fields.add("like_options", node.like_options());
+            fields.add("relation_name_ref", node.relation_name_ref());
+            fields.add("like_token", node.like_token());
+        }
+        SyntaxKind::LIMIT_CLAUSE => {
+            let node = ast::LimitClause {
+                syntax: node.clone(),
+            };
+            fields.add("limit_value", node.limit_value());
+            fields.add("limit_token", node.limit_token());
+        }
+        SyntaxKind::LIMIT_TO_TABLES => {
+            let node = ast::LimitToTables {
+                syntax: node.clone(),
+            };
+            fields.add("remote_table_name_refs", node.remote_table_name_refs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("limit_token", node.limit_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::LISTEN => {
+            let node = ast::Listen {
+                syntax: node.clone(),
+            };
+            fields.add("channel", node.channel());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("listen_token", node.listen_token());
+        }
+        SyntaxKind::LITERAL => {
+            let node = ast::Literal {
+                syntax: node.clone(),
+            };
+            fields.add("value", node.value());
+        }
+        SyntaxKind::LOAD => {
+            let node = ast::Load {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("load_token", node.load_token());
+        }
+        SyntaxKind::LOCAL_CHECK_OPTION => {
+            let node = ast::LocalCheckOption {
+                syntax: node.clone(),
+            };
+            fields.add("local_token", node.local_token());
+        }
+        SyntaxKind::LOCAL_SCOPE => {
+            let node = ast::LocalScope {
+                syntax: node.clone(),
+            };
+            fields.add("local_token", node.local_token());
+        }
+        SyntaxKind::LOCK => {
+            let node = ast::Lock {
+                syntax: node.clone(),
+            };
+            fields.add("lock_mode_clause", node.lock_mode_clause());
+            fields.add("nowait", node.nowait());
+            fields.add("relation_list", node.relation_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("lock_token", node.lock_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::LOCK_MODE_CLAUSE => {
+            let node = ast::LockModeClause {
+                syntax: node.clone(),
+            };
+            fields.add("lock_mode", node.lock_mode());
+            fields.add("in_token", node.in_token());
+            fields.add("mode_token", node.mode_token());
+        }
+        SyntaxKind::LOCKING_CLAUSE => {
+            let node = ast::LockingClause {
+                syntax: node.clone(),
+            };
+            fields.add("lock_strength", node.lock_strength());
+            fields.add("lock_wait", node.lock_wait());
+            fields.add("locking_of", node.locking_of());
+            fields.add("for_token", node.for_token());
+        }
+        SyntaxKind::LOCKING_OF => {
+            let node = ast::LockingOf {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("of_token", node.of_token());
+        }
+        SyntaxKind::LTEQ => {
+            let node = ast::Lteq {
+                syntax: node.clone(),
+            };
+            fields.add("l_angle_token", node.l_angle_token());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::MATCH_FULL => {
+            let node = ast::MatchFull {
+                syntax: node.clone(),
+            };
+            fields.add("full_token", node.full_token());
+            fields.add("match_token", node.match_token());
+        }
+        SyntaxKind::MATCH_PARTIAL => {
+            let node = ast::MatchPartial {
+                syntax: node.clone(),
+            };
+            fields.add("match_token", node.match_token());
+            fields.add("partial_token", node.partial_token());
+        }
+        SyntaxKind::MATCH_SIMPLE => {
+            let node = ast::MatchSimple {
+                syntax: node.clone(),
+            };
+            fields.add("match_token", node.match_token());
+            fields.add("simple_token", node.simple_token());
+        }
+        SyntaxKind::MATERIALIZED => {
+            let node = ast::Materialized {
+                syntax: node.clone(),
+            };
+            fields.add("materialized_token", node.materialized_token());
+        }
+        SyntaxKind::MERGE => {
+            let node = ast::Merge {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("merge_when_clauses", node.merge_when_clauses());
+            fields.add("returning_clause", node.returning_clause());
+            fields.add("table_relation_name", node.table_relation_name());
+            fields.add("using_on_clause", node.using_on_clause());
+            fields.add("with_clause", node.with_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("into_token", node.into_token());
+            fields.add("merge_token", node.merge_token());
+        }
+        SyntaxKind::MERGE_CONDITION => {
+            let node = ast::MergeCondition {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("and_token", node.and_token());
+        }
+        SyntaxKind::MERGE_DELETE => {
+            let node = ast::MergeDelete {
+                syntax: node.clone(),
+            };
+            fields.add("delete_token", node.delete_token());
+        }
+        SyntaxKind::MERGE_DO_NOTHING => {
+            let node = ast::MergeDoNothing {
+                syntax: node.clone(),
+            };
+            fields.add("do_token", node.do_token());
+            fields.add("nothing_token", node.nothing_token());
+        }
+        SyntaxKind::MERGE_INSERT => {
+            let node = ast::MergeInsert {
+                syntax: node.clone(),
+            };
+            fields.add("column_target_list", node.column_target_list());
+            fields.add("default_values", node.default_values());
+            fields.add("overriding_clause", node.overriding_clause());
+            fields.add("values", node.values());
+            fields.add("insert_token", node.insert_token());
+        }
+        SyntaxKind::MERGE_PARTITIONS => {
+            let node = ast::MergePartitions {
+                syntax: node.clone(),
+            };
+            fields.add("table_name", node.table_name());
+            fields.add("table_name_ref_list", node.table_name_ref_list());
+            fields.add("into_token", node.into_token());
+            fields.add("merge_token", node.merge_token());
+            fields.add("partitions_token", node.partitions_token());
+        }
+        SyntaxKind::MERGE_UPDATE => {
+            let node = ast::MergeUpdate {
+                syntax: node.clone(),
+            };
+            fields.add("set_clause", node.set_clause());
+            fields.add("set_token", node.set_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::MERGE_WHEN_MATCHED => {
+            let node = ast::MergeWhenMatched {
+                syntax: node.clone(),
+            };
+            fields.add("merge_action", node.merge_action());
+            fields.add("merge_condition", node.merge_condition());
+            fields.add("matched_token", node.matched_token());
+            fields.add("then_token", node.then_token());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::MERGE_WHEN_NOT_MATCHED_SOURCE => {
+            let node = ast::MergeWhenNotMatchedSource {
+                syntax: node.clone(),
+            };
+            fields.add("merge_action", node.merge_action());
+            fields.add("merge_condition", node.merge_condition());
+            fields.add("by_token", node.by_token());
+            fields.add("matched_token", node.matched_token());
+            fields.add("not_token", node.not_token());
+            fields.add("source_token", node.source_token());
+            fields.add("then_token", node.then_token());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::MERGE_WHEN_NOT_MATCHED_TARGET => {
+            let node = ast::MergeWhenNotMatchedTarget {
+                syntax: node.clone(),
+            };
+            fields.add("by_target", node.by_target());
+            fields.add("merge_action", node.merge_action());
+            fields.add("merge_condition", node.merge_condition());
+            fields.add("matched_token", node.matched_token());
+            fields.add("not_token", node.not_token());
+            fields.add("then_token", node.then_token());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::MOVE => {
+            let node = ast::Move {
+                syntax: node.clone(),
+            };
+            fields.add("cursor_action", node.cursor_action());
+            fields.add("cursor_ref", node.cursor_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("from_token", node.from_token());
+            fields.add("in_token", node.in_token());
+            fields.add("move_token", node.move_token());
+        }
+        SyntaxKind::NAME_REF => {
+            let node = ast::NameRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::NAMED_ARG => {
+            let node = ast::NamedArg {
+                syntax: node.clone(),
+            };
+            fields.add("colon_eq", node.colon_eq());
+            fields.add("expr", node.expr());
+            fields.add("fat_arrow", node.fat_arrow());
+            fields.add("name", node.name());
+        }
+        SyntaxKind::NAMED_LABEL => {
+            let node = ast::NamedLabel {
+                syntax: node.clone(),
+            };
+            fields.add("label", node.label());
+            fields.add("label_token", node.label_token());
+        }
+        SyntaxKind::NAMED_SCHEMA => {
+            let node = ast::NamedSchema {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref", node.role_ref());
+            fields.add("schema", node.schema());
+            fields.add("authorization_token", node.authorization_token());
+        }
+        SyntaxKind::NEQ => {
+            let node = ast::Neq {
+                syntax: node.clone(),
+            };
+            fields.add("bang_token", node.bang_token());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::NEQB => {
+            let node = ast::Neqb {
+                syntax: node.clone(),
+            };
+            fields.add("l_angle_token", node.l_angle_token());
+            fields.add("r_angle_token", node.r_angle_token());
+        }
+        SyntaxKind::NEW_TABLE => {
+            let node = ast::NewTable {
+                syntax: node.clone(),
+            };
+            fields.add("transition_relation_name", node.transition_relation_name());
+            fields.add("as_token", node.as_token());
+            fields.add("new_token", node.new_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::NEXT => {
+            let node = ast::Next {
+                syntax: node.clone(),
+            };
+            fields.add("next_token", node.next_token());
+        }
+        SyntaxKind::NO_ACTION => {
+            let node = ast::NoAction {
+                syntax: node.clone(),
+            };
+            fields.add("action_token", node.action_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::NO_DEPENDS_ON_EXTENSION => {
+            let node = ast::NoDependsOnExtension {
+                syntax: node.clone(),
+            };
+            fields.add("extension_ref", node.extension_ref());
+            fields.add("depends_token", node.depends_token());
+            fields.add("extension_token", node.extension_token());
+            fields.add("no_token", node.no_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::NO_FORCE_RLS => {
+            let node = ast::NoForceRls {
+                syntax: node.clone(),
+            };
+            fields.add("force_token", node.force_token());
+            fields.add("level_token", node.level_token());
+            fields.add("no_token", node.no_token());
+            fields.add("row_token", node.row_token());
+            fields.add("security_token", node.security_token());
+        }
+        SyntaxKind::NO_INDENT => {
+            let node = ast::NoIndent {
+                syntax: node.clone(),
+            };
+            fields.add("indent_token", node.indent_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::NO_INHERIT => {
+            let node = ast::NoInherit {
+                syntax: node.clone(),
+            };
+            fields.add("inherit_token", node.inherit_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::NO_INHERIT_TABLE => {
+            let node = ast::NoInheritTable {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("inherit_token", node.inherit_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::NO_OTHERS => {
+            let node = ast::NoOthers {
+                syntax: node.clone(),
+            };
+            fields.add("no_token", node.no_token());
+            fields.add("others_token", node.others_token());
+        }
+        SyntaxKind::NO_PROPERTIES => {
+            let node = ast::NoProperties {
+                syntax: node.clone(),
+            };
+            fields.add("no_token", node.no_token());
+            fields.add("properties_token", node.properties_token());
+        }
+        SyntaxKind::NO_SCROLL => {
+            let node = ast::NoScroll {
+                syntax: node.clone(),
+            };
+            fields.add("no_token", node.no_token());
+            fields.add("scroll_token", node.scroll_token());
+        }
+        SyntaxKind::NON_STANDARD_PARAM => {
+            let node = ast::NonStandardParam {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("colon_token", node.colon_token());
+        }
+        SyntaxKind::NOT_DEFERRABLE => {
+            let node = ast::NotDeferrable {
+                syntax: node.clone(),
+            };
+            fields.add("deferrable_token", node.deferrable_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::NOT_DEFERRABLE_CONSTRAINT_OPTION => {
+            let node = ast::NotDeferrableConstraintOption {
+                syntax: node.clone(),
+            };
+            fields.add("deferrable_token", node.deferrable_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::NOT_ENFORCED => {
+            let node = ast::NotEnforced {
+                syntax: node.clone(),
+            };
+            fields.add("enforced_token", node.enforced_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::NOT_ILIKE => {
+            let node = ast::NotIlike {
+                syntax: node.clone(),
+            };
+            fields.add("ilike_token", node.ilike_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::NOT_IN => {
+            let node = ast::NotIn {
+                syntax: node.clone(),
+            };
+            fields.add("in_token", node.in_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::NOT_LEAKPROOF_FUNC_OPTION => {
+            let node = ast::NotLeakproofFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("leakproof_token", node.leakproof_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::NOT_LIKE => {
+            let node = ast::NotLike {
+                syntax: node.clone(),
+            };
+            fields.add("like_token", node.like_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::NOT_MATERIALIZED => {
+            let node = ast::NotMaterialized {
+                syntax: node.clone(),
+            };
+            fields.add("materialized_token", node.materialized_token());
+            fields.add("not_token", node.not_token());
+        }
+        SyntaxKind::NOT_NULL_CONSTRAINT => {
+            let node = ast::NotNullConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("not_token", node.not_token());
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::NOT_OF => {
+            let node = ast::NotOf {
+                syntax: node.clone(),
+            };
+            fields.add("not_token", node.not_token());
+            fields.add("of_token", node.of_token());
+        }
+        SyntaxKind::NOT_SIMILAR_TO => {
+            let node = ast::NotSimilarTo {
+                syntax: node.clone(),
+            };
+            fields.add("not_token", node.not_token());
+            fields.add("similar_token", node.similar_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::NOT_VALID => {
+            let node = ast::NotValid {
+                syntax: node.clone(),
+            };
+            fields.add("not_token", node.not_token());
+            fields.add("valid_token", node.valid_token());
+        }
+        SyntaxKind::NOTHING => {
+            let node = ast::Nothing {
+                syntax: node.clone(),
+            };
+            fields.add("nothing_token", node.nothing_token());
+        }
+        SyntaxKind::NOTIFY => {
+            let node = ast::Notify {
+                syntax: node.clone(),
+            };
+            fields.add("channel_ref", node.channel_ref());
+            fields.add("literal", node.literal());
+            fields.add("comma_token", node.comma_token());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("notify_token", node.notify_token());
+        }
+        SyntaxKind::NOWAIT => {
+            let node = ast::Nowait {
+                syntax: node.clone(),
+            };
+            fields.add("nowait_token", node.nowait_token());
+        }
+        SyntaxKind::NULL_CONSTRAINT => {
+            let node = ast::NullConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::NULLS_DISTINCT => {
+            let node = ast::NullsDistinct {
+                syntax: node.clone(),
+            };
+            fields.add("distinct_token", node.distinct_token());
+            fields.add("nulls_token", node.nulls_token());
+        }
+        SyntaxKind::NULLS_FIRST => {
+            let node = ast::NullsFirst {
+                syntax: node.clone(),
+            };
+            fields.add("first_token", node.first_token());
+            fields.add("nulls_token", node.nulls_token());
+        }
+        SyntaxKind::NULLS_LAST => {
+            let node = ast::NullsLast {
+                syntax: node.clone(),
+            };
+            fields.add("last_token", node.last_token());
+            fields.add("nulls_token", node.nulls_token());
+        }
+        SyntaxKind::NULLS_NOT_DISTINCT => {
+            let node = ast::NullsNotDistinct {
+                syntax: node.clone(),
+            };
+            fields.add("distinct_token", node.distinct_token());
+            fields.add("not_token", node.not_token());
+            fields.add("nulls_token", node.nulls_token());
+        }
+        SyntaxKind::OBJECT_ACCESS_METHOD => {
+            let node = ast::ObjectAccessMethod {
+                syntax: node.clone(),
+            };
+            fields.add("access_method_ref", node.access_method_ref());
+            fields.add("access_token", node.access_token());
+            fields.add("method_token", node.method_token());
+        }
+        SyntaxKind::OBJECT_AGGREGATE => {
+            let node = ast::ObjectAggregate {
+                syntax: node.clone(),
+            };
+            fields.add("aggregate", node.aggregate());
+            fields.add("aggregate_token", node.aggregate_token());
+        }
+        SyntaxKind::OBJECT_CAST => {
+            let node = ast::ObjectCast {
+                syntax: node.clone(),
+            };
+            fields.add("cast_sig", node.cast_sig());
+            fields.add("cast_token", node.cast_token());
+        }
+        SyntaxKind::OBJECT_COLLATION => {
+            let node = ast::ObjectCollation {
+                syntax: node.clone(),
+            };
+            fields.add("collation_ref", node.collation_ref());
+            fields.add("collation_token", node.collation_token());
+        }
+        SyntaxKind::OBJECT_COLUMN => {
+            let node = ast::ObjectColumn {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("column_token", node.column_token());
+        }
+        SyntaxKind::OBJECT_CONSTRAINT => {
+            let node = ast::ObjectConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_ref", node.constraint_name_ref());
+            fields.add("domain_ref", node.domain_ref());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("constraint_token", node.constraint_token());
+            fields.add("domain_token", node.domain_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::OBJECT_CONVERSION => {
+            let node = ast::ObjectConversion {
+                syntax: node.clone(),
+            };
+            fields.add("conversion_ref", node.conversion_ref());
+            fields.add("conversion_token", node.conversion_token());
+        }
+        SyntaxKind::OBJECT_DATABASE => {
+            let node = ast::ObjectDatabase {
+                syntax: node.clone(),
+            };
+            fields.add("database_ref", node.database_ref());
+            fields.add("database_token", node.database_token());
+        }
+        SyntaxKind::OBJECT_DOMAIN => {
+            let node = ast::ObjectDomain {
+                syntax: node.clone(),
+            };
+            fields.add("domain_ref", node.domain_ref());
+            fields.add("domain_token", node.domain_token());
+        }
+        SyntaxKind::OBJECT_EVENT_TRIGGER => {
+            let node = ast::ObjectEventTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("event_trigger_ref", node.event_trigger_ref());
+            fields.add("event_token", node.event_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::OBJECT_EXTENSION => {
+            let node = ast::ObjectExtension {
+                syntax: node.clone(),
+            };
+            fields.add("extension_ref", node.extension_ref());
+            fields.add("extension_token", node.extension_token());
+        }
+        SyntaxKind::OBJECT_FOREIGN_DATA_WRAPPER => {
+            let node = ast::ObjectForeignDataWrapper {
+                syntax: node.clone(),
+            };
+            fields.add("foreign_data_wrapper_ref", node.foreign_data_wrapper_ref());
+            fields.add("data_token", node.data_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::OBJECT_FOREIGN_TABLE => {
+            let node = ast::ObjectForeignTable {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::OBJECT_FUNCTION => {
+            let node = ast::ObjectFunction {
+                syntax: node.clone(),
+            };
+            fields.add("function_sig", node.function_sig());
+            fields.add("function_token", node.function_token());
+        }
+        SyntaxKind::OBJECT_INDEX => {
+            let node = ast::ObjectIndex {
+                syntax: node.clone(),
+            };
+            fields.add("index_ref", node.index_ref());
+            fields.add("index_token", node.index_token());
+        }
+        SyntaxKind::OBJECT_LANGUAGE => {
+            let node = ast::ObjectLanguage {
+                syntax: node.clone(),
+            };
+            fields.add("language_ref", node.language_ref());
+            fields.add("language_token", node.language_token());
+            fields.add("procedural_token", node.procedural_token());
+        }
+        SyntaxKind::OBJECT_LARGE_OBJECT => {
+            let node = ast::ObjectLargeObject {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("large_token", node.large_token());
+            fields.add("object_token", node.object_token());
+        }
+        SyntaxKind::OBJECT_MATERIALIZED_VIEW => {
+            let node = ast::ObjectMaterializedView {
+                syntax: node.clone(),
+            };
+            fields.add("view_ref", node.view_ref());
+            fields.add("materialized_token", node.materialized_token());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::OBJECT_OPERATOR => {
+            let node = ast::ObjectOperator {
+                syntax: node.clone(),
+            };
+            fields.add("lhs", node.lhs());
+            fields.add("rhs", node.rhs());
+            fields.add("op", node.op());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::OBJECT_OPERATOR_CLASS => {
+            let node = ast::ObjectOperatorClass {
+                syntax: node.clone(),
+            };
+            fields.add("op_class_ref", node.op_class_ref());
+            fields.add("using_method", node.using_method());
+            fields.add("class_token", node.class_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::OBJECT_OPERATOR_FAMILY => {
+            let node = ast::ObjectOperatorFamily {
+                syntax: node.clone(),
+            };
+            fields.add("op_family_ref", node.op_family_ref());
+            fields.add("using_method", node.using_method());
+            fields.add("family_token", node.family_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::OBJECT_POLICY => {
+            let node = ast::ObjectPolicy {
+                syntax: node.clone(),
+            };
+            fields.add("policy_ref", node.policy_ref());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("on_token", node.on_token());
+            fields.add("policy_token", node.policy_token());
+        }
+        SyntaxKind::OBJECT_PROCEDURE => {
+            let node = ast::ObjectProcedure {
+                syntax: node.clone(),
+            };
+            fields.add("procedure_sig", node.procedure_sig());
+            fields.add("procedure_token", node.procedure_token());
+        }
+        SyntaxKind::OBJECT_PROPERTY_GRAPH => {
+            let node = ast::ObjectPropertyGraph {
+                syntax: node.clone(),
+            };
+            fields.add("property_graph_ref", node.property_graph_ref());
+            fields.add("graph_token", node.graph_token());
+            fields.add("property_token", node.property_token());
+        }
+        SyntaxKind::OBJECT_PUBLICATION => {
+            let node = ast::ObjectPublication {
+                syntax: node.clone(),
+            };
+            fields.add("publication_ref", node.publication_ref());
+            fields.add("publication_token", node.publication_token());
+        }
+        SyntaxKind::OBJECT_ROLE => {
+            let node = ast::ObjectRole {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref", node.role_ref());
+            fields.add("role_token", node.role_token());
+        }
+        SyntaxKind::OBJECT_ROUTINE => {
+            let node = ast::ObjectRoutine {
+                syntax: node.clone(),
+            };
+            fields.add("routine_sig", node.routine_sig());
+            fields.add("routine_token", node.routine_token());
+        }
+        SyntaxKind::OBJECT_RULE => {
+            let node = ast::ObjectRule {
+                syntax: node.clone(),
+            };
+            fields.add("relation_name_ref", node.relation_name_ref());
+            fields.add("rule_ref", node.rule_ref());
+            fields.add("on_token", node.on_token());
+            fields.add("rule_token", node.rule_token());
+        }
+        SyntaxKind::OBJECT_SCHEMA => {
+            let node = ast::ObjectSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_ref", node.schema_ref());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::OBJECT_SEQUENCE => {
+            let node = ast::ObjectSequence {
+                syntax: node.clone(),
+            };
+            fields.add("sequence_ref", node.sequence_ref());
+            fields.add("sequence_token", node.sequence_token());
+        }
+        SyntaxKind::OBJECT_SERVER => {
+            let node = ast::ObjectServer {
+                syntax: node.clone(),
+            };
+            fields.add("server_ref", node.server_ref());
+            fields.add("server_token", node.server_token());
+        }
+        SyntaxKind::OBJECT_STATISTICS => {
+            let node = ast::ObjectStatistics {
+                syntax: node.clone(),
+            };
+            fields.add("statistics_ref", node.statistics_ref());
+            fields.add("statistics_token", node.statistics_token());
+        }
+        SyntaxKind::OBJECT_SUBSCRIPTION => {
+            let node = ast::ObjectSubscription {
+                syntax: node.clone(),
+            };
+            fields.add("subscription_ref", node.subscription_ref());
+            fields.add("subscription_token", node.subscription_token());
+        }
+        SyntaxKind::OBJECT_TABLE => {
+            let node = ast::ObjectTable {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::OBJECT_TABLESPACE => {
+            let node = ast::ObjectTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::OBJECT_TEXT_SEARCH_CONFIG => {
+            let node = ast::ObjectTextSearchConfig {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "text_search_configuration_ref",
+                node.text_search_configuration_ref(),
+            );
+            fields.add("configuration_token", node.configuration_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::OBJECT_TEXT_SEARCH_DICT => {
+            let node = ast::ObjectTextSearchDict {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "text_search_dictionary_ref",
+                node.text_search_dictionary_ref(),
+            );
+            fields.add("dictionary_token", node.dictionary_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::OBJECT_TEXT_SEARCH_PARSER => {
+            let node = ast::ObjectTextSearchParser {
+                syntax: node.clone(),
+            };
+            fields.add("text_search_parser_ref", node.text_search_parser_ref());
+            fields.add("parser_token", node.parser_token());
+            fields.add("search_token", node.search_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::OBJECT_TEXT_SEARCH_TEMPLATE => {
+            let node = ast::ObjectTextSearchTemplate {
+                syntax: node.clone(),
+            };
+            fields.add("text_search_template_ref", node.text_search_template_ref());
+            fields.add("search_token", node.search_token());
+            fields.add("template_token", node.template_token());
+            fields.add("text_token", node.text_token());
+        }
+        SyntaxKind::OBJECT_TRANSFORM => {
+            let node = ast::ObjectTransform {
+                syntax: node.clone(),
+            };
+            fields.add("language_ref", node.language_ref());
+            fields.add("type", node.ty());
+            fields.add("for_token", node.for_token());
+            fields.add("language_token", node.language_token());
+            fields.add("transform_token", node.transform_token());
+        }
+        SyntaxKind::OBJECT_TRIGGER => {
+            let node = ast::ObjectTrigger {
+                syntax: node.clone(),
+            };
+            fields.add("relation_name_ref", node.relation_name_ref());
+            fields.add("trigger_ref", node.trigger_ref());
+            fields.add("on_token", node.on_token());
+            fields.add("trigger_token", node.trigger_token());
+        }
+        SyntaxKind::OBJECT_TYPE => {
+            let node = ast::ObjectType {
+                syntax: node.clone(),
+            };
+            fields.add("type_name_ref", node.type_name_ref());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::OBJECT_VIEW => {
+            let node = ast::ObjectView {
+                syntax: node.clone(),
+            };
+            fields.add("view_ref", node.view_ref());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::OF_TYPE => {
+            let node = ast::OfType {
+                syntax: node.clone(),
+            };
+            fields.add("type", node.ty());
+            fields.add("of_token", node.of_token());
+        }
+        SyntaxKind::OFFSET_CLAUSE => {
+            let node = ast::OffsetClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("offset_token", node.offset_token());
+            fields.add("row_token", node.row_token());
+            fields.add("rows_token", node.rows_token());
+        }
+        SyntaxKind::OLD_TABLE => {
+            let node = ast::OldTable {
+                syntax: node.clone(),
+            };
+            fields.add("transition_relation_name", node.transition_relation_name());
+            fields.add("as_token", node.as_token());
+            fields.add("old_token", node.old_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::OMIT_QUOTES => {
+            let node = ast::OmitQuotes {
+                syntax: node.clone(),
+            };
+            fields.add("omit_token", node.omit_token());
+            fields.add("quotes_token", node.quotes_token());
+        }
+        SyntaxKind::ON_CLAUSE => {
+            let node = ast::OnClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::ON_COMMIT => {
+            let node = ast::OnCommit {
+                syntax: node.clone(),
+            };
+            fields.add("on_commit_action", node.on_commit_action());
+            fields.add("commit_token", node.commit_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::ON_CONFLICT_CLAUSE => {
+            let node = ast::OnConflictClause {
+                syntax: node.clone(),
+            };
+            fields.add("conflict_action", node.conflict_action());
+            fields.add("conflict_target", node.conflict_target());
+            fields.add("conflict_token", node.conflict_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::ON_DELETE_ACTION => {
+            let node = ast::OnDeleteAction {
+                syntax: node.clone(),
+            };
+            fields.add("ref_action", node.ref_action());
+            fields.add("delete_token", node.delete_token());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::ON_PATH => {
+            let node = ast::OnPath {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::ON_PRIVILEGE_OBJECTS_CLAUSE => {
+            let node = ast::OnPrivilegeObjectsClause {
+                syntax: node.clone(),
+            };
+            fields.add("privilege_objects", node.privilege_objects());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::ON_RELATION => {
+            let node = ast::OnRelation {
+                syntax: node.clone(),
+            };
+            fields.add("relation_name_ref", node.relation_name_ref());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::ON_SCALAR_STRING => {
+            let node = ast::OnScalarString {
+                syntax: node.clone(),
+            };
+            fields.add("on_token", node.on_token());
+            fields.add("scalar_token", node.scalar_token());
+            fields.add("string_token", node.string_token());
+        }
+        SyntaxKind::ON_TABLE => {
+            let node = ast::OnTable {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::ON_UPDATE_ACTION => {
+            let node = ast::OnUpdateAction {
+                syntax: node.clone(),
+            };
+            fields.add("ref_action", node.ref_action());
+            fields.add("on_token", node.on_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::OP => {
+            let node = ast::Op {
+                syntax: node.clone(),
+            };
+            fields.add("at_local", node.at_local());
+            fields.add("at_time_zone", node.at_time_zone());
+            fields.add("colon_colon", node.colon_colon());
+            fields.add("colon_eq", node.colon_eq());
+            fields.add("custom_op", node.custom_op());
+            fields.add("fat_arrow", node.fat_arrow());
+            fields.add("gteq", node.gteq());
+            fields.add("is_distinct_from", node.is_distinct_from());
+            fields.add("is_json", node.is_json());
+            fields.add("is_json_array", node.is_json_array());
+            fields.add("is_json_object", node.is_json_object());
+            fields.add("is_json_scalar", node.is_json_scalar());
+            fields.add("is_json_value", node.is_json_value());
+            fields.add("is_normalized", node.is_normalized());
+            fields.add("is_not", node.is_not());
+            fields.add("is_not_distinct_from", node.is_not_distinct_from());
+            fields.add("is_not_json", node.is_not_json());
+            fields.add("is_not_json_array", node.is_not_json_array());
+            fields.add("is_not_json_object", node.is_not_json_object());
+            fields.add("is_not_json_scalar", node.is_not_json_scalar());
+            fields.add("is_not_json_value", node.is_not_json_value());
+            fields.add("is_not_normalized", node.is_not_normalized());
+            fields.add("lteq", node.lteq());
+            fields.add("neq", node.neq());
+            fields.add("neqb", node.neqb());
+            fields.add("not_ilike", node.not_ilike());
+            fields.add("not_in", node.not_in());
+            fields.add("not_like", node.not_like());
+            fields.add("not_similar_to", node.not_similar_to());
+            fields.add("operator_call", node.operator_call());
+            fields.add("path_ref", node.path_ref());
+            fields.add("similar_to", node.similar_to());
+            fields.add("percent_token", node.percent_token());
+            fields.add("plus_token", node.plus_token());
+            fields.add("minus_token", node.minus_token());
+            fields.add("slash_token", node.slash_token());
+            fields.add("colon_token", node.colon_token());
+            fields.add("l_angle_token", node.l_angle_token());
+            fields.add("eq_token", node.eq_token());
+            fields.add("r_angle_token", node.r_angle_token());
+            fields.add("caret_token", node.caret_token());
+            fields.add("and_token", node.and_token());
+            fields.add("escape_token", node.escape_token());
+            fields.add("ilike_token", node.ilike_token());
+            fields.add("in_token", node.in_token());
+            fields.add("is_token", node.is_token());
+            fields.add("isnull_token", node.isnull_token());
+            fields.add("like_token", node.like_token());
+            fields.add("notnull_token", node.notnull_token());
+            fields.add("or_token", node.or_token());
+            fields.add("overlaps_token", node.overlaps_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::OP_CLASS_FOR_ORDER_BY => {
+            let node = ast::OpClassForOrderBy {
+                syntax: node.clone(),
+            };
+            fields.add("op_family_ref", node.op_family_ref());
+            fields.add("by_token", node.by_token());
+            fields.add("for_token", node.for_token());
+            fields.add("order_token", node.order_token());
+        }
+        SyntaxKind::OP_CLASS_FOR_SEARCH => {
+            let node = ast::OpClassForSearch {
+                syntax: node.clone(),
+            };
+            fields.add("for_token", node.for_token());
+            fields.add("search_token", node.search_token());
+        }
+        SyntaxKind::OP_CLASS_NAME => {
+            let node = ast::OpClassName {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::OP_CLASS_OPTION_FUNCTION => {
+            let node = ast::OpClassOptionFunction {
+                syntax: node.clone(),
+            };
+            fields.add("function_sig", node.function_sig());
+            fields.add("literal", node.literal());
+            fields.add("param_list", node.param_list());
+            fields.add("function_token", node.function_token());
+        }
+        SyntaxKind::OP_CLASS_OPTION_OPERATOR => {
+            let node = ast::OpClassOptionOperator {
+                syntax: node.clone(),
+            };
+            fields.add("lhs", node.lhs());
+            fields.add("rhs", node.rhs());
+            fields.add("literal", node.literal());
+            fields.add("op", node.op());
+            fields.add("op_class_purpose", node.op_class_purpose());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::OP_CLASS_OPTION_STORAGE => {
+            let node = ast::OpClassOptionStorage {
+                syntax: node.clone(),
+            };
+            fields.add("type", node.ty());
+            fields.add("storage_token", node.storage_token());
+        }
+        SyntaxKind::OP_CLASS_REF => {
+            let node = ast::OpClassRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::OP_CLASS_RENAME_TO => {
+            let node = ast::OpClassRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("op_class_name", node.op_class_name());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::OP_FAMILY_NAME => {
+            let node = ast::OpFamilyName {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::OP_FAMILY_REF => {
+            let node = ast::OpFamilyRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::OP_FAMILY_RENAME_TO => {
+            let node = ast::OpFamilyRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("op_family_name", node.op_family_name());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::OP_SIG => {
+            let node = ast::OpSig {
+                syntax: node.clone(),
+            };
+            fields.add("lhs", node.lhs());
+            fields.add("rhs", node.rhs());
+            fields.add("op", node.op());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("none_token", node.none_token());
+        }
+        SyntaxKind::OP_SIG_LIST => {
+            let node = ast::OpSigList {
+                syntax: node.clone(),
+            };
+            fields.add("op_sigs", node.op_sigs());
+        }
+        SyntaxKind::OPERATOR_CALL => {
+            let node = ast::OperatorCall {
+                syntax: node.clone(),
+            };
+            fields.add("op", node.op());
+            fields.add("path_ref", node.path_ref());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("dot_token", node.dot_token());
+            fields.add("operator_token", node.operator_token());
+        }
+        SyntaxKind::OPERATOR_CLASS_OPTION_LIST => {
+            let node = ast::OperatorClassOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("op_class_options", node.op_class_options());
+        }
+        SyntaxKind::OPERATOR_FAMILY_CLAUSE => {
+            let node = ast::OperatorFamilyClause {
+                syntax: node.clone(),
+            };
+            fields.add("op_family_ref", node.op_family_ref());
+            fields.add("family_token", node.family_token());
+        }
+        SyntaxKind::OPTION_ALTER_OPTION_LIST => {
+            let node = ast::OptionAlterOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("alter_options", node.alter_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("options_token", node.options_token());
+        }
+        SyntaxKind::OPTION_AS_TYPE => {
+            let node = ast::OptionAsType {
+                syntax: node.clone(),
+            };
+            fields.add("type", node.ty());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::OPTION_CACHE => {
+            let node = ast::OptionCache {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("cache_token", node.cache_token());
+        }
+        SyntaxKind::OPTION_CONNECTION => {
+            let node = ast::OptionConnection {
+                syntax: node.clone(),
+            };
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("connection_token", node.connection_token());
+        }
+        SyntaxKind::OPTION_CYCLE => {
+            let node = ast::OptionCycle {
+                syntax: node.clone(),
+            };
+            fields.add("cycle_token", node.cycle_token());
+        }
+        SyntaxKind::OPTION_DEFAULT => {
+            let node = ast::OptionDefault {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::OPTION_HANDLER => {
+            let node = ast::OptionHandler {
+                syntax: node.clone(),
+            };
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("handler_token", node.handler_token());
+        }
+        SyntaxKind::OPTION_IDENT => {
+            let node = ast::OptionIdent {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::OPTION_INCREMENT => {
+            let node = ast::OptionIncrement {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("by_token", node.by_token());
+            fields.add("increment_token", node.increment_token());
+        }
+        SyntaxKind::OPTION_ITEM => {
+            let node = ast::OptionItem {
+                syntax: node.clone(),
+            };
+            fields.add("option_item_key", node.option_item_key());
+            fields.add("option_item_value", node.option_item_value());
+        }
+        SyntaxKind::OPTION_ITEM_KEY => {
+            let node = ast::OptionItemKey {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::OPTION_ITEM_LIST => {
+            let node = ast::OptionItemList {
+                syntax: node.clone(),
+            };
+            fields.add("option_items", node.option_items());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::OPTION_ITEM_VALUE => {
+            let node = ast::OptionItemValue {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("option_item_value_name", node.option_item_value_name());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::OPTION_ITEM_VALUE_NAME => {
+            let node = ast::OptionItemValueName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::OPTION_LOGGED => {
+            let node = ast::OptionLogged {
+                syntax: node.clone(),
+            };
+            fields.add("logged_token", node.logged_token());
+        }
+        SyntaxKind::OPTION_MAX_VALUE => {
+            let node = ast::OptionMaxValue {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("maxvalue_token", node.maxvalue_token());
+        }
+        SyntaxKind::OPTION_MIN_VALUE => {
+            let node = ast::OptionMinValue {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("minvalue_token", node.minvalue_token());
+        }
+        SyntaxKind::OPTION_NO_CONNECTION => {
+            let node = ast::OptionNoConnection {
+                syntax: node.clone(),
+            };
+            fields.add("connection_token", node.connection_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::OPTION_NO_CYCLE => {
+            let node = ast::OptionNoCycle {
+                syntax: node.clone(),
+            };
+            fields.add("cycle_token", node.cycle_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::OPTION_NO_HANDLER => {
+            let node = ast::OptionNoHandler {
+                syntax: node.clone(),
+            };
+            fields.add("handler_token", node.handler_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::OPTION_NO_MAX_VALUE => {
+            let node = ast::OptionNoMaxValue {
+                syntax: node.clone(),
+            };
+            fields.add("maxvalue_token", node.maxvalue_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::OPTION_NO_MIN_VALUE => {
+            let node = ast::OptionNoMinValue {
+                syntax: node.clone(),
+            };
+            fields.add("minvalue_token", node.minvalue_token());
+            fields.add("no_token", node.no_token());
+        }
+        SyntaxKind::OPTION_NO_VALIDATOR => {
+            let node = ast::OptionNoValidator {
+                syntax: node.clone(),
+            };
+            fields.add("no_token", node.no_token());
+            fields.add("validator_token", node.validator_token());
+        }
+        SyntaxKind::OPTION_NOT_NULL => {
+            let node = ast::OptionNotNull {
+                syntax: node.clone(),
+            };
+            fields.add("not_token", node.not_token());
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::OPTION_NULL => {
+            let node = ast::OptionNull {
+                syntax: node.clone(),
+            };
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::OPTION_OWNED_BY => {
+            let node = ast::OptionOwnedBy {
+                syntax: node.clone(),
+            };
+            fields.add("owned_by_target", node.owned_by_target());
+            fields.add("by_token", node.by_token());
+            fields.add("owned_token", node.owned_token());
+        }
+        SyntaxKind::OPTION_PATH => {
+            let node = ast::OptionPath {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("path_token", node.path_token());
+        }
+        SyntaxKind::OPTION_RESTART => {
+            let node = ast::OptionRestart {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("restart_token", node.restart_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::OPTION_SEQUENCE_NAME => {
+            let node = ast::OptionSequenceName {
+                syntax: node.clone(),
+            };
+            fields.add("sequence", node.sequence());
+            fields.add("name_token", node.name_token());
+            fields.add("sequence_token", node.sequence_token());
+        }
+        SyntaxKind::OPTION_START => {
+            let node = ast::OptionStart {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("start_token", node.start_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::OPTION_UNLOGGED => {
+            let node = ast::OptionUnlogged {
+                syntax: node.clone(),
+            };
+            fields.add("unlogged_token", node.unlogged_token());
+        }
+        SyntaxKind::OPTION_VALIDATOR => {
+            let node = ast::OptionValidator {
+                syntax: node.clone(),
+            };
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("validator_token", node.validator_token());
+        }
+        SyntaxKind::OPTIONAL_AS_ALIAS => {
+            let node = ast::OptionalAsAlias {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::OR_REPLACE => {
+            let node = ast::OrReplace {
+                syntax: node.clone(),
+            };
+            fields.add("or_token", node.or_token());
+            fields.add("replace_token", node.replace_token());
+        }
+        SyntaxKind::ORDER_BY_CLAUSE => {
+            let node = ast::OrderByClause {
+                syntax: node.clone(),
+            };
+            fields.add("sort_by_list", node.sort_by_list());
+            fields.add("by_token", node.by_token());
+            fields.add("order_token", node.order_token());
+        }
+        SyntaxKind::OVER_CLAUSE => {
+            let node = ast::OverClause {
+                syntax: node.clone(),
+            };
+            fields.add("over_target", node.over_target());
+            fields.add("over_token", node.over_token());
+        }
+        SyntaxKind::OVER_WINDOW_SPEC => {
+            let node = ast::OverWindowSpec {
+                syntax: node.clone(),
+            };
+            fields.add("window_spec", node.window_spec());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::OVERLAY_EXPRS => {
+            let node = ast::OverlayExprs {
+                syntax: node.clone(),
+            };
+            fields.add("overlay_exprs", node.overlay_exprs());
+        }
+        SyntaxKind::OVERLAY_FN => {
+            let node = ast::OverlayFn {
+                syntax: node.clone(),
+            };
+            fields.add("overlay_args", node.overlay_args());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("overlay_token", node.overlay_token());
+        }
+        SyntaxKind::OVERLAY_PLACING => {
+            let node = ast::OverlayPlacing {
+                syntax: node.clone(),
+            };
+            fields.add("string", node.string());
+            fields.add("placing", node.placing());
+            fields.add("from", node.from());
+            fields.add("for", node.for_());
+            fields.add("for_token", node.for_token());
+            fields.add("from_token", node.from_token());
+            fields.add("placing_token", node.placing_token());
+        }
+        SyntaxKind::OVERRIDING_SYSTEM_VALUE => {
+            let node = ast::OverridingSystemValue {
+                syntax: node.clone(),
+            };
+            fields.add("overriding_token", node.overriding_token());
+            fields.add("system_token", node.system_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::OVERRIDING_USER_VALUE => {
+            let node = ast::OverridingUserValue {
+                syntax: node.clone(),
+            };
+            fields.add("overriding_token", node.overriding_token());
+            fields.add("user_token", node.user_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::OWNED_BY_NONE => {
+            let node = ast::OwnedByNone {
+                syntax: node.clone(),
+            };
+            fields.add("none_token", node.none_token());
+        }
+        SyntaxKind::OWNED_BY_ROLES => {
+            let node = ast::OwnedByRoles {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("by_token", node.by_token());
+            fields.add("owned_token", node.owned_token());
+        }
+        SyntaxKind::OWNER_TO => {
+            let node = ast::OwnerTo {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref", node.role_ref());
+            fields.add("owner_token", node.owner_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::PARALLEL_FUNC_OPTION => {
+            let node = ast::ParallelFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+            fields.add("parallel_token", node.parallel_token());
+        }
+        SyntaxKind::PARAM => {
+            let node = ast::Param {
+                syntax: node.clone(),
+            };
+            fields.add("mode", node.mode());
+            fields.add("name", node.name());
+            fields.add("param_default", node.param_default());
+            fields.add("type", node.ty());
+        }
+        SyntaxKind::PARAM_DEFAULT => {
+            let node = ast::ParamDefault {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("eq_token", node.eq_token());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::PARAM_IN => {
+            let node = ast::ParamIn {
+                syntax: node.clone(),
+            };
+            fields.add("in_token", node.in_token());
+        }
+        SyntaxKind::PARAM_IN_OUT => {
+            let node = ast::ParamInOut {
+                syntax: node.clone(),
+            };
+            fields.add("in_token", node.in_token());
+            fields.add("inout_token", node.inout_token());
+            fields.add("out_token", node.out_token());
+        }
+        SyntaxKind::PARAM_LIST => {
+            let node = ast::ParamList {
+                syntax: node.clone(),
+            };
+            fields.add("params", node.params());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("star_token", node.star_token());
+            fields.add("by_token", node.by_token());
+            fields.add("order_token", node.order_token());
+        }
+        SyntaxKind::PARAM_NAME => {
+            let node = ast::ParamName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PARAM_NAME_REF => {
+            let node = ast::ParamNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PARAM_OUT => {
+            let node = ast::ParamOut {
+                syntax: node.clone(),
+            };
+            fields.add("out_token", node.out_token());
+        }
+        SyntaxKind::PARAM_VARIADIC => {
+            let node = ast::ParamVariadic {
+                syntax: node.clone(),
+            };
+            fields.add("variadic_token", node.variadic_token());
+        }
+        SyntaxKind::PAREN_EXPR => {
+            let node = ast::ParenExpr {
+                syntax: node.clone(),
+            };
+            fields.add("compound_select", node.compound_select());
+            fields.add("expr", node.expr());
+            fields.add("from_item", node.from_item());
+            fields.add("join_expr", node.join_expr());
+            fields.add("select", node.select());
+            fields.add("table", node.table());
+            fields.add("values", node.values());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::PAREN_FROM_ITEM => {
+            let node = ast::ParenFromItem {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("paren_expr", node.paren_expr());
+            fields.add("paren_select", node.paren_select());
+            fields.add("lateral_token", node.lateral_token());
+            fields.add("only_token", node.only_token());
+        }
+        SyntaxKind::PAREN_GRAPH_PATTERN => {
+            let node = ast::ParenGraphPattern {
+                syntax: node.clone(),
+            };
+            fields.add("path_pattern", node.path_pattern());
+            fields.add("where_clause", node.where_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::PAREN_JSON_TABLE_PLAN => {
+            let node = ast::ParenJsonTablePlan {
+                syntax: node.clone(),
+            };
+            fields.add("json_table_plan", node.json_table_plan());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::PAREN_SELECT => {
+            let node = ast::ParenSelect {
+                syntax: node.clone(),
+            };
+            fields.add("fetch_clause", node.fetch_clause());
+            fields.add("limit_clause", node.limit_clause());
+            fields.add("locking_clauses", node.locking_clauses());
+            fields.add("offset_clause", node.offset_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("select", node.select());
+            fields.add("with_clause", node.with_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("semicolon_token", node.semicolon_token());
+        }
+        SyntaxKind::PARTITION => {
+            let node = ast::Partition {
+                syntax: node.clone(),
+            };
+            fields.add("partition_type", node.partition_type());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("partition_token", node.partition_token());
+        }
+        SyntaxKind::PARTITION_BY => {
+            let node = ast::PartitionBy {
+                syntax: node.clone(),
+            };
+            fields.add("partition_item_list", node.partition_item_list());
+            fields.add("partition_strategy", node.partition_strategy());
+            fields.add("by_token", node.by_token());
+            fields.add("partition_token", node.partition_token());
+        }
+        SyntaxKind::PARTITION_BY_CLAUSE => {
+            let node = ast::PartitionByClause {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("by_token", node.by_token());
+            fields.add("partition_token", node.partition_token());
+        }
+        SyntaxKind::PARTITION_DEFAULT => {
+            let node = ast::PartitionDefault {
+                syntax: node.clone(),
+            };
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::PARTITION_FOR_VALUES_FROM => {
+            let node = ast::PartitionForValuesFrom {
+                syntax: node.clone(),
+            };
+            fields.add("from", node.from());
+            fields.add("to", node.to());
+            fields.add("for_token", node.for_token());
+            fields.add("from_token", node.from_token());
+            fields.add("to_token", node.to_token());
+            fields.add("values_token", node.values_token());
+        }
+        SyntaxKind::PARTITION_FOR_VALUES_IN => {
+            let node = ast::PartitionForValuesIn {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("for_token", node.for_token());
+            fields.add("in_token", node.in_token());
+            fields.add("values_token", node.values_token());
+        }
+        SyntaxKind::PARTITION_FOR_VALUES_WITH => {
+            let node = ast::PartitionForValuesWith {
+                syntax: node.clone(),
+            };
+            fields.add("modulus", node.modulus());
+            fields.add("remainder", node.remainder());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("for_token", node.for_token());
+            fields.add("values_token", node.values_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::PARTITION_FROM_VALUES => {
+            let node = ast::PartitionFromValues {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::PARTITION_ITEM => {
+            let node = ast::PartitionItem {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("collate", node.collate());
+            fields.add("expr", node.expr());
+            fields.add("nulls_order", node.nulls_order());
+            fields.add("op_class_ref", node.op_class_ref());
+            fields.add("sort_order", node.sort_order());
+        }
+        SyntaxKind::PARTITION_ITEM_LIST => {
+            let node = ast::PartitionItemList {
+                syntax: node.clone(),
+            };
+            fields.add("partition_items", node.partition_items());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::PARTITION_LIST => {
+            let node = ast::PartitionList {
+                syntax: node.clone(),
+            };
+            fields.add("partitions", node.partitions());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::PARTITION_MODULUS => {
+            let node = ast::PartitionModulus {
+                syntax: node.clone(),
+            };
+            fields.add("int_number_token", node.int_number_token());
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PARTITION_OF => {
+            let node = ast::PartitionOf {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("of_token", node.of_token());
+            fields.add("partition_token", node.partition_token());
+        }
+        SyntaxKind::PARTITION_REMAINDER => {
+            let node = ast::PartitionRemainder {
+                syntax: node.clone(),
+            };
+            fields.add("int_number_token", node.int_number_token());
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PARTITION_STRATEGY => {
+            let node = ast::PartitionStrategy {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+            fields.add("range_token", node.range_token());
+        }
+        SyntaxKind::PARTITION_TO_VALUES => {
+            let node = ast::PartitionToValues {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::PATH => {
+            let node = ast::Path {
+                syntax: node.clone(),
+            };
+            fields.add("qualifier", node.qualifier());
+            fields.add("segment", node.segment());
+            fields.add("dot_token", node.dot_token());
+        }
+        SyntaxKind::PATH_FACTOR => {
+            let node = ast::PathFactor {
+                syntax: node.clone(),
+            };
+            fields.add("graph_pattern_qualifier", node.graph_pattern_qualifier());
+            fields.add("path_primary", node.path_primary());
+        }
+        SyntaxKind::PATH_PATTERN => {
+            let node = ast::PathPattern {
+                syntax: node.clone(),
+            };
+            fields.add("path_factor", node.path_factor());
+            fields.add("path_factors", node.path_factors());
+        }
+        SyntaxKind::PATH_PATTERN_LIST => {
+            let node = ast::PathPatternList {
+                syntax: node.clone(),
+            };
+            fields.add("path_patterns", node.path_patterns());
+        }
+        SyntaxKind::PATH_REF => {
+            let node = ast::PathRef {
+                syntax: node.clone(),
+            };
+            fields.add("qualifier", node.qualifier());
+            fields.add("segment", node.segment());
+            fields.add("dot_token", node.dot_token());
+        }
+        SyntaxKind::PATH_SEGMENT => {
+            let node = ast::PathSegment {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PATH_SEGMENT_REF => {
+            let node = ast::PathSegmentRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PATH_TYPE => {
+            let node = ast::PathType {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("path_ref", node.path_ref());
+            fields.add("setof_token", node.setof_token());
+        }
+        SyntaxKind::PERCENT_TYPE => {
+            let node = ast::PercentType {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+            fields.add("percent_type_clause", node.percent_type_clause());
+            fields.add("setof_token", node.setof_token());
+        }
+        SyntaxKind::PERCENT_TYPE_CLAUSE => {
+            let node = ast::PercentTypeClause {
+                syntax: node.clone(),
+            };
+            fields.add("percent_token", node.percent_token());
+            fields.add("rowtype_token", node.rowtype_token());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::PERIOD_COLUMN => {
+            let node = ast::PeriodColumn {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("period_token", node.period_token());
+        }
+        SyntaxKind::PLPGSQL_ALIAS_DECL => {
+            let node = ast::PlpgsqlAliasDecl {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("plpgsql_variable", node.plpgsql_variable());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("alias_token", node.alias_token());
+            fields.add("for_token", node.for_token());
+        }
+        SyntaxKind::PLPGSQL_ASSERT => {
+            let node = ast::PlpgsqlAssert {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("assert_token", node.assert_token());
+        }
+        SyntaxKind::PLPGSQL_ASSIGN => {
+            let node = ast::PlpgsqlAssign {
+                syntax: node.clone(),
+            };
+            fields.add("target", node.target());
+            fields.add("value", node.value());
+            fields.add("from_clause", node.from_clause());
+            fields.add("group_by_clause", node.group_by_clause());
+            fields.add("having_clause", node.having_clause());
+            fields.add("limit_clause", node.limit_clause());
+            fields.add("offset_clause", node.offset_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("target_list", node.target_list());
+            fields.add("where_clause", node.where_clause());
+            fields.add("window_clause", node.window_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::PLPGSQL_BLOCK => {
+            let node = ast::PlpgsqlBlock {
+                syntax: node.clone(),
+            };
+            fields.add("name_ref", node.name_ref());
+            fields.add("plpgsql_declare_section", node.plpgsql_declare_section());
+            fields.add(
+                "plpgsql_exception_section",
+                node.plpgsql_exception_section(),
+            );
+            fields.add("plpgsql_label", node.plpgsql_label());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("begin_token", node.begin_token());
+            fields.add("end_token", node.end_token());
+        }
+        SyntaxKind::PLPGSQL_CASE => {
+            let node = ast::PlpgsqlCase {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("plpgsql_case_whens", node.plpgsql_case_whens());
+            fields.add("plpgsql_else", node.plpgsql_else());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("case_token", node.case_token());
+            fields.add("end_token", node.end_token());
+        }
+        SyntaxKind::PLPGSQL_CASE_WHEN => {
+            let node = ast::PlpgsqlCaseWhen {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("then_token", node.then_token());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::PLPGSQL_CONDITION => {
+            let node = ast::PlpgsqlCondition {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("name_ref", node.name_ref());
+            fields.add("sqlstate_token", node.sqlstate_token());
+        }
+        SyntaxKind::PLPGSQL_CURSOR_DECL => {
+            let node = ast::PlpgsqlCursorDecl {
+                syntax: node.clone(),
+            };
+            fields.add("param_list", node.param_list());
+            fields.add("plpgsql_variable", node.plpgsql_variable());
+            fields.add("stmt", node.stmt());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("cursor_token", node.cursor_token());
+            fields.add("for_token", node.for_token());
+            fields.add("is_token", node.is_token());
+            fields.add("no_token", node.no_token());
+            fields.add("scroll_token", node.scroll_token());
+        }
+        SyntaxKind::PLPGSQL_DECLARE_SECTION => {
+            let node = ast::PlpgsqlDeclareSection {
+                syntax: node.clone(),
+            };
+            fields.add("plpgsql_decls", node.plpgsql_decls());
+            fields.add("declare_token", node.declare_token());
+        }
+        SyntaxKind::PLPGSQL_DEFAULT => {
+            let node = ast::PlpgsqlDefault {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("eq_token", node.eq_token());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::PLPGSQL_DIAGNOSTICS_ITEM => {
+            let node = ast::PlpgsqlDiagnosticsItem {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("name_ref", node.name_ref());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::PLPGSQL_DYNAMIC_QUERY => {
+            let node = ast::PlpgsqlDynamicQuery {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("plpgsql_using", node.plpgsql_using());
+            fields.add("execute_token", node.execute_token());
+        }
+        SyntaxKind::PLPGSQL_ELSE => {
+            let node = ast::PlpgsqlElse {
+                syntax: node.clone(),
+            };
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("else_token", node.else_token());
+        }
+        SyntaxKind::PLPGSQL_ELSIF => {
+            let node = ast::PlpgsqlElsif {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("elseif_token", node.elseif_token());
+            fields.add("elsif_token", node.elsif_token());
+            fields.add("then_token", node.then_token());
+        }
+        SyntaxKind::PLPGSQL_END_LOOP => {
+            let node = ast::PlpgsqlEndLoop {
+                syntax: node.clone(),
+            };
+            fields.add("name_ref", node.name_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("end_token", node.end_token());
+            fields.add("loop_token", node.loop_token());
+        }
+        SyntaxKind::PLPGSQL_EXCEPTION_HANDLER => {
+            let node = ast::PlpgsqlExceptionHandler {
+                syntax: node.clone(),
+            };
+            fields.add("plpgsql_conditions", node.plpgsql_conditions());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("then_token", node.then_token());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::PLPGSQL_EXCEPTION_SECTION => {
+            let node = ast::PlpgsqlExceptionSection {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "plpgsql_exception_handlers",
+                node.plpgsql_exception_handlers(),
+            );
+            fields.add("exception_token", node.exception_token());
+        }
+        SyntaxKind::PLPGSQL_EXECUTE => {
+            let node = ast::PlpgsqlExecute {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("plpgsql_into", node.plpgsql_into());
+            fields.add("plpgsql_using", node.plpgsql_using());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("execute_token", node.execute_token());
+        }
+        SyntaxKind::PLPGSQL_EXIT => {
+            let node = ast::PlpgsqlExit {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("name_ref", node.name_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("continue_token", node.continue_token());
+            fields.add("exit_token", node.exit_token());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::PLPGSQL_FOR_INT => {
+            let node = ast::PlpgsqlForInt {
+                syntax: node.clone(),
+            };
+            fields.add("lower", node.lower());
+            fields.add("upper", node.upper());
+            fields.add("step", node.step());
+            fields.add("plpgsql_end_loop", node.plpgsql_end_loop());
+            fields.add("plpgsql_label", node.plpgsql_label());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("plpgsql_variable", node.plpgsql_variable());
+            fields.add("dot_token", node.dot_token());
+            fields.add("by_token", node.by_token());
+            fields.add("for_token", node.for_token());
+            fields.add("in_token", node.in_token());
+            fields.add("loop_token", node.loop_token());
+            fields.add("reverse_token", node.reverse_token());
+        }
+        SyntaxKind::PLPGSQL_FOR_QUERY => {
+            let node = ast::PlpgsqlForQuery {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("cursor_ref", node.cursor_ref());
+            fields.add("plpgsql_dynamic_query", node.plpgsql_dynamic_query());
+            fields.add("plpgsql_end_loop", node.plpgsql_end_loop());
+            fields.add("plpgsql_label", node.plpgsql_label());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("plpgsql_variables", node.plpgsql_variables());
+            fields.add("stmt", node.stmt());
+            fields.add("for_token", node.for_token());
+            fields.add("in_token", node.in_token());
+            fields.add("loop_token", node.loop_token());
+        }
+        SyntaxKind::PLPGSQL_FOREACH => {
+            let node = ast::PlpgsqlForeach {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("literal", node.literal());
+            fields.add("plpgsql_end_loop", node.plpgsql_end_loop());
+            fields.add("plpgsql_label", node.plpgsql_label());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("plpgsql_variables", node.plpgsql_variables());
+            fields.add("array_token", node.array_token());
+            fields.add("foreach_token", node.foreach_token());
+            fields.add("in_token", node.in_token());
+            fields.add("loop_token", node.loop_token());
+            fields.add("slice_token", node.slice_token());
+        }
+        SyntaxKind::PLPGSQL_GET_DIAGNOSTICS => {
+            let node = ast::PlpgsqlGetDiagnostics {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "plpgsql_diagnostics_items",
+                node.plpgsql_diagnostics_items(),
+            );
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("current_token", node.current_token());
+            fields.add("diagnostics_token", node.diagnostics_token());
+            fields.add("get_token", node.get_token());
+            fields.add("stacked_token", node.stacked_token());
+        }
+        SyntaxKind::PLPGSQL_IF => {
+            let node = ast::PlpgsqlIf {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("plpgsql_else", node.plpgsql_else());
+            fields.add("plpgsql_elsifs", node.plpgsql_elsifs());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("end_token", node.end_token());
+            fields.add("if_token", node.if_token());
+            fields.add("then_token", node.then_token());
+        }
+        SyntaxKind::PLPGSQL_INTO => {
+            let node = ast::PlpgsqlInto {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("into_token", node.into_token());
+            fields.add("strict_token", node.strict_token());
+        }
+        SyntaxKind::PLPGSQL_LABEL => {
+            let node = ast::PlpgsqlLabel {
+                syntax: node.clone(),
+            };
+            fields.add("l_angle_token", node.l_angle_token());
+            fields.add("r_angle_token", node.r_angle_token());
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PLPGSQL_LOOP => {
+            let node = ast::PlpgsqlLoop {
+                syntax: node.clone(),
+            };
+            fields.add("plpgsql_end_loop", node.plpgsql_end_loop());
+            fields.add("plpgsql_label", node.plpgsql_label());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("loop_token", node.loop_token());
+        }
+        SyntaxKind::PLPGSQL_NULL => {
+            let node = ast::PlpgsqlNull {
+                syntax: node.clone(),
+            };
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::PLPGSQL_OPEN => {
+            let node = ast::PlpgsqlOpen {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("cursor_ref", node.cursor_ref());
+            fields.add("plpgsql_dynamic_query", node.plpgsql_dynamic_query());
+            fields.add("stmt", node.stmt());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("for_token", node.for_token());
+            fields.add("no_token", node.no_token());
+            fields.add("open_token", node.open_token());
+            fields.add("scroll_token", node.scroll_token());
+        }
+        SyntaxKind::PLPGSQL_OPTION => {
+            let node = ast::PlpgsqlOption {
+                syntax: node.clone(),
+            };
+            fields.add("name_ref", node.name_ref());
+            fields.add("pound_token", node.pound_token());
+        }
+        SyntaxKind::PLPGSQL_PERFORM => {
+            let node = ast::PlpgsqlPerform {
+                syntax: node.clone(),
+            };
+            fields.add("from_clause", node.from_clause());
+            fields.add("group_by_clause", node.group_by_clause());
+            fields.add("having_clause", node.having_clause());
+            fields.add("limit_clause", node.limit_clause());
+            fields.add("offset_clause", node.offset_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("target_list", node.target_list());
+            fields.add("where_clause", node.where_clause());
+            fields.add("window_clause", node.window_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("perform_token", node.perform_token());
+        }
+        SyntaxKind::PLPGSQL_RAISE => {
+            let node = ast::PlpgsqlRaise {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("literal", node.literal());
+            fields.add("plpgsql_raise_using", node.plpgsql_raise_using());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("debug_token", node.debug_token());
+            fields.add("exception_token", node.exception_token());
+            fields.add("info_token", node.info_token());
+            fields.add("log_token", node.log_token());
+            fields.add("notice_token", node.notice_token());
+            fields.add("raise_token", node.raise_token());
+            fields.add("sqlstate_token", node.sqlstate_token());
+            fields.add("warning_token", node.warning_token());
+        }
+        SyntaxKind::PLPGSQL_RAISE_OPTION => {
+            let node = ast::PlpgsqlRaiseOption {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("name_ref", node.name_ref());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::PLPGSQL_RAISE_USING => {
+            let node = ast::PlpgsqlRaiseUsing {
+                syntax: node.clone(),
+            };
+            fields.add("plpgsql_raise_options", node.plpgsql_raise_options());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::PLPGSQL_RETURN => {
+            let node = ast::PlpgsqlReturn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("plpgsql_dynamic_query", node.plpgsql_dynamic_query());
+            fields.add("stmt", node.stmt());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("next_token", node.next_token());
+            fields.add("query_token", node.query_token());
+            fields.add("return_token", node.return_token());
+        }
+        SyntaxKind::PLPGSQL_ROOT => {
+            let node = ast::PlpgsqlRoot {
+                syntax: node.clone(),
+            };
+            fields.add("plpgsql_block", node.plpgsql_block());
+            fields.add("plpgsql_options", node.plpgsql_options());
+            fields.add("semicolon_token", node.semicolon_token());
+        }
+        SyntaxKind::PLPGSQL_SQL_STMT => {
+            let node = ast::PlpgsqlSqlStmt {
+                syntax: node.clone(),
+            };
+            fields.add("plpgsql_into", node.plpgsql_into());
+            fields.add("stmt", node.stmt());
+            fields.add("semicolon_token", node.semicolon_token());
+        }
+        SyntaxKind::PLPGSQL_USING => {
+            let node = ast::PlpgsqlUsing {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::PLPGSQL_VAR_DECL => {
+            let node = ast::PlpgsqlVarDecl {
+                syntax: node.clone(),
+            };
+            fields.add("collate", node.collate());
+            fields.add("plpgsql_default", node.plpgsql_default());
+            fields.add("plpgsql_variable", node.plpgsql_variable());
+            fields.add("type", node.ty());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("constant_token", node.constant_token());
+            fields.add("not_token", node.not_token());
+            fields.add("null_token", node.null_token());
+        }
+        SyntaxKind::PLPGSQL_VARIABLE => {
+            let node = ast::PlpgsqlVariable {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PLPGSQL_WHILE => {
+            let node = ast::PlpgsqlWhile {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("plpgsql_end_loop", node.plpgsql_end_loop());
+            fields.add("plpgsql_label", node.plpgsql_label());
+            fields.add("plpgsql_stmts", node.plpgsql_stmts());
+            fields.add("loop_token", node.loop_token());
+            fields.add("while_token", node.while_token());
+        }
+        SyntaxKind::POLICY => {
+            let node = ast::Policy {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::POLICY_COMMAND => {
+            let node = ast::PolicyCommand {
+                syntax: node.clone(),
+            };
+            fields.add("command", node.command());
+            fields.add("for_token", node.for_token());
+        }
+        SyntaxKind::POLICY_COMMAND_ALL => {
+            let node = ast::PolicyCommandAll {
+                syntax: node.clone(),
+            };
+            fields.add("all_token", node.all_token());
+        }
+        SyntaxKind::POLICY_COMMAND_DELETE => {
+            let node = ast::PolicyCommandDelete {
+                syntax: node.clone(),
+            };
+            fields.add("delete_token", node.delete_token());
+        }
+        SyntaxKind::POLICY_COMMAND_INSERT => {
+            let node = ast::PolicyCommandInsert {
+                syntax: node.clone(),
+            };
+            fields.add("insert_token", node.insert_token());
+        }
+        SyntaxKind::POLICY_COMMAND_SELECT => {
+            let node = ast::PolicyCommandSelect {
+                syntax: node.clone(),
+            };
+            fields.add("select_token", node.select_token());
+        }
+        SyntaxKind::POLICY_COMMAND_UPDATE => {
+            let node = ast::PolicyCommandUpdate {
+                syntax: node.clone(),
+            };
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::POLICY_REF => {
+            let node = ast::PolicyRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::POLICY_RENAME_TO => {
+            let node = ast::PolicyRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("policy", node.policy());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::POLICY_ROLES => {
+            let node = ast::PolicyRoles {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::PORTION_FROM_TO => {
+            let node = ast::PortionFromTo {
+                syntax: node.clone(),
+            };
+            fields.add("from", node.from());
+            fields.add("to", node.to());
+            fields.add("from_token", node.from_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::PORTION_RANGE => {
+            let node = ast::PortionRange {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::POSITION_FN => {
+            let node = ast::PositionFn {
+                syntax: node.clone(),
+            };
+            fields.add("pos", node.pos());
+            fields.add("string", node.string());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("in_token", node.in_token());
+            fields.add("position_token", node.position_token());
+        }
+        SyntaxKind::POSTFIX_EXPR => {
+            let node = ast::PostfixExpr {
+                syntax: node.clone(),
+            };
+            fields.add("op", node.op());
+            fields.add("expr", node.expr());
+        }
+        SyntaxKind::PREFIX_EXPR => {
+            let node = ast::PrefixExpr {
+                syntax: node.clone(),
+            };
+            fields.add("op", node.op());
+            fields.add("expr", node.expr());
+        }
+        SyntaxKind::PREPARE => {
+            let node = ast::Prepare {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("param_list", node.param_list());
+            fields.add("stmt", node.stmt());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("as_token", node.as_token());
+            fields.add("prepare_token", node.prepare_token());
+        }
+        SyntaxKind::PREPARE_TRANSACTION => {
+            let node = ast::PrepareTransaction {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("prepare_token", node.prepare_token());
+            fields.add("transaction_token", node.transaction_token());
+        }
+        SyntaxKind::PREPARED_STATEMENT => {
+            let node = ast::PreparedStatement {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PREPARED_STATEMENT_REF => {
+            let node = ast::PreparedStatementRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PRESERVE_ROWS => {
+            let node = ast::PreserveRows {
+                syntax: node.clone(),
+            };
+            fields.add("preserve_token", node.preserve_token());
+            fields.add("rows_token", node.rows_token());
+        }
+        SyntaxKind::PRESERVE_WHITESPACE => {
+            let node = ast::PreserveWhitespace {
+                syntax: node.clone(),
+            };
+            fields.add("preserve_token", node.preserve_token());
+            fields.add("whitespace_token", node.whitespace_token());
+        }
+        SyntaxKind::PRIMARY_KEY_CONSTRAINT => {
+            let node = ast::PrimaryKeyConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("index_parameters", node.index_parameters());
+            fields.add("using_index", node.using_index());
+            fields.add("key_token", node.key_token());
+            fields.add("primary_token", node.primary_token());
+        }
+        SyntaxKind::PRIOR => {
+            let node = ast::Prior {
+                syntax: node.clone(),
+            };
+            fields.add("prior_token", node.prior_token());
+        }
+        SyntaxKind::PRIVILEGE_ALL_FUNCTIONS_IN_SCHEMA => {
+            let node = ast::PrivilegeAllFunctionsInSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_refs", node.schema_refs());
+            fields.add("all_token", node.all_token());
+            fields.add("functions_token", node.functions_token());
+            fields.add("in_token", node.in_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::PRIVILEGE_ALL_PROCEDURES_IN_SCHEMA => {
+            let node = ast::PrivilegeAllProceduresInSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_refs", node.schema_refs());
+            fields.add("all_token", node.all_token());
+            fields.add("in_token", node.in_token());
+            fields.add("procedures_token", node.procedures_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::PRIVILEGE_ALL_ROUTINES_IN_SCHEMA => {
+            let node = ast::PrivilegeAllRoutinesInSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_refs", node.schema_refs());
+            fields.add("all_token", node.all_token());
+            fields.add("in_token", node.in_token());
+            fields.add("routines_token", node.routines_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::PRIVILEGE_ALL_SEQUENCES_IN_SCHEMA => {
+            let node = ast::PrivilegeAllSequencesInSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_refs", node.schema_refs());
+            fields.add("all_token", node.all_token());
+            fields.add("in_token", node.in_token());
+            fields.add("schema_token", node.schema_token());
+            fields.add("sequences_token", node.sequences_token());
+        }
+        SyntaxKind::PRIVILEGE_ALL_TABLES_IN_SCHEMA => {
+            let node = ast::PrivilegeAllTablesInSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_refs", node.schema_refs());
+            fields.add("all_token", node.all_token());
+            fields.add("in_token", node.in_token());
+            fields.add("schema_token", node.schema_token());
+            fields.add("tables_token", node.tables_token());
+        }
+        SyntaxKind::PRIVILEGE_DATABASE => {
+            let node = ast::PrivilegeDatabase {
+                syntax: node.clone(),
+            };
+            fields.add("database_refs", node.database_refs());
+            fields.add("database_token", node.database_token());
+        }
+        SyntaxKind::PRIVILEGE_DEFAULT => {
+            let node = ast::PrivilegeDefault {
+                syntax: node.clone(),
+            };
+            fields.add("relation_name_refs", node.relation_name_refs());
+        }
+        SyntaxKind::PRIVILEGE_DOMAIN => {
+            let node = ast::PrivilegeDomain {
+                syntax: node.clone(),
+            };
+            fields.add("domain_refs", node.domain_refs());
+            fields.add("domain_token", node.domain_token());
+        }
+        SyntaxKind::PRIVILEGE_FOREIGN_DATA_WRAPPER => {
+            let node = ast::PrivilegeForeignDataWrapper {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "foreign_data_wrapper_refs",
+                node.foreign_data_wrapper_refs(),
+            );
+            fields.add("data_token", node.data_token());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("wrapper_token", node.wrapper_token());
+        }
+        SyntaxKind::PRIVILEGE_FOREIGN_SERVER => {
+            let node = ast::PrivilegeForeignServer {
+                syntax: node.clone(),
+            };
+            fields.add("server_refs", node.server_refs());
+            fields.add("foreign_token", node.foreign_token());
+            fields.add("server_token", node.server_token());
+        }
+        SyntaxKind::PRIVILEGE_FUNCTION => {
+            let node = ast::PrivilegeFunction {
+                syntax: node.clone(),
+            };
+            fields.add("function_sig_list", node.function_sig_list());
+            fields.add("function_token", node.function_token());
+        }
+        SyntaxKind::PRIVILEGE_LANGUAGE => {
+            let node = ast::PrivilegeLanguage {
+                syntax: node.clone(),
+            };
+            fields.add("language_refs", node.language_refs());
+            fields.add("language_token", node.language_token());
+        }
+        SyntaxKind::PRIVILEGE_LARGE_OBJECT => {
+            let node = ast::PrivilegeLargeObject {
+                syntax: node.clone(),
+            };
+            fields.add("literals", node.literals());
+            fields.add("large_token", node.large_token());
+            fields.add("object_token", node.object_token());
+        }
+        SyntaxKind::PRIVILEGE_PARAMETER => {
+            let node = ast::PrivilegeParameter {
+                syntax: node.clone(),
+            };
+            fields.add("config_parameter_refs", node.config_parameter_refs());
+            fields.add("parameter_token", node.parameter_token());
+        }
+        SyntaxKind::PRIVILEGE_PROCEDURE => {
+            let node = ast::PrivilegeProcedure {
+                syntax: node.clone(),
+            };
+            fields.add("procedure_sig_list", node.procedure_sig_list());
+            fields.add("procedure_token", node.procedure_token());
+        }
+        SyntaxKind::PRIVILEGE_PROPERTY_GRAPH => {
+            let node = ast::PrivilegePropertyGraph {
+                syntax: node.clone(),
+            };
+            fields.add("property_graph_refs", node.property_graph_refs());
+            fields.add("graph_token", node.graph_token());
+            fields.add("property_token", node.property_token());
+        }
+        SyntaxKind::PRIVILEGE_ROUTINE => {
+            let node = ast::PrivilegeRoutine {
+                syntax: node.clone(),
+            };
+            fields.add("routine_sig_list", node.routine_sig_list());
+            fields.add("routine_token", node.routine_token());
+        }
+        SyntaxKind::PRIVILEGE_SCHEMA => {
+            let node = ast::PrivilegeSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_refs", node.schema_refs());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::PRIVILEGE_SEQUENCE => {
+            let node = ast::PrivilegeSequence {
+                syntax: node.clone(),
+            };
+            fields.add("sequence_refs", node.sequence_refs());
+            fields.add("sequence_token", node.sequence_token());
+        }
+        SyntaxKind::PRIVILEGE_TABLE => {
+            let node = ast::PrivilegeTable {
+                syntax: node.clone(),
+            };
+            fields.add("relation_name_refs", node.relation_name_refs());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::PRIVILEGE_TABLESPACE => {
+            let node = ast::PrivilegeTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("tablespace_refs", node.tablespace_refs());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::PRIVILEGE_TARGET_FUNCTIONS => {
+            let node = ast::PrivilegeTargetFunctions {
+                syntax: node.clone(),
+            };
+            fields.add("functions_token", node.functions_token());
+        }
+        SyntaxKind::PRIVILEGE_TARGET_LARGE_OBJECTS => {
+            let node = ast::PrivilegeTargetLargeObjects {
+                syntax: node.clone(),
+            };
+            fields.add("large_token", node.large_token());
+            fields.add("objects_token", node.objects_token());
+        }
+        SyntaxKind::PRIVILEGE_TARGET_ROUTINES => {
+            let node = ast::PrivilegeTargetRoutines {
+                syntax: node.clone(),
+            };
+            fields.add("routines_token", node.routines_token());
+        }
+        SyntaxKind::PRIVILEGE_TARGET_SCHEMAS => {
+            let node = ast::PrivilegeTargetSchemas {
+                syntax: node.clone(),
+            };
+            fields.add("schemas_token", node.schemas_token());
+        }
+        SyntaxKind::PRIVILEGE_TARGET_SEQUENCES => {
+            let node = ast::PrivilegeTargetSequences {
+                syntax: node.clone(),
+            };
+            fields.add("sequences_token", node.sequences_token());
+        }
+        SyntaxKind::PRIVILEGE_TARGET_TABLES => {
+            let node = ast::PrivilegeTargetTables {
+                syntax: node.clone(),
+            };
+            fields.add("tables_token", node.tables_token());
+        }
+        SyntaxKind::PRIVILEGE_TARGET_TYPES => {
+            let node = ast::PrivilegeTargetTypes {
+                syntax: node.clone(),
+            };
+            fields.add("types_token", node.types_token());
+        }
+        SyntaxKind::PRIVILEGE_TYPE => {
+            let node = ast::PrivilegeType {
+                syntax: node.clone(),
+            };
+            fields.add("type_name_refs", node.type_name_refs());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::PROCEDURE_NAME => {
+            let node = ast::ProcedureName {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::PROCEDURE_NAME_REF => {
+            let node = ast::ProcedureNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::PROCEDURE_RENAME_TO => {
+            let node = ast::ProcedureRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("procedure_name", node.procedure_name());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::PROCEDURE_SIG => {
+            let node = ast::ProcedureSig {
+                syntax: node.clone(),
+            };
+            fields.add("param_list", node.param_list());
+            fields.add("procedure_name_ref", node.procedure_name_ref());
+        }
+        SyntaxKind::PROCEDURE_SIG_LIST => {
+            let node = ast::ProcedureSigList {
+                syntax: node.clone(),
+            };
+            fields.add("procedure_sigs", node.procedure_sigs());
+        }
+        SyntaxKind::PROPERTIES => {
+            let node = ast::Properties {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "expr_as_property_name_list",
+                node.expr_as_property_name_list(),
+            );
+            fields.add("properties_token", node.properties_token());
+        }
+        SyntaxKind::PROPERTY_ALL => {
+            let node = ast::PropertyAll {
+                syntax: node.clone(),
+            };
+            fields.add("all_token", node.all_token());
+        }
+        SyntaxKind::PROPERTY_COMMENTS => {
+            let node = ast::PropertyComments {
+                syntax: node.clone(),
+            };
+            fields.add("comments_token", node.comments_token());
+        }
+        SyntaxKind::PROPERTY_COMPRESSION => {
+            let node = ast::PropertyCompression {
+                syntax: node.clone(),
+            };
+            fields.add("compression_token", node.compression_token());
+        }
+        SyntaxKind::PROPERTY_CONSTRAINTS => {
+            let node = ast::PropertyConstraints {
+                syntax: node.clone(),
+            };
+            fields.add("constraints_token", node.constraints_token());
+        }
+        SyntaxKind::PROPERTY_DEFAULTS => {
+            let node = ast::PropertyDefaults {
+                syntax: node.clone(),
+            };
+            fields.add("defaults_token", node.defaults_token());
+        }
+        SyntaxKind::PROPERTY_GENERATED => {
+            let node = ast::PropertyGenerated {
+                syntax: node.clone(),
+            };
+            fields.add("generated_token", node.generated_token());
+        }
+        SyntaxKind::PROPERTY_GRAPH => {
+            let node = ast::PropertyGraph {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::PROPERTY_GRAPH_REF => {
+            let node = ast::PropertyGraphRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::PROPERTY_GRAPH_RENAME_TO => {
+            let node = ast::PropertyGraphRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("property_graph", node.property_graph());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::PROPERTY_IDENTITY => {
+            let node = ast::PropertyIdentity {
+                syntax: node.clone(),
+            };
+            fields.add("identity_token", node.identity_token());
+        }
+        SyntaxKind::PROPERTY_INDEXES => {
+            let node = ast::PropertyIndexes {
+                syntax: node.clone(),
+            };
+            fields.add("indexes_token", node.indexes_token());
+        }
+        SyntaxKind::PROPERTY_NAME => {
+            let node = ast::PropertyName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PROPERTY_NAME_REF => {
+            let node = ast::PropertyNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PROPERTY_STATISTICS => {
+            let node = ast::PropertyStatistics {
+                syntax: node.clone(),
+            };
+            fields.add("statistics_token", node.statistics_token());
+        }
+        SyntaxKind::PROPERTY_STORAGE => {
+            let node = ast::PropertyStorage {
+                syntax: node.clone(),
+            };
+            fields.add("storage_token", node.storage_token());
+        }
+        SyntaxKind::PSQL_META_COMMAND => {
+            let node = ast::PsqlMetaCommand {
+                syntax: node.clone(),
+            };
+            fields.add("meta_command_token", node.meta_command_token());
+        }
+        SyntaxKind::PUBLICATION => {
+            let node = ast::Publication {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PUBLICATION_OBJECT_CURRENT_SCHEMA => {
+            let node = ast::PublicationObjectCurrentSchema {
+                syntax: node.clone(),
+            };
+            fields.add("current_schema_token", node.current_schema_token());
+        }
+        SyntaxKind::PUBLICATION_OBJECT_TABLE => {
+            let node = ast::PublicationObjectTable {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("where_condition_clause", node.where_condition_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("star_token", node.star_token());
+            fields.add("only_token", node.only_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::PUBLICATION_OBJECT_TABLES_IN_SCHEMA => {
+            let node = ast::PublicationObjectTablesInSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_ref", node.schema_ref());
+            fields.add("where_condition_clause", node.where_condition_clause());
+            fields.add("current_schema_token", node.current_schema_token());
+            fields.add("in_token", node.in_token());
+            fields.add("schema_token", node.schema_token());
+            fields.add("tables_token", node.tables_token());
+        }
+        SyntaxKind::PUBLICATION_REF => {
+            let node = ast::PublicationRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::PUBLICATION_RENAME_TO => {
+            let node = ast::PublicationRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("publication", node.publication());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::QUALIFIED_COLUMN_NAME_REF => {
+            let node = ast::QualifiedColumnNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::QUALIFIER_MAX => {
+            let node = ast::QualifierMax {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+        }
+        SyntaxKind::QUALIFIER_MIN => {
+            let node = ast::QualifierMin {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+        }
+        SyntaxKind::RANGE_TYPE => {
+            let node = ast::RangeType {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("as_token", node.as_token());
+            fields.add("range_token", node.range_token());
+        }
+        SyntaxKind::READ_COMMITTED => {
+            let node = ast::ReadCommitted {
+                syntax: node.clone(),
+            };
+            fields.add("committed_token", node.committed_token());
+            fields.add("isolation_token", node.isolation_token());
+            fields.add("level_token", node.level_token());
+            fields.add("read_token", node.read_token());
+        }
+        SyntaxKind::READ_ONLY => {
+            let node = ast::ReadOnly {
+                syntax: node.clone(),
+            };
+            fields.add("only_token", node.only_token());
+            fields.add("read_token", node.read_token());
+        }
+        SyntaxKind::READ_UNCOMMITTED => {
+            let node = ast::ReadUncommitted {
+                syntax: node.clone(),
+            };
+            fields.add("isolation_token", node.isolation_token());
+            fields.add("level_token", node.level_token());
+            fields.add("read_token", node.read_token());
+            fields.add("uncommitted_token", node.uncommitted_token());
+        }
+        SyntaxKind::READ_WRITE => {
+            let node = ast::ReadWrite {
+                syntax: node.clone(),
+            };
+            fields.add("read_token", node.read_token());
+            fields.add("write_token", node.write_token());
+        }
+        SyntaxKind::REASSIGN => {
+            let node = ast::Reassign {
+                syntax: node.clone(),
+            };
+            fields.add("before", node.before());
+            fields.add("after", node.after());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("by_token", node.by_token());
+            fields.add("owned_token", node.owned_token());
+            fields.add("reassign_token", node.reassign_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::REFERENCES_CONSTRAINT => {
+            let node = ast::ReferencesConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("column", node.column());
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("match_type", node.match_type());
+            fields.add("on_delete_action", node.on_delete_action());
+            fields.add("on_update_action", node.on_update_action());
+            fields.add("table", node.table());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("references_token", node.references_token());
+        }
+        SyntaxKind::REFERENCES_TABLE => {
+            let node = ast::ReferencesTable {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("vertex_table_ref", node.vertex_table_ref());
+            fields.add("references_token", node.references_token());
+        }
+        SyntaxKind::REFERENCING => {
+            let node = ast::Referencing {
+                syntax: node.clone(),
+            };
+            fields.add("referencing_tables", node.referencing_tables());
+            fields.add("referencing_token", node.referencing_token());
+        }
+        SyntaxKind::REFRESH => {
+            let node = ast::Refresh {
+                syntax: node.clone(),
+            };
+            fields.add("data_option", node.data_option());
+            fields.add("view_ref", node.view_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("materialized_token", node.materialized_token());
+            fields.add("refresh_token", node.refresh_token());
+            fields.add("view_token", node.view_token());
+        }
+        SyntaxKind::REFRESH_COLLATION_VERSION => {
+            let node = ast::RefreshCollationVersion {
+                syntax: node.clone(),
+            };
+            fields.add("collation_token", node.collation_token());
+            fields.add("refresh_token", node.refresh_token());
+            fields.add("version_token", node.version_token());
+        }
+        SyntaxKind::REFRESH_PUBLICATION => {
+            let node = ast::RefreshPublication {
+                syntax: node.clone(),
+            };
+            fields.add("with_params", node.with_params());
+            fields.add("publication_token", node.publication_token());
+            fields.add("refresh_token", node.refresh_token());
+        }
+        SyntaxKind::REFRESH_VERSION => {
+            let node = ast::RefreshVersion {
+                syntax: node.clone(),
+            };
+            fields.add("refresh_token", node.refresh_token());
+            fields.add("version_token", node.version_token());
+        }
+        SyntaxKind::REINDEX => {
+            let node = ast::Reindex {
+                syntax: node.clone(),
+            };
+            fields.add("reindex_option_list", node.reindex_option_list());
+            fields.add("reindex_target", node.reindex_target());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("reindex_token", node.reindex_token());
+        }
+        SyntaxKind::REINDEX_OPTION_CONCURRENTLY => {
+            let node = ast::ReindexOptionConcurrently {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("ident_token", node.ident_token());
+            fields.add("no_token", node.no_token());
+            fields.add("yes_token", node.yes_token());
+        }
+        SyntaxKind::REINDEX_OPTION_LIST => {
+            let node = ast::ReindexOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("reindex_options", node.reindex_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::REINDEX_OPTION_TABLESPACE => {
+            let node = ast::ReindexOptionTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::REINDEX_OPTION_VERBOSE => {
+            let node = ast::ReindexOptionVerbose {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("ident_token", node.ident_token());
+            fields.add("no_token", node.no_token());
+            fields.add("verbose_token", node.verbose_token());
+            fields.add("yes_token", node.yes_token());
+        }
+        SyntaxKind::REINDEX_TARGET_DATABASE => {
+            let node = ast::ReindexTargetDatabase {
+                syntax: node.clone(),
+            };
+            fields.add("database_ref", node.database_ref());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("database_token", node.database_token());
+        }
+        SyntaxKind::REINDEX_TARGET_INDEX => {
+            let node = ast::ReindexTargetIndex {
+                syntax: node.clone(),
+            };
+            fields.add("index_ref", node.index_ref());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("index_token", node.index_token());
+        }
+        SyntaxKind::REINDEX_TARGET_SCHEMA => {
+            let node = ast::ReindexTargetSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_ref", node.schema_ref());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::REINDEX_TARGET_SYSTEM => {
+            let node = ast::ReindexTargetSystem {
+                syntax: node.clone(),
+            };
+            fields.add("database_ref", node.database_ref());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("system_token", node.system_token());
+        }
+        SyntaxKind::REINDEX_TARGET_TABLE => {
+            let node = ast::ReindexTargetTable {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("concurrently_token", node.concurrently_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::RELATION_FROM_ITEM => {
+            let node = ast::RelationFromItem {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("relation_name_ref", node.relation_name_ref());
+            fields.add("tablesample_clause", node.tablesample_clause());
+            fields.add("star_token", node.star_token());
+            fields.add("only_token", node.only_token());
+        }
+        SyntaxKind::RELATION_LIST => {
+            let node = ast::RelationList {
+                syntax: node.clone(),
+            };
+            fields.add("relation_names", node.relation_names());
+        }
+        SyntaxKind::RELATION_NAME => {
+            let node = ast::RelationName {
+                syntax: node.clone(),
+            };
+            fields.add("relation_name_ref", node.relation_name_ref());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("star_token", node.star_token());
+            fields.add("only_token", node.only_token());
+        }
+        SyntaxKind::RELATION_NAME_REF => {
+            let node = ast::RelationNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::RELATIVE => {
+            let node = ast::Relative {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("relative_token", node.relative_token());
+        }
+        SyntaxKind::RELEASE_SAVEPOINT => {
+            let node = ast::ReleaseSavepoint {
+                syntax: node.clone(),
+            };
+            fields.add("savepoint_ref", node.savepoint_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("release_token", node.release_token());
+            fields.add("savepoint_token", node.savepoint_token());
+        }
+        SyntaxKind::REMOTE_TABLE_NAME_REF => {
+            let node = ast::RemoteTableNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::RENAME_ATTRIBUTE => {
+            let node = ast::RenameAttribute {
+                syntax: node.clone(),
+            };
+            fields.add("composite_field", node.composite_field());
+            fields.add("composite_field_ref", node.composite_field_ref());
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("attribute_token", node.attribute_token());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::RENAME_COLUMN => {
+            let node = ast::RenameColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name", node.column_name());
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("column_token", node.column_token());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::RENAME_CONSTRAINT => {
+            let node = ast::RenameConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name", node.constraint_name());
+            fields.add("constraint_name_ref", node.constraint_name_ref());
+            fields.add("constraint_token", node.constraint_token());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::RENAME_VALUE => {
+            let node = ast::RenameValue {
+                syntax: node.clone(),
+            };
+            fields.add("from", node.from());
+            fields.add("to", node.to());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::REPACK => {
+            let node = ast::Repack {
+                syntax: node.clone(),
+            };
+            fields.add("option_item_list", node.option_item_list());
+            fields.add("table_and_columns_list", node.table_and_columns_list());
+            fields.add("using_index", node.using_index());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("repack_token", node.repack_token());
+        }
+        SyntaxKind::REPEATABLE_CLAUSE => {
+            let node = ast::RepeatableClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("repeatable_token", node.repeatable_token());
+        }
+        SyntaxKind::REPEATABLE_READ => {
+            let node = ast::RepeatableRead {
+                syntax: node.clone(),
+            };
+            fields.add("isolation_token", node.isolation_token());
+            fields.add("level_token", node.level_token());
+            fields.add("read_token", node.read_token());
+            fields.add("repeatable_token", node.repeatable_token());
+        }
+        SyntaxKind::REPLACE_DICTIONARY => {
+            let node = ast::ReplaceDictionary {
+                syntax: node.clone(),
+            };
+            fields.add("before", node.before());
+            fields.add("after", node.after());
+            fields.add("replace_token", node.replace_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::REPLICA_IDENTITY => {
+            let node = ast::ReplicaIdentity {
+                syntax: node.clone(),
+            };
+            fields.add("replica_identity_option", node.replica_identity_option());
+            fields.add("identity_token", node.identity_token());
+            fields.add("replica_token", node.replica_token());
+        }
+        SyntaxKind::REPLICA_IDENTITY_DEFAULT => {
+            let node = ast::ReplicaIdentityDefault {
+                syntax: node.clone(),
+            };
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::REPLICA_IDENTITY_FULL => {
+            let node = ast::ReplicaIdentityFull {
+                syntax: node.clone(),
+            };
+            fields.add("full_token", node.full_token());
+        }
+        SyntaxKind::REPLICA_IDENTITY_NOTHING => {
+            let node = ast::ReplicaIdentityNothing {
+                syntax: node.clone(),
+            };
+            fields.add("nothing_token", node.nothing_token());
+        }
+        SyntaxKind::REQUIRED_AS_ALIAS => {
+            let node = ast::RequiredAsAlias {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::RESET => {
+            let node = ast::Reset {
+                syntax: node.clone(),
+            };
+            fields.add("reset_target", node.reset_target());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("reset_token", node.reset_token());
+        }
+        SyntaxKind::RESET_CONFIG_PARAM => {
+            let node = ast::ResetConfigParam {
+                syntax: node.clone(),
+            };
+            fields.add("config_parameter_ref", node.config_parameter_ref());
+            fields.add("all_token", node.all_token());
+            fields.add("reset_token", node.reset_token());
+        }
+        SyntaxKind::RESET_FUNC_OPTION => {
+            let node = ast::ResetFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("config_parameter_ref", node.config_parameter_ref());
+            fields.add("all_token", node.all_token());
+            fields.add("reset_token", node.reset_token());
+        }
+        SyntaxKind::RESET_OPTIONS => {
+            let node = ast::ResetOptions {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("reset_token", node.reset_token());
+        }
+        SyntaxKind::RESET_ROLE => {
+            let node = ast::ResetRole {
+                syntax: node.clone(),
+            };
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("reset_token", node.reset_token());
+            fields.add("role_token", node.role_token());
+        }
+        SyntaxKind::RESET_SESSION_AUTH => {
+            let node = ast::ResetSessionAuth {
+                syntax: node.clone(),
+            };
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("authorization_token", node.authorization_token());
+            fields.add("reset_token", node.reset_token());
+            fields.add("session_token", node.session_token());
+        }
+        SyntaxKind::RESET_TIME_ZONE => {
+            let node = ast::ResetTimeZone {
+                syntax: node.clone(),
+            };
+            fields.add("time_token", node.time_token());
+            fields.add("zone_token", node.zone_token());
+        }
+        SyntaxKind::RESET_TRANSACTION_ISOLATION => {
+            let node = ast::ResetTransactionIsolation {
+                syntax: node.clone(),
+            };
+            fields.add("isolation_token", node.isolation_token());
+            fields.add("level_token", node.level_token());
+            fields.add("transaction_token", node.transaction_token());
+        }
+        SyntaxKind::RESPECT_NULLS => {
+            let node = ast::RespectNulls {
+                syntax: node.clone(),
+            };
+            fields.add("nulls_token", node.nulls_token());
+            fields.add("respect_token", node.respect_token());
+        }
+        SyntaxKind::RESTART => {
+            let node = ast::Restart {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("restart_token", node.restart_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::RESTART_IDENTITY => {
+            let node = ast::RestartIdentity {
+                syntax: node.clone(),
+            };
+            fields.add("identity_token", node.identity_token());
+            fields.add("restart_token", node.restart_token());
+        }
+        SyntaxKind::RESTRICT => {
+            let node = ast::Restrict {
+                syntax: node.clone(),
+            };
+            fields.add("restrict_token", node.restrict_token());
+        }
+        SyntaxKind::RET_TYPE => {
+            let node = ast::RetType {
+                syntax: node.clone(),
+            };
+            fields.add("table_arg_list", node.table_arg_list());
+            fields.add("type", node.ty());
+            fields.add("returns_token", node.returns_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::RETURN_FUNC_OPTION => {
+            let node = ast::ReturnFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("return_token", node.return_token());
+        }
+        SyntaxKind::RETURNING_CLAUSE => {
+            let node = ast::ReturningClause {
+                syntax: node.clone(),
+            };
+            fields.add("returning_option_list", node.returning_option_list());
+            fields.add("target_list", node.target_list());
+            fields.add("returning_token", node.returning_token());
+        }
+        SyntaxKind::RETURNING_NEW => {
+            let node = ast::ReturningNew {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("as_token", node.as_token());
+            fields.add("new_token", node.new_token());
+        }
+        SyntaxKind::RETURNING_OLD => {
+            let node = ast::ReturningOld {
+                syntax: node.clone(),
+            };
+            fields.add("name", node.name());
+            fields.add("as_token", node.as_token());
+            fields.add("old_token", node.old_token());
+        }
+        SyntaxKind::RETURNING_OPTION_LIST => {
+            let node = ast::ReturningOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("returning_options", node.returning_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::RETURNS_NULL_ON_NULL_INPUT_FUNC_OPTION => {
+            let node = ast::ReturnsNullOnNullInputFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("input_token", node.input_token());
+            fields.add("null_token", node.null_token());
+            fields.add("on_token", node.on_token());
+            fields.add("returns_token", node.returns_token());
+        }
+        SyntaxKind::REVOKE => {
+            let node = ast::Revoke {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("granted_by_clause", node.granted_by_clause());
+            fields.add(
+                "on_privilege_objects_clause",
+                node.on_privilege_objects_clause(),
+            );
+            fields.add("privileges", node.privileges());
+            fields.add("revoke_option_for", node.revoke_option_for());
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("from_token", node.from_token());
+            fields.add("revoke_token", node.revoke_token());
+        }
+        SyntaxKind::REVOKE_COMMAND => {
+            let node = ast::RevokeCommand {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("role_ref", node.role_ref());
+            fields.add("all_token", node.all_token());
+            fields.add("alter_token", node.alter_token());
+            fields.add("create_token", node.create_token());
+            fields.add("delete_token", node.delete_token());
+            fields.add("execute_token", node.execute_token());
+            fields.add("ident_token", node.ident_token());
+            fields.add("insert_token", node.insert_token());
+            fields.add("references_token", node.references_token());
+            fields.add("select_token", node.select_token());
+            fields.add("system_token", node.system_token());
+            fields.add("temp_token", node.temp_token());
+            fields.add("temporary_token", node.temporary_token());
+            fields.add("trigger_token", node.trigger_token());
+            fields.add("truncate_token", node.truncate_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::REVOKE_COMMAND_LIST => {
+            let node = ast::RevokeCommandList {
+                syntax: node.clone(),
+            };
+            fields.add("revoke_commands", node.revoke_commands());
+        }
+        SyntaxKind::REVOKE_DEFAULT_PRIVILEGES => {
+            let node = ast::RevokeDefaultPrivileges {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("grant_option_for", node.grant_option_for());
+            fields.add("privilege_target", node.privilege_target());
+            fields.add("privileges", node.privileges());
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("from_token", node.from_token());
+            fields.add("on_token", node.on_token());
+            fields.add("revoke_token", node.revoke_token());
+        }
+        SyntaxKind::ROLE => {
+            let node = ast::Role {
+                syntax: node.clone(),
+            };
+            fields.add("current_role_token", node.current_role_token());
+            fields.add("current_user_token", node.current_user_token());
+            fields.add("group_token", node.group_token());
+            fields.add("ident_token", node.ident_token());
+            fields.add("session_user_token", node.session_user_token());
+        }
+        SyntaxKind::ROLE_OPTION_ADMIN => {
+            let node = ast::RoleOptionAdmin {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("admin_token", node.admin_token());
+        }
+        SyntaxKind::ROLE_OPTION_CONNECTION_LIMIT => {
+            let node = ast::RoleOptionConnectionLimit {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("connection_token", node.connection_token());
+            fields.add("limit_token", node.limit_token());
+        }
+        SyntaxKind::ROLE_OPTION_ENCRYPTED_PASSWORD => {
+            let node = ast::RoleOptionEncryptedPassword {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("encrypted_token", node.encrypted_token());
+            fields.add("password_token", node.password_token());
+        }
+        SyntaxKind::ROLE_OPTION_GENERIC => {
+            let node = ast::RoleOptionGeneric {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::ROLE_OPTION_IN_GROUP => {
+            let node = ast::RoleOptionInGroup {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("group_token", node.group_token());
+            fields.add("in_token", node.in_token());
+        }
+        SyntaxKind::ROLE_OPTION_IN_ROLE => {
+            let node = ast::RoleOptionInRole {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("in_token", node.in_token());
+            fields.add("role_token", node.role_token());
+        }
+        SyntaxKind::ROLE_OPTION_INHERIT => {
+            let node = ast::RoleOptionInherit {
+                syntax: node.clone(),
+            };
+            fields.add("inherit_token", node.inherit_token());
+        }
+        SyntaxKind::ROLE_OPTION_LIST => {
+            let node = ast::RoleOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("role_options", node.role_options());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::ROLE_OPTION_PASSWORD => {
+            let node = ast::RoleOptionPassword {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("null_token", node.null_token());
+            fields.add("password_token", node.password_token());
+        }
+        SyntaxKind::ROLE_OPTION_ROLE => {
+            let node = ast::RoleOptionRole {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("role_token", node.role_token());
+        }
+        SyntaxKind::ROLE_OPTION_SYSID => {
+            let node = ast::RoleOptionSysid {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("sysid_token", node.sysid_token());
+        }
+        SyntaxKind::ROLE_OPTION_USER => {
+            let node = ast::RoleOptionUser {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref_list", node.role_ref_list());
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::ROLE_OPTION_VALID_UNTIL => {
+            let node = ast::RoleOptionValidUntil {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("until_token", node.until_token());
+            fields.add("valid_token", node.valid_token());
+        }
+        SyntaxKind::ROLE_REF => {
+            let node = ast::RoleRef {
+                syntax: node.clone(),
+            };
+            fields.add("current_role_token", node.current_role_token());
+            fields.add("current_user_token", node.current_user_token());
+            fields.add("group_token", node.group_token());
+            fields.add("ident_token", node.ident_token());
+            fields.add("session_user_token", node.session_user_token());
+        }
+        SyntaxKind::ROLE_REF_LIST => {
+            let node = ast::RoleRefList {
+                syntax: node.clone(),
+            };
+            fields.add("role_refs", node.role_refs());
+        }
+        SyntaxKind::ROLE_RENAME_TO => {
+            let node = ast::RoleRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("role", node.role());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::ROLLBACK_PREPARED => {
+            let node = ast::RollbackPrepared {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("prepared_token", node.prepared_token());
+            fields.add("rollback_token", node.rollback_token());
+        }
+        SyntaxKind::ROLLBACK_TO_SAVEPOINT => {
+            let node = ast::RollbackToSavepoint {
+                syntax: node.clone(),
+            };
+            fields.add("savepoint_ref", node.savepoint_ref());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("rollback_token", node.rollback_token());
+            fields.add("savepoint_token", node.savepoint_token());
+            fields.add("to_token", node.to_token());
+            fields.add("transaction_token", node.transaction_token());
+            fields.add("work_token", node.work_token());
+        }
+        SyntaxKind::ROLLBACK_TRANSACTION => {
+            let node = ast::RollbackTransaction {
+                syntax: node.clone(),
+            };
+            fields.add("chain_clause", node.chain_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("abort_token", node.abort_token());
+            fields.add("rollback_token", node.rollback_token());
+            fields.add("transaction_token", node.transaction_token());
+            fields.add("work_token", node.work_token());
+        }
+        SyntaxKind::ROUTINE_NAME => {
+            let node = ast::RoutineName {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::ROUTINE_NAME_REF => {
+            let node = ast::RoutineNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::ROUTINE_RENAME_TO => {
+            let node = ast::RoutineRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("routine_name", node.routine_name());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::ROUTINE_SIG => {
+            let node = ast::RoutineSig {
+                syntax: node.clone(),
+            };
+            fields.add("param_list", node.param_list());
+            fields.add("routine_name_ref", node.routine_name_ref());
+        }
+        SyntaxKind::ROUTINE_SIG_LIST => {
+            let node = ast::RoutineSigList {
+                syntax: node.clone(),
+            };
+            fields.add("routine_sigs", node.routine_sigs());
+        }
+        SyntaxKind::ROW => {
+            let node = ast::Row {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::ROW_EXCLUSIVE => {
+            let node = ast::RowExclusive {
+                syntax: node.clone(),
+            };
+            fields.add("exclusive_token", node.exclusive_token());
+            fields.add("row_token", node.row_token());
+        }
+        SyntaxKind::ROW_LIST => {
+            let node = ast::RowList {
+                syntax: node.clone(),
+            };
+            fields.add("rows", node.rows());
+        }
+        SyntaxKind::ROW_SHARE => {
+            let node = ast::RowShare {
+                syntax: node.clone(),
+            };
+            fields.add("row_token", node.row_token());
+            fields.add("share_token", node.share_token());
+        }
+        SyntaxKind::ROWS_FROM_ARG => {
+            let node = ast::RowsFromArg {
+                syntax: node.clone(),
+            };
+            fields.add("call_expr", node.call_expr());
+            fields.add("column_def_list", node.column_def_list());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::ROWS_FROM_ITEM => {
+            let node = ast::RowsFromItem {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("rows_from_args", node.rows_from_args());
+            fields.add("with_ordinality", node.with_ordinality());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("from_token", node.from_token());
+            fields.add("lateral_token", node.lateral_token());
+            fields.add("rows_token", node.rows_token());
+        }
+        SyntaxKind::ROWS_FUNC_OPTION => {
+            let node = ast::RowsFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("rows_token", node.rows_token());
+        }
+        SyntaxKind::RULE => {
+            let node = ast::Rule {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::RULE_DO => {
+            let node = ast::RuleDo {
+                syntax: node.clone(),
+            };
+            fields.add("rule_action", node.rule_action());
+            fields.add("also_token", node.also_token());
+            fields.add("do_token", node.do_token());
+            fields.add("instead_token", node.instead_token());
+        }
+        SyntaxKind::RULE_EVENT => {
+            let node = ast::RuleEvent {
+                syntax: node.clone(),
+            };
+            fields.add("delete_token", node.delete_token());
+            fields.add("ident_token", node.ident_token());
+            fields.add("insert_token", node.insert_token());
+            fields.add("select_token", node.select_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::RULE_ON => {
+            let node = ast::RuleOn {
+                syntax: node.clone(),
+            };
+            fields.add("relation_name_ref", node.relation_name_ref());
+            fields.add("rule_event", node.rule_event());
+            fields.add("where_clause", node.where_clause());
+            fields.add("on_token", node.on_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::RULE_REF => {
+            let node = ast::RuleRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::RULE_RENAME_TO => {
+            let node = ast::RuleRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("rule", node.rule());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::RULE_STMT_LIST => {
+            let node = ast::RuleStmtList {
+                syntax: node.clone(),
+            };
+            fields.add("rule_stmts", node.rule_stmts());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::SAVEPOINT => {
+            let node = ast::Savepoint {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SAVEPOINT_CREATE => {
+            let node = ast::SavepointCreate {
+                syntax: node.clone(),
+            };
+            fields.add("savepoint", node.savepoint());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("savepoint_token", node.savepoint_token());
+        }
+        SyntaxKind::SAVEPOINT_REF => {
+            let node = ast::SavepointRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SCHEMA => {
+            let node = ast::Schema {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SCHEMA_REF => {
+            let node = ast::SchemaRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SCHEMA_RENAME_TO => {
+            let node = ast::SchemaRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("schema", node.schema());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::SCROLL => {
+            let node = ast::Scroll {
+                syntax: node.clone(),
+            };
+            fields.add("scroll_token", node.scroll_token());
+        }
+        SyntaxKind::SEARCH_CLAUSE => {
+            let node = ast::SearchClause {
+                syntax: node.clone(),
+            };
+            fields.add("columns", node.columns());
+            fields.add("search_order", node.search_order());
+            fields.add("set_column", node.set_column());
+            fields.add("by_token", node.by_token());
+            fields.add("search_token", node.search_token());
+        }
+        SyntaxKind::SEARCH_COLUMNS => {
+            let node = ast::SearchColumns {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_refs", node.column_name_refs());
+        }
+        SyntaxKind::SEARCH_SET_COLUMN => {
+            let node = ast::SearchSetColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_ref", node.column_name_ref());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SECURITY_DEFINER_FUNC_OPTION => {
+            let node = ast::SecurityDefinerFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("definer_token", node.definer_token());
+            fields.add("external_token", node.external_token());
+            fields.add("security_token", node.security_token());
+        }
+        SyntaxKind::SECURITY_INVOKER_FUNC_OPTION => {
+            let node = ast::SecurityInvokerFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("external_token", node.external_token());
+            fields.add("invoker_token", node.invoker_token());
+            fields.add("security_token", node.security_token());
+        }
+        SyntaxKind::SECURITY_LABEL => {
+            let node = ast::SecurityLabel {
+                syntax: node.clone(),
+            };
+            fields.add("for_provider", node.for_provider());
+            fields.add("literal", node.literal());
+            fields.add("security_label_object", node.security_label_object());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("is_token", node.is_token());
+            fields.add("label_token", node.label_token());
+            fields.add("null_token", node.null_token());
+            fields.add("on_token", node.on_token());
+            fields.add("security_token", node.security_token());
+        }
+        SyntaxKind::SECURITY_LABEL_PROVIDER => {
+            let node = ast::SecurityLabelProvider {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SELECT => {
+            let node = ast::Select {
+                syntax: node.clone(),
+            };
+            fields.add("fetch_clause", node.fetch_clause());
+            fields.add("filter_clause", node.filter_clause());
+            fields.add("from_clause", node.from_clause());
+            fields.add("group_by_clause", node.group_by_clause());
+            fields.add("having_clause", node.having_clause());
+            fields.add("limit_clause", node.limit_clause());
+            fields.add("locking_clauses", node.locking_clauses());
+            fields.add("offset_clause", node.offset_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("plpgsql_into", node.plpgsql_into());
+            fields.add("select_clause", node.select_clause());
+            fields.add("where_clause", node.where_clause());
+            fields.add("window_clause", node.window_clause());
+            fields.add("with_clause", node.with_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+        }
+        SyntaxKind::SELECT_CLAUSE => {
+            let node = ast::SelectClause {
+                syntax: node.clone(),
+            };
+            fields.add("plpgsql_into", node.plpgsql_into());
+            fields.add("select_quantifier", node.select_quantifier());
+            fields.add("target_list", node.target_list());
+            fields.add("select_token", node.select_token());
+        }
+        SyntaxKind::SELECT_INTO => {
+            let node = ast::SelectInto {
+                syntax: node.clone(),
+            };
+            fields.add("filter_clause", node.filter_clause());
+            fields.add("from_clause", node.from_clause());
+            fields.add("group_by_clause", node.group_by_clause());
+            fields.add("having_clause", node.having_clause());
+            fields.add("into_clause", node.into_clause());
+            fields.add("limit_clause", node.limit_clause());
+            fields.add("locking_clauses", node.locking_clauses());
+            fields.add("offset_clause", node.offset_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("select_clause", node.select_clause());
+            fields.add("where_clause", node.where_clause());
+            fields.add("window_clause", node.window_clause());
+            fields.add("with_clause", node.with_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+        }
+        SyntaxKind::SEQUENCE => {
+            let node = ast::Sequence {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::SEQUENCE_OPTION_LIST => {
+            let node = ast::SequenceOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("sequence_options", node.sequence_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::SEQUENCE_REF => {
+            let node = ast::SequenceRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::SEQUENCE_RENAME_TO => {
+            let node = ast::SequenceRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("sequence", node.sequence());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::SERIALIZABLE => {
+            let node = ast::Serializable {
+                syntax: node.clone(),
+            };
+            fields.add("isolation_token", node.isolation_token());
+            fields.add("level_token", node.level_token());
+            fields.add("serializable_token", node.serializable_token());
+        }
+        SyntaxKind::SERVER => {
+            let node = ast::Server {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SERVER_CLAUSE => {
+            let node = ast::ServerClause {
+                syntax: node.clone(),
+            };
+            fields.add("server_ref", node.server_ref());
+            fields.add("server_token", node.server_token());
+        }
+        SyntaxKind::SERVER_REF => {
+            let node = ast::ServerRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SERVER_RENAME_TO => {
+            let node = ast::ServerRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("server", node.server());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::SERVER_TYPE => {
+            let node = ast::ServerType {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::SERVER_VERSION => {
+            let node = ast::ServerVersion {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("version_token", node.version_token());
+        }
+        SyntaxKind::SESSION_AUTHORIZATION => {
+            let node = ast::SessionAuthorization {
+                syntax: node.clone(),
+            };
+            fields.add("authorization_token", node.authorization_token());
+            fields.add("session_token", node.session_token());
+        }
+        SyntaxKind::SESSION_CHARACTERISTICS => {
+            let node = ast::SessionCharacteristics {
+                syntax: node.clone(),
+            };
+            fields.add("transaction_mode_list", node.transaction_mode_list());
+            fields.add("as_token", node.as_token());
+            fields.add("characteristics_token", node.characteristics_token());
+            fields.add("session_token", node.session_token());
+            fields.add("transaction_token", node.transaction_token());
+        }
+        SyntaxKind::SESSION_SCOPE => {
+            let node = ast::SessionScope {
+                syntax: node.clone(),
+            };
+            fields.add("session_token", node.session_token());
+        }
+        SyntaxKind::SET => {
+            let node = ast::Set {
+                syntax: node.clone(),
+            };
+            fields.add("set_scope", node.set_scope());
+            fields.add("set_target", node.set_target());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_ACCESS_METHOD => {
+            let node = ast::SetAccessMethod {
+                syntax: node.clone(),
+            };
+            fields.add("access_method_ref", node.access_method_ref());
+            fields.add("access_token", node.access_token());
+            fields.add("default_token", node.default_token());
+            fields.add("method_token", node.method_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_ALL_PUBLICATION_OBJECT_LIST => {
+            let node = ast::SetAllPublicationObjectList {
+                syntax: node.clone(),
+            };
+            fields.add("all_publication_objects", node.all_publication_objects());
+            fields.add("except_table_clause", node.except_table_clause());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_CATALOG => {
+            let node = ast::SetCatalog {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("catalog_token", node.catalog_token());
+        }
+        SyntaxKind::SET_CLAUSE => {
+            let node = ast::SetClause {
+                syntax: node.clone(),
+            };
+            fields.add("set_column_list", node.set_column_list());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_COLUMN_LIST => {
+            let node = ast::SetColumnList {
+                syntax: node.clone(),
+            };
+            fields.add("set_columns", node.set_columns());
+        }
+        SyntaxKind::SET_COMPRESSION => {
+            let node = ast::SetCompression {
+                syntax: node.clone(),
+            };
+            fields.add("compression_method_name", node.compression_method_name());
+            fields.add("compression_token", node.compression_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_CONFIG => {
+            let node = ast::SetConfig {
+                syntax: node.clone(),
+            };
+            fields.add("config_assignment", node.config_assignment());
+            fields.add("config_parameter_ref", node.config_parameter_ref());
+        }
+        SyntaxKind::SET_CONFIG_PARAM => {
+            let node = ast::SetConfigParam {
+                syntax: node.clone(),
+            };
+            fields.add("config_assignment", node.config_assignment());
+            fields.add("config_parameter_ref", node.config_parameter_ref());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_CONSTRAINTS => {
+            let node = ast::SetConstraints {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_refs", node.constraint_name_refs());
+            fields.add("constraint_timing", node.constraint_timing());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("all_token", node.all_token());
+            fields.add("constraints_token", node.constraints_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_DEFAULT => {
+            let node = ast::SetDefault {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("default_token", node.default_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_DEFAULT_COLUMNS => {
+            let node = ast::SetDefaultColumns {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("default_token", node.default_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_EXPR => {
+            let node = ast::SetExpr {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::SET_EXPR_LIST => {
+            let node = ast::SetExprList {
+                syntax: node.clone(),
+            };
+            fields.add("set_exprs", node.set_exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("row_token", node.row_token());
+        }
+        SyntaxKind::SET_EXPRESSION => {
+            let node = ast::SetExpression {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("as_token", node.as_token());
+            fields.add("expression_token", node.expression_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_FOREIGN_OPTION => {
+            let node = ast::SetForeignOption {
+                syntax: node.clone(),
+            };
+            fields.add("foreign_option_name", node.foreign_option_name());
+            fields.add("literal", node.literal());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_FUNC_OPTION => {
+            let node = ast::SetFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("set_config_param", node.set_config_param());
+        }
+        SyntaxKind::SET_GENERATED => {
+            let node = ast::SetGenerated {
+                syntax: node.clone(),
+            };
+            fields.add("generated_when", node.generated_when());
+            fields.add("generated_token", node.generated_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_GENERATED_OPTIONS => {
+            let node = ast::SetGeneratedOptions {
+                syntax: node.clone(),
+            };
+            fields.add("set_generated_options", node.set_generated_options());
+        }
+        SyntaxKind::SET_LOGGED => {
+            let node = ast::SetLogged {
+                syntax: node.clone(),
+            };
+            fields.add("logged_token", node.logged_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_MULTIPLE_COLUMNS => {
+            let node = ast::SetMultipleColumns {
+                syntax: node.clone(),
+            };
+            fields.add("column_target_list", node.column_target_list());
+            fields.add("paren_select", node.paren_select());
+            fields.add("set_expr_list", node.set_expr_list());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::SET_NOT_NULL => {
+            let node = ast::SetNotNull {
+                syntax: node.clone(),
+            };
+            fields.add("not_token", node.not_token());
+            fields.add("null_token", node.null_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_NULL_COLUMNS => {
+            let node = ast::SetNullColumns {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("null_token", node.null_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_OPTION_FOR => {
+            let node = ast::SetOptionFor {
+                syntax: node.clone(),
+            };
+            fields.add("for_token", node.for_token());
+            fields.add("option_token", node.option_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_OPTIONS => {
+            let node = ast::SetOptions {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_OPTIONS_LIST => {
+            let node = ast::SetOptionsList {
+                syntax: node.clone(),
+            };
+            fields.add("alter_option_list", node.alter_option_list());
+            fields.add("options_token", node.options_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_PUBLICATION => {
+            let node = ast::SetPublication {
+                syntax: node.clone(),
+            };
+            fields.add("publication_refs", node.publication_refs());
+            fields.add("with_params", node.with_params());
+            fields.add("publication_token", node.publication_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_PUBLICATION_OBJECTS => {
+            let node = ast::SetPublicationObjects {
+                syntax: node.clone(),
+            };
+            fields.add("publication_objects", node.publication_objects());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_ROLE => {
+            let node = ast::SetRole {
+                syntax: node.clone(),
+            };
+            fields.add("set_role_target", node.set_role_target());
+            fields.add("set_scope", node.set_scope());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("role_token", node.role_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_ROLE_NONE => {
+            let node = ast::SetRoleNone {
+                syntax: node.clone(),
+            };
+            fields.add("none_token", node.none_token());
+        }
+        SyntaxKind::SET_SCHEMA => {
+            let node = ast::SetSchema {
+                syntax: node.clone(),
+            };
+            fields.add("schema_ref", node.schema_ref());
+            fields.add("schema_token", node.schema_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_SCHEMA_VALUE => {
+            let node = ast::SetSchemaValue {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("schema_token", node.schema_token());
+        }
+        SyntaxKind::SET_SEQUENCE_OPTION => {
+            let node = ast::SetSequenceOption {
+                syntax: node.clone(),
+            };
+            fields.add("sequence_option", node.sequence_option());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_SESSION_AUTH => {
+            let node = ast::SetSessionAuth {
+                syntax: node.clone(),
+            };
+            fields.add("set_scope", node.set_scope());
+            fields.add("set_session_auth_target", node.set_session_auth_target());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("authorization_token", node.authorization_token());
+            fields.add("session_token", node.session_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_SESSION_AUTH_DEFAULT => {
+            let node = ast::SetSessionAuthDefault {
+                syntax: node.clone(),
+            };
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::SET_SINGLE_COLUMN => {
+            let node = ast::SetSingleColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_target", node.column_target());
+            fields.add("set_expr", node.set_expr());
+            fields.add("eq_token", node.eq_token());
+        }
+        SyntaxKind::SET_STATISTICS => {
+            let node = ast::SetStatistics {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("default_token", node.default_token());
+            fields.add("set_token", node.set_token());
+            fields.add("statistics_token", node.statistics_token());
+        }
+        SyntaxKind::SET_STORAGE => {
+            let node = ast::SetStorage {
+                syntax: node.clone(),
+            };
+            fields.add("storage_mode", node.storage_mode());
+            fields.add("set_token", node.set_token());
+            fields.add("storage_token", node.storage_token());
+        }
+        SyntaxKind::SET_TABLESPACE => {
+            let node = ast::SetTablespace {
+                syntax: node.clone(),
+            };
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("set_token", node.set_token());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::SET_TIME_ZONE => {
+            let node = ast::SetTimeZone {
+                syntax: node.clone(),
+            };
+            fields.add("config_value", node.config_value());
+            fields.add("default_token", node.default_token());
+            fields.add("local_token", node.local_token());
+            fields.add("time_token", node.time_token());
+            fields.add("zone_token", node.zone_token());
+        }
+        SyntaxKind::SET_TRANSACTION => {
+            let node = ast::SetTransaction {
+                syntax: node.clone(),
+            };
+            fields.add("session_characteristics", node.session_characteristics());
+            fields.add("transaction_modes", node.transaction_modes());
+            fields.add("transaction_snapshot", node.transaction_snapshot());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("set_token", node.set_token());
+        }
+        SyntaxKind::SET_TYPE => {
+            let node = ast::SetType {
+                syntax: node.clone(),
+            };
+            fields.add("collate", node.collate());
+            fields.add("expr", node.expr());
+            fields.add("type", node.ty());
+            fields.add("data_token", node.data_token());
+            fields.add("set_token", node.set_token());
+            fields.add("type_token", node.type_token());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::SET_UNLOGGED => {
+            let node = ast::SetUnlogged {
+                syntax: node.clone(),
+            };
+            fields.add("set_token", node.set_token());
+            fields.add("unlogged_token", node.unlogged_token());
+        }
+        SyntaxKind::SET_WITHOUT_CLUSTER => {
+            let node = ast::SetWithoutCluster {
+                syntax: node.clone(),
+            };
+            fields.add("cluster_token", node.cluster_token());
+            fields.add("set_token", node.set_token());
+            fields.add("without_token", node.without_token());
+        }
+        SyntaxKind::SET_WITHOUT_OIDS => {
+            let node = ast::SetWithoutOids {
+                syntax: node.clone(),
+            };
+            fields.add("oids_token", node.oids_token());
+            fields.add("set_token", node.set_token());
+            fields.add("without_token", node.without_token());
+        }
+        SyntaxKind::SET_XML_OPTION => {
+            let node = ast::SetXmlOption {
+                syntax: node.clone(),
+            };
+            fields.add("xml_document_or_content", node.xml_document_or_content());
+            fields.add("option_token", node.option_token());
+            fields.add("xml_token", node.xml_token());
+        }
+        SyntaxKind::SHARE => {
+            let node = ast::Share {
+                syntax: node.clone(),
+            };
+            fields.add("share_token", node.share_token());
+        }
+        SyntaxKind::SHARE_ROW_EXCLUSIVE => {
+            let node = ast::ShareRowExclusive {
+                syntax: node.clone(),
+            };
+            fields.add("exclusive_token", node.exclusive_token());
+            fields.add("row_token", node.row_token());
+            fields.add("share_token", node.share_token());
+        }
+        SyntaxKind::SHARE_UPDATE_EXCLUSIVE => {
+            let node = ast::ShareUpdateExclusive {
+                syntax: node.clone(),
+            };
+            fields.add("exclusive_token", node.exclusive_token());
+            fields.add("share_token", node.share_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::SHOW => {
+            let node = ast::Show {
+                syntax: node.clone(),
+            };
+            fields.add("show_action", node.show_action());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("show_token", node.show_token());
+        }
+        SyntaxKind::SIMILAR_TO => {
+            let node = ast::SimilarTo {
+                syntax: node.clone(),
+            };
+            fields.add("similar_token", node.similar_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::SKIP_LOCKED => {
+            let node = ast::SkipLocked {
+                syntax: node.clone(),
+            };
+            fields.add("locked_token", node.locked_token());
+            fields.add("skip_token", node.skip_token());
+        }
+        SyntaxKind::SKIP_SUBSCRIPTION => {
+            let node = ast::SkipSubscription {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("skip_token", node.skip_token());
+        }
+        SyntaxKind::SLICE_ACCESSOR => {
+            let node = ast::SliceAccessor {
+                syntax: node.clone(),
+            };
+            fields.add("start", node.start());
+            fields.add("end", node.end());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+            fields.add("colon_token", node.colon_token());
+        }
+        SyntaxKind::SLICE_EXPR => {
+            let node = ast::SliceExpr {
+                syntax: node.clone(),
+            };
+            fields.add("base", node.base());
+            fields.add("start", node.start());
+            fields.add("end", node.end());
+            fields.add("l_brack_token", node.l_brack_token());
+            fields.add("r_brack_token", node.r_brack_token());
+            fields.add("colon_token", node.colon_token());
+        }
+        SyntaxKind::SOME_FN => {
+            let node = ast::SomeFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("select_variant", node.select_variant());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("some_token", node.some_token());
+        }
+        SyntaxKind::SORT_ASC => {
+            let node = ast::SortAsc {
+                syntax: node.clone(),
+            };
+            fields.add("asc_token", node.asc_token());
+        }
+        SyntaxKind::SORT_BY => {
+            let node = ast::SortBy {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("nulls_order", node.nulls_order());
+            fields.add("sort_order", node.sort_order());
+        }
+        SyntaxKind::SORT_BY_LIST => {
+            let node = ast::SortByList {
+                syntax: node.clone(),
+            };
+            fields.add("sort_bys", node.sort_bys());
+        }
+        SyntaxKind::SORT_DESC => {
+            let node = ast::SortDesc {
+                syntax: node.clone(),
+            };
+            fields.add("desc_token", node.desc_token());
+        }
+        SyntaxKind::SORT_USING => {
+            let node = ast::SortUsing {
+                syntax: node.clone(),
+            };
+            fields.add("op", node.op());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::SOURCE_FILE => {
+            let node = ast::SourceFile {
+                syntax: node.clone(),
+            };
+            fields.add("stmts", node.stmts());
+        }
+        SyntaxKind::SOURCE_VERTEX_TABLE => {
+            let node = ast::SourceVertexTable {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("references_table", node.references_table());
+            fields.add("vertex_table_ref", node.vertex_table_ref());
+            fields.add("key_token", node.key_token());
+            fields.add("source_token", node.source_token());
+        }
+        SyntaxKind::SPLIT_PARTITION => {
+            let node = ast::SplitPartition {
+                syntax: node.clone(),
+            };
+            fields.add("partition_list", node.partition_list());
+            fields.add("into_token", node.into_token());
+            fields.add("partition_token", node.partition_token());
+            fields.add("split_token", node.split_token());
+        }
+        SyntaxKind::STABLE => {
+            let node = ast::Stable {
+                syntax: node.clone(),
+            };
+            fields.add("stable_token", node.stable_token());
+        }
+        SyntaxKind::STANDALONE_NO => {
+            let node = ast::StandaloneNo {
+                syntax: node.clone(),
+            };
+            fields.add("comma_token", node.comma_token());
+            fields.add("no_token", node.no_token());
+            fields.add("standalone_token", node.standalone_token());
+        }
+        SyntaxKind::STANDALONE_NO_VALUE => {
+            let node = ast::StandaloneNoValue {
+                syntax: node.clone(),
+            };
+            fields.add("comma_token", node.comma_token());
+            fields.add("no_token", node.no_token());
+            fields.add("standalone_token", node.standalone_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::STANDALONE_YES => {
+            let node = ast::StandaloneYes {
+                syntax: node.clone(),
+            };
+            fields.add("comma_token", node.comma_token());
+            fields.add("standalone_token", node.standalone_token());
+            fields.add("yes_token", node.yes_token());
+        }
+        SyntaxKind::STAT_TYPES => {
+            let node = ast::StatTypes {
+                syntax: node.clone(),
+            };
+            fields.add("statistics_kinds", node.statistics_kinds());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::STATISTICS => {
+            let node = ast::Statistics {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::STATISTICS_KIND => {
+            let node = ast::StatisticsKind {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::STATISTICS_ON_CLAUSE => {
+            let node = ast::StatisticsOnClause {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("on_token", node.on_token());
+        }
+        SyntaxKind::STATISTICS_REF => {
+            let node = ast::StatisticsRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::STATISTICS_RENAME_TO => {
+            let node = ast::StatisticsRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("statistics", node.statistics());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::STORAGE => {
+            let node = ast::Storage {
+                syntax: node.clone(),
+            };
+            fields.add("storage_mode", node.storage_mode());
+            fields.add("storage_token", node.storage_token());
+        }
+        SyntaxKind::STORAGE_MODE => {
+            let node = ast::StorageMode {
+                syntax: node.clone(),
+            };
+            fields.add("default_token", node.default_token());
+            fields.add("external_token", node.external_token());
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::STORED => {
+            let node = ast::Stored {
+                syntax: node.clone(),
+            };
+            fields.add("stored_token", node.stored_token());
+        }
+        SyntaxKind::STRICT_FUNC_OPTION => {
+            let node = ast::StrictFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("strict_token", node.strict_token());
+        }
+        SyntaxKind::STRIP_WHITESPACE => {
+            let node = ast::StripWhitespace {
+                syntax: node.clone(),
+            };
+            fields.add("strip_token", node.strip_token());
+            fields.add("whitespace_token", node.whitespace_token());
+        }
+        SyntaxKind::SUBSCRIPTION => {
+            let node = ast::Subscription {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SUBSCRIPTION_REF => {
+            let node = ast::SubscriptionRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::SUBSCRIPTION_RENAME_TO => {
+            let node = ast::SubscriptionRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("subscription", node.subscription());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::SUBSTRING_EXPRS => {
+            let node = ast::SubstringExprs {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+        }
+        SyntaxKind::SUBSTRING_FN => {
+            let node = ast::SubstringFn {
+                syntax: node.clone(),
+            };
+            fields.add("substring_args", node.substring_args());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("substring_token", node.substring_token());
+        }
+        SyntaxKind::SUBSTRING_FOR_FROM => {
+            let node = ast::SubstringForFrom {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("for_token", node.for_token());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::SUBSTRING_FROM_FOR => {
+            let node = ast::SubstringFromFor {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("for_token", node.for_token());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::SUBSTRING_SIMILAR_ESCAPE => {
+            let node = ast::SubstringSimilarEscape {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("escape_token", node.escape_token());
+            fields.add("similar_token", node.similar_token());
+        }
+        SyntaxKind::SUPPORT_FUNC_OPTION => {
+            let node = ast::SupportFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("support_token", node.support_token());
+        }
+        SyntaxKind::SYMMETRIC => {
+            let node = ast::Symmetric {
+                syntax: node.clone(),
+            };
+            fields.add("symmetric_token", node.symmetric_token());
+        }
+        SyntaxKind::TABLE => {
+            let node = ast::Table {
+                syntax: node.clone(),
+            };
+            fields.add("fetch_clause", node.fetch_clause());
+            fields.add("limit_clause", node.limit_clause());
+            fields.add("locking_clauses", node.locking_clauses());
+            fields.add("offset_clause", node.offset_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("relation_name", node.relation_name());
+            fields.add("with_clause", node.with_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("table_token", node.table_token());
+        }
+        SyntaxKind::TABLE_ALIAS => {
+            let node = ast::TableAlias {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::TABLE_AND_COLUMNS => {
+            let node = ast::TableAndColumns {
+                syntax: node.clone(),
+            };
+            fields.add("column_ref_list", node.column_ref_list());
+            fields.add("table_relation_name", node.table_relation_name());
+        }
+        SyntaxKind::TABLE_AND_COLUMNS_LIST => {
+            let node = ast::TableAndColumnsList {
+                syntax: node.clone(),
+            };
+            fields.add("table_and_columnss", node.table_and_columnss());
+        }
+        SyntaxKind::TABLE_ARG_LIST => {
+            let node = ast::TableArgList {
+                syntax: node.clone(),
+            };
+            fields.add("args", node.args());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::TABLE_LIST => {
+            let node = ast::TableList {
+                syntax: node.clone(),
+            };
+            fields.add("table_relation_names", node.table_relation_names());
+        }
+        SyntaxKind::TABLE_NAME => {
+            let node = ast::TableName {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::TABLE_NAME_REF => {
+            let node = ast::TableNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::TABLE_NAME_REF_LIST => {
+            let node = ast::TableNameRefList {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_refs", node.table_name_refs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::TABLE_RELATION_NAME => {
+            let node = ast::TableRelationName {
+                syntax: node.clone(),
+            };
+            fields.add("table_name_ref", node.table_name_ref());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("star_token", node.star_token());
+            fields.add("only_token", node.only_token());
+        }
+        SyntaxKind::TABLE_RENAME_TO => {
+            let node = ast::TableRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("table_name", node.table_name());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::TABLESAMPLE_CLAUSE => {
+            let node = ast::TablesampleClause {
+                syntax: node.clone(),
+            };
+            fields.add("call_expr", node.call_expr());
+            fields.add("repeatable_clause", node.repeatable_clause());
+            fields.add("tablesample_token", node.tablesample_token());
+        }
+        SyntaxKind::TABLESPACE => {
+            let node = ast::Tablespace {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::TABLESPACE_CLAUSE => {
+            let node = ast::TablespaceClause {
+                syntax: node.clone(),
+            };
+            fields.add("tablespace_ref", node.tablespace_ref());
+            fields.add("tablespace_token", node.tablespace_token());
+        }
+        SyntaxKind::TABLESPACE_OWNER => {
+            let node = ast::TablespaceOwner {
+                syntax: node.clone(),
+            };
+            fields.add("role_ref", node.role_ref());
+            fields.add("owner_token", node.owner_token());
+        }
+        SyntaxKind::TABLESPACE_REF => {
+            let node = ast::TablespaceRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::TABLESPACE_RENAME_TO => {
+            let node = ast::TablespaceRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("tablespace", node.tablespace());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::TARGET => {
+            let node = ast::Target {
+                syntax: node.clone(),
+            };
+            fields.add("as_name", node.as_name());
+            fields.add("expr", node.expr());
+            fields.add("star_token", node.star_token());
+        }
+        SyntaxKind::TARGET_LIST => {
+            let node = ast::TargetList {
+                syntax: node.clone(),
+            };
+            fields.add("targets", node.targets());
+        }
+        SyntaxKind::TEMP => {
+            let node = ast::Temp {
+                syntax: node.clone(),
+            };
+            fields.add("global_token", node.global_token());
+            fields.add("local_token", node.local_token());
+            fields.add("temp_token", node.temp_token());
+            fields.add("temporary_token", node.temporary_token());
+        }
+        SyntaxKind::TEXT_SEARCH_CONFIGURATION => {
+            let node = ast::TextSearchConfiguration {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::TEXT_SEARCH_CONFIGURATION_REF => {
+            let node = ast::TextSearchConfigurationRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::TEXT_SEARCH_CONFIGURATION_RENAME_TO => {
+            let node = ast::TextSearchConfigurationRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "text_search_configuration",
+                node.text_search_configuration(),
+            );
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::TEXT_SEARCH_DICTIONARY => {
+            let node = ast::TextSearchDictionary {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::TEXT_SEARCH_DICTIONARY_REF => {
+            let node = ast::TextSearchDictionaryRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::TEXT_SEARCH_DICTIONARY_RENAME_TO => {
+            let node = ast::TextSearchDictionaryRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("text_search_dictionary", node.text_search_dictionary());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::TEXT_SEARCH_PARSER => {
+            let node = ast::TextSearchParser {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::TEXT_SEARCH_PARSER_REF => {
+            let node = ast::TextSearchParserRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::TEXT_SEARCH_PARSER_RENAME_TO => {
+            let node = ast::TextSearchParserRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("text_search_parser", node.text_search_parser());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::TEXT_SEARCH_TEMPLATE => {
+            let node = ast::TextSearchTemplate {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::TEXT_SEARCH_TEMPLATE_REF => {
+            let node = ast::TextSearchTemplateRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::TEXT_SEARCH_TEMPLATE_RENAME_TO => {
+            let node = ast::TextSearchTemplateRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("text_search_template", node.text_search_template());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::TEXT_SEARCH_TOKEN_KIND => {
+            let node = ast::TextSearchTokenKind {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::TIES => {
+            let node = ast::Ties {
+                syntax: node.clone(),
+            };
+            fields.add("ties_token", node.ties_token());
+        }
+        SyntaxKind::TIME_TYPE => {
+            let node = ast::TimeType {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("timezone", node.timezone());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("setof_token", node.setof_token());
+            fields.add("time_token", node.time_token());
+        }
+        SyntaxKind::TIME_ZONE => {
+            let node = ast::TimeZone {
+                syntax: node.clone(),
+            };
+            fields.add("time_token", node.time_token());
+            fields.add("zone_token", node.zone_token());
+        }
+        SyntaxKind::TIMESTAMP_TYPE => {
+            let node = ast::TimestampType {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("timezone", node.timezone());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("setof_token", node.setof_token());
+            fields.add("timestamp_token", node.timestamp_token());
+        }
+        SyntaxKind::TIMING_AFTER => {
+            let node = ast::TimingAfter {
+                syntax: node.clone(),
+            };
+            fields.add("after_token", node.after_token());
+        }
+        SyntaxKind::TIMING_BEFORE => {
+            let node = ast::TimingBefore {
+                syntax: node.clone(),
+            };
+            fields.add("before_token", node.before_token());
+        }
+        SyntaxKind::TIMING_INSTEAD_OF => {
+            let node = ast::TimingInsteadOf {
+                syntax: node.clone(),
+            };
+            fields.add("instead_token", node.instead_token());
+            fields.add("of_token", node.of_token());
+        }
+        SyntaxKind::TO_CONFIG_VALUE => {
+            let node = ast::ToConfigValue {
+                syntax: node.clone(),
+            };
+            fields.add("config_values", node.config_values());
+            fields.add("eq_token", node.eq_token());
+            fields.add("default_token", node.default_token());
+            fields.add("null_token", node.null_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::TRANSACTION_ISOLATION_LEVEL => {
+            let node = ast::TransactionIsolationLevel {
+                syntax: node.clone(),
+            };
+            fields.add("isolation_token", node.isolation_token());
+            fields.add("level_token", node.level_token());
+            fields.add("transaction_token", node.transaction_token());
+        }
+        SyntaxKind::TRANSACTION_MODE_LIST => {
+            let node = ast::TransactionModeList {
+                syntax: node.clone(),
+            };
+            fields.add("transaction_modes", node.transaction_modes());
+        }
+        SyntaxKind::TRANSACTION_MODES => {
+            let node = ast::TransactionModes {
+                syntax: node.clone(),
+            };
+            fields.add("transaction_mode_list", node.transaction_mode_list());
+            fields.add("transaction_token", node.transaction_token());
+        }
+        SyntaxKind::TRANSACTION_SNAPSHOT => {
+            let node = ast::TransactionSnapshot {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+            fields.add("snapshot_token", node.snapshot_token());
+            fields.add("transaction_token", node.transaction_token());
+        }
+        SyntaxKind::TRANSFORM_FOR_TYPE => {
+            let node = ast::TransformForType {
+                syntax: node.clone(),
+            };
+            fields.add("type", node.ty());
+            fields.add("for_token", node.for_token());
+            fields.add("type_token", node.type_token());
+        }
+        SyntaxKind::TRANSFORM_FROM_FUNC => {
+            let node = ast::TransformFromFunc {
+                syntax: node.clone(),
+            };
+            fields.add("function_sig", node.function_sig());
+            fields.add("from_token", node.from_token());
+            fields.add("function_token", node.function_token());
+            fields.add("sql_token", node.sql_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::TRANSFORM_FUNC_OPTION => {
+            let node = ast::TransformFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("transform_for_types", node.transform_for_types());
+            fields.add("transform_token", node.transform_token());
+        }
+        SyntaxKind::TRANSFORM_TO_FUNC => {
+            let node = ast::TransformToFunc {
+                syntax: node.clone(),
+            };
+            fields.add("function_sig", node.function_sig());
+            fields.add("function_token", node.function_token());
+            fields.add("sql_token", node.sql_token());
+            fields.add("to_token", node.to_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::TRANSITION_RELATION_NAME => {
+            let node = ast::TransitionRelationName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::TRIGGER => {
+            let node = ast::Trigger {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::TRIGGER_EVENT_DELETE => {
+            let node = ast::TriggerEventDelete {
+                syntax: node.clone(),
+            };
+            fields.add("delete_token", node.delete_token());
+        }
+        SyntaxKind::TRIGGER_EVENT_INSERT => {
+            let node = ast::TriggerEventInsert {
+                syntax: node.clone(),
+            };
+            fields.add("insert_token", node.insert_token());
+        }
+        SyntaxKind::TRIGGER_EVENT_LIST => {
+            let node = ast::TriggerEventList {
+                syntax: node.clone(),
+            };
+            fields.add("trigger_events", node.trigger_events());
+        }
+        SyntaxKind::TRIGGER_EVENT_TRUNCATE => {
+            let node = ast::TriggerEventTruncate {
+                syntax: node.clone(),
+            };
+            fields.add("truncate_token", node.truncate_token());
+        }
+        SyntaxKind::TRIGGER_EVENT_UPDATE => {
+            let node = ast::TriggerEventUpdate {
+                syntax: node.clone(),
+            };
+            fields.add("column_name_refs", node.column_name_refs());
+            fields.add("of_token", node.of_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::TRIGGER_REF => {
+            let node = ast::TriggerRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::TRIGGER_RENAME_TO => {
+            let node = ast::TriggerRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("trigger", node.trigger());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::TRIM_BOTH => {
+            let node = ast::TrimBoth {
+                syntax: node.clone(),
+            };
+            fields.add("both_token", node.both_token());
+        }
+        SyntaxKind::TRIM_EXPR_FROM => {
+            let node = ast::TrimExprFrom {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("exprs", node.exprs());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::TRIM_EXPRS => {
+            let node = ast::TrimExprs {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+        }
+        SyntaxKind::TRIM_FN => {
+            let node = ast::TrimFn {
+                syntax: node.clone(),
+            };
+            fields.add("trim_args", node.trim_args());
+            fields.add("trim_side", node.trim_side());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("trim_token", node.trim_token());
+        }
+        SyntaxKind::TRIM_FROM => {
+            let node = ast::TrimFrom {
+                syntax: node.clone(),
+            };
+            fields.add("exprs", node.exprs());
+            fields.add("from_token", node.from_token());
+        }
+        SyntaxKind::TRIM_LEADING => {
+            let node = ast::TrimLeading {
+                syntax: node.clone(),
+            };
+            fields.add("leading_token", node.leading_token());
+        }
+        SyntaxKind::TRIM_TRAILING => {
+            let node = ast::TrimTrailing {
+                syntax: node.clone(),
+            };
+            fields.add("trailing_token", node.trailing_token());
+        }
+        SyntaxKind::TRUNCATE => {
+            let node = ast::Truncate {
+                syntax: node.clone(),
+            };
+            fields.add("drop_behavior", node.drop_behavior());
+            fields.add("identity_action", node.identity_action());
+            fields.add("table_list", node.table_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("table_token", node.table_token());
+            fields.add("truncate_token", node.truncate_token());
+        }
+        SyntaxKind::TUPLE_EXPR => {
+            let node = ast::TupleExpr {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("exprs", node.exprs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("row_token", node.row_token());
+        }
+        SyntaxKind::TYPE_NAME => {
+            let node = ast::TypeName {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::TYPE_NAME_REF => {
+            let node = ast::TypeNameRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::TYPE_RENAME_TO => {
+            let node = ast::TypeRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("type_name", node.type_name());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::UNBOUNDED_FOLLOWING => {
+            let node = ast::UnboundedFollowing {
+                syntax: node.clone(),
+            };
+            fields.add("following_token", node.following_token());
+            fields.add("unbounded_token", node.unbounded_token());
+        }
+        SyntaxKind::UNBOUNDED_PRECEDING => {
+            let node = ast::UnboundedPreceding {
+                syntax: node.clone(),
+            };
+            fields.add("preceding_token", node.preceding_token());
+            fields.add("unbounded_token", node.unbounded_token());
+        }
+        SyntaxKind::UNICODE_NORMAL_FORM => {
+            let node = ast::UnicodeNormalForm {
+                syntax: node.clone(),
+            };
+            fields.add("nfc_token", node.nfc_token());
+            fields.add("nfd_token", node.nfd_token());
+            fields.add("nfkc_token", node.nfkc_token());
+            fields.add("nfkd_token", node.nfkd_token());
+        }
+        SyntaxKind::UNION => {
+            let node = ast::Union {
+                syntax: node.clone(),
+            };
+            fields.add("all_or_distinct", node.all_or_distinct());
+            fields.add("union_token", node.union_token());
+        }
+        SyntaxKind::UNIQUE_CONSTRAINT => {
+            let node = ast::UniqueConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_clause", node.constraint_name_clause());
+            fields.add("constraint_options", node.constraint_options());
+            fields.add("index_parameters", node.index_parameters());
+            fields.add("using_index", node.using_index());
+            fields.add("unique_token", node.unique_token());
+        }
+        SyntaxKind::UNLISTEN => {
+            let node = ast::Unlisten {
+                syntax: node.clone(),
+            };
+            fields.add("channel_ref", node.channel_ref());
+            fields.add("star_token", node.star_token());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("unlisten_token", node.unlisten_token());
+        }
+        SyntaxKind::UNLOGGED => {
+            let node = ast::Unlogged {
+                syntax: node.clone(),
+            };
+            fields.add("unlogged_token", node.unlogged_token());
+        }
+        SyntaxKind::UPDATE => {
+            let node = ast::Update {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("for_portion_of", node.for_portion_of());
+            fields.add("from_clause", node.from_clause());
+            fields.add("relation_name", node.relation_name());
+            fields.add("returning_clause", node.returning_clause());
+            fields.add("set_clause", node.set_clause());
+            fields.add(
+                "where_clause_or_current_of",
+                node.where_clause_or_current_of(),
+            );
+            fields.add("with_clause", node.with_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("update_token", node.update_token());
+        }
+        SyntaxKind::UPDATE_TO => {
+            let node = ast::UpdateTo {
+                syntax: node.clone(),
+            };
+            fields.add("extension_version", node.extension_version());
+            fields.add("literal", node.literal());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::USER => {
+            let node = ast::User {
+                syntax: node.clone(),
+            };
+            fields.add("user_token", node.user_token());
+        }
+        SyntaxKind::USING_CLAUSE => {
+            let node = ast::UsingClause {
+                syntax: node.clone(),
+            };
+            fields.add("from_items", node.from_items());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::USING_EXPR_CLAUSE => {
+            let node = ast::UsingExprClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::USING_INDEX => {
+            let node = ast::UsingIndex {
+                syntax: node.clone(),
+            };
+            fields.add("index_ref", node.index_ref());
+            fields.add("index_token", node.index_token());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::USING_INDEX_NAME => {
+            let node = ast::UsingIndexName {
+                syntax: node.clone(),
+            };
+            fields.add("index_ref", node.index_ref());
+            fields.add("index_token", node.index_token());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::USING_METHOD => {
+            let node = ast::UsingMethod {
+                syntax: node.clone(),
+            };
+            fields.add("access_method_ref", node.access_method_ref());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::USING_ON_CLAUSE => {
+            let node = ast::UsingOnClause {
+                syntax: node.clone(),
+            };
+            fields.add("from_item", node.from_item());
+            fields.add("on_clause", node.on_clause());
+            fields.add("using_token", node.using_token());
+        }
+        SyntaxKind::VACUUM => {
+            let node = ast::Vacuum {
+                syntax: node.clone(),
+            };
+            fields.add("table_and_columns_list", node.table_and_columns_list());
+            fields.add("vacuum_option_list", node.vacuum_option_list());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("analyse_token", node.analyse_token());
+            fields.add("analyze_token", node.analyze_token());
+            fields.add("freeze_token", node.freeze_token());
+            fields.add("full_token", node.full_token());
+            fields.add("vacuum_token", node.vacuum_token());
+            fields.add("verbose_token", node.verbose_token());
+        }
+        SyntaxKind::VACUUM_OPTION => {
+            let node = ast::VacuumOption {
+                syntax: node.clone(),
+            };
+            fields.add("vacuum_option_name", node.vacuum_option_name());
+            fields.add("vacuum_option_value", node.vacuum_option_value());
+        }
+        SyntaxKind::VACUUM_OPTION_LIST => {
+            let node = ast::VacuumOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("vacuum_options", node.vacuum_options());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::VACUUM_OPTION_NAME => {
+            let node = ast::VacuumOptionName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::VACUUM_OPTION_VALUE => {
+            let node = ast::VacuumOptionValue {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("vacuum_option_value_name", node.vacuum_option_value_name());
+            fields.add("no_token", node.no_token());
+            fields.add("yes_token", node.yes_token());
+        }
+        SyntaxKind::VACUUM_OPTION_VALUE_NAME => {
+            let node = ast::VacuumOptionValueName {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::VALIDATE_CONSTRAINT => {
+            let node = ast::ValidateConstraint {
+                syntax: node.clone(),
+            };
+            fields.add("constraint_name_ref", node.constraint_name_ref());
+            fields.add("constraint_token", node.constraint_token());
+            fields.add("validate_token", node.validate_token());
+        }
+        SyntaxKind::VALIDATOR_CLAUSE => {
+            let node = ast::ValidatorClause {
+                syntax: node.clone(),
+            };
+            fields.add("function_name_ref", node.function_name_ref());
+            fields.add("validator_token", node.validator_token());
+        }
+        SyntaxKind::VALUES => {
+            let node = ast::Values {
+                syntax: node.clone(),
+            };
+            fields.add("fetch_clause", node.fetch_clause());
+            fields.add("limit_clause", node.limit_clause());
+            fields.add("locking_clauses", node.locking_clauses());
+            fields.add("offset_clause", node.offset_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("row_list", node.row_list());
+            fields.add("with_clause", node.with_clause());
+            fields.add("semicolon_token", node.semicolon_token());
+            fields.add("values_token", node.values_token());
+        }
+        SyntaxKind::VARCHAR_TYPE => {
+            let node = ast::VarcharType {
+                syntax: node.clone(),
+            };
+            fields.add("arg_list", node.arg_list());
+            fields.add("char_token", node.char_token());
+            fields.add("character_token", node.character_token());
+            fields.add("national_token", node.national_token());
+            fields.add("nchar_token", node.nchar_token());
+            fields.add("setof_token", node.setof_token());
+            fields.add("varchar_token", node.varchar_token());
+            fields.add("varying_token", node.varying_token());
+        }
+        SyntaxKind::VARIANT => {
+            let node = ast::Variant {
+                syntax: node.clone(),
+            };
+            fields.add("literal", node.literal());
+        }
+        SyntaxKind::VARIANT_LIST => {
+            let node = ast::VariantList {
+                syntax: node.clone(),
+            };
+            fields.add("variants", node.variants());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::VERTEX_PATTERN => {
+            let node = ast::VertexPattern {
+                syntax: node.clone(),
+            };
+            fields.add("element_variable", node.element_variable());
+            fields.add("is_label", node.is_label());
+            fields.add("where_clause", node.where_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::VERTEX_TABLE_DEF => {
+            let node = ast::VertexTableDef {
+                syntax: node.clone(),
+            };
+            fields.add(
+                "element_table_alias_clause",
+                node.element_table_alias_clause(),
+            );
+            fields.add("element_table_key_clause", node.element_table_key_clause());
+            fields.add(
+                "element_table_label_and_properties",
+                node.element_table_label_and_properties(),
+            );
+            fields.add("table_name_ref", node.table_name_ref());
+        }
+        SyntaxKind::VERTEX_TABLE_REF => {
+            let node = ast::VertexTableRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::VERTEX_TABLES => {
+            let node = ast::VertexTables {
+                syntax: node.clone(),
+            };
+            fields.add("vertex_table_defs", node.vertex_table_defs());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("node_token", node.node_token());
+            fields.add("tables_token", node.tables_token());
+            fields.add("vertex_token", node.vertex_token());
+        }
+        SyntaxKind::VIEW => {
+            let node = ast::View {
+                syntax: node.clone(),
+            };
+            fields.add("path", node.path());
+        }
+        SyntaxKind::VIEW_REF => {
+            let node = ast::ViewRef {
+                syntax: node.clone(),
+            };
+            fields.add("path_ref", node.path_ref());
+        }
+        SyntaxKind::VIEW_RENAME_TO => {
+            let node = ast::ViewRenameTo {
+                syntax: node.clone(),
+            };
+            fields.add("view", node.view());
+            fields.add("rename_token", node.rename_token());
+            fields.add("to_token", node.to_token());
+        }
+        SyntaxKind::VIRTUAL => {
+            let node = ast::Virtual {
+                syntax: node.clone(),
+            };
+            fields.add("virtual_token", node.virtual_token());
+        }
+        SyntaxKind::VOLATILE => {
+            let node = ast::Volatile {
+                syntax: node.clone(),
+            };
+            fields.add("volatile_token", node.volatile_token());
+        }
+        SyntaxKind::WHEN_CLAUSE => {
+            let node = ast::WhenClause {
+                syntax: node.clone(),
+            };
+            fields.add("condition", node.condition());
+            fields.add("then", node.then());
+            fields.add("then_token", node.then_token());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::WHEN_CLAUSE_LIST => {
+            let node = ast::WhenClauseList {
+                syntax: node.clone(),
+            };
+            fields.add("when_clause", node.when_clause());
+            fields.add("when_clauses", node.when_clauses());
+        }
+        SyntaxKind::WHEN_CONDITION => {
+            let node = ast::WhenCondition {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("when_token", node.when_token());
+        }
+        SyntaxKind::WHERE_CLAUSE => {
+            let node = ast::WhereClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("where_token", node.where_token());
+        }
+        SyntaxKind::WHERE_CONDITION_CLAUSE => {
+            let node = ast::WhereConditionClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("where_token", node.where_token());
+        }
+        SyntaxKind::WHERE_CURRENT_OF => {
+            let node = ast::WhereCurrentOf {
+                syntax: node.clone(),
+            };
+            fields.add("cursor_ref", node.cursor_ref());
+            fields.add("current_token", node.current_token());
+            fields.add("of_token", node.of_token());
+            fields.add("where_token", node.where_token());
+        }
+        SyntaxKind::WINDOW => {
+            let node = ast::Window {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::WINDOW_CLAUSE => {
+            let node = ast::WindowClause {
+                syntax: node.clone(),
+            };
+            fields.add("window_defs", node.window_defs());
+            fields.add("window_token", node.window_token());
+        }
+        SyntaxKind::WINDOW_DEF => {
+            let node = ast::WindowDef {
+                syntax: node.clone(),
+            };
+            fields.add("window", node.window());
+            fields.add("window_spec", node.window_spec());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::WINDOW_FUNC_OPTION => {
+            let node = ast::WindowFuncOption {
+                syntax: node.clone(),
+            };
+            fields.add("window_token", node.window_token());
+        }
+        SyntaxKind::WINDOW_REF => {
+            let node = ast::WindowRef {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::WINDOW_SPEC => {
+            let node = ast::WindowSpec {
+                syntax: node.clone(),
+            };
+            fields.add("frame_clause", node.frame_clause());
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("partition_by_clause", node.partition_by_clause());
+            fields.add("window_ref", node.window_ref());
+        }
+        SyntaxKind::WITH_CHECK_EXPR_CLAUSE => {
+            let node = ast::WithCheckExprClause {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("check_token", node.check_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_CHECK_OPTION => {
+            let node = ast::WithCheckOption {
+                syntax: node.clone(),
+            };
+            fields.add("check_option_level", node.check_option_level());
+            fields.add("check_token", node.check_token());
+            fields.add("option_token", node.option_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_CLAUSE => {
+            let node = ast::WithClause {
+                syntax: node.clone(),
+            };
+            fields.add("with_tables", node.with_tables());
+            fields.add("recursive_token", node.recursive_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_DATA => {
+            let node = ast::WithData {
+                syntax: node.clone(),
+            };
+            fields.add("data_token", node.data_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_FUNCTION => {
+            let node = ast::WithFunction {
+                syntax: node.clone(),
+            };
+            fields.add("function_sig", node.function_sig());
+            fields.add("function_token", node.function_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_HOLD => {
+            let node = ast::WithHold {
+                syntax: node.clone(),
+            };
+            fields.add("hold_token", node.hold_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_INOUT => {
+            let node = ast::WithInout {
+                syntax: node.clone(),
+            };
+            fields.add("inout_token", node.inout_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_NO_DATA => {
+            let node = ast::WithNoData {
+                syntax: node.clone(),
+            };
+            fields.add("data_token", node.data_token());
+            fields.add("no_token", node.no_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_OPTIONS => {
+            let node = ast::WithOptions {
+                syntax: node.clone(),
+            };
+            fields.add("options_token", node.options_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_ORDINALITY => {
+            let node = ast::WithOrdinality {
+                syntax: node.clone(),
+            };
+            fields.add("ordinality_token", node.ordinality_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_PARAMS => {
+            let node = ast::WithParams {
+                syntax: node.clone(),
+            };
+            fields.add("attribute_list", node.attribute_list());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_TABLE => {
+            let node = ast::WithTable {
+                syntax: node.clone(),
+            };
+            fields.add("column_list", node.column_list());
+            fields.add("cycle_clause", node.cycle_clause());
+            fields.add("materialized_option", node.materialized_option());
+            fields.add("name", node.name());
+            fields.add("query", node.query());
+            fields.add("search_clause", node.search_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("as_token", node.as_token());
+        }
+        SyntaxKind::WITH_TIES => {
+            let node = ast::WithTies {
+                syntax: node.clone(),
+            };
+            fields.add("ties_token", node.ties_token());
+            fields.add("with_token", node.with_token());
+        }
+        SyntaxKind::WITH_TIMEZONE => {
+            let node = ast::WithTimezone {
+                syntax: node.clone(),
+            };
+            fields.add("time_token", node.time_token());
+            fields.add("with_token", node.with_token());
+            fields.add("zone_token", node.zone_token());
+        }
+        SyntaxKind::WITHIN_CLAUSE => {
+            let node = ast::WithinClause {
+                syntax: node.clone(),
+            };
+            fields.add("order_by_clause", node.order_by_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("group_token", node.group_token());
+            fields.add("within_token", node.within_token());
+        }
+        SyntaxKind::WITHOUT_FUNCTION => {
+            let node = ast::WithoutFunction {
+                syntax: node.clone(),
+            };
+            fields.add("function_token", node.function_token());
+            fields.add("without_token", node.without_token());
+        }
+        SyntaxKind::WITHOUT_HOLD => {
+            let node = ast::WithoutHold {
+                syntax: node.clone(),
+            };
+            fields.add("hold_token", node.hold_token());
+            fields.add("without_token", node.without_token());
+        }
+        SyntaxKind::WITHOUT_OIDS => {
+            let node = ast::WithoutOids {
+                syntax: node.clone(),
+            };
+            fields.add("oids_token", node.oids_token());
+            fields.add("without_token", node.without_token());
+        }
+        SyntaxKind::WITHOUT_OVERLAPS => {
+            let node = ast::WithoutOverlaps {
+                syntax: node.clone(),
+            };
+            fields.add("overlaps_token", node.overlaps_token());
+            fields.add("without_token", node.without_token());
+        }
+        SyntaxKind::WITHOUT_TIMEZONE => {
+            let node = ast::WithoutTimezone {
+                syntax: node.clone(),
+            };
+            fields.add("time_token", node.time_token());
+            fields.add("without_token", node.without_token());
+            fields.add("zone_token", node.zone_token());
+        }
+        SyntaxKind::XML_ATTR => {
+            let node = ast::XmlAttr {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::XML_COLUMN_OPTION_LIST => {
+            let node = ast::XmlColumnOptionList {
+                syntax: node.clone(),
+            };
+            fields.add("xml_column_option", node.xml_column_option());
+            fields.add("xml_column_options", node.xml_column_options());
+        }
+        SyntaxKind::XML_CONTENT => {
+            let node = ast::XmlContent {
+                syntax: node.clone(),
+            };
+            fields.add("content_token", node.content_token());
+        }
+        SyntaxKind::XML_DOCUMENT => {
+            let node = ast::XmlDocument {
+                syntax: node.clone(),
+            };
+            fields.add("document_token", node.document_token());
+        }
+        SyntaxKind::XML_ELEMENT_FN => {
+            let node = ast::XmlElementFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr_as_xml_attr_list", node.expr_as_xml_attr_list());
+            fields.add("exprs", node.exprs());
+            fields.add("tag", node.tag());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("name_token", node.name_token());
+            fields.add("xmlattributes_token", node.xmlattributes_token());
+            fields.add("xmlelement_token", node.xmlelement_token());
+        }
+        SyntaxKind::XML_EXISTS_FN => {
+            let node = ast::XmlExistsFn {
+                syntax: node.clone(),
+            };
+            fields.add("xml_row_passing_clause", node.xml_row_passing_clause());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("xmlexists_token", node.xmlexists_token());
+        }
+        SyntaxKind::XML_FOREST_FN => {
+            let node = ast::XmlForestFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr_as_element_tag_list", node.expr_as_element_tag_list());
+            fields.add("xmlforest_token", node.xmlforest_token());
+        }
+        SyntaxKind::XML_NAMESPACE => {
+            let node = ast::XmlNamespace {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("prefix", node.prefix());
+            fields.add("as_token", node.as_token());
+            fields.add("default_token", node.default_token());
+        }
+        SyntaxKind::XML_NAMESPACE_LIST => {
+            let node = ast::XmlNamespaceList {
+                syntax: node.clone(),
+            };
+            fields.add("xml_namespaces", node.xml_namespaces());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+        }
+        SyntaxKind::XML_NAMESPACE_PREFIX => {
+            let node = ast::XmlNamespacePrefix {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::XML_PARSE_FN => {
+            let node = ast::XmlParseFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("xml_document_or_content", node.xml_document_or_content());
+            fields.add("xml_whitespace", node.xml_whitespace());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("xmlparse_token", node.xmlparse_token());
+        }
+        SyntaxKind::XML_PASSING_DOC => {
+            let node = ast::XmlPassingDoc {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("xml_passing_mech", node.xml_passing_mech());
+        }
+        SyntaxKind::XML_PASSING_MECH_BY_REF => {
+            let node = ast::XmlPassingMechByRef {
+                syntax: node.clone(),
+            };
+            fields.add("by_token", node.by_token());
+            fields.add("ref_token", node.ref_token());
+        }
+        SyntaxKind::XML_PASSING_MECH_BY_VALUE => {
+            let node = ast::XmlPassingMechByValue {
+                syntax: node.clone(),
+            };
+            fields.add("by_token", node.by_token());
+            fields.add("value_token", node.value_token());
+        }
+        SyntaxKind::XML_PI_FN => {
+            let node = ast::XmlPiFn {
+                syntax: node.clone(),
+            };
+            fields.add("target", node.target());
+            fields.add("expr", node.expr());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("name_token", node.name_token());
+            fields.add("xmlpi_token", node.xmlpi_token());
+        }
+        SyntaxKind::XML_PI_TARGET => {
+            let node = ast::XmlPiTarget {
+                syntax: node.clone(),
+            };
+            fields.add("ident_token", node.ident_token());
+        }
+        SyntaxKind::XML_ROOT_FN => {
+            let node = ast::XmlRootFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("xml_root_version", node.xml_root_version());
+            fields.add("xml_standalone", node.xml_standalone());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("xmlroot_token", node.xmlroot_token());
+        }
+        SyntaxKind::XML_ROW_PASSING_CLAUSE => {
+            let node = ast::XmlRowPassingClause {
+                syntax: node.clone(),
+            };
+            fields.add("row", node.row());
+            fields.add("xml_passing_doc", node.xml_passing_doc());
+            fields.add("xml_passing_mech", node.xml_passing_mech());
+            fields.add("passing_token", node.passing_token());
+        }
+        SyntaxKind::XML_SERIALIZE_FN => {
+            let node = ast::XmlSerializeFn {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("type", node.ty());
+            fields.add("xml_document_or_content", node.xml_document_or_content());
+            fields.add("xml_indent", node.xml_indent());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("as_token", node.as_token());
+            fields.add("xmlserialize_token", node.xmlserialize_token());
+        }
+        SyntaxKind::XML_TABLE => {
+            let node = ast::XmlTable {
+                syntax: node.clone(),
+            };
+            fields.add("xml_namespace_list", node.xml_namespace_list());
+            fields.add("xml_row_passing_clause", node.xml_row_passing_clause());
+            fields.add("xml_table_column_list", node.xml_table_column_list());
+            fields.add("l_paren_token", node.l_paren_token());
+            fields.add("r_paren_token", node.r_paren_token());
+            fields.add("comma_token", node.comma_token());
+            fields.add("xmlnamespaces_token", node.xmlnamespaces_token());
+            fields.add("xmltable_token", node.xmltable_token());
+        }
+        SyntaxKind::XML_TABLE_COLUMN => {
+            let node = ast::XmlTableColumn {
+                syntax: node.clone(),
+            };
+            fields.add("column_name", node.column_name());
+            fields.add("type", node.ty());
+            fields.add("xml_column_option_list", node.xml_column_option_list());
+            fields.add("for_token", node.for_token());
+            fields.add("ordinality_token", node.ordinality_token());
+        }
+        SyntaxKind::XML_TABLE_COLUMN_LIST => {
+            let node = ast::XmlTableColumnList {
+                syntax: node.clone(),
+            };
+            fields.add("xml_table_columns", node.xml_table_columns());
+            fields.add("columns_token", node.columns_token());
+        }
+        SyntaxKind::XML_TABLE_FROM_ITEM => {
+            let node = ast::XmlTableFromItem {
+                syntax: node.clone(),
+            };
+            fields.add("alias", node.alias());
+            fields.add("xml_table", node.xml_table());
+            fields.add("lateral_token", node.lateral_token());
+        }
+        SyntaxKind::XML_VERSION_EXPR => {
+            let node = ast::XmlVersionExpr {
+                syntax: node.clone(),
+            };
+            fields.add("expr", node.expr());
+            fields.add("version_token", node.version_token());
+        }
+        SyntaxKind::XML_VERSION_NO_VALUE => {
+            let node = ast::XmlVersionNoValue {
+                syntax: node.clone(),
+            };
+            fields.add("no_token", node.no_token());
+            fields.add("value_token", node.value_token());
+            fields.add("version_token", node.version_token());
+        }
+        _ => (),
+    }
+}
@@ -0,0 +1,324 @@
+//! JSON export of the typed AST, so tools written in other languages can
+//! build on the parser.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "root": {
+//!     "kind": "SOURCE_FILE",
+//!     "range": [0, 9],
+//!     "fields": {
+//!       "stmts": [
+//!         {
+//!           "kind": "SELECT",
+//!           "range": [0, 8],
+//!           "fields": { "select_clause": { "kind": "SELECT_CLAUSE", ... } }
+//!         }
+//!       ]
+//!     }
+//!   },
+//!   "errors": []
+//! }
+//! ```
+//!
+//! Nodes have a `kind`, `range` and `fields`, tokens have a `kind`, `range`
+//! and `text`. Fields come from `postgresql.ungram` and are named like the
+//! accessors on the `ast` nodes, e.g. `targets` or `select_token`, except
+//! `type` which is `ty` in Rust. Missing fields are left out. Ranges are
+//! UTF-8 byte offsets into the source.
+//!
+//! Fields are added as the grammar grows. [`VERSION`] changes when fields
+//! are renamed or removed, or the layout of the export changes.
+
+use serde_json::{Map, Value, json};
+
+use crate::{
+    Parse, SourceFile, SyntaxNode, SyntaxToken,
+    ast::{self, AstChildren, AstNode},
+    generated,
+};
+
+pub const VERSION: u32 = 1;
+
+/// The AST and syntax errors of a parsed file.
+pub fn to_json(parse: &Parse<SourceFile>) -> Value {
+    let errors = parse
+        .errors()
+        .iter()
+        .map(|error| {
+            json!({
+                "message": error.message(),
+                "range": range(error.range()),
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "version": VERSION,
+        "root": node_to_json(&parse.syntax_node()),
+        "errors": errors,
+    })
+}
+
+/// A single node and its fields, see the module docs for the format.
+pub fn node_to_json(node: &SyntaxNode) -> Value {
+    let mut fields = Fields { map: Map::new() };
+    generated::json::fields(node, &mut fields);
+    json!({
+        "kind": format!("{:?}", node.kind()),
+        "range": range(node.text_range()),
+        "fields": fields.map,
+    })
+}
+
+fn token_to_json(token: &SyntaxToken) -> Value {
+    json!({
+        "kind": format!("{:?}", token.kind()),
+        "range": range(token.text_range()),
+        "text": token.text(),
+    })
+}
+
+fn range(range: rowan::TextRange) -> Value {
+    json!([u32::from(range.start()), u32::from(range.end())])
+}
+
+pub(crate) struct Fields {
+    map: Map<String, Value>,
+}
+
+impl Fields {
+    pub(crate) fn add(&mut self, name: &str, value: impl FieldValue) {
+        if let Some(value) = value.to_json() {
+            self.map.insert(name.to_owned(), value);
+        }
+    }
+}
+
+pub(crate) trait FieldValue {
+    fn to_json(self) -> Option<Value>;
+}
+
+impl<N: AstNode> FieldValue for Option<N> {
+    fn to_json(self) -> Option<Value> {
+        self.map(|node| node_to_json(node.syntax()))
+    }
+}
+
+impl FieldValue for Option<SyntaxToken> {
+    fn to_json(self) -> Option<Value> {
+        self.as_ref().map(token_to_json)
+    }
+}
+
+impl<N: AstNode> FieldValue for AstChildren<N> {
+    fn to_json(self) -> Option<Value> {
+        let nodes = self
+            .map(|node| node_to_json(node.syntax()))
+            .collect::<Vec<_>>();
+        (!nodes.is_empty()).then_some(Value::Array(nodes))
+    }
+}
+
+// Operators are either a token or a node, e.g. `=` or `is distinct from`.
+macro_rules! impl_op_field_value {
+    ($op:ident { tokens: [$($token:ident),*], nodes: [$($node:ident),*] }) => {
+        impl FieldValue for Option<ast::$op> {
+            fn to_json(self) -> Option<Value> {
+                let json = match self? {
+                    $(ast::$op::$token(token) => token_to_json(&token),)*
+                    $(ast::$op::$node(node) => node_to_json(node.syntax()),)*
+                };
+                Some(json)
+            }
+        }
+    };
+}
+
+impl_op_field_value!(BinOp {
+    tokens: [
+        And, Caret, ColonEq, Eq, Escape, FatArrow, Gteq, Ilike, In, Is, LAngle, Like, Lteq, Minus,
+        Neq, Neqb, Or, Overlaps, Percent, Plus, RAngle, Slash, Star
+    ],
+    nodes: [
+        AtTimeZone,
+        ColonColon,
+        CustomOp,
+        IsDistinctFrom,
+        IsNot,
+        IsNotDistinctFrom,
+        NotIlike,
+        NotIn,
+        NotLike,
+        NotSimilarTo,
+        OperatorCall,
+        SimilarTo
+    ]
+});
+
+impl_op_field_value!(PostfixOp {
+    tokens: [IsNull, NotNull],
+    nodes: [
+        AtLocal,
+        IsJson,
+        IsJsonArray,
+        IsJsonObject,
+        IsJsonScalar,
+        IsJsonValue,
+        IsNormalized,
+        IsNotJson,
+        IsNotJsonArray,
+        IsNotJsonObject,
+        IsNotJsonScalar,
+        IsNotJsonValue,
+        IsNotNormalized
+    ]
+});
+
+impl_op_field_value!(PrefixOp {
+    tokens: [Minus, Not, Plus],
+    nodes: [CustomOp, OperatorCall]
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::to_json;
+    use crate::SourceFile;
+
+    fn export(sql: &str) -> String {
+        serde_json::to_string_pretty(&to_json(&SourceFile::parse(sql))).unwrap()
+    }
+
+    #[test]
+    fn select() {
+        assert_snapshot!(export("select a + 1;"), @r#"
+        {
+          "errors": [],
+          "root": {
+            "fields": {
+              "stmts": [
+                {
+                  "fields": {
+                    "select_clause": {
+                      "fields": {
+                        "select_token": {
+                          "kind": "SELECT_KW",
+                          "range": [
+                            0,
+                            6
+                          ],
+                          "text": "select"
+                        },
+                        "target_list": {
+                          "fields": {
+                            "targets": [
+                              {
+                                "fields": {
+                                  "expr": {
+                                    "fields": {
+                                      "lhs": {
+                                        "fields": {
+                                          "ident_token": {
+                                            "kind": "IDENT",
+                                            "range": [
+                                              7,
+                                              8
+                                            ],
+                                            "text": "a"
+                                          }
+                                        },
+                                        "kind": "NAME_REF",
+                                        "range": [
+                                          7,
+                                          8
+                                        ]
+                                      },
+                                      "op": {
+                                        "kind": "PLUS",
+                                        "range": [
+                                          9,
+                                          10
+                                        ],
+                                        "text": "+"
+                                      },
+                                      "rhs": {
+                                        "fields": {
+                                          "value": {
+                                            "kind": "INT_NUMBER",
+                                            "range": [
+                                              11,
+                                              12
+                                            ],
+                                            "text": "1"
+                                          }
+                                        },
+                                        "kind": "LITERAL",
+                                        "range": [
+                                          11,
+                                          12
+                                        ]
+                                      }
+                                    },
+                                    "kind": "BIN_EXPR",
+                                    "range": [
+                                      7,
+                                      12
+                                    ]
+                                  }
+                                },
+                                "kind": "TARGET",
+                                "range": [
+                                  7,
+                                  12
+                                ]
+                              }
+                            ]
+                          },
+                          "kind": "TARGET_LIST",
+                          "range": [
+                            7,
+                            12
+                          ]
+                        }
+                      },
+                      "kind": "SELECT_CLAUSE",
+                      "range": [
+                        0,
+                        12
+                      ]
+                    },
+                    "semicolon_token": {
+                      "kind": "SEMICOLON",
+                      "range": [
+                        12,
+                        13
+                      ],
+                      "text": ";"
+                    }
+                  },
+                  "kind": "SELECT",
+                  "range": [
+                    0,
+                    13
+                  ]
+                }
+              ]
+            },
+            "kind": "SOURCE_FILE",
+            "range": [
+              0,
+              13
+            ]
+          },
+          "version": 1
+        }
+        "#);
+    }
+
+    #[test]
+    fn errors() {
+        let json = to_json(&SourceFile::parse("select 1 +"));
+        assert_snapshot!(serde_json::to_string(&json["errors"]).unwrap(), @r#"[{"message":"expected an expression, found end of file","range":[10,10]}]"#);
+    }
+}
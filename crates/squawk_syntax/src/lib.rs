@@ -26,13 +26,19 @@
 
 pub mod ast;
 pub mod column_name;
+pub mod edit;
+pub mod fingerprint;
 mod generated;
+pub mod json;
 mod parsing;
+pub mod plpgsql;
 mod ptr;
 pub mod quote;
+pub mod syntax_editor;
 pub mod syntax_error;
 mod syntax_node;
 mod token_text;
+pub mod type_alias;
 pub mod unescape;
 mod validation;
 
@@ -41,11 +47,12 @@ mod test;
 
 use std::{marker::PhantomData, sync::Arc};
 
-pub use squawk_parser::SyntaxKind;
+pub use squawk_parser::{Dialect, SyntaxKind, Templating};
 
 use ast::AstNode;
+use edit::Edit;
 pub use ptr::{AstPtr, SyntaxNodePtr};
-use rowan::GreenNode;
+use rowan::{GreenNode, TextSize};
 use syntax_error::SyntaxError;
 pub use syntax_node::{SyntaxElement, SyntaxNode, SyntaxToken};
 pub use token_text::TokenText;
@@ -90,12 +97,32 @@ impl<T> Parse<T> {
     }
 
     pub fn errors(&self) -> Vec<SyntaxError> {
+        self.validate(None, Dialect::Postgres)
+    }
+
+    /// Like [`Parse::errors`], but also reports syntax that's newer than the
+    /// target Postgres major version, e.g. `merge` before 15.
+    pub fn errors_for_version(&self, pg_version: i32) -> Vec<SyntaxError> {
+        self.validate(Some(pg_version), Dialect::Postgres)
+    }
+
+    /// Like [`Parse::errors_for_version`], but also reports features that
+    /// `dialect` doesn't support, e.g. triggers on Aurora DSQL.
+    pub fn errors_for_dialect(
+        &self,
+        pg_version: Option<i32>,
+        dialect: Dialect,
+    ) -> Vec<SyntaxError> {
+        self.validate(pg_version, dialect)
+    }
+
+    fn validate(&self, pg_version: Option<i32>, dialect: Dialect) -> Vec<SyntaxError> {
         let mut errors = if let Some(e) = self.errors.as_deref() {
             e.to_vec()
         } else {
             vec![]
         };
-        validation::validate(&self.syntax_node(), &mut errors);
+        validation::validate(&self.syntax_node(), pg_version, dialect, &mut errors);
         errors.sort_by_key(|error| error.range().start());
         errors
     }
@@ -149,7 +176,24 @@ pub use crate::ast::SourceFile;
 
 impl SourceFile {
     pub fn parse(text: &str) -> Parse<SourceFile> {
-        let (green, errors) = parsing::parse_text(text);
+        SourceFile::parse_with(text, None)
+    }
+
+    /// Parses SQL that's rendered by a template engine before it's run,
+    /// e.g., a dbt model. Template expressions are parsed as identifiers and
+    /// template tags as comments.
+    pub fn parse_with(text: &str, templating: Option<Templating>) -> Parse<SourceFile> {
+        SourceFile::parse_dialect(text, templating, Dialect::Postgres)
+    }
+
+    /// Parses SQL for a Postgres compatible database, accepting the grammar
+    /// extensions of `dialect`.
+    pub fn parse_dialect(
+        text: &str,
+        templating: Option<Templating>,
+        dialect: Dialect,
+    ) -> Parse<SourceFile> {
+        let (green, errors) = parsing::parse_text(text, templating, dialect);
         let root = SyntaxNode::new_root(green.clone());
 
         assert_eq!(root.kind(), SyntaxKind::SOURCE_FILE);
@@ -157,6 +201,53 @@ impl SourceFile {
     }
 }
 
+impl Parse<SourceFile> {
+    /// The parse of the text after `edit`.
+    ///
+    /// When the edit stays within a single token or statement only that part
+    /// is reparsed, otherwise we parse the whole file again.
+    ///
+    /// `dialect` should be the one the file was parsed with.
+    pub fn reparse(&self, edit: &Edit, dialect: Dialect) -> Parse<SourceFile> {
+        self.incremental_reparse(edit, dialect)
+            .unwrap_or_else(|| self.full_reparse(edit, dialect))
+    }
+
+    fn incremental_reparse(&self, edit: &Edit, dialect: Dialect) -> Option<Parse<SourceFile>> {
+        let errors = self.errors.as_deref().unwrap_or_default().iter().cloned();
+        parsing::incremental_reparse(&self.syntax_node(), edit, errors, dialect)
+            .map(|(green_node, errors, _reparsed_range)| Parse::new(green_node, errors))
+    }
+
+    fn full_reparse(&self, edit: &Edit, dialect: Dialect) -> Parse<SourceFile> {
+        let mut text = self.syntax_node().text().to_string();
+        edit.apply(&mut text);
+        SourceFile::parse_dialect(&text, None, dialect)
+    }
+}
+
+impl ast::PlpgsqlRoot {
+    /// Parses the text of a PL/pgSQL function body or `DO` block, without the
+    /// surrounding quotes.
+    pub fn parse(text: &str) -> Parse<ast::PlpgsqlRoot> {
+        let (green, errors) = parsing::parse_plpgsql_text(text);
+        let root = SyntaxNode::new_root(green.clone());
+
+        assert_eq!(root.kind(), SyntaxKind::PLPGSQL_ROOT);
+        Parse::new(green, errors)
+    }
+
+    /// Like [`ast::PlpgsqlRoot::parse`], for a body that starts at `offset` in
+    /// a file, so ranges in the tree are ranges in the file.
+    pub(crate) fn parse_at(text: &str, offset: TextSize) -> Parse<ast::PlpgsqlRoot> {
+        let (green, errors) = parsing::parse_plpgsql_text_at(text, offset);
+        let root = SyntaxNode::new_root(green.clone());
+
+        assert_eq!(root.kind(), SyntaxKind::PLPGSQL_ROOT);
+        Parse::new(green, errors)
+    }
+}
+
 /// Matches a `SyntaxNode` against an `ast` type.
 ///
 /// # Example:
@@ -24,18 +24,63 @@
 // IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
 // DEALINGS IN THE SOFTWARE.
 
-use rowan::{GreenNode, TextRange};
+mod reparsing;
 
-use crate::{syntax_error::SyntaxError, syntax_node::SyntaxTreeBuilder};
+use rowan::{GreenNode, GreenToken, NodeOrToken, TextRange, TextSize};
 
-pub(crate) fn parse_text(text: &str) -> (GreenNode, Vec<SyntaxError>) {
+use crate::{SyntaxKind, syntax_error::SyntaxError, syntax_node::SyntaxTreeBuilder};
+
+pub(crate) use reparsing::incremental_reparse;
+
+pub(crate) fn parse_text(
+    text: &str,
+    templating: Option<squawk_parser::Templating>,
+    dialect: squawk_parser::Dialect,
+) -> (GreenNode, Vec<SyntaxError>) {
+    let lexed = squawk_parser::LexedStr::with_templating(text, templating);
+    let parser_input = lexed.to_input();
+    let parser_output = squawk_parser::parse_dialect(&parser_input, dialect);
+    let (node, errors, _eof) = build_tree(lexed, parser_output);
+    (node, errors)
+}
+
+pub(crate) fn parse_plpgsql_text(text: &str) -> (GreenNode, Vec<SyntaxError>) {
     let lexed = squawk_parser::LexedStr::new(text);
     let parser_input = lexed.to_input();
-    let parser_output = squawk_parser::parse(&parser_input);
+    let parser_output = squawk_parser::parse_plpgsql(&parser_input);
     let (node, errors, _eof) = build_tree(lexed, parser_output);
     (node, errors)
 }
 
+/// Like [`parse_plpgsql_text`], for a body that starts at `offset` in a file.
+/// The tree starts with whitespace up to `offset` so its ranges are the
+/// file's ranges.
+pub(crate) fn parse_plpgsql_text_at(text: &str, offset: TextSize) -> (GreenNode, Vec<SyntaxError>) {
+    // The padding tokens are shared, so this stays cheap for bodies deep into
+    // a large file.
+    const CHUNK: usize = 4096;
+    let (green, errors) = parse_plpgsql_text(text);
+    let len = usize::from(offset);
+    let whitespace = rowan::SyntaxKind(SyntaxKind::WHITESPACE.into());
+    let chunk = GreenToken::new(whitespace, &" ".repeat(CHUNK));
+    let mut padding = vec![NodeOrToken::Token(chunk); len / CHUNK];
+    if len % CHUNK != 0 {
+        padding.push(NodeOrToken::Token(GreenToken::new(
+            whitespace,
+            &" ".repeat(len % CHUNK),
+        )));
+    }
+    let green = green.splice_children(0..0, padding);
+    let errors = errors
+        .into_iter()
+        .map(|err| {
+            let range = err.range() + offset;
+            err.with_range(range)
+        })
+        .collect();
+    (green, errors)
+}
+
 pub(crate) fn build_tree(
     lexed: squawk_parser::LexedStr<'_>,
     parser_output: squawk_parser::Output,
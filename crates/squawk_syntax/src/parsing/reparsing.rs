@@ -0,0 +1,535 @@
+// via https://github.com/rust-lang/rust-analyzer/blob/d8887c0758bbd2d5f752d5bd405d4491e90e7ed6/crates/syntax/src/parsing/reparsing.rs
+//
+// Permission is hereby granted, free of charge, to any
+// person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the
+// Software without restriction, including without
+// limitation the rights to use, copy, modify, merge,
+// publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software
+// is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice
+// shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF
+// ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A
+// PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR
+// IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+//! Implementation of incremental re-parsing.
+//!
+//! We use two simple strategies for this:
+//!   - if the edit modifies only a single token (like changing an identifier's
+//!     letter), we replace only this token.
+//!   - otherwise, we search for the statement that encloses the edit and
+//!     reparse only that statement.
+//!
+//! Anything else falls back to parsing the whole file.
+
+use rowan::{GreenNode, GreenToken, NodeOrToken, TextRange, TextSize};
+use squawk_parser::LexedStr;
+
+use crate::{Dialect, SyntaxError, SyntaxKind, SyntaxNode, edit::Edit, parsing::parse_text};
+
+pub(crate) fn incremental_reparse(
+    node: &SyntaxNode,
+    edit: &Edit,
+    errors: impl IntoIterator<Item = SyntaxError>,
+    dialect: Dialect,
+) -> Option<(GreenNode, Vec<SyntaxError>, TextRange)> {
+    if !node.text_range().contains_range(edit.text_range) {
+        return None;
+    }
+
+    let errors = errors.into_iter().collect::<Vec<_>>();
+    // Parser errors quote the token after them, e.g., ``expected `;`, found
+    // `foo` ``, and can suggest fixes based on the token after that, so they'd
+    // be stale after editing those tokens.
+    if errors.iter().any(|error| {
+        quoted_range(node, error.range().start())
+            .is_some_and(|range| range.intersect(edit.text_range).is_some())
+    }) {
+        return None;
+    }
+
+    if let Some((green, new_errors, old_range)) = reparse_token(node, edit) {
+        return Some((
+            green,
+            merge_errors(errors, new_errors, old_range, edit),
+            old_range,
+        ));
+    }
+
+    if let Some((green, new_errors, old_range)) = reparse_stmt(node, edit, dialect) {
+        // Parser errors are empty ranges, so when one sits right at the start
+        // or end of the statement we can't tell if it came from this statement
+        // or its neighbour.
+        if errors.iter().any(|error| {
+            error.range().is_empty()
+                && (error.range().start() == old_range.start()
+                    || error.range().start() == old_range.end())
+        }) {
+            return None;
+        }
+        return Some((
+            green,
+            merge_errors(errors, new_errors, old_range, edit),
+            old_range,
+        ));
+    }
+    None
+}
+
+/// The range from `offset` to the end of the second non-trivia token after it.
+fn quoted_range(root: &SyntaxNode, offset: TextSize) -> Option<TextRange> {
+    let mut token = root.token_at_offset(offset).right_biased()?;
+    let mut seen = 0;
+    loop {
+        if !token.kind().is_trivia() {
+            seen += 1;
+        }
+        if seen == 2 {
+            break;
+        }
+        match token.next_token() {
+            Some(next) => token = next,
+            None => break,
+        }
+    }
+    Some(TextRange::new(offset, token.text_range().end()))
+}
+
+fn reparse_token(
+    root: &SyntaxNode,
+    edit: &Edit,
+) -> Option<(GreenNode, Vec<SyntaxError>, TextRange)> {
+    let prev_token = root.covering_element(edit.text_range).as_token()?.clone();
+    let prev_token_kind = prev_token.kind();
+    match prev_token_kind {
+        SyntaxKind::WHITESPACE | SyntaxKind::COMMENT | SyntaxKind::IDENT | SyntaxKind::STRING => {
+            if prev_token_kind == SyntaxKind::WHITESPACE || prev_token_kind == SyntaxKind::COMMENT {
+                // removing a new line may extend the previous token, e.g., a
+                // line comment or a psql meta-command
+                let deleted_range = edit.text_range - prev_token.text_range().start();
+                if prev_token.text()[deleted_range].contains(['\n', '\r']) {
+                    return None;
+                }
+            }
+
+            let mut new_text = get_text_after_edit(prev_token.clone().into(), edit);
+            let (new_token_kind, new_err) = LexedStr::single_token(&new_text)?;
+
+            if new_token_kind != prev_token_kind {
+                return None;
+            }
+
+            // PL/pgSQL keywords are lexed as identifiers, so changing one
+            // changes how the statement parses.
+            if new_token_kind == SyntaxKind::IDENT
+                && (SyntaxKind::from_contextual_keyword(&new_text).is_some()
+                    || SyntaxKind::from_contextual_keyword(prev_token.text()).is_some())
+            {
+                return None;
+            }
+
+            // Check that edited token is not a part of the bigger token.
+            // E.g. if for source code `bruh"str"` the user removed `ruh`, then
+            // `b` no longer remains an identifier, but becomes a part of byte string literal
+            let text = root.text();
+            if let Some(next_char) = text.char_at(prev_token.text_range().end()) {
+                new_text.push(next_char);
+                let token_with_next_char = LexedStr::single_token(&new_text);
+                if token_with_next_char.is_some() {
+                    return None;
+                }
+                new_text.pop();
+            }
+            if let Some(prev_char) = prev_token
+                .text_range()
+                .start()
+                .checked_sub(TextSize::new(1))
+                .and_then(|offset| text.char_at(offset))
+            {
+                let token_with_prev_char = format!("{prev_char}{new_text}");
+                if LexedStr::single_token(&token_with_prev_char).is_some() {
+                    return None;
+                }
+            }
+
+            let new_token = GreenToken::new(rowan::SyntaxKind(prev_token_kind.into()), &new_text);
+            let range = TextRange::up_to(TextSize::of(&new_text));
+            Some((
+                prev_token.replace_with(new_token),
+                new_err
+                    .into_iter()
+                    .map(|msg| SyntaxError::new(msg, range))
+                    .collect(),
+                prev_token.text_range(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn reparse_stmt(
+    root: &SyntaxNode,
+    edit: &Edit,
+    dialect: Dialect,
+) -> Option<(GreenNode, Vec<SyntaxError>, TextRange)> {
+    let stmt = find_reparsable_stmt(root, edit.text_range)?;
+    let text = get_text_after_edit(stmt.clone().into(), edit);
+
+    let (green, new_errors) = parse_text(&text, None, dialect);
+
+    // The statement has to parse to a single statement that still ends with a
+    // semicolon, otherwise the edit could have moved the statement boundaries.
+    let new_root = SyntaxNode::new_root(green);
+    let mut children = new_root.children_with_tokens();
+    let (Some(NodeOrToken::Node(new_stmt)), None) = (children.next(), children.next()) else {
+        return None;
+    };
+    if !ends_with_semicolon(&new_stmt) {
+        return None;
+    }
+    // whether the lines after a `COPY ... FROM STDIN;` are rows or SQL depends
+    // on the statement
+    if stmt.kind() == SyntaxKind::COPY || new_stmt.kind() == SyntaxKind::COPY {
+        return None;
+    }
+
+    // unlike `replace_with`, the statement is allowed to change kind
+    let green = root.green().replace_child(
+        stmt.index(),
+        NodeOrToken::Node(new_stmt.green().into_owned()),
+    );
+    Some((green, new_errors, stmt.text_range()))
+}
+
+/// The top level statement containing the edit, if the edit doesn't touch its
+/// start or end.
+fn find_reparsable_stmt(root: &SyntaxNode, range: TextRange) -> Option<SyntaxNode> {
+    let covering = root.covering_element(range);
+    let stmt = covering
+        .ancestors()
+        .find(|node| node.parent().as_ref() == Some(root))?;
+
+    let stmt_range = stmt.text_range();
+    if range.start() <= stmt_range.start() || range.end() >= stmt_range.end() {
+        return None;
+    }
+
+    // without a semicolon, where the statement ends depends on what follows
+    if !ends_with_semicolon(&stmt) {
+        return None;
+    }
+
+    Some(stmt)
+}
+
+// The semicolon has to be the statement's own, if it's nested then error
+// recovery consumed it, e.g., while looking for a `)`, and would keep going
+// past it.
+fn ends_with_semicolon(stmt: &SyntaxNode) -> bool {
+    stmt.last_token().is_some_and(|token| {
+        token.kind() == SyntaxKind::SEMICOLON && token.parent().as_ref() == Some(stmt)
+    })
+}
+
+fn get_text_after_edit(element: crate::SyntaxElement, edit: &Edit) -> String {
+    let edit_range = edit.text_range - element.text_range().start();
+
+    let mut text = match element {
+        NodeOrToken::Token(token) => token.text().to_owned(),
+        NodeOrToken::Node(node) => node.text().to_string(),
+    };
+    text.replace_range(
+        std::ops::Range::<usize>::from(edit_range),
+        edit.text.as_deref().unwrap_or(""),
+    );
+    text
+}
+
+fn merge_errors(
+    old_errors: impl IntoIterator<Item = SyntaxError>,
+    new_errors: Vec<SyntaxError>,
+    range_before_reparse: TextRange,
+    edit: &Edit,
+) -> Vec<SyntaxError> {
+    let mut res = Vec::new();
+
+    let inserted_len = TextSize::of(edit.text.as_deref().unwrap_or(""));
+    for old_err in old_errors {
+        let old_err_range = old_err.range();
+        if old_err_range.end() <= range_before_reparse.start() {
+            res.push(old_err);
+        } else if old_err_range.start() >= range_before_reparse.end() {
+            res.push(old_err.with_range((old_err_range + inserted_len) - edit.text_range.len()));
+        }
+    }
+    res.extend(new_errors.into_iter().map(|new_err| {
+        let offsetted_range = new_err.range() + range_before_reparse.start();
+        new_err.with_range(offsetted_range)
+    }));
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use camino::Utf8Path;
+    use rowan::{TextRange, TextSize};
+
+    use super::*;
+    use crate::{Parse, SourceFile};
+
+    fn do_check(before: &str, replace_with: &str, reparsed_len: u32) {
+        let (range, before) = extract_range(before);
+        let edit = Edit::replace(range, replace_with);
+        let after = {
+            let mut after = before.clone();
+            edit.apply(&mut after);
+            after
+        };
+
+        let fully_reparsed = SourceFile::parse(&after);
+        let incrementally_reparsed: Parse<SourceFile> = {
+            let before = SourceFile::parse(&before);
+            let (green, new_errors, range) = incremental_reparse(
+                &before.syntax_node(),
+                &edit,
+                before.errors(),
+                Dialect::Postgres,
+            )
+            .unwrap();
+            assert_eq!(
+                range.len(),
+                reparsed_len.into(),
+                "reparsed fragment has wrong length"
+            );
+            Parse::new(green, new_errors)
+        };
+
+        assert_eq!(
+            format!("{:#?}", fully_reparsed.syntax_node()),
+            format!("{:#?}", incrementally_reparsed.syntax_node()),
+        );
+        assert_eq!(fully_reparsed.errors(), incrementally_reparsed.errors());
+    }
+
+    fn do_check_full(before: &str, replace_with: &str) {
+        let (range, before) = extract_range(before);
+        let edit = Edit::replace(range, replace_with);
+        let before = SourceFile::parse(&before);
+        assert!(
+            incremental_reparse(
+                &before.syntax_node(),
+                &edit,
+                before.errors(),
+                Dialect::Postgres
+            )
+            .is_none()
+        );
+    }
+
+    // `$0` marks the start and end of the range to replace
+    fn extract_range(text: &str) -> (TextRange, String) {
+        let start = text.find("$0").unwrap();
+        let text = text.replacen("$0", "", 1);
+        let end = text.find("$0").unwrap();
+        let text = text.replacen("$0", "", 1);
+        (
+            TextRange::new(TextSize::new(start as u32), TextSize::new(end as u32)),
+            text,
+        )
+    }
+
+    #[test]
+    fn reparse_token() {
+        do_check("select $0foo$0 from t;", "bar", 3);
+        do_check("select 'a$0b$0c' from t;", "x", 5);
+        do_check("select 1; -- $0hello$0\nselect 2;", "bye", 8);
+        do_check("select 1;$0 $0  select 2;", "\n\n", 3);
+        do_check("select a /* $0one$0 */ from t;", "two", 9);
+    }
+
+    #[test]
+    fn reparse_stmt() {
+        do_check("select 1;\nselect a, $0b$0 from t;\nselect 3;", "b + 1", 19);
+        do_check(
+            "select 1;\ncreate table t(a int$0$0);\nselect 3;",
+            ", b text",
+            22,
+        );
+        do_check("select 1;\nselect 2 $0from t$0;\n", "", 16);
+        // a keyword isn't an identifier so we can't just replace the token
+        do_check("select $0foo$0 from t;", "1", 18);
+        // the identifier merges with the following string
+        do_check("select $0foo$0'a';", "e", 14);
+    }
+
+    #[test]
+    fn reparse_stmt_with_dialect() {
+        let (range, before) = extract_range("select 1;\ncreate index $0$0t_a_idx on t (a);\n");
+        let edit = Edit::replace(range, "async ");
+        let mut after = before.clone();
+        edit.apply(&mut after);
+
+        let before = SourceFile::parse_dialect(&before, None, Dialect::AuroraDsql);
+        let reparsed = before.reparse(&edit, Dialect::AuroraDsql);
+        let fully_reparsed = SourceFile::parse_dialect(&after, None, Dialect::AuroraDsql);
+        assert!(reparsed.errors().is_empty());
+        assert_eq!(
+            format!("{:#?}", fully_reparsed.syntax_node()),
+            format!("{:#?}", reparsed.syntax_node()),
+        );
+    }
+
+    #[test]
+    fn reparse_falls_back_to_full_parse() {
+        // the line comment now continues onto the next line
+        do_check_full("select 1; -- a$0\n$0select 2;", "");
+        // the string now swallows the semicolon
+        do_check_full("select 1;\nselect $0a$0;\nselect 3;", "'a");
+        // the statement's missing a semicolon
+        do_check_full("select 1;\nselect $0a$0\n", "b + 1");
+        // splits the statement in two
+        do_check_full("select 1;\nselect a$0$0 from t;\n", "; select b");
+        // the next lines are no longer rows
+        do_check_full("copy t from $0stdin$0;\n1\n\\.\n", "stdout");
+        // the next lines are now rows
+        do_check_full("cop$0x$0 t from stdin;\n1\n\\.\n", "y");
+    }
+
+    #[test]
+    fn reparse_matches_full_parse_for_random_edits() {
+        const FRAGMENTS: &[&str] = &[
+            "a",
+            "x1",
+            " ",
+            "\n",
+            "\r\n",
+            ";",
+            "'",
+            "\"",
+            "--",
+            "/*",
+            "*/",
+            "$$",
+            "$a$",
+            "(",
+            ")",
+            ",",
+            ".",
+            "::",
+            "select",
+            "from",
+            "1",
+            "e'",
+            "b'",
+            "\\",
+            "\\i f.sql\n",
+            ":a",
+        ];
+
+        let dir = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("../squawk_parser/tests/data/ok");
+        let mut paths = dir
+            .read_dir_utf8()
+            .unwrap()
+            .map(|entry| entry.unwrap().into_path())
+            .filter(|path| path.extension() == Some("sql"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let mut rng = oorandom::Rand32::new(42);
+        let mut incremental = 0;
+        for path in paths {
+            let mut text = std::fs::read_to_string(&path).unwrap();
+            let mut parse = SourceFile::parse(&text);
+            for _ in 0..25 {
+                let start = random_char_boundary(&mut rng, &text);
+                let end = if rng.rand_range(0..3) == 0 {
+                    start
+                } else {
+                    let len = rng.rand_range(0..16) as usize;
+                    let mut end = (start + len).min(text.len());
+                    while !text.is_char_boundary(end) {
+                        end += 1;
+                    }
+                    end
+                };
+                let insert = if rng.rand_range(0..3) == 0 {
+                    ""
+                } else {
+                    FRAGMENTS[rng.rand_range(0..FRAGMENTS.len() as u32) as usize]
+                };
+                let edit = Edit::replace(
+                    TextRange::new(TextSize::new(start as u32), TextSize::new(end as u32)),
+                    insert,
+                );
+
+                if parse
+                    .incremental_reparse(&edit, Dialect::Postgres)
+                    .is_some()
+                {
+                    incremental += 1;
+                }
+                let reparsed = parse.reparse(&edit, Dialect::Postgres);
+                edit.apply(&mut text);
+                let fully_reparsed = SourceFile::parse(&text);
+
+                let expected = format!("{:#?}", fully_reparsed.syntax_node());
+                let actual = format!("{:#?}", reparsed.syntax_node());
+                // the trees are large, so only show the first line that differs
+                if let Some((expected, actual)) = expected
+                    .split('\n')
+                    .zip(actual.split('\n'))
+                    .find(|(expected, actual)| expected != actual)
+                {
+                    panic!(
+                        "{path}: reparse after {edit:?} differs from a full parse\nexpected: {expected}\nactual: {actual}"
+                    );
+                }
+                assert_eq!(expected.len(), actual.len());
+                assert_eq!(
+                    sorted_errors(&fully_reparsed),
+                    sorted_errors(&reparsed),
+                    "{path}: reparse errors after {edit:?} differ from a full parse"
+                );
+                parse = reparsed;
+            }
+        }
+        // make sure we're testing the incremental paths and not just the
+        // fallback
+        assert!(
+            incremental > 1000,
+            "only {incremental} incremental reparses"
+        );
+    }
+
+    fn random_char_boundary(rng: &mut oorandom::Rand32, text: &str) -> usize {
+        let mut offset = rng.rand_range(0..text.len() as u32 + 1) as usize;
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+
+    fn sorted_errors(parse: &Parse<SourceFile>) -> Vec<SyntaxError> {
+        let mut errors = parse.errors();
+        errors.sort_by_key(|error| {
+            (
+                error.range().start(),
+                error.range().end(),
+                error.to_string(),
+            )
+        });
+        errors
+    }
+}
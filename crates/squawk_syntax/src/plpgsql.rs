@@ -0,0 +1,264 @@
+//! PL/pgSQL function bodies and `DO` blocks are string literals in the SQL
+//! tree. We parse their contents separately, into trees whose ranges line up
+//! with the file.
+
+use rowan::{TextRange, TextSize};
+
+use crate::{
+    Parse, SyntaxKind, SyntaxNode,
+    ast::{self, AstNode},
+    quote::{dollar_quote_tag, strip_dollar_quotes, strip_quotes},
+    syntax_error::SyntaxError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlpgsqlBody {
+    literal: ast::Literal,
+    offset: TextSize,
+    parse: Parse<ast::PlpgsqlRoot>,
+}
+
+impl PlpgsqlBody {
+    /// Parses the string literal of a function body or `DO` block.
+    ///
+    /// Only dollar-quoted strings and plain strings without doubled quotes
+    /// are supported, since otherwise offsets in the body wouldn't line up
+    /// with the file.
+    pub fn from_literal(literal: ast::Literal) -> Option<PlpgsqlBody> {
+        let token = literal.syntax().first_token()?;
+        let text = token.text();
+        let (body, start) = match token.kind() {
+            SyntaxKind::DOLLAR_QUOTED_STRING => {
+                let tag = dollar_quote_tag(text)?;
+                (strip_dollar_quotes(text)?, tag.len() + 2)
+            }
+            SyntaxKind::STRING => {
+                let body = strip_quotes(text)?;
+                if body.contains('\'') {
+                    return None;
+                }
+                (body, 1)
+            }
+            _ => return None,
+        };
+        let offset = token.text_range().start() + TextSize::new(start as u32);
+        Some(PlpgsqlBody {
+            parse: ast::PlpgsqlRoot::parse_at(body, offset),
+            literal,
+            offset,
+        })
+    }
+
+    /// The string literal containing the body.
+    pub fn literal(&self) -> &ast::Literal {
+        &self.literal
+    }
+
+    /// Where the body's text starts in the file, after the opening quote.
+    pub fn offset(&self) -> TextSize {
+        self.offset
+    }
+
+    pub fn parse(&self) -> &Parse<ast::PlpgsqlRoot> {
+        &self.parse
+    }
+
+    /// The body's tree, its ranges are ranges in the file.
+    pub fn tree(&self) -> ast::PlpgsqlRoot {
+        self.parse.tree()
+    }
+
+    /// The range of the body's text in the file, without the quotes.
+    pub fn text_range(&self) -> TextRange {
+        TextRange::new(self.offset, self.tree().syntax().text_range().end())
+    }
+
+    pub fn errors(&self) -> Vec<SyntaxError> {
+        self.parse.errors()
+    }
+}
+
+fn is_plpgsql(language: Option<String>) -> bool {
+    language.is_some_and(|language| language.eq_ignore_ascii_case("plpgsql"))
+}
+
+fn language_name(
+    language_ref: Option<ast::LanguageRef>,
+    literal: Option<ast::Literal>,
+) -> Option<String> {
+    if let Some(language_ref) = language_ref {
+        return Some(ast::normalize_name_node(language_ref.syntax()));
+    }
+    let literal = literal?;
+    let token = literal.syntax().first_token()?;
+    strip_quotes(token.text()).map(|name| name.to_owned())
+}
+
+fn func_option_list_body(option_list: Option<ast::FuncOptionList>) -> Option<PlpgsqlBody> {
+    let option_list = option_list?;
+    let mut language = None;
+    let mut body = None;
+    for option in option_list.options() {
+        match option {
+            ast::FuncOption::LanguageFuncOption(option) => {
+                language = language_name(option.language_ref(), option.literal());
+            }
+            ast::FuncOption::AsFuncOption(option) => {
+                if let Some(ast::AsFuncTarget::AsDefinition(definition)) = option.as_func_target() {
+                    body = definition.literal();
+                }
+            }
+            _ => (),
+        }
+    }
+    if !is_plpgsql(language) {
+        return None;
+    }
+    PlpgsqlBody::from_literal(body?)
+}
+
+impl ast::Do {
+    /// The block's body, when it's written in PL/pgSQL, the default language.
+    pub fn plpgsql_body(&self) -> Option<PlpgsqlBody> {
+        let language = match self.do_language() {
+            Some(do_language) => language_name(do_language.language_ref(), do_language.literal()),
+            None => Some("plpgsql".to_owned()),
+        };
+        if !is_plpgsql(language) {
+            return None;
+        }
+        PlpgsqlBody::from_literal(self.body()?)
+    }
+}
+
+impl ast::CreateFunction {
+    /// The function's body, when it's written in PL/pgSQL.
+    pub fn plpgsql_body(&self) -> Option<PlpgsqlBody> {
+        func_option_list_body(self.option_list())
+    }
+}
+
+impl ast::CreateProcedure {
+    /// The procedure's body, when it's written in PL/pgSQL.
+    pub fn plpgsql_body(&self) -> Option<PlpgsqlBody> {
+        func_option_list_body(self.option_list())
+    }
+}
+
+fn stmt_body(node: SyntaxNode) -> Option<PlpgsqlBody> {
+    if let Some(do_stmt) = ast::Do::cast(node.clone()) {
+        do_stmt.plpgsql_body()
+    } else if let Some(create_function) = ast::CreateFunction::cast(node.clone()) {
+        create_function.plpgsql_body()
+    } else {
+        ast::CreateProcedure::cast(node)?.plpgsql_body()
+    }
+}
+
+impl ast::SourceFile {
+    /// Syntax errors inside the file's PL/pgSQL bodies.
+    pub fn plpgsql_errors(&self) -> Vec<SyntaxError> {
+        self.plpgsql_bodies()
+            .flat_map(|body| body.errors())
+            .collect()
+    }
+
+    /// Bodies of the file's `DO` blocks and PL/pgSQL functions and procedures.
+    pub fn plpgsql_bodies(&self) -> impl Iterator<Item = PlpgsqlBody> {
+        self.syntax().descendants().filter_map(stmt_body)
+    }
+
+    /// The PL/pgSQL body whose text contains `offset`, only parsing that body.
+    pub fn plpgsql_body_at(&self, offset: TextSize) -> Option<PlpgsqlBody> {
+        let token = self.syntax().token_at_offset(offset).find(|token| {
+            matches!(
+                token.kind(),
+                SyntaxKind::STRING | SyntaxKind::DOLLAR_QUOTED_STRING
+            )
+        })?;
+        let literal = ast::Literal::cast(token.parent()?)?;
+        let body = literal.syntax().ancestors().find_map(stmt_body)?;
+        (body.literal() == &literal && body.text_range().contains_inclusive(offset)).then_some(body)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use crate::ast::{self, AstNode, SourceFile};
+
+    fn bodies(sql: &str) -> String {
+        let file = SourceFile::parse(sql).tree();
+        file.plpgsql_bodies()
+            .map(|body| {
+                let mut out = format!("{:?}\n", &sql[body.text_range()]);
+                for err in body.errors() {
+                    out.push_str(&format!("  {:?} {}\n", &sql[err.range()], err.message()));
+                }
+                out
+            })
+            .collect()
+    }
+
+    #[test]
+    fn do_blocks() {
+        assert_snapshot!(bodies(r#"
+do $$ begin perform 1; end $$;
+do $body$ begin null; end $body$ language plpgsql;
+do 'begin null; end';
+do 'begin raise notice ''hi''; end';
+do language plpython3u $$ print(1) $$;
+"#), @r#"
+        " begin perform 1; end "
+        " begin null; end "
+        "begin null; end"
+        "#);
+    }
+
+    #[test]
+    fn ranges_in_file() {
+        let sql = format!("-- {}\ndo $$ begin drop table t; end $$;", "x".repeat(5000));
+        let file = SourceFile::parse(&sql).tree();
+        let body = file.plpgsql_bodies().next().unwrap();
+        let stmt = body
+            .tree()
+            .syntax()
+            .descendants()
+            .find_map(ast::PlpgsqlSqlStmt::cast)
+            .unwrap();
+        assert_eq!(&sql[stmt.syntax().text_range()], "drop table t;");
+    }
+
+    #[test]
+    fn body_at() {
+        let sql = "select 'a';\ndo $$ begin drop table t; end $$;";
+        let file = SourceFile::parse(sql).tree();
+        let body_text = |offset: usize| {
+            file.plpgsql_body_at((offset as u32).into())
+                .map(|body| &sql[body.text_range()])
+        };
+        assert_eq!(body_text(8), None);
+        assert_eq!(body_text(15), None);
+        assert_eq!(body_text(20), Some(" begin drop table t; end "));
+        assert_eq!(body_text(42), Some(" begin drop table t; end "));
+        assert_eq!(body_text(44), None);
+    }
+
+    #[test]
+    fn functions() {
+        assert_snapshot!(bodies(r#"
+create function f() returns int language plpgsql as $$
+begin
+  return 1
+end;
+$$;
+create function g() returns int as $$ select 1 $$ language sql;
+create procedure p() language 'plpgsql' as $$ begin commit; end $$;
+"#), @r#"
+        "\nbegin\n  return 1\nend;\n"
+          "" expected `;`, found `end`
+        " begin commit; end "
+        "#);
+    }
+}
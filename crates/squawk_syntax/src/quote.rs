@@ -1,11 +1,13 @@
 use crate::SyntaxNode;
-use crate::generated::keywords::{AS_LABEL_KEYWORDS, RESERVED_KEYWORDS, TYPE_FUNC_NAME_KEYWORDS};
+use crate::generated::keywords::{
+    AS_LABEL_KEYWORDS, COL_NAME_KEYWORDS, RESERVED_KEYWORDS, TYPE_FUNC_NAME_KEYWORDS,
+};
 
 pub fn quote_string_literal(text: &str) -> String {
     format!("'{}'", text.replace('\'', "''"))
 }
 
-fn quote(text: &str) -> String {
+pub(crate) fn quote(text: &str) -> String {
     format!(r#""{}""#, text.replace('"', r#""""#))
 }
 
@@ -34,15 +36,18 @@ pub fn quote_ident(text: &str) -> String {
 }
 
 pub fn unquote_ident(node: &SyntaxNode) -> Option<String> {
-    let text = node.text().to_string();
-
-    if !text.starts_with('"') || !text.ends_with('"') {
-        return None;
-    }
+    unquote_ident_text(&node.text().to_string()).map(str::to_string)
+}
 
-    let text = &text[1..text.len() - 1];
+/// The identifier without quotes, when dropping them doesn't change what it
+/// refers to, e.g., `"foo"` but not `"Foo"` or `"select"`.
+///
+/// Like Postgres' `quote_identifier`, we keep quotes on every keyword that
+/// isn't unreserved, since `"char"` and `char` are different types.
+pub fn unquote_ident_text(text: &str) -> Option<&str> {
+    let text = text.strip_prefix('"')?.strip_suffix('"')?;
 
-    if is_reserved_word(text) || is_type_func_name_word(text) {
+    if is_reserved_word(text) || is_type_func_name_word(text) || is_col_name_word(text) {
         return None;
     }
 
@@ -65,7 +70,7 @@ pub fn unquote_ident(node: &SyntaxNode) -> Option<String> {
         return None;
     }
 
-    Some(text.to_string())
+    Some(text)
 }
 
 pub fn needs_quoting(text: &str) -> bool {
@@ -106,6 +111,12 @@ fn is_type_func_name_word(text: &str) -> bool {
         .is_ok()
 }
 
+fn is_col_name_word(text: &str) -> bool {
+    COL_NAME_KEYWORDS
+        .binary_search(&text.to_ascii_lowercase().as_str())
+        .is_ok()
+}
+
 fn is_as_label_word(text: &str) -> bool {
     AS_LABEL_KEYWORDS
         .binary_search(&text.to_ascii_lowercase().as_str())
@@ -212,6 +223,18 @@ mod tests {
         assert_snapshot!(quote_ident("join"), @r#""join""#);
     }
 
+    #[test]
+    fn unquote_ident_text_keeps_quotes_on_keywords() {
+        assert_eq!(unquote_ident_text(r#""users""#), Some("users"));
+        // unreserved
+        assert_eq!(unquote_ident_text(r#""data""#), Some("data"));
+        // col name
+        assert_eq!(unquote_ident_text(r#""char""#), None);
+        // reserved
+        assert_eq!(unquote_ident_text(r#""select""#), None);
+        assert_eq!(unquote_ident_text("users"), None);
+    }
+
     #[test]
     fn quote_ident_quotes_names_that_dont_fold_to_themselves() {
         assert_snapshot!(quote_ident("Mixed"), @r#""Mixed""#);
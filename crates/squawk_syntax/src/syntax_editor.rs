@@ -0,0 +1,306 @@
+//! Records changes to a syntax tree and turns them into text edits.
+//!
+//! Similar to rust-analyzer's `SyntaxEditor`, but since fixes and code actions
+//! only need the edits, we don't build a new tree. Changes are made with nodes
+//! from [`crate::ast::make`] or the tree itself, so we don't have to build up
+//! replacement text by hand.
+//!
+//! ```ignore
+//! let mut editor = SyntaxEditor::new(file.syntax().clone());
+//! editor.replace(cast_expr, make::cast_expr(expr, ty));
+//! let edits = editor.finish();
+//! ```
+
+use std::ops::RangeInclusive;
+
+use rowan::{NodeOrToken, TextRange, TextSize};
+use squawk_line_index::{LineEnding, find_newline};
+
+use crate::{
+    SyntaxElement, SyntaxNode, SyntaxToken,
+    ast::AstNode,
+    edit::{Edit, minimal_edit},
+};
+
+#[derive(Debug)]
+pub struct SyntaxEditor {
+    root: SyntaxNode,
+    changes: Vec<Change>,
+}
+
+#[derive(Debug)]
+struct Change {
+    range: TextRange,
+    text: String,
+}
+
+impl SyntaxEditor {
+    /// Creates an editor for the tree at `root`, usually the `SourceFile`.
+    pub fn new(root: SyntaxNode) -> SyntaxEditor {
+        SyntaxEditor {
+            root,
+            changes: vec![],
+        }
+    }
+
+    pub fn root(&self) -> &SyntaxNode {
+        &self.root
+    }
+
+    pub fn insert(&mut self, position: Position, element: impl Element) {
+        self.insert_all(position, vec![element.syntax_element()]);
+    }
+
+    pub fn insert_all(&mut self, position: Position, elements: Vec<SyntaxElement>) {
+        self.change(TextRange::empty(position.offset), &elements);
+    }
+
+    pub fn delete(&mut self, element: impl Element) {
+        let element = element.syntax_element();
+        self.check_in_tree(&element);
+        self.change(element.text_range(), &[]);
+    }
+
+    /// Deletes everything from the start of the first element to the end of
+    /// the last.
+    pub fn delete_all(&mut self, range: RangeInclusive<SyntaxElement>) {
+        self.replace_all(range, vec![]);
+    }
+
+    pub fn replace(&mut self, old: impl Element, new: impl Element) {
+        self.replace_with_many(old, vec![new.syntax_element()]);
+    }
+
+    pub fn replace_with_many(&mut self, old: impl Element, new: Vec<SyntaxElement>) {
+        let old = old.syntax_element();
+        self.check_in_tree(&old);
+        self.change(old.text_range(), &new);
+    }
+
+    /// Replaces everything from the start of the first element to the end of
+    /// the last.
+    pub fn replace_all(&mut self, range: RangeInclusive<SyntaxElement>, new: Vec<SyntaxElement>) {
+        let (first, last) = range.into_inner();
+        self.check_in_tree(&first);
+        self.check_in_tree(&last);
+        debug_assert!(
+            first.text_range().start() <= last.text_range().start(),
+            "range is backwards"
+        );
+        let range = first.text_range().cover(last.text_range());
+        self.change(range, &new);
+    }
+
+    /// The edits for the recorded changes, relative to the original text.
+    ///
+    /// Touching changes are merged and unchanged text at the start and end of
+    /// each replacement is trimmed, so we don't rewrite more than we need to.
+    /// Newlines in new text use the tree's line ending.
+    pub fn finish(self) -> Vec<Edit> {
+        let text = self.root.text().to_string();
+        let line_ending = find_newline(&text)
+            .map(|(_, line_ending)| line_ending)
+            .unwrap_or_default();
+        let root_start = self.root.text_range().start();
+
+        let mut changes = self.changes;
+        // stable, so insertions at the same offset stay in the order they were made
+        changes.sort_by_key(|change| (change.range.start(), change.range.end()));
+
+        let mut merged: Vec<Change> = vec![];
+        for change in changes {
+            if let Some(last) = merged.last_mut() {
+                assert!(
+                    last.range.end() <= change.range.start(),
+                    "overlapping changes: {:?} and {:?}",
+                    last.range,
+                    change.range
+                );
+                if last.range.end() == change.range.start() {
+                    last.range = last.range.cover(change.range);
+                    last.text.push_str(&change.text);
+                    continue;
+                }
+            }
+            merged.push(change);
+        }
+
+        merged
+            .into_iter()
+            .filter_map(|change| {
+                let new = with_line_ending(&change.text, line_ending);
+                let old = &text[change.range - root_start];
+                minimal_edit(change.range, old, &new)
+            })
+            .collect()
+    }
+
+    fn change(&mut self, range: TextRange, elements: &[SyntaxElement]) {
+        let text = elements.iter().map(|element| element.to_string()).collect();
+        self.changes.push(Change { range, text });
+    }
+
+    fn check_in_tree(&self, element: &SyntaxElement) {
+        let parent = match element {
+            NodeOrToken::Node(node) => Some(node.clone()),
+            NodeOrToken::Token(token) => token.parent(),
+        };
+        debug_assert!(
+            parent.is_some_and(|parent| parent.ancestors().any(|node| node == self.root)),
+            "element isn't part of the editor's tree: {element:?}"
+        );
+    }
+}
+
+/// Where to insert new elements.
+#[derive(Debug, Clone, Copy)]
+pub struct Position {
+    offset: TextSize,
+}
+
+impl Position {
+    pub fn before(element: impl Element) -> Position {
+        Position {
+            offset: element.syntax_element().text_range().start(),
+        }
+    }
+
+    pub fn after(element: impl Element) -> Position {
+        Position {
+            offset: element.syntax_element().text_range().end(),
+        }
+    }
+}
+
+pub trait Element {
+    fn syntax_element(self) -> SyntaxElement;
+}
+
+impl<N: AstNode> Element for N {
+    fn syntax_element(self) -> SyntaxElement {
+        self.syntax().clone().into()
+    }
+}
+
+impl Element for SyntaxNode {
+    fn syntax_element(self) -> SyntaxElement {
+        self.into()
+    }
+}
+
+impl Element for SyntaxToken {
+    fn syntax_element(self) -> SyntaxElement {
+        self.into()
+    }
+}
+
+impl Element for SyntaxElement {
+    fn syntax_element(self) -> SyntaxElement {
+        self
+    }
+}
+
+// Nodes from `make` always use `\n`, text copied from the tree already has
+// the right line ending.
+fn with_line_ending(text: &str, line_ending: LineEnding) -> String {
+    if line_ending == LineEnding::Lf {
+        return text.to_owned();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut prev = None;
+    for c in text.chars() {
+        if c == '\n' && prev != Some('\r') {
+            out.push_str(line_ending.as_str());
+        } else {
+            out.push(c);
+        }
+        prev = Some(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+    use crate::ast::{self, make};
+
+    fn apply(text: &str, edits: &[Edit]) -> String {
+        let mut result = text.to_owned();
+        for edit in edits.iter().rev() {
+            result.replace_range(
+                std::ops::Range::<usize>::from(edit.text_range),
+                edit.text.as_deref().unwrap_or(""),
+            );
+        }
+        result
+    }
+
+    fn find<N: AstNode>(root: &SyntaxNode) -> N {
+        root.descendants().find_map(N::cast).unwrap()
+    }
+
+    #[test]
+    fn trims_unchanged_text() {
+        let file = ast::SourceFile::parse("select a != b;").tree();
+        let bin_expr: ast::BinExpr = find(file.syntax());
+        let mut editor = SyntaxEditor::new(file.syntax().clone());
+        let (lhs, rhs) = (bin_expr.lhs().unwrap(), bin_expr.rhs().unwrap());
+        editor.replace(bin_expr, make::bin_expr(lhs, "<>", rhs));
+        let edits = editor.finish();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].text_range, TextRange::new(9.into(), 11.into()));
+        assert_snapshot!(apply("select a != b;", &edits), @"select a <> b;");
+    }
+
+    #[test]
+    fn merges_touching_changes() {
+        let sql = "select a from t;";
+        let file = ast::SourceFile::parse(sql).tree();
+        let target: ast::Target = find(file.syntax());
+        let mut editor = SyntaxEditor::new(file.syntax().clone());
+        editor.insert_all(
+            Position::after(target.clone()),
+            vec![
+                make::tokens::single_space().into(),
+                make::as_name("b").syntax().clone().into(),
+            ],
+        );
+        editor.replace(target, make::target(make::number_literal("1").into(), None));
+        let edits = editor.finish();
+        assert_eq!(edits.len(), 1);
+        assert_snapshot!(apply(sql, &edits), @"select 1 as b from t;");
+    }
+
+    #[test]
+    fn uses_the_files_line_ending() {
+        let sql = "values (1), (2);\r\n";
+        let file = ast::SourceFile::parse(sql).tree();
+        let values: ast::Values = find(file.syntax());
+        let selects = values.row_list().unwrap().rows().map(|row| {
+            let targets = row.exprs().map(|expr| make::target(expr, None));
+            make::select(targets, None)
+        });
+        let mut editor = SyntaxEditor::new(file.syntax().clone());
+        editor.replace_all(
+            values.values_token().unwrap().into()
+                ..=values.row_list().unwrap().syntax().clone().into(),
+            vec![make::union_all(selects).syntax().clone().into()],
+        );
+        assert_snapshot!(apply(sql, &editor.finish()).replace('\r', "\\r"), @r#"
+        select 1\r
+        union all\r
+        select 2;\r
+        "#);
+    }
+
+    #[test]
+    fn no_edits_for_unchanged_tree() {
+        let file = ast::SourceFile::parse("select 1;").tree();
+        let literal: ast::Literal = find(file.syntax());
+        let mut editor = SyntaxEditor::new(file.syntax().clone());
+        editor.replace(literal, make::number_literal("1"));
+        assert!(editor.finish().is_empty());
+    }
+}
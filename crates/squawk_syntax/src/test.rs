@@ -4,7 +4,7 @@ use camino::Utf8Path;
 use dir_test::{Fixture, dir_test};
 use insta::{assert_snapshot, with_settings};
 
-use crate::{SourceFile, syntax_error::SyntaxError};
+use crate::{Dialect, SourceFile, Templating, syntax_error::SyntaxError};
 
 fn render_errors(sql: &str, errors: &[SyntaxError]) -> String {
     let mut rendered = String::new();
@@ -97,6 +97,38 @@ fn regression_suite_validation(fixture: Fixture<&str>) {
     );
 }
 
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/../../postgres/plpgsql",
+    glob: "*.sql",
+)]
+fn plpgsql_regression_suite(fixture: Fixture<&str>) {
+    let content = fixture.content();
+    let absolute_fixture_path = Utf8Path::new(fixture.path());
+    let input_file = absolute_fixture_path;
+    let test_name = absolute_fixture_path
+        .file_name()
+        .and_then(|x| x.strip_suffix(".sql"))
+        .unwrap();
+
+    let parse = SourceFile::parse(content);
+    let mut errors = vec![];
+    for body in parse.tree().plpgsql_bodies() {
+        errors.extend(body.errors());
+    }
+
+    // The suite includes bodies that Postgres rejects, so we snapshot the
+    // errors instead of asserting there are none.
+    with_settings!({
+      omit_expression => true,
+      input_file => input_file,
+    }, {
+      assert_snapshot!(
+        format!("plpgsql_regression_{test_name}"),
+        render_errors(content, &errors)
+      );
+    });
+}
+
 #[dir_test(
     dir: "$CARGO_MANIFEST_DIR/test_data",
     glob: "**/*.sql",
@@ -157,8 +189,123 @@ fn parse_errors_are_sorted_by_position() {
         .collect::<Vec<_>>()
         .join("\n");
 
-    assert_snapshot!(rendered, @r"
+    assert_snapshot!(rendered, @"
     0..6: Leading from clauses are not supported in Postgres
-    17..17: expected an expression, found SEMICOLON
+    17..17: expected an expression, found `;`
+    ");
+}
+
+#[test]
+fn errors_for_version() {
+    let sql = "
+merge into t using s on t.id = s.id
+  when not matched by source then delete
+  returning *;
+create table t (
+  a int unique nulls not distinct,
+  b int generated always as (a * 2),
+  c int generated always as (a * 2) stored,
+  d int check (d > 0) not enforced
+);
+alter table t split partition p into (partition p1 for values from (1) to (2), partition p2 for values from (2) to (3));
+select json_object('a': 1), x is json, 0x1f, 1_000
+from json_table('[]', '$[*]' columns (a int path '$.a'));
+";
+    let parse = SourceFile::parse(sql);
+    assert_eq!(parse.errors(), vec![]);
+    assert_snapshot!(render_errors(sql, &parse.errors_for_version(14)));
+}
+
+#[test]
+fn exponent_literals_allowed_before_16() {
+    let sql = "select 0e5, 0E1, 0.5e3, 0X1F;";
+    let parse = SourceFile::parse(sql);
+    assert_eq!(parse.errors(), vec![]);
+    assert_snapshot!(render_errors(sql, &parse.errors_for_version(15)), @"
+    error[syntax-error]: non-decimal integer literal requires PostgreSQL 16
+      ╭▸ 
+    1 │ select 0e5, 0E1, 0.5e3, 0X1F;
+      ╰╴                        ━━━━
+    ");
+}
+
+#[test]
+fn errors_for_dialect() {
+    let sql = "
+create sequence s;
+create table t (
+  id bigserial primary key,
+  user_id bigint references users (id)
+);
+create index async t_user_id_idx on t (user_id);
+create index concurrently on t (id);
+create trigger tr before insert on t execute function f();
+truncate t;
+";
+    let parse = SourceFile::parse_dialect(sql, None, Dialect::AuroraDsql);
+    assert_eq!(parse.errors(), vec![]);
+    assert_snapshot!(render_errors(
+        sql,
+        &parse.errors_for_dialect(None, Dialect::AuroraDsql)
+    ));
+
+    let sql = "
+create domain d as int check (value > 0);
+create table t (c circle, exclude using gist (c with &&));
+listen c;
+";
+    let parse = SourceFile::parse(sql);
+    assert_eq!(parse.errors(), vec![]);
+    assert_snapshot!(render_errors(
+        sql,
+        &parse.errors_for_dialect(None, Dialect::CockroachDb)
+    ));
+}
+
+#[test]
+fn templated_sql() {
+    let sql = "
+{{ config(materialized='incremental') }}
+{% set cutoff = '2024-01-01' %}
+select u.id, {{ dbt_utils.star(ref('orders')) }}
+from {{ ref('users') }} as u
+join {{ source('shop', 'orders') }} o on o.user_id = u.id
+{% if is_incremental() %}
+where u.updated_at > (select max(updated_at) from {{ this }})
+{% endif %};
+";
+    assert_eq!(
+        SourceFile::parse_with(sql, Some(Templating::Jinja)).errors(),
+        vec![]
+    );
+
+    let sql = "
+select $__timeGroup(time, '5m') as time, avg(value)
+from metrics
+where $__timeFilter(time) and region in (${region:sqlstring}) and host = $host
+group by 1;
+";
+    assert_eq!(
+        SourceFile::parse_with(sql, Some(Templating::Grafana)).errors(),
+        vec![]
+    );
+}
+
+#[test]
+fn templated_sql_errors() {
+    let sql = "
+select {{ col }} from {{ ref('users') }} where;
+select {{ oops from t;
+";
+    let parse = SourceFile::parse_with(sql, Some(Templating::Jinja));
+    assert_snapshot!(render_errors(sql, &parse.errors()), @"
+    error[syntax-error]: expected an expression, found `;`
+      ╭▸ 
+    2 │ select {{ col }} from {{ ref('users') }} where;
+      ╰╴                                              ━
+    error[syntax-error]: Unterminated template expression
+      ╭▸ 
+    3 │ select {{ oops from t;
+      ╰╴       ━━━━━━━━━━━━━━━
     ");
 }
@@ -0,0 +1,274 @@
+use crate::ast::{self, AstNode, make};
+use crate::{SyntaxKind, SyntaxToken};
+
+/// Builtin types that have more than one name, e.g., `int4`, `int`, and
+/// `integer`.
+///
+/// see: <https://www.postgresql.org/docs/18/datatype.html#DATATYPE-TABLE>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BuiltinType {
+    SmallInt,
+    Integer,
+    BigInt,
+    SmallSerial,
+    Serial,
+    BigSerial,
+    Real,
+    DoublePrecision,
+    Numeric,
+    Boolean,
+    Char,
+    Varchar,
+    Varbit,
+    Time,
+    TimeTz,
+    Timestamp,
+    TimestampTz,
+}
+
+impl BuiltinType {
+    const ALL: [BuiltinType; 17] = [
+        BuiltinType::SmallInt,
+        BuiltinType::Integer,
+        BuiltinType::BigInt,
+        BuiltinType::SmallSerial,
+        BuiltinType::Serial,
+        BuiltinType::BigSerial,
+        BuiltinType::Real,
+        BuiltinType::DoublePrecision,
+        BuiltinType::Numeric,
+        BuiltinType::Boolean,
+        BuiltinType::Char,
+        BuiltinType::Varchar,
+        BuiltinType::Varbit,
+        BuiltinType::Time,
+        BuiltinType::TimeTz,
+        BuiltinType::Timestamp,
+        BuiltinType::TimestampTz,
+    ];
+
+    /// Every name of the type, starting with the canonical one.
+    pub fn names(self) -> &'static [&'static str] {
+        match self {
+            BuiltinType::SmallInt => &["smallint", "int2"],
+            BuiltinType::Integer => &["integer", "int", "int4"],
+            BuiltinType::BigInt => &["bigint", "int8"],
+            BuiltinType::SmallSerial => &["smallserial", "serial2"],
+            BuiltinType::Serial => &["serial", "serial4"],
+            BuiltinType::BigSerial => &["bigserial", "serial8"],
+            BuiltinType::Real => &["real", "float4"],
+            BuiltinType::DoublePrecision => &["double precision", "float8"],
+            BuiltinType::Numeric => &["numeric", "decimal", "dec"],
+            BuiltinType::Boolean => &["boolean", "bool"],
+            BuiltinType::Char => &["char", "character"],
+            BuiltinType::Varchar => &["varchar", "character varying", "char varying"],
+            BuiltinType::Varbit => &["varbit", "bit varying"],
+            BuiltinType::Time => &["time", "time without time zone"],
+            BuiltinType::TimeTz => &["timetz", "time with time zone"],
+            BuiltinType::Timestamp => &["timestamp", "timestamp without time zone"],
+            BuiltinType::TimestampTz => &["timestamptz", "timestamp with time zone"],
+        }
+    }
+
+    pub fn canonical_name(self) -> &'static str {
+        self.names()[0]
+    }
+
+    /// Finds the type from any of its names, ignoring case and extra
+    /// whitespace, e.g., `Character  Varying`.
+    pub fn from_name(name: &str) -> Option<(BuiltinType, &'static str)> {
+        let name = name
+            .split_whitespace()
+            .map(|word| word.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Self::ALL.into_iter().find_map(|ty| {
+            ty.names()
+                .iter()
+                .find(|candidate| **candidate == name)
+                .map(|candidate| (ty, *candidate))
+        })
+    }
+}
+
+/// A builtin type along with the tokens that spell its name, e.g., the
+/// `timestamp`, `with`, `time`, and `zone` of `timestamp(3) with time zone`.
+#[derive(Debug, Clone)]
+pub struct BuiltinTypeName {
+    pub ty: BuiltinType,
+    pub name: &'static str,
+    pub tokens: Vec<SyntaxToken>,
+}
+
+/// The builtin type `ty` refers to, when it's spelled with one of the names
+/// we know about.
+///
+/// Quoted and schema qualified names are left out since they skip the
+/// grammar's aliases, e.g., `"char"` is the single byte internal type, not
+/// `character`, and `pg_catalog.integer` isn't a valid type. So are types
+/// with comments between their words, since we'd lose them when renaming.
+pub fn builtin_type_name(ty: &ast::Type) -> Option<BuiltinTypeName> {
+    if !matches!(
+        ty,
+        ast::Type::PathType(_)
+            | ast::Type::DoubleType(_)
+            | ast::Type::CharacterType(_)
+            | ast::Type::VarcharType(_)
+            | ast::Type::BitVaryingType(_)
+            | ast::Type::TimeType(_)
+            | ast::Type::TimestampType(_)
+    ) {
+        return None;
+    }
+    let mut tokens = vec![];
+    for token in ty
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|el| el.into_token())
+    {
+        match token.kind() {
+            SyntaxKind::COMMENT | SyntaxKind::DOT => return None,
+            SyntaxKind::IDENT if token.text().starts_with('"') => return None,
+            SyntaxKind::WHITESPACE
+            | SyntaxKind::SETOF_KW
+            | SyntaxKind::L_PAREN
+            | SyntaxKind::R_PAREN => (),
+            _ if token
+                .parent_ancestors()
+                .any(|node| matches!(node.kind(), SyntaxKind::ARG_LIST | SyntaxKind::LITERAL)) => {}
+            _ => tokens.push(token),
+        }
+    }
+    let name = tokens
+        .iter()
+        .map(|token| token.text())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let (builtin, name) = BuiltinType::from_name(&name)?;
+    Some(BuiltinTypeName {
+        ty: builtin,
+        name,
+        tokens,
+    })
+}
+
+/// Renames the builtin type, keeping its arguments, e.g., `character
+/// varying(10)` becomes `varchar(10)`.
+pub fn rename_builtin_type(ty: &ast::Type, name: &str) -> ast::Type {
+    let mut text = String::new();
+    let (setof, args) = match ty {
+        ast::Type::PathType(ty) => (
+            ty.setof_token(),
+            ty.arg_list().map(|x| x.syntax().to_string()),
+        ),
+        ast::Type::CharacterType(ty) => (
+            ty.setof_token(),
+            ty.arg_list().map(|x| x.syntax().to_string()),
+        ),
+        ast::Type::VarcharType(ty) => (
+            ty.setof_token(),
+            ty.arg_list().map(|x| x.syntax().to_string()),
+        ),
+        ast::Type::BitVaryingType(ty) => (
+            ty.setof_token(),
+            ty.arg_list().map(|x| x.syntax().to_string()),
+        ),
+        ast::Type::TimeType(ty) => (
+            ty.setof_token(),
+            ty.literal().map(|x| format!("({})", x.syntax())),
+        ),
+        ast::Type::TimestampType(ty) => (
+            ty.setof_token(),
+            ty.literal().map(|x| format!("({})", x.syntax())),
+        ),
+        ast::Type::DoubleType(ty) => (ty.setof_token(), None),
+        _ => (None, None),
+    };
+    if setof.is_some() {
+        text.push_str("setof ");
+    }
+    text.push_str(name);
+    // `time with time zone(3)` isn't valid, the precision goes after `time`
+    if let Some(args) = args {
+        match name.split_once(' ') {
+            Some((first, rest)) if matches!(first, "time" | "timestamp") => {
+                text.truncate(text.len() - name.len());
+                text.push_str(&format!("{first}{args} {rest}"));
+            }
+            _ => text.push_str(&args),
+        }
+    }
+    make::ty(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+    use crate::ast;
+
+    fn rename(sql: &str, name: &str) -> String {
+        let ty = make::ty(sql);
+        let builtin = builtin_type_name(&ty)
+            .map(|builtin| format!("{:?} {}", builtin.ty, builtin.name))
+            .unwrap_or_else(|| "not builtin".to_string());
+        format!("{builtin}: {}", rename_builtin_type(&ty, name).syntax())
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(
+            BuiltinType::from_name("INT4"),
+            Some((BuiltinType::Integer, "int4"))
+        );
+        assert_eq!(
+            BuiltinType::from_name("Character  Varying"),
+            Some((BuiltinType::Varchar, "character varying"))
+        );
+        assert_eq!(BuiltinType::from_name("text"), None);
+    }
+
+    #[test]
+    fn rename_path_type() {
+        assert_snapshot!(rename("int4", "integer"), @"Integer int4: integer");
+        assert_snapshot!(rename("setof bool", "boolean"), @"Boolean bool: setof boolean");
+        assert_snapshot!(rename("numeric(10, 2)", "decimal"), @"Numeric numeric: decimal(10, 2)");
+    }
+
+    #[test]
+    fn rename_keyword_types() {
+        assert_snapshot!(rename("character varying(100)", "varchar"), @"Varchar character varying: varchar(100)");
+        assert_snapshot!(rename("double precision", "float8"), @"DoublePrecision double precision: float8");
+        assert_snapshot!(rename("bit varying(3)", "varbit"), @"Varbit bit varying: varbit(3)");
+    }
+
+    #[test]
+    fn rename_time_types() {
+        assert_snapshot!(rename("timestamp(3) with time zone", "timestamptz"), @"TimestampTz timestamp with time zone: timestamptz(3)");
+        assert_snapshot!(rename("timetz(3)", "time with time zone"), @"TimeTz timetz: time(3) with time zone");
+        assert_snapshot!(rename("time without time zone", "time"), @"Time time without time zone: time");
+    }
+
+    #[test]
+    fn not_builtin() {
+        for sql in [
+            "text",
+            r#""int4""#,
+            "pg_catalog.int4",
+            "int4[]",
+            "timestamp /* utc */ with time zone",
+            "national character varying",
+        ] {
+            assert!(
+                builtin_type_name(&make::ty(sql)).is_none(),
+                "{sql} shouldn't be a builtin type name"
+            );
+        }
+        let ty: ast::Type = make::ty("int4[]");
+        let ast::Type::ArrayType(array) = ty else {
+            unreachable!()
+        };
+        assert!(builtin_type_name(&array.ty().unwrap()).is_some());
+    }
+}
@@ -10,8 +10,15 @@ use crate::ast::{AstNode, LitKind};
 use crate::unescape::{escape_unicode_esc_str, uescape_char};
 use crate::{SyntaxNode, SyntaxToken, ast, match_ast, syntax_error::SyntaxError};
 use rowan::{TextRange, TextSize};
+use squawk_parser::Dialect;
 use squawk_parser::SyntaxKind::*;
-pub(crate) fn validate(root: &SyntaxNode, errors: &mut Vec<SyntaxError>) {
+pub(crate) fn validate(
+    root: &SyntaxNode,
+    pg_version: Option<i32>,
+    dialect: Dialect,
+    errors: &mut Vec<SyntaxError>,
+) {
+    let psql_script = is_psql_script(root);
     for node in root.descendants() {
         match_ast! {
             match node {
@@ -29,7 +36,7 @@ pub(crate) fn validate(root: &SyntaxNode, errors: &mut Vec<SyntaxError>) {
                 ast::DropAggregate(it) => validate_drop_aggregate(it, errors),
                 ast::JoinExpr(it) => validate_join_expr(it, errors),
                 ast::Literal(it) => validate_literal(it, errors),
-                ast::NonStandardParam(it) => validate_non_standard_param(it, errors),
+                ast::NonStandardParam(it) => validate_non_standard_param(it, psql_script, errors),
                 ast::RuleStmtList(it) => validate_rule_stmt_list(it, errors),
                 ast::Select(it) => validate_select(it, errors),
                 ast::SelectInto(it) => validate_select_into(it, errors),
@@ -46,6 +53,169 @@ pub(crate) fn validate(root: &SyntaxNode, errors: &mut Vec<SyntaxError>) {
             validate_unicode_esc_ident(&token, errors);
         }
     }
+    if let Some(pg_version) = pg_version {
+        validate_pg_version(root, pg_version, errors);
+    }
+    if dialect != Dialect::Postgres {
+        validate_dialect(root, dialect, errors);
+    }
+}
+
+// Postgres features that other databases, which otherwise speak Postgres,
+// reject.
+fn validate_dialect(root: &SyntaxNode, dialect: Dialect, acc: &mut Vec<SyntaxError>) {
+    let mut unsupported = |feature: &str, range: Option<TextRange>| {
+        if let Some(range) = range {
+            acc.push(SyntaxError::new(
+                format!("{feature} isn't supported by {}", dialect.name()),
+                range,
+            ));
+        }
+    };
+    for node in root.descendants() {
+        let range = Some(node.text_range());
+        match dialect {
+            Dialect::Postgres => (),
+            Dialect::AuroraDsql => match_ast! {
+                match node {
+                    ast::CreateSequence(it) => unsupported("`create sequence`", token_range(it.create_token(), it.sequence_token())),
+                    ast::Column(it) => {
+                        if let Some(ty) = it.ty()
+                            && is_serial_type(&ty)
+                        {
+                            unsupported("`serial`", Some(ty.syntax().text_range()));
+                        }
+                    },
+                    ast::CreateTrigger(it) => unsupported("`create trigger`", token_range(it.create_token(), it.trigger_token())),
+                    ast::CreateEventTrigger(it) => unsupported("`create event trigger`", token_range(it.create_token(), it.trigger_token())),
+                    ast::ForeignKeyConstraint(_) => unsupported("foreign key", range),
+                    ast::ReferencesConstraint(_) => unsupported("foreign key", range),
+                    ast::CreateIndex(it) => {
+                        unsupported("`create index concurrently`", it.concurrently_token().map(|t| t.text_range()));
+                    },
+                    ast::CreateExtension(it) => unsupported("`create extension`", token_range(it.create_token(), it.extension_token())),
+                    ast::Truncate(it) => unsupported("`truncate`", it.truncate_token().map(|t| t.text_range())),
+                    ast::Temp(_) => unsupported("temporary table", range),
+                    ast::PartitionBy(_) => unsupported("partitioning", range),
+                    _ => (),
+                }
+            },
+            Dialect::CockroachDb => match_ast! {
+                match node {
+                    ast::CreateDomain(it) => unsupported("`create domain`", token_range(it.create_token(), it.domain_token())),
+                    ast::CreateRule(it) => unsupported("`create rule`", token_range(it.create_token(), it.rule_token())),
+                    ast::CreateEventTrigger(it) => unsupported("`create event trigger`", token_range(it.create_token(), it.trigger_token())),
+                    ast::CreateTablespace(it) => unsupported("`create tablespace`", token_range(it.create_token(), it.tablespace_token())),
+                    ast::ExcludeConstraint(_) => unsupported("exclusion constraint", range),
+                    ast::Listen(it) => unsupported("`listen`", it.listen_token().map(|t| t.text_range())),
+                    ast::Notify(it) => unsupported("`notify`", it.notify_token().map(|t| t.text_range())),
+                    _ => (),
+                }
+            },
+            Dialect::YugabyteDb => match_ast! {
+                match node {
+                    ast::ExcludeConstraint(_) => unsupported("exclusion constraint", range),
+                    ast::Listen(it) => unsupported("`listen`", it.listen_token().map(|t| t.text_range())),
+                    ast::Notify(it) => unsupported("`notify`", it.notify_token().map(|t| t.text_range())),
+                    _ => (),
+                }
+            },
+        }
+    }
+}
+
+fn is_serial_type(ty: &ast::Type) -> bool {
+    let ast::Type::PathType(path_type) = ty else {
+        return false;
+    };
+    path_type
+        .path_ref()
+        .and_then(|path| path.segment())
+        .is_some_and(|segment| {
+            matches!(
+                segment.text().to_ascii_lowercase().as_str(),
+                "serial" | "serial2" | "serial4" | "serial8" | "smallserial" | "bigserial"
+            )
+        })
+}
+
+// Syntax that older versions of Postgres reject.
+fn validate_pg_version(root: &SyntaxNode, pg_version: i32, acc: &mut Vec<SyntaxError>) {
+    let mut requires = |feature: &str, version: i32, range: Option<TextRange>| {
+        if pg_version < version
+            && let Some(range) = range
+        {
+            acc.push(SyntaxError::new(
+                format!("{feature} requires PostgreSQL {version}"),
+                range,
+            ));
+        }
+    };
+    for node in root.descendants() {
+        let range = Some(node.text_range());
+        match_ast! {
+            match node {
+                ast::Merge(it) => {
+                    requires("`merge`", 15, it.merge_token().map(|t| t.text_range()));
+                    if let Some(returning) = it.returning_clause() {
+                        requires("`merge ... returning`", 17, Some(returning.syntax().text_range()));
+                    }
+                },
+                ast::MergeWhenNotMatchedSource(_) => requires("`when not matched by source`", 17, range),
+                ast::ByTarget(_) => requires("`when not matched by target`", 17, range),
+                ast::NullsNotDistinct(_) => requires("`nulls not distinct`", 15, range),
+                ast::SplitPartition(it) => requires("`split partition`", 19, token_range(it.split_token(), it.partition_token())),
+                ast::MergePartitions(it) => requires("`merge partitions`", 19, token_range(it.merge_token(), it.partitions_token())),
+                ast::JsonTable(_) => requires("`json_table`", 17, range),
+                ast::JsonQueryFn(_) => requires("`json_query`", 17, range),
+                ast::JsonValueFn(_) => requires("`json_value`", 17, range),
+                ast::JsonExistsFn(_) => requires("`json_exists`", 17, range),
+                ast::JsonFn(_) => requires("`json()`", 17, range),
+                ast::JsonScalarFn(_) => requires("`json_scalar`", 17, range),
+                ast::JsonSerializeFn(_) => requires("`json_serialize`", 17, range),
+                ast::JsonObjectFn(_) => requires("`json_object`", 16, range),
+                ast::JsonArrayFn(_) => requires("`json_array`", 16, range),
+                ast::JsonObjectAggFn(_) => requires("`json_objectagg`", 16, range),
+                ast::JsonArrayAggFn(_) => requires("`json_arrayagg`", 16, range),
+                ast::IsJson(_) => requires("`is json`", 16, range),
+                ast::IsJsonValue(_) => requires("`is json`", 16, range),
+                ast::IsJsonObject(_) => requires("`is json`", 16, range),
+                ast::IsJsonArray(_) => requires("`is json`", 16, range),
+                ast::IsJsonScalar(_) => requires("`is json`", 16, range),
+                ast::GeneratedStored(it) => {
+                    // before 18, generated columns had to be `stored`
+                    if !matches!(it.generated_kind(), Some(ast::GeneratedKind::Stored(_))) {
+                        requires("virtual generated column", 18, range);
+                    }
+                },
+                ast::ReturningOptionList(_) => requires("`returning with (old/new as ...)`", 18, range),
+                ast::WithoutOverlaps(_) => requires("`without overlaps`", 18, range),
+                ast::PeriodColumn(_) => requires("`period` in foreign keys", 18, range),
+                ast::NotEnforced(_) => requires("`not enforced`", 18, range),
+                ast::Enforced(_) => requires("`enforced`", 18, range),
+                ast::Literal(it) => {
+                    let (is_int, token) = match it.kind() {
+                        Some(LitKind::IntNumber(token)) => (true, token),
+                        Some(LitKind::NumericNumber(token)) => (false, token),
+                        _ => continue,
+                    };
+                    let text = token.text();
+                    // 0x1F, 0o17, 0b101, but not exponents like 0e5
+                    let prefix = text.get(..2).map(str::to_ascii_lowercase);
+                    if is_int && text.len() > 2 && matches!(prefix.as_deref(), Some("0x" | "0o" | "0b")) {
+                        requires("non-decimal integer literal", 16, range);
+                    } else if text.contains('_') {
+                        requires("underscore in numeric literal", 16, range);
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+fn token_range(first: Option<SyntaxToken>, last: Option<SyntaxToken>) -> Option<TextRange> {
+    Some(first?.text_range().cover(last?.text_range()))
 }
 
 // an empty column list is only valid for a composite type:
@@ -118,6 +288,14 @@ fn validate_source_file(it: ast::SourceFile, acc: &mut Vec<SyntaxError>) {
         if ends_with_semi || next.syntax().kind() == EMPTY_STMT {
             continue;
         }
+        // e.g., `select 1 \gset`
+        let ends_with_meta_command = syntax
+            .next_sibling()
+            .and_then(ast::PsqlMetaCommand::cast)
+            .is_some_and(|meta_command| meta_command.sends_query());
+        if ends_with_meta_command {
+            continue;
+        }
         let end = syntax.text_range().end();
         acc.push(SyntaxError::new(
             "Missing semicolon between statements",
@@ -779,7 +957,25 @@ fn validate_aggregate_params(aggregate_params: Option<ast::ParamList>, acc: &mut
     }
 }
 
-fn validate_non_standard_param(param: ast::NonStandardParam, acc: &mut Vec<SyntaxError>) {
+// Scripts run with `psql -f` use `:name` for variables.
+fn is_psql_script(root: &SyntaxNode) -> bool {
+    ast::SourceFile::cast(root.clone())
+        .is_some_and(|file| file.psql_meta_commands().next().is_some())
+        || root.descendants_with_tokens().any(|element| {
+            element.into_token().is_some_and(|token| {
+                matches!(token.kind(), STRING | IDENT) && token.text().starts_with(':')
+            })
+        })
+}
+
+fn validate_non_standard_param(
+    param: ast::NonStandardParam,
+    psql_script: bool,
+    acc: &mut Vec<SyntaxError>,
+) {
+    if psql_script {
+        return;
+    }
     acc.push(SyntaxError::new(
         "Invalid parameter type. Use positional params like $1 instead.",
         param.syntax().text_range(),
@@ -0,0 +1,7 @@
+-- `\echo` doesn't send the query, so the statements run together
+select 1
+\echo hi
+select 2;
+
+select 3 \gset
+select 4;
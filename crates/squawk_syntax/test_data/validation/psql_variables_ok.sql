@@ -0,0 +1,2 @@
+\set id 1
+select * from t where id = :id and name = :'name';
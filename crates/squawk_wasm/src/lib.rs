@@ -146,6 +146,25 @@ impl SquawkDatabase {
         }
     }
 
+    /// Report syntax that isn't available in the given Postgres version, e.g. `"14"`.
+    pub fn set_pg_version(&mut self, pg_version: Option<String>) -> Result<(), Error> {
+        let pg_version = pg_version
+            .map(|v| v.parse::<squawk_linter::Version>().map_err(into_error))
+            .transpose()?;
+        db::set_pg_version(&mut self.db, pg_version.map(|v| v.major()));
+        Ok(())
+    }
+
+    /// Parse and lint for the given dialect, e.g. `"aurora-dsql"`, or
+    /// Postgres when `None`.
+    pub fn set_dialect(&mut self, dialect: Option<String>) -> Result<(), Error> {
+        let dialect = dialect
+            .map(|v| v.parse::<squawk_linter::Dialect>().map_err(into_error))
+            .transpose()?;
+        db::set_dialect(&mut self.db, dialect.unwrap_or_default());
+        Ok(())
+    }
+
     fn file(&self) -> Result<File, Error> {
         self.file
             .ok_or_else(|| Error::new("No file open. Call open_file first."))
@@ -157,6 +176,13 @@ impl SquawkDatabase {
         Ok(format!("{:#?}", parse.syntax_node()))
     }
 
+    /// The typed AST as JSON, see `squawk_syntax::json` for the format.
+    pub fn dump_json(&self) -> Result<String, Error> {
+        let file = self.file()?;
+        let parse = db::parse(&self.db, file);
+        serde_json::to_string(&squawk_syntax::json::to_json(&parse)).map_err(into_error)
+    }
+
     pub fn dump_tokens(&self) -> Result<String, Error> {
         let file = self.file()?;
         let content = file.content(&self.db);
@@ -176,8 +202,9 @@ impl SquawkDatabase {
         let file = self.file()?;
         let content = file.content(&self.db);
         let mut linter = squawk_linter::Linter::with_default_rules();
+        linter.settings.dialect = db::dialect(&self.db);
         let parse = db::parse(&self.db, file);
-        let parse_errors = parse.errors();
+        let parse_errors = db::syntax_errors(&self.db, file);
 
         let line_index = db::line_index(&self.db, file);
 
@@ -348,6 +375,65 @@ impl SquawkDatabase {
         serde_wasm_bindgen::to_value(&locations).map_err(into_error)
     }
 
+    pub fn prepare_rename(&self, line: u32, col: u32) -> Result<JsValue, Error> {
+        let file = self.file()?;
+        let position = position_to_offset(&self.db, file, line, col)?;
+        let range = squawk_ide::rename::prepare_rename(&self.db, position).map_err(into_error)?;
+        let line_index = db::line_index(&self.db, file);
+        let start = line_index.line_col(range.start());
+        let end = line_index.line_col(range.end());
+        let start_wide = line_index
+            .to_wide(squawk_line_index::WideEncoding::Utf16, start)
+            .unwrap();
+        let end_wide = line_index
+            .to_wide(squawk_line_index::WideEncoding::Utf16, end)
+            .unwrap();
+
+        let location = LocationRange {
+            file: file_string(&self.db, file).to_string(),
+            start_line: start_wide.line,
+            start_column: start_wide.col,
+            end_line: end_wide.line,
+            end_column: end_wide.col,
+        };
+
+        serde_wasm_bindgen::to_value(&location).map_err(into_error)
+    }
+
+    pub fn rename(&self, line: u32, col: u32, new_name: String) -> Result<JsValue, Error> {
+        let file = self.file()?;
+        let line_index = db::line_index(&self.db, file);
+        let position = position_to_offset(&self.db, file, line, col)?;
+        let changes =
+            squawk_ide::rename::rename(&self.db, position, &new_name).map_err(into_error)?;
+
+        let edits: Vec<TextEdit> = changes
+            .into_iter()
+            .filter(|change| change.file == file)
+            .flat_map(|change| change.edits)
+            .map(|edit| {
+                let start_pos = line_index.line_col(edit.text_range.start());
+                let end_pos = line_index.line_col(edit.text_range.end());
+                let start_wide = line_index
+                    .to_wide(squawk_line_index::WideEncoding::Utf16, start_pos)
+                    .unwrap();
+                let end_wide = line_index
+                    .to_wide(squawk_line_index::WideEncoding::Utf16, end_pos)
+                    .unwrap();
+
+                TextEdit {
+                    start_line_number: start_wide.line,
+                    start_column: start_wide.col,
+                    end_line_number: end_wide.line,
+                    end_column: end_wide.col,
+                    text: edit.text.unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        serde_wasm_bindgen::to_value(&edits).map_err(into_error)
+    }
+
     pub fn document_symbols(&self) -> Result<JsValue, Error> {
         let file = self.file()?;
         let line_index = db::line_index(&self.db, file);
@@ -587,6 +673,7 @@ impl SquawkDatabase {
                     squawk_ide::completion::CompletionItemKind::Type => "type",
                     squawk_ide::completion::CompletionItemKind::Snippet => "snippet",
                     squawk_ide::completion::CompletionItemKind::Operator => "operator",
+                    squawk_ide::completion::CompletionItemKind::Variable => "variable",
                 }
                 .to_string(),
                 detail: item.detail,
@@ -75,6 +75,10 @@ pub(crate) fn codegen() -> Result<()> {
     let ast_nodes_file = project_root().join("crates/squawk_syntax/src/ast/generated/nodes.rs");
     std::fs::write(ast_nodes_file, ast_nodes).context("problem writing generated nodes")?;
 
+    let json_fields = generate_json_fields(&ast_src.nodes);
+    let json_fields_file = project_root().join("crates/squawk_syntax/src/generated/json.rs");
+    std::fs::write(json_fields_file, json_fields).context("problem writing json fields")?;
+
     let keyword_kinds = keyword_kinds()?;
 
     let token_sets = generate_token_sets(&keyword_kinds)?;
@@ -106,6 +110,7 @@ pub(crate) fn codegen() -> Result<()> {
 pub(crate) struct KindsSrc {
     pub(crate) punct: &'static [(&'static str, &'static str)],
     pub(crate) keywords: &'static [&'static str],
+    pub(crate) contextual_keywords: &'static [&'static str],
     pub(crate) literals: &'static [&'static str],
     pub(crate) tokens: &'static [&'static str],
     pub(crate) nodes: &'static [&'static str],
@@ -113,6 +118,51 @@ pub(crate) struct KindsSrc {
 
 const TOKENS: &[&str] = &["ERROR", "WHITESPACE", "COMMENT"];
 
+/// Keywords of PL/pgSQL that aren't also SQL keywords.
+///
+/// The lexer never produces these, instead the PL/pgSQL parser checks for
+/// them on identifiers and remaps the token.
+///
+/// see: <https://github.com/postgres/postgres/blob/db0c96cc18aec417101e37e59fcc53d4bf647915/src/pl/plpgsql/src/pl_unreserved_kwlist.h>
+const PLPGSQL_KEYWORDS: &[&str] = &[
+    "alias",
+    "assert",
+    "constant",
+    "debug",
+    "diagnostics",
+    "elseif",
+    "elsif",
+    "exception",
+    "exit",
+    "foreach",
+    "get",
+    "info",
+    "log",
+    "loop",
+    "notice",
+    "open",
+    "perform",
+    "query",
+    "raise",
+    "reverse",
+    "rowtype",
+    "slice",
+    "sqlstate",
+    "stacked",
+    "warning",
+    "while",
+];
+
+/// Keywords that only some Postgres compatible databases have, see
+/// `squawk_parser::Dialect`.
+///
+/// Like the PL/pgSQL keywords, they're identifiers to the lexer and the parser
+/// remaps them when the dialect allows it.
+const DIALECT_KEYWORDS: &[&str] = &[
+    // aurora dsql: create index async
+    "async",
+];
+
 const EOF: &str = "EOF";
 const NAME_TOKEN: &str = "#name";
 const LITERAL_TOKEN: &str = "@";
@@ -199,6 +249,14 @@ fn generate_kind_src(
     keywords.extend(pg_keywords.into_iter().map(|s| &*s.leak()));
     keywords.sort();
     keywords.dedup();
+    let contextual_keywords = Vec::leak(
+        PLPGSQL_KEYWORDS
+            .iter()
+            .chain(DIALECT_KEYWORDS)
+            .copied()
+            .filter(|kw| !keywords.contains(kw))
+            .collect(),
+    );
 
     // we leak things here for simplicity, that way we don't have to deal with lifetimes
     // The execution is a one shot job so thats fine
@@ -224,6 +282,7 @@ fn generate_kind_src(
         punct: PUNCT,
         nodes,
         keywords,
+        contextual_keywords,
         literals,
         tokens,
     }
@@ -246,6 +305,7 @@ fn generate_keyword_arrays(keyword_kinds: &KeywordKinds) -> Result<String> {
     };
     let reserved_keywords = sorted(&keyword_kinds.reserved_keywords);
     let type_func_name_keywords = sorted(&keyword_kinds.type_func_name_keywords);
+    let col_name_keywords = sorted(&keyword_kinds.col_name_keywords);
     let as_label_keywords = sorted(&keyword_kinds.as_label_keywords);
 
     let output = reformat(
@@ -258,6 +318,10 @@ fn generate_keyword_arrays(keyword_kinds: &KeywordKinds) -> Result<String> {
                 #(#type_func_name_keywords),*
             ];
 
+            pub(crate) const COL_NAME_KEYWORDS: &[&str] = &[
+                #(#col_name_keywords),*
+            ];
+
             pub(crate) const AS_LABEL_KEYWORDS: &[&str] = &[
                 #(#as_label_keywords),*
             ];
@@ -293,13 +357,28 @@ fn generate_syntax_kinds(grammar: KindsSrc) -> Result<String> {
         })
         .collect::<Vec<_>>();
 
+    let contextual_conditions = grammar
+        .contextual_keywords
+        .iter()
+        .map(|keyword| {
+            let kind_ident = format_ident!("{}_KW", keyword.to_case(Case::UpperSnake));
+            quote! {
+                if ident.eq_ignore_ascii_case(#keyword) {
+                    return Some(SyntaxKind::#kind_ident);
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
     let punctuation = grammar
         .punct
         .iter()
         .map(|(_token, name)| format_ident!("{}", name))
         .collect::<Vec<_>>();
 
-    let all_keywords_values = grammar.keywords.to_vec();
+    let mut all_keywords_values = grammar.keywords.to_vec();
+    all_keywords_values.extend(grammar.contextual_keywords);
+    all_keywords_values.sort();
     let all_keywords = all_keywords_values
         .iter()
         .map(|&name| match name {
@@ -358,6 +437,11 @@ fn generate_syntax_kinds(grammar: KindsSrc) -> Result<String> {
                     };
                     Some(kw)
                 }
+
+                pub fn from_contextual_keyword(ident: &str) -> Option<SyntaxKind> {
+                    #(#contextual_conditions)*
+                    None
+                }
             }
         }
         .to_string(),
@@ -467,6 +551,8 @@ struct AstSrc {
 struct AstNodeSrc {
     name: String,
     fields: Vec<Field>,
+    /// Labeled fields with accessors in `node_ext.rs`, in grammar order.
+    manual_fields: Vec<String>,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -488,6 +574,8 @@ fn token_to_name(tk: &str) -> Option<&'static str> {
         "(" => "l_paren",
         "')'" => "r_paren",
         "ident" => "ident",
+        "meta_command" => "meta_command",
+        "copy_data" => "copy_data",
         ")" => "r_paren",
         "'['" => "l_brack",
         "[" => "l_brack",
@@ -615,7 +703,13 @@ fn lower(grammar: &Grammar) -> AstSrc {
             None => {
                 let mut fields = Vec::new();
                 lower_rule(&mut fields, grammar, None, rule);
-                res.nodes.push(AstNodeSrc { name, fields });
+                let mut manual_fields = Vec::new();
+                lower_manual_fields(&mut manual_fields, rule);
+                res.nodes.push(AstNodeSrc {
+                    name,
+                    fields,
+                    manual_fields,
+                });
             }
         }
     }
@@ -729,25 +823,7 @@ fn lower_rule(acc: &mut Vec<Field>, grammar: &Grammar, label: Option<&String>, r
         }
         Rule::Labeled { label: l, rule } => {
             assert!(label.is_none());
-            let manually_implemented = matches!(
-                l.as_str(),
-                "value"
-                // bin expr fields
-                | "lhs" | "rhs" | "op"
-                // between expr fields
-                | "target" | "start" | "end" 
-                // index expr fields
-                | "base" | "index"
-                // field expr fields
-                | /* "base" | */ "field"
-                | /* case expr  */ "condition" | "then"
-                | /* add foreign key constraint */ "from_columns" | "to_columns"
-                | /* rename column */ "from" | "to"
-                | /* reassign owned, alter mapping replace */ "before" | "after"
-                | /* as func option */ "obj_file" | "link_symbol"
-                | /* position & overlay fields */ "pos" | "string" | "placing" | "for"
-            );
-            if manually_implemented {
+            if is_manually_implemented(l) {
                 return;
             }
             lower_rule(acc, grammar, Some(l), rule);
@@ -761,6 +837,45 @@ fn lower_rule(acc: &mut Vec<Field>, grammar: &Grammar, label: Option<&String>, r
     }
 }
 
+fn is_manually_implemented(label: &str) -> bool {
+    matches!(
+        label,
+        "value"
+        // bin expr fields
+        | "lhs" | "rhs" | "op"
+        // between expr fields
+        | "target" | "start" | "end"
+        // index expr fields
+        | "base" | "index"
+        // field expr fields
+        | /* "base" | */ "field"
+        | /* case expr  */ "condition" | "then"
+        | /* add foreign key constraint */ "from_columns" | "to_columns"
+        | /* rename column */ "from" | "to"
+        | /* reassign owned, alter mapping replace */ "before" | "after"
+        | /* as func option */ "obj_file" | "link_symbol"
+        | /* position & overlay fields */ "pos" | "string" | "placing" | "for"
+        | /* plpgsql integer for loop */ "lower" | "upper" | "step"
+    )
+}
+
+fn lower_manual_fields(acc: &mut Vec<String>, rule: &Rule) {
+    match rule {
+        Rule::Labeled { label, .. } => {
+            if is_manually_implemented(label) && !acc.contains(label) {
+                acc.push(label.clone());
+            }
+        }
+        Rule::Seq(rules) | Rule::Alt(rules) => {
+            for rule in rules {
+                lower_manual_fields(acc, rule);
+            }
+        }
+        Rule::Opt(rule) | Rule::Rep(rule) => lower_manual_fields(acc, rule),
+        Rule::Node(_) | Rule::Token(_) => (),
+    }
+}
+
 fn contains_token(grammar: &Grammar, rule: &Rule, expected: &str) -> bool {
     match rule {
         Rule::Labeled { rule, .. } | Rule::Opt(rule) | Rule::Rep(rule) => {
@@ -994,6 +1109,56 @@ fn generate_nodes(nodes: &[AstNodeSrc], enums: &[AstEnumSrc]) -> String {
     format!("{PRELUDE}{output}")
 }
 
+fn generate_json_fields(nodes: &[AstNodeSrc]) -> String {
+    let arms = nodes.iter().filter_map(|node| {
+        if node.fields.is_empty() && node.manual_fields.is_empty() {
+            return None;
+        }
+        let name = format_ident!("{}", node.name.to_case(Case::Pascal));
+        let kind = format_ident!("{}", node.name.to_case(Case::UpperSnake));
+        let manual_fields = node.manual_fields.iter().map(|field| {
+            // `for` is a keyword, so the accessor is `for_`
+            let method_name = if field == "for" {
+                format_ident!("for_")
+            } else {
+                format_ident!("{}", field)
+            };
+            quote! { fields.add(#field, node.#method_name()); }
+        });
+        let generated_fields = node.fields.iter().map(|field| {
+            let method_name = format_ident!("{}", field.method_name());
+            let field_name = match field {
+                Field::Node { name, .. } => name.clone(),
+                Field::Token(_) => field.method_name(),
+            };
+            quote! { fields.add(#field_name, node.#method_name()); }
+        });
+        Some(quote! {
+            SyntaxKind::#kind => {
+                let node = ast::#name { syntax: node.clone() };
+                #(#manual_fields)*
+                #(#generated_fields)*
+            }
+        })
+    });
+
+    let file = quote! {
+        use crate::SyntaxKind;
+        use crate::ast;
+        use crate::json::Fields;
+        use crate::syntax_node::SyntaxNode;
+
+        pub(crate) fn fields(node: &SyntaxNode, fields: &mut Fields) {
+            match node.kind() {
+                #(#arms)*
+                _ => (),
+            }
+        }
+    };
+    let output = reformat(file.to_string());
+    format!("{PRELUDE}{output}")
+}
+
 // Multi-word keyword phrases that should be highlighted as keywords, not
 // operators.
 const KEYWORD_PHRASES: &[&str] = &[
@@ -19,6 +19,8 @@ import {
   provideHover,
   provideDefinition,
   provideReferences,
+  provideRenameEdits,
+  resolveRenameLocation,
   provideDocumentSymbols,
   provideFoldingRanges,
   provideSelectionRanges,
@@ -467,6 +469,11 @@ function registerMonacoProvidersOnce() {
     },
   )
 
+  const renameProvider = monaco.languages.registerRenameProvider("pgsql", {
+    provideRenameEdits,
+    resolveRenameLocation,
+  })
+
   const documentSymbolProvider =
     monaco.languages.registerDocumentSymbolProvider("pgsql", {
       provideDocumentSymbols,
@@ -512,6 +519,7 @@ function registerMonacoProvidersOnce() {
     hoverProvider.dispose()
     definitionProvider.dispose()
     referencesProvider.dispose()
+    renameProvider.dispose()
     documentSymbolProvider.dispose()
     foldingRangeProvider.dispose()
     inlayHintsProvider.dispose()
@@ -8,6 +8,8 @@ import {
   goto_definition,
   hover,
   inlay_hints,
+  prepare_rename,
+  rename,
   selection_ranges,
   semantic_tokens,
   semantic_tokens_legend,
@@ -248,6 +250,80 @@ export async function provideReferences(
   }
 }
 
+export async function resolveRenameLocation(
+  model: monaco.editor.ITextModel,
+  position: monaco.Position,
+): Promise<monaco.languages.RenameLocation & monaco.languages.Rejection> {
+  const content = model.getValue()
+  const version = model.getVersionId()
+
+  try {
+    const result = prepare_rename(
+      content,
+      version,
+      position.lineNumber - 1,
+      position.column - 1,
+    )
+    const range = {
+      startLineNumber: result.start_line + 1,
+      startColumn: result.start_column + 1,
+      endLineNumber: result.end_line + 1,
+      endColumn: result.end_column + 1,
+    }
+    return { range, text: model.getValueInRange(range) }
+  } catch (e) {
+    return {
+      range: new monaco.Range(
+        position.lineNumber,
+        position.column,
+        position.lineNumber,
+        position.column,
+      ),
+      text: "",
+      rejectReason: e instanceof Error ? e.message : String(e),
+    }
+  }
+}
+
+export async function provideRenameEdits(
+  model: monaco.editor.ITextModel,
+  position: monaco.Position,
+  newName: string,
+): Promise<monaco.languages.WorkspaceEdit & monaco.languages.Rejection> {
+  const content = model.getValue()
+  const version = model.getVersionId()
+
+  try {
+    const edits = rename(
+      content,
+      version,
+      position.lineNumber - 1,
+      position.column - 1,
+      newName,
+    )
+    return {
+      edits: edits.map((edit) => ({
+        resource: model.uri,
+        versionId: version,
+        textEdit: {
+          range: {
+            startLineNumber: edit.start_line_number + 1,
+            startColumn: edit.start_column + 1,
+            endLineNumber: edit.end_line_number + 1,
+            endColumn: edit.end_column + 1,
+          },
+          text: edit.text,
+        },
+      })),
+    }
+  } catch (e) {
+    return {
+      edits: [],
+      rejectReason: e instanceof Error ? e.message : String(e),
+    }
+  }
+}
+
 export async function provideSelectionRanges(
   model: monaco.editor.ITextModel,
   positions: monaco.Position[],
@@ -322,6 +398,8 @@ function convertCompletionKind(
       return monaco.languages.CompletionItemKind.TypeParameter
     case "snippet":
       return monaco.languages.CompletionItemKind.Snippet
+    case "variable":
+      return monaco.languages.CompletionItemKind.Variable
     default:
       return monaco.languages.CompletionItemKind.Text
   }
@@ -102,6 +102,25 @@ export function find_references(
   return getDb(content, version).find_references(line, column)
 }
 
+export function prepare_rename(
+  content: string,
+  version: number,
+  line: number,
+  column: number,
+): LocationRange {
+  return getDb(content, version).prepare_rename(line, column)
+}
+
+export function rename(
+  content: string,
+  version: number,
+  line: number,
+  column: number,
+  newName: string,
+): TextEdit[] {
+  return getDb(content, version).rename(line, column, newName)
+}
+
 export function selection_ranges(
   content: string,
   version: number,
@@ -143,6 +162,10 @@ export function dump_cst(content: string, version: number): string {
   return getDb(content, version).dump_cst()
 }
 
+export function dump_json(content: string, version: number): string {
+  return getDb(content, version).dump_json()
+}
+
 export function dump_tokens(content: string, version: number): string {
   return getDb(content, version).dump_tokens()
 }
@@ -282,6 +282,11 @@ async function startServer(context: vscode.ExtensionContext) {
     documentSelector: [{ language: "sql" }, { language: "postgres" }],
     traceOutputChannel: new LazyOutputChannel("Squawk Trace"),
     outputChannel: vscode.window.createOutputChannel("Squawk Language Server"),
+    initializationOptions: {
+      pgVersion: vscode.workspace
+        .getConfiguration("squawk")
+        .get<string | null>("pgVersion", null),
+    },
   }
   client = new LanguageClient(
     "squawk",